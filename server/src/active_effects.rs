@@ -104,6 +104,44 @@ pub enum EffectType {
 
     // === CHEWING GUM ===
     ChewingGum, // Continuous chewing sound from player position (2 min per gum, stacks on consume)
+
+    // === DIVING ===
+    Drowning, // Out of oxygen while submerged - fixed damage per tick until the player surfaces
+
+    // === PARRY ===
+    Staggered, // Attack was parried - briefly unable to attack
+
+    // === MEDICAL ===
+    Fractured,      // Broken limb from heavy blows or impacts - half speed, no sprint or dodge roll until splinted or healed
+    MedKitCooldown, // Med kits can't be used again until this expires
+
+    // === DISEASES ===
+    Dysentery,  // Untreated food poisoning progressed - extra thirst drain until cured by a brew
+    CommonCold, // Caught in blizzards or from sick players nearby - extra warmth loss, contagious
+
+    // === HAZARDS ===
+    MemoryStatic, // Inside a charged rune stone's static without a ward - insanity and damage over time
+
+    // === WORLD EVENTS ===
+    AuroraBlessing, // Stood under an aurora over the arctic - faster crafting and insanity relief for a while
+}
+
+/// One effect as shown to other players (icon above the player's head)
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub struct VisibleEffect {
+    pub effect_type: EffectType,
+    pub ends_at: Option<Timestamp>, // None for effects without a meaningful end (zone effects, drowning)
+}
+
+/// Compact public summary of each player's visible effects, so other clients can render icons.
+/// Only effects on the public allowlist appear here - see is_effect_publicly_visible.
+#[table(accessor = player_visible_effects, public)]
+#[derive(Clone, Debug)]
+pub struct PlayerVisibleEffects {
+    #[primary_key]
+    pub player_id: Identity,
+    pub effects: Vec<VisibleEffect>,
+    pub updated_at: Timestamp,
 }
 
 // Table defining food poisoning risks for different food items
@@ -116,6 +154,7 @@ pub struct FoodPoisoningRisk {
     pub damage_per_tick: f32, // Damage dealt per tick
     pub duration_seconds: f32, // How long the poisoning lasts
     pub tick_interval_seconds: f32, // How often damage is applied
+    pub dysentery_chance_percent: f32, // 0.0 to 100.0 chance the poisoning progresses to dysentery if untreated
 }
 
 // Schedule table for processing effects
@@ -287,6 +326,9 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
     // Process hot combat ladle: apply self-burn if holding hot ladle without gloves
     process_hot_combat_ladle_self_burn(ctx);
 
+    // Refresh the public effect icons feed before the quick exit so cleared effects disappear too
+    refresh_player_visible_effects(ctx);
+
     // PERFORMANCE: Quick exit if no active effects exist (most common case when idle)
     if ctx.db.active_consumable_effect().iter().next().is_none() {
         return Ok(());
@@ -298,6 +340,8 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
     // A temporary Vec to store effects that need item consumption to avoid borrowing issues with ctx.db
     let mut effects_requiring_consumption: Vec<(u64, Identity, EffectType, Option<f32>)> = Vec::new();
     let mut player_ids_who_took_external_damage_this_tick = std::collections::HashSet::<Identity>::new(); // Renamed for clarity
+    // Completed medical channels (item_def_id, healer, target) - splints and med kit cooldowns are applied after the loop
+    let mut completed_medical_channels: Vec<(u64, Identity, Identity)> = Vec::new();
 
     for effect_row in ctx.db.active_consumable_effect().iter() {
         let effect = effect_row.clone(); // Clone to work with
@@ -307,7 +351,7 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
 
     // Skip cozy, tree cover, exhausted, building privilege, rune stone effects, hot spring, fumarole, safe zone, fishing village bonus, and Lagunov's Ghost - they are managed by other systems, not the effect tick system
     // These effects are permanent until removed by other systems, so skip them entirely
-    if effect.effect_type == EffectType::Cozy || effect.effect_type == EffectType::TreeCover || effect.effect_type == EffectType::Exhausted || effect.effect_type == EffectType::BuildingPrivilege || effect.effect_type == EffectType::ProductionRune || effect.effect_type == EffectType::AgrarianRune || effect.effect_type == EffectType::MemoryRune || effect.effect_type == EffectType::HotSpring || effect.effect_type == EffectType::Fumarole || effect.effect_type == EffectType::SafeZone || effect.effect_type == EffectType::FishingVillageBonus || effect.effect_type == EffectType::NearCookingStation || effect.effect_type == EffectType::LagunovGhost || effect.effect_type == EffectType::MemoryBeaconSanity {
+    if effect.effect_type == EffectType::Cozy || effect.effect_type == EffectType::TreeCover || effect.effect_type == EffectType::Exhausted || effect.effect_type == EffectType::BuildingPrivilege || effect.effect_type == EffectType::ProductionRune || effect.effect_type == EffectType::AgrarianRune || effect.effect_type == EffectType::MemoryRune || effect.effect_type == EffectType::HotSpring || effect.effect_type == EffectType::Fumarole || effect.effect_type == EffectType::SafeZone || effect.effect_type == EffectType::FishingVillageBonus || effect.effect_type == EffectType::NearCookingStation || effect.effect_type == EffectType::LagunovGhost || effect.effect_type == EffectType::MemoryBeaconSanity || effect.effect_type == EffectType::MemoryStatic {
         continue;
     }
     
@@ -366,7 +410,7 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
                         effect.target_player_id
                     },
                     // Other effect types shouldn't reach this code path, but we need to handle them
-                    EffectType::HealthRegen | EffectType::Burn | EffectType::Bleed | EffectType::Venom | EffectType::SeawaterPoisoning | EffectType::FoodPoisoning | EffectType::Cozy | EffectType::Wet | EffectType::TreeCover | EffectType::WaterDrinking | EffectType::Exhausted | EffectType::BuildingPrivilege | EffectType::ProductionRune | EffectType::AgrarianRune | EffectType::MemoryRune | EffectType::HotSpring | EffectType::Fumarole | EffectType::SafeZone | EffectType::FishingVillageBonus | EffectType::NearCookingStation | EffectType::Intoxicated | EffectType::Poisoned | EffectType::SpeedBoost | EffectType::StaminaBoost | EffectType::NightVision | EffectType::WarmthBoost | EffectType::ColdResistance | EffectType::PoisonResistance | EffectType::FireResistance | EffectType::PoisonCoating | EffectType::PassiveHealthRegen | EffectType::HarvestBoost | EffectType::Entrainment | EffectType::ValidolProtection | EffectType::BrewCooldown | EffectType::Stun | EffectType::LagunovGhost | EffectType::MemoryBeaconSanity | EffectType::HotCombatLadle | EffectType::ChewingGum => {
+                    EffectType::HealthRegen | EffectType::Burn | EffectType::Bleed | EffectType::Venom | EffectType::SeawaterPoisoning | EffectType::FoodPoisoning | EffectType::Cozy | EffectType::Wet | EffectType::TreeCover | EffectType::WaterDrinking | EffectType::Exhausted | EffectType::BuildingPrivilege | EffectType::ProductionRune | EffectType::AgrarianRune | EffectType::MemoryRune | EffectType::HotSpring | EffectType::Fumarole | EffectType::SafeZone | EffectType::FishingVillageBonus | EffectType::NearCookingStation | EffectType::Intoxicated | EffectType::Poisoned | EffectType::SpeedBoost | EffectType::StaminaBoost | EffectType::NightVision | EffectType::WarmthBoost | EffectType::ColdResistance | EffectType::PoisonResistance | EffectType::FireResistance | EffectType::PoisonCoating | EffectType::PassiveHealthRegen | EffectType::HarvestBoost | EffectType::Entrainment | EffectType::ValidolProtection | EffectType::BrewCooldown | EffectType::Stun | EffectType::LagunovGhost | EffectType::MemoryBeaconSanity | EffectType::HotCombatLadle | EffectType::ChewingGum | EffectType::Drowning | EffectType::Staggered | EffectType::Fractured | EffectType::MedKitCooldown | EffectType::Dysentery | EffectType::CommonCold | EffectType::MemoryStatic | EffectType::AuroraBlessing => {
                         log::warn!("[EffectTick] Unexpected effect type {:?} in bandage processing", effect.effect_type);
                         Some(effect.player_id)
                     }
@@ -452,6 +496,7 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
                             log::warn!("[EffectTick] No health change detected for target {:?}. Old: {:.2}, New: {:.2}", 
                                 target_id, old_health, target_player_to_update.health);
                         }
+                        completed_medical_channels.push((effect.item_def_id, effect.player_id, target_id));
                         effect_ended = true;
                     } else {
                         // Timer still running for BandageBurst, do nothing to health, don't end yet.
@@ -477,7 +522,7 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
             EffectType::ColdResistance | EffectType::PoisonResistance | EffectType::FireResistance |
             EffectType::PoisonCoating | EffectType::HarvestBoost | EffectType::BrewCooldown);
         
-        if effect.effect_type == EffectType::Wet || effect.effect_type == EffectType::WaterDrinking || effect.effect_type == EffectType::Stun || effect.effect_type == EffectType::ValidolProtection || is_broth_buff_effect {
+        if effect.effect_type == EffectType::Wet || effect.effect_type == EffectType::WaterDrinking || effect.effect_type == EffectType::Stun || effect.effect_type == EffectType::Staggered || effect.effect_type == EffectType::Fractured || effect.effect_type == EffectType::MedKitCooldown || effect.effect_type == EffectType::Dysentery || effect.effect_type == EffectType::CommonCold || effect.effect_type == EffectType::AuroraBlessing || effect.effect_type == EffectType::ValidolProtection || is_broth_buff_effect {
             // These effects are purely time-based, no per-tick processing needed
             // They just exist until they expire or are removed
             // Check for time-based expiration (this was missing, causing effects to persist indefinitely!)
@@ -514,7 +559,7 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
                             log::trace!("[EffectTick] HealthRegen Post-Heal for Player {:?}: Health now {:.2}",
                                 effect.player_id, player_to_update.health);
                         }
-                        EffectType::Bleed | EffectType::Burn | EffectType::Venom | EffectType::Entrainment => {
+                        EffectType::Bleed | EffectType::Burn | EffectType::Venom | EffectType::Entrainment | EffectType::Drowning => {
                             log::trace!("[EffectTick] {:?} Pre-Damage for Player {:?}: Health {:.2}, AmountThisTick {:.2}",
                                 effect.effect_type, effect.player_id, player_to_update.health, amount_this_tick);
                             
@@ -526,8 +571,8 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
                             }
                             // --- END SAFE ZONE CHECK ---
                             
-                            // --- KNOCKED OUT PLAYERS ARE IMMUNE TO BLEED, BURN, VENOM, AND ENTRAINMENT DAMAGE ---
-                            if player_to_update.is_knocked_out && (effect.effect_type == EffectType::Bleed || effect.effect_type == EffectType::Burn || effect.effect_type == EffectType::Venom || effect.effect_type == EffectType::Entrainment) {
+                            // --- KNOCKED OUT PLAYERS ARE IMMUNE TO BLEED, BURN, VENOM, ENTRAINMENT, AND DROWNING DAMAGE ---
+                            if player_to_update.is_knocked_out && (effect.effect_type == EffectType::Bleed || effect.effect_type == EffectType::Burn || effect.effect_type == EffectType::Venom || effect.effect_type == EffectType::Entrainment || effect.effect_type == EffectType::Drowning) {
                                 // Knocked out players are completely immune to DOT damage
                                 amount_this_tick = 0.0; // No damage applied
                                 log::info!("[EffectTick] Knocked out player {:?} is immune to {:?} damage. No damage applied.",
@@ -563,6 +608,12 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
                                     }
                                 }
                                 
+                                // Drowning: fixed damage per tick while out of oxygen (cancelled on surfacing)
+                                if effect.effect_type == EffectType::Drowning {
+                                    amount_this_tick = DROWNING_DAMAGE_PER_TICK;
+                                    log::trace!("Player {:?} taking Drowning damage: {:.1} HP", effect.player_id, amount_this_tick);
+                                }
+                                
                                 // <<< APPLY FIRE DAMAGE MULTIPLIER FOR BURN EFFECTS (WOODEN ARMOR VULNERABILITY) >>>
                                 if effect.effect_type == EffectType::Burn {
                                     let fire_multiplier = armor::calculate_fire_damage_multiplier(ctx, effect.player_id);
@@ -845,6 +896,30 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
                             // No per-tick stat changes, just a flag that expires
                             amount_this_tick = 0.0;
                         },
+                        EffectType::Staggered => {
+                            // Staggered blocks attacking after being parried (checked in active_equipment.rs)
+                            // No per-tick stat changes, just a flag that expires
+                            amount_this_tick = 0.0;
+                        },
+                        EffectType::Fractured | EffectType::MedKitCooldown => {
+                            // Medical flags: fractures block sprinting/dodging (player_movement.rs),
+                            // the med kit cooldown blocks another med kit (active_equipment.rs)
+                            amount_this_tick = 0.0;
+                        },
+                        EffectType::Dysentery | EffectType::CommonCold => {
+                            // Diseases: their thirst/warmth drains are applied in player_stats.rs
+                            amount_this_tick = 0.0;
+                        },
+                        EffectType::MemoryStatic => {
+                            // Zone effect managed by update_player_memory_static_status
+                            // Insanity and damage are applied in player_stats.rs
+                            amount_this_tick = 0.0;
+                        },
+                        EffectType::AuroraBlessing => {
+                            // Timed buff refreshed by update_player_aurora_blessing while under the aurora
+                            // Crafting speed is applied in crafting_queue.rs, insanity relief in player_stats.rs
+                            amount_this_tick = 0.0;
+                        },
                         EffectType::LagunovGhost => {
                             // Lagunov's Ghost is an informational effect for shipwreck protection
                             // No per-tick processing needed - managed by proximity system
@@ -877,10 +952,15 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
                         log::info!("[BurnDamage] Health NOT changed for player {:?} (old={:.1}, new={:.1}), player_effect_applied=false", 
                             effect.player_id, old_health, player_to_update.health);
                     }
+
+                    // Damage-over-time ticks show up in the death recap
+                    if let Some(cause) = effect_damage_cause(&effect.effect_type) {
+                        crate::kill_feed::record_damage_event(ctx, effect.player_id, None, cause, None, old_health - player_to_update.health, player_to_update.health);
+                    }
                     
-                    // For SeawaterPoisoning, Venom, and Entrainment, we don't track amount_applied_so_far
+                    // For SeawaterPoisoning, Venom, Entrainment, and Drowning, we don't track amount_applied_so_far
                     // These effects: fixed damage per tick, ends based on time only
-                    if effect.effect_type != EffectType::SeawaterPoisoning && effect.effect_type != EffectType::Venom && effect.effect_type != EffectType::Entrainment {
+                    if effect.effect_type != EffectType::SeawaterPoisoning && effect.effect_type != EffectType::Venom && effect.effect_type != EffectType::Entrainment && effect.effect_type != EffectType::Drowning {
                         current_effect_applied_so_far += amount_this_tick; // Increment amount applied *for this effect*
                     }
 
@@ -906,8 +986,11 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
             EffectType::ColdResistance | EffectType::PoisonResistance | EffectType::FireResistance |
             EffectType::PoisonCoating | EffectType::HarvestBoost | EffectType::BrewCooldown);
         
-        if effect.effect_type == EffectType::SeawaterPoisoning || effect.effect_type == EffectType::Venom || effect.effect_type == EffectType::Entrainment || 
-           effect.effect_type == EffectType::Wet || effect.effect_type == EffectType::WaterDrinking || effect.effect_type == EffectType::Stun ||
+        if effect.effect_type == EffectType::SeawaterPoisoning || effect.effect_type == EffectType::Venom || effect.effect_type == EffectType::Entrainment || effect.effect_type == EffectType::Drowning || 
+           effect.effect_type == EffectType::Wet || effect.effect_type == EffectType::WaterDrinking || effect.effect_type == EffectType::Stun || effect.effect_type == EffectType::Staggered ||
+           effect.effect_type == EffectType::Fractured || effect.effect_type == EffectType::MedKitCooldown ||
+           effect.effect_type == EffectType::Dysentery || effect.effect_type == EffectType::CommonCold ||
+           effect.effect_type == EffectType::AuroraBlessing ||
            effect.effect_type == EffectType::ValidolProtection || effect.effect_type == EffectType::HotCombatLadle || is_broth_buff_effect_end_check {
             if current_time >= effect.ends_at {
                 effect_ended = true;
@@ -1019,6 +1102,19 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
         log::debug!("[EffectTick] Player {:?} took external damage this tick. Cancelling their BandageBurst effects.", player_id_damaged);
         cancel_bandage_burst_effects(ctx, player_id_damaged);
     }
+
+    // --- Finish medical channels: splints set fractures, med kits start their cooldown ---
+    for (item_def_id, healer_id, target_id) in completed_medical_channels {
+        match ctx.db.item_definition().id().find(item_def_id).map(|def| def.name) {
+            Some(name) if name == "Splint" => cure_fractured_effect(ctx, target_id),
+            Some(name) if name == "Med Kit" => {
+                if let Err(e) = apply_med_kit_cooldown(ctx, healer_id) {
+                    log::error!("[EffectTick] Failed to start med kit cooldown for {:?}: {}", healer_id, e);
+                }
+            }
+            _ => {}
+        }
+    }
     
     // --- Consume items for effects that ended and had a consuming_item_instance_id ---
     for (item_instance_id, player_id, effect_type, amount_applied) in effects_requiring_consumption {
@@ -1066,6 +1162,32 @@ pub fn process_active_consumable_effects_tick(ctx: &ReducerContext, _args: Proce
     Ok(())
 }
 
+/// Effects that deal health damage over time (see effect_damage_cause)
+const DAMAGE_OVER_TIME_EFFECTS: [EffectType; 7] = [
+    EffectType::Bleed, EffectType::Burn, EffectType::Venom,
+    EffectType::Entrainment, EffectType::FoodPoisoning, EffectType::Poisoned,
+    EffectType::Drowning,
+];
+
+/// Damage-over-time effects and the cause they're reported under in the kill feed and death recap
+pub fn effect_damage_cause(effect_type: &EffectType) -> Option<&'static str> {
+    match effect_type {
+        EffectType::Bleed => Some("Bleeding"),
+        EffectType::Burn => Some("Burning"),
+        EffectType::Venom => Some("Venom"),
+        EffectType::Entrainment => Some("Entrainment"),
+        EffectType::FoodPoisoning => Some("Food Poisoning"),
+        EffectType::Poisoned => Some("Poison"),
+        EffectType::Drowning => Some("Drowning"),
+        _ => None,
+    }
+}
+
+/// Whether a damage recap source name is a damage-over-time effect cause
+pub fn is_effect_damage_cause(source_name: &str) -> bool {
+    DAMAGE_OVER_TIME_EFFECTS.iter().any(|effect_type| effect_damage_cause(effect_type) == Some(source_name))
+}
+
 pub fn cancel_health_regen_effects(ctx: &ReducerContext, player_id: Identity) {
     let mut effects_to_cancel = Vec::new();
     for effect in ctx.db.active_consumable_effect().player_id().filter(&player_id) {
@@ -1079,6 +1201,12 @@ pub fn cancel_health_regen_effects(ctx: &ReducerContext, player_id: Identity) {
     }
 }
 
+/// Checks if a player is currently bleeding (used by shark blood-in-water perception)
+pub fn player_has_bleed_effect(ctx: &ReducerContext, player_id: Identity) -> bool {
+    ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .any(|effect| effect.effect_type == EffectType::Bleed)
+}
+
 pub fn cancel_bleed_effects(ctx: &ReducerContext, player_id: Identity) {
     let mut effects_to_cancel = Vec::new();
     for effect in ctx.db.active_consumable_effect().player_id().filter(&player_id) {
@@ -1150,6 +1278,11 @@ pub fn apply_food_poisoning_effect(ctx: &ReducerContext, player_id: Identity, it
         emit_throwing_up_sound(ctx, player.position_x, player.position_y, player_id);
     }
 
+    // Some foods leave an infection behind that turns into dysentery unless treated in time
+    if ctx.rng().gen_range(0.0..100.0) < poisoning_risk.dysentery_chance_percent {
+        schedule_dysentery_progression(ctx, player_id);
+    }
+
     // Check for existing food poisoning effects and extend if found (indexed lookup)
     for existing_effect in ctx.db.active_consumable_effect().player_id().filter(&player_id) {
         if existing_effect.effect_type == EffectType::FoodPoisoning {
@@ -2127,6 +2260,7 @@ pub fn clear_all_effects_on_death(ctx: &ReducerContext, player_id: Identity) {
     for effect_id in effects_to_remove {
         ctx.db.active_consumable_effect().effect_id().delete(&effect_id);
     }
+    cancel_disease_progressions(ctx, player_id);
     
     log::info!("[PlayerDeath] Cleared all active effects for deceased player {:?}", player_id);
 }
@@ -2185,6 +2319,104 @@ pub fn update_player_rune_stone_zone_effects(ctx: &ReducerContext, player_id: Id
     Ok(())
 }
 
+/// Updates the memory static hazard effect: applied inside a charged rune stone's static
+/// unless the player wears static protection (Static Ward Cowl)
+pub fn update_player_memory_static_status(ctx: &ReducerContext, player_id: Identity, player_x: f32, player_y: f32) -> Result<(), String> {
+    let is_exposed = crate::rune_stone::is_position_in_memory_static(ctx, player_x, player_y)
+        && !armor::has_environmental_protection(ctx, player_id, crate::models::EnvironmentalHazard::Static);
+    let has_static_effect = player_has_memory_static_effect(ctx, player_id);
+
+    if is_exposed && !has_static_effect {
+        let current_time = ctx.timestamp;
+        let effect = ActiveConsumableEffect {
+            effect_id: 0,
+            player_id,
+            target_player_id: None,
+            item_def_id: 0,
+            consuming_item_instance_id: None,
+            started_at: current_time,
+            ends_at: current_time + TimeDuration::from_micros(365 * 24 * 60 * 60 * 1_000_000i64), // Until the player leaves
+            total_amount: None,
+            amount_applied_so_far: None,
+            effect_type: EffectType::MemoryStatic,
+            tick_interval_micros: 1_000_000,
+            next_tick_at: current_time + TimeDuration::from_micros(1_000_000),
+        };
+        ctx.db.active_consumable_effect().try_insert(effect)
+            .map_err(|e| format!("Failed to apply memory static effect: {:?}", e))?;
+        log::info!("Player {:?} entered memory static unprotected", player_id);
+    } else if !is_exposed && has_static_effect {
+        let effect_ids: Vec<u64> = ctx.db.active_consumable_effect().player_id().filter(&player_id)
+            .filter(|e| e.effect_type == EffectType::MemoryStatic)
+            .map(|e| e.effect_id)
+            .collect();
+        for effect_id in effect_ids {
+            ctx.db.active_consumable_effect().effect_id().delete(&effect_id);
+        }
+        log::info!("Player {:?} is no longer exposed to memory static", player_id);
+    }
+    Ok(())
+}
+
+/// Checks if a player is exposed to memory static
+pub fn player_has_memory_static_effect(ctx: &ReducerContext, player_id: Identity) -> bool {
+    ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .any(|effect| effect.effect_type == EffectType::MemoryStatic)
+}
+
+pub const AURORA_BLESSING_DURATION_SECS: i64 = 600; // Lingers 10 minutes after leaving the aurora
+pub const AURORA_CRAFTING_TIME_MULTIPLIER: f32 = 0.75; // 25% faster crafting
+pub const AURORA_INSANITY_REDUCTION_PER_SECOND: f32 = 0.5;
+/// Only refresh the blessing once it has run down this far, to avoid rewriting it every stat tick
+const AURORA_BLESSING_REFRESH_THRESHOLD_SECS: i64 = AURORA_BLESSING_DURATION_SECS - 60;
+
+/// Grants or refreshes the aurora blessing while the player stands under an active aurora
+pub fn update_player_aurora_blessing(ctx: &ReducerContext, player_id: Identity, player_x: f32, player_y: f32) -> Result<(), String> {
+    if !crate::world_state::is_under_aurora(ctx, player_x, player_y) {
+        return Ok(()); // The blessing runs out on its own
+    }
+    let current_time = ctx.timestamp;
+    let ends_at = current_time + TimeDuration::from_micros(AURORA_BLESSING_DURATION_SECS * 1_000_000);
+    let existing = ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .find(|e| e.effect_type == EffectType::AuroraBlessing);
+
+    match existing {
+        Some(mut effect) => {
+            let remaining_micros = effect.ends_at.to_micros_since_unix_epoch() - current_time.to_micros_since_unix_epoch();
+            if remaining_micros < AURORA_BLESSING_REFRESH_THRESHOLD_SECS * 1_000_000 {
+                effect.ends_at = ends_at;
+                ctx.db.active_consumable_effect().effect_id().update(effect);
+            }
+        }
+        None => {
+            let effect = ActiveConsumableEffect {
+                effect_id: 0,
+                player_id,
+                target_player_id: None,
+                item_def_id: 0,
+                consuming_item_instance_id: None,
+                started_at: current_time,
+                ends_at,
+                total_amount: None,
+                amount_applied_so_far: None,
+                effect_type: EffectType::AuroraBlessing,
+                tick_interval_micros: 1_000_000,
+                next_tick_at: current_time + TimeDuration::from_micros(1_000_000),
+            };
+            ctx.db.active_consumable_effect().try_insert(effect)
+                .map_err(|e| format!("Failed to apply aurora blessing: {:?}", e))?;
+            log::info!("Player {:?} received the aurora blessing", player_id);
+        }
+    }
+    Ok(())
+}
+
+/// Checks if a player is blessed by the aurora
+pub fn player_has_aurora_blessing_effect(ctx: &ReducerContext, player_id: Identity) -> bool {
+    ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .any(|effect| effect.effect_type == EffectType::AuroraBlessing)
+}
+
 /// Checks if a player has the production rune effect
 pub fn player_has_production_rune_effect(ctx: &ReducerContext, player_id: Identity) -> bool {
     ctx.db.active_consumable_effect().player_id().filter(&player_id)
@@ -2544,86 +2776,6 @@ fn remove_fumarole_effect(ctx: &ReducerContext, player_id: Identity) {
 // Safe Zone Effect Management
 // ============================
 
-/// Safe zone radius multipliers - safe zone extends beyond interaction radius
-/// Central compound gets larger protection, substations get standard protection
-/// These MUST match the building restriction radii to prevent abuse
-pub const SAFE_ZONE_RADIUS_MULTIPLIER_CENTRAL: f32 = 8.75; // 8.75x for central compound (station_id = 0) - scaled 25% from 7.0 to match larger asphalt
-pub const SAFE_ZONE_RADIUS_MULTIPLIER_SUBSTATION: f32 = 3.0; // 3x for substations (station_id 1-4) - 1/3 of original 10x
-
-/// Checks if a player is currently within a safe zone (near ALK monuments)
-/// Safe zones protect from player/animal/projectile damage but NOT environmental damage (except burn)
-/// Safe zone radius matches building restriction radius to prevent abuse
-pub fn is_player_in_safe_zone(ctx: &ReducerContext, player_x: f32, player_y: f32) -> bool {
-    use crate::alk::alk_station as AlkStationTableTrait;
-    use crate::monument_part as MonumentPartTableTrait;
-    use crate::MonumentType;
-    use crate::whale_bone_graveyard::is_position_in_whale_bone_graveyard_safe_zone;
-    use crate::hunting_village::is_position_in_hunting_village_safe_zone;
-    
-    // Fishing village safe zone radius - communal protection area
-    const FISHING_VILLAGE_SAFE_ZONE_RADIUS: f32 = 600.0; // Protective radius around the campfire
-    const FISHING_VILLAGE_SAFE_ZONE_RADIUS_SQ: f32 = FISHING_VILLAGE_SAFE_ZONE_RADIUS * FISHING_VILLAGE_SAFE_ZONE_RADIUS;
-    
-    // Check all ALK stations (central compound + 4 substations)
-    for station in ctx.db.alk_station().iter() {
-        // Skip inactive stations
-        if !station.is_active {
-            continue;
-        }
-        
-        // Calculate distance squared between player and station
-        let dx = player_x - station.world_pos_x;
-        let dy = player_y - station.world_pos_y;
-        let distance_sq = dx * dx + dy * dy;
-        
-        // Safe zone radius matches building restriction radius
-        // Central compound (station_id = 0): 8.75x interaction_radius (~2188px)
-        // Substations (station_id 1-4): 3x interaction_radius (~600px)
-        let multiplier = if station.station_id == 0 {
-            SAFE_ZONE_RADIUS_MULTIPLIER_CENTRAL
-        } else {
-            SAFE_ZONE_RADIUS_MULTIPLIER_SUBSTATION
-        };
-        let safe_zone_radius = station.interaction_radius * multiplier;
-        let safe_zone_radius_sq = safe_zone_radius * safe_zone_radius;
-        
-        // Check if player is within safe zone radius
-        if distance_sq <= safe_zone_radius_sq {
-            return true;
-        }
-    }
-    
-    // Check fishing village communal safe zone
-    // The village center provides protection from PvP and hostile animal attacks
-    for part in ctx.db.monument_part().iter() {
-        // Only the center piece creates the safe zone
-        if part.monument_type != MonumentType::FishingVillage || !part.is_center {
-            continue;
-        }
-        let dx = player_x - part.world_x;
-        let dy = player_y - part.world_y;
-        let distance_sq = dx * dx + dy * dy;
-        
-        if distance_sq <= FISHING_VILLAGE_SAFE_ZONE_RADIUS_SQ {
-            return true;
-        }
-    }
-    
-    // Check whale bone graveyard safe zone
-    // The ancient graveyard and hermit's camp provide protection
-    if is_position_in_whale_bone_graveyard_safe_zone(ctx, player_x, player_y) {
-        return true;
-    }
-    
-    // Check hunting village safe zone
-    // The boreal hunting village provides a safe haven for travelers
-    if is_position_in_hunting_village_safe_zone(ctx, player_x, player_y) {
-        return true;
-    }
-    
-    false
-}
-
 /// Container access timeout in seconds for safe zone exclusivity
 const CONTAINER_ACCESS_TIMEOUT_SECS: u64 = 60;
 
@@ -2640,7 +2792,7 @@ pub fn validate_safe_zone_container_access(
     active_user_since: Option<spacetimedb::Timestamp>,
 ) -> Result<(), String> {
     // Only enforce exclusivity in safe zones
-    if !is_player_in_safe_zone(ctx, container_pos_x, container_pos_y) {
+    if !crate::safe_zone::is_position_in_safe_zone(ctx, container_pos_x, container_pos_y) {
         return Ok(());
     }
     
@@ -2667,8 +2819,9 @@ pub fn validate_safe_zone_container_access(
 
 /// Updates safe zone effect for a player based on their position
 /// Safe zones protect from player/animal/projectile damage but NOT environmental damage (except burn)
+/// Protection is only regained after the re-entry grace timer (see `safe_zone`)
 pub fn update_player_safe_zone_status(ctx: &ReducerContext, player_id: Identity, player_x: f32, player_y: f32) -> Result<(), String> {
-    let is_in_safe_zone = is_player_in_safe_zone(ctx, player_x, player_y);
+    let is_in_safe_zone = crate::safe_zone::is_position_in_pvp_safe_zone(ctx, player_x, player_y);
     let has_safe_zone_effect = player_has_safe_zone_effect(ctx, player_id);
     
     log::debug!("Safe zone status check for player {:?}: in_safe_zone={}, has_safe_zone_effect={}", 
@@ -2677,6 +2830,10 @@ pub fn update_player_safe_zone_status(ctx: &ReducerContext, player_id: Identity,
     if is_in_safe_zone {
         // Apply safe zone effect if not present
         if !has_safe_zone_effect {
+            if crate::safe_zone::is_reentry_grace_active(ctx, player_id) {
+                log::debug!("Player {:?} re-entered a safe zone during the grace period - protection delayed", player_id);
+                return Ok(());
+            }
             log::info!("Applying safe zone effect to player {:?} (protection from player/animal/projectile damage)", player_id);
             apply_safe_zone_effect(ctx, player_id)?;
         }
@@ -2685,6 +2842,7 @@ pub fn update_player_safe_zone_status(ctx: &ReducerContext, player_id: Identity,
         if has_safe_zone_effect {
             log::info!("Removing safe zone effect from player {:?}", player_id);
             remove_safe_zone_effect(ctx, player_id);
+            crate::safe_zone::record_safe_zone_exit(ctx, player_id);
         }
     }
     
@@ -3721,6 +3879,69 @@ pub fn apply_entrainment_effect(ctx: &ReducerContext, player_id: Identity) -> Re
     }
 }
 
+// ============================================================================
+// DROWNING (Diving - out of oxygen)
+// ============================================================================
+
+/// Damage dealt per Drowning tick (every 2 seconds - ~40 seconds to death from 100 HP)
+pub const DROWNING_DAMAGE_PER_TICK: f32 = 5.0;
+const DROWNING_TICK_INTERVAL_MICROS: u64 = 2_000_000;
+
+/// Checks if a player is currently drowning
+pub fn player_has_drowning_effect(ctx: &ReducerContext, player_id: Identity) -> bool {
+    ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .any(|e| e.effect_type == EffectType::Drowning)
+}
+
+/// Applies Drowning effect - lasts until the player surfaces (cancelled from player_stats.rs) or dies
+pub fn apply_drowning_effect(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
+    if player_has_drowning_effect(ctx, player_id) {
+        return Ok(());
+    }
+
+    let current_time = ctx.timestamp;
+    let duration_micros = (86400.0 * 365.0 * 1_000_000.0) as i64; // Effectively indefinite - removed on surfacing
+
+    let drowning_effect = ActiveConsumableEffect {
+        effect_id: 0, // auto_inc
+        player_id,
+        target_player_id: None,
+        item_def_id: 0, // Not from an item, from running out of oxygen
+        consuming_item_instance_id: None,
+        started_at: current_time,
+        ends_at: current_time + TimeDuration::from_micros(duration_micros),
+        total_amount: Some(f32::MAX),
+        amount_applied_so_far: Some(0.0),
+        effect_type: EffectType::Drowning,
+        tick_interval_micros: DROWNING_TICK_INTERVAL_MICROS,
+        next_tick_at: current_time + TimeDuration::from_micros(DROWNING_TICK_INTERVAL_MICROS as i64),
+    };
+
+    match ctx.db.active_consumable_effect().try_insert(drowning_effect) {
+        Ok(inserted_effect) => {
+            log::info!("Applied Drowning effect {} to player {:?} ({} damage every 2 seconds until surfacing)",
+                inserted_effect.effect_id, player_id, DROWNING_DAMAGE_PER_TICK);
+            Ok(())
+        }
+        Err(e) => {
+            log::error!("Failed to apply Drowning effect to player {:?}: {:?}", player_id, e);
+            Err("Failed to apply Drowning effect".to_string())
+        }
+    }
+}
+
+/// Removes Drowning effects once the player has air again
+pub fn cancel_drowning_effects(ctx: &ReducerContext, player_id: Identity) {
+    let effects_to_cancel: Vec<u64> = ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .filter(|e| e.effect_type == EffectType::Drowning)
+        .map(|e| e.effect_id)
+        .collect();
+    for effect_id in effects_to_cancel {
+        ctx.db.active_consumable_effect().effect_id().delete(&effect_id);
+        log::info!("Cancelled drowning effect {} for player {:?} (surfaced).", effect_id, player_id);
+    }
+}
+
 // ============================================================================
 // VALIDOL PROTECTION (Insanity/Entrainment Countermeasure)
 // ============================================================================
@@ -4138,6 +4359,273 @@ pub fn try_apply_blunt_weapon_stun(
     false
 }
 
+// ============================================================================
+// STAGGERED EFFECT (Parried attackers)
+// ============================================================================
+// A player whose melee attack is parried can't attack for a moment.
+// Duration is tuned in combat_balance (player_stagger_secs).
+
+/// Checks if a player is currently staggered
+pub fn player_has_staggered_effect(ctx: &ReducerContext, player_id: Identity) -> bool {
+    ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .any(|e| e.effect_type == EffectType::Staggered && e.ends_at > ctx.timestamp)
+}
+
+/// Staggers a player (does not stack - an active stagger is left alone)
+pub fn apply_staggered_effect(ctx: &ReducerContext, player_id: Identity, duration_seconds: f32) -> Result<(), String> {
+    if player_has_staggered_effect(ctx, player_id) {
+        return Ok(());
+    }
+
+    let current_time = ctx.timestamp;
+    let ends_at = current_time + TimeDuration::from_micros((duration_seconds * 1_000_000.0) as i64);
+    let stagger_effect = ActiveConsumableEffect {
+        effect_id: 0, // auto_inc
+        player_id,
+        target_player_id: None,
+        item_def_id: 0, // From a parry, not an item
+        consuming_item_instance_id: None,
+        started_at: current_time,
+        ends_at,
+        total_amount: Some(0.0),
+        amount_applied_so_far: Some(0.0),
+        effect_type: EffectType::Staggered,
+        tick_interval_micros: 1_000_000, // Not used - time-based flag
+        next_tick_at: current_time + TimeDuration::from_micros(1_000_000),
+    };
+
+    ctx.db.active_consumable_effect().try_insert(stagger_effect)
+        .map_err(|e| format!("Failed to apply staggered effect: {:?}", e))?;
+    log::info!("[Parry] Player {:?} staggered for {:.1}s", player_id, duration_seconds);
+    Ok(())
+}
+
+// ============================================================================
+// MEDICAL EFFECTS (Fractures and med kit cooldown)
+// ============================================================================
+// Heavy blows and hard impacts against cliffs can fracture a limb, halving movement
+// speed; a splint (BandageBurst channel) sets it.
+// Med kits heal in one burst but then need a long cooldown.
+
+/// Chance that a heavy melee hit on a player fractures a limb
+pub const HEAVY_HIT_FRACTURE_CHANCE: f64 = 0.2;
+/// How long an untreated fracture lasts
+pub const FRACTURE_DURATION_SECS: f32 = 600.0;
+/// Movement speed multiplier while a limb is fractured
+pub const FRACTURE_SPEED_MULTIPLIER: f32 = 0.5;
+/// Cooldown after a med kit has been applied
+pub const MED_KIT_COOLDOWN_SECS: f32 = 180.0;
+
+fn has_timed_effect(ctx: &ReducerContext, player_id: Identity, effect_type: EffectType) -> bool {
+    ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .any(|e| e.effect_type == effect_type && e.ends_at > ctx.timestamp)
+}
+
+fn apply_timed_effect(ctx: &ReducerContext, player_id: Identity, effect_type: EffectType, duration_seconds: f32) -> Result<(), String> {
+    let current_time = ctx.timestamp;
+    let effect = ActiveConsumableEffect {
+        effect_id: 0, // auto_inc
+        player_id,
+        target_player_id: None,
+        item_def_id: 0,
+        consuming_item_instance_id: None,
+        started_at: current_time,
+        ends_at: current_time + TimeDuration::from_micros((duration_seconds * 1_000_000.0) as i64),
+        total_amount: Some(0.0),
+        amount_applied_so_far: Some(0.0),
+        effect_type: effect_type.clone(),
+        tick_interval_micros: 1_000_000, // Not used - time-based flag
+        next_tick_at: current_time + TimeDuration::from_micros(1_000_000),
+    };
+    ctx.db.active_consumable_effect().try_insert(effect)
+        .map_err(|e| format!("Failed to apply {:?} effect: {:?}", effect_type, e))?;
+    Ok(())
+}
+
+/// Checks if a player has a fractured limb
+pub fn player_has_fractured_effect(ctx: &ReducerContext, player_id: Identity) -> bool {
+    has_timed_effect(ctx, player_id, EffectType::Fractured)
+}
+
+/// Fractures a limb (does not stack - an existing fracture is left alone)
+pub fn apply_fractured_effect(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
+    if player_has_fractured_effect(ctx, player_id) {
+        return Ok(());
+    }
+    apply_timed_effect(ctx, player_id, EffectType::Fractured, FRACTURE_DURATION_SECS)?;
+    log::info!("[Medical] Player {:?} fractured a limb", player_id);
+    Ok(())
+}
+
+/// Sets a fractured limb (splint applied)
+pub fn cure_fractured_effect(ctx: &ReducerContext, player_id: Identity) {
+    let fractures: Vec<u64> = ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .filter(|e| e.effect_type == EffectType::Fractured)
+        .map(|e| e.effect_id)
+        .collect();
+    for effect_id in fractures {
+        ctx.db.active_consumable_effect().effect_id().delete(&effect_id);
+        log::info!("[Medical] Fracture {} of player {:?} splinted", effect_id, player_id);
+    }
+}
+
+/// Checks if a player must wait before using another med kit
+pub fn player_has_med_kit_cooldown(ctx: &ReducerContext, player_id: Identity) -> bool {
+    has_timed_effect(ctx, player_id, EffectType::MedKitCooldown)
+}
+
+pub fn apply_med_kit_cooldown(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
+    apply_timed_effect(ctx, player_id, EffectType::MedKitCooldown, MED_KIT_COOLDOWN_SECS)
+}
+
+// ============================================================================
+// DISEASES (Dysentery and common cold)
+// ============================================================================
+// Food poisoning can progress: when the food's dysentery roll hits, a progression is scheduled
+// and turns into dysentery after DYSENTERY_INCUBATION_SECS unless the player drinks a curing
+// brew first. Colds are caught outdoors in blizzards and spread between nearby players.
+// Disease stat drains are applied in player_stats.rs; curing brews are listed in
+// ai_brewing::diseases_cured_by_category.
+
+/// Delay between the poisoning and dysentery setting in - the window for treatment
+pub const DYSENTERY_INCUBATION_SECS: u64 = 120;
+pub const DYSENTERY_DURATION_SECS: f32 = 480.0;
+/// Extra thirst lost per second with dysentery
+pub const DYSENTERY_THIRST_DRAIN_PER_SECOND: f32 = 0.15;
+pub const COMMON_COLD_DURATION_SECS: f32 = 600.0;
+/// Extra warmth lost per second with a cold
+pub const COMMON_COLD_WARMTH_DRAIN_PER_SECOND: f32 = 0.05;
+/// Chance per stat tick to catch a cold outdoors in a blizzard
+const BLIZZARD_COLD_CHANCE_PER_TICK: f32 = 0.01;
+/// Chance per stat tick to catch a cold from each sick player nearby
+const COLD_CONTAGION_CHANCE_PER_TICK: f32 = 0.02;
+const COLD_CONTAGION_RADIUS: f32 = 150.0;
+
+/// A disease stage waiting to set in (one-shot schedule, cancelled by treatment or death)
+#[table(accessor = disease_progression_schedule, scheduled(process_disease_progression))]
+#[derive(Clone, Debug)]
+pub struct DiseaseProgressionSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+    #[index(btree)]
+    pub player_id: Identity,
+    pub next_stage: EffectType,
+}
+
+pub fn player_has_dysentery_effect(ctx: &ReducerContext, player_id: Identity) -> bool {
+    has_timed_effect(ctx, player_id, EffectType::Dysentery)
+}
+
+pub fn player_has_common_cold_effect(ctx: &ReducerContext, player_id: Identity) -> bool {
+    has_timed_effect(ctx, player_id, EffectType::CommonCold)
+}
+
+/// Schedules dysentery for a poisoned player, unless it is already on its way
+fn schedule_dysentery_progression(ctx: &ReducerContext, player_id: Identity) {
+    let schedules = ctx.db.disease_progression_schedule();
+    if schedules.player_id().filter(&player_id).any(|p| p.next_stage == EffectType::Dysentery) {
+        return;
+    }
+    crate::try_insert_schedule!(
+        schedules,
+        DiseaseProgressionSchedule {
+            id: 0,
+            scheduled_at: ScheduleAt::Time(ctx.timestamp + std::time::Duration::from_secs(DYSENTERY_INCUBATION_SECS)),
+            player_id,
+            next_stage: EffectType::Dysentery,
+        },
+        "Dysentery progression"
+    );
+}
+
+/// Drops every pending disease stage for a player (treated or dead)
+fn cancel_disease_progressions(ctx: &ReducerContext, player_id: Identity) {
+    let schedules = ctx.db.disease_progression_schedule();
+    let pending: Vec<u64> = schedules.player_id().filter(&player_id).map(|p| p.id).collect();
+    for id in pending {
+        schedules.id().delete(&id);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn process_disease_progression(ctx: &ReducerContext, progression: DiseaseProgressionSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("process_disease_progression can only be called by the scheduler.".to_string());
+    }
+    let player_id = progression.player_id;
+    if !ctx.db.player().identity().find(&player_id).is_some_and(|p| !p.is_dead) {
+        return Ok(());
+    }
+    if has_timed_effect(ctx, player_id, progression.next_stage.clone()) {
+        return Ok(());
+    }
+    let duration_secs = match progression.next_stage {
+        EffectType::Dysentery => DYSENTERY_DURATION_SECS,
+        EffectType::CommonCold => COMMON_COLD_DURATION_SECS,
+        ref other => return Err(format!("{:?} is not a disease stage", other)),
+    };
+    apply_timed_effect(ctx, player_id, progression.next_stage.clone(), duration_secs)?;
+    log::info!("[Disease] Player {:?} developed {:?}", player_id, progression.next_stage);
+    Ok(())
+}
+
+/// Rolls for catching a cold - outdoors in a blizzard, and from each sick player nearby.
+/// Called from the player stat tick; a ColdResistance brew protects against both.
+pub fn update_player_disease_exposure(ctx: &ReducerContext, player: &Player) {
+    let player_id = player.identity;
+    if player_has_common_cold_effect(ctx, player_id) || player_has_cold_resistance_effect(ctx, player_id) {
+        return;
+    }
+
+    let mut catch_chance = 0.0;
+    if !player.is_inside_building && crate::world_state::is_blizzard_at_position(ctx, player.position_x, player.position_y) {
+        catch_chance += BLIZZARD_COLD_CHANCE_PER_TICK;
+    }
+    let sick_player_ids: Vec<Identity> = ctx.db.active_consumable_effect().iter()
+        .filter(|e| e.effect_type == EffectType::CommonCold && e.ends_at > ctx.timestamp)
+        .map(|e| e.player_id)
+        .collect();
+    for sick_id in sick_player_ids {
+        let Some(sick) = ctx.db.player().identity().find(&sick_id) else { continue };
+        if sick.is_dead || !sick.is_online {
+            continue;
+        }
+        let dx = sick.position_x - player.position_x;
+        let dy = sick.position_y - player.position_y;
+        if dx * dx + dy * dy <= COLD_CONTAGION_RADIUS * COLD_CONTAGION_RADIUS {
+            catch_chance += COLD_CONTAGION_CHANCE_PER_TICK;
+        }
+    }
+
+    if catch_chance > 0.0 && ctx.rng().gen::<f32>() < catch_chance {
+        match apply_timed_effect(ctx, player_id, EffectType::CommonCold, COMMON_COLD_DURATION_SECS) {
+            Ok(()) => log::info!("[Disease] Player {:?} caught a cold", player_id),
+            Err(e) => log::error!("[Disease] Failed to give player {:?} a cold: {}", player_id, e),
+        }
+    }
+}
+
+/// Cures the diseases a drunk brew treats. Treating food poisoning also stops it progressing.
+pub fn cure_diseases_with_brew(ctx: &ReducerContext, player_id: Identity, brew_category: &str) {
+    let cured = crate::ai_brewing::diseases_cured_by_category(brew_category);
+    if cured.is_empty() {
+        return;
+    }
+    let effect_ids: Vec<u64> = ctx.db.active_consumable_effect().player_id().filter(&player_id)
+        .filter(|e| cured.contains(&e.effect_type))
+        .map(|e| e.effect_id)
+        .collect();
+    for effect_id in effect_ids {
+        ctx.db.active_consumable_effect().effect_id().delete(&effect_id);
+    }
+    if cured.contains(&EffectType::FoodPoisoning) {
+        cancel_disease_progressions(ctx, player_id);
+    }
+    log::info!("[Disease] Player {:?} drank a {} brew, curing {:?}", player_id, brew_category, cured);
+}
+
 // Memory Beacon Sanity Effect Management
 // ============================
 // This is a display-only effect that shows players they're in a Memory Beacon sanity zone
@@ -4226,4 +4714,80 @@ fn remove_memory_beacon_effect(ctx: &ReducerContext, player_id: Identity) {
         ctx.db.active_consumable_effect().effect_id().delete(&effect_id);
         log::info!("Removed Memory Beacon sanity effect {} from player {:?}", effect_id, player_id);
     }
-}
\ No newline at end of file
+}
+
+// ============================================================================
+// PUBLIC EFFECT ICONS FEED
+// ============================================================================
+
+/// Effects longer than this are shown without a countdown (zone effects, permanent debuffs)
+const VISIBLE_EFFECT_MAX_COUNTDOWN_MICROS: i64 = 24 * 60 * 60 * 1_000_000;
+
+/// Whether other players may see this effect on someone.
+/// Anything not listed stays private - insanity (Entrainment, Validol, Memory Beacon), base ownership
+/// (BuildingPrivilege, runes) and tactical brew buffs must not leak to other clients.
+pub fn is_effect_publicly_visible(effect_type: &EffectType) -> bool {
+    matches!(effect_type,
+        EffectType::Bleed | EffectType::Burn | EffectType::Venom | EffectType::Poisoned |
+        EffectType::FoodPoisoning | EffectType::SeawaterPoisoning | EffectType::Drowning |
+        EffectType::HealthRegen | EffectType::PassiveHealthRegen | EffectType::BandageBurst |
+        EffectType::Cozy | EffectType::Wet | EffectType::Exhausted | EffectType::Stun | EffectType::Staggered | EffectType::Fractured |
+        EffectType::Dysentery | EffectType::CommonCold | EffectType::MemoryStatic | EffectType::AuroraBlessing |
+        EffectType::Intoxicated | EffectType::HotSpring | EffectType::SafeZone |
+        EffectType::HotCombatLadle)
+}
+
+/// Rebuilds the public per-player effect summary from active effects.
+/// Rows are only written when a player's visible effects actually change.
+fn refresh_player_visible_effects(ctx: &ReducerContext) {
+    let current_micros = ctx.timestamp.to_micros_since_unix_epoch();
+    let mut summaries: std::collections::HashMap<Identity, Vec<VisibleEffect>> = std::collections::HashMap::new();
+
+    for effect in ctx.db.active_consumable_effect().iter() {
+        if !is_effect_publicly_visible(&effect.effect_type) {
+            continue;
+        }
+        // Effects cast on someone else (remote bandages) belong to the target
+        let affected_player = effect.target_player_id.unwrap_or(effect.player_id);
+        let ends_at = if effect.ends_at.to_micros_since_unix_epoch() - current_micros > VISIBLE_EFFECT_MAX_COUNTDOWN_MICROS {
+            None
+        } else {
+            Some(effect.ends_at)
+        };
+
+        let effects = summaries.entry(affected_player).or_default();
+        // Stacked effects of one type show as a single icon with the latest end time
+        match effects.iter_mut().find(|e| e.effect_type == effect.effect_type) {
+            Some(existing) => {
+                existing.ends_at = match (existing.ends_at, ends_at) {
+                    (Some(a), Some(b)) => Some(if b > a { b } else { a }),
+                    _ => None,
+                };
+            }
+            None => effects.push(VisibleEffect { effect_type: effect.effect_type.clone(), ends_at }),
+        }
+    }
+
+    let table = ctx.db.player_visible_effects();
+    let stale_rows: Vec<Identity> = table.iter()
+        .filter(|row| !summaries.contains_key(&row.player_id))
+        .map(|row| row.player_id)
+        .collect();
+    for player_id in stale_rows {
+        table.player_id().delete(&player_id);
+    }
+
+    for (player_id, effects) in summaries {
+        match table.player_id().find(&player_id) {
+            Some(existing) if existing.effects == effects => {}
+            Some(mut existing) => {
+                existing.effects = effects;
+                existing.updated_at = ctx.timestamp;
+                table.player_id().update(existing);
+            }
+            None => {
+                table.insert(PlayerVisibleEffects { player_id, effects, updated_at: ctx.timestamp });
+            }
+        }
+    }
+}
@@ -21,6 +21,10 @@ use crate::combat::{RESPAWN_TIME_MS};
 use crate::combat::{
     find_targets_in_cone, find_best_target, process_attack, TargetId
 };
+use crate::combat::{
+    MeleeSwing, HEAVY_ATTACK_DAMAGE_MULTIPLIER, HEAVY_CLEAVE_DAMAGE_FRACTION, HEAVY_CLEAVE_MAX_EXTRA_TARGETS,
+    get_combo_damage_multiplier, record_combo_swing, is_cleaving_axe,
+};
 // Tilled tiles system import
 use crate::tilled_tiles;
 
@@ -220,6 +224,9 @@ pub fn set_active_item_reducer(ctx: &ReducerContext, item_instance_id: u64) -> R
     if player.is_knocked_out {
         return Err("Cannot equip items while knocked out.".to_string());
     }
+    if crate::restraints::is_restrained(ctx, sender_id) {
+        return Err("Cannot equip items while restrained.".to_string());
+    }
 
     // Cancel any ongoing BandageBurst effect before equipping a new item or re-equipping.
     cancel_bandage_burst_effects(ctx, sender_id);
@@ -289,6 +296,9 @@ pub fn set_active_item_reducer(ctx: &ReducerContext, item_instance_id: u64) -> R
         // log::debug!("Item {} is already the active item for player {:?}. No change to ActiveEquipment needed.", item_instance_id, sender_id);
         return Ok(());
     }
+
+    // Switching items lowers any spyglass or binoculars
+    crate::spyglass::stop_scoping(ctx, sender_id);
     
     // --- SAVE OLD WEAPON'S AMMO STATE ---
     // Before switching, save the current weapon's loaded ammo to its item_data
@@ -418,6 +428,7 @@ pub fn clear_active_item_reducer(ctx: &ReducerContext, player_identity: Identity
             equipment.loaded_ammo_count = 0; // Clear from ActiveEquipment (ammo is now on the weapon itself)
             equipment.is_ready_to_fire = false;
             active_equipments.player_identity().update(equipment);
+            crate::spyglass::stop_scoping(ctx, player_identity);
 
             // --- Handle Torch/Flashlight/Snorkel State on Unequip ---
             // Always turn off torch/flashlight/snorkel when unequipping, regardless of which item was equipped
@@ -486,6 +497,9 @@ pub fn load_ranged_weapon(ctx: &ReducerContext) -> Result<(), String> {
     if player.is_knocked_out {
         return Err("Cannot load weapons while knocked out.".to_string());
     }
+    if crate::restraints::is_restrained(ctx, sender_id) {
+        return Err("Cannot load weapons while restrained.".to_string());
+    }
 
     let mut current_equipment = get_or_create_active_equipment(ctx, sender_id)?;
     log::info!("[LoadRangedWeapon] Found ActiveEquipment for player {:?}: {:?}", sender_id, current_equipment);
@@ -534,13 +548,7 @@ pub fn load_ranged_weapon(ctx: &ReducerContext) -> Result<(), String> {
     let is_firearm = item_def.name == "Makarov PM" || item_def.name == "PP-91 KEDR"; // Firearms use 9x18mm bullets
     let is_harpoon_gun = item_def.name == "Reed Harpoon Gun"; // Underwater harpoon weapons
     
-    let required_ammo_type = if is_harpoon_gun {
-        crate::models::AmmoType::HarpoonDart
-    } else if is_firearm {
-        crate::models::AmmoType::Bullet
-    } else {
-        crate::models::AmmoType::Arrow
-    };
+    let required_ammo_type = crate::ammunition::required_ammo_type_for_weapon(&item_def.name);
     
     let ammo_type_name = if is_harpoon_gun { 
         "harpoon darts" 
@@ -739,12 +747,84 @@ pub fn load_ranged_weapon(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Selects which ammunition type the equipped ranged weapon uses
+///
+/// Sets the player's preferred ammo type. Single-shot weapons (bows, crossbows) that are
+/// already loaded swap to the new type immediately, since ammo is only consumed on fire.
+/// Magazine weapons keep their loaded rounds and use the new type on the next reload.
+#[spacetimedb::reducer]
+pub fn select_ammo_type(ctx: &ReducerContext, ammo_item_name: String) -> Result<(), String> {
+    let sender_id = ctx.sender();
+
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+    if player.is_dead || player.is_knocked_out {
+        return Err("Cannot change ammunition right now.".to_string());
+    }
+
+    let mut current_equipment = get_or_create_active_equipment(ctx, sender_id)?;
+    let equipped_item_def_id = current_equipment.equipped_item_def_id
+        .ok_or_else(|| "No item equipped.".to_string())?;
+    let item_def = ctx.db.item_definition().id().find(equipped_item_def_id)
+        .ok_or_else(|| "Equipped item definition not found".to_string())?;
+
+    if item_def.category != crate::items::ItemCategory::RangedWeapon {
+        return Err("Equipped item is not a ranged weapon.".to_string());
+    }
+    if item_def.name == "Grenade" || item_def.name == "Flare" {
+        return Err("This item doesn't take ammunition.".to_string());
+    }
+
+    // Ammo must exist and fit the weapon
+    let required_ammo_type = crate::ammunition::required_ammo_type_for_weapon(&item_def.name);
+    let ammo_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == ammo_item_name && def.category == crate::items::ItemCategory::Ammunition)
+        .ok_or_else(|| format!("'{}' is not ammunition.", ammo_item_name))?;
+    if ammo_def.ammo_type != Some(required_ammo_type) {
+        return Err(format!("{} can't fire {}.", item_def.name, ammo_def.name));
+    }
+
+    let has_ammo = ctx.db.inventory_item().iter().any(|item| {
+        item.item_def_id == ammo_def.id
+            && item.quantity > 0
+            && match &item.location {
+                crate::models::ItemLocation::Inventory(data) => data.owner_id == sender_id,
+                crate::models::ItemLocation::Hotbar(data) => data.owner_id == sender_id,
+                _ => false,
+            }
+    });
+    if !has_ammo {
+        return Err(format!("You don't have any {}.", ammo_def.name));
+    }
+
+    current_equipment.preferred_arrow_type = Some(ammo_def.name.clone());
+
+    let magazine_capacity = ctx.db.ranged_weapon_stats().item_name().find(&item_def.name)
+        .map(|s| s.magazine_capacity)
+        .unwrap_or(0);
+    if magazine_capacity == 0 && current_equipment.is_ready_to_fire {
+        current_equipment.loaded_ammo_def_id = Some(ammo_def.id);
+        log::info!("[SelectAmmoType] Player {:?} swapped {} to {}.", sender_id, item_def.name, ammo_def.name);
+    } else {
+        log::info!("[SelectAmmoType] Player {:?} will load {} into {} on next reload.", sender_id, ammo_def.name, item_def.name);
+    }
+
+    ctx.db.active_equipment().player_identity().update(current_equipment);
+    Ok(())
+}
+
 /// Processes the use/swing action for the currently equipped item
 ///
 /// Handles tool usage including swinging animations, target finding,
 /// damage application, and resource gathering.
 #[spacetimedb::reducer]
 pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
+    use_equipped_item_with_swing(ctx, MeleeSwing::Light)
+}
+
+/// Shared swing logic for light attacks (use_equipped_item) and heavy attacks (combat::heavy_attack).
+/// Heavy swings deal more damage and cleave with axes; both advance the player's melee combo.
+pub fn use_equipped_item_with_swing(ctx: &ReducerContext, swing: MeleeSwing) -> Result<(), String> {
     let sender_id = ctx.sender();
     let now_ts = ctx.timestamp;
     let now_micros = now_ts.to_micros_since_unix_epoch();
@@ -768,6 +848,15 @@ pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
     if player.is_knocked_out {
         return Err("Cannot use items while knocked out.".to_string());
     }
+    if player.is_sleeping {
+        return Err("Cannot use items while asleep.".to_string());
+    }
+    if crate::restraints::is_restrained(ctx, sender_id) {
+        return Err("Cannot use items while restrained.".to_string());
+    }
+    if crate::active_effects::player_has_staggered_effect(ctx, sender_id) {
+        return Err("You're staggered and can't attack yet.".to_string());
+    }
     
     let mut current_equipment = get_or_create_active_equipment(ctx, sender_id)?;
 
@@ -837,8 +926,14 @@ pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
     }
     // --- END ATTACK SPEED CHECK ---
 
+    // --- Powered tools burn fuel (auto-refuelled from inventory) and are loud ---
+    if crate::powered_tool::is_powered_tool(&item_def) {
+        crate::powered_tool::consume_fuel_for_use(ctx, sender_id, equipped_item_instance_id)?;
+        crate::powered_tool::emit_powered_tool_noise(ctx, &player, &item_def);
+    }
+
     // --- BEGIN BANDAGE-LIKE MEDICAL HANDLING ---
-    if item_def.name == "Bandage" || item_def.name == "Med Kit" || item_def.name == "Jellyfish Compress" {
+    if item_def.name == "Bandage" || item_def.name == "Med Kit" || item_def.name == "Jellyfish Compress" || item_def.name == "Splint" {
         log::info!("[UseEquippedItem] Player {:?} is using equipped {} (Instance: {}, Def: {}, Health Gain: {:?}).", 
             sender_id, item_def.name, equipped_item_instance_id, item_def.id, item_def.consumable_health_gain);
 
@@ -855,6 +950,11 @@ pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
             return Err("You are already applying or receiving a bandage.".to_string());
         }
 
+        let is_splint = item_def.name == "Splint";
+        if item_def.name == "Med Kit" && crate::active_effects::player_has_med_kit_cooldown(ctx, sender_id) {
+            return Err("You need to wait before using another med kit.".to_string());
+        }
+
         // Get the player's position to check for nearby players
         let player_pos = players_table.identity().find(sender_id)
             .ok_or_else(|| "Player not found for medical consumable use".to_string())?;
@@ -866,7 +966,9 @@ pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
         for other_player in players_table.iter() {
             if other_player.identity == sender_id { continue; } // Skip self
             if other_player.is_dead { continue; } // Skip dead players
-            if other_player.health >= 100.0 { continue; } // Skip players at full health
+            if is_splint {
+                if !crate::active_effects::player_has_fractured_effect(ctx, other_player.identity) { continue; } // Splints only help fractures
+            } else if other_player.health >= 100.0 { continue; } // Skip players at full health
 
             let dx = other_player.position_x - player_pos.position_x;
             let dy = other_player.position_y - player_pos.position_y;
@@ -915,6 +1017,9 @@ pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
             players_table.identity().update(player_to_update);
         } else {
             // No nearby wounded players, apply to self as normal
+            if is_splint && !crate::active_effects::player_has_fractured_effect(ctx, sender_id) {
+                return Err("There is no fracture to splint.".to_string());
+            }
             log::info!("[UseEquippedItem] No nearby wounded players found, applying bandage to self (Player {:?})", sender_id);
             
             let duration_secs = item_def.consumable_duration_secs.unwrap_or(5.0).max(0.1);
@@ -1143,6 +1248,10 @@ pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
         );
     }
     
+    // Heavy swings hit harder; every third consecutive hit is a combo finisher
+    let heavy_multiplier = if swing == MeleeSwing::Heavy { HEAVY_ATTACK_DAMAGE_MULTIPLIER } else { 1.0 };
+    let damage_multiplier = heavy_multiplier * get_combo_damage_multiplier(ctx, sender_id, now_ts);
+
     // === SCYTHE CLEAVE: Hit ALL targets in the arc ===
     if is_scythe && !targets.is_empty() {
         log::info!(
@@ -1152,7 +1261,7 @@ pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
         
         // Hit ALL targets in the arc
         for target in &targets {
-            match process_attack(ctx, sender_id, target, &item_def, now_ts, &mut rng) {
+            match process_attack(ctx, sender_id, target, &item_def, now_ts, heavy_multiplier, &mut rng) {
                 Ok(result) => {
                     if result.hit {
                         log::debug!("Scythe hit {:?} at distance {:.1}", target.id, target.distance_sq.sqrt());
@@ -1218,10 +1327,35 @@ pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
             "[UseEquippedItem] Player {:?} selected best target: {:?}",
             sender_id, target.id
         );
-        match process_attack(ctx, sender_id, &target, &item_def, now_ts, &mut rng) {
+        let is_combat_target = matches!(target.id, TargetId::Player(_) | TargetId::WildAnimal(_));
+        match process_attack(ctx, sender_id, &target, &item_def, now_ts, damage_multiplier, &mut rng) {
             Ok(result) => {
+                record_combo_swing(ctx, sender_id, result.hit && is_combat_target, now_ts);
                 if result.hit {
                     log::debug!("Player {:?} hit a {:?} with {}.", sender_id, result.target_type, item_def.name);
+
+                    // --- Heavy blows can fracture a player's limb ---
+                    if let (MeleeSwing::Heavy, TargetId::Player(target_player_id)) = (swing, &target.id) {
+                        if rng.gen_bool(crate::active_effects::HEAVY_HIT_FRACTURE_CHANCE) {
+                            if let Err(e) = crate::active_effects::apply_fractured_effect(ctx, *target_player_id) {
+                                log::error!("[UseEquippedItem] Failed to fracture {:?}: {}", target_player_id, e);
+                            }
+                        }
+                    }
+
+                    // --- Axe heavy attack cleave: nearby players/animals in the arc take reduced damage ---
+                    if swing == MeleeSwing::Heavy && is_cleaving_axe(&item_def) && is_combat_target {
+                        let cleave_multiplier = damage_multiplier * HEAVY_CLEAVE_DAMAGE_FRACTION;
+                        let cleave_targets = targets.iter()
+                            .filter(|t| matches!(t.id, TargetId::Player(_) | TargetId::WildAnimal(_)))
+                            .filter(|t| t.id != target.id)
+                            .take(HEAVY_CLEAVE_MAX_EXTRA_TARGETS);
+                        for cleave_target in cleave_targets {
+                            if let Err(e) = process_attack(ctx, sender_id, cleave_target, &item_def, now_ts, cleave_multiplier, &mut rng) {
+                                log::warn!("Cleave hit error on {:?}: {}", cleave_target.id, e);
+                            }
+                        }
+                    }
                     
                     // --- Reduce durability on successful hit ---
                     if crate::durability::has_durability_system(&item_def) {
@@ -1243,6 +1377,7 @@ pub fn use_equipped_item(ctx: &ReducerContext) -> Result<(), String> {
             Err(e) => log::error!("Error processing attack: {}", e),
         }
     } else {
+        record_combo_swing(ctx, sender_id, false, now_ts);
         log::debug!("Player {:?} swung {} but hit nothing.", sender_id, item_def.name);
     }
 
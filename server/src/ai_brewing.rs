@@ -128,6 +128,19 @@ pub fn map_category_to_effect(category: &str) -> Option<EffectType> {
     }
 }
 
+/// Diseases a brew of this category cures when drunk (see active_effects::cure_diseases_with_brew)
+/// - antidote_brew: food poisoning (stopping it from turning into dysentery) and dysentery
+/// - medicinal_tea: dysentery and the common cold
+/// - warming_broth: the common cold
+pub fn diseases_cured_by_category(category: &str) -> &'static [EffectType] {
+    match category {
+        "antidote_brew" => &[EffectType::FoodPoisoning, EffectType::Dysentery],
+        "medicinal_tea" => &[EffectType::Dysentery, EffectType::CommonCold],
+        "warming_broth" => &[EffectType::CommonCold],
+        _ => &[],
+    }
+}
+
 /// Maps effect type string from AI to EffectType enum
 pub fn parse_effect_type(effect_str: &str) -> Option<EffectType> {
     match effect_str {
@@ -419,6 +432,7 @@ pub fn create_generated_brew(
             
             // Station requirement (not applicable for AI-generated brews)
             requires_station: None,
+            required_workbench_tier: None,
             
             // Attack arc (not applicable for consumables)
             attack_arc_degrees: None,
@@ -438,6 +452,9 @@ pub fn create_generated_brew(
             bleed_chance_on_melee: None,
             reduces_animal_detection: None,
             melee_damage_bonus: None,
+            insulation: None,
+            wetness_penalty: None,
+            environmental_protection: vec![],
         };
         
         let inserted = item_defs.insert(new_item_def);
@@ -0,0 +1,281 @@
+/******************************************************************************
+ *                                                                            *
+ * Tripwire alarms for base defense. An armed alarm goes off when a player   *
+ * who isn't authorized there walks across its wire (jumping clears it):     *
+ * its owner gets a private message saying where the intruder is, and the    *
+ * alarm rings loud enough to be heard across the base - and to draw hostile *
+ * NPCs and wolves (see sound_events::register_noise).                       *
+ *                                                                            *
+ * The owner and anyone authorized on the hearth whose land it sits on can   *
+ * arm, disarm and pick up an alarm.                                          *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::Player;
+use crate::PrivateMessage;
+use crate::private_message as PrivateMessageTableTrait;
+use crate::items::{
+    InventoryItem,
+    inventory_item as InventoryItemTableTrait,
+    item_definition as ItemDefinitionTableTrait,
+};
+use crate::homestead_hearth::building_privilege_zone as BuildingPrivilegeZoneTableTrait;
+use crate::player_inventory::find_first_empty_player_slot;
+use crate::environment::calculate_chunk_index;
+
+// --- Constants ---
+pub const ALARM_TRAP_ITEM_NAME: &str = "Tripwire Alarm";
+
+/// How far the wire stretches from the alarm's center
+pub const ALARM_TRIGGER_RADIUS: f32 = 32.0;
+/// An alarm won't go off again (or message its owner again) within this time
+pub const ALARM_COOLDOWN_SECS: i64 = 30;
+pub const ALARM_SOUND_DISTANCE: f32 = 1200.0;
+pub const ALARM_NOISE_RADIUS: f32 = 800.0;
+/// As loud as a noisemaker decoy
+pub const ALARM_NOISE_INTENSITY: f32 = 0.7;
+
+const ALARM_PLACEMENT_MAX_DISTANCE: f32 = 150.0;
+const ALARM_INTERACTION_DISTANCE_SQUARED: f32 = 120.0 * 120.0;
+
+/// --- Alarm Trap Data Structure ---
+#[spacetimedb::table(accessor = alarm_trap, public)]
+#[derive(Clone, Debug)]
+pub struct AlarmTrap {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub chunk_index: u32,
+    pub placed_by: Identity,
+    pub placed_at: Timestamp,
+    pub is_armed: bool,
+    pub last_triggered_at: Option<Timestamp>,
+}
+
+// --- Helper Functions ---
+
+/// The owner, or anyone authorized on the hearth whose land the alarm is on
+fn is_authorized_for_alarm(ctx: &ReducerContext, trap: &AlarmTrap, player_id: Identity) -> bool {
+    trap.placed_by == player_id ||
+        (crate::homestead_hearth::is_position_claimed(ctx, trap.pos_x, trap.pos_y) &&
+         crate::homestead_hearth::is_position_privileged(ctx, trap.pos_x, trap.pos_y, player_id))
+}
+
+/// Validates that an authorized player can reach an alarm
+fn validate_alarm_interaction(ctx: &ReducerContext, trap_id: u32) -> Result<(Player, AlarmTrap), String> {
+    let player = ctx.db.player().identity().find(&ctx.sender())
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot interact with alarm while dead.".to_string());
+    }
+    if player.is_knocked_out {
+        return Err("Cannot interact with alarm while knocked out.".to_string());
+    }
+
+    let trap = ctx.db.alarm_trap().id().find(&trap_id)
+        .ok_or_else(|| format!("Alarm {} not found.", trap_id))?;
+
+    let dx = trap.pos_x - player.position_x;
+    let dy = trap.pos_y - player.position_y;
+    if dx * dx + dy * dy > ALARM_INTERACTION_DISTANCE_SQUARED {
+        return Err("Too far away from alarm.".to_string());
+    }
+    if !is_authorized_for_alarm(ctx, &trap, player.identity) {
+        return Err("You are not authorized to touch this alarm.".to_string());
+    }
+
+    Ok((player, trap))
+}
+
+/// Compass direction of a point from a center, e.g. "north" or "south-east"
+fn compass_direction(from_x: f32, from_y: f32, to_x: f32, to_y: f32) -> &'static str {
+    // World y grows southwards
+    let angle = (to_y - from_y).atan2(to_x - from_x).to_degrees();
+    match angle {
+        a if (-22.5..22.5).contains(&a) => "east",
+        a if (22.5..67.5).contains(&a) => "south-east",
+        a if (67.5..112.5).contains(&a) => "south",
+        a if (112.5..157.5).contains(&a) => "south-west",
+        a if (-67.5..-22.5).contains(&a) => "north-east",
+        a if (-112.5..-67.5).contains(&a) => "north",
+        a if (-157.5..-112.5).contains(&a) => "north-west",
+        _ => "west",
+    }
+}
+
+/// Where the alarm is, as its owner would describe it
+fn describe_alarm_location(ctx: &ReducerContext, trap: &AlarmTrap) -> String {
+    let base_zone = ctx.db.building_privilege_zone().iter()
+        .find(|zone| zone.contains(trap.pos_x, trap.pos_y) && zone.is_authorized(trap.placed_by));
+    match base_zone {
+        Some(zone) => format!("Intruder at your base, {} side", compass_direction(zone.center_x, zone.center_y, trap.pos_x, trap.pos_y)),
+        None => {
+            let (tile_x, tile_y) = crate::world_pos_to_tile_coords(trap.pos_x, trap.pos_y);
+            format!("Intruder tripped your alarm at ({}, {})", tile_x, tile_y)
+        }
+    }
+}
+
+fn trigger_alarm(ctx: &ReducerContext, mut trap: AlarmTrap, intruder_id: Identity) {
+    trap.last_triggered_at = Some(ctx.timestamp);
+
+    ctx.db.private_message().insert(PrivateMessage {
+        id: 0, // Auto-incremented
+        recipient_identity: trap.placed_by,
+        sender_display_name: "ALARM".to_string(),
+        text: describe_alarm_location(ctx, &trap),
+        sent: ctx.timestamp,
+    });
+    crate::sound_events::emit_alarm_trap_sound(ctx, trap.pos_x, trap.pos_y, ALARM_SOUND_DISTANCE, intruder_id);
+    crate::sound_events::register_noise(ctx, trap.pos_x, trap.pos_y, ALARM_NOISE_RADIUS, ALARM_NOISE_INTENSITY, intruder_id);
+
+    log::info!("[Alarm] Alarm {} at ({:.1}, {:.1}) tripped by {:?}", trap.id, trap.pos_x, trap.pos_y, intruder_id);
+    ctx.db.alarm_trap().id().update(trap);
+}
+
+/// Sets off any armed alarm whose wire the player crossed moving from (from_x, from_y) to (to_x, to_y)
+pub fn check_alarm_traps(ctx: &ReducerContext, player_id: Identity, from_x: f32, from_y: f32, to_x: f32, to_y: f32) {
+    let cooldown_start = ctx.timestamp.to_micros_since_unix_epoch() - ALARM_COOLDOWN_SECS * 1_000_000;
+    let mut chunks = vec![calculate_chunk_index(to_x, to_y)];
+    let from_chunk = calculate_chunk_index(from_x, from_y);
+    if from_chunk != chunks[0] {
+        chunks.push(from_chunk);
+    }
+
+    for chunk_index in chunks {
+        let tripped: Vec<AlarmTrap> = ctx.db.alarm_trap().chunk_index().filter(chunk_index)
+            .filter(|trap| trap.is_armed)
+            .filter(|trap| trap.last_triggered_at.map_or(true, |t| t.to_micros_since_unix_epoch() <= cooldown_start))
+            .filter(|trap| crate::projectile::line_intersects_circle(from_x, from_y, to_x, to_y, trap.pos_x, trap.pos_y, ALARM_TRIGGER_RADIUS))
+            .collect();
+        for trap in tripped {
+            if !is_authorized_for_alarm(ctx, &trap, player_id) {
+                trigger_alarm(ctx, trap, player_id);
+            }
+        }
+    }
+}
+
+// --- Reducers ---
+
+/// --- Place Tripwire Alarm ---
+/// Places an armed alarm from the player's inventory on land they are privileged on.
+#[spacetimedb::reducer]
+pub fn place_alarm_trap(ctx: &ReducerContext, item_instance_id: u64, world_x: f32, world_y: f32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+
+    crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, sender_id)?;
+
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot place alarm while dead.".to_string());
+    }
+
+    // --- Validate item ---
+    let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+        .ok_or_else(|| "Item not found.".to_string())?;
+
+    if item.location.is_player_bound() != Some(sender_id) {
+        return Err("You don't own this item.".to_string());
+    }
+
+    let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
+        .ok_or_else(|| "Item definition not found.".to_string())?;
+
+    if item_def.name != ALARM_TRAP_ITEM_NAME {
+        return Err("This item is not an alarm.".to_string());
+    }
+
+    // --- Validate placement position ---
+    let dx = world_x - player.position_x;
+    let dy = world_y - player.position_y;
+    if dx * dx + dy * dy > ALARM_PLACEMENT_MAX_DISTANCE * ALARM_PLACEMENT_MAX_DISTANCE {
+        return Err("Cannot place alarm that far away.".to_string());
+    }
+
+    if crate::environment::is_position_on_water(ctx, world_x, world_y) {
+        return Err("Cannot place alarm on water.".to_string());
+    }
+
+    if crate::building::is_position_on_wall(ctx, world_x, world_y) {
+        return Err("Cannot place alarm on a wall.".to_string());
+    }
+
+    crate::placeable_collision::check_placeable_overlap(ctx, world_x, world_y, 24.0, 24.0, true)?;
+
+    ctx.db.alarm_trap().try_insert(AlarmTrap {
+        id: 0, // Auto-increment
+        pos_x: world_x,
+        pos_y: world_y,
+        chunk_index: calculate_chunk_index(world_x, world_y),
+        placed_by: sender_id,
+        placed_at: ctx.timestamp,
+        is_armed: true,
+        last_triggered_at: None,
+    }).map_err(|e| format!("Failed to place alarm: {}", e))?;
+
+    // Consume one alarm item
+    if item.quantity > 1 {
+        let mut updated_item = item.clone();
+        updated_item.quantity -= 1;
+        ctx.db.inventory_item().instance_id().update(updated_item);
+    } else {
+        ctx.db.inventory_item().instance_id().delete(&item_instance_id);
+    }
+
+    log::info!("Player {:?} placed tripwire alarm at ({:.1}, {:.1})", sender_id, world_x, world_y);
+    Ok(())
+}
+
+/// --- Arm / Disarm Tripwire Alarm ---
+#[spacetimedb::reducer]
+pub fn set_alarm_trap_armed(ctx: &ReducerContext, trap_id: u32, armed: bool) -> Result<(), String> {
+    let (_player, mut trap) = validate_alarm_interaction(ctx, trap_id)?;
+
+    if trap.is_armed == armed {
+        return Ok(());
+    }
+    trap.is_armed = armed;
+    ctx.db.alarm_trap().id().update(trap);
+
+    log::info!("Player {:?} {} alarm {}", ctx.sender(), if armed { "armed" } else { "disarmed" }, trap_id);
+    Ok(())
+}
+
+/// --- Pickup Tripwire Alarm ---
+#[spacetimedb::reducer]
+pub fn pickup_alarm_trap(ctx: &ReducerContext, trap_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let (_player, _trap) = validate_alarm_interaction(ctx, trap_id)?;
+
+    let item_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == ALARM_TRAP_ITEM_NAME)
+        .ok_or_else(|| format!("{} item definition not found.", ALARM_TRAP_ITEM_NAME))?;
+
+    let new_location = find_first_empty_player_slot(ctx, sender_id)
+        .ok_or_else(|| format!("Player inventory is full, cannot pickup {}.", ALARM_TRAP_ITEM_NAME))?;
+
+    ctx.db.inventory_item().try_insert(InventoryItem {
+        instance_id: 0, // Auto-inc
+        item_def_id: item_def.id,
+        quantity: 1,
+        location: new_location,
+        item_data: None,
+    }).map_err(|e| format!("Failed to insert {} item: {}", ALARM_TRAP_ITEM_NAME, e))?;
+
+    ctx.db.alarm_trap().id().delete(trap_id);
+
+    log::info!("Player {:?} picked up tripwire alarm {}", sender_id, trap_id);
+    Ok(())
+}
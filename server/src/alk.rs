@@ -32,6 +32,8 @@ use crate::alk::alk_contract as AlkContractTableTrait;
 use crate::alk::alk_player_contract as AlkPlayerContractTableTrait;
 use crate::dropped_item::give_item_to_player_or_drop;
 use crate::alk::player_shard_balance as PlayerShardBalanceTableTrait;
+use crate::alk::alk_shard_bank as AlkShardBankTableTrait;
+use crate::alk::alk_vending_stock as AlkVendingStockTableTrait;
 use crate::alk::alk_contract_refresh_schedule as AlkContractRefreshScheduleTableTrait;
 use crate::items::item_definition as ItemDefinitionTableTrait;
 use crate::items::inventory_item as InventoryItemTableTrait;
@@ -101,6 +103,15 @@ pub const MAX_ACTIVE_PLAYER_CONTRACTS: usize = 10;
 /// Contract refresh check interval in seconds
 pub const CONTRACT_REFRESH_INTERVAL_SECONDS: u64 = 60; // Check every minute
 
+/// Fee deducted from Memory Shards deposited into the ALK shard bank (destroyed - acts as a shard sink)
+pub const ALK_BANK_DEPOSIT_FEE_RATE: f32 = 0.05; // 5% (minimum 1 shard)
+
+/// Cooldown between shard bank withdrawals - banked shards can't be pulled out mid-fight
+pub const ALK_BANK_WITHDRAWAL_COOLDOWN_SECONDS: u64 = 600; // 10 minutes
+
+/// Number of vending offers each substation stocks per ALK cycle
+pub const ALK_VENDING_OFFERS_PER_STATION: usize = 4;
+
 // ============================================================================
 // ENUMS AND TYPES
 // ============================================================================
@@ -354,6 +365,71 @@ pub struct PlayerShardBalance {
     pub last_transaction: Timestamp,
 }
 
+/// ALK shard bank - Memory Shards deposited at a station are protected (not dropped on death,
+/// don't count towards insanity) until withdrawn
+#[spacetimedb::table(accessor = alk_shard_bank, public)]
+#[derive(Clone, Debug)]
+pub struct AlkShardBank {
+    #[primary_key]
+    pub player_id: Identity,
+    
+    /// Shards currently held in the bank
+    pub banked_shards: u64,
+    
+    /// Total shards ever deposited (after fees)
+    pub total_deposited: u64,
+    
+    /// Total shards ever withdrawn
+    pub total_withdrawn: u64,
+    
+    /// Total shards lost to deposit fees
+    pub total_fees_paid: u64,
+    
+    /// Last withdrawal (for the withdrawal cooldown)
+    pub last_withdrawal_at: Option<Timestamp>,
+    
+    /// Last transaction timestamp
+    pub last_transaction: Timestamp,
+}
+
+/// ALK vending stock - rotating, limited-quantity offers at substations priced in Memory Shards
+/// Restocked every ALK cycle by the contract refresh schedule
+#[spacetimedb::table(
+    accessor = alk_vending_stock,
+    public,
+    index(accessor = idx_vending_station, name = "idx_vending_station", btree(columns = [station_id]))
+)]
+#[derive(Clone, Debug)]
+pub struct AlkVendingStock {
+    #[primary_key]
+    #[auto_inc]
+    pub stock_id: u64,
+    
+    /// Substation selling this offer (1-4)
+    pub station_id: u32,
+    
+    /// Item definition ID from items_database
+    pub item_def_id: u64,
+    
+    /// Item name (cached for convenience)
+    pub item_name: String,
+    
+    /// Items per purchased bundle
+    pub bundle_size: u32,
+    
+    /// Memory Shard cost per bundle
+    pub shard_cost_per_bundle: u32,
+    
+    /// Bundles left this cycle (shared by all players - first come, first served)
+    pub bundles_remaining: u32,
+    
+    /// Bundles stocked at the start of the cycle
+    pub bundles_stocked: u32,
+    
+    /// ALK cycle this stock belongs to
+    pub cycle_index: u32,
+}
+
 /// Item ALK tags - determines which items can appear in which contracts
 #[spacetimedb::table(
     accessor = item_alk_tag, 
@@ -830,13 +906,29 @@ fn seed_alk_stations(ctx: &ReducerContext) -> Result<(), String> {
                     carve_dirt_paths_in_compound(ctx, pos_x, pos_y);
                 }
                 
-                // Spawn monument placeables only at the central compound
+                // Spawn monument placeables - the full set at the central compound, a recycler at substations
+                if !is_central {
+                    let placeable_configs = crate::monument::get_alk_substation_placeables();
+                    match crate::monument::spawn_monument_placeables(ctx, &station.name, pos_x, pos_y, &placeable_configs) {
+                        Ok(count) => log::info!("🏭 Spawned {} monument placeables at {}", count, station.name),
+                        Err(e) => log::warn!("Failed to spawn {} placeables: {}", station.name, e),
+                    }
+                }
                 if is_central {
                     let placeable_configs = crate::monument::get_central_compound_placeables();
                     match crate::monument::spawn_monument_placeables(ctx, "Central Compound", pos_x, pos_y, &placeable_configs) {
                         Ok(count) => log::info!("🏭 Spawned {} monument placeables at Central Compound", count),
                         Err(e) => log::warn!("Failed to spawn central compound placeables: {}", e),
                     }
+                    // Blue keycard crate in the compound yard (keycard loop - see locked_crate.rs)
+                    match crate::locked_crate::spawn_locked_crate(ctx, crate::locked_crate::KEYCARD_BLUE, pos_x - 150.0, pos_y - 350.0) {
+                        Ok(id) => log::info!("🏭 Spawned blue locked crate {} at Central Compound", id),
+                        Err(e) => log::warn!("Failed to spawn central compound locked crate: {}", e),
+                    }
+                    // Garage vault puzzle room (switches + fuse box guard a vault crate)
+                    if let Err(e) = crate::monument_puzzle::spawn_puzzle_room(ctx, &crate::monument_puzzle::compound_garage_vault(), pos_x, pos_y) {
+                        log::warn!("Failed to spawn central compound puzzle room: {}", e);
+                    }
                     // Spawn compound perimeter fence (square with corner openings for player entry)
                     match crate::fence::spawn_compound_perimeter_fences(ctx, pos_x, pos_y) {
                         Ok(count) => log::info!("🏭 Spawned {} monument fences around compound perimeter", count),
@@ -1771,6 +1863,76 @@ fn generate_buyorder_contracts(ctx: &ReducerContext, world_day: u32) -> Result<(
     Ok(())
 }
 
+/// Vending pool: (item name, bundle size, shard cost per bundle, bundles per cycle)
+/// Scarce gear, meds and seeds - priced above contract rewards so vending stays a shard sink
+const VENDING_POOL: &[(&str, u32, u32, u32)] = &[
+    // Meds
+    ("Med Kit", 1, 60, 3),
+    ("Anti-Venom", 1, 25, 5),
+    ("Bandage", 3, 15, 8),
+    // Gear & ammo
+    ("Flashlight", 1, 50, 2),
+    ("9x18mm Round", 10, 40, 5),
+    ("Fire Arrow", 5, 20, 5),
+    // Seeds
+    ("Ginseng Seeds", 2, 30, 4),
+    ("Mandrake Seeds", 1, 45, 3),
+    ("Corn Seeds", 5, 12, 6),
+    ("Pumpkin Seeds", 5, 12, 6),
+    ("Carrot Seeds", 5, 10, 6),
+    ("Flax Seeds", 5, 10, 6),
+];
+
+/// Replace every substation's vending stock with a fresh random selection for this cycle
+fn restock_vending(ctx: &ReducerContext, cycle_index: u32) -> Result<(), String> {
+    let stock_table = ctx.db.alk_vending_stock();
+    let item_defs = ctx.db.item_definition();
+    let mut rng = ctx.rng();
+    
+    // Clear last cycle's stock (unsold bundles are lost)
+    let old_stock: Vec<u64> = stock_table.iter().map(|s| s.stock_id).collect();
+    for stock_id in old_stock {
+        stock_table.stock_id().delete(&stock_id);
+    }
+    
+    let substations: Vec<AlkStation> = ctx.db.alk_station().iter()
+        .filter(|s| s.station_id > 0 && s.is_active)
+        .collect();
+    
+    let mut created = 0;
+    for station in substations {
+        let mut pool: Vec<&(&str, u32, u32, u32)> = VENDING_POOL.iter().collect();
+        let num_to_select = ALK_VENDING_OFFERS_PER_STATION.min(pool.len());
+        
+        for _ in 0..num_to_select {
+            if pool.is_empty() { break; }
+            let idx = rng.gen_range(0..pool.len());
+            let (item_name, bundle_size, cost, bundles) = *pool.remove(idx);
+            
+            let Some(item_def) = item_defs.iter().find(|d| d.name == item_name) else {
+                log::warn!("Vending item not found: {}", item_name);
+                continue;
+            };
+            
+            stock_table.insert(AlkVendingStock {
+                stock_id: 0,
+                station_id: station.station_id,
+                item_def_id: item_def.id,
+                item_name: item_def.name.clone(),
+                bundle_size,
+                shard_cost_per_bundle: cost,
+                bundles_remaining: bundles,
+                bundles_stocked: bundles,
+                cycle_index,
+            });
+            created += 1;
+        }
+    }
+    
+    log::info!("🏪 Restocked ALK vending with {} offers for cycle {}", created, cycle_index);
+    Ok(())
+}
+
 // ============================================================================
 // CONTRACT PARAMETER FUNCTIONS - Dynamic reward calculation
 // ============================================================================
@@ -1974,10 +2136,10 @@ fn get_provisions_contract_params(item_name: &str, item_def: &crate::items::Item
         // === COOKED FISH (fishing + cooking) ===
         "Cooked Pink Salmon" | "Cooked Sockeye Salmon" | "Cooked King Salmon" => (12, 95), // Premium salmon
         "Cooked Pacific Cod" | "Cooked Herring" | "Cooked Smelt" => (18, 65), // Common fish
-        "Cooked Dolly Varden" | "Cooked Rockfish" | "Cooked Steelhead" => (15, 75), // Mid-tier fish
+        "Cooked Dolly Varden" | "Cooked Rockfish" | "Cooked Steelhead" | "Cooked Northern Pike" => (15, 75), // Mid-tier fish
         "Cooked Halibut" => (10, 90),          // Large premium fish
         "Cooked Twigfish" => (25, 45),         // Common fish
-        "Cooked Greenling" | "Cooked Sculpin" => (20, 55), // Basic fish
+        "Cooked Greenling" | "Cooked Sculpin" | "Cooked Arctic Grayling" => (20, 55), // Basic fish
         "Cooked Crab Meat" | "Cooked Blue Mussel" | "Cooked Sea Urchin" | "Cooked Black Katy Chiton" => (15, 70), // Seafood
         
         // === COOKED MEAT (hunting + cooking) ===
@@ -2334,8 +2496,14 @@ pub fn process_alk_contract_refresh(ctx: &ReducerContext, _args: AlkContractRefr
         // Generate new bonus contracts
         generate_bonus_contracts(ctx, current_world_day, current_season_index)?;
         
+        // Restock substation vending
+        restock_vending(ctx, current_daily_cycle)?;
+        
         alk_state.daily_cycle_index = current_daily_cycle;
         did_refresh = true;
+    } else if ctx.db.alk_vending_stock().iter().next().is_none() {
+        // First run (or existing world without vending yet) - stock immediately
+        restock_vending(ctx, current_daily_cycle)?;
     }
     
     // Check and expire individual contracts
@@ -3005,7 +3173,8 @@ pub fn purchase_from_alk(
     let cost_per_bundle = contract.shard_cost_per_bundle
         .ok_or("Buy order has no cost defined")?;
     
-    let total_cost = cost_per_bundle * bundles_to_buy;
+    // Control point owners get discounted ALK prices
+    let total_cost = crate::control_point::apply_alk_discount(ctx, player_id, cost_per_bundle * bundles_to_buy);
     let items_to_receive = contract.bundle_size * bundles_to_buy;
     
     // Buy orders are Central Compound only
@@ -3108,6 +3277,313 @@ pub fn purchase_from_alk(
     Ok(())
 }
 
+/// Buy bundles from a substation's rotating vending stock using Memory Shards
+/// Quantities are limited per ALK cycle and shared by all players
+#[spacetimedb::reducer]
+pub fn purchase_alk_vending_stock(
+    ctx: &ReducerContext,
+    stock_id: u64,
+    bundles_to_buy: u32,
+) -> Result<(), String> {
+    let player_id = ctx.sender();
+    
+    if bundles_to_buy == 0 {
+        return Err("Must buy at least 1 bundle".to_string());
+    }
+    
+    let player = ctx.db.player().identity().find(&player_id)
+        .ok_or("Player not found")?;
+    
+    let stock_table = ctx.db.alk_vending_stock();
+    let mut stock = stock_table.stock_id().find(&stock_id)
+        .ok_or("This offer is no longer available")?;
+    
+    if stock.bundles_remaining < bundles_to_buy {
+        return Err(format!("Only {} bundles of {} left this cycle", stock.bundles_remaining, stock.item_name));
+    }
+    
+    // Player must be at the substation selling this stock
+    let station = ctx.db.alk_station().station_id().find(&stock.station_id)
+        .ok_or("Station not found")?;
+    
+    if !station.is_active {
+        return Err("Station is not operational".to_string());
+    }
+    
+    let dx = player.position_x - station.world_pos_x;
+    let dy = player.position_y - station.world_pos_y;
+    let distance_sq = dx * dx + dy * dy;
+    let purchase_radius = station.interaction_radius * ALK_DELIVERY_RADIUS_MULTIPLIER;
+    
+    if distance_sq > purchase_radius * purchase_radius {
+        return Err(format!("You must be at {} to buy this", station.name));
+    }
+    
+    // Control point owners get discounted ALK prices
+    let total_cost = crate::control_point::apply_alk_discount(ctx, player_id, stock.shard_cost_per_bundle * bundles_to_buy);
+    let items_to_receive = stock.bundle_size * bundles_to_buy;
+    
+    // Check player has enough Memory Shards in inventory
+    let items_table = ctx.db.inventory_item();
+    let memory_shard_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == "Memory Shard")
+        .ok_or("Memory Shard item definition not found")?;
+    
+    let player_shards: Vec<_> = items_table.iter()
+        .filter(|item| {
+            item.item_def_id == memory_shard_def.id &&
+            (matches!(&item.location, ItemLocation::Inventory(loc) if loc.owner_id == player_id) ||
+            matches!(&item.location, ItemLocation::Hotbar(loc) if loc.owner_id == player_id))
+        })
+        .collect();
+    
+    let total_shards: u32 = player_shards.iter().map(|i| i.quantity).sum();
+    
+    if total_shards < total_cost {
+        return Err(format!(
+            "Not enough Memory Shards. Need {} ({}x{} per bundle), have {}",
+            total_cost, bundles_to_buy, stock.shard_cost_per_bundle, total_shards
+        ));
+    }
+    
+    // Consume Memory Shards from player inventory
+    let mut shards_to_consume = total_cost;
+    for shard_stack in player_shards {
+        if shards_to_consume == 0 { break; }
+        
+        let consume_from_stack = shard_stack.quantity.min(shards_to_consume);
+        shards_to_consume -= consume_from_stack;
+        
+        if consume_from_stack >= shard_stack.quantity {
+            items_table.instance_id().delete(shard_stack.instance_id);
+        } else {
+            let mut updated_item = shard_stack.clone();
+            updated_item.quantity -= consume_from_stack;
+            items_table.instance_id().update(updated_item);
+        }
+    }
+    
+    // Draw down the shared stock
+    stock.bundles_remaining -= bundles_to_buy;
+    stock_table.stock_id().update(stock.clone());
+    
+    // Give purchased items to player
+    match give_item_to_player_or_drop(ctx, player_id, stock.item_def_id, items_to_receive) {
+        Ok(added_to_inv) => {
+            if added_to_inv {
+                log::info!("📦 Added {} {} to player {:?} inventory", items_to_receive, stock.item_name, player_id);
+            } else {
+                log::info!("📦 Dropped {} {} at player {:?} feet (inventory full)", items_to_receive, stock.item_name, player_id);
+            }
+        }
+        Err(e) => {
+            log::error!("Failed to give vending items to player: {}", e);
+            return Err(format!("Failed to complete purchase: {}", e));
+        }
+    }
+    
+    // Update player shard balance for tracking/statistics
+    let balance_table = ctx.db.player_shard_balance();
+    let mut balance = balance_table.player_id().find(&player_id)
+        .unwrap_or(PlayerShardBalance {
+            player_id,
+            balance: 0,
+            total_earned: 0,
+            total_spent: 0,
+            last_transaction: ctx.timestamp,
+        });
+    
+    balance.total_spent += total_cost as u64;
+    balance.last_transaction = ctx.timestamp;
+    
+    if balance_table.player_id().find(&player_id).is_some() {
+        balance_table.player_id().update(balance);
+    } else {
+        let _ = balance_table.try_insert(balance);
+    }
+    
+    log::info!("🏪 Player {:?} bought {} {} for {} Memory Shards at {} ({} bundles left)",
+              player_id, items_to_receive, stock.item_name, total_cost, station.name, stock.bundles_remaining);
+    
+    Ok(())
+}
+
+// ============================================================================
+// SHARD BANK
+// ============================================================================
+
+/// Validate the player is at an active ALK station (any station offers banking)
+fn validate_player_at_bank_station(ctx: &ReducerContext, player_id: Identity, station_id: u32) -> Result<AlkStation, String> {
+    let player = ctx.db.player().identity().find(&player_id)
+        .ok_or("Player not found")?;
+    
+    if player.is_dead || player.is_knocked_out {
+        return Err("You can't use the shard bank right now".to_string());
+    }
+    
+    let station = ctx.db.alk_station().station_id().find(&station_id)
+        .ok_or("Station not found")?;
+    
+    if !station.is_active {
+        return Err("Station is not operational".to_string());
+    }
+    
+    // Same range as deliveries (client opens the ALK panel from 280px)
+    let dx = player.position_x - station.world_pos_x;
+    let dy = player.position_y - station.world_pos_y;
+    let distance_sq = dx * dx + dy * dy;
+    let bank_radius = station.interaction_radius * ALK_DELIVERY_RADIUS_MULTIPLIER;
+    
+    if distance_sq > bank_radius * bank_radius {
+        return Err("You must be at an ALK station to use the shard bank".to_string());
+    }
+    
+    Ok(station)
+}
+
+/// Deposit Memory Shards from inventory into the ALK shard bank (minus a small fee)
+#[spacetimedb::reducer]
+pub fn deposit_shards_to_alk_bank(ctx: &ReducerContext, station_id: u32, amount: u32) -> Result<(), String> {
+    let player_id = ctx.sender();
+    
+    if amount == 0 {
+        return Err("Must deposit at least 1 shard".to_string());
+    }
+    
+    let station = validate_player_at_bank_station(ctx, player_id, station_id)?;
+    
+    let fee = ((amount as f32 * ALK_BANK_DEPOSIT_FEE_RATE).ceil() as u32).max(1);
+    if amount <= fee {
+        return Err(format!("Deposit too small - the {} shard fee would consume it all", fee));
+    }
+    let net_deposit = amount - fee;
+    
+    // Find Memory Shards in player inventory
+    let items_table = ctx.db.inventory_item();
+    let memory_shard_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == "Memory Shard")
+        .ok_or("Memory Shard item definition not found")?;
+    
+    let player_shards: Vec<_> = items_table.iter()
+        .filter(|item| {
+            item.item_def_id == memory_shard_def.id &&
+            (matches!(&item.location, ItemLocation::Inventory(loc) if loc.owner_id == player_id) ||
+            matches!(&item.location, ItemLocation::Hotbar(loc) if loc.owner_id == player_id))
+        })
+        .collect();
+    
+    let total_shards: u32 = player_shards.iter().map(|i| i.quantity).sum();
+    
+    if total_shards < amount {
+        return Err(format!("Not enough Memory Shards. Trying to deposit {}, have {}", amount, total_shards));
+    }
+    
+    // Consume Memory Shards from player inventory
+    let mut shards_to_consume = amount;
+    for shard_stack in player_shards {
+        if shards_to_consume == 0 { break; }
+        
+        let consume_from_stack = shard_stack.quantity.min(shards_to_consume);
+        shards_to_consume -= consume_from_stack;
+        
+        if consume_from_stack >= shard_stack.quantity {
+            items_table.instance_id().delete(shard_stack.instance_id);
+        } else {
+            let mut updated_item = shard_stack.clone();
+            updated_item.quantity -= consume_from_stack;
+            items_table.instance_id().update(updated_item);
+        }
+    }
+    
+    // Credit the bank
+    let bank_table = ctx.db.alk_shard_bank();
+    let mut bank = bank_table.player_id().find(&player_id)
+        .unwrap_or(AlkShardBank {
+            player_id,
+            banked_shards: 0,
+            total_deposited: 0,
+            total_withdrawn: 0,
+            total_fees_paid: 0,
+            last_withdrawal_at: None,
+            last_transaction: ctx.timestamp,
+        });
+    
+    bank.banked_shards += net_deposit as u64;
+    bank.total_deposited += net_deposit as u64;
+    bank.total_fees_paid += fee as u64;
+    bank.last_transaction = ctx.timestamp;
+    
+    if bank_table.player_id().find(&player_id).is_some() {
+        bank_table.player_id().update(bank);
+    } else {
+        let _ = bank_table.try_insert(bank);
+    }
+    
+    log::info!("🏦 Player {:?} deposited {} Memory Shards at {} ({} banked, fee: {})",
+              player_id, amount, station.name, net_deposit, fee);
+    
+    Ok(())
+}
+
+/// Withdraw banked Memory Shards back into inventory (subject to a cooldown)
+#[spacetimedb::reducer]
+pub fn withdraw_shards_from_alk_bank(ctx: &ReducerContext, station_id: u32, amount: u32) -> Result<(), String> {
+    let player_id = ctx.sender();
+    
+    if amount == 0 {
+        return Err("Must withdraw at least 1 shard".to_string());
+    }
+    
+    let station = validate_player_at_bank_station(ctx, player_id, station_id)?;
+    
+    let bank_table = ctx.db.alk_shard_bank();
+    let mut bank = bank_table.player_id().find(&player_id)
+        .ok_or("You have no banked shards")?;
+    
+    if bank.banked_shards < amount as u64 {
+        return Err(format!("Not enough banked shards. Trying to withdraw {}, have {}", amount, bank.banked_shards));
+    }
+    
+    if let Some(last_withdrawal) = bank.last_withdrawal_at {
+        let elapsed_secs = (ctx.timestamp.to_micros_since_unix_epoch() - last_withdrawal.to_micros_since_unix_epoch()) / 1_000_000;
+        if elapsed_secs < ALK_BANK_WITHDRAWAL_COOLDOWN_SECONDS as i64 {
+            return Err(format!(
+                "Withdrawal cooldown active - try again in {} seconds",
+                ALK_BANK_WITHDRAWAL_COOLDOWN_SECONDS as i64 - elapsed_secs
+            ));
+        }
+    }
+    
+    let memory_shard_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == "Memory Shard")
+        .ok_or("Memory Shard item definition not found")?;
+    
+    // Debit the bank (an Err below rolls the whole withdrawal back)
+    bank.banked_shards -= amount as u64;
+    bank.total_withdrawn += amount as u64;
+    bank.last_withdrawal_at = Some(ctx.timestamp);
+    bank.last_transaction = ctx.timestamp;
+    bank_table.player_id().update(bank);
+    
+    match give_item_to_player_or_drop(ctx, player_id, memory_shard_def.id, amount) {
+        Ok(added_to_inv) => {
+            if added_to_inv {
+                log::info!("🏦 Added {} banked Memory Shards to player {:?} inventory", amount, player_id);
+            } else {
+                log::info!("🏦 Dropped {} banked Memory Shards at player {:?} feet (inventory full)", amount, player_id);
+            }
+        }
+        Err(e) => {
+            log::error!("Failed to give banked Memory Shards to player: {}", e);
+            return Err(format!("Failed to withdraw shards: {}", e));
+        }
+    }
+    
+    log::info!("🏦 Player {:?} withdrew {} Memory Shards at {}", player_id, amount, station.name);
+    
+    Ok(())
+}
+
 /// Check if player is near any ALK station (for UI purposes)
 #[spacetimedb::reducer]
 pub fn check_alk_station_proximity(ctx: &ReducerContext) -> Result<(), String> {
@@ -3177,6 +3653,9 @@ pub fn debug_refresh_alk_contracts(ctx: &ReducerContext) -> Result<(), String> {
     // Buy order contracts (spend shards to buy materials - shard sink)
     generate_buyorder_contracts(ctx, world_day)?;
     
+    // Substation vending stock
+    restock_vending(ctx, world_day / DAYS_PER_ALK_CYCLE)?;
+    
     log::info!("✅ Debug: ALK contracts refreshed");
     Ok(())
 }
@@ -0,0 +1,108 @@
+// server/src/ammunition.rs
+// ------------------------------------
+// Ammunition type profiles - how the loaded ammo modifies a shot.
+//
+// Item definitions (items_database/ammunition.rs) hold the ammo's damage
+// range, bleed parameters and crafting cost. This module layers the
+// behaviour on top, so projectile.rs asks "what does this ammo do?" instead
+// of matching item names all over the place:
+// - Damage mode (add to weapon damage, replace it, or subtract from it)
+// - Projectile speed / range multipliers
+// - On-hit effects (bleed, burn, venom) and fire patches on impact
+// - Weapon wear per shot (heavy or burning ammo costs more durability)
+// ------------------------------------
+
+use crate::models::AmmoType;
+
+/// How an ammo's pvp damage range combines with the weapon's damage
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmmoDamageMode {
+    /// Weapon damage + ammo damage (standard arrows, bullets)
+    Additive,
+    /// Ammo damage only - the burn does the work (Fire Arrow)
+    Replace,
+    /// Weapon damage - ammo damage, minimum 1 (light, fast ammo)
+    Subtract,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct AmmoProfile {
+    pub damage_mode: AmmoDamageMode,
+    pub speed_multiplier: f32,
+    pub range_multiplier: f32,
+    /// Applies the bleed parameters from the item definition on player hits
+    pub applies_bleed: bool,
+    /// Applies a short burn on player hits (blocked by wet status)
+    pub applies_burn: bool,
+    /// Applies venom using the item definition's bleed parameters
+    pub applies_venom: bool,
+    /// Creates a fire patch where it lands
+    pub creates_fire_patch: bool,
+    /// Multiplier on the weapon's durability loss per shot
+    pub weapon_wear_multiplier: f32,
+}
+
+impl AmmoProfile {
+    const STANDARD: AmmoProfile = AmmoProfile {
+        damage_mode: AmmoDamageMode::Additive,
+        speed_multiplier: 1.0,
+        range_multiplier: 1.0,
+        applies_bleed: true,
+        applies_burn: false,
+        applies_venom: false,
+        creates_fire_patch: false,
+        weapon_wear_multiplier: 1.0,
+    };
+}
+
+/// Returns the behaviour profile for an ammunition item
+pub fn get_ammo_profile(ammo_name: &str) -> AmmoProfile {
+    match ammo_name {
+        // Bone-tipped: heavier head hits harder and tears (bleed), flies a bit slower
+        "Bone Arrow" => AmmoProfile {
+            speed_multiplier: 0.95,
+            ..AmmoProfile::STANDARD
+        },
+        // Fire: light direct damage, burn + fire patch. Burning cloth chars the string
+        "Fire Arrow" => AmmoProfile {
+            damage_mode: AmmoDamageMode::Replace,
+            speed_multiplier: 0.9,
+            applies_bleed: false,
+            applies_burn: true,
+            creates_fire_patch: true,
+            weapon_wear_multiplier: 1.5,
+            ..AmmoProfile::STANDARD
+        },
+        // High-velocity: light reed shaft flies fast and far but hits softer.
+        // The harder draw wears the weapon faster
+        "Hollow Reed Arrow" => AmmoProfile {
+            damage_mode: AmmoDamageMode::Subtract,
+            speed_multiplier: 1.25,
+            range_multiplier: 1.1,
+            weapon_wear_multiplier: 1.25,
+            ..AmmoProfile::STANDARD
+        },
+        // Venom: bleed parameters are repurposed as venom
+        "Venom Arrow" | "Venom Harpoon Dart" => AmmoProfile {
+            applies_bleed: false,
+            applies_venom: true,
+            ..AmmoProfile::STANDARD
+        },
+        // Thrown items: the item is both weapon and ammo, so its damage counts once
+        "Throwing Stone" | "Smoke Bomb" | "Flashbang" => AmmoProfile {
+            damage_mode: AmmoDamageMode::Replace,
+            applies_bleed: false,
+            ..AmmoProfile::STANDARD
+        },
+        _ => AmmoProfile::STANDARD,
+    }
+}
+
+/// The ammo type a ranged weapon accepts
+pub fn required_ammo_type_for_weapon(weapon_name: &str) -> AmmoType {
+    match weapon_name {
+        "Reed Harpoon Gun" => AmmoType::HarpoonDart,
+        "Makarov PM" | "PP-91 KEDR" => AmmoType::Bullet,
+        _ => AmmoType::Arrow,
+    }
+}
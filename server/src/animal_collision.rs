@@ -30,7 +30,7 @@ use crate::building::foundation_cell as FoundationCellTableTrait;
 use crate::building::FOUNDATION_TILE_SIZE_PX;
 use crate::door::door as DoorTableTrait;
 use crate::fence::fence as FenceTableTrait;
-use crate::fence::{check_fence_collision, FENCE_COLLISION_THICKNESS};
+use crate::fence::{check_fence_collision, fence_blocks, FENCE_COLLISION_THICKNESS};
 use crate::wild_animal_npc::{WildAnimal, wild_animal as WildAnimalTableTrait, AnimalSpecies};
 use crate::fishing::is_water_tile;
 use crate::TILE_SIZE_PX;
@@ -42,6 +42,9 @@ pub const ANIMAL_PLAYER_COLLISION_RADIUS: f32 = 65.0; // Animals maintain 65px d
 pub const ANIMAL_PLAYER_ATTACK_COLLISION_RADIUS: f32 = 50.0; // Closer distance when attacking to allow hits
 pub const COLLISION_PUSHBACK_FORCE: f32 = 80.0; // Strong pushback to prevent fast NPCs from overlapping
 pub const ANIMAL_SEPARATION_DISTANCE: f32 = 15.0; // Minimum separation after collision resolution
+/// Longest move resolved in one collision check. Sharded animals cover several AI ticks per update,
+/// so longer moves are walked in sub-steps instead of jumping over water, cliffs and obstacles.
+pub const MAX_ANIMAL_COLLISION_STEP_PX: f32 = TILE_SIZE_PX as f32;
 
 /// Represents the result of a collision check
 #[derive(Debug, Clone)]
@@ -72,6 +75,8 @@ pub enum CollisionType {
 
 /// Comprehensive collision check for animal movement
 /// Returns the final position after all collision resolution
+/// Moves longer than MAX_ANIMAL_COLLISION_STEP_PX are resolved one sub-step at a time,
+/// stopping at the first sub-step that gets blocked or pushed back
 pub fn resolve_animal_collision(
     ctx: &ReducerContext,
     animal_id: u64,
@@ -80,6 +85,33 @@ pub fn resolve_animal_collision(
     proposed_x: f32,
     proposed_y: f32,
     is_attacking: bool,
+) -> (f32, f32) {
+    let dx = proposed_x - current_x;
+    let dy = proposed_y - current_y;
+    let distance = (dx * dx + dy * dy).sqrt();
+    let sub_steps = ((distance / MAX_ANIMAL_COLLISION_STEP_PX).ceil() as u32).max(1);
+
+    let (mut pos_x, mut pos_y) = (current_x, current_y);
+    for step in 1..=sub_steps {
+        let t = step as f32 / sub_steps as f32;
+        let (step_x, step_y) = (current_x + dx * t, current_y + dy * t);
+        (pos_x, pos_y) = resolve_animal_collision_step(ctx, animal_id, pos_x, pos_y, step_x, step_y, is_attacking);
+        if (pos_x - step_x).abs() > 0.01 || (pos_y - step_y).abs() > 0.01 {
+            break; // Hit something - don't carry on past it
+        }
+    }
+    (pos_x, pos_y)
+}
+
+/// Resolves collisions for a single step of at most MAX_ANIMAL_COLLISION_STEP_PX
+fn resolve_animal_collision_step(
+    ctx: &ReducerContext,
+    animal_id: u64,
+    current_x: f32,
+    current_y: f32,
+    proposed_x: f32,
+    proposed_y: f32,
+    is_attacking: bool,
 ) -> (f32, f32) {
     let mut final_x = proposed_x;
     let mut final_y = proposed_y;
@@ -115,6 +147,13 @@ pub fn resolve_animal_collision(
                    animal_id, proposed_x, proposed_y);
     }
     
+    // Cliffs block everything that walks or swims; flying animals pass over them
+    if !is_bee && !animal_is_flying && crate::is_position_on_cliff(ctx, proposed_x, proposed_y) {
+        log::debug!("[AnimalCollision] Animal {} movement blocked by cliff at ({:.1}, {:.1})", 
+                   animal_id, proposed_x, proposed_y);
+        return (current_x, current_y);
+    }
+    
     // Check shelter collision (absolute blocker) - but bees can fly through
     if !is_bee && check_shelter_collision(ctx, proposed_x, proposed_y) {
         log::debug!("[AnimalCollision] Animal {} movement blocked by shelter at ({:.1}, {:.1})", 
@@ -284,7 +323,7 @@ fn check_wall_line_collision<DB: WallCellTableTrait>(
     for cell_x in min_cell_x..=max_cell_x {
         for cell_y in min_cell_y..=max_cell_y {
             for wall in wall_cells.idx_cell_coords().filter((cell_x, cell_y)) {
-                if wall.is_destroyed { continue; }
+                if wall.is_destroyed || !crate::building::wall_type_blocks_movement(wall.wall_type) { continue; }
                 walls_to_check.push((cell_x, cell_y, wall.edge));
             }
         }
@@ -435,7 +474,7 @@ fn check_door_line_collision(
 
 /// ANTI-TUNNELING: Check if a movement line crosses any fences
 /// Returns the safe position just before hitting the fence, or None if path is clear
-fn check_fence_line_collision(
+pub fn check_fence_line_collision(
     ctx: &ReducerContext,
     start_x: f32,
     start_y: f32,
@@ -470,7 +509,8 @@ fn check_fence_line_collision(
     for cell_x in min_cell_x..=max_cell_x {
         for cell_y in min_cell_y..=max_cell_y {
             for fence in fences.idx_cell_coords().filter((cell_x, cell_y)) {
-                if fence.is_destroyed {
+                // Closed gates pen animals in like fences; open gates let them through
+                if !fence_blocks(&fence) {
                     continue;
                 }
                 fences_to_check.push((fence.edge, fence.pos_x, fence.pos_y));
@@ -559,7 +599,7 @@ fn check_fence_collision_pushback(
             let check_cell_y = cell_y + offset_y;
             
             for fence in fences.idx_cell_coords().filter((check_cell_x, check_cell_y)) {
-                if fence.is_destroyed {
+                if !fence_blocks(&fence) {
                     continue;
                 }
                 
@@ -651,7 +691,7 @@ pub fn check_animal_collision(
     proposed_x: f32,
     proposed_y: f32,
 ) -> Option<(f32, f32)> {
-    for other_animal in ctx.db.wild_animal().iter() {
+    for other_animal in ctx.db.wild_animal().iter().map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row)) {
         if other_animal.id == animal_id {
             continue; // Skip self
         }
@@ -975,7 +1015,7 @@ pub fn check_wall_collision<DB: WallCellTableTrait>(
             
             // Find walls on this foundation cell
             for wall in wall_cells.idx_cell_coords().filter((check_cell_x, check_cell_y)) {
-                if wall.is_destroyed { continue; }
+                if wall.is_destroyed || !crate::building::wall_type_blocks_movement(wall.wall_type) { continue; }
                 
                 // Calculate wall edge collision bounds using foundation cell size (96px)
                 let cell_left = check_cell_x as f32 * FOUNDATION_TILE_SIZE_PX as f32;
@@ -1145,6 +1185,11 @@ pub fn validate_animal_spawn_position(
         return Err(format!("Cannot spawn animal on water tile at ({:.1}, {:.1})", pos_x, pos_y));
     }
     
+    // Check cliff tiles
+    if crate::is_position_on_cliff(ctx, pos_x, pos_y) {
+        return Err(format!("Cannot spawn animal on cliff at ({:.1}, {:.1})", pos_x, pos_y));
+    }
+    
     // Check shelter collision
     if check_shelter_collision(ctx, pos_x, pos_y) {
         return Err(format!("Cannot spawn animal inside shelter at ({:.1}, {:.1})", pos_x, pos_y));
@@ -1196,6 +1241,10 @@ pub fn can_animal_move_to_position(
         }
     }
     
+    if crate::is_position_on_cliff(ctx, proposed_x, proposed_y) {
+        return false;
+    }
+    
     if check_shelter_collision(ctx, proposed_x, proposed_y) {
         return false;
     }
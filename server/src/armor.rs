@@ -1,7 +1,7 @@
 use spacetimedb::{Identity, ReducerContext, Table};
 use crate::active_equipment::{ActiveEquipment, active_equipment as ActiveEquipmentTableTrait};
 use crate::items::{ItemDefinition, item_definition as ItemDefinitionTableTrait, InventoryItem, inventory_item as InventoryItemTableTrait};
-use crate::models::{EquipmentSlotType, DamageType, ImmunityType, ArmorResistances}; // For matching slot types and new armor system
+use crate::models::{EquipmentSlotType, DamageType, ImmunityType, ArmorResistances, EnvironmentalHazard}; // For matching slot types and new armor system
 use log;
 
 /// Calculates the total damage resistance from all equipped armor pieces.
@@ -38,37 +38,26 @@ pub fn calculate_total_damage_resistance(ctx: &ReducerContext, player_id: Identi
     total_resistance.min(0.9) 
 }
 
-/// Calculates the total warmth bonus from all equipped armor pieces.
-/// Warmth bonus is a float value added to the player's warmth regeneration or subtracted from warmth loss.
-pub fn calculate_total_warmth_bonus(ctx: &ReducerContext, player_id: Identity) -> f32 {
-    let active_equipments = ctx.db.active_equipment();
-    let inventory_items = ctx.db.inventory_item();
-    let item_defs = ctx.db.item_definition();
-    let mut total_warmth_bonus = 0.0;
-
-    if let Some(equipment) = active_equipments.player_identity().find(player_id) {
-        let armor_instance_ids = [
-            equipment.head_item_instance_id,
-            equipment.chest_item_instance_id,
-            equipment.legs_item_instance_id,
-            equipment.feet_item_instance_id,
-            equipment.hands_item_instance_id,
-            equipment.back_item_instance_id,
-        ];
-
-        for maybe_instance_id in armor_instance_ids.iter().flatten() {
-            if let Some(item_instance) = inventory_items.instance_id().find(*maybe_instance_id) {
-                if let Some(item_def) = item_defs.id().find(item_instance.item_def_id) {
-                    if let Some(warmth) = item_def.warmth_bonus {
-                        total_warmth_bonus += warmth;
-                         // log::trace!("[Armor] Player {:?} adding warmth bonus {:.2} from {} (Instance ID: {})", 
-                         //           player_id, warmth, item_def.name, *maybe_instance_id);
-                    }
-                }
+/// Default share of a piece's insulation lost while wet (wet clothing insulates half as well)
+pub const DEFAULT_WETNESS_PENALTY: f32 = 0.5;
+/// Clothing can never block all cold loss
+pub const MAX_TOTAL_INSULATION: f32 = 0.9;
+
+/// Calculates combined clothing insulation (0.0-0.9) from all equipped armor pieces.
+/// Pieces stack multiplicatively: each blocks its share of the cold loss that gets past the others.
+/// When wet, each piece loses its wetness_penalty share of insulation.
+pub fn calculate_total_insulation(ctx: &ReducerContext, player_id: Identity, is_wet: bool) -> f32 {
+    let mut cold_passing_through = 1.0;
+    for item_def in get_equipped_armor_pieces(ctx, player_id) {
+        if let Some(insulation) = item_def.insulation {
+            let mut piece_insulation = insulation.clamp(0.0, 1.0);
+            if is_wet {
+                piece_insulation *= 1.0 - item_def.wetness_penalty.unwrap_or(DEFAULT_WETNESS_PENALTY).clamp(0.0, 1.0);
             }
+            cold_passing_through *= 1.0 - piece_insulation;
         }
     }
-    total_warmth_bonus
+    (1.0 - cold_passing_through).min(MAX_TOTAL_INSULATION)
 }
 
 /// Helper function to get all equipped armor pieces for a player
@@ -161,6 +150,26 @@ pub fn has_armor_immunity(
     immunity_count >= required_pieces
 }
 
+/// Checks if any equipped armor piece protects the player from an environmental hazard.
+/// Unlike set immunities, a single piece (gas mask, ward, diving gear) is enough.
+pub fn has_environmental_protection(
+    ctx: &ReducerContext,
+    player_id: Identity,
+    hazard: EnvironmentalHazard,
+) -> bool {
+    get_equipped_armor_pieces(ctx, player_id).iter()
+        .any(|piece| piece.environmental_protection.contains(&hazard))
+}
+
+/// Checks if the player wears any armor piece forged with metal (conducts lightning)
+pub fn is_wearing_metal_armor(ctx: &ReducerContext, player_id: Identity) -> bool {
+    get_equipped_armor_pieces(ctx, player_id).iter().any(|piece| {
+        piece.crafting_cost.as_ref().is_some_and(|cost| {
+            cost.iter().any(|ingredient| ingredient.item_name.starts_with("Metal"))
+        })
+    })
+}
+
 /// Calculates fire damage multiplier from equipped armor (for wooden armor vulnerability)
 pub fn calculate_fire_damage_multiplier(ctx: &ReducerContext, player_id: Identity) -> f32 {
     let armor_pieces = get_equipped_armor_pieces(ctx, player_id);
@@ -143,6 +143,7 @@ pub fn consolidate_dropped_items_near_position(
 
     for item_id in source_item_ids {
         ctx.db.dropped_item().id().delete(item_id);
+        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(item_id));
     }
     
     // Save final backpack
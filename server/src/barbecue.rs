@@ -23,10 +23,10 @@ use crate::items::{
     inventory_item as InventoryItemTableTrait,
     item_definition as ItemDefinitionTableTrait,
     InventoryItem, ItemDefinition,
-    calculate_merge_result, split_stack_helper, add_item_to_player_inventory
+    calculate_merge_result, add_item_to_player_inventory
 };
-use crate::inventory_management::{self, ItemContainer, ContainerItemClearer, merge_or_place_into_container_slot};
-use crate::player_inventory::{move_item_to_inventory, move_item_to_hotbar, find_first_empty_player_slot, get_player_item};
+use crate::inventory_management::{self, ItemContainer, ContainerItemClearer};
+use crate::player_inventory::{move_item_to_inventory, move_item_to_hotbar, find_first_empty_player_slot};
 use crate::environment::calculate_chunk_index;
 use crate::dropped_item::create_dropped_item_entity;
 use crate::sound_events::{start_barbecue_sound, stop_barbecue_sound};
@@ -152,276 +152,57 @@ pub struct BarbecueProcessingSchedule {
  *                           REDUCERS (Generic Handlers)                        *
  ******************************************************************************/
 
-/// --- Add Item to Barbecue ---
-#[spacetimedb::reducer]
-pub fn move_item_to_barbecue(ctx: &ReducerContext, barbecue_id: u32, target_slot_index: u8, item_instance_id: u64) -> Result<(), String> {
-    let (_player, mut barbecue) = validate_barbecue_interaction(ctx, barbecue_id)?;
-    
-    // Validate item type - prevent water bottles and cauldrons
-    let items = ctx.db.inventory_item();
-    let item = items.instance_id().find(&item_instance_id)
-        .ok_or_else(|| "Item not found.".to_string())?;
-    let item_defs = ctx.db.item_definition();
-    let item_def = item_defs.id().find(&item.item_def_id)
-        .ok_or_else(|| "Item definition not found.".to_string())?;
-    
-    let blocked_items = ["Reed Water Bottle", "Plastic Water Jug", "Cerametal Field Cauldron Mk. II"];
-    if blocked_items.contains(&item_def.name.as_str()) {
-        return Err(format!("Cannot place '{}' in barbecue. Use the broth pot's water container slot for water bottles, or place the cauldron on a campfire.", item_def.name));
+impl inventory_management::ContainerHandle for Barbecue {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_barbecue_interaction(ctx, container_id)
     }
-    
-    inventory_management::handle_move_to_container_slot(ctx, &mut barbecue, target_slot_index, item_instance_id)?;
-    ctx.db.barbecue().id().update(barbecue.clone());
-    schedule_next_barbecue_processing(ctx, barbecue_id);
-    Ok(())
-}
 
-/// --- Remove Item from Barbecue ---
-#[spacetimedb::reducer]
-pub fn quick_move_from_barbecue(ctx: &ReducerContext, barbecue_id: u32, source_slot_index: u8) -> Result<(), String> {
-    let (_player, mut barbecue) = validate_barbecue_interaction(ctx, barbecue_id)?;
-    
-    inventory_management::handle_quick_move_from_container(ctx, &mut barbecue, source_slot_index)?;
-    let still_has_fuel = check_if_barbecue_has_fuel(ctx, &barbecue);
-    if !still_has_fuel && barbecue.is_burning {
-        barbecue.is_burning = false;
-        barbecue.current_fuel_def_id = None;
-        barbecue.remaining_fuel_burn_time_secs = None;
-        log::info!("Barbecue {} extinguished as last valid fuel was removed.", barbecue_id);
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.barbecue().id().update(self.clone());
+        let _ = schedule_next_barbecue_processing(ctx, self.id);
     }
-    ctx.db.barbecue().id().update(barbecue.clone());
-    schedule_next_barbecue_processing(ctx, barbecue_id);
-    Ok(())
-}
 
-/// --- Split Stack Into Barbecue ---
-#[spacetimedb::reducer]
-pub fn split_stack_into_barbecue(
-    ctx: &ReducerContext,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-    target_barbecue_id: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut barbecue) = validate_barbecue_interaction(ctx, target_barbecue_id)?;
-    
-    let items = ctx.db.inventory_item();
-    let source_item = items.instance_id().find(&source_item_instance_id)
-        .ok_or_else(|| "Source item not found.".to_string())?;
-    let item_defs = ctx.db.item_definition();
-    let item_def = item_defs.id().find(&source_item.item_def_id)
-        .ok_or_else(|| "Item definition not found.".to_string())?;
-    
-    let blocked_items = ["Reed Water Bottle", "Plastic Water Jug", "Cerametal Field Cauldron Mk. II"];
-    if blocked_items.contains(&item_def.name.as_str()) {
-        return Err(format!("Cannot place '{}' in barbecue. Use the broth pot's water container slot for water bottles, or place the cauldron on a campfire.", item_def.name));
-    }
-    
-    let mut source_item_mut = get_player_item(ctx, source_item_instance_id)?;
-    let new_item_target_location = ItemLocation::Container(ContainerLocationData {
-        container_type: ContainerType::Barbecue,
-        container_id: barbecue.id as u64,
-        slot_index: target_slot_index,
-    });
-    let new_item_instance_id = split_stack_helper(ctx, &mut source_item_mut, quantity_to_split, new_item_target_location)?;
-    
-    let mut new_item = ctx.db.inventory_item().instance_id().find(new_item_instance_id)
-        .ok_or_else(|| format!("Failed to find newly split item instance {}", new_item_instance_id))?;
-    let new_item_def = ctx.db.item_definition().id().find(new_item.item_def_id)
-        .ok_or_else(|| format!("Failed to find definition for new item {}", new_item.item_def_id))?;
-
-    merge_or_place_into_container_slot(ctx, &mut barbecue, target_slot_index, &mut new_item, &new_item_def)?;
-    
-    ctx.db.inventory_item().instance_id().update(source_item);
-    ctx.db.barbecue().id().update(barbecue.clone());
-    schedule_next_barbecue_processing(ctx, target_barbecue_id);
-    Ok(())
-}
-
-/// --- Quick Move to Barbecue ---
-#[spacetimedb::reducer]
-pub fn quick_move_to_barbecue(
-    ctx: &ReducerContext,
-    barbecue_id: u32,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_player, mut barbecue) = validate_barbecue_interaction(ctx, barbecue_id)?;
-    
-    let items = ctx.db.inventory_item();
-    let item = items.instance_id().find(&item_instance_id)
-        .ok_or_else(|| "Item not found.".to_string())?;
-    let item_defs = ctx.db.item_definition();
-    let item_def = item_defs.id().find(&item.item_def_id)
-        .ok_or_else(|| "Item definition not found.".to_string())?;
-    
-    let blocked_items = ["Reed Water Bottle", "Plastic Water Jug", "Cerametal Field Cauldron Mk. II"];
-    if blocked_items.contains(&item_def.name.as_str()) {
-        return Err(format!("Cannot place '{}' in barbecue. Use the broth pot's water container slot for water bottles, or place the cauldron on a campfire.", item_def.name));
-    }
-    
-    inventory_management::handle_quick_move_to_container(ctx, &mut barbecue, item_instance_id)?;
-    ctx.db.barbecue().id().update(barbecue.clone());
-    schedule_next_barbecue_processing(ctx, barbecue_id);
-    Ok(())
-}
-
-/// --- Barbecue Internal Item Movement ---
-/// Moves/merges/swaps an item BETWEEN two slots within the same barbecue.
-#[spacetimedb::reducer]
-pub fn move_item_within_barbecue(
-    ctx: &ReducerContext,
-    barbecue_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut barbecue) = validate_barbecue_interaction(ctx, barbecue_id)?;
-    
-    // Save cooking progress before move (since set_slot clears it)
-    use crate::cooking::CookableAppliance;
-    let source_progress = barbecue.get_slot_cooking_progress(source_slot_index);
-    let target_progress = barbecue.get_slot_cooking_progress(target_slot_index);
-    let source_had_item = barbecue.get_slot_instance_id(source_slot_index).is_some();
-    let target_had_item = barbecue.get_slot_instance_id(target_slot_index).is_some();
-    
-    inventory_management::handle_move_within_container(ctx, &mut barbecue, source_slot_index, target_slot_index)?;
-    
-    // Transfer cooking progress based on what happened:
-    // - Move to empty slot: source progress -> target
-    // - Swap: exchange progress
-    // - Merge: target keeps its progress (items combined there)
-    if source_had_item && !target_had_item {
-        // Move to empty slot: transfer source progress to target
-        barbecue.set_slot_cooking_progress(target_slot_index, source_progress);
-    } else if source_had_item && target_had_item {
-        // Check if it was a swap (source slot now has an item) or merge (source slot empty)
-        if barbecue.get_slot_instance_id(source_slot_index).is_some() {
-            // Swap: exchange cooking progress
-            barbecue.set_slot_cooking_progress(target_slot_index, source_progress);
-            barbecue.set_slot_cooking_progress(source_slot_index, target_progress);
+    /// Water containers and cauldrons can't be cooked on the grill
+    fn check_operation(&self, ctx: &ReducerContext, op: &inventory_management::ContainerOp) -> Result<(), String> {
+        let Some(item_instance_id) = op.incoming_item() else {
+            return Ok(());
+        };
+        let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+            .ok_or_else(|| "Item not found.".to_string())?;
+        let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
+            .ok_or_else(|| "Item definition not found.".to_string())?;
+        let blocked_items = ["Reed Water Bottle", "Plastic Water Jug", "Cerametal Field Cauldron Mk. II"];
+        if blocked_items.contains(&item_def.name.as_str()) {
+            return Err(format!("Cannot place '{}' in barbecue. Use the broth pot's water container slot for water bottles, or place the cauldron on a campfire.", item_def.name));
         }
-        // If merge: target keeps its progress (already in place), source was cleared
+        Ok(())
     }
-    
-    ctx.db.barbecue().id().update(barbecue.clone());
-    schedule_next_barbecue_processing(ctx, barbecue_id);
-    Ok(())
-}
-
-/// --- Barbecue Internal Stack Splitting ---
-/// Splits a stack FROM one barbecue slot TO another within the same barbecue.
-#[spacetimedb::reducer]
-pub fn split_stack_within_barbecue(
-    ctx: &ReducerContext,
-    barbecue_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut barbecue) = validate_barbecue_interaction(ctx, barbecue_id)?;
-    
-    inventory_management::handle_split_within_container(ctx, &mut barbecue, source_slot_index, target_slot_index, quantity_to_split)?;
-    ctx.db.barbecue().id().update(barbecue.clone());
-    schedule_next_barbecue_processing(ctx, barbecue_id);
-    Ok(())
-}
-
-/// --- Move From Barbecue to Player ---
-/// Moves a specific item FROM a barbecue slot TO a specific player inventory/hotbar slot.
-#[spacetimedb::reducer]
-pub fn move_item_from_barbecue_to_player_slot(
-    ctx: &ReducerContext,
-    barbecue_id: u32,
-    source_slot_index: u8,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let (_player, mut barbecue) = validate_barbecue_interaction(ctx, barbecue_id)?;
-    
-    inventory_management::handle_move_from_container_slot(ctx, &mut barbecue, source_slot_index, target_slot_type, target_slot_index)?;
-    let still_has_fuel = check_if_barbecue_has_fuel(ctx, &barbecue);
-    if !still_has_fuel && barbecue.is_burning {
-        barbecue.is_burning = false;
-        barbecue.current_fuel_def_id = None;
-        barbecue.remaining_fuel_burn_time_secs = None;
-    }
-    ctx.db.barbecue().id().update(barbecue.clone());
-    schedule_next_barbecue_processing(ctx, barbecue_id);
-    Ok(())
-}
-
-/// --- Split From Barbecue to Player ---
-/// Splits a stack FROM a barbecue slot TO a specific player inventory/hotbar slot.
-#[spacetimedb::reducer]
-pub fn split_stack_from_barbecue(
-    ctx: &ReducerContext,
-    source_barbecue_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let mut barbecues = ctx.db.barbecue();
-    let (_player, mut barbecue) = validate_barbecue_interaction(ctx, source_barbecue_id)?;
 
-    log::info!(
-        "[SplitFromBarbecue] Player {:?} delegating split {} from barbecue {} slot {} to {} slot {}",
-        ctx.sender(), quantity_to_split, source_barbecue_id, source_slot_index, target_slot_type, target_slot_index
-    );
-
-    inventory_management::handle_split_from_container(
-        ctx,
-        &mut barbecue,
-        source_slot_index,
-        quantity_to_split,
-        target_slot_type,
-        target_slot_index
-    )?;
-
-    barbecues.id().update(barbecue);
-    Ok(())
-}
-
-/// --- Drop Item From Barbecue Slot to World ---
-#[spacetimedb::reducer]
-pub fn drop_item_from_barbecue_slot_to_world(
-    ctx: &ReducerContext,
-    barbecue_id: u32,
-    slot_index: u8,
-) -> Result<(), String> {
-    let (player, mut barbecue) = validate_barbecue_interaction(ctx, barbecue_id)?;
-    
-    log::info!("[DropFromBarbecueToWorld] Player {} attempting to drop from barbecue ID {}, slot {}.", 
-             ctx.sender(), barbecue_id, slot_index);
-    
-    inventory_management::handle_drop_from_container_slot(ctx, &mut barbecue, slot_index, &player)?;
-    let still_has_fuel = check_if_barbecue_has_fuel(ctx, &barbecue);
-    if !still_has_fuel && barbecue.is_burning {
-        barbecue.is_burning = false;
-        barbecue.current_fuel_def_id = None;
-        barbecue.remaining_fuel_burn_time_secs = None;
+    fn after_operation(&mut self, ctx: &ReducerContext, op: &inventory_management::ContainerOp, before: &Self) {
+        if let inventory_management::ContainerOp::MoveWithin { source_slot, target_slot } = *op {
+            crate::cooking::carry_cooking_progress_after_move(self, before, source_slot, target_slot);
+        }
+        if op.removes_items() && self.is_burning && !check_if_barbecue_has_fuel(ctx, self) {
+            self.is_burning = false;
+            self.current_fuel_def_id = None;
+            self.remaining_fuel_burn_time_secs = None;
+            log::info!("Barbecue {} extinguished as last valid fuel was removed.", self.id);
+        }
     }
-    ctx.db.barbecue().id().update(barbecue.clone());
-    schedule_next_barbecue_processing(ctx, barbecue_id);
-    Ok(())
 }
 
-/// --- Split and Drop Item from Barbecue Slot to World ---
-#[spacetimedb::reducer]
-pub fn split_and_drop_item_from_barbecue_slot_to_world(
-    ctx: &ReducerContext,
-    barbecue_id: u32,
-    slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (player, mut barbecue) = validate_barbecue_interaction(ctx, barbecue_id)?;
-    
-    log::info!("[SplitDropFromBarbecueToWorld] Player {} attempting to split {} from barbecue ID {}, slot {}.", 
-             ctx.sender(), quantity_to_split, barbecue_id, slot_index);
-    
-    inventory_management::handle_split_and_drop_from_container_slot(ctx, &mut barbecue, slot_index, quantity_to_split, &player)?;
-    ctx.db.barbecue().id().update(barbecue.clone());
-    schedule_next_barbecue_processing(ctx, barbecue_id);
-    Ok(())
+crate::container_reducers! {
+    Barbecue;
+    move_to: move_item_to_barbecue(barbecue_id),
+    quick_move_from: quick_move_from_barbecue(barbecue_id),
+    split_into_item_first: split_stack_into_barbecue(target_barbecue_id),
+    quick_move_to: quick_move_to_barbecue(barbecue_id),
+    move_within: move_item_within_barbecue(barbecue_id),
+    split_within_quantity_first: split_stack_within_barbecue(barbecue_id),
+    move_from: move_item_from_barbecue_to_player_slot(barbecue_id),
+    split_from: split_stack_from_barbecue(source_barbecue_id),
+    drop: drop_item_from_barbecue_slot_to_world(barbecue_id),
+    split_and_drop: split_and_drop_item_from_barbecue_slot_to_world(barbecue_id),
 }
 
 /******************************************************************************
@@ -491,6 +272,9 @@ pub fn place_barbecue(ctx: &ReducerContext, item_instance_id: u64, world_x: f32,
 
     crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     let player = players.identity().find(sender_id)
         .ok_or_else(|| "Player not found".to_string())?;
 
@@ -17,7 +17,7 @@
 //! - Automatic respawning after destruction
 //! - Collision detection similar to storage boxes
 
-use spacetimedb::{ReducerContext, SpacetimeType, Table, Timestamp, Identity, TimeDuration};
+use spacetimedb::{ReducerContext, Table, Timestamp, Identity, TimeDuration};
 use log;
 use rand::Rng;
 use std::time::Duration;
@@ -25,7 +25,6 @@ use std::collections::{HashMap, HashSet};
 use spacetimedb::spacetimedb_lib::ScheduleAt;
 
 // Import necessary items from other modules
-use crate::items::item_definition as ItemDefinitionTableTrait;
 use crate::player as PlayerTableTrait;
 use crate::world_tile as WorldTileTableTrait;
 use crate::dropped_item::{create_dropped_item_entity, calculate_drop_position};
@@ -33,6 +32,7 @@ use crate::wooden_storage_box::wooden_storage_box;
 use crate::{Player, PLAYER_RADIUS, TileType};
 use crate::utils::get_distance_squared;
 use crate::environment::calculate_chunk_index;
+use crate::loot_table::{LootEntryDefault, LootSourceDefault};
 
 // Constants for barrel system
 pub const BARREL_INITIAL_HEALTH: f32 = 50.0; // Less health than storage boxes
@@ -109,15 +109,6 @@ pub struct Barrel {
     pub is_monument: bool,
 }
 
-// Loot table definition
-#[derive(SpacetimeType, Clone, Debug)]
-pub struct BarrelLootEntry {
-    pub item_def_id: u64,
-    pub min_quantity: u32,
-    pub max_quantity: u32,
-    pub drop_chance: f32, // 0.0 to 1.0
-}
-
 // Schedule table for barrel respawning
 #[spacetimedb::table(accessor = barrel_respawn_schedule, scheduled(respawn_destroyed_barrels))]
 #[derive(Clone)]
@@ -129,70 +120,75 @@ pub struct BarrelRespawnSchedule {
 }
 
 // --- Loot Table Configuration ---
-pub fn get_barrel_loot_table(ctx: &ReducerContext) -> Vec<BarrelLootEntry> {
-    let item_defs = ctx.db.item_definition();
-    let mut loot_table = Vec::new();
-    
-    // Define all loot entries as (name, min_qty, max_qty, drop_chance)  
-    let loot_definitions = [
+pub const BARREL_LOOT_SOURCE: &str = "barrel";
+/// Extra roll for sea barrel variants (3-5) on top of the normal barrel loot
+pub const SEA_BARREL_BONUS_LOOT_SOURCE: &str = "barrel_sea_bonus";
+
+/// Default barrel loot sources, seeded into the loot_table subsystem
+pub fn default_barrel_loot_sources() -> Vec<LootSourceDefault> {
+    let barrel_entries = vec![
         // --- GUARANTEED TIER - Always drops (barrels are a reliable memory shard source) ---
         // Memory shards: LOW quantity but HIGH consistency - barrels are "the shard meta"
         // Players can farm barrels for steady shard income (1-2 per barrel, nearly guaranteed)
-        ("Memory Shard", 1, 2, 0.92),          // BALANCED: 1-2 shards at 92% = reliable, consistent source
+        LootEntryDefault::new("Memory Shard", 1, 2, 0.92),          // BALANCED: 1-2 shards at 92% = reliable, consistent source
         
         // --- COMMON TIER (40-65% drop rates) - Basic survival items ---
-        ("Rope", 1, 2, 0.50),                  // Essential crafting material
-        ("Metal Fragments", 1, 3, 0.45),       // Crafting material - reduced quantity
-        ("Wooden Arrow", 2, 5, 0.50),          // Basic ammunition - reduced quantity
-        ("Bandage", 1, 2, 0.45),               // Healing consumable
-        ("Wood", 15, 30, 0.42),                // Basic crafting material - reduced quantity
+        LootEntryDefault::new("Rope", 1, 2, 0.50),                  // Essential crafting material
+        LootEntryDefault::new("Metal Fragments", 1, 3, 0.45),       // Crafting material - reduced quantity
+        LootEntryDefault::new("Wooden Arrow", 2, 5, 0.50),          // Basic ammunition - reduced quantity
+        LootEntryDefault::new("Bandage", 1, 2, 0.45),               // Healing consumable
+        LootEntryDefault::new("Wood", 15, 30, 0.42),                // Basic crafting material - reduced quantity
         
         // --- UNCOMMON TIER (20-35% drop rates) - Useful items ---
-        ("Bone Arrow", 1, 3, 0.28),            // Better ammunition - reduced from 45%
-        ("Hollow Reed Arrow", 1, 3, 0.25),     // Special ammunition - reduced from 40%
-        ("Stone Hatchet", 1, 1, 0.22),         // Useful tool - reduced from 35%
-        ("Torch", 1, 1, 0.20),                 // Utility item - reduced from 30%
-        ("Reed Water Bottle", 1, 1, 0.25),     // Water container - reduced from 35%
-        ("Tin Can", 1, 2, 0.28),               // Metal source - moved from rare tier
+        LootEntryDefault::new("Bone Arrow", 1, 3, 0.28),            // Better ammunition - reduced from 45%
+        LootEntryDefault::new("Hollow Reed Arrow", 1, 3, 0.25),     // Special ammunition - reduced from 40%
+        LootEntryDefault::new("Stone Hatchet", 1, 1, 0.22),         // Useful tool - reduced from 35%
+        LootEntryDefault::new("Torch", 1, 1, 0.20),                 // Utility item - reduced from 30%
+        LootEntryDefault::new("Reed Water Bottle", 1, 1, 0.25),     // Water container - reduced from 35%
+        LootEntryDefault::new("Tin Can", 1, 2, 0.28),               // Metal source - moved from rare tier
         
         // --- RARE TIER (8-18% drop rates) - Valuable items ---
-        ("AK74 Bayonet", 1, 1, 0.12),          // Fast military melee weapon - reduced from 18%
-        ("Hunting Bow", 1, 1, 0.08),           // Ranged weapon - reduced from 10%
-        ("Fire Arrow", 1, 2, 0.10),            // Special ammunition - reduced quantity
-        ("Bush Knife", 1, 1, 0.09),            // Military clearing blade - reduced from 12%
-        ("Engineers Maul", 1, 1, 0.08),        // Military engineering tool - reduced from 10%
-        ("Plastic Water Jug", 1, 1, 0.09),     // Large water storage - reduced from 12%
-        ("Anti-Venom", 1, 1, 0.10),            // Antidote - reduced from 15%
-        ("Reed Diver's Helm", 1, 1, 0.06),     // Underwater breathing helmet - moderate low drop
-        ("Headlamp", 1, 1, 0.08),              // Light source equipment
-        ("Med Kit", 1, 1, 0.08),               // Military first aid kit - better than bandage
+        LootEntryDefault::new("AK74 Bayonet", 1, 1, 0.12),          // Fast military melee weapon - reduced from 18%
+        LootEntryDefault::new("Hunting Bow", 1, 1, 0.08),           // Ranged weapon - reduced from 10%
+        LootEntryDefault::new("Fire Arrow", 1, 2, 0.10),            // Special ammunition - reduced quantity
+        LootEntryDefault::new("Bush Knife", 1, 1, 0.09),            // Military clearing blade - reduced from 12%
+        LootEntryDefault::new("Engineers Maul", 1, 1, 0.08),        // Military engineering tool - reduced from 10%
+        LootEntryDefault::new("Plastic Water Jug", 1, 1, 0.09),     // Large water storage - reduced from 12%
+        LootEntryDefault::new("Anti-Venom", 1, 1, 0.10),            // Antidote - reduced from 15%
+        LootEntryDefault::new("Reed Diver's Helm", 1, 1, 0.06),     // Underwater breathing helmet - moderate low drop
+        LootEntryDefault::new("Headlamp", 1, 1, 0.08),              // Light source equipment
+        LootEntryDefault::new("Med Kit", 1, 1, 0.08),               // Military first aid kit - better than bandage
         
         // --- VERY RARE TIER (3-6% drop rates) - Premium items ---
-        ("Naval Cutlass", 1, 1, 0.05),         // Rare ceremonial naval weapon - reduced from 8%
+        LootEntryDefault::new("Naval Cutlass", 1, 1, 0.05),         // Rare ceremonial naval weapon - reduced from 8%
         
         // --- ULTRA-RARE TIER (1-2% drop rates) - Jackpot items ---
-        ("Military Crowbar", 1, 1, 0.02),      // Highest damage weapon in game - reduced from 5%
-        ("Scrap Batteries", 1, 1, 0.015),      // Ultra-rare electronics material - reduced from 3%
-        ("Makarov PM", 1, 1, 0.01),            // Ultra-rare Soviet pistol - 1% drop rate
-        ("PP-91 KEDR", 1, 1, 0.005),           // Extremely rare Soviet SMG - 0.5% drop rate (jackpot)
-        ("9x18mm Round", 3, 8, 0.015),         // Ultra-rare pistol/SMG ammunition - 1.5% drop rate
+        LootEntryDefault::new("Military Crowbar", 1, 1, 0.02),      // Highest damage weapon in game - reduced from 5%
+        LootEntryDefault::new("Scrap Batteries", 1, 1, 0.015),      // Ultra-rare electronics material - reduced from 3%
+        LootEntryDefault::new("Makarov PM", 1, 1, 0.01),            // Ultra-rare Soviet pistol - 1% drop rate
+        LootEntryDefault::new("PP-91 KEDR", 1, 1, 0.005),           // Extremely rare Soviet SMG - 0.5% drop rate (jackpot)
+        LootEntryDefault::new("9x18mm Round", 3, 8, 0.015),         // Ultra-rare pistol/SMG ammunition - 1.5% drop rate
+        
+        // --- BLUEPRINTS - Teach blueprint-locked recipes (see research.rs) ---
+        LootEntryDefault::new("Flashbang Blueprint", 1, 1, 0.04),
+        LootEntryDefault::new("Water Purifier Blueprint", 1, 1, 0.04),
+        LootEntryDefault::new("Signal Disruptor Blueprint", 1, 1, 0.03),
+        LootEntryDefault::new("Tallow Steam Turret Blueprint", 1, 1, 0.02),
+        LootEntryDefault::new("Makarov PM Blueprint", 1, 1, 0.01),
+        LootEntryDefault::new("PP-91 KEDR Blueprint", 1, 1, 0.005),
+        
+        // --- KEYCARDS - Start of the locked crate loop (see locked_crate.rs) ---
+        LootEntryDefault::new("Green Keycard", 1, 1, 0.03),
+        LootEntryDefault::new("Fuse", 1, 1, 0.04),              // Powers monument puzzle fuse boxes
     ];
-    
-    // Process each loot definition
-    for (item_name, min_quantity, max_quantity, drop_chance) in loot_definitions {
-        if let Some(item) = item_defs.iter().find(|def| def.name == item_name) {
-            loot_table.push(BarrelLootEntry {
-                item_def_id: item.id,
-                min_quantity,
-                max_quantity,
-                drop_chance,
-            });
-        } else {
-            log::warn!("[BarrelLoot] {} item not found in database", item_name);
-        }
-    }
-    
-    loot_table
+
+    vec![
+        // Barrels always drop 1-2 items
+        LootSourceDefault::new(BARREL_LOOT_SOURCE, barrel_entries).drops(1, 2),
+        LootSourceDefault::new(SEA_BARREL_BONUS_LOOT_SOURCE, vec![
+            LootEntryDefault::new("Olive Pit", 1, 1, SEA_BARREL_OLIVE_PIT_DROP_CHANCE), // Very rare maritime flotsam
+        ]),
+    ]
 }
 
 // --- Helper Functions ---
@@ -276,71 +272,21 @@ fn has_player_barrel_collision_with_size(
 /// Generates loot drops around a destroyed barrel
 /// Guarantees 1 item, max 2 items (like Rust)
 fn generate_barrel_loot_drops(ctx: &ReducerContext, barrel_pos_x: f32, barrel_pos_y: f32, barrel_variant: u8) -> Result<(), String> {
-    let loot_table = get_barrel_loot_table(ctx);
-    const MAX_DROPS_PER_BARREL: usize = 2;
-    
     log::info!("[BarrelLoot] Generating loot drops for barrel at ({:.1}, {:.1})", barrel_pos_x, barrel_pos_y);
     
-    // FIXED: Roll for ALL items first, then randomly select from successful rolls
-    let mut successful_rolls = Vec::new();
-    
-    // Roll for every item in the loot table (no early breaks!)
-    for loot_entry in &loot_table {
-        let roll: f32 = ctx.rng().gen();
-        if roll <= loot_entry.drop_chance {
-            successful_rolls.push(loot_entry);
-        }
-    }
-    
-    log::info!("[BarrelLoot] {} items passed their drop chance rolls", successful_rolls.len());
+    // Roll the shared loot table (guaranteed 1, max 2 - see default_barrel_loot_sources)
+    let mut items_to_drop = crate::loot_table::roll_loot(ctx, BARREL_LOOT_SOURCE, 0);
     
-    // If we have more successful rolls than max drops, randomly select which ones to actually drop
-    let mut items_to_drop = if successful_rolls.len() <= MAX_DROPS_PER_BARREL {
-        // All successful rolls can drop
-        successful_rolls.clone()
-    } else {
-        // Randomly select MAX_DROPS_PER_BARREL items from successful rolls
-        use rand::seq::SliceRandom;
-        let mut rng = ctx.rng();
-        let mut shuffled = successful_rolls.clone();
-        shuffled.shuffle(&mut rng);
-        shuffled.truncate(MAX_DROPS_PER_BARREL);
-        shuffled
-    };
-    
-    // GUARANTEE: If no items rolled to drop, force drop 1 common item (barrels always drop something)
-    if items_to_drop.is_empty() {
-        log::info!("[BarrelLoot] No items rolled to drop, forcing a guaranteed common item drop");
-        
-        // Find a common tier item (highest drop chances) to guarantee
-        let fallback_item = loot_table.iter()
-            .filter(|item| item.drop_chance >= 0.40) // Common tier items (40%+ drop chance)
-            .next();
-            
-        if let Some(guaranteed_item) = fallback_item {
-            items_to_drop.push(guaranteed_item);
-            log::info!("[BarrelLoot] Guaranteed drop: item {}", guaranteed_item.item_def_id);
-        } else {
-            // Ultimate fallback - use the first item in the table
-            if let Some(first_item) = loot_table.first() {
-                items_to_drop.push(first_item);
-                log::warn!("[BarrelLoot] Using first item as guaranteed drop: item {}", first_item.item_def_id);
-            }
-        }
+    // Sea-only bonus drop: rare Olive Pit from maritime flotsam barrels (variants 3-5).
+    let is_sea_variant = barrel_variant >= SEA_BARREL_VARIANT_START && barrel_variant < SEA_BARREL_VARIANT_END;
+    if is_sea_variant {
+        items_to_drop.extend(crate::loot_table::roll_loot(ctx, SEA_BARREL_BONUS_LOOT_SOURCE, 0));
     }
     
     let drops_created = items_to_drop.len();
-    log::info!("[BarrelLoot] Selected {} items to drop from {} successful rolls", drops_created, successful_rolls.len());
     
     // Create the actual dropped items WITHOUT triggering consolidation per-item
-    for (index, loot_entry) in items_to_drop.iter().enumerate() {
-        // Determine quantity
-        let quantity = if loot_entry.min_quantity == loot_entry.max_quantity {
-            loot_entry.min_quantity
-        } else {
-            ctx.rng().gen_range(loot_entry.min_quantity..=loot_entry.max_quantity)
-        };
-        
+    for (index, drop) in items_to_drop.iter().enumerate() {
         // Calculate drop position around the barrel (spread them out)
         let angle = (index as f32) * (2.0 * std::f32::consts::PI / drops_created.max(1) as f32) + 
                    ctx.rng().gen_range(-0.5..0.5); // Add some randomness
@@ -349,39 +295,21 @@ fn generate_barrel_loot_drops(ctx: &ReducerContext, barrel_pos_x: f32, barrel_po
         let drop_y = barrel_pos_y + angle.sin() * distance;
         
         // Create the dropped item without auto-consolidation
-        match crate::dropped_item::create_dropped_item_entity_no_consolidation(ctx, loot_entry.item_def_id, quantity, drop_x, drop_y) {
+        match crate::dropped_item::create_dropped_item_entity_no_consolidation(ctx, drop.item_def_id, drop.quantity, drop_x, drop_y) {
             Ok(_) => {
-                log::info!("[BarrelLoot] Created {} of item {} at ({:.1}, {:.1})", 
-                          quantity, loot_entry.item_def_id, drop_x, drop_y);
+                log::info!("[BarrelLoot] Created {} of {} at ({:.1}, {:.1})", 
+                          drop.quantity, drop.item_name, drop_x, drop_y);
             }
             Err(e) => {
-                log::error!("[BarrelLoot] Failed to create dropped item {}: {}", loot_entry.item_def_id, e);
-            }
-        }
-    }
-    
-    // Sea-only bonus drop: rare Olive Pit from maritime flotsam barrels (variants 3-5).
-    let is_sea_variant = barrel_variant >= SEA_BARREL_VARIANT_START && barrel_variant < SEA_BARREL_VARIANT_END;
-    if is_sea_variant && ctx.rng().gen::<f32>() < SEA_BARREL_OLIVE_PIT_DROP_CHANCE {
-        if let Some(olive_pit_def) = ctx.db.item_definition().iter().find(|def| def.name == "Olive Pit") {
-            let angle = ctx.rng().gen_range(0.0..std::f32::consts::TAU);
-            let distance = ctx.rng().gen_range(30.0..60.0);
-            let drop_x = barrel_pos_x + angle.cos() * distance;
-            let drop_y = barrel_pos_y + angle.sin() * distance;
-            if let Err(e) = crate::dropped_item::create_dropped_item_entity_no_consolidation(ctx, olive_pit_def.id, 1, drop_x, drop_y) {
-                log::error!("[BarrelLoot] Failed to create Olive Pit sea bonus drop: {}", e);
-            } else {
-                log::info!("[BarrelLoot] Sea bonus drop: Olive Pit at ({:.1}, {:.1})", drop_x, drop_y);
+                log::error!("[BarrelLoot] Failed to create dropped item {}: {}", drop.item_name, e);
             }
-        } else {
-            log::warn!("[BarrelLoot] Olive Pit item definition not found for sea bonus drop");
         }
     }
 
     // Trigger consolidation ONCE after all items are dropped
     crate::dropped_item::trigger_consolidation_at_position(ctx, barrel_pos_x, barrel_pos_y);
     
-    log::info!("[BarrelLoot] Created {} loot drops for destroyed barrel (guaranteed 1, max 2)", drops_created);
+    log::info!("[BarrelLoot] Created {} loot drops for destroyed barrel", drops_created);
     Ok(())
 }
 
@@ -433,6 +361,9 @@ pub fn damage_barrel(
             log::error!("[BarrelDamage] Failed to generate loot for barrel {}: {}", barrel_id, e);
         }
         
+        // Rare cosmetic skin unlock
+        crate::item_cosmetic::try_roll_barrel_skin_drop(ctx, attacker_id);
+        
         // Track barrels_destroyed stat for achievements
         if let Err(e) = crate::player_progression::track_stat_and_check_achievements(ctx, attacker_id, "barrels_destroyed", 1) {
             log::error!("[BarrelDamage] Failed to track barrel destroyed stat: {}", e);
@@ -1367,6 +1298,7 @@ pub fn spawn_buoy_barrels(ctx: &ReducerContext) -> Result<(), String> {
         .map(|t| ((t.world_x, t.world_y), t.tile_type))
         .collect();
 
+    // Buoys mark the ocean shelf edge; Freshwater rivers and lakes never border DeepSea
     let is_open_ocean = |t: &TileType| *t == TileType::Sea || *t == TileType::DeepSea;
     let is_transition_tile = |tx: i32, ty: i32| -> bool {
         let center = match tile_map.get(&(tx, ty)) {
@@ -16,6 +16,13 @@
  *   - If all output slots are full, honeycomb drops as item nearby           *
  *   - Queen Bee doesn't spoil while in the beehive                           *
  *                                                                            *
+ * Pollination:                                                               *
+ *   - Flowering player crops near the hive add bonus honeycomb per cycle     *
+ *                                                                            *
+ * Defense:                                                                   *
+ *   - Striking a hive with a Queen Bee rouses angry bees that chase the      *
+ *     attacker, then settle back into the hive once the threat is gone       *
+ *                                                                            *
  ******************************************************************************/
 
 use spacetimedb::{ReducerContext, Table, Timestamp, Identity};
@@ -30,6 +37,9 @@ use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as
 use crate::dropped_item::create_dropped_item_entity;
 use crate::inventory_management::{merge_or_place_into_container_slot, ItemContainer};
 use crate::models::{ItemLocation, ContainerType, ContainerLocationData};
+use crate::harvestable_resource::harvestable_resource as HarvestableResourceTableTrait;
+use crate::plants_database::PlantType;
+use crate::wild_animal_npc::{AnimalSpecies, AnimalState, transition_to_state, wild_animal as WildAnimalTableTrait};
 use serde_json;
 
 // --- Beehive Constants ---
@@ -43,6 +53,15 @@ pub const BEEHIVE_PROCESS_INTERVAL_SECS: u64 = 60; // Check every minute
 pub const BEEHIVE_PRODUCTION_TIME_SECS: u64 = 300; // 5 minutes to produce 1 honeycomb
 pub const HONEYCOMB_PER_PRODUCTION: u32 = 1; // Produce 1 honeycomb per cycle
 
+// Pollination bonus constants
+pub const BEEHIVE_POLLINATION_RADIUS: f32 = 500.0; // Flowering crops within this range feed the hive
+const BEEHIVE_POLLINATION_RADIUS_SQUARED: f32 = BEEHIVE_POLLINATION_RADIUS * BEEHIVE_POLLINATION_RADIUS;
+pub const FLOWERING_CROPS_PER_BONUS: u32 = 3; // +1 honeycomb per 3 flowering crops nearby
+pub const MAX_POLLINATION_BONUS: u32 = 2; // At most +2 honeycomb per cycle
+
+// Hive defense constants
+pub const BEEHIVE_DEFENDER_BEES: u32 = 3; // Angry bees roused when the hive is attacked
+
 // --- Beehive Schedule Table ---
 #[spacetimedb::table(accessor = beehive_process_schedule, scheduled(process_beehive_production))]
 #[derive(Clone)]
@@ -126,6 +145,36 @@ pub fn is_queen_bee(item_def: &ItemDefinition) -> bool {
     item_def.name == "Queen Bee"
 }
 
+/// Crops that flower and can be pollinated by hive bees
+fn is_flowering_plant(plant_type: &PlantType) -> bool {
+    matches!(plant_type,
+        PlantType::Yarrow | PlantType::Chamomile | PlantType::Chicory | PlantType::Mint |
+        PlantType::Valerian | PlantType::Flax | PlantType::Fireweed | PlantType::ArcticPoppy |
+        PlantType::Sunflowers | PlantType::KamchatkaLily | PlantType::Nagoonberry |
+        PlantType::Cloudberries | PlantType::Lingonberries | PlantType::Bilberries |
+        PlantType::WildStrawberries | PlantType::Cranberries | PlantType::Pumpkin
+    )
+}
+
+/// Counts grown (unharvested) player-planted flowering crops within pollination range of a hive
+fn count_flowering_crops_near(ctx: &ReducerContext, hive_x: f32, hive_y: f32) -> u32 {
+    ctx.db.harvestable_resource().iter()
+        .filter(|r| r.is_player_planted && r.respawn_at == Timestamp::UNIX_EPOCH && is_flowering_plant(&r.plant_type))
+        .filter(|r| {
+            let dx = r.pos_x - hive_x;
+            let dy = r.pos_y - hive_y;
+            dx * dx + dy * dy <= BEEHIVE_POLLINATION_RADIUS_SQUARED
+        })
+        .count() as u32
+}
+
+/// Honeycomb produced per cycle, including the pollination bonus from nearby flowering crops
+fn get_honeycomb_yield(ctx: &ReducerContext, beehive: &WoodenStorageBox) -> u32 {
+    let flowering_crops = count_flowering_crops_near(ctx, beehive.pos_x, beehive.pos_y);
+    let pollination_bonus = (flowering_crops / FLOWERING_CROPS_PER_BONUS).min(MAX_POLLINATION_BONUS);
+    HONEYCOMB_PER_PRODUCTION + pollination_bonus
+}
+
 /// Checks if an item is Honeycomb
 pub fn is_honeycomb(item_def: &ItemDefinition) -> bool {
     item_def.name == "Honeycomb"
@@ -292,6 +341,7 @@ pub fn process_beehive_production(ctx: &ReducerContext, _schedule: BeehiveProces
         }
         
         // Time to produce honeycomb!
+        let honeycomb_yield = get_honeycomb_yield(ctx, &beehive);
         let mut honeycomb_placed = false;
         let mut beehive_struct_modified = false; // OPTIMIZATION: track if box struct needs writing
         let mut updated_beehive = beehive.clone();
@@ -311,7 +361,7 @@ pub fn process_beehive_production(ctx: &ReducerContext, _schedule: BeehiveProces
                 let new_honeycomb = InventoryItem {
                     instance_id: 0, // Auto-increment
                     item_def_id: honeycomb_def.id,
-                    quantity: HONEYCOMB_PER_PRODUCTION,
+                    quantity: honeycomb_yield,
                     location: ItemLocation::Container(ContainerLocationData {
                         container_type: ContainerType::WoodenStorageBox,
                         container_id: beehive.id as u64,
@@ -333,7 +383,7 @@ pub fn process_beehive_production(ctx: &ReducerContext, _schedule: BeehiveProces
                 
                 honeycomb_placed = true;
                 beehive_struct_modified = true; // New item placed in previously empty slot
-                total_produced += 1;
+                total_produced += honeycomb_yield;
                 log::debug!("[Beehive] Produced honeycomb in beehive {} slot {}", beehive.id, slot_idx);
                 break;
             } else if let Some(inst_id) = slot_instance {
@@ -342,13 +392,13 @@ pub fn process_beehive_production(ctx: &ReducerContext, _schedule: BeehiveProces
                     if let Some(existing_def) = item_defs.id().find(existing_item.item_def_id) {
                         if is_honeycomb(&existing_def) && existing_item.quantity < honeycomb_def.stack_size as u32 {
                             // Stack with existing honeycomb
-                            existing_item.quantity += HONEYCOMB_PER_PRODUCTION;
+                            existing_item.quantity += honeycomb_yield;
                             if existing_item.quantity > honeycomb_def.stack_size as u32 {
                                 existing_item.quantity = honeycomb_def.stack_size as u32;
                             }
                             inventory_items.instance_id().update(existing_item);
                             honeycomb_placed = true;
-                            total_produced += 1;
+                            total_produced += honeycomb_yield;
                             log::debug!("[Beehive] Stacked honeycomb in beehive {} slot {}", beehive.id, slot_idx);
                             break;
                         }
@@ -363,7 +413,7 @@ pub fn process_beehive_production(ctx: &ReducerContext, _schedule: BeehiveProces
             let drop_x = beehive.pos_x + (rng.gen::<f32>() - 0.5) * 40.0;
             let drop_y = beehive.pos_y + (rng.gen::<f32>() - 0.5) * 40.0;
             
-            match create_dropped_item_entity(ctx, honeycomb_def.id, HONEYCOMB_PER_PRODUCTION, drop_x, drop_y) {
+            match create_dropped_item_entity(ctx, honeycomb_def.id, honeycomb_yield, drop_x, drop_y) {
                 Ok(_) => {
                     total_dropped += 1;
                     log::info!("[Beehive] Output full - dropped honeycomb near beehive {} at ({:.1}, {:.1})", beehive.id, drop_x, drop_y);
@@ -395,5 +445,42 @@ pub fn process_beehive_production(ctx: &ReducerContext, _schedule: BeehiveProces
     Ok(())
 }
 
+/// Rouses a player beehive's bees against whoever struck it. Only hives with a Queen Bee
+/// have a colony to defend. Bees settle back into the hive once the attacker is gone.
+pub fn rouse_beehive_defenders(ctx: &ReducerContext, beehive: &WoodenStorageBox, attacker_id: Identity) {
+    if beehive.box_type != BOX_TYPE_PLAYER_BEEHIVE || beehive.is_destroyed {
+        return;
+    }
+    
+    let has_queen = beehive.slot_def_id_0
+        .and_then(|def_id| ctx.db.item_definition().id().find(def_id))
+        .map_or(false, |def| is_queen_bee(&def));
+    if !has_queen {
+        return;
+    }
+    
+    let hive_id = beehive.id as u64;
+    let existing = crate::wild_animal_npc::bee::count_bees_at_hive(ctx, beehive.pos_x, beehive.pos_y);
+    if existing < BEEHIVE_DEFENDER_BEES {
+        let mut rng = ctx.rng();
+        if let Err(e) = crate::wild_animal_npc::bee::spawn_bees_at_hive(ctx, hive_id, beehive.pos_x, beehive.pos_y, BEEHIVE_DEFENDER_BEES - existing, &mut rng) {
+            log::warn!("[Beehive] Failed to rouse bees at beehive {}: {}", beehive.id, e);
+            return;
+        }
+    }
+    
+    // Send the whole colony after the attacker
+    let colony: Vec<_> = ctx.db.wild_animal().iter()
+        .filter(|a| a.species == AnimalSpecies::Bee && a.pack_id == Some(hive_id))
+        .collect();
+    for mut bee in colony {
+        if bee.state != AnimalState::Chasing {
+            transition_to_state(&mut bee, AnimalState::Chasing, ctx.timestamp, Some(attacker_id), "hive attacked");
+            ctx.db.wild_animal().id().update(bee);
+        }
+    }
+    log::info!("[Beehive] Beehive {} attacked by {:?} - bees roused to defend it", beehive.id, attacker_id);
+}
+
 // Yeast extraction is now unified with Queen Bee extraction in bones.rs
 // See extract_from_honeycomb reducer which gives 15% Queen Bee, 85% Yeast
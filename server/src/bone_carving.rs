@@ -379,11 +379,12 @@ pub fn start_bone_carving(ctx: &ReducerContext, recipe_id: u64) -> Result<(), St
         recipe_id: 0, // Use 0 to indicate bone carving (no Recipe table entry)
         output_item_def_id: output_def.id,
         output_quantity: recipe.output_quantity,
-        start_time: ctx.timestamp,
+        start_time: latest_finish_time,
         finish_time,
     };
 
     queue_table.insert(queue_item);
+    crate::crafting_queue::sync_player_crafting_queue(ctx, sender_id);
 
     log::info!(
         "Player {} started bone carving: {} (finishes in {}s)",
@@ -151,3 +151,17 @@ pub fn extract_from_honeycomb(ctx: &ReducerContext, item_instance_id: u64) -> Re
     
     Ok(())
 }
+
+// =============================================================================
+// BONE PILES - Fully decayed corpses
+// =============================================================================
+// Corpses that reach the Bones decay stage (see corpse_decay.rs) have nothing
+// left but bones. Every hit on a bone pile yields Animal Bone, which can then be
+// crushed into Bone Fragments with process_extraction above.
+
+pub const BONE_PILE_ITEM_NAME: &str = "Animal Bone";
+
+/// Bones collected from one hit on a bone pile. Always at least one - there's nothing else to find.
+pub fn roll_bone_pile_loot(rng: &mut impl Rng, quantity_per_hit: u32) -> u32 {
+    quantity_per_hit.max(1) + rng.gen_range(0..=1)
+}
@@ -814,22 +814,6 @@ pub fn pickup_broth_pot(ctx: &ReducerContext, broth_pot_id: u32) -> Result<(), S
  *                           REDUCERS (Basic Interaction)                     *
  ******************************************************************************/
 
-/// --- Move Item to Broth Pot Ingredient Slot ---
-/// Moves an item from player inventory/hotbar to a specific ingredient slot in the broth pot.
-#[spacetimedb::reducer]
-pub fn move_item_to_broth_pot(
-    ctx: &ReducerContext,
-    broth_pot_id: u32,
-    target_slot_index: u8,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_player, mut broth_pot) = validate_broth_pot_interaction(ctx, broth_pot_id)?;
-    inventory_management::handle_move_to_container_slot(ctx, &mut broth_pot, target_slot_index, item_instance_id)?;
-    ctx.db.broth_pot().id().update(broth_pot);
-    schedule_next_broth_pot_processing(ctx, broth_pot_id);
-    Ok(())
-}
-
 /// --- Quick Move to Broth Pot ---
 /// Quickly moves an item from player inventory/hotbar to the first available/mergeable slot in the broth pot.
 /// SPECIAL CASE: Fuel items (Wood, Charcoal, etc.) are redirected to the attached campfire's fuel slot
@@ -874,123 +858,27 @@ pub fn quick_move_to_broth_pot(
     Ok(())
 }
 
-/// --- Move Item from Broth Pot Ingredient Slot ---
-/// Moves an item from a specific ingredient slot in the broth pot to player inventory/hotbar.
-#[spacetimedb::reducer]
-pub fn move_item_from_broth_pot(
-    ctx: &ReducerContext,
-    broth_pot_id: u32,
-    source_slot_index: u8,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let (_player, mut broth_pot) = validate_broth_pot_interaction(ctx, broth_pot_id)?;
-    inventory_management::handle_move_from_container_slot(ctx, &mut broth_pot, source_slot_index, target_slot_type, target_slot_index)?;
-    ctx.db.broth_pot().id().update(broth_pot);
-    schedule_next_broth_pot_processing(ctx, broth_pot_id);
-    Ok(())
-}
-
-/// --- Quick Move from Broth Pot Ingredient Slot ---
-/// Quickly moves an item from a specific ingredient slot in the broth pot to player inventory/hotbar.
-#[spacetimedb::reducer]
-pub fn quick_move_from_broth_pot(
-    ctx: &ReducerContext,
-    broth_pot_id: u32,
-    source_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut broth_pot) = validate_broth_pot_interaction(ctx, broth_pot_id)?;
-    inventory_management::handle_quick_move_from_container(ctx, &mut broth_pot, source_slot_index)?;
-    ctx.db.broth_pot().id().update(broth_pot);
-    schedule_next_broth_pot_processing(ctx, broth_pot_id);
-    Ok(())
-}
-
-/// --- Move Item Within Broth Pot ---
-/// Moves an item between two ingredient slots within the same broth pot.
-#[spacetimedb::reducer]
-pub fn move_item_within_broth_pot(
-    ctx: &ReducerContext,
-    broth_pot_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut broth_pot) = validate_broth_pot_interaction(ctx, broth_pot_id)?;
-    inventory_management::handle_move_within_container(ctx, &mut broth_pot, source_slot_index, target_slot_index)?;
-    ctx.db.broth_pot().id().update(broth_pot);
-    schedule_next_broth_pot_processing(ctx, broth_pot_id);
-    Ok(())
-}
-
-/// --- Split Stack Into Broth Pot ---
-/// Splits a stack from player inventory/hotbar into a specific ingredient slot in the broth pot.
-#[spacetimedb::reducer]
-pub fn split_stack_into_broth_pot(
-    ctx: &ReducerContext,
-    broth_pot_id: u32,
-    target_slot_index: u8,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (_player, mut broth_pot) = validate_broth_pot_interaction(ctx, broth_pot_id)?;
-    inventory_management::handle_split_into_container(
-        ctx,
-        &mut broth_pot,
-        target_slot_index,
-        source_item_instance_id,
-        quantity_to_split
-    )?;
-    ctx.db.broth_pot().id().update(broth_pot);
-    schedule_next_broth_pot_processing(ctx, broth_pot_id);
-    Ok(())
-}
+impl inventory_management::ContainerHandle for BrothPot {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_broth_pot_interaction(ctx, container_id)
+    }
 
-/// --- Split Stack From Broth Pot ---
-/// Splits a stack from an ingredient slot in the broth pot into the player's inventory/hotbar.
-#[spacetimedb::reducer]
-pub fn split_stack_from_broth_pot(
-    ctx: &ReducerContext,
-    broth_pot_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let (_player, mut broth_pot) = validate_broth_pot_interaction(ctx, broth_pot_id)?;
-    inventory_management::handle_split_from_container(
-        ctx,
-        &mut broth_pot,
-        source_slot_index,
-        quantity_to_split,
-        target_slot_type,
-        target_slot_index
-    )?;
-    ctx.db.broth_pot().id().update(broth_pot);
-    schedule_next_broth_pot_processing(ctx, broth_pot_id);
-    Ok(())
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.broth_pot().id().update(self.clone());
+        let _ = schedule_next_broth_pot_processing(ctx, self.id);
+    }
 }
 
-/// --- Split Stack Within Broth Pot ---
-/// Splits a stack FROM one ingredient slot TO another within the same broth pot.
-#[spacetimedb::reducer]
-pub fn split_stack_within_broth_pot(
-    ctx: &ReducerContext,
-    broth_pot_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (_player, mut broth_pot) = validate_broth_pot_interaction(ctx, broth_pot_id)?;
-    inventory_management::handle_split_within_container(
-        ctx,
-        &mut broth_pot,
-        source_slot_index,
-        target_slot_index,
-        quantity_to_split
-    )?;
-    ctx.db.broth_pot().id().update(broth_pot);
-    schedule_next_broth_pot_processing(ctx, broth_pot_id);
-    Ok(())
+// Ingredient slots only; quick_move_to_broth_pot (fuel redirect) and the water/output reducers are hand-written
+crate::container_reducers! {
+    BrothPot;
+    move_to: move_item_to_broth_pot(broth_pot_id),
+    move_from: move_item_from_broth_pot(broth_pot_id),
+    quick_move_from: quick_move_from_broth_pot(broth_pot_id),
+    move_within: move_item_within_broth_pot(broth_pot_id),
+    split_into: split_stack_into_broth_pot(broth_pot_id),
+    split_from: split_stack_from_broth_pot(broth_pot_id),
+    split_within: split_stack_within_broth_pot(broth_pot_id),
 }
 
 /// --- Broth Pot Interaction Check ---
@@ -3,12 +3,15 @@
  * Building System - Foundations, Walls, Doors                                *
  *                                                                            *
  * Handles placement, destruction, and management of building pieces.        *
+ * Walls come in four types: solid walls, window walls (projectiles pass     *
+ * through), half walls (projectiles pass over) and doorframes (open edge    *
+ * that a door can be fitted into).                                           *
  *                                                                            *
  ******************************************************************************/
 
 use spacetimedb::{Identity, Timestamp, ReducerContext, Table, log};
 use crate::{
-    models::{FoundationShape, BuildingEdge, BuildingFacing, BuildingTier, TargetType, ItemLocation},
+    models::{FoundationShape, BuildingEdge, BuildingFacing, BuildingTier, WallType, TargetType, ItemLocation},
     environment::{calculate_chunk_index, is_position_on_water},
     TILE_SIZE_PX,
     world_pos_to_tile_coords,
@@ -16,7 +19,6 @@ use crate::{
 use crate::player as PlayerTableTrait;
 use crate::active_equipment::active_equipment as ActiveEquipmentTableTrait;
 use crate::items::{item_definition as ItemDefinitionTableTrait, inventory_item as InventoryItemTableTrait};
-use crate::homestead_hearth::homestead_hearth as HomesteadHearthTableTrait;
 use crate::rune_stone::{rune_stone as RuneStoneTableTrait, RUNE_STONE_LIGHT_RADIUS};
 use crate::world_tile as WorldTileTableTrait;
 use crate::grass::grass as GrassTableTrait;
@@ -28,6 +30,12 @@ use crate::reed_marsh as ReedMarshTableTrait;
 use crate::tide_pool as TidePoolTableTrait;
 use crate::planted_seeds::planted_seed as PlantedSeedTableTrait;
 use crate::harvestable_resource::harvestable_resource as HarvestableResourceTableTrait;
+use crate::building_floor::{floor_cell as FloorCellTableTrait, stairs_cell as StairsCellTableTrait};
+use crate::door::door as DoorTableTrait;
+use crate::fence::fence as FenceTableTrait;
+use crate::shelter::shelter as ShelterTableTrait;
+use crate::homestead_hearth::homestead_hearth as HomesteadHearthTableTrait;
+use crate::lantern::lantern as LanternTableTrait;
 
 // Import table traits
 // Table trait will be auto-generated by SpacetimeDB
@@ -58,6 +66,17 @@ pub const DOOR_WOOD_MAX_HEALTH: f32 = 1500.0;  // Matches STONE wall - ~5 min so
 pub const DOOR_STONE_MAX_HEALTH: f32 = 1500.0; // Same as wood door (no stone door exists)
 pub const DOOR_METAL_MAX_HEALTH: f32 = 4000.0; // Matches METAL wall - ~13 min solo raid
 
+// Wall variants: fraction of a solid wall's HP for the same tier
+// Openings are the weak point of a base - cheaper to build, faster to raid
+pub const WINDOW_WALL_HEALTH_MULT: f32 = 0.6;
+pub const HALF_WALL_HEALTH_MULT: f32 = 0.5;
+pub const DOORFRAME_HEALTH_MULT: f32 = 0.75; // Sturdy frame, but the door is what really defends the opening
+
+// Wall variants: fraction of a solid wall's placement/upgrade cost
+pub const WINDOW_WALL_COST_MULT: f32 = 0.8;
+pub const HALF_WALL_COST_MULT: f32 = 0.6;
+pub const DOORFRAME_COST_MULT: f32 = 0.8;
+
 // --- Melee Damage Reduction Multipliers ---
 // Melee weapons are ineffective against upgraded structures - explosives needed later
 // Twig: Full damage (early game entry point)
@@ -132,6 +151,7 @@ pub struct WallCell {
     pub last_hit_time: Option<Timestamp>,
     pub last_damaged_by: Option<Identity>,
     pub group_id: Option<i64>,  // For future building groups/clans
+    pub wall_type: u8, // WallType enum (0-3: Solid, Window, Half, Doorframe)
 }
 
 // --- Helper Functions ---
@@ -188,6 +208,77 @@ pub fn get_wall_max_health(tier: BuildingTier) -> f32 {
     }
 }
 
+/// Convert a stored wall_type value to WallType (unknown values are treated as Solid)
+pub fn wall_type_from_u8(wall_type: u8) -> WallType {
+    match wall_type {
+        1 => WallType::Window,
+        2 => WallType::Half,
+        3 => WallType::Doorframe,
+        _ => WallType::Solid,
+    }
+}
+
+/// Display name used in logs and error messages
+pub fn get_wall_type_name(wall_type: WallType) -> &'static str {
+    match wall_type {
+        WallType::Solid => "Wall",
+        WallType::Window => "Window Wall",
+        WallType::Half => "Half Wall",
+        WallType::Doorframe => "Doorframe",
+    }
+}
+
+/// HP multiplier relative to a solid wall of the same tier
+pub fn get_wall_type_health_multiplier(wall_type: WallType) -> f32 {
+    match wall_type {
+        WallType::Solid => 1.0,
+        WallType::Window => WINDOW_WALL_HEALTH_MULT,
+        WallType::Half => HALF_WALL_HEALTH_MULT,
+        WallType::Doorframe => DOORFRAME_HEALTH_MULT,
+    }
+}
+
+/// Cost multiplier relative to a solid wall (applies to placement and upgrades)
+pub fn get_wall_type_cost_multiplier(wall_type: WallType) -> f32 {
+    match wall_type {
+        WallType::Solid => 1.0,
+        WallType::Window => WINDOW_WALL_COST_MULT,
+        WallType::Half => HALF_WALL_COST_MULT,
+        WallType::Doorframe => DOORFRAME_COST_MULT,
+    }
+}
+
+/// Get max health for a wall piece of the given tier and type
+pub fn get_wall_piece_max_health(tier: BuildingTier, wall_type: WallType) -> f32 {
+    get_wall_max_health(tier) * get_wall_type_health_multiplier(wall_type)
+}
+
+/// Whether projectiles (arrows, bullets, thrown spears) are stopped by this wall type
+/// Windows have an opening, half walls can be shot over, empty doorframes are open
+pub fn wall_type_blocks_projectiles(wall_type: u8) -> bool {
+    matches!(wall_type_from_u8(wall_type), WallType::Solid)
+}
+
+/// Whether melee attacks and line-of-sight checks are stopped by this wall type
+/// Only empty doorframes let melee through - windows and half walls still block swings
+pub fn wall_type_blocks_melee(wall_type: u8) -> bool {
+    !matches!(wall_type_from_u8(wall_type), WallType::Doorframe)
+}
+
+/// Whether players/animals are blocked from walking through this wall type
+/// Doorframes are walkable; a door fitted into the frame provides its own collision
+pub fn wall_type_blocks_movement(wall_type: u8) -> bool {
+    !matches!(wall_type_from_u8(wall_type), WallType::Doorframe)
+}
+
+/// Apply a wall type cost multiplier to a base resource cost (rounded up, never zero)
+fn scale_wall_cost(base_cost: u32, wall_type: WallType) -> u32 {
+    if base_cost == 0 {
+        return 0;
+    }
+    ((base_cost as f32 * get_wall_type_cost_multiplier(wall_type)).ceil() as u32).max(1)
+}
+
 /// Get door max health based on tier
 pub fn get_door_max_health(tier: BuildingTier) -> f32 {
     match tier {
@@ -656,6 +747,8 @@ pub fn check_monument_zone_placement(ctx: &ReducerContext, world_x: f32, world_y
     const WOLF_DEN_RESTRICTION_RADIUS_SQ: f32 = WOLF_DEN_RESTRICTION_RADIUS * WOLF_DEN_RESTRICTION_RADIUS;
     const ALPINE_VILLAGE_RESTRICTION_RADIUS: f32 = 800.0; // Alpine village lodge (800px minimum, like other monuments)
     const ALPINE_VILLAGE_RESTRICTION_RADIUS_SQ: f32 = ALPINE_VILLAGE_RESTRICTION_RADIUS * ALPINE_VILLAGE_RESTRICTION_RADIUS;
+    const FOREST_RUIN_RESTRICTION_RADIUS: f32 = 300.0; // Abandoned cabins / ruined towers (small prefabs)
+    const FOREST_RUIN_RESTRICTION_RADIUS_SQ: f32 = FOREST_RUIN_RESTRICTION_RADIUS * FOREST_RUIN_RESTRICTION_RADIUS;
     
     for part in ctx.db.monument_part().iter() {
         // Only check against center pieces for simplicity
@@ -713,6 +806,11 @@ pub fn check_monument_zone_placement(ctx: &ReducerContext, world_x: f32, world_y
                     return Err("Cannot place items within the Alpine Village. This monument must remain unobstructed.".to_string());
                 }
             }
+            MonumentType::AbandonedCabin | MonumentType::RuinedTower => {
+                if distance_sq <= FOREST_RUIN_RESTRICTION_RADIUS_SQ {
+                    return Err("Cannot place items within the ruins. This monument must remain unobstructed.".to_string());
+                }
+            }
         }
     }
     
@@ -832,6 +930,9 @@ pub fn place_foundation(
     // 5.2. Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
     check_monument_zone_placement(ctx, world_x, world_y)?;
     
+    // 5.21. Check building privilege (cannot build inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, sender_id)?;
+    
     // 5.25. Check if position has grass (cannot place foundation on grass - must clear first)
     // Foundation is 96x96 pixels, check if any alive grass is within the foundation bounds
     // Uses split tables: GrassState (health) + Grass (position)
@@ -1106,21 +1207,6 @@ pub fn upgrade_foundation(
         return Err("Repair Hammer must be equipped to upgrade foundations.".to_string());
     }
     
-    // 2.5. Check building privilege (only if hearths exist)
-    // EARLY GAME: If no hearths exist, allow anyone to upgrade (pre-privilege phase)
-    // LATE GAME: Once hearths exist, require building privilege
-    use crate::homestead_hearth::player_has_building_privilege;
-    let hearths = ctx.db.homestead_hearth();
-    let any_hearth_exists = hearths.iter().any(|h| !h.is_destroyed);
-    
-    if any_hearth_exists {
-        // Hearths exist - require building privilege
-        if !player_has_building_privilege(ctx, sender_id) {
-            return Err("Building privilege required. Hold E near a Homestead Hearth to gain building privilege.".to_string());
-        }
-    }
-    // If no hearths exist, skip privilege check entirely (early game freedom)
-    
     // 3. Find foundation
     let foundation = foundations.id().find(&foundation_id)
         .ok_or_else(|| "Foundation not found".to_string())?;
@@ -1129,6 +1215,11 @@ pub fn upgrade_foundation(
         return Err("Cannot upgrade destroyed foundation.".to_string());
     }
     
+    // 3.5. Check building privilege at the foundation's cell (unclaimed land is open to everyone)
+    let privilege_x = (foundation.cell_x as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
+    let privilege_y = (foundation.cell_y as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
+    crate::homestead_hearth::check_position_privileged(ctx, privilege_x, privilege_y, sender_id)?;
+    
     // 4. Validate new tier
     if !is_valid_building_tier(new_tier) {
         return Err(format!("Invalid building tier: {}. Must be 0-3 (Twig, Wood, Stone, Metal).", new_tier));
@@ -1513,15 +1604,68 @@ pub fn place_wall(
     world_x: f32,
     world_y: f32,
     tier: u8,
+) -> Result<(), String> {
+    place_wall_piece(ctx, cell_x, cell_y, world_x, world_y, tier, WallType::Solid)
+}
+
+/// Place a window wall (projectiles pass through the opening, melee and movement are blocked)
+#[spacetimedb::reducer]
+pub fn place_window_wall(
+    ctx: &ReducerContext,
+    cell_x: i64,
+    cell_y: i64,
+    world_x: f32,
+    world_y: f32,
+    tier: u8,
+) -> Result<(), String> {
+    place_wall_piece(ctx, cell_x, cell_y, world_x, world_y, tier, WallType::Window)
+}
+
+/// Place a half wall (projectiles pass over it, melee and movement are blocked)
+#[spacetimedb::reducer]
+pub fn place_half_wall(
+    ctx: &ReducerContext,
+    cell_x: i64,
+    cell_y: i64,
+    world_x: f32,
+    world_y: f32,
+    tier: u8,
+) -> Result<(), String> {
+    place_wall_piece(ctx, cell_x, cell_y, world_x, world_y, tier, WallType::Half)
+}
+
+/// Place a doorframe (open edge that a door can be fitted into - North/South edges only, like doors)
+#[spacetimedb::reducer]
+pub fn place_doorframe(
+    ctx: &ReducerContext,
+    cell_x: i64,
+    cell_y: i64,
+    world_x: f32,
+    world_y: f32,
+    tier: u8,
+) -> Result<(), String> {
+    place_wall_piece(ctx, cell_x, cell_y, world_x, world_y, tier, WallType::Doorframe)
+}
+
+/// Shared placement logic for all wall types
+fn place_wall_piece(
+    ctx: &ReducerContext,
+    cell_x: i64,
+    cell_y: i64,
+    world_x: f32,
+    world_y: f32,
+    tier: u8,
+    wall_type: WallType,
 ) -> Result<(), String> {
     let sender_id = ctx.sender();
     let players = ctx.db.player();
     let walls = ctx.db.wall_cell();
     let foundations = ctx.db.foundation_cell();
+    let piece_name = get_wall_type_name(wall_type);
     
     log::info!(
-        "[PlaceWall] Player {:?} attempting to place wall at cell ({}, {}), world=({:.1}, {:.1}), tier={}",
-        sender_id, cell_x, cell_y, world_x, world_y, tier
+        "[PlaceWall] Player {:?} attempting to place {} at cell ({}, {}), world=({:.1}, {:.1}), tier={}",
+        sender_id, piece_name, cell_x, cell_y, world_x, world_y, tier
     );
     
     // 1. Validate player
@@ -1586,6 +1730,11 @@ pub fn place_wall(
         foundation_shape,
     );
     
+    // 6.5. Doorframes must be on an edge that can hold a door
+    if wall_type == WallType::Doorframe && !matches!(edge, BuildingEdge::N | BuildingEdge::S) {
+        return Err("Doorframes can only be placed on North or South edges.".to_string());
+    }
+    
     // 7. Validate wall position (foundation exists, no overlapping wall)
     is_wall_position_valid(ctx, cell_x_i32, cell_y_i32, edge, facing)?;
     
@@ -1605,16 +1754,19 @@ pub fn place_wall(
         ));
     }
     
+    // 8.5. Check building privilege at the wall's cell
+    crate::homestead_hearth::check_position_privileged(ctx, tile_center_x, tile_center_y, sender_id)?;
+    
     // 9. Calculate chunk index
     let chunk_index = calculate_chunk_index(tile_center_x, tile_center_y);
     
-    // 10. Get max health for this tier
-    let max_health = get_wall_max_health(building_tier);
+    // 10. Get max health for this tier and wall type
+    let max_health = get_wall_piece_max_health(building_tier, wall_type);
     
-    // 11. Check and consume resources (Twig tier uses wood, cost: 25 wood per wall)
+    // 11. Check and consume resources (Twig tier uses wood, cost: 25 wood per solid wall)
     // Twig walls are CHEAP placeholders - get your base shape down quickly, then upgrade
     // The real cost comes from upgrading to wood/stone/metal
-    let required_wood = 25;
+    let required_wood = scale_wall_cost(25, wall_type);
     
     let inventory = ctx.db.inventory_item();
     let item_defs = ctx.db.item_definition();
@@ -1691,6 +1843,7 @@ pub fn place_wall(
         last_hit_time: None,
         last_damaged_by: None,
         group_id: None,
+        wall_type: wall_type as u8,
     };
     
     walls.try_insert(new_wall)
@@ -1700,8 +1853,8 @@ pub fn place_wall(
     crate::sound_events::emit_foundation_wood_constructed_sound(ctx, tile_center_x, tile_center_y, sender_id);
     
     log::info!(
-        "[PlaceWall] Successfully placed wall at cell ({}, {}), edge={:?}, facing={:?}, tier={:?}, health={:.1}",
-        cell_x, cell_y, edge, facing, building_tier, max_health
+        "[PlaceWall] Successfully placed {} at cell ({}, {}), edge={:?}, facing={:?}, tier={:?}, health={:.1}",
+        piece_name, cell_x, cell_y, edge, facing, building_tier, max_health
     );
     
     Ok(())
@@ -1740,21 +1893,6 @@ pub fn upgrade_wall(
         return Err("Repair Hammer must be equipped to upgrade walls.".to_string());
     }
     
-    // 2.5. Check building privilege (only if hearths exist)
-    // EARLY GAME: If no hearths exist, allow anyone to upgrade (pre-privilege phase)
-    // LATE GAME: Once hearths exist, require building privilege
-    use crate::homestead_hearth::player_has_building_privilege;
-    let hearths = ctx.db.homestead_hearth();
-    let any_hearth_exists = hearths.iter().any(|h| !h.is_destroyed);
-    
-    if any_hearth_exists {
-        // Hearths exist - require building privilege
-        if !player_has_building_privilege(ctx, sender_id) {
-            return Err("Building privilege required. Hold E near a Homestead Hearth to gain building privilege.".to_string());
-        }
-    }
-    // If no hearths exist, skip privilege check entirely (early game freedom)
-    
     // 3. Find wall
     let wall = walls.id().find(&wall_id)
         .ok_or_else(|| "Wall not found".to_string())?;
@@ -1763,6 +1901,11 @@ pub fn upgrade_wall(
         return Err("Cannot upgrade destroyed wall.".to_string());
     }
     
+    // 3.5. Check building privilege at the wall's cell (unclaimed land is open to everyone)
+    let privilege_x = (wall.cell_x as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
+    let privilege_y = (wall.cell_y as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
+    crate::homestead_hearth::check_position_privileged(ctx, privilege_x, privilege_y, sender_id)?;
+    
     // 4. Validate new tier
     if !is_valid_building_tier(new_tier) {
         return Err(format!("Invalid building tier: {}. Must be 0-3 (Twig, Wood, Stone, Metal).", new_tier));
@@ -1808,9 +1951,11 @@ pub fn upgrade_wall(
     // Wall upgrade costs: SIGNIFICANT - walls are the real defense in a 2D game
     // Wood tier = 50 wood, Stone tier = 75 stone, Metal tier = 50 metal fragments
     // These are the upgrades that actually matter for base security
-    let required_wood = if target_tier == BuildingTier::Wood { 50 } else { 0 };
-    let required_stone = if target_tier == BuildingTier::Stone { 75 } else { 0 };
-    let required_metal = if target_tier == BuildingTier::Metal { 50 } else { 0 };
+    // Window walls, half walls and doorframes cost a fraction of a solid wall
+    let wall_type = wall_type_from_u8(wall.wall_type);
+    let required_wood = scale_wall_cost(if target_tier == BuildingTier::Wood { 50 } else { 0 }, wall_type);
+    let required_stone = scale_wall_cost(if target_tier == BuildingTier::Stone { 75 } else { 0 }, wall_type);
+    let required_metal = scale_wall_cost(if target_tier == BuildingTier::Metal { 50 } else { 0 }, wall_type);
     
     // Check and consume wood
     if required_wood > 0 {
@@ -1969,7 +2114,7 @@ pub fn upgrade_wall(
     }
     
     // 8. Update wall tier and health
-    let new_max_health = get_wall_max_health(target_tier);
+    let new_max_health = get_wall_piece_max_health(target_tier, wall_type);
     let health_ratio = wall.health / wall.max_health;
     let new_health = new_max_health * health_ratio; // Preserve health percentage
     
@@ -2069,6 +2214,9 @@ pub fn destroy_wall(ctx: &ReducerContext, wall_id: u64) -> Result<(), String> {
     
     walls.id().update(updated_wall);
     
+    // 6.5. Floors resting on this wall may have lost their support
+    crate::building_floor::collapse_unsupported_floors(ctx, wall.cell_x, wall.cell_y);
+    
     // 7. Emit destroy sound (using foundation destroy sound for now)
     sound_events::emit_foundation_twig_destroyed_sound(ctx, world_x, world_y, sender_id);
     
@@ -2094,211 +2242,274 @@ pub fn get_melee_damage_multiplier(tier: u8) -> f32 {
     }
 }
 
-/// Applies weapon damage to a wall (called from combat system or projectile collision)
-/// Damage is reduced based on wall tier - melee is ineffective vs upgraded structures
-pub fn damage_wall(
+// --- Unified Structure Damage ---
+
+/// A structure that can take damage through `apply_structure_damage`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StructureRef {
+    Foundation(u64),
+    Wall(u64),
+    Floor(u64),
+    Stairs(u64),
+    Door(u64),
+    Fence(u64),
+    Shelter(u32),
+    Hearth(u32),
+    Lantern(u32), // Lanterns and wards
+}
+
+/// How a structure is being damaged - decides tier resistance, PvP checks and hit sounds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StructureDamageType {
+    /// Melee weapons and projectiles - reduced by the structure's tier
+    Weapon,
+    /// Explosives - full damage to every tier
+    Explosive,
+    /// Hostile NPC attacks - full damage; the NPC plays its own hit sound
+    Hostile,
+}
+
+/// Applies damage to a row with the shared structure fields, returning true if it was destroyed
+macro_rules! apply_structure_hit {
+    ($ctx:expr, $row:expr, $damage:expr, $attacker:expr, $target:expr, $damage_type:expr) => {{
+        let old_health = $row.health;
+        $row.health = ($row.health - $damage).max(0.0);
+        $row.last_hit_time = Some($ctx.timestamp);
+        if $attacker.is_some() {
+            $row.last_damaged_by = $attacker;
+        }
+        let destroyed = $row.health <= 0.0;
+        if destroyed {
+            $row.is_destroyed = true;
+            $row.destroyed_at = Some($ctx.timestamp);
+        }
+        log::info!(
+            "[StructureDamage] {:?} took {:.1} {:?} damage from {:?}. Health: {:.1} -> {:.1}",
+            $target, $damage, $damage_type, $attacker, old_health, $row.health
+        );
+        destroyed
+    }};
+}
+
+/// PvP raiding check - both attacker and owner need PvP active to damage someone else's structure
+pub fn is_raiding_allowed(ctx: &ReducerContext, attacker_id: Identity, owner_id: Identity) -> bool {
+    if owner_id == attacker_id {
+        return true;
+    }
+    let (Some(attacker), Some(owner)) = (
+        ctx.db.player().identity().find(&attacker_id),
+        ctx.db.player().identity().find(&owner_id),
+    ) else {
+        return true;
+    };
+    let attacker_pvp = crate::combat::is_pvp_active_for_player(&attacker, ctx.timestamp);
+    let owner_pvp = crate::combat::is_pvp_active_for_player(&owner, ctx.timestamp);
+    if !attacker_pvp || !owner_pvp {
+        log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", attacker_pvp, owner_pvp);
+        return false;
+    }
+    true
+}
+
+/// Whether a structure can be hit at all: not already destroyed, and raiding rules allow it
+fn check_structure_hit(
     ctx: &ReducerContext,
-    attacker_id: Identity,
-    wall_id: u64,
-    damage: f32,
-    timestamp: Timestamp,
+    target: StructureRef,
+    is_destroyed: bool,
+    owner_id: Identity,
+    attacker: Option<Identity>,
 ) -> Result<(), String> {
-    let walls = ctx.db.wall_cell();
-    
-    // Find the wall
-    let mut wall = walls.id().find(&wall_id)
-        .ok_or_else(|| format!("Wall with ID {} not found.", wall_id))?;
-    
-    if wall.is_destroyed {
-        return Err("Wall is already destroyed.".to_string());
+    if is_destroyed {
+        return Err(format!("{:?} is already destroyed.", target));
     }
-    
-    // <<< PVP RAIDING CHECK >>>
-    if let Some(attacker_player) = ctx.db.player().identity().find(&attacker_id) {
-        let attacker_pvp = crate::combat::is_pvp_active_for_player(&attacker_player, timestamp);
-        
-        // Check if owner has PvP enabled (if owner is not the attacker)
-        if wall.owner != attacker_id {
-            if let Some(owner_player) = ctx.db.player().identity().find(&wall.owner) {
-                let owner_pvp = crate::combat::is_pvp_active_for_player(&owner_player, timestamp);
-                
-                if !attacker_pvp || !owner_pvp {
-                    log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", 
-                        attacker_pvp, owner_pvp);
-                    return Err("Cannot damage structure - PvP raiding requires both players to have PvP enabled.".to_string());
-                }
-            }
+    if let Some(attacker_id) = attacker {
+        if !is_raiding_allowed(ctx, attacker_id, owner_id) {
+            return Err("Cannot damage structure - PvP raiding requires both players to have PvP enabled.".to_string());
         }
     }
-    // <<< END PVP RAIDING CHECK >>>
-    
-    // Apply melee damage reduction based on wall tier
-    // Higher tier walls resist melee damage significantly (Rust-like mechanics)
-    let damage_multiplier = get_melee_damage_multiplier(wall.tier);
-    let effective_damage = damage * damage_multiplier;
-    
-    let old_health = wall.health;
-    wall.health = (wall.health - effective_damage).max(0.0);
-    wall.last_hit_time = Some(timestamp);
-    wall.last_damaged_by = Some(attacker_id);
-    
-    let tier_name = match wall.tier {
-        0 => "Twig",
-        1 => "Wood",
-        2 => "Stone",
-        3 => "Metal",
-        _ => "Unknown",
-    };
-    
-    log::info!(
-        "Player {:?} hit {} Wall {} for {:.1} damage (base: {:.1}, mult: {:.0}%). Health: {:.1} -> {:.1}",
-        attacker_id, tier_name, wall_id, effective_damage, damage, damage_multiplier * 100.0, old_health, wall.health
-    );
-    
-    if wall.health <= 0.0 {
-        // Wall destroyed
-        wall.health = 0.0;
-        wall.is_destroyed = true;
-        wall.destroyed_at = Some(timestamp);
-        
-        log::info!("[WallDamage] Wall {} destroyed by player {:?}", wall_id, attacker_id);
-        
-        // Emit destruction sound (use twig destroyed sound for all tiers, or melee hit sound)
-        let world_x = (wall.cell_x as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
-        let world_y = (wall.cell_y as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
-        
-        // Use foundation twig destroyed sound for all wall tiers (or could use melee hit sound)
-        crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, world_x, world_y, attacker_id);
-    } else {
-        // Wall damaged but not destroyed
-        log::info!("[WallDamage] Wall {} damaged, health: {:.1}", wall_id, wall.health);
-        
-        // Emit hit sound (use melee hit sound for all tiers)
-        let world_x = (wall.cell_x as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
-        let world_y = (wall.cell_y as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
-        
-        // Use melee hit sound for wall hits (similar to other structures)
-        crate::sound_events::emit_melee_hit_sharp_sound(ctx, world_x, world_y, attacker_id);
-    }
-    
-    // Update the wall
-    walls.id().update(wall);
-    
     Ok(())
 }
 
-/// Applies explosive damage to a wall (bypasses melee damage reduction)
-/// Used by explosion system - explosives are effective against all tiers
-pub fn damage_wall_explosive(
+fn emit_structure_damage_sound(
     ctx: &ReducerContext,
-    attacker_id: Identity,
-    wall_id: u64,
-    damage: f32,
+    target: StructureRef,
+    pos_x: f32,
+    pos_y: f32,
+    destroyed: bool,
+    damage_type: StructureDamageType,
+    source: Identity,
 ) {
-    let walls = ctx.db.wall_cell();
-    
-    if let Some(mut wall) = walls.id().find(&wall_id) {
-        if wall.is_destroyed {
-            return;
-        }
-        
-        // <<< PVP RAIDING CHECK >>>
-        if let Some(attacker_player) = ctx.db.player().identity().find(&attacker_id) {
-            let attacker_pvp = crate::combat::is_pvp_active_for_player(&attacker_player, ctx.timestamp);
-            
-            if wall.owner != attacker_id {
-                if let Some(owner_player) = ctx.db.player().identity().find(&wall.owner) {
-                    let owner_pvp = crate::combat::is_pvp_active_for_player(&owner_player, ctx.timestamp);
-                    
-                    if !attacker_pvp || !owner_pvp {
-                        log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", 
-                            attacker_pvp, owner_pvp);
-                        return; // Skip this structure in explosion
-                    }
-                }
-            }
-        }
-        // <<< END PVP RAIDING CHECK >>>
-        
-        // Explosive damage bypasses melee reduction - full damage to all tiers
-        let old_health = wall.health;
-        wall.health = (wall.health - damage).max(0.0);
-        wall.last_hit_time = Some(ctx.timestamp);
-        
-        let world_x = (wall.cell_x as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
-        let world_y = (wall.cell_y as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
-        
-        if wall.health <= 0.0 {
-            wall.is_destroyed = true;
-            wall.destroyed_at = Some(ctx.timestamp);
-            crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, world_x, world_y, ctx.sender());
-            log::info!("[WallExplosiveDamage] Wall {} destroyed by explosion", wall_id);
-        } else {
-            crate::sound_events::emit_melee_hit_sharp_sound(ctx, world_x, world_y, ctx.sender());
-            log::info!("[WallExplosiveDamage] Wall {} took {:.1} explosive damage, health: {:.1}", wall_id, damage, wall.health);
-        }
-        
-        walls.id().update(wall);
+    match (target, destroyed) {
+        (StructureRef::Door(_), true) => crate::sound_events::emit_door_destroyed_sound(ctx, pos_x, pos_y, source),
+        (StructureRef::Lantern(_), true) => crate::sound_events::emit_barrel_destroyed_sound(ctx, pos_x, pos_y, source),
+        (_, true) => crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, pos_x, pos_y, source),
+        _ if damage_type == StructureDamageType::Hostile => {}
+        (StructureRef::Lantern(_), false) => crate::sound_events::emit_barrel_hit_sound(ctx, pos_x, pos_y, source),
+        (_, false) => crate::sound_events::emit_melee_hit_sharp_sound(ctx, pos_x, pos_y, source),
+    }
+}
+
+/// Whether a structure still exists and hasn't been destroyed
+pub fn is_structure_standing(ctx: &ReducerContext, target: StructureRef) -> bool {
+    match target {
+        StructureRef::Foundation(id) => ctx.db.foundation_cell().id().find(&id).is_some_and(|s| !s.is_destroyed),
+        StructureRef::Wall(id) => ctx.db.wall_cell().id().find(&id).is_some_and(|s| !s.is_destroyed),
+        StructureRef::Floor(id) => ctx.db.floor_cell().id().find(&id).is_some_and(|s| !s.is_destroyed),
+        StructureRef::Stairs(id) => ctx.db.stairs_cell().id().find(&id).is_some_and(|s| !s.is_destroyed),
+        StructureRef::Door(id) => ctx.db.door().id().find(&id).is_some_and(|s| !s.is_destroyed),
+        StructureRef::Fence(id) => ctx.db.fence().id().find(&id).is_some_and(|s| !s.is_destroyed),
+        StructureRef::Shelter(id) => ctx.db.shelter().id().find(id).is_some_and(|s| !s.is_destroyed),
+        StructureRef::Hearth(id) => ctx.db.homestead_hearth().id().find(&id).is_some_and(|s| !s.is_destroyed),
+        StructureRef::Lantern(id) => ctx.db.lantern().id().find(id).is_some_and(|s| !s.is_destroyed),
     }
 }
 
-/// Applies explosive damage to a foundation (bypasses melee damage reduction)
-pub fn damage_foundation_explosive(
+/// The single write path for structure damage from player weapons, projectiles, explosives and hostile NPCs.
+/// Handles tier resistance, PvP raiding rules, health and hit bookkeeping, destruction side effects
+/// (floor collapse, door locks, hearth upkeep and container contents) and sounds.
+/// `attacker` is None for hostile NPCs. Returns true if the structure was destroyed.
+/// Errors if the structure is missing, already destroyed, or protected (PvP rules, monument fences);
+/// callers that treat those as a harmless miss check `is_structure_standing` first.
+/// Destroyed shelters and lanterns are removed whatever destroyed them, hostile NPCs included.
+pub fn apply_structure_damage(
     ctx: &ReducerContext,
-    attacker_id: Identity,
-    foundation_id: u64,
+    target: StructureRef,
     damage: f32,
-) {
-    let foundations = ctx.db.foundation_cell();
-    
-    if let Some(mut foundation) = foundations.id().find(&foundation_id) {
-        if foundation.is_destroyed {
-            return;
+    damage_type: StructureDamageType,
+    attacker: Option<Identity>,
+) -> Result<bool, String> {
+    let resist = |multiplier: f32| if damage_type == StructureDamageType::Weapon { multiplier } else { 1.0 };
+
+    let (pos_x, pos_y, destroyed) = match target {
+        StructureRef::Foundation(foundation_id) => {
+            let mut foundation = ctx.db.foundation_cell().id().find(&foundation_id)
+                .ok_or_else(|| format!("Foundation with ID {} not found.", foundation_id))?;
+            check_structure_hit(ctx, target, foundation.is_destroyed, foundation.owner, attacker)?;
+            let effective_damage = damage * resist(get_melee_damage_multiplier(foundation.tier));
+            let destroyed = apply_structure_hit!(ctx, foundation, effective_damage, attacker, target, damage_type);
+            let (world_x, world_y) = crate::building_floor::cell_center(foundation.cell_x, foundation.cell_y);
+            ctx.db.foundation_cell().id().update(foundation);
+            (world_x, world_y, destroyed)
         }
-        
-        // <<< PVP RAIDING CHECK >>>
-        if let Some(attacker_player) = ctx.db.player().identity().find(&attacker_id) {
-            let attacker_pvp = crate::combat::is_pvp_active_for_player(&attacker_player, ctx.timestamp);
-            
-            if foundation.owner != attacker_id {
-                if let Some(owner_player) = ctx.db.player().identity().find(&foundation.owner) {
-                    let owner_pvp = crate::combat::is_pvp_active_for_player(&owner_player, ctx.timestamp);
-                    
-                    if !attacker_pvp || !owner_pvp {
-                        log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", 
-                            attacker_pvp, owner_pvp);
-                        return; // Skip this structure in explosion
-                    }
-                }
+        StructureRef::Wall(wall_id) => {
+            let mut wall = ctx.db.wall_cell().id().find(&wall_id)
+                .ok_or_else(|| format!("Wall with ID {} not found.", wall_id))?;
+            check_structure_hit(ctx, target, wall.is_destroyed, wall.owner, attacker)?;
+            let effective_damage = damage * resist(get_melee_damage_multiplier(wall.tier));
+            let destroyed = apply_structure_hit!(ctx, wall, effective_damage, attacker, target, damage_type);
+            let (cell_x, cell_y) = (wall.cell_x, wall.cell_y);
+            ctx.db.wall_cell().id().update(wall);
+            // Floors resting on this wall may have lost their support
+            if destroyed {
+                crate::building_floor::collapse_unsupported_floors(ctx, cell_x, cell_y);
             }
+            let (world_x, world_y) = crate::building_floor::cell_center(cell_x, cell_y);
+            (world_x, world_y, destroyed)
         }
-        // <<< END PVP RAIDING CHECK >>>
-        
-        // Explosive damage bypasses melee reduction - full damage to all tiers
-        let old_health = foundation.health;
-        foundation.health = (foundation.health - damage).max(0.0);
-        foundation.last_hit_time = Some(ctx.timestamp);
-        
-        let world_x = (foundation.cell_x as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
-        let world_y = (foundation.cell_y as f32 * FOUNDATION_TILE_SIZE_PX as f32) + (FOUNDATION_TILE_SIZE_PX as f32 / 2.0);
-        
-        if foundation.health <= 0.0 {
-            foundation.is_destroyed = true;
-            foundation.destroyed_at = Some(ctx.timestamp);
-            crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, world_x, world_y, ctx.sender());
-            log::info!("[FoundationExplosiveDamage] Foundation {} destroyed by explosion", foundation_id);
-        } else {
-            crate::sound_events::emit_melee_hit_sharp_sound(ctx, world_x, world_y, ctx.sender());
-            log::info!("[FoundationExplosiveDamage] Foundation {} took {:.1} explosive damage, health: {:.1}", foundation_id, damage, foundation.health);
+        StructureRef::Floor(floor_id) => {
+            let mut floor = ctx.db.floor_cell().id().find(&floor_id)
+                .ok_or_else(|| format!("Floor with ID {} not found.", floor_id))?;
+            check_structure_hit(ctx, target, floor.is_destroyed, floor.owner, attacker)?;
+            let effective_damage = damage * resist(get_melee_damage_multiplier(floor.tier));
+            let destroyed = apply_structure_hit!(ctx, floor, effective_damage, attacker, target, damage_type);
+            let (world_x, world_y) = crate::building_floor::cell_center(floor.cell_x, floor.cell_y);
+            if destroyed {
+                crate::building_floor::mark_floor_destroyed(ctx, floor);
+            } else {
+                ctx.db.floor_cell().id().update(floor);
+            }
+            (world_x, world_y, destroyed)
         }
-        
-        foundations.id().update(foundation);
+        StructureRef::Stairs(stairs_id) => {
+            let mut stairs = ctx.db.stairs_cell().id().find(&stairs_id)
+                .ok_or_else(|| format!("Stairs with ID {} not found.", stairs_id))?;
+            check_structure_hit(ctx, target, stairs.is_destroyed, stairs.owner, attacker)?;
+            let effective_damage = damage * resist(get_melee_damage_multiplier(stairs.tier));
+            let destroyed = apply_structure_hit!(ctx, stairs, effective_damage, attacker, target, damage_type);
+            let (world_x, world_y) = crate::building_floor::cell_center(stairs.cell_x, stairs.cell_y);
+            ctx.db.stairs_cell().id().update(stairs);
+            (world_x, world_y, destroyed)
+        }
+        StructureRef::Door(door_id) => {
+            let mut door = ctx.db.door().id().find(&door_id)
+                .ok_or_else(|| format!("Door {} not found", door_id))?;
+            check_structure_hit(ctx, target, door.is_destroyed, door.owner_id, attacker)?;
+            // Doors resist melee like walls so they can't be used to bypass them
+            let effective_damage = damage * resist(crate::door::get_door_damage_multiplier(door.door_type));
+            let destroyed = apply_structure_hit!(ctx, door, effective_damage, attacker, target, damage_type);
+            let (world_x, world_y) = (door.pos_x, door.pos_y);
+            ctx.db.door().id().update(door);
+            if destroyed {
+                crate::door::clear_door_lock(ctx, door_id);
+            }
+            (world_x, world_y, destroyed)
+        }
+        StructureRef::Fence(fence_id) => {
+            let mut fence = ctx.db.fence().id().find(&fence_id)
+                .ok_or_else(|| format!("Fence with ID {} not found.", fence_id))?;
+            // Monument fences are indestructible
+            if fence.is_monument {
+                return Err("Cannot damage monument fences.".to_string());
+            }
+            check_structure_hit(ctx, target, fence.is_destroyed, fence.owner_id, attacker)?;
+            let effective_damage = damage * resist(crate::fence::get_fence_damage_multiplier(fence.tier));
+            let destroyed = apply_structure_hit!(ctx, fence, effective_damage, attacker, target, damage_type);
+            let (world_x, world_y) = (fence.pos_x, fence.pos_y);
+            ctx.db.fence().id().update(fence);
+            (world_x, world_y, destroyed)
+        }
+        StructureRef::Shelter(shelter_id) => {
+            let mut shelter = ctx.db.shelter().id().find(shelter_id)
+                .ok_or_else(|| format!("Target shelter {} disappeared", shelter_id))?;
+            check_structure_hit(ctx, target, shelter.is_destroyed, shelter.placed_by, attacker)?;
+            let destroyed = apply_structure_hit!(ctx, shelter, damage, attacker, target, damage_type);
+            let (world_x, world_y) = (shelter.pos_x, shelter.pos_y);
+            // Update before deleting so clients see the destroyed state
+            ctx.db.shelter().id().update(shelter);
+            if destroyed {
+                ctx.db.shelter().id().delete(shelter_id);
+                crate::spatial_grid::invalidate_static_grid();
+            }
+            (world_x, world_y, destroyed)
+        }
+        StructureRef::Hearth(hearth_id) => {
+            let mut hearth = ctx.db.homestead_hearth().id().find(&hearth_id)
+                .ok_or_else(|| format!("Hearth with ID {} not found.", hearth_id))?;
+            check_structure_hit(ctx, target, hearth.is_destroyed, hearth.placed_by, attacker)?;
+            let destroyed = apply_structure_hit!(ctx, hearth, damage, attacker, target, damage_type);
+            let (world_x, world_y) = (hearth.pos_x, hearth.pos_y);
+            if destroyed {
+                crate::homestead_hearth::handle_hearth_destroyed(ctx, &mut hearth);
+            }
+            ctx.db.homestead_hearth().id().update(hearth);
+            (world_x, world_y, destroyed)
+        }
+        StructureRef::Lantern(lantern_id) => {
+            let mut lantern = ctx.db.lantern().id().find(lantern_id)
+                .ok_or_else(|| format!("Target lantern {} disappeared", lantern_id))?;
+            check_structure_hit(ctx, target, lantern.is_destroyed, lantern.placed_by, attacker)?;
+            let destroyed = apply_structure_hit!(ctx, lantern, damage, attacker, target, damage_type);
+            let (world_x, world_y) = (lantern.pos_x, lantern.pos_y);
+            if destroyed {
+                crate::lantern::handle_lantern_destroyed(ctx, lantern);
+            } else {
+                ctx.db.lantern().id().update(lantern);
+            }
+            (world_x, world_y, destroyed)
+        }
+    };
+
+    emit_structure_damage_sound(ctx, target, pos_x, pos_y, destroyed, damage_type, attacker.unwrap_or(ctx.identity()));
+    if destroyed {
+        log::info!("[StructureDamage] {:?} destroyed by {:?} ({:?})", target, attacker, damage_type);
     }
+    Ok(destroyed)
 }
 
 // --- Projectile and Melee Collision Detection ---
 
-/// Checks if a line segment intersects with a wall edge
+/// Checks if a projectile path intersects with a wall edge
+/// Window walls, half walls and empty doorframes let projectiles through
 /// Returns Some((wall_id, collision_x, collision_y)) if collision occurs
 pub fn check_projectile_wall_collision(
     ctx: &ReducerContext,
@@ -2306,6 +2517,19 @@ pub fn check_projectile_wall_collision(
     start_y: f32,
     end_x: f32,
     end_y: f32,
+) -> Option<(u64, f32, f32)> {
+    find_wall_on_line(ctx, start_x, start_y, end_x, end_y, wall_type_blocks_projectiles)
+}
+
+/// Finds the first wall edge a line segment intersects, considering only
+/// wall types for which `blocks(wall_type)` returns true
+fn find_wall_on_line(
+    ctx: &ReducerContext,
+    start_x: f32,
+    start_y: f32,
+    end_x: f32,
+    end_y: f32,
+    blocks: fn(u8) -> bool,
 ) -> Option<(u64, f32, f32)> {
     const WALL_COLLISION_THICKNESS: f32 = 6.0; // Same as player collision
     
@@ -2326,7 +2550,7 @@ pub fn check_projectile_wall_collision(
         for tile_y in start_tile_y..=end_tile_y {
             // Find walls on this tile
             for wall in walls.idx_cell_coords().filter((tile_x, tile_y)) {
-                if wall.is_destroyed {
+                if wall.is_destroyed || !blocks(wall.wall_type) {
                     continue;
                 }
                 
@@ -2375,6 +2599,7 @@ pub fn check_projectile_wall_collision(
 }
 
 /// Checks if a line segment is blocked by walls (for melee attacks)
+/// Window and half walls block melee; empty doorframes do not
 /// Returns Some(wall_id) if a wall is hit, None otherwise
 pub fn check_line_hits_wall(
     ctx: &ReducerContext,
@@ -2383,7 +2608,7 @@ pub fn check_line_hits_wall(
     end_x: f32,
     end_y: f32,
 ) -> Option<u64> {
-    check_projectile_wall_collision(ctx, start_x, start_y, end_x, end_y)
+    find_wall_on_line(ctx, start_x, start_y, end_x, end_y, wall_type_blocks_melee)
         .map(|(wall_id, _, _)| wall_id)
 }
 
@@ -30,6 +30,11 @@
  *   • Decay time if unprotected: ~1000 hours (~42 days)                       *
  *   • Protection cost: 220 metal/hour in hearth                              *
  *                                                                            *
+ * Upkeep Tax:                                                                 *
+ * - Base upkeep is scaled by Rust-style tax brackets on the number of        *
+ *   non-twig pieces tied to the hearth (10% for the first 15 pieces up to    *
+ *   35% beyond 325), see UPKEEP_TAX_BRACKETS in homestead_hearth.rs          *
+ *                                                                            *
  * Grace Period:                                                               *
  * - Buildings have 1 hour grace period after placement before decay starts   *
  * - This gives players time to set up hearth and deposit resources           *
@@ -363,5 +368,8 @@ pub fn process_building_decay(ctx: &ReducerContext, _schedule: BuildingDecaySche
         ctx.db.wall_cell().id().update(wall);
     }
     
+    // Process sign/banner decay (shares this schedule's interval)
+    crate::sign::process_sign_decay(ctx);
+    
     Ok(())
 }
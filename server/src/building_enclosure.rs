@@ -7,6 +7,9 @@
  * 2. Calculating the perimeter of the building                               *
  * 3. Checking what percentage of the perimeter has walls                     *
  *                                                                            *
+ * Solid and window walls fully cover an edge, half walls cover half of it,  *
+ * and doorframes only count once a door has been fitted into them.          *
+ *                                                                            *
  * This enables rain protection, campfire lighting, and other mechanics       *
  * that depend on being "inside" vs "outside".                                *
  *                                                                            *
@@ -20,15 +23,20 @@ use crate::building::{
     FoundationCell,
     WallCell,
     FOUNDATION_TILE_SIZE_PX,
+    wall_type_from_u8,
 };
-use crate::models::BuildingEdge;
+use crate::door::door as DoorTableTrait;
+use crate::models::{BuildingEdge, WallType};
 
 // --- Constants ---
 
 /// Minimum percentage of perimeter that must have walls to be considered "inside"
-/// 0.70 = 70% coverage allows for 30% door/window gaps
+/// 0.70 = 70% coverage allows for 30% door/doorframe/half wall gaps
 pub const ENCLOSURE_THRESHOLD: f32 = 0.70;
 
+/// How much of a perimeter edge a half wall covers (rain/wind get over the top)
+pub const HALF_WALL_COVERAGE: f32 = 0.5;
+
 /// Maximum distance to search for adjacent foundations (in foundation cells)
 const ADJACENT_FOUNDATION_MAX_DISTANCE: i32 = 1;
 
@@ -48,7 +56,7 @@ pub struct EnclosureAnalysis {
     pub is_enclosed: bool,
     pub wall_coverage_ratio: f32,
     pub total_perimeter_edges: usize,
-    pub covered_perimeter_edges: usize,
+    pub covered_perimeter_edges: f32, // Weighted: half walls count as HALF_WALL_COVERAGE
     pub foundation_count: usize,
 }
 
@@ -123,7 +131,7 @@ pub fn analyze_building_enclosure(
             is_enclosed: false,
             wall_coverage_ratio: 0.0,
             total_perimeter_edges: 0,
-            covered_perimeter_edges: 0,
+            covered_perimeter_edges: 0.0,
             foundation_count: 0,
         };
     }
@@ -136,7 +144,7 @@ pub fn analyze_building_enclosure(
             is_enclosed: false,
             wall_coverage_ratio: 0.0,
             total_perimeter_edges: 0,
-            covered_perimeter_edges: 0,
+            covered_perimeter_edges: 0.0,
             foundation_count: foundation_cluster.len(),
         };
     }
@@ -145,7 +153,7 @@ pub fn analyze_building_enclosure(
     let covered_edges = count_covered_perimeter_edges(ctx, &perimeter_edges);
     
     // Step 4: Calculate coverage ratio
-    let coverage_ratio = covered_edges as f32 / perimeter_edges.len() as f32;
+    let coverage_ratio = covered_edges / perimeter_edges.len() as f32;
     let is_enclosed = coverage_ratio >= ENCLOSURE_THRESHOLD;
     
     EnclosureAnalysis {
@@ -277,12 +285,12 @@ fn get_adjacent_cell_coords(cell_x: i32, cell_y: i32, edge: BuildingEdge) -> (i3
     }
 }
 
-/// Counts how many perimeter edges have walls on them
+/// Counts how many perimeter edges have walls on them, weighted by wall type
 fn count_covered_perimeter_edges(
     ctx: &ReducerContext,
     perimeter_edges: &[PerimeterEdge],
-) -> usize {
-    let mut covered_count = 0;
+) -> f32 {
+    let mut covered_count = 0.0;
     
     // Get all non-destroyed walls for quick lookup
     let walls: Vec<WallCell> = ctx.db.wall_cell().iter()
@@ -291,28 +299,43 @@ fn count_covered_perimeter_edges(
     
     // Check each perimeter edge
     for edge in perimeter_edges {
-        // Check if a wall exists at this position and edge
-        let has_wall = walls.iter().any(|wall| {
-            wall.cell_x == edge.cell_x &&
-            wall.cell_y == edge.cell_y &&
-            building_edge_matches(wall.edge, edge.edge)
-        });
+        // Best coverage provided by any wall at this position and edge
+        let coverage = walls.iter()
+            .filter(|wall| {
+                wall.cell_x == edge.cell_x &&
+                wall.cell_y == edge.cell_y &&
+                building_edge_matches(wall.edge, edge.edge)
+            })
+            .map(|wall| wall_edge_coverage(ctx, wall))
+            .fold(0.0, f32::max);
         
-        if has_wall {
-            covered_count += 1;
-        }
+        covered_count += coverage;
     }
     
     log::debug!(
-        "[BuildingEnclosure] {} out of {} perimeter edges have walls ({:.1}% coverage)",
+        "[BuildingEnclosure] {:.1} out of {} perimeter edges have walls ({:.1}% coverage)",
         covered_count,
         perimeter_edges.len(),
-        (covered_count as f32 / perimeter_edges.len() as f32) * 100.0
+        (covered_count / perimeter_edges.len() as f32) * 100.0
     );
     
     covered_count
 }
 
+/// How much of its edge a wall covers for enclosure purposes (0.0 - 1.0)
+fn wall_edge_coverage(ctx: &ReducerContext, wall: &WallCell) -> f32 {
+    match wall_type_from_u8(wall.wall_type) {
+        WallType::Solid | WallType::Window => 1.0,
+        WallType::Half => HALF_WALL_COVERAGE,
+        WallType::Doorframe => {
+            // An empty doorframe is a gap - only counts once a door fills it
+            let has_door = ctx.db.door().idx_cell_coords().filter((wall.cell_x, wall.cell_y))
+                .any(|door| !door.is_destroyed && door.edge == wall.edge);
+            if has_door { 1.0 } else { 0.0 }
+        }
+    }
+}
+
 /// Checks if a wall edge value matches a BuildingEdge
 fn building_edge_matches(wall_edge: u8, perimeter_edge: BuildingEdge) -> bool {
     let wall_edge_enum = match wall_edge {
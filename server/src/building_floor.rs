@@ -0,0 +1,636 @@
+/******************************************************************************
+ *                                                                            *
+ * Multi-Story Building - Floors, Stairs, Player Elevation                   *
+ *                                                                            *
+ * Floor pieces sit on top of walls to form a second story over a           *
+ * foundation cell. Stairs placed on the ground floor move players between  *
+ * levels via `Player.elevation`. Players on the second floor are out of    *
+ * reach of ground hostiles and only collide with projectiles fired from    *
+ * the same level.                                                           *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, Timestamp, ReducerContext, Table, log};
+use crate::{
+    models::{BuildingTier, ItemLocation},
+    environment::calculate_chunk_index,
+    Player,
+};
+use crate::player as PlayerTableTrait;
+use crate::items::{item_definition as ItemDefinitionTableTrait, inventory_item as InventoryItemTableTrait};
+use crate::building::{
+    foundation_cell as FoundationCellTableTrait,
+    wall_cell as WallCellTableTrait,
+    player_has_blueprint, player_has_repair_hammer, is_valid_building_tier,
+    apply_structure_damage, StructureRef, StructureDamageType,
+    FOUNDATION_TILE_SIZE_PX, BUILDING_PLACEMENT_MAX_DISTANCE, BUILDING_PLACEMENT_MAX_DISTANCE_SQUARED,
+};
+
+// --- Constants ---
+
+/// Elevation levels stored in `Player.elevation` and `Projectile.elevation`
+pub const ELEVATION_GROUND: u8 = 0;
+pub const ELEVATION_SECOND_FLOOR: u8 = 1;
+
+// Floor health per tier - weaker than foundations (they're held up by walls, not the ground)
+pub const FLOOR_TWIG_MAX_HEALTH: f32 = 75.0;
+pub const FLOOR_WOOD_MAX_HEALTH: f32 = 400.0;
+pub const FLOOR_STONE_MAX_HEALTH: f32 = 800.0;
+pub const FLOOR_METAL_MAX_HEALTH: f32 = 1600.0;
+
+// Stairs are a fraction of a floor's health for the same tier
+pub const STAIRS_HEALTH_MULT: f32 = 0.75;
+
+/// A floor needs at least this many walls under its cell edges to stand on its own
+pub const FLOOR_MIN_SUPPORTING_WALLS: usize = 2;
+
+/// How close a player must be to a stairs piece to use it
+pub const STAIRS_USE_DISTANCE: f32 = 64.0;
+pub const STAIRS_USE_DISTANCE_SQUARED: f32 = STAIRS_USE_DISTANCE * STAIRS_USE_DISTANCE;
+
+// --- Floor Cell Table ---
+#[spacetimedb::table(
+    accessor = floor_cell,
+    public,
+    index(accessor = idx_chunk, name = "idx_floor_chunk", btree(columns = [chunk_index])),
+    index(accessor = idx_cell_coords, name = "idx_floor_cell_coords", btree(columns = [cell_x, cell_y]))
+)]
+#[derive(Clone, Debug)]
+pub struct FloorCell {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub cell_x: i32,  // Foundation cell X coordinate (96px grid) this floor covers
+    pub cell_y: i32,  // Foundation cell Y coordinate (96px grid) this floor covers
+    pub chunk_index: u32,  // For chunk-based spatial subscriptions
+    pub level: u8,    // Elevation this floor is walked on (1 = second floor)
+    pub tier: u8,     // BuildingTier enum (0-3: Twig, Wood, Stone, Metal)
+    pub health: f32,
+    pub max_health: f32,
+    pub owner: Identity,
+    pub placed_at: Timestamp,
+    pub is_destroyed: bool,
+    pub destroyed_at: Option<Timestamp>,
+    pub last_hit_time: Option<Timestamp>,
+    pub last_damaged_by: Option<Identity>,
+}
+
+// --- Stairs Cell Table ---
+#[spacetimedb::table(
+    accessor = stairs_cell,
+    public,
+    index(accessor = idx_chunk, name = "idx_stairs_chunk", btree(columns = [chunk_index])),
+    index(accessor = idx_cell_coords, name = "idx_stairs_cell_coords", btree(columns = [cell_x, cell_y]))
+)]
+#[derive(Clone, Debug)]
+pub struct StairsCell {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub cell_x: i32,  // Foundation cell the stairs stand on (floor above must exist)
+    pub cell_y: i32,
+    pub chunk_index: u32,
+    pub tier: u8,     // BuildingTier enum (0-3: Twig, Wood, Stone, Metal)
+    pub health: f32,
+    pub max_health: f32,
+    pub owner: Identity,
+    pub placed_at: Timestamp,
+    pub is_destroyed: bool,
+    pub destroyed_at: Option<Timestamp>,
+    pub last_hit_time: Option<Timestamp>,
+    pub last_damaged_by: Option<Identity>,
+}
+
+// --- Helper Functions ---
+
+fn building_tier_from_u8(tier: u8) -> Option<BuildingTier> {
+    match tier {
+        0 => Some(BuildingTier::Twig),
+        1 => Some(BuildingTier::Wood),
+        2 => Some(BuildingTier::Stone),
+        3 => Some(BuildingTier::Metal),
+        _ => None,
+    }
+}
+
+/// Get floor max health based on tier
+pub fn get_floor_max_health(tier: BuildingTier) -> f32 {
+    match tier {
+        BuildingTier::Twig => FLOOR_TWIG_MAX_HEALTH,
+        BuildingTier::Wood => FLOOR_WOOD_MAX_HEALTH,
+        BuildingTier::Stone => FLOOR_STONE_MAX_HEALTH,
+        BuildingTier::Metal => FLOOR_METAL_MAX_HEALTH,
+    }
+}
+
+/// Get stairs max health based on tier
+pub fn get_stairs_max_health(tier: BuildingTier) -> f32 {
+    get_floor_max_health(tier) * STAIRS_HEALTH_MULT
+}
+
+/// Resource cost (wood, stone, metal fragments) for placing a floor or stairs at a tier
+/// Placed directly at the chosen tier - there is no separate upgrade step for upper pieces
+fn get_floor_piece_cost(tier: BuildingTier, is_stairs: bool) -> (u32, u32, u32) {
+    let (wood, stone, metal) = match tier {
+        BuildingTier::Twig => (20, 0, 0),
+        BuildingTier::Wood => (60, 0, 0),
+        BuildingTier::Stone => (20, 75, 0),
+        BuildingTier::Metal => (20, 0, 50),
+    };
+    if is_stairs {
+        // Stairs take extra wood for the steps
+        (wood + 10, stone, metal)
+    } else {
+        (wood, stone, metal)
+    }
+}
+
+/// Cell center in world coordinates
+pub(crate) fn cell_center(cell_x: i32, cell_y: i32) -> (f32, f32) {
+    let half = FOUNDATION_TILE_SIZE_PX as f32 / 2.0;
+    (
+        cell_x as f32 * FOUNDATION_TILE_SIZE_PX as f32 + half,
+        cell_y as f32 * FOUNDATION_TILE_SIZE_PX as f32 + half,
+    )
+}
+
+/// Foundation cell containing a world position
+fn world_to_cell(world_x: f32, world_y: f32) -> (i32, i32) {
+    (
+        (world_x / FOUNDATION_TILE_SIZE_PX as f32).floor() as i32,
+        (world_y / FOUNDATION_TILE_SIZE_PX as f32).floor() as i32,
+    )
+}
+
+/// Finds the intact floor covering a cell at the given level
+pub fn find_floor_at(ctx: &ReducerContext, cell_x: i32, cell_y: i32, level: u8) -> Option<FloorCell> {
+    ctx.db.floor_cell().idx_cell_coords().filter((cell_x, cell_y))
+        .find(|floor| !floor.is_destroyed && floor.level == level)
+}
+
+/// Whether a world position is over an intact second-story floor
+pub fn is_position_on_upper_floor(ctx: &ReducerContext, world_x: f32, world_y: f32) -> bool {
+    let (cell_x, cell_y) = world_to_cell(world_x, world_y);
+    find_floor_at(ctx, cell_x, cell_y, ELEVATION_SECOND_FLOOR).is_some()
+}
+
+/// Whether a player at `elevation` can stand at this position (ground is always valid)
+pub fn can_stand_at_elevation(ctx: &ReducerContext, world_x: f32, world_y: f32, elevation: u8) -> bool {
+    elevation == ELEVATION_GROUND || is_position_on_upper_floor(ctx, world_x, world_y)
+}
+
+/// Counts walls along the edges of a cell (including shared edges stored on neighbours)
+fn count_supporting_walls(ctx: &ReducerContext, cell_x: i32, cell_y: i32) -> usize {
+    let walls = ctx.db.wall_cell();
+    let mut supported_edges = [false; 4];
+
+    for wall in walls.idx_cell_coords().filter((cell_x, cell_y)) {
+        if !wall.is_destroyed && wall.edge < 4 {
+            supported_edges[wall.edge as usize] = true;
+        }
+    }
+
+    // North edge of (x, y) = South edge of (x, y-1), etc.
+    let neighbours = [
+        (cell_x, cell_y - 1, 2u8, 0usize), // N
+        (cell_x + 1, cell_y, 3u8, 1usize), // E
+        (cell_x, cell_y + 1, 0u8, 2usize), // S
+        (cell_x - 1, cell_y, 1u8, 3usize), // W
+    ];
+    for (nx, ny, opposite_edge, own_edge) in neighbours {
+        if supported_edges[own_edge] {
+            continue;
+        }
+        if walls.idx_cell_coords().filter((nx, ny)).any(|w| !w.is_destroyed && w.edge == opposite_edge) {
+            supported_edges[own_edge] = true;
+        }
+    }
+
+    supported_edges.iter().filter(|s| **s).count()
+}
+
+/// A second-story floor is supported if it rests on enough walls, or overhangs
+/// by one cell from an adjacent floor that is itself wall-supported
+pub fn is_floor_supported(ctx: &ReducerContext, cell_x: i32, cell_y: i32) -> bool {
+    if count_supporting_walls(ctx, cell_x, cell_y) >= FLOOR_MIN_SUPPORTING_WALLS {
+        return true;
+    }
+
+    [(0, -1), (1, 0), (0, 1), (-1, 0)].iter().any(|(dx, dy)| {
+        let (nx, ny) = (cell_x + dx, cell_y + dy);
+        find_floor_at(ctx, nx, ny, ELEVATION_SECOND_FLOOR).is_some()
+            && count_supporting_walls(ctx, nx, ny) >= FLOOR_MIN_SUPPORTING_WALLS
+    })
+}
+
+/// Drops any players standing on the second floor over this cell back to the ground
+fn drop_players_from_cell(ctx: &ReducerContext, cell_x: i32, cell_y: i32) {
+    let players = ctx.db.player();
+    let elevated: Vec<Player> = players.iter()
+        .filter(|p| p.elevation != ELEVATION_GROUND && world_to_cell(p.position_x, p.position_y) == (cell_x, cell_y))
+        .collect();
+
+    for mut player in elevated {
+        player.elevation = ELEVATION_GROUND;
+        log::info!("[BuildingFloor] Player {:?} dropped to ground - floor at ({}, {}) is gone", player.identity, cell_x, cell_y);
+        players.identity().update(player);
+    }
+}
+
+/// Marks a floor destroyed and drops players standing on it
+pub(crate) fn mark_floor_destroyed(ctx: &ReducerContext, mut floor: FloorCell) {
+    let (cell_x, cell_y) = (floor.cell_x, floor.cell_y);
+    floor.is_destroyed = true;
+    floor.destroyed_at = Some(ctx.timestamp);
+    floor.health = 0.0;
+    ctx.db.floor_cell().id().update(floor);
+    drop_players_from_cell(ctx, cell_x, cell_y);
+}
+
+/// Collapses second-story floors around a cell that lost their wall support.
+/// Call after a wall on this cell is destroyed.
+pub fn collapse_unsupported_floors(ctx: &ReducerContext, cell_x: i32, cell_y: i32) {
+    // A wall edge is shared with a neighbour, and overhangs depend on neighbours, so check a 3x3 area
+    for dx in -1..=1 {
+        for dy in -1..=1 {
+            let (fx, fy) = (cell_x + dx, cell_y + dy);
+            if let Some(floor) = find_floor_at(ctx, fx, fy, ELEVATION_SECOND_FLOOR) {
+                if !is_floor_supported(ctx, fx, fy) {
+                    log::info!("[BuildingFloor] Floor {} at ({}, {}) collapsed - no wall support", floor.id, fx, fy);
+                    let (world_x, world_y) = cell_center(fx, fy);
+                    mark_floor_destroyed(ctx, floor);
+                    crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, world_x, world_y, ctx.sender());
+                }
+            }
+        }
+    }
+}
+
+/// Checks and consumes building resources from the player's inventory and hotbar
+pub(crate) fn consume_building_resources(
+    ctx: &ReducerContext,
+    player: &Player,
+    wood_needed: u32,
+    stone_needed: u32,
+    metal_needed: u32,
+) -> Result<(), String> {
+    let requirements = [("Wood", wood_needed), ("Stone", stone_needed), ("Metal Fragments", metal_needed)];
+    let inventory = ctx.db.inventory_item();
+    let item_defs = ctx.db.item_definition();
+
+    // Validate everything first so a failed placement never consumes anything
+    let mut to_consume = Vec::new();
+    for (name, needed) in requirements {
+        if needed == 0 {
+            continue;
+        }
+        let def = item_defs.iter()
+            .find(|def| def.name == name)
+            .ok_or_else(|| format!("{} item definition not found", name))?;
+        let items: Vec<_> = inventory.iter()
+            .filter(|item| {
+                let is_owned = match &item.location {
+                    ItemLocation::Inventory(data) => data.owner_id == player.identity,
+                    ItemLocation::Hotbar(data) => data.owner_id == player.identity,
+                    _ => false,
+                };
+                is_owned && item.item_def_id == def.id && item.quantity > 0
+            })
+            .collect();
+        let available: u32 = items.iter().map(|item| item.quantity).sum();
+        if available < needed {
+            crate::sound_events::emit_error_resources_sound(ctx, player.position_x, player.position_y, player.identity);
+            return Err(format!("Not enough {}. Required: {}, Available: {}", name.to_lowercase(), needed, available));
+        }
+        to_consume.push((items, needed));
+    }
+
+    for (items, needed) in to_consume {
+        let mut remaining = needed;
+        for item in items {
+            if remaining == 0 {
+                break;
+            }
+            let take = remaining.min(item.quantity);
+            remaining -= take;
+            if take == item.quantity {
+                inventory.instance_id().delete(item.instance_id);
+            } else {
+                let mut updated = item.clone();
+                updated.quantity -= take;
+                inventory.instance_id().update(updated);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Common player checks for placing upper-story pieces
+fn validate_builder(ctx: &ReducerContext, cell_x: i32, cell_y: i32, action: &str) -> Result<Player, String> {
+    let sender_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead {
+        return Err(format!("Cannot {} while dead.", action));
+    }
+    if player.is_knocked_out {
+        return Err(format!("Cannot {} while knocked out.", action));
+    }
+    if !player_has_blueprint(ctx, sender_id) {
+        return Err("Blueprint must be equipped to place building pieces.".to_string());
+    }
+
+    let (center_x, center_y) = cell_center(cell_x, cell_y);
+    let dx = center_x - player.position_x;
+    let dy = center_y - player.position_y;
+    let dist_sq = dx * dx + dy * dy;
+    if dist_sq > BUILDING_PLACEMENT_MAX_DISTANCE_SQUARED {
+        return Err(format!(
+            "Placement too far from player. Distance: {:.1}px, Max: {:.1}px",
+            dist_sq.sqrt(),
+            BUILDING_PLACEMENT_MAX_DISTANCE
+        ));
+    }
+
+    crate::homestead_hearth::check_position_privileged(ctx, center_x, center_y, sender_id)?;
+
+    Ok(player)
+}
+
+fn has_foundation(ctx: &ReducerContext, cell_x: i32, cell_y: i32) -> bool {
+    ctx.db.foundation_cell().idx_cell_coords().filter((cell_x, cell_y)).any(|f| !f.is_destroyed)
+}
+
+// --- Reducers ---
+
+/// Place a second-story floor over a foundation cell, resting on its walls
+#[spacetimedb::reducer]
+pub fn place_floor(ctx: &ReducerContext, cell_x: i64, cell_y: i64, tier: u8) -> Result<(), String> {
+    let cell_x = cell_x as i32;
+    let cell_y = cell_y as i32;
+
+    let building_tier = building_tier_from_u8(tier)
+        .filter(|_| is_valid_building_tier(tier))
+        .ok_or_else(|| format!("Invalid building tier: {}. Must be 0-3 (Twig, Wood, Stone, Metal).", tier))?;
+
+    let player = validate_builder(ctx, cell_x, cell_y, "place floor")?;
+
+    if !has_foundation(ctx, cell_x, cell_y) {
+        return Err("Cannot place floor: no foundation below.".to_string());
+    }
+    if find_floor_at(ctx, cell_x, cell_y, ELEVATION_SECOND_FLOOR).is_some() {
+        return Err("A floor already exists here.".to_string());
+    }
+    if !is_floor_supported(ctx, cell_x, cell_y) {
+        return Err(format!(
+            "Floor needs support: at least {} walls on this cell, or an adjacent supported floor.",
+            FLOOR_MIN_SUPPORTING_WALLS
+        ));
+    }
+
+    let (wood, stone, metal) = get_floor_piece_cost(building_tier, false);
+    consume_building_resources(ctx, &player, wood, stone, metal)?;
+
+    let (world_x, world_y) = cell_center(cell_x, cell_y);
+    let max_health = get_floor_max_health(building_tier);
+    ctx.db.floor_cell().try_insert(FloorCell {
+        id: 0, // Auto-incremented
+        cell_x,
+        cell_y,
+        chunk_index: calculate_chunk_index(world_x, world_y),
+        level: ELEVATION_SECOND_FLOOR,
+        tier,
+        health: max_health,
+        max_health,
+        owner: player.identity,
+        placed_at: ctx.timestamp,
+        is_destroyed: false,
+        destroyed_at: None,
+        last_hit_time: None,
+        last_damaged_by: None,
+    }).map_err(|e| format!("Failed to insert floor: {}", e))?;
+
+    crate::sound_events::emit_foundation_wood_constructed_sound(ctx, world_x, world_y, player.identity);
+
+    log::info!(
+        "[PlaceFloor] Player {:?} placed {:?} floor at cell ({}, {}), health={:.1}",
+        player.identity, building_tier, cell_x, cell_y, max_health
+    );
+
+    Ok(())
+}
+
+/// Place stairs on a ground-floor foundation that has a floor above it
+#[spacetimedb::reducer]
+pub fn place_stairs(ctx: &ReducerContext, cell_x: i64, cell_y: i64, tier: u8) -> Result<(), String> {
+    let cell_x = cell_x as i32;
+    let cell_y = cell_y as i32;
+
+    let building_tier = building_tier_from_u8(tier)
+        .filter(|_| is_valid_building_tier(tier))
+        .ok_or_else(|| format!("Invalid building tier: {}. Must be 0-3 (Twig, Wood, Stone, Metal).", tier))?;
+
+    let player = validate_builder(ctx, cell_x, cell_y, "place stairs")?;
+
+    if !has_foundation(ctx, cell_x, cell_y) {
+        return Err("Cannot place stairs: no foundation at this location.".to_string());
+    }
+    if find_floor_at(ctx, cell_x, cell_y, ELEVATION_SECOND_FLOOR).is_none() {
+        return Err("Stairs need a floor above them to lead up to.".to_string());
+    }
+    if ctx.db.stairs_cell().idx_cell_coords().filter((cell_x, cell_y)).any(|s| !s.is_destroyed) {
+        return Err("Stairs already exist here.".to_string());
+    }
+
+    let (wood, stone, metal) = get_floor_piece_cost(building_tier, true);
+    consume_building_resources(ctx, &player, wood, stone, metal)?;
+
+    let (world_x, world_y) = cell_center(cell_x, cell_y);
+    let max_health = get_stairs_max_health(building_tier);
+    ctx.db.stairs_cell().try_insert(StairsCell {
+        id: 0, // Auto-incremented
+        cell_x,
+        cell_y,
+        chunk_index: calculate_chunk_index(world_x, world_y),
+        tier,
+        health: max_health,
+        max_health,
+        owner: player.identity,
+        placed_at: ctx.timestamp,
+        is_destroyed: false,
+        destroyed_at: None,
+        last_hit_time: None,
+        last_damaged_by: None,
+    }).map_err(|e| format!("Failed to insert stairs: {}", e))?;
+
+    crate::sound_events::emit_foundation_wood_constructed_sound(ctx, world_x, world_y, player.identity);
+
+    log::info!(
+        "[PlaceStairs] Player {:?} placed {:?} stairs at cell ({}, {})",
+        player.identity, building_tier, cell_x, cell_y
+    );
+
+    Ok(())
+}
+
+/// Walk up or down a set of stairs, toggling the player's elevation
+#[spacetimedb::reducer]
+pub fn use_stairs(ctx: &ReducerContext, stairs_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let players = ctx.db.player();
+    let mut player = players.identity().find(&sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead || player.is_knocked_out {
+        return Err("Cannot use stairs right now.".to_string());
+    }
+
+    let stairs = ctx.db.stairs_cell().id().find(&stairs_id)
+        .ok_or_else(|| "Stairs not found".to_string())?;
+    if stairs.is_destroyed {
+        return Err("These stairs are destroyed.".to_string());
+    }
+
+    let (stairs_x, stairs_y) = cell_center(stairs.cell_x, stairs.cell_y);
+    let dx = stairs_x - player.position_x;
+    let dy = stairs_y - player.position_y;
+    if dx * dx + dy * dy > STAIRS_USE_DISTANCE_SQUARED {
+        return Err("Too far from the stairs.".to_string());
+    }
+
+    if player.elevation == ELEVATION_GROUND {
+        if find_floor_at(ctx, stairs.cell_x, stairs.cell_y, ELEVATION_SECOND_FLOOR).is_none() {
+            return Err("There is no floor above these stairs.".to_string());
+        }
+        player.elevation = ELEVATION_SECOND_FLOOR;
+    } else {
+        player.elevation = ELEVATION_GROUND;
+    }
+
+    log::info!("[UseStairs] Player {:?} used stairs {} - elevation now {}", sender_id, stairs_id, player.elevation);
+    players.identity().update(player);
+
+    Ok(())
+}
+
+/// Destroy your own twig floor (mirrors destroy_wall rules)
+#[spacetimedb::reducer]
+pub fn destroy_floor(ctx: &ReducerContext, floor_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_knocked_out {
+        return Err("Cannot destroy floor while knocked out.".to_string());
+    }
+    if !player_has_repair_hammer(ctx, sender_id) {
+        return Err("Repair Hammer must be equipped to destroy floors.".to_string());
+    }
+
+    let floor = ctx.db.floor_cell().id().find(&floor_id)
+        .ok_or_else(|| "Floor not found".to_string())?;
+    if floor.is_destroyed {
+        return Err("Floor is already destroyed.".to_string());
+    }
+    if floor.tier != BuildingTier::Twig as u8 {
+        return Err("Only twig floors can be destroyed.".to_string());
+    }
+    if floor.owner != sender_id {
+        return Err("You can only destroy floors that you built.".to_string());
+    }
+
+    let (world_x, world_y) = cell_center(floor.cell_x, floor.cell_y);
+    let dx = world_x - player.position_x;
+    let dy = world_y - player.position_y;
+    if dx * dx + dy * dy > BUILDING_PLACEMENT_MAX_DISTANCE_SQUARED {
+        return Err("Floor is too far away.".to_string());
+    }
+
+    let (cell_x, cell_y) = (floor.cell_x, floor.cell_y);
+    mark_floor_destroyed(ctx, floor);
+    crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, world_x, world_y, sender_id);
+
+    log::info!("[DestroyFloor] Successfully destroyed twig floor {} at ({}, {})", floor_id, cell_x, cell_y);
+
+    Ok(())
+}
+
+/// Destroy your own twig stairs (mirrors destroy_wall rules)
+#[spacetimedb::reducer]
+pub fn destroy_stairs(ctx: &ReducerContext, stairs_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_knocked_out {
+        return Err("Cannot destroy stairs while knocked out.".to_string());
+    }
+    if !player_has_repair_hammer(ctx, sender_id) {
+        return Err("Repair Hammer must be equipped to destroy stairs.".to_string());
+    }
+
+    let mut stairs = ctx.db.stairs_cell().id().find(&stairs_id)
+        .ok_or_else(|| "Stairs not found".to_string())?;
+    if stairs.is_destroyed {
+        return Err("Stairs are already destroyed.".to_string());
+    }
+    if stairs.tier != BuildingTier::Twig as u8 {
+        return Err("Only twig stairs can be destroyed.".to_string());
+    }
+    if stairs.owner != sender_id {
+        return Err("You can only destroy stairs that you built.".to_string());
+    }
+
+    let (world_x, world_y) = cell_center(stairs.cell_x, stairs.cell_y);
+    let dx = world_x - player.position_x;
+    let dy = world_y - player.position_y;
+    if dx * dx + dy * dy > BUILDING_PLACEMENT_MAX_DISTANCE_SQUARED {
+        return Err("Stairs are too far away.".to_string());
+    }
+
+    stairs.is_destroyed = true;
+    stairs.destroyed_at = Some(ctx.timestamp);
+    ctx.db.stairs_cell().id().update(stairs);
+    crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, world_x, world_y, sender_id);
+
+    log::info!("[DestroyStairs] Successfully destroyed twig stairs {}", stairs_id);
+
+    Ok(())
+}
+
+// --- Explosive Damage ---
+
+/// Applies explosive damage to floors and stairs within a radius (bypasses melee reduction)
+/// Used by the explosion system alongside walls/doors/foundations
+pub fn damage_floors_explosive_in_radius(
+    ctx: &ReducerContext,
+    attacker_id: Identity,
+    center_x: f32,
+    center_y: f32,
+    radius_sq: f32,
+    damage: f32,
+) {
+    let in_radius = |cell_x: i32, cell_y: i32| {
+        let (world_x, world_y) = cell_center(cell_x, cell_y);
+        let dx = world_x - center_x;
+        let dy = world_y - center_y;
+        dx * dx + dy * dy <= radius_sq
+    };
+
+    let floor_ids: Vec<u64> = ctx.db.floor_cell().iter()
+        .filter(|f| !f.is_destroyed && in_radius(f.cell_x, f.cell_y))
+        .map(|f| f.id)
+        .collect();
+    let stairs_ids: Vec<u64> = ctx.db.stairs_cell().iter()
+        .filter(|s| !s.is_destroyed && in_radius(s.cell_x, s.cell_y))
+        .map(|s| s.id)
+        .collect();
+    let targets = floor_ids.into_iter().map(StructureRef::Floor)
+        .chain(stairs_ids.into_iter().map(StructureRef::Stairs));
+    for target in targets {
+        // Raiding checks happen inside - blocked pieces are skipped
+        let _ = apply_structure_damage(ctx, target, damage, StructureDamageType::Explosive, Some(attacker_id));
+    }
+}
@@ -3,13 +3,16 @@
  * Defines the cairn system - interactive monuments that provide lore        *
  * when players interact with them. Each cairn contains a unique lore entry *
  * that plays audio and displays text in SOVA chat.                          *
+ * Discovering every cairn in a region completes that region's memory        *
+ * fragment collection, synthesizing a Memory Photograph lore item and       *
+ * granting a one-time Memory Grid node discount.                            *
  *                                                                            *
  ******************************************************************************/
 
 use spacetimedb::{SpacetimeType, Timestamp, Table, Identity, ReducerContext};
 use crate::PLAYER_RADIUS;
 use crate::player; // Import Player table trait for ctx.db.player()
-use crate::dropped_item::give_item_to_player_or_drop;
+use crate::dropped_item::{give_item_to_player_or_drop, give_item_to_player_or_drop_with_data};
 use crate::items::item_definition as ItemDefinitionTableTrait;
 // Import player progression table traits
 use crate::player_progression::player_stats as PlayerStatsTableTrait;
@@ -81,6 +84,38 @@ fn get_cairn_reward_for_lore_id(lore_id: &str) -> u32 {
     }
 }
 
+// --- Memory Fragment Collection Constants ---
+// Regions are latitude bands matching world generation (north = top of the map = tundra/alpine)
+pub const CAIRN_REGION_NORTH: u8 = 0;
+pub const CAIRN_REGION_CENTRAL: u8 = 1;
+pub const CAIRN_REGION_SOUTH: u8 = 2;
+
+/// Lore item synthesized when a region's collection is completed
+pub const MEMORY_PHOTOGRAPH_ITEM_NAME: &str = "Memory Photograph";
+/// Discount applied to the next Memory Grid node purchase per completed region
+pub const MEMORY_FRAGMENT_NODE_DISCOUNT_PERCENT: u64 = 25;
+
+/// Get the collection region for a world position
+pub fn get_cairn_region(pos_y: f32) -> u8 {
+    let latitude = pos_y / crate::WORLD_HEIGHT_PX;
+    if latitude < 1.0 / 3.0 {
+        CAIRN_REGION_NORTH
+    } else if latitude < 2.0 / 3.0 {
+        CAIRN_REGION_CENTRAL
+    } else {
+        CAIRN_REGION_SOUTH
+    }
+}
+
+/// Display name for a collection region
+pub fn get_cairn_region_name(region: u8) -> &'static str {
+    match region {
+        CAIRN_REGION_NORTH => "Northern Reaches",
+        CAIRN_REGION_CENTRAL => "Central Highlands",
+        _ => "Southern Shores",
+    }
+}
+
 // --- Cairn Table ---
 
 #[spacetimedb::table(accessor = cairn, public)]
@@ -111,6 +146,111 @@ pub struct PlayerDiscoveredCairn {
     pub discovered_at: Timestamp,
 }
 
+// --- Memory Fragment Collection ---
+
+/// Per-player, per-region cairn collection progress (public for the client journal UI)
+#[spacetimedb::table(accessor = memory_fragment_collection, public)]
+#[derive(Clone, Debug)]
+pub struct MemoryFragmentCollection {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_identity: Identity,
+    pub region: u8,                           // CAIRN_REGION_*
+    pub cairns_discovered: u32,
+    pub cairns_total: u32,
+    pub completed_at: Option<Timestamp>,      // Set once every cairn in the region is discovered
+    pub discount_redeemed_on: Option<String>, // Memory Grid node the completion discount was spent on
+}
+
+/// Recomputes a player's collection progress for a region and completes it if every cairn is found
+fn update_memory_fragment_collection(ctx: &ReducerContext, player_id: Identity, region: u8) {
+    let cairns_in_region: Vec<u64> = ctx.db.cairn().iter()
+        .filter(|c| get_cairn_region(c.pos_y) == region)
+        .map(|c| c.id)
+        .collect();
+    let cairns_total = cairns_in_region.len() as u32;
+    let cairns_discovered = ctx.db.player_discovered_cairn()
+        .player_identity()
+        .filter(&player_id)
+        .filter(|d| cairns_in_region.contains(&d.cairn_id))
+        .count() as u32;
+
+    let collections = ctx.db.memory_fragment_collection();
+    let existing = collections.player_identity()
+        .filter(&player_id)
+        .find(|c| c.region == region);
+
+    let mut collection = existing.clone().unwrap_or(MemoryFragmentCollection {
+        id: 0,
+        player_identity: player_id,
+        region,
+        cairns_discovered: 0,
+        cairns_total: 0,
+        completed_at: None,
+        discount_redeemed_on: None,
+    });
+    collection.cairns_discovered = cairns_discovered;
+    collection.cairns_total = cairns_total;
+
+    let newly_completed = collection.completed_at.is_none()
+        && cairns_total > 0
+        && cairns_discovered >= cairns_total;
+    if newly_completed {
+        collection.completed_at = Some(ctx.timestamp);
+    }
+
+    if existing.is_some() {
+        collections.id().update(collection);
+    } else {
+        collections.insert(collection);
+    }
+
+    if newly_completed {
+        synthesize_memory_photograph(ctx, player_id, region);
+    }
+}
+
+/// Gives the player the region's Memory Photograph lore item
+fn synthesize_memory_photograph(ctx: &ReducerContext, player_id: Identity, region: u8) {
+    let region_name = get_cairn_region_name(region);
+    let Some(photo_def) = ctx.db.item_definition().iter().find(|def| def.name == MEMORY_PHOTOGRAPH_ITEM_NAME) else {
+        log::error!("❌ {} item definition not found! Cannot reward region completion", MEMORY_PHOTOGRAPH_ITEM_NAME);
+        return;
+    };
+
+    let item_data = serde_json::json!({
+        "region": region,
+        "region_name": region_name,
+    }).to_string();
+
+    match give_item_to_player_or_drop_with_data(ctx, player_id, photo_def.id, 1, Some(item_data)) {
+        Ok(_) => log::info!("📷 Player {} completed the {} memory fragment collection", player_id, region_name),
+        Err(e) => log::error!("❌ Failed to give Memory Photograph to player {}: {}", player_id, e),
+    }
+}
+
+/// Oldest completed collection whose Memory Grid discount hasn't been spent yet
+pub fn get_pending_fragment_discount(ctx: &ReducerContext, player_id: Identity) -> Option<MemoryFragmentCollection> {
+    ctx.db.memory_fragment_collection()
+        .player_identity()
+        .filter(&player_id)
+        .filter(|c| c.completed_at.is_some() && c.discount_redeemed_on.is_none())
+        .min_by_key(|c| c.completed_at.map(|t| t.to_micros_since_unix_epoch()))
+}
+
+/// Node cost after a collection discount
+pub fn apply_fragment_discount(cost: u64) -> u64 {
+    cost * (100 - MEMORY_FRAGMENT_NODE_DISCOUNT_PERCENT) / 100
+}
+
+/// Marks a collection's discount as spent on a Memory Grid node
+pub fn redeem_fragment_discount(ctx: &ReducerContext, mut collection: MemoryFragmentCollection, node_id: &str) {
+    collection.discount_redeemed_on = Some(node_id.to_string());
+    ctx.db.memory_fragment_collection().id().update(collection);
+}
+
 // --- Interaction Reducer ---
 
 /// Player interacts with a cairn to discover its lore
@@ -252,6 +392,9 @@ pub fn interact_with_cairn(ctx: &ReducerContext, cairn_id: u64) -> Result<(), St
             log::error!("Failed to track quest progress for cairn discovery: {}", e);
         }
         
+        // Update the region's memory fragment collection (may synthesize a Memory Photograph)
+        update_memory_fragment_collection(ctx, player_id, get_cairn_region(cairn.pos_y));
+        
         // Note: cairn_unlock sound is played client-side for instant feedback
     } else {
         log::info!(
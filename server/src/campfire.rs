@@ -25,8 +25,8 @@
      InventoryItem, ItemDefinition,
      calculate_merge_result, split_stack_helper, add_item_to_player_inventory
  };
- use crate::inventory_management::{self, ItemContainer, ContainerItemClearer, merge_or_place_into_container_slot};
- use crate::player_inventory::{move_item_to_inventory, move_item_to_hotbar, find_first_empty_player_slot, get_player_item};
+ use crate::inventory_management::{self, ItemContainer, ContainerItemClearer};
+ use crate::player_inventory::{move_item_to_inventory, move_item_to_hotbar, find_first_empty_player_slot};
  use crate::environment::calculate_chunk_index; // Assuming helper is here or in utils
  use crate::dropped_item::create_dropped_item_entity; // For dropping charcoal
  
@@ -153,308 +153,86 @@ const CAMPFIRE_DAMAGE_RADIUS_SQUARED: f32 = 2500.0; // 50.0 * 50.0
   *                           REDUCERS (Generic Handlers)                        *
   ******************************************************************************/
  
-/// --- Add Fuel to Campfire ---
-/// Adds an item from the player's inventory as fuel to a specific campfire slot.
-/// Validates the campfire interaction and fuel item, then uses the generic container handler
-/// to move the item to the campfire. Updates the campfire state after successful addition.
-#[spacetimedb::reducer]
-pub fn move_item_to_campfire(ctx: &ReducerContext, campfire_id: u32, target_slot_index: u8, item_instance_id: u64) -> Result<(), String> {
-    let (_player, mut campfire) = validate_campfire_interaction(ctx, campfire_id)?;
-    
-    // --- SECURITY: Prevent interaction with campfire fuel slots 1-4 when broth pot is attached ---
-    // Slot 0 remains accessible for fuel management even when broth pot is attached
-    if campfire.attached_broth_pot_id.is_some() && target_slot_index != 0 {
-        return Err("Cannot add fuel to campfire slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
-    }
-    
-    // --- Validate item type - prevent water bottles and cauldrons ---
-    let items = ctx.db.inventory_item();
-    let item = items.instance_id().find(&item_instance_id)
-        .ok_or_else(|| "Item not found.".to_string())?;
-    let item_defs = ctx.db.item_definition();
-    let item_def = item_defs.id().find(&item.item_def_id)
-        .ok_or_else(|| "Item definition not found.".to_string())?;
-    
-    // Prevent water containers and cauldrons from being placed in campfires
-    let blocked_items = ["Reed Water Bottle", "Plastic Water Jug", "Cerametal Field Cauldron Mk. II"];
-    if blocked_items.contains(&item_def.name.as_str()) {
-        return Err(format!("Cannot place '{}' in campfire. Use the broth pot's water container slot for water bottles, or place the cauldron on the campfire.", item_def.name));
-    }
-    
-    inventory_management::handle_move_to_container_slot(ctx, &mut campfire, target_slot_index, item_instance_id)?;
-    ctx.db.campfire().id().update(campfire.clone()); // Persist campfire slot changes
-    Ok(())
-}
- 
-/// --- Remove Fuel from Campfire ---
-/// Removes the fuel item from a specific campfire slot and returns it to the player inventory/hotbar.
-/// Uses the quick move logic (attempts merge, then finds first empty slot).
-#[spacetimedb::reducer]
-pub fn quick_move_from_campfire(ctx: &ReducerContext, campfire_id: u32, source_slot_index: u8) -> Result<(), String> {
-    let (_player, mut campfire) = validate_campfire_interaction(ctx, campfire_id)?;
-    
-    // --- SECURITY: Prevent interaction with campfire fuel slots 1-4 when broth pot is attached ---
-    // Slot 0 remains accessible for fuel management even when broth pot is attached
-    if campfire.attached_broth_pot_id.is_some() && source_slot_index != 0 {
-        return Err("Cannot remove fuel from campfire slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
+impl inventory_management::ContainerHandle for Campfire {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_campfire_interaction(ctx, container_id)
     }
-    
-    inventory_management::handle_quick_move_from_container(ctx, &mut campfire, source_slot_index)?;
-     let still_has_fuel = check_if_campfire_has_fuel(ctx, &campfire);
-     if !still_has_fuel && campfire.is_burning {
-         campfire.is_burning = false;
-         campfire.current_fuel_def_id = None;
-         campfire.remaining_fuel_burn_time_secs = None;
-         stop_campfire_sound(ctx, campfire_id as u64);
-         log::info!("Campfire {} extinguished as last valid fuel was removed.", campfire_id);
-     }
-     ctx.db.campfire().id().update(campfire.clone());
-     Ok(())
- }
- 
-/// --- Split Stack Into Campfire ---
-/// Splits a stack from player inventory into a campfire slot.
-#[spacetimedb::reducer]
-pub fn split_stack_into_campfire(
-    ctx: &ReducerContext,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-    target_campfire_id: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut campfire) = validate_campfire_interaction(ctx, target_campfire_id)?;
-    
-    // --- SECURITY: Prevent interaction with campfire fuel slots 1-4 when broth pot is attached ---
-    // Slot 0 remains accessible for fuel management even when broth pot is attached
-    if campfire.attached_broth_pot_id.is_some() && target_slot_index != 0 {
-        return Err("Cannot add fuel to campfire slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
+
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.campfire().id().update(self.clone());
     }
-    
-    // --- Validate item type - prevent water bottles and cauldrons ---
-     let items = ctx.db.inventory_item();
-     let source_item = items.instance_id().find(&source_item_instance_id)
-         .ok_or_else(|| "Source item not found.".to_string())?;
-     let item_defs = ctx.db.item_definition();
-     let item_def = item_defs.id().find(&source_item.item_def_id)
-         .ok_or_else(|| "Item definition not found.".to_string())?;
-     
-     // Prevent water containers and cauldrons from being placed in campfires
-     let blocked_items = ["Reed Water Bottle", "Plastic Water Jug", "Cerametal Field Cauldron Mk. II"];
-     if blocked_items.contains(&item_def.name.as_str()) {
-         return Err(format!("Cannot place '{}' in campfire. Use the broth pot's water container slot for water bottles, or place the cauldron on the campfire.", item_def.name));
-     }
-     
-     let mut source_item_mut = get_player_item(ctx, source_item_instance_id)?;
-     let new_item_target_location = ItemLocation::Container(crate::models::ContainerLocationData {
-         container_type: ContainerType::Campfire,
-         container_id: campfire.id as u64,
-         slot_index: target_slot_index,
-     });
-     let new_item_instance_id = split_stack_helper(ctx, &mut source_item_mut, quantity_to_split, new_item_target_location)?;
-     
-     // Fetch the newly created item and its definition to pass to merge_or_place
-     let mut new_item = ctx.db.inventory_item().instance_id().find(new_item_instance_id)
-         .ok_or_else(|| format!("Failed to find newly split item instance {}", new_item_instance_id))?;
-     let new_item_def = ctx.db.item_definition().id().find(new_item.item_def_id)
-         .ok_or_else(|| format!("Failed to find definition for new item {}", new_item.item_def_id))?;
- 
-     merge_or_place_into_container_slot(ctx, &mut campfire, target_slot_index, &mut new_item, &new_item_def)?;
-     
-     // Update the source item (quantity changed by split_stack_helper)
-     ctx.db.inventory_item().instance_id().update(source_item); 
-     ctx.db.campfire().id().update(campfire.clone());
-     Ok(())
- }
- 
-/// --- Campfire Internal Item Movement ---
-/// Moves/merges/swaps an item BETWEEN two slots within the same campfire.
-#[spacetimedb::reducer]
-pub fn move_item_within_campfire(
-    ctx: &ReducerContext,
-    campfire_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut campfire) = validate_campfire_interaction(ctx, campfire_id)?;
-    
-    // --- SECURITY: Prevent interaction with campfire fuel slots 1-4 when broth pot is attached ---
-    // Slot 0 remains accessible for fuel management even when broth pot is attached
-    if campfire.attached_broth_pot_id.is_some() && source_slot_index != 0 && target_slot_index != 0 {
-        return Err("Cannot move fuel between slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
+
+    fn check_operation(&self, ctx: &ReducerContext, op: &inventory_management::ContainerOp) -> Result<(), String> {
+        use inventory_management::ContainerOp;
+
+        // --- SECURITY: Prevent interaction with campfire fuel slots 1-4 when broth pot is attached ---
+        // Slot 0 remains accessible for fuel management even when broth pot is attached
+        if self.attached_broth_pot_id.is_some() {
+            match *op {
+                ContainerOp::MoveTo { target_slot, .. } | ContainerOp::SplitInto { target_slot, .. } if target_slot != 0 => {
+                    return Err("Cannot add fuel to campfire slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
+                }
+                // Quick move might pick slots 1-4; use move_item_to_campfire with slot 0 instead
+                ContainerOp::QuickMoveTo { .. } => {
+                    return Err("Cannot use quick move while broth pot is attached. Use move_item_to_campfire with slot 0 for fuel management.".to_string());
+                }
+                ContainerOp::MoveFrom { source_slot } | ContainerOp::SplitFrom { source_slot } | ContainerOp::QuickMoveFrom { source_slot } if source_slot != 0 => {
+                    return Err("Cannot remove fuel from campfire slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
+                }
+                ContainerOp::Drop { source_slot } | ContainerOp::SplitAndDrop { source_slot } if source_slot != 0 => {
+                    return Err("Cannot drop fuel from campfire slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
+                }
+                ContainerOp::MoveWithin { source_slot, target_slot } if source_slot != 0 && target_slot != 0 => {
+                    return Err("Cannot move fuel between slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
+                }
+                ContainerOp::SplitWithin { source_slot, target_slot } if source_slot != 0 && target_slot != 0 => {
+                    return Err("Cannot split fuel between slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
+                }
+                _ => {}
+            }
+        }
+
+        // --- Validate item type - prevent water bottles and cauldrons ---
+        if let Some(item_instance_id) = op.incoming_item() {
+            let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+                .ok_or_else(|| "Item not found.".to_string())?;
+            let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
+                .ok_or_else(|| "Item definition not found.".to_string())?;
+            let blocked_items = ["Reed Water Bottle", "Plastic Water Jug", "Cerametal Field Cauldron Mk. II"];
+            if blocked_items.contains(&item_def.name.as_str()) {
+                return Err(format!("Cannot place '{}' in campfire. Use the broth pot's water container slot for water bottles, or place the cauldron on the campfire.", item_def.name));
+            }
+        }
+        Ok(())
     }
-    
-    // Save cooking progress before move (since set_slot clears it)
-    use crate::cooking::CookableAppliance;
-    let source_progress = campfire.get_slot_cooking_progress(source_slot_index);
-    let target_progress = campfire.get_slot_cooking_progress(target_slot_index);
-    let source_had_item = campfire.get_slot_instance_id(source_slot_index).is_some();
-    let target_had_item = campfire.get_slot_instance_id(target_slot_index).is_some();
-    
-    inventory_management::handle_move_within_container(ctx, &mut campfire, source_slot_index, target_slot_index)?;
-    
-    // Transfer cooking progress based on what happened:
-    // - Move to empty slot: source progress -> target
-    // - Swap: exchange progress
-    // - Merge: target keeps its progress (items combined there)
-    if source_had_item && !target_had_item {
-        // Move to empty slot: transfer source progress to target
-        campfire.set_slot_cooking_progress(target_slot_index, source_progress);
-    } else if source_had_item && target_had_item {
-        // Check if it was a swap (source slot now has an item) or merge (source slot empty)
-        if campfire.get_slot_instance_id(source_slot_index).is_some() {
-            // Swap: exchange cooking progress
-            campfire.set_slot_cooking_progress(target_slot_index, source_progress);
-            campfire.set_slot_cooking_progress(source_slot_index, target_progress);
+
+    fn after_operation(&mut self, ctx: &ReducerContext, op: &inventory_management::ContainerOp, before: &Self) {
+        if let inventory_management::ContainerOp::MoveWithin { source_slot, target_slot } = *op {
+            crate::cooking::carry_cooking_progress_after_move(self, before, source_slot, target_slot);
+        }
+        if op.removes_items() && self.is_burning && !check_if_campfire_has_fuel(ctx, self) {
+            self.is_burning = false;
+            self.current_fuel_def_id = None;
+            self.remaining_fuel_burn_time_secs = None;
+            stop_campfire_sound(ctx, self.id as u64);
+            log::info!("Campfire {} extinguished as last valid fuel was removed.", self.id);
         }
-        // If merge: target keeps its progress (already in place), source was cleared
     }
-    
-    ctx.db.campfire().id().update(campfire.clone());
-    Ok(())
 }
- 
-/// --- Campfire Internal Stack Splitting ---
-/// Splits a stack FROM one campfire slot TO another within the same campfire.
-#[spacetimedb::reducer]
-pub fn split_stack_within_campfire(
-    ctx: &ReducerContext,
-    campfire_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut campfire) = validate_campfire_interaction(ctx, campfire_id)?;
-    
-    // --- SECURITY: Prevent interaction with campfire fuel slots 1-4 when broth pot is attached ---
-    // Slot 0 remains accessible for fuel management even when broth pot is attached
-    if campfire.attached_broth_pot_id.is_some() && source_slot_index != 0 && target_slot_index != 0 {
-        return Err("Cannot split fuel between slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
-    }
-    
-    inventory_management::handle_split_within_container(ctx, &mut campfire, source_slot_index, target_slot_index, quantity_to_split)?;
-    
-    // IMPORTANT: Keep cooking progress on source slot when splitting (same as compost keeps timestamp)
-    // The remaining stack continues from where it was - only the new split item starts fresh
-    // Note: Progress is per-slot, so the remaining stack will continue cooking with existing progress
-    // The new split item in target slot will start cooking fresh when placed (no progress on new slot)
-    
-     ctx.db.campfire().id().update(campfire.clone());
-     Ok(())
- }
- 
-/// --- Quick Move to Campfire ---
-/// Quickly moves an item from player inventory/hotbar to the first available/mergeable slot in the campfire.
-#[spacetimedb::reducer]
-pub fn quick_move_to_campfire(
-    ctx: &ReducerContext,
-    campfire_id: u32,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_player, mut campfire) = validate_campfire_interaction(ctx, campfire_id)?;
-    
-    // --- SECURITY: Prevent quick move when broth pot is attached ---
-    // Quick move might try to use slots 1-4 which are blocked when broth pot is attached
-    // Use move_item_to_campfire with explicit slot 0 instead
-    if campfire.attached_broth_pot_id.is_some() {
-        return Err("Cannot use quick move while broth pot is attached. Use move_item_to_campfire with slot 0 for fuel management.".to_string());
-    }
-    
-    // --- Validate item type - prevent water bottles and cauldrons ---
-     let items = ctx.db.inventory_item();
-     let item = items.instance_id().find(&item_instance_id)
-         .ok_or_else(|| "Item not found.".to_string())?;
-     let item_defs = ctx.db.item_definition();
-     let item_def = item_defs.id().find(&item.item_def_id)
-         .ok_or_else(|| "Item definition not found.".to_string())?;
-     
-     // Prevent water containers and cauldrons from being placed in campfires
-     let blocked_items = ["Reed Water Bottle", "Plastic Water Jug", "Cerametal Field Cauldron Mk. II"];
-     if blocked_items.contains(&item_def.name.as_str()) {
-         return Err(format!("Cannot place '{}' in campfire. Use the broth pot's water container slot for water bottles, or place the cauldron on the campfire.", item_def.name));
-     }
-     
-     inventory_management::handle_quick_move_to_container(ctx, &mut campfire, item_instance_id)?;
-     ctx.db.campfire().id().update(campfire.clone());
-     Ok(())
- }
- 
-/// --- Move From Campfire to Player ---
-/// Moves a specific item FROM a campfire slot TO a specific player inventory/hotbar slot.
-#[spacetimedb::reducer]
-pub fn move_item_from_campfire_to_player_slot(
-    ctx: &ReducerContext,
-    campfire_id: u32,
-    source_slot_index: u8,
-    target_slot_type: String,
-    target_slot_index: u32, // u32 to match client flexibility
-) -> Result<(), String> {
-    let (_player, mut campfire) = validate_campfire_interaction(ctx, campfire_id)?;
-    
-    // --- SECURITY: Prevent interaction with campfire fuel slots 1-4 when broth pot is attached ---
-    // Slot 0 remains accessible for fuel management even when broth pot is attached
-    if campfire.attached_broth_pot_id.is_some() && source_slot_index != 0 {
-        return Err("Cannot remove fuel from campfire slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
-    }
-    
-    inventory_management::handle_move_from_container_slot(ctx, &mut campfire, source_slot_index, target_slot_type, target_slot_index)?;
-     let still_has_fuel = check_if_campfire_has_fuel(ctx, &campfire);
-     if !still_has_fuel && campfire.is_burning {
-         campfire.is_burning = false;
-         campfire.current_fuel_def_id = None;
-         campfire.remaining_fuel_burn_time_secs = None;
-     }
-     ctx.db.campfire().id().update(campfire.clone());
-     Ok(())
- }
- 
- /// --- Split From Campfire to Player ---
- /// Splits a stack FROM a campfire slot TO a specific player inventory/hotbar slot.
- #[spacetimedb::reducer]
- pub fn split_stack_from_campfire(
-     ctx: &ReducerContext,
-     source_campfire_id: u32,
-     source_slot_index: u8,
-     quantity_to_split: u32,
-     target_slot_type: String,    // "inventory" or "hotbar"
-     target_slot_index: u32,     // Numeric index for inventory/hotbar
- ) -> Result<(), String> {
-     // Get mutable campfire table handle
-     let mut campfires = ctx.db.campfire();
- 
-     // --- Basic Validations --- 
-     let (_player, mut campfire) = validate_campfire_interaction(ctx, source_campfire_id)?;
-     // Note: Further validations (item existence, stackability, quantity) are handled 
-     //       within the generic handle_split_from_container function.
- 
-     log::info!(
-         "[SplitFromCampfire] Player {:?} delegating split {} from campfire {} slot {} to {} slot {}",
-         ctx.sender(), quantity_to_split, source_campfire_id, source_slot_index, target_slot_type, target_slot_index
-     );
- 
-     // --- Call GENERIC Handler --- 
-     inventory_management::handle_split_from_container(
-         ctx, 
-         &mut campfire, 
-         source_slot_index, 
-         quantity_to_split,
-         target_slot_type, 
-         target_slot_index
-     )?;
-
-     // IMPORTANT: Keep cooking progress on source slot when splitting (same as compost keeps timestamp)
-     // The remaining stack continues from where it was - only the new split item starts fresh
-     // Note: Progress is per-slot, so the remaining stack will continue cooking with existing progress
-
-     // --- Commit Campfire Update --- 
-     // The handler might have modified the source item quantity via split_stack_helper,
-     // but the campfire state itself (slots) isn't directly changed by this handler.
-     // However, to be safe and consistent with other reducers that fetch a mutable container,
-     // we update it here. In the future, if the handler needed to modify the container state
-     // (e.g., if the split failed and we needed to revert something), this update is necessary.
-     campfires.id().update(campfire);
- 
-     Ok(())
- }
+
+crate::container_reducers! {
+    Campfire;
+    move_to: move_item_to_campfire(campfire_id),
+    quick_move_from: quick_move_from_campfire(campfire_id),
+    split_into_item_first: split_stack_into_campfire(target_campfire_id),
+    move_within: move_item_within_campfire(campfire_id),
+    split_within_quantity_first: split_stack_within_campfire(campfire_id),
+    quick_move_to: quick_move_to_campfire(campfire_id),
+    move_from: move_item_from_campfire_to_player_slot(campfire_id),
+    split_from: split_stack_from_campfire(source_campfire_id),
+    drop: drop_item_from_campfire_slot_to_world(campfire_id),
+    split_and_drop: split_and_drop_item_from_campfire_slot_to_world(campfire_id),
+}
  
  /// --- Split and Move From Campfire ---
  /// Splits a stack FROM a campfire slot and moves/merges the new stack 
@@ -501,15 +279,7 @@ pub fn move_item_from_campfire_to_player_slot(
      let item_def = ctx.db.item_definition().id().find(source_item.item_def_id)
          .ok_or_else(|| format!("Definition not found for item ID {}", source_item.item_def_id))?;
      
-     if !item_def.is_stackable {
-         return Err(format!("Item '{}' is not stackable.", item_def.name));
-     }
-     if quantity_to_split == 0 {
-         return Err("Cannot split a quantity of 0.".to_string());
-     }
-     if quantity_to_split >= source_item.quantity {
-         return Err(format!("Cannot split {} items, only {} available.", quantity_to_split, source_item.quantity));
-     }
+     crate::items::validate_split_quantity(&item_def, &source_item, quantity_to_split)?;
  
      // --- 3. Perform Split --- 
      // Determine the initial location for the NEWLY SPLIT item.
@@ -630,6 +400,9 @@ pub fn place_campfire(ctx: &ReducerContext, item_instance_id: u64, world_x: f32,
     // Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
     crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     // --- 1. Validate Player and Placement Rules ---
     let player = players.identity().find(sender_id)
         .ok_or_else(|| "Player not found".to_string())?;
@@ -1372,81 +1145,7 @@ pub fn close_campfire_container(ctx: &ReducerContext, campfire_id: u32) -> Resul
      false
  }
  
-// --- NEW: Drop Item from Campfire Fuel Slot to World ---
-#[spacetimedb::reducer]
-pub fn drop_item_from_campfire_slot_to_world(
-    ctx: &ReducerContext,
-    campfire_id: u32,
-    slot_index: u8, // This will be 0-4 for fuel slots
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player();
-    let mut campfire_table = ctx.db.campfire();
-
-    log::info!("[DropFromCampfireToWorld] Player {} attempting to drop fuel from campfire ID {}, slot index {}.", 
-             sender_id, campfire_id, slot_index);
-
-    // 1. Validate interaction and get campfire
-    let (_player_for_validation, mut campfire) = validate_campfire_interaction(ctx, campfire_id)?;
-    
-    // --- SECURITY: Prevent interaction with campfire fuel slots 1-4 when broth pot is attached ---
-    // Slot 0 remains accessible for fuel management even when broth pot is attached
-    if campfire.attached_broth_pot_id.is_some() && slot_index != 0 {
-        return Err("Cannot drop fuel from campfire slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
-    }
- 
-     // 2. Get Player for drop location
-     let player_for_drop_location = player_table.identity().find(sender_id)
-         .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
- 
-     // 3. Call the generic handler from inventory_management
-     // The ItemContainer trait for Campfire handles the slot_index for fuel slots
-     crate::inventory_management::handle_drop_from_container_slot(ctx, &mut campfire, slot_index, &player_for_drop_location)?;
  
-     // 4. Persist changes to the Campfire
-     campfire_table.id().update(campfire);
-     log::info!("[DropFromCampfireToWorld] Successfully dropped fuel from campfire {}, slot {}. Campfire updated.", campfire_id, slot_index);
- 
-     Ok(())
- }
- 
-// --- NEW: Split and Drop Item from Campfire Fuel Slot to World ---
-#[spacetimedb::reducer]
-pub fn split_and_drop_item_from_campfire_slot_to_world(
-    ctx: &ReducerContext,
-    campfire_id: u32,
-    slot_index: u8, // This will be 0-4 for fuel slots
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player();
-    let mut campfire_table = ctx.db.campfire();
-
-    log::info!("[SplitDropFromCampfireToWorld] Player {} attempting to split {} fuel from campfire ID {}, slot {}.", 
-             sender_id, quantity_to_split, campfire_id, slot_index);
-
-    // 1. Validate interaction and get campfire
-    let (_player_for_validation, mut campfire) = validate_campfire_interaction(ctx, campfire_id)?;
-    
-    // --- SECURITY: Prevent interaction with campfire fuel slots 1-4 when broth pot is attached ---
-    // Slot 0 remains accessible for fuel management even when broth pot is attached
-    if campfire.attached_broth_pot_id.is_some() && slot_index != 0 {
-        return Err("Cannot drop fuel from campfire slots 1-4 while broth pot is attached. Use slot 0 for fuel management.".to_string());
-    }
- 
-     // 2. Get Player for drop location
-     let player_for_drop_location = player_table.identity().find(sender_id)
-         .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
- 
-     // 3. Call the generic handler from inventory_management
-     crate::inventory_management::handle_split_and_drop_from_container_slot(ctx, &mut campfire, slot_index, quantity_to_split, &player_for_drop_location)?;
- 
-     // 4. Persist changes to the Campfire
-     campfire_table.id().update(campfire);
-     log::info!("[SplitDropFromCampfireToWorld] Successfully split and dropped fuel from campfire {}, slot {}. Campfire updated.", campfire_id, slot_index);
-     
-     Ok(())
- }
  
  // --- Helper: Get Item Definition by Name ---
  fn get_item_def_by_name<'a>(ctx: &'a ReducerContext, name: &str) -> Option<ItemDefinition> {
@@ -149,6 +149,7 @@ pub fn send_message(ctx: &ReducerContext, text: String) -> Result<(), String> {
                         current_player.health = 0.0;
                         current_player.is_dead = true;
                         current_player.death_timestamp = Some(current_time);
+                        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::Player(current_player.identity));
                         current_player.last_update = current_time;
                         
                         players.identity().update(current_player);
@@ -198,6 +199,8 @@ pub fn send_message(ctx: &ReducerContext, text: String) -> Result<(), String> {
                     }
                     // --- End DeathMarker ---
 
+                    crate::kill_feed::record_player_death(ctx, &player, None, "", "Suicide", None, None);
+
                     // Update cooldown record even when cooldown is disabled (for consistency)
                     let new_cooldown_record = crate::PlayerKillCommandCooldown {
                         player_id: sender_id,
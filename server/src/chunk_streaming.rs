@@ -0,0 +1,118 @@
+/******************************************************************************
+ *                                                                            *
+ * Chunk Streaming - Viewport-driven row visibility                           *
+ *                                                                            *
+ * Each client reports its viewport through update_viewport. The chunks it   *
+ * covers (plus a margin) are stored in client_visible_chunk, and row-level  *
+ * security filters on the high-volume entity tables (grass, dropped items,  *
+ * wild animals) only send a client the rows in its own visible chunks.      *
+ * Clients must report a viewport before they receive any of those rows.     *
+ * Local sound events are filtered the same way (see sound_events).          *
+ * Players looking through a spyglass stream a larger area (see spyglass).   *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table};
+use std::collections::HashSet;
+
+use crate::environment::{CHUNK_SIZE_PX, WORLD_WIDTH_CHUNKS, WORLD_HEIGHT_CHUNKS};
+
+/// Extra chunks streamed around the viewport so entities don't pop in at the edges
+pub const VIEWPORT_CHUNK_MARGIN: i32 = 1;
+/// Cap on chunks per client so an oversized viewport can't subscribe to the whole world
+pub const MAX_VISIBLE_CHUNKS_PER_CLIENT: usize = 400;
+
+/// A chunk currently streamed to a client
+#[spacetimedb::table(accessor = client_visible_chunk, public)]
+#[derive(Clone, Debug)]
+pub struct ClientVisibleChunk {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub client_identity: Identity,
+    pub chunk_index: u32,
+}
+
+// --- Row-Level Security Filters ---
+// Grass, dropped items and wild animals are only sent for chunks in the client's viewport.
+
+#[client_visibility_filter]
+const GRASS_IN_VIEWPORT: Filter = Filter::Sql(
+    "SELECT grass.* FROM grass JOIN client_visible_chunk ON grass.chunk_index = client_visible_chunk.chunk_index WHERE client_visible_chunk.client_identity = :sender"
+);
+
+#[client_visibility_filter]
+const DROPPED_ITEMS_IN_VIEWPORT: Filter = Filter::Sql(
+    "SELECT dropped_item.* FROM dropped_item JOIN client_visible_chunk ON dropped_item.chunk_index = client_visible_chunk.chunk_index WHERE client_visible_chunk.client_identity = :sender"
+);
+
+#[client_visibility_filter]
+const WILD_ANIMALS_IN_VIEWPORT: Filter = Filter::Sql(
+    "SELECT wild_animal.* FROM wild_animal JOIN client_visible_chunk ON wild_animal.chunk_index = client_visible_chunk.chunk_index WHERE client_visible_chunk.client_identity = :sender"
+);
+
+/// Chunk indices covered by a viewport (in world pixels), including the margin.
+/// Viewports larger than `max_chunks` are shrunk around their center.
+pub fn chunks_in_viewport(min_x: f32, min_y: f32, max_x: f32, max_y: f32, max_chunks: usize) -> Vec<u32> {
+    let max_chunk_x = WORLD_WIDTH_CHUNKS as i32 - 1;
+    let max_chunk_y = WORLD_HEIGHT_CHUNKS as i32 - 1;
+    let mut start_x = (min_x.min(max_x) / CHUNK_SIZE_PX).floor() as i32 - VIEWPORT_CHUNK_MARGIN;
+    let mut end_x = (min_x.max(max_x) / CHUNK_SIZE_PX).floor() as i32 + VIEWPORT_CHUNK_MARGIN;
+    let mut start_y = (min_y.min(max_y) / CHUNK_SIZE_PX).floor() as i32 - VIEWPORT_CHUNK_MARGIN;
+    let mut end_y = (min_y.max(max_y) / CHUNK_SIZE_PX).floor() as i32 + VIEWPORT_CHUNK_MARGIN;
+
+    let max_span = (max_chunks as f32).sqrt() as i32;
+    if end_x - start_x + 1 > max_span {
+        let center = (start_x + end_x) / 2;
+        start_x = center - max_span / 2;
+        end_x = start_x + max_span - 1;
+    }
+    if end_y - start_y + 1 > max_span {
+        let center = (start_y + end_y) / 2;
+        start_y = center - max_span / 2;
+        end_y = start_y + max_span - 1;
+    }
+
+    let mut chunks = Vec::new();
+    for chunk_y in start_y.max(0)..=end_y.min(max_chunk_y) {
+        for chunk_x in start_x.max(0)..=end_x.min(max_chunk_x) {
+            chunks.push(chunk_y as u32 * WORLD_WIDTH_CHUNKS + chunk_x as u32);
+        }
+    }
+    chunks
+}
+
+/// Updates a client's streamed chunks to match its viewport, only touching chunks that changed.
+/// A scoping client's viewport is scaled up around its center, and so is its chunk cap.
+pub fn refresh_visible_chunks(ctx: &ReducerContext, client_identity: Identity, min_x: f32, min_y: f32, max_x: f32, max_y: f32) {
+    let visible_chunks = ctx.db.client_visible_chunk();
+    let zoom = crate::spyglass::get_viewport_multiplier(ctx, client_identity);
+    let (center_x, center_y) = ((min_x + max_x) / 2.0, (min_y + max_y) / 2.0);
+    let (half_width, half_height) = ((max_x - min_x).abs() / 2.0 * zoom, (max_y - min_y).abs() / 2.0 * zoom);
+    let max_chunks = (MAX_VISIBLE_CHUNKS_PER_CLIENT as f32 * zoom * zoom) as usize;
+    let wanted: HashSet<u32> = chunks_in_viewport(
+        center_x - half_width, center_y - half_height,
+        center_x + half_width, center_y + half_height,
+        max_chunks,
+    ).into_iter().collect();
+    let mut current: HashSet<u32> = HashSet::new();
+
+    for row in visible_chunks.client_identity().filter(&client_identity).collect::<Vec<_>>() {
+        if wanted.contains(&row.chunk_index) && current.insert(row.chunk_index) {
+            continue;
+        }
+        visible_chunks.id().delete(row.id); // Out of view (or a duplicate)
+    }
+    for chunk_index in wanted.difference(&current) {
+        visible_chunks.insert(ClientVisibleChunk { id: 0, client_identity, chunk_index: *chunk_index });
+    }
+}
+
+/// Stops streaming chunks to a client (on disconnect)
+pub fn clear_visible_chunks(ctx: &ReducerContext, client_identity: Identity) {
+    let visible_chunks = ctx.db.client_visible_chunk();
+    for row in visible_chunks.client_identity().filter(&client_identity).collect::<Vec<_>>() {
+        visible_chunks.id().delete(row.id);
+    }
+}
@@ -56,6 +56,7 @@ use crate::inventory_management::ItemContainer;
 use crate::environment::calculate_chunk_index;
 use crate::campfire::{Campfire, campfire as CampfireTableTrait};
 use crate::lantern::{Lantern, lantern as LanternTableTrait};
+use crate::building::{apply_structure_damage, StructureRef, StructureDamageType};
 use crate::turret::{Turret, turret as TurretTableTrait, NUM_AMMO_SLOTS};
 use crate::stash::{Stash, stash as StashTableTrait};
 use crate::PrivateMessage;
@@ -90,7 +91,8 @@ use crate::furnace::{furnace as FurnaceTableTrait};
 // Import wild animal types
 use crate::wild_animal_npc::wild_animal as WildAnimalTableTrait;
 // Import animal corpse types
-use crate::wild_animal_npc::animal_corpse::{AnimalCorpse, ANIMAL_CORPSE_COLLISION_Y_OFFSET, animal_corpse as AnimalCorpseTableTrait};
+use crate::wild_animal_npc::animal_corpse::{AnimalCorpse, ButcheringStage, ButcheringTool, ANIMAL_CORPSE_COLLISION_Y_OFFSET, animal_corpse as AnimalCorpseTableTrait};
+use crate::corpse_decay::CorpseDecayStage;
 // Import barrel types
 use crate::barrel::{Barrel, BARREL_COLLISION_Y_OFFSET, BUOY_COLLISION_Y_OFFSET, BUOY_VARIANT, barrel as BarrelTableTrait};
 use crate::homestead_hearth::{HomesteadHearth, HEARTH_COLLISION_Y_OFFSET, homestead_hearth as HomesteadHearthTableTrait};
@@ -98,8 +100,6 @@ use crate::coral::{LivingCoral, LIVING_CORAL_COLLISION_Y_OFFSET, LIVING_CORAL_RA
 // --- Game Balance Constants ---
 /// Time in milliseconds before a dead player can respawn
 pub const RESPAWN_TIME_MS: u64 = 5000; // 5 seconds
-/// Distance player is knocked back in PvP
-pub const PVP_KNOCKBACK_DISTANCE: f32 = 32.0;
 
 // --- PvP Configuration ---
 /// When false, players cannot damage other players with any weapon, projectile, or explosive.
@@ -193,7 +193,7 @@ fn check_for_nearby_allies(ctx: &ReducerContext, player_id: Identity, pos_x: f32
 // --- Combat System Types ---
 
 /// Identifiers for specific combat targets
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TargetId {
     Tree(u64),
     Stone(u64),
@@ -235,6 +235,215 @@ pub struct AttackResult {
     pub resource_granted: Option<(String, u32)>, // (resource_name, amount)
 }
 
+// --- Melee Combos & Heavy Attacks ---
+
+/// Hits on players/animals within this window of the previous one continue the combo
+pub const MELEE_COMBO_WINDOW_SECS: f32 = 1.5;
+/// Every third consecutive hit in a combo deals bonus damage
+pub const MELEE_COMBO_HITS_FOR_BONUS: u8 = 3;
+pub const MELEE_COMBO_DAMAGE_MULTIPLIER: f32 = 1.3;
+
+/// Heavy attacks must be wound up this long before release
+pub const HEAVY_ATTACK_WINDUP_SECS: f32 = 0.8;
+pub const HEAVY_ATTACK_STAMINA_COST: f32 = 20.0;
+pub const HEAVY_ATTACK_DAMAGE_MULTIPLIER: f32 = 1.75;
+/// Axe heavy attacks cleave extra players/animals in the swing arc for a fraction of the damage
+pub const HEAVY_CLEAVE_MAX_EXTRA_TARGETS: usize = 2;
+pub const HEAVY_CLEAVE_DAMAGE_FRACTION: f32 = 0.5;
+
+/// Melee/animal hits landing this soon after a parry are negated
+pub const PARRY_WINDOW_SECS: f32 = 0.35;
+/// Minimum time between parries (so parry can't be spammed as a permanent block)
+pub const PARRY_COOLDOWN_SECS: f32 = 1.5;
+// Stagger durations are tuned live in combat_balance
+
+/// Kind of melee swing being performed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeleeSwing {
+    Light,
+    Heavy,
+}
+
+/// Per-player melee state: combo progress and heavy attack windup
+#[spacetimedb::table(accessor = player_melee_state)]
+#[derive(Clone, Debug)]
+pub struct PlayerMeleeState {
+    #[primary_key]
+    pub player_id: Identity,
+    pub combo_count: u8,                            // Consecutive hits in the current combo
+    pub last_combo_hit_at: Option<Timestamp>,
+    pub heavy_windup_started_at: Option<Timestamp>, // Set by start_heavy_attack, consumed by heavy_attack
+    pub parry_started_at: Option<Timestamp>,        // Last parry activation
+}
+
+fn get_or_default_melee_state(ctx: &ReducerContext, player_id: Identity) -> PlayerMeleeState {
+    ctx.db.player_melee_state().player_id().find(&player_id).unwrap_or(PlayerMeleeState {
+        player_id,
+        combo_count: 0,
+        last_combo_hit_at: None,
+        heavy_windup_started_at: None,
+        parry_started_at: None,
+    })
+}
+
+fn save_melee_state(ctx: &ReducerContext, state: PlayerMeleeState) {
+    let table = ctx.db.player_melee_state();
+    if table.player_id().find(&state.player_id).is_some() {
+        table.player_id().update(state);
+    } else {
+        table.insert(state);
+    }
+}
+
+/// Consecutive hits the player has landed, or 0 if the combo window has lapsed
+fn current_combo_count(state: &PlayerMeleeState, now: Timestamp) -> u8 {
+    match state.last_combo_hit_at.and_then(|t| now.duration_since(t)) {
+        Some(elapsed) if elapsed.as_secs_f32() <= MELEE_COMBO_WINDOW_SECS => state.combo_count,
+        _ => 0,
+    }
+}
+
+/// Damage multiplier for the player's next swing (combo finisher bonus)
+pub fn get_combo_damage_multiplier(ctx: &ReducerContext, player_id: Identity, now: Timestamp) -> f32 {
+    let state = get_or_default_melee_state(ctx, player_id);
+    if current_combo_count(&state, now) + 1 >= MELEE_COMBO_HITS_FOR_BONUS {
+        MELEE_COMBO_DAMAGE_MULTIPLIER
+    } else {
+        1.0
+    }
+}
+
+/// Advances (on a player/animal hit) or resets (on a miss) the player's combo.
+/// The combo restarts after the bonus hit lands.
+pub fn record_combo_swing(ctx: &ReducerContext, player_id: Identity, landed_combat_hit: bool, now: Timestamp) {
+    let mut state = get_or_default_melee_state(ctx, player_id);
+    if landed_combat_hit {
+        let count = current_combo_count(&state, now) + 1;
+        if count >= MELEE_COMBO_HITS_FOR_BONUS {
+            log::debug!("[MeleeCombo] Player {:?} landed a combo finisher", player_id);
+            state.combo_count = 0;
+        } else {
+            state.combo_count = count;
+        }
+        state.last_combo_hit_at = Some(now);
+    } else {
+        state.combo_count = 0;
+        state.last_combo_hit_at = None;
+    }
+    save_melee_state(ctx, state);
+}
+
+/// True if the player activated a parry within the parry window
+pub fn is_parrying(ctx: &ReducerContext, player_id: Identity) -> bool {
+    ctx.db.player_melee_state().player_id().find(&player_id)
+        .and_then(|state| state.parry_started_at)
+        .and_then(|started| ctx.timestamp.duration_since(started))
+        .map(|elapsed| elapsed.as_secs_f32() <= PARRY_WINDOW_SECS)
+        .unwrap_or(false)
+}
+
+/// Raises the equipped melee weapon to parry. Incoming player melee or animal attacks
+/// within PARRY_WINDOW_SECS are negated and the attacker is staggered.
+#[spacetimedb::reducer]
+pub fn parry(ctx: &ReducerContext) -> Result<(), String> {
+    let player_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&player_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    if player.is_dead || player.is_knocked_out || player.is_sleeping || crate::restraints::is_restrained(ctx, player_id) {
+        return Err("Cannot parry right now.".to_string());
+    }
+    if active_effects::player_has_stun_effect(ctx, player_id) || active_effects::player_has_staggered_effect(ctx, player_id) {
+        return Err("Cannot parry while stunned or staggered.".to_string());
+    }
+
+    let item_def = ctx.db.active_equipment().player_identity().find(&player_id)
+        .and_then(|e| e.equipped_item_def_id)
+        .and_then(|def_id| ctx.db.item_definition().id().find(def_id))
+        .ok_or_else(|| "Equip a melee weapon to parry.".to_string())?;
+    if !matches!(item_def.category, ItemCategory::Weapon | ItemCategory::Tool) {
+        return Err("Equip a melee weapon to parry.".to_string());
+    }
+
+    let mut state = get_or_default_melee_state(ctx, player_id);
+    if let Some(elapsed) = state.parry_started_at.and_then(|t| ctx.timestamp.duration_since(t)) {
+        if elapsed.as_secs_f32() < PARRY_COOLDOWN_SECS {
+            return Err("Parry is on cooldown.".to_string());
+        }
+    }
+    state.parry_started_at = Some(ctx.timestamp);
+    save_melee_state(ctx, state);
+    Ok(())
+}
+
+/// Axes get a cleave on heavy attacks
+pub fn is_cleaving_axe(item_def: &ItemDefinition) -> bool {
+    matches!(item_def.name.as_str(), "Stone Hatchet" | "Metal Hatchet" | "Battle Axe")
+}
+
+/// Begins winding up a heavy attack with the equipped melee weapon
+#[spacetimedb::reducer]
+pub fn start_heavy_attack(ctx: &ReducerContext) -> Result<(), String> {
+    let player_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&player_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    if player.is_dead || player.is_knocked_out || player.is_sleeping || crate::restraints::is_restrained(ctx, player_id) {
+        return Err("Cannot attack right now.".to_string());
+    }
+    if player.stamina < HEAVY_ATTACK_STAMINA_COST {
+        return Err("Not enough stamina for a heavy attack.".to_string());
+    }
+
+    let mut state = get_or_default_melee_state(ctx, player_id);
+    state.heavy_windup_started_at = Some(ctx.timestamp);
+    save_melee_state(ctx, state);
+    Ok(())
+}
+
+/// Releases a wound-up heavy attack: costs stamina, hits harder, and cleaves with axes
+#[spacetimedb::reducer]
+pub fn heavy_attack(ctx: &ReducerContext) -> Result<(), String> {
+    let player_id = ctx.sender();
+    let mut player = ctx.db.player().identity().find(&player_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    if player.is_dead || player.is_knocked_out || player.is_sleeping || crate::restraints::is_restrained(ctx, player_id) {
+        return Err("Cannot attack right now.".to_string());
+    }
+
+    // Windup must have been started and held long enough
+    let windup_started_at = get_or_default_melee_state(ctx, player_id).heavy_windup_started_at
+        .ok_or_else(|| "Heavy attack was not wound up.".to_string())?;
+    let held_secs = ctx.timestamp.duration_since(windup_started_at)
+        .map(|d| d.as_secs_f32())
+        .unwrap_or(0.0);
+    if held_secs < HEAVY_ATTACK_WINDUP_SECS {
+        return Err("Heavy attack released too early.".to_string());
+    }
+
+    // Only melee weapons and tools can heavy attack
+    let item_def = ctx.db.active_equipment().player_identity().find(&player_id)
+        .and_then(|e| e.equipped_item_def_id)
+        .and_then(|def_id| ctx.db.item_definition().id().find(def_id))
+        .ok_or_else(|| "No item equipped.".to_string())?;
+    if !matches!(item_def.category, ItemCategory::Weapon | ItemCategory::Tool)
+        || item_def.name == "Blueprint"
+        || item_def.name == "Stone Tiller"
+    {
+        return Err("This item can't perform a heavy attack.".to_string());
+    }
+
+    if player.stamina < HEAVY_ATTACK_STAMINA_COST {
+        return Err("Not enough stamina for a heavy attack.".to_string());
+    }
+    player.stamina -= HEAVY_ATTACK_STAMINA_COST;
+    ctx.db.player().identity().update(player);
+
+    let mut state = get_or_default_melee_state(ctx, player_id);
+    state.heavy_windup_started_at = None;
+    save_melee_state(ctx, state);
+
+    crate::active_equipment::use_equipped_item_with_swing(ctx, MeleeSwing::Heavy)
+}
+
 // --- Direction & Movement Functions ---
 
 /// Calculates player's forward vector based on direction string
@@ -392,6 +601,9 @@ pub fn find_targets_in_cone(
         if other_player.identity == player.identity || other_player.is_dead || !other_player.is_online {
             continue;
         }
+        if other_player.elevation != player.elevation {
+            continue; // Different building level - can't swing through the floor
+        }
         
         let dx = other_player.position_x - player.position_x;
         let dy = other_player.position_y - player.position_y;
@@ -945,11 +1157,15 @@ pub fn find_targets_in_cone(
     // Without this offset, attacks only connect when aiming at the bottom half of the sprite
     const WILD_ANIMAL_TARGET_Y_OFFSET: f32 = 40.0; // Offset to target body center instead of feet
     
-    for wild_animal in ctx.db.wild_animal().iter() {
+    for wild_animal in ctx.db.wild_animal().iter().map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row)) {
         // Skip dead animals or animals that are burrowed
         if wild_animal.health <= 0.0 || wild_animal.state == crate::wild_animal_npc::AnimalState::Burrowed {
             continue;
         }
+        // A ground animal can't be reached from a second floor (and can't reach up to it either)
+        if !crate::wild_animal_npc::core::can_reach_player_elevation(&wild_animal, player) {
+            continue;
+        }
         
         let dx = wild_animal.pos_x - player.position_x;
         // Apply Y offset to target the visual body center, not the feet position
@@ -1547,6 +1763,7 @@ pub fn calculate_damage_and_yield(
             "Repair Hammer",    // For repairing structures, not harvesting
             "Blueprint",        // For building/placing structures
             "Bone Knife",       // Specialized for corpse harvesting only
+            "Metal Cleaver",    // Specialized for butchering only
             "Bandage",          // Medical tool, not for harvesting
             "Torch",            // Light source, not for harvesting
             "Broken Lighter",   // Junk tool - right-click only plays sound, no harvest
@@ -1556,19 +1773,16 @@ pub fn calculate_damage_and_yield(
         
         if !excluded_tools.contains(&item_def.name.as_str()) {
             match target_type {
-                TargetType::Tree => {
-                    // Tools can harvest wood, but at lower efficiency than proper hatchets
-                    // Target: ~40 hits to harvest an average tree (325 resources) for reasonable bootstrapping
-                    // Proper hatchets still get 2-4x more yield/hit AND kill faster, so strong upgrade incentive
-                    let fallback_damage = item_def.primary_target_damage_min.unwrap_or(5) as f32 * 0.5; // 50% of normal damage
-                    let fallback_yield = rng.gen_range(7..=10); // 7-10 wood per hit (vs 16-22 for Stone Hatchet, 30-40 for Metal)
-                    return (fallback_damage, fallback_yield, "Wood".to_string());
-                },
-                TargetType::Stone => {
-                    // Tools can harvest stone, but at lower efficiency than proper pickaxes
-                    let fallback_damage = item_def.primary_target_damage_min.unwrap_or(5) as f32 * 0.5; // 50% of normal damage
-                    let fallback_yield = rng.gen_range(7..=10); // 7-10 stone per hit (vs 35-50 for Stone Pickaxe, 60-85 for Metal)
-                    return (fallback_damage, fallback_yield, "Stone".to_string());
+                TargetType::Tree | TargetType::Stone => {
+                    // Tools outside a dedicated harvest tier gather at Improvised tier
+                    // (~47 hits for an average tree) - proper hatchets/pickaxes kill faster
+                    // AND yield more per hit, so there's a strong upgrade incentive
+                    let tier = crate::items_database::get_harvest_tool_tier(&item_def.name);
+                    if let Some(stats) = crate::items_database::get_tiered_harvest_stats(target_type, tier) {
+                        let fallback_damage = rng.gen_range(stats.damage_min..=stats.damage_max) as f32;
+                        let fallback_yield = rng.gen_range(stats.yield_min..=stats.yield_max);
+                        return (fallback_damage, fallback_yield, stats.resource_name.to_string());
+                    }
                 },
                 _ => {
                     // For other target types, use default behavior
@@ -2005,6 +2219,21 @@ pub fn damage_stone(
     stone.health = stone.health.saturating_sub(damage as u32);
     stone.last_hit_time = Some(timestamp);
     
+    // <<< ORE QUALITY + HOT SPOT: rich veins yield more, hitting the sweet spot yields more still >>>
+    let mut yield_amount = ((yield_amount as f32) * stone::ore_quality_yield_multiplier(stone.quality)).round() as u32;
+    if yield_amount > 0 {
+        let hit_hot_spot = ctx.db.player().identity().find(&attacker_id)
+            .map(|attacker| stone::is_hot_spot_hit(&stone, attacker.position_x, attacker.position_y))
+            .unwrap_or(false);
+        if hit_hot_spot {
+            yield_amount = ((yield_amount as f32) * stone::HOT_SPOT_YIELD_MULTIPLIER).round() as u32;
+            stone.hot_spot_angle = stone::next_hot_spot_angle(stone.hot_spot_angle, rng);
+            log::info!("Player {:?} hit the hot spot on Stone {} - yield boosted to {} (quality {})",
+                attacker_id, stone_id, yield_amount, stone.quality);
+        }
+    }
+    // <<< END ORE QUALITY + HOT SPOT >>>
+    
     // NEW: Resource depletion system - limit yield to remaining resources
     let mut actual_yield = std::cmp::min(yield_amount, stone.resource_remaining);
     
@@ -2478,9 +2707,19 @@ pub fn damage_player(
     }
     // <<< END ALLY DAMAGE BONUS >>>
 
-    // <<< APPLY TYPED ARMOR RESISTANCE >>>
     // Determine damage type from weapon (default to Melee if not specified)
     let damage_type = item_def.damage_type.unwrap_or(DamageType::Melee);
+
+    // <<< SLEEPING TARGET: extra melee damage, and the hit wakes them >>>
+    if target_player.is_sleeping {
+        if damage_type == DamageType::Melee {
+            final_damage *= crate::sleeping_bag::get_sleep_settings(ctx).sleeping_damage_multiplier;
+        }
+        target_player.is_sleeping = false;
+        log::info!("Player {:?} was woken up by an attack from {:?}", target_id, attacker_id);
+    }
+
+    // <<< APPLY TYPED ARMOR RESISTANCE >>>
     let resistance = armor::calculate_resistance_for_damage_type(ctx, target_id, damage_type);
     
     if resistance > 0.0 {
@@ -2514,10 +2753,17 @@ pub fn damage_player(
     target_player.health = (target_player.health - final_damage).clamp(0.0, MAX_HEALTH_VALUE);
     let actual_damage_applied = old_health - target_player.health; // This is essentially final_damage clamped by remaining health
 
+    // Track for death recap
+    let attacker_name = attacker_player_opt.as_ref().map(|p| p.username.clone()).unwrap_or_default();
+    crate::kill_feed::record_damage_event(ctx, target_id, Some(attacker_id), &attacker_name, Some(&item_def.name), actual_damage_applied, target_player.health);
+
     // Update PvP combat timestamps for both players (extends timer if in combat near expiry)
     if actual_damage_applied > 0.0 && attacker_player_opt.is_some() {
         update_pvp_combat_time(ctx, attacker_id, timestamp);
         update_pvp_combat_time(ctx, target_id, timestamp);
+        // Combat tag for the combat logging penalty
+        crate::combat_log::tag_player_in_combat(ctx, attacker_id);
+        crate::combat_log::tag_player_in_combat(ctx, target_id);
     }
 
     // <<< APPLY MELEE DAMAGE REFLECTION (WOODEN ARMOR) >>>
@@ -2533,6 +2779,7 @@ pub fn damage_player(
                     let attacker_old_health = attacker_to_damage.health;
                     attacker_to_damage.health = (attacker_to_damage.health - reflected_damage).clamp(0.0, MAX_HEALTH_VALUE);
                     let attacker_actual_reflected = attacker_old_health - attacker_to_damage.health;
+                    crate::kill_feed::record_damage_event(ctx, attacker_id, Some(target_id), &target_player.username, Some("Reflected Damage"), attacker_actual_reflected, attacker_to_damage.health);
                     
                     log::info!(
                         "Player {:?} reflected {:.1} damage ({:.0}%) back to attacker {:?} (wooden armor reflection)",
@@ -2543,6 +2790,7 @@ pub fn damage_player(
                     if attacker_to_damage.health <= 0.0 && !attacker_to_damage.is_dead {
                         attacker_to_damage.is_dead = true;
                         attacker_to_damage.death_timestamp = Some(timestamp);
+                        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::Player(attacker_to_damage.identity));
                         log::info!("Attacker {:?} killed by reflected damage from {:?}!", attacker_id, target_id);
                         
                         // Drop active weapon on death (before clearing equipment and creating corpse)
@@ -2567,6 +2815,11 @@ pub fn damage_player(
                             Ok(_) => log::info!("Created corpse for attacker {:?} killed by reflected damage", attacker_id),
                             Err(e) => log::error!("Failed to create corpse for attacker {:?}: {}", attacker_id, e),
                         }
+
+                        crate::kill_feed::record_player_death(
+                            ctx, &attacker_to_damage, Some(target_id), &target_player.username,
+                            "Combat", Some("Reflected Damage"), Some((target_player.position_x, target_player.position_y)),
+                        );
                     }
                     
                     players.identity().update(attacker_to_damage);
@@ -2593,6 +2846,7 @@ pub fn damage_player(
             // <<< END KNOCKBACK IMMUNITY CHECK >>>
             
             if should_apply_knockback {
+                let balance = crate::combat_balance::get_config(ctx);
                 let dx_target_from_attacker = target_player.position_x - attacker.position_x;
                 let dy_target_from_attacker = target_player.position_y - attacker.position_y;
                 let distance_sq = dx_target_from_attacker * dx_target_from_attacker + dy_target_from_attacker * dy_target_from_attacker;
@@ -2600,8 +2854,8 @@ pub fn damage_player(
                 if distance_sq > 0.001 { // Avoid division by zero or tiny distances
                     let distance = distance_sq.sqrt();
                     // Knockback for Target
-                    let knockback_dx_target = (dx_target_from_attacker / distance) * PVP_KNOCKBACK_DISTANCE;
-                    let knockback_dy_target = (dy_target_from_attacker / distance) * PVP_KNOCKBACK_DISTANCE;
+                    let knockback_dx_target = (dx_target_from_attacker / distance) * balance.pvp_knockback_distance;
+                    let knockback_dy_target = (dy_target_from_attacker / distance) * balance.pvp_knockback_distance;
                     
                     let current_target_x = target_player.position_x;
                     let current_target_y = target_player.position_y;
@@ -2618,12 +2872,13 @@ pub fn damage_player(
                     );
                     target_player.position_x = final_target_x;
                     target_player.position_y = final_target_y;
+                    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(target_player.identity), target_player.position_x, target_player.position_y);
                     log::debug!("Applied knockback to target player {:?}: new pos ({:.1}, {:.1})", 
                         target_id, target_player.position_x, target_player.position_y);
 
                     // --- MODIFIED: Only apply recoil if it's not a ranged weapon --- 
                     if item_def.category != crate::items::ItemCategory::RangedWeapon {
-                        let attacker_recoil_distance = PVP_KNOCKBACK_DISTANCE / 3.0; 
+                        let attacker_recoil_distance = balance.pvp_attacker_recoil_distance; 
                         let knockback_dx_attacker = (-dx_target_from_attacker / distance) * attacker_recoil_distance; 
                         let knockback_dy_attacker = (-dy_target_from_attacker / distance) * attacker_recoil_distance; 
                         
@@ -2642,6 +2897,7 @@ pub fn damage_player(
                         );
                         attacker.position_x = final_attacker_x;
                         attacker.position_y = final_attacker_y;
+                        crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(attacker.identity), attacker.position_x, attacker.position_y);
                         attacker.last_update = timestamp; 
                         players.identity().update(attacker.clone()); 
                         log::debug!("Applied recoil to attacking player {:?}: new pos ({:.1}, {:.1})", 
@@ -2902,6 +3158,7 @@ pub fn damage_player(
         target_player.knocked_out_at = None;
         target_player.is_dead = true;
         target_player.death_timestamp = Some(timestamp);
+        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::Player(target_player.identity));
         target_player.health = 0.0;
 
         // Cancel any recovery schedule - find by player_id since we don't have schedule_id
@@ -2962,6 +3219,13 @@ pub fn damage_player(
         }
         // --- End DeathMarker ---
 
+        // --- Kill Feed / Death Recap ---
+        let attacker_pos = attacker_player_opt.as_ref().map(|p| (p.position_x, p.position_y));
+        crate::kill_feed::record_player_death(
+            ctx, &target_player, Some(attacker_id), &attacker_name,
+            "Combat", Some(&item_def.name), attacker_pos,
+        );
+
     } else if killed && !target_player.is_knocked_out {
         // Player health reached 0 but they weren't already knocked out - enter knocked out state
         log::info!("[DEBUG] Branch 2: Player {:?} health reached 0, entering knocked out state", target_id);
@@ -3001,14 +3265,13 @@ pub fn damage_player(
     })
 }
 
-/// Applies damage to a lantern and handles destruction/item scattering
+/// Applies a player's weapon hit to a lantern or ward (or repairs it when holding a repair hammer)
 pub fn damage_lantern(
     ctx: &ReducerContext,
     attacker_id: Identity,
     lantern_id: u32,
     damage: f32,
     timestamp: Timestamp,
-    rng: &mut impl Rng // Added RNG for item scattering
 ) -> Result<AttackResult, String> {
     // Check if the attacker is using a repair hammer
     if let Some(active_equip) = ctx.db.active_equipment().player_identity().find(attacker_id) {
@@ -3024,107 +3287,17 @@ pub fn damage_lantern(
         }
     }
 
-    // Original damage logic if not using repair hammer
-    let mut lanterns_table = ctx.db.lantern();
-    let mut lantern = lanterns_table.id().find(lantern_id)
-        .ok_or_else(|| format!("Target lantern {} disappeared", lantern_id))?;
-
-    if lantern.is_destroyed {
-        return Ok(AttackResult { hit: false, target_type: Some(TargetType::Lantern), resource_granted: None });
-    }
-
-    // <<< PVP RAIDING CHECK >>>
-    if let Some(attacker_player) = ctx.db.player().identity().find(attacker_id) {
-        let attacker_pvp = is_pvp_active_for_player(&attacker_player, timestamp);
-        
-        if lantern.placed_by != attacker_id {
-            if let Some(owner_player) = ctx.db.player().identity().find(&lantern.placed_by) {
-                let owner_pvp = is_pvp_active_for_player(&owner_player, timestamp);
-                
-                if !attacker_pvp || !owner_pvp {
-                    log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", 
-                        attacker_pvp, owner_pvp);
-                    return Ok(AttackResult { hit: false, target_type: Some(TargetType::Lantern), resource_granted: None });
-                }
-            }
-        }
-    }
-    // <<< END PVP RAIDING CHECK >>>
-
-    let old_health = lantern.health;
-    lantern.health = (lantern.health - damage).max(0.0);
-    lantern.last_hit_time = Some(timestamp);
-    lantern.last_damaged_by = Some(attacker_id);
-
-    log::info!(
-        "Player {:?} hit Lantern {} for {:.1} damage. Health: {:.1} -> {:.1}",
-        attacker_id, lantern_id, damage, old_health, lantern.health
-    );
-
-    // Play hit sound for all hits
-    sound_events::emit_barrel_hit_sound(ctx, lantern.pos_x, lantern.pos_y, attacker_id);
-
-    if lantern.health <= 0.0 {
-        // Play destroyed sound
-        sound_events::emit_barrel_destroyed_sound(ctx, lantern.pos_x, lantern.pos_y, attacker_id);
-        lantern.is_destroyed = true;
-        lantern.destroyed_at = Some(timestamp);
-        
-        // 🔊 Stop lantern sound when destroyed
-        if lantern.is_burning {
-            crate::sound_events::stop_lantern_sound(ctx, lantern.id as u64);
-        }
-        
-        // Scatter items
-        let mut items_to_drop: Vec<(u64, u32)> = Vec::new(); // (item_def_id, quantity)
-        for i in 0..crate::lantern::NUM_FUEL_SLOTS {
-            if let (Some(instance_id), Some(def_id)) = (lantern.get_slot_instance_id(i as u8), lantern.get_slot_def_id(i as u8)) {
-                if let Some(item) = ctx.db.inventory_item().instance_id().find(instance_id) {
-                    items_to_drop.push((def_id, item.quantity));
-                    // Delete the InventoryItem from the central table
-                    ctx.db.inventory_item().instance_id().delete(instance_id);
-                }
-                lantern.set_slot(i as u8, None, None); // Clear slot in lantern struct (though it's about to be deleted)
-            }
-        }
-
-        // Update the lantern one last time to ensure is_destroyed and destroyed_at are sent to client
-        lanterns_table.id().update(lantern.clone()); 
-        // Then immediately delete the lantern entity itself
-        lanterns_table.id().delete(lantern_id);
-
-        log::info!(
-            "Lantern {} destroyed by player {:?}. Dropping items.",
-            lantern_id, attacker_id
-        );
-
-        // Scatter collected items around the lantern's location WITHOUT triggering consolidation per-item
-        for (item_def_id, quantity) in items_to_drop {
-            // Spawn slightly offset from lantern center
-            let offset_x = (rng.gen::<f32>() - 0.5) * 2.0 * 15.0; // Spread within +/- 15px (smaller than campfire)
-            let offset_y = (rng.gen::<f32>() - 0.5) * 2.0 * 15.0;
-            let drop_pos_x = lantern.pos_x + offset_x;
-            let drop_pos_y = lantern.pos_y + offset_y;
-
-            match dropped_item::create_dropped_item_entity_no_consolidation(ctx, item_def_id, quantity, drop_pos_x, drop_pos_y) {
-                Ok(_) => log::debug!("Dropped {} of item_def_id {} from destroyed lantern {}", quantity, item_def_id, lantern_id),
-                Err(e) => log::error!("Failed to drop item_def_id {}: {}", item_def_id, e),
-            }
+    match crate::building::apply_structure_damage(ctx, StructureRef::Lantern(lantern_id), damage, StructureDamageType::Weapon, Some(attacker_id)) {
+        Ok(_) => Ok(AttackResult {
+            hit: true,
+            target_type: Some(TargetType::Lantern),
+            resource_granted: None,
+        }),
+        Err(e) => {
+            log::debug!("Lantern {} not damaged: {}", lantern_id, e);
+            Ok(AttackResult { hit: false, target_type: Some(TargetType::Lantern), resource_granted: None })
         }
-        
-        // Trigger consolidation ONCE after all items are dropped
-        dropped_item::trigger_consolidation_at_position(ctx, lantern.pos_x, lantern.pos_y);
-
-    } else {
-        // Lantern still has health, just update it
-        lanterns_table.id().update(lantern);
     }
-
-    Ok(AttackResult {
-        hit: true,
-        target_type: Some(TargetType::Lantern),
-        resource_granted: None,
-    })
 }
 
 /// Applies damage to a turret and handles destruction/item scattering
@@ -3499,6 +3672,11 @@ pub fn damage_wooden_storage_box(
         attacker_id, box_id, damage, old_health, wooden_box.health
     );
 
+    // Angry bees defend a player beehive that still has its colony
+    if wooden_box.box_type == crate::wooden_storage_box::BOX_TYPE_PLAYER_BEEHIVE {
+        crate::beehive::rouse_beehive_defenders(ctx, &wooden_box, attacker_id);
+    }
+
     // Play hit sound based on box type
     // Normal and large wooden storage boxes use wood hit sound
     if wooden_box.box_type == crate::wooden_storage_box::BOX_TYPE_NORMAL ||
@@ -3524,6 +3702,11 @@ pub fn damage_wooden_storage_box(
             sound_events::stop_beehive_sound(ctx, box_id as u64);
         }
 
+        // Let any stored animals out of a destroyed kennel
+        if crate::kennel::is_kennel(wooden_box.box_type) {
+            crate::kennel::release_all_from_kennel(ctx, &wooden_box);
+        }
+
         let mut items_to_drop: Vec<(u64, u32)> = Vec::new();
         for i in 0..crate::wooden_storage_box::NUM_BOX_SLOTS {
             if let (Some(instance_id), Some(def_id)) = (wooden_box.get_slot_instance_id(i as u8), wooden_box.get_slot_def_id(i as u8)) {
@@ -3731,6 +3914,7 @@ pub fn damage_sleeping_bag(
         
         bags_table.id().update(bag.clone()); 
         bags_table.id().delete(bag_id);
+        crate::respawn_points::sync_respawn_points(ctx, bag.placed_by);
 
         log::info!(
             "SleepingBag {} destroyed by player {:?}.",
@@ -3900,12 +4084,16 @@ pub fn damage_player_corpse(
         }
     };
 
+    // Decaying corpses give less fat and flesh; a bone pile only gives bones
+    let decay_stage = corpse.decay_stage;
+    let is_bone_pile = decay_stage == CorpseDecayStage::Bones;
+
     // Example: 50% chance to get Animal Fat per hit, if corpse still has health
     // Apply logarithmic bonus based on time alive
-    if corpse.health > 0 && rng.gen_bool(actual_chance_fat) {
+    if corpse.health > 0 && !is_bone_pile && rng.gen_bool(actual_chance_fat) {
         let base_fat = quantity_per_successful_hit;
         let time_alive_bonus = calculate_fat_bonus_from_time_alive(corpse.spawned_at, corpse.death_time);
-        let total_fat = base_fat + time_alive_bonus;
+        let total_fat = crate::corpse_decay::apply_decay_to_yield(decay_stage, base_fat + time_alive_bonus);
         
         log::debug!(
             "[DamagePlayerCorpse:{}] Time alive bonus: {} (base: {}, total: {})",
@@ -3919,15 +4107,22 @@ pub fn damage_player_corpse(
     }
 
     // Example: 30% chance to get 1 Raw Human Flesh per hit
-    if corpse.health > 0 && rng.gen_bool(actual_chance_flesh) {
-        match grant_resource(ctx, attacker_id, "Raw Human Flesh", quantity_per_successful_hit) {
-            Ok(_) => resources_granted.push(("Raw Human Flesh".to_string(), quantity_per_successful_hit)),
+    if corpse.health > 0 && !is_bone_pile && rng.gen_bool(actual_chance_flesh) {
+        let flesh = crate::corpse_decay::apply_decay_to_yield(decay_stage, quantity_per_successful_hit);
+        match grant_resource(ctx, attacker_id, "Raw Human Flesh", flesh) {
+            Ok(_) => resources_granted.push(("Raw Human Flesh".to_string(), flesh)),
             Err(e) => log::error!("Failed to grant Raw Human Flesh: {}", e),
         }
     }
     
-    // Example: 20% chance to get 1 Animal Bone per hit
-    if corpse.health > 0 && rng.gen_bool(actual_chance_bone) {
+    if is_bone_pile {
+        let bones = crate::bones::roll_bone_pile_loot(rng, quantity_per_successful_hit);
+        match grant_resource(ctx, attacker_id, crate::bones::BONE_PILE_ITEM_NAME, bones) {
+            Ok(_) => resources_granted.push((crate::bones::BONE_PILE_ITEM_NAME.to_string(), bones)),
+            Err(e) => log::error!("Failed to grant bone pile bones: {}", e),
+        }
+    } else if corpse.health > 0 && rng.gen_bool(actual_chance_bone) {
+        // Example: 20% chance to get 1 Animal Bone per hit
         match grant_resource(ctx, attacker_id, "Animal Bone", quantity_per_successful_hit) {
             Ok(_) => resources_granted.push(("Animal Bone".to_string(), quantity_per_successful_hit)),
             Err(e) => log::error!("Failed to grant Animal Bone: {}", e),
@@ -4059,6 +4254,7 @@ pub fn process_attack(
     target: &Target,
     item_def: &ItemDefinition,
     timestamp: Timestamp,
+    damage_multiplier: f32, // Heavy attack / combo scaling (1.0 for a normal swing)
     rng: &mut impl Rng
 ) -> Result<AttackResult, String> {
     // NEW: Check line of sight before processing any attack
@@ -4167,7 +4363,8 @@ pub fn process_attack(
         TargetId::WildAnimal(animal_id) => {
             use crate::wild_animal_npc::wild_animal as WildAnimalTableTrait;
             if let Some(animal) = ctx.db.wild_animal().id().find(animal_id) {
-                (animal.pos_x, animal.pos_y, None)
+                let (pos_x, pos_y) = crate::wild_animal_npc::transform_batch::live_position(ctx, &animal);
+                (pos_x, pos_y, None)
             } else {
                 return Err("Target wild animal not found".to_string());
             }
@@ -4286,13 +4483,7 @@ pub fn process_attack(
         let (damage, _, _) = calculate_damage_and_yield(item_def, TargetType::Wall, rng);
         
         // Apply damage to the wall
-        match crate::building::damage_wall(
-            ctx,
-            attacker_id,
-            wall_id,
-            damage,
-            timestamp,
-        ) {
+        match apply_structure_damage(ctx, StructureRef::Wall(wall_id), damage, StructureDamageType::Weapon, Some(attacker_id)) {
             Ok(_) => {
                 log::info!(
                     "[ProcessAttack] Melee attack dealt {:.1} damage to Wall {}",
@@ -4353,13 +4544,7 @@ pub fn process_attack(
         let (damage, _, _) = calculate_damage_and_yield(item_def, TargetType::Wall, rng);
         
         // Apply damage to the fence
-        match crate::fence::damage_fence(
-            ctx,
-            attacker_id,
-            fence_id,
-            damage,
-            timestamp,
-        ) {
+        match apply_structure_damage(ctx, StructureRef::Fence(fence_id), damage, StructureDamageType::Weapon, Some(attacker_id)) {
             Ok(_) => {
                 log::info!(
                     "[ProcessAttack] Melee attack dealt {:.1} damage to Fence {}",
@@ -4455,6 +4640,7 @@ pub fn process_attack(
             log::info!("[HotCombatLadle] 2x wildlife damage: {:.1} (attacker {:?})", damage, attacker_id);
         }
     }
+    damage *= damage_multiplier;
 
     match &target.id {
         TargetId::Tree(tree_id) => {
@@ -4464,13 +4650,21 @@ pub fn process_attack(
             damage_stone(ctx, attacker_id, *stone_id, damage, yield_amount, &resource_name, timestamp, rng)
         },
         TargetId::Player(player_id) => {
+            // Parried swings deal no damage and stagger the attacker
+            if is_parrying(ctx, *player_id) {
+                log::info!("[Parry] Player {:?} parried {:?}'s {}", player_id, attacker_id, item_def.name);
+                if let Err(e) = active_effects::apply_staggered_effect(ctx, attacker_id, crate::combat_balance::get_config(ctx).player_stagger_secs) {
+                    log::error!("[Parry] Failed to stagger attacker {:?}: {}", attacker_id, e);
+                }
+                return Ok(AttackResult { hit: false, target_type: Some(TargetType::Player), resource_granted: None });
+            }
             damage_player(ctx, attacker_id, *player_id, damage, item_def, timestamp)
         },
         TargetId::Campfire(campfire_id) => {
             damage_campfire(ctx, attacker_id, *campfire_id, damage, timestamp, rng)
         },
         TargetId::Lantern(lantern_id) => {
-            damage_lantern(ctx, attacker_id, *lantern_id, damage, timestamp, rng)
+            damage_lantern(ctx, attacker_id, *lantern_id, damage, timestamp)
         },
         TargetId::WoodenStorageBox(box_id) => {
             damage_wooden_storage_box(ctx, attacker_id, *box_id, damage, timestamp, rng)
@@ -4501,7 +4695,7 @@ pub fn process_attack(
             }
         },
         TargetId::Shelter(shelter_id) => {
-            crate::shelter::damage_shelter(ctx, attacker_id, *shelter_id, damage, timestamp, rng)
+            crate::shelter::damage_shelter(ctx, attacker_id, *shelter_id, damage, timestamp)
         },
         TargetId::RainCollector(rain_collector_id) => {
             damage_rain_collector(ctx, attacker_id, *rain_collector_id, damage, timestamp, rng)
@@ -4533,8 +4727,7 @@ pub fn process_attack(
                 })
         },
         TargetId::HomesteadHearth(hearth_id) => {
-            // Use the damage_hearth helper function (called from combat system)
-            crate::homestead_hearth::damage_hearth(ctx, attacker_id, *hearth_id, damage, timestamp)
+            apply_structure_damage(ctx, StructureRef::Hearth(*hearth_id), damage, StructureDamageType::Weapon, Some(attacker_id))
                 .map(|_| AttackResult {
                     hit: true,
                     target_type: Some(TargetType::HomesteadHearth),
@@ -4557,7 +4750,7 @@ pub fn process_attack(
             }
             
             // Direct wall attack - damage the targeted wall
-            crate::building::damage_wall(ctx, attacker_id, *wall_id, damage, timestamp)
+            apply_structure_damage(ctx, StructureRef::Wall(*wall_id), damage, StructureDamageType::Weapon, Some(attacker_id))
                 .map(|_| AttackResult {
                     hit: true,
                     target_type: Some(TargetType::Wall),
@@ -4566,7 +4759,7 @@ pub fn process_attack(
         },
         TargetId::Door(door_id) => {
             // Direct door attack - damage the targeted door
-            crate::door::damage_door(ctx, attacker_id, *door_id, damage, timestamp)
+            apply_structure_damage(ctx, StructureRef::Door(*door_id), damage, StructureDamageType::Weapon, Some(attacker_id))
                 .map(|_| AttackResult {
                     hit: true,
                     target_type: Some(TargetType::Door),
@@ -4589,7 +4782,7 @@ pub fn process_attack(
             }
             
             // Direct fence attack - damage the targeted fence
-            crate::fence::damage_fence(ctx, attacker_id, *fence_id, damage, timestamp)
+            apply_structure_damage(ctx, StructureRef::Fence(*fence_id), damage, StructureDamageType::Weapon, Some(attacker_id))
                 .map(|_| AttackResult {
                     hit: true,
                     target_type: Some(TargetType::Wall), // Use Wall target type for consistency
@@ -4793,16 +4986,13 @@ pub fn damage_animal_corpse(
 
     let mut resources_granted: Vec<(String, u32)> = Vec::new();
 
-    // Get animal-specific loot chances
-    let (base_fat_chance, base_cloth_chance, base_bone_chance, base_meat_chance) = 
-        crate::wild_animal_npc::animal_corpse::get_animal_loot_chances(animal_corpse.animal_species);
-
     // Determine tool effectiveness
     const BONE_KNIFE_MULTIPLIER: f64 = 8.0;
     const BONE_CLUB_MULTIPLIER: f64 = 3.0;
     const MACHETE_MULTIPLIER: f64 = 7.0; // High effectiveness for sharp cutting tool
     const AK74_BAYONET_MULTIPLIER: f64 = 10.0; // Highest effectiveness for modern military bayonet
     const TIDEBREAKER_BLADE_MULTIPLIER: f64 = 9.0; // High effectiveness for underwater harvesting
+    const METAL_CLEAVER_MULTIPLIER: f64 = 8.0; // Heavy butchering tool (pelts are ruined - see ButcheringTool)
     const PRIMARY_CORPSE_TOOL_MULTIPLIER: f64 = 1.0;
     const NON_PRIMARY_ITEM_MULTIPLIER: f64 = 0.4; // Increased from 0.1 to 0.4 - allows new players to harvest basic resources
 
@@ -4812,6 +5002,7 @@ pub fn damage_animal_corpse(
         "Bone Club" => BONE_CLUB_MULTIPLIER,
         "Bush Knife" => MACHETE_MULTIPLIER,
         "Tidebreaker Blade" => TIDEBREAKER_BLADE_MULTIPLIER,
+        "Metal Cleaver" => METAL_CLEAVER_MULTIPLIER,
         _ => {
             if item_def.primary_target_type == Some(TargetType::AnimalCorpse) {
                 PRIMARY_CORPSE_TOOL_MULTIPLIER
@@ -4821,23 +5012,10 @@ pub fn damage_animal_corpse(
         }
     };
 
-    // Calculate actual chances based on tool effectiveness
-    // For non-primary tools, ensure minimum 10% chance for basic resources (fat, bone, meat, leather)
+    // Chances are capped at the loot table's base chance; non-primary tools get a minimum
+    // 10% chance for basic resources (fat, bone, meat, leather)
     const MIN_BASIC_RESOURCE_CHANCE: f64 = 0.10; // 10% minimum chance for basic resources
     let is_non_primary_tool = effectiveness_multiplier == NON_PRIMARY_ITEM_MULTIPLIER;
-    
-    let mut actual_fat_chance = (base_fat_chance * effectiveness_multiplier).clamp(0.0, base_fat_chance);
-    let mut actual_cloth_chance = (base_cloth_chance * effectiveness_multiplier).clamp(0.0, base_cloth_chance);
-    let mut actual_bone_chance = (base_bone_chance * effectiveness_multiplier).clamp(0.0, base_bone_chance);
-    let mut actual_meat_chance = (base_meat_chance * effectiveness_multiplier).clamp(0.0, base_meat_chance);
-    
-    // Apply minimum floor for basic resources when using non-primary tools
-    if is_non_primary_tool {
-        actual_fat_chance = actual_fat_chance.max(MIN_BASIC_RESOURCE_CHANCE);
-        actual_bone_chance = actual_bone_chance.max(MIN_BASIC_RESOURCE_CHANCE);
-        actual_meat_chance = actual_meat_chance.max(MIN_BASIC_RESOURCE_CHANCE);
-        // Cloth/leather don't get minimum floor as they're more specialized
-    }
 
     // Determine quantity based on tool
     let quantity_per_hit = match item_def.name.as_str() {
@@ -4846,6 +5024,7 @@ pub fn damage_animal_corpse(
         "Bone Club" => rng.gen_range(2..=4),
         "Bush Knife" => rng.gen_range(1..=3),
         "Tidebreaker Blade" => rng.gen_range(3..=6), // Good yield for underwater harvesting
+        "Metal Cleaver" => rng.gen_range(3..=5),
         _ => {
             if item_def.primary_target_type == Some(TargetType::AnimalCorpse) && item_def.category == ItemCategory::Tool {
                 rng.gen_range(1..=2)
@@ -4870,234 +5049,106 @@ pub fn damage_animal_corpse(
             (1.0, 1.0, 1.0) // Non-caribou get full drops
         };
     
-    // Grant resources based on RNG and animal type
-    // Apply logarithmic bonus based on time alive
-    // Note: Crabs don't drop Animal Fat - they have exoskeletons, not fat reserves
-    if animal_corpse.health > 0 
-        && animal_corpse.animal_species != crate::wild_animal_npc::AnimalSpecies::BeachCrab
-        && rng.gen_bool(actual_fat_chance) 
-    {
-        let base_fat = quantity_per_hit;
-        let time_alive_bonus = calculate_fat_bonus_from_time_alive(animal_corpse.spawned_at, animal_corpse.death_time);
-        // Apply caribou age multiplier for fat
-        let total_fat = ((base_fat + time_alive_bonus) as f32 * caribou_fat_mult).round() as u32;
-        
-        if total_fat > 0 {
-            log::debug!(
-                "[DamageAnimalCorpse:{}] Time alive bonus: {} (base: {}, total: {})",
-                animal_corpse_id, time_alive_bonus, base_fat, total_fat
-            );
-            
-            match grant_resource(ctx, attacker_id, "Animal Fat", total_fat) {
-                Ok(_) => resources_granted.push(("Animal Fat".to_string(), total_fat)),
-                Err(e) => log::error!("Failed to grant Animal Fat: {}", e),
-            }
-        }
-    }
-
-    if animal_corpse.health > 0 && rng.gen_bool(actual_cloth_chance) {
-        let cloth_type = match animal_corpse.animal_species {
-            crate::wild_animal_npc::AnimalSpecies::CinderFox => Some("Fox Fur"),
-            crate::wild_animal_npc::AnimalSpecies::TundraWolf => Some("Wolf Fur"),
-            crate::wild_animal_npc::AnimalSpecies::CableViper => Some("Viper Scale"),
-            crate::wild_animal_npc::AnimalSpecies::ArcticWalrus => None, // Walrus doesn't drop cloth-type resources
-            crate::wild_animal_npc::AnimalSpecies::BeachCrab => None, // Crabs don't drop fur/cloth - they have shells
-            crate::wild_animal_npc::AnimalSpecies::Tern => Some("Tern Feathers"), // Terns drop feathers
-            crate::wild_animal_npc::AnimalSpecies::Crow => Some("Crow Feathers"), // Crows drop feathers
-            crate::wild_animal_npc::AnimalSpecies::Vole => None, // Voles are too small for usable fur
-            crate::wild_animal_npc::AnimalSpecies::Wolverine => None, // Wolverines drop Animal Leather instead
-            crate::wild_animal_npc::AnimalSpecies::Caribou => None, // Caribou drop warm hides
-            // SalmonShark - no cloth (sharks have skin, not fur)
-            crate::wild_animal_npc::AnimalSpecies::SalmonShark => None,
-            // Jellyfish - drops jellyfish membrane
-            crate::wild_animal_npc::AnimalSpecies::Jellyfish => Some("Jellyfish Membrane"),
-            // Night hostile NPCs don't drop items - they grant memory shards instead
-            crate::wild_animal_npc::AnimalSpecies::Shorebound | 
-            crate::wild_animal_npc::AnimalSpecies::Shardkin | 
-            crate::wild_animal_npc::AnimalSpecies::DrownedWatch => None,
-            // Bees don't drop cloth - they're tiny insects
-            crate::wild_animal_npc::AnimalSpecies::Bee => None,
-            // Alpine animals - no special cloth/fur per user request
-            crate::wild_animal_npc::AnimalSpecies::PolarBear => None,
-            crate::wild_animal_npc::AnimalSpecies::Hare => None,
-            crate::wild_animal_npc::AnimalSpecies::SnowyOwl => None,
+    // A bone pile (fully decayed corpse) only gives bones
+    let decay_stage = animal_corpse.decay_stage;
+    if decay_stage == CorpseDecayStage::Bones {
+        let bones = crate::bones::roll_bone_pile_loot(rng, quantity_per_hit);
+        match grant_resource(ctx, attacker_id, crate::bones::BONE_PILE_ITEM_NAME, bones) {
+            Ok(_) => resources_granted.push((crate::bones::BONE_PILE_ITEM_NAME.to_string(), bones)),
+            Err(e) => log::error!("Failed to grant bone pile bones: {}", e),
+        }
+    }
+
+    // Butcher in stages: pelt first, then meat and fat, then bones. The tool decides what comes off intact.
+    let source_key = crate::wild_animal_npc::animal_corpse::animal_corpse_harvest_source_key(animal_corpse.animal_species);
+    let harvest_entries = crate::loot_table::get_loot_entries(ctx, &source_key);
+    let butchering_tool = ButcheringTool::from_item_name(&item_def.name);
+    let mut butchering_stage = animal_corpse.butchering_stage;
+    if butchering_stage == ButcheringStage::Skinning {
+        let has_pelt = harvest_entries.iter().any(|entry| ButcheringStage::Skinning.yields_tag(&entry.tag));
+        if !has_pelt || !butchering_tool.keeps_pelts_intact() {
+            // Nothing to skin, or the tool hacks straight through the hide - the pelt is lost
+            log::debug!("[DamageAnimalCorpse:{}] Skipping skinning ({} with {:?} tool)",
+                       animal_corpse_id, if has_pelt { "pelt ruined" } else { "no pelt" }, butchering_tool);
+            butchering_stage = ButcheringStage::Butchering;
+        }
+    }
+
+    // Roll the species' harvest loot for the current butchering stage (tunable via the loot_table subsystem)
+    // Apply logarithmic bonus based on time alive to fat, and caribou age multipliers to fat/bone/meat
+    // Decaying corpses give less of everything except bone
+    if animal_corpse.health > 0 && decay_stage != CorpseDecayStage::Bones {
+        use crate::wild_animal_npc::animal_corpse::{
+            CORPSE_LOOT_TAG_FAT, CORPSE_LOOT_TAG_CLOTH, CORPSE_LOOT_TAG_BONE, CORPSE_LOOT_TAG_MEAT,
         };
-        
-        if let Some(cloth_name) = cloth_type {
-            match grant_resource(ctx, attacker_id, cloth_name, quantity_per_hit) {
-                Ok(_) => resources_granted.push((cloth_name.to_string(), quantity_per_hit)),
-                Err(e) => log::error!("Failed to grant {}: {}", cloth_name, e),
+        for entry in harvest_entries {
+            if !butchering_stage.yields_tag(&entry.tag) {
+                continue;
+            }
+            let base_chance = (entry.drop_chance as f64).clamp(0.0, 1.0);
+            let mut chance = (base_chance * effectiveness_multiplier).clamp(0.0, base_chance);
+            // Cloth/fur doesn't get the minimum floor as it's more specialized
+            if is_non_primary_tool && entry.tag != CORPSE_LOOT_TAG_CLOTH {
+                chance = chance.max(MIN_BASIC_RESOURCE_CHANCE);
+            }
+            if !rng.gen_bool(chance) {
+                continue;
             }
-        }
-    }
-
-    // Universal Animal Leather drop for most animals (like Animal Fat/Bone)
-    // Note: Crabs don't drop Animal Leather - they have shells/carapace instead
-    let mut animal_leather_chance = (0.40 * effectiveness_multiplier).clamp(0.0, 0.40); // 40% base chance
-    // Apply minimum floor for animal leather when using non-primary tools
-    if is_non_primary_tool {
-        animal_leather_chance = animal_leather_chance.max(MIN_BASIC_RESOURCE_CHANCE);
-    }
-    if animal_corpse.health > 0 
-        && animal_corpse.animal_species != crate::wild_animal_npc::AnimalSpecies::BeachCrab
-        && rng.gen_bool(animal_leather_chance) 
-    {
-        match grant_resource(ctx, attacker_id, "Animal Leather", quantity_per_hit) {
-            Ok(_) => resources_granted.push(("Animal Leather".to_string(), quantity_per_hit)),
-            Err(e) => log::error!("Failed to grant Animal Leather: {}", e),
-        }
-    }
 
-    // Note: Crabs don't drop Animal Bone - they have exoskeletons, not internal bones
-    if animal_corpse.health > 0 
-        && animal_corpse.animal_species != crate::wild_animal_npc::AnimalSpecies::BeachCrab
-        && rng.gen_bool(actual_bone_chance) 
-    {
-        // Apply caribou age multiplier for bones
-        let bone_quantity = (quantity_per_hit as f32 * caribou_bone_mult).round() as u32;
-        if bone_quantity > 0 {
-            match grant_resource(ctx, attacker_id, "Animal Bone", bone_quantity) {
-                Ok(_) => resources_granted.push(("Animal Bone".to_string(), bone_quantity)),
-                Err(e) => log::error!("Failed to grant Animal Bone: {}", e),
-            }
-        }
-    }
-
-    if animal_corpse.health > 0 && rng.gen_bool(actual_meat_chance) {
-        let meat_type: Option<&str> = match animal_corpse.animal_species {
-            crate::wild_animal_npc::AnimalSpecies::CinderFox => Some("Raw Fox Meat"),
-            crate::wild_animal_npc::AnimalSpecies::TundraWolf => Some("Raw Wolf Meat"),
-            crate::wild_animal_npc::AnimalSpecies::CableViper => Some("Raw Viper Meat"),
-            crate::wild_animal_npc::AnimalSpecies::ArcticWalrus => Some("Raw Walrus Meat"),
-            crate::wild_animal_npc::AnimalSpecies::BeachCrab => Some("Raw Crab Meat"),
-            crate::wild_animal_npc::AnimalSpecies::Tern => Some("Raw Tern Meat"),
-            crate::wild_animal_npc::AnimalSpecies::Crow => Some("Raw Crow Meat"),
-            crate::wild_animal_npc::AnimalSpecies::Vole => Some("Raw Vole Meat"),
-            crate::wild_animal_npc::AnimalSpecies::Wolverine => Some("Raw Wolverine Meat"),
-            crate::wild_animal_npc::AnimalSpecies::Caribou => Some("Raw Caribou Meat"),
-            // SalmonShark - drops shark meat
-            crate::wild_animal_npc::AnimalSpecies::SalmonShark => Some("Raw Shark Meat"),
-            // Jellyfish - drops jellyfish gel instead of meat
-            crate::wild_animal_npc::AnimalSpecies::Jellyfish => Some("Jellyfish Gel"),
-            // Night hostile NPCs don't drop items
-            crate::wild_animal_npc::AnimalSpecies::Shorebound | 
-            crate::wild_animal_npc::AnimalSpecies::Shardkin | 
-            crate::wild_animal_npc::AnimalSpecies::DrownedWatch => None,
-            // Bees don't drop meat - they're tiny insects
-            crate::wild_animal_npc::AnimalSpecies::Bee => None,
-            // Alpine animals
-            crate::wild_animal_npc::AnimalSpecies::PolarBear => Some("Raw Bear Meat"),
-            crate::wild_animal_npc::AnimalSpecies::Hare => Some("Raw Hare Meat"),
-            crate::wild_animal_npc::AnimalSpecies::SnowyOwl => Some("Raw Owl Meat"),
-        };
-        if let Some(meat_name) = meat_type {
-            // Apply caribou age multiplier for meat
-            let meat_quantity = (quantity_per_hit as f32 * caribou_meat_mult).round() as u32;
-            if meat_quantity > 0 {
-                match grant_resource(ctx, attacker_id, meat_name, meat_quantity) {
-                    Ok(_) => resources_granted.push((meat_name.to_string(), meat_quantity)),
-                    Err(e) => log::error!("Failed to grant {}: {}", meat_name, e),
+            let base_quantity = quantity_per_hit * crate::loot_table::roll_entry_quantity(ctx, &entry);
+            let quantity = match entry.tag.as_str() {
+                CORPSE_LOOT_TAG_FAT => {
+                    let time_alive_bonus = calculate_fat_bonus_from_time_alive(animal_corpse.spawned_at, animal_corpse.death_time);
+                    log::debug!(
+                        "[DamageAnimalCorpse:{}] Time alive bonus: {} (base: {})",
+                        animal_corpse_id, time_alive_bonus, base_quantity
+                    );
+                    ((base_quantity + time_alive_bonus) as f32 * caribou_fat_mult).round() as u32
                 }
+                CORPSE_LOOT_TAG_BONE => (base_quantity as f32 * caribou_bone_mult).round() as u32,
+                CORPSE_LOOT_TAG_MEAT => (base_quantity as f32 * caribou_meat_mult).round() as u32,
+                _ => base_quantity,
+            };
+            let quantity = (quantity as f32 * butchering_tool.yield_multiplier(&entry.tag)).round() as u32;
+            let quantity = if entry.tag == CORPSE_LOOT_TAG_BONE {
+                quantity
+            } else {
+                crate::corpse_decay::apply_decay_to_yield(decay_stage, quantity)
+            };
+            if quantity == 0 {
+                continue;
+            }
+
+            match grant_resource(ctx, attacker_id, &entry.item_name, quantity) {
+                Ok(_) => resources_granted.push((entry.item_name.clone(), quantity)),
+                Err(e) => log::error!("Failed to grant {}: {}", entry.item_name, e),
             }
         }
     }
 
+    // Stages only move forward - harvesting down the corpse's health moves on to the next one
+    let stage_for_health = ButcheringStage::for_health(animal_corpse.health, animal_corpse.max_health);
+    animal_corpse.butchering_stage = if stage_for_health > butchering_stage { stage_for_health } else { butchering_stage };
+
     if animal_corpse.health == 0 {
         log::info!("[DamageAnimalCorpse:{}] Animal corpse depleted by Player {:?} using item {} (category {:?}, multiplier {:.1})", 
                  animal_corpse_id, attacker_id, item_def.name, item_def.category, effectiveness_multiplier);
         
-        // Grant 1 skull when corpse is depleted, regardless of tool used (like player corpses)
-        // Note: Crabs and birds don't drop skulls
-        let skull_type: Option<&str> = match animal_corpse.animal_species {
-            crate::wild_animal_npc::AnimalSpecies::CinderFox => Some("Fox Skull"),
-            crate::wild_animal_npc::AnimalSpecies::TundraWolf => Some("Wolf Skull"),
-            crate::wild_animal_npc::AnimalSpecies::CableViper => Some("Viper Skull"),
-            crate::wild_animal_npc::AnimalSpecies::ArcticWalrus => Some("Walrus Skull"), // Large, imposing skull with tusks
-            crate::wild_animal_npc::AnimalSpecies::BeachCrab => None, // Crabs don't have skulls - they have exoskeletons
-            crate::wild_animal_npc::AnimalSpecies::Tern => Some("Tern Skull"), // Seabird skull trophy
-            crate::wild_animal_npc::AnimalSpecies::Crow => Some("Crow Skull"), // Clever scavenger skull trophy
-            crate::wild_animal_npc::AnimalSpecies::Vole => Some("Vole Skull"), // Tiny novelty trophy skull
-            crate::wild_animal_npc::AnimalSpecies::Wolverine => Some("Wolverine Skull"), // Fierce predator skull
-            crate::wild_animal_npc::AnimalSpecies::Caribou => Some("Caribou Skull"), // Large herbivore skull
-            // SalmonShark - drops shark skull (cartilage but still a trophy)
-            crate::wild_animal_npc::AnimalSpecies::SalmonShark => Some("Shark Skull"),
-            // Jellyfish - no skull (invertebrate)
-            crate::wild_animal_npc::AnimalSpecies::Jellyfish => None,
-            // Night hostile NPCs don't drop items
-            crate::wild_animal_npc::AnimalSpecies::Shorebound | 
-            crate::wild_animal_npc::AnimalSpecies::Shardkin | 
-            crate::wild_animal_npc::AnimalSpecies::DrownedWatch => None,
-            // Bees don't drop skulls - they're tiny insects
-            crate::wild_animal_npc::AnimalSpecies::Bee => None,
-            // Alpine animals
-            crate::wild_animal_npc::AnimalSpecies::PolarBear => Some("Polar Bear Skull"), // Massive apex predator skull
-            crate::wild_animal_npc::AnimalSpecies::Hare => Some("Hare Skull"), // Small prey skull
-            crate::wild_animal_npc::AnimalSpecies::SnowyOwl => Some("Owl Skull"), // Flying predator skull
-        };
-        
-        if let Some(skull_name) = skull_type {
-            match grant_resource(ctx, attacker_id, skull_name, 1) {
+        // Grant depletion drops regardless of tool used (like player corpses):
+        // 1 skull (crabs and jellyfish have none) plus guaranteed species drops (viper gland, shark fin, stinger)
+        let depleted_key = crate::wild_animal_npc::animal_corpse::animal_corpse_depleted_source_key(animal_corpse.animal_species);
+        for drop in crate::loot_table::roll_loot(ctx, &depleted_key, 0) {
+            match grant_resource(ctx, attacker_id, &drop.item_name, drop.quantity) {
                 Ok(_) => {
-                    resources_granted.push((skull_name.to_string(), 1));
                     log::info!(
-                        "[DamageAnimalCorpse:{}] Granted 1 {} to Player {:?} (corpse depleted).",
-                        animal_corpse_id, skull_name, attacker_id
+                        "[DamageAnimalCorpse:{}] Granted {} {} to Player {:?} (corpse depleted).",
+                        animal_corpse_id, drop.quantity, drop.item_name, attacker_id
                     );
+                    resources_granted.push((drop.item_name, drop.quantity));
                 }
                 Err(e) => log::error!(
                     "[DamageAnimalCorpse:{}] Failed to grant {} to Player {:?}: {}",
-                    animal_corpse_id, skull_name, attacker_id, e
-                ),
-            }
-        }
-        
-        // GUARANTEED: Cable Viper Gland drop for Cable Vipers (100% chance when corpse depleted)
-        if animal_corpse.animal_species == crate::wild_animal_npc::AnimalSpecies::CableViper {
-            match grant_resource(ctx, attacker_id, "Cable Viper Gland", 1) {
-                Ok(_) => {
-                    resources_granted.push(("Cable Viper Gland".to_string(), 1));
-                    log::info!(
-                        "[DamageAnimalCorpse:{}] Granted 1 Cable Viper Gland to Player {:?} (guaranteed viper drop).",
-                        animal_corpse_id, attacker_id
-                    );
-                }
-                Err(e) => log::error!(
-                    "[DamageAnimalCorpse:{}] Failed to grant Cable Viper Gland to Player {:?}: {}",
-                    animal_corpse_id, attacker_id, e
-                ),
-            }
-        }
-        
-        // GUARANTEED: Shark Fin drop for SalmonShark (100% chance when corpse depleted with Tidebreaker Blade)
-        if animal_corpse.animal_species == crate::wild_animal_npc::AnimalSpecies::SalmonShark {
-            match grant_resource(ctx, attacker_id, "Shark Fin", 1) {
-                Ok(_) => {
-                    resources_granted.push(("Shark Fin".to_string(), 1));
-                    log::info!(
-                        "[DamageAnimalCorpse:{}] Granted 1 Shark Fin to Player {:?} (guaranteed shark drop).",
-                        animal_corpse_id, attacker_id
-                    );
-                }
-                Err(e) => log::error!(
-                    "[DamageAnimalCorpse:{}] Failed to grant Shark Fin to Player {:?}: {}",
-                    animal_corpse_id, attacker_id, e
-                ),
-            }
-        }
-        
-        // GUARANTEED: Jellyfish Stinger drop for Jellyfish (100% chance when corpse depleted with Tidebreaker Blade)
-        if animal_corpse.animal_species == crate::wild_animal_npc::AnimalSpecies::Jellyfish {
-            match grant_resource(ctx, attacker_id, "Jellyfish Stinger", 1) {
-                Ok(_) => {
-                    resources_granted.push(("Jellyfish Stinger".to_string(), 1));
-                    log::info!(
-                        "[DamageAnimalCorpse:{}] Granted 1 Jellyfish Stinger to Player {:?} (guaranteed jellyfish drop).",
-                        animal_corpse_id, attacker_id
-                    );
-                }
-                Err(e) => log::error!(
-                    "[DamageAnimalCorpse:{}] Failed to grant Jellyfish Stinger to Player {:?}: {}",
-                    animal_corpse_id, attacker_id, e
+                    animal_corpse_id, drop.item_name, attacker_id, e
                 ),
             }
         }
@@ -5112,6 +5163,9 @@ pub fn damage_animal_corpse(
             crate::wild_animal_npc::cleanup_walrus_breeding_data(ctx, animal_corpse.animal_id);
         }
         
+        // Tamed animal product cooldowns die with the animal
+        crate::wild_animal_npc::animal_products::cleanup_tamed_animal_production(ctx, animal_corpse.animal_id);
+        
         // Delete the corpse entity
         animal_corpse_table.id().delete(&animal_corpse_id);
         log::info!("[DamageAnimalCorpse] AnimalCorpse {} entity deleted after being depleted.", animal_corpse_id);
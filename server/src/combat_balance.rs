@@ -0,0 +1,282 @@
+/******************************************************************************
+ *                                                                            *
+ * Combat feel tuning (knockback and stagger).                                *
+ *                                                                            *
+ * Knockback distances and stagger durations live in public config tables    *
+ * seeded at init, so combat feel can be tuned live without republishing.    *
+ * Clients can read them for prediction. The tuning reducers can only be     *
+ * called by balance admins - the identity that published the module is      *
+ * registered as the first admin during init.                                 *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table};
+use log;
+
+use crate::wild_animal_npc::AnimalSpecies;
+
+// --- Defaults ---
+
+/// Distance a player is knocked back by a PvP hit
+pub const DEFAULT_PVP_KNOCKBACK_DISTANCE: f32 = 32.0;
+/// Distance a melee attacker recoils after a PvP hit (ranged attackers don't recoil)
+pub const DEFAULT_PVP_ATTACKER_RECOIL_DISTANCE: f32 = DEFAULT_PVP_KNOCKBACK_DISTANCE / 3.0;
+/// Distance a player recoils after meleeing an animal
+pub const DEFAULT_ANIMAL_HIT_RECOIL_DISTANCE: f32 = 8.0;
+/// Distance an animal is pushed by another animal's attack
+pub const DEFAULT_ANIMAL_VS_ANIMAL_KNOCKBACK_DISTANCE: f32 = 12.0;
+/// How long a player attacker stays staggered after being parried
+pub const DEFAULT_PLAYER_STAGGER_SECS: f32 = 1.0;
+/// How long an animal waits before attacking again after being parried
+pub const DEFAULT_ANIMAL_PARRY_STAGGER_SECS: f32 = 1.5;
+
+const ALL_SPECIES: [AnimalSpecies; 19] = [
+    AnimalSpecies::CinderFox,
+    AnimalSpecies::TundraWolf,
+    AnimalSpecies::CableViper,
+    AnimalSpecies::ArcticWalrus,
+    AnimalSpecies::BeachCrab,
+    AnimalSpecies::Tern,
+    AnimalSpecies::Crow,
+    AnimalSpecies::Vole,
+    AnimalSpecies::Wolverine,
+    AnimalSpecies::Caribou,
+    AnimalSpecies::SalmonShark,
+    AnimalSpecies::Jellyfish,
+    AnimalSpecies::Shorebound,
+    AnimalSpecies::Shardkin,
+    AnimalSpecies::DrownedWatch,
+    AnimalSpecies::Bee,
+    AnimalSpecies::PolarBear,
+    AnimalSpecies::Hare,
+    AnimalSpecies::SnowyOwl,
+];
+
+// --- Tables ---
+
+/// Global knockback/stagger tuning (singleton, id 0)
+#[spacetimedb::table(accessor = combat_balance_config, public)]
+#[derive(Clone, Debug)]
+pub struct CombatBalanceConfig {
+    #[primary_key]
+    pub id: u8,
+    pub pvp_knockback_distance: f32,
+    pub pvp_attacker_recoil_distance: f32,
+    pub animal_hit_recoil_distance: f32,
+    pub animal_vs_animal_knockback_distance: f32,
+    pub player_stagger_secs: f32,
+    pub animal_parry_stagger_secs: f32,
+}
+
+/// Per-species knockback tuning
+#[spacetimedb::table(accessor = animal_knockback_config, public)]
+#[derive(Clone, Debug)]
+pub struct AnimalKnockbackConfig {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub species: AnimalSpecies,
+    /// How far this species' attacks push a player
+    pub knockback_to_player: f32,
+    /// How far a player's melee hit pushes this species (0 = immune, too heavy to push)
+    pub knockback_from_player: f32,
+}
+
+/// Identities allowed to call the tuning reducers
+#[spacetimedb::table(accessor = balance_admin)]
+#[derive(Clone, Debug)]
+pub struct BalanceAdmin {
+    #[primary_key]
+    pub identity: Identity,
+}
+
+// --- Configuration ---
+
+fn default_config() -> CombatBalanceConfig {
+    CombatBalanceConfig {
+        id: 0,
+        pvp_knockback_distance: DEFAULT_PVP_KNOCKBACK_DISTANCE,
+        pvp_attacker_recoil_distance: DEFAULT_PVP_ATTACKER_RECOIL_DISTANCE,
+        animal_hit_recoil_distance: DEFAULT_ANIMAL_HIT_RECOIL_DISTANCE,
+        animal_vs_animal_knockback_distance: DEFAULT_ANIMAL_VS_ANIMAL_KNOCKBACK_DISTANCE,
+        player_stagger_secs: DEFAULT_PLAYER_STAGGER_SECS,
+        animal_parry_stagger_secs: DEFAULT_ANIMAL_PARRY_STAGGER_SECS,
+    }
+}
+
+/// Default (knockback_to_player, knockback_from_player) per species
+fn default_animal_knockback(species: AnimalSpecies) -> (f32, f32) {
+    match species {
+        AnimalSpecies::TundraWolf => (48.0, 16.0),
+        AnimalSpecies::CinderFox => (32.0, 22.0),
+        AnimalSpecies::CableViper => (24.0, 18.0),
+        AnimalSpecies::ArcticWalrus => (64.0, 0.0), // Massive walrus attack, too heavy to push
+        AnimalSpecies::BeachCrab => (16.0, 18.0),   // Crab pinch
+        AnimalSpecies::Tern => (8.0, 20.0),         // Bird peck
+        AnimalSpecies::Crow => (12.0, 20.0),        // Crow peck
+        AnimalSpecies::Vole => (4.0, 28.0),         // Tiny rodent bite, sent flying when hit
+        AnimalSpecies::Wolverine => (56.0, 14.0),   // Aggressive predator
+        AnimalSpecies::Caribou => (48.0, 12.0),     // Large herbivore charge
+        AnimalSpecies::SalmonShark => (40.0, 0.0),  // Shark bite
+        AnimalSpecies::Jellyfish => (16.0, 16.0),   // Electric shock pushback
+        // Hostile NPCs
+        AnimalSpecies::Shorebound => (36.0, 16.0),  // Fast stalker
+        AnimalSpecies::Shardkin => (20.0, 22.0),    // Small swarmer
+        AnimalSpecies::DrownedWatch => (72.0, 0.0), // Heavy brute
+        AnimalSpecies::Bee => (8.0, 20.0),          // Tiny insect
+        // Alpine animals
+        AnimalSpecies::PolarBear => (72.0, 0.0),    // Massive apex predator
+        AnimalSpecies::Hare => (4.0, 28.0),         // Tiny prey
+        AnimalSpecies::SnowyOwl => (12.0, 20.0),    // Medium bird
+    }
+}
+
+/// Seeds the config singleton, any missing species rows, and the publishing identity as admin (idempotent)
+pub fn seed_combat_balance(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.combat_balance_config().id().find(0).is_none() {
+        log::info!("Seeding combat balance config with defaults");
+        ctx.db.combat_balance_config().insert(default_config());
+    }
+
+    let species_configs = ctx.db.animal_knockback_config();
+    for species in ALL_SPECIES {
+        if !species_configs.iter().any(|c| c.species == species) {
+            let (knockback_to_player, knockback_from_player) = default_animal_knockback(species);
+            species_configs.insert(AnimalKnockbackConfig { id: 0, species, knockback_to_player, knockback_from_player });
+        }
+    }
+
+    if ctx.db.balance_admin().identity().find(&ctx.sender()).is_none() {
+        log::info!("Registering {:?} as balance admin", ctx.sender());
+        ctx.db.balance_admin().insert(BalanceAdmin { identity: ctx.sender() });
+    }
+    Ok(())
+}
+
+// --- Lookups ---
+
+/// Current global tuning (defaults until the table is seeded)
+pub fn get_config(ctx: &ReducerContext) -> CombatBalanceConfig {
+    ctx.db.combat_balance_config().id().find(0).unwrap_or_else(default_config)
+}
+
+fn get_animal_knockback(ctx: &ReducerContext, species: AnimalSpecies) -> (f32, f32) {
+    ctx.db.animal_knockback_config().iter()
+        .find(|c| c.species == species)
+        .map(|c| (c.knockback_to_player, c.knockback_from_player))
+        .unwrap_or_else(|| default_animal_knockback(species))
+}
+
+/// How far an attack from this species pushes a player
+pub fn animal_knockback_to_player(ctx: &ReducerContext, species: AnimalSpecies) -> f32 {
+    get_animal_knockback(ctx, species).0
+}
+
+/// How far a player's melee hit pushes this species
+pub fn animal_knockback_from_player(ctx: &ReducerContext, species: AnimalSpecies) -> f32 {
+    get_animal_knockback(ctx, species).1
+}
+
+// --- Admin Reducers ---
+
+/// Whether the caller is the module itself or a registered balance admin
+pub fn is_balance_admin(ctx: &ReducerContext) -> bool {
+    ctx.sender() == ctx.identity() || ctx.db.balance_admin().identity().find(&ctx.sender()).is_some()
+}
+
+fn require_balance_admin(ctx: &ReducerContext) -> Result<(), String> {
+    if is_balance_admin(ctx) {
+        Ok(())
+    } else {
+        Err("Only balance admins can change combat tuning.".to_string())
+    }
+}
+
+fn validate_non_negative(values: &[(&str, f32)]) -> Result<(), String> {
+    for (name, value) in values {
+        if !value.is_finite() || *value < 0.0 {
+            return Err(format!("{} must be a non-negative number.", name));
+        }
+    }
+    Ok(())
+}
+
+/// Updates the global knockback/stagger tuning
+#[spacetimedb::reducer]
+pub fn set_combat_balance_config(
+    ctx: &ReducerContext,
+    pvp_knockback_distance: f32,
+    pvp_attacker_recoil_distance: f32,
+    animal_hit_recoil_distance: f32,
+    animal_vs_animal_knockback_distance: f32,
+    player_stagger_secs: f32,
+    animal_parry_stagger_secs: f32,
+) -> Result<(), String> {
+    require_balance_admin(ctx)?;
+    validate_non_negative(&[
+        ("pvp_knockback_distance", pvp_knockback_distance),
+        ("pvp_attacker_recoil_distance", pvp_attacker_recoil_distance),
+        ("animal_hit_recoil_distance", animal_hit_recoil_distance),
+        ("animal_vs_animal_knockback_distance", animal_vs_animal_knockback_distance),
+        ("player_stagger_secs", player_stagger_secs),
+        ("animal_parry_stagger_secs", animal_parry_stagger_secs),
+    ])?;
+
+    let config = CombatBalanceConfig {
+        id: 0,
+        pvp_knockback_distance,
+        pvp_attacker_recoil_distance,
+        animal_hit_recoil_distance,
+        animal_vs_animal_knockback_distance,
+        player_stagger_secs,
+        animal_parry_stagger_secs,
+    };
+    if ctx.db.combat_balance_config().id().find(0).is_some() {
+        ctx.db.combat_balance_config().id().update(config.clone());
+    } else {
+        ctx.db.combat_balance_config().insert(config.clone());
+    }
+    log::info!("[CombatBalance] {:?} updated combat balance config: {:?}", ctx.sender(), config);
+    Ok(())
+}
+
+/// Updates knockback tuning for one species
+#[spacetimedb::reducer]
+pub fn set_animal_knockback(
+    ctx: &ReducerContext,
+    species: AnimalSpecies,
+    knockback_to_player: f32,
+    knockback_from_player: f32,
+) -> Result<(), String> {
+    require_balance_admin(ctx)?;
+    validate_non_negative(&[
+        ("knockback_to_player", knockback_to_player),
+        ("knockback_from_player", knockback_from_player),
+    ])?;
+
+    let species_configs = ctx.db.animal_knockback_config();
+    match species_configs.iter().find(|c| c.species == species) {
+        Some(mut config) => {
+            config.knockback_to_player = knockback_to_player;
+            config.knockback_from_player = knockback_from_player;
+            species_configs.id().update(config);
+        }
+        None => {
+            species_configs.insert(AnimalKnockbackConfig { id: 0, species, knockback_to_player, knockback_from_player });
+        }
+    }
+    log::info!("[CombatBalance] {:?} set {:?} knockback: to player {:.1}, from player {:.1}",
+        ctx.sender(), species, knockback_to_player, knockback_from_player);
+    Ok(())
+}
+
+/// Grants another identity access to the tuning reducers
+#[spacetimedb::reducer]
+pub fn add_balance_admin(ctx: &ReducerContext, identity: Identity) -> Result<(), String> {
+    require_balance_admin(ctx)?;
+    if ctx.db.balance_admin().identity().find(&identity).is_none() {
+        ctx.db.balance_admin().insert(BalanceAdmin { identity });
+        log::info!("[CombatBalance] {:?} added balance admin {:?}", ctx.sender(), identity);
+    }
+    Ok(())
+}
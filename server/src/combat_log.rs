@@ -0,0 +1,109 @@
+/******************************************************************************
+ *                                                                            *
+ * Combat logging penalty.                                                    *
+ *                                                                            *
+ * Player-vs-player damage tags both players as "in combat" for               *
+ * COMBAT_TAG_WINDOW_SECS. A player who disconnects while tagged is a combat *
+ * logger: their offline corpse skips the usual loot protection and is open  *
+ * to everyone immediately, and a public marker stays up for                 *
+ * COMBAT_LOGGER_MARKER_SECS so clients can flag the body. Reconnecting     *
+ * clears the marker.                                                         *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp, TimeDuration};
+use log;
+
+use crate::player_corpse::player_corpse as PlayerCorpseTableTrait;
+
+// --- Constants ---
+
+/// How long after dealing or taking PvP damage a player counts as in combat
+pub const COMBAT_TAG_WINDOW_SECS: u64 = 60;
+
+/// How long the combat logger marker is shown on the body
+pub const COMBAT_LOGGER_MARKER_SECS: u64 = 300;
+
+// --- Tables ---
+
+/// Last time each player dealt or received PvP damage
+#[spacetimedb::table(accessor = player_combat_tag)]
+#[derive(Clone, Debug)]
+pub struct PlayerCombatTag {
+    #[primary_key]
+    pub player_id: Identity,
+    pub last_combat_at: Timestamp,
+}
+
+/// Players who logged out mid-fight (public so clients can mark their body)
+#[spacetimedb::table(accessor = combat_logger, public)]
+#[derive(Clone, Debug)]
+pub struct CombatLogger {
+    #[primary_key]
+    pub player_id: Identity,
+    pub corpse_id: u32,
+    pub logged_out_at: Timestamp,
+    pub marked_until: Timestamp,
+}
+
+// --- Combat Tagging ---
+
+/// Tags a player as in combat (called for both attacker and target on PvP damage)
+pub fn tag_player_in_combat(ctx: &ReducerContext, player_id: Identity) {
+    let tags = ctx.db.player_combat_tag();
+    let tag = PlayerCombatTag { player_id, last_combat_at: ctx.timestamp };
+    if tags.player_id().find(&player_id).is_some() {
+        tags.player_id().update(tag);
+    } else {
+        tags.insert(tag);
+    }
+}
+
+/// Checks if a player dealt or took PvP damage within the combat window
+pub fn is_player_in_recent_combat(ctx: &ReducerContext, player_id: Identity) -> bool {
+    ctx.db.player_combat_tag().player_id().find(&player_id)
+        .and_then(|tag| ctx.timestamp.duration_since(tag.last_combat_at))
+        .map(|elapsed| elapsed.as_secs() < COMBAT_TAG_WINDOW_SECS)
+        .unwrap_or(false)
+}
+
+// --- Disconnect / Reconnect ---
+
+/// Called after a player's offline corpse is created on disconnect.
+/// If they were in combat, strips the corpse's loot protection and marks them as a combat logger.
+pub fn handle_disconnect(ctx: &ReducerContext, player_id: Identity, corpse_id: u32) {
+    if !is_player_in_recent_combat(ctx, player_id) {
+        return;
+    }
+
+    let corpses = ctx.db.player_corpse();
+    if let Some(mut corpse) = corpses.id().find(corpse_id) {
+        corpse.locked_until = None; // Lootable by anyone right away
+        corpse.username = corpse.username.replace("(Offline)", "(Combat Logger)");
+        corpses.id().update(corpse);
+    }
+
+    let loggers = ctx.db.combat_logger();
+    let marker = CombatLogger {
+        player_id,
+        corpse_id,
+        logged_out_at: ctx.timestamp,
+        marked_until: ctx.timestamp + TimeDuration::from_micros(COMBAT_LOGGER_MARKER_SECS as i64 * 1_000_000),
+    };
+    if loggers.player_id().find(&player_id).is_some() {
+        loggers.player_id().update(marker);
+    } else {
+        loggers.insert(marker);
+    }
+
+    log::info!("[CombatLog] Player {:?} logged out in combat - offline corpse {} left unprotected", player_id, corpse_id);
+}
+
+/// Called on reconnect - removes the combat logger marker.
+/// The combat tag is left alone so a quick relog can't reset it.
+pub fn clear_on_reconnect(ctx: &ReducerContext, player_id: Identity) {
+    if ctx.db.combat_logger().player_id().find(&player_id).is_some() {
+        ctx.db.combat_logger().player_id().delete(&player_id);
+        log::info!("[CombatLog] Cleared combat logger marker for reconnecting player {:?}", player_id);
+    }
+}
@@ -55,6 +55,9 @@ pub fn consume_item(ctx: &ReducerContext, item_instance_id: u64) -> Result<(), S
     if player_to_update.is_knocked_out {
         return Err("Cannot consume items while knocked out.".to_string());
     }
+    if crate::restraints::is_restrained(ctx, sender_id) {
+        return Err("Cannot consume items while restrained.".to_string());
+    }
 
     if let Some(last_consumed_ts) = player_to_update.last_consumed_at {
         let cooldown_duration = TimeDuration::from_micros(CONSUMPTION_COOLDOWN_MICROS as i64);
@@ -593,6 +596,9 @@ fn apply_brewing_recipe_effect(
             item_def.name, recipe.category, recipe.effect_type, player_id
         );
         
+        // Curing brews work regardless of the buff they grant
+        crate::active_effects::cure_diseases_with_brew(ctx, player_id, &recipe.category);
+        
         // First try explicit effect_type if specified in the recipe
         if let Some(ref effect_type_str) = recipe.effect_type {
             log::info!("[BrewingEffect] Applying explicit effect type: '{}'", effect_type_str);
@@ -0,0 +1,309 @@
+// ============================================================================
+// TERRITORY CONTROL POINTS
+// ============================================================================
+// Each monument center hosts a capturable control point. Matronages compete
+// for them:
+// - Stand uncontested inside the capture radius for CONTROL_POINT_CAPTURE_SECS
+//   to flip ownership to your matronage
+// - Owned points trickle Memory Shards into the owning matronage's pool
+//   (distributed by the regular matronage payout)
+// - Members of an owning matronage get discounted ALK purchases
+// - Ownership lapses after a week, so every point is contested weekly
+//
+// Players without a matronage can contest (block) a capture but never capture.
+// ============================================================================
+
+use spacetimedb::{ReducerContext, Table, Timestamp, Identity, TimeDuration, ScheduleAt};
+use log;
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::{MonumentType, monument_part as MonumentPartTableTrait};
+use crate::player as PlayerTableTrait;
+use crate::matronage::matronage as MatronageTableTrait;
+use crate::matronage::matronage_member as MatronageMemberTableTrait;
+
+// Table trait imports for generated accessor methods
+use crate::control_point::control_point as ControlPointTableTrait;
+use crate::control_point::control_point_schedule as ControlPointScheduleTableTrait;
+
+// ============================================================================
+// CONSTANTS
+// ============================================================================
+
+/// How often control points are processed
+pub const CONTROL_POINT_TICK_INTERVAL_SECS: u64 = 5;
+
+/// Capture radius around the monument center (pixels)
+pub const CONTROL_POINT_CAPTURE_RADIUS: f32 = 300.0;
+pub const CONTROL_POINT_CAPTURE_RADIUS_SQ: f32 = CONTROL_POINT_CAPTURE_RADIUS * CONTROL_POINT_CAPTURE_RADIUS;
+
+/// Uncontested time needed to flip a point (5 minutes)
+pub const CONTROL_POINT_CAPTURE_SECS: f32 = 300.0;
+
+/// Capture progress lost per second while nobody is capturing
+pub const CONTROL_POINT_PROGRESS_DECAY_PER_SEC: f32 = 0.5;
+
+/// Ownership lasts one week before the point resets to neutral
+pub const CONTROL_POINT_OWNERSHIP_DURATION_SECS: u64 = 7 * 24 * 60 * 60;
+
+/// Shard trickle paid into the owning matronage's pool
+pub const CONTROL_POINT_TRICKLE_INTERVAL_SECS: u64 = 600; // Every 10 minutes
+pub const CONTROL_POINT_TRICKLE_SHARDS: u64 = 10;
+
+/// ALK purchase discount per owned control point, and the cap
+pub const CONTROL_POINT_ALK_DISCOUNT_PER_POINT: f32 = 0.05; // 5% per point
+pub const CONTROL_POINT_MAX_ALK_DISCOUNT: f32 = 0.25; // Never more than 25% off
+
+// ============================================================================
+// TABLES
+// ============================================================================
+
+/// A capturable control point at a monument center
+#[spacetimedb::table(accessor = control_point, public)]
+#[derive(Clone, Debug)]
+pub struct ControlPoint {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+
+    /// Monument this point sits on
+    pub monument_type: MonumentType,
+
+    /// World position (monument center)
+    pub pos_x: f32,
+    pub pos_y: f32,
+
+    /// Matronage that currently owns the point
+    pub owner_matronage_id: Option<u64>,
+
+    /// When the current owner captured it (ownership lapses after a week)
+    pub owned_since: Option<Timestamp>,
+
+    /// Matronage currently capturing the point
+    pub capturing_matronage_id: Option<u64>,
+
+    /// Seconds of uncontested capture accumulated (0 to CONTROL_POINT_CAPTURE_SECS)
+    pub capture_progress_secs: f32,
+
+    /// Whether rival players are inside the capture radius (capture frozen)
+    pub is_contested: bool,
+
+    /// When the owner last received a shard trickle
+    pub last_trickle_at: Option<Timestamp>,
+}
+
+/// Scheduled control point processing
+#[spacetimedb::table(accessor = control_point_schedule, scheduled(process_control_points))]
+#[derive(Clone, Debug)]
+pub struct ControlPointSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// ============================================================================
+// INITIALIZATION
+// ============================================================================
+
+/// Initialize the control point schedule (called from lib.rs init and resume)
+pub fn init_control_point_system(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.control_point_schedule();
+
+    if schedule_table.iter().count() == 0 {
+        log::info!("🚩 Starting control point schedule (every {}s).", CONTROL_POINT_TICK_INTERVAL_SECS);
+        let interval = Duration::from_secs(CONTROL_POINT_TICK_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            ControlPointSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Control point processing"
+        );
+    }
+
+    Ok(())
+}
+
+/// Create one control point per monument center (no-op once seeded).
+/// Monuments are placed during world generation, so this runs lazily from the tick.
+fn seed_control_points(ctx: &ReducerContext) {
+    let points_table = ctx.db.control_point();
+    if points_table.iter().next().is_some() {
+        return;
+    }
+
+    let mut created = 0;
+    for part in ctx.db.monument_part().iter().filter(|p| p.is_center) {
+        points_table.insert(ControlPoint {
+            id: 0,
+            monument_type: part.monument_type.clone(),
+            pos_x: part.world_x,
+            pos_y: part.world_y,
+            owner_matronage_id: None,
+            owned_since: None,
+            capturing_matronage_id: None,
+            capture_progress_secs: 0.0,
+            is_contested: false,
+            last_trickle_at: None,
+        });
+        created += 1;
+    }
+
+    if created > 0 {
+        log::info!("🚩 Seeded {} control points at monument centers", created);
+    }
+}
+
+// ============================================================================
+// HELPER FUNCTIONS
+// ============================================================================
+
+/// ALK price multiplier for a player (1.0 = full price).
+/// Members of a matronage that owns control points pay less.
+pub fn get_alk_price_multiplier(ctx: &ReducerContext, player_id: Identity) -> f32 {
+    let Some(member) = ctx.db.matronage_member().player_id().find(&player_id) else {
+        return 1.0;
+    };
+
+    let owned_points = ctx.db.control_point().iter()
+        .filter(|p| p.owner_matronage_id == Some(member.matronage_id))
+        .count();
+
+    let discount = (owned_points as f32 * CONTROL_POINT_ALK_DISCOUNT_PER_POINT).min(CONTROL_POINT_MAX_ALK_DISCOUNT);
+    1.0 - discount
+}
+
+/// Apply the control point discount to a shard price (rounded up, never below 1)
+pub fn apply_alk_discount(ctx: &ReducerContext, player_id: Identity, base_cost: u32) -> u32 {
+    let multiplier = get_alk_price_multiplier(ctx, player_id);
+    if multiplier >= 1.0 {
+        return base_cost;
+    }
+    ((base_cost as f32 * multiplier).ceil() as u32).max(1)
+}
+
+fn elapsed_secs(now: Timestamp, since: Timestamp) -> u64 {
+    now.duration_since(since).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+// ============================================================================
+// SCHEDULED REDUCER
+// ============================================================================
+
+/// Scheduled reducer that advances capture progress, expires ownership and pays trickles
+#[spacetimedb::reducer]
+pub fn process_control_points(ctx: &ReducerContext, _args: ControlPointSchedule) -> Result<(), String> {
+    // Security check - only scheduler can run this
+    if ctx.sender() != ctx.identity() {
+        return Err("Control point processing can only be run by scheduler".to_string());
+    }
+
+    seed_control_points(ctx);
+
+    let points: Vec<ControlPoint> = ctx.db.control_point().iter().collect();
+    if points.is_empty() {
+        return Ok(());
+    }
+
+    // Players able to hold a point: online, alive and conscious
+    let players: Vec<(Identity, f32, f32)> = ctx.db.player().iter()
+        .filter(|p| p.is_online && !p.is_dead && !p.is_knocked_out)
+        .map(|p| (p.identity, p.position_x, p.position_y))
+        .collect();
+
+    let dt = CONTROL_POINT_TICK_INTERVAL_SECS as f32;
+
+    for point in points {
+        let mut updated = point.clone();
+
+        // --- Ownership lapses weekly, or when the owning matronage dissolves ---
+        if let Some(owner_id) = updated.owner_matronage_id {
+            let expired = updated.owned_since
+                .map(|since| elapsed_secs(ctx.timestamp, since) >= CONTROL_POINT_OWNERSHIP_DURATION_SECS)
+                .unwrap_or(true);
+            let dissolved = ctx.db.matronage().id().find(&owner_id).is_none();
+            if expired || dissolved {
+                log::info!("🚩 Control point {} ({:?}) reset to neutral (expired: {}, dissolved: {})",
+                          point.id, point.monument_type, expired, dissolved);
+                updated.owner_matronage_id = None;
+                updated.owned_since = None;
+                updated.last_trickle_at = None;
+            }
+        }
+
+        // --- Who is standing on the point ---
+        let mut factions: HashSet<Option<u64>> = HashSet::new();
+        for (player_id, x, y) in &players {
+            let dx = x - point.pos_x;
+            let dy = y - point.pos_y;
+            if dx * dx + dy * dy <= CONTROL_POINT_CAPTURE_RADIUS_SQ {
+                let matronage_id = ctx.db.matronage_member().player_id().find(player_id).map(|m| m.matronage_id);
+                factions.insert(matronage_id);
+            }
+        }
+
+        updated.is_contested = factions.len() > 1;
+        let sole_matronage = if factions.len() == 1 { factions.into_iter().next().flatten() } else { None };
+
+        // --- Capture progress ---
+        if updated.is_contested {
+            // Rivals on the point - progress frozen
+        } else if let Some(capturer) = sole_matronage {
+            if updated.owner_matronage_id == Some(capturer) {
+                // Owner defending - wipe any enemy progress
+                updated.capturing_matronage_id = None;
+                updated.capture_progress_secs = 0.0;
+            } else {
+                if updated.capturing_matronage_id != Some(capturer) {
+                    updated.capturing_matronage_id = Some(capturer);
+                    updated.capture_progress_secs = 0.0;
+                }
+                updated.capture_progress_secs += dt;
+
+                if updated.capture_progress_secs >= CONTROL_POINT_CAPTURE_SECS {
+                    log::info!("🚩 Matronage {} captured control point {} ({:?})",
+                              capturer, point.id, point.monument_type);
+                    updated.owner_matronage_id = Some(capturer);
+                    updated.owned_since = Some(ctx.timestamp);
+                    updated.last_trickle_at = Some(ctx.timestamp);
+                    updated.capturing_matronage_id = None;
+                    updated.capture_progress_secs = 0.0;
+                }
+            }
+        } else if updated.capture_progress_secs > 0.0 {
+            // Nobody capturing (empty, or only unaffiliated players) - progress decays
+            updated.capture_progress_secs = (updated.capture_progress_secs - CONTROL_POINT_PROGRESS_DECAY_PER_SEC * dt).max(0.0);
+            if updated.capture_progress_secs == 0.0 {
+                updated.capturing_matronage_id = None;
+            }
+        }
+
+        // --- Shard trickle into the owner's pool ---
+        if let (Some(owner_id), Some(last_trickle)) = (updated.owner_matronage_id, updated.last_trickle_at) {
+            if elapsed_secs(ctx.timestamp, last_trickle) >= CONTROL_POINT_TRICKLE_INTERVAL_SECS {
+                if let Some(mut matronage) = ctx.db.matronage().id().find(&owner_id) {
+                    matronage.pool_balance += CONTROL_POINT_TRICKLE_SHARDS;
+                    ctx.db.matronage().id().update(matronage);
+                    log::debug!("🚩 Control point {} paid {} shards to matronage {}",
+                               point.id, CONTROL_POINT_TRICKLE_SHARDS, owner_id);
+                }
+                updated.last_trickle_at = Some(ctx.timestamp);
+            }
+        }
+
+        if updated.owner_matronage_id != point.owner_matronage_id
+            || updated.owned_since != point.owned_since
+            || updated.capturing_matronage_id != point.capturing_matronage_id
+            || updated.capture_progress_secs != point.capture_progress_secs
+            || updated.is_contested != point.is_contested
+            || updated.last_trickle_at != point.last_trickle_at
+        {
+            ctx.db.control_point().id().update(updated);
+        }
+    }
+
+    Ok(())
+}
@@ -53,6 +53,26 @@ pub trait CookableAppliance: ItemContainer {
     fn get_appliance_world_position(&self) -> (f32, f32); // For dropping items
 }
 
+/// Carries per-slot cooking progress along after a move within an appliance (set_slot clears it).
+/// `before` is the appliance as it was before the move:
+/// - Move to empty slot: source progress -> target
+/// - Swap: exchange progress
+/// - Merge: target keeps its progress (items combined there)
+/// Splits need nothing: the source slot keeps its progress and the new stack starts fresh.
+pub(crate) fn carry_cooking_progress_after_move<T: CookableAppliance>(appliance: &mut T, before: &T, source_slot: u8, target_slot: u8) {
+    let source_progress = before.get_slot_cooking_progress(source_slot);
+    let target_progress = before.get_slot_cooking_progress(target_slot);
+    let source_had_item = before.get_slot_instance_id(source_slot).is_some();
+    let target_had_item = before.get_slot_instance_id(target_slot).is_some();
+
+    if source_had_item && !target_had_item {
+        appliance.set_slot_cooking_progress(target_slot, source_progress);
+    } else if source_had_item && target_had_item && appliance.get_slot_instance_id(source_slot).is_some() {
+        appliance.set_slot_cooking_progress(target_slot, source_progress);
+        appliance.set_slot_cooking_progress(source_slot, target_progress);
+    }
+}
+
 /// OPTIMIZED: Fast-path transform that tries to directly increment an existing output stack
 /// before falling back to the expensive create-then-place path.
 /// This avoids creating a temporary InventoryItem (insert + delete) in the common case
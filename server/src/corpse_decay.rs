@@ -0,0 +1,290 @@
+/******************************************************************************
+ *                                                                            *
+ * Corpse decay and scavenging for player and animal corpses.                *
+ *                                                                            *
+ * Corpses rot through three stages over their lifetime:                     *
+ *   Fresh -> Decaying -> Bones                                               *
+ * Decaying corpses give reduced harvest yields; a bone pile only yields     *
+ * bones (see bones::roll_bone_pile_loot). Player corpses keep their items   *
+ * in every stage - only the body rots. Offline (sleeping) bodies never rot. *
+ *                                                                            *
+ * Scavengers (Tern, Crow, Wolverine) are drawn to unattended corpses and    *
+ * feed on them (see wild_animal_npc::corpse_feeding), eating into the       *
+ * harvestable remains. A corpse that's eaten down is picked clean to bones. *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, ScheduleAt, SpacetimeType, Table, Timestamp, TimeDuration};
+use std::collections::HashSet;
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::player_corpse::player_corpse as PlayerCorpseTableTrait;
+use crate::wild_animal_npc::animal_corpse::animal_corpse as AnimalCorpseTableTrait;
+use crate::utils::get_distance_squared;
+
+// --- Constants ---
+
+/// Fraction of a corpse's lifetime after which it starts decaying
+pub const DECAYING_AT_LIFETIME_FRACTION: f32 = 0.35;
+/// Fraction of a corpse's lifetime after which only bones are left
+pub const BONES_AT_LIFETIME_FRACTION: f32 = 0.70;
+/// Harvest yield multiplier for decaying corpses
+pub const DECAYING_YIELD_MULTIPLIER: f32 = 0.5;
+/// Health of a bone pile (a few hits to collect the bones)
+pub const BONE_PILE_HEALTH: u32 = 20;
+
+pub const CORPSE_DECAY_CHECK_INTERVAL_SECS: u64 = 5;
+
+/// How far scavengers notice an unattended corpse
+pub const SCAVENGER_CORPSE_DETECTION_RADIUS: f32 = 600.0;
+/// Corpses with a player this close are attended - scavengers keep away
+pub const CORPSE_ATTENDED_RADIUS: f32 = 300.0;
+/// Corpse health eaten per scavenger bite
+pub const SCAVENGER_BITE_DAMAGE: u32 = 4;
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+pub enum CorpseDecayStage {
+    Fresh,
+    Decaying,
+    Bones,
+}
+
+// --- Corpse Decay Schedule ---
+#[spacetimedb::table(accessor = corpse_decay_schedule, scheduled(process_corpse_decay))]
+#[derive(Clone, Debug)]
+pub struct CorpseDecaySchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// --- Initialization ---
+pub fn init_corpse_decay_system(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.corpse_decay_schedule().iter().next().is_some() {
+        return Ok(());
+    }
+
+    let check_interval = TimeDuration::from_micros(CORPSE_DECAY_CHECK_INTERVAL_SECS as i64 * 1_000_000);
+    crate::try_insert_schedule!(
+        ctx.db.corpse_decay_schedule(),
+        CorpseDecaySchedule {
+            id: 0,
+            scheduled_at: ScheduleAt::Interval(check_interval),
+        },
+        "Corpse decay"
+    );
+    log::info!("[CorpseDecay] Corpse decay check scheduled every {} seconds", CORPSE_DECAY_CHECK_INTERVAL_SECS);
+    Ok(())
+}
+
+// --- Stage Helpers ---
+
+/// Decay stage reached purely from age, given when the corpse appeared and when it despawns
+pub fn decay_stage_for_age(created_at: Timestamp, despawn_at: Timestamp, now: Timestamp) -> CorpseDecayStage {
+    let lifetime = (despawn_at.to_micros_since_unix_epoch() - created_at.to_micros_since_unix_epoch()).max(1) as f32;
+    let age = (now.to_micros_since_unix_epoch() - created_at.to_micros_since_unix_epoch()).max(0) as f32;
+    let fraction = age / lifetime;
+    if fraction >= BONES_AT_LIFETIME_FRACTION {
+        CorpseDecayStage::Bones
+    } else if fraction >= DECAYING_AT_LIFETIME_FRACTION {
+        CorpseDecayStage::Decaying
+    } else {
+        CorpseDecayStage::Fresh
+    }
+}
+
+/// Scales a flesh/fat/hide harvest quantity by decay stage (bones are handled separately)
+pub fn apply_decay_to_yield(stage: CorpseDecayStage, quantity: u32) -> u32 {
+    match stage {
+        CorpseDecayStage::Fresh => quantity,
+        CorpseDecayStage::Decaying => ((quantity as f32) * DECAYING_YIELD_MULTIPLIER).round() as u32,
+        CorpseDecayStage::Bones => 0,
+    }
+}
+
+fn stage_rank(stage: CorpseDecayStage) -> u8 {
+    match stage {
+        CorpseDecayStage::Fresh => 0,
+        CorpseDecayStage::Decaying => 1,
+        CorpseDecayStage::Bones => 2,
+    }
+}
+
+/// A corpse a scavenger is feeding on
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ScavengedCorpse {
+    Animal(u32),
+    Player(u32),
+}
+
+fn is_attended(ctx: &ReducerContext, x: f32, y: f32) -> bool {
+    let attended_radius_sq = CORPSE_ATTENDED_RADIUS * CORPSE_ATTENDED_RADIUS;
+    ctx.db.player().iter()
+        .filter(|p| p.is_online && !p.is_dead)
+        .any(|p| get_distance_squared(p.position_x, p.position_y, x, y) <= attended_radius_sq)
+}
+
+/// Sleeping bodies of offline players are never eaten and never rot
+fn is_offline_corpse(ctx: &ReducerContext, corpse_id: u32) -> bool {
+    ctx.db.player().iter().any(|p| p.offline_corpse_id == Some(corpse_id))
+}
+
+fn offline_corpse_ids(ctx: &ReducerContext) -> HashSet<u32> {
+    ctx.db.player().iter().filter_map(|p| p.offline_corpse_id).collect()
+}
+
+/// Position of a corpse if scavengers can still feed on it (exists, not yet bones, no player nearby)
+pub fn edible_corpse_position(ctx: &ReducerContext, corpse: ScavengedCorpse) -> Option<(f32, f32)> {
+    let (pos_x, pos_y) = match corpse {
+        ScavengedCorpse::Animal(id) => {
+            let c = ctx.db.animal_corpse().id().find(id)?;
+            if c.decay_stage == CorpseDecayStage::Bones || c.health == 0 {
+                return None;
+            }
+            (c.pos_x, c.pos_y)
+        }
+        ScavengedCorpse::Player(id) => {
+            let c = ctx.db.player_corpse().id().find(id)?;
+            if c.decay_stage == CorpseDecayStage::Bones || c.health == 0 || is_offline_corpse(ctx, id) {
+                return None;
+            }
+            (c.pos_x, c.pos_y)
+        }
+    };
+    if is_attended(ctx, pos_x, pos_y) {
+        return None;
+    }
+    Some((pos_x, pos_y))
+}
+
+/// Nearest unattended corpse a scavenger at this position can smell
+pub fn find_nearest_edible_corpse(ctx: &ReducerContext, x: f32, y: f32) -> Option<(ScavengedCorpse, f32, f32)> {
+    let detection_radius_sq = SCAVENGER_CORPSE_DETECTION_RADIUS * SCAVENGER_CORPSE_DETECTION_RADIUS;
+    let in_range = |cx: f32, cy: f32| get_distance_squared(x, y, cx, cy) <= detection_radius_sq;
+
+    let mut candidates: Vec<(ScavengedCorpse, f32, f32)> = ctx.db.animal_corpse().iter()
+        .filter(|c| c.decay_stage != CorpseDecayStage::Bones && c.health > 0 && in_range(c.pos_x, c.pos_y))
+        .map(|c| (ScavengedCorpse::Animal(c.id), c.pos_x, c.pos_y))
+        .collect();
+    if candidates.is_empty() && ctx.db.player_corpse().iter().next().is_none() {
+        return None;
+    }
+    let offline_ids = offline_corpse_ids(ctx);
+    candidates.extend(ctx.db.player_corpse().iter()
+        .filter(|c| c.decay_stage != CorpseDecayStage::Bones && c.health > 0 && in_range(c.pos_x, c.pos_y))
+        .filter(|c| !offline_ids.contains(&c.id))
+        .map(|c| (ScavengedCorpse::Player(c.id), c.pos_x, c.pos_y)));
+
+    candidates.into_iter()
+        .filter(|(_, cx, cy)| !is_attended(ctx, *cx, *cy))
+        .min_by(|a, b| {
+            let da = get_distance_squared(x, y, a.1, a.2);
+            let db = get_distance_squared(x, y, b.1, b.2);
+            da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+        })
+}
+
+/// A scavenger takes a bite out of a corpse, eating into what's left to harvest.
+/// A corpse that's eaten down is picked clean to bones.
+pub fn take_scavenger_bite(ctx: &ReducerContext, corpse: ScavengedCorpse) {
+    match corpse {
+        ScavengedCorpse::Animal(id) => {
+            let Some(mut c) = ctx.db.animal_corpse().id().find(id) else { return };
+            if let Some((stage, health)) = advance_corpse(c.decay_stage, c.health, c.decay_stage, 1) {
+                log::debug!("[CorpseDecay] Scavenger fed on animal corpse {} (health {} -> {})", id, c.health, health);
+                c.decay_stage = stage;
+                c.health = health;
+                ctx.db.animal_corpse().id().update(c);
+            }
+        }
+        ScavengedCorpse::Player(id) => {
+            let Some(mut c) = ctx.db.player_corpse().id().find(id) else { return };
+            if let Some((stage, health)) = advance_corpse(c.decay_stage, c.health, c.decay_stage, 1) {
+                log::debug!("[CorpseDecay] Scavenger fed on player corpse {} (health {} -> {})", id, c.health, health);
+                c.decay_stage = stage;
+                c.health = health;
+                ctx.db.player_corpse().id().update(c);
+            }
+        }
+    }
+}
+
+/// Works out a corpse's new stage and health after aging and scavenger bites.
+/// Returns None if nothing changed.
+fn advance_corpse(
+    stage: CorpseDecayStage,
+    health: u32,
+    age_stage: CorpseDecayStage,
+    bites: u32,
+) -> Option<(CorpseDecayStage, u32)> {
+    let mut new_stage = if stage_rank(age_stage) > stage_rank(stage) { age_stage } else { stage };
+    let mut new_health = health;
+
+    if new_stage != CorpseDecayStage::Bones && bites > 0 {
+        new_health = new_health.saturating_sub(bites * SCAVENGER_BITE_DAMAGE);
+        if new_health == 0 {
+            new_stage = CorpseDecayStage::Bones; // Picked clean
+        }
+    }
+    if new_stage == CorpseDecayStage::Bones && stage != CorpseDecayStage::Bones {
+        // Whatever was left of the body, the bones are still all there
+        new_health = if new_health == 0 { BONE_PILE_HEALTH } else { new_health.min(BONE_PILE_HEALTH) };
+    }
+
+    if new_stage != stage || new_health != health {
+        Some((new_stage, new_health))
+    } else {
+        None
+    }
+}
+
+// --- Scheduled Decay ---
+
+/// Ages every corpse through its decay stages (scavenger bites are applied as they feed)
+#[spacetimedb::reducer]
+pub fn process_corpse_decay(ctx: &ReducerContext, _schedule: CorpseDecaySchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("process_corpse_decay may only be called by the scheduler.".to_string());
+    }
+
+    let animal_corpses = ctx.db.animal_corpse();
+    let player_corpses = ctx.db.player_corpse();
+    if animal_corpses.iter().next().is_none() && player_corpses.iter().next().is_none() {
+        return Ok(());
+    }
+    let now = ctx.timestamp;
+
+    let animal_updates: Vec<_> = animal_corpses.iter()
+        .filter_map(|mut corpse| {
+            let age_stage = decay_stage_for_age(corpse.death_time, corpse.despawn_at, now);
+            let (stage, health) = advance_corpse(corpse.decay_stage, corpse.health, age_stage, 0)?;
+            log::debug!("[CorpseDecay] Animal corpse {} {:?} -> {:?}", corpse.id, corpse.decay_stage, stage);
+            corpse.decay_stage = stage;
+            corpse.health = health;
+            Some(corpse)
+        })
+        .collect();
+    for corpse in animal_updates {
+        animal_corpses.id().update(corpse);
+    }
+
+    let offline_ids = offline_corpse_ids(ctx);
+    let player_updates: Vec<_> = player_corpses.iter()
+        .filter(|corpse| !offline_ids.contains(&corpse.id))
+        .filter_map(|mut corpse| {
+            let age_stage = decay_stage_for_age(corpse.death_time, corpse.despawn_scheduled_at, now);
+            let (stage, health) = advance_corpse(corpse.decay_stage, corpse.health, age_stage, 0)?;
+            log::debug!("[CorpseDecay] Player corpse {} {:?} -> {:?}", corpse.id, corpse.decay_stage, stage);
+            corpse.decay_stage = stage;
+            corpse.health = health;
+            Some(corpse)
+        })
+        .collect();
+    for corpse in player_updates {
+        player_corpses.id().update(corpse);
+    }
+
+    Ok(())
+}
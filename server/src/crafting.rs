@@ -28,7 +28,7 @@ pub struct Recipe {
     pub output_quantity: u32,    // How many items are crafted
     pub ingredients: Vec<RecipeIngredient>, // List of required ingredients
     pub crafting_time_secs: u32, // Time in seconds to craft
-    // pub required_station: Option<String>, // Future extension: e.g., "Workbench"
+    pub required_workbench_tier: u8, // Workbench tier needed nearby (0 = craftable anywhere)
 }
 
 // Function to get the initial set of recipes data (before resolving IDs)
@@ -138,6 +138,7 @@ pub fn seed_recipes(ctx: &ReducerContext) -> Result<(), String> {
                 output_quantity: output_qty,
                 ingredients: resolved_ingredients_for_recipe,
                 crafting_time_secs: time_secs,
+                required_workbench_tier: item_def_for_output.required_workbench_tier.unwrap_or(0),
             };
 
             // log::debug!("Inserting recipe for: {}", item_def_for_output.name);
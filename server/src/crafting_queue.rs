@@ -49,6 +49,127 @@ pub struct CraftingFinishSchedule {
 }
 
 const CRAFTING_CHECK_INTERVAL_SECS: u64 = 1; // Check every second
+const MAX_CRAFT_ALL_QUANTITY: u32 = 100; // Cap on a single "craft all" batch
+
+// --- Per-Player Queue Table ---
+/// Crafting order of one player's queue, kept in sync whenever the queue changes so the client
+/// can render a progress bar for every entry (each entry's start/finish live on CraftingQueueItem).
+#[spacetimedb::table(accessor = player_crafting_queue, public)]
+#[derive(Clone, Debug)]
+pub struct PlayerCraftingQueue {
+    #[primary_key]
+    pub player_identity: Identity,
+    pub entry_ids: Vec<u64>, // queue_item_ids in crafting order - the first one is in progress
+    pub queue_finish_time: Timestamp, // When the last entry finishes
+    pub updated_at: Timestamp,
+}
+
+// --- Queue Helpers ---
+
+/// A player's queue entries in crafting order (the first entry is the one in progress)
+fn player_queue_in_order(ctx: &ReducerContext, player_id: Identity) -> Vec<CraftingQueueItem> {
+    let mut entries: Vec<CraftingQueueItem> = ctx.db.crafting_queue_item().iter()
+        .filter(|q| q.player_identity == player_id)
+        .collect();
+    entries.sort_by_key(|q| q.finish_time);
+    entries
+}
+
+/// Rebuilds the player's public queue row from their queue entries
+pub(crate) fn sync_player_crafting_queue(ctx: &ReducerContext, player_id: Identity) {
+    let entries = player_queue_in_order(ctx, player_id);
+    let queue_rows = ctx.db.player_crafting_queue();
+    let Some(last) = entries.last() else {
+        queue_rows.player_identity().delete(player_id);
+        return;
+    };
+    let row = PlayerCraftingQueue {
+        player_identity: player_id,
+        entry_ids: entries.iter().map(|q| q.queue_item_id).collect(),
+        queue_finish_time: last.finish_time,
+        updated_at: ctx.timestamp,
+    };
+    if queue_rows.player_identity().find(player_id).is_some() {
+        queue_rows.player_identity().update(row);
+    } else {
+        queue_rows.insert(row);
+    }
+}
+
+fn craft_duration(item: &CraftingQueueItem) -> TimeDuration {
+    TimeDuration::from_micros(item.finish_time.to_micros_since_unix_epoch() - item.start_time.to_micros_since_unix_epoch())
+}
+
+/// Lays queue entries back to back from `start`, keeping each entry's craft duration
+fn resequence_queue(ctx: &ReducerContext, entries: Vec<CraftingQueueItem>, start: Timestamp) {
+    let queue_table = ctx.db.crafting_queue_item();
+    let mut next_start = start;
+    for mut entry in entries {
+        let duration = craft_duration(&entry);
+        entry.start_time = next_start;
+        entry.finish_time = next_start + duration;
+        next_start = entry.finish_time;
+        queue_table.queue_item_id().update(entry);
+    }
+}
+
+/// Share of an entry's materials returned on cancel: all of them if it hasn't started yet,
+/// otherwise the part of the craft still left to do.
+fn refund_fraction(item: &CraftingQueueItem, now: Timestamp) -> f32 {
+    let start = item.start_time.to_micros_since_unix_epoch();
+    let finish = item.finish_time.to_micros_since_unix_epoch();
+    let now = now.to_micros_since_unix_epoch();
+    if now <= start || finish <= start {
+        return 1.0;
+    }
+    ((finish - now) as f32 / (finish - start) as f32).clamp(0.0, 1.0)
+}
+
+fn scaled_refund(quantity: u32, fraction: f32) -> u32 {
+    (quantity as f32 * fraction).round() as u32
+}
+
+/// How many times the player can craft a recipe with what they're carrying
+fn max_craftable_quantity(ctx: &ReducerContext, player_id: Identity, recipe: &Recipe, output_item_def: &ItemDefinition) -> u32 {
+    let mut available: HashMap<u64, u32> = HashMap::new();
+    for item in ctx.db.inventory_item().iter() {
+        let is_in_player_possession = match &item.location {
+            ItemLocation::Inventory(crate::models::InventoryLocationData { owner_id, .. }) => *owner_id == player_id,
+            ItemLocation::Hotbar(crate::models::HotbarLocationData { owner_id, .. }) => *owner_id == player_id,
+            _ => false,
+        };
+        if is_in_player_possession {
+            *available.entry(item.item_def_id).or_insert(0) += item.quantity;
+        }
+    }
+
+    let item_defs = ctx.db.item_definition();
+    let mut max_quantity = MAX_CRAFT_ALL_QUANTITY;
+    let mut flexible_first_options: Vec<u64> = Vec::new();
+    if let Some(ref flex_ingredients) = output_item_def.flexible_ingredients {
+        for flex_ing in flex_ingredients {
+            let valid_ids: Vec<u64> = flex_ing.valid_items.iter()
+                .filter_map(|name| item_defs.iter().find(|d| &d.name == name).map(|d| d.id))
+                .collect();
+            if let Some(first) = valid_ids.first() {
+                flexible_first_options.push(*first);
+            }
+            if valid_ids.is_empty() || flex_ing.total_required == 0 {
+                continue;
+            }
+            let group_available: u32 = valid_ids.iter().map(|id| available.get(id).copied().unwrap_or(0)).sum();
+            max_quantity = max_quantity.min(group_available / flex_ing.total_required);
+        }
+    }
+    for ingredient in &recipe.ingredients {
+        if ingredient.quantity == 0 || flexible_first_options.contains(&ingredient.item_def_id) {
+            continue;
+        }
+        let have = available.get(&ingredient.item_def_id).copied().unwrap_or(0);
+        max_quantity = max_quantity.min(have / ingredient.quantity);
+    }
+    max_quantity
+}
 
 // --- Reducers ---
 
@@ -85,6 +206,12 @@ pub fn start_crafting(ctx: &ReducerContext, recipe_id: u64) -> Result<(), String
         }
     }
 
+    // 1c. Check Workbench Tier
+    crate::workbench::check_workbench_requirement(ctx, sender_id, recipe.required_workbench_tier, &output_item_def.name)?;
+
+    // 1d. Check Blueprint
+    crate::research::check_blueprint_requirement(ctx, sender_id, output_item_def.id, &output_item_def.name)?;
+
     // 2. Check Resources
     // Build a map of valid item IDs for each flexible ingredient group
     // Map: group_index -> (required_qty, Vec<valid_item_def_ids>)
@@ -254,9 +381,16 @@ pub fn start_crafting(ctx: &ReducerContext, recipe_id: u64) -> Result<(), String
     // Check for Memory Grid crafting speed nodes
     let memory_grid_multiplier = crate::memory_grid::get_crafting_speed_multiplier(ctx, sender_id);
     
+    // Check for the aurora blessing
+    let aurora_multiplier = if crate::active_effects::player_has_aurora_blessing_effect(ctx, sender_id) {
+        crate::active_effects::AURORA_CRAFTING_TIME_MULTIPLIER
+    } else {
+        1.0
+    };
+    
     let base_crafting_time = recipe.crafting_time_secs as f32;
-    // Apply both multipliers (red rune and memory grid)
-    let adjusted_crafting_time = (base_crafting_time * red_rune_multiplier * memory_grid_multiplier) as u64;
+    // Apply all multipliers (red rune, memory grid and aurora)
+    let adjusted_crafting_time = (base_crafting_time * red_rune_multiplier * memory_grid_multiplier * aurora_multiplier) as u64;
     let crafting_duration = Duration::from_secs(std::cmp::max(1, adjusted_crafting_time)); // Minimum 1 second
     let finish_time = last_finish_time + spacetimedb::TimeDuration::from(crafting_duration);
     
@@ -272,10 +406,11 @@ pub fn start_crafting(ctx: &ReducerContext, recipe_id: u64) -> Result<(), String
         recipe_id,
         output_item_def_id: recipe.output_item_def_id,
         output_quantity: recipe.output_quantity,
-        start_time: now,
+        start_time: last_finish_time, // Starts once the entries ahead of it are done
         finish_time,
     };
     queue_table.insert(queue_item);
+    sync_player_crafting_queue(ctx, sender_id);
 
     let item_name = ctx.db.item_definition().id().find(recipe.output_item_def_id).map(|d| d.name.clone()).unwrap_or_else(|| format!("ID {}", recipe.output_item_def_id));
     log::info!("[Crafting] Player {:?} started crafting {} (Recipe ID {}). Finish time: {:?}", sender_id, item_name, recipe_id, finish_time);
@@ -320,6 +455,12 @@ pub fn start_crafting_multiple(ctx: &ReducerContext, recipe_id: u64, quantity_to
         }
     }
 
+    // 1c. Check Workbench Tier
+    crate::workbench::check_workbench_requirement(ctx, sender_id, recipe.required_workbench_tier, &output_item_def.name)?;
+
+    // 1d. Check Blueprint
+    crate::research::check_blueprint_requirement(ctx, sender_id, output_item_def.id, &output_item_def.name)?;
+
     // 2. Check Resources for the total quantity
     // Build a map of valid item IDs for each flexible ingredient group
     let mut flexible_ingredient_groups: Vec<(u32, Vec<u64>)> = Vec::new();
@@ -477,9 +618,16 @@ pub fn start_crafting_multiple(ctx: &ReducerContext, recipe_id: u64, quantity_to
     // Check for Memory Grid crafting speed nodes
     let memory_grid_multiplier = crate::memory_grid::get_crafting_speed_multiplier(ctx, sender_id);
     
+    // Check for the aurora blessing
+    let aurora_multiplier = if crate::active_effects::player_has_aurora_blessing_effect(ctx, sender_id) {
+        crate::active_effects::AURORA_CRAFTING_TIME_MULTIPLIER
+    } else {
+        1.0
+    };
+    
     let base_crafting_time = recipe.crafting_time_secs as f32;
-    // Apply both multipliers (red rune and memory grid)
-    let adjusted_crafting_time = (base_crafting_time * red_rune_multiplier * memory_grid_multiplier) as u64;
+    // Apply all multipliers (red rune, memory grid and aurora)
+    let adjusted_crafting_time = (base_crafting_time * red_rune_multiplier * memory_grid_multiplier * aurora_multiplier) as u64;
     let crafting_duration_per_item = TimeDuration::from(Duration::from_secs(std::cmp::max(1, adjusted_crafting_time))); // Minimum 1 second
     
     if memory_grid_multiplier < 1.0 {
@@ -509,9 +657,27 @@ pub fn start_crafting_multiple(ctx: &ReducerContext, recipe_id: u64, quantity_to
                  sender_id, quantity_to_craft, item_name, recipe_id, item_finish_time, current_item_start_time);
         }
     }
+    sync_player_crafting_queue(ctx, sender_id);
     Ok(())
 }
 
+/// Queues as many crafts of a recipe as the player's materials allow (up to MAX_CRAFT_ALL_QUANTITY).
+#[spacetimedb::reducer]
+pub fn start_crafting_all(ctx: &ReducerContext, recipe_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let recipe = ctx.db.recipe().recipe_id().find(&recipe_id)
+        .ok_or(format!("Recipe with ID {} not found.", recipe_id))?;
+    let output_item_def = ctx.db.item_definition().id().find(recipe.output_item_def_id)
+        .ok_or(format!("Output item definition not found for recipe {}", recipe_id))?;
+
+    let quantity = max_craftable_quantity(ctx, sender_id, &recipe, &output_item_def);
+    if quantity == 0 {
+        return Err(format!("You don't have the materials to craft any {}.", output_item_def.name));
+    }
+    log::info!("[Crafting All] Player {:?} queueing {}x {} (Recipe ID {}).", sender_id, quantity, output_item_def.name, recipe_id);
+    start_crafting_multiple(ctx, recipe_id, quantity)
+}
+
 /// Scheduled reducer to check for and grant finished crafting items.
 #[spacetimedb::reducer]
 pub fn check_finished_crafting(ctx: &ReducerContext, _schedule: CraftingFinishSchedule) -> Result<(), String> {
@@ -553,6 +719,7 @@ pub fn check_finished_crafting(ctx: &ReducerContext, _schedule: CraftingFinishSc
             // Refund resources (or they are lost if player doesn't exist?)
             // For simplicity now, just delete the queue item. Refund on death handles it.
             queue_table.queue_item_id().delete(item.queue_item_id);
+            sync_player_crafting_queue(ctx, item.player_identity);
             continue; // Skip to next item
         }
 
@@ -638,12 +805,15 @@ pub fn check_finished_crafting(ctx: &ReducerContext, _schedule: CraftingFinishSc
 
         // Delete the finished item from the queue
         queue_table.queue_item_id().delete(item.queue_item_id);
+        sync_player_crafting_queue(ctx, item.player_identity);
     }
 
     Ok(())
 }
 
 /// Cancels a specific item in the player's crafting queue and refunds resources.
+/// Queued items refund everything; the item in progress refunds the share of the craft left to do.
+/// Entries behind the cancelled one move up to close the gap.
 #[spacetimedb::reducer]
 pub fn cancel_crafting_item(ctx: &ReducerContext, queue_item_id: u64) -> Result<(), String> {
     let sender_id = ctx.sender();
@@ -664,16 +834,21 @@ pub fn cancel_crafting_item(ctx: &ReducerContext, queue_item_id: u64) -> Result<
     let recipe = recipe_table.recipe_id().find(&queue_item.recipe_id)
         .ok_or(format!("Recipe {} for queue item {} not found.", queue_item.recipe_id, queue_item_id))?;
 
-    log::info!("[Crafting Cancel] Player {:?} cancelling queue item {} (Recipe ID {}). Refunding resources...",
-             sender_id, queue_item_id, queue_item.recipe_id);
+    let fraction = refund_fraction(&queue_item, ctx.timestamp);
+    log::info!("[Crafting Cancel] Player {:?} cancelling queue item {} (Recipe ID {}). Refunding {:.0}% of resources...",
+             sender_id, queue_item_id, queue_item.recipe_id, fraction * 100.0);
 
     // 4. Refund Resources
     let mut refund_failed = false;
     for ingredient in &recipe.ingredients {
-        match crate::items::add_item_to_player_inventory(ctx, sender_id, ingredient.item_def_id, ingredient.quantity) {
+        let refund_quantity = scaled_refund(ingredient.quantity, fraction);
+        if refund_quantity == 0 {
+            continue;
+        }
+        match crate::items::add_item_to_player_inventory(ctx, sender_id, ingredient.item_def_id, refund_quantity) {
             Ok(_) => {
                 let item_name = ctx.db.item_definition().id().find(ingredient.item_def_id).map(|d| d.name.clone()).unwrap_or_else(|| format!("ID {}", ingredient.item_def_id));
-                log::debug!("[Crafting Cancel] Refunded {} {} to player {:?}.", ingredient.quantity, item_name, sender_id);
+                log::debug!("[Crafting Cancel] Refunded {} {} to player {:?}.", refund_quantity, item_name, sender_id);
             }
             Err(e) => {
                 log::warn!("[Crafting Cancel] Inventory full for player {:?}. Dropping refunded item {}: {}", sender_id, ingredient.item_def_id, e);
@@ -681,7 +856,7 @@ pub fn cancel_crafting_item(ctx: &ReducerContext, queue_item_id: u64) -> Result<
                 // Find player position to drop item
                 if let Some(player) = player_table.identity().find(&sender_id) {
                      let (drop_x, drop_y) = dropped_item::calculate_drop_position(&player);
-                     if let Err(drop_err) = dropped_item::create_dropped_item_entity(ctx, ingredient.item_def_id, ingredient.quantity, drop_x, drop_y) {
+                     if let Err(drop_err) = dropped_item::create_dropped_item_entity(ctx, ingredient.item_def_id, refund_quantity, drop_x, drop_y) {
                          log::error!("[Crafting Cancel] Failed to drop refunded item {} for player {:?}: {}", ingredient.item_def_id, sender_id, drop_err);
                          // Resource is lost if dropping fails
                      }
@@ -696,6 +871,14 @@ pub fn cancel_crafting_item(ctx: &ReducerContext, queue_item_id: u64) -> Result<
     queue_table.queue_item_id().delete(queue_item_id);
     log::info!("[Crafting Cancel] Deleted queue item {}.", queue_item_id);
 
+    // 6. Move the entries behind it up - if it was in progress the next one starts now
+    let remaining = player_queue_in_order(ctx, sender_id);
+    if let Some(head) = remaining.first() {
+        let start = if queue_item.start_time <= ctx.timestamp { ctx.timestamp } else { head.start_time };
+        resequence_queue(ctx, remaining, start);
+    }
+    sync_player_crafting_queue(ctx, sender_id);
+
     if refund_failed {
         // Optionally return a specific error or warning if dropping occurred
         // Ok(()) // Or maybe return an error/warning string?
@@ -752,6 +935,7 @@ fn clear_player_crafting_queue_with_corpse_refund(ctx: &ReducerContext, player_i
     for queue_id in items_to_remove {
         queue_table.queue_item_id().delete(queue_id);
     }
+    sync_player_crafting_queue(ctx, player_id);
     log::info!("[Clear Queue] Deleted {} items from queue for player {:?}. Refunding {} resources...", 
              items_to_remove_count, player_id, resources_to_refund.len());
 
@@ -892,54 +1076,50 @@ fn refund_crafting_materials_to_inventory_or_drop(ctx: &ReducerContext, player_i
     }
 }
 
-/// Moves a crafting queue item to the front of the queue by swapping its start/finish times with the first item.
-/// Right-click on a queue item to prioritize it. Does not refund or lose any items.
+/// Moves a crafting queue item to a new position in the player's queue (0 = front).
+/// Entries are laid back to back from the current head's start, so the in-progress craft
+/// keeps its progress only if it stays in front. Does not refund or lose any items.
 #[spacetimedb::reducer]
-pub fn move_crafting_queue_item_to_front(ctx: &ReducerContext, queue_item_id: u64) -> Result<(), String> {
+pub fn move_crafting_queue_item(ctx: &ReducerContext, queue_item_id: u64, new_index: u32) -> Result<(), String> {
     let sender_id = ctx.sender();
     let queue_table = ctx.db.crafting_queue_item();
 
-    // 1. Find the queue item
-    let mut target_item = queue_table.queue_item_id().find(&queue_item_id)
+    // 1. Find the queue item and verify ownership
+    let target_item = queue_table.queue_item_id().find(&queue_item_id)
         .ok_or(format!("Crafting queue item {} not found.", queue_item_id))?;
-
-    // 2. Verify ownership
     if target_item.player_identity != sender_id {
         return Err("Cannot reorder crafting items for another player.".to_string());
     }
 
-    // 3. Find the first item in this player's queue (earliest finish_time)
-    let first_item = queue_table.iter()
-        .filter(|q| q.player_identity == sender_id)
-        .min_by_key(|q| q.finish_time);
-
-    let Some(mut first_item) = first_item else {
-        return Err("No items in crafting queue.".to_string());
-    };
-
-    // 4. If target is already first, nothing to do
-    if first_item.queue_item_id == target_item.queue_item_id {
+    // 2. Move it within the ordered queue
+    let mut entries = player_queue_in_order(ctx, sender_id);
+    let queue_start = entries[0].start_time;
+    let current_index = entries.iter().position(|q| q.queue_item_id == queue_item_id)
+        .ok_or("Crafting queue item not found in queue.".to_string())?;
+    let new_index = (new_index as usize).min(entries.len() - 1);
+    if current_index == new_index {
         return Ok(());
     }
+    let moved = entries.remove(current_index);
+    entries.insert(new_index, moved);
 
-    // 5. Swap start_time and finish_time between the two items
-    let (target_start, target_finish) = (target_item.start_time, target_item.finish_time);
-    let (first_start, first_finish) = (first_item.start_time, first_item.finish_time);
-
-    target_item.start_time = first_start;
-    target_item.finish_time = first_finish;
-    first_item.start_time = target_start;
-    first_item.finish_time = target_finish;
+    // 3. Re-lay the queue from where it currently starts
+    resequence_queue(ctx, entries, queue_start);
+    sync_player_crafting_queue(ctx, sender_id);
 
-    // 6. Update both rows
-    queue_table.queue_item_id().update(target_item);
-    queue_table.queue_item_id().update(first_item);
-
-    log::info!("[Crafting Reorder] Player {:?} moved queue item {} to front.", sender_id, queue_item_id);
+    log::info!("[Crafting Reorder] Player {:?} moved queue item {} from position {} to {}.", sender_id, queue_item_id, current_index, new_index);
     Ok(())
 }
 
-/// Cancels all items in the player's crafting queue and refunds all resources.
+/// Moves a crafting queue item to the front of the queue.
+/// Right-click on a queue item to prioritize it. Does not refund or lose any items.
+#[spacetimedb::reducer]
+pub fn move_crafting_queue_item_to_front(ctx: &ReducerContext, queue_item_id: u64) -> Result<(), String> {
+    move_crafting_queue_item(ctx, queue_item_id, 0)
+}
+
+/// Cancels all items in the player's crafting queue and refunds resources
+/// (the item in progress refunds the share of the craft left to do).
 #[spacetimedb::reducer]
 pub fn cancel_all_crafting(ctx: &ReducerContext) -> Result<(), String> {
     let sender_id = ctx.sender();
@@ -957,8 +1137,9 @@ pub fn cancel_all_crafting(ctx: &ReducerContext) -> Result<(), String> {
     for item in queue_table.iter().filter(|q| q.player_identity == sender_id) {
         items_to_remove_from_queue.push(item.queue_item_id);
         if let Some(recipe) = recipe_table.recipe_id().find(&item.recipe_id) {
+            let fraction = refund_fraction(&item, ctx.timestamp);
             for ingredient in &recipe.ingredients {
-                *total_resources_to_refund.entry(ingredient.item_def_id).or_insert(0) += ingredient.quantity;
+                *total_resources_to_refund.entry(ingredient.item_def_id).or_insert(0) += scaled_refund(ingredient.quantity, fraction);
             }
         } else {
             log::warn!("[Cancel All Crafting] Recipe {} not found for queue item {}. Resources for this item might not be refunded.", item.recipe_id, item.queue_item_id);
@@ -974,6 +1155,7 @@ pub fn cancel_all_crafting(ctx: &ReducerContext) -> Result<(), String> {
     for queue_id in &items_to_remove_from_queue {
         queue_table.queue_item_id().delete(*queue_id);
     }
+    sync_player_crafting_queue(ctx, sender_id);
     log::info!("[Cancel All Crafting] Deleted {} items from queue for player {:?}. Now refunding resources.", items_to_remove_from_queue.len(), sender_id);
 
     // 3. Refund all collected resources
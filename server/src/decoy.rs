@@ -0,0 +1,270 @@
+/******************************************************************************
+ *                                                                            *
+ * Noisemaker decoys for stealth play. A placed decoy sits quietly until a   *
+ * player triggers it, then rattles for 30 seconds - each rattle is a noise  *
+ * (see sound_events::register_noise) loud enough to drown out thrown        *
+ * stones and powered tools, so hostile NPCs and wolves that haven't         *
+ * spotted anyone wander over to investigate it. Spent decoys are removed.   *
+ * An untriggered decoy can be picked back up by whoever placed it.          *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, Timestamp, TimeDuration};
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::Player;
+use crate::items::{
+    InventoryItem,
+    inventory_item as InventoryItemTableTrait,
+    item_definition as ItemDefinitionTableTrait,
+};
+use crate::player_inventory::find_first_empty_player_slot;
+use crate::environment::calculate_chunk_index;
+
+// --- Constants ---
+pub const NOISEMAKER_DECOY_ITEM_NAME: &str = "Noisemaker Decoy";
+
+/// How long a triggered decoy keeps making noise
+pub const DECOY_ACTIVE_DURATION_SECS: i64 = 30;
+/// Time between rattles (each noise lasts sound_events::NOISE_LIFETIME_MICROS, so this keeps it continuous)
+pub const DECOY_PULSE_INTERVAL_SECS: u64 = 2;
+pub const DECOY_NOISE_RADIUS: f32 = 800.0;
+/// Louder than thrown stones and powered tools, quieter than gunfire
+pub const DECOY_NOISE_INTENSITY: f32 = 0.7;
+
+const DECOY_PLACEMENT_MAX_DISTANCE: f32 = 150.0;
+const DECOY_INTERACTION_DISTANCE_SQUARED: f32 = 120.0 * 120.0;
+
+/// --- Noisemaker Decoy Data Structure ---
+/// A placed decoy. `triggered_at` and `expires_at` are set once it starts rattling.
+#[spacetimedb::table(accessor = noisemaker_decoy, public)]
+#[derive(Clone, Debug)]
+pub struct NoisemakerDecoy {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub chunk_index: u32,
+    pub placed_by: Identity,
+    pub placed_at: Timestamp,
+    pub triggered_by: Option<Identity>,
+    pub triggered_at: Option<Timestamp>,
+    pub expires_at: Option<Timestamp>,
+}
+
+// --- Decoy Pulse Schedule ---
+#[spacetimedb::table(accessor = noisemaker_decoy_schedule, scheduled(process_noisemaker_decoys))]
+#[derive(Clone, Debug)]
+pub struct NoisemakerDecoySchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// --- Initialization ---
+pub fn init_decoy_system(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.noisemaker_decoy_schedule().iter().next().is_some() {
+        return Ok(());
+    }
+
+    let pulse_interval = TimeDuration::from_micros(DECOY_PULSE_INTERVAL_SECS as i64 * 1_000_000);
+    crate::try_insert_schedule!(
+        ctx.db.noisemaker_decoy_schedule(),
+        NoisemakerDecoySchedule {
+            id: 0,
+            scheduled_at: ScheduleAt::Interval(pulse_interval),
+        },
+        "Noisemaker decoy pulse"
+    );
+    log::info!("[DecoyInit] Noisemaker decoy pulse scheduled every {} seconds", DECOY_PULSE_INTERVAL_SECS);
+    Ok(())
+}
+
+// --- Helper Functions ---
+
+/// Validates that the player can reach a decoy
+fn validate_decoy_interaction(ctx: &ReducerContext, decoy_id: u32) -> Result<(Player, NoisemakerDecoy), String> {
+    let player = ctx.db.player().identity().find(&ctx.sender())
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot interact with decoy while dead.".to_string());
+    }
+    if player.is_knocked_out {
+        return Err("Cannot interact with decoy while knocked out.".to_string());
+    }
+
+    let decoy = ctx.db.noisemaker_decoy().id().find(&decoy_id)
+        .ok_or_else(|| format!("Decoy {} not found.", decoy_id))?;
+
+    let dx = decoy.pos_x - player.position_x;
+    let dy = decoy.pos_y - player.position_y;
+    if dx * dx + dy * dy > DECOY_INTERACTION_DISTANCE_SQUARED {
+        return Err("Too far away from decoy.".to_string());
+    }
+
+    Ok((player, decoy))
+}
+
+fn emit_decoy_noise(ctx: &ReducerContext, decoy: &NoisemakerDecoy) {
+    let triggered_by = decoy.triggered_by.unwrap_or(decoy.placed_by);
+    crate::sound_events::register_noise(ctx, decoy.pos_x, decoy.pos_y, DECOY_NOISE_RADIUS, DECOY_NOISE_INTENSITY, triggered_by);
+}
+
+// --- Reducers ---
+
+/// --- Place Noisemaker Decoy ---
+/// Places an untriggered decoy from the player's inventory.
+#[spacetimedb::reducer]
+pub fn place_noisemaker_decoy(ctx: &ReducerContext, item_instance_id: u64, world_x: f32, world_y: f32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+
+    crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
+
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot place decoy while dead.".to_string());
+    }
+
+    // --- Validate item ---
+    let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+        .ok_or_else(|| "Item not found.".to_string())?;
+
+    if item.location.is_player_bound() != Some(sender_id) {
+        return Err("You don't own this item.".to_string());
+    }
+
+    let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
+        .ok_or_else(|| "Item definition not found.".to_string())?;
+
+    if item_def.name != NOISEMAKER_DECOY_ITEM_NAME {
+        return Err("This item is not a decoy.".to_string());
+    }
+
+    // --- Validate placement position ---
+    let dx = world_x - player.position_x;
+    let dy = world_y - player.position_y;
+    if dx * dx + dy * dy > DECOY_PLACEMENT_MAX_DISTANCE * DECOY_PLACEMENT_MAX_DISTANCE {
+        return Err("Cannot place decoy that far away.".to_string());
+    }
+
+    if crate::environment::is_position_on_water(ctx, world_x, world_y) {
+        return Err("Cannot place decoy on water.".to_string());
+    }
+
+    if crate::building::is_position_on_wall(ctx, world_x, world_y) {
+        return Err("Cannot place decoy on a wall.".to_string());
+    }
+
+    crate::placeable_collision::check_placeable_overlap(ctx, world_x, world_y, 24.0, 24.0, true)?;
+
+    ctx.db.noisemaker_decoy().try_insert(NoisemakerDecoy {
+        id: 0, // Auto-increment
+        pos_x: world_x,
+        pos_y: world_y,
+        chunk_index: calculate_chunk_index(world_x, world_y),
+        placed_by: sender_id,
+        placed_at: ctx.timestamp,
+        triggered_by: None,
+        triggered_at: None,
+        expires_at: None,
+    }).map_err(|e| format!("Failed to place decoy: {}", e))?;
+
+    // Consume one decoy item
+    if item.quantity > 1 {
+        let mut updated_item = item.clone();
+        updated_item.quantity -= 1;
+        ctx.db.inventory_item().instance_id().update(updated_item);
+    } else {
+        ctx.db.inventory_item().instance_id().delete(&item_instance_id);
+    }
+
+    log::info!("Player {:?} placed noisemaker decoy at ({:.1}, {:.1})", sender_id, world_x, world_y);
+    Ok(())
+}
+
+/// --- Trigger Noisemaker Decoy ---
+/// Starts the decoy rattling. Anyone can trigger a decoy; it can't be stopped once started.
+#[spacetimedb::reducer]
+pub fn trigger_noisemaker_decoy(ctx: &ReducerContext, decoy_id: u32) -> Result<(), String> {
+    let (_player, mut decoy) = validate_decoy_interaction(ctx, decoy_id)?;
+
+    if decoy.triggered_at.is_some() {
+        return Err("Decoy is already going off.".to_string());
+    }
+
+    decoy.triggered_by = Some(ctx.sender());
+    decoy.triggered_at = Some(ctx.timestamp);
+    decoy.expires_at = Some(ctx.timestamp + TimeDuration::from_micros(DECOY_ACTIVE_DURATION_SECS * 1_000_000));
+    emit_decoy_noise(ctx, &decoy);
+    ctx.db.noisemaker_decoy().id().update(decoy);
+
+    log::info!("Player {:?} triggered noisemaker decoy {}", ctx.sender(), decoy_id);
+    Ok(())
+}
+
+/// --- Pickup Noisemaker Decoy ---
+/// Returns an untriggered decoy to the player who placed it.
+#[spacetimedb::reducer]
+pub fn pickup_noisemaker_decoy(ctx: &ReducerContext, decoy_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let (_player, decoy) = validate_decoy_interaction(ctx, decoy_id)?;
+
+    if decoy.placed_by != sender_id {
+        return Err("You can only pick up your own decoys.".to_string());
+    }
+    if decoy.triggered_at.is_some() {
+        return Err("Cannot pick up a decoy that's going off.".to_string());
+    }
+
+    let item_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == NOISEMAKER_DECOY_ITEM_NAME)
+        .ok_or_else(|| format!("{} item definition not found.", NOISEMAKER_DECOY_ITEM_NAME))?;
+
+    let new_location = find_first_empty_player_slot(ctx, sender_id)
+        .ok_or_else(|| format!("Player inventory is full, cannot pickup {}.", NOISEMAKER_DECOY_ITEM_NAME))?;
+
+    ctx.db.inventory_item().try_insert(InventoryItem {
+        instance_id: 0, // Auto-inc
+        item_def_id: item_def.id,
+        quantity: 1,
+        location: new_location,
+        item_data: None,
+    }).map_err(|e| format!("Failed to insert {} item: {}", NOISEMAKER_DECOY_ITEM_NAME, e))?;
+
+    ctx.db.noisemaker_decoy().id().delete(decoy_id);
+
+    log::info!("Player {:?} picked up noisemaker decoy {}", sender_id, decoy_id);
+    Ok(())
+}
+
+// --- Scheduled Pulse ---
+
+/// Re-emits noise for every active decoy and removes spent ones
+#[spacetimedb::reducer]
+pub fn process_noisemaker_decoys(ctx: &ReducerContext, _schedule: NoisemakerDecoySchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("process_noisemaker_decoys may only be called by the scheduler.".to_string());
+    }
+
+    let decoys = ctx.db.noisemaker_decoy();
+    let active: Vec<NoisemakerDecoy> = decoys.iter()
+        .filter(|decoy| decoy.expires_at.is_some())
+        .collect();
+
+    for decoy in active {
+        if decoy.expires_at.map_or(false, |expires_at| expires_at <= ctx.timestamp) {
+            log::info!("[Decoy] Noisemaker decoy {} at ({:.1}, {:.1}) is spent", decoy.id, decoy.pos_x, decoy.pos_y);
+            decoys.id().delete(decoy.id);
+        } else {
+            emit_decoy_noise(ctx, &decoy);
+        }
+    }
+    Ok(())
+}
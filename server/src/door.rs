@@ -4,15 +4,16 @@
  *                                                                            *
  * Handles placement, opening/closing, and pickup of doors.                   *
  * Doors can only be placed on North/South edges of foundations.              *
+ * Doors can carry a code lock or a key lock that lets guests through         *
+ * without hearth building privilege.                                         *
  *                                                                            *
  ******************************************************************************/
 
 use spacetimedb::{Identity, Timestamp, ReducerContext, Table, log};
 use crate::{
-    models::{BuildingEdge, ItemLocation, InventoryLocationData},
+    models::{BuildingEdge, WallType, ItemLocation, InventoryLocationData},
     environment::calculate_chunk_index,
     building::{FOUNDATION_TILE_SIZE_PX, BUILDING_PLACEMENT_MAX_DISTANCE_SQUARED, BUILDING_PLACEMENT_MAX_DISTANCE, DOOR_WOOD_MAX_HEALTH, DOOR_METAL_MAX_HEALTH},
-    homestead_hearth::homestead_hearth, // Import the trait for accessing homestead_hearth table
 };
 use crate::player as PlayerTableTrait;
 use crate::items::{item_definition as ItemDefinitionTableTrait, inventory_item as InventoryItemTableTrait};
@@ -28,6 +29,19 @@ pub const DOOR_TYPE_METAL: u8 = 1;
 /// Door collision thickness (same as walls)
 pub const DOOR_COLLISION_THICKNESS: f32 = 6.0;
 
+/// Door lock types
+pub const DOOR_LOCK_NONE: u8 = 0;
+pub const DOOR_LOCK_CODE: u8 = 1;
+pub const DOOR_LOCK_KEY: u8 = 2;
+
+/// Code locks use a fixed-length numeric code
+pub const DOOR_CODE_LENGTH: usize = 4;
+
+/// Key item and the resources needed to cut one
+pub const DOOR_KEY_ITEM_NAME: &str = "Door Key";
+pub const DOOR_KEY_WOOD_COST: u32 = 10;
+pub const DOOR_KEY_METAL_COST: u32 = 15;
+
 /// Door interaction distance (same as other building objects like campfires, storage boxes)
 pub const DOOR_INTERACTION_DISTANCE: f32 = 96.0; // Standard interaction distance (matches campfire, storage box, etc.)
 pub const DOOR_INTERACTION_DISTANCE_SQUARED: f32 = DOOR_INTERACTION_DISTANCE * DOOR_INTERACTION_DISTANCE;
@@ -61,6 +75,21 @@ pub struct Door {
     pub destroyed_at: Option<Timestamp>,
     pub last_hit_time: Option<Timestamp>,
     pub last_damaged_by: Option<Identity>,
+    pub lock_type: u8,        // 0 = None, 1 = Code, 2 = Key
+}
+
+// --- Door Lock Table ---
+
+/// Lock secrets for a door (private - the code and guest list never reach clients)
+#[spacetimedb::table(accessor = door_lock)]
+#[derive(Clone, Debug)]
+pub struct DoorLock {
+    #[primary_key]
+    pub door_id: u64,
+    pub code: Option<String>,                 // Set while the door has a code lock
+    pub authorized_identities: Vec<Identity>, // Players who entered the correct code
+    pub key_generation: u32,                  // Bumped when a lock is removed so old keys stop working
+    pub updated_at: Timestamp,
 }
 
 // --- Helper Functions ---
@@ -83,6 +112,119 @@ fn get_door_max_health(door_type: u8) -> f32 {
     }
 }
 
+/// Item data stored on a key: the door it opens and the lock generation it was cut for
+fn door_key_item_data(door_id: u64, key_generation: u32) -> String {
+    serde_json::json!({ "door_id": door_id, "key_generation": key_generation }).to_string()
+}
+
+/// Reads the door binding from a key's item data
+fn parse_door_key_item_data(item: &crate::items::InventoryItem) -> Option<(u64, u32)> {
+    let data_str = item.item_data.as_ref()?;
+    let json_value = serde_json::from_str::<serde_json::Value>(data_str).ok()?;
+    let door_id = json_value.get("door_id")?.as_u64()?;
+    let key_generation = json_value.get("key_generation")?.as_u64()? as u32;
+    Some((door_id, key_generation))
+}
+
+/// Checks if a player carries a key cut for the door's current lock
+fn player_has_door_key(ctx: &ReducerContext, player_id: Identity, door_id: u64, key_generation: u32) -> bool {
+    let key_def_id = match ctx.db.item_definition().iter().find(|def| def.name == DOOR_KEY_ITEM_NAME) {
+        Some(def) => def.id,
+        None => return false,
+    };
+
+    ctx.db.inventory_item().iter().any(|item| {
+        item.item_def_id == key_def_id
+            && item.location.is_player_bound() == Some(player_id)
+            && parse_door_key_item_data(&item) == Some((door_id, key_generation))
+    })
+}
+
+/// Fetches the lock row for a door, creating an empty one if needed
+fn get_or_create_door_lock(ctx: &ReducerContext, door_id: u64) -> DoorLock {
+    let locks = ctx.db.door_lock();
+    if let Some(lock) = locks.door_id().find(&door_id) {
+        return lock;
+    }
+    locks.insert(DoorLock {
+        door_id,
+        code: None,
+        authorized_identities: Vec::new(),
+        key_generation: 0,
+        updated_at: ctx.timestamp,
+    })
+}
+
+/// Removes a door's lock data (door picked up or destroyed)
+pub(crate) fn clear_door_lock(ctx: &ReducerContext, door_id: u64) {
+    ctx.db.door_lock().door_id().delete(&door_id);
+}
+
+/// Checks if a player can open/close a door
+/// Inside a hearth's privilege zone: authorized players, or guests let in by the lock
+/// On unclaimed land: anyone if unlocked, otherwise the owner or guests let in by the lock
+pub fn can_player_use_door(ctx: &ReducerContext, door: &Door, player_id: Identity) -> bool {
+    use crate::homestead_hearth::{is_position_claimed, is_position_privileged};
+
+    let claimed = is_position_claimed(ctx, door.pos_x, door.pos_y);
+    if claimed && is_position_privileged(ctx, door.pos_x, door.pos_y, player_id) {
+        return true;
+    }
+    if !claimed && (door.lock_type == DOOR_LOCK_NONE || door.owner_id == player_id) {
+        return true;
+    }
+
+    let lock = match ctx.db.door_lock().door_id().find(&door.id) {
+        Some(lock) => lock,
+        None => return false,
+    };
+    match door.lock_type {
+        DOOR_LOCK_CODE => lock.authorized_identities.contains(&player_id),
+        DOOR_LOCK_KEY => player_has_door_key(ctx, player_id, door.id, lock.key_generation),
+        _ => false,
+    }
+}
+
+/// Checks if a player may install, change, or remove a door's lock
+/// Requires hearth privilege inside a claimed zone, or door ownership on unclaimed land
+fn can_player_manage_door_lock(ctx: &ReducerContext, door: &Door, player_id: Identity) -> bool {
+    use crate::homestead_hearth::{is_position_claimed, is_position_privileged};
+
+    if is_position_claimed(ctx, door.pos_x, door.pos_y) {
+        is_position_privileged(ctx, door.pos_x, door.pos_y, player_id)
+    } else {
+        door.owner_id == player_id
+    }
+}
+
+/// Common checks for lock reducers: player alive, door exists and is in reach
+fn validate_door_lock_interaction(ctx: &ReducerContext, door_id: u64) -> Result<(crate::Player, Door), String> {
+    let sender_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot use door locks while dead.".to_string());
+    }
+    if player.is_knocked_out {
+        return Err("Cannot use door locks while knocked out.".to_string());
+    }
+
+    let door = ctx.db.door().id().find(&door_id)
+        .ok_or_else(|| "Door not found".to_string())?;
+    if door.is_destroyed {
+        return Err("Door is destroyed.".to_string());
+    }
+
+    let dx = door.pos_x - player.position_x;
+    let dy = door.pos_y - player.position_y;
+    if dx * dx + dy * dy > DOOR_INTERACTION_DISTANCE_SQUARED {
+        return Err("Door is too far away.".to_string());
+    }
+
+    Ok((player, door))
+}
+
 /// Check if a door position is valid (foundation exists, N/S edge only, no existing door/wall)
 pub fn is_door_position_valid(
     ctx: &ReducerContext,
@@ -110,10 +252,10 @@ pub fn is_door_position_valid(
         return Err("Cannot place door: no foundation at this location.".to_string());
     }
     
-    // 3. Check if there's already a wall at this edge
+    // 3. Check if there's already a wall at this edge (doorframes are meant to hold a door)
     let walls = ctx.db.wall_cell();
     for wall in walls.idx_cell_coords().filter((cell_x, cell_y)) {
-        if !wall.is_destroyed && wall.edge == edge as u8 {
+        if !wall.is_destroyed && wall.edge == edge as u8 && wall.wall_type != WallType::Doorframe as u8 {
             return Err("A wall already exists at this edge.".to_string());
         }
     }
@@ -135,7 +277,7 @@ pub fn is_door_position_valid(
     
     // Check adjacent cell for walls on the opposite edge
     for wall in walls.idx_cell_coords().filter((adjacent_cell_x, adjacent_cell_y)) {
-        if !wall.is_destroyed && wall.edge == opposite_edge {
+        if !wall.is_destroyed && wall.edge == opposite_edge && wall.wall_type != WallType::Doorframe as u8 {
             return Err("A wall already exists on the shared edge with the adjacent tile.".to_string());
         }
     }
@@ -168,7 +310,7 @@ pub fn calculate_door_position(cell_x: i32, cell_y: i32, edge: BuildingEdge) ->
 /// Doors resist melee damage significantly to prevent bypassing walls
 /// Wood Door (type 0): Same resistance as Stone walls (10%)
 /// Metal Door (type 1): Same resistance as Metal walls (5%)
-fn get_door_damage_multiplier(door_type: u8) -> f32 {
+pub fn get_door_damage_multiplier(door_type: u8) -> f32 {
     match door_type {
         DOOR_TYPE_WOOD => crate::building::MELEE_DAMAGE_MULT_STONE,  // Wood doors = Stone wall resistance
         DOOR_TYPE_METAL => crate::building::MELEE_DAMAGE_MULT_METAL, // Metal doors = Metal wall resistance
@@ -176,126 +318,6 @@ fn get_door_damage_multiplier(door_type: u8) -> f32 {
     }
 }
 
-/// Apply damage to a door and handle destruction
-/// Damage is reduced based on door type - melee is ineffective vs doors (like walls)
-pub fn damage_door(
-    ctx: &ReducerContext,
-    attacker_id: Identity,
-    door_id: u64,
-    damage: f32,
-    current_time: Timestamp,
-) -> Result<(), String> {
-    let doors = ctx.db.door();
-    
-    let mut door = doors.id().find(door_id)
-        .ok_or_else(|| format!("Door {} not found", door_id))?;
-    
-    if door.is_destroyed {
-        return Err(format!("Door {} is already destroyed", door_id));
-    }
-    
-    // <<< PVP RAIDING CHECK >>>
-    if let Some(attacker_player) = ctx.db.player().identity().find(&attacker_id) {
-        let attacker_pvp = crate::combat::is_pvp_active_for_player(&attacker_player, current_time);
-        
-        // Check if owner has PvP enabled (if owner is not the attacker)
-        if door.owner_id != attacker_id {
-            if let Some(owner_player) = ctx.db.player().identity().find(&door.owner_id) {
-                let owner_pvp = crate::combat::is_pvp_active_for_player(&owner_player, current_time);
-                
-                if !attacker_pvp || !owner_pvp {
-                    log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", 
-                        attacker_pvp, owner_pvp);
-                    return Err("Cannot damage structure - PvP raiding requires both players to have PvP enabled.".to_string());
-                }
-            }
-        }
-    }
-    // <<< END PVP RAIDING CHECK >>>
-    
-    // Apply melee damage reduction based on door type
-    // Doors resist melee significantly to prevent bypassing walls
-    let damage_multiplier = get_door_damage_multiplier(door.door_type);
-    let effective_damage = damage * damage_multiplier;
-    
-    // Apply damage
-    door.health = (door.health - effective_damage).max(0.0);
-    door.last_hit_time = Some(current_time);
-    door.last_damaged_by = Some(attacker_id);
-    
-    let door_name = if door.door_type == DOOR_TYPE_WOOD { "Wood" } else { "Metal" };
-    log::info!(
-        "[DoorDamage] {} Door {} took {:.1} damage (base: {:.1}, mult: {:.0}%) from {:?}. Health: {:.1}/{:.1}",
-        door_name, door_id, effective_damage, damage, damage_multiplier * 100.0, attacker_id, door.health, door.max_health
-    );
-    
-    // Check if door is destroyed
-    if door.health <= 0.0 {
-        door.is_destroyed = true;
-        door.destroyed_at = Some(current_time);
-        log::info!("[DoorDamage] {} Door {} destroyed by {:?}", door_name, door_id, attacker_id);
-    }
-    
-    // Update door in database
-    ctx.db.door().id().update(door);
-    
-    Ok(())
-}
-
-/// Applies explosive damage to a door (bypasses melee damage reduction)
-/// Used by explosion system - explosives are effective against all door types
-pub fn damage_door_explosive(
-    ctx: &ReducerContext,
-    attacker_id: Identity,
-    door_id: u64,
-    damage: f32,
-) {
-    let doors = ctx.db.door();
-    
-    if let Some(mut door) = doors.id().find(door_id) {
-        if door.is_destroyed {
-            return;
-        }
-        
-        // <<< PVP RAIDING CHECK >>>
-        if let Some(attacker_player) = ctx.db.player().identity().find(&attacker_id) {
-            let attacker_pvp = crate::combat::is_pvp_active_for_player(&attacker_player, ctx.timestamp);
-            
-            if door.owner_id != attacker_id {
-                if let Some(owner_player) = ctx.db.player().identity().find(&door.owner_id) {
-                    let owner_pvp = crate::combat::is_pvp_active_for_player(&owner_player, ctx.timestamp);
-                    
-                    if !attacker_pvp || !owner_pvp {
-                        log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", 
-                            attacker_pvp, owner_pvp);
-                        return; // Skip this structure in explosion
-                    }
-                }
-            }
-        }
-        // <<< END PVP RAIDING CHECK >>>
-        
-        // Explosive damage bypasses melee reduction - full damage to all door types
-        let old_health = door.health;
-        door.health = (door.health - damage).max(0.0);
-        door.last_hit_time = Some(ctx.timestamp);
-        
-        let door_name = if door.door_type == DOOR_TYPE_WOOD { "Wood" } else { "Metal" };
-        
-        if door.health <= 0.0 {
-            door.is_destroyed = true;
-            door.destroyed_at = Some(ctx.timestamp);
-            crate::sound_events::emit_door_destroyed_sound(ctx, door.pos_x, door.pos_y, ctx.sender());
-            log::info!("[DoorExplosiveDamage] {} Door {} destroyed by explosion", door_name, door_id);
-        } else {
-            crate::sound_events::emit_melee_hit_sharp_sound(ctx, door.pos_x, door.pos_y, ctx.sender());
-            log::info!("[DoorExplosiveDamage] {} Door {} took {:.1} explosive damage, health: {:.1}", door_name, door_id, damage, door.health);
-        }
-        
-        doors.id().update(door);
-    }
-}
-
 /// Check if a world position collides with any closed door
 /// Returns pushback vector if collision detected
 /// Uses circle-AABB collision detection (like walls)
@@ -593,6 +615,9 @@ pub fn place_door(
         ));
     }
     
+    // 5.5. Check building privilege at the door's position
+    crate::homestead_hearth::check_position_privileged(ctx, door_pos_x, door_pos_y, sender_id)?;
+    
     // 6. Check and consume door item from inventory
     let door_item_name = get_door_item_name(door_type);
     let inventory = ctx.db.inventory_item();
@@ -657,6 +682,7 @@ pub fn place_door(
         destroyed_at: None,
         last_hit_time: None,
         last_damaged_by: None,
+        lock_type: DOOR_LOCK_NONE,
     };
     
     match doors.try_insert(new_door) {
@@ -674,7 +700,7 @@ pub fn place_door(
     }
 }
 
-/// Toggle door open/closed state (requires building privilege)
+/// Toggle door open/closed state (requires building privilege or lock access)
 #[spacetimedb::reducer]
 pub fn interact_door(ctx: &ReducerContext, door_id: u64) -> Result<(), String> {
     let sender_id = ctx.sender();
@@ -703,15 +729,15 @@ pub fn interact_door(ctx: &ReducerContext, door_id: u64) -> Result<(), String> {
         return Err("Door is destroyed.".to_string());
     }
     
-    // 3. Check building privilege - anyone with privilege can open/close doors
-    // EARLY GAME: If no hearths exist, allow anyone to use doors (pre-privilege phase)
-    // LATE GAME: Once hearths exist, require building privilege (prevents former owner abuse)
-    use crate::homestead_hearth::player_has_building_privilege;
-    let hearths = ctx.db.homestead_hearth();
-    let any_hearth_exists = hearths.iter().any(|h| !h.is_destroyed);
-    
-    if any_hearth_exists && !player_has_building_privilege(ctx, sender_id) {
-        return Err("Building privilege required to open/close doors.".to_string());
+    // 3. Check access - hearth privilege, or a guest let in by the door's code/key lock
+    // Unlocked doors outside any hearth's privilege zone are open to everyone (pre-privilege phase)
+    // Inside a zone, only authorized players and lock guests may use them (prevents former owner abuse)
+    if !can_player_use_door(ctx, &door, sender_id) {
+        return Err(match door.lock_type {
+            DOOR_LOCK_CODE => "This door is code locked. Enter the code to open it.".to_string(),
+            DOOR_LOCK_KEY => "This door is locked. You need its key to open it.".to_string(),
+            _ => "Building privilege required to open/close doors.".to_string(),
+        });
     }
     
     // 4. Check distance
@@ -767,17 +793,18 @@ pub fn pickup_door(ctx: &ReducerContext, door_id: u64) -> Result<(), String> {
         return Err("Door is destroyed.".to_string());
     }
     
-    // 3. Check building privilege - anyone with privilege can pickup doors
-    // EARLY GAME: If no hearths exist, allow anyone to pickup doors (pre-privilege phase)
-    // LATE GAME: Once hearths exist, require building privilege (prevents former owner abuse)
-    use crate::homestead_hearth::player_has_building_privilege;
-    let hearths = ctx.db.homestead_hearth();
-    let any_hearth_exists = hearths.iter().any(|h| !h.is_destroyed);
-    
-    if any_hearth_exists && !player_has_building_privilege(ctx, sender_id) {
+    // 3. Check building privilege - anyone authorized at the door's hearth can pickup doors
+    // Doors outside any hearth's privilege zone are open to everyone (pre-privilege phase)
+    // Inside a zone, only that hearth's authorized players may use them (prevents former owner abuse)
+    if !crate::homestead_hearth::is_position_privileged(ctx, door.pos_x, door.pos_y, sender_id) {
         return Err("Building privilege required to pickup doors.".to_string());
     }
     
+    // 3.5. Locked doors can only be picked up by whoever can manage the lock
+    if door.lock_type != DOOR_LOCK_NONE && !can_player_manage_door_lock(ctx, &door, sender_id) {
+        return Err("Cannot pickup a locked door.".to_string());
+    }
+    
     // 4. Check distance
     let dx = door.pos_x - player.position_x;
     let dy = door.pos_y - player.position_y;
@@ -804,11 +831,130 @@ pub fn pickup_door(ctx: &ReducerContext, door_id: u64) -> Result<(), String> {
         }
     }
     
-    // 8. Delete the door entity
+    // 8. Delete the door entity and its lock
     doors.id().delete(door_id);
+    clear_door_lock(ctx, door_id);
     
     log::info!("[PickupDoor] Successfully picked up door {} by player {:?}", door_id, sender_id);
     
     Ok(())
 }
 
+
+/// Install or change a door's code lock. Changing the code clears the guest list.
+#[spacetimedb::reducer]
+pub fn set_door_code(ctx: &ReducerContext, door_id: u64, code: String) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let (_player, mut door) = validate_door_lock_interaction(ctx, door_id)?;
+
+    if !can_player_manage_door_lock(ctx, &door, sender_id) {
+        return Err("Building privilege required to set a door code.".to_string());
+    }
+    if door.lock_type == DOOR_LOCK_KEY {
+        return Err("This door has a key lock. Remove it before setting a code.".to_string());
+    }
+    if code.len() != DOOR_CODE_LENGTH || !code.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!("Door code must be exactly {} digits.", DOOR_CODE_LENGTH));
+    }
+
+    let mut lock = get_or_create_door_lock(ctx, door_id);
+    lock.code = Some(code);
+    lock.authorized_identities = vec![sender_id];
+    lock.updated_at = ctx.timestamp;
+    ctx.db.door_lock().door_id().update(lock);
+
+    door.lock_type = DOOR_LOCK_CODE;
+    ctx.db.door().id().update(door);
+
+    log::info!("[DoorLock] Player {:?} set code on door {}", sender_id, door_id);
+    Ok(())
+}
+
+/// Enter a door's code; on success the player is remembered as a guest of that door
+#[spacetimedb::reducer]
+pub fn enter_door_code(ctx: &ReducerContext, door_id: u64, code: String) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let (player, door) = validate_door_lock_interaction(ctx, door_id)?;
+
+    if door.lock_type != DOOR_LOCK_CODE {
+        return Err("This door does not have a code lock.".to_string());
+    }
+
+    let mut lock = ctx.db.door_lock().door_id().find(&door_id)
+        .ok_or_else(|| "Door lock not found".to_string())?;
+
+    if lock.code.as_deref() != Some(code.as_str()) {
+        crate::sound_events::emit_error_resources_sound(ctx, player.position_x, player.position_y, sender_id);
+        log::info!("[DoorLock] Player {:?} entered wrong code for door {}", sender_id, door_id);
+        return Err("Incorrect door code.".to_string());
+    }
+
+    if !lock.authorized_identities.contains(&sender_id) {
+        lock.authorized_identities.push(sender_id);
+        lock.updated_at = ctx.timestamp;
+        ctx.db.door_lock().door_id().update(lock);
+    }
+
+    log::info!("[DoorLock] Player {:?} authorized on door {} by code", sender_id, door_id);
+    Ok(())
+}
+
+/// Cut a key for a door. Installs a key lock if the door is unlocked.
+#[spacetimedb::reducer]
+pub fn craft_door_key(ctx: &ReducerContext, door_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let (player, mut door) = validate_door_lock_interaction(ctx, door_id)?;
+
+    if !can_player_manage_door_lock(ctx, &door, sender_id) {
+        return Err("Building privilege required to cut a door key.".to_string());
+    }
+    if door.lock_type == DOOR_LOCK_CODE {
+        return Err("This door has a code lock. Remove it before adding a key lock.".to_string());
+    }
+
+    let key_def_id = ctx.db.item_definition().iter()
+        .find(|def| def.name == DOOR_KEY_ITEM_NAME)
+        .map(|def| def.id)
+        .ok_or_else(|| format!("{} item definition not found", DOOR_KEY_ITEM_NAME))?;
+
+    crate::building_floor::consume_building_resources(ctx, &player, DOOR_KEY_WOOD_COST, 0, DOOR_KEY_METAL_COST)?;
+
+    let lock = get_or_create_door_lock(ctx, door_id);
+    let item_data = door_key_item_data(door_id, lock.key_generation);
+    crate::items::add_item_to_player_inventory_with_data(ctx, sender_id, key_def_id, 1, Some(item_data))?;
+
+    if door.lock_type != DOOR_LOCK_KEY {
+        door.lock_type = DOOR_LOCK_KEY;
+        ctx.db.door().id().update(door);
+    }
+
+    log::info!("[DoorLock] Player {:?} cut a key for door {} (generation {})", sender_id, door_id, lock.key_generation);
+    Ok(())
+}
+
+/// Remove a door's lock. Invalidates existing keys and clears code guests.
+#[spacetimedb::reducer]
+pub fn remove_door_lock(ctx: &ReducerContext, door_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let (_player, mut door) = validate_door_lock_interaction(ctx, door_id)?;
+
+    if !can_player_manage_door_lock(ctx, &door, sender_id) {
+        return Err("Building privilege required to remove a door lock.".to_string());
+    }
+    if door.lock_type == DOOR_LOCK_NONE {
+        return Err("This door is not locked.".to_string());
+    }
+
+    let mut lock = get_or_create_door_lock(ctx, door_id);
+    lock.code = None;
+    lock.authorized_identities.clear();
+    lock.key_generation += 1;
+    lock.updated_at = ctx.timestamp;
+    ctx.db.door_lock().door_id().update(lock);
+
+    door.lock_type = DOOR_LOCK_NONE;
+    ctx.db.door().id().update(door);
+
+    log::info!("[DoorLock] Player {:?} removed lock from door {}", sender_id, door_id);
+    Ok(())
+}
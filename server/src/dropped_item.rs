@@ -141,6 +141,7 @@ pub fn pickup_dropped_item(ctx: &ReducerContext, dropped_item_id: u64) -> Result
         Ok(added_to_inventory) => {
             // 5. Delete the original dropped item regardless of whether it went to inventory or was re-dropped
             dropped_items_table.id().delete(dropped_item_id);
+            crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(dropped_item_id));
             
             // Emit pickup sound at the dropped item's position
             crate::sound_events::emit_pickup_item_sound(ctx, dropped_item.pos_x, dropped_item.pos_y, sender_id);
@@ -295,6 +296,7 @@ pub fn despawn_expired_items(ctx: &ReducerContext, _schedule: DroppedItemDespawn
     for item_id in items_to_despawn {
         if dropped_items_table.id().find(item_id).is_some() { // Check if still exists
             dropped_items_table.id().delete(item_id);
+            crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(item_id));
             despawn_count += 1;
         } else {
             log::warn!("[DespawnCheck] Tried to despawn item ID {}, but it was already gone.", item_id);
@@ -352,6 +354,7 @@ pub fn check_flare_expiry(ctx: &ReducerContext, _schedule: FlareExpirySchedule)
     let mut expired_count = 0u32;
     for id in to_delete {
         dropped_items.id().delete(&id);
+        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(id));
         expired_count += 1;
     }
     if expired_count > 0 {
@@ -528,9 +531,13 @@ fn create_dropped_item_entity_internal_v2(
     };
 
     match ctx.db.dropped_item().try_insert(new_dropped_item) {
-        Ok(_) => {
+        Ok(inserted) => {
             log::info!("[CreateDroppedItem] Created dropped item entity (DefID: {}, Qty: {}) at ({:.1}, {:.1})",
                      item_def_id, quantity, pos_x, pos_y);
+            crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), pos_x, pos_y);
+
+            // 🥩 Raw meat on the ground draws predators
+            crate::wild_animal_npc::scent::leave_scent_if_raw_meat(ctx, item_def_id, pos_x, pos_y);
             
             // Only check for consolidation if requested (skip for batch drops)
             if trigger_consolidation {
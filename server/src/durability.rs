@@ -383,6 +383,15 @@ pub fn ensure_food_durability_initialized(
 pub fn reduce_durability_on_hit(
     ctx: &ReducerContext,
     item_instance_id: u64,
+) -> Result<bool, String> {
+    reduce_durability_scaled(ctx, item_instance_id, 1.0)
+}
+
+/// Same as reduce_durability_on_hit, with the loss scaled (e.g. ammo that wears a bow faster)
+pub fn reduce_durability_scaled(
+    ctx: &ReducerContext,
+    item_instance_id: u64,
+    loss_multiplier: f32,
 ) -> Result<bool, String> {
     let inventory_items = ctx.db.inventory_item();
     
@@ -392,9 +401,17 @@ pub fn reduce_durability_on_hit(
     // Initialize durability if not set
     ensure_durability_initialized(&mut item);
     
+    // Powered tools wear out much faster than hand tools
+    let loss_per_hit = match ctx.db.item_definition().id().find(item.item_def_id) {
+        Some(def) if crate::powered_tool::is_powered_tool(&def) => {
+            DURABILITY_LOSS_PER_HIT * crate::powered_tool::POWERED_TOOL_DURABILITY_LOSS_MULTIPLIER
+        }
+        _ => DURABILITY_LOSS_PER_HIT,
+    } * loss_multiplier;
+    
     // Get current durability
     let current_durability = get_durability(&item).unwrap_or(MAX_DURABILITY);
-    let new_durability = (current_durability - DURABILITY_LOSS_PER_HIT).max(0.0);
+    let new_durability = (current_durability - loss_per_hit).max(0.0);
     
     // Update durability
     set_durability(&mut item, new_durability);
@@ -670,6 +670,7 @@ pub fn is_position_on_sea_deepsea_transition(ctx: &ReducerContext, pos_x: f32, p
     }
     
     // Check if any adjacent tile is the OTHER type (Sea↔DeepSea boundary)
+    // Freshwater never borders DeepSea, so rivers and lakes are never part of this edge
     let other_type = if center_type == crate::TileType::Sea {
         crate::TileType::DeepSea
     } else {
@@ -708,13 +709,10 @@ pub fn is_position_on_ocean_water(ctx: &ReducerContext, pos_x: f32, pos_y: f32)
     
     // NEW: Try compressed lookup first for better performance
     if let Some(tile_type) = crate::get_tile_type_at_position(ctx, tile_x, tile_y) {
-        // Must be deep sea water (NOT beach, NOT inland water)
+        // Must be sea water (NOT beach, NOT Freshwater rivers/lakes)
         if tile_type == crate::TileType::Sea {
-            // Check if it's ocean water (not inland water like rivers/lakes)
-            if !is_tile_inland_water(ctx, tile_x, tile_y) {
-                // Also check that it's not too close to beach tiles
-                return !is_too_close_to_beach(ctx, tile_x, tile_y);
-            }
+            // Also check that it's not too close to beach tiles
+            return !is_too_close_to_beach(ctx, tile_x, tile_y);
         }
         // Explicitly reject beach tiles and any other non-sea tiles
         return false;
@@ -725,13 +723,10 @@ pub fn is_position_on_ocean_water(ctx: &ReducerContext, pos_x: f32, pos_y: f32)
     
     // Use the multi-column index to efficiently find the tile at (world_x, world_y)
     for tile in world_tiles.idx_world_position().filter((tile_x, tile_y)) {
-        // Must be deep sea water (NOT hot springs, NOT beach, NOT inland water)
+        // Must be sea water (NOT hot springs, NOT beach, NOT Freshwater rivers/lakes)
         if tile.tile_type == crate::TileType::Sea {
-            // Check if it's ocean water (not inland water like rivers/lakes)
-            if !is_tile_inland_water(ctx, tile_x, tile_y) {
-                // Also check that it's not too close to beach tiles
-                return !is_too_close_to_beach(ctx, tile_x, tile_y);
-            }
+            // Also check that it's not too close to beach tiles
+            return !is_too_close_to_beach(ctx, tile_x, tile_y);
         }
     }
     
@@ -742,6 +737,7 @@ pub fn is_position_on_ocean_water(ctx: &ReducerContext, pos_x: f32, pos_y: f32)
 /// Checks if the given world position is on a water tile (Sea or HotSpringWater)
 /// Returns true if the position is on water and resources/placeables should NOT spawn there
 /// NEW: Uses compressed chunk data for much better performance
+/// Tidal flats exposed at low tide and sea, river or lake ice are not water
 pub fn is_position_on_water(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
     // Convert pixel position to tile coordinates
     let tile_x = (pos_x / TILE_SIZE_PX as f32).floor() as i32;
@@ -755,6 +751,12 @@ pub fn is_position_on_water(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> boo
     
     // NEW: Try compressed lookup first for better performance
     if let Some(tile_type) = crate::get_tile_type_at_position(ctx, tile_x, tile_y) {
+        if tile_type == crate::TileType::Sea && crate::world_state::is_tile_exposed_by_tide(ctx, tile_x, tile_y) {
+            return false; // Walkable tidal flat
+        }
+        if matches!(tile_type, crate::TileType::Sea | crate::TileType::Freshwater) && crate::ice::is_tile_frozen(ctx, tile_x, tile_y) {
+            return false; // Walkable sea, river or lake ice
+        }
         return tile_type.is_water(); // Includes both Sea and HotSpringWater
     }
     
@@ -805,44 +807,18 @@ pub fn is_position_on_inland_water(ctx: &ReducerContext, pos_x: f32, pos_y: f32)
 }
 
 /// Helper function to determine if a water tile is inland (river/lake) vs ocean
-/// Uses aggressive coastal zone detection - most water is salty except deep inland areas
+/// Rivers and lakes are generated as Freshwater tiles; hot springs are spring-fed and always fresh
 pub fn is_tile_inland_water(ctx: &ReducerContext, tile_x: i32, tile_y: i32) -> bool {
-    // First, verify this is actually a water tile
-    let world_tiles = ctx.db.world_tile();
-    let mut is_water = false;
-    for tile in world_tiles.idx_world_position().filter((tile_x, tile_y)) {
-        is_water = tile.tile_type.is_water(); // Includes both Sea and HotSpringWater
-        break;
+    if let Some(tile_type) = crate::get_tile_type_at_position(ctx, tile_x, tile_y) {
+        return tile_type.is_fresh_water();
     }
     
-    if !is_water {
-        return false; // Not water, so not inland water either
+    // Fallback during early world init before compressed chunks are generated
+    let world_tiles = ctx.db.world_tile();
+    for tile in world_tiles.idx_world_position().filter((tile_x, tile_y)) {
+        return tile.tile_type.is_fresh_water();
     }
-    
-    // BALANCED COASTAL ZONE: Make coastal water salty, keep inland lakes/rivers fresh
-    // Use a reasonable percentage of map size for realistic coastal zones
-    let map_width = WORLD_WIDTH_TILES as f32;
-    let map_height = WORLD_HEIGHT_TILES as f32;
-    
-    // Coastal zone extends 20% into the map from each edge (40% total coastal coverage)
-    let coastal_zone_x = (map_width * 0.2) as i32;
-    let coastal_zone_y = (map_height * 0.2) as i32;
-    
-    // Calculate distance from each edge
-    let distance_from_left = tile_x;
-    let distance_from_right = (WORLD_WIDTH_TILES as i32) - 1 - tile_x;
-    let distance_from_top = tile_y;
-    let distance_from_bottom = (WORLD_HEIGHT_TILES as i32) - 1 - tile_y;
-    
-    // Check if we're in the coastal zone from any direction
-    let in_coastal_zone = distance_from_left < coastal_zone_x ||
-                         distance_from_right < coastal_zone_x ||
-                         distance_from_top < coastal_zone_y ||
-                         distance_from_bottom < coastal_zone_y;
-    
-    // If in coastal zone, water is salty (return false for "not inland")
-    // Only the very center of large landmasses has fresh water
-    !in_coastal_zone
+    false
 }
 
 /// Checks if a position is on shore (land tile adjacent to water)
@@ -990,12 +966,7 @@ pub fn is_position_in_deep_sea(ctx: &ReducerContext, pos_x: f32, pos_y: f32, min
         return true;
     }
     if tile_type != crate::TileType::Sea {
-        return false; // Must be sea water
-    }
-    
-    // Check if it's inland water (rivers/lakes)
-    if is_tile_inland_water(ctx, tile_x, tile_y) {
-        return false;
+        return false; // Must be sea water - Freshwater rivers/lakes are never deep sea
     }
     
     // Check distance to nearest shore/beach tile
@@ -1165,6 +1136,7 @@ pub fn is_wild_animal_location_suitable(ctx: &ReducerContext, pos_x: f32, pos_y:
                         
                         // Check if adjacent tile is water or beach (efficient chunk-based lookup)
                         if let Some(adj_tile_type) = crate::get_tile_type_at_position(ctx, check_x, check_y) {
+                            // Walruses haul out on the sea coast, not river or lake banks
                             if matches!(adj_tile_type, TileType::Sea | TileType::Beach) {
                                 return true; // Coastal area suitable for walrus
                             }
@@ -1241,7 +1213,8 @@ pub fn is_wild_animal_location_suitable(ctx: &ReducerContext, pos_x: f32, pos_y:
                         
                         // Check if adjacent tile is water or beach (efficient chunk-based lookup)
                         if let Some(adj_tile_type) = crate::get_tile_type_at_position(ctx, check_x, check_y) {
-                            if matches!(adj_tile_type, TileType::Sea | TileType::Beach) {
+                            // Terns also hunt along river and lake shores
+                            if matches!(adj_tile_type, TileType::Sea | TileType::Freshwater | TileType::Beach) {
                                 return true; // Coastal area suitable for tern
                             }
                         }
@@ -1316,7 +1289,7 @@ pub fn is_wild_animal_location_suitable(ctx: &ReducerContext, pos_x: f32, pos_y:
                     }
                     
                     if let Some(adj_tile_type) = crate::get_tile_type_at_position(ctx, check_x, check_y) {
-                        if matches!(adj_tile_type, TileType::Sea) {
+                        if matches!(adj_tile_type, TileType::Sea | TileType::Freshwater) {
                             return false; // Too close to water
                         }
                     }
@@ -1587,7 +1560,7 @@ pub fn validate_spawn_location(
                     let check_y = tile_y + dy;
                     
                     for tile in world_tiles.idx_world_position().filter((check_x, check_y)) {
-                        if matches!(tile.tile_type, TileType::Sea | TileType::Beach | TileType::Sand) {
+                        if matches!(tile.tile_type, TileType::Sea | TileType::Freshwater | TileType::Beach | TileType::Sand) {
                             return true;
                         }
                     }
@@ -1669,7 +1642,7 @@ pub fn validate_spawn_location(
         
         plants_database::SpawnCondition::Underwater => {
             // Underwater plants (seaweed): Must be in sea water
-            // Seaweed can spawn in Sea tiles - just needs to be in ocean water
+            // Seaweed can spawn in Sea tiles - just needs to be in ocean water (never Freshwater)
             if !matches!(current_tile_type, Some(TileType::Sea)) {
                 return false;
             }
@@ -2300,6 +2273,7 @@ pub fn seed_environment(ctx: &ReducerContext) -> Result<(), String> {
                     last_hit_time: None,
                     respawn_at: Timestamp::UNIX_EPOCH, // 0 = not respawning
                     is_player_planted: false, // Wild trees respawn and yield full resources
+                    is_burnt: false,
                 }
             },
             (tree_type_roll_for_this_attempt, resource_roll),
@@ -2394,13 +2368,17 @@ pub fn seed_environment(ctx: &ReducerContext) -> Result<(), String> {
                     },
                 };
                 
+                let quality = crate::stone::roll_ore_quality(&mut position_rng);
+                
                 crate::stone::Stone {
                     id: 0,
                     pos_x,
                     pos_y,
                     health: crate::stone::STONE_INITIAL_HEALTH,
-                    resource_remaining: resource_amount, // Set based on ore type
+                    resource_remaining: crate::stone::apply_ore_quality(resource_amount, quality), // Set based on ore type and quality
                     ore_type, // Set the ore type based on location
+                    quality,
+                    hot_spot_angle: crate::stone::roll_hot_spot_angle(&mut position_rng),
                     chunk_index: chunk_idx, // Set the chunk index
                     last_hit_time: None,
                     respawn_at: Timestamp::UNIX_EPOCH, // 0 = not respawning
@@ -3265,13 +3243,17 @@ pub fn seed_environment(ctx: &ReducerContext) -> Result<(), String> {
                     },
                 };
                 
+                let quality = crate::stone::roll_ore_quality(&mut rng);
+                
                 let stone = crate::stone::Stone {
                     id: 0,
                     pos_x: world_x_px,
                     pos_y: world_y_px,
                     health: crate::stone::STONE_INITIAL_HEALTH,
-                    resource_remaining: stone_resource_amount, // Set based on ore type
+                    resource_remaining: crate::stone::apply_ore_quality(stone_resource_amount, quality), // Set based on ore type and quality
                     ore_type, // Set the ore type based on location
+                    quality,
+                    hot_spot_angle: crate::stone::roll_hot_spot_angle(&mut rng),
                     chunk_index: chunk_idx,
                     last_hit_time: None,
                     respawn_at: Timestamp::UNIX_EPOCH, // 0 = not respawning
@@ -4046,6 +4028,7 @@ pub fn seed_environment(ctx: &ReducerContext) -> Result<(), String> {
 
             match ctx.db.wild_animal().try_insert(new_animal) {
                 Ok(inserted_animal) => {
+                    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted_animal.id), inserted_animal.pos_x, inserted_animal.pos_y);
                     spawned_wild_animal_positions.push((spawn_x, spawn_y));
                     spawned_wild_animal_count += 1;
                     
@@ -4821,6 +4804,11 @@ pub fn seed_environment(ctx: &ReducerContext) -> Result<(), String> {
     let spawned_beehive_count = crate::wild_beehive::spawn_wild_beehives_in_forests(ctx, &spawned_tree_positions);
     log::info!("Successfully spawned {} wild beehives", spawned_beehive_count);
 
+    // --- Spawn Crow Nests in Forest Areas ---
+    log::info!("Seeding crow nests in forest areas...");
+    let spawned_nest_count = crate::wild_animal_npc::crow_nest::spawn_crow_nests_in_forests(ctx, &spawned_tree_positions);
+    log::info!("Successfully spawned {} crow nests", spawned_nest_count);
+
     // Generate summary for harvestable resources
     let mut harvestable_summary = String::new();
     for (plant_type, count) in &plant_spawned_counts {
@@ -5235,9 +5223,8 @@ pub fn seed_environment(ctx: &ReducerContext) -> Result<(), String> {
     // --- Seed Cairns ---
     seed_cairns(ctx)?;
 
-    // --- Wild Animal Population Maintenance ---
-    // Periodically checks if more animals should be spawned to maintain population
-    crate::wild_animal_npc::respawn::maintain_wild_animal_population(ctx)?;
+    // Wild animal population after seeding is balanced by the population census
+    // (see wild_animal_npc::population - target densities per species per biome)
 
     Ok(())
 }
@@ -5300,6 +5287,10 @@ pub fn check_resource_respawns(ctx: &ReducerContext) -> Result<(), String> {
                     position_rng.gen_range(crate::stone::MEMORY_SHARD_MIN_RESOURCES..=crate::stone::MEMORY_SHARD_MAX_RESOURCES)
                 },
             };
+            // Fresh vein: new quality and hot spot on every respawn
+            s.quality = crate::stone::roll_ore_quality(&mut position_rng);
+            s.resource_remaining = crate::stone::apply_ore_quality(s.resource_remaining, s.quality);
+            s.hot_spot_angle = crate::stone::roll_hot_spot_angle(&mut position_rng);
             s.respawn_at = Timestamp::UNIX_EPOCH; // 0 = not respawning
             s.last_hit_time = None;
         }
@@ -5341,6 +5332,7 @@ pub fn check_resource_respawns(ctx: &ReducerContext) -> Result<(), String> {
             t.resource_remaining = ctx.rng().gen_range(min_wood..=max_wood);
             t.respawn_at = Timestamp::UNIX_EPOCH; // 0 = not respawning
             t.last_hit_time = None;
+            t.is_burnt = false; // Burnt stumps regrow into healthy trees
             // Position doesn't change during respawn, so chunk_index stays the same
         }
     );
@@ -5511,11 +5503,9 @@ pub fn check_resource_respawns(ctx: &ReducerContext) -> Result<(), String> {
     // scheduled reducer would be needed here or in `cloud.rs`.
 
     // --- Wild Animal Population Maintenance ---
-    // NOTE: Spawn zone maintenance (wolves at dens, wolverines at graveyard, terns at marshes)
-    // runs on a separate 8-minute schedule for balance - see process_spawn_zone_maintenance
-    if let Err(e) = crate::wild_animal_npc::respawn::maintain_wild_animal_population(ctx) {
-        log::warn!("Wild animal population maintenance failed: {}", e);
-    }
+    // NOTE: General wild animal respawning is handled by the biome-aware population census
+    // (process_animal_population_census). Spawn zone maintenance (wolves at dens, wolverines at
+    // graveyard, terns at marshes) runs on a separate 8-minute schedule - see process_spawn_zone_maintenance
 
     Ok(())
 }
@@ -5641,6 +5631,7 @@ fn spawn_terns_near_reed_marshes(
             
             match ctx.db.wild_animal().try_insert(tern) {
                 Ok(inserted) => {
+                    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
                     spawned_count += 1;
                     log::debug!("🐦 Spawned Tern #{} near reed marsh at ({:.1}, {:.1})", 
                                inserted.id, spawn_x, spawn_y);
@@ -5756,7 +5747,8 @@ fn spawn_crabs_and_terns_near_tide_pools(
                 last_shock_time: None,
             };
             
-            if ctx.db.wild_animal().try_insert(crab).is_ok() {
+            if let Ok(inserted) = ctx.db.wild_animal().try_insert(crab) {
+                crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
                 crabs_spawned += 1;
             }
         }
@@ -5830,7 +5822,8 @@ fn spawn_crabs_and_terns_near_tide_pools(
             last_shock_time: None,
         };
         
-        if ctx.db.wild_animal().try_insert(tern).is_ok() {
+        if let Ok(inserted) = ctx.db.wild_animal().try_insert(tern) {
+            crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
             terns_spawned += 1;
         }
     }
@@ -5949,6 +5942,7 @@ fn spawn_wolverines_near_whale_bone_graveyard(
         
         match ctx.db.wild_animal().try_insert(wolverine) {
             Ok(inserted) => {
+                crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
                 spawned_count += 1;
                 log::info!("🦡 Spawned Wolverine #{} near whale bone graveyard at ({:.1}, {:.1})", 
                            inserted.id, spawn_x, spawn_y);
@@ -22,6 +22,8 @@ use crate::shelter::shelter;
 // Import building table traits (auto-generated by SpacetimeDB)
 use crate::building::wall_cell;
 use crate::building::foundation_cell;
+use crate::building::{apply_structure_damage, StructureRef, StructureDamageType};
+use crate::fence::fence;
 
 // Additional table traits for explosion damage
 use crate::tree::tree;
@@ -430,7 +432,7 @@ fn damage_structures_in_radius(ctx: &ReducerContext, attacker_id: Identity, cent
         
         if dist_sq <= radius_sq {
             // Explosive damage bypasses melee reduction (PvP check inside function)
-            crate::building::damage_wall_explosive(ctx, attacker_id, wall.id, damage);
+            let _ = apply_structure_damage(ctx, StructureRef::Wall(wall.id), damage, StructureDamageType::Explosive, Some(attacker_id));
         }
     }
     
@@ -442,7 +444,18 @@ fn damage_structures_in_radius(ctx: &ReducerContext, attacker_id: Identity, cent
         
         if dist_sq <= radius_sq {
             // PvP check inside function
-            crate::door::damage_door_explosive(ctx, attacker_id, door.id, damage);
+            let _ = apply_structure_damage(ctx, StructureRef::Door(door.id), damage, StructureDamageType::Explosive, Some(attacker_id));
+        }
+    }
+    
+    // Damage fences (monument fences are skipped inside)
+    for fence in ctx.db.fence().iter() {
+        let dx = fence.pos_x - center_x;
+        let dy = fence.pos_y - center_y;
+        let dist_sq = dx * dx + dy * dy;
+        
+        if dist_sq <= radius_sq {
+            let _ = apply_structure_damage(ctx, StructureRef::Fence(fence.id), damage, StructureDamageType::Explosive, Some(attacker_id));
         }
     }
     
@@ -458,9 +471,12 @@ fn damage_structures_in_radius(ctx: &ReducerContext, attacker_id: Identity, cent
         
         if dist_sq <= radius_sq {
             // PvP check inside function
-            crate::building::damage_foundation_explosive(ctx, attacker_id, foundation.id, damage);
+            let _ = apply_structure_damage(ctx, StructureRef::Foundation(foundation.id), damage, StructureDamageType::Explosive, Some(attacker_id));
         }
     }
+    
+    // Damage second-story floors and stairs
+    crate::building_floor::damage_floors_explosive_in_radius(ctx, attacker_id, center_x, center_y, radius_sq, damage);
 }
 
 // --- Placeable Damage ---
@@ -508,7 +524,7 @@ fn damage_placeables_in_radius(ctx: &ReducerContext, attacker_id: Identity, cent
         let dist_sq = dx * dx + dy * dy;
         
         if dist_sq <= radius_sq {
-            let _ = crate::shelter::damage_shelter(ctx, attacker_id, shelter.id, damage, current_time, &mut rng);
+            let _ = apply_structure_damage(ctx, StructureRef::Shelter(shelter.id), damage, StructureDamageType::Explosive, Some(attacker_id));
         }
     }
     
@@ -519,7 +535,7 @@ fn damage_placeables_in_radius(ctx: &ReducerContext, attacker_id: Identity, cent
         let dist_sq = dx * dx + dy * dy;
         
         if dist_sq <= radius_sq {
-            let _ = crate::combat::damage_lantern(ctx, attacker_id, lantern.id, damage, current_time, &mut rng);
+            let _ = apply_structure_damage(ctx, StructureRef::Lantern(lantern.id), damage, StructureDamageType::Explosive, Some(attacker_id));
         }
     }
     
@@ -563,7 +579,7 @@ fn damage_placeables_in_radius(ctx: &ReducerContext, attacker_id: Identity, cent
         let dist_sq = dx * dx + dy * dy;
         
         if dist_sq <= radius_sq {
-            let _ = crate::homestead_hearth::damage_hearth(ctx, attacker_id, hearth.id, damage, current_time);
+            let _ = apply_structure_damage(ctx, StructureRef::Hearth(hearth.id), damage, StructureDamageType::Explosive, Some(attacker_id));
         }
     }
 }
@@ -687,6 +703,7 @@ fn damage_animals_in_radius(ctx: &ReducerContext, center_x: f32, center_y: f32,
     
     // Damage wild animals
     let animals_to_damage: Vec<u64> = ctx.db.wild_animal().iter()
+        .map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row))
         .filter(|animal| {
             let dx = animal.pos_x - center_x;
             let dy = animal.pos_y - center_y;
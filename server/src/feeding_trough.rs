@@ -0,0 +1,191 @@
+/******************************************************************************
+ *                                                                            *
+ * Feeding Trough - Tamed animal hunger and upkeep                            *
+ *                                                                            *
+ * Tamed animals get hungry. Each tamed animal has a TamedAnimalHunger row    *
+ * whose fullness drains every tick. When an animal is peckish it eats one    *
+ * of its taming foods from any feeding trough in range (troughs are          *
+ * WoodenStorageBox entities with BOX_TYPE_FEEDING_TROUGH).                   *
+ *                                                                            *
+ * An animal whose fullness hits zero starts starving. If it stays unfed for  *
+ * STARVATION_GRACE_SECS it gives up on its owner and goes wild again.        *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table, Timestamp};
+use spacetimedb::spacetimedb_lib::{ScheduleAt, TimeDuration};
+use log;
+use std::time::Duration;
+
+use crate::wooden_storage_box::{WoodenStorageBox, BOX_TYPE_FEEDING_TROUGH, NUM_FEEDING_TROUGH_SLOTS, wooden_storage_box as WoodenStorageBoxTableTrait};
+use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::inventory_management::ItemContainer;
+use crate::wild_animal_npc::{AnimalBehavior, AnimalState, WildAnimal, transition_to_state, emit_species_sound, wild_animal as WildAnimalTableTrait};
+
+// --- Feeding Constants ---
+pub const FEEDING_PROCESS_INTERVAL_SECS: u64 = 60; // Hunger ticks once a minute
+pub const MAX_FULLNESS: f32 = 100.0;
+pub const FULLNESS_DECAY_PER_TICK: f32 = 100.0 / 90.0; // Full to empty in ~90 minutes (3 game days)
+pub const FEED_FULLNESS_THRESHOLD: f32 = 70.0; // Animals only eat once they're below this
+pub const FULLNESS_PER_FOOD_ITEM: f32 = 35.0; // One food item restores this much
+pub const FEEDING_TROUGH_RANGE: f32 = 400.0; // Tamed animals eat from troughs within this range
+pub const STARVATION_GRACE_SECS: i64 = 3600; // An hour of starving before the animal goes wild
+
+// --- Hunger Table ---
+#[spacetimedb::table(accessor = tamed_animal_hunger, public)]
+#[derive(Clone, Debug)]
+pub struct TamedAnimalHunger {
+    #[primary_key]
+    pub animal_id: u64,
+    pub fullness: f32, // 0.0 (starving) to MAX_FULLNESS
+    pub last_fed_at: Option<Timestamp>,
+    pub starving_since: Option<Timestamp>, // Set while fullness is at zero
+}
+
+// --- Feeding Schedule Table ---
+#[spacetimedb::table(accessor = feeding_process_schedule, scheduled(process_tamed_animal_feeding))]
+#[derive(Clone)]
+pub struct FeedingProcessSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Initialize the tamed animal feeding system
+pub fn init_feeding_system(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.feeding_process_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("Starting tamed animal feeding schedule (every {}s).", FEEDING_PROCESS_INTERVAL_SECS);
+        let interval = Duration::from_secs(FEEDING_PROCESS_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            FeedingProcessSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Tamed animal feeding"
+        );
+    } else {
+        log::debug!("Tamed animal feeding schedule already exists.");
+    }
+    Ok(())
+}
+
+/// Takes one item the animal will eat out of the trough. Returns true if food was eaten.
+fn eat_from_trough(ctx: &ReducerContext, trough: &mut WoodenStorageBox, foods: &[&str]) -> bool {
+    let inventory_items = ctx.db.inventory_item();
+    let item_defs = ctx.db.item_definition();
+
+    for slot in 0..NUM_FEEDING_TROUGH_SLOTS as u8 {
+        let (Some(instance_id), Some(def_id)) = (trough.get_slot_instance_id(slot), trough.get_slot_def_id(slot)) else {
+            continue;
+        };
+        let Some(item_def) = item_defs.id().find(def_id) else { continue };
+        if !foods.contains(&item_def.name.as_str()) {
+            continue;
+        }
+        let Some(mut item) = inventory_items.instance_id().find(instance_id) else { continue };
+
+        if item.quantity > 1 {
+            item.quantity -= 1;
+            inventory_items.instance_id().update(item);
+        } else {
+            inventory_items.instance_id().delete(instance_id);
+            trough.set_slot(slot, None, None);
+            ctx.db.wooden_storage_box().id().update(trough.clone());
+        }
+        return true;
+    }
+    false
+}
+
+/// The animal gives up on its owner after starving too long
+fn return_to_wild(ctx: &ReducerContext, mut animal: WildAnimal) {
+    let former_owner = animal.tamed_by.take();
+    animal.tamed_at = None;
+    animal.heart_effect_until = None;
+    transition_to_state(&mut animal, AnimalState::Patrolling, ctx.timestamp, None, "starved - returned to the wild");
+    animal.spawn_x = animal.pos_x; // Roam from where it was abandoned
+    animal.spawn_y = animal.pos_y;
+    log::info!("[Feeding] {:?} {} starved and went wild again (former owner {:?})", animal.species, animal.id, former_owner);
+    ctx.db.wild_animal().id().update(animal);
+}
+
+/// Scheduled reducer - drains tamed animal hunger and feeds them from nearby troughs
+#[spacetimedb::reducer]
+pub fn process_tamed_animal_feeding(ctx: &ReducerContext, _args: FeedingProcessSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Tamed animal feeding can only be run by scheduler".to_string());
+    }
+
+    let current_time = ctx.timestamp;
+    let hunger_table = ctx.db.tamed_animal_hunger();
+
+    // Drop hunger rows for animals that died or went wild
+    let stale_ids: Vec<u64> = hunger_table.iter()
+        .filter(|h| ctx.db.wild_animal().id().find(h.animal_id).map_or(true, |a| a.tamed_by.is_none()))
+        .map(|h| h.animal_id)
+        .collect();
+    for animal_id in stale_ids {
+        hunger_table.animal_id().delete(animal_id);
+    }
+
+    let tamed_animals: Vec<WildAnimal> = ctx.db.wild_animal().iter()
+        .filter(|a| a.tamed_by.is_some())
+        .map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row))
+        .collect();
+    if tamed_animals.is_empty() {
+        return Ok(());
+    }
+
+    let mut troughs: Vec<WoodenStorageBox> = ctx.db.wooden_storage_box().iter()
+        .filter(|b| b.box_type == BOX_TYPE_FEEDING_TROUGH && !b.is_destroyed)
+        .collect();
+    let range_sq = FEEDING_TROUGH_RANGE * FEEDING_TROUGH_RANGE;
+
+    for animal in tamed_animals {
+        let mut hunger = hunger_table.animal_id().find(animal.id).unwrap_or(TamedAnimalHunger {
+            animal_id: animal.id,
+            fullness: MAX_FULLNESS, // Freshly tamed animals were just fed
+            last_fed_at: animal.tamed_at,
+            starving_since: None,
+        });
+
+        hunger.fullness = (hunger.fullness - FULLNESS_DECAY_PER_TICK).max(0.0);
+
+        if hunger.fullness < FEED_FULLNESS_THRESHOLD {
+            let foods = animal.species.get_behavior().get_taming_foods();
+            let fed = troughs.iter_mut()
+                .filter(|t| (t.pos_x - animal.pos_x).powi(2) + (t.pos_y - animal.pos_y).powi(2) <= range_sq)
+                .any(|trough| eat_from_trough(ctx, trough, &foods));
+            if fed {
+                hunger.fullness = (hunger.fullness + FULLNESS_PER_FOOD_ITEM).min(MAX_FULLNESS);
+                hunger.last_fed_at = Some(current_time);
+                hunger.starving_since = None;
+                if let Some(owner_id) = animal.tamed_by {
+                    emit_species_sound(ctx, &animal, owner_id, "eating");
+                }
+                log::debug!("[Feeding] {:?} {} ate from a trough (fullness {:.0})", animal.species, animal.id, hunger.fullness);
+            }
+        }
+
+        if hunger.fullness <= 0.0 {
+            let starving_since = *hunger.starving_since.get_or_insert(current_time);
+            let starving_secs = (current_time.to_micros_since_unix_epoch() - starving_since.to_micros_since_unix_epoch()) / 1_000_000;
+            if starving_secs >= STARVATION_GRACE_SECS {
+                hunger_table.animal_id().delete(animal.id);
+                return_to_wild(ctx, animal);
+                continue;
+            }
+        }
+
+        if hunger_table.animal_id().find(animal.id).is_some() {
+            hunger_table.animal_id().update(hunger);
+        } else {
+            hunger_table.insert(hunger);
+        }
+    }
+
+    Ok(())
+}
@@ -5,6 +5,8 @@
  * Handles placement, damage, and destruction of fences.                    *
  * Fences snap to 96px foundation cell edges (same as walls) but don't      *
  * require a foundation underneath.                                          *
+ * Gate pieces (single and double) open/close like doors so compounds and   *
+ * animal pens can be closed off.                                            *
  *                                                                            *
  ******************************************************************************/
 
@@ -17,7 +19,6 @@ use crate::{
 use crate::player as PlayerTableTrait;
 use crate::items::{item_definition as ItemDefinitionTableTrait, inventory_item as InventoryItemTableTrait};
 use crate::building::wall_cell as WallCellTableTrait;
-use crate::homestead_hearth::homestead_hearth;
 
 // --- Constants ---
 
@@ -42,6 +43,17 @@ pub const FENCE_EDGE_EAST: u8 = 1;
 pub const FENCE_EDGE_SOUTH: u8 = 2;
 pub const FENCE_EDGE_WEST: u8 = 3;
 
+/// Gate types (plain fences are FENCE_GATE_NONE)
+pub const FENCE_GATE_NONE: u8 = 0;
+pub const FENCE_GATE_SINGLE: u8 = 1;
+pub const FENCE_GATE_DOUBLE: u8 = 2; // Two linked edges (192px) for animal pens
+
+/// Gate wood costs (per gate piece; a double gate costs two halves)
+pub const FENCE_GATE_WOOD_COST: u32 = 25;
+
+/// Gate interaction distance (same as doors)
+pub const FENCE_GATE_INTERACTION_DISTANCE_SQUARED: f32 = crate::door::DOOR_INTERACTION_DISTANCE_SQUARED;
+
 // --- Fence Table ---
 
 #[spacetimedb::table(
@@ -72,6 +84,9 @@ pub struct Fence {
     pub last_damaged_by: Option<Identity>,
     /// Monument fences (e.g. compound perimeter) are indestructible and non-upgradeable
     pub is_monument: bool,
+    pub gate_type: u8,               // 0 = Fence, 1 = Gate, 2 = Double gate half
+    pub is_open: bool,               // Only meaningful for gates
+    pub paired_gate_id: Option<u64>, // Other half of a double gate
 }
 
 // --- Helper Functions ---
@@ -96,6 +111,50 @@ pub fn get_fence_damage_multiplier(tier: u8) -> f32 {
     }
 }
 
+/// Whether a fence piece currently blocks movement, projectiles, and melee
+/// Open gates let everything through; destroyed pieces never block
+pub fn fence_blocks(fence: &Fence) -> bool {
+    !fence.is_destroyed && (fence.gate_type == FENCE_GATE_NONE || !fence.is_open)
+}
+
+/// Get the display name for a fence piece
+fn get_fence_piece_name(gate_type: u8) -> &'static str {
+    match gate_type {
+        FENCE_GATE_SINGLE => "fence gate",
+        FENCE_GATE_DOUBLE => "double gate",
+        _ => "fence",
+    }
+}
+
+/// Get the wood cost for a fence piece (double gates cover two edges)
+fn get_fence_piece_wood_cost(gate_type: u8) -> u32 {
+    match gate_type {
+        FENCE_GATE_SINGLE => FENCE_GATE_WOOD_COST,
+        FENCE_GATE_DOUBLE => FENCE_GATE_WOOD_COST * 2,
+        _ => FENCE_WOOD_COST,
+    }
+}
+
+/// Second edge covered by a double gate: the neighbouring cell along the edge direction
+fn get_double_gate_partner_cell(cell_x: i32, cell_y: i32, edge: u8) -> (i32, i32) {
+    match edge {
+        FENCE_EDGE_NORTH | FENCE_EDGE_SOUTH => (cell_x + 1, cell_y),
+        _ => (cell_x, cell_y + 1),
+    }
+}
+
+/// Checks if a player may open/close a gate
+/// Inside a hearth's privilege zone this follows hearth privilege; on unclaimed land only the owner
+fn can_player_toggle_gate(ctx: &ReducerContext, fence: &Fence, player_id: Identity) -> bool {
+    use crate::homestead_hearth::{is_position_claimed, is_position_privileged};
+
+    if is_position_claimed(ctx, fence.pos_x, fence.pos_y) {
+        is_position_privileged(ctx, fence.pos_x, fence.pos_y, player_id)
+    } else {
+        fence.owner_id == player_id
+    }
+}
+
 /// Calculate world position for a fence at a cell edge (exactly like walls)
 /// Returns (pos_x, pos_y) for the center of the edge
 fn calculate_fence_world_position(cell_x: i32, cell_y: i32, edge: u8) -> (f32, f32) {
@@ -192,47 +251,50 @@ pub fn place_fence(
     cell_y: i64,
     edge: u8,
 ) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let players = ctx.db.player();
-    
-    log::info!(
-        "[PlaceFence] Player {:?} attempting to place fence at cell ({}, {}), edge={}",
-        sender_id, cell_x, cell_y, edge
-    );
-    
-    // 1. Validate player
-    let player = players.identity().find(&sender_id)
-        .ok_or_else(|| "Player not found".to_string())?;
-    
-    if player.is_dead {
-        return Err("Cannot place fence while dead.".to_string());
-    }
-    
-    if player.is_knocked_out {
-        return Err("Cannot place fence while knocked out.".to_string());
-    }
-    
-    // 2. Validate Blueprint equipped
-    if !player_has_blueprint(ctx, sender_id) {
-        return Err("Blueprint must be equipped to place fences.".to_string());
-    }
-    
-    // 3. Validate edge
-    if edge > 3 {
-        return Err(format!("Invalid edge: {}. Must be 0-3 (N, E, S, W).", edge));
-    }
-    
-    // 4. Convert cell coordinates
-    let cell_x_i32 = cell_x as i32;
-    let cell_y_i32 = cell_y as i32;
-    
-    // 5. Validate fence position
+    place_fence_piece(ctx, cell_x, cell_y, edge, FENCE_GATE_NONE)
+}
+
+/// Place a single gate on a foundation cell edge (opens/closes like a door)
+#[spacetimedb::reducer]
+pub fn place_fence_gate(
+    ctx: &ReducerContext,
+    cell_x: i64,
+    cell_y: i64,
+    edge: u8,
+) -> Result<(), String> {
+    place_fence_piece(ctx, cell_x, cell_y, edge, FENCE_GATE_SINGLE)
+}
+
+/// Place a double gate spanning this edge and the neighbouring cell's edge
+/// (East for North/South edges, South for East/West edges). Both halves open together.
+#[spacetimedb::reducer]
+pub fn place_double_fence_gate(
+    ctx: &ReducerContext,
+    cell_x: i64,
+    cell_y: i64,
+    edge: u8,
+) -> Result<(), String> {
+    place_fence_piece(ctx, cell_x, cell_y, edge, FENCE_GATE_DOUBLE)
+}
+
+/// Validates a single fence edge for placement and returns its world position
+fn validate_fence_edge_placement(
+    ctx: &ReducerContext,
+    player: &crate::Player,
+    cell_x_i32: i32,
+    cell_y_i32: i32,
+    edge: u8,
+) -> Result<(f32, f32), String> {
+    // Validate fence position
     is_fence_position_valid(ctx, cell_x_i32, cell_y_i32, edge)?;
     
-    // 6. Calculate world position at cell edge (exactly like walls)
+    // Calculate world position at cell edge (exactly like walls)
     let (world_x, world_y) = calculate_fence_world_position(cell_x_i32, cell_y_i32, edge);
     
-    // 7. Check that player is NOT standing on the fence position
+    // Check building privilege at the fence position
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, player.identity)?;
+    
+    // Check that player is NOT standing on the fence position
     let half_edge = FOUNDATION_TILE_SIZE_PX as f32 / 2.0;
     let half_thickness = FENCE_COLLISION_THICKNESS / 2.0;
     let player_radius = crate::PLAYER_RADIUS;
@@ -264,7 +326,7 @@ pub fn place_fence(
         return Err("Cannot place fence where you are standing.".to_string());
     }
     
-    // 8. Check placement distance from player
+    // Check placement distance from player
     let dx = world_x - player.position_x;
     let dy = world_y - player.position_y;
     let dist_sq = dx * dx + dy * dy;
@@ -277,11 +339,66 @@ pub fn place_fence(
         ));
     }
     
-    // 9. Calculate chunk index
-    let chunk_index = calculate_chunk_index(world_x, world_y);
+    Ok((world_x, world_y))
+}
+
+/// Shared placement logic for fences and gates
+fn place_fence_piece(
+    ctx: &ReducerContext,
+    cell_x: i64,
+    cell_y: i64,
+    edge: u8,
+    gate_type: u8,
+) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let players = ctx.db.player();
+    let piece_name = get_fence_piece_name(gate_type);
+    
+    log::info!(
+        "[PlaceFence] Player {:?} attempting to place {} at cell ({}, {}), edge={}",
+        sender_id, piece_name, cell_x, cell_y, edge
+    );
+    
+    // 1. Validate player
+    let player = players.identity().find(&sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    
+    if player.is_dead {
+        return Err("Cannot place fence while dead.".to_string());
+    }
+    
+    if player.is_knocked_out {
+        return Err("Cannot place fence while knocked out.".to_string());
+    }
+    
+    // 2. Validate Blueprint equipped
+    if !player_has_blueprint(ctx, sender_id) {
+        return Err("Blueprint must be equipped to place fences.".to_string());
+    }
+    
+    // 3. Validate edge
+    if edge > 3 {
+        return Err(format!("Invalid edge: {}. Must be 0-3 (N, E, S, W).", edge));
+    }
+    
+    // 4. Convert cell coordinates
+    let cell_x_i32 = cell_x as i32;
+    let cell_y_i32 = cell_y as i32;
+    
+    // 5. Validate every edge this piece covers (double gates cover two)
+    let mut edges_to_place = vec![(cell_x_i32, cell_y_i32)];
+    if gate_type == FENCE_GATE_DOUBLE {
+        edges_to_place.push(get_double_gate_partner_cell(cell_x_i32, cell_y_i32, edge));
+    }
+    
+    let mut placements = Vec::new();
+    for &(piece_cell_x, piece_cell_y) in &edges_to_place {
+        let (world_x, world_y) = validate_fence_edge_placement(ctx, &player, piece_cell_x, piece_cell_y, edge)?;
+        placements.push((piece_cell_x, piece_cell_y, world_x, world_y));
+    }
     
-    // 10. Check and consume resources
-    let required_wood = FENCE_WOOD_COST;
+    // 6. Check and consume resources
+    let required_wood = get_fence_piece_wood_cost(gate_type);
     
     let inventory = ctx.db.inventory_item();
     let item_defs = ctx.db.item_definition();
@@ -339,180 +456,128 @@ pub fn place_fence(
     
     log::info!("[PlaceFence] Consumed {} wood from player {:?}", required_wood, sender_id);
     
-    // 11. Create and insert fence (fences start at Wood tier = 1)
+    // 7. Create and insert fence pieces (fences start at Wood tier = 1, gates start closed)
     let fences = ctx.db.fence();
     let initial_tier = 1u8; // Wood tier
-    let new_fence = Fence {
-        id: 0, // Auto-incremented
-        owner_id: sender_id,
-        cell_x: cell_x_i32,
-        cell_y: cell_y_i32,
-        edge,
-        pos_x: world_x,
-        pos_y: world_y,
-        tier: initial_tier,
-        health: FENCE_WOOD_MAX_HEALTH,
-        max_health: FENCE_WOOD_MAX_HEALTH,
-        placed_at: ctx.timestamp,
-        chunk_index,
-        is_destroyed: false,
-        destroyed_at: None,
-        last_hit_time: None,
-        last_damaged_by: None,
-        is_monument: false,
-    };
+    let mut inserted_ids = Vec::new();
+    for &(piece_cell_x, piece_cell_y, world_x, world_y) in &placements {
+        let new_fence = Fence {
+            id: 0, // Auto-incremented
+            owner_id: sender_id,
+            cell_x: piece_cell_x,
+            cell_y: piece_cell_y,
+            edge,
+            pos_x: world_x,
+            pos_y: world_y,
+            tier: initial_tier,
+            health: FENCE_WOOD_MAX_HEALTH,
+            max_health: FENCE_WOOD_MAX_HEALTH,
+            placed_at: ctx.timestamp,
+            chunk_index: calculate_chunk_index(world_x, world_y),
+            is_destroyed: false,
+            destroyed_at: None,
+            last_hit_time: None,
+            last_damaged_by: None,
+            is_monument: false,
+            gate_type,
+            is_open: false,
+            paired_gate_id: None,
+        };
+        
+        let inserted = fences.try_insert(new_fence)
+            .map_err(|e| format!("Failed to insert {}: {}", piece_name, e))?;
+        inserted_ids.push(inserted.id);
+    }
     
-    fences.try_insert(new_fence)
-        .map_err(|e| format!("Failed to insert fence: {}", e))?;
+    // 8. Link the two halves of a double gate
+    if let [first_id, second_id] = inserted_ids[..] {
+        for (id, partner_id) in [(first_id, second_id), (second_id, first_id)] {
+            if let Some(mut half) = fences.id().find(&id) {
+                half.paired_gate_id = Some(partner_id);
+                fences.id().update(half);
+            }
+        }
+    }
     
-    // 12. Emit construction sound
-    crate::sound_events::emit_foundation_wood_constructed_sound(ctx, world_x, world_y, sender_id);
+    // 9. Emit construction sound
+    let (_, _, sound_x, sound_y) = placements[0];
+    crate::sound_events::emit_foundation_wood_constructed_sound(ctx, sound_x, sound_y, sender_id);
     
     log::info!(
-        "[PlaceFence] Successfully placed fence at cell ({}, {}), edge={}, health={:.1}",
-        cell_x, cell_y, edge, FENCE_MAX_HEALTH
+        "[PlaceFence] Successfully placed {} at cell ({}, {}), edge={}, health={:.1}",
+        piece_name, cell_x, cell_y, edge, FENCE_MAX_HEALTH
     );
     
     Ok(())
 }
 
-/// Applies weapon damage to a fence (called from combat system or projectile collision)
-/// Fences use wood tier damage reduction (25% melee damage)
-pub fn damage_fence(
-    ctx: &ReducerContext,
-    attacker_id: Identity,
-    fence_id: u64,
-    damage: f32,
-    timestamp: Timestamp,
-) -> Result<(), String> {
+/// Open or close a fence gate. Double gates swing both halves together.
+#[spacetimedb::reducer]
+pub fn toggle_fence_gate(ctx: &ReducerContext, fence_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
     let fences = ctx.db.fence();
     
-    // Find the fence
-    let mut fence = fences.id().find(&fence_id)
-        .ok_or_else(|| format!("Fence with ID {} not found.", fence_id))?;
-    
-    if fence.is_destroyed {
-        return Err("Fence is already destroyed.".to_string());
-    }
+    // 1. Validate player
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
     
-    // Monument fences are indestructible
-    if fence.is_monument {
-        return Err("Cannot damage monument fences.".to_string());
+    if player.is_dead {
+        return Err("Cannot use gate while dead.".to_string());
     }
     
-    // <<< PVP RAIDING CHECK >>>
-    if let Some(attacker_player) = ctx.db.player().identity().find(&attacker_id) {
-        let attacker_pvp = crate::combat::is_pvp_active_for_player(&attacker_player, timestamp);
-        
-        // Check if owner has PvP enabled (if owner is not the attacker)
-        if fence.owner_id != attacker_id {
-            if let Some(owner_player) = ctx.db.player().identity().find(&fence.owner_id) {
-                let owner_pvp = crate::combat::is_pvp_active_for_player(&owner_player, timestamp);
-                
-                if !attacker_pvp || !owner_pvp {
-                    log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", 
-                        attacker_pvp, owner_pvp);
-                    return Err("Cannot damage structure - PvP raiding requires both players to have PvP enabled.".to_string());
-                }
-            }
-        }
+    if player.is_knocked_out {
+        return Err("Cannot use gate while knocked out.".to_string());
     }
-    // <<< END PVP RAIDING CHECK >>>
-    
-    // Apply tier-based damage reduction
-    let damage_mult = get_fence_damage_multiplier(fence.tier);
-    let effective_damage = damage * damage_mult;
     
-    let old_health = fence.health;
-    fence.health = (fence.health - effective_damage).max(0.0);
-    fence.last_hit_time = Some(timestamp);
-    fence.last_damaged_by = Some(attacker_id);
+    // 2. Find gate
+    let gate = fences.id().find(&fence_id)
+        .ok_or_else(|| "Fence not found".to_string())?;
     
-    log::info!(
-        "Player {:?} hit Fence {} (tier {}) for {:.1} damage (base: {:.1}, mult: {:.0}%). Health: {:.1} -> {:.1}",
-        attacker_id, fence_id, fence.tier, effective_damage, damage, damage_mult * 100.0, old_health, fence.health
-    );
+    if gate.is_destroyed {
+        return Err("Gate is destroyed.".to_string());
+    }
     
-    if fence.health <= 0.0 {
-        // Fence destroyed
-        fence.health = 0.0;
-        fence.is_destroyed = true;
-        fence.destroyed_at = Some(timestamp);
-        
-        log::info!("[FenceDamage] Fence {} destroyed by player {:?}", fence_id, attacker_id);
-        
-        // Emit destruction sound
-        crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, fence.pos_x, fence.pos_y, attacker_id);
-    } else {
-        // Fence damaged but not destroyed
-        log::info!("[FenceDamage] Fence {} damaged, health: {:.1}", fence_id, fence.health);
-        
-        // Emit hit sound
-        crate::sound_events::emit_melee_hit_sharp_sound(ctx, fence.pos_x, fence.pos_y, attacker_id);
+    if gate.gate_type == FENCE_GATE_NONE {
+        return Err("This fence is not a gate.".to_string());
     }
     
-    // Update the fence
-    fences.id().update(fence);
+    // 3. Check access - owner on unclaimed land, hearth privilege inside a zone
+    if !can_player_toggle_gate(ctx, &gate, sender_id) {
+        return Err("Only the gate's owner or players with building privilege can use this gate.".to_string());
+    }
     
-    Ok(())
-}
-
-/// Applies explosive damage to a fence (bypasses melee damage reduction)
-/// Used by explosion system - explosives are effective against fences
-pub fn damage_fence_explosive(
-    ctx: &ReducerContext,
-    attacker_id: Identity,
-    fence_id: u64,
-    damage: f32,
-) {
-    let fences = ctx.db.fence();
+    // 4. Check distance
+    let dx = gate.pos_x - player.position_x;
+    let dy = gate.pos_y - player.position_y;
+    if dx * dx + dy * dy > FENCE_GATE_INTERACTION_DISTANCE_SQUARED {
+        return Err("Gate is too far away.".to_string());
+    }
     
-    if let Some(mut fence) = fences.id().find(&fence_id) {
-        if fence.is_destroyed {
-            return;
-        }
-        
-        // Monument fences are indestructible
-        if fence.is_monument {
-            return;
-        }
-        
-        // <<< PVP RAIDING CHECK >>>
-        if let Some(attacker_player) = ctx.db.player().identity().find(&attacker_id) {
-            let attacker_pvp = crate::combat::is_pvp_active_for_player(&attacker_player, ctx.timestamp);
-            
-            if fence.owner_id != attacker_id {
-                if let Some(owner_player) = ctx.db.player().identity().find(&fence.owner_id) {
-                    let owner_pvp = crate::combat::is_pvp_active_for_player(&owner_player, ctx.timestamp);
-                    
-                    if !attacker_pvp || !owner_pvp {
-                        log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", 
-                            attacker_pvp, owner_pvp);
-                        return; // Skip this structure in explosion
-                    }
-                }
+    // 5. Toggle this gate and its partner half
+    let new_is_open = !gate.is_open;
+    let mut gate_ids = vec![gate.id];
+    if let Some(partner_id) = gate.paired_gate_id {
+        gate_ids.push(partner_id);
+    }
+    for id in gate_ids {
+        if let Some(mut half) = fences.id().find(&id) {
+            if half.is_destroyed {
+                continue;
             }
+            half.is_open = new_is_open;
+            fences.id().update(half);
         }
-        // <<< END PVP RAIDING CHECK >>>
-        
-        // Explosive damage bypasses melee reduction - full damage
-        let old_health = fence.health;
-        fence.health = (fence.health - damage).max(0.0);
-        fence.last_hit_time = Some(ctx.timestamp);
-        fence.last_damaged_by = Some(attacker_id);
-        
-        if fence.health <= 0.0 {
-            fence.is_destroyed = true;
-            fence.destroyed_at = Some(ctx.timestamp);
-            crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, fence.pos_x, fence.pos_y, ctx.sender());
-            log::info!("[FenceExplosiveDamage] Fence {} destroyed by explosion", fence_id);
-        } else {
-            crate::sound_events::emit_melee_hit_sharp_sound(ctx, fence.pos_x, fence.pos_y, ctx.sender());
-            log::info!("[FenceExplosiveDamage] Fence {} took {:.1} explosive damage, health: {:.1}", fence_id, damage, fence.health);
-        }
-        
-        fences.id().update(fence);
     }
+    
+    // 6. Play the door sound for gates too
+    crate::sound_events::emit_door_opening_sound(ctx, gate.pos_x, gate.pos_y, sender_id);
+    
+    log::info!(
+        "[ToggleFenceGate] Gate {} {} by player {:?}",
+        fence_id, if new_is_open { "opened" } else { "closed" }, sender_id
+    );
+    
+    Ok(())
 }
 
 // --- Collision Detection Functions ---
@@ -592,9 +657,9 @@ pub fn check_projectile_fence_collision(
     
     for cell_x in start_cell_x..=end_cell_x {
         for cell_y in start_cell_y..=end_cell_y {
-            // Find fences at this cell
+            // Find fences at this cell (open gates let projectiles through)
             for fence in fences.idx_cell_coords().filter((cell_x, cell_y)) {
-                if fence.is_destroyed {
+                if !fence_blocks(&fence) {
                     continue;
                 }
                 
@@ -678,7 +743,7 @@ pub fn check_fence_collision(
             let check_cell_y = cell_y + offset_y;
             
             for fence in fences.idx_cell_coords().filter((check_cell_x, check_cell_y)) {
-                if fence.is_destroyed {
+                if !fence_blocks(&fence) {
                     continue;
                 }
                 
@@ -820,17 +885,6 @@ pub fn upgrade_fence(
         return Err("Repair Hammer must be equipped to upgrade fences.".to_string());
     }
     
-    // 2.5. Check building privilege (only if hearths exist)
-    use crate::homestead_hearth::player_has_building_privilege;
-    let hearths = ctx.db.homestead_hearth();
-    let any_hearth_exists = hearths.iter().any(|h| !h.is_destroyed);
-    
-    if any_hearth_exists {
-        if !player_has_building_privilege(ctx, sender_id) {
-            return Err("Building privilege required. Hold E near a Homestead Hearth to gain building privilege.".to_string());
-        }
-    }
-    
     // 3. Find fence
     let fence = fences.id().find(&fence_id)
         .ok_or_else(|| "Fence not found".to_string())?;
@@ -839,6 +893,9 @@ pub fn upgrade_fence(
         return Err("Cannot upgrade destroyed fence.".to_string());
     }
     
+    // 3.5. Check building privilege at the fence's position
+    crate::homestead_hearth::check_position_privileged(ctx, fence.pos_x, fence.pos_y, sender_id)?;
+    
     // Monument fences cannot be upgraded
     if fence.is_monument {
         return Err("Cannot upgrade monument fences.".to_string());
@@ -1114,6 +1171,9 @@ pub fn spawn_compound_perimeter_fences(
             last_hit_time: None,
             last_damaged_by: None,
             is_monument: true,
+            gate_type: FENCE_GATE_NONE,
+            is_open: false,
+            paired_gate_id: None,
         };
         
         if fences.try_insert(new_fence).is_ok() {
@@ -1148,6 +1208,9 @@ pub fn spawn_compound_perimeter_fences(
             last_hit_time: None,
             last_damaged_by: None,
             is_monument: true,
+            gate_type: FENCE_GATE_NONE,
+            is_open: false,
+            paired_gate_id: None,
         };
         
         if fences.try_insert(new_fence).is_ok() {
@@ -1182,6 +1245,9 @@ pub fn spawn_compound_perimeter_fences(
             last_hit_time: None,
             last_damaged_by: None,
             is_monument: true,
+            gate_type: FENCE_GATE_NONE,
+            is_open: false,
+            paired_gate_id: None,
         };
         
         if fences.try_insert(new_fence).is_ok() {
@@ -1216,6 +1282,9 @@ pub fn spawn_compound_perimeter_fences(
             last_hit_time: None,
             last_damaged_by: None,
             is_monument: true,
+            gate_type: FENCE_GATE_NONE,
+            is_open: false,
+            paired_gate_id: None,
         };
         
         if fences.try_insert(new_fence).is_ok() {
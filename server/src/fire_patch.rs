@@ -7,6 +7,12 @@
  * HeavyStorm). They can be extinguished by water patches or naturally       *
  * expire over time. Consistent with campfire rain rules.                    *
  *                                                                            *
+ * In dry (Clear) weather fires also burn grass and trees and spread across  *
+ * vegetated tiles as a wildfire. Burned trees become charcoal-yielding      *
+ * stumps. Bare tiles (dirt, roads, sand, water, stone) don't carry fire, so *
+ * a strip of dirt works as a firebreak. Rain dims fire patches each tick    *
+ * until they go out.                                                         *
+ *                                                                            *
  ******************************************************************************/
 
 use spacetimedb::{table, reducer, ReducerContext, Identity, Timestamp, ScheduleAt, Table};
@@ -32,6 +38,15 @@ pub const FIRE_PATCH_NPC_DAMAGE: f32 = 5.0; // Damage per tick to hostile NPCs
 pub const FIRE_PROPAGATION_CHANCE: f32 = 0.10; // 10% chance to spread to nearby wooden structures (reduced from 15% to prevent chain reactions)
 pub const FIRE_PATCH_SEED_DAMAGE_RADIUS: f32 = 40.0; // Radius for damaging planted seeds (smaller than structures)
 pub const FIRE_PROPAGATION_TO_SEED_CHANCE: f32 = 0.15; // 15% chance to spread to nearby planted seeds (crop fields are dry and flammable)
+pub const FIRE_PATCH_GRASS_BURN_RADIUS: f32 = 45.0; // Grass within this radius burns away
+pub const FIRE_PATCH_TREE_IGNITE_RADIUS: f32 = 64.0; // Fire patch radius + tree trunk radius
+pub const FIRE_PROPAGATION_TO_GRASS_CHANCE: f32 = 0.25; // 25% chance a burning grass patch starts a new fire (wildfire spread)
+pub const FIRE_PROPAGATION_TO_TREE_CHANCE: f32 = 0.20; // 20% chance per tick that a nearby tree catches fire
+pub const BURNT_TREE_CHARCOAL_MIN: u32 = 5;
+pub const BURNT_TREE_CHARCOAL_MAX: u32 = 12;
+// Intensity lost per cleanup tick while raining (patch goes out at 0)
+pub const FIRE_PATCH_LIGHT_RAIN_DECAY: f32 = 0.2;
+pub const FIRE_PATCH_MODERATE_RAIN_DECAY: f32 = 0.4;
 
 // --- Fire Patch Table ---
 #[table(accessor = fire_patch, public)]
@@ -241,7 +256,7 @@ pub fn process_fire_patch_damage(ctx: &ReducerContext, _args: FirePatchDamageSch
         }
         
         // Check each hostile NPC (always damaged by fire)
-        for mut animal in ctx.db.wild_animal().iter() {
+        for mut animal in ctx.db.wild_animal().iter().map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row)) {
             if !animal.is_hostile_npc || animal.health <= 0.0 {
                 continue;
             }
@@ -496,6 +511,144 @@ pub fn apply_fire_damage_to_planted_seeds(ctx: &ReducerContext) -> Result<(), St
     Ok(())
 }
 
+/// Whether fire can spread onto the tile at this position. Only vegetated ground
+/// carries fire - dirt, roads, sand, water and stone act as firebreaks.
+fn is_flammable_ground(ctx: &ReducerContext, x: f32, y: f32) -> bool {
+    use crate::TileType;
+    
+    let (tile_x, tile_y) = crate::world_pos_to_tile_coords(x, y);
+    matches!(
+        crate::get_tile_type_at_position(ctx, tile_x, tile_y),
+        Some(TileType::Grass | TileType::Forest | TileType::Tundra | TileType::TundraGrass)
+    )
+}
+
+/// Burns grass and trees near fire patches and spreads the fire across vegetation.
+/// Only happens in dry (Clear) weather - any rain keeps vegetation too wet to catch.
+pub fn apply_fire_to_vegetation(ctx: &ReducerContext) -> Result<(), String> {
+    use crate::grass::{grass as GrassTableTrait, grass_state as GrassStateTableTrait};
+    use crate::tree::tree as TreeTableTrait;
+    use crate::items::item_definition as ItemDefinitionTableTrait;
+    use crate::world_state::WeatherType;
+    use rand::{Rng, SeedableRng};
+    
+    let current_time = ctx.timestamp;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(current_time.to_micros_since_unix_epoch() as u64);
+    
+    let grass_radius_sq = FIRE_PATCH_GRASS_BURN_RADIUS * FIRE_PATCH_GRASS_BURN_RADIUS;
+    let tree_radius_sq = FIRE_PATCH_TREE_IGNITE_RADIUS * FIRE_PATCH_TREE_IGNITE_RADIUS;
+    // (pos_x, pos_y, creator) of burned vegetation that may carry the fire further
+    let mut spread_points: Vec<(f32, f32, Identity)> = Vec::new();
+    let mut burned_trees: Vec<(u64, Identity)> = Vec::new();
+    
+    let fire_patches: Vec<FirePatch> = ctx.db.fire_patch().iter().collect();
+    for fire_patch in &fire_patches {
+        let chunk_weather = crate::world_state::get_weather_for_position(ctx, fire_patch.pos_x, fire_patch.pos_y);
+        if chunk_weather.current_weather != WeatherType::Clear {
+            continue; // Wet vegetation doesn't burn
+        }
+        
+        // Grass burns away (brambles are too tough to burn)
+        for grass in ctx.db.grass().chunk_index().filter(fire_patch.chunk_index) {
+            if grass.appearance_type.is_bramble()
+                || get_distance_squared(grass.pos_x, grass.pos_y, fire_patch.pos_x, fire_patch.pos_y) >= grass_radius_sq {
+                continue;
+            }
+            let Some(mut state) = ctx.db.grass_state().grass_id().find(grass.id) else { continue; };
+            if !state.is_alive {
+                continue;
+            }
+            state.health = 0;
+            state.is_alive = false;
+            state.last_hit_time = Some(current_time);
+            let respawn_secs = rng.gen_range(crate::grass::MIN_GRASS_RESPAWN_TIME_SECS..=crate::grass::MAX_GRASS_RESPAWN_TIME_SECS);
+            state.respawn_at = current_time + spacetimedb::TimeDuration::from_micros(respawn_secs as i64 * 1_000_000);
+            ctx.db.grass_state().grass_id().update(state);
+            
+            if rng.gen::<f32>() < FIRE_PROPAGATION_TO_GRASS_CHANCE {
+                spread_points.push((grass.pos_x, grass.pos_y, fire_patch.created_by));
+            }
+        }
+        
+        // Standing trees may catch fire
+        for tree in ctx.db.tree().chunk_index().filter(fire_patch.chunk_index) {
+            if tree.health == 0 || tree.respawn_at != Timestamp::UNIX_EPOCH
+                || get_distance_squared(tree.pos_x, tree.pos_y, fire_patch.pos_x, fire_patch.pos_y) >= tree_radius_sq {
+                continue;
+            }
+            if rng.gen::<f32>() < FIRE_PROPAGATION_TO_TREE_CHANCE && !burned_trees.iter().any(|(id, _)| *id == tree.id) {
+                burned_trees.push((tree.id, fire_patch.created_by));
+            }
+        }
+    }
+    
+    // Burn trees down to stumps that yield charcoal
+    let charcoal_def_id = ctx.db.item_definition().iter().find(|def| def.name == "Charcoal").map(|def| def.id);
+    for (tree_id, fire_creator) in burned_trees {
+        let Some(mut tree) = ctx.db.tree().id().find(tree_id) else { continue; };
+        tree.health = 0;
+        tree.resource_remaining = 0;
+        tree.is_burnt = true;
+        tree.last_hit_time = Some(current_time);
+        let respawn_secs = if tree.is_player_planted {
+            100 * 365 * 24 * 3600 // Player-planted trees never regrow (same as when chopped down)
+        } else {
+            rng.gen_range(crate::tree::MIN_TREE_RESPAWN_TIME_SECS..=crate::tree::MAX_TREE_RESPAWN_TIME_SECS) as i64
+        };
+        tree.respawn_at = current_time + spacetimedb::TimeDuration::from_micros(respawn_secs * 1_000_000);
+        let (tree_x, tree_y) = (tree.pos_x, tree.pos_y);
+        ctx.db.tree().id().update(tree);
+        crate::spatial_grid::invalidate_static_grid();
+        
+        if let Some(charcoal_def_id) = charcoal_def_id {
+            let quantity = rng.gen_range(BURNT_TREE_CHARCOAL_MIN..=BURNT_TREE_CHARCOAL_MAX);
+            if let Err(e) = crate::dropped_item::create_dropped_item_entity(ctx, charcoal_def_id, quantity, tree_x, tree_y + 20.0) {
+                log::error!("[FirePatch] Failed to drop charcoal from burnt tree {}: {}", tree_id, e);
+            }
+        }
+        log::info!("[FirePatch] Fire burned tree {} at ({:.1}, {:.1}) down to a stump", tree_id, tree_x, tree_y);
+        
+        // A burning tree always lights the ground around it
+        spread_points.push((tree_x, tree_y, fire_creator));
+    }
+    
+    // Spread the fire onto nearby vegetated ground (dirt and other bare tiles act as firebreaks)
+    for (x, y, fire_creator) in spread_points {
+        let spread_x = x + rng.gen_range(-50.0..50.0);
+        let spread_y = y + rng.gen_range(-50.0..50.0);
+        if !is_flammable_ground(ctx, spread_x, spread_y) {
+            continue;
+        }
+        let _ = create_fire_patch(ctx, spread_x, spread_y, fire_creator, false, None, None);
+    }
+    
+    Ok(())
+}
+
+/// Rain dims fire patches each tick; heavy rain puts them out at once
+pub fn apply_rain_to_fire_patches(ctx: &ReducerContext) -> Result<(), String> {
+    use crate::world_state::WeatherType;
+    
+    for mut fire_patch in ctx.db.fire_patch().iter().collect::<Vec<_>>() {
+        let chunk_weather = crate::world_state::get_weather_for_position(ctx, fire_patch.pos_x, fire_patch.pos_y);
+        let decay = match chunk_weather.current_weather {
+            WeatherType::Clear => continue,
+            WeatherType::LightRain => FIRE_PATCH_LIGHT_RAIN_DECAY,
+            WeatherType::ModerateRain => FIRE_PATCH_MODERATE_RAIN_DECAY,
+            WeatherType::HeavyRain | WeatherType::HeavyStorm => 1.0,
+        };
+        fire_patch.current_intensity -= decay;
+        if fire_patch.current_intensity <= 0.0 {
+            log::info!("[FirePatch] Rain extinguished fire patch {} at ({:.1}, {:.1})", fire_patch.id, fire_patch.pos_x, fire_patch.pos_y);
+            ctx.db.fire_patch().id().delete(fire_patch.id);
+        } else {
+            ctx.db.fire_patch().id().update(fire_patch);
+        }
+    }
+    
+    Ok(())
+}
+
 /// Checks if water patches can extinguish fire patches
 pub fn check_water_extinguishes_fire(ctx: &ReducerContext) -> Result<(), String> {
     use crate::water_patch::water_patch as WaterPatchTableTrait;
@@ -568,9 +721,15 @@ pub fn cleanup_expired_fire_patches(ctx: &ReducerContext, _args: FirePatchCleanu
     // Apply fire damage to planted seeds (crop sabotage)
     apply_fire_damage_to_planted_seeds(ctx)?;
     
+    // Burn grass and trees (wildfire spread in dry weather)
+    apply_fire_to_vegetation(ctx)?;
+    
     // Check if water extinguishes fire
     check_water_extinguishes_fire(ctx)?;
     
+    // Rain dims fires until they go out
+    apply_rain_to_fire_patches(ctx)?;
+    
     Ok(())
 }
 
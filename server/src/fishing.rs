@@ -34,12 +34,30 @@ pub struct FishingSession {
     pub has_bite: bool,
 }
 
+// Roaming fish school - a visible fishing hotspot drifting through Sea tiles.
+// Casting into one greatly improves the catch; heavy fishing scares it off and eventually depletes it.
+#[table(accessor = fish_school, public)]
+#[derive(Clone, Debug)]
+pub struct FishSchool {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub target_x: f32, // Where the school is drifting to
+    pub target_y: f32,
+    pub radius: f32,
+    pub fish_remaining: u32, // Catches left before the school scatters and reforms elsewhere
+    pub max_fish: u32,
+    pub last_moved_at: Timestamp,
+}
+
 // Fish tier enum for categorizing fish rarity
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum FishTier {
     Common,    // Tier 1: Twigfish, Herring, Smelt
-    Uncommon,  // Tier 2: Greenling, Sculpin, Pacific Cod
-    Rare,      // Tier 3: Dolly Varden, Rockfish, Steelhead
+    Uncommon,  // Tier 2: Greenling, Sculpin, Pacific Cod, Arctic Grayling
+    Rare,      // Tier 3: Dolly Varden, Rockfish, Steelhead, Northern Pike
     Premium,   // Tier 4: Pink Salmon, Sockeye Salmon, King Salmon, Halibut
 }
 
@@ -72,6 +90,7 @@ pub enum FishWaterType {
     Shore,    // Shallow ocean (near beach)
     Ocean,    // Any ocean (shore or deep)
     DeepSea,  // Deep ocean only (far from shore)
+    Migratory, // Any ocean plus rivers/lakes (salmon running upriver to spawn)
 }
 
 // Fish weather preference (matches WeatherType variants)
@@ -96,7 +115,7 @@ pub struct FishEntry {
     pub deep_water_bonus: f32,      // Extra weight for cast variation (0.0 to 1.0)
 }
 
-// Map fish names to bit positions for unique fish tracking (0-15 for 16 fish types)
+// Map fish names to bit positions for unique fish tracking (0-17 for 18 fish types)
 // This bitmask is stored in PlayerStats.unique_fish_bitmask
 pub fn get_fish_bit_index(fish_name: &str) -> Option<u32> {
     match fish_name {
@@ -120,6 +139,9 @@ pub fn get_fish_bit_index(fish_name: &str) -> Option<u32> {
         "Raw Sockeye Salmon" => Some(13),
         "Raw King Salmon" => Some(14),
         "Raw Halibut" => Some(15),
+        // River and lake fish: Bits 16-17
+        "Raw Arctic Grayling" => Some(16),
+        "Raw Northern Pike" => Some(17),
         _ => None, // Not a tracked fish type (junk items)
     }
 }
@@ -211,6 +233,15 @@ fn get_fish_database() -> Vec<FishEntry> {
             base_weight: 0.7,
             deep_water_bonus: 0.4,
         },
+        FishEntry {
+            name: "Raw Arctic Grayling",
+            tier: FishTier::Uncommon,
+            time_preference: FishTimePreference::Day,
+            weather_preference: FishWeatherPreference::Clear,
+            water_type: FishWaterType::Inland, // Clear, cold rivers and streams
+            base_weight: 0.9,
+            deep_water_bonus: -0.1,
+        },
         
         // === TIER 3: RARE (Large Fish) ===
         FishEntry {
@@ -240,6 +271,15 @@ fn get_fish_database() -> Vec<FishEntry> {
             base_weight: 0.7,
             deep_water_bonus: 0.3,
         },
+        FishEntry {
+            name: "Raw Northern Pike",
+            tier: FishTier::Rare,
+            time_preference: FishTimePreference::Day,
+            weather_preference: FishWeatherPreference::LightRain,
+            water_type: FishWaterType::Inland, // Ambush predator of weedy lakes and slow rivers
+            base_weight: 0.8,
+            deep_water_bonus: 0.2,
+        },
         
         // === TIER 4: PREMIUM (Very Large/Rare Fish) ===
         FishEntry {
@@ -247,7 +287,7 @@ fn get_fish_database() -> Vec<FishEntry> {
             tier: FishTier::Premium,
             time_preference: FishTimePreference::DawnDusk,
             weather_preference: FishWeatherPreference::ModerateRain,
-            water_type: FishWaterType::Ocean,
+            water_type: FishWaterType::Migratory, // Runs up rivers to spawn
             base_weight: 1.0,
             deep_water_bonus: 0.3,
         },
@@ -256,7 +296,7 @@ fn get_fish_database() -> Vec<FishEntry> {
             tier: FishTier::Premium,
             time_preference: FishTimePreference::Twilight,
             weather_preference: FishWeatherPreference::HeavyRain,
-            water_type: FishWaterType::Ocean,
+            water_type: FishWaterType::Migratory, // Runs up rivers to spawn
             base_weight: 0.7,
             deep_water_bonus: 0.4,
         },
@@ -301,6 +341,7 @@ fn fish_matches_water_type(fish: &FishEntry, pos_water_type: FishingWaterType) -
         (FWT::Shore, PWT::Shore) => true,
         (FWT::Ocean, PWT::Shore) | (FWT::Ocean, PWT::DeepSea) => true,
         (FWT::DeepSea, PWT::DeepSea) => true,
+        (FWT::Migratory, PWT::Inland | PWT::Shore | PWT::DeepSea) => true, // Salmon runs reach every water
         _ => false,
     }
 }
@@ -427,7 +468,8 @@ fn get_tier_spawn_chance(tier: FishTier, effectiveness: f32, deep_water_factor:
 // Generate loot for a successful fishing attempt with time-of-day, weather, depth, and location bonuses
 // Uses chunk-based weather at the fishing target location for accurate local conditions
 // Includes fishing village bonus when player has the FishingVillageBonus effect (standing in Aleut village)
-pub fn generate_fishing_loot(ctx: &ReducerContext, target_x: f32, target_y: f32, player_id: spacetimedb::Identity) -> Vec<String> {
+// Casting into a fish school (in_fish_school) boosts rare tiers, doubles catches more often and rules out junk
+pub fn generate_fishing_loot(ctx: &ReducerContext, target_x: f32, target_y: f32, player_id: spacetimedb::Identity, in_fish_school: bool) -> Vec<String> {
     let mut loot = Vec::new();
     
     // Get current time of day (global)
@@ -471,9 +513,16 @@ pub fn generate_fishing_loot(ctx: &ReducerContext, target_x: f32, target_y: f32,
         0.0 
     };
     
+    // Fish schools draw in bigger fish - flat bonus to the rare and premium tiers
+    let (school_rare_bonus, school_premium_bonus) = if in_fish_school {
+        (FISH_SCHOOL_RARE_TIER_BONUS, FISH_SCHOOL_PREMIUM_TIER_BONUS)
+    } else {
+        (0.0, 0.0)
+    };
+    
     let selected_tier = {
-        let premium_chance = get_tier_spawn_chance(FishTier::Premium, total_effectiveness, deep_water_factor) + village_premium_bonus;
-        let rare_chance = get_tier_spawn_chance(FishTier::Rare, total_effectiveness, deep_water_factor);
+        let premium_chance = get_tier_spawn_chance(FishTier::Premium, total_effectiveness, deep_water_factor) + village_premium_bonus + school_premium_bonus;
+        let rare_chance = get_tier_spawn_chance(FishTier::Rare, total_effectiveness, deep_water_factor) + school_rare_bonus;
         let uncommon_chance = get_tier_spawn_chance(FishTier::Uncommon, total_effectiveness, deep_water_factor);
         
         let roll = ctx.rng().gen_range(0.0..1.0);
@@ -538,13 +587,21 @@ pub fn generate_fishing_loot(ctx: &ReducerContext, target_x: f32, target_y: f32,
         log::info!("🏘️ Village fishing bonus! Doubled catch: +1 {}", selected_fish);
     }
     
+    // === FISH SCHOOL HAUL ===
+    // The line comes up through a whole school - good odds of a second fish of the same kind
+    if in_fish_school && ctx.rng().gen_range(0.0..1.0) < FISH_SCHOOL_DOUBLE_CATCH_CHANCE {
+        loot.push(selected_fish.to_string());
+        log::info!("🐟🐟 Fish school bonus! Doubled catch: +1 {}", selected_fish);
+    }
+    
     // Bonus fish chance during excellent conditions (enhanced by fishing village bonus)
     let village_bonus_mult = if has_fishing_village_bonus { 
         crate::fishing_village::FISHING_VILLAGE_BONUS_FISH_CHANCE_MULTIPLIER 
     } else { 
         1.0 
     };
-    let bonus_fish_chance = 0.15 * total_effectiveness * village_bonus_mult; // 15% base, up to ~37% in perfect conditions, 1.5x in village
+    let school_bonus_fish_chance = if in_fish_school { FISH_SCHOOL_BONUS_FISH_CHANCE } else { 0.0 };
+    let bonus_fish_chance = 0.15 * total_effectiveness * village_bonus_mult + school_bonus_fish_chance; // 15% base, up to ~37% in perfect conditions, 1.5x in village
     if ctx.rng().gen_range(0.0..1.0) < bonus_fish_chance {
         // Bonus fish is always common tier, filtered by water type
         let common_fish: Vec<&FishEntry> = fish_database
@@ -564,7 +621,8 @@ pub fn generate_fishing_loot(ctx: &ReducerContext, target_x: f32, target_y: f32,
     // Formula: 25% base chance, reduced by effectiveness (down to ~0% in perfect conditions)
     // Perfect conditions (effectiveness > 3.0): ~0% junk chance
     // Poor conditions (effectiveness < 1.0): up to 25% junk chance
-    let junk_chance = 0.25 * (2.0 - total_effectiveness).max(0.0);
+    // Fish schools: no junk - the hook never reaches the bottom
+    let junk_chance = if in_fish_school { 0.0 } else { 0.25 * (2.0 - total_effectiveness).max(0.0) };
     if ctx.rng().gen_range(0.0..1.0) < junk_chance {
         // Randomly select from junk items (Aleutian Islands themed)
         let junk_items = vec![
@@ -657,6 +715,10 @@ pub fn is_water_tile(ctx: &ReducerContext, x: f32, y: f32) -> bool {
     
     // NEW: Try compressed lookup first for better performance
     if let Some(tile_type) = get_tile_type_at_position(ctx, tile_x, tile_y) {
+        // Frozen sea, rivers and lakes can only be fished through their holes
+        if matches!(tile_type, TileType::Sea | TileType::Freshwater) && crate::ice::is_tile_frozen(ctx, tile_x, tile_y) {
+            return false;
+        }
         return tile_type.is_water(); // Includes both Sea and HotSpringWater
     }
     
@@ -783,7 +845,12 @@ pub fn finish_fishing(ctx: &ReducerContext, success: bool, _caught_items: Vec<St
         // Generate loot server-side to ensure fairness and prevent cheating
         // Pass fishing target position for accurate chunk-based weather calculations
         // Pass player_id for fishing village bonus effect check
-        let generated_loot = generate_fishing_loot(ctx, fishing_session.target_x, fishing_session.target_y, player_id);
+        // Casting into a fish school boosts the catch and thins the school out
+        let school = find_fish_school_at(ctx, fishing_session.target_x, fishing_session.target_y);
+        let generated_loot = generate_fishing_loot(ctx, fishing_session.target_x, fishing_session.target_y, player_id, school.is_some());
+        if let Some(school) = school {
+            deplete_fish_school(ctx, school, fishing_session.target_x, fishing_session.target_y);
+        }
         
         if generated_loot.is_empty() {
             log::error!("Player {} successful fishing but no loot generated! This should never happen!", player_id);
@@ -880,7 +947,7 @@ pub fn finish_fishing(ctx: &ReducerContext, success: bool, _caught_items: Vec<St
                     
                     ctx.db.player_stats().player_id().update(stats);
                     
-                    log::info!("🐟 Player {} caught new fish type: {} (bit {}). Total unique types: {}/16", 
+                    log::info!("🐟 Player {} caught new fish type: {} (bit {}). Total unique types: {}/18", 
                               player_id, item_name, bit_index, unique_count);
                     
                     // Check for fish variety achievements
@@ -924,4 +991,176 @@ pub fn is_player_fishing(ctx: &ReducerContext, player_id: &Identity) -> bool {
 // Get fishing session info for a player
 pub fn get_fishing_session(ctx: &ReducerContext, player_id: &Identity) -> Option<FishingSession> {
     ctx.db.fishing_session().player_id().find(player_id)
-} 
\ No newline at end of file
+} 
+// === FISH SCHOOLS ===
+// Roaming fishing hotspots in Sea tiles. Movement piggybacks on the wild animal AI schedule
+// (see update_fish_schools), throttled per school so clients only get a few updates a second.
+
+const FISH_SCHOOL_TARGET_COUNT: usize = 8;
+const FISH_SCHOOL_RADIUS: f32 = 120.0;
+const FISH_SCHOOL_MIN_FISH: u32 = 6;
+const FISH_SCHOOL_MAX_FISH: u32 = 10;
+const FISH_SCHOOL_MOVE_INTERVAL_MS: i64 = 1000; // Position update rate per school
+const FISH_SCHOOL_DRIFT_SPEED: f32 = 15.0; // px/s - lazy drift, easy to follow along the shore
+const FISH_SCHOOL_WANDER_DISTANCE: f32 = 800.0; // Max distance of a new drift target
+const FISH_SCHOOL_SCARE_DISTANCE: f32 = 300.0; // How far a school bolts after being fished
+const FISH_SCHOOL_MIN_SEPARATION: f32 = 600.0;
+const FISH_SCHOOL_SPAWN_ATTEMPTS: u32 = 30;
+const FISH_SCHOOL_RARE_TIER_BONUS: f32 = 0.15;
+const FISH_SCHOOL_PREMIUM_TIER_BONUS: f32 = 0.08;
+const FISH_SCHOOL_DOUBLE_CATCH_CHANCE: f32 = 0.5;
+const FISH_SCHOOL_BONUS_FISH_CHANCE: f32 = 0.5;
+
+// Fish schools only form in open Sea tiles (not deep sea, Freshwater rivers/lakes or hot springs)
+fn is_fish_school_water(ctx: &ReducerContext, x: f32, y: f32) -> bool {
+    use crate::{world_pos_to_tile_coords, get_tile_type_at_position, TileType};
+    let (tile_x, tile_y) = world_pos_to_tile_coords(x, y);
+    get_tile_type_at_position(ctx, tile_x, tile_y) == Some(TileType::Sea)
+}
+
+// Random open-sea position away from the given schools
+fn find_fish_school_spawn_position(ctx: &ReducerContext, existing: &[(f32, f32)]) -> Option<(f32, f32)> {
+    use crate::{WORLD_WIDTH_TILES, WORLD_HEIGHT_TILES, TILE_SIZE_PX};
+    let mut rng = ctx.rng();
+    for _ in 0..FISH_SCHOOL_SPAWN_ATTEMPTS {
+        let x = (rng.gen_range(0..WORLD_WIDTH_TILES) as f32 + 0.5) * TILE_SIZE_PX as f32;
+        let y = (rng.gen_range(0..WORLD_HEIGHT_TILES) as f32 + 0.5) * TILE_SIZE_PX as f32;
+        let too_close = existing.iter().any(|&(ex, ey)| {
+            crate::utils::get_distance_squared(x, y, ex, ey) < FISH_SCHOOL_MIN_SEPARATION * FISH_SCHOOL_MIN_SEPARATION
+        });
+        if !too_close && is_fish_school_water(ctx, x, y) {
+            return Some((x, y));
+        }
+    }
+    None
+}
+
+// New drift target near a point, staying in open sea (falls back to the point itself)
+fn pick_fish_school_target(ctx: &ReducerContext, from_x: f32, from_y: f32, max_distance: f32) -> (f32, f32) {
+    let mut rng = ctx.rng();
+    for _ in 0..10 {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let distance = rng.gen_range(max_distance * 0.3..max_distance);
+        let x = from_x + angle.cos() * distance;
+        let y = from_y + angle.sin() * distance;
+        if is_fish_school_water(ctx, x, y) {
+            return (x, y);
+        }
+    }
+    (from_x, from_y)
+}
+
+// Find the school a fishing line landed in, if any
+pub fn find_fish_school_at(ctx: &ReducerContext, x: f32, y: f32) -> Option<FishSchool> {
+    ctx.db.fish_school().iter().find(|school| {
+        crate::utils::get_distance_squared(x, y, school.pos_x, school.pos_y) <= school.radius * school.radius
+    })
+}
+
+// A catch thins the school and spooks it away from the line. Once empty it scatters and reforms elsewhere.
+fn deplete_fish_school(ctx: &ReducerContext, mut school: FishSchool, line_x: f32, line_y: f32) {
+    school.fish_remaining = school.fish_remaining.saturating_sub(1);
+
+    if school.fish_remaining == 0 {
+        let others: Vec<(f32, f32)> = ctx.db.fish_school().iter()
+            .filter(|s| s.id != school.id)
+            .map(|s| (s.pos_x, s.pos_y))
+            .collect();
+        match find_fish_school_spawn_position(ctx, &others) {
+            Some((x, y)) => {
+                log::info!("🐟 Fish school {} fished out - reforming at ({:.0}, {:.0})", school.id, x, y);
+                school.pos_x = x;
+                school.pos_y = y;
+                school.target_x = x;
+                school.target_y = y;
+                school.max_fish = ctx.rng().gen_range(FISH_SCHOOL_MIN_FISH..=FISH_SCHOOL_MAX_FISH);
+                school.fish_remaining = school.max_fish;
+                school.last_moved_at = ctx.timestamp;
+                ctx.db.fish_school().id().update(school);
+            }
+            None => {
+                log::info!("🐟 Fish school {} fished out", school.id);
+                ctx.db.fish_school().id().delete(&school.id);
+            }
+        }
+        return;
+    }
+
+    // Bolt away from where the line landed
+    let dx = school.pos_x - line_x;
+    let dy = school.pos_y - line_y;
+    let len = (dx * dx + dy * dy).sqrt();
+    let (dir_x, dir_y) = if len > 1.0 { (dx / len, dy / len) } else { (1.0, 0.0) };
+    let flee_x = school.pos_x + dir_x * FISH_SCHOOL_SCARE_DISTANCE;
+    let flee_y = school.pos_y + dir_y * FISH_SCHOOL_SCARE_DISTANCE;
+    if is_fish_school_water(ctx, flee_x, flee_y) {
+        school.target_x = flee_x;
+        school.target_y = flee_y;
+    } else {
+        let (x, y) = pick_fish_school_target(ctx, school.pos_x, school.pos_y, FISH_SCHOOL_SCARE_DISTANCE);
+        school.target_x = x;
+        school.target_y = y;
+    }
+    ctx.db.fish_school().id().update(school);
+}
+
+// Drifts fish schools and keeps their number topped up. Called from the wild animal AI tick.
+pub fn update_fish_schools(ctx: &ReducerContext) {
+    let schools: Vec<FishSchool> = ctx.db.fish_school().iter().collect();
+
+    if schools.len() < FISH_SCHOOL_TARGET_COUNT {
+        use crate::world_chunk_data as WorldChunkDataTableTrait;
+        if ctx.db.world_chunk_data().iter().next().is_some() {
+            let existing: Vec<(f32, f32)> = schools.iter().map(|s| (s.pos_x, s.pos_y)).collect();
+            if let Some((x, y)) = find_fish_school_spawn_position(ctx, &existing) {
+                let max_fish = ctx.rng().gen_range(FISH_SCHOOL_MIN_FISH..=FISH_SCHOOL_MAX_FISH);
+                let inserted = ctx.db.fish_school().insert(FishSchool {
+                    id: 0,
+                    pos_x: x,
+                    pos_y: y,
+                    target_x: x,
+                    target_y: y,
+                    radius: FISH_SCHOOL_RADIUS,
+                    fish_remaining: max_fish,
+                    max_fish,
+                    last_moved_at: ctx.timestamp,
+                });
+                log::info!("🐟 Fish school {} formed at ({:.0}, {:.0})", inserted.id, x, y);
+            }
+        }
+    }
+
+    for mut school in schools {
+        let elapsed_ms = (ctx.timestamp.to_micros_since_unix_epoch() - school.last_moved_at.to_micros_since_unix_epoch()) / 1000;
+        if elapsed_ms < FISH_SCHOOL_MOVE_INTERVAL_MS {
+            continue;
+        }
+
+        let dx = school.target_x - school.pos_x;
+        let dy = school.target_y - school.pos_y;
+        let distance = (dx * dx + dy * dy).sqrt();
+        let step = FISH_SCHOOL_DRIFT_SPEED * (elapsed_ms.min(5000) as f32 / 1000.0);
+
+        if distance <= step {
+            school.pos_x = school.target_x;
+            school.pos_y = school.target_y;
+            let (x, y) = pick_fish_school_target(ctx, school.pos_x, school.pos_y, FISH_SCHOOL_WANDER_DISTANCE);
+            school.target_x = x;
+            school.target_y = y;
+        } else {
+            let next_x = school.pos_x + dx / distance * step;
+            let next_y = school.pos_y + dy / distance * step;
+            if is_fish_school_water(ctx, next_x, next_y) {
+                school.pos_x = next_x;
+                school.pos_y = next_y;
+            } else {
+                // Drifting into the shallows - turn around
+                let (x, y) = pick_fish_school_target(ctx, school.pos_x, school.pos_y, FISH_SCHOOL_WANDER_DISTANCE);
+                school.target_x = x;
+                school.target_y = y;
+            }
+        }
+        school.last_moved_at = ctx.timestamp;
+        ctx.db.fish_school().id().update(school);
+    }
+}
@@ -23,7 +23,6 @@ use crate::items::{
     InventoryItem, ItemDefinition,
 };
 use crate::inventory_management::{self, ItemContainer, ContainerItemClearer};
-use crate::player_inventory::{get_player_item};
 use crate::environment::calculate_chunk_index;
 use crate::dropped_item::create_dropped_item_entity;
 
@@ -200,171 +199,45 @@ pub struct FumaroleGlobalSchedule {
  *                           REDUCERS (Generic Handlers)                     *
  ******************************************************************************/
 
-/// --- Add Item to Fumarole ---
-#[spacetimedb::reducer]
-pub fn move_item_to_fumarole(ctx: &ReducerContext, fumarole_id: u32, target_slot_index: u8, item_instance_id: u64) -> Result<(), String> {
-    let (_player, mut fumarole) = validate_fumarole_interaction(ctx, fumarole_id)?;
-    
-    // --- SECURITY: Prevent interaction with fumarole slots when broth pot is attached ---
-    if fumarole.attached_broth_pot_id.is_some() {
-        return Err("Cannot add items to fumarole while broth pot is attached. Remove the broth pot first.".to_string());
-    }
-    
-    inventory_management::handle_move_to_container_slot(ctx, &mut fumarole, target_slot_index, item_instance_id)?;
-    ctx.db.fumarole().id().update(fumarole.clone());
-    Ok(())
-}
-
-/// --- Remove Item from Fumarole ---
-#[spacetimedb::reducer]
-pub fn quick_move_from_fumarole(ctx: &ReducerContext, fumarole_id: u32, source_slot_index: u8) -> Result<(), String> {
-    let (_player, mut fumarole) = validate_fumarole_interaction(ctx, fumarole_id)?;
-    
-    // --- SECURITY: Prevent interaction when broth pot is attached ---
-    if fumarole.attached_broth_pot_id.is_some() {
-        return Err("Cannot remove items from fumarole while broth pot is attached. Remove the broth pot first.".to_string());
-    }
-    
-    inventory_management::handle_quick_move_from_container(ctx, &mut fumarole, source_slot_index)?;
-    ctx.db.fumarole().id().update(fumarole.clone());
-    Ok(())
-}
-
-/// --- Split Stack Into Fumarole ---
-#[spacetimedb::reducer]
-pub fn split_stack_into_fumarole(
-    ctx: &ReducerContext,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-    target_fumarole_id: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut fumarole) = validate_fumarole_interaction(ctx, target_fumarole_id)?;
-    
-    if fumarole.attached_broth_pot_id.is_some() {
-        return Err("Cannot add items to fumarole while broth pot is attached.".to_string());
-    }
-    
-    let mut source_item_mut = get_player_item(ctx, source_item_instance_id)?;
-    let new_item_target_location = ItemLocation::Container(ContainerLocationData {
-        container_type: ContainerType::Fumarole,
-        container_id: fumarole.id as u64,
-        slot_index: target_slot_index,
-    });
-    let new_item_instance_id = crate::items::split_stack_helper(ctx, &mut source_item_mut, quantity_to_split, new_item_target_location)?;
-    
-    let mut new_item = ctx.db.inventory_item().instance_id().find(new_item_instance_id)
-        .ok_or_else(|| format!("Failed to find newly split item instance {}", new_item_instance_id))?;
-    let new_item_def = ctx.db.item_definition().id().find(new_item.item_def_id)
-        .ok_or_else(|| format!("Failed to find definition for new item {}", new_item.item_def_id))?;
-
-    crate::inventory_management::merge_or_place_into_container_slot(ctx, &mut fumarole, target_slot_index, &mut new_item, &new_item_def)?;
-    
-    ctx.db.inventory_item().instance_id().update(source_item_mut);
-    ctx.db.fumarole().id().update(fumarole.clone());
-    Ok(())
-}
-
-/// --- Fumarole Internal Item Movement ---
-#[spacetimedb::reducer]
-pub fn move_item_within_fumarole(
-    ctx: &ReducerContext,
-    fumarole_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut fumarole) = validate_fumarole_interaction(ctx, fumarole_id)?;
-    
-    if fumarole.attached_broth_pot_id.is_some() {
-        return Err("Cannot move items in fumarole while broth pot is attached.".to_string());
+impl inventory_management::ContainerHandle for Fumarole {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_fumarole_interaction(ctx, container_id)
     }
-    
-    inventory_management::handle_move_within_container(ctx, &mut fumarole, source_slot_index, target_slot_index)?;
-    ctx.db.fumarole().id().update(fumarole.clone());
-    Ok(())
-}
 
-/// --- Fumarole Internal Stack Splitting ---
-#[spacetimedb::reducer]
-pub fn split_stack_within_fumarole(
-    ctx: &ReducerContext,
-    fumarole_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut fumarole) = validate_fumarole_interaction(ctx, fumarole_id)?;
-    
-    if fumarole.attached_broth_pot_id.is_some() {
-        return Err("Cannot split items in fumarole while broth pot is attached.".to_string());
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.fumarole().id().update(self.clone());
     }
-    
-    inventory_management::handle_split_within_container(ctx, &mut fumarole, source_slot_index, target_slot_index, quantity_to_split)?;
-    ctx.db.fumarole().id().update(fumarole.clone());
-    Ok(())
-}
 
-/// --- Quick Move to Fumarole ---
-#[spacetimedb::reducer]
-pub fn quick_move_to_fumarole(
-    ctx: &ReducerContext,
-    fumarole_id: u32,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_player, mut fumarole) = validate_fumarole_interaction(ctx, fumarole_id)?;
-    
-    if fumarole.attached_broth_pot_id.is_some() {
-        return Err("Cannot add items to fumarole while broth pot is attached.".to_string());
-    }
-    
-    inventory_management::handle_quick_move_to_container(ctx, &mut fumarole, item_instance_id)?;
-    ctx.db.fumarole().id().update(fumarole.clone());
-    Ok(())
-}
-
-/// --- Move From Fumarole to Player ---
-#[spacetimedb::reducer]
-pub fn move_item_from_fumarole_to_player_slot(
-    ctx: &ReducerContext,
-    fumarole_id: u32,
-    source_slot_index: u8,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let (_player, mut fumarole) = validate_fumarole_interaction(ctx, fumarole_id)?;
-    
-    if fumarole.attached_broth_pot_id.is_some() {
-        return Err("Cannot remove items from fumarole while broth pot is attached.".to_string());
+    /// --- SECURITY: All slots are locked while a broth pot is attached ---
+    fn check_operation(&self, _ctx: &ReducerContext, op: &inventory_management::ContainerOp) -> Result<(), String> {
+        use inventory_management::ContainerOp;
+        if self.attached_broth_pot_id.is_none() {
+            return Ok(());
+        }
+        let action = match op {
+            ContainerOp::MoveTo { .. } | ContainerOp::SplitInto { .. } | ContainerOp::QuickMoveTo { .. } | ContainerOp::DepositMany => "add items to",
+            ContainerOp::MoveFrom { .. } | ContainerOp::SplitFrom { .. } | ContainerOp::QuickMoveFrom { .. } | ContainerOp::TakeAll => "remove items from",
+            ContainerOp::Drop { .. } | ContainerOp::SplitAndDrop { .. } => "drop items from",
+            ContainerOp::MoveWithin { .. } => "move items in",
+            ContainerOp::SplitWithin { .. } => "split items in",
+            ContainerOp::Sort => "sort items in",
+        };
+        Err(format!("Cannot {} fumarole while broth pot is attached. Remove the broth pot first.", action))
     }
-    
-    inventory_management::handle_move_from_container_slot(ctx, &mut fumarole, source_slot_index, target_slot_type, target_slot_index)?;
-    ctx.db.fumarole().id().update(fumarole.clone());
-    Ok(())
 }
 
-/// --- Split From Fumarole to Player ---
-#[spacetimedb::reducer]
-pub fn split_stack_from_fumarole(
-    ctx: &ReducerContext,
-    source_fumarole_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let (_player, mut fumarole) = validate_fumarole_interaction(ctx, source_fumarole_id)?;
-    
-    inventory_management::handle_split_from_container(
-        ctx,
-        &mut fumarole,
-        source_slot_index,
-        quantity_to_split,
-        target_slot_type,
-        target_slot_index
-    )?;
-    
-    ctx.db.fumarole().id().update(fumarole);
-    Ok(())
+crate::container_reducers! {
+    Fumarole;
+    move_to: move_item_to_fumarole(fumarole_id),
+    quick_move_from: quick_move_from_fumarole(fumarole_id),
+    split_into_item_first: split_stack_into_fumarole(target_fumarole_id),
+    move_within: move_item_within_fumarole(fumarole_id),
+    split_within_quantity_first: split_stack_within_fumarole(fumarole_id),
+    quick_move_to: quick_move_to_fumarole(fumarole_id),
+    move_from: move_item_from_fumarole_to_player_slot(fumarole_id),
+    split_from: split_stack_from_fumarole(source_fumarole_id),
+    drop: drop_item_from_fumarole_slot_to_world(fumarole_id),
+    split_and_drop: split_and_drop_item_from_fumarole_slot_to_world(fumarole_id),
 }
 
 /// --- Split and Move From Fumarole ---
@@ -413,15 +286,7 @@ pub fn split_and_move_from_fumarole(
     let item_def = ctx.db.item_definition().id().find(source_item.item_def_id)
         .ok_or_else(|| format!("Definition not found for item ID {}", source_item.item_def_id))?;
     
-    if !item_def.is_stackable {
-        return Err(format!("Item '{}' is not stackable.", item_def.name));
-    }
-    if quantity_to_split == 0 {
-        return Err("Cannot split a quantity of 0.".to_string());
-    }
-    if quantity_to_split >= source_item.quantity {
-        return Err(format!("Cannot split {} items, only {} available.", quantity_to_split, source_item.quantity));
-    }
+    crate::items::validate_split_quantity(&item_def, &source_item, quantity_to_split)?;
 
     // --- 3. Perform Split --- 
     let initial_location_for_new_split_item = 
@@ -457,43 +322,6 @@ pub fn split_and_move_from_fumarole(
     }
 }
 
-/// --- Drop Item from Fumarole Slot to World ---
-#[spacetimedb::reducer]
-pub fn drop_item_from_fumarole_slot_to_world(
-    ctx: &ReducerContext,
-    fumarole_id: u32,
-    slot_index: u8,
-) -> Result<(), String> {
-    let (player, mut fumarole) = validate_fumarole_interaction(ctx, fumarole_id)?;
-    
-    if fumarole.attached_broth_pot_id.is_some() {
-        return Err("Cannot drop items from fumarole while broth pot is attached.".to_string());
-    }
-    
-    crate::inventory_management::handle_drop_from_container_slot(ctx, &mut fumarole, slot_index, &player)?;
-    ctx.db.fumarole().id().update(fumarole);
-    Ok(())
-}
-
-/// --- Split and Drop Item from Fumarole Slot to World ---
-#[spacetimedb::reducer]
-pub fn split_and_drop_item_from_fumarole_slot_to_world(
-    ctx: &ReducerContext,
-    fumarole_id: u32,
-    slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (player, mut fumarole) = validate_fumarole_interaction(ctx, fumarole_id)?;
-    
-    if fumarole.attached_broth_pot_id.is_some() {
-        return Err("Cannot drop items from fumarole while broth pot is attached.".to_string());
-    }
-    
-    crate::inventory_management::handle_split_and_drop_from_container_slot(ctx, &mut fumarole, slot_index, quantity_to_split, &player)?;
-    ctx.db.fumarole().id().update(fumarole);
-    Ok(())
-}
-
 /******************************************************************************
  *                       REDUCERS (Fumarole-Specific Logic)                  *
  ******************************************************************************/
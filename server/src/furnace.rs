@@ -4,7 +4,8 @@
  * Handles interactions like adding/removing fuel, lighting/extinguishing,    *
  * fuel consumption checks, and managing items within the furnace's fuel      *
  * slots. Uses generic handlers from inventory_management.rs where applicable.*
- * Furnaces smelt Metal Ore into Metal Fragments using Wood as fuel.         *
+ * Furnaces smelt Metal Ore into Metal Fragments. Wood, Charcoal and Crude   *
+ * Fuel burn at different rates and smelt speeds (see FURNACE_FUEL_TIERS).   *
  *                                                                            *
  ******************************************************************************/
 
@@ -26,8 +27,8 @@ use crate::items::{
     InventoryItem, ItemDefinition, ItemCategory,
     calculate_merge_result, split_stack_helper, add_item_to_player_inventory
 };
-use crate::inventory_management::{self, ItemContainer, ContainerItemClearer, merge_or_place_into_container_slot};
-use crate::player_inventory::{move_item_to_inventory, move_item_to_hotbar, find_first_empty_player_slot, get_player_item};
+use crate::inventory_management::{self, ItemContainer, ContainerItemClearer};
+use crate::player_inventory::{move_item_to_inventory, move_item_to_hotbar, find_first_empty_player_slot};
 use crate::environment::calculate_chunk_index;
 use crate::dropped_item::create_dropped_item_entity;
 
@@ -79,7 +80,41 @@ pub const NUM_FUEL_SLOTS: usize = 5;
 pub const NUM_LARGE_FURNACE_SLOTS: usize = 18;
 const FUEL_CHECK_INTERVAL_SECS: u64 = 1;
 pub const FURNACE_PROCESS_INTERVAL_SECS: u64 = 1;
-const CHARCOAL_PRODUCTION_CHANCE: u8 = 75; // 75% chance
+
+// --- Fuel Tiers ---
+// Each furnace fuel burns for its own duration per unit and runs the fire at its own
+// temperature (smelt speed). Only raw wood leaves charcoal behind as a byproduct, so
+// players choose between cheap wood that pays back in charcoal and hotter, longer
+// burning charcoal or crude fuel that smelts faster.
+pub(crate) struct FurnaceFuelTier {
+    pub item_name: &'static str,
+    pub burn_secs_per_unit: f32,
+    pub smelt_speed_multiplier: f32,
+    pub charcoal_chance: u8, // % chance to leave 1 Charcoal per unit burned
+}
+
+pub(crate) const FURNACE_FUEL_TIERS: &[FurnaceFuelTier] = &[
+    FurnaceFuelTier { item_name: "Wood", burn_secs_per_unit: 5.0, smelt_speed_multiplier: 1.0, charcoal_chance: 75 },
+    FurnaceFuelTier { item_name: "Charcoal", burn_secs_per_unit: 8.0, smelt_speed_multiplier: 1.5, charcoal_chance: 0 },
+    FurnaceFuelTier { item_name: "Crude Fuel", burn_secs_per_unit: 25.0, smelt_speed_multiplier: 2.0, charcoal_chance: 0 },
+];
+
+pub(crate) fn get_furnace_fuel_tier(item_name: &str) -> Option<&'static FurnaceFuelTier> {
+    FURNACE_FUEL_TIERS.iter().find(|tier| tier.item_name == item_name)
+}
+
+/// Fuel tier for an item definition id (None if the item isn't furnace fuel)
+fn get_furnace_fuel_tier_for_def(ctx: &ReducerContext, def_id: u64) -> Option<&'static FurnaceFuelTier> {
+    ctx.db.item_definition().id().find(def_id).and_then(|def| get_furnace_fuel_tier(&def.name))
+}
+
+/// Smelt speed of the fuel currently burning (1.0 when nothing is loaded)
+fn get_current_fuel_smelt_multiplier(ctx: &ReducerContext, furnace: &Furnace) -> f32 {
+    furnace.current_fuel_def_id
+        .and_then(|def_id| get_furnace_fuel_tier_for_def(ctx, def_id))
+        .map(|tier| tier.smelt_speed_multiplier)
+        .unwrap_or(1.0)
+}
 
 // --- Furnace Types ---
 pub const FURNACE_TYPE_NORMAL: u8 = 0;
@@ -191,209 +226,41 @@ pub struct FurnaceProcessingSchedule {
  *                           REDUCERS (Generic Handlers)                        *
  ******************************************************************************/
 
-/// --- Move Item to Furnace ---
-#[spacetimedb::reducer]
-pub fn move_item_to_furnace(ctx: &ReducerContext, furnace_id: u32, target_slot_index: u8, item_instance_id: u64) -> Result<(), String> {
-    let (_player, mut furnace) = validate_furnace_interaction(ctx, furnace_id)?;
-    inventory_management::handle_move_to_container_slot(ctx, &mut furnace, target_slot_index, item_instance_id)?;
-    ctx.db.furnace().id().update(furnace.clone());
-    schedule_next_furnace_processing(ctx, furnace_id);
-    Ok(())
-}
-
-/// --- Remove Fuel from Furnace ---
-#[spacetimedb::reducer]
-pub fn quick_move_from_furnace(ctx: &ReducerContext, furnace_id: u32, source_slot_index: u8) -> Result<(), String> {
-    let (_player, mut furnace) = validate_furnace_interaction(ctx, furnace_id)?;
-    inventory_management::handle_quick_move_from_container(ctx, &mut furnace, source_slot_index)?;
-    let still_has_fuel = check_if_furnace_has_fuel(ctx, &furnace);
-    if !still_has_fuel && furnace.is_burning {
-        furnace.is_burning = false;
-        furnace.current_fuel_def_id = None;
-        furnace.remaining_fuel_burn_time_secs = None;
-        log::info!("Furnace {} extinguished as last valid fuel was removed.", furnace_id);
+impl inventory_management::ContainerHandle for Furnace {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_furnace_interaction(ctx, container_id)
     }
-    ctx.db.furnace().id().update(furnace.clone());
-    schedule_next_furnace_processing(ctx, furnace_id);
-    Ok(())
-}
-
-/// --- Split Stack Into Furnace ---
-#[spacetimedb::reducer]
-pub fn split_stack_into_furnace(
-    ctx: &ReducerContext,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-    target_furnace_id: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut furnace) = validate_furnace_interaction(ctx, target_furnace_id)?;
-    let mut source_item = get_player_item(ctx, source_item_instance_id)?;
-    let new_item_target_location = ItemLocation::Container(crate::models::ContainerLocationData {
-        container_type: ContainerType::Furnace,
-        container_id: furnace.id as u64,
-        slot_index: target_slot_index,
-    });
-    let new_item_instance_id = split_stack_helper(ctx, &mut source_item, quantity_to_split, new_item_target_location)?;
-    
-    let mut new_item = ctx.db.inventory_item().instance_id().find(new_item_instance_id)
-        .ok_or_else(|| format!("Failed to find newly split item instance {}", new_item_instance_id))?;
-    let new_item_def = ctx.db.item_definition().id().find(new_item.item_def_id)
-        .ok_or_else(|| format!("Failed to find definition for new item {}", new_item.item_def_id))?;
-
-    merge_or_place_into_container_slot(ctx, &mut furnace, target_slot_index, &mut new_item, &new_item_def)?;
-    
-    ctx.db.inventory_item().instance_id().update(source_item);
-    ctx.db.furnace().id().update(furnace.clone());
-    schedule_next_furnace_processing(ctx, target_furnace_id);
-    Ok(())
-}
 
-/// --- Furnace Internal Item Movement ---
-#[spacetimedb::reducer]
-pub fn move_item_within_furnace(
-    ctx: &ReducerContext,
-    furnace_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut furnace) = validate_furnace_interaction(ctx, furnace_id)?;
-    
-    // Save cooking progress before move (since set_slot clears it)
-    use crate::cooking::CookableAppliance;
-    let source_progress = furnace.get_slot_cooking_progress(source_slot_index);
-    let target_progress = furnace.get_slot_cooking_progress(target_slot_index);
-    let source_had_item = furnace.get_slot_instance_id(source_slot_index).is_some();
-    let target_had_item = furnace.get_slot_instance_id(target_slot_index).is_some();
-    
-    inventory_management::handle_move_within_container(ctx, &mut furnace, source_slot_index, target_slot_index)?;
-    
-    // Transfer cooking progress based on what happened:
-    // - Move to empty slot: source progress -> target
-    // - Swap: exchange progress
-    // - Merge: target keeps its progress (items combined there)
-    if source_had_item && !target_had_item {
-        // Move to empty slot: transfer source progress to target
-        furnace.set_slot_cooking_progress(target_slot_index, source_progress);
-    } else if source_had_item && target_had_item {
-        // Check if it was a swap (source slot now has an item) or merge (source slot empty)
-        if furnace.get_slot_instance_id(source_slot_index).is_some() {
-            // Swap: exchange cooking progress
-            furnace.set_slot_cooking_progress(target_slot_index, source_progress);
-            furnace.set_slot_cooking_progress(source_slot_index, target_progress);
-        }
-        // If merge: target keeps its progress (already in place), source was cleared
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.furnace().id().update(self.clone());
+        let _ = schedule_next_furnace_processing(ctx, self.id);
     }
-    
-    ctx.db.furnace().id().update(furnace.clone());
-    schedule_next_furnace_processing(ctx, furnace_id);
-    Ok(())
-}
-
-/// --- Furnace Internal Stack Splitting ---
-#[spacetimedb::reducer]
-pub fn split_stack_within_furnace(
-    ctx: &ReducerContext,
-    furnace_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut furnace) = validate_furnace_interaction(ctx, furnace_id)?;
-    inventory_management::handle_split_within_container(ctx, &mut furnace, source_slot_index, target_slot_index, quantity_to_split)?;
-    
-    // IMPORTANT: Keep cooking progress on source slot when splitting (same as compost keeps timestamp)
-    // The remaining stack continues from where it was - only the new split item starts fresh
-    // Note: Progress is per-slot, so the remaining stack will continue cooking with existing progress
-    // The new split item in target slot will start cooking fresh when placed (no progress on new slot)
-    
-    ctx.db.furnace().id().update(furnace.clone());
-    schedule_next_furnace_processing(ctx, furnace_id);
-    Ok(())
-}
-
-/// --- Quick Move to Furnace ---
-#[spacetimedb::reducer]
-pub fn quick_move_to_furnace(
-    ctx: &ReducerContext,
-    furnace_id: u32,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_player, mut furnace) = validate_furnace_interaction(ctx, furnace_id)?;
-    inventory_management::handle_quick_move_to_container(ctx, &mut furnace, item_instance_id)?;
-    ctx.db.furnace().id().update(furnace.clone());
-    schedule_next_furnace_processing(ctx, furnace_id);
-    Ok(())
-}
 
-/// --- Move From Furnace to Player ---
-#[spacetimedb::reducer]
-pub fn move_item_from_furnace_to_player_slot(
-    ctx: &ReducerContext,
-    furnace_id: u32,
-    source_slot_index: u8,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let (_player, mut furnace) = validate_furnace_interaction(ctx, furnace_id)?;
-    inventory_management::handle_move_from_container_slot(ctx, &mut furnace, source_slot_index, target_slot_type, target_slot_index)?;
-    let still_has_fuel = check_if_furnace_has_fuel(ctx, &furnace);
-    if !still_has_fuel && furnace.is_burning {
-        furnace.is_burning = false;
-        furnace.current_fuel_def_id = None;
-        furnace.remaining_fuel_burn_time_secs = None;
+    fn after_operation(&mut self, ctx: &ReducerContext, op: &inventory_management::ContainerOp, before: &Self) {
+        if let inventory_management::ContainerOp::MoveWithin { source_slot, target_slot } = *op {
+            crate::cooking::carry_cooking_progress_after_move(self, before, source_slot, target_slot);
+        }
+        if op.removes_items() && self.is_burning && !check_if_furnace_has_fuel(ctx, self) {
+            self.is_burning = false;
+            self.current_fuel_def_id = None;
+            self.remaining_fuel_burn_time_secs = None;
+            log::info!("Furnace {} extinguished as last valid fuel was removed.", self.id);
+        }
     }
-    ctx.db.furnace().id().update(furnace.clone());
-    schedule_next_furnace_processing(ctx, furnace_id);
-    Ok(())
 }
 
-/// --- Split From Furnace to Player ---
-#[spacetimedb::reducer]
-pub fn split_stack_from_furnace(
-    ctx: &ReducerContext,
-    source_furnace_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_type: String,    // "inventory" or "hotbar"
-    target_slot_index: u32,     // Numeric index for inventory/hotbar
-) -> Result<(), String> {
-    // Get mutable furnace table handle
-    let mut furnaces = ctx.db.furnace();
-
-    // --- Basic Validations --- 
-    let (_player, mut furnace) = validate_furnace_interaction(ctx, source_furnace_id)?;
-    // Note: Further validations (item existence, stackability, quantity) are handled 
-    //       within the generic handle_split_from_container function.
-
-    log::info!(
-        "[SplitFromFurnace] Player {:?} delegating split {} from furnace {} slot {} to {} slot {}",
-        ctx.sender(), quantity_to_split, source_furnace_id, source_slot_index, target_slot_type, target_slot_index
-    );
-
-    // --- Call GENERIC Handler --- 
-    inventory_management::handle_split_from_container(
-        ctx, 
-        &mut furnace, 
-        source_slot_index, 
-        quantity_to_split,
-        target_slot_type, 
-        target_slot_index
-    )?;
-
-    // IMPORTANT: Keep cooking progress on source slot when splitting (same as compost keeps timestamp)
-    // The remaining stack continues from where it was - only the new split item starts fresh
-    // Note: Progress is per-slot, so the remaining stack will continue cooking with existing progress
-
-    // --- Commit Furnace Update --- 
-    // The handler might have modified the source item quantity via split_stack_helper,
-    // but the furnace state itself (slots) isn't directly changed by this handler.
-    // However, to be safe and consistent with other reducers that fetch a mutable container,
-    // we update it here. In the future, if the handler needed to modify the container state
-    // (e.g., if the split failed and we needed to revert something), this update is necessary.
-    furnaces.id().update(furnace);
-
-    Ok(())
+crate::container_reducers! {
+    Furnace;
+    move_to: move_item_to_furnace(furnace_id),
+    quick_move_from: quick_move_from_furnace(furnace_id),
+    split_into_item_first: split_stack_into_furnace(target_furnace_id),
+    move_within: move_item_within_furnace(furnace_id),
+    split_within_quantity_first: split_stack_within_furnace(furnace_id),
+    quick_move_to: quick_move_to_furnace(furnace_id),
+    move_from: move_item_from_furnace_to_player_slot(furnace_id),
+    split_from: split_stack_from_furnace(source_furnace_id),
+    drop: drop_item_from_furnace_slot_to_world(furnace_id),
+    split_and_drop: split_and_drop_item_from_furnace_slot_to_world(furnace_id),
 }
 
 /// --- Split and Move From Furnace ---
@@ -435,15 +302,7 @@ pub fn split_and_move_from_furnace(
     let item_def = ctx.db.item_definition().id().find(source_item.item_def_id)
         .ok_or_else(|| format!("Definition not found for item ID {}", source_item.item_def_id))?;
     
-    if !item_def.is_stackable {
-        return Err(format!("Item '{}' is not stackable.", item_def.name));
-    }
-    if quantity_to_split == 0 {
-        return Err("Cannot split a quantity of 0.".to_string());
-    }
-    if quantity_to_split >= source_item.quantity {
-        return Err(format!("Cannot split {} items, only {} available.", quantity_to_split, source_item.quantity));
-    }
+    crate::items::validate_split_quantity(&item_def, &source_item, quantity_to_split)?;
 
     // --- 3. Perform Split --- 
     // Determine the initial location for the NEWLY SPLIT item.
@@ -487,70 +346,6 @@ pub fn split_and_move_from_furnace(
     }
 }
 
-/// --- Drop Item from Furnace Fuel Slot to World ---
-#[spacetimedb::reducer]
-pub fn drop_item_from_furnace_slot_to_world(
-    ctx: &ReducerContext,
-    furnace_id: u32,
-    slot_index: u8, // This will be 0-4 for fuel slots
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player();
-    let mut furnace_table = ctx.db.furnace();
-
-    log::info!("[DropFromFurnaceToWorld] Player {} attempting to drop fuel from furnace ID {}, slot index {}.", 
-             sender_id, furnace_id, slot_index);
-
-    // 1. Validate interaction and get furnace
-    let (_player_for_validation, mut furnace) = validate_furnace_interaction(ctx, furnace_id)?;
-
-    // 2. Get Player for drop location
-    let player_for_drop_location = player_table.identity().find(sender_id)
-        .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
-
-    // 3. Call the generic handler from inventory_management
-    // The ItemContainer trait for Furnace handles the slot_index for fuel slots
-    crate::inventory_management::handle_drop_from_container_slot(ctx, &mut furnace, slot_index, &player_for_drop_location)?;
-
-    // 4. Persist changes to the Furnace
-    furnace_table.id().update(furnace);
-    log::info!("[DropFromFurnaceToWorld] Successfully dropped fuel from furnace {}, slot {}. Furnace updated.", furnace_id, slot_index);
-
-    Ok(())
-}
-
-/// --- Split and Drop Item from Furnace Fuel Slot to World ---
-#[spacetimedb::reducer]
-pub fn split_and_drop_item_from_furnace_slot_to_world(
-    ctx: &ReducerContext,
-    furnace_id: u32,
-    slot_index: u8, // This will be 0-4 for fuel slots
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player();
-    let mut furnace_table = ctx.db.furnace();
-
-    log::info!("[SplitDropFromFurnaceToWorld] Player {} attempting to split {} fuel from furnace ID {}, slot {}.", 
-             sender_id, quantity_to_split, furnace_id, slot_index);
-
-    // 1. Validate interaction and get furnace
-    let (_player_for_validation, mut furnace) = validate_furnace_interaction(ctx, furnace_id)?;
-
-    // 2. Get Player for drop location
-    let player_for_drop_location = player_table.identity().find(sender_id)
-        .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
-
-    // 3. Call the generic handler from inventory_management
-    crate::inventory_management::handle_split_and_drop_from_container_slot(ctx, &mut furnace, slot_index, quantity_to_split, &player_for_drop_location)?;
-
-    // 4. Persist changes to the Furnace
-    furnace_table.id().update(furnace);
-    log::info!("[SplitDropFromFurnaceToWorld] Successfully split and dropped fuel from furnace {}, slot {}. Furnace updated.", furnace_id, slot_index);
-    
-    Ok(())
-}
-
 /// --- Split Furnace Ore Evenly ---
 /// Distributes the chosen ore type evenly across all slots that have that ore + empty slots.
 /// Picks the ore type with the largest total quantity (or first by slot order if tie).
@@ -726,6 +521,9 @@ pub fn place_furnace(ctx: &ReducerContext, item_instance_id: u64, world_x: f32,
     // Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
     crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     // Find the player who wants to place the furnace
     let player = players.identity().find(sender_id)
         .ok_or_else(|| "Player not found.".to_string())?;
@@ -1010,13 +808,15 @@ pub fn process_furnace_logic_scheduled(ctx: &ReducerContext, schedule_args: Furn
                         if let Some(instance_id) = furnace.get_slot_instance_id(i) {
                             if let Some(mut fuel_item) = ctx.db.inventory_item().instance_id().find(instance_id) {
                                 let consumed_item_def_id = fuel_item.item_def_id;
+                                let consumed_fuel_tier = get_furnace_fuel_tier_for_def(ctx, consumed_item_def_id);
                                 fuel_item.quantity -= 1;
 
                                 if fuel_item.quantity > 0 {
-                                    // Still has fuel, update quantity and reload burn time
+                                    // Still has fuel, update quantity and reload burn time for this fuel tier
                                     ctx.db.inventory_item().instance_id().update(fuel_item.clone());
-                                    // Set burn time for Wood (5.0 seconds)
-                                    furnace.remaining_fuel_burn_time_secs = Some(5.0);
+                                    furnace.remaining_fuel_burn_time_secs = Some(
+                                        consumed_fuel_tier.map(|tier| tier.burn_secs_per_unit).unwrap_or(FURNACE_FUEL_TIERS[0].burn_secs_per_unit)
+                                    );
                                     consumed_and_reloaded_from_stack = true;
                                 } else {
                                     // No more fuel in this stack, remove item and clear slot
@@ -1027,13 +827,12 @@ pub fn process_furnace_logic_scheduled(ctx: &ReducerContext, schedule_args: Furn
                                 }
                                 needs_update = true;
 
-                                // Produce charcoal from consumed Wood
-                                if let Some(consumed_def) = ctx.db.item_definition().id().find(consumed_item_def_id) {
-                                    if consumed_def.name == "Wood" && ctx.rng().gen_range(0..100) < CHARCOAL_PRODUCTION_CHANCE {
-                                        if let Some(charcoal_def) = get_item_def_by_name(ctx, "Charcoal") {
-                                            let _ = try_add_charcoal_to_furnace_or_drop(ctx, &mut furnace, &charcoal_def, 1);
-                                            needs_update = true; // Charcoal might have been added to slots
-                                        }
+                                // Produce charcoal byproduct from fuels that leave it (raw wood)
+                                let charcoal_chance = consumed_fuel_tier.map(|tier| tier.charcoal_chance).unwrap_or(0);
+                                if charcoal_chance > 0 && ctx.rng().gen_range(0..100) < charcoal_chance {
+                                    if let Some(charcoal_def) = get_item_def_by_name(ctx, "Charcoal") {
+                                        let _ = try_add_charcoal_to_furnace_or_drop(ctx, &mut furnace, &charcoal_def, 1);
+                                        needs_update = true; // Charcoal might have been added to slots
                                     }
                                 }
                                 break; 
@@ -1093,6 +892,7 @@ pub fn process_furnace_logic_scheduled(ctx: &ReducerContext, schedule_args: Furn
                 _ => 1.0,
             };
             if cached_has_bellows { smelting_speed_multiplier *= 1.2; }
+            smelting_speed_multiplier *= get_current_fuel_smelt_multiplier(ctx, &furnace);
             if crate::rune_stone::is_position_in_red_rune_zone(ctx, furnace.pos_x, furnace.pos_y) {
                 smelting_speed_multiplier *= 2.0;
             }
@@ -1394,21 +1194,13 @@ pub fn close_furnace_container(ctx: &ReducerContext, furnace_id: u32) -> Result<
 }
 
 pub(crate) fn check_if_furnace_has_fuel(ctx: &ReducerContext, furnace: &Furnace) -> bool {
-    let wood_def_id = match get_item_def_by_name(ctx, "Wood") {
-        Some(def) => def.id,
-        None => {
-            log::error!("Wood item definition not found!");
-            return false;
-        }
-    };
-
     for slot_index in 0..furnace.num_slots() {
         if let (Some(instance_id), Some(def_id)) = (
             furnace.get_slot_instance_id(slot_index as u8),
             furnace.get_slot_def_id(slot_index as u8),
         ) {
-            // Only Wood is valid fuel for furnaces
-            if def_id == wood_def_id {
+            // Any item with a furnace fuel tier is valid fuel
+            if get_furnace_fuel_tier_for_def(ctx, def_id).is_some() {
                 if let Some(item) = ctx.db.inventory_item().instance_id().find(instance_id) {
                     if item.quantity > 0 {
                         return true;
@@ -1421,52 +1213,40 @@ pub(crate) fn check_if_furnace_has_fuel(ctx: &ReducerContext, furnace: &Furnace)
 }
 
 fn find_and_consume_next_fuel(ctx: &ReducerContext, furnace: &mut Furnace) -> bool {
-    let wood_def_id = match get_item_def_by_name(ctx, "Wood") {
-        Some(def) => def.id,
-        None => {
-            log::error!("Wood item definition not found!");
-            return false;
-        }
-    };
-
     for slot_index in 0..furnace.num_slots() {
         if let (Some(instance_id), Some(def_id)) = (
             furnace.get_slot_instance_id(slot_index as u8),
             furnace.get_slot_def_id(slot_index as u8),
         ) {
-            // Only Wood is valid fuel for furnaces
-            if def_id == wood_def_id {
-                if let Some(mut item) = ctx.db.inventory_item().instance_id().find(instance_id) {
-                    if item.quantity > 0 {
-                        // Store current quantity before modification
-                        let original_quantity = item.quantity;
-                        
-                        // Consume one unit of fuel
-                        item.quantity -= 1;
-                        let remaining_quantity = item.quantity; // Store remaining quantity before move
-                        
-                        if item.quantity == 0 {
-                            // Remove item and clear slot
-                            ctx.db.inventory_item().instance_id().delete(instance_id);
-                            furnace.set_slot(slot_index as u8, None, None);
-                        } else {
-                            // Update item quantity
-                            ctx.db.inventory_item().instance_id().update(item);
-                        }
-
-                        // Set burn time for Wood (5.0 seconds)
-                        furnace.current_fuel_def_id = Some(def_id);
-                        furnace.remaining_fuel_burn_time_secs = Some(5.0);
-                        
-                        log::debug!("Furnace {} consumed 1 Wood from slot {}, {} remaining", 
-                                   furnace.id, slot_index, remaining_quantity);
-                        return true;
+            // Any item with a furnace fuel tier is valid fuel
+            let Some(fuel_tier) = get_furnace_fuel_tier_for_def(ctx, def_id) else { continue };
+            if let Some(mut item) = ctx.db.inventory_item().instance_id().find(instance_id) {
+                if item.quantity > 0 {
+                    // Consume one unit of fuel
+                    item.quantity -= 1;
+                    let remaining_quantity = item.quantity; // Store remaining quantity before move
+                    
+                    if item.quantity == 0 {
+                        // Remove item and clear slot
+                        ctx.db.inventory_item().instance_id().delete(instance_id);
+                        furnace.set_slot(slot_index as u8, None, None);
+                    } else {
+                        // Update item quantity
+                        ctx.db.inventory_item().instance_id().update(item);
                     }
-                } else {
-                    // Slot references wood that no longer exists in DB - clear orphaned slot
-                    log::warn!("Furnace {} slot {} references item {} which is not in DB - clearing orphaned slot.", furnace.id, slot_index, instance_id);
-                    furnace.set_slot(slot_index as u8, None, None);
+
+                    // Burn time depends on the fuel tier
+                    furnace.current_fuel_def_id = Some(def_id);
+                    furnace.remaining_fuel_burn_time_secs = Some(fuel_tier.burn_secs_per_unit);
+                    
+                    log::debug!("Furnace {} consumed 1 {} from slot {}, {} remaining", 
+                               furnace.id, fuel_tier.item_name, slot_index, remaining_quantity);
+                    return true;
                 }
+            } else {
+                // Slot references fuel that no longer exists in DB - clear orphaned slot
+                log::warn!("Furnace {} slot {} references item {} which is not in DB - clearing orphaned slot.", furnace.id, slot_index, instance_id);
+                furnace.set_slot(slot_index as u8, None, None);
             }
         }
     }
@@ -1585,6 +1365,7 @@ pub fn get_fuel_burn_rate_multiplier(ctx: &ReducerContext, furnace: &Furnace) ->
 /// Large furnace: 2x faster smelting (base multiplier = 2.0)
 /// Reed Bellows makes smelting 20% faster (multiplier *= 1.2)
 /// Red rune stone zone doubles smelting speed (multiplier *= 2.0)
+/// Burning fuel tier scales smelting speed (see FURNACE_FUEL_TIERS)
 /// Multipliers stack multiplicatively (e.g., large furnace + bellows = 2.0 * 1.2 = 2.4x)
 pub fn get_smelting_speed_multiplier(ctx: &ReducerContext, furnace: &Furnace) -> f32 {
     // Large furnace smelts 2x faster (5s base -> 2.5s effective for Metal Ore)
@@ -1598,6 +1379,9 @@ pub fn get_smelting_speed_multiplier(ctx: &ReducerContext, furnace: &Furnace) ->
         multiplier *= 1.2;
     }
     
+    // Hotter fuels smelt faster (charcoal 1.5x, crude fuel 2x)
+    multiplier *= get_current_fuel_smelt_multiplier(ctx, furnace);
+    
     // Check for red rune stone zone (2x faster smelting)
     if crate::rune_stone::is_position_in_red_rune_zone(ctx, furnace.pos_x, furnace.pos_y) {
         multiplier *= 2.0;
@@ -0,0 +1,87 @@
+/******************************************************************************
+ *                                                                            *
+ * Ghost Mode - Spectating your own corpse between death and respawn          *
+ *                                                                            *
+ * When a player's corpse is created they become a ghost: a camera position   *
+ * in ghost_position, anchored on the corpse, that the client can drift      *
+ * around within GHOST_MAX_DISTANCE_FROM_CORPSE to watch who loots it. The    *
+ * ghost has no body - the player stays dead, so every interaction reducer   *
+ * keeps rejecting them - and the row is removed on respawn.                 *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table, Timestamp};
+use log;
+
+use crate::player as PlayerTableTrait;
+
+/// How far the ghost camera may drift from the corpse
+pub const GHOST_MAX_DISTANCE_FROM_CORPSE: f32 = 400.0;
+
+/// Camera position of a dead player (one row per ghost)
+#[spacetimedb::table(accessor = ghost_position, public)]
+#[derive(Clone, Debug)]
+pub struct GhostPosition {
+    #[primary_key]
+    pub player_id: Identity,
+    pub corpse_id: u32,
+    pub anchor_x: f32,
+    pub anchor_y: f32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub updated_at: Timestamp,
+}
+
+// Only the ghost's own player needs its camera position
+#[client_visibility_filter]
+const ONLY_OWN_GHOST: Filter = Filter::Sql("SELECT * FROM ghost_position WHERE player_id = :sender");
+
+/// Turns a dead player into a ghost anchored on their new corpse
+pub fn enter_ghost_mode(ctx: &ReducerContext, player_id: Identity, corpse_id: u32, corpse_x: f32, corpse_y: f32) {
+    let ghost = GhostPosition {
+        player_id,
+        corpse_id,
+        anchor_x: corpse_x,
+        anchor_y: corpse_y,
+        pos_x: corpse_x,
+        pos_y: corpse_y,
+        updated_at: ctx.timestamp,
+    };
+    let ghosts = ctx.db.ghost_position();
+    if ghosts.player_id().find(player_id).is_some() {
+        ghosts.player_id().update(ghost);
+    } else {
+        ghosts.insert(ghost);
+    }
+}
+
+/// Ends ghost mode (on respawn)
+pub fn clear_ghost(ctx: &ReducerContext, player_id: Identity) {
+    ctx.db.ghost_position().player_id().delete(player_id);
+}
+
+/// --- Update Ghost Position ---
+/// Moves the ghost camera; positions beyond the allowed radius are pulled back onto its edge.
+#[spacetimedb::reducer]
+pub fn update_ghost_position(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    if !pos_x.is_finite() || !pos_y.is_finite() {
+        return Err("Invalid ghost position.".to_string());
+    }
+    if !ctx.db.player().identity().find(sender_id).is_some_and(|p| p.is_dead) {
+        return Err("Only dead players can move a ghost.".to_string());
+    }
+    let mut ghost = ctx.db.ghost_position().player_id().find(sender_id)
+        .ok_or_else(|| "You are not a ghost.".to_string())?;
+
+    let dx = pos_x - ghost.anchor_x;
+    let dy = pos_y - ghost.anchor_y;
+    let distance = (dx * dx + dy * dy).sqrt();
+    let scale = if distance > GHOST_MAX_DISTANCE_FROM_CORPSE { GHOST_MAX_DISTANCE_FROM_CORPSE / distance } else { 1.0 };
+    ghost.pos_x = ghost.anchor_x + dx * scale;
+    ghost.pos_y = ghost.anchor_y + dy * scale;
+    ghost.updated_at = ctx.timestamp;
+    log::trace!("[Ghost] Player {:?} ghost moved to ({:.1}, {:.1})", sender_id, ghost.pos_x, ghost.pos_y);
+    ctx.db.ghost_position().player_id().update(ghost);
+    Ok(())
+}
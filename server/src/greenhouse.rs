@@ -0,0 +1,87 @@
+/******************************************************************************
+ *                                                                            *
+ * Greenhouse - Sheltered farming structure                                   *
+ *                                                                            *
+ * Greenhouses are WoodenStorageBox entities (BOX_TYPE_GREENHOUSE) with no    *
+ * inventory and no player collision. They cover a square footprint of       *
+ * GREENHOUSE_FOOTPRINT_TILES x GREENHOUSE_FOOTPRINT_TILES foundation cells   *
+ * centred on the placement point. Crops planted inside the footprint:        *
+ *   - keep growing out of season (no winter dormancy)                        *
+ *   - ignore storm growth penalties and can't be killed by storms            *
+ *   - can't be trampled or eaten by wildlife                                 *
+ *                                                                            *
+ * Greenhouses must be built on open soil - no foundation may sit under any   *
+ * part of the footprint, and footprints can't overlap.                       *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table};
+
+use crate::wooden_storage_box::{WoodenStorageBox, BOX_TYPE_GREENHOUSE, BOX_COLLISION_Y_OFFSET, wooden_storage_box as WoodenStorageBoxTableTrait};
+use crate::building::{foundation_cell as FoundationCellTableTrait, FOUNDATION_TILE_SIZE_PX};
+
+// --- Greenhouse Constants ---
+pub const GREENHOUSE_FOOTPRINT_TILES: i32 = 3; // 3x3 foundation cells (288x288px)
+pub const GREENHOUSE_FOOTPRINT_HALF_SIZE: f32 = (GREENHOUSE_FOOTPRINT_TILES as f32 * FOUNDATION_TILE_SIZE_PX as f32) / 2.0;
+
+/// Footprint centre of a greenhouse (pos_y carries the standard box placement offset)
+pub fn get_greenhouse_footprint_center(greenhouse: &WoodenStorageBox) -> (f32, f32) {
+    (greenhouse.pos_x, greenhouse.pos_y - BOX_COLLISION_Y_OFFSET)
+}
+
+fn is_inside_footprint(center_x: f32, center_y: f32, x: f32, y: f32) -> bool {
+    (x - center_x).abs() <= GREENHOUSE_FOOTPRINT_HALF_SIZE && (y - center_y).abs() <= GREENHOUSE_FOOTPRINT_HALF_SIZE
+}
+
+/// Footprint centres of every intact greenhouse. Batch callers (the growth tick)
+/// collect these once and test positions with `is_position_in_any_greenhouse`.
+pub fn get_greenhouse_footprints(ctx: &ReducerContext) -> Vec<(f32, f32)> {
+    ctx.db.wooden_storage_box().iter()
+        .filter(|b| b.box_type == BOX_TYPE_GREENHOUSE && !b.is_destroyed)
+        .map(|b| get_greenhouse_footprint_center(&b))
+        .collect()
+}
+
+/// Checks a position against pre-collected greenhouse footprints
+pub fn is_position_in_any_greenhouse(footprints: &[(f32, f32)], x: f32, y: f32) -> bool {
+    footprints.iter().any(|&(center_x, center_y)| is_inside_footprint(center_x, center_y, x, y))
+}
+
+/// Checks whether a position lies inside any intact greenhouse
+pub fn is_position_in_greenhouse(ctx: &ReducerContext, x: f32, y: f32) -> bool {
+    ctx.db.wooden_storage_box().iter()
+        .filter(|b| b.box_type == BOX_TYPE_GREENHOUSE && !b.is_destroyed)
+        .any(|b| {
+            let (center_x, center_y) = get_greenhouse_footprint_center(&b);
+            is_inside_footprint(center_x, center_y, x, y)
+        })
+}
+
+/// Validates a greenhouse placement centred on (world_x, world_y):
+/// no foundation under the footprint and no overlap with another greenhouse.
+pub fn validate_greenhouse_placement(ctx: &ReducerContext, world_x: f32, world_y: f32) -> Result<(), String> {
+    let tile_size = FOUNDATION_TILE_SIZE_PX as f32;
+    let min_cell_x = ((world_x - GREENHOUSE_FOOTPRINT_HALF_SIZE) / tile_size).floor() as i32;
+    let max_cell_x = ((world_x + GREENHOUSE_FOOTPRINT_HALF_SIZE) / tile_size).floor() as i32;
+    let min_cell_y = ((world_y - GREENHOUSE_FOOTPRINT_HALF_SIZE) / tile_size).floor() as i32;
+    let max_cell_y = ((world_y + GREENHOUSE_FOOTPRINT_HALF_SIZE) / tile_size).floor() as i32;
+
+    let foundations = ctx.db.foundation_cell();
+    for cell_x in min_cell_x..=max_cell_x {
+        for cell_y in min_cell_y..=max_cell_y {
+            if foundations.idx_cell_coords().filter((cell_x, cell_y)).any(|f| !f.is_destroyed) {
+                return Err("Greenhouses must be built on open soil - a foundation is in the way.".to_string());
+            }
+        }
+    }
+
+    let overlaps_greenhouse = get_greenhouse_footprints(ctx).iter().any(|&(center_x, center_y)| {
+        (world_x - center_x).abs() < GREENHOUSE_FOOTPRINT_HALF_SIZE * 2.0
+            && (world_y - center_y).abs() < GREENHOUSE_FOOTPRINT_HALF_SIZE * 2.0
+    });
+    if overlaps_greenhouse {
+        return Err("Too close to another greenhouse.".to_string());
+    }
+
+    Ok(())
+}
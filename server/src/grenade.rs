@@ -114,6 +114,7 @@ pub fn check_grenade_fuses(ctx: &ReducerContext, _schedule: GrenadeFuseSchedule)
             GRENADE_PLAYER_DAMAGE,
         );
         ctx.db.dropped_item().id().delete(&dropped_id);
+        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(dropped_id));
         log::info!("[GrenadeFuse] Detonated dropped grenade {} at ({:.1}, {:.1})", dropped_id, pos_x, pos_y);
     }
 
@@ -0,0 +1,321 @@
+/******************************************************************************
+ *                                                                            *
+ * Hallucinations - What a fraying mind sees past the 50% insanity mark      *
+ *                                                                            *
+ * Driven from the insanity block of the player stat tick:                   *
+ *   - 50%+: phantom hostiles appear around the player and fade after a few  *
+ *           seconds. They have no collision, AI or damage - they only exist *
+ *           as rows for the affected player's client to render.             *
+ *   - 75%+: the minimap distorts (intensity scales up to 100%).             *
+ *   - 90%+: SOVA occasionally manifests nearby as a glitched apparition.    *
+ *                                                                            *
+ * Every row is scoped to one player via `player_id`; clients only render    *
+ * events whose player_id matches their own identity.                        *
+ *                                                                            *
+ * Rune stones offer a way back: a ritual that offers Memory Shards to the   *
+ * stone and pulls the player's insanity down (on a cooldown).               *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp, TimeDuration};
+use rand::Rng;
+
+use crate::Player;
+use crate::player as PlayerTableTrait;
+use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::models::ItemLocation;
+use crate::rune_stone::{rune_stone as RuneStoneTableTrait, RuneStoneType};
+use crate::wild_animal_npc::AnimalSpecies;
+use crate::player_stats::{INSANITY_THRESHOLD_50, INSANITY_THRESHOLD_75, INSANITY_THRESHOLD_90};
+
+// --- Phantom Hostile Constants ---
+const PHANTOM_SPAWN_CHANCE_AT_50: f32 = 0.10; // Per stat tick (2s)
+const PHANTOM_SPAWN_CHANCE_AT_75: f32 = 0.20;
+const PHANTOM_SPAWN_CHANCE_AT_90: f32 = 0.35;
+const MAX_PHANTOMS_AT_50: usize = 1;
+const MAX_PHANTOMS_AT_75: usize = 3;
+const MAX_PHANTOMS_AT_90: usize = 5;
+const PHANTOM_MIN_DISTANCE: f32 = 250.0; // Just at the edge of vision
+const PHANTOM_MAX_DISTANCE: f32 = 500.0;
+const PHANTOM_MIN_LIFETIME_SECS: u64 = 12;
+const PHANTOM_MAX_LIFETIME_SECS: u64 = 20;
+const PHANTOM_SPECIES: [AnimalSpecies; 4] = [
+    AnimalSpecies::Shorebound,
+    AnimalSpecies::Shardkin,
+    AnimalSpecies::DrownedWatch,
+    AnimalSpecies::TundraWolf,
+];
+
+// --- Minimap Distortion Constants ---
+const MINIMAP_DISTORTION_MIN_INTENSITY: f32 = 0.3; // Intensity at 75% insanity, ramps to 1.0 at 100%
+const MINIMAP_DISTORTION_LINGER_SECS: u64 = 5; // Fades out shortly after insanity drops below 75%
+
+// --- SOVA Encounter Constants ---
+const SOVA_ENCOUNTER_CHANCE: f32 = 0.05; // Per stat tick (2s) at 90%+
+const SOVA_ENCOUNTER_MIN_DISTANCE: f32 = 150.0;
+const SOVA_ENCOUNTER_MAX_DISTANCE: f32 = 300.0;
+const SOVA_ENCOUNTER_LIFETIME_SECS: u64 = 8;
+
+// --- Rune Stone Ritual Constants ---
+pub(crate) const RUNE_STONE_RITUAL_RANGE: f32 = 200.0;
+pub(crate) const RUNE_STONE_RITUAL_RANGE_SQUARED: f32 = RUNE_STONE_RITUAL_RANGE * RUNE_STONE_RITUAL_RANGE;
+pub(crate) const RUNE_STONE_RITUAL_SHARD_COST: u32 = 10;
+pub(crate) const RUNE_STONE_RITUAL_INSANITY_REDUCTION: f32 = 35.0;
+pub(crate) const BLUE_RUNE_RITUAL_MULTIPLIER: f32 = 1.5; // Memory stones are attuned to shards
+pub(crate) const RUNE_STONE_RITUAL_COOLDOWN_SECS: u64 = 300;
+
+// --- Types ---
+
+#[derive(Clone, Copy, Debug, PartialEq, SpacetimeType)]
+pub enum HallucinationKind {
+    PhantomHostile,     // A hostile that isn't there (species says what to draw)
+    MinimapDistortion,  // Minimap warps and drifts (intensity 0.0-1.0)
+    SovaEncounter,      // SOVA manifests near the player
+}
+
+/// A hallucination seen by exactly one player.
+#[spacetimedb::table(accessor = hallucination_event, public)]
+#[derive(Clone, Debug)]
+pub struct HallucinationEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: Identity,
+    pub kind: HallucinationKind,
+    pub species: Option<AnimalSpecies>, // Only for PhantomHostile
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub intensity: f32,
+    pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+}
+
+/// Last time each player performed a rune stone ritual.
+#[spacetimedb::table(accessor = rune_stone_ritual_cooldown)]
+#[derive(Clone, Debug)]
+pub struct RuneStoneRitualCooldown {
+    #[primary_key]
+    pub player_id: Identity,
+    pub last_ritual_at: Timestamp,
+}
+
+// --- Stat Tick Integration ---
+
+/// Called from the player stat tick with the player's freshly computed insanity.
+/// Expires old hallucinations and rolls for new ones based on the insanity threshold.
+pub fn update_player_hallucinations(ctx: &ReducerContext, player: &Player, insanity: f32) {
+    let player_id = player.identity;
+    let now = ctx.timestamp;
+    let events = ctx.db.hallucination_event();
+
+    let mut active: Vec<HallucinationEvent> = Vec::new();
+    for event in events.player_id().filter(&player_id) {
+        if event.expires_at <= now {
+            events.id().delete(event.id);
+        } else {
+            active.push(event);
+        }
+    }
+
+    if insanity < INSANITY_THRESHOLD_50 {
+        return;
+    }
+
+    let mut rng = ctx.rng();
+
+    // Phantom hostiles
+    let (spawn_chance, max_phantoms) = if insanity >= INSANITY_THRESHOLD_90 {
+        (PHANTOM_SPAWN_CHANCE_AT_90, MAX_PHANTOMS_AT_90)
+    } else if insanity >= INSANITY_THRESHOLD_75 {
+        (PHANTOM_SPAWN_CHANCE_AT_75, MAX_PHANTOMS_AT_75)
+    } else {
+        (PHANTOM_SPAWN_CHANCE_AT_50, MAX_PHANTOMS_AT_50)
+    };
+    let phantom_count = active.iter().filter(|e| e.kind == HallucinationKind::PhantomHostile).count();
+    if phantom_count < max_phantoms && rng.gen::<f32>() < spawn_chance {
+        let (pos_x, pos_y) = random_position_around(&mut rng, player, PHANTOM_MIN_DISTANCE, PHANTOM_MAX_DISTANCE);
+        let species = PHANTOM_SPECIES[rng.gen_range(0..PHANTOM_SPECIES.len())];
+        let lifetime = rng.gen_range(PHANTOM_MIN_LIFETIME_SECS..=PHANTOM_MAX_LIFETIME_SECS);
+        events.insert(HallucinationEvent {
+            id: 0,
+            player_id,
+            kind: HallucinationKind::PhantomHostile,
+            species: Some(species),
+            pos_x,
+            pos_y,
+            intensity: insanity / 100.0,
+            created_at: now,
+            expires_at: now + TimeDuration::from_micros(lifetime as i64 * 1_000_000),
+        });
+        log::debug!("Player {:?} hallucinates a phantom {:?} at ({:.0}, {:.0})", player_id, species, pos_x, pos_y);
+    }
+
+    // Minimap distortion - a single row kept alive while at 75%+
+    if insanity >= INSANITY_THRESHOLD_75 {
+        let ramp = (insanity - INSANITY_THRESHOLD_75) / (100.0 - INSANITY_THRESHOLD_75);
+        let intensity = MINIMAP_DISTORTION_MIN_INTENSITY + (1.0 - MINIMAP_DISTORTION_MIN_INTENSITY) * ramp.clamp(0.0, 1.0);
+        let expires_at = now + TimeDuration::from_micros(MINIMAP_DISTORTION_LINGER_SECS as i64 * 1_000_000);
+        match active.iter().find(|e| e.kind == HallucinationKind::MinimapDistortion) {
+            Some(existing) => {
+                let mut updated = existing.clone();
+                updated.intensity = intensity;
+                updated.expires_at = expires_at;
+                events.id().update(updated);
+            }
+            None => {
+                events.insert(HallucinationEvent {
+                    id: 0,
+                    player_id,
+                    kind: HallucinationKind::MinimapDistortion,
+                    species: None,
+                    pos_x: 0.0,
+                    pos_y: 0.0,
+                    intensity,
+                    created_at: now,
+                    expires_at,
+                });
+            }
+        }
+    }
+
+    // SOVA encounter - rare, one at a time
+    if insanity >= INSANITY_THRESHOLD_90
+        && !active.iter().any(|e| e.kind == HallucinationKind::SovaEncounter)
+        && rng.gen::<f32>() < SOVA_ENCOUNTER_CHANCE
+    {
+        let (pos_x, pos_y) = random_position_around(&mut rng, player, SOVA_ENCOUNTER_MIN_DISTANCE, SOVA_ENCOUNTER_MAX_DISTANCE);
+        events.insert(HallucinationEvent {
+            id: 0,
+            player_id,
+            kind: HallucinationKind::SovaEncounter,
+            species: None,
+            pos_x,
+            pos_y,
+            intensity: insanity / 100.0,
+            created_at: now,
+            expires_at: now + TimeDuration::from_micros(SOVA_ENCOUNTER_LIFETIME_SECS as i64 * 1_000_000),
+        });
+        log::info!("Player {:?} SOVA encounter at ({:.0}, {:.0})", player_id, pos_x, pos_y);
+    }
+}
+
+/// Removes every hallucination for a player (respawn, ritual).
+pub fn clear_player_hallucinations(ctx: &ReducerContext, player_id: Identity) {
+    let events = ctx.db.hallucination_event();
+    let ids: Vec<u64> = events.player_id().filter(&player_id).map(|e| e.id).collect();
+    for id in ids {
+        events.id().delete(id);
+    }
+}
+
+fn random_position_around(rng: &mut impl Rng, player: &Player, min_distance: f32, max_distance: f32) -> (f32, f32) {
+    let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+    let distance = rng.gen_range(min_distance..max_distance);
+    (
+        player.position_x + angle.cos() * distance,
+        player.position_y + angle.sin() * distance,
+    )
+}
+
+// --- Rune Stone Ritual ---
+
+/// Offer Memory Shards to a nearby rune stone to calm the mind.
+/// Reduces insanity (more at blue Memory stones) and dispels active hallucinations.
+#[spacetimedb::reducer]
+pub fn perform_rune_stone_ritual(ctx: &ReducerContext, rune_stone_id: u64) -> Result<(), String> {
+    let player_id = ctx.sender();
+    let mut player = ctx.db.player().identity().find(&player_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead || player.is_knocked_out {
+        return Err("You cannot perform a ritual right now".to_string());
+    }
+
+    let rune_stone = ctx.db.rune_stone().id().find(rune_stone_id)
+        .ok_or_else(|| "Rune stone not found".to_string())?;
+    let dx = player.position_x - rune_stone.pos_x;
+    let dy = player.position_y - rune_stone.pos_y;
+    if dx * dx + dy * dy > RUNE_STONE_RITUAL_RANGE_SQUARED {
+        return Err("Too far from the rune stone".to_string());
+    }
+
+    if player.insanity <= 0.0 {
+        return Err("Your mind is already clear".to_string());
+    }
+
+    let cooldowns = ctx.db.rune_stone_ritual_cooldown();
+    if let Some(cooldown) = cooldowns.player_id().find(&player_id) {
+        let elapsed_secs = ctx.timestamp.duration_since(cooldown.last_ritual_at)
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+        if elapsed_secs < RUNE_STONE_RITUAL_COOLDOWN_SECS {
+            return Err(format!("The rune stone is silent. Try again in {}s", RUNE_STONE_RITUAL_COOLDOWN_SECS - elapsed_secs));
+        }
+    }
+
+    consume_ritual_shards(ctx, player_id)?;
+
+    let reduction = match rune_stone.rune_type {
+        RuneStoneType::Blue => RUNE_STONE_RITUAL_INSANITY_REDUCTION * BLUE_RUNE_RITUAL_MULTIPLIER,
+        RuneStoneType::Green | RuneStoneType::Red => RUNE_STONE_RITUAL_INSANITY_REDUCTION,
+    };
+    let old_insanity = player.insanity;
+    player.insanity = (player.insanity - reduction).max(0.0);
+    ctx.db.player().identity().update(player.clone());
+
+    let cooldown = RuneStoneRitualCooldown { player_id, last_ritual_at: ctx.timestamp };
+    if cooldowns.player_id().find(&player_id).is_some() {
+        cooldowns.player_id().update(cooldown);
+    } else {
+        cooldowns.insert(cooldown);
+    }
+
+    clear_player_hallucinations(ctx, player_id);
+
+    log::info!(
+        "Player {:?} performed a ritual at {:?} rune stone {} - insanity {:.1} -> {:.1}",
+        player_id, rune_stone.rune_type, rune_stone_id, old_insanity, player.insanity
+    );
+    Ok(())
+}
+
+/// Takes the ritual offering from the player's inventory and hotbar, or fails without consuming anything.
+fn consume_ritual_shards(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
+    let inventory = ctx.db.inventory_item();
+    let shard_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == "Memory Shard")
+        .ok_or_else(|| "Memory Shard item definition not found".to_string())?;
+
+    let shards: Vec<_> = inventory.iter()
+        .filter(|item| {
+            let is_owned = match &item.location {
+                ItemLocation::Inventory(data) => data.owner_id == player_id,
+                ItemLocation::Hotbar(data) => data.owner_id == player_id,
+                _ => false,
+            };
+            is_owned && item.item_def_id == shard_def.id && item.quantity > 0
+        })
+        .collect();
+    let available: u32 = shards.iter().map(|item| item.quantity).sum();
+    if available < RUNE_STONE_RITUAL_SHARD_COST {
+        return Err(format!("The ritual requires {} Memory Shards (you have {})", RUNE_STONE_RITUAL_SHARD_COST, available));
+    }
+
+    let mut remaining = RUNE_STONE_RITUAL_SHARD_COST;
+    for item in shards {
+        if remaining == 0 {
+            break;
+        }
+        let take = remaining.min(item.quantity);
+        remaining -= take;
+        if take == item.quantity {
+            inventory.instance_id().delete(item.instance_id);
+        } else {
+            let mut updated = item.clone();
+            updated.quantity -= take;
+            inventory.instance_id().update(updated);
+        }
+    }
+    Ok(())
+}
@@ -16,6 +16,7 @@ use crate::plants_database::{PlantType, PlantConfig, SpawnCondition, PLANT_CONFI
 // Table trait imports for database access
 use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
 use crate::player as PlayerTableTrait;
+use crate::active_equipment::active_equipment as ActiveEquipmentTableTrait;
 use crate::player_progression::player_stats as PlayerStatsTableTrait;
 
 // --- Unified Harvestable Resource Table ---
@@ -149,9 +150,9 @@ pub fn interact_with_harvestable_resource(ctx: &ReducerContext, resource_id: u64
     // Check if underwater harvesting requires snorkeling
     if matches!(config.spawn_condition, SpawnCondition::Underwater) {
         if !player.is_snorkeling {
-            // Emit error sound for trying to harvest seaweed while above water
+            // Emit error sound for trying to harvest an underwater resource while above water
             crate::sound_events::emit_error_seaweed_above_water_sound(ctx, resource.pos_x, resource.pos_y, player_id);
-            return Err("You must be underwater (snorkeling) to harvest seaweed beds.".to_string());
+            return Err(format!("You must be underwater (snorkeling) to harvest the {}.", config.entity_name.to_lowercase()));
         }
     }
 
@@ -190,7 +191,8 @@ pub fn interact_with_harvestable_resource(ctx: &ReducerContext, resource_id: u64
     } else {
         ctx.rng().gen_range(config.primary_yield.1..=config.primary_yield.2) // Random range
     };
-    let primary_yield_amount = ((base_primary_yield as f32) * farming_multiplier).round() as u32;
+    let tool_yield_multiplier = get_pile_tool_yield_multiplier(ctx, player_id, &resource.plant_type);
+    let primary_yield_amount = ((base_primary_yield as f32) * farming_multiplier * tool_yield_multiplier).round() as u32;
 
     // Calculate secondary yield min/max with farming multiplier applied
     let (secondary_min, secondary_max) = if let Some((_, min, max, _)) = &config.secondary_yield {
@@ -201,6 +203,11 @@ pub fn interact_with_harvestable_resource(ctx: &ReducerContext, resource_id: u64
         (0, 0)
     };
 
+    if tool_yield_multiplier > 1.0 {
+        log::debug!("Player {:?} harvesting {:?} with tool bonus {:.2}x, primary yield: {}",
+                    player_id, resource.plant_type, tool_yield_multiplier, primary_yield_amount);
+    }
+
     // Log farming bonus yields
     if resource.is_player_planted {
         log::info!("🌾 FARM HARVEST: Player {:?} harvesting {:?} - multiplier: {:.1}x, primary yield: {} items (base: {}), secondary range: {}-{} (farming bonus!)", 
@@ -248,6 +255,11 @@ pub fn interact_with_harvestable_resource(ctx: &ReducerContext, resource_id: u64
         &mut ctx.rng().clone(),
     )?;
     
+    // Fulgurite and tidal flat finds are one-time spawns - remove them instead of respawning
+    if matches!(resource.plant_type, PlantType::Fulgurite | PlantType::TidePoolCrab | PlantType::TidePoolClams) {
+        ctx.db.harvestable_resource().id().delete(resource.id);
+    }
+    
     // === PLANT DISCOVERY: Track which plants this player has harvested ===
     record_plant_discovery(ctx, player_id, resource.plant_type.clone());
     
@@ -274,7 +286,11 @@ pub fn interact_with_harvestable_resource(ctx: &ReducerContext, resource_id: u64
         PlantType::SulfurPile |
         PlantType::CharcoalPile |
         PlantType::SoggyPlantFiberPile |
-        PlantType::BonePile
+        PlantType::Fulgurite |
+        PlantType::BonePile |
+        PlantType::SunkenCrate |
+        PlantType::TidePoolCrab |
+        PlantType::TidePoolClams
     );
     
     // Track plants harvested for achievements (count harvest ACTIONS, not items received)
@@ -413,4 +429,29 @@ pub fn is_spawn_location_suitable(
     } else {
         false
     }
-}
\ No newline at end of file
+}
+/// Harvest node type whose tools also work resource piles (hatchets on wood piles,
+/// pickaxes on stone/ore piles). Piles without one are always gathered by hand.
+fn get_pile_harvest_target(plant_type: &PlantType) -> Option<crate::models::TargetType> {
+    match plant_type {
+        PlantType::WoodPile | PlantType::BeachWoodPile => Some(crate::models::TargetType::Tree),
+        PlantType::StonePile | PlantType::MetalOrePile | PlantType::SulfurPile | PlantType::BonePile => Some(crate::models::TargetType::Stone),
+        _ => None,
+    }
+}
+
+/// Primary yield multiplier from the player's equipped tool when picking up a resource pile.
+/// The tool must target the pile's node type; its harvest tier decides the bonus.
+fn get_pile_tool_yield_multiplier(ctx: &ReducerContext, player_id: Identity, plant_type: &PlantType) -> f32 {
+    let Some(target_type) = get_pile_harvest_target(plant_type) else { return 1.0 };
+    let equipped_def = ctx.db.active_equipment().player_identity().find(player_id)
+        .and_then(|equipment| equipment.equipped_item_def_id)
+        .and_then(|def_id| ctx.db.item_definition().id().find(def_id));
+    match equipped_def {
+        Some(item_def) if item_def.primary_target_type == Some(target_type) => {
+            let tier = crate::items_database::get_harvest_tool_tier(&item_def.name);
+            crate::items_database::get_harvest_tier_stats(tier).pile_yield_multiplier
+        }
+        _ => 1.0,
+    }
+}
@@ -51,6 +51,19 @@ pub const DEFAULT_UPKEEP_INTERVAL_SECONDS: u64 = 3600; // 1 hour default upkeep
 // PERFORMANCE: Increased from 60s to 300s - upkeep happens every hour anyway
 pub const UPKEEP_PROCESS_INTERVAL_SECONDS: u64 = 300; // Check every 5 minutes for upkeep processing
 
+// --- Upkeep tax brackets (Rust-style) ---
+// Each bracket taxes only the pieces that fall inside it, so small bases stay cheap
+// while sprawling compounds pay progressively more per piece.
+// (upper bound of bracket in building pieces, tax rate applied to pieces in that bracket)
+pub const UPKEEP_TAX_BRACKETS: [(u32, f32); 6] = [
+    (15, 0.10),
+    (50, 0.15),
+    (125, 0.20),
+    (200, 0.25),
+    (325, 0.30),
+    (u32::MAX, 0.35),
+];
+
 // --- Health constants ---
 pub const HEARTH_INITIAL_HEALTH: f32 = 1000.0;
 pub const HEARTH_MAX_HEALTH: f32 = 1000.0;
@@ -71,7 +84,7 @@ use crate::inventory_management::{self, ItemContainer};
 use crate::homestead_hearth::homestead_hearth as HomesteadHearthTableTrait;
 use crate::building::{foundation_cell, wall_cell}; // ADDED: For foundation and wall table access
 use crate::environment::calculate_chunk_index;
-use crate::models::{ContainerType, ItemLocation, ContainerLocationData};
+use crate::models::{ContainerType, ItemLocation};
 use crate::player_inventory::{find_first_empty_player_slot, move_item_to_inventory, move_item_to_hotbar, get_player_item};
 use crate::active_effects::{EffectType, ActiveConsumableEffect, active_consumable_effect as ActiveConsumableEffectTableTrait};
 use crate::utils::get_distance_squared;
@@ -244,6 +257,152 @@ pub fn remove_building_privilege(ctx: &ReducerContext, player_id: Identity) {
     }
 }
 
+// --- Building Privilege Zones ---
+
+/// Public privilege boundary for a hearth so clients can render claimed areas.
+/// One row per standing hearth; kept in sync by the hearth placement, damage,
+/// and privilege reducers in this module.
+#[spacetimedb::table(accessor = building_privilege_zone, public)]
+#[derive(Clone, Debug)]
+pub struct BuildingPrivilegeZone {
+    #[primary_key]
+    pub hearth_id: u32,
+    pub center_x: f32,
+    pub center_y: f32, // Hearth visual center (pos_y + HEARTH_COLLISION_Y_OFFSET)
+    pub radius: f32,
+    pub owner_id: Identity, // Player who placed the hearth
+    pub authorized_identities: Vec<Identity>,
+    pub updated_at: Timestamp,
+}
+
+impl BuildingPrivilegeZone {
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        let dx = x - self.center_x;
+        let dy = y - self.center_y;
+        dx * dx + dy * dy <= self.radius * self.radius
+    }
+
+    pub fn is_authorized(&self, identity: Identity) -> bool {
+        self.authorized_identities.contains(&identity)
+    }
+}
+
+/// Creates the privilege zone for a hearth if it doesn't have one yet
+pub fn ensure_building_privilege_zone(ctx: &ReducerContext, hearth: &HomesteadHearth, authorized_identities: Vec<Identity>) {
+    let zones = ctx.db.building_privilege_zone();
+    if hearth.is_destroyed || zones.hearth_id().find(&hearth.id).is_some() {
+        return;
+    }
+
+    zones.insert(BuildingPrivilegeZone {
+        hearth_id: hearth.id,
+        center_x: hearth.pos_x,
+        center_y: hearth.pos_y + HEARTH_COLLISION_Y_OFFSET,
+        radius: BUILDING_PRIVILEGE_RADIUS,
+        owner_id: hearth.placed_by,
+        authorized_identities,
+        updated_at: ctx.timestamp,
+    });
+}
+
+/// Removes a hearth's privilege zone and drops privilege from players no longer authorized anywhere
+pub fn remove_building_privilege_zone(ctx: &ReducerContext, hearth_id: u32) {
+    let zones = ctx.db.building_privilege_zone();
+    if let Some(zone) = zones.hearth_id().find(&hearth_id) {
+        zones.hearth_id().delete(&hearth_id);
+        for identity in zone.authorized_identities {
+            remove_building_privilege_if_unauthorized(ctx, identity);
+        }
+    }
+}
+
+/// Adds or removes a player from a hearth's authorized list
+fn set_zone_authorization(ctx: &ReducerContext, hearth_id: u32, player_id: Identity, authorized: bool) -> Result<(), String> {
+    let zones = ctx.db.building_privilege_zone();
+    let mut zone = zones.hearth_id().find(&hearth_id)
+        .ok_or_else(|| format!("Building privilege zone for hearth {} not found", hearth_id))?;
+
+    let already_authorized = zone.is_authorized(player_id);
+    if authorized == already_authorized {
+        return Ok(());
+    }
+
+    if authorized {
+        zone.authorized_identities.push(player_id);
+    } else {
+        zone.authorized_identities.retain(|id| *id != player_id);
+    }
+    zone.updated_at = ctx.timestamp;
+    zones.hearth_id().update(zone);
+    Ok(())
+}
+
+/// Checks if a player is on the authorized list of a specific hearth
+pub fn is_player_authorized_at_hearth(ctx: &ReducerContext, hearth_id: u32, player_id: Identity) -> bool {
+    ctx.db.building_privilege_zone().hearth_id().find(&hearth_id)
+        .map(|zone| zone.is_authorized(player_id))
+        .unwrap_or(false)
+}
+
+/// Removes the building privilege effect if the player isn't authorized at any remaining hearth
+fn remove_building_privilege_if_unauthorized(ctx: &ReducerContext, player_id: Identity) {
+    let still_authorized = ctx.db.building_privilege_zone().iter()
+        .any(|zone| zone.is_authorized(player_id));
+    if !still_authorized {
+        remove_building_privilege(ctx, player_id);
+    }
+}
+
+/// Creates zones for hearths that predate the zone table and clears zones of destroyed hearths.
+/// Hearths without a zone inherit every current privilege holder, matching the old global privilege.
+pub fn sync_building_privilege_zones(ctx: &ReducerContext) {
+    let legacy_holders: Vec<Identity> = ctx.db.active_consumable_effect().iter()
+        .filter(|effect| effect.effect_type == EffectType::BuildingPrivilege)
+        .map(|effect| effect.player_id)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+
+    for hearth in ctx.db.homestead_hearth().iter() {
+        if hearth.is_destroyed {
+            remove_building_privilege_zone(ctx, hearth.id);
+        } else {
+            ensure_building_privilege_zone(ctx, &hearth, legacy_holders.clone());
+        }
+    }
+
+    let orphaned_zones: Vec<u32> = ctx.db.building_privilege_zone().iter()
+        .filter(|zone| ctx.db.homestead_hearth().id().find(&zone.hearth_id).is_none())
+        .map(|zone| zone.hearth_id)
+        .collect();
+    for hearth_id in orphaned_zones {
+        remove_building_privilege_zone(ctx, hearth_id);
+    }
+}
+
+/// Checks if a player may build or interact with structures at a position.
+/// Unclaimed land is open to everyone; inside a hearth's radius the player must be
+/// on that hearth's authorized list (overlapping zones must all authorize the player).
+pub fn is_position_privileged(ctx: &ReducerContext, x: f32, y: f32, identity: Identity) -> bool {
+    ctx.db.building_privilege_zone().iter()
+        .filter(|zone| zone.contains(x, y))
+        .all(|zone| zone.is_authorized(identity))
+}
+
+/// Checks if a position lies inside any hearth's privilege zone
+pub fn is_position_claimed(ctx: &ReducerContext, x: f32, y: f32) -> bool {
+    ctx.db.building_privilege_zone().iter().any(|zone| zone.contains(x, y))
+}
+
+/// Result-returning wrapper around `is_position_privileged` for placement and upgrade reducers
+pub fn check_position_privileged(ctx: &ReducerContext, x: f32, y: f32, identity: Identity) -> Result<(), String> {
+    if is_position_privileged(ctx, x, y, identity) {
+        Ok(())
+    } else {
+        Err("Building privilege required. This area is claimed by a Homestead Hearth you are not authorized on.".to_string())
+    }
+}
+
 /// Updates cozy effect for players with building privilege near hearths
 /// Note: This is now redundant since should_player_be_cozy checks hearths,
 /// but kept for explicit hearth-specific logic if needed in the future.
@@ -277,6 +436,29 @@ pub struct UpkeepCosts {
     pub wood: u32,
     pub stone: u32,
     pub metal: u32,
+    pub piece_count: u32, // Building pieces tied to the hearth that pay upkeep (non-twig)
+    pub tax_rate: f32,    // Effective tax rate applied on top of base costs (0.10 = +10%)
+}
+
+/// Effective tax rate for a number of building pieces, blending the brackets
+/// the pieces fall into (e.g. 20 pieces = 15 at 10% + 5 at 15%).
+pub fn calculate_upkeep_tax_rate(piece_count: u32) -> f32 {
+    if piece_count == 0 {
+        return 0.0;
+    }
+
+    let mut taxed_total = 0.0;
+    let mut lower_bound = 0u32;
+    for (upper_bound, rate) in UPKEEP_TAX_BRACKETS.iter() {
+        if piece_count <= lower_bound {
+            break;
+        }
+        let pieces_in_bracket = piece_count.min(*upper_bound) - lower_bound;
+        taxed_total += pieces_in_bracket as f32 * rate;
+        lower_bound = *upper_bound;
+    }
+
+    taxed_total / piece_count as f32
 }
 
 /// Find the foundation cell that a hearth is placed on
@@ -438,6 +620,8 @@ pub fn calculate_upkeep_costs(
         wood: 0,
         stone: 0,
         metal: 0,
+        piece_count: 0,
+        tax_rate: 0.0,
     };
     
     // Find the foundation the hearth is on
@@ -455,6 +639,10 @@ pub fn calculate_upkeep_costs(
             continue;
         }
         
+        if foundation.tier > 0 {
+            costs.piece_count += 1;
+        }
+        
         // Only wood, stone, and metal tiers require minimal upkeep (not twig)
         // Foundation upkeep is minimal since it's purely aesthetic - main cost is walls
         match foundation.tier {
@@ -492,6 +680,10 @@ pub fn calculate_upkeep_costs(
             continue;
         }
         
+        if wall.tier > 0 {
+            costs.piece_count += 1;
+        }
+        
         match wall.tier {
             1 => { // Wood tier
                 costs.wood += 5; // 5 wood per hour per wall
@@ -509,9 +701,37 @@ pub fn calculate_upkeep_costs(
     // TODO: Add door upkeep when doors are implemented
     // Doors would have similar costs but lower (maybe 3 wood/stone, 2 metal)
     
+    // Apply tax brackets based on how many pieces this hearth is keeping up
+    costs.tax_rate = calculate_upkeep_tax_rate(costs.piece_count);
+    let multiplier = 1.0 + costs.tax_rate;
+    costs.wood = (costs.wood as f32 * multiplier).ceil() as u32;
+    costs.stone = (costs.stone as f32 * multiplier).ceil() as u32;
+    costs.metal = (costs.metal as f32 * multiplier).ceil() as u32;
+    
     costs
 }
 
+/// Hours the hearth's current stock can pay upkeep for.
+/// Returns None when nothing tied to the hearth requires upkeep.
+pub fn calculate_upkeep_hours_remaining(
+    costs: &UpkeepCosts,
+    available: (u32, u32, u32),
+    upkeep_interval_seconds: u64,
+) -> Option<f32> {
+    let (available_wood, available_stone, available_metal) = available;
+    let interval_hours = upkeep_interval_seconds as f32 / 3600.0;
+    
+    [
+        (costs.wood, available_wood),
+        (costs.stone, available_stone),
+        (costs.metal, available_metal),
+    ]
+    .iter()
+    .filter(|(required, _)| *required > 0)
+    .map(|(required, available)| (*available / *required) as f32 * interval_hours)
+    .fold(None, |shortest: Option<f32>, hours| Some(shortest.map_or(hours, |s| s.min(hours))))
+}
+
 /// Get resource counts from hearth inventory
 pub(crate) fn get_hearth_resources(
     ctx: &ReducerContext,
@@ -717,6 +937,63 @@ pub struct HearthUpkeepQueryResult {
     pub last_updated: Timestamp,
 }
 
+/// Public upkeep status per hearth so clients can show a decay timer
+/// Refreshed by the upkeep schedule, on placement, and on upkeep queries
+#[spacetimedb::table(accessor = hearth_upkeep_status, public)]
+#[derive(Clone)]
+pub struct HearthUpkeepStatus {
+    #[primary_key]
+    pub hearth_id: u32,
+    pub piece_count: u32,
+    pub tax_rate: f32,
+    pub wood_per_interval: u32,
+    pub stone_per_interval: u32,
+    pub metal_per_interval: u32,
+    pub upkeep_interval_seconds: u64,
+    pub hours_remaining: Option<f32>, // None if nothing requires upkeep
+    pub is_protected: bool,           // True while the hearth can cover the next upkeep payment
+    pub next_upkeep_at: Timestamp,
+    pub last_updated: Timestamp,
+}
+
+/// Recomputes and stores the public upkeep status row for a hearth
+pub fn refresh_hearth_upkeep_status(ctx: &ReducerContext, hearth: &HomesteadHearth) {
+    let statuses = ctx.db.hearth_upkeep_status();
+    
+    if hearth.is_destroyed {
+        statuses.hearth_id().delete(&hearth.id);
+        return;
+    }
+    
+    let costs = calculate_upkeep_costs(ctx, hearth);
+    let available = get_hearth_resources(ctx, hearth);
+    let hours_remaining = calculate_upkeep_hours_remaining(&costs, available, hearth.upkeep_interval_seconds);
+    let is_protected = available.0 >= costs.wood && available.1 >= costs.stone && available.2 >= costs.metal;
+    
+    let last_upkeep = hearth.last_upkeep_time.unwrap_or(hearth.placed_at);
+    let next_upkeep_at = last_upkeep + TimeDuration::from_micros((hearth.upkeep_interval_seconds as i64) * 1_000_000);
+    
+    let status = HearthUpkeepStatus {
+        hearth_id: hearth.id,
+        piece_count: costs.piece_count,
+        tax_rate: costs.tax_rate,
+        wood_per_interval: costs.wood,
+        stone_per_interval: costs.stone,
+        metal_per_interval: costs.metal,
+        upkeep_interval_seconds: hearth.upkeep_interval_seconds,
+        hours_remaining,
+        is_protected,
+        next_upkeep_at,
+        last_updated: ctx.timestamp,
+    };
+    
+    if statuses.hearth_id().find(&hearth.id).is_some() {
+        statuses.hearth_id().update(status);
+    } else {
+        statuses.insert(status);
+    }
+}
+
 /// Initialize the hearth upkeep processing schedule
 pub fn init_hearth_upkeep_schedule(ctx: &ReducerContext) -> Result<(), String> {
     let schedule_table = ctx.db.hearth_upkeep_schedule();
@@ -757,6 +1034,9 @@ pub fn process_hearth_upkeep(ctx: &ReducerContext, _schedule: HearthUpkeepSchedu
         return Ok(());
     }
 
+    // Keep privilege zones consistent with hearths (covers modules upgraded in place)
+    sync_building_privilege_zones(ctx);
+
     let current_time = ctx.timestamp;
     let hearths = ctx.db.homestead_hearth();
     
@@ -771,6 +1051,7 @@ pub fn process_hearth_upkeep(ctx: &ReducerContext, _schedule: HearthUpkeepSchedu
         let next_upkeep_time = last_upkeep + TimeDuration::from_micros(interval_micros);
         
         if current_time < next_upkeep_time {
+            refresh_hearth_upkeep_status(ctx, &hearth);
             continue; // Not time yet
         }
         
@@ -780,6 +1061,7 @@ pub fn process_hearth_upkeep(ctx: &ReducerContext, _schedule: HearthUpkeepSchedu
         // If no upkeep needed, skip
         if costs.wood == 0 && costs.stone == 0 && costs.metal == 0 {
             hearth.last_upkeep_time = Some(current_time);
+            refresh_hearth_upkeep_status(ctx, &hearth);
             ctx.db.homestead_hearth().id().update(hearth);
             continue;
         }
@@ -796,8 +1078,8 @@ pub fn process_hearth_upkeep(ctx: &ReducerContext, _schedule: HearthUpkeepSchedu
             if consume_upkeep_resources(ctx, &mut hearth, &costs) {
                 hearth.last_upkeep_time = Some(current_time);
                 log::info!(
-                    "[Upkeep] Processed upkeep for hearth {}: consumed {} wood, {} stone, {} metal",
-                    hearth.id, costs.wood, costs.stone, costs.metal
+                    "[Upkeep] Processed upkeep for hearth {}: consumed {} wood, {} stone, {} metal ({} pieces, {:.0}% tax)",
+                    hearth.id, costs.wood, costs.stone, costs.metal, costs.piece_count, costs.tax_rate * 100.0
                 );
             } else {
                 log::warn!(
@@ -815,6 +1097,8 @@ pub fn process_hearth_upkeep(ctx: &ReducerContext, _schedule: HearthUpkeepSchedu
             hearth.last_upkeep_time = Some(current_time);
         }
         
+        refresh_hearth_upkeep_status(ctx, &hearth);
+        
         // Update hearth
         ctx.db.homestead_hearth().id().update(hearth);
     }
@@ -840,6 +1124,7 @@ pub fn query_hearth_upkeep_costs(
         if let Some(existing) = query_results.hearth_id().find(&hearth_id) {
             query_results.hearth_id().delete(&hearth_id);
         }
+        refresh_hearth_upkeep_status(ctx, &hearth);
         return Err("Cannot query upkeep for destroyed hearth".to_string());
     }
     
@@ -872,6 +1157,8 @@ pub fn query_hearth_upkeep_costs(
         query_results.insert(result);
     }
     
+    refresh_hearth_upkeep_status(ctx, &hearth);
+    
     Ok(())
 }
 
@@ -1148,11 +1435,13 @@ pub fn place_homestead_hearth(
         upkeep_interval_seconds: DEFAULT_UPKEEP_INTERVAL_SECONDS,
     };
 
-    hearths.try_insert(new_hearth)
+    let inserted_hearth = hearths.try_insert(new_hearth)
         .map_err(|e| format!("Failed to insert hearth: {}", e))?;
+    refresh_hearth_upkeep_status(ctx, &inserted_hearth);
 
     // Note: Building privilege is NOT automatically granted when placing a hearth
     // Players must manually hold E near the hearth to gain building privilege
+    ensure_building_privilege_zone(ctx, &inserted_hearth, Vec::new());
 
     log::info!(
         "[PlaceHomesteadHearth] Successfully placed hearth at ({:.1}, {:.1}) by player {:?}",
@@ -1163,7 +1452,7 @@ pub fn place_homestead_hearth(
 }
 
 /// Grant building privilege to the calling player (hold E interaction)
-/// Toggles privilege: removes if player already has it, grants if they don't
+/// Toggles privilege: removes if player is already authorized at this hearth, grants if they aren't
 #[spacetimedb::reducer]
 pub fn grant_building_privilege_from_hearth(
     ctx: &ReducerContext,
@@ -1171,9 +1460,10 @@ pub fn grant_building_privilege_from_hearth(
 ) -> Result<(), String> {
     let sender_id = ctx.sender();
     let (_player, hearth) = validate_hearth_interaction(ctx, hearth_id)?;
+    ensure_building_privilege_zone(ctx, &hearth, Vec::new());
 
-    // Check if player already has building privilege
-    let already_has_privilege = player_has_building_privilege(ctx, sender_id);
+    // Check if player is already authorized at this hearth
+    let already_has_privilege = is_player_authorized_at_hearth(ctx, hearth_id, sender_id);
     
     // If granting privilege (don't have it yet), check building privilege radius
     // If revoking privilege (already have it), only need to be within interaction distance (already validated)
@@ -1192,11 +1482,13 @@ pub fn grant_building_privilege_from_hearth(
             return Err("Too far from hearth to toggle building privilege.".to_string());
         }
         
+        set_zone_authorization(ctx, hearth_id, sender_id, true)?;
         grant_building_privilege(ctx, sender_id)?;
         log::info!("Player {:?} granted building privilege from hearth {}", sender_id, hearth_id);
     } else {
         // Player already has privilege - can revoke from interaction distance (already validated)
-        remove_building_privilege(ctx, sender_id);
+        set_zone_authorization(ctx, hearth_id, sender_id, false)?;
+        remove_building_privilege_if_unauthorized(ctx, sender_id);
         log::info!("Player {:?} revoked building privilege from hearth {}", sender_id, hearth_id);
     }
 
@@ -1204,7 +1496,7 @@ pub fn grant_building_privilege_from_hearth(
 }
 
 /// Revoke building privilege from a specific player (UI button action)
-/// Can be called by anyone with building privilege (team management)
+/// Can be called by anyone authorized at this hearth (team management)
 #[spacetimedb::reducer]
 pub fn revoke_player_building_privilege(
     ctx: &ReducerContext,
@@ -1214,20 +1506,21 @@ pub fn revoke_player_building_privilege(
     let sender_id = ctx.sender();
     let (_player, _hearth) = validate_hearth_interaction(ctx, hearth_id)?;
 
-    // Only players with building privilege can revoke others' privileges
-    if !player_has_building_privilege(ctx, sender_id) {
+    // Only players authorized at this hearth can revoke others' privileges
+    if !is_player_authorized_at_hearth(ctx, hearth_id, sender_id) {
         return Err("You must have building privilege to revoke others' privileges.".to_string());
     }
 
-    // Remove the target player's privilege
-    remove_building_privilege(ctx, target_player_id);
+    // Remove the target player from this hearth (and drop the effect if they have no other hearth)
+    set_zone_authorization(ctx, hearth_id, target_player_id, false)?;
+    remove_building_privilege_if_unauthorized(ctx, target_player_id);
     log::info!("Player {:?} revoked building privilege from player {:?} via hearth {}", sender_id, target_player_id, hearth_id);
 
     Ok(())
 }
 
-/// Wipe all building privileges (emergency reset)
-/// Can be called by anyone with building privilege (team management)
+/// Wipe all building privileges at this hearth (emergency reset)
+/// Can be called by anyone authorized at this hearth (team management)
 #[spacetimedb::reducer]
 pub fn wipe_all_building_privileges(
     ctx: &ReducerContext,
@@ -1236,372 +1529,114 @@ pub fn wipe_all_building_privileges(
     let sender_id = ctx.sender();
     let (_player, _hearth) = validate_hearth_interaction(ctx, hearth_id)?;
 
-    // Only players with building privilege can wipe all privileges
-    if !player_has_building_privilege(ctx, sender_id) {
+    // Only players authorized at this hearth can wipe its privileges
+    if !is_player_authorized_at_hearth(ctx, hearth_id, sender_id) {
         return Err("You must have building privilege to wipe all privileges.".to_string());
     }
 
-    // Remove all building privilege effects
-    let mut effects_to_remove = Vec::new();
-    for effect in ctx.db.active_consumable_effect().iter() {
-        if effect.effect_type == EffectType::BuildingPrivilege {
-            effects_to_remove.push(effect.effect_id);
+    // Clear this hearth's authorized list; players authorized elsewhere keep their effect
+    let zones = ctx.db.building_privilege_zone();
+    if let Some(mut zone) = zones.hearth_id().find(&hearth_id) {
+        let previously_authorized = std::mem::take(&mut zone.authorized_identities);
+        zone.updated_at = ctx.timestamp;
+        zones.hearth_id().update(zone);
+        for identity in previously_authorized {
+            remove_building_privilege_if_unauthorized(ctx, identity);
         }
     }
     
-    for effect_id in effects_to_remove {
-        ctx.db.active_consumable_effect().effect_id().delete(&effect_id);
-    }
-    
     log::info!("Player {:?} wiped all building privileges via hearth {}", sender_id, hearth_id);
 
     Ok(())
 }
 
-/// Move item to hearth (with item restriction check)
-#[spacetimedb::reducer]
-pub fn move_item_to_hearth(
-    ctx: &ReducerContext,
-    hearth_id: u32,
-    target_slot_index: u8,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_player, mut hearth) = validate_hearth_interaction(ctx, hearth_id)?;
-    
-    // Check if item is allowed
-    let inventory_table = ctx.db.inventory_item();
-    let item_def_table = ctx.db.item_definition();
-    
-    let item_to_move = inventory_table.instance_id().find(item_instance_id)
-        .ok_or_else(|| format!("Item instance {} not found", item_instance_id))?;
-    
-    let item_def = item_def_table.id().find(item_to_move.item_def_id)
-        .ok_or_else(|| format!("Item definition {} not found", item_to_move.item_def_id))?;
-    
-    if !is_item_allowed(&item_def) {
-        return Err(format!("Item '{}' is not allowed in Matron's Chest. Only materials are allowed.", item_def.name));
+impl inventory_management::ContainerHandle for HomesteadHearth {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_hearth_interaction(ctx, container_id)
     }
-    
-    // Use generic handler
-    inventory_management::handle_move_to_container_slot(ctx, &mut hearth, target_slot_index, item_instance_id)?;
-    ctx.db.homestead_hearth().id().update(hearth);
-    
-    Ok(())
-}
-
-/// Move item from hearth
-#[spacetimedb::reducer]
-pub fn move_item_from_hearth(
-    ctx: &ReducerContext,
-    hearth_id: u32,
-    source_slot_index: u8,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let (_player, mut hearth) = validate_hearth_interaction(ctx, hearth_id)?;
-    inventory_management::handle_move_from_container_slot(ctx, &mut hearth, source_slot_index, target_slot_type, target_slot_index)?;
-    ctx.db.homestead_hearth().id().update(hearth);
-    Ok(())
-}
 
-/// Move item within hearth
-#[spacetimedb::reducer]
-pub fn move_item_within_hearth(
-    ctx: &ReducerContext,
-    hearth_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut hearth) = validate_hearth_interaction(ctx, hearth_id)?;
-    inventory_management::handle_move_within_container(ctx, &mut hearth, source_slot_index, target_slot_index)?;
-    ctx.db.homestead_hearth().id().update(hearth);
-    Ok(())
-}
-
-/// Split stack into hearth (with item restriction check)
-#[spacetimedb::reducer]
-pub fn split_stack_into_hearth(
-    ctx: &ReducerContext,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-    target_hearth_id: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut hearth) = validate_hearth_interaction(ctx, target_hearth_id)?;
-    
-    // Check if item is allowed
-    let inventory_table = ctx.db.inventory_item();
-    let item_def_table = ctx.db.item_definition();
-    
-    let source_item = inventory_table.instance_id().find(source_item_instance_id)
-        .ok_or_else(|| format!("Source item {} not found", source_item_instance_id))?;
-    
-    let item_def = item_def_table.id().find(source_item.item_def_id)
-        .ok_or_else(|| format!("Item definition {} not found", source_item.item_def_id))?;
-    
-    if !is_item_allowed(&item_def) {
-        return Err(format!("Item '{}' is not allowed in Matron's Chest. Only materials are allowed.", item_def.name));
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.homestead_hearth().id().update(self.clone());
     }
-    
-    let mut source_item_mut = source_item;
-    let new_item_target_location = ItemLocation::Container(ContainerLocationData {
-        container_type: ContainerType::HomesteadHearth,
-        container_id: hearth.id as u64,
-        slot_index: target_slot_index,
-    });
-    
-    use crate::items::split_stack_helper;
-    let new_item_instance_id = split_stack_helper(ctx, &mut source_item_mut, quantity_to_split, new_item_target_location)?;
-    
-    let mut new_item = inventory_table.instance_id().find(new_item_instance_id)
-        .ok_or_else(|| "Failed to find newly split item".to_string())?;
-    let new_item_def = item_def_table.id().find(new_item.item_def_id)
-        .ok_or_else(|| "Failed to find item definition".to_string())?;
-    
-    inventory_management::merge_or_place_into_container_slot(ctx, &mut hearth, target_slot_index, &mut new_item, &new_item_def)?;
-    ctx.db.homestead_hearth().id().update(hearth);
-    
-    Ok(())
-}
 
-/// Split stack from hearth
-#[spacetimedb::reducer]
-pub fn split_stack_from_hearth(
-    ctx: &ReducerContext,
-    hearth_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let (_player, mut hearth) = validate_hearth_interaction(ctx, hearth_id)?;
-    inventory_management::handle_split_from_container(ctx, &mut hearth, source_slot_index, quantity_to_split, target_slot_type, target_slot_index)?;
-    ctx.db.homestead_hearth().id().update(hearth);
-    Ok(())
-}
-
-/// Split stack within hearth
-#[spacetimedb::reducer]
-pub fn split_stack_within_hearth(
-    ctx: &ReducerContext,
-    hearth_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (_player, mut hearth) = validate_hearth_interaction(ctx, hearth_id)?;
-    inventory_management::handle_split_within_container(ctx, &mut hearth, source_slot_index, target_slot_index, quantity_to_split)?;
-    ctx.db.homestead_hearth().id().update(hearth);
-    Ok(())
-}
-
-/// Quick move from hearth
-#[spacetimedb::reducer]
-pub fn quick_move_from_hearth(
-    ctx: &ReducerContext,
-    hearth_id: u32,
-    source_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut hearth) = validate_hearth_interaction(ctx, hearth_id)?;
-    inventory_management::handle_quick_move_from_container(ctx, &mut hearth, source_slot_index)?;
-    ctx.db.homestead_hearth().id().update(hearth);
-    Ok(())
-}
-
-/// Quick move to hearth (with item restriction check)
-#[spacetimedb::reducer]
-pub fn quick_move_to_hearth(
-    ctx: &ReducerContext,
-    hearth_id: u32,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_player, mut hearth) = validate_hearth_interaction(ctx, hearth_id)?;
-    
-    // Check if item is allowed
-    let inventory_table = ctx.db.inventory_item();
-    let item_def_table = ctx.db.item_definition();
-    
-    let item_to_move = inventory_table.instance_id().find(item_instance_id)
-        .ok_or_else(|| format!("Item instance {} not found", item_instance_id))?;
-    
-    let item_def = item_def_table.id().find(item_to_move.item_def_id)
-        .ok_or_else(|| format!("Item definition {} not found", item_to_move.item_def_id))?;
-    
-    if !is_item_allowed(&item_def) {
-        return Err(format!("Item '{}' is not allowed in Matron's Chest. Only materials are allowed.", item_def.name));
+    /// Matron's Chest only takes materials
+    fn check_operation(&self, ctx: &ReducerContext, op: &inventory_management::ContainerOp) -> Result<(), String> {
+        let Some(item_instance_id) = op.incoming_item() else {
+            return Ok(());
+        };
+        let item = ctx.db.inventory_item().instance_id().find(item_instance_id)
+            .ok_or_else(|| format!("Item instance {} not found", item_instance_id))?;
+        let item_def = ctx.db.item_definition().id().find(item.item_def_id)
+            .ok_or_else(|| format!("Item definition {} not found", item.item_def_id))?;
+        if !is_item_allowed(&item_def) {
+            return Err(format!("Item '{}' is not allowed in Matron's Chest. Only materials are allowed.", item_def.name));
+        }
+        Ok(())
     }
-    
-    inventory_management::handle_quick_move_to_container(ctx, &mut hearth, item_instance_id)?;
-    ctx.db.homestead_hearth().id().update(hearth);
-    Ok(())
 }
 
-/// Drop item from hearth slot to world
-#[spacetimedb::reducer]
-pub fn drop_item_from_hearth_slot_to_world(
-    ctx: &ReducerContext,
-    hearth_id: u32,
-    slot_index: u8,
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player();
-    let mut hearth_table = ctx.db.homestead_hearth();
-
-    log::info!("[DropFromHearthToWorld] Player {} attempting to drop item from hearth ID {}, slot index {}.", 
-             sender_id, hearth_id, slot_index);
-
-    // 1. Validate interaction and get hearth
-    let (_player_for_validation, mut hearth) = validate_hearth_interaction(ctx, hearth_id)?;
-
-    // 2. Get Player for drop location
-    let player_for_drop_location = player_table.identity().find(sender_id)
-        .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
-
-    // 3. Call the generic handler from inventory_management
-    crate::inventory_management::handle_drop_from_container_slot(ctx, &mut hearth, slot_index, &player_for_drop_location)?;
-
-    // 4. Persist changes to the Hearth
-    hearth_table.id().update(hearth);
-    log::info!("[DropFromHearthToWorld] Successfully dropped item from hearth {}, slot {}. Hearth updated.", hearth_id, slot_index);
-
-    Ok(())
+crate::container_reducers! {
+    HomesteadHearth;
+    move_to: move_item_to_hearth(hearth_id),
+    move_from: move_item_from_hearth(hearth_id),
+    move_within: move_item_within_hearth(hearth_id),
+    split_into_item_first: split_stack_into_hearth(target_hearth_id),
+    split_from: split_stack_from_hearth(hearth_id),
+    split_within: split_stack_within_hearth(hearth_id),
+    quick_move_from: quick_move_from_hearth(hearth_id),
+    quick_move_to: quick_move_to_hearth(hearth_id),
+    drop: drop_item_from_hearth_slot_to_world(hearth_id),
+    split_and_drop: split_and_drop_item_from_hearth_slot_to_world(hearth_id),
+    move_all_of_type: move_all_of_type_to_hearth(hearth_id),
+    deposit_matching: deposit_matching_to_hearth(hearth_id),
+    take_all: take_all_from_hearth(hearth_id),
 }
 
-/// Split and drop item from hearth slot to world
-#[spacetimedb::reducer]
-pub fn split_and_drop_item_from_hearth_slot_to_world(
-    ctx: &ReducerContext,
-    hearth_id: u32,
-    slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player();
-    let mut hearth_table = ctx.db.homestead_hearth();
-
-    log::info!("[SplitDropFromHearthToWorld] Player {} attempting to split {} from hearth ID {}, slot {}.", 
-             sender_id, quantity_to_split, hearth_id, slot_index);
-
-    // 1. Validate interaction and get hearth
-    let (_player_for_validation, mut hearth) = validate_hearth_interaction(ctx, hearth_id)?;
-
-    // 2. Get Player for drop location
-    let player_for_drop_location = player_table.identity().find(sender_id)
-        .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
-
-    // 3. Call the generic handler from inventory_management
-    crate::inventory_management::handle_split_and_drop_from_container_slot(ctx, &mut hearth, slot_index, quantity_to_split, &player_for_drop_location)?;
-
-    // 4. Persist changes to the Hearth
-    hearth_table.id().update(hearth);
-    log::info!("[SplitDropFromHearthToWorld] Successfully split and dropped item from hearth {}, slot {}. Hearth updated.", hearth_id, slot_index);
-    
-    Ok(())
-}
-
-/// Applies weapon damage to a hearth (called from combat system)
-/// This is separate from attack_hearth reducer which is called directly by clients
-pub fn damage_hearth(
-    ctx: &ReducerContext,
-    attacker_id: Identity,
-    hearth_id: u32,
-    damage: f32,
-    timestamp: Timestamp,
-) -> Result<(), String> {
-    let mut hearths = ctx.db.homestead_hearth();
+/// Drops a destroyed hearth's contents and tears down its upkeep timer and privilege zone.
+/// Called by `building::apply_structure_damage`; the caller saves the hearth afterwards.
+pub fn handle_hearth_destroyed(ctx: &ReducerContext, hearth: &mut HomesteadHearth) {
     let inventory_items = ctx.db.inventory_item();
-    
-    // Find the hearth
-    let mut hearth = hearths.id().find(&hearth_id)
-        .ok_or_else(|| format!("Hearth with ID {} not found.", hearth_id))?;
-    
-    if hearth.is_destroyed {
-        return Err("Hearth is already destroyed.".to_string());
-    }
-    
-    // <<< PVP RAIDING CHECK >>>
-    if let Some(attacker_player) = ctx.db.player().identity().find(&attacker_id) {
-        let attacker_pvp = crate::combat::is_pvp_active_for_player(&attacker_player, timestamp);
-        
-        if hearth.placed_by != attacker_id {
-            if let Some(owner_player) = ctx.db.player().identity().find(&hearth.placed_by) {
-                let owner_pvp = crate::combat::is_pvp_active_for_player(&owner_player, timestamp);
+    let hearth_id = hearth.id;
+
+    // Drop all items from inventory to world WITHOUT triggering consolidation per-item
+    let mut items_dropped = 0;
+    for slot_index in 0..NUM_HEARTH_SLOTS {
+        if let Some(instance_id) = hearth.get_slot_instance_id(slot_index as u8) {
+            if let Some(item) = inventory_items.instance_id().find(instance_id) {
+                // Calculate drop position around the hearth
+                let angle = (items_dropped as f32) * (std::f32::consts::PI * 2.0 / 8.0); // Spread items in a circle
+                let drop_radius = 30.0 + (items_dropped as f32 * 5.0); // Increasing radius
+                let drop_x = hearth.pos_x + angle.cos() * drop_radius;
+                let drop_y = hearth.pos_y + angle.sin() * drop_radius;
                 
-                if !attacker_pvp || !owner_pvp {
-                    log::debug!("Structure raiding blocked - Attacker PvP: {}, Owner PvP: {}", 
-                        attacker_pvp, owner_pvp);
-                    return Err("Cannot damage structure - PvP raiding requires both players to have PvP enabled.".to_string());
-                }
-            }
-        }
-    }
-    // <<< END PVP RAIDING CHECK >>>
-    
-    let old_health = hearth.health;
-    hearth.health = (hearth.health - damage).max(0.0);
-    hearth.last_hit_time = Some(timestamp);
-    hearth.last_damaged_by = Some(attacker_id);
-    
-    log::info!(
-        "Player {:?} hit Hearth {} for {:.1} damage. Health: {:.1} -> {:.1}",
-        attacker_id, hearth_id, damage, old_health, hearth.health
-    );
-    
-    if hearth.health <= 0.0 {
-        // Destroy the hearth
-        hearth.health = 0.0;
-        hearth.is_destroyed = true;
-        hearth.destroyed_at = Some(timestamp);
-        
-        log::info!("[HearthDamage] Hearth {} destroyed by player {:?}", hearth_id, attacker_id);
-        
-        // Emit destruction sound
-        crate::sound_events::emit_foundation_twig_destroyed_sound(ctx, hearth.pos_x, hearth.pos_y, attacker_id);
-        
-        // Drop all items from inventory to world WITHOUT triggering consolidation per-item
-        let mut items_dropped = 0;
-        for slot_index in 0..NUM_HEARTH_SLOTS {
-            if let Some(instance_id) = hearth.get_slot_instance_id(slot_index as u8) {
-                if let Some(item) = inventory_items.instance_id().find(instance_id) {
-                    // Calculate drop position around the hearth
-                    let angle = (items_dropped as f32) * (std::f32::consts::PI * 2.0 / 8.0); // Spread items in a circle
-                    let drop_radius = 30.0 + (items_dropped as f32 * 5.0); // Increasing radius
-                    let drop_x = hearth.pos_x + angle.cos() * drop_radius;
-                    let drop_y = hearth.pos_y + angle.sin() * drop_radius;
-                    
-                    // Create dropped item without auto-consolidation
-                    if let Err(e) = crate::dropped_item::create_dropped_item_entity_no_consolidation(ctx, item.item_def_id, item.quantity, drop_x, drop_y) {
-                        log::error!("[HearthDamage] Failed to drop item {} from hearth {}: {}", instance_id, hearth_id, e);
-                    } else {
-                        items_dropped += 1;
-                        log::info!("[HearthDamage] Dropped item {} (def {}, qty {}) from destroyed hearth {}",
-                                 instance_id, item.item_def_id, item.quantity, hearth_id);
-                    }
-                    
-                    // Delete the inventory item
-                    inventory_items.instance_id().delete(instance_id);
+                // Create dropped item without auto-consolidation
+                if let Err(e) = crate::dropped_item::create_dropped_item_entity_no_consolidation(ctx, item.item_def_id, item.quantity, drop_x, drop_y) {
+                    log::error!("[HearthDamage] Failed to drop item {} from hearth {}: {}", instance_id, hearth_id, e);
+                } else {
+                    items_dropped += 1;
+                    log::info!("[HearthDamage] Dropped item {} (def {}, qty {}) from destroyed hearth {}",
+                             instance_id, item.item_def_id, item.quantity, hearth_id);
                 }
                 
-                // Clear the slot
-                hearth.set_slot(slot_index as u8, None, None);
+                // Delete the inventory item
+                inventory_items.instance_id().delete(instance_id);
             }
+            
+            // Clear the slot
+            hearth.set_slot(slot_index as u8, None, None);
         }
-        
-        // Trigger consolidation ONCE after all items are dropped
-        crate::dropped_item::trigger_consolidation_at_position(ctx, hearth.pos_x, hearth.pos_y);
-        
-        log::info!("[HearthDamage] Dropped {} items from destroyed hearth {}", items_dropped, hearth_id);
-    } else {
-        // Hearth damaged but not destroyed - emit hit sound
-        crate::sound_events::emit_melee_hit_sharp_sound(ctx, hearth.pos_x, hearth.pos_y, attacker_id);
     }
     
-    // Update the hearth
-    hearths.id().update(hearth);
+    // Trigger consolidation ONCE after all items are dropped
+    crate::dropped_item::trigger_consolidation_at_position(ctx, hearth.pos_x, hearth.pos_y);
     
-    Ok(())
+    log::info!("[HearthDamage] Dropped {} items from destroyed hearth {}", items_dropped, hearth_id);
+    
+    // Stop advertising an upkeep timer and privilege boundary for a destroyed hearth
+    refresh_hearth_upkeep_status(ctx, hearth);
+    remove_building_privilege_zone(ctx, hearth_id);
 }
 
 // Note: There is no attack_hearth reducer - damage is handled through the combat system
-// which calls damage_hearth() with weapon-based damage calculated from pvp_damage_min/max
+// which calls building::apply_structure_damage() with weapon-based damage calculated from pvp_damage_min/max
 
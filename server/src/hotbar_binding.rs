@@ -0,0 +1,132 @@
+/******************************************************************************
+ *                                                                            *
+ * Hotbar Bindings - Remembered item types per hotbar slot                    *
+ *                                                                            *
+ * A hotbar slot is bound to the item type placed in it. The binding outlives *
+ * the item: when a consumed or thrown stack is replenished (picked up,       *
+ * crafted, looted), the new stack returns to its bound slot instead of the   *
+ * first empty one. Empty slots bound to other item types are skipped when    *
+ * placing new items, unless the rest of the inventory is full.               *
+ *                                                                            *
+ * Placing an item in a slot by hand rebinds it; moving it back into the      *
+ * inventory or clear_hotbar_binding releases it.                             *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table};
+use std::collections::HashSet;
+use log;
+
+use crate::items::inventory_item as InventoryItemTableTrait;
+use crate::models::{ItemLocation, HotbarLocationData};
+use crate::player_inventory::{find_item_in_hotbar_slot, find_first_empty_inventory_slot, NUM_PLAYER_HOTBAR_SLOTS};
+
+/// The item type a player's hotbar slot is kept for
+#[spacetimedb::table(accessor = hotbar_binding, public)]
+#[derive(Clone, Debug)]
+pub struct HotbarBinding {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: Identity,
+    pub slot_index: u8,
+    pub item_def_id: u64,
+}
+
+fn find_binding(ctx: &ReducerContext, player_id: Identity, slot_index: u8) -> Option<HotbarBinding> {
+    ctx.db.hotbar_binding().player_id().filter(player_id).find(|b| b.slot_index == slot_index)
+}
+
+/// Binds a hotbar slot to an item type, replacing any previous binding
+pub fn bind_hotbar_slot(ctx: &ReducerContext, player_id: Identity, slot_index: u8, item_def_id: u64) {
+    match find_binding(ctx, player_id, slot_index) {
+        Some(binding) if binding.item_def_id == item_def_id => {}
+        Some(mut binding) => {
+            binding.item_def_id = item_def_id;
+            ctx.db.hotbar_binding().id().update(binding);
+        }
+        None => {
+            ctx.db.hotbar_binding().insert(HotbarBinding { id: 0, player_id, slot_index, item_def_id });
+        }
+    }
+}
+
+/// Binds a slot an item was auto-placed in, unless it is already kept for something
+pub fn bind_hotbar_slot_if_unbound(ctx: &ReducerContext, player_id: Identity, slot_index: u8, item_def_id: u64) {
+    if find_binding(ctx, player_id, slot_index).is_none() {
+        bind_hotbar_slot(ctx, player_id, slot_index, item_def_id);
+    }
+}
+
+pub fn unbind_hotbar_slot(ctx: &ReducerContext, player_id: Identity, slot_index: u8) {
+    if let Some(binding) = find_binding(ctx, player_id, slot_index) {
+        ctx.db.hotbar_binding().id().delete(binding.id);
+    }
+}
+
+/// Empty hotbar slot a new stack of `item_def_id` should go into, or None to use the inventory.
+/// Prefers the slot bound to the item type, then unbound slots; bound slots of other types are
+/// only used once the inventory is full. Without an item type only unbound slots are preferred.
+pub fn choose_empty_hotbar_slot(ctx: &ReducerContext, player_id: Identity, item_def_id: Option<u64>, occupied_slots: &HashSet<u8>) -> Option<u8> {
+    let bindings: Vec<HotbarBinding> = ctx.db.hotbar_binding().player_id().filter(player_id).collect();
+    let is_empty = |slot: &u8| *slot < NUM_PLAYER_HOTBAR_SLOTS && !occupied_slots.contains(slot);
+
+    if let Some(bound) = bindings.iter().filter(|b| Some(b.item_def_id) == item_def_id).map(|b| b.slot_index).find(is_empty) {
+        return Some(bound);
+    }
+    let reserved: HashSet<u8> = bindings.iter().map(|b| b.slot_index).collect();
+    if let Some(unbound) = (0..NUM_PLAYER_HOTBAR_SLOTS).filter(|slot| !reserved.contains(slot)).find(is_empty) {
+        return Some(unbound);
+    }
+    if find_first_empty_inventory_slot(ctx, player_id).is_some() {
+        return None;
+    }
+    (0..NUM_PLAYER_HOTBAR_SLOTS).find(is_empty)
+}
+
+/// --- Swap Hotbar Slots ---
+/// Swaps the contents and bindings of two hotbar slots; either may be empty.
+#[spacetimedb::reducer]
+pub fn swap_hotbar_slots(ctx: &ReducerContext, slot_a: u8, slot_b: u8) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    if slot_a >= NUM_PLAYER_HOTBAR_SLOTS || slot_b >= NUM_PLAYER_HOTBAR_SLOTS {
+        return Err("Invalid hotbar slot index.".to_string());
+    }
+    if slot_a == slot_b {
+        return Ok(());
+    }
+
+    let inventory_items = ctx.db.inventory_item();
+    let item_a = find_item_in_hotbar_slot(ctx, slot_a);
+    let item_b = find_item_in_hotbar_slot(ctx, slot_b);
+    for (item, new_slot) in [(item_a, slot_b), (item_b, slot_a)] {
+        if let Some(mut item) = item {
+            item.location = ItemLocation::Hotbar(HotbarLocationData { owner_id: sender_id, slot_index: new_slot });
+            inventory_items.instance_id().update(item);
+        }
+    }
+
+    let binding_a = find_binding(ctx, sender_id, slot_a);
+    let binding_b = find_binding(ctx, sender_id, slot_b);
+    for (binding, new_slot) in [(binding_a, slot_b), (binding_b, slot_a)] {
+        if let Some(mut binding) = binding {
+            binding.slot_index = new_slot;
+            ctx.db.hotbar_binding().id().update(binding);
+        }
+    }
+
+    log::debug!("[HotbarBinding] Player {:?} swapped hotbar slots {} and {}", sender_id, slot_a, slot_b);
+    Ok(())
+}
+
+/// --- Clear Hotbar Binding ---
+/// Releases a hotbar slot so any item type can be auto-placed in it again.
+#[spacetimedb::reducer]
+pub fn clear_hotbar_binding(ctx: &ReducerContext, slot_index: u8) -> Result<(), String> {
+    if slot_index >= NUM_PLAYER_HOTBAR_SLOTS {
+        return Err("Invalid hotbar slot index.".to_string());
+    }
+    unbind_hotbar_slot(ctx, ctx.sender(), slot_index);
+    Ok(())
+}
@@ -0,0 +1,179 @@
+/******************************************************************************
+ *                                                                            *
+ * Hunting blinds for stealth play. A blind is a small brush-covered hide:   *
+ * animals have a much shorter perception range for a player standing in     *
+ * one (on top of crouching and fox fur), and its screen walls block sight    *
+ * so hostile NPCs only spot someone inside when they get right up to it.    *
+ * The checks live in wild_animal_npc::core::find_detected_player.           *
+ * A blind can be picked back up by whoever placed it.                        *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::items::{
+    InventoryItem,
+    inventory_item as InventoryItemTableTrait,
+    item_definition as ItemDefinitionTableTrait,
+};
+use crate::player_inventory::find_first_empty_player_slot;
+use crate::environment::calculate_chunk_index;
+
+// --- Constants ---
+pub const HUNTING_BLIND_ITEM_NAME: &str = "Hunting Blind";
+
+/// Players within this distance of a blind's center are inside it
+pub const HUNTING_BLIND_RADIUS: f32 = 48.0;
+/// Animal perception range multiplier for a player inside a blind (stacks with crouch and fox fur)
+pub const HUNTING_BLIND_PERCEPTION_MULTIPLIER: f32 = 0.4;
+/// Hostile NPCs closer than this can see past the blind's walls
+pub const HUNTING_BLIND_HOSTILE_REVEAL_DISTANCE: f32 = 96.0;
+
+const HUNTING_BLIND_PLACEMENT_MAX_DISTANCE: f32 = 150.0;
+const HUNTING_BLIND_INTERACTION_DISTANCE_SQUARED: f32 = 120.0 * 120.0;
+
+/// --- Hunting Blind Data Structure ---
+#[spacetimedb::table(accessor = hunting_blind, public)]
+#[derive(Clone, Debug)]
+pub struct HuntingBlind {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub chunk_index: u32,
+    pub placed_by: Identity,
+    pub placed_at: Timestamp,
+}
+
+// --- Queries ---
+
+/// True if a position is inside a placed hunting blind
+pub fn is_position_in_hunting_blind(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    let chunk_index = calculate_chunk_index(pos_x, pos_y);
+    ctx.db.hunting_blind().chunk_index().filter(chunk_index)
+        .any(|blind| crate::utils::get_distance_squared(blind.pos_x, blind.pos_y, pos_x, pos_y)
+            <= HUNTING_BLIND_RADIUS * HUNTING_BLIND_RADIUS)
+}
+
+// --- Reducers ---
+
+/// --- Place Hunting Blind ---
+/// Places a hunting blind from the player's inventory.
+#[spacetimedb::reducer]
+pub fn place_hunting_blind(ctx: &ReducerContext, item_instance_id: u64, world_x: f32, world_y: f32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+
+    crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
+
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot place hunting blind while dead.".to_string());
+    }
+
+    // --- Validate item ---
+    let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+        .ok_or_else(|| "Item not found.".to_string())?;
+
+    if item.location.is_player_bound() != Some(sender_id) {
+        return Err("You don't own this item.".to_string());
+    }
+
+    let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
+        .ok_or_else(|| "Item definition not found.".to_string())?;
+
+    if item_def.name != HUNTING_BLIND_ITEM_NAME {
+        return Err("This item is not a hunting blind.".to_string());
+    }
+
+    // --- Validate placement position ---
+    let dx = world_x - player.position_x;
+    let dy = world_y - player.position_y;
+    if dx * dx + dy * dy > HUNTING_BLIND_PLACEMENT_MAX_DISTANCE * HUNTING_BLIND_PLACEMENT_MAX_DISTANCE {
+        return Err("Cannot place hunting blind that far away.".to_string());
+    }
+
+    if crate::environment::is_position_on_water(ctx, world_x, world_y) {
+        return Err("Cannot place hunting blind on water.".to_string());
+    }
+
+    if crate::building::is_position_on_wall(ctx, world_x, world_y) {
+        return Err("Cannot place hunting blind on a wall.".to_string());
+    }
+
+    crate::placeable_collision::check_placeable_overlap(ctx, world_x, world_y, 48.0, 48.0, true)?;
+
+    ctx.db.hunting_blind().try_insert(HuntingBlind {
+        id: 0, // Auto-increment
+        pos_x: world_x,
+        pos_y: world_y,
+        chunk_index: calculate_chunk_index(world_x, world_y),
+        placed_by: sender_id,
+        placed_at: ctx.timestamp,
+    }).map_err(|e| format!("Failed to place hunting blind: {}", e))?;
+
+    // Consume one hunting blind item
+    if item.quantity > 1 {
+        let mut updated_item = item.clone();
+        updated_item.quantity -= 1;
+        ctx.db.inventory_item().instance_id().update(updated_item);
+    } else {
+        ctx.db.inventory_item().instance_id().delete(&item_instance_id);
+    }
+
+    log::info!("Player {:?} placed hunting blind at ({:.1}, {:.1})", sender_id, world_x, world_y);
+    Ok(())
+}
+
+/// --- Pickup Hunting Blind ---
+/// Returns a hunting blind to the player who placed it.
+#[spacetimedb::reducer]
+pub fn pickup_hunting_blind(ctx: &ReducerContext, blind_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot pick up hunting blind while dead.".to_string());
+    }
+    if player.is_knocked_out {
+        return Err("Cannot pick up hunting blind while knocked out.".to_string());
+    }
+
+    let blind = ctx.db.hunting_blind().id().find(&blind_id)
+        .ok_or_else(|| format!("Hunting blind {} not found.", blind_id))?;
+
+    let dx = blind.pos_x - player.position_x;
+    let dy = blind.pos_y - player.position_y;
+    if dx * dx + dy * dy > HUNTING_BLIND_INTERACTION_DISTANCE_SQUARED {
+        return Err("Too far away from hunting blind.".to_string());
+    }
+    if blind.placed_by != sender_id {
+        return Err("You can only pick up your own hunting blinds.".to_string());
+    }
+
+    let item_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == HUNTING_BLIND_ITEM_NAME)
+        .ok_or_else(|| format!("{} item definition not found.", HUNTING_BLIND_ITEM_NAME))?;
+
+    let new_location = find_first_empty_player_slot(ctx, sender_id)
+        .ok_or_else(|| format!("Player inventory is full, cannot pickup {}.", HUNTING_BLIND_ITEM_NAME))?;
+
+    ctx.db.inventory_item().try_insert(InventoryItem {
+        instance_id: 0, // Auto-inc
+        item_def_id: item_def.id,
+        quantity: 1,
+        location: new_location,
+        item_data: None,
+    }).map_err(|e| format!("Failed to insert {} item: {}", HUNTING_BLIND_ITEM_NAME, e))?;
+
+    ctx.db.hunting_blind().id().delete(blind_id);
+
+    log::info!("Player {:?} picked up hunting blind {}", sender_id, blind_id);
+    Ok(())
+}
@@ -29,7 +29,6 @@ use spacetimedb::Table;
 /// Players within this radius are protected from PvP and hostile NPC attacks
 /// Similar to Fishing Village - a communal safe haven
 pub const HUNTING_VILLAGE_SAFE_ZONE_RADIUS: f32 = 600.0;
-pub const HUNTING_VILLAGE_SAFE_ZONE_RADIUS_SQ: f32 = HUNTING_VILLAGE_SAFE_ZONE_RADIUS * HUNTING_VILLAGE_SAFE_ZONE_RADIUS;
 
 /// NPC exclusion zone radius - matches building restriction radius
 /// Hostile NPCs will actively avoid entering this entire zone
@@ -49,26 +48,6 @@ pub const HUNTING_VILLAGE_ZONE_Y_OFFSET: f32 = 0.0;
 // HUNTING VILLAGE ZONE CHECKS
 // =============================================================================
 
-/// Checks if a position is within the hunting village safe zone
-/// Returns true if the position is within safe zone radius of the center piece (lodge)
-pub fn is_position_in_hunting_village_safe_zone(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
-    for part in ctx.db.monument_part().iter() {
-        // Only check against the center piece for zone determination
-        if part.monument_type != MonumentType::HuntingVillage || !part.is_center {
-            continue;
-        }
-        let dx = pos_x - part.world_x;
-        let dy = pos_y - (part.world_y - HUNTING_VILLAGE_ZONE_Y_OFFSET);
-        let dist_sq = dx * dx + dy * dy;
-        
-        if dist_sq <= HUNTING_VILLAGE_SAFE_ZONE_RADIUS_SQ {
-            return true;
-        }
-    }
-    
-    false
-}
-
 /// Gets the hunting village center position if it exists
 /// Returns Some((x, y)) of the village center (lodge), or None if not found
 pub fn get_hunting_village_center(ctx: &ReducerContext) -> Option<(f32, f32)> {
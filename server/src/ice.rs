@@ -0,0 +1,199 @@
+/******************************************************************************
+ *                                                                            *
+ * Sea & River Ice                                                            *
+ *                                                                            *
+ * Sea, river and lake tiles close to shore freeze over into walkable ice: *
+ * all shores in winter, and the shores of the arctic biomes all year round. *
+ * Ice is an overlay on top of the tile map (one ice_tile row per frozen     *
+ * tile), so freezing and thawing never touch the world chunks.              *
+ *                                                                            *
+ * Fresh ice comes with a few fishing holes. Sprinting onto ice can crack it *
+ * open, dropping the player into freezing water that drains warmth fast.    *
+ * Cracked ice freezes over again after a while.                              *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use rand::Rng;
+use log;
+
+use crate::world_chunk_data as WorldChunkDataTableTrait;
+use crate::player as PlayerTableTrait;
+use crate::world_state::Season;
+use crate::environment::calculate_chunk_index;
+use crate::{TileType, TILE_SIZE_PX, WORLD_WIDTH_TILES, WORLD_HEIGHT_TILES};
+
+// === CONSTANTS ===
+
+/// Sea and Freshwater tiles within this many tiles of land freeze
+pub const ICE_SHORE_DISTANCE_TILES: i32 = 2;
+/// Share of fresh ice that comes with a fishing hole
+pub const ICE_FISHING_HOLE_CHANCE: f32 = 0.04;
+/// Chance ice cracks when a sprinting player runs onto it
+pub const ICE_CRACK_CHANCE: f32 = 0.15;
+/// How long cracked ice stays open before it freezes over
+pub const ICE_HOLE_REFREEZE_SECS: i64 = 600;
+/// Extra warmth lost per second while in the water of an ice hole
+pub const ICE_WATER_WARMTH_DRAIN_PER_SECOND: f32 = 4.0;
+
+/// A frozen Sea or Freshwater tile. Public so clients can draw the ice over the water
+#[spacetimedb::table(
+    accessor = ice_tile,
+    public,
+    index(accessor = idx_ice_position, btree(columns = [tile_x, tile_y]))
+)]
+#[derive(Clone, Debug)]
+pub struct IceTile {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub tile_x: i32,
+    pub tile_y: i32,
+    #[index(btree)]
+    pub chunk_index: u32,
+    /// Frozen year-round (arctic coast) rather than only in winter
+    pub is_arctic: bool,
+    /// A fishing hole that stays open while the ice lasts
+    pub is_fishing_hole: bool,
+    /// When the ice here was cracked open (None = not cracked)
+    pub hole_opened_at: Option<Timestamp>,
+}
+
+impl IceTile {
+    /// Open water through the ice (fishing hole or cracked ice)
+    pub fn is_open(&self) -> bool {
+        self.is_fishing_hole || self.hole_opened_at.is_some()
+    }
+}
+
+fn find_ice_tile(ctx: &ReducerContext, tile_x: i32, tile_y: i32) -> Option<IceTile> {
+    ctx.db.ice_tile().idx_ice_position().filter((tile_x, tile_y)).next()
+}
+
+/// Whether a tile is covered by solid, walkable ice
+pub fn is_tile_frozen(ctx: &ReducerContext, tile_x: i32, tile_y: i32) -> bool {
+    find_ice_tile(ctx, tile_x, tile_y).is_some_and(|ice| !ice.is_open())
+}
+
+/// Whether a position is in the open water of a hole in the ice
+pub fn is_in_ice_hole(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    let (tile_x, tile_y) = crate::world_pos_to_tile_coords(pos_x, pos_y);
+    find_ice_tile(ctx, tile_x, tile_y).is_some_and(|ice| ice.is_open())
+}
+
+/// Rolls for the ice to crack under a player sprinting onto this tile
+pub fn roll_ice_crack(ctx: &ReducerContext, player_id: Identity, tile_x: i32, tile_y: i32) {
+    let Some(mut ice) = find_ice_tile(ctx, tile_x, tile_y) else {
+        return;
+    };
+    if ice.is_open() || ctx.rng().gen::<f32>() >= ICE_CRACK_CHANCE {
+        return;
+    }
+    ice.hole_opened_at = Some(ctx.timestamp);
+    ctx.db.ice_tile().id().update(ice);
+    log::info!("[Ice] Ice cracked under sprinting player {:?} at tile ({}, {})", player_id, tile_x, tile_y);
+}
+
+/// Freezes cracked ice over again once it has been open long enough
+pub fn refreeze_ice_holes(ctx: &ReducerContext) {
+    let refreeze_before = ctx.timestamp.to_micros_since_unix_epoch() - ICE_HOLE_REFREEZE_SECS * 1_000_000;
+    let refrozen: Vec<IceTile> = ctx.db.ice_tile().iter()
+        .filter(|ice| ice.hole_opened_at.is_some_and(|t| t.to_micros_since_unix_epoch() <= refreeze_before))
+        .collect();
+    for mut ice in refrozen {
+        // Don't seal a hole over someone swimming in it
+        let occupied = ctx.db.player().iter()
+            .filter(|p| !p.is_dead)
+            .any(|p| crate::world_pos_to_tile_coords(p.position_x, p.position_y) == (ice.tile_x, ice.tile_y));
+        if occupied {
+            continue;
+        }
+        ice.hole_opened_at = None;
+        ctx.db.ice_tile().id().update(ice);
+    }
+}
+
+/// Builds the ice for the current season on first startup
+pub fn init_ice_system(ctx: &ReducerContext, season: &Season) {
+    if ctx.db.ice_tile().iter().next().is_some() {
+        return;
+    }
+    update_ice_for_season(ctx, season);
+}
+
+/// Rebuilds the ice overlay for a season: every coast freezes in winter, only arctic coasts otherwise
+pub fn update_ice_for_season(ctx: &ReducerContext, season: &Season) {
+    let ice_ids: Vec<u64> = ctx.db.ice_tile().iter().map(|ice| ice.id).collect();
+    let thawed = ice_ids.len();
+    for ice_id in ice_ids {
+        ctx.db.ice_tile().id().delete(ice_id);
+    }
+
+    let tiles = load_tile_grid(ctx);
+    let width = WORLD_WIDTH_TILES as i32;
+    let height = WORLD_HEIGHT_TILES as i32;
+    let tile_at = |x: i32, y: i32| -> Option<TileType> {
+        if x < 0 || y < 0 || x >= width || y >= height {
+            return None;
+        }
+        tiles[(y * width + x) as usize].clone()
+    };
+
+    let mut rng = ctx.rng();
+    let mut frozen = 0;
+    for tile_y in 0..height {
+        for tile_x in 0..width {
+            if !matches!(tile_at(tile_x, tile_y), Some(TileType::Sea | TileType::Freshwater)) {
+                continue;
+            }
+            let mut near_land = false;
+            let mut near_arctic_land = false;
+            for dy in -ICE_SHORE_DISTANCE_TILES..=ICE_SHORE_DISTANCE_TILES {
+                for dx in -ICE_SHORE_DISTANCE_TILES..=ICE_SHORE_DISTANCE_TILES {
+                    match tile_at(tile_x + dx, tile_y + dy) {
+                        Some(TileType::Tundra | TileType::TundraGrass | TileType::Alpine) => {
+                            near_land = true;
+                            near_arctic_land = true;
+                        }
+                        Some(tile_type) if !tile_type.is_water() => near_land = true,
+                        _ => {}
+                    }
+                }
+            }
+            if !near_arctic_land && !(near_land && *season == Season::Winter) {
+                continue;
+            }
+
+            let center_x = (tile_x as f32 + 0.5) * TILE_SIZE_PX as f32;
+            let center_y = (tile_y as f32 + 0.5) * TILE_SIZE_PX as f32;
+            ctx.db.ice_tile().insert(IceTile {
+                id: 0,
+                tile_x,
+                tile_y,
+                chunk_index: calculate_chunk_index(center_x, center_y),
+                is_arctic: near_arctic_land,
+                is_fishing_hole: rng.gen::<f32>() < ICE_FISHING_HOLE_CHANCE,
+                hole_opened_at: None,
+            });
+            frozen += 1;
+        }
+    }
+    log::info!("[Ice] Ice rebuilt for {:?}: {} tiles thawed, {} tiles frozen", season, thawed, frozen);
+}
+
+/// The whole tile map decoded from the compressed chunks, indexed by y * width + x
+fn load_tile_grid(ctx: &ReducerContext) -> Vec<Option<TileType>> {
+    let width = WORLD_WIDTH_TILES as usize;
+    let mut tiles = vec![None; width * WORLD_HEIGHT_TILES as usize];
+    for chunk in ctx.db.world_chunk_data().iter() {
+        let chunk_size = chunk.chunk_size as usize;
+        for (index, tile_byte) in chunk.tile_types.iter().enumerate() {
+            let x = chunk.chunk_x as usize * chunk_size + index % chunk_size;
+            let y = chunk.chunk_y as usize * chunk_size + index / chunk_size;
+            if x < width && y < WORLD_HEIGHT_TILES as usize {
+                tiles[y * width + x] = TileType::from_u8(*tile_byte);
+            }
+        }
+    }
+    tiles
+}
@@ -20,7 +20,7 @@ use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as
 // Import new models
 use crate::models::{ItemLocation, ContainerType, EquipmentSlotType};
 // Import player inventory helpers
-use crate::player_inventory::{move_item_to_inventory, move_item_to_hotbar, find_first_empty_player_slot, NUM_PLAYER_INVENTORY_SLOTS, NUM_PLAYER_HOTBAR_SLOTS};
+use crate::player_inventory::{move_item_to_inventory, move_item_to_hotbar, find_empty_player_slot_for_item, NUM_PLAYER_INVENTORY_SLOTS, NUM_PLAYER_HOTBAR_SLOTS};
 // Import for clearing active item
 use crate::active_equipment;
 // Import for active_equipment table trait
@@ -1205,8 +1205,8 @@ pub(crate) fn handle_quick_move_from_container<C: ItemContainer>(
         return Ok(());
     }
     
-    // Some or all items remain - need to find a slot
-    let target_location_opt = find_first_empty_player_slot(ctx, sender_id);
+    // Some or all items remain - need to find a slot (the hotbar slot bound to this item type first)
+    let target_location_opt = find_empty_player_slot_for_item(ctx, sender_id, Some(source_def_id));
 
     if let Some(target_location) = target_location_opt {
         // Update source item quantity if partially stacked
@@ -1512,4 +1512,411 @@ pub(crate) fn handle_split_and_drop_from_container_slot<C: ItemContainer>(
              quantity_to_split, source_item_def.id, source_item_instance_id, container.get_container_type(), container.get_container_id(), slot_index, source_item.quantity);
     
     Ok(())
-}
\ No newline at end of file
+}
+// --- Container Handles (Generated Reducers) ---
+
+/// A client-requested container operation, passed to the `ContainerHandle` hooks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ContainerOp {
+    MoveTo { target_slot: u8, item_instance_id: u64 },
+    SplitInto { target_slot: u8, item_instance_id: u64 },
+    QuickMoveTo { item_instance_id: u64 },
+    MoveFrom { source_slot: u8 },
+    SplitFrom { source_slot: u8 },
+    QuickMoveFrom { source_slot: u8 },
+    Drop { source_slot: u8 },
+    SplitAndDrop { source_slot: u8 },
+    MoveWithin { source_slot: u8, target_slot: u8 },
+    SplitWithin { source_slot: u8, target_slot: u8 },
+    /// Several player items deposited at once; each is also checked as a `QuickMoveTo`
+    DepositMany,
+    /// Every slot taken at once; each is also checked as a `QuickMoveFrom`
+    TakeAll,
+    Sort,
+}
+
+impl ContainerOp {
+    /// The player item being put into the container, if any
+    pub fn incoming_item(&self) -> Option<u64> {
+        match *self {
+            ContainerOp::MoveTo { item_instance_id, .. }
+            | ContainerOp::SplitInto { item_instance_id, .. }
+            | ContainerOp::QuickMoveTo { item_instance_id } => Some(item_instance_id),
+            _ => None,
+        }
+    }
+
+    /// Whether items leave the container (to the player or the world)
+    pub fn removes_items(&self) -> bool {
+        matches!(self,
+            ContainerOp::MoveFrom { .. } | ContainerOp::SplitFrom { .. } | ContainerOp::QuickMoveFrom { .. }
+            | ContainerOp::Drop { .. } | ContainerOp::SplitAndDrop { .. } | ContainerOp::TakeAll)
+    }
+}
+
+/// A container table row the move/split reducers can be generated for (see `container_reducers!`).
+/// Every generated reducer loads the container, checks the operation, runs the generic handler,
+/// then applies the container's follow-ups and saves it.
+pub(crate) trait ContainerHandle: ItemContainer + Clone {
+    /// Loads the container and checks the sender may use it (distance, access rules)
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String>;
+
+    /// Writes the container row back, plus anything that must follow a content change (e.g. rescheduling)
+    fn save(&self, ctx: &ReducerContext);
+
+    /// Container-specific rules, checked before the operation runs (slot locks, allowed items)
+    fn check_operation(&self, _ctx: &ReducerContext, _op: &ContainerOp) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// State changes after a successful operation, saved with the container. `before` is the loaded row.
+    fn after_operation(&mut self, _ctx: &ReducerContext, _op: &ContainerOp, _before: &Self) {}
+
+    /// Side effects that need the saved row (sounds, despawning emptied containers)
+    fn after_saved(&self, _ctx: &ReducerContext, _op: &ContainerOp, _before: &Self) {}
+}
+
+/// Runs one container operation through the `ContainerHandle` hooks
+pub(crate) fn run_container_op<C: ContainerHandle>(
+    ctx: &ReducerContext,
+    container_id: u32,
+    op: ContainerOp,
+    action: impl FnOnce(&mut C, &Player) -> Result<(), String>,
+) -> Result<(), String> {
+    let (player, mut container) = C::load_for_interaction(ctx, container_id)?;
+    container.check_operation(ctx, &op)?;
+    let before = container.clone();
+    action(&mut container, &player)?;
+    container.after_operation(ctx, &op, &before);
+    container.save(ctx);
+    container.after_saved(ctx, &op, &before);
+    Ok(())
+}
+
+pub(crate) fn container_move_to<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, target_slot_index: u8, item_instance_id: u64) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::MoveTo { target_slot: target_slot_index, item_instance_id },
+        |c, _| handle_move_to_container_slot(ctx, c, target_slot_index, item_instance_id))
+}
+
+pub(crate) fn container_move_from<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, source_slot_index: u8, target_slot_type: String, target_slot_index: u32) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::MoveFrom { source_slot: source_slot_index },
+        |c, _| handle_move_from_container_slot(ctx, c, source_slot_index, target_slot_type, target_slot_index))
+}
+
+pub(crate) fn container_move_within<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, source_slot_index: u8, target_slot_index: u8) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::MoveWithin { source_slot: source_slot_index, target_slot: target_slot_index },
+        |c, _| handle_move_within_container(ctx, c, source_slot_index, target_slot_index))
+}
+
+pub(crate) fn container_split_into<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, target_slot_index: u8, source_item_instance_id: u64, quantity_to_split: u32) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::SplitInto { target_slot: target_slot_index, item_instance_id: source_item_instance_id },
+        |c, _| handle_split_into_container(ctx, c, target_slot_index, source_item_instance_id, quantity_to_split))
+}
+
+pub(crate) fn container_split_from<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, source_slot_index: u8, quantity_to_split: u32, target_slot_type: String, target_slot_index: u32) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::SplitFrom { source_slot: source_slot_index },
+        |c, _| handle_split_from_container(ctx, c, source_slot_index, quantity_to_split, target_slot_type, target_slot_index))
+}
+
+pub(crate) fn container_split_within<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, source_slot_index: u8, target_slot_index: u8, quantity_to_split: u32) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::SplitWithin { source_slot: source_slot_index, target_slot: target_slot_index },
+        |c, _| handle_split_within_container(ctx, c, source_slot_index, target_slot_index, quantity_to_split))
+}
+
+pub(crate) fn container_quick_move_to<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, item_instance_id: u64) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::QuickMoveTo { item_instance_id },
+        |c, _| handle_quick_move_to_container(ctx, c, item_instance_id))
+}
+
+pub(crate) fn container_quick_move_from<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, source_slot_index: u8) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::QuickMoveFrom { source_slot: source_slot_index },
+        |c, _| handle_quick_move_from_container(ctx, c, source_slot_index))
+}
+
+pub(crate) fn container_drop<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, slot_index: u8) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::Drop { source_slot: slot_index },
+        |c, player| handle_drop_from_container_slot(ctx, c, slot_index, player))
+}
+
+pub(crate) fn container_split_and_drop<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, slot_index: u8, quantity_to_split: u32) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::SplitAndDrop { source_slot: slot_index },
+        |c, player| handle_split_and_drop_from_container_slot(ctx, c, slot_index, quantity_to_split, player))
+}
+
+// --- Compound Container Operations ---
+// Each runs in a single reducer call, so a whole batch applies without other players'
+// moves interleaving. Items the container's rules reject are skipped.
+
+/// Instance IDs of the sender's inventory (and optionally hotbar) items matching `filter`, in slot order
+fn sender_item_ids(ctx: &ReducerContext, include_hotbar: bool, filter: impl Fn(&InventoryItem) -> bool) -> Vec<u64> {
+    let sender_id = ctx.sender();
+    let mut items: Vec<((u8, u16), u64)> = ctx.db.inventory_item().iter()
+        .filter_map(|item| {
+            let order = match &item.location {
+                ItemLocation::Inventory(data) if data.owner_id == sender_id => (0, data.slot_index),
+                ItemLocation::Hotbar(data) if include_hotbar && data.owner_id == sender_id => (1, data.slot_index as u16),
+                _ => return None,
+            };
+            filter(&item).then_some((order, item.instance_id))
+        })
+        .collect();
+    items.sort_by_key(|(order, _)| *order);
+    items.into_iter().map(|(_, instance_id)| instance_id).collect()
+}
+
+/// Quick-moves each player item into the container. Fails only if none of them could be moved.
+fn deposit_player_items<C: ContainerHandle>(ctx: &ReducerContext, container: &mut C, item_ids: Vec<u64>) -> Result<(), String> {
+    if item_ids.is_empty() {
+        return Err("You have no matching items to deposit.".to_string());
+    }
+    let total = item_ids.len();
+    let mut moved = 0;
+    let mut last_error = None;
+    for item_instance_id in item_ids {
+        let result = container.check_operation(ctx, &ContainerOp::QuickMoveTo { item_instance_id })
+            .and_then(|_| handle_quick_move_to_container(ctx, container, item_instance_id));
+        match result {
+            Ok(()) => moved += 1,
+            Err(e) => last_error = Some(e),
+        }
+    }
+    if moved == 0 {
+        return Err(last_error.unwrap_or_else(|| "No items could be deposited.".to_string()));
+    }
+    log::info!("[InvManager Deposit] Player {:?} deposited {}/{} stacks into container {:?} {}.",
+             ctx.sender(), moved, total, container.get_container_type(), container.get_container_id());
+    Ok(())
+}
+
+/// Moves every stack of one item type from the sender's inventory and hotbar into the container.
+pub(crate) fn container_move_all_of_type<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32, item_def_id: u64) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::DepositMany, |c, _| {
+        let item_ids = sender_item_ids(ctx, true, |item| item.item_def_id == item_def_id);
+        deposit_player_items(ctx, c, item_ids)
+    })
+}
+
+/// Moves inventory stacks whose item type is already in the container on top of those stacks.
+/// The hotbar is left alone so players keep their tools and weapons.
+pub(crate) fn container_deposit_matching<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::DepositMany, |c, _| {
+        let present: std::collections::HashSet<u64> = (0..c.num_slots() as u8).filter_map(|slot| c.get_slot_def_id(slot)).collect();
+        let item_ids = sender_item_ids(ctx, false, |item| present.contains(&item.item_def_id));
+        deposit_player_items(ctx, c, item_ids)
+    })
+}
+
+/// Quick-moves every container slot to the sender, stacking where possible.
+/// Stops filling once the player runs out of room; fails only if nothing was taken.
+pub(crate) fn container_take_all<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::TakeAll, |c, _| {
+        let mut taken = 0;
+        let mut last_error = None;
+        for slot in 0..c.num_slots() as u8 {
+            if c.get_slot_instance_id(slot).is_none() {
+                continue;
+            }
+            let result = c.check_operation(ctx, &ContainerOp::QuickMoveFrom { source_slot: slot })
+                .and_then(|_| handle_quick_move_from_container(ctx, c, slot));
+            match result {
+                Ok(()) => taken += 1,
+                Err(e) => last_error = Some(e),
+            }
+        }
+        match (taken, last_error) {
+            (0, Some(e)) => Err(e),
+            (0, None) => Err("Container is empty.".to_string()),
+            _ => Ok(()),
+        }
+    })
+}
+
+/// Category order used when sorting containers
+fn category_sort_order(category: &crate::items::ItemCategory) -> u8 {
+    use crate::items::ItemCategory;
+    match category {
+        ItemCategory::Material => 0,
+        ItemCategory::Consumable => 1,
+        ItemCategory::Ammunition => 2,
+        ItemCategory::Tool => 3,
+        ItemCategory::Weapon => 4,
+        ItemCategory::RangedWeapon => 5,
+        ItemCategory::Armor => 6,
+        ItemCategory::Placeable => 7,
+    }
+}
+
+/// Packs the container's items into the first slots, grouped by category then alphabetically by name.
+pub(crate) fn container_sort<C: ContainerHandle>(ctx: &ReducerContext, container_id: u32) -> Result<(), String> {
+    run_container_op::<C>(ctx, container_id, ContainerOp::Sort, |c, _| {
+        let num_slots = c.num_slots();
+        if num_slots <= 1 {
+            return Err("Container has too few slots to sort.".to_string());
+        }
+        let inventory_items = ctx.db.inventory_item();
+        let item_defs = ctx.db.item_definition();
+
+        // (instance ID, def ID, category order, item name) for every occupied slot
+        let mut items_with_meta: Vec<(u64, u64, u8, String)> = (0..num_slots as u8)
+            .filter_map(|slot| {
+                let (inst_id, def_id) = (c.get_slot_instance_id(slot)?, c.get_slot_def_id(slot)?);
+                let (order, name) = item_defs.id().find(def_id)
+                    .map(|def| (category_sort_order(&def.category), def.name.clone()))
+                    .unwrap_or_else(|| (0, format!("def_{}", def_id)));
+                Some((inst_id, def_id, order, name))
+            })
+            .collect();
+        items_with_meta.sort_by(|a, b| a.2.cmp(&b.2).then_with(|| a.3.cmp(&b.3)));
+
+        for slot in 0..num_slots as u8 {
+            c.set_slot(slot, None, None);
+        }
+        let container_type = c.get_container_type();
+        let container_id = c.get_container_id();
+        for (new_slot, (inst_id, def_id, _, _)) in items_with_meta.into_iter().enumerate() {
+            let new_slot = new_slot as u8;
+            c.set_slot(new_slot, Some(inst_id), Some(def_id));
+            if let Some(mut item) = inventory_items.instance_id().find(inst_id) {
+                item.location = ItemLocation::Container(crate::models::ContainerLocationData {
+                    container_type: container_type.clone(),
+                    container_id,
+                    slot_index: new_slot,
+                });
+                inventory_items.instance_id().update(item);
+            }
+        }
+        log::debug!("Player {:?} sorted container {:?} {}", ctx.sender(), container_type, container_id);
+        Ok(())
+    })
+}
+
+/// Generates the standard move/split reducers for a `ContainerHandle` type.
+/// Each entry is `kind: reducer_name(id_param)`; the kind fixes the remaining parameters
+/// (names and order are part of the client API). Kinds ending in `_item_first` /
+/// `_quantity_first` are the argument orders used by the cooking appliances.
+///
+/// ```ignore
+/// crate::container_reducers! {
+///     Stash;
+///     move_to: move_item_to_stash(stash_id),
+///     quick_move_from: quick_move_from_stash(stash_id),
+/// }
+/// ```
+#[macro_export]
+macro_rules! container_reducers {
+    ($container:ty; $($kind:ident : $name:ident ($id:ident)),* $(,)?) => {
+        $( $crate::container_reducers!(@$kind $container, $name, $id); )*
+    };
+    (@move_to $c:ty, $name:ident, $id:ident) => {
+        /// Moves an item from the player's inventory/hotbar into a container slot.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, target_slot_index: u8, item_instance_id: u64) -> Result<(), String> {
+            $crate::inventory_management::container_move_to::<$c>(ctx, $id, target_slot_index, item_instance_id)
+        }
+    };
+    (@move_from $c:ty, $name:ident, $id:ident) => {
+        /// Moves an item from a container slot to a specific player inventory/hotbar slot.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, source_slot_index: u8, target_slot_type: String, target_slot_index: u32) -> Result<(), String> {
+            $crate::inventory_management::container_move_from::<$c>(ctx, $id, source_slot_index, target_slot_type, target_slot_index)
+        }
+    };
+    (@move_within $c:ty, $name:ident, $id:ident) => {
+        /// Moves/merges/swaps an item between two slots of the same container.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, source_slot_index: u8, target_slot_index: u8) -> Result<(), String> {
+            $crate::inventory_management::container_move_within::<$c>(ctx, $id, source_slot_index, target_slot_index)
+        }
+    };
+    (@split_into $c:ty, $name:ident, $id:ident) => {
+        /// Splits a stack from the player's inventory/hotbar into a container slot.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, target_slot_index: u8, source_item_instance_id: u64, quantity_to_split: u32) -> Result<(), String> {
+            $crate::inventory_management::container_split_into::<$c>(ctx, $id, target_slot_index, source_item_instance_id, quantity_to_split)
+        }
+    };
+    (@split_into_item_first $c:ty, $name:ident, $id:ident) => {
+        /// Splits a stack from the player's inventory/hotbar into a container slot.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, source_item_instance_id: u64, quantity_to_split: u32, $id: u32, target_slot_index: u8) -> Result<(), String> {
+            $crate::inventory_management::container_split_into::<$c>(ctx, $id, target_slot_index, source_item_instance_id, quantity_to_split)
+        }
+    };
+    (@split_from $c:ty, $name:ident, $id:ident) => {
+        /// Splits a stack from a container slot to a specific player inventory/hotbar slot.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, source_slot_index: u8, quantity_to_split: u32, target_slot_type: String, target_slot_index: u32) -> Result<(), String> {
+            $crate::inventory_management::container_split_from::<$c>(ctx, $id, source_slot_index, quantity_to_split, target_slot_type, target_slot_index)
+        }
+    };
+    (@split_within $c:ty, $name:ident, $id:ident) => {
+        /// Splits a stack from one container slot to another in the same container.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, source_slot_index: u8, target_slot_index: u8, quantity_to_split: u32) -> Result<(), String> {
+            $crate::inventory_management::container_split_within::<$c>(ctx, $id, source_slot_index, target_slot_index, quantity_to_split)
+        }
+    };
+    (@split_within_quantity_first $c:ty, $name:ident, $id:ident) => {
+        /// Splits a stack from one container slot to another in the same container.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, source_slot_index: u8, quantity_to_split: u32, target_slot_index: u8) -> Result<(), String> {
+            $crate::inventory_management::container_split_within::<$c>(ctx, $id, source_slot_index, target_slot_index, quantity_to_split)
+        }
+    };
+    (@quick_move_to $c:ty, $name:ident, $id:ident) => {
+        /// Moves an item from the player's inventory/hotbar to the first mergeable/empty container slot.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, item_instance_id: u64) -> Result<(), String> {
+            $crate::inventory_management::container_quick_move_to::<$c>(ctx, $id, item_instance_id)
+        }
+    };
+    (@quick_move_from $c:ty, $name:ident, $id:ident) => {
+        /// Moves an item from a container slot to the first mergeable/empty player slot.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, source_slot_index: u8) -> Result<(), String> {
+            $crate::inventory_management::container_quick_move_from::<$c>(ctx, $id, source_slot_index)
+        }
+    };
+    (@drop $c:ty, $name:ident, $id:ident) => {
+        /// Drops the stack in a container slot into the world near the player.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, slot_index: u8) -> Result<(), String> {
+            $crate::inventory_management::container_drop::<$c>(ctx, $id, slot_index)
+        }
+    };
+    (@split_and_drop $c:ty, $name:ident, $id:ident) => {
+        /// Splits a quantity from a container slot and drops it into the world near the player.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, slot_index: u8, quantity_to_split: u32) -> Result<(), String> {
+            $crate::inventory_management::container_split_and_drop::<$c>(ctx, $id, slot_index, quantity_to_split)
+        }
+    };
+    (@move_all_of_type $c:ty, $name:ident, $id:ident) => {
+        /// Moves every stack of one item type from the player's inventory and hotbar into the container.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32, item_def_id: u64) -> Result<(), String> {
+            $crate::inventory_management::container_move_all_of_type::<$c>(ctx, $id, item_def_id)
+        }
+    };
+    (@deposit_matching $c:ty, $name:ident, $id:ident) => {
+        /// Deposits inventory stacks of every item type already in the container.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32) -> Result<(), String> {
+            $crate::inventory_management::container_deposit_matching::<$c>(ctx, $id)
+        }
+    };
+    (@take_all $c:ty, $name:ident, $id:ident) => {
+        /// Moves everything in the container to the player, as far as their inventory allows.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32) -> Result<(), String> {
+            $crate::inventory_management::container_take_all::<$c>(ctx, $id)
+        }
+    };
+    (@sort $c:ty, $name:ident, $id:ident) => {
+        /// Sorts the container's items by category, then alphabetically by name.
+        #[spacetimedb::reducer]
+        pub fn $name(ctx: &spacetimedb::ReducerContext, $id: u32) -> Result<(), String> {
+            $crate::inventory_management::container_sort::<$c>(ctx, $id)
+        }
+    };
+}
@@ -0,0 +1,309 @@
+/******************************************************************************
+ *                                                                            *
+ * Cosmetic item skins. A skin only changes how an item looks - damage,      *
+ * durability and all other stats come from the ItemDefinition as before.    *
+ * Skins are unlocked per player (via achievements or rare barrel drops)     *
+ * and applied per item instance through the `item_cosmetic` table.          *
+ * Skins only apply to non-stackable items (tools, weapons, armor), so the   *
+ * instance ID stays stable through moves; splits copy the skin and          *
+ * dropping an item into the world removes it (the unlock is kept).          *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use rand::Rng;
+use log;
+
+use crate::items::{
+    inventory_item as InventoryItemTableTrait,
+    item_definition as ItemDefinitionTableTrait,
+};
+use crate::player_progression::player_achievement as PlayerAchievementTableTrait;
+
+// --- Constants ---
+pub const SKIN_UNLOCK_SOURCE_ACHIEVEMENT: u8 = 0;
+pub const SKIN_UNLOCK_SOURCE_BARREL: u8 = 1;
+
+/// Chance that destroying a barrel unlocks a barrel-drop skin the player doesn't own yet
+pub const BARREL_SKIN_DROP_CHANCE: f32 = 0.01; // 1%
+
+/// SkinDefinition - seeded at init, read by the client for skin names and sprites
+#[spacetimedb::table(accessor = skin_definition, public)]
+#[derive(Clone, Debug)]
+pub struct SkinDefinition {
+    #[primary_key]
+    pub id: String,                            // e.g., "stone_hatchet_driftwood"
+    pub name: String,
+    pub description: String,
+    pub item_name: String,                     // ItemDefinition name this skin applies to
+    pub icon: String,                          // Replacement icon/sprite asset
+    pub unlock_achievement_id: Option<String>, // Unlocked by this achievement (None = not an achievement skin)
+    pub is_barrel_drop: bool,                  // Can be unlocked as a rare barrel drop
+}
+
+/// PlayerSkinUnlock - skins a player has unlocked
+#[spacetimedb::table(
+    accessor = player_skin_unlock,
+    public,
+    index(accessor = idx_player_skin_unlocks, name = "idx_player_skin_unlocks", btree(columns = [player_id]))
+)]
+#[derive(Clone, Debug)]
+pub struct PlayerSkinUnlock {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub player_id: Identity,
+    pub skin_id: String,
+    pub source: u8, // SKIN_UNLOCK_SOURCE_*
+    pub unlocked_at: Timestamp,
+}
+
+/// ItemCosmetic - skin applied to a specific InventoryItem instance
+#[spacetimedb::table(accessor = item_cosmetic, public)]
+#[derive(Clone, Debug)]
+pub struct ItemCosmetic {
+    #[primary_key]
+    pub item_instance_id: u64,
+    pub skin_id: String,
+    pub applied_by: Identity,
+    pub applied_at: Timestamp,
+}
+
+/// Initialize skin definitions (called in init_module)
+/// Upserts so new skins and text changes apply on republish
+pub fn seed_skin_definitions(ctx: &ReducerContext) -> Result<(), String> {
+    let skin_table = ctx.db.skin_definition();
+
+    let skins = vec![
+        SkinDefinition {
+            id: "stone_hatchet_driftwood".to_string(),
+            name: "Driftwood Hatchet".to_string(),
+            description: "Sea-bleached handle, salt-worn head".to_string(),
+            item_name: "Stone Hatchet".to_string(),
+            icon: "stone_hatchet_driftwood.png".to_string(),
+            unlock_achievement_id: Some("trees_250".to_string()),
+            is_barrel_drop: false,
+        },
+        SkinDefinition {
+            id: "stone_pickaxe_obsidian".to_string(),
+            name: "Obsidian Pickaxe".to_string(),
+            description: "Volcanic glass lashed to blackened wood".to_string(),
+            item_name: "Stone Pickaxe".to_string(),
+            icon: "stone_pickaxe_obsidian.png".to_string(),
+            unlock_achievement_id: Some("stones_250".to_string()),
+            is_barrel_drop: false,
+        },
+        SkinDefinition {
+            id: "hunting_bow_bone".to_string(),
+            name: "Bone Bow".to_string(),
+            description: "Carved from whale bone, strung with sinew".to_string(),
+            item_name: "Hunting Bow".to_string(),
+            icon: "hunting_bow_bone.png".to_string(),
+            unlock_achievement_id: Some("bow_kills_50".to_string()),
+            is_barrel_drop: false,
+        },
+        SkinDefinition {
+            id: "wooden_spear_tribal".to_string(),
+            name: "Tribal Spear".to_string(),
+            description: "Painted shaft wrapped in feathers".to_string(),
+            item_name: "Wooden Spear".to_string(),
+            icon: "wooden_spear_tribal.png".to_string(),
+            unlock_achievement_id: Some("spear_kills_25".to_string()),
+            is_barrel_drop: false,
+        },
+        SkinDefinition {
+            id: "combat_ladle_rusted".to_string(),
+            name: "Rusted Ladle".to_string(),
+            description: "Scavenged from a roadside barrel. Still serves soup".to_string(),
+            item_name: "Combat Ladle".to_string(),
+            icon: "combat_ladle_rusted.png".to_string(),
+            unlock_achievement_id: None,
+            is_barrel_drop: true,
+        },
+        SkinDefinition {
+            id: "stone_hatchet_military".to_string(),
+            name: "Military Surplus Hatchet".to_string(),
+            description: "Olive drab paint and a stenciled serial number".to_string(),
+            item_name: "Stone Hatchet".to_string(),
+            icon: "stone_hatchet_military.png".to_string(),
+            unlock_achievement_id: None,
+            is_barrel_drop: true,
+        },
+        SkinDefinition {
+            id: "hunting_bow_flotsam".to_string(),
+            name: "Flotsam Bow".to_string(),
+            description: "Pieced together from cargo crate slats".to_string(),
+            item_name: "Hunting Bow".to_string(),
+            icon: "hunting_bow_flotsam.png".to_string(),
+            unlock_achievement_id: None,
+            is_barrel_drop: true,
+        },
+    ];
+
+    let skin_count = skins.len();
+    for skin in skins {
+        if skin_table.id().find(&skin.id).is_some() {
+            skin_table.id().update(skin);
+        } else {
+            skin_table.insert(skin);
+        }
+    }
+
+    log::info!("Seeded {} skin definitions", skin_count);
+    Ok(())
+}
+
+// --- Helper Functions ---
+
+/// Checks if a player owns a skin (explicit unlock, or the skin's unlock achievement)
+pub fn player_has_skin(ctx: &ReducerContext, player_id: Identity, skin: &SkinDefinition) -> bool {
+    let explicitly_unlocked = ctx.db.player_skin_unlock()
+        .idx_player_skin_unlocks()
+        .filter(&player_id)
+        .any(|unlock| unlock.skin_id == skin.id);
+    if explicitly_unlocked {
+        return true;
+    }
+
+    // Achievements unlocked before skins existed still count
+    match &skin.unlock_achievement_id {
+        Some(achievement_id) => ctx.db.player_achievement()
+            .idx_player_achievements()
+            .filter(&player_id)
+            .any(|a| &a.achievement_id == achievement_id),
+        None => false,
+    }
+}
+
+/// Records a skin unlock for a player. Returns false if they already had it.
+fn unlock_skin_for_player(ctx: &ReducerContext, player_id: Identity, skin_id: &str, source: u8) -> bool {
+    let already_unlocked = ctx.db.player_skin_unlock()
+        .idx_player_skin_unlocks()
+        .filter(&player_id)
+        .any(|unlock| unlock.skin_id == skin_id);
+    if already_unlocked {
+        return false;
+    }
+
+    ctx.db.player_skin_unlock().insert(PlayerSkinUnlock {
+        id: 0,
+        player_id,
+        skin_id: skin_id.to_string(),
+        source,
+        unlocked_at: ctx.timestamp,
+    });
+    log::info!("Skin '{}' unlocked for player {} (source {})", skin_id, player_id, source);
+    true
+}
+
+/// Unlocks every skin tied to an achievement (called from player_progression on unlock)
+pub fn grant_achievement_skins(ctx: &ReducerContext, player_id: Identity, achievement_id: &str) {
+    let skin_ids: Vec<String> = ctx.db.skin_definition().iter()
+        .filter(|skin| skin.unlock_achievement_id.as_deref() == Some(achievement_id))
+        .map(|skin| skin.id)
+        .collect();
+
+    for skin_id in skin_ids {
+        unlock_skin_for_player(ctx, player_id, &skin_id, SKIN_UNLOCK_SOURCE_ACHIEVEMENT);
+    }
+}
+
+/// Rolls for a rare barrel skin drop when a player destroys a barrel
+pub fn try_roll_barrel_skin_drop(ctx: &ReducerContext, player_id: Identity) {
+    if ctx.rng().gen::<f32>() >= BARREL_SKIN_DROP_CHANCE {
+        return;
+    }
+
+    let candidates: Vec<SkinDefinition> = ctx.db.skin_definition().iter()
+        .filter(|skin| skin.is_barrel_drop && !player_has_skin(ctx, player_id, skin))
+        .collect();
+    if candidates.is_empty() {
+        return;
+    }
+
+    let skin = &candidates[ctx.rng().gen_range(0..candidates.len())];
+    unlock_skin_for_player(ctx, player_id, &skin.id, SKIN_UNLOCK_SOURCE_BARREL);
+}
+
+/// Copies an item's skin onto a new instance (used when splitting stacks)
+pub fn copy_item_cosmetic(ctx: &ReducerContext, source_instance_id: u64, new_instance_id: u64) {
+    let cosmetics = ctx.db.item_cosmetic();
+    if let Some(cosmetic) = cosmetics.item_instance_id().find(&source_instance_id) {
+        let copied = ItemCosmetic { item_instance_id: new_instance_id, ..cosmetic };
+        if cosmetics.item_instance_id().find(&new_instance_id).is_some() {
+            cosmetics.item_instance_id().update(copied);
+        } else {
+            cosmetics.insert(copied);
+        }
+    }
+}
+
+/// Removes any skin applied to an item instance (used when the instance is removed)
+pub fn clear_item_cosmetic(ctx: &ReducerContext, item_instance_id: u64) {
+    ctx.db.item_cosmetic().item_instance_id().delete(&item_instance_id);
+}
+
+// --- Reducers ---
+
+/// Applies an unlocked skin to an item the player owns
+#[spacetimedb::reducer]
+pub fn apply_skin(ctx: &ReducerContext, item_instance_id: u64, skin_id: String) -> Result<(), String> {
+    let sender_id = ctx.sender();
+
+    let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+        .ok_or_else(|| format!("Item instance {} not found.", item_instance_id))?;
+    if item.location.is_player_bound() != Some(sender_id) {
+        return Err("You don't own this item.".to_string());
+    }
+
+    let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
+        .ok_or_else(|| "Item definition not found.".to_string())?;
+    if item_def.is_stackable {
+        return Err("Skins can't be applied to stackable items.".to_string());
+    }
+
+    let skin = ctx.db.skin_definition().id().find(&skin_id)
+        .ok_or_else(|| format!("Skin '{}' not found.", skin_id))?;
+    if skin.item_name != item_def.name {
+        return Err(format!("{} can't be applied to {}.", skin.name, item_def.name));
+    }
+    if !player_has_skin(ctx, sender_id, &skin) {
+        return Err(format!("You haven't unlocked {}.", skin.name));
+    }
+
+    let cosmetic = ItemCosmetic {
+        item_instance_id,
+        skin_id: skin.id.clone(),
+        applied_by: sender_id,
+        applied_at: ctx.timestamp,
+    };
+    let cosmetics = ctx.db.item_cosmetic();
+    if cosmetics.item_instance_id().find(&item_instance_id).is_some() {
+        cosmetics.item_instance_id().update(cosmetic);
+    } else {
+        cosmetics.insert(cosmetic);
+    }
+
+    log::info!("Player {} applied skin '{}' to item {}", sender_id, skin.id, item_instance_id);
+    Ok(())
+}
+
+/// Restores an item's default appearance
+#[spacetimedb::reducer]
+pub fn remove_skin(ctx: &ReducerContext, item_instance_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+
+    let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+        .ok_or_else(|| format!("Item instance {} not found.", item_instance_id))?;
+    if item.location.is_player_bound() != Some(sender_id) {
+        return Err("You don't own this item.".to_string());
+    }
+
+    if ctx.db.item_cosmetic().item_instance_id().find(&item_instance_id).is_none() {
+        return Err("This item has no skin applied.".to_string());
+    }
+    clear_item_cosmetic(ctx, item_instance_id);
+
+    log::info!("Player {} removed skin from item {}", sender_id, item_instance_id);
+    Ok(())
+}
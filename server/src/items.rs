@@ -121,6 +121,7 @@ pub struct ItemDefinition {
     pub crafting_output_quantity: Option<u32>,      // How many items this recipe produces
     pub crafting_time_secs: Option<u32>,            // Time in seconds to craft
     pub requires_station: Option<String>,           // Name of station required for crafting (e.g., "Cooking Station")
+    pub required_workbench_tier: Option<u8>,        // Workbench tier needed nearby to craft (None = no bench)
 
     // Consumable Effects
     pub consumable_health_gain: Option<f32>,
@@ -185,6 +186,11 @@ pub struct ItemDefinition {
     pub bleed_chance_on_melee: Option<f32>, // 0.1 = 10% chance to inflict bleed on melee hit
     pub reduces_animal_detection: Option<f32>, // 0.2 = -20% animal detection radius
     pub melee_damage_bonus: Option<f32>, // 0.15 = +15% melee damage bonus
+    
+    // CLOTHING INSULATION (temperature model - see temperature.rs)
+    pub insulation: Option<f32>, // 0.2 = blocks 20% of cold loss (pieces stack multiplicatively)
+    pub wetness_penalty: Option<f32>, // Share of insulation lost while wet (None = 0.5, wet clothing halves insulation)
+    pub environmental_protection: Vec<crate::models::EnvironmentalHazard>, // Hazards this piece protects against on its own
 }
 
 // --- Inventory Table ---
@@ -267,6 +273,11 @@ pub fn seed_ranged_weapon_stats(ctx: &ReducerContext) -> Result<(), String> {
             magazine_capacity: 0,      // Single-shot (arrows loaded one at a time)
             is_automatic: false,       // Semi-auto - must click for each shot
             magazine_reload_time_secs: 0.0, // Instant nock - no reload animation
+            spread_base_deg: 1.5,      // Slight hand shake, settles quickly
+            spread_per_shot_deg: 1.0,
+            spread_max_deg: 5.0,
+            spread_recovery_secs: 0.8,
+            draw_time_secs: 1.0,       // Full draw in 1s - release early for a weaker, slower arrow
         },
         
         // TIER 2: Crossbow (Mid Game)
@@ -286,6 +297,11 @@ pub fn seed_ranged_weapon_stats(ctx: &ReducerContext) -> Result<(), String> {
             magazine_capacity: 0,      // Single-shot (bolts loaded one at a time)
             is_automatic: false,       // Semi-auto - must click for each shot
             magazine_reload_time_secs: 2.0, // 2 seconds to crank crossbow string (right-click)
+            spread_base_deg: 0.5,      // Rested stock - tightest first shot
+            spread_per_shot_deg: 1.5,
+            spread_max_deg: 4.0,
+            spread_recovery_secs: 1.0,
+            draw_time_secs: 0.0,       // Bolt is pre-loaded by the crank - no draw
         },
         
         // TIER 3: Makarov PM (Late Game)
@@ -304,6 +320,11 @@ pub fn seed_ranged_weapon_stats(ctx: &ReducerContext) -> Result<(), String> {
             magazine_capacity: 8,      // 8-round magazine
             is_automatic: false,       // Semi-auto pistol - must click for each shot
             magazine_reload_time_secs: 2.5, // 2.5 seconds to swap magazine
+            spread_base_deg: 1.0,      // Snappy recoil punishes fast follow-ups
+            spread_per_shot_deg: 1.5,
+            spread_max_deg: 6.0,
+            spread_recovery_secs: 0.6,
+            draw_time_secs: 0.0,
         },
         
         // TIER 4: PP-91 KEDR (Endgame)
@@ -323,6 +344,11 @@ pub fn seed_ranged_weapon_stats(ctx: &ReducerContext) -> Result<(), String> {
             magazine_capacity: 30,     // 30-round magazine
             is_automatic: true,        // FULL AUTO - hold mouse button to fire continuously
             magazine_reload_time_secs: 3.0, // 3 seconds to swap larger magazine
+            spread_base_deg: 2.0,      // Sustained bursts climb toward the cap
+            spread_per_shot_deg: 0.8,
+            spread_max_deg: 10.0,
+            spread_recovery_secs: 0.25,
+            draw_time_secs: 0.0,
         },
         
         // UNDERWATER: Reed Harpoon Gun
@@ -342,6 +368,11 @@ pub fn seed_ranged_weapon_stats(ctx: &ReducerContext) -> Result<(), String> {
             magazine_capacity: 2,      // 2-dart magazine - limited capacity
             is_automatic: false,       // Semi-auto - must click for each shot
             magazine_reload_time_secs: 2.0, // 2 seconds to reload darts
+            spread_base_deg: 1.0,      // Pneumatic kick between darts
+            spread_per_shot_deg: 1.0,
+            spread_max_deg: 4.0,
+            spread_recovery_secs: 1.0,
+            draw_time_secs: 0.0,
         },
 
         // Grenade - self-ammo throwable, straight-line, no bounce
@@ -354,6 +385,11 @@ pub fn seed_ranged_weapon_stats(ctx: &ReducerContext) -> Result<(), String> {
             magazine_capacity: 0,
             is_automatic: false,
             magazine_reload_time_secs: 0.0,
+            spread_base_deg: 0.0,
+            spread_per_shot_deg: 0.0,
+            spread_max_deg: 0.0,
+            spread_recovery_secs: 1.0,
+            draw_time_secs: 0.0,
         },
 
         // Flare - self-ammo throwable, straight-line, no bounce
@@ -366,6 +402,11 @@ pub fn seed_ranged_weapon_stats(ctx: &ReducerContext) -> Result<(), String> {
             magazine_capacity: 0,
             is_automatic: false,
             magazine_reload_time_secs: 0.0,
+            spread_base_deg: 0.0,
+            spread_per_shot_deg: 0.0,
+            spread_max_deg: 0.0,
+            spread_recovery_secs: 1.0,
+            draw_time_secs: 0.0,
         },
     ];
 
@@ -383,6 +424,11 @@ pub fn seed_ranged_weapon_stats(ctx: &ReducerContext) -> Result<(), String> {
                 || existing.magazine_capacity != stats.magazine_capacity
                 || existing.is_automatic != stats.is_automatic
                 || existing.magazine_reload_time_secs != stats.magazine_reload_time_secs
+                || existing.spread_base_deg != stats.spread_base_deg
+                || existing.spread_per_shot_deg != stats.spread_per_shot_deg
+                || existing.spread_max_deg != stats.spread_max_deg
+                || existing.spread_recovery_secs != stats.spread_recovery_secs
+                || existing.draw_time_secs != stats.draw_time_secs
             {
                 log::info!("Updating ranged stats for '{}': range {:.0}->{:.0}, reload_time={:.1}s, magazine_reload={:.1}s", 
                     stats.item_name, existing.weapon_range, stats.weapon_range, 
@@ -423,71 +469,74 @@ pub fn seed_food_poisoning_risks(ctx: &ReducerContext) -> Result<(), String> {
     let item_defs = ctx.db.item_definition();
     let mut seeded_count = 0;
 
-    // Define food poisoning risks: (item_name, chance%, damage_per_tick, duration_secs, tick_interval_secs)
+    // Define food poisoning risks: (item_name, chance%, damage_per_tick, duration_secs, tick_interval_secs, dysentery_chance%)
+    // Toxins don't progress to dysentery - only infections from raw meat, shellfish, scavengers and milk do
     // Organized by risk category for clarity
     let food_risks_data = vec![
         // === EXTREMELY DANGEROUS (Toxic/Poisonous) ===
-        ("Raw Human Flesh", 100.0, 2.0, 15.0, 1.5),      // 100% - prion disease, always dangerous
-        ("Destroying Angel", 95.0, 4.0, 20.0, 1.0),      // 95% - one of deadliest mushrooms, severe liver damage
-        ("Deadly Webcap", 90.0, 3.5, 18.0, 1.0),         // 90% - deadly nephrotoxic mushroom
-        ("Fly Agaric", 75.0, 2.5, 12.0, 1.5),            // 75% - toxic psychoactive, can cause organ damage
-        ("Henbane", 70.0, 2.0, 10.0, 1.5),               // 70% - toxic plant with dangerous alkaloids
+        ("Raw Human Flesh", 100.0, 2.0, 15.0, 1.5, 40.0),      // 100% - prion disease, always dangerous
+        ("Destroying Angel", 95.0, 4.0, 20.0, 1.0, 0.0),      // 95% - one of deadliest mushrooms, severe liver damage
+        ("Deadly Webcap", 90.0, 3.5, 18.0, 1.0, 0.0),         // 90% - deadly nephrotoxic mushroom
+        ("Fly Agaric", 75.0, 2.5, 12.0, 1.5, 0.0),            // 75% - toxic psychoactive, can cause organ damage
+        ("Henbane", 70.0, 2.0, 10.0, 1.5, 0.0),               // 70% - toxic plant with dangerous alkaloids
         
         // === HIGH RISK (Raw Predator/Scavenger Meat - Parasites, Trichinosis) ===
-        ("Raw Bear Meat", 45.0, 1.5, 12.0, 2.0),         // 45% - high trichinosis risk in bears
-        ("Raw Wolverine Meat", 40.0, 1.5, 10.0, 2.0),    // 40% - scavenger, high parasite load
-        ("Raw Wolf Meat", 35.0, 1.2, 10.0, 2.0),         // 35% - predator meat, parasites common
-        ("Raw Fox Meat", 30.0, 1.0, 8.0, 2.0),           // 30% - wild canid, parasites
-        ("Raw Walrus Meat", 30.0, 1.2, 10.0, 2.0),       // 30% - trichinosis risk in marine mammals
+        ("Raw Bear Meat", 45.0, 1.5, 12.0, 2.0, 25.0),         // 45% - high trichinosis risk in bears
+        ("Raw Wolverine Meat", 40.0, 1.5, 10.0, 2.0, 30.0),    // 40% - scavenger, high parasite load
+        ("Raw Wolf Meat", 35.0, 1.2, 10.0, 2.0, 20.0),         // 35% - predator meat, parasites common
+        ("Raw Fox Meat", 30.0, 1.0, 8.0, 2.0, 20.0),           // 30% - wild canid, parasites
+        ("Raw Walrus Meat", 30.0, 1.2, 10.0, 2.0, 20.0),       // 30% - trichinosis risk in marine mammals
         
         // === MEDIUM-HIGH RISK (Raw Seafood - Bacteria, Parasites) ===
-        ("Raw Sea Urchin", 25.0, 1.0, 8.0, 2.0),         // 25% - raw roe can harbor bacteria
-        ("Raw Blue Mussel", 30.0, 1.2, 10.0, 2.0),       // 30% - filter feeders accumulate toxins/bacteria
-        ("Raw Black Katy Chiton", 25.0, 1.0, 8.0, 2.0),  // 25% - raw shellfish risk
-        ("Raw Crab Meat", 20.0, 0.8, 6.0, 2.0),          // 20% - less risky but still raw shellfish
+        ("Raw Sea Urchin", 25.0, 1.0, 8.0, 2.0, 15.0),         // 25% - raw roe can harbor bacteria
+        ("Raw Blue Mussel", 30.0, 1.2, 10.0, 2.0, 30.0),       // 30% - filter feeders accumulate toxins/bacteria
+        ("Raw Black Katy Chiton", 25.0, 1.0, 8.0, 2.0, 20.0),  // 25% - raw shellfish risk
+        ("Raw Crab Meat", 20.0, 0.8, 6.0, 2.0, 15.0),          // 20% - less risky but still raw shellfish
         
         // === MEDIUM RISK (Raw Fish - Parasites, Must Be Prepared Properly) ===
-        ("Raw Halibut", 20.0, 0.8, 6.0, 2.0),            // 20% - large bottom fish, parasites
-        ("Raw King Salmon", 18.0, 0.7, 6.0, 2.0),        // 18% - salmon can have parasites
-        ("Raw Sockeye Salmon", 18.0, 0.7, 6.0, 2.0),     // 18% - salmon parasites
-        ("Raw Pink Salmon", 18.0, 0.7, 6.0, 2.0),        // 18% - salmon parasites
-        ("Raw Steelhead", 15.0, 0.6, 5.0, 2.0),          // 15% - trout family
-        ("Raw Pacific Cod", 15.0, 0.6, 5.0, 2.0),        // 15% - white fish, anisakis risk
-        ("Raw Rockfish", 15.0, 0.6, 5.0, 2.0),           // 15% - deep water fish
-        ("Raw Dolly Varden", 12.0, 0.5, 5.0, 2.0),       // 12% - char family
-        ("Raw Sculpin", 12.0, 0.5, 5.0, 2.0),            // 12% - spiny fish, bacteria
-        ("Raw Greenling", 10.0, 0.5, 4.0, 2.0),          // 10% - coastal fish
-        ("Raw Herring", 10.0, 0.5, 4.0, 2.0),            // 10% - small oily fish
-        ("Raw Smelt", 8.0, 0.4, 4.0, 2.0),               // 8% - small fish, lower risk
-        ("Raw Twigfish", 8.0, 0.4, 4.0, 2.0),            // 8% - small bony fish
-        ("Raw Shark Meat", 25.0, 1.0, 8.0, 2.0),         // 25% - can accumulate mercury/toxins
+        ("Raw Halibut", 20.0, 0.8, 6.0, 2.0, 10.0),            // 20% - large bottom fish, parasites
+        ("Raw King Salmon", 18.0, 0.7, 6.0, 2.0, 10.0),        // 18% - salmon can have parasites
+        ("Raw Sockeye Salmon", 18.0, 0.7, 6.0, 2.0, 10.0),     // 18% - salmon parasites
+        ("Raw Pink Salmon", 18.0, 0.7, 6.0, 2.0, 10.0),        // 18% - salmon parasites
+        ("Raw Steelhead", 15.0, 0.6, 5.0, 2.0, 8.0),          // 15% - trout family
+        ("Raw Pacific Cod", 15.0, 0.6, 5.0, 2.0, 8.0),        // 15% - white fish, anisakis risk
+        ("Raw Rockfish", 15.0, 0.6, 5.0, 2.0, 8.0),           // 15% - deep water fish
+        ("Raw Dolly Varden", 12.0, 0.5, 5.0, 2.0, 8.0),       // 12% - char family
+        ("Raw Northern Pike", 12.0, 0.5, 5.0, 2.0, 8.0),      // 12% - lake fish, tapeworm risk
+        ("Raw Arctic Grayling", 10.0, 0.5, 4.0, 2.0, 5.0),    // 10% - cold clear rivers
+        ("Raw Sculpin", 12.0, 0.5, 5.0, 2.0, 10.0),            // 12% - spiny fish, bacteria
+        ("Raw Greenling", 10.0, 0.5, 4.0, 2.0, 5.0),          // 10% - coastal fish
+        ("Raw Herring", 10.0, 0.5, 4.0, 2.0, 5.0),            // 10% - small oily fish
+        ("Raw Smelt", 8.0, 0.4, 4.0, 2.0, 5.0),               // 8% - small fish, lower risk
+        ("Raw Twigfish", 8.0, 0.4, 4.0, 2.0, 5.0),            // 8% - small bony fish
+        ("Raw Shark Meat", 25.0, 1.0, 8.0, 2.0, 10.0),         // 25% - can accumulate mercury/toxins
         
         // === MEDIUM RISK (Raw Game Meat - Parasites) ===
-        ("Raw Caribou Meat", 20.0, 0.8, 6.0, 2.0),       // 20% - wild game, parasites possible
-        ("Raw Hare Meat", 15.0, 0.6, 5.0, 2.0),          // 15% - tularemia risk in hares
-        ("Raw Viper Meat", 15.0, 0.6, 5.0, 2.0),         // 15% - snake parasites, bacteria
+        ("Raw Caribou Meat", 20.0, 0.8, 6.0, 2.0, 15.0),       // 20% - wild game, parasites possible
+        ("Raw Hare Meat", 15.0, 0.6, 5.0, 2.0, 15.0),          // 15% - tularemia risk in hares
+        ("Raw Viper Meat", 15.0, 0.6, 5.0, 2.0, 15.0),         // 15% - snake parasites, bacteria
         
         // === LOW-MEDIUM RISK (Raw Birds - Salmonella, Parasites) ===
-        ("Raw Owl Meat", 18.0, 0.7, 6.0, 2.0),           // 18% - raptor, accumulates toxins from prey
-        ("Raw Crow Meat", 15.0, 0.6, 5.0, 2.0),          // 15% - scavenger bird, bacteria
-        ("Raw Tern Meat", 12.0, 0.5, 4.0, 2.0),          // 12% - seabird
-        ("Raw Vole Meat", 10.0, 0.4, 4.0, 2.0),          // 10% - tiny, lower risk if fresh
+        ("Raw Owl Meat", 18.0, 0.7, 6.0, 2.0, 15.0),           // 18% - raptor, accumulates toxins from prey
+        ("Raw Crow Meat", 15.0, 0.6, 5.0, 2.0, 25.0),          // 15% - scavenger bird, bacteria
+        ("Raw Tern Meat", 12.0, 0.5, 4.0, 2.0, 10.0),          // 12% - seabird
+        ("Raw Vole Meat", 10.0, 0.4, 4.0, 2.0, 15.0),          // 10% - tiny, lower risk if fresh
         
         // === LOW RISK (Irritating/Mildly Risky Plants) ===
-        ("Nettle Leaves", 20.0, 0.8, 6.0, 2.0),          // 20% - formic acid stings, irritation
-        ("Shaggy Ink Cap", 15.0, 0.6, 5.0, 2.0),         // 15% - must be eaten fresh, deteriorates
-        ("Seaweed", 10.0, 0.4, 4.0, 2.0),                // 10% - raw ocean bacteria
-        ("Raw Milk", 8.0, 0.4, 4.0, 2.0),                // 8% - unpasteurized, bacteria risk
+        ("Nettle Leaves", 20.0, 0.8, 6.0, 2.0, 0.0),          // 20% - formic acid stings, irritation
+        ("Shaggy Ink Cap", 15.0, 0.6, 5.0, 2.0, 5.0),         // 15% - must be eaten fresh, deteriorates
+        ("Seaweed", 10.0, 0.4, 4.0, 2.0, 10.0),                // 10% - raw ocean bacteria
+        ("Raw Milk", 8.0, 0.4, 4.0, 2.0, 20.0),                // 8% - unpasteurized, bacteria risk
         
         // === VERY LOW RISK (Raw Vegetables - Minimal But Possible) ===
-        ("Raw Corn", 5.0, 0.5, 6.0, 2.0),                // 5% - generally safe raw
-        ("Raw Potato", 4.0, 0.4, 4.0, 2.0),              // 4% - solanine in green parts
-        ("Pumpkin", 3.0, 0.3, 3.0, 2.0),                 // 3% - generally safe raw
-        ("Chanterelle", 5.0, 0.4, 4.0, 2.0),             // 5% - safe mushroom but raw is risky
-        ("Porcini", 5.0, 0.4, 4.0, 2.0),                 // 5% - safe mushroom but better cooked
+        ("Raw Corn", 5.0, 0.5, 6.0, 2.0, 0.0),                // 5% - generally safe raw
+        ("Raw Potato", 4.0, 0.4, 4.0, 2.0, 0.0),              // 4% - solanine in green parts
+        ("Pumpkin", 3.0, 0.3, 3.0, 2.0, 0.0),                 // 3% - generally safe raw
+        ("Chanterelle", 5.0, 0.4, 4.0, 2.0, 0.0),             // 5% - safe mushroom but raw is risky
+        ("Porcini", 5.0, 0.4, 4.0, 2.0, 0.0),                 // 5% - safe mushroom but better cooked
     ];
 
-    for (item_name, chance_percent, damage_per_tick, duration_seconds, tick_interval_seconds) in food_risks_data {
+    for (item_name, chance_percent, damage_per_tick, duration_seconds, tick_interval_seconds, dysentery_chance_percent) in food_risks_data {
         // Find the item definition by name
         if let Some(item_def) = item_defs.iter().find(|def| def.name == item_name) {
             let food_risk = crate::active_effects::FoodPoisoningRisk {
@@ -496,6 +545,7 @@ pub fn seed_food_poisoning_risks(ctx: &ReducerContext) -> Result<(), String> {
                 damage_per_tick,
                 duration_seconds,
                 tick_interval_seconds,
+                dysentery_chance_percent,
             };
 
             match food_risks.try_insert(food_risk) {
@@ -517,6 +567,7 @@ pub fn seed_food_poisoning_risks(ctx: &ReducerContext) -> Result<(), String> {
     const SPOILED_DAMAGE_PER_TICK: f32 = 2.0;
     const SPOILED_DURATION: f32 = 15.0;
     const SPOILED_TICK_INTERVAL: f32 = 1.5;
+    const SPOILED_DYSENTERY_CHANCE: f32 = 40.0;
     for item_def in item_defs.iter() {
         if item_def.name.starts_with("Spoiled ") {
             // Skip if already seeded (e.g. raw human flesh might overlap)
@@ -529,6 +580,7 @@ pub fn seed_food_poisoning_risks(ctx: &ReducerContext) -> Result<(), String> {
                 damage_per_tick: SPOILED_DAMAGE_PER_TICK,
                 duration_seconds: SPOILED_DURATION,
                 tick_interval_seconds: SPOILED_TICK_INTERVAL,
+                dysentery_chance_percent: SPOILED_DYSENTERY_CHANCE,
             };
             if let Ok(_) = food_risks.try_insert(food_risk) {
                 seeded_count += 1;
@@ -681,7 +733,7 @@ pub(crate) fn add_item_to_player_inventory(ctx: &ReducerContext, player_id: Iden
             })
             .collect();
 
-        if let Some(empty_hotbar_slot) = (0..crate::player_inventory::NUM_PLAYER_HOTBAR_SLOTS as u8).find(|slot| !occupied_hotbar_slots.contains(slot)) {
+        if let Some(empty_hotbar_slot) = crate::hotbar_binding::choose_empty_hotbar_slot(ctx, player_id, Some(item_def_id), &occupied_hotbar_slots) {
             let new_item = InventoryItem {
                 instance_id: 0, 
                 item_def_id,
@@ -690,6 +742,7 @@ pub(crate) fn add_item_to_player_inventory(ctx: &ReducerContext, player_id: Iden
                 item_data: None, // Initialize as empty
             };
             let inserted_item = inventory.insert(new_item);
+            crate::hotbar_binding::bind_hotbar_slot_if_unbound(ctx, player_id, empty_hotbar_slot, item_def_id);
             log::info!("[AddItem] Added {} of item def {} to hotbar slot {} for player {:?}. New ID: {}",
                      final_quantity_to_add, item_def_id, empty_hotbar_slot, player_id, inserted_item.instance_id);
             return Ok(Some(inserted_item.instance_id));
@@ -807,7 +860,7 @@ pub(crate) fn add_item_to_player_inventory_with_data(
             })
             .collect();
 
-        if let Some(empty_hotbar_slot) = (0..crate::player_inventory::NUM_PLAYER_HOTBAR_SLOTS as u8).find(|slot| !occupied_hotbar_slots.contains(slot)) {
+        if let Some(empty_hotbar_slot) = crate::hotbar_binding::choose_empty_hotbar_slot(ctx, player_id, Some(item_def_id), &occupied_hotbar_slots) {
             let new_item = InventoryItem {
                 instance_id: 0, 
                 item_def_id,
@@ -816,6 +869,7 @@ pub(crate) fn add_item_to_player_inventory_with_data(
                 item_data: item_data.clone(), // Preserve the item data
             };
             let inserted_item = inventory.insert(new_item);
+            crate::hotbar_binding::bind_hotbar_slot_if_unbound(ctx, player_id, empty_hotbar_slot, item_def_id);
             log::info!("[AddItemWithData] Added {} of item def {} to hotbar slot {} for player {:?}. New ID: {} (with data: {})",
                      final_quantity_to_add, item_def_id, empty_hotbar_slot, player_id, inserted_item.instance_id, 
                      item_data.is_some());
@@ -1368,6 +1422,21 @@ pub(crate) fn calculate_merge_result(
     Ok((qty_to_transfer, source_new_qty, target_new_qty, delete_source))
 }
 
+/// Checks a client-chosen split amount: the item must stack, and the split must leave
+/// at least one item behind (1..=quantity-1).
+pub(crate) fn validate_split_quantity(item_def: &ItemDefinition, source_item: &InventoryItem, quantity_to_split: u32) -> Result<(), String> {
+    if !item_def.is_stackable {
+        return Err(format!("Item '{}' is not stackable.", item_def.name));
+    }
+    if quantity_to_split == 0 {
+        return Err("Cannot split a quantity of 0.".to_string());
+    }
+    if quantity_to_split >= source_item.quantity {
+        return Err(format!("Cannot split {} items, only {} available.", quantity_to_split, source_item.quantity));
+    }
+    Ok(())
+}
+
 // Renamed helper function
 pub(crate) fn split_stack_helper(
     ctx: &ReducerContext,
@@ -1375,10 +1444,10 @@ pub(crate) fn split_stack_helper(
     quantity_to_split: u32,
     initial_location_for_new_item: ItemLocation // Explicitly pass the initial location for the new stack
 ) -> Result<u64, String> {
-    // Validations already done in reducers calling this, but sanity check:
-    if quantity_to_split == 0 || quantity_to_split >= source_item.quantity {
-        return Err("Invalid split quantity".to_string());
-    }
+    // Every split reducer ends up here, so the requested quantity is validated in one place
+    let item_def = ctx.db.item_definition().id().find(source_item.item_def_id)
+        .ok_or_else(|| format!("Definition not found for item ID {}", source_item.item_def_id))?;
+    validate_split_quantity(&item_def, source_item, quantity_to_split)?;
 
     // Decrease quantity of the source item
     source_item.quantity -= quantity_to_split;
@@ -1396,6 +1465,9 @@ pub(crate) fn split_stack_helper(
     let inserted_item = ctx.db.inventory_item().insert(new_item);
     let new_instance_id = inserted_item.instance_id;
 
+    // Keep the cosmetic skin on both halves
+    crate::item_cosmetic::copy_item_cosmetic(ctx, source_item.instance_id, new_instance_id);
+
     log::info!(
         "[SplitStack Helper] Split {} from item {}. New stack ID: {}. Original stack qty: {}. New item location: {:?}",
         quantity_to_split, source_item.instance_id, new_instance_id, source_item.quantity, initial_location_for_new_item
@@ -1488,6 +1560,8 @@ pub fn drop_item(
         
         clear_item_from_source_location(ctx, item_instance_id)?;
         inventory_items.instance_id().delete(item_instance_id);
+        // Dropped items aren't InventoryItems, so the skin comes off (the unlock is kept)
+        crate::item_cosmetic::clear_item_cosmetic(ctx, item_instance_id);
     } else {
         // Dropping part of the stack
         if !item_def.is_stackable {
@@ -7,6 +7,7 @@ pub fn get_ammunition_definitions() -> Vec<ItemDefinition> {
         // === ARROWS ===
         // Various arrow types with different damage modifiers and special effects
         // All arrows use AmmoType::Arrow for compatibility with bows and crossbows
+        // Speed, damage mode, on-hit effects and weapon wear per type: see crate::ammunition
 
         // Wooden Arrow - Standard balanced projectile
         ItemBuilder::new("Wooden Arrow", "The standard arrow with balanced damage and range. No bleeding effect.", ItemCategory::Ammunition)
@@ -22,12 +23,13 @@ pub fn get_ammunition_definitions() -> Vec<ItemDefinition> {
             .respawn_time(180)
             .build(),
 
-        // Bone Arrow - High damage projectile  
-        ItemBuilder::new("Bone Arrow", "Features a larger arrowhead with higher damage but no bleeding effect.", ItemCategory::Ammunition)
+        // Bone Arrow - High damage bone-tipped projectile that causes bleeding
+        ItemBuilder::new("Bone Arrow", "Features a larger, jagged bone arrowhead that hits harder and leaves the target bleeding. Slightly slower in flight.", ItemCategory::Ammunition)
             .icon("bone_arrow.png")
             .stackable(50)
             .pvp_damage(5, 10) // Adds 5-10 extra damage to weapon base
             .ammo_type(AmmoType::Arrow)
+            .bleed_effect(1.5, 6.0, 1.0) // Jagged bone tip tears (1.5 dps for 6 seconds = 9 total)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Wood".to_string(), quantity: 25 },
                 CostIngredient { item_name: "Bone Fragments".to_string(), quantity: 25 },
@@ -101,6 +103,7 @@ pub fn get_ammunition_definitions() -> Vec<ItemDefinition> {
             .stackable(30)
             .pvp_damage(0, 0) // Neutral modifier - weapon base damage only
             .ammo_type(AmmoType::Bullet)
+            .requires_workbench(2)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 10 },
                 CostIngredient { item_name: "Gunpowder".to_string(), quantity: 3 },
@@ -1,6 +1,6 @@
 use super::builders::{ItemBuilder};
 use crate::items::{ItemDefinition, ItemCategory, CostIngredient};
-use crate::models::{EquipmentSlotType, ArmorResistances};
+use crate::models::{EquipmentSlotType, ArmorResistances, EnvironmentalHazard};
 
 pub fn get_armor_definitions() -> Vec<ItemDefinition> {
     vec![
@@ -22,6 +22,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.0,
             })
             .warmth_bonus(0.2)
+            .insulation(0.05) // Thin cloth - a little insulation
             .movement_speed_modifier(0.02) // +2% speed per piece
             .crafting_cost(vec![
                 CostIngredient { item_name: "Cloth".to_string(), quantity: 8 },
@@ -45,6 +46,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.0,
             })
             .warmth_bonus(0.2)
+            .insulation(0.05)
             .movement_speed_modifier(0.02)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Cloth".to_string(), quantity: 16 },
@@ -68,6 +70,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.0,
             })
             .warmth_bonus(0.2)
+            .insulation(0.05)
             .movement_speed_modifier(0.02)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Cloth".to_string(), quantity: 12 },
@@ -91,6 +94,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.0,
             })
             .warmth_bonus(0.2)
+            .insulation(0.05)
             .movement_speed_modifier(0.02)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Cloth".to_string(), quantity: 5 },
@@ -114,6 +118,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.0,
             })
             .warmth_bonus(0.2)
+            .insulation(0.05)
             .movement_speed_modifier(0.02)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Cloth".to_string(), quantity: 5 },
@@ -137,6 +142,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.0,
             })
             .warmth_bonus(0.25)
+            .insulation(0.06)
             .movement_speed_modifier(0.02)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Cloth".to_string(), quantity: 12 },
@@ -199,11 +205,36 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
             .respawn_time(480)
             .build(),
 
+        // Static Ward Cowl - Head armor that blocks memory static
+        // Charged (night-time) blue rune stones drive unprotected players insane,
+        // so farming their shards requires wearing this
+        ItemBuilder::new("Static Ward Cowl", "A heavy hood lined with woven shard dust. Shields the mind from the memory static around charged rune stones at night.", ItemCategory::Armor)
+            .icon("static_ward_cowl.png")
+            .equippable(Some(EquipmentSlotType::Head))
+            .armor_resistances(ArmorResistances {
+                melee_resistance: 0.02,
+                projectile_resistance: 0.02,
+                fire_resistance: 0.0,
+                blunt_resistance: 0.02,
+                slash_resistance: 0.02,
+                pierce_resistance: 0.02,
+                cold_resistance: 0.02,
+            })
+            .environmental_protection(vec![EnvironmentalHazard::Static])
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Memory Shard".to_string(), quantity: 20 },
+                CostIngredient { item_name: "Cloth".to_string(), quantity: 10 },
+                CostIngredient { item_name: "Animal Leather".to_string(), quantity: 4 },
+            ])
+            .crafting_output(1, 10)
+            .respawn_time(600)
+            .build(),
+
         // === BONE ARMOR SET ===
         // High projectile resistance, moderate melee protection, good fire resistance
         // Full set provides strong defense against ranged attacks and fire
 
-        ItemBuilder::new("Bone Helmet", "A helmet crafted from animal bones. Lightweight yet surprisingly durable against arrows and bolts. Bones don't burn easily.", ItemCategory::Armor)
+        ItemBuilder::new("Bone Helmet", "A helmet crafted from animal bones. Lightweight yet surprisingly durable against arrows and bolts. Bones don't burn easily, and the open face vents scalding steam.", ItemCategory::Armor)
             .icon("bone_helmet.png")
             .equippable(Some(EquipmentSlotType::Head))
             .armor_resistances(ArmorResistances {
@@ -217,6 +248,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
             })
             .noise_on_sprint(true)
             .grants_burn_immunity(true) // Each piece contributes to full set immunity
+            .environmental_protection(vec![EnvironmentalHazard::Heat]) // No overheating at fumaroles
             .crafting_cost(vec![
                 CostIngredient { item_name: "Animal Bone".to_string(), quantity: 25 },
                 CostIngredient { item_name: "Rope".to_string(), quantity: 3 },
@@ -448,8 +480,11 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.06,
                 cold_resistance: 0.0,
             })
+            .insulation(0.08)
+            .wetness_penalty(0.3) // Leather sheds some water - loses less insulation when wet
             .stamina_regen_modifier(0.02) // +2% stamina regen per piece
             .grants_bleed_immunity(true) // 3 pieces needed for full immunity
+            .requires_workbench(1)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Animal Leather".to_string(), quantity: 10 },
                 CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
@@ -470,8 +505,11 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.09,
                 cold_resistance: 0.0,
             })
+            .insulation(0.08)
+            .wetness_penalty(0.3)
             .stamina_regen_modifier(0.02)
             .grants_bleed_immunity(true)
+            .requires_workbench(1)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Animal Leather".to_string(), quantity: 18 },
                 CostIngredient { item_name: "Rope".to_string(), quantity: 4 },
@@ -492,8 +530,11 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.08,
                 cold_resistance: 0.0,
             })
+            .insulation(0.08)
+            .wetness_penalty(0.3)
             .stamina_regen_modifier(0.02)
             .grants_bleed_immunity(true)
+            .requires_workbench(1)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Animal Leather".to_string(), quantity: 14 },
                 CostIngredient { item_name: "Rope".to_string(), quantity: 3 },
@@ -514,8 +555,11 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.05,
                 cold_resistance: 0.0,
             })
+            .insulation(0.08)
+            .wetness_penalty(0.3)
             .stamina_regen_modifier(0.02)
             .grants_bleed_immunity(true)
+            .requires_workbench(1)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Animal Leather".to_string(), quantity: 8 },
                 CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
@@ -536,8 +580,11 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.05,
                 cold_resistance: 0.0,
             })
+            .insulation(0.08)
+            .wetness_penalty(0.3)
             .stamina_regen_modifier(0.02)
             .grants_bleed_immunity(true)
+            .requires_workbench(1)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Animal Leather".to_string(), quantity: 8 },
                 CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
@@ -558,8 +605,11 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.06,
                 cold_resistance: 0.0,
             })
+            .insulation(0.08)
+            .wetness_penalty(0.3)
             .stamina_regen_modifier(0.02)
             .grants_bleed_immunity(true)
+            .requires_workbench(1)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Animal Leather".to_string(), quantity: 10 },
                 CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
@@ -584,6 +634,8 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.14,
                 cold_resistance: 0.0,
             })
+            .insulation(0.04)
+            .wetness_penalty(0.0) // Scales shed water - insulation unaffected by wetness
             .movement_speed_modifier(-0.03) // -3% speed per piece
             .grants_wetness_immunity(true) // 5 pieces for full immunity
             .grants_knockback_immunity(true)
@@ -608,6 +660,8 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.20,
                 cold_resistance: 0.0,
             })
+            .insulation(0.04)
+            .wetness_penalty(0.0)
             .movement_speed_modifier(-0.03)
             .grants_wetness_immunity(true)
             .grants_knockback_immunity(true)
@@ -632,6 +686,8 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.17,
                 cold_resistance: 0.0,
             })
+            .insulation(0.04)
+            .wetness_penalty(0.0)
             .movement_speed_modifier(-0.03)
             .grants_wetness_immunity(true)
             .grants_knockback_immunity(true)
@@ -656,6 +712,8 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.12,
                 cold_resistance: 0.0,
             })
+            .insulation(0.04)
+            .wetness_penalty(0.0)
             .movement_speed_modifier(-0.03)
             .grants_wetness_immunity(true)
             .grants_knockback_immunity(true)
@@ -680,6 +738,8 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 pierce_resistance: 0.12,
                 cold_resistance: 0.0,
             })
+            .insulation(0.04)
+            .wetness_penalty(0.0)
             .movement_speed_modifier(-0.03)
             .grants_wetness_immunity(true)
             .grants_knockback_immunity(true)
@@ -709,6 +769,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20, // 20% cold resist per piece
             })
             .warmth_bonus(0.8)
+            .insulation(0.12) // Fur traps heat: warm in the tundra, overheats near fumaroles
             .detection_radius_bonus(0.10) // +10% detection per piece
             .grants_cold_immunity(true) // 5 pieces for full immunity
             .crafting_cost(vec![
@@ -732,6 +793,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20,
             })
             .warmth_bonus(1.2)
+            .insulation(0.18)
             .detection_radius_bonus(0.10)
             .grants_cold_immunity(true)
             .crafting_cost(vec![
@@ -755,6 +817,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20,
             })
             .warmth_bonus(0.9)
+            .insulation(0.14)
             .detection_radius_bonus(0.10)
             .grants_cold_immunity(true)
             .crafting_cost(vec![
@@ -778,6 +841,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20,
             })
             .warmth_bonus(0.5)
+            .insulation(0.08)
             .detection_radius_bonus(0.10)
             .grants_cold_immunity(true)
             .crafting_cost(vec![
@@ -801,6 +865,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20,
             })
             .warmth_bonus(0.5)
+            .insulation(0.08)
             .detection_radius_bonus(0.10)
             .silences_movement(true) // Fox fur boots silence footsteps
             .grants_cold_immunity(true)
@@ -829,6 +894,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20, // 20% cold resist per piece
             })
             .warmth_bonus(1.0)
+            .insulation(0.15) // Dense fur - the warmest set (and the worst for overheating)
             .low_health_damage_bonus(0.04) // +4% damage per piece when low health
             .intimidates_animals(true)
             .grants_cold_immunity(true) // 5 pieces for full immunity
@@ -853,6 +919,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20,
             })
             .warmth_bonus(1.5)
+            .insulation(0.22)
             .low_health_damage_bonus(0.04)
             .intimidates_animals(true)
             .grants_cold_immunity(true)
@@ -877,6 +944,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20,
             })
             .warmth_bonus(1.2)
+            .insulation(0.18)
             .low_health_damage_bonus(0.04)
             .intimidates_animals(true)
             .grants_cold_immunity(true)
@@ -901,6 +969,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20,
             })
             .warmth_bonus(0.7)
+            .insulation(0.1)
             .low_health_damage_bonus(0.04)
             .intimidates_animals(true)
             .grants_cold_immunity(true)
@@ -925,6 +994,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.20,
             })
             .warmth_bonus(0.7)
+            .insulation(0.1)
             .low_health_damage_bonus(0.04)
             .intimidates_animals(true)
             .grants_cold_immunity(true)
@@ -998,6 +1068,7 @@ pub fn get_armor_definitions() -> Vec<ItemDefinition> {
                 cold_resistance: 0.0, // No protection - purely functional
             })
             .water_speed_bonus(1.25) // +125% water speed (2.25x normal speed)
+            .environmental_protection(vec![EnvironmentalHazard::Pressure]) // Normal breath in deep water
             .crafting_cost(vec![
                 CostIngredient { item_name: "Reed Flippers".to_string(), quantity: 1 },
                 CostIngredient { item_name: "Shark Fin".to_string(), quantity: 2 },
@@ -0,0 +1,21 @@
+//! Blueprint item definitions.
+//! One "<Item> Blueprint" per blueprint-locked recipe (see research.rs). Reading a blueprint
+//! teaches the recipe permanently; blueprints turn up in barrels and supply drops.
+
+use crate::items::{ItemDefinition, ItemCategory};
+use crate::items_database::builders::ItemBuilder;
+use crate::research::{BLUEPRINT_LOCKED_ITEMS, BLUEPRINT_ITEM_SUFFIX};
+
+/// Returns a blueprint item for every blueprint-locked recipe.
+pub fn get_blueprint_definitions() -> Vec<ItemDefinition> {
+    BLUEPRINT_LOCKED_ITEMS.iter()
+        .map(|(item_name, _)| {
+            let name = format!("{}{}", item_name, BLUEPRINT_ITEM_SUFFIX);
+            let description = format!("Faded schematics for the {}. Read it to learn how to craft one.", item_name);
+            ItemBuilder::new(&name, &description, ItemCategory::Material)
+                .icon("recipe_blueprint.png")
+                .stackable(5)
+                .build()
+        })
+        .collect()
+}
@@ -1,5 +1,5 @@
 use crate::items::{ItemDefinition, ItemCategory, CostIngredient, FlexibleIngredient};
-use crate::models::{EquipmentSlotType, TargetType, DamageType, ArmorResistances, AmmoType};
+use crate::models::{EquipmentSlotType, TargetType, DamageType, ArmorResistances, AmmoType, EnvironmentalHazard};
 
 pub struct ItemBuilder {
     inner: ItemDefinition,
@@ -36,6 +36,7 @@ impl ItemBuilder {
                 crafting_output_quantity: None,
                 crafting_time_secs: None,
                 requires_station: None,
+                required_workbench_tier: None,
                 consumable_health_gain: None,
                 consumable_hunger_satiated: None,
                 consumable_thirst_quenched: None,
@@ -82,6 +83,10 @@ impl ItemBuilder {
                 bleed_chance_on_melee: None,
                 reduces_animal_detection: None,
                 melee_damage_bonus: None,
+                // Clothing insulation
+                insulation: None,
+                wetness_penalty: None,
+                environmental_protection: vec![],
             }
         }
     }
@@ -210,6 +215,12 @@ impl ItemBuilder {
         self
     }
     
+    /// Set the workbench tier the player must be near to craft this item (1-3)
+    pub fn requires_workbench(mut self, tier: u8) -> Self {
+        self.inner.required_workbench_tier = Some(tier);
+        self
+    }
+    
     pub fn cook_time(mut self, time_secs: f32) -> Self {
         self.inner.cook_time_secs = Some(time_secs);
         self
@@ -403,6 +414,22 @@ impl ItemBuilder {
         self.inner.water_speed_bonus = Some(bonus);
         self
     }
+
+    pub fn insulation(mut self, insulation: f32) -> Self {
+        self.inner.insulation = Some(insulation);
+        self
+    }
+
+    pub fn wetness_penalty(mut self, penalty: f32) -> Self {
+        self.inner.wetness_penalty = Some(penalty);
+        self
+    }
+
+    /// Set the environmental hazards this piece protects against (one piece is enough)
+    pub fn environmental_protection(mut self, hazards: Vec<EnvironmentalHazard>) -> Self {
+        self.inner.environmental_protection = hazards;
+        self
+    }
     
     // BONE TOTEM PASSIVE BONUS BUILDER METHODS
     
@@ -772,6 +772,30 @@ pub fn get_consumable_definitions() -> Vec<ItemDefinition> {
             .crafting_output(12, 0)
             .build(),
 
+        ItemBuilder::new("Raw Arctic Grayling", "A silvery river fish with a tall, sail-like dorsal fin. Rises to insects in clear, cold streams on calm days.", ItemCategory::Consumable)
+            .icon("raw_arctic_grayling.png")
+            .stackable(10)
+            .consumable(8.0, 12.0, -2.0)
+            .cookable(40.0, "Cooked Arctic Grayling")
+            .extractable("Animal Fat", 2, 3, "Gut")
+            .respawn_time(200)
+            .build(),
+
+        ItemBuilder::new("Cooked Arctic Grayling", "Pan-fried grayling with delicate white flesh and a faint scent of thyme.", ItemCategory::Consumable)
+            .icon("cooked_arctic_grayling.png")
+            .stackable(10)
+            .consumable(24.0, 36.0, 10.0)
+            .cookable(28.0, "Burnt Arctic Grayling")
+            .build(),
+
+        ItemBuilder::new("Burnt Arctic Grayling", "Overcooked grayling. The fine flesh has turned dry and bitter.", ItemCategory::Consumable)
+            .icon("burnt_arctic_grayling.png")
+            .stackable(10)
+            .consumable(4.0, 10.0, -6.0)
+            .preserved() // Burnt items are shelf-stable
+            .crafting_output(9, 0)
+            .build(),
+
         // --- TIER 3: LARGE FISH (Rare) ---
 
         ItemBuilder::new("Raw Dolly Varden", "A beautiful Arctic char with pink-spotted sides. Most active during twilight hours in cold streams and coastal waters.", ItemCategory::Consumable)
@@ -846,6 +870,30 @@ pub fn get_consumable_definitions() -> Vec<ItemDefinition> {
             .crafting_output(13, 0)
             .build(),
 
+        ItemBuilder::new("Raw Northern Pike", "A long, toothy ambush predator of weedy lakes and slow rivers. Bony, but there's plenty of meat on it.", ItemCategory::Consumable)
+            .icon("raw_northern_pike.png")
+            .stackable(8)
+            .consumable(12.0, 17.0, -1.0)
+            .cookable(46.0, "Cooked Northern Pike")
+            .extractable("Animal Fat", 2, 4, "Gut")
+            .respawn_time(300)
+            .build(),
+
+        ItemBuilder::new("Cooked Northern Pike", "Baked pike with firm, mild white flesh. Pick out the Y-bones and it's a feast.", ItemCategory::Consumable)
+            .icon("cooked_northern_pike.png")
+            .stackable(8)
+            .consumable(38.0, 52.0, 15.0)
+            .cookable(30.0, "Burnt Northern Pike")
+            .build(),
+
+        ItemBuilder::new("Burnt Northern Pike", "Overcooked pike. Tough, bony and charred.", ItemCategory::Consumable)
+            .icon("burnt_northern_pike.png")
+            .stackable(8)
+            .consumable(6.0, 14.0, -5.0)
+            .preserved() // Burnt items are shelf-stable
+            .crafting_output(12, 0)
+            .build(),
+
         // --- TIER 4: PREMIUM FISH (Very Rare) ---
 
         ItemBuilder::new("Raw Pink Salmon", "A robust Pacific salmon with distinctive humped back. Returns to coastal waters to spawn, most active at dawn and dusk.", ItemCategory::Consumable)
@@ -1572,9 +1620,11 @@ pub fn get_consumable_definitions() -> Vec<ItemDefinition> {
                 "Raw Greenling",
                 "Raw Sculpin",
                 "Raw Pacific Cod",
+                "Raw Arctic Grayling",
                 "Raw Dolly Varden",
                 "Raw Rockfish",
                 "Raw Steelhead",
+                "Raw Northern Pike",
                 "Raw Pink Salmon",
                 "Raw Sockeye Salmon",
                 "Raw King Salmon",
@@ -1627,9 +1677,11 @@ pub fn get_consumable_definitions() -> Vec<ItemDefinition> {
                 "Raw Greenling",
                 "Raw Sculpin",
                 "Raw Pacific Cod",
+                "Raw Arctic Grayling",
                 "Raw Dolly Varden",
                 "Raw Rockfish",
                 "Raw Steelhead",
+                "Raw Northern Pike",
                 "Raw Pink Salmon",
                 "Raw Sockeye Salmon",
                 "Raw King Salmon",
@@ -2170,7 +2222,7 @@ pub fn get_consumable_definitions() -> Vec<ItemDefinition> {
             .consumable(70.0, 100.0, 30.0)
             .flexible_ingredient("Any Cooked Fish", 2, vec![
                 "Cooked Twigfish", "Cooked Herring", "Cooked Smelt", "Cooked Greenling", "Cooked Sculpin",
-                "Cooked Pacific Cod", "Cooked Dolly Varden", "Cooked Rockfish", "Cooked Steelhead",
+                "Cooked Pacific Cod", "Cooked Arctic Grayling", "Cooked Dolly Varden", "Cooked Rockfish", "Cooked Steelhead", "Cooked Northern Pike",
                 "Cooked Pink Salmon", "Cooked Sockeye Salmon", "Cooked King Salmon", "Cooked Halibut",
             ])
             .flexible_ingredient("Any Cooked Starchy", 1, vec![
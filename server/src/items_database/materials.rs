@@ -82,6 +82,18 @@ pub fn get_material_definitions() -> Vec<ItemDefinition> {
             .respawn_time(300)
             .build(),
 
+        // Crude Fuel - Rendered fat thinned with charcoal, runs powered gathering tools
+        ItemBuilder::new("Crude Fuel", "Animal fat rendered down and thinned with crushed charcoal into a foul-smelling oil. Chainsaws and jackhammers draw it from your inventory automatically - one unit keeps an engine running for about 30 seconds.", ItemCategory::Material)
+            .icon("crude_fuel.png")
+            .stackable(50)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Animal Fat".to_string(), quantity: 2 },
+                CostIngredient { item_name: "Charcoal".to_string(), quantity: 3 },
+            ])
+            .crafting_output(2, 4)
+            .respawn_time(300)
+            .build(),
+
         // === METAL MATERIALS ===
 
         // Scrap Batteries - Craftable power source for advanced wards
@@ -202,6 +214,13 @@ pub fn get_material_definitions() -> Vec<ItemDefinition> {
             .icon("sea_glass.png")
             .build(),
 
+        // Fulgurite - Rare lightning-fused glass, only found where lightning struck
+        basic_material("Fulgurite", "A branching tube of glass fused by a lightning strike. Flecks of metal melted into it can be crushed out.", 10)
+            .icon("fulgurite.png")
+            .extractable("Metal Fragments", 8, 15, "Crush")
+            .respawn_time(0) // Only from lightning strikes
+            .build(),
+
         // Whale Bone Fragment - Small bone piece from Aleutian waters, can be crushed into bone fragments
         basic_material("Whale Bone Fragment", "A small fragment of whale bone pulled from the Aleutian depths. Can be crushed into bone fragments.", 20)
             .icon("whale_bone_fragment.png")
@@ -314,11 +333,28 @@ pub fn get_material_definitions() -> Vec<ItemDefinition> {
             .icon("caribou_antlers.png")
             .build(),
 
+        // Raw Hide - Uncured skin, tanned into Animal Leather on a Tanning Rack
+        basic_material("Raw Hide", "A freshly skinned animal hide. Stretch it on a tanning rack to cure it into leather.", 50)
+            .icon("raw_hide.png")
+            .build(),
+
         // Animal Leather - Universal leather resource
         basic_material("Animal Leather", "Processed leather from various animal hides. A versatile material for crafting clothing, armor, and equipment.", 50)
             .icon("animal_leather.png")
             .build(),
 
+        // Leather Saddle - Riding gear for mounts
+        basic_material("Leather Saddle", "A stitched leather saddle on a wooden frame. Needed to ride a tamed mount.", 1)
+            .icon("leather_saddle.png")
+            .requires_workbench(1)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Animal Leather".to_string(), quantity: 15 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 4 },
+                CostIngredient { item_name: "Wood".to_string(), quantity: 100 },
+            ])
+            .crafting_output(1, 30)
+            .build(),
+
         // Crab Carapace - Armor component from crabs
         basic_material("Crab Carapace", "A hard, protective shell from a beach crab. Lightweight yet sturdy, useful for crafting protective gear.", 10)
             .icon("crab_carapace.png")
@@ -348,6 +384,19 @@ pub fn get_material_definitions() -> Vec<ItemDefinition> {
 
         // NOTE: Animal skulls (Fox, Wolf, Viper) moved to weapons.rs as they are weapons like Human Skull
 
+        // === LORE ITEMS ===
+
+        // Memory Photograph - Synthesized by completing a region's cairn collection
+        ItemBuilder::new("Memory Photograph", "A faded photograph reconstructed from memory fragments. The scene belongs to a region you have fully explored.", ItemCategory::Material)
+            .icon("memory_photograph.png")
+            .build(),
+
+        // Sealed Parcel - Handed out by quest givers for delivery steps
+        ItemBuilder::new("Sealed Parcel", "A bundle wrapped in oilcloth and twine. Someone is expecting it.", ItemCategory::Material)
+            .icon("sealed_parcel.png")
+            .stackable(5)
+            .build(),
+
         // === CEREMONIAL MATERIALS ===
 
         // Matron's Mark - Founding token for creating a Matronage
@@ -360,5 +409,45 @@ pub fn get_material_definitions() -> Vec<ItemDefinition> {
             .crafting_output(1, 1)
             .build(),
 
+        // === LOCKED CRATE ACCESS === (see locked_crate.rs)
+
+        // Lockpick - Opens any locked crate, with a chance to break instead
+        ItemBuilder::new("Lockpick", "A bent tension wrench and rake. Works on any locked crate if your hands are steady - the pick snaps either way.", ItemCategory::Material)
+            .icon("lockpick.png")
+            .stackable(10)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 15 },
+            ])
+            .crafting_output(1, 5)
+            .build(),
+
+        // Keycards - Found in barrels and locked crates, each opens the next crate in the loop
+        ItemBuilder::new("Green Keycard", "A scuffed green access card. Opens the locked crate at the shipwreck.", ItemCategory::Material)
+            .icon("keycard_green.png")
+            .stackable(5)
+            .build(),
+
+        ItemBuilder::new("Blue Keycard", "A blue ALK access card. Opens the locked crate in the ALK compound.", ItemCategory::Material)
+            .icon("keycard_blue.png")
+            .stackable(5)
+            .build(),
+
+        ItemBuilder::new("Red Keycard", "A red high-clearance access card. Opens the locked crates at the quarries.", ItemCategory::Material)
+            .icon("keycard_red.png")
+            .stackable(5)
+            .build(),
+
+        // Fuse - Powers a fuse box in a monument puzzle room (see monument_puzzle.rs)
+        ItemBuilder::new("Fuse", "A ceramic cartridge fuse. Fits the dead fuse boxes wired to monument vault doors.", ItemCategory::Material)
+            .icon("fuse.png")
+            .stackable(5)
+            .requires_workbench(1)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 25 },
+                CostIngredient { item_name: "Scrap Batteries".to_string(), quantity: 1 },
+            ])
+            .crafting_output(1, 10)
+            .build(),
+
     ]
 }
@@ -8,7 +8,9 @@ mod armor;
 mod placeables;
 mod ammunition;
 mod spoiled_items;
+mod blueprints;
 pub use spoiled_items::get_spoiled_item_name;
+pub use tools::{HarvestToolTier, get_harvest_tool_tier, get_harvest_tier_stats, get_tiered_harvest_stats};
 
 use crate::items::ItemDefinition;
 
@@ -25,6 +27,7 @@ pub fn get_initial_item_definitions() -> Vec<ItemDefinition> {
     items.extend(armor::get_armor_definitions());
     items.extend(placeables::get_placeable_definitions());
     items.extend(ammunition::get_ammunition_definitions());
+    items.extend(blueprints::get_blueprint_definitions());
     
     items
 }
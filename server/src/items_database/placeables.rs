@@ -19,7 +19,7 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
             .build(),
 
         // Furnace - Metal smelting station
-        ItemBuilder::new("Furnace", "A stone furnace for smelting metal ore into metal fragments. Burns wood as fuel.", ItemCategory::Placeable)
+        ItemBuilder::new("Furnace", "A stone furnace for smelting metal ore into metal fragments. Burns wood, charcoal or crude fuel - hotter fuels smelt faster, and burning wood leaves charcoal behind.", ItemCategory::Placeable)
             .icon("furnace_simple.png")
             .stackable(3)
             .crafting_cost(vec![
@@ -32,9 +32,10 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
             .build(),
 
         // Large Furnace - Massive industrial furnace with 18 slots
-        ItemBuilder::new("Large Furnace", "A massive industrial furnace with 18 slots for high-volume smelting. Burns wood as fuel.", ItemCategory::Placeable)
+        ItemBuilder::new("Large Furnace", "A massive industrial furnace with 18 slots for high-volume smelting at twice the speed of a regular furnace. Burns wood, charcoal or crude fuel - hotter fuels smelt faster, and burning wood leaves charcoal behind.", ItemCategory::Placeable)
             .icon("large_furnace.png")
             .stackable(2)
+            .requires_workbench(1)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Stone".to_string(), quantity: 200 },
                 CostIngredient { item_name: "Wood".to_string(), quantity: 100 },
@@ -87,6 +88,55 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
             .respawn_time(300)
             .build(),
 
+        // Workbenches - Stand nearby to craft higher-tier recipes
+        ItemBuilder::new("Workbench Tier 1", "A sturdy wooden workbench. Stand nearby to craft basic metal tools, leatherwork and other Tier 1 recipes.", ItemCategory::Placeable)
+            .icon("workbench_tier_1.png")
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 300 },
+                CostIngredient { item_name: "Stone".to_string(), quantity: 100 },
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 25 },
+            ])
+            .crafting_output(1, 15)
+            .respawn_time(300)
+            .build(),
+
+        ItemBuilder::new("Workbench Tier 2", "A reinforced workbench with a vise and anvil. Stand nearby to craft crossbows, heavy weapons and Tier 2 recipes.", ItemCategory::Placeable)
+            .icon("workbench_tier_2.png")
+            .requires_workbench(1)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 500 },
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 300 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 5 },
+            ])
+            .crafting_output(1, 30)
+            .respawn_time(450)
+            .build(),
+
+        ItemBuilder::new("Workbench Tier 3", "A precision workbench fitted with salvaged machining tools. Stand nearby to craft firearms and other Tier 3 recipes.", ItemCategory::Placeable)
+            .icon("workbench_tier_3.png")
+            .requires_workbench(2)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 1000 },
+                CostIngredient { item_name: "Wood".to_string(), quantity: 500 },
+                CostIngredient { item_name: "Scrap Batteries".to_string(), quantity: 5 },
+            ])
+            .crafting_output(1, 60)
+            .respawn_time(600)
+            .build(),
+
+        // Research Table - Learn blueprints by sacrificing items
+        ItemBuilder::new("Research Table", "A cluttered drafting table. Place an item on it and spend memory shards to learn its blueprint for good - the item is destroyed in the process.", ItemCategory::Placeable)
+            .icon("research_table.png")
+            .requires_workbench(1)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 200 },
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 75 },
+                CostIngredient { item_name: "Memory Shard".to_string(), quantity: 10 },
+            ])
+            .crafting_output(1, 20)
+            .respawn_time(300)
+            .build(),
+
         // Cooking Station - Advanced food crafting station (no inventory, proximity-based)
         ItemBuilder::new("Cooking Station", "A kitchen station for preparing advanced recipes. Stand nearby to craft gourmet meals from cooked ingredients.", ItemCategory::Placeable)
             .icon("cooking_station.png")
@@ -159,7 +209,7 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
             .build(),
 
         // Scarecrow - Deters crows within a large radius
-        ItemBuilder::new("Scarecrow", "A makeshift scarecrow cobbled together from driftwood and scraps. Deters crows from destroying crops and stealing food from campfires and barbecues!", ItemCategory::Placeable)
+        ItemBuilder::new("Scarecrow", "A makeshift scarecrow cobbled together from driftwood and scraps. Deters crows from destroying crops and stealing food from campfires and barbecues, and keeps terns off dropped items nearby!", ItemCategory::Placeable)
             .icon("scarecrow.png")
             .crafting_cost(vec![
                 CostIngredient { item_name: "Wood".to_string(), quantity: 150 },
@@ -171,6 +221,91 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
             .respawn_time(600)
             .build(),
 
+        // Sprinkler - Automatically waters crops from a nearby rain collector
+        ItemBuilder::new("Sprinkler", "A rotating reed-and-scrap sprinkler. Place it near a rain collector and it will keep the crops around it watered automatically - but it won't spray salt water.", ItemCategory::Placeable)
+            .icon("sprinkler.png")
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Common Reed Stalk".to_string(), quantity: 10 }, // For the feed line
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 40 },
+                CostIngredient { item_name: "Wood".to_string(), quantity: 100 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 3 },
+            ])
+            .crafting_output(1, 8)
+            .respawn_time(600)
+            .build(),
+
+        // Greenhouse - Crops planted inside are protected from seasons, storms and wildlife
+        ItemBuilder::new("Greenhouse", "A walk-in frame of driftwood and tallow-waxed cloth panes covering a 3x3 plot. Crops planted inside keep growing through winter, shrug off storms, and are safe from hungry wildlife. Must be built on open soil.", ItemCategory::Placeable)
+            .icon("greenhouse.png")
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 400 },
+                CostIngredient { item_name: "Cloth".to_string(), quantity: 40 }, // Translucent panes
+                CostIngredient { item_name: "Tallow".to_string(), quantity: 10 }, // Waxed to keep the weather out
+                CostIngredient { item_name: "Rope".to_string(), quantity: 8 },
+            ])
+            .crafting_output(1, 20)
+            .respawn_time(1200)
+            .build(),
+
+        // Feeding Trough - Keeps tamed animals fed
+        ItemBuilder::new("Feeding Trough", "A long wooden trough for penned animals. Tamed animals nearby eat their favourite foods from it when hungry - leave them unfed for too long and they'll wander off wild.", ItemCategory::Placeable)
+            .icon("feeding_trough.png")
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 150 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
+            ])
+            .crafting_output(1, 10)
+            .respawn_time(300)
+            .build(),
+
+        // Kennel - Stores one tamed animal while its owner is away
+        ItemBuilder::new("Kennel", "A sturdy wooden kennel. Lead a tamed animal up to it to tuck it away safely - nothing can harm it inside. Holds one animal.", ItemCategory::Placeable)
+            .icon("kennel.png")
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 200 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 3 },
+            ])
+            .crafting_output(1, 15)
+            .respawn_time(300)
+            .build(),
+
+        // Stable - Larger kennel tier
+        ItemBuilder::new("Stable", "A roomy timber stable with stalls for up to three tamed animals. Keeps your herd safe while you're away.", ItemCategory::Placeable)
+            .icon("stable.png")
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 600 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 6 },
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 50 },
+            ])
+            .crafting_output(1, 30)
+            .respawn_time(600)
+            .build(),
+
+        // Tanning Rack - Cures raw hides into leather
+        ItemBuilder::new("Tanning Rack", "A wooden frame for stretching hides. Raw hides left on the rack slowly cure into leather.", ItemCategory::Placeable)
+            .icon("tanning_rack.png")
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 150 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 3 },
+                CostIngredient { item_name: "Animal Fat".to_string(), quantity: 5 },
+            ])
+            .crafting_output(1, 15)
+            .respawn_time(300)
+            .build(),
+
+        // Shredder - Player-built recycler (ALK stations have better ones)
+        ItemBuilder::new("Shredder", "A hand-cranked shredder. Items fed into it are slowly broken back into some of their crafting materials - less efficiently than an ALK recycler.", ItemCategory::Placeable)
+            .icon("shredder.png")
+            .requires_workbench(1)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 200 },
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 150 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
+            ])
+            .crafting_output(1, 30)
+            .respawn_time(300)
+            .build(),
+
         // === SHELTER & RESPAWN ===
 
         // Sleeping Bag - Portable respawn point
@@ -197,6 +332,100 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
             .respawn_time(300)
             .build(),
 
+        // === STEALTH ===
+
+        // Noisemaker Decoy - Rattles for 30s once triggered, drawing hostiles away
+        ItemBuilder::new("Noisemaker Decoy", "A tin can rattle on a stake. Once triggered it clatters for half a minute, drawing hostiles and wolves to investigate it instead of you.", ItemCategory::Placeable)
+            .icon("noisemaker_decoy.png")
+            .stackable(5)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Tin Can".to_string(), quantity: 2 },
+                CostIngredient { item_name: "Wood".to_string(), quantity: 25 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 1 },
+            ])
+            .crafting_output(1, 5)
+            .respawn_time(300)
+            .build(),
+
+        // Hunting Blind - Hides a player from animals and hostile NPCs while they stand inside
+        ItemBuilder::new("Hunting Blind", "A low screen of branches and woven grass. Animals barely notice someone waiting inside, and hostiles can't see in unless they come right up to it.", ItemCategory::Placeable)
+            .icon("hunting_blind.png")
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 150 },
+                CostIngredient { item_name: "Plant Fiber".to_string(), quantity: 50 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
+            ])
+            .crafting_output(1, 15)
+            .respawn_time(300)
+            .build(),
+
+        // === SECURITY ===
+
+        // Tripwire Alarm - Messages its owner when an intruder crosses it (see alarm_trap)
+        ItemBuilder::new("Tripwire Alarm", "A wire strung low between two stakes, hung with tin cans. When someone who doesn't belong crosses it, it rattles loudly and you're told where.", ItemCategory::Placeable)
+            .icon("tripwire_alarm.png")
+            .stackable(5)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Tin Can".to_string(), quantity: 2 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 20 },
+            ])
+            .crafting_output(1, 10)
+            .respawn_time(300)
+            .build(),
+
+        // Security Camera - Watched from a Security Monitor (see security_camera)
+        ItemBuilder::new("Security Camera", "A salvaged camera on a bracket. Watch what it sees from a Security Monitor - walls block its view.", ItemCategory::Placeable)
+            .icon("security_camera.png")
+            .stackable(5)
+            .requires_workbench(2)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 75 },
+                CostIngredient { item_name: "Scrap Batteries".to_string(), quantity: 1 },
+                CostIngredient { item_name: "Cloth".to_string(), quantity: 5 },
+            ])
+            .crafting_output(1, 20)
+            .respawn_time(600)
+            .build(),
+
+        // Security Monitor - Shows the feed of its owner's Security Cameras
+        ItemBuilder::new("Security Monitor", "A flickering screen wired to a receiver. Pick any of your Security Cameras nearby to watch its feed.", ItemCategory::Placeable)
+            .icon("security_monitor.png")
+            .requires_workbench(2)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 150 },
+                CostIngredient { item_name: "Scrap Batteries".to_string(), quantity: 2 },
+                CostIngredient { item_name: "Wood".to_string(), quantity: 50 },
+            ])
+            .crafting_output(1, 30)
+            .respawn_time(600)
+            .build(),
+
+        // === SIGNS ===
+
+        // Wooden Sign - Placeable sign with editable text
+        ItemBuilder::new("Wooden Sign", "A sturdy wooden sign. Write a short message for anyone passing by.", ItemCategory::Placeable)
+            .icon("wooden_sign.png")
+            .stackable(5)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 50 },
+            ])
+            .crafting_output(1, 5)
+            .respawn_time(300)
+            .build(),
+
+        // Banner - Lighter cloth sign with editable text
+        ItemBuilder::new("Banner", "A cloth banner on a wooden pole. Mark your territory or leave a message.", ItemCategory::Placeable)
+            .icon("banner.png")
+            .stackable(5)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 25 },
+                CostIngredient { item_name: "Cloth".to_string(), quantity: 20 },
+            ])
+            .crafting_output(1, 5)
+            .respawn_time(300)
+            .build(),
+
         // === LIGHTING ===
 
         // Lantern - Deployable light source
@@ -244,6 +473,20 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
             .respawn_time(900)
             .build(),
 
+        // Water Purifier - Rain collector with a charcoal still that desalinates salt water
+        ItemBuilder::new("Water Purifier", "A rain collector fitted with a charcoal filter and evaporation still. Pour sea water in and it slowly turns it into clean drinking water, leaving a quarter behind as brine. Also collects rainwater. Capacity: 40L.", ItemCategory::Placeable)
+            .icon("water_purifier.png")
+            .requires_workbench(2)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 75 },
+                CostIngredient { item_name: "Charcoal".to_string(), quantity: 30 }, // For the filter bed
+                CostIngredient { item_name: "Cloth".to_string(), quantity: 10 },
+                CostIngredient { item_name: "Stone".to_string(), quantity: 100 },
+            ])
+            .crafting_output(1, 10)
+            .respawn_time(900)
+            .build(),
+
         // === COOKING EQUIPMENT ===
 
         // Broth Pot - Advanced cooking vessel that snaps to campfires
@@ -299,6 +542,7 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
         ItemBuilder::new("Metal Door", "A reinforced metal door that can be placed into a doorframe. Much stronger than wooden doors, providing superior protection.", ItemCategory::Placeable)
             .icon("metal_door.png")
             .stackable(3)
+            .requires_workbench(2)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 100 },
                 CostIngredient { item_name: "Wood".to_string(), quantity: 25 },
@@ -308,6 +552,11 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
             .respawn_time(600)
             .build(),
 
+        // Door Key - Cut at a locked door via craft_door_key, bound to that door
+        ItemBuilder::new("Door Key", "A hand-filed metal key cut for one specific door. Whoever carries it can open that door, so keep it safe.", ItemCategory::Material)
+            .icon("door_key.png")
+            .build(), // No crafting cost - cut for a specific door
+
         // === EXPLOSIVES ===
 
         // Babushka's Surprise - Tier 1 explosive (BALANCED: 150 gunpowder = 150 sulfur + 300 charcoal)
@@ -385,6 +634,7 @@ pub fn get_placeable_definitions() -> Vec<ItemDefinition> {
         ItemBuilder::new("Tallow Steam Turret", "A scrap turret built from warped sheet metal, splintered wood, and tallow-sealed joints, with a squat boiler core and a short, reinforced nozzle. The chamber heats rendered fat to a rolling boil and ejects it as a pressurized stream of scalding tallow toward targets.", ItemCategory::Placeable)
             .icon("turret_tallow.png")
             .stackable(2)
+            .requires_workbench(2)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 150 },
                 CostIngredient { item_name: "Wood".to_string(), quantity: 75 },
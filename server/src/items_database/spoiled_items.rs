@@ -97,6 +97,10 @@ pub fn get_spoiled_item_definitions() -> Vec<ItemDefinition> {
         spoiled_item("Spoiled Cooked Rockfish", "cooked_rockfish.png", "Spoiled cooked rockfish.", 6, -16.0, 5.0, -22.0),
         spoiled_item("Spoiled Raw Steelhead", "raw_steelhead.png", "Spoiled steelhead.", 6, -14.0, 4.0, -20.0),
         spoiled_item("Spoiled Cooked Steelhead", "cooked_steelhead.png", "Spoiled cooked steelhead.", 6, -16.0, 5.0, -22.0),
+        spoiled_item("Spoiled Raw Arctic Grayling", "raw_arctic_grayling.png", "Spoiled grayling.", 10, -10.0, 2.0, -14.0),
+        spoiled_item("Spoiled Cooked Arctic Grayling", "cooked_arctic_grayling.png", "Spoiled cooked grayling.", 10, -12.0, 3.0, -16.0),
+        spoiled_item("Spoiled Raw Northern Pike", "raw_northern_pike.png", "Spoiled pike.", 8, -12.0, 3.0, -18.0),
+        spoiled_item("Spoiled Cooked Northern Pike", "cooked_northern_pike.png", "Spoiled cooked pike.", 8, -14.0, 4.0, -20.0),
         spoiled_item("Spoiled Raw Pink Salmon", "raw_pink_salmon.png", "Spoiled salmon.", 5, -14.0, 4.0, -20.0),
         spoiled_item("Spoiled Cooked Pink Salmon", "cooked_pink_salmon.png", "Spoiled cooked salmon.", 5, -16.0, 5.0, -22.0),
         spoiled_item("Spoiled Raw Sockeye Salmon", "raw_sockeye_salmon.png", "Spoiled sockeye.", 5, -16.0, 5.0, -22.0),
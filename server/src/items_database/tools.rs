@@ -83,6 +83,26 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
             .attack_interval(0.55)
             .build(),
 
+        // Metal Cleaver - Heavy butchering tool: the most meat, fat and bone per hit, but hacks straight through pelts
+        ItemBuilder::new("Metal Cleaver", "A heavy scrap-metal cleaver for butchering game. Chops through meat and bone faster than any knife, but hacks the hide to ribbons - skin the animal with a knife first if you want the pelt.", ItemCategory::Tool)
+            .icon("metal_cleaver.png")
+            .equippable(None)
+            
+            .primary_target_damage(35, 35)
+            .primary_target_yield(4, 6)
+            .primary_target_type(TargetType::AnimalCorpse)
+            .primary_yield_resource("Animal Parts")
+            .pvp_damage(24, 30)
+            .bleed_effect(2.0, 6.0, 1.0)
+            .requires_workbench(1)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 60 },
+                CostIngredient { item_name: "Wood".to_string(), quantity: 40 },
+            ])
+            .crafting_output(1, 8)
+            .attack_interval(0.7)
+            .build(),
+
         // Repair Tool
         ItemBuilder::new("Repair Hammer", "A simple hammer for repairing structures and maintaining equipment.", ItemCategory::Tool)
             .icon("repair_hammer.png")
@@ -98,10 +118,10 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
             .build(),
 
         // Primary Gathering Tools
+        // Damage/yield come from the harvest tier tables below (node base stats x tier multipliers)
         // Yield formula: For 100% resource extraction, yield = resources * damage / health
         // Trees: 800 HP, 150-500 resources (avg 325). Stone Hatchet should get ~90%, Metal ~100%
-        basic_tool("Stone Hatchet", "A simple hatchet for chopping wood.", 
-                  TargetType::Tree, 35, 50, 16, 22, "Wood") // Increased yield from 12-18 to 16-22 for ~90% extraction
+        harvest_tool("Stone Hatchet", "A simple hatchet for chopping wood.", TargetType::Tree)
             .icon("stone_hatchet.png")
             .pvp_damage(15, 20)
             .crafting_cost(vec![
@@ -113,8 +133,8 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
             .attack_interval(0.8)
             .build(),
 
-        basic_tool("Metal Hatchet", "A robust metal hatchet that cuts through wood efficiently. Gathers significantly more wood than its stone counterpart.",
-                  TargetType::Tree, 60, 80, 30, 40, "Wood") // Increased yield from 25-35 to 30-40 for ~100% extraction
+        harvest_tool("Metal Hatchet", "A robust metal hatchet that cuts through wood efficiently. Gathers significantly more wood than its stone counterpart.",
+                  TargetType::Tree)
             .icon("metal_hatchet.png")
             .pvp_damage(22, 30)
             .crafting_cost(vec![
@@ -142,9 +162,8 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
 
         // Stones: 400 HP, 200-400 resources (avg 300). Yields need to be MUCH higher to extract before HP depletes.
         // Stone Pickaxe: ~7 hits to kill (400/55), needs ~43 yield/hit for 300 resources
-        // Metal Pickaxe: ~4 hits to kill (400/90), needs ~75 yield/hit for 300 resources
-        basic_tool("Stone Pickaxe", "A simple pickaxe for breaking rocks.",
-                  TargetType::Stone, 40, 70, 35, 50, "Stone") // Massively increased yield from 8-14 to 35-50 for ~90% extraction
+        // Metal Pickaxe: ~4 hits to kill (400/88), needs ~75 yield/hit for 300 resources
+        harvest_tool("Stone Pickaxe", "A simple pickaxe for breaking rocks.", TargetType::Stone)
             .icon("stone_pickaxe.png")
             .equippable(None)
             .pvp_damage(18, 25)
@@ -157,8 +176,8 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
             .attack_interval(1.2)
             .build(),
 
-        basic_tool("Metal Pickaxe", "A sturdy metal pickaxe that breaks rocks efficiently. Gathers significantly more stone than its stone counterpart.",
-                  TargetType::Stone, 60, 120, 60, 85, "Stone") // Massively increased yield from 13-22 to 60-85 for ~100% extraction
+        harvest_tool("Metal Pickaxe", "A sturdy metal pickaxe that breaks rocks efficiently. Gathers significantly more stone than its stone counterpart.",
+                  TargetType::Stone)
             .icon("metal_pickaxe.png")
             .equippable(None)
             .pvp_damage(25, 35)
@@ -171,6 +190,35 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
             .attack_interval(1.0)
             .build(),
 
+        // Powered Gathering Tools - burn Crude Fuel (see powered_tool.rs), wear out fast and are LOUD
+        harvest_tool("Chainsaw", "A salvaged two-stroke chainsaw that tears through timber in a handful of cuts. Burns Crude Fuel from your inventory, wears out quickly, and its howl carries far enough to draw anything hostile nearby.",
+                  TargetType::Tree)
+            .icon("chainsaw.png")
+            .pvp_damage(30, 40)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 300 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
+                CostIngredient { item_name: "Scrap Batteries".to_string(), quantity: 2 },
+            ])
+            .crafting_output(1, 20)
+            .respawn_time(1800)
+            .attack_interval(0.5)
+            .build(),
+
+        harvest_tool("Jackhammer", "A pneumatic breaker rebuilt around a small fuel engine. Shatters rock and ore nodes in a few bursts. Burns Crude Fuel from your inventory, wears out quickly, and the racket draws hostile attention from afar.",
+                  TargetType::Stone)
+            .icon("jackhammer.png")
+            .pvp_damage(20, 28)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 350 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 2 },
+                CostIngredient { item_name: "Scrap Batteries".to_string(), quantity: 2 },
+            ])
+            .crafting_output(1, 20)
+            .respawn_time(1800)
+            .attack_interval(0.6)
+            .build(),
+
         ItemBuilder::new("Rock", "A basic tool for gathering.", ItemCategory::Tool)
             .icon("rock_item.png")
             .equippable(None)
@@ -203,6 +251,7 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
             .icon("flashlight.png")
             .equippable(None)
             
+            .requires_workbench(1)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 50 },
                 CostIngredient { item_name: "Cloth".to_string(), quantity: 10 },
@@ -219,6 +268,38 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
             .respawn_time(300)
             .build(),
 
+        // Spyglass - Look through it to see much further (see spyglass)
+        ItemBuilder::new("Spyglass", "A brass tube with a pair of ground lenses. Raise it to watch distant shores, but you can't walk while you're looking through it.", ItemCategory::Tool)
+            .icon("spyglass.png")
+            .equippable(None)
+            .requires_workbench(1)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 75 },
+                CostIngredient { item_name: "Cloth".to_string(), quantity: 5 },
+            ])
+            .crafting_output(1, 10)
+            .respawn_time(600)
+            .build(),
+
+        // Binoculars - Military optics that see further than a spyglass (loot only, not craftable)
+        ItemBuilder::new("Binoculars", "Rubber-armored military binoculars. Sees further than any spyglass, but you have to stand still to use them.", ItemCategory::Tool)
+            .icon("binoculars.png")
+            .equippable(None)
+            .respawn_time(900)
+            .build(),
+
+        // Handcuffs - Restrain a knocked out player (see restraints)
+        ItemBuilder::new("Handcuffs", "Crude iron shackles joined by a short length of rope. Use on a knocked out player to stop them fighting or using items until they struggle free or are released.", ItemCategory::Tool)
+            .icon("handcuffs.png")
+            .stackable(5)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 25 },
+                CostIngredient { item_name: "Rope".to_string(), quantity: 1 },
+            ])
+            .crafting_output(1, 10)
+            .respawn_time(600)
+            .build(),
+
         // Medical Tools
         ItemBuilder::new("Bandage", "A simple bandage to patch up wounds. Stops bleeding and restores health.", ItemCategory::Tool)
             .icon("bandage.png")
@@ -234,12 +315,27 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
             .respawn_time(300)
             .build(),
 
+        // Splint - Sets a fractured limb (cures the Fractured effect)
+        ItemBuilder::new("Splint", "Two straight sticks bound tight with cloth. Sets a broken limb so you can run again.", ItemCategory::Tool)
+            .icon("splint.png")
+            .stackable(5)
+            .equippable(None)
+            .consumable(5.0, 0.0, 0.0)
+            .consumable_duration(6.0) // Slow to apply - hard to do mid-fight
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Wood".to_string(), quantity: 10 },
+                CostIngredient { item_name: "Cloth".to_string(), quantity: 2 },
+            ])
+            .crafting_output(1, 3)
+            .respawn_time(300)
+            .build(),
+
         // Med Kit - Military-grade first aid kit, better than bandage
         ItemBuilder::new("Med Kit", "A compact military first aid kit containing sterile dressings, antiseptic, and compression bandages. Standard issue for Soviet troops.", ItemCategory::Tool)
             .icon("med_kit.png")
             .stackable(5)
             .equippable(None)
-            .consumable(35.0, 0.0, 0.0) // Better than bandage (25), worse than jellyfish compress (45)
+            .consumable(60.0, 0.0, 0.0) // Biggest burst heal, but starts a long cooldown (MED_KIT_COOLDOWN_SECS)
             .consumable_duration(4.0) // Faster than bandage (5s), slower than jellyfish (3.5s)
             .respawn_time(600) // Not craftable - loot only
             .build(),
@@ -413,3 +509,93 @@ pub fn get_tool_definitions() -> Vec<ItemDefinition> {
             .build(),
     ]
 }
+
+// --- Harvest Tool Tiers ---
+// Gathering tools belong to a tier that scales their per-hit damage (fewer hits to fell a tree
+// or break a stone) and yield against each harvestable node type. Retune a tier or a node here
+// and every tool in it follows - tree.rs, stone.rs and harvestable_resource.rs read these tables.
+
+/// Quality tier of a gathering tool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HarvestToolTier {
+    Improvised, // Rocks, ladles, repurposed weapons - anything without a dedicated tier
+    Stone,
+    Metal,
+    Powered, // Fuel-driven tools - see powered_tool.rs
+}
+
+/// Multipliers a tier applies on top of the node base stats
+#[derive(Debug, Clone, Copy)]
+pub struct HarvestTierStats {
+    pub damage_multiplier: f32,      // Per-hit damage to trees/stones (higher = fewer hits)
+    pub yield_multiplier: f32,       // Per-hit primary resource yield from trees/stones
+    pub pile_yield_multiplier: f32,  // Primary yield when picking up resource piles (hands = 1.0)
+}
+
+/// Per-hit stats against a node type at Stone tier (1.0x)
+#[derive(Debug, Clone, Copy)]
+pub struct HarvestNodeBaseStats {
+    pub damage_min: u32,
+    pub damage_max: u32,
+    pub yield_min: u32,
+    pub yield_max: u32,
+    pub resource_name: &'static str,
+}
+
+/// Tool name -> tier. Tools not listed here gather at Improvised tier.
+const HARVEST_TOOL_TIERS: &[(&str, HarvestToolTier)] = &[
+    ("Stone Hatchet", HarvestToolTier::Stone),
+    ("Stone Pickaxe", HarvestToolTier::Stone),
+    ("Metal Hatchet", HarvestToolTier::Metal),
+    ("Metal Pickaxe", HarvestToolTier::Metal),
+    ("Chainsaw", HarvestToolTier::Powered),
+    ("Jackhammer", HarvestToolTier::Powered),
+];
+
+pub fn get_harvest_tool_tier(item_name: &str) -> HarvestToolTier {
+    HARVEST_TOOL_TIERS.iter()
+        .find(|(name, _)| *name == item_name)
+        .map(|(_, tier)| *tier)
+        .unwrap_or(HarvestToolTier::Improvised)
+}
+
+pub fn get_harvest_tier_stats(tier: HarvestToolTier) -> HarvestTierStats {
+    match tier {
+        HarvestToolTier::Improvised => HarvestTierStats { damage_multiplier: 0.4, yield_multiplier: 0.4, pile_yield_multiplier: 1.0 },
+        HarvestToolTier::Stone => HarvestTierStats { damage_multiplier: 1.0, yield_multiplier: 1.0, pile_yield_multiplier: 1.25 },
+        HarvestToolTier::Metal => HarvestTierStats { damage_multiplier: 1.6, yield_multiplier: 1.8, pile_yield_multiplier: 1.5 },
+        HarvestToolTier::Powered => HarvestTierStats { damage_multiplier: 2.5, yield_multiplier: 2.6, pile_yield_multiplier: 1.75 },
+    }
+}
+
+pub fn get_harvest_node_base_stats(target_type: TargetType) -> Option<HarvestNodeBaseStats> {
+    match target_type {
+        // Trees: 800 HP - a Stone Hatchet takes ~19 hits
+        TargetType::Tree => Some(HarvestNodeBaseStats { damage_min: 35, damage_max: 50, yield_min: 16, yield_max: 22, resource_name: "Wood" }),
+        // Stones: 400 HP - a Stone Pickaxe takes ~7 hits
+        TargetType::Stone => Some(HarvestNodeBaseStats { damage_min: 40, damage_max: 70, yield_min: 35, yield_max: 50, resource_name: "Stone" }),
+        _ => None,
+    }
+}
+
+/// Node base stats scaled by a tier's damage/yield multipliers (all values at least 1)
+pub fn get_tiered_harvest_stats(target_type: TargetType, tier: HarvestToolTier) -> Option<HarvestNodeBaseStats> {
+    let base = get_harvest_node_base_stats(target_type)?;
+    let stats = get_harvest_tier_stats(tier);
+    let scale = |value: u32, multiplier: f32| ((value as f32 * multiplier).round() as u32).max(1);
+    Some(HarvestNodeBaseStats {
+        damage_min: scale(base.damage_min, stats.damage_multiplier),
+        damage_max: scale(base.damage_max, stats.damage_multiplier),
+        yield_min: scale(base.yield_min, stats.yield_multiplier),
+        yield_max: scale(base.yield_max, stats.yield_multiplier),
+        resource_name: base.resource_name,
+    })
+}
+
+/// Gathering tool whose primary target stats are derived from its harvest tier
+fn harvest_tool(name: &str, description: &str, target_type: TargetType) -> ItemBuilder {
+    let stats = get_tiered_harvest_stats(target_type, get_harvest_tool_tier(name))
+        .expect("harvest_tool target must have node base stats");
+    basic_tool(name, description, target_type,
+               stats.damage_min, stats.damage_max, stats.yield_min, stats.yield_max, stats.resource_name)
+}
@@ -186,6 +186,7 @@ pub fn get_weapon_definitions() -> Vec<ItemDefinition> {
             .icon("war_hammer.png")
             .weapon(42, 48, 1.4) // Very high damage, very slow - commitment weapon
             .damage_type(DamageType::Blunt) // Blunt crushing damage
+            .requires_workbench(2)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Wood".to_string(), quantity: 200 },
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 150 },
@@ -222,6 +223,7 @@ pub fn get_weapon_definitions() -> Vec<ItemDefinition> {
             .weapon(22, 26, 0.45) // Low-medium damage, FASTEST weapon - DPS king
             .damage_type(DamageType::Slash) // Slashing cuts
             .bleed_effect(4.0, 12.0, 1.0) // VICIOUS bleed - 48 total bleed damage!
+            .requires_workbench(1)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 100 },
                 CostIngredient { item_name: "Cloth".to_string(), quantity: 15 },
@@ -242,6 +244,7 @@ pub fn get_weapon_definitions() -> Vec<ItemDefinition> {
             .weapon(45, 52, 1.0) // Very high damage, moderate speed - power weapon
             .damage_type(DamageType::Slash) // Cleaving slash damage
             .bleed_effect(3.5, 10.0, 1.0) // Strong bleed - 35 total
+            .requires_workbench(2)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 200 },
                 CostIngredient { item_name: "Wood".to_string(), quantity: 150 },
@@ -364,6 +367,7 @@ pub fn get_weapon_definitions() -> Vec<ItemDefinition> {
             .weapon(78, 95, 0.0) // High burst damage (78-95) - rewards patience
             .damage_type(DamageType::Projectile)
             .equippable(None)
+            .requires_workbench(2)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Wood".to_string(), quantity: 300 },
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 100 },
@@ -409,6 +413,7 @@ pub fn get_weapon_definitions() -> Vec<ItemDefinition> {
             .weapon(38, 48, 0.0) // Lower per-shot (38-48) - compensated by fire rate and magazine
             .damage_type(DamageType::Projectile)
             .equippable(None)
+            .requires_workbench(3)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 400 },
                 CostIngredient { item_name: "Wood".to_string(), quantity: 50 },
@@ -429,6 +434,7 @@ pub fn get_weapon_definitions() -> Vec<ItemDefinition> {
             .weapon(22, 28, 0.0) // Very low per-shot (22-28) - compensated by extreme fire rate
             .damage_type(DamageType::Projectile)
             .equippable(None)
+            .requires_workbench(3)
             .crafting_cost(vec![
                 CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 600 },
                 CostIngredient { item_name: "Wood".to_string(), quantity: 75 },
@@ -458,5 +464,48 @@ pub fn get_weapon_definitions() -> Vec<ItemDefinition> {
             .damage_type(DamageType::Projectile)
             .equippable(None)
             .build(),
+
+        // === HAND THROWABLES (Throwing Stone, Smoke Bomb, Flashbang) ===
+        // Thrown straight from the inventory with throw_item - not equippable.
+        // Impact effects live in throwables.rs.
+
+        ItemBuilder::new("Throwing Stone", "A palm-sized stone, good for throwing. Stings on a direct hit, and the clatter where it lands draws the attention of anything hostile nearby.", ItemCategory::Weapon)
+            .icon("throwing_stone.png")
+            .stackable(20)
+            .pvp_damage(4, 6) // Minor damage on a direct hit
+            .damage_type(DamageType::Projectile)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Stone".to_string(), quantity: 5 },
+            ])
+            .crafting_output(2, 1)
+            .respawn_time(120)
+            .build(),
+
+        ItemBuilder::new("Smoke Bomb", "A cloth pouch of smouldering charcoal and fibre. Bursts into a thick cloud of smoke where it lands, hiding anyone inside from prying eyes.", ItemCategory::Weapon)
+            .icon("smoke_bomb.png")
+            .stackable(5)
+            .damage_type(DamageType::Projectile) // No direct damage; smoke cloud on impact
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Cloth".to_string(), quantity: 5 },
+                CostIngredient { item_name: "Charcoal".to_string(), quantity: 15 },
+                CostIngredient { item_name: "Plant Fiber".to_string(), quantity: 20 },
+            ])
+            .crafting_output(1, 5)
+            .respawn_time(600)
+            .build(),
+
+        ItemBuilder::new("Flashbang", "A tin-cased charge of packed gunpowder. Goes off with a blinding flash and a deafening bang on impact, staggering everything caught in the blast.", ItemCategory::Weapon)
+            .icon("flashbang.png")
+            .stackable(5)
+            .damage_type(DamageType::Projectile) // No direct damage; stagger on impact
+            .requires_workbench(2)
+            .crafting_cost(vec![
+                CostIngredient { item_name: "Metal Fragments".to_string(), quantity: 25 },
+                CostIngredient { item_name: "Gunpowder".to_string(), quantity: 10 },
+                CostIngredient { item_name: "Cloth".to_string(), quantity: 2 },
+            ])
+            .crafting_output(1, 8)
+            .respawn_time(900)
+            .build(),
     ]
 }
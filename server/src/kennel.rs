@@ -0,0 +1,151 @@
+/******************************************************************************
+ *                                                                            *
+ * Kennel / Stable - Safe storage for tamed animals                           *
+ *                                                                            *
+ * Kennels and stables are WoodenStorageBox entities (BOX_TYPE_KENNEL,        *
+ * BOX_TYPE_STABLE) with no item slots. An owner can lead a tamed animal up   *
+ * to one and store it: the WildAnimal row is removed from the world and its  *
+ * full snapshot kept in KennelStoredAnimal, so nothing can hurt it while the *
+ * owner is offline. Retrieving it re-inserts the animal under its original   *
+ * ID, so breeding data and product cooldowns stay attached.                  *
+ *                                                                            *
+ * Capacity depends on tier: a Kennel holds 1 animal, a Stable holds 3.       *
+ * If a kennel is destroyed, the animals inside are let out where it stood.   *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Identity, Table, Timestamp};
+use log;
+
+use crate::wooden_storage_box::{WoodenStorageBox, BOX_TYPE_KENNEL, BOX_TYPE_STABLE, validate_box_interaction, wooden_storage_box as WoodenStorageBoxTableTrait};
+use crate::wild_animal_npc::{AnimalState, WildAnimal, transition_to_state, wild_animal as WildAnimalTableTrait};
+use crate::environment::calculate_chunk_index;
+use crate::utils::get_distance_squared;
+
+// --- Kennel Constants ---
+pub const KENNEL_CAPACITY: usize = 1;
+pub const STABLE_CAPACITY: usize = 3;
+pub const KENNEL_ANIMAL_RANGE: f32 = 250.0; // The animal must be led this close to the kennel to store it
+const KENNEL_RELEASE_OFFSET_Y: f32 = 60.0; // Retrieved animals step out in front of the kennel
+
+// --- Stored Animal Table ---
+#[spacetimedb::table(accessor = kennel_stored_animal, public)]
+#[derive(Clone, Debug)]
+pub struct KennelStoredAnimal {
+    #[primary_key]
+    pub animal_id: u64, // Original WildAnimal ID - restored on retrieval
+    #[index(btree)]
+    pub kennel_id: u32,
+    pub owner_id: Identity,
+    pub stored_at: Timestamp,
+    pub animal: WildAnimal, // Full snapshot of the animal as it was stored
+}
+
+/// How many animals a kennel tier can hold (0 for non-kennel boxes)
+pub fn get_kennel_capacity(box_type: u8) -> usize {
+    match box_type {
+        BOX_TYPE_KENNEL => KENNEL_CAPACITY,
+        BOX_TYPE_STABLE => STABLE_CAPACITY,
+        _ => 0,
+    }
+}
+
+/// Whether this box is a kennel of any tier
+pub fn is_kennel(box_type: u8) -> bool {
+    get_kennel_capacity(box_type) > 0
+}
+
+/// Number of animals currently stored in a kennel
+pub fn count_stored_animals(ctx: &ReducerContext, kennel_id: u32) -> usize {
+    ctx.db.kennel_stored_animal().kennel_id().filter(kennel_id).count()
+}
+
+/// Puts a stored animal back into the world in front of the kennel
+fn release_stored_animal(ctx: &ReducerContext, stored: KennelStoredAnimal, kennel: &WoodenStorageBox) -> WildAnimal {
+    let mut animal = stored.animal;
+    animal.pos_x = kennel.pos_x;
+    animal.pos_y = kennel.pos_y + KENNEL_RELEASE_OFFSET_Y;
+    animal.spawn_x = animal.pos_x;
+    animal.spawn_y = animal.pos_y;
+    animal.chunk_index = calculate_chunk_index(animal.pos_x, animal.pos_y);
+    animal.investigation_x = None;
+    animal.investigation_y = None;
+    let owner = animal.tamed_by;
+    transition_to_state(&mut animal, AnimalState::Following, ctx.timestamp, owner, "released from kennel");
+
+    ctx.db.kennel_stored_animal().animal_id().delete(stored.animal_id);
+    let released = ctx.db.wild_animal().insert(animal);
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(released.id), released.pos_x, released.pos_y);
+    released
+}
+
+/// Store a tamed animal in a kennel
+#[spacetimedb::reducer]
+pub fn store_animal_in_kennel(ctx: &ReducerContext, kennel_id: u32, animal_id: u64) -> Result<(), String> {
+    let (player, kennel) = validate_box_interaction(ctx, kennel_id)?;
+    if player.is_dead {
+        return Err("Cannot store animals while dead".to_string());
+    }
+
+    let capacity = get_kennel_capacity(kennel.box_type);
+    if capacity == 0 {
+        return Err("That isn't a kennel.".to_string());
+    }
+
+    let animal = ctx.db.wild_animal().id().find(animal_id)
+        .map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row))
+        .ok_or_else(|| "Animal not found".to_string())?;
+    if animal.tamed_by != Some(ctx.sender()) {
+        return Err("You can only kennel animals you have tamed.".to_string());
+    }
+
+    let distance_sq = get_distance_squared(animal.pos_x, animal.pos_y, kennel.pos_x, kennel.pos_y);
+    if distance_sq > KENNEL_ANIMAL_RANGE * KENNEL_ANIMAL_RANGE {
+        return Err("Lead the animal closer to the kennel first.".to_string());
+    }
+
+    if count_stored_animals(ctx, kennel_id) >= capacity {
+        return Err(format!("This kennel is full ({} animal{} max).", capacity, if capacity == 1 { "" } else { "s" }));
+    }
+
+    ctx.db.wild_animal().id().delete(animal_id);
+    crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(animal_id));
+    ctx.db.kennel_stored_animal().insert(KennelStoredAnimal {
+        animal_id,
+        kennel_id,
+        owner_id: ctx.sender(),
+        stored_at: ctx.timestamp,
+        animal: animal.clone(),
+    });
+
+    log::info!("[Kennel] Player {} stored {:?} {} in kennel {}", ctx.sender(), animal.species, animal_id, kennel_id);
+    Ok(())
+}
+
+/// Retrieve a stored animal from its kennel
+#[spacetimedb::reducer]
+pub fn retrieve_animal_from_kennel(ctx: &ReducerContext, animal_id: u64) -> Result<(), String> {
+    let stored = ctx.db.kennel_stored_animal().animal_id().find(animal_id)
+        .ok_or_else(|| "That animal isn't in a kennel.".to_string())?;
+    if stored.owner_id != ctx.sender() {
+        return Err("You don't own this animal.".to_string());
+    }
+
+    let (player, kennel) = validate_box_interaction(ctx, stored.kennel_id)?;
+    if player.is_dead {
+        return Err("Cannot retrieve animals while dead".to_string());
+    }
+
+    let animal = release_stored_animal(ctx, stored, &kennel);
+    log::info!("[Kennel] Player {} retrieved {:?} {} from kennel {}", ctx.sender(), animal.species, animal.id, kennel.id);
+    Ok(())
+}
+
+/// Lets every animal out of a kennel - called when the kennel is destroyed
+pub fn release_all_from_kennel(ctx: &ReducerContext, kennel: &WoodenStorageBox) {
+    let stored: Vec<KennelStoredAnimal> = ctx.db.kennel_stored_animal().kennel_id().filter(kennel.id).collect();
+    for stored_animal in stored {
+        let animal = release_stored_animal(ctx, stored_animal, kennel);
+        log::info!("[Kennel] {:?} {} escaped from destroyed kennel {}", animal.species, animal.id, kennel.id);
+    }
+}
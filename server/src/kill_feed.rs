@@ -0,0 +1,249 @@
+/******************************************************************************
+ *                                                                            *
+ * Kill feed and death recap tables. Every player death path (PvP combat,    *
+ * wild animals, NPC projectiles and turrets via handle_player_death, stat   *
+ * decay, knocked-out bleed-out, /kill) reports here so clients get a public *
+ * kill feed and a per-player recap of the last damage events taken before   *
+ * dying.                                                                     *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{table, reducer, Identity, ReducerContext, SpacetimeType, Table, Timestamp, TimeDuration, ScheduleAt};
+use std::time::Duration;
+
+use crate::Player;
+
+// --- Constants ---
+/// How long kill feed entries stay visible before cleanup removes them
+pub const KILL_FEED_RETENTION_SECS: u64 = 300; // 5 minutes
+/// How often the cleanup schedule runs
+pub const KILL_FEED_CLEANUP_INTERVAL_SECS: u64 = 30;
+/// Number of damage events kept per player for the death recap
+pub const DEATH_RECAP_MAX_EVENTS: usize = 10;
+
+/// Single damage event as shown in the death recap
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct DamageRecapEvent {
+    pub source_id: Option<Identity>, // Attacking player (None for animals/environment)
+    pub source_name: String,         // Player username, animal name, or cause ("Bleeding", etc.)
+    pub weapon_name: Option<String>, // Item used, if any
+    pub amount: f32,                 // Damage actually applied
+    pub health_after: f32,
+    pub occurred_at: Timestamp,
+}
+
+/// Public kill feed - one row per player death, cleaned up on a schedule
+#[table(accessor = kill_feed_entry, public)]
+#[derive(Clone, Debug)]
+pub struct KillFeedEntry {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub victim_id: Identity,
+    pub victim_name: String,
+    pub killer_id: Option<Identity>, // None for non-player killers
+    pub killer_name: String,         // Username, animal name, or empty for environmental deaths
+    pub death_cause: String,         // Matches DeathMarker.death_cause ("Combat", "Tundra Wolf", "Starvation", ...)
+    pub weapon_name: Option<String>,
+    pub distance: f32,               // Distance between killer and victim in pixels (0.0 if no killer)
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub created_at: Timestamp,
+}
+
+/// Rolling damage history for living players (server-only, feeds DeathRecap)
+#[table(accessor = player_damage_history)]
+#[derive(Clone, Debug)]
+pub struct PlayerDamageHistory {
+    #[primary_key]
+    pub player_id: Identity,
+    pub events: Vec<DamageRecapEvent>, // Oldest first, capped at DEATH_RECAP_MAX_EVENTS
+}
+
+/// Death recap for a player's most recent death
+#[table(accessor = death_recap, public)] // Public so client can subscribe with filter
+#[derive(Clone, Debug)]
+pub struct DeathRecap {
+    #[primary_key]
+    pub player_id: Identity,
+    pub killer_id: Option<Identity>,
+    pub killer_name: String,
+    pub death_cause: String,
+    pub weapon_name: Option<String>,
+    pub distance: f32,
+    pub events: Vec<DamageRecapEvent>, // Last damage events before death, oldest first
+    pub died_at: Timestamp,
+}
+
+/// Appends a damage event to a player's rolling history, dropping the oldest beyond the cap
+pub fn record_damage_event(
+    ctx: &ReducerContext,
+    victim_id: Identity,
+    source_id: Option<Identity>,
+    source_name: &str,
+    weapon_name: Option<&str>,
+    amount: f32,
+    health_after: f32,
+) {
+    if amount <= 0.0 {
+        return;
+    }
+
+    let event = DamageRecapEvent {
+        source_id,
+        source_name: source_name.to_string(),
+        weapon_name: weapon_name.map(|w| w.to_string()),
+        amount,
+        health_after,
+        occurred_at: ctx.timestamp,
+    };
+
+    let histories = ctx.db.player_damage_history();
+    if let Some(mut history) = histories.player_id().find(&victim_id) {
+        history.events.push(event);
+        if history.events.len() > DEATH_RECAP_MAX_EVENTS {
+            let overflow = history.events.len() - DEATH_RECAP_MAX_EVENTS;
+            history.events.drain(0..overflow);
+        }
+        histories.player_id().update(history);
+    } else {
+        histories.insert(PlayerDamageHistory {
+            player_id: victim_id,
+            events: vec![event],
+        });
+    }
+}
+
+/// Most recent damage event a player has taken this life, if any
+pub fn last_damage_event(ctx: &ReducerContext, player_id: Identity) -> Option<DamageRecapEvent> {
+    ctx.db.player_damage_history().player_id().find(&player_id)
+        .and_then(|history| history.events.last().cloned())
+}
+
+/// Records a player death: inserts a kill feed entry and snapshots the damage
+/// history into the victim's death recap. Call once per death, after `is_dead` is set.
+pub fn record_player_death(
+    ctx: &ReducerContext,
+    victim: &Player,
+    killer_id: Option<Identity>,
+    killer_name: &str,
+    death_cause: &str,
+    weapon_name: Option<&str>,
+    killer_pos: Option<(f32, f32)>,
+) {
+    let distance = killer_pos
+        .map(|(kx, ky)| {
+            let dx = victim.position_x - kx;
+            let dy = victim.position_y - ky;
+            (dx * dx + dy * dy).sqrt()
+        })
+        .unwrap_or(0.0);
+
+    ctx.db.kill_feed_entry().insert(KillFeedEntry {
+        id: 0, // auto_inc
+        victim_id: victim.identity,
+        victim_name: victim.username.clone(),
+        killer_id,
+        killer_name: killer_name.to_string(),
+        death_cause: death_cause.to_string(),
+        weapon_name: weapon_name.map(|w| w.to_string()),
+        distance,
+        pos_x: victim.position_x,
+        pos_y: victim.position_y,
+        created_at: ctx.timestamp,
+    });
+
+    // Move the rolling history into the recap so the next life starts clean
+    let events = match ctx.db.player_damage_history().player_id().find(&victim.identity) {
+        Some(history) => {
+            ctx.db.player_damage_history().player_id().delete(&victim.identity);
+            history.events
+        }
+        None => Vec::new(),
+    };
+
+    let recap = DeathRecap {
+        player_id: victim.identity,
+        killer_id,
+        killer_name: killer_name.to_string(),
+        death_cause: death_cause.to_string(),
+        weapon_name: weapon_name.map(|w| w.to_string()),
+        distance,
+        events,
+        died_at: ctx.timestamp,
+    };
+
+    let recaps = ctx.db.death_recap();
+    if recaps.player_id().find(&victim.identity).is_some() {
+        recaps.player_id().update(recap);
+    } else {
+        recaps.insert(recap);
+    }
+
+    log::info!("[KillFeed] {} died ({}) - killer: '{}', weapon: {:?}, distance: {:.1}px",
+        victim.username, death_cause, killer_name, weapon_name, distance);
+}
+
+// --- Cleanup Schedule ---
+
+#[table(accessor = kill_feed_cleanup_schedule, scheduled(cleanup_old_kill_feed_entries))]
+#[derive(Clone, Debug)]
+pub struct KillFeedCleanupSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Initialize the kill feed cleanup schedule
+pub fn init_kill_feed_cleanup_schedule(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.kill_feed_cleanup_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("Starting kill feed cleanup schedule (every {}s).", KILL_FEED_CLEANUP_INTERVAL_SECS);
+        let interval = Duration::from_secs(KILL_FEED_CLEANUP_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            KillFeedCleanupSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Kill feed cleanup"
+        );
+    } else {
+        log::debug!("Kill feed cleanup schedule already exists.");
+    }
+    Ok(())
+}
+
+/// Removes kill feed entries older than KILL_FEED_RETENTION_SECS
+#[reducer]
+pub fn cleanup_old_kill_feed_entries(ctx: &ReducerContext, _args: KillFeedCleanupSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Kill feed cleanup can only be run by scheduler".to_string());
+    }
+
+    let kill_feed = ctx.db.kill_feed_entry();
+
+    // PERFORMANCE: Early exit if the feed is empty
+    if kill_feed.iter().next().is_none() {
+        return Ok(());
+    }
+
+    let cutoff_time = ctx.timestamp - TimeDuration::from_micros((KILL_FEED_RETENTION_SECS * 1_000_000) as i64);
+    let old_entries: Vec<u64> = kill_feed.iter()
+        .filter(|entry| entry.created_at < cutoff_time)
+        .map(|entry| entry.id)
+        .collect();
+
+    let removed_count = old_entries.len();
+    for entry_id in old_entries {
+        kill_feed.id().delete(entry_id);
+    }
+
+    if removed_count > 0 {
+        log::debug!("[KillFeed] Cleaned up {} old kill feed entries", removed_count);
+    }
+
+    Ok(())
+}
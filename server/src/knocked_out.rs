@@ -4,6 +4,9 @@ use rand::Rng; // For random recovery/death chances
 // Import table traits needed
 use crate::player as PlayerTableTrait;
 use crate::player_stats::stat_thresholds_config as StatThresholdsConfigTableTrait;
+use crate::items::{InventoryItem, inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::active_equipment::active_equipment as ActiveEquipmentTableTrait;
+use crate::models::ItemLocation;
 
 // Import the Player struct
 use crate::Player;
@@ -142,6 +145,7 @@ pub fn process_knocked_out_recovery(ctx: &ReducerContext, args: KnockedOutRecove
         player.knocked_out_at = None;
         player.is_dead = true;
         player.death_timestamp = Some(ctx.timestamp);
+        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::Player(player.identity));
         player.health = 0.0;
 
         // Drop active weapon on death (before clearing equipment and creating corpse)
@@ -167,6 +171,15 @@ pub fn process_knocked_out_recovery(ctx: &ReducerContext, args: KnockedOutRecove
             Err(e) => log::error!("[KnockedOutDeath] Failed to create corpse for player {:?}: {}", player_id, e),
         }
 
+        // Credit whoever knocked them down (last recorded damage source)
+        let last_hit = crate::kill_feed::last_damage_event(ctx, player_id);
+        let (killer_id, killer_name, weapon_name) = match &last_hit {
+            Some(event) => (event.source_id, event.source_name.as_str(), event.weapon_name.as_deref()),
+            None => (None, "", None),
+        };
+        crate::kill_feed::record_player_death(ctx, &player, killer_id, killer_name, "Knocked Out", weapon_name, None);
+
+        release_carry(ctx, player_id);
         players.identity().update(player);
         recovery_schedule.schedule_id().delete(&schedule_id);
 
@@ -178,6 +191,7 @@ pub fn process_knocked_out_recovery(ctx: &ReducerContext, args: KnockedOutRecove
         player.knocked_out_at = None;
         player.health = 10.0; // Recover with low health
 
+        release_carry(ctx, player_id);
         players.identity().update(player);
         recovery_schedule.schedule_id().delete(&schedule_id);
 
@@ -257,69 +271,198 @@ pub fn schedule_knocked_out_recovery(ctx: &ReducerContext, player_id: Identity)
     }
 }
 
-/// Reducer for other players to revive a knocked out player
-#[spacetimedb::reducer]
-pub fn revive_knocked_out_player(ctx: &ReducerContext, target_player_id: Identity) -> Result<(), String> {
-    let reviver_id = ctx.sender();
-    let players = ctx.db.player();
-    let recovery_schedule_table = ctx.db.knocked_out_recovery_schedule();
+// --- Reviving With Medical Items ---
 
-    // Get both players
-    let reviver = players.identity().find(&reviver_id)
-        .ok_or_else(|| "Reviver player not found".to_string())?;
-    
-    let mut target_player = players.identity().find(&target_player_id)
-        .ok_or_else(|| "Target player not found".to_string())?;
+/// Medical items that can revive, with the health they restore coming from their consumable_health_gain
+pub const REVIVE_MEDICAL_ITEMS: [&str; 3] = ["Bandage", "Med Kit", "Jellyfish Compress"];
+/// How long a revive takes to apply
+pub const REVIVE_CHANNEL_SECS: u64 = 5;
+/// How far the reviver may drift during the channel before it is interrupted
+const REVIVE_CHANNEL_MOVE_TOLERANCE: f32 = 24.0;
+const REVIVE_INTERACTION_DISTANCE: f32 = 128.0; // Matches client-side distance
 
-    // Validate reviver is alive and not knocked out
+/// An in-progress revive; completes when the schedule fires unless interrupted
+#[spacetimedb::table(accessor = revive_channel, public, scheduled(complete_revive_channel))]
+#[derive(Clone, Debug)]
+pub struct ReviveChannel {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+    #[unique]
+    pub reviver_id: Identity,
+    pub target_player_id: Identity,
+    pub item_instance_id: u64,
+    pub started_at: Timestamp,
+    pub start_x: f32,
+    pub start_y: f32,
+}
+
+/// The medical item a reviver will use: the equipped one if it is medical, otherwise the one that heals most
+fn find_revive_item(ctx: &ReducerContext, reviver_id: Identity) -> Option<(InventoryItem, f32)> {
+    let item_defs = ctx.db.item_definition();
+    let medical = |item: &InventoryItem| item_defs.id().find(item.item_def_id)
+        .filter(|def| REVIVE_MEDICAL_ITEMS.contains(&def.name.as_str()))
+        .map(|def| def.consumable_health_gain.unwrap_or(0.0));
+
+    let equipped = ctx.db.active_equipment().player_identity().find(reviver_id)
+        .and_then(|eq| eq.equipped_item_instance_id)
+        .and_then(|id| ctx.db.inventory_item().instance_id().find(id));
+    if let Some(item) = equipped {
+        if let Some(heal) = medical(&item) {
+            return Some((item, heal));
+        }
+    }
+    ctx.db.inventory_item().iter()
+        .filter(|item| matches!(item.location, ItemLocation::Inventory(_) | ItemLocation::Hotbar(_)) && item.location.is_player_bound() == Some(reviver_id))
+        .filter_map(|item| medical(&item).map(|heal| (item, heal)))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+fn validate_revive(reviver: &Player, target_player: &Player) -> Result<(), String> {
     if reviver.is_dead {
         return Err("Dead players cannot revive others".to_string());
     }
     if reviver.is_knocked_out {
         return Err("Knocked out players cannot revive others".to_string());
     }
-
-    // Validate target is knocked out
     if !target_player.is_knocked_out {
         return Err("Target player is not knocked out".to_string());
     }
     if target_player.is_dead {
         return Err("Target player is already dead".to_string());
     }
-
-    // Check distance (similar to other interaction distances)
-    const REVIVE_INTERACTION_DISTANCE: f32 = 128.0; // Increased to match client-side distance
-    const REVIVE_INTERACTION_DISTANCE_SQ: f32 = REVIVE_INTERACTION_DISTANCE * REVIVE_INTERACTION_DISTANCE;
-    
     let dx = reviver.position_x - target_player.position_x;
     let dy = reviver.position_y - target_player.position_y;
-    let distance_sq = dx * dx + dy * dy;
-    
-    if distance_sq > REVIVE_INTERACTION_DISTANCE_SQ {
+    if dx * dx + dy * dy > REVIVE_INTERACTION_DISTANCE * REVIVE_INTERACTION_DISTANCE {
         return Err("Too far away to revive player".to_string());
     }
+    Ok(())
+}
+
+/// Reducer for other players to start reviving a knocked out player.
+/// Needs a medical item; the revive completes after REVIVE_CHANNEL_SECS unless the reviver moves or is hit.
+#[spacetimedb::reducer]
+pub fn revive_knocked_out_player(ctx: &ReducerContext, target_player_id: Identity) -> Result<(), String> {
+    let reviver_id = ctx.sender();
+    let players = ctx.db.player();
+
+    let reviver = players.identity().find(&reviver_id)
+        .ok_or_else(|| "Reviver player not found".to_string())?;
+    let target_player = players.identity().find(&target_player_id)
+        .ok_or_else(|| "Target player not found".to_string())?;
+    validate_revive(&reviver, &target_player)?;
+    if crate::restraints::is_restrained(ctx, reviver_id) {
+        return Err("Restrained players cannot revive others".to_string());
+    }
+    if ctx.db.revive_channel().reviver_id().find(reviver_id).is_some() {
+        return Err("You are already reviving someone".to_string());
+    }
+    if ctx.db.revive_channel().iter().any(|c| c.target_player_id == target_player_id) {
+        return Err("Someone is already reviving that player".to_string());
+    }
+    let (item, _) = find_revive_item(ctx, reviver_id)
+        .ok_or_else(|| "You need a bandage or medical kit to revive someone".to_string())?;
+
+    crate::try_insert_schedule!(
+        ctx.db.revive_channel(),
+        ReviveChannel {
+            id: 0,
+            scheduled_at: ScheduleAt::Time(ctx.timestamp + std::time::Duration::from_secs(REVIVE_CHANNEL_SECS)),
+            reviver_id,
+            target_player_id,
+            item_instance_id: item.instance_id,
+            started_at: ctx.timestamp,
+            start_x: reviver.position_x,
+            start_y: reviver.position_y,
+        },
+        "Revive channel"
+    );
+    log::info!("[Revive] Player {:?} started reviving {:?}", reviver_id, target_player_id);
+    Ok(())
+}
+
+/// Reducer for a reviver to stop reviving
+#[spacetimedb::reducer]
+pub fn cancel_revive(ctx: &ReducerContext) -> Result<(), String> {
+    let channel = ctx.db.revive_channel().reviver_id().find(ctx.sender())
+        .ok_or_else(|| "You are not reviving anyone".to_string())?;
+    ctx.db.revive_channel().id().delete(channel.id);
+    Ok(())
+}
+
+/// Scheduled reducer that finishes a revive channel
+#[spacetimedb::reducer]
+pub fn complete_revive_channel(ctx: &ReducerContext, channel: ReviveChannel) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("complete_revive_channel can only be called by the scheduler".to_string());
+    }
+    ctx.db.revive_channel().id().delete(channel.id); // One-shot; free the reviver for the next attempt
+    let players = ctx.db.player();
+    let (Some(reviver), Some(mut target_player)) = (
+        players.identity().find(&channel.reviver_id),
+        players.identity().find(&channel.target_player_id),
+    ) else {
+        return Ok(());
+    };
+
+    // --- Interruptions ---
+    if let Err(e) = validate_revive(&reviver, &target_player) {
+        log::info!("[Revive] Revive of {:?} by {:?} interrupted: {}", channel.target_player_id, channel.reviver_id, e);
+        return Ok(());
+    }
+    let dx = reviver.position_x - channel.start_x;
+    let dy = reviver.position_y - channel.start_y;
+    if dx * dx + dy * dy > REVIVE_CHANNEL_MOVE_TOLERANCE * REVIVE_CHANNEL_MOVE_TOLERANCE {
+        log::info!("[Revive] Revive of {:?} interrupted: reviver {:?} moved", channel.target_player_id, channel.reviver_id);
+        return Ok(());
+    }
+    if reviver.last_hit_time.is_some_and(|hit| hit >= channel.started_at) {
+        log::info!("[Revive] Revive of {:?} interrupted: reviver {:?} took damage", channel.target_player_id, channel.reviver_id);
+        return Ok(());
+    }
+
+    // --- Consume the medical item (it may have moved or been used up meanwhile) ---
+    let inventory_items = ctx.db.inventory_item();
+    let item = inventory_items.instance_id().find(channel.item_instance_id)
+        .filter(|item| item.location.is_player_bound() == Some(channel.reviver_id))
+        .or_else(|| find_revive_item(ctx, channel.reviver_id).map(|(item, _)| item));
+    let Some(item) = item else {
+        log::info!("[Revive] Revive of {:?} interrupted: reviver {:?} has no medical item left", channel.target_player_id, channel.reviver_id);
+        return Ok(());
+    };
+    let heal_amount = ctx.db.item_definition().id().find(item.item_def_id)
+        .and_then(|def| def.consumable_health_gain)
+        .unwrap_or(10.0);
+    if item.quantity > 1 {
+        let mut updated_item = item.clone();
+        updated_item.quantity -= 1;
+        inventory_items.instance_id().update(updated_item);
+    } else {
+        inventory_items.instance_id().delete(item.instance_id);
+    }
 
-    // Revive the player
+    // --- Revive the player ---
     target_player.is_knocked_out = false;
     target_player.knocked_out_at = None;
-    target_player.health = 10.0; // Revive with low health
+    target_player.health = heal_amount.max(10.0); // Never weaker than a self-recovery
     target_player.last_update = ctx.timestamp;
-
     players.identity().update(target_player.clone());
+    release_carry(ctx, channel.target_player_id);
 
     // Cancel recovery schedule - find by player_id since we don't have schedule_id
+    let recovery_schedule_table = ctx.db.knocked_out_recovery_schedule();
     let schedules_to_remove: Vec<u64> = recovery_schedule_table.iter()
-        .filter(|schedule| schedule.player_id == target_player_id)
+        .filter(|schedule| schedule.player_id == channel.target_player_id)
         .map(|schedule| schedule.schedule_id)
         .collect();
-    
     for schedule_id in schedules_to_remove {
         recovery_schedule_table.schedule_id().delete(&schedule_id);
-        log::info!("[Revive] Canceled recovery schedule {} for revived player {:?}", schedule_id, target_player_id);
+        log::info!("[Revive] Canceled recovery schedule {} for revived player {:?}", schedule_id, channel.target_player_id);
     }
 
-    log::info!("Player {:?} ({}) revived player {:?} ({}) with 10 health", 
-             reviver_id, reviver.username, target_player_id, target_player.username);
+    log::info!("Player {:?} ({}) revived player {:?} ({}) with {:.0} health",
+             channel.reviver_id, reviver.username, channel.target_player_id, target_player.username, target_player.health);
 
     Ok(())
 }
@@ -441,4 +584,128 @@ pub fn get_knocked_out_status(ctx: &ReducerContext) -> Result<(), String> {
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+// --- Carrying Knocked Out Players ---
+
+/// How close a player must be to pick up a knocked out body
+pub const CARRY_PICKUP_DISTANCE: f32 = 96.0;
+/// Movement speed of a player carrying someone, relative to walking
+pub const CARRY_SPEED_MULTIPLIER: f32 = 0.6;
+/// The carried body trails slightly behind the carrier
+const CARRIED_BODY_OFFSET_Y: f32 = 12.0;
+
+/// A knocked out player being carried (one row per carried body)
+#[spacetimedb::table(accessor = carried_player, public)]
+#[derive(Clone, Debug)]
+pub struct CarriedPlayer {
+    #[primary_key]
+    pub carried_id: Identity,
+    #[unique]
+    pub carrier_id: Identity,
+    pub started_at: Timestamp,
+}
+
+fn can_carry(player: &Player) -> bool {
+    player.is_online && !player.is_dead && !player.is_knocked_out && !player.is_sleeping
+}
+
+/// The carry a player is part of (as carrier or carried), dropping it if either side can no longer take part
+fn find_valid_carry(ctx: &ReducerContext, player_id: Identity) -> Option<CarriedPlayer> {
+    let carries = ctx.db.carried_player();
+    let carry = carries.carried_id().find(player_id).or_else(|| carries.carrier_id().find(player_id))?;
+    let players = ctx.db.player();
+    let carrier_ok = players.identity().find(carry.carrier_id).is_some_and(|p| can_carry(&p));
+    let carried_ok = players.identity().find(carry.carried_id).is_some_and(|p| p.is_knocked_out && !p.is_dead);
+    if carrier_ok && carried_ok {
+        Some(carry)
+    } else {
+        carries.carried_id().delete(carry.carried_id);
+        None
+    }
+}
+
+pub fn is_being_carried(ctx: &ReducerContext, player_id: Identity) -> bool {
+    find_valid_carry(ctx, player_id).is_some_and(|c| c.carried_id == player_id)
+}
+
+pub fn is_carrying(ctx: &ReducerContext, player_id: Identity) -> bool {
+    find_valid_carry(ctx, player_id).is_some_and(|c| c.carrier_id == player_id)
+}
+
+/// Ends any carry the player is part of (on recovery, revive or death)
+pub fn release_carry(ctx: &ReducerContext, player_id: Identity) {
+    let carries = ctx.db.carried_player();
+    carries.carried_id().delete(player_id);
+    carries.carrier_id().delete(player_id);
+}
+
+/// Moves the body a carrier is holding along with them
+pub fn drag_carried_player(ctx: &ReducerContext, carrier_id: Identity, carrier_x: f32, carrier_y: f32) {
+    let Some(carry) = find_valid_carry(ctx, carrier_id).filter(|c| c.carrier_id == carrier_id) else {
+        return;
+    };
+    let players = ctx.db.player();
+    if let Some(mut carried) = players.identity().find(carry.carried_id) {
+        carried.position_x = carrier_x;
+        carried.position_y = carrier_y + CARRIED_BODY_OFFSET_Y;
+        carried.last_update = ctx.timestamp;
+        crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(carried.identity), carried.position_x, carried.position_y);
+        players.identity().update(carried);
+    }
+}
+
+/// Reducer to pick up a knocked out player and carry them
+#[spacetimedb::reducer]
+pub fn start_carrying(ctx: &ReducerContext, target_player_id: Identity) -> Result<(), String> {
+    let carrier_id = ctx.sender();
+    let players = ctx.db.player();
+
+    let mut carrier = players.identity().find(&carrier_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    let target_player = players.identity().find(&target_player_id)
+        .ok_or_else(|| "Target player not found".to_string())?;
+
+    if !can_carry(&carrier) {
+        return Err("You can't carry anyone right now".to_string());
+    }
+    if !target_player.is_knocked_out || target_player.is_dead {
+        return Err("Only knocked out players can be carried".to_string());
+    }
+    if find_valid_carry(ctx, carrier_id).is_some() {
+        return Err("You are already carrying someone".to_string());
+    }
+    if find_valid_carry(ctx, target_player_id).is_some() {
+        return Err("Someone else is already carrying that player".to_string());
+    }
+
+    let dx = carrier.position_x - target_player.position_x;
+    let dy = carrier.position_y - target_player.position_y;
+    if dx * dx + dy * dy > CARRY_PICKUP_DISTANCE * CARRY_PICKUP_DISTANCE {
+        return Err("Too far away to pick up player".to_string());
+    }
+
+    ctx.db.carried_player().insert(CarriedPlayer {
+        carried_id: target_player_id,
+        carrier_id,
+        started_at: ctx.timestamp,
+    });
+    carrier.is_sprinting = false;
+    carrier.is_crouching = false;
+    let (carrier_x, carrier_y) = (carrier.position_x, carrier.position_y);
+    players.identity().update(carrier);
+    drag_carried_player(ctx, carrier_id, carrier_x, carrier_y);
+
+    log::info!("[Carry] Player {:?} picked up knocked out player {:?}", carrier_id, target_player_id);
+    Ok(())
+}
+
+/// Reducer to put down the player being carried
+#[spacetimedb::reducer]
+pub fn drop_carried(ctx: &ReducerContext) -> Result<(), String> {
+    let carrier_id = ctx.sender();
+    let carry = ctx.db.carried_player().carrier_id().find(carrier_id)
+        .ok_or_else(|| "You are not carrying anyone".to_string())?;
+    ctx.db.carried_player().carried_id().delete(carry.carried_id);
+    log::info!("[Carry] Player {:?} put down player {:?}", carrier_id, carry.carried_id);
+    Ok(())
+}
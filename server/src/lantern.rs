@@ -1,14 +1,15 @@
 use spacetimedb::{table, reducer, ReducerContext, Table, Identity, Timestamp, ScheduleAt, TimeDuration};
 use log;
-use crate::items::{InventoryItem, ItemDefinition, add_item_to_player_inventory, split_stack_helper};
+use rand::Rng;
+use crate::items::{InventoryItem, ItemDefinition, add_item_to_player_inventory};
 use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
 use crate::models::{ItemLocation, ContainerType, ContainerLocationData, InventoryLocationData, HotbarLocationData, EquippedLocationData, DroppedLocationData};
-use crate::inventory_management::{self, ItemContainer, ContainerItemClearer, handle_move_to_container_slot, handle_quick_move_from_container, handle_move_from_container_slot, handle_move_within_container, handle_split_within_container, handle_quick_move_to_container, handle_split_from_container, handle_drop_from_container_slot, handle_split_and_drop_from_container_slot, merge_or_place_into_container_slot};
+use crate::inventory_management::{self, ItemContainer, ContainerItemClearer};
 use crate::{Player, player as PlayerTableTrait};
 use crate::campfire::{campfire as CampfireTableTrait};
 use crate::wooden_storage_box::{wooden_storage_box as WoodenStorageBoxTableTrait};
 use crate::environment::calculate_chunk_index;
-use crate::player_inventory::{get_player_item, find_first_empty_player_slot, move_item_to_inventory, move_item_to_hotbar};
+use crate::player_inventory::{find_first_empty_player_slot, move_item_to_inventory, move_item_to_hotbar};
 use crate::dropped_item::create_dropped_item_entity_with_data;
 
 // --- ADDED: Import for sound events ---
@@ -181,53 +182,9 @@ impl ItemContainer for Lantern {
 }
 
 /******************************************************************************
- *                           REDUCERS (Generic Handlers)                        *
+ *                       REDUCERS (Lantern-Specific Logic)                     *
  ******************************************************************************/
 
-/// --- Move Item to Lantern ---
-/// Validates fuel type based on lantern_type:
-/// - Lantern (0) and Ancestral Ward (1): Accept Tallow only
-/// - Signal Disruptor (2) and Memory Beacon (3): Accept Scrap Batteries only
-#[spacetimedb::reducer]
-pub fn move_item_to_lantern(ctx: &ReducerContext, lantern_id: u32, target_slot_index: u8, item_instance_id: u64) -> Result<(), String> {
-    let (_player, mut lantern) = validate_lantern_interaction(ctx, lantern_id)?;
-    
-    // Validate fuel type based on lantern_type
-    let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
-        .ok_or_else(|| "Item not found.".to_string())?;
-    let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
-        .ok_or_else(|| "Item definition not found.".to_string())?;
-    
-    let is_valid_fuel = match lantern.lantern_type {
-        LANTERN_TYPE_LANTERN | LANTERN_TYPE_ANCESTRAL_WARD => {
-            // Lanterns and Ancestral Wards accept Tallow
-            item_def.name == "Tallow"
-        }
-        LANTERN_TYPE_SIGNAL_DISRUPTOR | LANTERN_TYPE_MEMORY_BEACON => {
-            // Signal Disruptors and Memory Beacons accept Scrap Batteries
-            item_def.name == "Scrap Batteries"
-        }
-        _ => false,
-    };
-    
-    if !is_valid_fuel {
-        let expected_fuel = match lantern.lantern_type {
-            LANTERN_TYPE_LANTERN => "Tallow",
-            LANTERN_TYPE_ANCESTRAL_WARD => "Tallow",
-            LANTERN_TYPE_SIGNAL_DISRUPTOR => "Scrap Batteries",
-            LANTERN_TYPE_MEMORY_BEACON => "Scrap Batteries",
-            _ => "valid fuel",
-        };
-        let structure_name = get_lantern_type_name(lantern.lantern_type);
-        return Err(format!("{} requires {} as fuel, not {}.", structure_name, expected_fuel, item_def.name));
-    }
-    
-    handle_move_to_container_slot(ctx, &mut lantern, target_slot_index, item_instance_id)?;
-    ctx.db.lantern().id().update(lantern.clone());
-    schedule_next_lantern_processing(ctx, lantern_id);
-    Ok(())
-}
-
 /// Helper function to get the display name for a lantern type
 fn get_lantern_type_name(lantern_type: u8) -> &'static str {
     match lantern_type {
@@ -459,6 +416,9 @@ pub fn place_lantern(ctx: &ReducerContext, item_instance_id: u64, world_x: f32,
     // Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
     crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     // Validate player and placement rules
     let player = players.identity().find(sender_id)
         .ok_or_else(|| "Player not found".to_string())?;
@@ -902,24 +862,41 @@ pub fn schedule_next_lantern_processing(ctx: &ReducerContext, lantern_id: u32) -
     Ok(())
 }
 
-/// --- Drop Item from Lantern Slot to World ---
-#[spacetimedb::reducer]
-pub fn drop_item_from_lantern_slot_to_world(
-    ctx: &ReducerContext,
-    lantern_id: u32,
-    slot_index: u8,
-) -> Result<(), String> {
-    let (player, mut lantern) = validate_lantern_interaction(ctx, lantern_id)?;
-    
-    handle_drop_from_container_slot(
-        ctx,
-        &mut lantern,
-        slot_index,
-        &player,
-    )?;
-    
+/// Scatters a destroyed lantern's fuel around it and removes the lantern.
+/// Called by `building::apply_structure_damage` once the lantern is marked destroyed.
+pub fn handle_lantern_destroyed(ctx: &ReducerContext, mut lantern: Lantern) {
+    // 🔊 Stop lantern sound when destroyed
+    if lantern.is_burning {
+        stop_lantern_sound(ctx, lantern.id as u64);
+    }
+
+    let mut items_to_drop: Vec<(u64, u32)> = Vec::new(); // (item_def_id, quantity)
+    for i in 0..NUM_FUEL_SLOTS as u8 {
+        if let (Some(instance_id), Some(def_id)) = (lantern.get_slot_instance_id(i), lantern.get_slot_def_id(i)) {
+            if let Some(item) = ctx.db.inventory_item().instance_id().find(instance_id) {
+                items_to_drop.push((def_id, item.quantity));
+                ctx.db.inventory_item().instance_id().delete(instance_id);
+            }
+            lantern.set_slot(i, None, None);
+        }
+    }
+
+    // Update one last time so clients see is_destroyed, then delete the lantern
+    let (lantern_id, pos_x, pos_y) = (lantern.id, lantern.pos_x, lantern.pos_y);
     ctx.db.lantern().id().update(lantern);
-    Ok(())
+    ctx.db.lantern().id().delete(lantern_id);
+
+    // Scatter items WITHOUT triggering consolidation per-item
+    let mut rng = ctx.rng();
+    for (item_def_id, quantity) in items_to_drop {
+        let offset_x = (rng.gen::<f32>() - 0.5) * 2.0 * 15.0; // Spread within +/- 15px
+        let offset_y = (rng.gen::<f32>() - 0.5) * 2.0 * 15.0;
+        match crate::dropped_item::create_dropped_item_entity_no_consolidation(ctx, item_def_id, quantity, pos_x + offset_x, pos_y + offset_y) {
+            Ok(_) => log::debug!("Dropped {} of item_def_id {} from destroyed lantern {}", quantity, item_def_id, lantern_id),
+            Err(e) => log::error!("Failed to drop item_def_id {}: {}", item_def_id, e),
+        }
+    }
+    crate::dropped_item::trigger_consolidation_at_position(ctx, pos_x, pos_y);
 }
 
 // --- ContainerItemClearer Implementation ---
@@ -958,242 +935,68 @@ impl ContainerItemClearer for LanternClearer {
  *                           INVENTORY MANAGEMENT REDUCERS                     *
  ******************************************************************************/
 
-/// --- Remove Fuel from Lantern ---
-/// Removes the fuel item from a specific lantern slot and returns it to the player inventory/hotbar.
-/// Uses the quick move logic (attempts merge, then finds first empty slot).
-/// Note: If the lantern is burning with remaining burn time, it will continue until that time runs out.
-#[spacetimedb::reducer]
-pub fn quick_move_from_lantern(ctx: &ReducerContext, lantern_id: u32, source_slot_index: u8) -> Result<(), String> {
-    let (_player, mut lantern) = validate_lantern_interaction(ctx, lantern_id)?;
-    inventory_management::handle_quick_move_from_container(ctx, &mut lantern, source_slot_index)?;
-    
-    // Only extinguish immediately if there's no fuel in slots AND no remaining burn time
-    // (already-consumed fuel should continue burning until it runs out)
-    let still_has_fuel = check_if_lantern_has_fuel(ctx, &lantern);
-    let has_remaining_burn_time = lantern.remaining_fuel_burn_time_secs.map_or(false, |t| t > 0.0);
-    
-    if !still_has_fuel && !has_remaining_burn_time && lantern.is_burning {
-        lantern.is_burning = false;
-        lantern.current_fuel_def_id = None;
-        lantern.remaining_fuel_burn_time_secs = None;
-        log::info!("Lantern {} extinguished as last valid fuel was removed and no burn time remaining.", lantern_id);
+impl inventory_management::ContainerHandle for Lantern {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_lantern_interaction(ctx, container_id)
     }
-    ctx.db.lantern().id().update(lantern.clone());
-    schedule_next_lantern_processing(ctx, lantern_id);
-    Ok(())
-}
 
-/// --- Split Stack Into Lantern ---
-/// Splits a stack from player inventory into a lantern slot.
-#[spacetimedb::reducer]
-pub fn split_stack_into_lantern(
-    ctx: &ReducerContext,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-    target_lantern_id: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut lantern) = validate_lantern_interaction(ctx, target_lantern_id)?;
-    let mut source_item = get_player_item(ctx, source_item_instance_id)?;
-    let new_item_target_location = ItemLocation::Container(crate::models::ContainerLocationData {
-        container_type: ContainerType::Lantern,
-        container_id: lantern.id as u64,
-        slot_index: target_slot_index,
-    });
-    let new_item_instance_id = split_stack_helper(ctx, &mut source_item, quantity_to_split, new_item_target_location)?;
-    
-    // Fetch the newly created item and its definition to pass to merge_or_place
-    let mut new_item = ctx.db.inventory_item().instance_id().find(new_item_instance_id)
-        .ok_or_else(|| format!("Failed to find newly split item instance {}", new_item_instance_id))?;
-    let new_item_def = ctx.db.item_definition().id().find(new_item.item_def_id)
-        .ok_or_else(|| format!("Failed to find definition for new item {}", new_item.item_def_id))?;
-
-    merge_or_place_into_container_slot(ctx, &mut lantern, target_slot_index, &mut new_item, &new_item_def)?;
-    
-    // Update the source item (quantity changed by split_stack_helper)
-    ctx.db.inventory_item().instance_id().update(source_item); 
-    ctx.db.lantern().id().update(lantern.clone());
-    schedule_next_lantern_processing(ctx, target_lantern_id);
-    Ok(())
-}
-
-/// --- Lantern Internal Item Movement ---
-/// Moves/merges/swaps an item BETWEEN two slots within the same lantern.
-#[spacetimedb::reducer]
-pub fn move_item_within_lantern(
-    ctx: &ReducerContext,
-    lantern_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut lantern) = validate_lantern_interaction(ctx, lantern_id)?;
-    inventory_management::handle_move_within_container(ctx, &mut lantern, source_slot_index, target_slot_index)?;
-    ctx.db.lantern().id().update(lantern.clone());
-    schedule_next_lantern_processing(ctx, lantern_id);
-    Ok(())
-}
-
-/// --- Lantern Internal Stack Splitting ---
-/// Splits a stack FROM one lantern slot TO another within the same lantern.
-#[spacetimedb::reducer]
-pub fn split_stack_within_lantern(
-    ctx: &ReducerContext,
-    lantern_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut lantern) = validate_lantern_interaction(ctx, lantern_id)?;
-    inventory_management::handle_split_within_container(ctx, &mut lantern, source_slot_index, target_slot_index, quantity_to_split)?;
-    ctx.db.lantern().id().update(lantern.clone());
-    schedule_next_lantern_processing(ctx, lantern_id);
-    Ok(())
-}
-
-/// --- Quick Move to Lantern ---
-/// Quickly moves an item from player inventory/hotbar to the first available/mergeable slot in the lantern.
-#[spacetimedb::reducer]
-pub fn quick_move_to_lantern(
-    ctx: &ReducerContext,
-    lantern_id: u32,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_player, mut lantern) = validate_lantern_interaction(ctx, lantern_id)?;
-    inventory_management::handle_quick_move_to_container(ctx, &mut lantern, item_instance_id)?;
-    ctx.db.lantern().id().update(lantern.clone());
-    schedule_next_lantern_processing(ctx, lantern_id);
-    Ok(())
-}
-
-/// --- Move From Lantern to Player ---
-/// Moves a specific item FROM a lantern slot TO a specific player inventory/hotbar slot.
-/// Note: If the lantern is burning with remaining burn time, it will continue until that time runs out.
-#[spacetimedb::reducer]
-pub fn move_item_from_lantern_to_player_slot(
-    ctx: &ReducerContext,
-    lantern_id: u32,
-    source_slot_index: u8,
-    target_slot_type: String,
-    target_slot_index: u32, // u32 to match client flexibility
-) -> Result<(), String> {
-    let (_player, mut lantern) = validate_lantern_interaction(ctx, lantern_id)?;
-    inventory_management::handle_move_from_container_slot(ctx, &mut lantern, source_slot_index, target_slot_type, target_slot_index)?;
-    
-    // Only extinguish immediately if there's no fuel in slots AND no remaining burn time
-    let still_has_fuel = check_if_lantern_has_fuel(ctx, &lantern);
-    let has_remaining_burn_time = lantern.remaining_fuel_burn_time_secs.map_or(false, |t| t > 0.0);
-    
-    if !still_has_fuel && !has_remaining_burn_time && lantern.is_burning {
-        lantern.is_burning = false;
-        lantern.current_fuel_def_id = None;
-        lantern.remaining_fuel_burn_time_secs = None;
-        log::info!("Lantern {} extinguished - no fuel remaining.", lantern_id);
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.lantern().id().update(self.clone());
+        let _ = schedule_next_lantern_processing(ctx, self.id);
     }
-    ctx.db.lantern().id().update(lantern.clone());
-    schedule_next_lantern_processing(ctx, lantern_id);
-    Ok(())
-}
 
-/// --- Split From Lantern to Player ---
-/// Splits a stack FROM a lantern slot TO a specific player inventory/hotbar slot.
-#[spacetimedb::reducer]
-pub fn split_stack_from_lantern(
-    ctx: &ReducerContext,
-    source_lantern_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_type: String,    // "inventory" or "hotbar"
-    target_slot_index: u32,     // Numeric index for inventory/hotbar
-) -> Result<(), String> {
-    let (_player, mut lantern) = validate_lantern_interaction(ctx, source_lantern_id)?;
-
-    log::info!(
-        "[SplitFromLantern] Player {:?} delegating split {} from lantern {} slot {} to {} slot {}",
-        ctx.sender(), quantity_to_split, source_lantern_id, source_slot_index, target_slot_type, target_slot_index
-    );
-
-    // Call GENERIC Handler
-    inventory_management::handle_split_from_container(
-        ctx, 
-        &mut lantern, 
-        source_slot_index, 
-        quantity_to_split,
-        target_slot_type, 
-        target_slot_index
-    )?;
-
-    // Check if lantern should be extinguished after fuel removal
-    // Only extinguish immediately if there's no fuel in slots AND no remaining burn time
-    let still_has_fuel = check_if_lantern_has_fuel(ctx, &lantern);
-    let has_remaining_burn_time = lantern.remaining_fuel_burn_time_secs.map_or(false, |t| t > 0.0);
-    
-    if !still_has_fuel && !has_remaining_burn_time && lantern.is_burning {
-        lantern.is_burning = false;
-        lantern.current_fuel_def_id = None;
-        lantern.remaining_fuel_burn_time_secs = None;
-        log::info!("Lantern {} extinguished - no fuel remaining.", source_lantern_id);
+    /// Validates fuel type based on lantern_type:
+    /// - Lantern (0) and Ancestral Ward (1): Accept Tallow only
+    /// - Signal Disruptor (2) and Memory Beacon (3): Accept Scrap Batteries only
+    fn check_operation(&self, ctx: &ReducerContext, op: &inventory_management::ContainerOp) -> Result<(), String> {
+        let Some(item_instance_id) = op.incoming_item() else {
+            return Ok(());
+        };
+        let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+            .ok_or_else(|| "Item not found.".to_string())?;
+        let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
+            .ok_or_else(|| "Item definition not found.".to_string())?;
+
+        let expected_fuel = match self.lantern_type {
+            LANTERN_TYPE_LANTERN | LANTERN_TYPE_ANCESTRAL_WARD => "Tallow",
+            LANTERN_TYPE_SIGNAL_DISRUPTOR | LANTERN_TYPE_MEMORY_BEACON => "Scrap Batteries",
+            _ => "valid fuel",
+        };
+        if item_def.name != expected_fuel {
+            let structure_name = get_lantern_type_name(self.lantern_type);
+            return Err(format!("{} requires {} as fuel, not {}.", structure_name, expected_fuel, item_def.name));
+        }
+        Ok(())
     }
 
-    ctx.db.lantern().id().update(lantern.clone());
-    schedule_next_lantern_processing(ctx, source_lantern_id);
-    Ok(())
+    /// Only extinguish immediately if there's no fuel in slots AND no remaining burn time
+    /// (already-consumed fuel keeps burning until it runs out)
+    fn after_operation(&mut self, ctx: &ReducerContext, op: &inventory_management::ContainerOp, _before: &Self) {
+        if !op.removes_items() || !self.is_burning {
+            return;
+        }
+        let has_remaining_burn_time = self.remaining_fuel_burn_time_secs.map_or(false, |t| t > 0.0);
+        if !has_remaining_burn_time && !check_if_lantern_has_fuel(ctx, self) {
+            self.is_burning = false;
+            self.current_fuel_def_id = None;
+            self.remaining_fuel_burn_time_secs = None;
+            log::info!("Lantern {} extinguished as last valid fuel was removed and no burn time remaining.", self.id);
+        }
+    }
 }
 
-/// --- Split and Drop Item from Lantern Slot to World ---
-/// Splits a specified quantity from a lantern slot and drops it as a world item.
-#[spacetimedb::reducer]
-pub fn split_and_drop_item_from_lantern_slot_to_world(
-    ctx: &ReducerContext,
-    lantern_id: u32,
-    slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (_player, mut lantern) = validate_lantern_interaction(ctx, lantern_id)?;
-    
-    // Get the item in the slot
-    let item_instance_id = lantern.get_slot_instance_id(slot_index)
-        .ok_or_else(|| format!("No item in lantern {} slot {}", lantern_id, slot_index))?;
-    
-    let mut source_item = ctx.db.inventory_item().instance_id().find(item_instance_id)
-        .ok_or_else(|| format!("Item instance {} not found", item_instance_id))?;
-    
-    if source_item.quantity < quantity_to_split {
-        return Err(format!("Cannot split {} items, only {} available", quantity_to_split, source_item.quantity));
-    }
-    
-    // Create the dropped item entity directly in the world
-    create_dropped_item_entity_with_data(
-        ctx,
-        source_item.item_def_id,
-        quantity_to_split,
-        lantern.pos_x,
-        lantern.pos_y,
-        source_item.item_data.clone()
-    )?;
-    
-    // Update source item quantity
-    source_item.quantity -= quantity_to_split;
-    
-    if source_item.quantity == 0 {
-        // Remove empty item and clear slot
-        ctx.db.inventory_item().instance_id().delete(item_instance_id);
-        lantern.set_slot(slot_index, None, None);
-    } else {
-        ctx.db.inventory_item().instance_id().update(source_item);
-    }
-    
-    // Check if lantern should be extinguished after fuel removal
-    let still_has_fuel = check_if_lantern_has_fuel(ctx, &lantern);
-    if !still_has_fuel && lantern.is_burning {
-        lantern.is_burning = false;
-        lantern.current_fuel_def_id = None;
-        lantern.remaining_fuel_burn_time_secs = None;
-        log::info!("Lantern {} extinguished as last valid fuel was removed.", lantern_id);
-    }
-    
-    ctx.db.lantern().id().update(lantern.clone());
-    schedule_next_lantern_processing(ctx, lantern_id);
-    Ok(())
+crate::container_reducers! {
+    Lantern;
+    move_to: move_item_to_lantern(lantern_id),
+    quick_move_from: quick_move_from_lantern(lantern_id),
+    split_into_item_first: split_stack_into_lantern(target_lantern_id),
+    move_within: move_item_within_lantern(lantern_id),
+    split_within_quantity_first: split_stack_within_lantern(lantern_id),
+    quick_move_to: quick_move_to_lantern(lantern_id),
+    move_from: move_item_from_lantern_to_player_slot(lantern_id),
+    split_from: split_stack_from_lantern(source_lantern_id),
+    drop: drop_item_from_lantern_slot_to_world(lantern_id),
+    split_and_drop: split_and_drop_item_from_lantern_slot_to_world(lantern_id),
 }
 
 /// --- Interact with Lantern ---
@@ -81,6 +81,10 @@ mod refrigerator; // Refrigerator-specific container logic
 mod compost; // Compost-specific container logic
 mod fish_trap; // Fish trap-specific container logic (passive fishing)
 mod beehive; // Player beehive - honeycomb production system
+mod sprinkler; // Irrigation sprinklers - automatic crop watering from rain collectors
+mod greenhouse; // Greenhouses - crops inside ignore seasons/storms and are safe from wildlife
+mod feeding_trough; // Feeding troughs - tamed animal hunger and upkeep
+mod kennel; // Kennels and stables - store tamed animals while offline
 mod backpack; // Backpack auto-consolidation system
 
 mod items_database; // <<< NEW: Modular items database
@@ -117,7 +121,9 @@ mod knocked_out; // <<< ADDED knocked out recovery module
 mod bones; // <<< ADDED bones module
 mod ranged_weapon_stats; // Add this line
 mod projectile; // Add this line
+mod ammunition; // <<< ADDED: Ammunition type profiles (damage mode, speed, on-hit effects, weapon wear)
 mod death_marker; // <<< ADDED death marker module
+mod kill_feed; // <<< ADDED: Kill feed and death recap tables
 mod torch; // <<< ADDED torch module
 mod flashlight; // <<< ADDED flashlight module
 mod headlamp; // <<< ADDED headlamp module
@@ -128,6 +134,8 @@ mod world_generation; // <<< ADDED world generation module
 mod fishing; // <<< ADDED fishing module
 mod drinking; // <<< ADDED drinking module
 mod wet; // <<< ADDED wet status effect module
+mod temperature; // Unified per-player temperature model (biome, time of day, weather, heat sources, clothing)
+mod hallucination; // Insanity hallucinations (phantom hostiles, minimap distortion, SOVA encounters) and rune stone rituals
 mod sound_events; // <<< ADDED sound events module
 mod rain_collector; // <<< ADDED rain collector module
 mod water_patch; // <<< ADDED water patch module for crop watering
@@ -136,11 +144,20 @@ mod tilled_tiles; // <<< ADDED tilled tiles module for farming soil preparation
 pub mod wild_animal_npc; // <<< ADDED wild animal NPC system (now modular, includes hostile NPCs)
 mod animal_collision; // <<< ADDED animal collision system
 mod barrel; // <<< ADDED roadside barrel loot system
+mod loot_table; // <<< ADDED: Shared data-driven loot tables (barrels, crates, corpses, hostile NPCs)
 mod metadata_providers; // <<< ADDED: Provides plant/seed metadata to client
 mod sea_stack; // <<< ADDED: Sea stack decorative entities
 mod memory_grid; // <<< ADDED: Memory Grid tech tree system
 mod building; // <<< ADDED: Building system (foundations, walls, doors)
 mod building_enclosure; // <<< ADDED: Building enclosure detection (rain protection, "inside" logic)
+mod building_floor; // <<< ADDED: Second-story floors, stairs and player elevation
+mod sign; // <<< ADDED: Sign and banner placeables with editable text
+mod item_cosmetic; // <<< ADDED: Cosmetic item skins
+mod meteor_shower; // <<< ADDED: Meteor shower world event with temporary rare ore nodes
+mod lightning; // <<< ADDED: Lightning strikes during thunderstorms (damage, fire patches, fulgurite)
+mod ice; // <<< ADDED: Seasonal sea ice overlay (walkable ice, fishing holes, cracking under sprinters)
+mod tracks; // <<< ADDED: Footprints in tundra and snow that skilled hunters can read
+mod supply_drop; // <<< ADDED: Random supply crate drops with tiered loot
 mod door; // <<< ADDED: Door system for building entrances
 mod fence; // <<< ADDED: Fence system for crop/base protection
 mod fumarole; // <<< ADDED: Fumarole module for quarry geothermal vents
@@ -159,6 +176,30 @@ mod grenade; // <<< ADDED: Grenade fuse system (armed grenades as items)
 mod ai_brewing; // <<< ADDED: AI-generated brew recipes system
 mod alk; // <<< ADDED: ALK (Automated Logistics Kernel) provisioning system
 mod matronage; // <<< ADDED: Matronage pooled rewards system
+mod control_point; // <<< ADDED: Weekly-contested territory control points at monuments
+mod combat_log; // <<< ADDED: Combat logging penalty (unprotected offline corpse + public marker)
+mod safe_zone; // <<< ADDED: Configurable PvP/hostile-free safe zones (ALK, villages) with re-entry grace
+mod combat_balance; // <<< ADDED: Live-tunable knockback and stagger config with admin reducers
+mod throwables; // <<< ADDED: Thrown stones, smoke bombs and flashbangs with area effects on impact
+mod decoy; // <<< ADDED: Noisemaker decoys that draw hostiles to investigate
+mod hunting_blind; // <<< ADDED: Hunting blinds that hide players from animals and hostile NPCs
+mod spyglass; // <<< ADDED: Spyglass and binoculars that widen the streamed viewport while scoping
+mod security_camera; // <<< ADDED: Security cameras watched from a monitor station
+mod alarm_trap; // <<< ADDED: Tripwire alarms that message their owner when an intruder crosses them
+mod corpse_decay; // <<< ADDED: Corpse decay stages and scavenger feeding
+mod tanning_rack; // <<< ADDED: Tanning racks that cure raw hides into leather
+mod workbench; // <<< ADDED: Tiered workbenches gating crafting recipes
+mod research; // <<< ADDED: Research tables and learned blueprints for locked recipes
+mod recycler; // <<< ADDED: Recyclers and shredders that break items into crafting materials
+mod locked_crate; // <<< ADDED: Keycard and lockpick locked loot crates at monuments
+mod monument_puzzle; // <<< ADDED: Monument puzzle rooms with switches, fuse boxes and timed vault doors
+mod world_regeneration; // <<< ADDED: Admin world regeneration from a seed, keeping players and their bases
+mod chunk_streaming; // <<< ADDED: Viewport-driven chunk visibility (RLS) for grass, dropped items and animals
+mod hotbar_binding; // <<< ADDED: Per-slot hotbar item type bindings and hotbar slot swapping
+mod loadout; // <<< ADDED: Named armor and hotbar loadout presets
+mod respawn_points; // <<< ADDED: Per-player respawn point list, bag naming/giving and shared respawn cooldowns
+mod ghost; // <<< ADDED: Ghost camera around the corpse between death and respawn
+mod restraints; // <<< ADDED: Handcuffing knocked out players, struggling free and releasing
 pub mod compound_buildings; // <<< ADDED: Static compound building collision system
 mod shipwreck; // <<< ADDED: Shipwreck monument collision system
 mod fishing_village; // <<< ADDED: Fishing village monument collision system
@@ -169,6 +210,7 @@ mod transistor_radio; // <<< ADDED: Transistor Radio spawn/respawn system
 mod tide_pool_items; // <<< ADDED: Tide pool washed-up item respawn (Coral Fragments, Plastic Water Jug, Vitamin Drink)
 pub mod monument; // <<< ADDED: Generic monument system for clearance zones (shipwrecks, ruins, crash sites, etc.)
 mod durability; // <<< ADDED: Item durability system for weapons, tools, and torches
+mod powered_tool; // <<< ADDED: Fuel-driven chainsaw/jackhammer tanks, fuel drain schedule and noise
 mod placeable_collision; // <<< ADDED: Shared placeable overlap prevention
 mod repair_bench; // <<< ADDED: Repair bench for item repair
 mod cooking_station; // <<< ADDED: Cooking station for advanced food recipes
@@ -484,6 +526,8 @@ use crate::wild_animal_npc::wild_animal_ai_schedule as WildAnimalAiScheduleTable
 use crate::wild_animal_npc::animal_corpse as AnimalCorpseTableTrait; // <<< ADDED: Import AnimalCorpse table trait
 use crate::wild_animal_npc::caribou::caribou_breeding_data as CaribouBreedingDataTableTrait; // <<< ADDED: Caribou breeding system
 use crate::wild_animal_npc::caribou::caribou_breeding_schedule as CaribouBreedingScheduleTableTrait; // <<< ADDED: Caribou breeding system
+use crate::wild_animal_npc::migration::caribou_migration_schedule as CaribouMigrationScheduleTableTrait; // <<< For pause/resume
+use crate::wild_animal_npc::population::animal_population_census_schedule as AnimalPopulationCensusScheduleTableTrait; // <<< For pause/resume
 use crate::wild_animal_npc::caribou::caribou_rut_state as CaribouRutStateTableTrait; // <<< ADDED: Caribou breeding system
 use crate::barrel::barrel as BarrelTableTrait; // <<< ADDED: Import Barrel table trait
 use crate::barrel::barrel_respawn_schedule as BarrelRespawnScheduleTableTrait; // <<< ADDED: Import BarrelRespawnSchedule table trait
@@ -507,6 +551,8 @@ use crate::drone::drone_daily_schedule as DroneDailyScheduleTableTrait; // <<< F
 use crate::drone::drone_flight_schedule as DroneFlightScheduleTableTrait; // <<< For pause/resume
 use crate::durability::torch_durability_schedule as TorchDurabilityScheduleTableTrait; // <<< For pause/resume
 use crate::durability::food_spoilage_schedule as FoodSpoilageScheduleTableTrait; // <<< For pause/resume
+use crate::powered_tool::powered_tool_fuel_schedule as PoweredToolFuelScheduleTableTrait; // <<< For pause/resume
+use crate::rain_collector::water_purifier_schedule as WaterPurifierScheduleTableTrait; // <<< For pause/resume
 use crate::planted_seeds::planted_seed_growth_schedule as PlantedSeedGrowthScheduleTableTrait; // <<< For pause/resume
 use crate::grass::grass_respawn_batch_schedule as GrassRespawnBatchScheduleTableTrait; // <<< For pause/resume
 use crate::fire_patch::fire_patch_cleanup_schedule as FirePatchCleanupScheduleTableTrait; // <<< For pause/resume
@@ -518,6 +564,7 @@ use crate::building_decay::building_decay_schedule as BuildingDecayScheduleTable
 use crate::fertilizer_patch::fertilizer_patch_cleanup_schedule as FertilizerPatchCleanupScheduleTableTrait; // <<< For pause/resume
 use crate::active_equipment::water_container_fill_schedule as WaterContainerFillScheduleTableTrait; // <<< For pause/resume
 use crate::matronage::matronage_payout_schedule as MatronagePayoutScheduleTableTrait; // <<< For pause/resume
+use crate::control_point::control_point_schedule as ControlPointScheduleTableTrait; // <<< For pause/resume
 use crate::alk::alk_contract_refresh_schedule as AlkContractRefreshScheduleTableTrait; // <<< For pause/resume
 use crate::wild_animal_npc::walrus::walrus_breeding_schedule as WalrusBreedingScheduleTableTrait; // <<< For pause/resume
 use crate::water_patch::water_patch_cleanup_schedule as WaterPatchCleanupScheduleTableTrait; // <<< For pause/resume
@@ -530,6 +577,9 @@ use crate::backpack::backpack_consolidation_schedule as BackpackConsolidationSch
 use crate::explosive::explosive_detonation_schedule as ExplosiveDetonationScheduleTableTrait; // <<< For pause/resume
 use crate::grenade::grenade_fuse_schedule as GrenadeFuseScheduleTableTrait; // <<< For pause/resume
 use crate::beehive::beehive_process_schedule as BeehiveProcessScheduleTableTrait; // <<< For pause/resume
+use crate::sprinkler::sprinkler_process_schedule as SprinklerProcessScheduleTableTrait; // <<< For pause/resume
+use crate::feeding_trough::feeding_process_schedule as FeedingProcessScheduleTableTrait; // <<< For pause/resume
+use crate::kill_feed::kill_feed_cleanup_schedule as KillFeedCleanupScheduleTableTrait; // <<< For pause/resume
 use crate::sea_stack::sea_stack as SeaStackTableTrait; // <<< ADDED: Import SeaStack table trait
 use crate::player_corpse::player_corpse as PlayerCorpseTableTrait; // <<< ADDED: Import PlayerCorpse table trait
 use crate::player_progression::player_stats as PlayerStatsTableTrait; // <<< ADDED: Import PlayerStats table trait
@@ -603,12 +653,20 @@ pub fn world_pos_to_tile_coords(world_x: f32, world_y: f32) -> (i32, i32) {
 /// Checks if a player is standing on a water tile (Sea type)
 /// This is highly optimized using direct tile coordinate lookup
 /// NEW: Uses compressed chunk data for much better performance
+/// Tide-aware: tidal flats exposed at low tide count as land
 pub fn is_player_on_water(ctx: &ReducerContext, player_x: f32, player_y: f32) -> bool {
     // Convert player position to tile coordinates
     let (tile_x, tile_y) = world_pos_to_tile_coords(player_x, player_y);
     
     // NEW: Try compressed lookup first for much better performance
     if let Some(tile_type) = get_tile_type_at_position(ctx, tile_x, tile_y) {
+        // Tides only reach the sea, never rivers or lakes
+        if tile_type == TileType::Sea && world_state::is_tile_exposed_by_tide(ctx, tile_x, tile_y) {
+            return false; // Walkable tidal flat
+        }
+        if matches!(tile_type, TileType::Sea | TileType::Freshwater) && ice::is_tile_frozen(ctx, tile_x, tile_y) {
+            return false; // Walkable sea, river or lake ice
+        }
         return tile_type.is_water(); // Includes both Sea and HotSpringWater
     }
     
@@ -624,6 +682,20 @@ pub fn is_player_on_water(ctx: &ReducerContext, player_x: f32, player_y: f32) ->
     false
 }
 
+/// Checks if a world position is on a cliff tile (impassable terrain)
+pub fn is_position_on_cliff(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    let (tile_x, tile_y) = world_pos_to_tile_coords(pos_x, pos_y);
+    if let Some(tile_type) = get_tile_type_at_position(ctx, tile_x, tile_y) {
+        return tile_type.is_cliff();
+    }
+    
+    // FALLBACK: Use original method if compressed data not available
+    for tile in ctx.db.world_tile().idx_world_position().filter((tile_x, tile_y)) {
+        return tile.tile_type.is_cliff();
+    }
+    false
+}
+
 /// Checks if a player is currently jumping (in the air)
 /// Returns true if the player started a jump and is still within the jump duration
 pub fn is_player_jumping(jump_start_time_ms: u64, current_time_ms: u64) -> bool {
@@ -656,6 +728,13 @@ pub fn is_player_on_water_compressed(ctx: &ReducerContext, player_x: f32, player
         // Check bounds and extract tile type
         if tile_index < chunk.tile_types.len() {
             if let Some(tile_type) = TileType::from_u8(chunk.tile_types[tile_index]) {
+                // Tides only reach the sea, never rivers or lakes
+                if tile_type == TileType::Sea && world_state::is_tile_exposed_by_tide(ctx, tile_x, tile_y) {
+                    return false; // Walkable tidal flat
+                }
+                if matches!(tile_type, TileType::Sea | TileType::Freshwater) && ice::is_tile_frozen(ctx, tile_x, tile_y) {
+                    return false; // Walkable sea, river or lake ice
+                }
                 return tile_type.is_water(); // Includes both Sea and HotSpringWater
             }
         }
@@ -739,6 +818,7 @@ pub struct Player {
     pub insanity: f32, // NEW: Hidden stat that increases when carrying memory shards or mining them (0.0-100.0)
     pub last_insanity_threshold: f32, // NEW: Last insanity threshold crossed (for SOVA sound triggers: 0.0, 25.0, 50.0, 75.0, 90.0, 100.0)
     pub shard_carry_start_time: Option<Timestamp>, // NEW: When player started carrying memory shards (for time-based insanity scaling)
+    pub oxygen: f32, // Breath while diving (0.0-100.0) - drains while snorkeling, refills at the surface
     pub offline_corpse_id: Option<u32>, // Links to corpse created when player went offline
     pub is_aiming_throw: bool, // NEW: Tracks if player is in throw-aiming state (right mouse held)
     // Unified tutorial progress: list of tutorial IDs this player has completed.
@@ -750,6 +830,8 @@ pub struct Player {
     // === NPC Agent Fields ===
     pub is_npc: bool, // True for ElizaOS-driven NPC agents, false for human players
     pub npc_role: String, // NPC role identifier: "gatherer", "warrior", "builder", "trader", etc. Empty for humans.
+    pub elevation: u8, // Building level the player stands on (0 = ground, 1 = second floor via stairs)
+    pub is_sleeping: bool, // Lying in a sleeping bag at night (see sleeping_bag) - blocks movement and attacks
 }
 
 pub const TUTORIAL_ID_MEMORY_SHARD: &str = "memoryShard";
@@ -829,11 +911,14 @@ pub fn init_module(ctx: &ReducerContext) -> Result<(), String> {
     crate::items::seed_food_poisoning_risks(ctx)?;
     crate::items::seed_ranged_weapon_stats(ctx)?;
     crate::crafting::seed_recipes(ctx)?;
+    // Seed loot sources (barrels, supply drops, hostile NPCs, animal corpses) - operator edits are kept
+    crate::loot_table::seed_loot_tables(ctx)?;
     // Seed plant configuration data for Encyclopedia
     crate::plants_database::populate_plant_config_definitions(ctx);
     // Seed progression system data
     crate::player_progression::seed_achievements(ctx)?;
     crate::player_progression::seed_daily_login_rewards(ctx)?;
+    crate::item_cosmetic::seed_skin_definitions(ctx)?;
     // Seed quest system data (tutorial + daily quests)
     crate::quests::init_quest_system(ctx)?;
     // NOTE: seed_environment is now called AFTER world generation (see below)
@@ -873,12 +958,39 @@ pub fn init_module(ctx: &ReducerContext) -> Result<(), String> {
     // ADD: Initialize player beehive production system
     crate::beehive::init_beehive_system(ctx)?;
     
+    // ADD: Initialize irrigation sprinkler system
+    crate::sprinkler::init_sprinkler_system(ctx)?;
+    
+    // ADD: Initialize tamed animal feeding system
+    crate::feeding_trough::init_feeding_system(ctx)?;
+    
     // ADD: Initialize fire patch cleanup system
     crate::fire_patch::init_fire_patch_system(ctx)?;
     
     // ADD: Initialize explosive system
     crate::explosive::init_explosive_system(ctx)?;
     
+    // ADD: Initialize noisemaker decoy pulse
+    crate::decoy::init_decoy_system(ctx)?;
+    
+    // ADD: Initialize security camera feeds
+    crate::security_camera::init_security_camera_system(ctx)?;
+    
+    // ADD: Initialize corpse decay
+    crate::corpse_decay::init_corpse_decay_system(ctx)?;
+    
+    // ADD: Initialize tanning rack curing
+    crate::tanning_rack::init_tanning_system(ctx)?;
+    
+    // ADD: Initialize recycler processing
+    crate::recycler::init_recycler_system(ctx)?;
+    
+    // ADD: Initialize monument puzzle rooms
+    crate::monument_puzzle::init_puzzle_system(ctx)?;
+    
+    // ADD: Initialize persistent spatial grid
+    crate::spatial_grid::init_spatial_grid_system(ctx)?;
+    
     // ADD: Initialize grenade fuse system
     crate::grenade::init_grenade_system(ctx)?;
     
@@ -891,6 +1003,9 @@ pub fn init_module(ctx: &ReducerContext) -> Result<(), String> {
     // ADD: Initialize sound event cleanup system
     crate::sound_events::init_sound_cleanup_system(ctx)?;
 
+    // ADD: Initialize kill feed cleanup system
+    crate::kill_feed::init_kill_feed_cleanup_schedule(ctx)?;
+
     // ADD: Initialize drone daily flyover system
     crate::drone::init_drone_system(ctx);
     
@@ -906,6 +1021,12 @@ pub fn init_module(ctx: &ReducerContext) -> Result<(), String> {
     // ADD: Initialize caribou breeding system (passive farming feature)
     crate::wild_animal_npc::init_caribou_breeding_schedule(ctx)?;
     
+    // ADD: Initialize caribou migration (seasonal herd movement between tundra and grassland)
+    crate::wild_animal_npc::migration::init_caribou_migration_system(ctx)?;
+    
+    // ADD: Initialize animal population census (biome-aware density balancing)
+    crate::wild_animal_npc::population::init_animal_population_system(ctx)?;
+    
     // ADD: Initialize walrus breeding system (passive farming feature)
     crate::wild_animal_npc::init_walrus_breeding_schedule(ctx)?;
     
@@ -915,6 +1036,9 @@ pub fn init_module(ctx: &ReducerContext) -> Result<(), String> {
     // ADD: Initialize hearth upkeep processing system
     crate::homestead_hearth::init_hearth_upkeep_schedule(ctx)?;
     
+    // Backfill privilege zones for hearths placed before zones existed
+    crate::homestead_hearth::sync_building_privilege_zones(ctx);
+    
     // ADD: Initialize building decay processing system
     crate::building_decay::init_building_decay_schedule(ctx)?;
     
@@ -929,6 +1053,15 @@ pub fn init_module(ctx: &ReducerContext) -> Result<(), String> {
     crate::rune_stone::init_rune_stone_item_spawning(ctx)?;
     crate::rune_stone::init_rune_stone_seed_spawning(ctx)?;
     
+    // ADD: Initialize territory control point processing
+    crate::control_point::init_control_point_system(ctx)?;
+    
+    // ADD: Seed safe zone configuration (ALK stations, villages)
+    crate::safe_zone::seed_safe_zone_configs(ctx)?;
+    
+    // ADD: Seed knockback/stagger tuning and register the publisher as balance admin
+    crate::combat_balance::seed_combat_balance(ctx)?;
+    
     // ADD: Initialize WorldState for scheduled systems
     crate::world_state::seed_world_state(ctx)?;
     
@@ -945,28 +1078,27 @@ pub fn init_module(ctx: &ReducerContext) -> Result<(), String> {
     // ADD: Initialize water container fill system for rain collection
     crate::active_equipment::init_water_container_fill_schedule(ctx)?;
     
+    // ADD: Initialize water purifier desalination system
+    crate::rain_collector::init_water_purifier_schedule(ctx)?;
+    
     // ADD: Initialize torch durability system
     crate::durability::init_torch_durability_schedule(ctx)?;
     
     // ADD: Initialize food spoilage system
     crate::durability::init_food_spoilage_schedule(ctx)?;
+    
+    // ADD: Initialize powered tool fuel drain system
+    crate::powered_tool::init_powered_tool_fuel_schedule(ctx)?;
+
+    // Fade footprints left in tundra and snow
+    crate::tracks::init_track_cleanup_schedule(ctx)?;
 
     // ADD: Generate world automatically on first startup
     let existing_tiles_count = ctx.db.world_tile().iter().count();
     if existing_tiles_count == 0 {
         log::info!("No world tiles found, generating initial world...");
         // Generate world with smaller size for better performance
-        let world_config = crate::WorldGenConfig {
-            seed: ctx.rng().gen::<u64>(), // Random seed each time using ctx.rng()
-            world_width_tiles: WORLD_WIDTH_TILES,  // Reduced from 250 for performance
-            world_height_tiles: WORLD_HEIGHT_TILES, // Reduced from 250 for performance  
-            chunk_size: environment::CHUNK_SIZE_TILES, // Use the same chunk size as runtime lookups
-            island_border_width: 5,  // Adjusted for smaller world
-            beach_width: 3,          // Adjusted for smaller world
-            river_frequency: 0.3,
-            dirt_patch_frequency: 0.2,
-            road_density: 0.1,
-        };
+        let world_config = crate::world_generation::default_world_gen_config(ctx.rng().gen::<u64>()); // Random seed each time using ctx.rng()
         
         match crate::world_generation::generate_world(ctx, world_config) {
             Ok(_) => {
@@ -1072,9 +1204,19 @@ pub fn init_module(ctx: &ReducerContext) -> Result<(), String> {
         }
     }
 
+    // Freeze the coasts for the current season (needs compressed chunk data)
+    let current_season = ctx.db.world_state().iter().next().map(|w| w.current_season).unwrap_or(crate::world_state::Season::Spring);
+    crate::ice::init_ice_system(ctx, &current_season);
+
+    // Place quest giver NPCs at the fishing village and ALK central compound (needs monuments + ALK stations)
+    crate::quests::spawn_quest_giver_npcs(ctx);
+
     // ADD: Initialize beacon event system (airdrop-style memory beacon spawning)
     crate::beacon_event::init_beacon_event_system(ctx);
 
+    // Schedule the first random supply drop
+    crate::supply_drop::init_supply_drop_system(ctx);
+
     log::info!("Module initialization complete.");
     Ok(())
 }
@@ -1180,6 +1322,10 @@ fn pause_game_systems(ctx: &ReducerContext) {
     for id in sound_ids {
         ctx.db.sound_event_cleanup_schedule().schedule_id().delete(&id);
     }
+    let kill_feed_ids: Vec<u64> = ctx.db.kill_feed_cleanup_schedule().iter().map(|r| r.id).collect();
+    for id in kill_feed_ids {
+        ctx.db.kill_feed_cleanup_schedule().id().delete(id);
+    }
     let thunder_cleanup_ids: Vec<u64> = ctx.db.thunder_event_cleanup_schedule().iter().map(|r| r.schedule_id).collect();
     for id in thunder_cleanup_ids {
         ctx.db.thunder_event_cleanup_schedule().schedule_id().delete(&id);
@@ -1212,6 +1358,14 @@ fn pause_game_systems(ctx: &ReducerContext) {
     for id in food_spoilage_ids {
         ctx.db.food_spoilage_schedule().schedule_id().delete(&id);
     }
+    let powered_tool_ids: Vec<u64> = ctx.db.powered_tool_fuel_schedule().iter().map(|r| r.schedule_id).collect();
+    for id in powered_tool_ids {
+        ctx.db.powered_tool_fuel_schedule().schedule_id().delete(&id);
+    }
+    let purifier_ids: Vec<u64> = ctx.db.water_purifier_schedule().iter().map(|r| r.schedule_id).collect();
+    for id in purifier_ids {
+        ctx.db.water_purifier_schedule().schedule_id().delete(&id);
+    }
     let barrel_ids: Vec<u64> = ctx.db.barrel_respawn_schedule().iter().map(|r| r.id).collect();
     for id in barrel_ids {
         ctx.db.barrel_respawn_schedule().id().delete(id);
@@ -1220,6 +1374,14 @@ fn pause_game_systems(ctx: &ReducerContext) {
     for id in caribou_ids {
         ctx.db.caribou_breeding_schedule().schedule_id().delete(&id);
     }
+    let migration_ids: Vec<u64> = ctx.db.caribou_migration_schedule().iter().map(|r| r.id).collect();
+    for id in migration_ids {
+        ctx.db.caribou_migration_schedule().id().delete(id);
+    }
+    let census_ids: Vec<u64> = ctx.db.animal_population_census_schedule().iter().map(|r| r.id).collect();
+    for id in census_ids {
+        ctx.db.animal_population_census_schedule().id().delete(id);
+    }
     let walrus_ids: Vec<u64> = ctx.db.walrus_breeding_schedule().iter().map(|r| r.schedule_id).collect();
     for id in walrus_ids {
         ctx.db.walrus_breeding_schedule().schedule_id().delete(&id);
@@ -1228,6 +1390,14 @@ fn pause_game_systems(ctx: &ReducerContext) {
     for id in beehive_ids {
         ctx.db.beehive_process_schedule().id().delete(id);
     }
+    let sprinkler_ids: Vec<u64> = ctx.db.sprinkler_process_schedule().iter().map(|r| r.id).collect();
+    for id in sprinkler_ids {
+        ctx.db.sprinkler_process_schedule().id().delete(id);
+    }
+    let feeding_ids: Vec<u64> = ctx.db.feeding_process_schedule().iter().map(|r| r.id).collect();
+    for id in feeding_ids {
+        ctx.db.feeding_process_schedule().id().delete(id);
+    }
     let planted_seed_ids: Vec<u64> = ctx.db.planted_seed_growth_schedule().iter().map(|r| r.id).collect();
     for id in planted_seed_ids {
         ctx.db.planted_seed_growth_schedule().id().delete(id);
@@ -1292,6 +1462,10 @@ fn pause_game_systems(ctx: &ReducerContext) {
     for id in matronage_ids {
         ctx.db.matronage_payout_schedule().id().delete(id);
     }
+    let control_point_ids: Vec<u64> = ctx.db.control_point_schedule().iter().map(|r| r.id).collect();
+    for id in control_point_ids {
+        ctx.db.control_point_schedule().id().delete(id);
+    }
     let rune_shard_ids: Vec<u64> = ctx.db.rune_stone_shard_spawn_schedule().iter().map(|r| r.id).collect();
     for id in rune_shard_ids {
         ctx.db.rune_stone_shard_spawn_schedule().id().delete(id);
@@ -1343,18 +1517,31 @@ fn resume_game_systems(ctx: &ReducerContext) -> Result<(), String> {
     crate::dropped_item::init_flare_expiry_schedule(ctx);
     crate::alk::init_alk_system(ctx)?;
     crate::sound_events::init_sound_cleanup_system(ctx)?;
+    crate::kill_feed::init_kill_feed_cleanup_schedule(ctx)?;
     crate::world_state::init_thunder_event_cleanup_schedule(ctx)?;
     // Note: seasonal_plant_management_schedule is created dynamically during tick_world_state when season changes
     crate::drone::init_drone_system(ctx);
     crate::compost::init_compost_system(ctx)?;
     crate::durability::init_torch_durability_schedule(ctx)?;
     crate::durability::init_food_spoilage_schedule(ctx)?;
+    crate::powered_tool::init_powered_tool_fuel_schedule(ctx)?;
     crate::barrel::init_barrel_system(ctx)?;
     crate::wild_animal_npc::caribou::init_caribou_breeding_schedule(ctx)?;
+    crate::wild_animal_npc::migration::init_caribou_migration_system(ctx)?;
+    crate::wild_animal_npc::population::init_animal_population_system(ctx)?;
     crate::wild_animal_npc::walrus::init_walrus_breeding_schedule(ctx)?;
     crate::beehive::init_beehive_system(ctx)?;
+    crate::sprinkler::init_sprinkler_system(ctx)?;
+    crate::feeding_trough::init_feeding_system(ctx)?;
     crate::planted_seeds::init_plant_growth_system(ctx)?;
     crate::explosive::init_explosive_system(ctx)?;
+    crate::decoy::init_decoy_system(ctx)?;
+    crate::security_camera::init_security_camera_system(ctx)?;
+    crate::corpse_decay::init_corpse_decay_system(ctx)?;
+    crate::tanning_rack::init_tanning_system(ctx)?;
+    crate::recycler::init_recycler_system(ctx)?;
+    crate::monument_puzzle::init_puzzle_system(ctx)?;
+    crate::spatial_grid::init_spatial_grid_system(ctx)?;
     crate::grenade::init_grenade_system(ctx)?;
     crate::grass::init_grass_respawn_scheduler(ctx);
     crate::fire_patch::init_fire_patch_system(ctx)?;
@@ -1367,10 +1554,12 @@ fn resume_game_systems(ctx: &ReducerContext) -> Result<(), String> {
     crate::fertilizer_patch::init_fertilizer_patch_system(ctx)?;
     crate::tilled_tiles::init_tilled_tile_system(ctx)?;
     crate::active_equipment::init_water_container_fill_schedule(ctx)?;
+    crate::rain_collector::init_water_purifier_schedule(ctx)?;
     crate::matronage::init_matronage_system(ctx)?;
     crate::rune_stone::init_rune_stone_shard_spawning(ctx)?;
     crate::rune_stone::init_rune_stone_item_spawning(ctx)?;
     crate::rune_stone::init_rune_stone_seed_spawning(ctx)?;
+    crate::control_point::init_control_point_system(ctx)?;
     Ok(())
 }
 
@@ -1440,6 +1629,9 @@ pub fn identity_connected(ctx: &ReducerContext) -> Result<(), String> {
             log::info!("[Connect] Set player {:?} to online.", client_identity);
         }
         
+        // Back online - drop any combat logger marker from the last session
+        crate::combat_log::clear_on_reconnect(ctx, client_identity);
+        
         // Handle Offline Corpse Restoration - restore items and delete corpse when player reconnects
         if let Some(corpse_id) = player.offline_corpse_id {
             log::info!("[Connect] Player {} has offline corpse ID {}. Checking if it still exists...", 
@@ -1470,6 +1662,7 @@ pub fn identity_connected(ctx: &ReducerContext) -> Result<(), String> {
                           corpse_id, player.username);
                 player.is_dead = true;
                 player.death_timestamp = Some(ctx.timestamp);
+                crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::Player(player.identity));
                 player.offline_corpse_id = None;
                 player_updated = true;
                 
@@ -1615,6 +1808,8 @@ pub fn identity_disconnected(ctx: &ReducerContext) {
             // --- Clean Up Connection --- 
             let was_last_player = active_connections.iter().count() == 1;
             active_connections.identity().delete(&sender_id);
+            crate::spyglass::stop_scoping(ctx, sender_id);
+            crate::chunk_streaming::clear_visible_chunks(ctx, sender_id);
             // --- END Clean Up Connection --- 
 
             // --- Pause game systems when last player leaves (saves ~23 tx/sec when idle) ---
@@ -1637,6 +1832,8 @@ pub fn identity_disconnected(ctx: &ReducerContext) {
                                 player.offline_corpse_id = Some(corpse_id);
                                 log::info!("[Disconnect] Created offline corpse {} for {} {:?}", 
                                     corpse_id, if player.is_npc { "NPC" } else { "player" }, sender_id);
+                                // Logging out mid-fight leaves the body unprotected
+                                crate::combat_log::handle_disconnect(ctx, sender_id, corpse_id);
                             }
                             Err(e) => {
                                 log::error!("[Disconnect] Failed to create offline corpse for {:?}: {}", sender_id, e);
@@ -1719,6 +1916,7 @@ pub fn register_player(ctx: &ReducerContext, username: String) -> Result<(), Str
                           corpse_id, existing_player.username);
                 existing_player.is_dead = true;
                 existing_player.death_timestamp = Some(ctx.timestamp);
+                crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::Player(existing_player.identity));
                 existing_player.offline_corpse_id = None;
                 
                 // Clear all active effects on death (any lingering effects from before disconnect)
@@ -2284,6 +2482,7 @@ pub fn register_player(ctx: &ReducerContext, username: String) -> Result<(), Str
         insanity: 0.0, // NEW: Start with no insanity
         last_insanity_threshold: 0.0, // NEW: No threshold crossed initially
         shard_carry_start_time: None, // NEW: Not carrying shards initially
+        oxygen: crate::player_stats::PLAYER_MAX_OXYGEN, // Full breath on spawn
         offline_corpse_id: None, // No offline corpse for new players
         seen_tutorial_ids: Vec::new(), // New players start with no completed tutorials
         pvp_enabled: false, // PvP disabled by default
@@ -2292,12 +2491,15 @@ pub fn register_player(ctx: &ReducerContext, username: String) -> Result<(), Str
         // NPC fields - human players are never NPCs
         is_npc: false,
         npc_role: String::new(),
+        elevation: crate::building_floor::ELEVATION_GROUND,
+        is_sleeping: false,
     };
 
     // Insert the new player
     match players.try_insert(player) {
         Ok(inserted_player) => {
             log::info!("Player registered: {}. Granting starting items...", username);
+            crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(inserted_player.identity), inserted_player.position_x, inserted_player.position_y);
 
             // --- ADD ActiveConnection record for NEW player ---
              let connection_id = ctx.connection_id().ok_or_else(|| {
@@ -2448,6 +2650,7 @@ pub fn register_npc(ctx: &ReducerContext, username: String, role: String) -> Res
         insanity: 0.0,
         last_insanity_threshold: 0.0,
         shard_carry_start_time: None,
+        oxygen: crate::player_stats::PLAYER_MAX_OXYGEN,
         offline_corpse_id: None,
         seen_tutorial_ids: ALL_TUTORIAL_IDS.iter().map(|id| (*id).to_string()).collect(), // NPCs skip tutorials
         pvp_enabled: false,
@@ -2456,11 +2659,14 @@ pub fn register_npc(ctx: &ReducerContext, username: String, role: String) -> Res
         // NPC-specific fields
         is_npc: true,
         npc_role: role.clone(),
+        elevation: crate::building_floor::ELEVATION_GROUND,
+        is_sleeping: false,
     };
 
     match players.try_insert(player) {
-        Ok(_inserted) => {
+        Ok(inserted) => {
             log::info!("[NPC] NPC registered: {} (role: {}). Granting starting items...", username, role);
+            crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(inserted.identity), inserted.position_x, inserted.position_y);
 
             // Track connection for NPCs too
             if let Some(connection_id) = ctx.connection_id() {
@@ -2571,8 +2777,8 @@ fn spawn_starter_beach_lyme_grass(ctx: &ReducerContext, spawn_x: f32, spawn_y: f
 /// Reducer that handles client viewport updates.
 /// 
 /// This reducer is called by the client to update their visible game area boundaries.
-/// It stores the viewport coordinates for each client, which can be used for
-/// optimizing game state updates and rendering.
+/// It stores the viewport coordinates for each client and refreshes the chunks
+/// streamed to it (see chunk_streaming).
 #[spacetimedb::reducer]
 pub fn update_viewport(ctx: &ReducerContext, min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Result<(), String> {
     let client_id = ctx.sender();
@@ -2604,6 +2810,7 @@ pub fn update_viewport(ctx: &ReducerContext, min_x: f32, min_y: f32, max_x: f32,
             }
         }
     }
+    crate::chunk_streaming::refresh_visible_chunks(ctx, client_id, min_x, min_y, max_x, max_y);
     Ok(())
 }
 
@@ -2625,13 +2832,16 @@ pub enum TileType {
     Alpine,      // High-altitude rocky terrain (far north - sparse, rocky)
     TundraGrass, // Grassy patches within tundra biome (lighter green tundra grass)
     Tilled,      // Temporarily tilled soil for farming (+50% growth bonus, reverts after 48h)
+    Freshwater,  // Rivers and lakes - drinkable, fished for river/lake species, distinct from Sea
+    Cliff,       // Rocky escarpment between terrain levels - blocks walking and projectiles, not vision
+    Ramp,        // Walkable slope cut through a cliff line
 }
 
 impl TileType {
-    /// Returns true if this tile type is any form of water (Sea, DeepSea, or HotSpringWater)
-    /// Use this instead of checking `== TileType::Sea` to include hot springs and deep sea
+    /// Returns true if this tile type is any form of water (Sea, DeepSea, Freshwater, or HotSpringWater)
+    /// Use this instead of checking `== TileType::Sea` to include rivers, lakes, hot springs and deep sea
     pub fn is_water(&self) -> bool {
-        matches!(self, TileType::Sea | TileType::DeepSea | TileType::HotSpringWater)
+        matches!(self, TileType::Sea | TileType::DeepSea | TileType::Freshwater | TileType::HotSpringWater)
     }
     
     /// Returns true if this tile type is specifically ocean/sea water (not hot springs)
@@ -2639,32 +2849,42 @@ impl TileType {
         matches!(self, TileType::Sea | TileType::DeepSea)
     }
     
+    /// Returns true if this tile is fresh (non-salt) water: rivers, lakes and hot springs
+    pub fn is_fresh_water(&self) -> bool {
+        matches!(self, TileType::Freshwater | TileType::HotSpringWater)
+    }
+    
     /// Returns true if this tile type is hot spring water
     pub fn is_hot_spring_water(&self) -> bool {
         matches!(self, TileType::HotSpringWater)
     }
     
-    /// Returns true if this tile is walkable (not water)
+    /// Returns true if this tile is a cliff face (impassable on foot, stops projectiles)
+    pub fn is_cliff(&self) -> bool {
+        matches!(self, TileType::Cliff)
+    }
+    
+    /// Returns true if this tile is walkable (not water or cliff)
     pub fn is_walkable(&self) -> bool {
-        !self.is_water()
+        !self.is_water() && !self.is_cliff()
     }
     
     /// Returns true if this tile can be fished in
     pub fn is_fishable(&self) -> bool {
-        // Both sea and hot springs can be fished
+        // Sea, rivers/lakes and hot springs can all be fished
         self.is_water()
     }
     
     /// Returns true if drinking is allowed from this tile
     pub fn is_drinkable(&self) -> bool {
-        // Both sea and hot springs can be drunk from
+        // Any water can be drunk from - only fresh water hydrates without poisoning
         self.is_water()
     }
     
     /// Returns true if this tile should block building placement
     pub fn blocks_building(&self) -> bool {
-        // Water tiles and asphalt (compounds) block building
-        matches!(self, TileType::Sea | TileType::DeepSea | TileType::HotSpringWater | TileType::Asphalt)
+        // Water tiles, asphalt (compounds), cliffs and their ramps block building
+        matches!(self, TileType::Sea | TileType::DeepSea | TileType::Freshwater | TileType::HotSpringWater | TileType::Asphalt | TileType::Cliff | TileType::Ramp)
     }
     
     /// Returns true if this tile should have water visual effects (waves, etc.)
@@ -2707,6 +2927,12 @@ pub struct WorldGenConfig {
     pub river_frequency: f32,     // 0.0-1.0
     pub dirt_patch_frequency: f32,
     pub road_density: f32,
+    pub tundra_fraction: f32,        // Share of the map (by latitude) that is tundra, 0.0-0.6
+    pub forest_density: f32,         // Forest coverage, 1.0 = default (higher = more forest)
+    pub quarry_count_scale: f32,     // Quarry count multiplier on top of map-size scaling
+    pub hot_spring_count_scale: f32, // Hot spring count multiplier on top of map-size scaling
+    pub wolf_den_count: u32,         // Wolf den monuments in the tundra
+    pub forest_ruin_count: u32,      // Abandoned cabins and ruined towers
 }
 
 // ADD: Compressed chunk data table for efficient tile transmission
@@ -2877,6 +3103,8 @@ pub enum MonumentType {
     HotSpring,
     WeatherStation,
     WolfDen,
+    AbandonedCabin,
+    RuinedTower,
 }
 
 /// Unified monument part table for all monument types
@@ -2977,6 +3205,9 @@ impl TileType {
             TileType::TundraGrass => 12,
             TileType::Tilled => 13,
             TileType::DeepSea => 14,
+            TileType::Freshwater => 15,
+            TileType::Cliff => 16,
+            TileType::Ramp => 17,
         }
     }
     
@@ -2998,6 +3229,9 @@ impl TileType {
             12 => Some(TileType::TundraGrass),
             13 => Some(TileType::Tilled),
             14 => Some(TileType::DeepSea),
+            15 => Some(TileType::Freshwater),
+            16 => Some(TileType::Cliff),
+            17 => Some(TileType::Ramp),
             _ => None,
         }
     }
@@ -3019,7 +3253,7 @@ impl TileType {
     
     /// Returns true if this tile can support trees (not water, alpine, or paved)
     pub fn can_have_trees(&self) -> bool {
-        !matches!(self, TileType::Sea | TileType::DeepSea | TileType::HotSpringWater | TileType::Asphalt | TileType::Alpine | TileType::Beach | TileType::Sand)
+        !matches!(self, TileType::Sea | TileType::DeepSea | TileType::Freshwater | TileType::HotSpringWater | TileType::Asphalt | TileType::Alpine | TileType::Beach | TileType::Sand | TileType::Cliff | TileType::Ramp)
     }
     
     /// Returns true if this tile is prepared soil (Dirt or Tilled) for farming growth bonus
@@ -3033,7 +3267,10 @@ impl TileType {
         !matches!(self, 
             TileType::Sea | 
             TileType::DeepSea | 
+            TileType::Freshwater | 
             TileType::HotSpringWater | 
+            TileType::Cliff | 
+            TileType::Ramp | 
             TileType::Asphalt | 
             TileType::DirtRoad | 
             TileType::Quarry |
@@ -0,0 +1,141 @@
+/******************************************************************************
+ *                                                                            *
+ * Lightning Strikes                                                          *
+ *                                                                            *
+ * Every thunder flash in a HeavyStorm chunk (see world_state.rs) also lands *
+ * a lightning strike somewhere in that chunk. The strike burns players and  *
+ * damages animals close to the impact point, sets a fire patch on land, and *
+ * very rarely leaves a Fulgurite deposit behind.                             *
+ *                                                                            *
+ * Players wearing metal armor out in the open (not inside a building or     *
+ * under forest cover) draw strikes onto themselves.                          *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table};
+use rand::Rng;
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::wild_animal_npc::wild_animal as WildAnimalTableTrait;
+use crate::harvestable_resource::harvestable_resource as HarvestableResourceTableTrait;
+use crate::environment::{calculate_chunk_index, is_position_on_water, CHUNK_SIZE_PX, WORLD_WIDTH_CHUNKS};
+use crate::plants_database::PlantType;
+use crate::TileType;
+
+// === CONSTANTS ===
+
+/// Radius around the impact point that takes damage
+pub const LIGHTNING_STRIKE_RADIUS: f32 = 96.0;
+pub const LIGHTNING_STRIKE_RADIUS_SQUARED: f32 = LIGHTNING_STRIKE_RADIUS * LIGHTNING_STRIKE_RADIUS;
+
+/// Burn dealt to players in the strike radius over a short duration
+pub const LIGHTNING_PLAYER_BURN_DAMAGE: f32 = 40.0;
+pub const LIGHTNING_PLAYER_BURN_DURATION_SECS: f32 = 4.0;
+pub const LIGHTNING_PLAYER_BURN_TICK_SECS: f32 = 1.0;
+
+/// Direct damage to animals in the strike radius
+pub const LIGHTNING_ANIMAL_DAMAGE: f32 = 60.0;
+
+/// Chance that a strike is drawn to an exposed player wearing metal armor in the chunk
+pub const METAL_ARMOR_STRIKE_CHANCE: f32 = 0.35;
+
+/// Chance a strike on land fuses the ground into a Fulgurite deposit
+pub const FULGURITE_SPAWN_CHANCE: f32 = 0.02;
+
+// === STRIKES ===
+
+/// Lands a lightning strike in a storm chunk (called with each thunder flash)
+pub fn strike_lightning_in_chunk(ctx: &ReducerContext, chunk_index: u32, rng: &mut impl Rng) {
+    let (strike_x, strike_y) = pick_strike_position(ctx, chunk_index, rng);
+    log::info!("[Lightning] ⚡ Strike in chunk {} at ({:.1}, {:.1})", chunk_index, strike_x, strike_y);
+
+    damage_players_near_strike(ctx, strike_x, strike_y);
+    damage_animals_near_strike(ctx, strike_x, strike_y);
+
+    if is_position_on_water(ctx, strike_x, strike_y) {
+        return; // Nothing to ignite or fuse on water
+    }
+
+    if let Err(e) = crate::fire_patch::create_fire_patch(ctx, strike_x, strike_y, ctx.identity(), false, None, None) {
+        log::debug!("[Lightning] No fire patch at strike point: {}", e);
+    }
+
+    if rng.gen::<f32>() < FULGURITE_SPAWN_CHANCE {
+        let fulgurite = crate::harvestable_resource::create_harvestable_resource(
+            PlantType::Fulgurite,
+            strike_x,
+            strike_y,
+            calculate_chunk_index(strike_x, strike_y),
+            false, // Not player-planted (lightning deposit)
+        );
+        ctx.db.harvestable_resource().insert(fulgurite);
+        log::info!("[Lightning] Strike fused a Fulgurite deposit at ({:.1}, {:.1})", strike_x, strike_y);
+    }
+}
+
+/// Strike point: an exposed metal-armored player in the chunk if one draws it, otherwise a random point
+fn pick_strike_position(ctx: &ReducerContext, chunk_index: u32, rng: &mut impl Rng) -> (f32, f32) {
+    let lightning_rods: Vec<(f32, f32)> = ctx.db.player().iter()
+        .filter(|p| p.is_online && !p.is_dead)
+        .filter(|p| calculate_chunk_index(p.position_x, p.position_y) == chunk_index)
+        .filter(|p| is_in_open_ground(ctx, p))
+        .filter(|p| crate::armor::is_wearing_metal_armor(ctx, p.identity))
+        .map(|p| (p.position_x, p.position_y))
+        .collect();
+    for position in lightning_rods {
+        if rng.gen::<f32>() < METAL_ARMOR_STRIKE_CHANCE {
+            return position;
+        }
+    }
+
+    let chunk_x = (chunk_index % WORLD_WIDTH_CHUNKS) as f32;
+    let chunk_y = (chunk_index / WORLD_WIDTH_CHUNKS) as f32;
+    let x = chunk_x * CHUNK_SIZE_PX + rng.gen_range(0.0..CHUNK_SIZE_PX);
+    let y = chunk_y * CHUNK_SIZE_PX + rng.gen_range(0.0..CHUNK_SIZE_PX);
+    (x, y)
+}
+
+/// Outdoors and not sheltered by forest canopy
+fn is_in_open_ground(ctx: &ReducerContext, player: &crate::Player) -> bool {
+    if player.is_inside_building {
+        return false;
+    }
+    let (tile_x, tile_y) = crate::world_pos_to_tile_coords(player.position_x, player.position_y);
+    crate::get_tile_type_at_position(ctx, tile_x, tile_y) != Some(TileType::Forest)
+}
+
+fn damage_players_near_strike(ctx: &ReducerContext, strike_x: f32, strike_y: f32) {
+    let struck_players: Vec<_> = ctx.db.player().iter()
+        .filter(|p| !p.is_dead)
+        .filter(|p| crate::utils::get_distance_squared(p.position_x, p.position_y, strike_x, strike_y) <= LIGHTNING_STRIKE_RADIUS_SQUARED)
+        .map(|p| p.identity)
+        .collect();
+    for player_id in struck_players {
+        match crate::active_effects::apply_burn_effect(
+            ctx,
+            player_id,
+            LIGHTNING_PLAYER_BURN_DAMAGE,
+            LIGHTNING_PLAYER_BURN_DURATION_SECS,
+            LIGHTNING_PLAYER_BURN_TICK_SECS,
+            0, // Environmental source
+        ) {
+            Ok(_) => log::info!("[Lightning] Player {:?} was struck by lightning", player_id),
+            Err(e) => log::error!("[Lightning] Failed to apply lightning burn to {:?}: {}", player_id, e),
+        }
+    }
+}
+
+fn damage_animals_near_strike(ctx: &ReducerContext, strike_x: f32, strike_y: f32) {
+    let struck_animals: Vec<u64> = ctx.db.wild_animal().iter()
+        .filter(|a| a.health > 0.0)
+        .map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row))
+        .filter(|a| crate::utils::get_distance_squared(a.pos_x, a.pos_y, strike_x, strike_y) <= LIGHTNING_STRIKE_RADIUS_SQUARED)
+        .map(|a| a.id)
+        .collect();
+    for animal_id in struck_animals {
+        if let Err(e) = crate::wild_animal_npc::core::damage_wild_animal(ctx, animal_id, LIGHTNING_ANIMAL_DAMAGE, ctx.identity()) {
+            log::error!("[Lightning] Failed to damage animal {}: {}", animal_id, e);
+        }
+    }
+}
@@ -0,0 +1,200 @@
+/******************************************************************************
+ *                                                                            *
+ * Loadouts - Named armor and hotbar presets                                  *
+ *                                                                            *
+ * save_loadout snapshots the item types of the player's worn armor and      *
+ * hotbar slots under a name. apply_loadout re-equips that armor and moves   *
+ * matching items from the inventory into their hotbar slots, swapping out   *
+ * whatever is there. Items the player no longer carries are skipped, so a   *
+ * partial kit still applies. Armor and hotbar slots the preset left empty   *
+ * are not touched.                                                           *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use log;
+
+use crate::active_equipment::active_equipment as ActiveEquipmentTableTrait;
+use crate::items::{InventoryItem, inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::models::ItemLocation;
+use crate::player as PlayerTableTrait;
+use crate::player_inventory::{find_item_in_hotbar_slot, NUM_PLAYER_HOTBAR_SLOTS};
+
+pub const MAX_LOADOUTS_PER_PLAYER: usize = 10;
+pub const MAX_LOADOUT_NAME_LENGTH: usize = 32;
+
+/// A saved kit: item types per armor slot and per hotbar slot
+#[spacetimedb::table(accessor = loadout_preset, public)]
+#[derive(Clone, Debug)]
+pub struct LoadoutPreset {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: Identity,
+    pub name: String,
+    pub head_def_id: Option<u64>,
+    pub chest_def_id: Option<u64>,
+    pub legs_def_id: Option<u64>,
+    pub feet_def_id: Option<u64>,
+    pub hands_def_id: Option<u64>,
+    pub back_def_id: Option<u64>,
+    /// Item type per hotbar slot, indexed by slot
+    pub hotbar_def_ids: Vec<Option<u64>>,
+    pub saved_at: Timestamp,
+}
+
+impl LoadoutPreset {
+    fn armor_def_ids(&self) -> [Option<u64>; 6] {
+        [self.head_def_id, self.chest_def_id, self.legs_def_id, self.feet_def_id, self.hands_def_id, self.back_def_id]
+    }
+}
+
+fn find_loadout(ctx: &ReducerContext, player_id: Identity, name: &str) -> Option<LoadoutPreset> {
+    ctx.db.loadout_preset().player_id().filter(player_id).find(|l| l.name == name)
+}
+
+fn item_def_of(ctx: &ReducerContext, instance_id: Option<u64>) -> Option<u64> {
+    instance_id.and_then(|id| ctx.db.inventory_item().instance_id().find(id)).map(|item| item.item_def_id)
+}
+
+fn validate_loadout_name(name: &str) -> Result<String, String> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err("Loadout name cannot be empty.".to_string());
+    }
+    if name.chars().count() > MAX_LOADOUT_NAME_LENGTH {
+        return Err(format!("Loadout name must be at most {} characters.", MAX_LOADOUT_NAME_LENGTH));
+    }
+    Ok(name.to_string())
+}
+
+/// --- Save Loadout ---
+/// Saves the current armor and hotbar arrangement, replacing a loadout with the same name.
+#[spacetimedb::reducer]
+pub fn save_loadout(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let name = validate_loadout_name(&name)?;
+    let equipment = ctx.db.active_equipment().player_identity().find(sender_id).unwrap_or_default();
+
+    let hotbar_def_ids = (0..NUM_PLAYER_HOTBAR_SLOTS)
+        .map(|slot| find_item_in_hotbar_slot(ctx, slot).map(|item| item.item_def_id))
+        .collect();
+    let mut preset = LoadoutPreset {
+        id: 0,
+        player_id: sender_id,
+        name: name.clone(),
+        head_def_id: item_def_of(ctx, equipment.head_item_instance_id),
+        chest_def_id: item_def_of(ctx, equipment.chest_item_instance_id),
+        legs_def_id: item_def_of(ctx, equipment.legs_item_instance_id),
+        feet_def_id: item_def_of(ctx, equipment.feet_item_instance_id),
+        hands_def_id: item_def_of(ctx, equipment.hands_item_instance_id),
+        back_def_id: item_def_of(ctx, equipment.back_item_instance_id),
+        hotbar_def_ids,
+        saved_at: ctx.timestamp,
+    };
+
+    if let Some(existing) = find_loadout(ctx, sender_id, &name) {
+        preset.id = existing.id;
+        ctx.db.loadout_preset().id().update(preset);
+    } else {
+        if ctx.db.loadout_preset().player_id().filter(sender_id).count() >= MAX_LOADOUTS_PER_PLAYER {
+            return Err(format!("You can save at most {} loadouts.", MAX_LOADOUTS_PER_PLAYER));
+        }
+        ctx.db.loadout_preset().insert(preset);
+    }
+    log::info!("[Loadout] Player {:?} saved loadout '{}'", sender_id, name);
+    Ok(())
+}
+
+/// --- Delete Loadout ---
+#[spacetimedb::reducer]
+pub fn delete_loadout(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let preset = find_loadout(ctx, ctx.sender(), name.trim())
+        .ok_or_else(|| format!("No loadout named '{}'.", name.trim()))?;
+    ctx.db.loadout_preset().id().delete(preset.id);
+    Ok(())
+}
+
+/// --- Apply Loadout ---
+/// Equips the loadout's armor and arranges its hotbar from the player's inventory.
+/// Missing items are skipped; the rest of the loadout still applies.
+#[spacetimedb::reducer]
+pub fn apply_loadout(ctx: &ReducerContext, name: String) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let preset = find_loadout(ctx, sender_id, name.trim())
+        .ok_or_else(|| format!("No loadout named '{}'.", name.trim()))?;
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+    if player.is_dead || player.is_knocked_out {
+        return Err("Cannot change loadout right now.".to_string());
+    }
+
+    let mut missing: Vec<u64> = Vec::new();
+
+    // --- Armor ---
+    for def_id in preset.armor_def_ids().into_iter().flatten() {
+        let already_worn = carried_items(ctx, sender_id).iter()
+            .any(|item| item.item_def_id == def_id && matches!(item.location, ItemLocation::Equipped(_)));
+        if already_worn {
+            continue;
+        }
+        let candidate = carried_items(ctx, sender_id).into_iter()
+            .filter(|item| item.item_def_id == def_id)
+            .min_by_key(|item| matches!(item.location, ItemLocation::Hotbar(_)));
+        match candidate {
+            Some(item) => {
+                if let Err(e) = crate::active_equipment::equip_armor(ctx, item.instance_id) {
+                    log::warn!("[Loadout] Could not equip armor {} for {:?}: {}", item.instance_id, sender_id, e);
+                    missing.push(def_id);
+                }
+            }
+            None => missing.push(def_id),
+        }
+    }
+
+    // --- Hotbar ---
+    for (slot, def_id) in preset.hotbar_def_ids.iter().enumerate().take(NUM_PLAYER_HOTBAR_SLOTS as usize) {
+        let (slot, Some(def_id)) = (slot as u8, *def_id) else {
+            continue;
+        };
+        if find_item_in_hotbar_slot(ctx, slot).is_some_and(|item| item.item_def_id == def_id) {
+            continue;
+        }
+        // Take it from the inventory, or from a hotbar slot the loadout doesn't keep for this item
+        let candidate = carried_items(ctx, sender_id).into_iter()
+            .filter(|item| item.item_def_id == def_id)
+            .find(|item| match &item.location {
+                ItemLocation::Inventory(_) => true,
+                ItemLocation::Hotbar(data) => preset.hotbar_def_ids.get(data.slot_index as usize).copied().flatten() != Some(def_id),
+                _ => false,
+            });
+        match candidate {
+            Some(item) => {
+                if let Err(e) = crate::player_inventory::move_item_to_hotbar(ctx, item.instance_id, slot) {
+                    log::warn!("[Loadout] Could not move item {} to hotbar slot {} for {:?}: {}", item.instance_id, slot, sender_id, e);
+                    missing.push(def_id);
+                }
+            }
+            None => missing.push(def_id),
+        }
+    }
+
+    if missing.is_empty() {
+        log::info!("[Loadout] Player {:?} applied loadout '{}'", sender_id, preset.name);
+    } else {
+        let item_defs = ctx.db.item_definition();
+        let names: Vec<String> = missing.iter()
+            .map(|def_id| item_defs.id().find(def_id).map(|def| def.name).unwrap_or_else(|| format!("def_{}", def_id)))
+            .collect();
+        log::info!("[Loadout] Player {:?} applied loadout '{}' without: {}", sender_id, preset.name, names.join(", "));
+    }
+    Ok(())
+}
+
+/// Items in the player's inventory, hotbar and armor slots
+fn carried_items(ctx: &ReducerContext, player_id: Identity) -> Vec<InventoryItem> {
+    ctx.db.inventory_item().iter()
+        .filter(|item| item.location.is_player_bound() == Some(player_id))
+        .collect()
+}
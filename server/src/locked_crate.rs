@@ -0,0 +1,361 @@
+/******************************************************************************
+ *                                                                            *
+ * Locked Crates - Keycard loop across the monuments                          *
+ *                                                                            *
+ * Locked crates are WoodenStorageBox entities (BOX_TYPE_LOCKED_CRATE) with a *
+ * CrateLock row naming the keycard color that opens them. A crate can be     *
+ * opened by swiping a matching keycard or by working a consumable lockpick,  *
+ * which can fail (harder on higher colors). Once open, anyone can loot it;  *
+ * emptied crates despawn and respawn locked later.                           *
+ *                                                                            *
+ * The loop: Green Keycards turn up in barrels and open the shipwreck crate,  *
+ * which holds a Blue Keycard for the ALK compound crate, which holds a Red   *
+ * Keycard for the quarry crates - the best loot on the island.               *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp, TimeDuration};
+use spacetimedb::spacetimedb_lib::ScheduleAt;
+use rand::Rng;
+use log;
+
+use crate::wooden_storage_box::{
+    WoodenStorageBox, BOX_TYPE_LOCKED_CRATE, LOCKED_CRATE_INITIAL_HEALTH, LOCKED_CRATE_MAX_HEALTH,
+    validate_box_reach, wooden_storage_box as WoodenStorageBoxTableTrait,
+};
+use crate::items::{InventoryItem, inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::inventory_management::{ItemContainer, is_container_empty};
+use crate::loot_table::{LootEntryDefault, LootSourceDefault};
+use crate::models::{ItemLocation, ContainerType, ContainerLocationData};
+use crate::environment::calculate_chunk_index;
+
+// --- Keycard Colors ---
+pub const KEYCARD_GREEN: u8 = 1;
+pub const KEYCARD_BLUE: u8 = 2;
+pub const KEYCARD_RED: u8 = 3;
+
+// --- Locked Crate Constants ---
+pub const LOCKPICK_ITEM_NAME: &str = "Lockpick";
+pub const LOCKED_CRATE_RESPAWN_SECS: u64 = 2700; // 45 minutes after being emptied
+const LOCKED_CRATE_LOOT_PREFIX: &str = "locked_crate_";
+
+/// Chance a lockpick opens a crate of the given color (the pick is used up either way)
+pub fn lockpick_success_chance(key_color: u8) -> f32 {
+    match key_color {
+        KEYCARD_GREEN => 0.5,
+        KEYCARD_BLUE => 0.3,
+        KEYCARD_RED => 0.15,
+        _ => 0.0,
+    }
+}
+
+pub fn keycard_item_name(key_color: u8) -> &'static str {
+    match key_color {
+        KEYCARD_GREEN => "Green Keycard",
+        KEYCARD_BLUE => "Blue Keycard",
+        _ => "Red Keycard",
+    }
+}
+
+fn key_color_name(key_color: u8) -> &'static str {
+    match key_color {
+        KEYCARD_GREEN => "green",
+        KEYCARD_BLUE => "blue",
+        _ => "red",
+    }
+}
+
+fn locked_crate_loot_source(key_color: u8) -> String {
+    format!("{}{}", LOCKED_CRATE_LOOT_PREFIX, key_color_name(key_color))
+}
+
+/// Lock state of a locked crate, keyed by its WoodenStorageBox id
+#[spacetimedb::table(accessor = crate_lock, public)]
+#[derive(Clone, Debug)]
+pub struct CrateLock {
+    #[primary_key]
+    pub box_id: u32,
+    pub key_color: u8,
+    pub is_locked: bool,
+    pub unlocked_by: Option<Identity>,
+    pub unlocked_at: Option<Timestamp>,
+}
+
+#[spacetimedb::table(accessor = locked_crate_respawn_schedule, scheduled(respawn_locked_crate))]
+#[derive(Clone)]
+pub struct LockedCrateRespawnSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub scheduled_id: u64,
+    pub scheduled_at: ScheduleAt,
+    pub key_color: u8,
+    pub pos_x: f32,
+    pub pos_y: f32,
+}
+
+// --- Loot ---
+
+/// Default loot for each crate color. The next keycard in the loop is seeded first so it
+/// always lands in the first slot.
+pub fn default_locked_crate_loot_sources() -> Vec<LootSourceDefault> {
+    vec![
+        // Shipwreck - field supplies and the Blue Keycard
+        LootSourceDefault::new(locked_crate_loot_source(KEYCARD_GREEN), vec![
+            LootEntryDefault::new("Blue Keycard", 1, 1, 1.0),
+            LootEntryDefault::new("Metal Fragments", 40, 80, 0.8),
+            LootEntryDefault::new("Bandage", 2, 4, 0.6),
+            LootEntryDefault::new("Anti-Venom", 1, 1, 0.3),
+            LootEntryDefault::new("Flashbang Blueprint", 1, 1, 0.15),
+            LootEntryDefault::new("Water Purifier Blueprint", 1, 1, 0.15),
+        ]).drops(3, 0),
+        // ALK compound - military gear and the Red Keycard
+        LootSourceDefault::new(locked_crate_loot_source(KEYCARD_BLUE), vec![
+            LootEntryDefault::new("Red Keycard", 1, 1, 1.0),
+            LootEntryDefault::new("Scrap Batteries", 1, 3, 0.6),
+            LootEntryDefault::new("9x18mm Round", 8, 16, 0.5),
+            LootEntryDefault::new("AK74 Bayonet", 1, 1, 0.3),
+            LootEntryDefault::new("Signal Disruptor Blueprint", 1, 1, 0.15),
+            LootEntryDefault::new("Tallow Steam Turret Blueprint", 1, 1, 0.1),
+        ]).drops(3, 0),
+        // Quarries - the end of the loop
+        LootSourceDefault::new(locked_crate_loot_source(KEYCARD_RED), vec![
+            LootEntryDefault::new("9x18mm Round", 16, 32, 0.8),
+            LootEntryDefault::new("Makarov PM", 1, 1, 0.35),
+            LootEntryDefault::new("PP-91 KEDR", 1, 1, 0.15),
+            LootEntryDefault::new("Military Crowbar", 1, 1, 0.3),
+            LootEntryDefault::new("Makarov PM Blueprint", 1, 1, 0.2),
+            LootEntryDefault::new("PP-91 KEDR Blueprint", 1, 1, 0.1),
+        ]).drops(3, 0),
+    ]
+}
+
+// --- Spawning ---
+
+/// Spawns a locked crate of the given keycard color with fresh loot. Returns the box ID on success.
+pub fn spawn_locked_crate(ctx: &ReducerContext, key_color: u8, pos_x: f32, pos_y: f32) -> Result<u32, String> {
+    if crate::environment::is_position_on_water(ctx, pos_x, pos_y) {
+        return Err("Cannot spawn locked crate on water".to_string());
+    }
+
+    let mut locked_crate = ctx.db.wooden_storage_box().insert(WoodenStorageBox {
+        id: 0,
+        pos_x,
+        pos_y,
+        chunk_index: calculate_chunk_index(pos_x, pos_y),
+        placed_by: ctx.identity(),
+        box_type: BOX_TYPE_LOCKED_CRATE,
+        slot_instance_id_0: None, slot_def_id_0: None,
+        slot_instance_id_1: None, slot_def_id_1: None,
+        slot_instance_id_2: None, slot_def_id_2: None,
+        slot_instance_id_3: None, slot_def_id_3: None,
+        slot_instance_id_4: None, slot_def_id_4: None,
+        slot_instance_id_5: None, slot_def_id_5: None,
+        slot_instance_id_6: None, slot_def_id_6: None,
+        slot_instance_id_7: None, slot_def_id_7: None,
+        slot_instance_id_8: None, slot_def_id_8: None,
+        slot_instance_id_9: None, slot_def_id_9: None,
+        slot_instance_id_10: None, slot_def_id_10: None,
+        slot_instance_id_11: None, slot_def_id_11: None,
+        slot_instance_id_12: None, slot_def_id_12: None,
+        slot_instance_id_13: None, slot_def_id_13: None,
+        slot_instance_id_14: None, slot_def_id_14: None,
+        slot_instance_id_15: None, slot_def_id_15: None,
+        slot_instance_id_16: None, slot_def_id_16: None,
+        slot_instance_id_17: None, slot_def_id_17: None,
+        slot_instance_id_18: None, slot_def_id_18: None,
+        slot_instance_id_19: None, slot_def_id_19: None,
+        slot_instance_id_20: None, slot_def_id_20: None,
+        slot_instance_id_21: None, slot_def_id_21: None,
+        slot_instance_id_22: None, slot_def_id_22: None,
+        slot_instance_id_23: None, slot_def_id_23: None,
+        slot_instance_id_24: None, slot_def_id_24: None,
+        slot_instance_id_25: None, slot_def_id_25: None,
+        slot_instance_id_26: None, slot_def_id_26: None,
+        slot_instance_id_27: None, slot_def_id_27: None,
+        slot_instance_id_28: None, slot_def_id_28: None,
+        slot_instance_id_29: None, slot_def_id_29: None,
+        slot_instance_id_30: None, slot_def_id_30: None,
+        slot_instance_id_31: None, slot_def_id_31: None,
+        slot_instance_id_32: None, slot_def_id_32: None,
+        slot_instance_id_33: None, slot_def_id_33: None,
+        slot_instance_id_34: None, slot_def_id_34: None,
+        slot_instance_id_35: None, slot_def_id_35: None,
+        slot_instance_id_36: None, slot_def_id_36: None,
+        slot_instance_id_37: None, slot_def_id_37: None,
+        slot_instance_id_38: None, slot_def_id_38: None,
+        slot_instance_id_39: None, slot_def_id_39: None,
+        slot_instance_id_40: None, slot_def_id_40: None,
+        slot_instance_id_41: None, slot_def_id_41: None,
+        slot_instance_id_42: None, slot_def_id_42: None,
+        slot_instance_id_43: None, slot_def_id_43: None,
+        slot_instance_id_44: None, slot_def_id_44: None,
+        slot_instance_id_45: None, slot_def_id_45: None,
+        slot_instance_id_46: None, slot_def_id_46: None,
+        slot_instance_id_47: None, slot_def_id_47: None,
+        health: LOCKED_CRATE_INITIAL_HEALTH,
+        max_health: LOCKED_CRATE_MAX_HEALTH,
+        is_destroyed: false,
+        destroyed_at: None,
+        last_hit_time: None,
+        last_damaged_by: None,
+        respawn_at: Timestamp::UNIX_EPOCH,
+        is_monument: true, // Indestructible - it has to be opened, not smashed
+        active_user_id: None,
+        active_user_since: None,
+    });
+
+    let inventory_items = ctx.db.inventory_item();
+    let num_slots = locked_crate.num_slots() as u8;
+    let drops = crate::loot_table::roll_loot(ctx, &locked_crate_loot_source(key_color), 0);
+    for (slot, drop) in (0..num_slots).zip(drops) {
+        let inserted = inventory_items.insert(InventoryItem {
+            instance_id: 0,
+            item_def_id: drop.item_def_id,
+            quantity: drop.quantity,
+            location: ItemLocation::Container(ContainerLocationData {
+                container_type: ContainerType::WoodenStorageBox,
+                container_id: locked_crate.id as u64,
+                slot_index: slot,
+            }),
+            item_data: None,
+        });
+        locked_crate.set_slot(slot, Some(inserted.instance_id), Some(inserted.item_def_id));
+    }
+    let box_id = locked_crate.id;
+    ctx.db.wooden_storage_box().id().update(locked_crate);
+
+    ctx.db.crate_lock().insert(CrateLock {
+        box_id,
+        key_color,
+        is_locked: true,
+        unlocked_by: None,
+        unlocked_at: None,
+    });
+
+    log::info!("[LockedCrate] Spawned {} locked crate {} at ({:.1}, {:.1})", key_color_name(key_color), box_id, pos_x, pos_y);
+    Ok(box_id)
+}
+
+/// Refuses access to a locked crate that hasn't been opened yet
+pub fn ensure_crate_unlocked(ctx: &ReducerContext, storage_box: &WoodenStorageBox) -> Result<(), String> {
+    if storage_box.box_type != BOX_TYPE_LOCKED_CRATE {
+        return Ok(());
+    }
+    match ctx.db.crate_lock().box_id().find(storage_box.id) {
+        Some(lock) if lock.is_locked => Err(format!(
+            "This crate is locked. Open it with a {} or a {}.", keycard_item_name(lock.key_color), LOCKPICK_ITEM_NAME
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Deletes an emptied locked crate and schedules a fresh locked one in its place
+pub fn check_and_despawn_locked_crate_if_empty(ctx: &ReducerContext, box_id: u32) -> Result<(), String> {
+    let locked_crate = ctx.db.wooden_storage_box().id().find(box_id)
+        .ok_or("Locked crate not found")?;
+    if locked_crate.box_type != BOX_TYPE_LOCKED_CRATE || !is_container_empty(&locked_crate) {
+        return Ok(());
+    }
+
+    let key_color = ctx.db.crate_lock().box_id().find(box_id).map(|lock| lock.key_color).unwrap_or(KEYCARD_GREEN);
+    if locked_crate.placed_by == ctx.identity() {
+        let respawn_time = ctx.timestamp + TimeDuration::from_micros((LOCKED_CRATE_RESPAWN_SECS * 1_000_000) as i64);
+        ctx.db.locked_crate_respawn_schedule().insert(LockedCrateRespawnSchedule {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(respawn_time),
+            key_color,
+            pos_x: locked_crate.pos_x,
+            pos_y: locked_crate.pos_y,
+        });
+    }
+
+    ctx.db.crate_lock().box_id().delete(box_id);
+    ctx.db.wooden_storage_box().id().delete(box_id);
+    log::info!("[LockedCrate] Despawned empty {} locked crate {}", key_color_name(key_color), box_id);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn respawn_locked_crate(ctx: &ReducerContext, schedule: LockedCrateRespawnSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Respawn reducer may only be called by the scheduler".to_string());
+    }
+    if let Err(e) = spawn_locked_crate(ctx, schedule.key_color, schedule.pos_x, schedule.pos_y) {
+        log::warn!("[LockedCrate] Failed to respawn at ({:.1}, {:.1}): {}", schedule.pos_x, schedule.pos_y, e);
+    }
+    Ok(())
+}
+
+// --- Unlocking ---
+
+/// Finds a locked crate within reach of the sender along with its lock
+fn find_locked_crate_in_reach(ctx: &ReducerContext, box_id: u32) -> Result<CrateLock, String> {
+    let (_player, storage_box) = validate_box_reach(ctx, box_id)?;
+    if storage_box.box_type != BOX_TYPE_LOCKED_CRATE {
+        return Err("This container has no lock.".to_string());
+    }
+    let lock = ctx.db.crate_lock().box_id().find(box_id)
+        .ok_or_else(|| "Crate lock not found.".to_string())?;
+    if !lock.is_locked {
+        return Err("This crate is already open.".to_string());
+    }
+    Ok(lock)
+}
+
+/// Takes one of the named item from the player's inventory or hotbar
+pub(crate) fn consume_one_carried_item(ctx: &ReducerContext, player_id: Identity, item_name: &str) -> Result<(), String> {
+    let inventory_items = ctx.db.inventory_item();
+    let def_id = ctx.db.item_definition().iter()
+        .find(|def| def.name == item_name)
+        .map(|def| def.id)
+        .ok_or_else(|| format!("{} item definition not found.", item_name))?;
+    let mut item = inventory_items.iter()
+        .find(|item| item.item_def_id == def_id && item.location.is_player_bound() == Some(player_id)
+            && matches!(item.location, ItemLocation::Inventory(_) | ItemLocation::Hotbar(_)))
+        .ok_or_else(|| format!("You need a {}.", item_name))?;
+
+    if item.quantity > 1 {
+        item.quantity -= 1;
+        inventory_items.instance_id().update(item);
+    } else {
+        inventory_items.instance_id().delete(item.instance_id);
+    }
+    Ok(())
+}
+
+fn unlock_crate(ctx: &ReducerContext, mut lock: CrateLock, player_id: Identity) {
+    lock.is_locked = false;
+    lock.unlocked_by = Some(player_id);
+    lock.unlocked_at = Some(ctx.timestamp);
+    log::info!("[LockedCrate] Player {:?} opened {} locked crate {}", player_id, key_color_name(lock.key_color), lock.box_id);
+    ctx.db.crate_lock().box_id().update(lock);
+}
+
+/// --- Swipe Keycard ---
+/// Opens a locked crate with a keycard of the matching color. The keycard is used up.
+#[spacetimedb::reducer]
+pub fn swipe_keycard(ctx: &ReducerContext, box_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let lock = find_locked_crate_in_reach(ctx, box_id)?;
+    consume_one_carried_item(ctx, sender_id, keycard_item_name(lock.key_color))?;
+    unlock_crate(ctx, lock, sender_id);
+    Ok(())
+}
+
+/// --- Pick Crate Lock ---
+/// Works a lockpick on a locked crate. The lockpick is used up whether or not the lock gives.
+#[spacetimedb::reducer]
+pub fn pick_crate_lock(ctx: &ReducerContext, box_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let lock = find_locked_crate_in_reach(ctx, box_id)?;
+    consume_one_carried_item(ctx, sender_id, LOCKPICK_ITEM_NAME)?;
+
+    let roll: f32 = ctx.rng().gen();
+    if roll < lockpick_success_chance(lock.key_color) {
+        unlock_crate(ctx, lock, sender_id);
+    } else {
+        // Not an Err - the broken lockpick has to stay consumed
+        log::info!("[LockedCrate] Player {:?} broke a lockpick on {} locked crate {}", sender_id, key_color_name(lock.key_color), box_id);
+    }
+    Ok(())
+}
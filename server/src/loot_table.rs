@@ -0,0 +1,286 @@
+/******************************************************************************
+ *                                                                            *
+ * Shared loot table subsystem. Every loot source (barrels, supply drops,     *
+ * hostile NPC kills, animal corpse harvesting) is identified by a source key *
+ * and rolls its drops from rows in `loot_table_entry`, shaped by the         *
+ * source's `loot_source_config` row.                                         *
+ *                                                                            *
+ * Sources are seeded from code defaults at init, but only when the source    *
+ * has no config row yet - once seeded the rows belong to the operator and    *
+ * can be retuned with `spacetime sql` without a code change or republish     *
+ * wiping them. Deleting a source's config row restores its defaults on the   *
+ * next init.                                                                 *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{table, ReducerContext, Table};
+use rand::Rng;
+use log;
+
+use crate::items::item_definition as ItemDefinitionTableTrait;
+
+/// One weighted drop in a loot source
+#[table(accessor = loot_table_entry)]
+#[derive(Clone, Debug)]
+pub struct LootTableEntry {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub source_key: String,
+    pub item_name: String,   // Resolved against ItemDefinition.name at roll time
+    pub min_quantity: u32,
+    pub max_quantity: u32,
+    pub drop_chance: f32,    // Independent roll per entry (0.0 - 1.0)
+    pub weight: f32,         // Relative weight when picking among rolled/fallback entries
+    pub min_tier: u8,        // Only rolls when the requested tier is at least this
+    pub tag: String,         // Free-form role for callers that post-process drops ("meat", "skull", ...)
+    pub enabled: bool,
+}
+
+/// Per-source roll settings
+#[table(accessor = loot_source_config)]
+#[derive(Clone, Debug)]
+pub struct LootSourceConfig {
+    #[primary_key]
+    pub source_key: String,
+    pub max_drops: u32,             // Cap on drops per roll (0 = no cap)
+    pub min_drops: u32,             // Guaranteed drops - filled with weighted picks if rolls come up short
+    pub quantity_multiplier: f32,   // Flat multiplier applied to every rolled quantity
+    pub tier_chance_scaling: f32,   // Drop chance multiplier added per tier (chance * (1 + s * tier))
+    pub tier_quantity_scaling: f32, // Quantity multiplier added per tier (qty * (1 + s * tier))
+}
+
+/// A resolved drop produced by `roll_loot`
+#[derive(Clone, Debug)]
+pub struct LootDrop {
+    pub item_def_id: u64,
+    pub item_name: String,
+    pub quantity: u32,
+}
+
+// --- Code Defaults ---
+
+/// Default entry for a source, seeded into `loot_table_entry`
+#[derive(Clone, Debug)]
+pub struct LootEntryDefault {
+    pub item_name: &'static str,
+    pub min_quantity: u32,
+    pub max_quantity: u32,
+    pub drop_chance: f32,
+    pub weight: f32,
+    pub min_tier: u8,
+    pub tag: &'static str,
+}
+
+impl LootEntryDefault {
+    /// Entry weighted by its own drop chance, available at every tier
+    pub fn new(item_name: &'static str, min_quantity: u32, max_quantity: u32, drop_chance: f32) -> Self {
+        Self { item_name, min_quantity, max_quantity, drop_chance, weight: drop_chance, min_tier: 0, tag: "" }
+    }
+
+    pub fn tier(mut self, min_tier: u8) -> Self {
+        self.min_tier = min_tier;
+        self
+    }
+
+    pub fn tag(mut self, tag: &'static str) -> Self {
+        self.tag = tag;
+        self
+    }
+}
+
+/// Default config + entries for one source
+#[derive(Clone, Debug)]
+pub struct LootSourceDefault {
+    pub source_key: String,
+    pub max_drops: u32,
+    pub min_drops: u32,
+    pub quantity_multiplier: f32,
+    pub tier_chance_scaling: f32,
+    pub tier_quantity_scaling: f32,
+    pub entries: Vec<LootEntryDefault>,
+}
+
+impl LootSourceDefault {
+    /// Source with no drop cap, no guarantee and no tier scaling
+    pub fn new(source_key: impl Into<String>, entries: Vec<LootEntryDefault>) -> Self {
+        Self {
+            source_key: source_key.into(),
+            max_drops: 0,
+            min_drops: 0,
+            quantity_multiplier: 1.0,
+            tier_chance_scaling: 0.0,
+            tier_quantity_scaling: 0.0,
+            entries,
+        }
+    }
+
+    pub fn drops(mut self, min_drops: u32, max_drops: u32) -> Self {
+        self.min_drops = min_drops;
+        self.max_drops = max_drops;
+        self
+    }
+
+    pub fn tier_scaling(mut self, chance_scaling: f32, quantity_scaling: f32) -> Self {
+        self.tier_chance_scaling = chance_scaling;
+        self.tier_quantity_scaling = quantity_scaling;
+        self
+    }
+}
+
+/// Seeds every source that doesn't have a config row yet. Called from init_module.
+pub fn seed_loot_tables(ctx: &ReducerContext) -> Result<(), String> {
+    let mut sources = Vec::new();
+    sources.extend(crate::barrel::default_barrel_loot_sources());
+    sources.extend(crate::supply_drop::default_supply_drop_loot_sources());
+    sources.extend(crate::wild_animal_npc::hostile_spawning::default_hostile_npc_loot_sources());
+    sources.extend(crate::wild_animal_npc::animal_corpse::default_animal_corpse_loot_sources());
+    sources.extend(crate::locked_crate::default_locked_crate_loot_sources());
+    sources.extend(crate::monument_puzzle::default_puzzle_vault_loot_sources());
+
+    let configs = ctx.db.loot_source_config();
+    let entries = ctx.db.loot_table_entry();
+    let mut seeded_sources = 0;
+
+    for source in sources {
+        if configs.source_key().find(&source.source_key).is_some() {
+            continue;
+        }
+
+        // Clear orphaned entries left behind if an operator deleted only the config row
+        let stale_ids: Vec<u64> = entries.source_key().filter(&source.source_key).map(|e| e.id).collect();
+        for id in stale_ids {
+            entries.id().delete(id);
+        }
+
+        for entry in &source.entries {
+            entries.insert(LootTableEntry {
+                id: 0,
+                source_key: source.source_key.clone(),
+                item_name: entry.item_name.to_string(),
+                min_quantity: entry.min_quantity,
+                max_quantity: entry.max_quantity.max(entry.min_quantity),
+                drop_chance: entry.drop_chance,
+                weight: entry.weight,
+                min_tier: entry.min_tier,
+                tag: entry.tag.to_string(),
+                enabled: true,
+            });
+        }
+        configs.insert(LootSourceConfig {
+            source_key: source.source_key,
+            max_drops: source.max_drops,
+            min_drops: source.min_drops,
+            quantity_multiplier: source.quantity_multiplier,
+            tier_chance_scaling: source.tier_chance_scaling,
+            tier_quantity_scaling: source.tier_quantity_scaling,
+        });
+        seeded_sources += 1;
+    }
+
+    log::info!("[LootTable] Seeded {} loot sources ({} total)", seeded_sources, configs.iter().count());
+    Ok(())
+}
+
+// --- Rolling ---
+
+/// Enabled entries for a source in seed order. For callers that apply their own
+/// chance logic (e.g. tool effectiveness when harvesting corpses).
+pub fn get_loot_entries(ctx: &ReducerContext, source_key: &str) -> Vec<LootTableEntry> {
+    let mut entries: Vec<LootTableEntry> = ctx.db.loot_table_entry().source_key().filter(source_key)
+        .filter(|e| e.enabled)
+        .collect();
+    entries.sort_by_key(|e| e.id);
+    entries
+}
+
+/// Random quantity within an entry's range
+pub fn roll_entry_quantity(ctx: &ReducerContext, entry: &LootTableEntry) -> u32 {
+    if entry.max_quantity <= entry.min_quantity {
+        entry.min_quantity
+    } else {
+        ctx.rng().gen_range(entry.min_quantity..=entry.max_quantity)
+    }
+}
+
+/// Rolls a source at the given tier: every eligible entry rolls its (tier-scaled) chance,
+/// successes beyond `max_drops` are trimmed by weight, and short rolls are topped up to
+/// `min_drops` with weighted picks. Entries whose item no longer exists are skipped.
+pub fn roll_loot(ctx: &ReducerContext, source_key: &str, tier: u8) -> Vec<LootDrop> {
+    let config = ctx.db.loot_source_config().source_key().find(source_key.to_string());
+    let Some(config) = config else {
+        log::warn!("[LootTable] No loot source config for '{}'", source_key);
+        return Vec::new();
+    };
+
+    let item_defs = ctx.db.item_definition();
+    let mut candidates: Vec<(LootTableEntry, u64)> = Vec::new();
+    for entry in get_loot_entries(ctx, source_key) {
+        if entry.min_tier > tier {
+            continue;
+        }
+        match item_defs.iter().find(|def| def.name == entry.item_name) {
+            Some(def) => candidates.push((entry, def.id)),
+            None => log::warn!("[LootTable] {} item not found in database (source '{}')", entry.item_name, source_key),
+        }
+    }
+
+    let chance_scale = 1.0 + config.tier_chance_scaling * tier as f32;
+    let quantity_scale = config.quantity_multiplier * (1.0 + config.tier_quantity_scaling * tier as f32);
+
+    // Roll every entry first, then trim/top up - no early breaks so late entries get a fair roll
+    let mut selected: Vec<usize> = Vec::new();
+    for (index, (entry, _)) in candidates.iter().enumerate() {
+        let roll: f32 = ctx.rng().gen();
+        if roll < (entry.drop_chance * chance_scale).min(1.0) {
+            selected.push(index);
+        }
+    }
+
+    if config.max_drops > 0 && selected.len() > config.max_drops as usize {
+        let mut kept = Vec::new();
+        while kept.len() < config.max_drops as usize {
+            let Some(pick) = weighted_pick(ctx, &candidates, &selected) else { break };
+            kept.push(selected.remove(pick));
+        }
+        selected = kept;
+    }
+
+    while selected.len() < config.min_drops as usize {
+        let remaining: Vec<usize> = (0..candidates.len()).filter(|i| !selected.contains(i)).collect();
+        let Some(pick) = weighted_pick(ctx, &candidates, &remaining) else { break };
+        selected.push(remaining[pick]);
+    }
+
+    selected.into_iter()
+        .map(|index| {
+            let (entry, item_def_id) = &candidates[index];
+            let quantity = ((roll_entry_quantity(ctx, entry) as f32) * quantity_scale).round().max(1.0) as u32;
+            LootDrop {
+                item_def_id: *item_def_id,
+                item_name: entry.item_name.clone(),
+                quantity,
+            }
+        })
+        .collect()
+}
+
+/// Weighted pick over `pool` (indices into candidates); returns a position in `pool`
+fn weighted_pick(ctx: &ReducerContext, candidates: &[(LootTableEntry, u64)], pool: &[usize]) -> Option<usize> {
+    if pool.is_empty() {
+        return None;
+    }
+    let total: f32 = pool.iter().map(|&i| candidates[i].0.weight.max(0.0)).sum();
+    if total <= 0.0 {
+        return Some(ctx.rng().gen_range(0..pool.len()));
+    }
+    let mut roll = ctx.rng().gen_range(0.0..total);
+    for (position, &i) in pool.iter().enumerate() {
+        roll -= candidates[i].0.weight.max(0.0);
+        if roll < 0.0 {
+            return Some(position);
+        }
+    }
+    Some(pool.len() - 1)
+}
@@ -62,7 +62,7 @@ pub fn initialize_memory_grid_progress(ctx: &spacetimedb::ReducerContext, player
 }
 
 /// Count memory shards in player's inventory
-fn count_memory_shards_in_inventory(ctx: &spacetimedb::ReducerContext, player_id: Identity) -> u64 {
+pub(crate) fn count_memory_shards_in_inventory(ctx: &spacetimedb::ReducerContext, player_id: Identity) -> u64 {
     let mut total_shards = 0u64;
     
     let memory_shard_name = "Memory Shard";
@@ -85,7 +85,7 @@ fn count_memory_shards_in_inventory(ctx: &spacetimedb::ReducerContext, player_id
 }
 
 /// Remove memory shards from player's inventory
-fn consume_memory_shards(ctx: &spacetimedb::ReducerContext, player_id: Identity, amount: u64) -> Result<(), String> {
+pub(crate) fn consume_memory_shards(ctx: &spacetimedb::ReducerContext, player_id: Identity, amount: u64) -> Result<(), String> {
     let mut remaining_to_consume = amount;
     let mut items_to_update = Vec::new();
     let mut items_to_delete = Vec::new();
@@ -412,9 +412,17 @@ pub fn purchase_memory_grid_node(ctx: &spacetimedb::ReducerContext, node_id: Str
     let player_id = ctx.sender();
     
     // Get node info (cost and prerequisites)
-    let (cost, prerequisites) = get_node_info(&node_id)
+    let (base_cost, prerequisites) = get_node_info(&node_id)
         .ok_or_else(|| format!("Unknown memory grid node: {}", node_id))?;
     
+    // Completed cairn region collections grant a one-time discount
+    let pending_discount = crate::cairn::get_pending_fragment_discount(ctx, player_id);
+    let cost = if pending_discount.is_some() {
+        crate::cairn::apply_fragment_discount(base_cost)
+    } else {
+        base_cost
+    };
+    
     // Get or create player's progress
     let existing = ctx.db.memory_grid_progress().player_id().find(&player_id);
     let mut progress = if let Some(p) = existing {
@@ -477,6 +485,10 @@ pub fn purchase_memory_grid_node(ctx: &spacetimedb::ReducerContext, node_id: Str
     };
     ctx.db.memory_grid_purchases().insert(purchase_record);
     
+    if let Some(collection) = pending_discount {
+        crate::cairn::redeem_fragment_discount(ctx, collection, &node_id);
+    }
+    
     spacetimedb::log::info!("Player {} purchased memory grid node '{}' for {} shards", player_id, node_id, cost);
     Ok(())
 }
@@ -0,0 +1,351 @@
+/******************************************************************************
+ *                                                                            *
+ * Meteor Shower World Event                                                  *
+ *                                                                            *
+ * A random night-time server event. When night falls there's a chance a     *
+ * meteor shower is sighted: the server announces the impact zone, marks it   *
+ * on the minimap, and after a short warning scatters high-yield ore nodes    *
+ * across random land tiles in the zone. The meteor ore nodes despawn an hour *
+ * after impact whether or not they've been mined out.                        *
+ *                                                                            *
+ * Key features:                                                              *
+ * - Rolls at the start of each night (requires at least 1 player online)    *
+ * - Only one meteor shower at a time                                         *
+ * - Impact zone avoids water, monuments and player bases                     *
+ * - Meteor ore nodes yield several times a normal node                       *
+ * - Impact zone shown via the public world_event_marker table                *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table, Timestamp, TimeDuration, ScheduleAt};
+use rand::Rng;
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::chat::message as MessageTableTrait;
+use crate::stone::{Stone, OreType, stone as StoneTableTrait};
+use crate::world_state::{WorldEventMarker, WorldEventType, world_event_marker as WorldEventMarkerTableTrait};
+use crate::environment::{is_position_on_water, calculate_chunk_index};
+use crate::{WORLD_WIDTH_PX, WORLD_HEIGHT_PX};
+
+// === CONSTANTS ===
+
+/// Chance of a meteor shower each time night falls (0.0 - 1.0)
+pub const METEOR_SHOWER_CHANCE: f32 = 0.20; // 20% per night
+
+/// Warning time between the announcement and the meteors landing
+pub const METEOR_SHOWER_WARNING_SECS: u64 = 180; // 3 minutes
+
+/// How long meteor ore nodes stay in the world after impact
+pub const METEOR_ORE_LIFETIME_SECS: u64 = 3600; // 1 hour
+
+/// Radius of the impact zone around the chosen center
+pub const METEOR_IMPACT_ZONE_RADIUS_PX: f32 = 1200.0;
+
+/// Number of meteor ore nodes spawned per shower
+pub const METEOR_ORE_NODE_MIN_COUNT: u32 = 6;
+pub const METEOR_ORE_NODE_MAX_COUNT: u32 = 10;
+
+/// Meteor ore nodes hold this multiple of a normal node's maximum resources
+pub const METEOR_ORE_YIELD_MULTIPLIER: u32 = 3;
+
+/// Minimum distance from player foundations for the impact zone center
+const MIN_DISTANCE_FROM_BASES_PX: f32 = 1500.0;
+
+/// Minimum distance from world edges (in pixels)
+const WORLD_EDGE_BUFFER_PX: f32 = 800.0;
+
+/// Attempts for finding the impact center / each ore node position
+const MAX_CENTER_ATTEMPTS: u32 = 100;
+const MAX_NODE_ATTEMPTS: u32 = 20;
+
+// === TABLES ===
+
+/// Tracks meteor shower events (at most one active)
+#[spacetimedb::table(accessor = meteor_shower_event, public)]
+#[derive(Clone, Debug)]
+pub struct MeteorShowerEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub center_x: f32,
+    pub center_y: f32,
+    pub announced_at: Timestamp,
+    pub impact_at: Timestamp,
+    pub expires_at: Timestamp,  // Meteor ore nodes despawn at this time
+    pub has_impacted: bool,
+    pub is_active: bool,        // False once the ore nodes have despawned
+}
+
+/// Stone nodes spawned by a meteor shower (removed when the event expires)
+#[spacetimedb::table(accessor = meteor_ore_node)]
+#[derive(Clone, Debug)]
+pub struct MeteorOreNode {
+    #[primary_key]
+    pub stone_id: u64,
+    #[index(btree)]
+    pub event_id: u64,
+}
+
+/// One-shot schedule that advances a meteor shower (impact, then despawn)
+#[spacetimedb::table(accessor = meteor_shower_schedule, scheduled(process_meteor_shower))]
+#[derive(Clone, Debug)]
+pub struct MeteorShowerSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub event_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// === NIGHT TRIGGER ===
+
+/// Called from world_state.rs when time transitions to Night
+/// Rolls a percentage chance and announces a meteor shower if conditions are met
+pub fn on_night_started(ctx: &ReducerContext) {
+    let online_players = ctx.db.player().iter().filter(|p| p.is_online).count();
+    if online_players == 0 {
+        log::debug!("[MeteorShower] No players online, skipping meteor shower roll");
+        return;
+    }
+
+    if ctx.db.meteor_shower_event().iter().any(|e| e.is_active) {
+        log::debug!("[MeteorShower] Meteor shower already active, skipping roll");
+        return;
+    }
+
+    let roll: f32 = ctx.rng().gen();
+    if roll > METEOR_SHOWER_CHANCE {
+        log::info!("[MeteorShower] Roll failed ({:.1}% > {:.1}%), no meteor shower tonight",
+                  roll * 100.0, METEOR_SHOWER_CHANCE * 100.0);
+        return;
+    }
+
+    if let Err(e) = start_meteor_shower(ctx) {
+        log::warn!("[MeteorShower] Failed to start meteor shower: {}", e);
+    }
+}
+
+/// Picks an impact zone, announces it and schedules the impact
+fn start_meteor_shower(ctx: &ReducerContext) -> Result<(), String> {
+    let (center_x, center_y) = find_impact_center(ctx)
+        .ok_or_else(|| format!("No valid impact zone found after {} attempts", MAX_CENTER_ATTEMPTS))?;
+
+    let now = ctx.timestamp;
+    let impact_at = now + TimeDuration::from_micros((METEOR_SHOWER_WARNING_SECS * 1_000_000) as i64);
+    let expires_at = impact_at + TimeDuration::from_micros((METEOR_ORE_LIFETIME_SECS * 1_000_000) as i64);
+
+    let event = ctx.db.meteor_shower_event().insert(MeteorShowerEvent {
+        id: 0,
+        center_x,
+        center_y,
+        announced_at: now,
+        impact_at,
+        expires_at,
+        has_impacted: false,
+        is_active: true,
+    });
+
+    ctx.db.world_event_marker().insert(WorldEventMarker {
+        id: 0,
+        event_type: WorldEventType::MeteorShower,
+        event_id: event.id,
+        pos_x: center_x,
+        pos_y: center_y,
+        radius: METEOR_IMPACT_ZONE_RADIUS_PX,
+        label: "Meteor Shower".to_string(),
+        created_at: now,
+        expires_at,
+    });
+
+    schedule_meteor_shower_step(ctx, event.id, impact_at)?;
+
+    let grid_label = crate::beacon_event::minimap_grid_label(center_x, center_y);
+    send_server_announcement(ctx, format!(
+        "A meteor shower is streaking toward grid {}! Impact in {} minutes - rare ore awaits.",
+        grid_label, METEOR_SHOWER_WARNING_SECS / 60
+    ));
+    log::info!("[MeteorShower] ☄️ Meteor shower {} announced at ({:.0}, {:.0}) grid {}",
+              event.id, center_x, center_y, grid_label);
+    Ok(())
+}
+
+fn schedule_meteor_shower_step(ctx: &ReducerContext, event_id: u64, at: Timestamp) -> Result<(), String> {
+    ctx.db.meteor_shower_schedule().try_insert(MeteorShowerSchedule {
+        id: 0,
+        event_id,
+        scheduled_at: ScheduleAt::Time(at),
+    }).map_err(|e| format!("Failed to schedule meteor shower step: {}", e))?;
+    Ok(())
+}
+
+// === SPAWN POSITIONS ===
+
+/// Random impact zone center away from water, monuments and player bases
+fn find_impact_center(ctx: &ReducerContext) -> Option<(f32, f32)> {
+    for _ in 0..MAX_CENTER_ATTEMPTS {
+        let pos_x = ctx.rng().gen_range(WORLD_EDGE_BUFFER_PX..(WORLD_WIDTH_PX - WORLD_EDGE_BUFFER_PX));
+        let pos_y = ctx.rng().gen_range(WORLD_EDGE_BUFFER_PX..(WORLD_HEIGHT_PX - WORLD_EDGE_BUFFER_PX));
+
+        if is_position_on_water(ctx, pos_x, pos_y)
+            || crate::building::check_monument_zone_placement(ctx, pos_x, pos_y).is_err()
+            || crate::monument::is_position_near_monument(ctx, pos_x, pos_y)
+            || is_near_player_base(ctx, pos_x, pos_y)
+        {
+            continue;
+        }
+        return Some((pos_x, pos_y));
+    }
+    None
+}
+
+fn is_near_player_base(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    use crate::building::{foundation_cell as FoundationCellTableTrait, FOUNDATION_TILE_SIZE_PX};
+    let min_dist_sq = MIN_DISTANCE_FROM_BASES_PX * MIN_DISTANCE_FROM_BASES_PX;
+    ctx.db.foundation_cell().iter()
+        .filter(|f| !f.is_destroyed)
+        .any(|f| {
+            let fx = (f.cell_x as f32 + 0.5) * FOUNDATION_TILE_SIZE_PX as f32;
+            let fy = (f.cell_y as f32 + 0.5) * FOUNDATION_TILE_SIZE_PX as f32;
+            let dx = pos_x - fx;
+            let dy = pos_y - fy;
+            dx * dx + dy * dy < min_dist_sq
+        })
+}
+
+/// Checks a single meteor ore node position inside the impact zone
+fn is_valid_ore_node_position(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    if pos_x < 0.0 || pos_y < 0.0 || pos_x >= WORLD_WIDTH_PX || pos_y >= WORLD_HEIGHT_PX {
+        return false;
+    }
+    if is_position_on_water(ctx, pos_x, pos_y)
+        || crate::building::check_monument_zone_placement(ctx, pos_x, pos_y).is_err()
+        || crate::homestead_hearth::is_position_claimed(ctx, pos_x, pos_y)
+        || crate::building_enclosure::is_position_inside_building(ctx, pos_x, pos_y)
+    {
+        return false;
+    }
+    // Keep clear of existing stone nodes (including other meteor nodes)
+    !ctx.db.stone().iter().any(|s| {
+        let dx = pos_x - s.pos_x;
+        let dy = pos_y - s.pos_y;
+        dx * dx + dy * dy < crate::stone::MIN_STONE_DISTANCE_SQ
+    })
+}
+
+/// Rare ore roll for a meteor node - never plain stone
+fn roll_meteor_ore(ctx: &ReducerContext) -> (OreType, u32) {
+    let roll: f32 = ctx.rng().gen();
+    let (ore_type, base_max) = if roll < 0.45 {
+        (OreType::Metal, crate::stone::METAL_ORE_MAX_RESOURCES)
+    } else if roll < 0.80 {
+        (OreType::Sulfur, crate::stone::SULFUR_ORE_MAX_RESOURCES)
+    } else {
+        (OreType::Memory, crate::stone::MEMORY_SHARD_MAX_RESOURCES)
+    };
+    (ore_type, base_max * METEOR_ORE_YIELD_MULTIPLIER)
+}
+
+// === EVENT PROCESSING ===
+
+/// Advances a meteor shower: spawns ore nodes at impact, removes them at expiry
+#[spacetimedb::reducer]
+pub fn process_meteor_shower(ctx: &ReducerContext, args: MeteorShowerSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Meteor shower processing can only be run by scheduler".to_string());
+    }
+
+    let Some(mut event) = ctx.db.meteor_shower_event().id().find(&args.event_id) else {
+        return Ok(());
+    };
+    if !event.is_active {
+        return Ok(());
+    }
+
+    if !event.has_impacted {
+        let spawned = spawn_meteor_ore_nodes(ctx, &event);
+        event.has_impacted = true;
+        let expires_at = event.expires_at;
+        ctx.db.meteor_shower_event().id().update(event.clone());
+        schedule_meteor_shower_step(ctx, event.id, expires_at)?;
+
+        let grid_label = crate::beacon_event::minimap_grid_label(event.center_x, event.center_y);
+        send_server_announcement(ctx, format!(
+            "Meteors have struck grid {}! {} ore deposits will crumble away within the hour.",
+            grid_label, spawned
+        ));
+        log::info!("[MeteorShower] ☄️ Meteor shower {} impacted - spawned {} ore nodes", event.id, spawned);
+    } else {
+        end_meteor_shower(ctx, event);
+    }
+    Ok(())
+}
+
+/// Scatters meteor ore nodes across random land tiles in the impact zone
+fn spawn_meteor_ore_nodes(ctx: &ReducerContext, event: &MeteorShowerEvent) -> u32 {
+    let target_count = ctx.rng().gen_range(METEOR_ORE_NODE_MIN_COUNT..=METEOR_ORE_NODE_MAX_COUNT);
+    let mut spawned = 0;
+
+    for _ in 0..target_count {
+        for _ in 0..MAX_NODE_ATTEMPTS {
+            let angle = ctx.rng().gen_range(0.0..std::f32::consts::TAU);
+            let distance = ctx.rng().gen_range(0.0..METEOR_IMPACT_ZONE_RADIUS_PX);
+            let pos_x = event.center_x + angle.cos() * distance;
+            let pos_y = event.center_y + angle.sin() * distance;
+            if !is_valid_ore_node_position(ctx, pos_x, pos_y) {
+                continue;
+            }
+
+            let (ore_type, resource_remaining) = roll_meteor_ore(ctx);
+            let quality = crate::stone::roll_ore_quality(&mut ctx.rng());
+            match ctx.db.stone().try_insert(Stone {
+                id: 0,
+                pos_x,
+                pos_y,
+                health: crate::stone::STONE_INITIAL_HEALTH,
+                resource_remaining: crate::stone::apply_ore_quality(resource_remaining, quality),
+                ore_type,
+                quality,
+                hot_spot_angle: crate::stone::roll_hot_spot_angle(&mut ctx.rng()),
+                chunk_index: calculate_chunk_index(pos_x, pos_y),
+                last_hit_time: None,
+                respawn_at: Timestamp::UNIX_EPOCH, // 0 = not respawning
+            }) {
+                Ok(stone) => {
+                    ctx.db.meteor_ore_node().insert(MeteorOreNode { stone_id: stone.id, event_id: event.id });
+                    spawned += 1;
+                }
+                Err(e) => log::error!("[MeteorShower] Failed to insert meteor ore node: {}", e),
+            }
+            break;
+        }
+    }
+    spawned
+}
+
+/// Removes the event's remaining ore nodes and minimap marker
+fn end_meteor_shower(ctx: &ReducerContext, mut event: MeteorShowerEvent) {
+    let nodes: Vec<MeteorOreNode> = ctx.db.meteor_ore_node().event_id().filter(&event.id).collect();
+    let node_count = nodes.len();
+    for node in nodes {
+        ctx.db.stone().id().delete(node.stone_id);
+        ctx.db.meteor_ore_node().stone_id().delete(node.stone_id);
+    }
+
+    crate::world_state::remove_world_event_markers(ctx, WorldEventType::MeteorShower, event.id);
+
+    event.is_active = false;
+    ctx.db.meteor_shower_event().id().update(event.clone());
+    log::info!("[MeteorShower] Meteor shower {} ended - removed {} ore nodes", event.id, node_count);
+}
+
+fn send_server_announcement(ctx: &ReducerContext, text: String) {
+    ctx.db.message().insert(crate::chat::Message {
+        id: 0,
+        sender: ctx.identity(), // Server identity
+        sender_username: "[SERVER]".to_string(),
+        sender_title: None,
+        text,
+        sent: ctx.timestamp,
+    });
+}
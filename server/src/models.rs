@@ -153,6 +153,15 @@ pub enum BuildingFacing {
     Exterior = 1,
 }
 
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
+#[repr(u8)]
+pub enum WallType {
+    Solid = 0,      // Full wall - blocks everything
+    Window = 1,     // Wall with an opening - projectiles pass through
+    Half = 2,       // Waist-high wall - projectiles pass over, blocks movement
+    Doorframe = 3,  // Frame for a door - open edge until a door is fitted
+}
+
 #[derive(SpacetimeType, Clone, Copy, Debug, PartialEq)]
 #[repr(u8)]
 pub enum BuildingTier {
@@ -184,6 +193,14 @@ pub enum ImmunityType {
     Bleed,     // Immunity to bleed effects
 }
 
+/// Environmental hazards a single piece of gear can protect against
+#[derive(SpacetimeType, Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub enum EnvironmentalHazard {
+    Heat,     // Scalding steam and heat exhaustion (fumaroles)
+    Static,   // Memory static around charged rune stones
+    Pressure, // Crushing pressure in deep water
+}
+
 /// Enum to differentiate between ammunition types for weapon compatibility
 #[derive(SpacetimeType, Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
 pub enum AmmoType {
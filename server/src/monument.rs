@@ -56,6 +56,10 @@ pub mod clearance {
     /// Alpine village - clear a 16-tile radius (800px)
     /// Single earth-sheltered lodge in alpine biome, like other village monuments
     pub const ALPINE_VILLAGE: f32 = 800.0;
+    
+    /// Forest ruins (abandoned cabins, ruined towers) - clear a 6-tile radius (300px)
+    /// Small single-structure prefabs scattered through forest and tundra
+    pub const FOREST_RUIN: f32 = 300.0;
 }
 
 /// Minimum distance between monument spawns (barrels, harvestables, placeables)
@@ -130,6 +134,11 @@ pub fn is_position_near_monument(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -
         return true;
     }
     
+    // Check scattered forest ruins (abandoned cabins, ruined towers)
+    if is_near_forest_ruin(ctx, pos_x, pos_y) {
+        return true;
+    }
+    
     // Check ALK substations (clearance for trees/stones)
     if is_near_alk_substation(ctx, pos_x, pos_y) {
         return true;
@@ -281,6 +290,26 @@ fn is_near_wolf_den(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
     false
 }
 
+/// Checks if position is near any abandoned cabin or ruined tower
+fn is_near_forest_ruin(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    let clearance_sq = clearance::FOREST_RUIN * clearance::FOREST_RUIN;
+    
+    for part in ctx.db.monument_part().iter() {
+        if part.monument_type != MonumentType::AbandonedCabin && part.monument_type != MonumentType::RuinedTower {
+            continue;
+        }
+        let dx = pos_x - part.world_x;
+        let dy = pos_y - part.world_y;
+        let dist_sq = dx * dx + dy * dy;
+        
+        if dist_sq < clearance_sq {
+            return true;
+        }
+    }
+    
+    false
+}
+
 /// Checks if position is near the alpine village (single lodge center)
 fn is_near_alpine_village(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
     let clearance_sq = clearance::ALPINE_VILLAGE * clearance::ALPINE_VILLAGE;
@@ -2194,8 +2223,9 @@ pub fn get_alpine_village_harvestables() -> Vec<MonumentHarvestableConfig> {
 /// Returns (center_positions, monument_parts) where:
 /// - center_positions: Vec of (x, y) in world pixels for each wolf mound
 /// - monument_parts: Vec of (x, y, image_path, part_type) for all wolf mound structures
-/// NOTE: Spawns up to 2 wolf dens in the tundra, with minimum distance between them
+/// NOTE: Spawns up to `max_dens` wolf dens in the tundra, with minimum distance between them
 pub fn generate_wolf_den(
+    max_dens: usize,
     noise: &Perlin,
     shore_distance: &[Vec<f64>],
     river_network: &[Vec<bool>],
@@ -2216,10 +2246,9 @@ pub fn generate_wolf_den(
     let mut den_centers: Vec<(f32, f32)> = Vec::new();
     let mut den_parts: Vec<(f32, f32, String, String)> = Vec::new();
     
-    const MAX_WOLF_DENS: usize = 2; // Allow up to 2 wolf dens
     const MIN_DISTANCE_BETWEEN_DENS: f32 = 80.0; // Minimum 80 tiles between wolf dens
     
-    log::info!("🐺 Generating wolf den monuments in tundra biome (up to {})...", MAX_WOLF_DENS);
+    log::info!("🐺 Generating wolf den monuments in tundra biome (up to {})...", max_dens);
     
     // Find suitable tundra tiles - must be in TUNDRA biome, away from water and rivers
     let min_shore_dist = 12.0;  // At least 12 tiles from water
@@ -2414,9 +2443,9 @@ pub fn generate_wolf_den(
     
     let tile_size_px = crate::TILE_SIZE_PX as f32;
     
-    // Select up to MAX_WOLF_DENS positions with minimum distance between them
+    // Select up to max_dens positions with minimum distance between them
     for ((center_x, center_y), _score) in scored_positions {
-        if den_centers.len() >= MAX_WOLF_DENS {
+        if den_centers.len() >= max_dens {
             break;
         }
         
@@ -2555,6 +2584,14 @@ pub fn get_shipwreck_harvestables() -> Vec<MonumentHarvestableConfig> {
             min_distance: 90.0,
             max_distance: 220.0,
         },
+        // Sunken Crate - cargo that spilled into the sea when the ship broke up (requires diving)
+        // Spawned further out so the crates land in the water off the wreck
+        MonumentHarvestableConfig {
+            plant_type: crate::plants_database::PlantType::SunkenCrate,
+            spawn_chance: 0.35, // 35% per part = ~2-3 crates per shipwreck
+            min_distance: 250.0,
+            max_distance: 450.0,
+        },
     ]
 }
 
@@ -2752,6 +2789,17 @@ pub fn spawn_monument_harvestables_with_collision(
                 }
             }
             
+            // Underwater resources (sunken crates) must always sit in the sea - they are only reachable by diving
+            let is_underwater_resource = crate::plants_database::PLANT_CONFIGS.get(&config.plant_type)
+                .map(|plant_config| matches!(plant_config.spawn_condition, crate::plants_database::SpawnCondition::Underwater))
+                .unwrap_or(false);
+            if is_underwater_resource
+                && (!crate::environment::is_position_on_water(ctx, spawn_x, spawn_y)
+                    || crate::environment::is_position_on_inland_water(ctx, spawn_x, spawn_y)) {
+                log::debug!("[MonumentHarvestables] Skipping {:?} at ({:.1}, {:.1}) - not in the sea", config.plant_type, spawn_x, spawn_y);
+                continue;
+            }
+            
             let chunk_idx = crate::environment::calculate_chunk_index(spawn_x, spawn_y);
             
             // Create harvestable resource
@@ -3589,9 +3637,10 @@ use crate::furnace::{Furnace, FURNACE_INITIAL_HEALTH, FURNACE_MAX_HEALTH, INITIA
 use crate::rain_collector::{RainCollector, RAIN_COLLECTOR_INITIAL_HEALTH, RAIN_COLLECTOR_MAX_HEALTH};
 use crate::lantern::{Lantern, LANTERN_INITIAL_HEALTH, LANTERN_MAX_HEALTH};
 use crate::wooden_storage_box::{
-    WoodenStorageBox, BOX_TYPE_COOKING_STATION, BOX_TYPE_REPAIR_BENCH, BOX_TYPE_COMPOST,
+    WoodenStorageBox, BOX_TYPE_COOKING_STATION, BOX_TYPE_REPAIR_BENCH, BOX_TYPE_COMPOST, BOX_TYPE_RECYCLER,
     COOKING_STATION_INITIAL_HEALTH, COOKING_STATION_MAX_HEALTH,
     REPAIR_BENCH_INITIAL_HEALTH, REPAIR_BENCH_MAX_HEALTH,
+    RECYCLER_INITIAL_HEALTH, RECYCLER_MAX_HEALTH,
     BOX_COLLISION_Y_OFFSET,
 };
 use crate::campfire::campfire as CampfireTableTrait;
@@ -3618,6 +3667,7 @@ pub enum MonumentPlaceableType {
     Barrel,
     MilitaryRation,
     Turret,
+    Recycler,
 }
 
 /// Configuration for a single monument placeable
@@ -3732,6 +3782,15 @@ impl MonumentPlaceableConfig {
             initial_fuel: None,
         }
     }
+    
+    pub fn recycler(offset_x: f32, offset_y: f32) -> Self {
+        Self {
+            placeable_type: MonumentPlaceableType::Recycler,
+            offset_x,
+            offset_y,
+            initial_fuel: None,
+        }
+    }
 }
 
 /// Get monument placeables for the Central ALK Compound
@@ -3757,6 +3816,8 @@ pub fn get_central_compound_placeables() -> Vec<MonumentPlaceableConfig> {
         MonumentPlaceableConfig::repair_bench(650.0, 0.0),
         // ALK Bio Processor (south-center) - compost facility
         MonumentPlaceableConfig::compost(0.0, 400.0),
+        // ALK Recycler (east of the bio processor) - breaks loot back into components
+        MonumentPlaceableConfig::recycler(250.0, 400.0),
         
         // === Monument Barrels (indestructible, scattered around compound) ===
         // Near garage (north-west) - stacked supplies
@@ -3824,6 +3885,32 @@ pub fn get_central_compound_placeables() -> Vec<MonumentPlaceableConfig> {
     ]
 }
 
+/// Get monument placeables for the ALK substations
+/// Offsets are from the station's interaction center, clear of the substation building
+pub fn get_alk_substation_placeables() -> Vec<MonumentPlaceableConfig> {
+    vec![
+        // Recycler beside the delivery point
+        MonumentPlaceableConfig::recycler(260.0, 120.0),
+    ]
+}
+
+/// Get monument placeables for an abandoned cabin (forest ruin)
+/// A ration crate left on the porch and a barrel round the side
+pub fn get_abandoned_cabin_placeables() -> Vec<MonumentPlaceableConfig> {
+    vec![
+        MonumentPlaceableConfig::military_ration(60.0, 100.0),
+        MonumentPlaceableConfig::barrel(-100.0, 80.0),
+    ]
+}
+
+/// Get monument placeables for a ruined tower (forest ruin)
+pub fn get_ruined_tower_placeables() -> Vec<MonumentPlaceableConfig> {
+    vec![
+        MonumentPlaceableConfig::barrel(80.0, 90.0),
+        MonumentPlaceableConfig::barrel(-70.0, 100.0),
+    ]
+}
+
 /// Get monument placeables for the Shipwreck monument
 pub fn get_shipwreck_placeables() -> Vec<MonumentPlaceableConfig> {
     vec![
@@ -4357,6 +4444,8 @@ pub fn spawn_monument_placeables(
                     total_water_collected: 0.0,
                     last_collection_time: None,
                     is_salt_water: false,
+                    is_purifier: false,
+                    purification_progress_secs: 0.0,
                     // Mark as monument placeable
                     is_monument: true,
                     active_user_id: None,
@@ -4624,6 +4713,88 @@ pub fn spawn_monument_placeables(
                 }
             }
             
+            MonumentPlaceableType::Recycler => {
+                let recycler = WoodenStorageBox {
+                    id: 0,
+                    pos_x: world_x,
+                    pos_y: world_y + BOX_COLLISION_Y_OFFSET,
+                    chunk_index: chunk_idx,
+                    placed_by: monument_owner,
+                    box_type: BOX_TYPE_RECYCLER,
+                    slot_instance_id_0: None, slot_def_id_0: None,
+                    slot_instance_id_1: None, slot_def_id_1: None,
+                    slot_instance_id_2: None, slot_def_id_2: None,
+                    slot_instance_id_3: None, slot_def_id_3: None,
+                    slot_instance_id_4: None, slot_def_id_4: None,
+                    slot_instance_id_5: None, slot_def_id_5: None,
+                    slot_instance_id_6: None, slot_def_id_6: None,
+                    slot_instance_id_7: None, slot_def_id_7: None,
+                    slot_instance_id_8: None, slot_def_id_8: None,
+                    slot_instance_id_9: None, slot_def_id_9: None,
+                    slot_instance_id_10: None, slot_def_id_10: None,
+                    slot_instance_id_11: None, slot_def_id_11: None,
+                    slot_instance_id_12: None, slot_def_id_12: None,
+                    slot_instance_id_13: None, slot_def_id_13: None,
+                    slot_instance_id_14: None, slot_def_id_14: None,
+                    slot_instance_id_15: None, slot_def_id_15: None,
+                    slot_instance_id_16: None, slot_def_id_16: None,
+                    slot_instance_id_17: None, slot_def_id_17: None,
+                    slot_instance_id_18: None, slot_def_id_18: None,
+                    slot_instance_id_19: None, slot_def_id_19: None,
+                    slot_instance_id_20: None, slot_def_id_20: None,
+                    slot_instance_id_21: None, slot_def_id_21: None,
+                    slot_instance_id_22: None, slot_def_id_22: None,
+                    slot_instance_id_23: None, slot_def_id_23: None,
+                    slot_instance_id_24: None, slot_def_id_24: None,
+                    slot_instance_id_25: None, slot_def_id_25: None,
+                    slot_instance_id_26: None, slot_def_id_26: None,
+                    slot_instance_id_27: None, slot_def_id_27: None,
+                    slot_instance_id_28: None, slot_def_id_28: None,
+                    slot_instance_id_29: None, slot_def_id_29: None,
+                    slot_instance_id_30: None, slot_def_id_30: None,
+                    slot_instance_id_31: None, slot_def_id_31: None,
+                    slot_instance_id_32: None, slot_def_id_32: None,
+                    slot_instance_id_33: None, slot_def_id_33: None,
+                    slot_instance_id_34: None, slot_def_id_34: None,
+                    slot_instance_id_35: None, slot_def_id_35: None,
+                    slot_instance_id_36: None, slot_def_id_36: None,
+                    slot_instance_id_37: None, slot_def_id_37: None,
+                    slot_instance_id_38: None, slot_def_id_38: None,
+                    slot_instance_id_39: None, slot_def_id_39: None,
+                    slot_instance_id_40: None, slot_def_id_40: None,
+                    slot_instance_id_41: None, slot_def_id_41: None,
+                    slot_instance_id_42: None, slot_def_id_42: None,
+                    slot_instance_id_43: None, slot_def_id_43: None,
+                    slot_instance_id_44: None, slot_def_id_44: None,
+                    slot_instance_id_45: None, slot_def_id_45: None,
+                    slot_instance_id_46: None, slot_def_id_46: None,
+                    slot_instance_id_47: None, slot_def_id_47: None,
+                    health: RECYCLER_INITIAL_HEALTH,
+                    max_health: RECYCLER_MAX_HEALTH,
+                    is_destroyed: false,
+                    destroyed_at: None,
+                    last_hit_time: None,
+                    last_damaged_by: None,
+                    respawn_at: Timestamp::UNIX_EPOCH, // 0 = not respawning (monument placeables don't respawn)
+                    // Mark as monument placeable
+                    is_monument: true,
+                    active_user_id: None,
+                    active_user_since: None,
+                };
+                
+                match ctx.db.wooden_storage_box().try_insert(recycler) {
+                    Ok(inserted) => {
+                        spawned_count += 1;
+                        log::info!("[MonumentPlaceables] Spawned monument recycler {} at ({:.1}, {:.1}) for {}", 
+                            inserted.id, world_x, world_y, monument_name);
+                    }
+                    Err(e) => {
+                        log::warn!("[MonumentPlaceables] Failed to spawn recycler at ({:.1}, {:.1}): {}", 
+                            world_x, world_y, e);
+                    }
+                }
+            }
+            
             MonumentPlaceableType::Turret => {
                 let turret = Turret {
                     id: 0,
@@ -0,0 +1,455 @@
+/******************************************************************************
+ *                                                                            *
+ * Monument Puzzle Rooms - Switches, fuse boxes and timed vault doors         *
+ *                                                                            *
+ * A puzzle room guards a vault crate (BOX_TYPE_VAULT_CRATE) behind a timed   *
+ * door. The door opens once every pressure switch is held and every fuse     *
+ * box has a Fuse in it. Switches latch for a few seconds after a player      *
+ * steps off, so one quick player can run the circuit alone. The door stays   *
+ * open for a while, then the room resets: fuses are spent, switches drop     *
+ * and the vault is restocked.                                                *
+ *                                                                            *
+ * Room state is driven by a schedule; elements are public so the client can  *
+ * render lit switches, powered fuse boxes and the door.                      *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp, TimeDuration};
+use spacetimedb::spacetimedb_lib::ScheduleAt;
+use log;
+use std::time::Duration;
+
+use crate::wooden_storage_box::{
+    WoodenStorageBox, BOX_TYPE_VAULT_CRATE, VAULT_CRATE_INITIAL_HEALTH, VAULT_CRATE_MAX_HEALTH,
+    wooden_storage_box as WoodenStorageBoxTableTrait,
+};
+use crate::items::{InventoryItem, inventory_item as InventoryItemTableTrait};
+use crate::inventory_management::ItemContainer;
+use crate::loot_table::{LootEntryDefault, LootSourceDefault};
+use crate::models::{ItemLocation, ContainerType, ContainerLocationData};
+use crate::environment::calculate_chunk_index;
+use crate::player as PlayerTableTrait;
+
+// --- Puzzle Constants ---
+pub const PUZZLE_TICK_INTERVAL_SECS: u64 = 1;
+pub const PRESSURE_SWITCH_RADIUS: f32 = 40.0; // Player must stand this close to hold a switch
+pub const PRESSURE_SWITCH_LATCH_SECS: u64 = 12; // Switch stays lit after the player steps off
+pub const FUSE_BOX_INTERACTION_DISTANCE: f32 = 96.0;
+pub const FUSE_ITEM_NAME: &str = "Fuse";
+pub const PUZZLE_VAULT_LOOT_SOURCE: &str = "puzzle_vault";
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum PuzzleElementKind {
+    PressureSwitch,
+    FuseBox,
+    TimedDoor,
+}
+
+#[derive(SpacetimeType, Clone, Debug, PartialEq)]
+pub enum PuzzleRoomState {
+    Armed,     // Waiting for switches and fuses
+    Open,      // Door open - vault can be looted
+    Resetting, // Door shut, waiting to re-arm
+}
+
+/// A puzzle room guarding a vault crate
+#[spacetimedb::table(accessor = puzzle_room, public)]
+#[derive(Clone, Debug)]
+pub struct PuzzleRoom {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub name: String,
+    pub vault_box_id: u32,
+    pub state: PuzzleRoomState,
+    pub door_open_secs: u32,
+    pub reset_secs: u32,
+    pub state_until: Option<Timestamp>, // When Open/Resetting ends
+    pub times_solved: u32,
+}
+
+/// A switch, fuse box or door belonging to a puzzle room
+#[spacetimedb::table(accessor = puzzle_element, public)]
+#[derive(Clone, Debug)]
+pub struct PuzzleElement {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub room_id: u64,
+    pub kind: PuzzleElementKind,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub is_active: bool, // Switch held/latched, fuse inserted, door open
+    pub active_until: Option<Timestamp>, // Switch latch expiry
+    pub activated_by: Option<Identity>,
+}
+
+#[spacetimedb::table(accessor = puzzle_tick_schedule, scheduled(process_puzzle_rooms))]
+#[derive(Clone)]
+pub struct PuzzleTickSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// --- Room Layouts ---
+
+/// Layout of a puzzle room, as offsets from the monument center
+pub struct PuzzleRoomConfig {
+    pub name: &'static str,
+    pub vault_offset: (f32, f32),
+    pub door_offset: (f32, f32),
+    pub switch_offsets: &'static [(f32, f32)],
+    pub fuse_box_offsets: &'static [(f32, f32)],
+    pub door_open_secs: u32,
+    pub reset_secs: u32,
+}
+
+/// ALK compound garage vault - two switches across the yard and a fuse box by the door
+pub fn compound_garage_vault() -> PuzzleRoomConfig {
+    PuzzleRoomConfig {
+        name: "Garage Vault",
+        vault_offset: (-350.0, -530.0),
+        door_offset: (-350.0, -560.0),
+        switch_offsets: &[(-560.0, -380.0), (-120.0, -380.0)],
+        fuse_box_offsets: &[(-260.0, -560.0)],
+        door_open_secs: 90,
+        reset_secs: 1800,
+    }
+}
+
+/// Weather station vault - one switch and two fuse boxes
+pub fn weather_station_vault() -> PuzzleRoomConfig {
+    PuzzleRoomConfig {
+        name: "Weather Station Vault",
+        vault_offset: (380.0, -60.0),
+        door_offset: (350.0, -60.0),
+        switch_offsets: &[(250.0, 220.0)],
+        fuse_box_offsets: &[(300.0, -220.0), (450.0, 120.0)],
+        door_open_secs: 60,
+        reset_secs: 2400,
+    }
+}
+
+/// Default vault loot
+pub fn default_puzzle_vault_loot_sources() -> Vec<LootSourceDefault> {
+    vec![
+        LootSourceDefault::new(PUZZLE_VAULT_LOOT_SOURCE, vec![
+            LootEntryDefault::new("9x18mm Round", 12, 24, 0.7),
+            LootEntryDefault::new("Scrap Batteries", 2, 4, 0.6),
+            LootEntryDefault::new("Red Keycard", 1, 1, 0.25),
+            LootEntryDefault::new("Makarov PM", 1, 1, 0.25),
+            LootEntryDefault::new("PP-91 KEDR", 1, 1, 0.1),
+            LootEntryDefault::new("Tallow Steam Turret Blueprint", 1, 1, 0.15),
+            LootEntryDefault::new("Makarov PM Blueprint", 1, 1, 0.15),
+        ]).drops(3, 5),
+    ]
+}
+
+// --- Init & Spawning ---
+
+/// Initialize the puzzle room schedule
+pub fn init_puzzle_system(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.puzzle_tick_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("Starting puzzle room schedule (every {}s).", PUZZLE_TICK_INTERVAL_SECS);
+        let interval = Duration::from_secs(PUZZLE_TICK_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            PuzzleTickSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Puzzle room"
+        );
+    } else {
+        log::debug!("Puzzle room schedule already exists.");
+    }
+    Ok(())
+}
+
+/// Spawns a puzzle room (vault crate + elements) around a monument center. Returns the room ID.
+pub fn spawn_puzzle_room(ctx: &ReducerContext, config: &PuzzleRoomConfig, center_x: f32, center_y: f32) -> Result<u64, String> {
+    let vault_x = center_x + config.vault_offset.0;
+    let vault_y = center_y + config.vault_offset.1;
+    let vault = ctx.db.wooden_storage_box().insert(WoodenStorageBox {
+        id: 0,
+        pos_x: vault_x,
+        pos_y: vault_y,
+        chunk_index: calculate_chunk_index(vault_x, vault_y),
+        placed_by: ctx.identity(),
+        box_type: BOX_TYPE_VAULT_CRATE,
+        slot_instance_id_0: None, slot_def_id_0: None,
+        slot_instance_id_1: None, slot_def_id_1: None,
+        slot_instance_id_2: None, slot_def_id_2: None,
+        slot_instance_id_3: None, slot_def_id_3: None,
+        slot_instance_id_4: None, slot_def_id_4: None,
+        slot_instance_id_5: None, slot_def_id_5: None,
+        slot_instance_id_6: None, slot_def_id_6: None,
+        slot_instance_id_7: None, slot_def_id_7: None,
+        slot_instance_id_8: None, slot_def_id_8: None,
+        slot_instance_id_9: None, slot_def_id_9: None,
+        slot_instance_id_10: None, slot_def_id_10: None,
+        slot_instance_id_11: None, slot_def_id_11: None,
+        slot_instance_id_12: None, slot_def_id_12: None,
+        slot_instance_id_13: None, slot_def_id_13: None,
+        slot_instance_id_14: None, slot_def_id_14: None,
+        slot_instance_id_15: None, slot_def_id_15: None,
+        slot_instance_id_16: None, slot_def_id_16: None,
+        slot_instance_id_17: None, slot_def_id_17: None,
+        slot_instance_id_18: None, slot_def_id_18: None,
+        slot_instance_id_19: None, slot_def_id_19: None,
+        slot_instance_id_20: None, slot_def_id_20: None,
+        slot_instance_id_21: None, slot_def_id_21: None,
+        slot_instance_id_22: None, slot_def_id_22: None,
+        slot_instance_id_23: None, slot_def_id_23: None,
+        slot_instance_id_24: None, slot_def_id_24: None,
+        slot_instance_id_25: None, slot_def_id_25: None,
+        slot_instance_id_26: None, slot_def_id_26: None,
+        slot_instance_id_27: None, slot_def_id_27: None,
+        slot_instance_id_28: None, slot_def_id_28: None,
+        slot_instance_id_29: None, slot_def_id_29: None,
+        slot_instance_id_30: None, slot_def_id_30: None,
+        slot_instance_id_31: None, slot_def_id_31: None,
+        slot_instance_id_32: None, slot_def_id_32: None,
+        slot_instance_id_33: None, slot_def_id_33: None,
+        slot_instance_id_34: None, slot_def_id_34: None,
+        slot_instance_id_35: None, slot_def_id_35: None,
+        slot_instance_id_36: None, slot_def_id_36: None,
+        slot_instance_id_37: None, slot_def_id_37: None,
+        slot_instance_id_38: None, slot_def_id_38: None,
+        slot_instance_id_39: None, slot_def_id_39: None,
+        slot_instance_id_40: None, slot_def_id_40: None,
+        slot_instance_id_41: None, slot_def_id_41: None,
+        slot_instance_id_42: None, slot_def_id_42: None,
+        slot_instance_id_43: None, slot_def_id_43: None,
+        slot_instance_id_44: None, slot_def_id_44: None,
+        slot_instance_id_45: None, slot_def_id_45: None,
+        slot_instance_id_46: None, slot_def_id_46: None,
+        slot_instance_id_47: None, slot_def_id_47: None,
+        health: VAULT_CRATE_INITIAL_HEALTH,
+        max_health: VAULT_CRATE_MAX_HEALTH,
+        is_destroyed: false,
+        destroyed_at: None,
+        last_hit_time: None,
+        last_damaged_by: None,
+        respawn_at: Timestamp::UNIX_EPOCH,
+        is_monument: true, // Indestructible - the door is the only way in
+        active_user_id: None,
+        active_user_since: None,
+    });
+    restock_vault(ctx, vault.id);
+
+    let room = ctx.db.puzzle_room().insert(PuzzleRoom {
+        id: 0,
+        name: config.name.to_string(),
+        vault_box_id: vault.id,
+        state: PuzzleRoomState::Armed,
+        door_open_secs: config.door_open_secs,
+        reset_secs: config.reset_secs,
+        state_until: None,
+        times_solved: 0,
+    });
+
+    let elements = config.switch_offsets.iter().map(|offset| (PuzzleElementKind::PressureSwitch, offset))
+        .chain(config.fuse_box_offsets.iter().map(|offset| (PuzzleElementKind::FuseBox, offset)))
+        .chain(std::iter::once((PuzzleElementKind::TimedDoor, &config.door_offset)));
+    for (kind, (offset_x, offset_y)) in elements {
+        ctx.db.puzzle_element().insert(PuzzleElement {
+            id: 0,
+            room_id: room.id,
+            kind,
+            pos_x: center_x + offset_x,
+            pos_y: center_y + offset_y,
+            is_active: false,
+            active_until: None,
+            activated_by: None,
+        });
+    }
+
+    log::info!("[Puzzle] Spawned puzzle room '{}' ({}) with vault {} at ({:.1}, {:.1})", config.name, room.id, vault.id, vault_x, vault_y);
+    Ok(room.id)
+}
+
+/// Fills the vault's empty slots with fresh loot
+fn restock_vault(ctx: &ReducerContext, vault_box_id: u32) {
+    let Some(mut vault) = ctx.db.wooden_storage_box().id().find(vault_box_id) else {
+        log::warn!("[Puzzle] Vault crate {} not found for restock", vault_box_id);
+        return;
+    };
+    let inventory_items = ctx.db.inventory_item();
+    let empty_slots: Vec<u8> = (0..vault.num_slots() as u8)
+        .filter(|slot| vault.get_slot_instance_id(*slot).is_none())
+        .collect();
+    let drops = crate::loot_table::roll_loot(ctx, PUZZLE_VAULT_LOOT_SOURCE, 0);
+    for (slot, drop) in empty_slots.into_iter().zip(drops) {
+        let inserted = inventory_items.insert(InventoryItem {
+            instance_id: 0,
+            item_def_id: drop.item_def_id,
+            quantity: drop.quantity,
+            location: ItemLocation::Container(ContainerLocationData {
+                container_type: ContainerType::WoodenStorageBox,
+                container_id: vault_box_id as u64,
+                slot_index: slot,
+            }),
+            item_data: None,
+        });
+        vault.set_slot(slot, Some(inserted.instance_id), Some(inserted.item_def_id));
+    }
+    ctx.db.wooden_storage_box().id().update(vault);
+}
+
+/// Refuses access to a vault crate unless its room's door is open
+pub fn ensure_vault_door_open(ctx: &ReducerContext, storage_box: &WoodenStorageBox) -> Result<(), String> {
+    if storage_box.box_type != BOX_TYPE_VAULT_CRATE {
+        return Ok(());
+    }
+    match ctx.db.puzzle_room().iter().find(|room| room.vault_box_id == storage_box.id) {
+        Some(room) if room.state != PuzzleRoomState::Open => {
+            Err("The vault door is sealed. Hold the switches and power the fuse boxes to open it.".to_string())
+        }
+        _ => Ok(()),
+    }
+}
+
+// --- Scheduled State Machine ---
+
+/// Scheduled reducer that updates switches and drives every room's door/reset cycle
+#[spacetimedb::reducer]
+pub fn process_puzzle_rooms(ctx: &ReducerContext, _args: PuzzleTickSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Puzzle room processing can only be run by scheduler".to_string());
+    }
+
+    let now = ctx.timestamp;
+    let rooms: Vec<PuzzleRoom> = ctx.db.puzzle_room().iter().collect();
+    for mut room in rooms {
+        let state_over = room.state_until.map_or(false, |until| now >= until);
+        match room.state {
+            PuzzleRoomState::Armed => {
+                update_pressure_switches(ctx, room.id);
+                let solved = ctx.db.puzzle_element().room_id().filter(room.id)
+                    .filter(|e| e.kind != PuzzleElementKind::TimedDoor)
+                    .all(|e| e.is_active);
+                if solved {
+                    set_door(ctx, room.id, true);
+                    room.state = PuzzleRoomState::Open;
+                    room.state_until = Some(now + TimeDuration::from_micros(room.door_open_secs as i64 * 1_000_000));
+                    room.times_solved += 1;
+                    log::info!("[Puzzle] Room '{}' solved - door open for {}s", room.name, room.door_open_secs);
+                    ctx.db.puzzle_room().id().update(room);
+                }
+            }
+            PuzzleRoomState::Open if state_over => {
+                set_door(ctx, room.id, false);
+                room.state = PuzzleRoomState::Resetting;
+                room.state_until = Some(now + TimeDuration::from_micros(room.reset_secs as i64 * 1_000_000));
+                log::info!("[Puzzle] Room '{}' door closed - resetting in {}s", room.name, room.reset_secs);
+                ctx.db.puzzle_room().id().update(room);
+            }
+            PuzzleRoomState::Resetting if state_over => {
+                reset_elements(ctx, room.id);
+                restock_vault(ctx, room.vault_box_id);
+                room.state = PuzzleRoomState::Armed;
+                room.state_until = None;
+                log::info!("[Puzzle] Room '{}' re-armed", room.name);
+                ctx.db.puzzle_room().id().update(room);
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Lights switches with a player standing on them and drops switches whose latch ran out
+fn update_pressure_switches(ctx: &ReducerContext, room_id: u64) {
+    let now = ctx.timestamp;
+    let latch = TimeDuration::from_micros(PRESSURE_SWITCH_LATCH_SECS as i64 * 1_000_000);
+    let switches: Vec<PuzzleElement> = ctx.db.puzzle_element().room_id().filter(room_id)
+        .filter(|e| e.kind == PuzzleElementKind::PressureSwitch)
+        .collect();
+
+    for mut switch in switches {
+        let presser = ctx.db.player().iter()
+            .filter(|p| p.is_online && !p.is_dead && !p.is_knocked_out)
+            .find(|p| {
+                let dx = p.position_x - switch.pos_x;
+                let dy = p.position_y - switch.pos_y;
+                dx * dx + dy * dy <= PRESSURE_SWITCH_RADIUS * PRESSURE_SWITCH_RADIUS
+            });
+        if let Some(player) = presser {
+            let newly_pressed = !switch.is_active;
+            switch.is_active = true;
+            switch.active_until = Some(now + latch);
+            switch.activated_by = Some(player.identity);
+            if newly_pressed {
+                log::debug!("[Puzzle] Player {:?} pressed switch {}", player.identity, switch.id);
+            }
+            ctx.db.puzzle_element().id().update(switch);
+        } else if switch.is_active && switch.active_until.map_or(true, |until| now >= until) {
+            switch.is_active = false;
+            switch.active_until = None;
+            ctx.db.puzzle_element().id().update(switch);
+        }
+    }
+}
+
+fn set_door(ctx: &ReducerContext, room_id: u64, open: bool) {
+    let doors: Vec<PuzzleElement> = ctx.db.puzzle_element().room_id().filter(room_id)
+        .filter(|e| e.kind == PuzzleElementKind::TimedDoor)
+        .collect();
+    for mut door in doors {
+        door.is_active = open;
+        ctx.db.puzzle_element().id().update(door);
+    }
+}
+
+/// Spends inserted fuses and drops every switch
+fn reset_elements(ctx: &ReducerContext, room_id: u64) {
+    let elements: Vec<PuzzleElement> = ctx.db.puzzle_element().room_id().filter(room_id).collect();
+    for mut element in elements {
+        element.is_active = false;
+        element.active_until = None;
+        element.activated_by = None;
+        ctx.db.puzzle_element().id().update(element);
+    }
+}
+
+// --- Reducers ---
+
+/// --- Insert Fuse ---
+/// Puts a Fuse from the player's inventory into a fuse box. The fuse is spent when the room resets.
+#[spacetimedb::reducer]
+pub fn insert_fuse(ctx: &ReducerContext, element_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+    let mut fuse_box = ctx.db.puzzle_element().id().find(element_id)
+        .ok_or_else(|| "Fuse box not found.".to_string())?;
+    if fuse_box.kind != PuzzleElementKind::FuseBox {
+        return Err("That is not a fuse box.".to_string());
+    }
+
+    let dx = player.position_x - fuse_box.pos_x;
+    let dy = player.position_y - fuse_box.pos_y;
+    if dx * dx + dy * dy > FUSE_BOX_INTERACTION_DISTANCE * FUSE_BOX_INTERACTION_DISTANCE {
+        return Err("Too far away".to_string());
+    }
+    let room = ctx.db.puzzle_room().id().find(fuse_box.room_id)
+        .ok_or_else(|| "Puzzle room not found.".to_string())?;
+    if room.state != PuzzleRoomState::Armed {
+        return Err("The circuit is dead - wait for the room to reset.".to_string());
+    }
+    if fuse_box.is_active {
+        return Err("This fuse box already has a fuse.".to_string());
+    }
+
+    crate::locked_crate::consume_one_carried_item(ctx, sender_id, FUSE_ITEM_NAME)?;
+    fuse_box.is_active = true;
+    fuse_box.activated_by = Some(sender_id);
+    ctx.db.puzzle_element().id().update(fuse_box);
+    log::info!("[Puzzle] Player {:?} inserted a fuse into fuse box {} ('{}')", sender_id, element_id, room.name);
+    Ok(())
+}
@@ -1410,6 +1410,7 @@ pub fn check_plant_growth(ctx: &ReducerContext, _args: PlantedSeedGrowthSchedule
     
     // Process all planted seeds to update their growth
     let all_plants: Vec<PlantedSeed> = ctx.db.planted_seed().iter().collect();
+    let greenhouse_footprints = crate::greenhouse::get_greenhouse_footprints(ctx);
     
     for mut plant in all_plants {
         // Calculate time elapsed since last update
@@ -1421,8 +1422,11 @@ pub fn check_plant_growth(ctx: &ReducerContext, _args: PlantedSeedGrowthSchedule
             continue; // No time has passed
         }
         
+        // Greenhouse crops are sheltered from the seasons and the weather
+        let in_greenhouse = crate::greenhouse::is_position_in_any_greenhouse(&greenhouse_footprints, plant.pos_x, plant.pos_y);
+        
         // Check if this plant can grow in the current season
-        if !crate::plants_database::can_grow_in_season(&plant.plant_type, &current_season) {
+        if !in_greenhouse && !crate::plants_database::can_grow_in_season(&plant.plant_type, &current_season) {
             // Plant is dormant this season - update last_growth_update but don't grow
             let plant_id = plant.id;
             let plant_type = plant.plant_type;
@@ -1444,7 +1448,7 @@ pub fn check_plant_growth(ctx: &ReducerContext, _args: PlantedSeedGrowthSchedule
         
         // Check if plant dies from severe weather (underwater plants are immune - storms don't affect them)
         let is_underwater_plant_for_death_check = matches!(plant.plant_type, PlantType::SeaweedBed);
-        if !is_underwater_plant_for_death_check && check_plant_death_from_weather(ctx, &chunk_weather.current_weather) {
+        if !is_underwater_plant_for_death_check && !in_greenhouse && check_plant_death_from_weather(ctx, &chunk_weather.current_weather) {
             let plant_id = plant.id;
             let plant_type = plant.seed_type.clone();
             let plant_pos_x = plant.pos_x;
@@ -1514,6 +1518,9 @@ pub fn check_plant_growth(ctx: &ReducerContext, _args: PlantedSeedGrowthSchedule
         } else {
             // Normal surface plants - apply all environmental modifiers
             weather_multiplier = get_weather_growth_multiplier(&chunk_weather.current_weather, chunk_weather.rain_intensity);
+            if in_greenhouse {
+                weather_multiplier = weather_multiplier.max(1.0); // Storms can't slow greenhouse crops
+            }
             
             // Calculate base growth multiplier (time * weather)
             let base_growth_multiplier = base_time_multiplier * weather_multiplier;
@@ -1734,6 +1741,7 @@ fn grow_tree_sapling_to_tree(ctx: &ReducerContext, plant: &PlantedSeed) -> Resul
         last_hit_time: None,
         respawn_at: spacetimedb::Timestamp::UNIX_EPOCH, // Not respawning (active tree)
         is_player_planted: true, // Mark as player-planted for reduced yield and no respawn
+        is_burnt: false,
     };
     
     match ctx.db.tree().try_insert(new_tree) {
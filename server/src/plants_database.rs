@@ -81,6 +81,13 @@ pub enum PlantType {
     
     // === UNDERWATER PLANTS (Require snorkeling to harvest) ===
     SeaweedBed, // Underwater seaweed - requires diving to harvest
+    KelpForest, // Tall underwater kelp stands - fiber and seaweed for divers
+    PearlOyster, // Oyster bed on the sea floor - shells with a chance of pearls
+    SunkenCrate, // Waterlogged cargo crate - shipwreck monument only, requires diving
+    
+    // === TIDAL FLATS (Exposed only at low tide, see world_state.rs) ===
+    TidePoolCrab,  // Crab stranded on the flats by the ebbing tide - crab meat
+    TidePoolClams, // Clams dug from the exposed flats - shells with a rare pearl
     
     // === RESOURCE PILES (Small bonus resources scattered in world) ===
     WoodPile,       // Small pile of wood - general terrain
@@ -91,6 +98,7 @@ pub enum PlantType {
     SulfurPile,     // Pile of sulfur deposits - alpine/volcanic areas only, rare
     CharcoalPile,   // Pile of charcoal - forest areas (old burn sites), rare
     SoggyPlantFiberPile, // Storm debris - spawns when plants are destroyed by heavy storms
+    Fulgurite,           // Lightning-fused glass - spawns only where lightning strikes (one-time)
     BonePile,           // Pile of bone fragments - whale bone graveyard monument only
     
     // === TREE SAPLINGS (Planted trees that grow into actual Tree entities) ===
@@ -1072,6 +1080,57 @@ lazy_static! {
             growing_seasons: vec![Season::Spring, Season::Summer, Season::Autumn, Season::Winter], // Year-round underwater plant
         });
         
+        configs.insert(PlantType::KelpForest, PlantConfig {
+            entity_name: "Kelp Forest".to_string(),
+            density_percent: 0.0008, // Slightly rarer than seaweed beds (~200 across the sea)
+            min_distance_sq: 70.0 * 70.0,
+            min_tree_distance_sq: 0.0 * 0.0, // No tree distance check underwater
+            min_stone_distance_sq: 30.0 * 30.0,
+            noise_threshold: 0.60,
+            primary_yield: ("Plant Fiber".to_string(), 8, 14), // Kelp stalks are tough and fibrous
+            secondary_yield: Some(("Seaweed".to_string(), 2, 4, 0.50)), // 50% chance for 2-4 seaweed
+            seed_type: "".to_string(), // Grows back from holdfasts - not plantable
+            seed_drop_chance: 0.0,
+            min_respawn_time_secs: 600, // 10 minutes
+            max_respawn_time_secs: 900, // 15 minutes
+            spawn_condition: SpawnCondition::Underwater, // MUST be underwater, requires snorkeling
+            growing_seasons: vec![Season::Spring, Season::Summer, Season::Autumn, Season::Winter], // Year-round underwater plant
+        });
+        
+        configs.insert(PlantType::PearlOyster, PlantConfig {
+            entity_name: "Pearl Oyster".to_string(),
+            density_percent: 0.0004, // Uncommon (~100 across the sea)
+            min_distance_sq: 90.0 * 90.0,
+            min_tree_distance_sq: 0.0 * 0.0, // No tree distance check underwater
+            min_stone_distance_sq: 30.0 * 30.0,
+            noise_threshold: 0.70,
+            primary_yield: ("Shell".to_string(), 1, 2), // Every oyster gives shells
+            secondary_yield: Some(("Pearl".to_string(), 1, 1, 0.15)), // 15% pearl - the main reason to dive for oysters
+            seed_type: "".to_string(), // Not plantable
+            seed_drop_chance: 0.0,
+            min_respawn_time_secs: 1200, // 20 minutes
+            max_respawn_time_secs: 1800, // 30 minutes
+            spawn_condition: SpawnCondition::Underwater, // MUST be underwater, requires snorkeling
+            growing_seasons: vec![Season::Spring, Season::Summer, Season::Autumn, Season::Winter], // Always available
+        });
+        
+        configs.insert(PlantType::SunkenCrate, PlantConfig {
+            entity_name: "Sunken Crate".to_string(),
+            density_percent: 0.0, // DISABLED - Only spawns around shipwreck monuments
+            min_distance_sq: 80.0 * 80.0,
+            min_tree_distance_sq: 0.0 * 0.0,
+            min_stone_distance_sq: 0.0 * 0.0,
+            noise_threshold: 0.0,
+            primary_yield: ("Metal Fragments".to_string(), 15, 30), // Rusted fittings from the wreck's cargo
+            secondary_yield: Some(("Rope".to_string(), 1, 2, 0.30)), // 30% chance of salvageable rope
+            seed_type: "".to_string(), // No seeds - salvage
+            seed_drop_chance: 0.0,
+            min_respawn_time_secs: 1800, // 30 minutes - more cargo drifts out of the hull
+            max_respawn_time_secs: 2700, // 45 minutes
+            spawn_condition: SpawnCondition::Underwater, // MUST be underwater, requires snorkeling
+            growing_seasons: vec![Season::Spring, Season::Summer, Season::Autumn, Season::Winter], // Always available - not biological
+        });
+        
         // === RESOURCE PILES (Small bonus resources) ===
         
         configs.insert(PlantType::WoodPile, PlantConfig {
@@ -1213,6 +1272,63 @@ lazy_static! {
             growing_seasons: vec![], // Never grows naturally
         });
         
+        // === TIDAL FLATS ===
+        // Spawned on the exposed flats at low tide and washed away when the tide floods in
+        // One-time - removed once harvested
+        configs.insert(PlantType::TidePoolCrab, PlantConfig {
+            entity_name: "Tide Pool Crab".to_string(),
+            density_percent: 0.0, // Never spawns naturally - only at low tide
+            min_distance_sq: 0.0,
+            min_tree_distance_sq: 0.0,
+            min_stone_distance_sq: 0.0,
+            noise_threshold: 1.0, // Never spawns naturally
+            primary_yield: ("Raw Crab Meat".to_string(), 1, 2),
+            secondary_yield: Some(("Shell Fragment".to_string(), 1, 2, 0.40)),
+            seed_type: "".to_string(), // No seeds
+            seed_drop_chance: 0.0,
+            min_respawn_time_secs: 0, // No respawn - deleted on harvest or high tide
+            max_respawn_time_secs: 0,
+            spawn_condition: SpawnCondition::Coastal,
+            growing_seasons: vec![], // Never grows naturally
+        });
+        
+        configs.insert(PlantType::TidePoolClams, PlantConfig {
+            entity_name: "Tide Pool Clams".to_string(),
+            density_percent: 0.0, // Never spawns naturally - only at low tide
+            min_distance_sq: 0.0,
+            min_tree_distance_sq: 0.0,
+            min_stone_distance_sq: 0.0,
+            noise_threshold: 1.0, // Never spawns naturally
+            primary_yield: ("Shell".to_string(), 1, 3),
+            secondary_yield: Some(("Pearl".to_string(), 1, 1, 0.05)), // Much rarer than diving for oysters
+            seed_type: "".to_string(), // No seeds
+            seed_drop_chance: 0.0,
+            min_respawn_time_secs: 0, // No respawn - deleted on harvest or high tide
+            max_respawn_time_secs: 0,
+            spawn_condition: SpawnCondition::Coastal,
+            growing_seasons: vec![], // Never grows naturally
+        });
+        
+        // === LIGHTNING DEPOSITS ===
+        // Rarely left behind by lightning strikes during thunderstorms (see lightning.rs)
+        // One-time deposit - removed once harvested
+        configs.insert(PlantType::Fulgurite, PlantConfig {
+            entity_name: "Fulgurite".to_string(),
+            density_percent: 0.0, // Never spawns naturally - only from lightning strikes
+            min_distance_sq: 0.0,
+            min_tree_distance_sq: 0.0,
+            min_stone_distance_sq: 0.0,
+            noise_threshold: 1.0, // Never spawns naturally
+            primary_yield: ("Fulgurite".to_string(), 1, 2),
+            secondary_yield: Some(("Memory Shard".to_string(), 3, 6, 0.25)), // 25% chance the strike fused buried shards
+            seed_type: "".to_string(), // No seeds
+            seed_drop_chance: 0.0,
+            min_respawn_time_secs: 0, // No respawn - deleted on harvest
+            max_respawn_time_secs: 0,
+            spawn_condition: SpawnCondition::Plains, // Doesn't matter - spawns at the strike point
+            growing_seasons: vec![], // Never grows naturally
+        });
+        
         // === MONUMENT-SPECIFIC PILES ===
         
         configs.insert(PlantType::BonePile, PlantConfig {
@@ -1460,10 +1576,13 @@ fn get_plant_category(plant_type: &PlantType) -> PlantCategory {
         // Resource piles
         PlantType::WoodPile | PlantType::BeachWoodPile | PlantType::StonePile |
         PlantType::LeavesPile | PlantType::MetalOrePile | PlantType::SulfurPile |
-        PlantType::CharcoalPile | PlantType::SoggyPlantFiberPile | PlantType::BonePile => PlantCategory::ResourcePile,
+        PlantType::CharcoalPile | PlantType::SoggyPlantFiberPile | PlantType::BonePile |
+        PlantType::Fulgurite => PlantCategory::ResourcePile,
         
         // Special (includes tree saplings which become Tree entities when mature)
         PlantType::MemoryShard | PlantType::SeaweedBed |
+        PlantType::KelpForest | PlantType::PearlOyster | PlantType::SunkenCrate |
+        PlantType::TidePoolCrab | PlantType::TidePoolClams |
         PlantType::ConiferSapling | PlantType::DeciduousSapling |
         PlantType::CrabAppleSapling | PlantType::HazelnutSapling |
         PlantType::RowanberrySapling | PlantType::OliveSapling => PlantCategory::Special,
@@ -1627,7 +1746,7 @@ pub fn get_seasonal_plants(season: &Season) -> Vec<(PlantType, &PlantConfig)> {
 /// - Bits 33-40: Vegetables (8 types)
 /// - Bits 41-48: Fiber (8 types)
 /// 
-/// Not tracked: Resource piles (WoodPile, etc.), MemoryShard, SeaweedBed
+/// Not tracked: Resource piles (WoodPile, etc.), MemoryShard, underwater resources
 pub fn get_plant_bit_index(plant_type: &PlantType) -> Option<u32> {
     match plant_type {
         // ===== BERRIES (Bits 0-5) - RowanBerries removed (now from tree) =====
@@ -1700,7 +1819,9 @@ pub fn get_plant_bit_index(plant_type: &PlantType) -> Option<u32> {
         PlantType::WoodPile | PlantType::BeachWoodPile | PlantType::StonePile |
         PlantType::LeavesPile | PlantType::MetalOrePile | PlantType::SulfurPile |
         PlantType::CharcoalPile | PlantType::SoggyPlantFiberPile | PlantType::BonePile |
-        PlantType::MemoryShard | PlantType::SeaweedBed | 
+        PlantType::Fulgurite | PlantType::MemoryShard | PlantType::SeaweedBed | 
+        PlantType::KelpForest | PlantType::PearlOyster | PlantType::SunkenCrate |
+        PlantType::TidePoolCrab | PlantType::TidePoolClams |
         PlantType::ConiferSapling | PlantType::DeciduousSapling |
         PlantType::CrabAppleSapling | PlantType::HazelnutSapling |
         PlantType::RowanberrySapling | PlantType::OliveSapling |
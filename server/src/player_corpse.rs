@@ -41,6 +41,7 @@ use crate::player;
 use crate::player_inventory::{move_item_to_inventory, move_item_to_hotbar, NUM_PLAYER_INVENTORY_SLOTS, NUM_PLAYER_HOTBAR_SLOTS};
 use crate::items::add_item_to_player_inventory;
 use crate::death_marker::death_marker as DeathMarkerTableTrait; // For ctx.db.death_marker()
+use crate::corpse_decay::CorpseDecayStage;
 
 /// --- Player Corpse Data Structure ---
 /// Represents a lootable backpack dropped when a player dies.
@@ -74,6 +75,8 @@ pub struct PlayerCorpse {
     /// Only the player_identity can loot this corpse until this time passes.
     pub locked_until: Option<Timestamp>,
 
+    pub decay_stage: CorpseDecayStage, // Fresh -> Decaying -> Bones (see corpse_decay.rs)
+
     // --- Inventory Slots (0-NUM_CORPSE_SLOTS-1) ---
     // Conceptually: Player inv (0-23), hotbar (24-29), equipment (30-34)
     pub slot_instance_id_0: Option<u64>, pub slot_def_id_0: Option<u64>,
@@ -445,189 +448,29 @@ fn validate_corpse_interaction(
     Ok((player, corpse))
 }
 
-/// --- Move Item FROM Corpse --- 
-/// Moves an item FROM a corpse slot INTO the player's inventory/hotbar.
-#[spacetimedb::reducer]
-pub fn move_item_from_corpse(
-    ctx: &ReducerContext, 
-    corpse_id: u32, 
-    source_slot_index: u8,
-    target_slot_type: String, // "inventory" or "hotbar"
-    target_slot_index: u32
-) -> Result<(), String> {
-    let (_, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-    inventory_management::handle_move_from_container_slot(ctx, &mut corpse, source_slot_index, target_slot_type, target_slot_index)?;
-    ctx.db.player_corpse().id().update(corpse);
-    Ok(())
-}
-
-/// --- Split Stack From Corpse ---
-/// Splits a stack from a corpse slot into the player's inventory/hotbar.
-#[spacetimedb::reducer]
-pub fn split_stack_from_corpse(
-    ctx: &ReducerContext,
-    corpse_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_type: String, 
-    target_slot_index: u32,   
-) -> Result<(), String> {
-    let (_, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-    inventory_management::handle_split_from_container(ctx, &mut corpse, source_slot_index, quantity_to_split, target_slot_type, target_slot_index)?;
-    ctx.db.player_corpse().id().update(corpse);
-    Ok(())
-}
-
-/// --- Quick Move From Corpse ---
-/// Quickly moves an item FROM a corpse slot TO the player inventory.
-#[spacetimedb::reducer]
-pub fn quick_move_from_corpse(
-    ctx: &ReducerContext, 
-    corpse_id: u32, 
-    source_slot_index: u8
-) -> Result<(), String> {
-    let (_, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-    inventory_management::handle_quick_move_from_container(ctx, &mut corpse, source_slot_index)?;
-    ctx.db.player_corpse().id().update(corpse);
-    Ok(())
-}
-
-/// --- Move Item Within Corpse --- 
-/// Moves an item BETWEEN two slots within the same corpse.
-#[spacetimedb::reducer]
-pub fn move_item_within_corpse(
-    ctx: &ReducerContext,
-    corpse_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-    inventory_management::handle_move_within_container(ctx, &mut corpse, source_slot_index, target_slot_index)?;
-    ctx.db.player_corpse().id().update(corpse);
-    Ok(())
-}
-
-/// --- Split Stack Within Corpse ---
-/// Splits a stack FROM one corpse slot TO another within the same corpse.
-#[spacetimedb::reducer]
-pub fn split_stack_within_corpse(
-    ctx: &ReducerContext,
-    corpse_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (_, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-    inventory_management::handle_split_within_container(ctx, &mut corpse, source_slot_index, target_slot_index, quantity_to_split)?;
-    ctx.db.player_corpse().id().update(corpse);
-    Ok(())
-}
-
-// --- Move Item TO Corpse ---
-/// Moves an item from the player's inventory/hotbar INTO a specified slot in the corpse.
-#[spacetimedb::reducer]
-pub fn move_item_to_corpse(
-    ctx: &ReducerContext,
-    corpse_id: u32,
-    target_slot_index: u8,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-    inventory_management::handle_move_to_container_slot(ctx, &mut corpse, target_slot_index, item_instance_id)?;
-    ctx.db.player_corpse().id().update(corpse);
-    Ok(())
-}
-
-// --- Split Stack INTO Corpse ---
-/// Splits a stack from player inventory/hotbar into a specific corpse slot.
-#[spacetimedb::reducer]
-pub fn split_stack_into_corpse(
-    ctx: &ReducerContext,
-    corpse_id: u32,
-    target_slot_index: u8,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (_, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-    inventory_management::handle_split_into_container(ctx, &mut corpse, target_slot_index, source_item_instance_id, quantity_to_split)?;
-    ctx.db.player_corpse().id().update(corpse);
-    Ok(())
-}
-
-// --- Quick Move TO Corpse ---
-/// Quickly moves an item from player inventory/hotbar TO the first available/mergeable slot in the corpse.
-#[spacetimedb::reducer]
-pub fn quick_move_to_corpse(
-    ctx: &ReducerContext,
-    corpse_id: u32,
-    item_instance_id: u64,
-) -> Result<(), String> {
-    let (_, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-    inventory_management::handle_quick_move_to_container(ctx, &mut corpse, item_instance_id)?;
-    ctx.db.player_corpse().id().update(corpse);
-    Ok(())
-}
-
-// --- NEW: Drop Item from Corpse Slot to World ---
-#[spacetimedb::reducer]
-pub fn drop_item_from_corpse_slot_to_world(
-    ctx: &ReducerContext,
-    corpse_id: u32,
-    slot_index: u8,
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player(); // For fetching the player for drop location
-    let mut corpse_table = ctx.db.player_corpse();
-
-    log::info!("[DropFromCorpseToWorld] Player {} attempting to drop item from corpse ID {}, slot index {}.", sender_id, corpse_id, slot_index);
-
-    // 1. Validate interaction and get corpse (also gets a player instance for validation)
-    let (_player_for_validation, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-    
-    // 2. Get Player again for drop location calculation (ensure it's the sender)
-    let player_for_drop_location = player_table.identity().find(sender_id)
-        .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
-
-    // 3. Call the generic handler from inventory_management
-    crate::inventory_management::handle_drop_from_container_slot(ctx, &mut corpse, slot_index, &player_for_drop_location)?;
-
-    // 4. Persist changes to the PlayerCorpse
-    corpse_table.id().update(corpse);
-    log::info!("[DropFromCorpseToWorld] Successfully dropped item from corpse {}, slot {}. Corpse updated.", corpse_id, slot_index);
+impl inventory_management::ContainerHandle for PlayerCorpse {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_corpse_interaction(ctx, container_id)
+    }
 
-    Ok(())
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.player_corpse().id().update(self.clone());
+    }
 }
 
-// --- NEW: Split and Drop Item from Corpse Slot to World ---
-#[spacetimedb::reducer]
-pub fn split_and_drop_item_from_corpse_slot_to_world(
-    ctx: &ReducerContext,
-    corpse_id: u32,
-    slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player(); // For fetching the player for drop location
-    let mut corpse_table = ctx.db.player_corpse();
-
-    log::info!("[SplitDropFromCorpseToWorld] Player {} attempting to split {} from corpse ID {}, slot {}.", 
-             sender_id, quantity_to_split, corpse_id, slot_index);
-
-    // 1. Validate interaction and get corpse
-    let (_player_for_validation, mut corpse) = validate_corpse_interaction(ctx, corpse_id)?;
-
-    // 2. Get Player again for drop location
-    let player_for_drop_location = player_table.identity().find(sender_id)
-        .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
-
-    // 3. Call the generic handler from inventory_management
-    crate::inventory_management::handle_split_and_drop_from_container_slot(ctx, &mut corpse, slot_index, quantity_to_split, &player_for_drop_location)?;
-
-    // 4. Persist changes to the PlayerCorpse
-    corpse_table.id().update(corpse);
-    log::info!("[SplitDropFromCorpseToWorld] Successfully split and dropped from corpse {}, slot {}. Corpse updated.", corpse_id, slot_index);
-    
-    Ok(())
+crate::container_reducers! {
+    PlayerCorpse;
+    move_from: move_item_from_corpse(corpse_id),
+    split_from: split_stack_from_corpse(corpse_id),
+    quick_move_from: quick_move_from_corpse(corpse_id),
+    move_within: move_item_within_corpse(corpse_id),
+    split_within: split_stack_within_corpse(corpse_id),
+    move_to: move_item_to_corpse(corpse_id),
+    split_into: split_stack_into_corpse(corpse_id),
+    quick_move_to: quick_move_to_corpse(corpse_id),
+    drop: drop_item_from_corpse_slot_to_world(corpse_id),
+    split_and_drop: split_and_drop_item_from_corpse_slot_to_world(corpse_id),
+    take_all: loot_all_from_corpse(corpse_id),
 }
 
 /// Creates a PlayerCorpse entity, transfers items from the dead player's inventory,
@@ -682,6 +525,7 @@ fn transfer_inventory_to_corpse(ctx: &ReducerContext, dead_player: &Player) -> R
         max_health: PLAYER_CORPSE_INITIAL_HEALTH, // Initialize max_health
         last_hit_time: None, // Initialize last_hit_time
         locked_until: Some(ctx.timestamp + Duration::from_secs(CORPSE_PROTECTION_SECONDS)), // Protected for 10 minutes
+        decay_stage: CorpseDecayStage::Fresh,
         slot_instance_id_0: None, slot_def_id_0: None,
         slot_instance_id_1: None, slot_def_id_1: None,
         slot_instance_id_2: None, slot_def_id_2: None,
@@ -777,7 +621,7 @@ fn transfer_inventory_to_corpse(ctx: &ReducerContext, dead_player: &Player) -> R
 
 /// --- CENTRALIZED DEATH HANDLER ---
 /// Call this from ANY code that kills a player. It handles all death logic:
-/// 1. Sets player state (is_dead, death_timestamp)
+/// 1. Sets player state (is_dead, death_timestamp) and reports to the kill feed
 /// 2. Drops active weapon
 /// 3. Clears active equipment reference
 /// 4. Clears all active effects (bleed, venom, burns, etc.)
@@ -806,6 +650,7 @@ pub fn handle_player_death(
     // 1. Set player death state
     player.is_dead = true;
     player.death_timestamp = Some(current_time);
+    crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::Player(player.identity));
     player.health = 0.0;
     
     let death_x = player.position_x;
@@ -816,7 +661,20 @@ pub fn handle_player_death(
               username, player_id, death_cause, death_x, death_y);
     
     // Update player state first
-    ctx.db.player().identity().update(player);
+    ctx.db.player().identity().update(player.clone());
+    
+    // 1b. Report to the kill feed and snapshot the death recap
+    let killer = killer_id.and_then(|id| ctx.db.player().identity().find(&id));
+    let killer_name = killer.as_ref().map_or(death_cause.to_string(), |k| k.username.clone());
+    crate::kill_feed::record_player_death(
+        ctx,
+        &player,
+        killer_id,
+        &killer_name,
+        death_cause,
+        None,
+        killer.as_ref().map(|k| (k.position_x, k.position_y)),
+    );
     
     // 2. Drop active weapon on death
     match crate::dropped_item::drop_active_weapon_on_death(ctx, player_id, death_x, death_y) {
@@ -970,6 +828,9 @@ pub fn create_player_corpse(ctx: &ReducerContext, dead_player_id: Identity, deat
         "Player corpse despawn"
     );
 
+    // The dead player spectates their corpse until they respawn
+    crate::ghost::enter_ghost_mode(ctx, dead_player_id, new_corpse_id, death_x, death_y);
+
     Ok(())
 }
 
@@ -1081,6 +942,7 @@ pub fn create_offline_corpse(ctx: &ReducerContext, player: &Player) -> Result<u3
         max_health: PLAYER_CORPSE_INITIAL_HEALTH,
         last_hit_time: None,
         locked_until: Some(ctx.timestamp + Duration::from_secs(CORPSE_PROTECTION_SECONDS)), // Protected for 10 minutes
+        decay_stage: CorpseDecayStage::Fresh,
         slot_instance_id_0: None, slot_def_id_0: None,
         slot_instance_id_1: None, slot_def_id_1: None,
         slot_instance_id_2: None, slot_def_id_2: None,
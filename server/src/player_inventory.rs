@@ -70,16 +70,21 @@ pub(crate) fn find_first_empty_inventory_slot(ctx: &ReducerContext, player_id: I
 
 // Function to find the first available player slot (hotbar preferred)
 pub(crate) fn find_first_empty_player_slot(ctx: &ReducerContext, player_id: Identity) -> Option<ItemLocation> {
+    find_empty_player_slot_for_item(ctx, player_id, None)
+}
+
+/// Like find_first_empty_player_slot, but returns the hotbar slot bound to the item type if it is free
+pub(crate) fn find_empty_player_slot_for_item(ctx: &ReducerContext, player_id: Identity, item_def_id: Option<u64>) -> Option<ItemLocation> {
     let inventory_table = ctx.db.inventory_item();
     
-    // Check Hotbar
+    // Check Hotbar (respecting slot bindings, see hotbar_binding)
     let occupied_hotbar_slots: HashSet<u8> = inventory_table.iter()
         .filter_map(|item| match &item.location {
             ItemLocation::Hotbar(data) if data.owner_id == player_id => Some(data.slot_index),
             _ => None,
         })
         .collect();
-    if let Some(empty_slot) = (0..NUM_PLAYER_HOTBAR_SLOTS).find(|slot| !occupied_hotbar_slots.contains(slot)) {
+    if let Some(empty_slot) = crate::hotbar_binding::choose_empty_hotbar_slot(ctx, player_id, item_def_id, &occupied_hotbar_slots) {
         return Some(ItemLocation::Hotbar(crate::models::HotbarLocationData { owner_id: player_id, slot_index: empty_slot }));
     }
 
@@ -158,6 +163,16 @@ pub fn move_item_to_inventory(ctx: &ReducerContext, item_instance_id: u64, targe
     let target_item_opt = find_item_in_inventory_slot(ctx, target_inventory_slot);
     let new_item_location = ItemLocation::Inventory(crate::models::InventoryLocationData { owner_id: sender_id, slot_index: target_inventory_slot });
 
+    // Moving an item off the hotbar by hand releases its slot, unless an inventory item is swapped into it
+    if let ItemLocation::Hotbar(source_data) = &original_location {
+        match &target_item_opt {
+            Some(target_item) if target_item.item_def_id != item_to_move.item_def_id => {
+                crate::hotbar_binding::bind_hotbar_slot(ctx, sender_id, source_data.slot_index, target_item.item_def_id);
+            }
+            _ => crate::hotbar_binding::unbind_hotbar_slot(ctx, sender_id, source_data.slot_index),
+        }
+    }
+
     if let Some(mut target_item) = target_item_opt {
         // --- 4a. Target Slot Occupied: Merge or Swap --- 
         if target_item.instance_id == item_instance_id { 
@@ -306,6 +321,14 @@ pub fn move_item_to_hotbar(ctx: &ReducerContext, item_instance_id: u64, target_h
     let target_item_opt = find_item_in_hotbar_slot(ctx, target_hotbar_slot);
     let new_item_location = ItemLocation::Hotbar(crate::models::HotbarLocationData { owner_id: sender_id, slot_index: target_hotbar_slot });
 
+    // The target slot is now kept for this item type; a hotbar item swapped out takes over the source slot
+    crate::hotbar_binding::bind_hotbar_slot(ctx, sender_id, target_hotbar_slot, item_to_move.item_def_id);
+    if let (ItemLocation::Hotbar(source_data), Some(target_item)) = (&original_location, &target_item_opt) {
+        if target_item.item_def_id != item_to_move.item_def_id {
+            crate::hotbar_binding::bind_hotbar_slot(ctx, sender_id, source_data.slot_index, target_item.item_def_id);
+        }
+    }
+
     if let Some(mut target_item) = target_item_opt {
         // --- 4a. Target Slot Occupied: Merge or Swap --- 
         if target_item.instance_id == item_instance_id {
@@ -448,9 +471,9 @@ pub fn split_stack(
     let original_location_of_source_stack = source_item_being_split.location.clone();
 
     // Validate split quantity
-    if quantity_to_split == 0 || quantity_to_split >= source_item_being_split.quantity {
-        return Err("Invalid split quantity".to_string());
-    }
+    let source_def = ctx.db.item_definition().id().find(source_item_being_split.item_def_id)
+        .ok_or_else(|| format!("Definition not found for item ID {}", source_item_being_split.item_def_id))?;
+    crate::items::validate_split_quantity(&source_def, &source_item_being_split, quantity_to_split)?;
 
     // --- 2. Determine Target Location for the New Split Stack ---
     let player_target_location_for_new_item = match target_slot_type.as_str() {
@@ -12,7 +12,7 @@ use crate::grass::grass as GrassTableTrait;
 use crate::player_stats::stat_thresholds_config as StatThresholdsConfigTableTrait;
 
 // Import constants from lib.rs
-use crate::{PLAYER_RADIUS, PLAYER_SPEED, WORLD_WIDTH_PX, WORLD_HEIGHT_PX, WATER_SPEED_PENALTY, is_player_on_water, is_player_jumping, get_effective_player_radius};
+use crate::{Player, PLAYER_RADIUS, PLAYER_SPEED, WORLD_WIDTH_PX, WORLD_HEIGHT_PX, WATER_SPEED_PENALTY, is_player_on_water, is_player_jumping, get_effective_player_radius};
 
 // Import constants from player_stats module
 use crate::player_stats::{SPRINT_SPEED_MULTIPLIER, JUMP_COOLDOWN_MS};
@@ -116,6 +116,15 @@ pub fn set_sprinting(ctx: &ReducerContext, sprinting: bool) -> Result<(), String
         if player.is_knocked_out {
             return Err("Cannot sprint while knocked out.".to_string());
         }
+        if player.is_sleeping {
+            return Err("Cannot sprint while asleep.".to_string());
+        }
+        if sprinting && crate::knocked_out::is_carrying(ctx, sender_id) {
+            return Err("Cannot sprint while carrying someone.".to_string());
+        }
+        if sprinting && crate::active_effects::player_has_fractured_effect(ctx, sender_id) {
+            return Err("Cannot sprint with a fractured limb.".to_string());
+        }
 
         // Players can sprint while crouching (sprinting speed applies even when crouched)
         // Players can sprint in water (with speed penalty applied during movement calculation)
@@ -151,6 +160,9 @@ pub fn toggle_crouch(ctx: &ReducerContext) -> Result<(), String> {
         if player.is_knocked_out {
             return Err("Cannot crouch while knocked out.".to_string());
         }
+        if player.is_sleeping {
+            return Err("Cannot crouch while asleep.".to_string());
+        }
 
         // Don't allow any crouching action when on water
         if is_player_on_water(ctx, player.position_x, player.position_y) {
@@ -196,6 +208,11 @@ pub fn jump(ctx: &ReducerContext) -> Result<(), String> {
            return Err("Cannot jump while knocked out.".to_string());
        }
 
+       // Don't allow jumping while asleep
+       if player.is_sleeping {
+           return Err("Cannot jump while asleep.".to_string());
+       }
+
        // Don't allow jumping while crouching
        if player.is_crouching {
            return Err("Cannot jump while crouching.".to_string());
@@ -239,7 +256,7 @@ pub fn dodge_roll(ctx: &ReducerContext, move_x: f32, move_y: f32) -> Result<(),
     let players = ctx.db.player();
     let dodge_roll_states = ctx.db.player_dodge_roll_state();
 
-    let current_player = players.identity()
+    let mut current_player = players.identity()
         .find(&sender_id)
         .ok_or_else(|| "Player not found".to_string())?;
 
@@ -253,6 +270,26 @@ pub fn dodge_roll(ctx: &ReducerContext, move_x: f32, move_y: f32) -> Result<(),
         return Err("Cannot dodge roll while knocked out.".to_string());
     }
 
+    // Don't allow dodge rolling while asleep
+    if current_player.is_sleeping {
+        return Err("Cannot dodge roll while asleep.".to_string());
+    }
+
+    // Don't allow dodge rolling while looking through a spyglass
+    if crate::spyglass::is_scoping(ctx, sender_id) {
+        return Err("Cannot dodge roll while looking through a scope.".to_string());
+    }
+
+    // Don't allow dodge rolling while carrying someone
+    if crate::knocked_out::is_carrying(ctx, sender_id) {
+        return Err("Cannot dodge roll while carrying someone.".to_string());
+    }
+
+    // Don't allow dodge rolling with a fractured limb
+    if crate::active_effects::player_has_fractured_effect(ctx, sender_id) {
+        return Err("Cannot dodge roll with a fractured limb.".to_string());
+    }
+
     // Don't allow dodge rolling while crouching
     if current_player.is_crouching {
         return Err("Cannot dodge roll while crouching.".to_string());
@@ -307,6 +344,16 @@ pub fn dodge_roll(ctx: &ReducerContext, move_x: f32, move_y: f32) -> Result<(),
     let clamped_target_x = target_x.max(effective_radius).min(WORLD_WIDTH_PX - effective_radius);
     let clamped_target_y = target_y.max(effective_radius).min(WORLD_HEIGHT_PX - effective_radius);
 
+    // Rolling into a cliff stops the roll at its edge, at full roll speed
+    let (clamped_target_x, clamped_target_y) = match find_cliff_stop_point(ctx, current_player.position_x, current_player.position_y, clamped_target_x, clamped_target_y) {
+        Some(stop_point) => {
+            apply_impact(ctx, &mut current_player, DODGE_ROLL_SPEED);
+            players.identity().update(current_player.clone());
+            stop_point
+        }
+        None => (clamped_target_x, clamped_target_y),
+    };
+
     // Determine direction string for 8-directional support
     let direction_string = if dodge_dx == 0.0 && dodge_dy < 0.0 {
         "up".to_string()
@@ -360,6 +407,61 @@ pub fn dodge_roll(ctx: &ReducerContext, move_x: f32, move_y: f32) -> Result<(),
     Ok(())
 }
 
+// === IMPACT DAMAGE ===
+// Being stopped hard by a cliff - rolling into one, or being thrown against one by a heavy
+// hitter's knockback - hurts and can fracture a limb. Walking into a cliff is always harmless.
+
+/// Impacts slower than this do nothing
+pub const IMPACT_MIN_SPEED_PX_PER_SEC: f32 = 400.0;
+/// Impact speed at which damage and fracture chance peak
+const IMPACT_MAX_SPEED_PX_PER_SEC: f32 = 1000.0;
+const IMPACT_MIN_DAMAGE: f32 = 5.0;
+const IMPACT_MAX_DAMAGE: f32 = 25.0;
+const IMPACT_MIN_FRACTURE_CHANCE: f32 = 0.15;
+const IMPACT_MAX_FRACTURE_CHANCE: f32 = 0.6;
+/// Spacing of the cliff checks along a traced path
+const IMPACT_PATH_STEP_PX: f32 = 16.0;
+
+/// Traces a straight path for cliff tiles. Returns the last point before the first cliff,
+/// or None if the whole path is clear.
+pub fn find_cliff_stop_point(ctx: &ReducerContext, from_x: f32, from_y: f32, to_x: f32, to_y: f32) -> Option<(f32, f32)> {
+    let (dx, dy) = (to_x - from_x, to_y - from_y);
+    let length = (dx * dx + dy * dy).sqrt();
+    let steps = (length / IMPACT_PATH_STEP_PX).ceil() as u32;
+    let mut last_free = (from_x, from_y);
+    for step in 1..=steps {
+        let t = step as f32 / steps as f32;
+        let (x, y) = (from_x + dx * t, from_y + dy * t);
+        if crate::is_position_on_cliff(ctx, x, y) {
+            return Some(last_free);
+        }
+        last_free = (x, y);
+    }
+    None
+}
+
+/// Applies impact damage and a chance of a fractured limb for a player stopped at `impact_speed`.
+/// Impacts never kill; health is left at 1 at worst. The caller saves the player.
+pub fn apply_impact(ctx: &ReducerContext, player: &mut Player, impact_speed: f32) {
+    if impact_speed < IMPACT_MIN_SPEED_PX_PER_SEC || player.is_dead || player.is_knocked_out || player.health <= 0.0 {
+        return;
+    }
+    let severity = ((impact_speed - IMPACT_MIN_SPEED_PX_PER_SEC) / (IMPACT_MAX_SPEED_PX_PER_SEC - IMPACT_MIN_SPEED_PX_PER_SEC)).clamp(0.0, 1.0);
+    let damage = IMPACT_MIN_DAMAGE + (IMPACT_MAX_DAMAGE - IMPACT_MIN_DAMAGE) * severity;
+    player.health = (player.health - damage).max(1.0_f32.min(player.health));
+    player.last_hit_time = Some(ctx.timestamp);
+
+    let fracture_chance = IMPACT_MIN_FRACTURE_CHANCE + (IMPACT_MAX_FRACTURE_CHANCE - IMPACT_MIN_FRACTURE_CHANCE) * severity;
+    let fractured = ctx.rng().gen::<f32>() < fracture_chance;
+    if fractured {
+        if let Err(e) = crate::active_effects::apply_fractured_effect(ctx, player.identity) {
+            log::error!("[Impact] Failed to fracture player {:?}: {}", player.identity, e);
+        }
+    }
+    log::info!("[Impact] Player {:?} hit a cliff at {:.0}px/s: {:.1} damage{}",
+               player.identity, impact_speed, damage, if fractured { ", limb fractured" } else { "" });
+}
+
 // === SIMPLE CLIENT-AUTHORITATIVE MOVEMENT SYSTEM ===
 
 /// Simple movement validation constants
@@ -389,6 +491,11 @@ fn get_max_movement_speed_for_player(ctx: &ReducerContext, player_id: Identity)
     }
     // <<< END BROTH EFFECTS >>>
     
+    // Fractured limb halves speed until splinted
+    if crate::active_effects::player_has_fractured_effect(ctx, player_id) {
+        speed_multiplier *= crate::active_effects::FRACTURE_SPEED_MULTIPLIER;
+    }
+    
     // Armor movement speed modifier (e.g., Babushka's Boots of Speed: 4.0 = 5x land speed)
     let armor_modifier = crate::armor::calculate_movement_speed_modifier(ctx, player_id);
     speed_multiplier *= 1.0 + armor_modifier;
@@ -435,6 +542,12 @@ pub fn update_player_position_simple(
         return Err("Player is dead".to_string());
     }
 
+    // 1b. Sleeping players stay in their bag until they wake up
+    if current_player.is_sleeping {
+        log::trace!("Ignoring position update for sleeping player {:?}", sender_id);
+        return Err("Player is asleep".to_string());
+    }
+
     // 2. Check if player is knocked out - severely restrict movement but allow facing direction updates
     if current_player.is_knocked_out {
         // Allow very limited movement for knocked out players (crawling)
@@ -477,6 +590,51 @@ pub fn update_player_position_simple(
         }
     }
 
+    // 2b-2. Scoping players stand still looking through their spyglass - only allow facing direction updates
+    if crate::spyglass::is_scoping(ctx, sender_id) {
+        let distance_moved = ((new_x - current_player.position_x).powi(2) + 
+                             (new_y - current_player.position_y).powi(2)).sqrt();
+        
+        if distance_moved > 0.5 {
+            current_player.direction = facing_direction.clone();
+            current_player.last_update = ctx.timestamp;
+            players.identity().update(current_player);
+            return Ok(());
+        }
+    }
+
+    // 2c. Carried bodies move with their carrier only. Carriers and players with a fractured limb
+    // are slowed and can't sprint
+    if current_player.is_knocked_out && crate::knocked_out::is_being_carried(ctx, sender_id) {
+        current_player.direction = facing_direction;
+        current_player.last_update = ctx.timestamp;
+        players.identity().update(current_player);
+        return Ok(());
+    }
+    let is_carrying = crate::knocked_out::is_carrying(ctx, sender_id);
+    let is_fractured = crate::active_effects::player_has_fractured_effect(ctx, sender_id);
+    let is_sprinting = is_sprinting && !is_carrying && !is_fractured;
+    let mut speed_penalty = 1.0;
+    if is_carrying {
+        speed_penalty *= crate::knocked_out::CARRY_SPEED_MULTIPLIER;
+    }
+    if is_fractured {
+        speed_penalty *= crate::active_effects::FRACTURE_SPEED_MULTIPLIER;
+    }
+    let (new_x, new_y) = if speed_penalty < 1.0 {
+        let elapsed_secs = (ctx.timestamp.to_micros_since_unix_epoch() - current_player.last_update.to_micros_since_unix_epoch()).max(50_000) as f32 / 1_000_000.0;
+        let max_step = PLAYER_SPEED * speed_penalty * elapsed_secs * 1.5; // 50% lag tolerance
+        let (dx, dy) = (new_x - current_player.position_x, new_y - current_player.position_y);
+        let step = (dx * dx + dy * dy).sqrt();
+        if step > max_step {
+            (current_player.position_x + dx * max_step / step, current_player.position_y + dy * max_step / step)
+        } else {
+            (new_x, new_y)
+        }
+    } else {
+        (new_x, new_y)
+    };
+
     // 2. Check world bounds
     let effective_radius = get_effective_player_radius(current_player.is_crouching);
     if new_x < effective_radius || new_x > WORLD_WIDTH_PX - effective_radius ||
@@ -485,6 +643,17 @@ pub fn update_player_position_simple(
         return Err("Position out of world bounds".to_string());
     }
 
+    // 2b. Cliff tiles block walking - only ramps lead up and down between terrain levels.
+    // Checked only when the player crosses into a new tile to avoid a lookup per update.
+    if crate::world_pos_to_tile_coords(new_x, new_y) != crate::world_pos_to_tile_coords(current_player.position_x, current_player.position_y)
+        && crate::is_position_on_cliff(ctx, new_x, new_y) {
+        // Keep the player at the cliff edge; writing the row makes the client reconcile
+        current_player.direction = facing_direction;
+        current_player.last_update = ctx.timestamp;
+        players.identity().update(current_player);
+        return Ok(());
+    }
+
     // 3. Calculate movement distance for sound detection
     let distance_moved = ((new_x - current_player.position_x).powi(2) + 
                          (new_y - current_player.position_y).powi(2)).sqrt();
@@ -553,6 +722,13 @@ pub fn update_player_position_simple(
     current_player.direction = facing_direction;
     current_player.last_update = ctx.timestamp;
 
+    // Walking off the edge of a second-story floor drops the player to the ground
+    if current_player.elevation != crate::building_floor::ELEVATION_GROUND
+        && !crate::building_floor::can_stand_at_elevation(ctx, new_x, new_y, current_player.elevation) {
+        log::info!("Player {:?} stepped off an upper floor at ({:.1}, {:.1}) - dropping to ground", sender_id, new_x, new_y);
+        current_player.elevation = crate::building_floor::ELEVATION_GROUND;
+    }
+
     // OPTIMIZATION: Batch micro-movements to reduce collision checks during sprinting
     if distance_moved < 3.0 {
         // For small movements, just update without expensive processing to reduce server load
@@ -561,9 +737,13 @@ pub fn update_player_position_simple(
         current_player.position_y = new_y;
         current_player.is_sprinting = is_sprinting;
         // Keep existing water status for micro-movements
+        crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(sender_id), new_x, new_y);
         
         // Update player without expensive processing
         players.identity().update(current_player);
+        if is_carrying {
+            crate::knocked_out::drag_carried_player(ctx, sender_id, new_x, new_y);
+        }
         return Ok(());
     }
 
@@ -595,7 +775,12 @@ pub fn update_player_position_simple(
     let new_tile_y = (final_y / crate::TILE_SIZE_PX as f32).floor() as i32;
     
     let is_on_water = if old_tile_x != new_tile_x || old_tile_y != new_tile_y {
+        // Sprinting onto sea ice may crack it, dropping the player into the water below
+        if is_sprinting {
+            crate::ice::roll_ice_crack(ctx, sender_id, new_tile_x, new_tile_y);
+        }
         // Player moved to a different tile, check water status
+        crate::tracks::leave_player_track(ctx, sender_id, current_player.position_x, current_player.position_y, final_x, final_y);
         is_player_on_water(ctx, final_x, final_y)
     } else {
         // Player is still on the same tile, keep current water status
@@ -604,6 +789,11 @@ pub fn update_player_position_simple(
     
     let is_jumping = is_player_jumping(current_player.jump_start_time_ms, now_ms);
 
+    // Walking across a tripwire alarm sets it off (jumping clears the wire)
+    if !is_jumping {
+        crate::alarm_trap::check_alarm_traps(ctx, sender_id, current_player.position_x, current_player.position_y, final_x, final_y);
+    }
+
     // --- Extinguish burn effects and apply wet effect if player entered water ---
     if is_on_water && !current_player.is_on_water {
         // Player just entered water - extinguish any burn effects
@@ -744,9 +934,13 @@ pub fn update_player_position_simple(
     current_player.is_sprinting = is_sprinting; // Allow sprinting in water
     current_player.is_on_water = is_on_water;
     // Note: is_crouching is already updated above when auto-disabled on water
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(sender_id), final_x, final_y);
 
     // Always update the player
     players.identity().update(current_player);
+    if is_carrying {
+        crate::knocked_out::drag_carried_player(ctx, sender_id, final_x, final_y);
+    }
 
     // Log crouching state changes for debugging
     if was_crouching_disabled {
@@ -815,6 +815,9 @@ fn unlock_achievement(
         award_xp(ctx, player_id, achievement_def.xp_reward)?;
     }
     
+    // Unlock any cosmetic skins tied to this achievement
+    crate::item_cosmetic::grant_achievement_skins(ctx, player_id, &achievement_def.id);
+    
     // Send notification
     let notif = AchievementUnlockNotification {
         id: 0,
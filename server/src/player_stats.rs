@@ -51,6 +51,8 @@ pub fn init_stat_thresholds_config(ctx: &ReducerContext) -> Result<(), String> {
 const HUNGER_DRAIN_PER_SECOND: f32 = 250.0 / (3.0 * 60.0 * 60.0);
 // Thirst drains from 250 to 0 in 2 hours
 const THIRST_DRAIN_PER_SECOND: f32 = 250.0 / (2.0 * 60.0 * 60.0);
+// A death is blamed on an effect's damage tick if it landed within one stat update of the death
+const EFFECT_DEATH_ATTRIBUTION_WINDOW_MICROS: i64 = (PLAYER_STAT_UPDATE_INTERVAL_SECS as i64 + 1) * 1_000_000;
 // Make stat constants pub(crate) as well for consistency, although not strictly needed if only used here
 // pub(crate) const STAMINA_DRAIN_PER_SECOND: f32 = 8.0; // REMOVED: No stamina drain from sprinting
 // pub(crate) const STAMINA_RECOVERY_PER_SECOND: f32 = 3.0; // REMOVED: No stamina processing
@@ -67,19 +69,9 @@ pub(crate) const SPRINT_SPEED_MULTIPLIER: f32 = 1.75; // MUST MATCH CLIENT (1.75
 pub(crate) const JUMP_COOLDOWN_MS: u64 = 300; // Reduced to 300ms for faster jumping
 // NOTE: Speed penalties are now handled by the exhausted effect in active_effects.rs
 
-// Add torch warmth constant
-// Neutralizes night cold (-1.5) but midnight (-2.0) still causes slow warmth loss
-pub(crate) const TORCH_WARMTH_PER_SECOND: f32 = 1.75;
-
 // Tree cover hydration conservation constant
 pub(crate) const TREE_COVER_HYDRATION_REDUCTION_MULTIPLIER: f32 = 0.75; // 25% reduction in thirst drain (75% of normal rate)
 
-// Indoor warmth protection constant
-// Reduces negative warmth drain when inside shelters or enclosed buildings
-// 0.65 = 35% reduction in cold drain (still get cold, but slower)
-// Example: -2.0 warmth/sec (midnight) becomes -1.3 warmth/sec when indoors
-pub(crate) const INDOOR_WARMTH_PROTECTION_MULTIPLIER: f32 = 0.65;
-
 // Add dodge roll stamina cost constant
 pub(crate) const DODGE_ROLL_STAMINA_COST: f32 = 10.0;
 
@@ -130,6 +122,12 @@ pub(crate) const INSANITY_THRESHOLD_75: f32 = 75.0; // Severe warning threshold
 pub(crate) const INSANITY_THRESHOLD_90: f32 = 90.0; // Critical warning threshold
 pub(crate) const INSANITY_THRESHOLD_100: f32 = 100.0; // Maximum threshold (Entrainment)
 
+// Oxygen (diving) constants
+pub const PLAYER_MAX_OXYGEN: f32 = 100.0;
+pub(crate) const OXYGEN_DRAIN_PER_SECOND: f32 = PLAYER_MAX_OXYGEN / 90.0; // ~90 seconds of breath while submerged
+pub(crate) const OXYGEN_REFILL_PER_SECOND: f32 = 25.0; // Full breath after ~4 seconds at the surface
+pub(crate) const DEEP_WATER_OXYGEN_DRAIN_MULTIPLIER: f32 = 2.0; // Deep sea pressure halves breath without protection
+
 // Add constants for starting values (when spawning/respawning)
 // Increased from 40% to 60% to give new players more breathing room before survival pressure kicks in
 pub(crate) const PLAYER_STARTING_HUNGER: f32 = 150.0; // Start at 60% of max (150/250)
@@ -139,22 +137,12 @@ pub(crate) const PLAYER_STARTING_THIRST: f32 = 150.0; // Start at 60% of max (15
 pub(crate) const HUNGER_DRAIN_MULTIPLIER_LOW_WARMTH: f32 = 1.5; // 50% faster hunger drain when cold
 pub(crate) const HUNGER_DRAIN_MULTIPLIER_ZERO_WARMTH: f32 = 2.0; // 100% faster hunger drain when freezing
 
-// Biome-based warmth decay multipliers (affects how fast you get cold in arctic biomes)
-// These multiply the base negative warmth change rate
-// BALANCE NOTES:
-// - Tundra: 50% faster cold drain - noticeable but manageable with basic gear
-// - Alpine: 100% faster cold drain - dangerous, needs good armor or fire
-// - These stack with rain (+1.5 warmth drain) and wetness (+0.5 warmth drain)
-// - Armor cold resistance helps mitigate the damage, not the decay rate
-// - Players need fur/warm armor to survive extended time in arctic regions
-pub(crate) const TUNDRA_WARMTH_DECAY_MULTIPLIER: f32 = 1.5;  // 50% faster cold drain in tundra
-pub(crate) const ALPINE_WARMTH_DECAY_MULTIPLIER: f32 = 2.0;  // 100% faster cold drain in alpine (harsh!)
+// Biome cold (tundra/alpine), rain and wetness now feed the temperature model in temperature.rs
 
 // Import necessary items from the main lib module or other modules
 use crate::{
     Player, // Player struct
     world_state::{self, TimeOfDay, BASE_WARMTH_DRAIN_PER_SECOND, WARMTH_DRAIN_MULTIPLIER_DAWN_DUSK, WARMTH_DRAIN_MULTIPLIER_NIGHT, WARMTH_DRAIN_MULTIPLIER_MIDNIGHT},
-    campfire::{self, Campfire, WARMTH_RADIUS_SQUARED, WARMTH_PER_SECOND},
     active_equipment, // For unequipping on death
     player_corpse::{self, PlayerCorpse, NUM_CORPSE_SLOTS, PlayerCorpseDespawnSchedule},
     environment::calculate_chunk_index,
@@ -163,7 +151,6 @@ use crate::{
 // Import table traits
 use crate::Player as PlayerTableTrait;
 use crate::world_state::world_state as WorldStateTableTrait;
-use crate::campfire::campfire as CampfireTableTrait;
 use crate::active_equipment::active_equipment as ActiveEquipmentTableTrait; // Needed for unequip on death
 use crate::player; // Added missing import for Player trait
 use crate::player_stats::PlayerStatSchedule as PlayerStatScheduleTableTrait; // Added Self trait import
@@ -195,28 +182,6 @@ fn player_has_damaging_effects(ctx: &ReducerContext, player_id: Identity) -> boo
     false
 }
 
-/// Get the biome-based warmth decay multiplier at a given position.
-/// Returns 1.0 for normal biomes, higher values for colder biomes (tundra/alpine).
-/// This multiplier is applied to negative warmth changes to make arctic regions more dangerous.
-pub fn get_biome_warmth_multiplier(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> f32 {
-    // Convert world position to tile coordinates
-    let (tile_x, tile_y) = crate::world_pos_to_tile_coords(pos_x, pos_y);
-    
-    // Get the tile type at this position
-    if let Some(tile_type) = crate::get_tile_type_at_position(ctx, tile_x, tile_y) {
-        if tile_type.is_alpine() {
-            // Alpine is the harshest - 2x faster cold drain
-            return ALPINE_WARMTH_DECAY_MULTIPLIER;
-        } else if tile_type.is_tundra() {
-            // Tundra is moderately harsh - 1.5x faster cold drain
-            return TUNDRA_WARMTH_DECAY_MULTIPLIER;
-        }
-    }
-    
-    // Default: no multiplier for temperate biomes
-    1.0
-}
-
 // --- Player Stat Schedule Table (Reverted to scheduled pattern) ---
 #[spacetimedb::table(accessor = player_stat_schedule, scheduled(process_player_stats))]
 #[derive(Clone)]
@@ -270,21 +235,9 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
     log::trace!("Processing player stats via schedule...");
     let current_time = ctx.timestamp;
     let world_states = ctx.db.world_state();
-    let campfires = ctx.db.campfire();
-
-    // Pre-collect village campfire positions (at most 2 in the world: fishing + hunting).
-    // Doing this once here avoids a full monument_part scan for every player.
-    let village_campfire_positions: Vec<(f32, f32)> = {
-        use crate::monument_part as MonumentPartTableTrait;
-        use crate::MonumentType;
-        ctx.db.monument_part().iter()
-            .filter(|p| {
-                (p.monument_type == MonumentType::FishingVillage || p.monument_type == MonumentType::HuntingVillage)
-                    && p.part_type == "campfire"
-            })
-            .map(|p| (p.world_x, p.world_y))
-            .collect()
-    };
+
+    // Gather heat sources (campfires, furnaces, village campfires) once for all players
+    let heat_sources = crate::temperature::collect_heat_sources(ctx);
 
     let game_config_table = ctx.db.stat_thresholds_config();
     let config = game_config_table.iter().next()
@@ -302,6 +255,14 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
         let mut player = players.identity().find(&player_id)
             .expect("Player should exist during stats processing");
 
+        // --- Wake sleepers that were hit, saw daybreak or lost their bag (before hit times are cleared) ---
+        if player.is_sleeping && crate::sleeping_bag::should_wake(ctx, &player) {
+            player.is_sleeping = false;
+            players.identity().update(player.clone());
+            log::info!("[Sleep] Player {:?} woke up", player_id);
+        }
+        let sleep_settings = if player.is_sleeping { Some(crate::sleeping_bag::get_sleep_settings(ctx)) } else { None };
+
         // --- Clear stale hit time to prevent stuck white hit flash state ---
         // Hit effect duration is ~500ms (200ms shake + 300ms latency buffer)
         // BUT burn effects tick every 2 seconds, so we need to keep last_hit_time valid
@@ -396,106 +357,51 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
         
         let new_thirst = (player.thirst - (elapsed_seconds * thirst_drain_rate)).max(0.0).min(PLAYER_MAX_THIRST);
 
-        // Calculate Warmth
-        // BALANCED WARMTH LOGIC: Daytime safe, nighttime challenging but fair
-        // Daytime: Strong warmth gain to offset rain
-        // Nighttime: Slow drain gives time to find shelter/warmth sources
-        let base_warmth_change_per_sec = match world_state.time_of_day {
-            TimeOfDay::Midnight => -0.10,  // ~16.7 min to 0 warmth
-            TimeOfDay::Night => -0.08,     // ~20.8 min to 0 warmth
-            TimeOfDay::TwilightEvening => -0.03,  // ~55 min to 0 warmth
-            TimeOfDay::Dusk => 0.2,        // Slight gain to offset rain (was 0.0)
-            TimeOfDay::Afternoon => 1.2,   // Increased from 1.0 for better rain resistance
-            TimeOfDay::Noon => 2.0,        // Peak warmth gain
-            TimeOfDay::Morning => 1.2,     // Increased from 1.0 for better rain resistance
-            TimeOfDay::TwilightMorning => 0.7,  // Increased from 0.5 for better rain resistance
-            TimeOfDay::Dawn => 0.2,         // Slight gain to offset rain (was 0.0)
-        };
+        // Calculate Warmth from the unified temperature model
+        // Biome, time of day, rain/wetness, heat sources (campfires, furnaces, torch), shelter and clothing
+        // all feed one effective temperature - see temperature.rs
+        let temperature_reading = crate::temperature::calculate_player_temperature(ctx, &player, &world_state.time_of_day, &heat_sources);
+        crate::temperature::record_player_temperature(ctx, player_id, &temperature_reading);
 
-        // <<< APPLY BIOME-BASED WARMTH DECAY MULTIPLIER >>>
-        // Tundra and Alpine biomes have faster warmth decay (get colder faster)
-        // This multiplier only affects NEGATIVE warmth changes - daytime warmth gain is unaffected
-        let biome_multiplier = get_biome_warmth_multiplier(ctx, player.position_x, player.position_y);
-        let mut biome_adjusted_warmth_change = if base_warmth_change_per_sec < 0.0 && biome_multiplier > 1.0 {
-            let adjusted = base_warmth_change_per_sec * biome_multiplier;
-            log::trace!(
-                "Player {:?} in cold biome - warmth decay multiplied by {:.1}x (from {:.3} to {:.3} warmth/sec)",
-                player_id, biome_multiplier, base_warmth_change_per_sec, adjusted
-            );
-            adjusted
+        // <<< BROTH EFFECT: WarmthBoost reduces warmth decay by 50% >>>
+        let cold_loss_multiplier = if crate::active_effects::player_has_warmth_boost_effect(ctx, player_id) {
+            crate::active_effects::WARMTH_BOOST_DECAY_REDUCTION
         } else {
-            base_warmth_change_per_sec
+            1.0
         };
-        // <<< END BIOME-BASED WARMTH DECAY MULTIPLIER >>>
-        
-        // <<< BROTH EFFECT: WarmthBoost reduces warmth decay by 50% >>>
-        if biome_adjusted_warmth_change < 0.0 && crate::active_effects::player_has_warmth_boost_effect(ctx, player_id) {
-            let original = biome_adjusted_warmth_change;
-            biome_adjusted_warmth_change *= crate::active_effects::WARMTH_BOOST_DECAY_REDUCTION;
-            log::trace!("Player {:?} has WarmthBoost broth - warmth decay reduced by {:.0}% (from {:.3} to {:.3} warmth/sec)",
-                player_id, (1.0 - crate::active_effects::WARMTH_BOOST_DECAY_REDUCTION) * 100.0, original, biome_adjusted_warmth_change);
-        }
         // <<< END BROTH EFFECT >>>
 
-        // Apply indoor warmth protection to reduce cold drain (but not eliminate it)
-        let mut total_warmth_change_per_sec = biome_adjusted_warmth_change;
-        if player.is_inside_building && biome_adjusted_warmth_change < 0.0 {
-            // Reduce negative warmth drain by 35% when indoors (applies AFTER biome multiplier)
-            total_warmth_change_per_sec = biome_adjusted_warmth_change * INDOOR_WARMTH_PROTECTION_MULTIPLIER;
-            log::trace!(
-                "Player {:?} is indoors - cold drain reduced by {:.0}% (from {:.2} to {:.2} warmth/sec)",
-                player_id,
-                (1.0 - INDOOR_WARMTH_PROTECTION_MULTIPLIER) * 100.0,
-                biome_adjusted_warmth_change,
-                total_warmth_change_per_sec
-            );
-        }
+        let mut total_warmth_change_per_sec = crate::temperature::get_warmth_change_per_second(&temperature_reading, cold_loss_multiplier);
+        log::trace!(
+            "Player {:?} temperature {:.1}C (ambient {:.1}, heat +{:.1}, chill -{:.1}, insulation {:.0}%) -> {:.3} warmth/sec",
+            player_id, temperature_reading.effective_c, temperature_reading.ambient_c, temperature_reading.heat_c,
+            temperature_reading.chill_c, temperature_reading.insulation * 100.0, total_warmth_change_per_sec
+        );
 
-        for fire in campfires.iter() {
-            // Only gain warmth from burning campfires
-            if fire.is_burning {
-                let dx = player.position_x - fire.pos_x;
-                let dy = player.position_y - fire.pos_y;
-                if (dx * dx + dy * dy) < WARMTH_RADIUS_SQUARED {
-                    total_warmth_change_per_sec += WARMTH_PER_SECOND;
-                    log::trace!("Player {:?} gaining warmth from campfire {}", player_id, fire.id);
-                }
-            }
-        }
+        // Overheating: heavy clothing in extreme heat makes the player sweat out water
+        let overheat_thirst_drain = crate::temperature::get_overheat_thirst_drain_per_second(&temperature_reading);
+        let new_thirst = if overheat_thirst_drain > 0.0 {
+            log::trace!("Player {:?} is overheating at {:.1}C - losing {:.2} extra thirst/sec", player_id, temperature_reading.effective_c, overheat_thirst_drain);
+            (new_thirst - overheat_thirst_drain * elapsed_seconds).max(0.0)
+        } else {
+            new_thirst
+        };
 
-        // Village campfires (fishing/hunting) are always burning - same warmth gain, larger radius
-        const VILLAGE_CAMPFIRE_WARMTH_RADIUS_SQ: f32 = 450.0 * 450.0;
-        for &(vx, vy) in &village_campfire_positions {
-            let dx = player.position_x - vx;
-            let dy = player.position_y - vy;
-            if (dx * dx + dy * dy) < VILLAGE_CAMPFIRE_WARMTH_RADIUS_SQ {
-                total_warmth_change_per_sec += WARMTH_PER_SECOND;
-                log::trace!("Player {:?} gaining warmth from village campfire at ({:.0}, {:.0})", player_id, vx, vy);
-                break; // Only one village campfire can be in range at a time
-            }
+        // Diseases: dysentery dehydrates, a cold makes the player lose warmth
+        let new_thirst = if crate::active_effects::player_has_dysentery_effect(ctx, player_id) {
+            (new_thirst - crate::active_effects::DYSENTERY_THIRST_DRAIN_PER_SECOND * elapsed_seconds).max(0.0)
+        } else {
+            new_thirst
+        };
+        if crate::active_effects::player_has_common_cold_effect(ctx, player_id) {
+            total_warmth_change_per_sec -= crate::active_effects::COMMON_COLD_WARMTH_DRAIN_PER_SECOND;
         }
+        crate::active_effects::update_player_disease_exposure(ctx, &player);
 
-        // <<< ADD WARMTH BONUS FROM LIT TORCH >>>
-        if player.is_torch_lit {
-            total_warmth_change_per_sec += TORCH_WARMTH_PER_SECOND;
-            log::trace!("Player {:?} gaining {:.2} warmth/sec from lit torch.", player_id, TORCH_WARMTH_PER_SECOND);
+        // Fell through the ice: the freezing water drains warmth fast
+        if player.is_on_water && crate::ice::is_in_ice_hole(ctx, player.position_x, player.position_y) {
+            total_warmth_change_per_sec -= crate::ice::ICE_WATER_WARMTH_DRAIN_PER_SECOND;
         }
-        // <<< END WARMTH BONUS FROM LIT TORCH >>>
-
-        // <<< ADD WARMTH BONUS FROM ARMOR >>>
-        let armor_warmth_bonus_per_interval = armor::calculate_total_warmth_bonus(ctx, player_id);
-        // Assuming PLAYER_STAT_UPDATE_INTERVAL_SECS is the interval length in seconds for this stat processing.
-        // If the bonus is defined as points per second, it can be added directly.
-        // If it's meant as points per processing interval, then we divide by the interval.
-        // For simplicity, let's assume warmth_bonus in ItemDefinition is points per second.
-        if armor_warmth_bonus_per_interval > 0.0 {
-            total_warmth_change_per_sec += armor_warmth_bonus_per_interval; 
-            log::trace!(
-                "Player {:?} gaining {:.2} warmth/sec from armor bonus.", 
-                player_id, armor_warmth_bonus_per_interval
-            );
-        }
-        // <<< END WARMTH BONUS FROM ARMOR >>>
 
         // <<< ADD COZY EFFECT MANAGEMENT >>>
         // Update cozy status based on proximity to campfires and owned shelters
@@ -518,6 +424,17 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
         }
         // <<< END RUNE STONE ZONE EFFECT MANAGEMENT >>>
 
+        // Memory static around charged rune stones (blocked by the Static Ward Cowl)
+        if let Err(e) = crate::active_effects::update_player_memory_static_status(ctx, player_id, player.position_x, player.position_y) {
+            log::warn!("Failed to update memory static status for player {:?}: {}", player_id, e);
+        }
+        let in_memory_static = crate::active_effects::player_has_memory_static_effect(ctx, player_id);
+
+        // Aurora over the arctic blesses players standing under it
+        if let Err(e) = crate::active_effects::update_player_aurora_blessing(ctx, player_id, player.position_x, player.position_y) {
+            log::warn!("Failed to update aurora blessing for player {:?}: {}", player_id, e);
+        }
+
         // <<< ADD HOT SPRING HEALING EFFECT MANAGEMENT >>>
         // Update hot spring healing status based on player position
         if let Err(e) = crate::active_effects::update_player_hot_spring_status(ctx, player_id, player.position_x, player.position_y) {
@@ -608,31 +525,6 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
         // This prevents conflicts between the 1-second player stats and 2-second effect processing
         // <<< END WET EFFECT MANAGEMENT >>>
 
-        // <<< ADD RAIN WARMTH DRAIN >>>
-        // Only apply if NOT in hot spring (hot springs provide immunity)
-        if !is_in_hot_spring {
-            let rain_warmth_drain = world_state::get_rain_warmth_drain_modifier(ctx, player.position_x, player.position_y);
-            log::info!("Rain warmth drain check: player at ({:.1}, {:.1}), drain = {:.2}", player.position_x, player.position_y, rain_warmth_drain);
-            if rain_warmth_drain > 0.0 {
-                total_warmth_change_per_sec -= rain_warmth_drain; // Subtract rain drain
-                log::info!(
-                    "Player {:?} losing {:.2} warmth/sec from rain (total warmth change now: {:.2})", 
-                    player_id, rain_warmth_drain, total_warmth_change_per_sec
-                );
-            } else {
-                log::info!("Player {:?} protected from rain or no rain active", player_id);
-            }
-        }
-        // <<< END RAIN WARMTH DRAIN >>>
-
-        // <<< ADD WET EFFECT WARMTH DRAIN >>>
-        // Only apply if NOT in hot spring (hot springs provide immunity)
-        if !is_in_hot_spring && crate::active_effects::player_has_wet_effect(ctx, player_id) {
-            total_warmth_change_per_sec -= crate::wet::WET_WARMTH_DRAIN_PER_SECOND;
-            log::trace!("Player {:?} losing {:.2} warmth/sec from being wet (total warmth change now: {:.2})", 
-                player_id, crate::wet::WET_WARMTH_DRAIN_PER_SECOND, total_warmth_change_per_sec);
-        }
-        // <<< END WET EFFECT WARMTH DRAIN >>>
 
         let new_warmth = (player.warmth + (total_warmth_change_per_sec * elapsed_seconds))
                          .max(0.0).min(100.0);
@@ -705,7 +597,7 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
         let mut insanity_change_per_sec = 0.0;
         
         // Check if player is in an ALK station safe zone (central compound or substations)
-        let is_in_alk_safe_zone = crate::active_effects::is_player_in_safe_zone(ctx, player.position_x, player.position_y);
+        let is_in_alk_safe_zone = crate::safe_zone::is_position_in_safe_zone(ctx, player.position_x, player.position_y);
         
         // Only increase insanity if carrying 100+ shards AND not in a safe zone
         if memory_shard_count >= INSANITY_MINIMUM_SHARD_THRESHOLD && !is_in_alk_safe_zone {
@@ -788,6 +680,16 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
             }
         }
         
+        // Aurora blessing eases the mind
+        if crate::active_effects::player_has_aurora_blessing_effect(ctx, player_id) {
+            insanity_change_per_sec -= crate::active_effects::AURORA_INSANITY_REDUCTION_PER_SECOND;
+        }
+        
+        // Memory static overrides any recovery and drives insanity up fast, shards or not
+        if in_memory_static {
+            insanity_change_per_sec = insanity_change_per_sec.max(0.0) + crate::rune_stone::MEMORY_STATIC_INSANITY_PER_SECOND;
+        }
+        
         // MEMORY BEACON SANITY HAVEN: If player is inside an active Memory Beacon zone,
         // immediately clear all insanity and prevent any accumulation.
         // This is the unique benefit of Memory Beacons over Signal Disruptors.
@@ -803,7 +705,10 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
             }
             0.0
         } else {
-            // Normal insanity calculation
+            // Normal insanity calculation (sleep in a bag calms the mind)
+            if let Some(settings) = &sleep_settings {
+                insanity_change_per_sec -= settings.insanity_reduction_per_sec;
+            }
             (player.insanity + (insanity_change_per_sec * elapsed_seconds))
                 .max(0.0).min(PLAYER_MAX_INSANITY)
         };
@@ -860,8 +765,39 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
                 log::error!("Failed to apply Entrainment effect to player {:?}: {}", player_id, e);
             }
         }
+
+        // Hallucinations past 50% (phantom hostiles, minimap distortion, SOVA encounters)
+        crate::hallucination::update_player_hallucinations(ctx, &player, new_insanity);
         // <<< END INSANITY SYSTEM >>>
 
+        // <<< OXYGEN (DIVING) >>>
+        // Breath drains while submerged and refills quickly at the surface. Running out of air
+        // applies the Drowning damage-over-time effect until the player surfaces.
+        // Diving in deep sea without pressure protection burns breath twice as fast.
+        let new_oxygen = if player.is_snorkeling && !player.is_dead {
+            let (tile_x, tile_y) = crate::world_pos_to_tile_coords(player.position_x, player.position_y);
+            let in_deep_water = crate::get_tile_type_at_position(ctx, tile_x, tile_y) == Some(crate::TileType::DeepSea);
+            let drain_multiplier = if in_deep_water && !armor::has_environmental_protection(ctx, player_id, crate::models::EnvironmentalHazard::Pressure) {
+                DEEP_WATER_OXYGEN_DRAIN_MULTIPLIER
+            } else {
+                1.0
+            };
+            (player.oxygen - OXYGEN_DRAIN_PER_SECOND * drain_multiplier * elapsed_seconds).max(0.0)
+        } else {
+            (player.oxygen + OXYGEN_REFILL_PER_SECOND * elapsed_seconds).min(PLAYER_MAX_OXYGEN)
+        };
+        if player.is_snorkeling && !player.is_dead && new_oxygen <= 0.0 {
+            if !crate::active_effects::player_has_drowning_effect(ctx, player_id) {
+                log::info!("Player {:?} ran out of oxygen - applying Drowning effect", player_id);
+                if let Err(e) = crate::active_effects::apply_drowning_effect(ctx, player_id) {
+                    log::error!("Failed to apply Drowning effect to player {:?}: {}", player_id, e);
+                }
+            }
+        } else if new_oxygen > 0.0 && crate::active_effects::player_has_drowning_effect(ctx, player_id) {
+            crate::active_effects::cancel_drowning_effects(ctx, player_id);
+        }
+        // <<< END OXYGEN (DIVING) >>>
+
         // Stamina processing removed - players can sprint without stamina cost
 
         // <<< ADD EXHAUSTED EFFECT MANAGEMENT >>>
@@ -943,6 +879,10 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
         }
         // <<< END COLD IMMUNITY CHECK >>>
 
+        if in_memory_static {
+            health_change_per_sec -= crate::rune_stone::MEMORY_STATIC_DAMAGE_PER_SECOND;
+        }
+
         // Health recovery only if needs are met and not taking damage from any source
         if health_change_per_sec == 0.0 && // No damage from needs
            player.health >= HEALTH_RECOVERY_THRESHOLD && // ADDED: Only regen if health is already high
@@ -962,6 +902,10 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
                 );
             }
             
+            if let Some(settings) = &sleep_settings {
+                health_regen *= settings.health_regen_multiplier;
+            }
+            
             health_change_per_sec += health_regen;
         }
 
@@ -987,6 +931,7 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
                      player.username, player_id, final_health);
             player.is_dead = true;
             player.death_timestamp = Some(ctx.timestamp); // Set death timestamp
+            crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::Player(player.identity));
 
             // Drop active weapon on death (before clearing equipment and creating corpse)
             match crate::dropped_item::drop_active_weapon_on_death(ctx, player_id, player.position_x, player.position_y) {
@@ -1002,6 +947,7 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
             }
 
             // Clear all active effects on death (bleed, venom, burns, healing, etc.)
+            let died_drowning = crate::active_effects::player_has_drowning_effect(ctx, player_id);
             crate::active_effects::clear_all_effects_on_death(ctx, player_id);
             log::info!("[PlayerDeath] Cleared all active effects for dying player {:?}", player_id);
 
@@ -1033,6 +979,28 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
                 log::info!("[DeathMarker] Inserting new death marker for player {:?} due to stats decay.", player.identity);
             }
             // --- End DeathMarker ---
+
+            // --- Kill Feed / Death Recap ---
+            // A player who dies still out of air drowned, whatever else ran out down there. Otherwise attribute
+            // the death to whichever need ran out (thirst drains fastest, so check it first), or to the
+            // damage-over-time effect whose tick just took the last of the player's health
+            let recent_effect_damage = crate::kill_feed::last_damage_event(ctx, player_id)
+                .filter(|event| crate::active_effects::is_effect_damage_cause(&event.source_name) &&
+                    ctx.timestamp.to_micros_since_unix_epoch() - event.occurred_at.to_micros_since_unix_epoch() <= EFFECT_DEATH_ATTRIBUTION_WINDOW_MICROS);
+            let stat_death_cause = if died_drowning {
+                "Drowning".to_string()
+            } else if new_thirst <= 0.0 {
+                "Dehydration".to_string()
+            } else if new_hunger <= 0.0 {
+                "Starvation".to_string()
+            } else if new_warmth <= 0.0 {
+                "Exposure".to_string()
+            } else if let Some(event) = recent_effect_damage {
+                event.source_name
+            } else {
+                "Environment".to_string()
+            };
+            crate::kill_feed::record_player_death(ctx, &player, None, "", &stat_death_cause, None, None);
             
             // --- Calculate Comparative Stats on Death ---
             let mut stats = crate::player_progression::get_or_init_player_stats(ctx, player_id);
@@ -1131,6 +1099,7 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
                             (player.warmth - new_warmth).abs() > 0.01 ||
                             (player.insanity - new_insanity).abs() > 0.01 ||
                             (player.last_insanity_threshold - new_threshold).abs() > 0.01 ||
+                            (player.oxygen - new_oxygen).abs() > 0.01 ||
                             player.is_dead; // Also update if other stats changed OR if player died
 
         if stats_changed {
@@ -1148,6 +1117,7 @@ pub fn process_player_stats(ctx: &ReducerContext, _schedule: PlayerStatSchedule)
             current_player.warmth = new_warmth;
             current_player.insanity = new_insanity;
             current_player.last_insanity_threshold = new_threshold;
+            current_player.oxygen = new_oxygen;
             current_player.shard_carry_start_time = shard_carry_start_time_to_update;
             if triggered_200_shard_tutorial {
                 crate::mark_tutorial_seen_in_player(&mut current_player, crate::TUTORIAL_ID_MEMORY_SHARD_200);
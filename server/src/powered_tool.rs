@@ -0,0 +1,248 @@
+/******************************************************************************
+ *                                                                            *
+ * Powered Tools Module                                                       *
+ * Chainsaws and jackhammers gather at the Powered harvest tier but run on    *
+ * Crude Fuel. Each tool carries a small fuel tank (item_data "fuel_secs")    *
+ * that refills from the player's inventory when a use finds it dry. While    *
+ * the engine runs (recently used and still equipped) a schedule drains the   *
+ * tank and wears the tool down. Every use is loud enough to pull nearby      *
+ * hostile NPCs towards the player.                                           *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table};
+use log;
+use serde_json;
+
+use crate::items::{InventoryItem, ItemDefinition};
+use crate::items::inventory_item as InventoryItemTableTrait;
+use crate::items::item_definition as ItemDefinitionTableTrait;
+use crate::active_equipment::active_equipment as ActiveEquipmentTableTrait;
+use crate::player as PlayerTableTrait;
+use crate::items_database::HarvestToolTier;
+use crate::models::TargetType;
+use crate::sound_events::{self, SoundType};
+use crate::Player;
+
+// --- Constants ---
+
+/// Item pulled from inventory to refill a powered tool's tank
+pub const POWERED_TOOL_FUEL_ITEM_NAME: &str = "Crude Fuel";
+
+/// Engine run time provided by one unit of fuel
+pub const POWERED_TOOL_FUEL_SECS_PER_UNIT: f32 = 30.0;
+
+/// Extra fuel burned by each cut/drill on top of idle running
+pub const POWERED_TOOL_FUEL_SECS_PER_USE: f32 = 1.5;
+
+/// Engine keeps running (and burning fuel) this long after the last use
+pub const POWERED_TOOL_IDLE_TIMEOUT_MS: u64 = 8_000;
+
+/// Fuel drain tick interval in seconds
+pub const POWERED_TOOL_FUEL_TICK_INTERVAL_SECS: u64 = 2;
+
+/// Per-hit durability loss compared to hand tools (0.2 * 5 = 100 hits before breaking)
+pub const POWERED_TOOL_DURABILITY_LOSS_MULTIPLIER: f32 = 5.0;
+
+/// Durability lost per fuel tick while the engine is running
+pub const POWERED_TOOL_RUNNING_DURABILITY_LOSS_PER_TICK: f32 = 0.1;
+
+/// How far the engine can be heard (vs ~1050px for a stone pickaxe)
+pub const POWERED_TOOL_SOUND_DISTANCE_PX: f32 = 2000.0;
+
+/// Hostile NPCs and wolves within this radius come looking for the noise
+pub const POWERED_TOOL_NOISE_RADIUS_PX: f32 = 900.0;
+pub const POWERED_TOOL_NOISE_INTENSITY: f32 = 0.6;
+
+// --- Schedule Table ---
+
+#[spacetimedb::table(accessor = powered_tool_fuel_schedule, scheduled(process_powered_tool_fuel))]
+#[derive(Clone, Debug)]
+pub struct PoweredToolFuelSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub schedule_id: u64,
+    pub scheduled_at: spacetimedb::ScheduleAt,
+}
+
+// --- Helper Functions ---
+
+/// Whether an item is a fuel-driven gathering tool
+pub fn is_powered_tool(item_def: &ItemDefinition) -> bool {
+    crate::items_database::get_harvest_tool_tier(&item_def.name) == HarvestToolTier::Powered
+}
+
+/// Seconds of engine time left in the tool's tank (0 for a tool that has never been fuelled)
+pub fn get_tank_fuel_secs(item: &InventoryItem) -> f32 {
+    item.item_data.as_ref().and_then(|data| {
+        if let Ok(parsed) = serde_json::from_str::<serde_json::Value>(data) {
+            parsed.get("fuel_secs").and_then(|v| v.as_f64()).map(|v| v as f32)
+        } else {
+            None
+        }
+    }).unwrap_or(0.0)
+}
+
+/// Sets the tank level in item_data, preserving durability and other fields
+pub fn set_tank_fuel_secs(item: &mut InventoryItem, fuel_secs: f32) {
+    let mut json_obj = if let Some(ref data) = item.item_data {
+        serde_json::from_str::<serde_json::Value>(data)
+            .unwrap_or_else(|_| serde_json::json!({}))
+    } else {
+        serde_json::json!({})
+    };
+    if !json_obj.is_object() {
+        json_obj = serde_json::json!({});
+    }
+    json_obj["fuel_secs"] = serde_json::json!(fuel_secs.max(0.0));
+    item.item_data = Some(json_obj.to_string());
+}
+
+/// Takes one unit of fuel from the player's inventory/hotbar. Returns false if they have none.
+fn take_fuel_unit_from_inventory(ctx: &ReducerContext, player_id: Identity) -> bool {
+    let inventory_items = ctx.db.inventory_item();
+    let Some(fuel_def) = ctx.db.item_definition().iter().find(|def| def.name == POWERED_TOOL_FUEL_ITEM_NAME) else {
+        log::error!("[PoweredTool] {} item definition not found", POWERED_TOOL_FUEL_ITEM_NAME);
+        return false;
+    };
+
+    let fuel_stack = inventory_items.iter().find(|item| {
+        item.item_def_id == fuel_def.id
+            && item.quantity > 0
+            && item.location.is_player_bound() == Some(player_id)
+    });
+    let Some(mut fuel_stack) = fuel_stack else {
+        return false;
+    };
+
+    if fuel_stack.quantity > 1 {
+        fuel_stack.quantity -= 1;
+        inventory_items.instance_id().update(fuel_stack);
+    } else {
+        inventory_items.instance_id().delete(fuel_stack.instance_id);
+    }
+    true
+}
+
+/// Burns fuel for one use of a powered tool, refilling the tank from inventory if it's dry.
+/// Called from use_equipped_item before the swing resolves.
+pub fn consume_fuel_for_use(ctx: &ReducerContext, player_id: Identity, tool_instance_id: u64) -> Result<(), String> {
+    let inventory_items = ctx.db.inventory_item();
+    let mut tool = inventory_items.instance_id().find(tool_instance_id)
+        .ok_or_else(|| format!("Powered tool {} not found", tool_instance_id))?;
+
+    let mut fuel_secs = get_tank_fuel_secs(&tool);
+    if fuel_secs < POWERED_TOOL_FUEL_SECS_PER_USE {
+        if !take_fuel_unit_from_inventory(ctx, player_id) {
+            return Err(format!("Out of fuel. Carry {} to run this tool.", POWERED_TOOL_FUEL_ITEM_NAME));
+        }
+        fuel_secs += POWERED_TOOL_FUEL_SECS_PER_UNIT;
+        log::debug!("[PoweredTool] Player {:?} refuelled tool {} ({:.1}s in tank)", player_id, tool_instance_id, fuel_secs);
+    }
+
+    set_tank_fuel_secs(&mut tool, fuel_secs - POWERED_TOOL_FUEL_SECS_PER_USE);
+    inventory_items.instance_id().update(tool);
+    Ok(())
+}
+
+/// Plays the engine sound and registers a noise that draws nearby hostile NPCs
+pub fn emit_powered_tool_noise(ctx: &ReducerContext, player: &Player, item_def: &ItemDefinition) {
+    let sound_type = if item_def.primary_target_type == Some(TargetType::Stone) {
+        SoundType::JackhammerDrill
+    } else {
+        SoundType::ChainsawCut
+    };
+    sound_events::emit_powered_tool_sound(ctx, sound_type, player.position_x, player.position_y, POWERED_TOOL_SOUND_DISTANCE_PX, player.identity);
+    sound_events::register_noise(ctx, player.position_x, player.position_y,
+        POWERED_TOOL_NOISE_RADIUS_PX, POWERED_TOOL_NOISE_INTENSITY, player.identity);
+}
+
+// --- Initialization ---
+
+/// Initializes the powered tool fuel drain schedule
+/// Called from init_module in lib.rs
+pub fn init_powered_tool_fuel_schedule(ctx: &ReducerContext) -> Result<(), String> {
+    use spacetimedb::spacetimedb_lib::ScheduleAt;
+    use spacetimedb::TimeDuration;
+
+    let schedule_table = ctx.db.powered_tool_fuel_schedule();
+    if schedule_table.iter().count() > 0 {
+        log::debug!("Powered tool fuel schedule already initialized.");
+        return Ok(());
+    }
+
+    log::info!("Initializing powered tool fuel schedule (runs every {} seconds)...",
+        POWERED_TOOL_FUEL_TICK_INTERVAL_SECS);
+
+    let interval = TimeDuration::from_micros((POWERED_TOOL_FUEL_TICK_INTERVAL_SECS * 1_000_000) as i64);
+    let schedule = PoweredToolFuelSchedule {
+        schedule_id: 0, // Auto-increment
+        scheduled_at: ScheduleAt::Interval(interval),
+    };
+
+    crate::try_insert_schedule!(
+        schedule_table,
+        schedule.clone(),
+        "Powered tool fuel"
+    );
+
+    Ok(())
+}
+
+// --- Scheduled Reducer ---
+
+/// Drains fuel and wears down every powered tool whose engine is running.
+/// An engine runs while the tool is equipped and was used within POWERED_TOOL_IDLE_TIMEOUT_MS.
+#[spacetimedb::reducer]
+pub fn process_powered_tool_fuel(ctx: &ReducerContext, _args: PoweredToolFuelSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Powered tool fuel processing can only be run by scheduler".to_string());
+    }
+
+    let players = ctx.db.player();
+    if !players.iter().any(|p| p.is_online) {
+        return Ok(());
+    }
+
+    let now_ms = (ctx.timestamp.to_micros_since_unix_epoch() / 1000) as u64;
+    let inventory_items = ctx.db.inventory_item();
+    let item_defs = ctx.db.item_definition();
+    let tick_secs = POWERED_TOOL_FUEL_TICK_INTERVAL_SECS as f32;
+    let mut stalled_count = 0;
+
+    for equipment in ctx.db.active_equipment().iter() {
+        if equipment.swing_start_time_ms == 0 || now_ms.saturating_sub(equipment.swing_start_time_ms) > POWERED_TOOL_IDLE_TIMEOUT_MS {
+            continue;
+        }
+        let (Some(def_id), Some(instance_id)) = (equipment.equipped_item_def_id, equipment.equipped_item_instance_id) else {
+            continue;
+        };
+        let Some(item_def) = item_defs.id().find(def_id) else { continue };
+        if !is_powered_tool(&item_def) {
+            continue;
+        }
+        let Some(mut tool) = inventory_items.instance_id().find(instance_id) else { continue };
+
+        let fuel_secs = get_tank_fuel_secs(&tool);
+        if fuel_secs <= 0.0 {
+            continue; // Engine already stalled - next use refuels
+        }
+        let remaining = (fuel_secs - tick_secs).max(0.0);
+        set_tank_fuel_secs(&mut tool, remaining);
+
+        crate::durability::ensure_durability_initialized(&mut tool);
+        let durability = crate::durability::get_durability(&tool).unwrap_or(crate::durability::MAX_DURABILITY);
+        crate::durability::set_durability(&mut tool, durability - POWERED_TOOL_RUNNING_DURABILITY_LOSS_PER_TICK);
+        inventory_items.instance_id().update(tool);
+
+        if remaining <= 0.0 {
+            stalled_count += 1;
+            log::debug!("[PoweredTool] Player {:?}'s {} ran its tank dry", equipment.player_identity, item_def.name);
+        }
+    }
+
+    if stalled_count > 0 {
+        log::info!("[PoweredTool] {} powered tools stalled this tick", stalled_count);
+    }
+    Ok(())
+}
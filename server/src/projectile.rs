@@ -18,6 +18,7 @@ use crate::active_effects; // Import the active effects module for applying ammu
 use crate::active_effects::active_consumable_effect; // Import the trait for the table
 use crate::shelter; // Import shelter module for collision detection
 use crate::shelter::shelter as ShelterTableTrait; // Import shelter table trait
+use crate::building::{apply_structure_damage, StructureRef, StructureDamageType};
 use crate::sound_events; // Import sound events for arrow hit sounds
 
 // Import deployable entity modules for collision detection
@@ -47,7 +48,6 @@ use crate::rune_stone::rune_stone as RuneStoneTableTrait;
 use crate::basalt_column::{BasaltColumn, basalt_column as BasaltColumnTableTrait};
 
 // Import wild animal module for collision detection
-use crate::wild_animal_npc::{wild_animal as WildAnimalTableTrait};
 use crate::turret::{self, TALLOW_PROJECTILE_DAMAGE};
 use crate::turret::turret as TurretTableTrait;
 use crate::environment::{calculate_chunk_index, WORLD_WIDTH_CHUNKS, WORLD_HEIGHT_CHUNKS};
@@ -76,6 +76,96 @@ pub const SHOREBOUND_PROJECTILE_SPEED: f32 = 500.0; // Medium-fast ghostly bolt
 pub const VIPER_PROJECTILE_DAMAGE: f32 = 5.0;       // Low impact, but applies venom
 pub const VIPER_PROJECTILE_SPEED: f32 = 450.0;      // Fast venom spittle
 
+// Player weapon spread modifiers (per-weapon spread/recoil stats live in ranged_weapon_stats)
+pub const SPREAD_CROUCH_MULTIPLIER: f32 = 0.6;   // Crouching steadies the aim
+pub const SPREAD_MOVING_MULTIPLIER: f32 = 1.5;   // Walking between shots widens the cone
+pub const SPREAD_SPRINTING_MULTIPLIER: f32 = 2.0; // Firing on the run is wild
+pub const SPREAD_MOVING_SPEED_THRESHOLD: f32 = 40.0; // px/s between shots that counts as moving
+
+// Bow draw constants (draw time per weapon lives in ranged_weapon_stats)
+pub const MIN_DRAW_POWER: f32 = 0.35;          // Damage multiplier for an instant, undrawn release
+pub const MIN_DRAW_SPEED_MULTIPLIER: f32 = 0.6; // Arrow speed multiplier for an undrawn release
+
+/// Calculates the spread cone half-angle (radians) for a player's shot and records the shot.
+/// Consecutive shots within the weapon's recovery window stack recoil; crouching tightens
+/// the cone and moving/sprinting widens it. Computed server-side so aim can't be scripted.
+fn calculate_and_record_shot_spread(
+    ctx: &ReducerContext,
+    player: &crate::Player,
+    weapon_stats: &crate::ranged_weapon_stats::RangedWeaponStats,
+    spawn_x: f32,
+    spawn_y: f32,
+) -> f32 {
+    let spreads = ctx.db.player_weapon_spread();
+    let previous = spreads.player_id().find(&player.identity)
+        .filter(|s| s.item_name == weapon_stats.item_name);
+
+    // Recoil left over from earlier shots, and whether the shooter moved since the last one
+    let mut recoil_shots = 0.0;
+    let mut moved_between_shots = false;
+    if let Some(prev) = &previous {
+        let elapsed_secs = ctx.timestamp.duration_since(prev.last_shot_at)
+            .map(|d| d.as_secs_f32())
+            .unwrap_or(0.0);
+        let recovered = if weapon_stats.spread_recovery_secs > 0.0 {
+            elapsed_secs / weapon_stats.spread_recovery_secs
+        } else {
+            f32::MAX
+        };
+        recoil_shots = (prev.recoil_shots - recovered).max(0.0);
+
+        if elapsed_secs > 0.0 {
+            let dx = spawn_x - prev.last_shot_pos_x;
+            let dy = spawn_y - prev.last_shot_pos_y;
+            moved_between_shots = (dx * dx + dy * dy).sqrt() / elapsed_secs > SPREAD_MOVING_SPEED_THRESHOLD;
+        }
+    }
+
+    let mut spread_deg = (weapon_stats.spread_base_deg + weapon_stats.spread_per_shot_deg * recoil_shots)
+        .min(weapon_stats.spread_max_deg);
+
+    if player.is_sprinting {
+        spread_deg *= SPREAD_SPRINTING_MULTIPLIER;
+    } else if moved_between_shots {
+        spread_deg *= SPREAD_MOVING_MULTIPLIER;
+    } else if player.is_crouching {
+        spread_deg *= SPREAD_CROUCH_MULTIPLIER;
+    }
+
+    let record = PlayerWeaponSpread {
+        player_id: player.identity,
+        item_name: weapon_stats.item_name.clone(),
+        recoil_shots: recoil_shots + 1.0,
+        last_shot_at: ctx.timestamp,
+        last_shot_pos_x: spawn_x,
+        last_shot_pos_y: spawn_y,
+    };
+    if spreads.player_id().find(&player.identity).is_some() {
+        spreads.player_id().update(record);
+    } else {
+        spreads.insert(record);
+    }
+
+    spread_deg.to_radians()
+}
+
+/// Returns the draw charge (0.0-1.0) for a shot and clears the player's draw state.
+/// Weapons without a draw time always fire at full charge; a drawn weapon released
+/// without a matching draw (e.g. no start_bow_draw call) fires at zero charge.
+fn take_draw_charge(ctx: &ReducerContext, player_id: Identity, weapon_instance_id: u64, draw_time_secs: f32) -> f32 {
+    let draw = ctx.db.draw_state().player_id().find(&player_id);
+    if draw.is_some() {
+        ctx.db.draw_state().player_id().delete(&player_id);
+    }
+    if draw_time_secs <= 0.0 {
+        return 1.0;
+    }
+    draw.filter(|d| d.weapon_instance_id == weapon_instance_id)
+        .and_then(|d| ctx.timestamp.duration_since(d.draw_started_at))
+        .map(|held| (held.as_secs_f32() / draw_time_secs).min(1.0))
+        .unwrap_or(0.0)
+}
+
 /// Helper function to check if a line segment intersects with a circle
 /// Returns true if the line from (x1,y1) to (x2,y2) intersects with circle at (cx,cy) with radius r
 pub fn line_intersects_circle(x1: f32, y1: f32, x2: f32, y2: f32, cx: f32, cy: f32, radius: f32) -> bool {
@@ -175,6 +265,8 @@ pub struct Projectile {
     pub velocity_x: f32,
     pub velocity_y: f32,
     pub max_range: f32,
+    pub elevation: u8, // Building level fired from - only hits players on the same level
+    pub damage_multiplier: f32, // Bow draw power scaling on hit damage (1.0 = full damage)
 }
 
 #[table(accessor = projectile_runtime_state)]
@@ -185,6 +277,29 @@ pub struct ProjectileRuntimeState {
     pub last_sample_elapsed_secs: f32,
 }
 
+// Server-side recoil state per shooter - consecutive shots stack spread until it recovers
+#[table(accessor = player_weapon_spread)]
+#[derive(Clone, Debug)]
+pub struct PlayerWeaponSpread {
+    #[primary_key]
+    pub player_id: Identity,
+    pub item_name: String,      // Weapon the recoil was built up on (switching weapons resets it)
+    pub recoil_shots: f32,      // Consecutive shots still "in the recoil", decays over time
+    pub last_shot_at: Timestamp,
+    pub last_shot_pos_x: f32,   // Used to detect movement between shots
+    pub last_shot_pos_y: f32,
+}
+
+// Bow draw in progress - started when the player begins holding attack, consumed on release
+#[table(accessor = draw_state, public)]
+#[derive(Clone, Debug)]
+pub struct DrawState {
+    #[primary_key]
+    pub player_id: Identity,
+    pub weapon_instance_id: u64, // Draw only counts for the weapon it was started on
+    pub draw_started_at: Timestamp,
+}
+
 // Scheduled table for projectile updates
 #[table(accessor = projectile_update_schedule, scheduled(update_projectiles))]
 #[derive(Clone, Debug)]
@@ -253,7 +368,7 @@ struct PendingProjectileResolution {
     target_id: u64,
 }
 
-fn insert_projectile_with_runtime_state(ctx: &ReducerContext, projectile: Projectile) -> Projectile {
+pub(crate) fn insert_projectile_with_runtime_state(ctx: &ReducerContext, projectile: Projectile) -> Projectile {
     let inserted = ctx.db.projectile().insert(projectile);
     ctx.db.projectile_runtime_state().insert(ProjectileRuntimeState {
         projectile_id: inserted.id,
@@ -338,6 +453,8 @@ pub fn fire_npc_projectile(
         velocity_x,
         velocity_y,
         max_range: adjusted_max_range,
+        elevation: crate::building_floor::ELEVATION_GROUND, // NPCs always fight on the ground
+        damage_multiplier: 1.0,
     };
     
     let inserted = insert_projectile_with_runtime_state(ctx, projectile);
@@ -377,6 +494,55 @@ pub fn init_projectile_system(ctx: &ReducerContext) -> Result<(), String> {
     Ok(())
 }
 
+/// Starts drawing the equipped bow (client calls this when attack is pressed).
+/// Release power is computed server-side in fire_projectile from how long the draw was held.
+#[reducer]
+pub fn start_bow_draw(ctx: &ReducerContext) -> Result<(), String> {
+    let player_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&player_id)
+        .ok_or("Player not found")?;
+    if player.is_dead || player.is_knocked_out || player.is_sleeping || crate::restraints::is_restrained(ctx, player_id) {
+        return Err("Cannot draw a bow right now".to_string());
+    }
+
+    let equipment = ctx.db.active_equipment().player_identity().find(&player_id)
+        .ok_or("No active equipment record found for player.")?;
+    let weapon_instance_id = equipment.equipped_item_instance_id
+        .ok_or("No weapon equipped.")?;
+    let item_def = equipment.equipped_item_def_id
+        .and_then(|def_id| ctx.db.item_definition().id().find(def_id))
+        .ok_or("Equipped item definition not found.")?;
+
+    let draw_time_secs = ctx.db.ranged_weapon_stats().item_name().find(&item_def.name)
+        .map(|s| s.draw_time_secs)
+        .unwrap_or(0.0);
+    if draw_time_secs <= 0.0 {
+        return Err(format!("{} doesn't need to be drawn.", item_def.name));
+    }
+    if !equipment.is_ready_to_fire {
+        return Err("Nock an arrow before drawing. Right-click to load ammunition.".to_string());
+    }
+
+    let draw = DrawState {
+        player_id,
+        weapon_instance_id,
+        draw_started_at: ctx.timestamp,
+    };
+    if ctx.db.draw_state().player_id().find(&player_id).is_some() {
+        ctx.db.draw_state().player_id().update(draw);
+    } else {
+        ctx.db.draw_state().insert(draw);
+    }
+    Ok(())
+}
+
+/// Lets the bowstring down without firing (e.g. attack released while the shot was blocked)
+#[reducer]
+pub fn cancel_bow_draw(ctx: &ReducerContext) -> Result<(), String> {
+    ctx.db.draw_state().player_id().delete(&ctx.sender());
+    Ok(())
+}
+
 #[reducer]
 pub fn fire_projectile(
     ctx: &ReducerContext, 
@@ -682,12 +848,22 @@ pub fn fire_projectile(
     // Apply ammunition-specific speed modifications
     let mut v0 = weapon_stats.projectile_speed;
     let mut max_range = weapon_stats.weapon_range;
+
+    // Bow draw power: a partial draw fires a slower arrow that hits softer
+    let draw_charge = take_draw_charge(ctx, player_id, equipped_item_instance_id, weapon_stats.draw_time_secs);
+    let draw_damage_multiplier = MIN_DRAW_POWER + (1.0 - MIN_DRAW_POWER) * draw_charge;
+    if draw_charge < 1.0 {
+        v0 *= MIN_DRAW_SPEED_MULTIPLIER + (1.0 - MIN_DRAW_SPEED_MULTIPLIER) * draw_charge;
+        log::debug!("{} released at {:.0}% draw: speed {:.1}, damage x{:.2}", item_def.name, draw_charge * 100.0, v0, draw_damage_multiplier);
+    }
     
-    // Hollow Reed Arrows: +25% speed (lighter, more aerodynamic)
+    // Ammo type profile: e.g. Hollow Reed Arrows fly 25% faster and further, Fire Arrows are slower
     // Applies to both bows and crossbows - only the physics differ, not ammo compatibility
-    if ammo_item_def.name == "Hollow Reed Arrow" {
-        v0 *= 1.25; // 25% faster
-        log::debug!("Hollow Reed Arrow: Enhanced speed ({:.1}) for weapon '{}'", v0, item_def.name);
+    let ammo_profile = crate::ammunition::get_ammo_profile(&ammo_item_def.name);
+    if ammo_profile.speed_multiplier != 1.0 || ammo_profile.range_multiplier != 1.0 {
+        v0 *= ammo_profile.speed_multiplier;
+        max_range *= ammo_profile.range_multiplier;
+        log::debug!("{}: speed {:.1}, range {:.1} for weapon '{}'", ammo_item_def.name, v0, max_range, item_def.name);
     }
     
     let g = GRAVITY; // GRAVITY const defined at the top of the file
@@ -811,6 +987,17 @@ pub fn fire_projectile(
     }
     // --- End Physics Calculation ---
 
+    // --- Apply server-side spread/recoil deviation ---
+    let spread_rad = calculate_and_record_shot_spread(ctx, &player, &weapon_stats, spawn_x, spawn_y);
+    let (final_vx, final_vy) = if spread_rad > 0.0 {
+        let deviation = ctx.rng().gen_range(-spread_rad..=spread_rad);
+        let (sin_d, cos_d) = deviation.sin_cos();
+        log::debug!("{} spread: cone {:.2} deg, deviation {:.2} deg", item_def.name, spread_rad.to_degrees(), deviation.to_degrees());
+        (final_vx * cos_d - final_vy * sin_d, final_vx * sin_d + final_vy * cos_d)
+    } else {
+        (final_vx, final_vy)
+    };
+
 
     // Create projectile using validated spawn position
     let projectile = Projectile {
@@ -827,6 +1014,8 @@ pub fn fire_projectile(
         velocity_x: final_vx, // Use calculated velocity
         velocity_y: final_vy, // Use calculated velocity
         max_range: max_range, // Use modified max_range for ammunition-specific flight limit
+        elevation: player.elevation, // Shots only hit players on the shooter's floor
+        damage_multiplier: draw_damage_multiplier,
     };
 
     insert_projectile_with_runtime_state(ctx, projectile);
@@ -858,8 +1047,9 @@ pub fn fire_projectile(
     }
     
     // --- Reduce durability on the ranged weapon after successful fire ---
+    // Heavier or burning ammo wears the weapon faster (see ammunition profiles)
     if crate::durability::has_durability_system(&item_def) {
-        match crate::durability::reduce_durability_on_hit(ctx, equipped_item_instance_id) {
+        match crate::durability::reduce_durability_scaled(ctx, equipped_item_instance_id, ammo_profile.weapon_wear_multiplier) {
             Ok(item_broke) => {
                 if item_broke {
                     log::info!("[Projectile] Player {:?}'s {} broke after firing!", player_id, item_def.name);
@@ -883,15 +1073,9 @@ fn apply_projectile_bleed_effect(
     ammo_item_def: &crate::items::ItemDefinition, // Pass the ammo definition
     _current_time: Timestamp,
 ) -> Result<(), String> {
-    // Fire arrows should NOT cause bleed effects - they only cause burn effects
-    if ammo_item_def.name == "Fire Arrow" {
-        log::debug!("Fire Arrow does not cause bleed effects - skipping bleed application");
-        return Ok(());
-    }
-    
-    // Venom arrows/darts should NOT cause bleed effects - they apply venom instead
-    if ammo_item_def.name == "Venom Arrow" || ammo_item_def.name == "Venom Harpoon Dart" {
-        log::debug!("{} does not cause bleed effects - applies venom instead", ammo_item_def.name);
+    // Fire arrows burn and venom ammo poisons instead of causing bleed
+    if !crate::ammunition::get_ammo_profile(&ammo_item_def.name).applies_bleed {
+        log::debug!("{} does not cause bleed effects - skipping bleed application", ammo_item_def.name);
         return Ok(());
     }
 
@@ -949,8 +1133,8 @@ fn apply_projectile_burn_effect(
     ammo_item_def: &crate::items::ItemDefinition,
     current_time: Timestamp,
 ) -> Result<(), String> {
-    // Only apply burn effects to fire arrows
-    if ammo_item_def.name != "Fire Arrow" {
+    // Only apply burn effects to burning ammo (Fire Arrow)
+    if !crate::ammunition::get_ammo_profile(&ammo_item_def.name).applies_burn {
         return Ok(());
     }
 
@@ -1004,8 +1188,8 @@ fn apply_projectile_venom_effect(
     ammo_item_def: &crate::items::ItemDefinition,
     _current_time: Timestamp,
 ) -> Result<(), String> {
-    // Only apply venom effects to Venom Arrow and Venom Harpoon Dart
-    if ammo_item_def.name != "Venom Arrow" && ammo_item_def.name != "Venom Harpoon Dart" {
+    // Only apply venom effects to venom ammo (Venom Arrow, Venom Harpoon Dart)
+    if !crate::ammunition::get_ammo_profile(&ammo_item_def.name).applies_venom {
         return Ok(());
     }
 
@@ -1094,14 +1278,15 @@ fn calculate_projectile_damage(
         rng.gen_range(ammo_damage_min..=ammo_damage_max)
     };
 
-    if ammo_item_def.name == "Fire Arrow" {
-        ammo_damage
-    } else if ammo_item_def.name == "Hollow Reed Arrow" {
-        // Hollow Reed Arrows: Subtract ammo damage from weapon damage due to light construction
-        (weapon_damage - ammo_damage).max(1.0) // Minimum 1 damage
-    } else {
-        weapon_damage + ammo_damage
-    }
+    let damage = match crate::ammunition::get_ammo_profile(&ammo_item_def.name).damage_mode {
+        crate::ammunition::AmmoDamageMode::Replace => ammo_damage,
+        // Light ammo (Hollow Reed Arrow): subtract ammo damage from weapon damage
+        crate::ammunition::AmmoDamageMode::Subtract => (weapon_damage - ammo_damage).max(1.0), // Minimum 1 damage
+        crate::ammunition::AmmoDamageMode::Additive => weapon_damage + ammo_damage,
+    };
+
+    // Partially drawn bow shots hit softer
+    damage * projectile.damage_multiplier
 }
 // --- END NEW HELPER FUNCTION ---
 
@@ -1171,7 +1356,7 @@ fn create_fire_patch_if_fire_arrow(
     pos_y: f32,
     owner_id: Identity,
 ) -> bool {
-    if ammo_item_def.name != "Fire Arrow" {
+    if !crate::ammunition::get_ammo_profile(&ammo_item_def.name).creates_fire_patch {
         return false;
     }
 
@@ -1264,6 +1449,18 @@ fn create_fire_patch_if_fire_arrow(
 }
 // --- END HELPER FUNCTION ---
 
+/// First point along a projectile's step that enters a cliff tile (cliffs stop projectiles)
+fn check_projectile_cliff_collision(ctx: &ReducerContext, prev_x: f32, prev_y: f32, current_x: f32, current_y: f32) -> Option<(f32, f32)> {
+    let step_distance = ((current_x - prev_x).powi(2) + (current_y - prev_y).powi(2)).sqrt();
+    let samples = (step_distance / (crate::TILE_SIZE_PX as f32 / 2.0)).ceil().max(1.0) as i32;
+    (1..=samples)
+        .map(|i| {
+            let t = i as f32 / samples as f32;
+            (prev_x + (current_x - prev_x) * t, prev_y + (current_y - prev_y) * t)
+        })
+        .find(|(x, y)| crate::is_position_on_cliff(ctx, *x, *y))
+}
+
 fn consume_projectile_on_impact(
     ctx: &ReducerContext,
     projectile: &Projectile,
@@ -1324,6 +1521,8 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
         } else if let Some(weapon_def) = weapon_item_def_cached.as_ref() {
             if weapon_def.name == "Crossbow" || weapon_def.name == "Hunting Bow" {
                 PROJECTILE_STRAIGHT_LINE_GRAVITY_MULTIPLIER
+            } else if crate::throwables::get_throwable_kind(&weapon_def.name).is_some() {
+                PROJECTILE_STRAIGHT_LINE_GRAVITY_MULTIPLIER // Thrown items fly straight to where they were aimed
             } else if weapon_def.name == "Makarov PM" || weapon_def.name == "PP-91 KEDR" {
                 PROJECTILE_FIREARM_GRAVITY_MULTIPLIER
             } else {
@@ -1375,6 +1574,26 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
             continue;
         }
         
+        // Cliffs stop every projectile, turret rounds included - it's terrain, not a structure
+        if let Some((collision_x, collision_y)) = check_projectile_cliff_collision(ctx, prev_x, prev_y, current_x, current_y) {
+            log::debug!("[ProjectileUpdate] Projectile {} hit a cliff at ({:.1}, {:.1})", projectile.id, collision_x, collision_y);
+            if let Some(ammo_item_def) = ammo_item_def_cached.as_ref() {
+                create_fire_patch_if_fire_arrow(ctx, &ammo_item_def, collision_x, collision_y, projectile.owner_id);
+            }
+            queue_projectile_resolution(
+                &mut pending_resolutions,
+                &projectile,
+                collision_x,
+                collision_y,
+                ProjectileResolvedReason::Impact,
+                ProjectileResolvedTargetKind::World,
+                0,
+            );
+            missed_projectiles_for_drops.push((projectile.id, projectile.ammo_def_id, collision_x, collision_y));
+            projectiles_to_delete.push(projectile.id);
+            continue;
+        }
+        
         // Monument turret projectiles pass through structures (doors, fences, walls, shelters)
         // to ensure they reach their targeted animals/players
         if projectile.source_type != PROJECTILE_SOURCE_MONUMENT_TURRET {
@@ -1392,13 +1611,7 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
                     let final_damage = calculate_projectile_damage(&weapon_item_def, &ammo_item_def, &projectile, &mut rng);
                     
                     if final_damage > 0.0 {
-                        match crate::door::damage_door(
-                            ctx,
-                            projectile.owner_id,
-                            door_id,
-                            final_damage,
-                            current_time,
-                        ) {
+                        match apply_structure_damage(ctx, StructureRef::Door(door_id), final_damage, StructureDamageType::Weapon, Some(projectile.owner_id)) {
                             Ok(_) => {
                                 log::info!(
                                     "[ProjectileUpdate] Projectile {} (weapon: {} + ammo: {}) dealt {:.1} damage to Door {}",
@@ -1447,13 +1660,7 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
                     let final_damage = calculate_projectile_damage(&weapon_item_def, &ammo_item_def, &projectile, &mut rng);
                     
                     if final_damage > 0.0 {
-                        match crate::fence::damage_fence(
-                            ctx,
-                            projectile.owner_id,
-                            fence_id,
-                            final_damage,
-                            current_time,
-                        ) {
+                        match apply_structure_damage(ctx, StructureRef::Fence(fence_id), final_damage, StructureDamageType::Weapon, Some(projectile.owner_id)) {
                             Ok(_) => {
                                 log::info!(
                                     "[ProjectileUpdate] Projectile {} (weapon: {} + ammo: {}) dealt {:.1} damage to Fence {}",
@@ -1508,13 +1715,7 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
                     let final_damage = calculate_projectile_damage(&weapon_item_def, &ammo_item_def, &projectile, &mut rng);
 
                     if final_damage > 0.0 {
-                        match crate::building::damage_wall(
-                            ctx,
-                            projectile.owner_id,
-                            wall_id,
-                            final_damage,
-                            current_time,
-                        ) {
+                        match apply_structure_damage(ctx, StructureRef::Wall(wall_id), final_damage, StructureDamageType::Weapon, Some(projectile.owner_id)) {
                             Ok(_) => {
                                 log::info!(
                                     "[ProjectileUpdate] Projectile {} (weapon: {} + ammo: {}) dealt {:.1} damage to Wall {}",
@@ -1624,21 +1825,12 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
                         let final_damage = calculate_projectile_damage(&weapon_item_def, &ammo_item_def, &projectile, &mut rng);
 
                     if final_damage > 0.0 {
-                        match crate::shelter::damage_shelter(
-                            ctx, 
-                            projectile.owner_id, 
-                            shelter_id, 
-                            final_damage, 
-                            current_time, 
-                            &mut rng
-                        ) {
-                            Ok(attack_result) => {
-                                if attack_result.hit {
-                                    log::info!(
-                                        "[ProjectileUpdate] Projectile {} (weapon: {} + ammo: {}) dealt {:.1} damage to Shelter {}",
-                                        projectile.id, weapon_item_def.name, ammo_item_def.name, final_damage, shelter_id
-                                    );
-                                }
+                        match apply_structure_damage(ctx, StructureRef::Shelter(shelter_id), final_damage, StructureDamageType::Weapon, Some(projectile.owner_id)) {
+                            Ok(_) => {
+                                log::info!(
+                                    "[ProjectileUpdate] Projectile {} (weapon: {} + ammo: {}) dealt {:.1} damage to Shelter {}",
+                                    projectile.id, weapon_item_def.name, ammo_item_def.name, final_damage, shelter_id
+                                );
                             }
                             Err(e) => {
                                 log::error!(
@@ -2533,14 +2725,12 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
                             let final_damage = calculate_projectile_damage(&weapon_item_def, &ammo_item_def, &projectile, &mut rng);
 
                             if final_damage > 0.0 {
-                                match combat::damage_lantern(ctx, projectile.owner_id, lantern.id, final_damage, current_time, &mut rng) {
-                                    Ok(attack_result) => {
-                                        if attack_result.hit {
-                                            log::info!(
-                                                "[ProjectileUpdate] Projectile {} dealt {:.1} damage to Lantern {}",
-                                                projectile.id, final_damage, lantern.id
-                                            );
-                                        }
+                                match apply_structure_damage(ctx, StructureRef::Lantern(lantern.id), final_damage, StructureDamageType::Weapon, Some(projectile.owner_id)) {
+                                    Ok(_) => {
+                                        log::info!(
+                                            "[ProjectileUpdate] Projectile {} dealt {:.1} damage to Lantern {}",
+                                            projectile.id, final_damage, lantern.id
+                                        );
                                     }
                                     Err(e) => {
                                         log::error!(
@@ -2593,7 +2783,7 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
                             let final_damage = calculate_projectile_damage(&weapon_item_def, &ammo_item_def, &projectile, &mut rng);
 
                             if final_damage > 0.0 {
-                                match crate::homestead_hearth::damage_hearth(ctx, projectile.owner_id, hearth.id, final_damage, current_time) {
+                                match apply_structure_damage(ctx, StructureRef::Hearth(hearth.id), final_damage, StructureDamageType::Weapon, Some(projectile.owner_id)) {
                                     Ok(_) => {
                                         log::info!(
                                             "[ProjectileUpdate] Projectile {} dealt {:.1} damage to Homestead Hearth {}",
@@ -2872,7 +3062,7 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
         // This prevents self-collision and friendly fire issues
         if projectile.source_type != PROJECTILE_SOURCE_NPC {
         'wild_animal_chunks: for chunk_idx in &chunk_indices[..chunk_count] {
-        for wild_animal in ctx.db.wild_animal().chunk_index().filter(*chunk_idx) {
+        for wild_animal in crate::wild_animal_npc::transform_batch::live_animals_in_chunk(ctx, *chunk_idx) {
             // Skip dead animals or animals that are burrowed
             if wild_animal.health <= 0.0 || wild_animal.state == crate::wild_animal_npc::AnimalState::Burrowed {
                 continue;
@@ -3049,6 +3239,9 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
             if player_to_check.identity == projectile.owner_id || player_to_check.is_dead {
                 continue; // Skip self and dead players (corpses handled above)
             }
+            if player_to_check.elevation != projectile.elevation {
+                continue; // Different building level - the floor between them stops the shot
+            }
             
             // Use line segment collision detection for players
             // NPC projectiles use a larger collision radius for reliable hits:
@@ -3092,22 +3285,24 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
                     // Get damage from encoded ammo_def_id
                     let npc_damage = get_npc_projectile_damage(&projectile);
                     
+                    // Determine the attacker (and death cause) from projectile type
+                    let npc_attacker_name = match projectile.npc_projectile_type {
+                        NPC_PROJECTILE_SPECTRAL_SHARD => "The Shardkin",
+                        NPC_PROJECTILE_SPECTRAL_BOLT => "The Shorebound", 
+                        NPC_PROJECTILE_VENOM_SPITTLE => "Cable Viper",
+                        _ => "Unknown Creature",
+                    };
+                    
                     // Apply damage directly (NPC projectiles bypass armor for simplicity)
                     if let Some(mut target_player) = ctx.db.player().identity().find(&player_to_check.identity) {
                         if !target_player.is_dead {
+                            let health_before = target_player.health;
                             target_player.health = (target_player.health - npc_damage).max(0.0);
                             target_player.last_hit_time = Some(current_time);
+                            crate::kill_feed::record_damage_event(ctx, target_player.identity, None, npc_attacker_name, None, health_before - target_player.health, target_player.health);
                             
                             // Check for death - use centralized death handler
                             if target_player.health <= 0.0 {
-                                // Determine death cause from projectile type
-                                let death_cause = match projectile.npc_projectile_type {
-                                    NPC_PROJECTILE_SPECTRAL_SHARD => "The Shardkin",
-                                    NPC_PROJECTILE_SPECTRAL_BOLT => "The Shorebound", 
-                                    NPC_PROJECTILE_VENOM_SPITTLE => "Cable Viper",
-                                    _ => "Unknown Creature",
-                                };
-                                
                                 // Update health first (handle_player_death will set is_dead)
                                 ctx.db.player().identity().update(target_player);
                                 
@@ -3115,7 +3310,7 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
                                 if let Err(e) = crate::player_corpse::handle_player_death(
                                     ctx,
                                     player_to_check.identity,
-                                    death_cause,
+                                    npc_attacker_name,
                                     None, // No killer identity for NPC kills
                                 ) {
                                     log::error!("[NPC Projectile] Death handling failed for player {:?}: {}", player_to_check.identity, e);
@@ -3190,8 +3385,10 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
                     
                     // Apply massive damage directly - will kill any player
                     if let Some(mut target_player) = ctx.db.player().identity().find(&player_to_check.identity) {
+                        let health_before = target_player.health;
                         target_player.health = (target_player.health - final_damage).max(0.0);
                         target_player.last_hit_time = Some(current_time);
+                        crate::kill_feed::record_damage_event(ctx, target_player.identity, None, "Monument Defense Turret", None, health_before - target_player.health, target_player.health);
                         
                         if target_player.health <= 0.0 {
                             ctx.db.player().identity().update(target_player);
@@ -3421,6 +3618,17 @@ pub fn update_projectiles(ctx: &ReducerContext, _args: ProjectileUpdateSchedule)
             .map(|def| def.name.clone())
             .unwrap_or_else(|| format!("Unknown (ID: {})", ammo_def_id));
         
+        // Throwables apply their area effect where they land (smoke, flash, stone distraction)
+        if let Some(kind) = ammo_item_def.as_ref().and_then(|def| crate::throwables::get_throwable_kind(&def.name)) {
+            let thrower_id = ctx.db.projectile().id().find(&projectile_id)
+                .map(|p| p.owner_id)
+                .unwrap_or(ctx.identity());
+            crate::throwables::resolve_throwable_impact(ctx, kind, thrower_id, pos_x, pos_y);
+            if !kind.drops_on_impact() {
+                continue; // Spent on impact - no dropped item
+            }
+        }
+        
         // Check if this is a bullet (bullets always break on impact, never become dropped items)
         let is_bullet = ammo_item_def
             .as_ref()
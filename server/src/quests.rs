@@ -5,6 +5,7 @@
  * Handles:                                                                   *
  * - Tutorial quests (sequential, one-time, teaches game basics)              *
  * - Daily quests (random pool, resets daily, rewards XP + shards)            *
+ * - NPC quest givers at monuments (daily rotating fetch/kill/deliver chains) *
  * - Quest progress tracking                                                  *
  * - SOVA announcements for quest events                                      *
  *                                                                            *
@@ -560,6 +561,9 @@ pub fn track_quest_progress(
     // Track daily quest progress
     track_daily_progress(ctx, player_id, &objective_type, target_id, amount)?;
     
+    // Track NPC quest chain progress (kill steps)
+    track_npc_chain_progress(ctx, player_id, &objective_type, target_id, amount)?;
+    
     Ok(())
 }
 
@@ -1097,6 +1101,8 @@ pub fn init_quest_system(ctx: &ReducerContext) -> Result<(), String> {
     
     seed_tutorial_quests(ctx)?;
     seed_daily_quests(ctx)?;
+    seed_npc_quest_chains(ctx)?;
+    init_npc_quest_rotation_schedule(ctx)?;
     
     log::info!("[Quests] Quest system initialized");
     Ok(())
@@ -2041,3 +2047,623 @@ fn seed_daily_quests(ctx: &ReducerContext) -> Result<(), String> {
     log::info!("[Quests] Seeded {} daily quests", quest_count);
     Ok(())
 }
+
+// ============================================================================
+// NPC QUEST GIVERS - Daily rotating quest chains at monuments
+// ============================================================================
+
+/// Interaction range for talking to a quest giver
+pub const NPC_QUEST_GIVER_INTERACTION_DISTANCE: f32 = 150.0;
+pub const NPC_QUEST_GIVER_INTERACTION_DISTANCE_SQUARED: f32 =
+    NPC_QUEST_GIVER_INTERACTION_DISTANCE * NPC_QUEST_GIVER_INTERACTION_DISTANCE;
+
+/// How often the rotation schedule checks for a new world day
+pub const NPC_QUEST_ROTATION_INTERVAL_SECS: u64 = 300; // 5 minutes
+
+/// Quest giver keys (stable IDs used by chain definitions and delivery steps)
+pub const NPC_KEY_FISHING_VILLAGE: &str = "fishing_village_elder";
+pub const NPC_KEY_ALK_COMPOUND: &str = "alk_quartermaster";
+
+/// Item handed out for delivery steps - consumed when turned in at the target NPC
+pub const NPC_DELIVERY_ITEM_NAME: &str = "Sealed Parcel";
+
+// How far from the monument center the NPC stands (keeps them clear of monument collision)
+const NPC_FISHING_VILLAGE_OFFSET_Y: f32 = 120.0;
+const NPC_ALK_COMPOUND_OFFSET_Y: f32 = 220.0;
+
+/// Step type within an NPC quest chain
+#[derive(SpacetimeType, Clone, Debug, PartialEq, Eq)]
+pub enum NpcQuestStepType {
+    Fetch,   // Bring target_amount of item target_id to the quest giver (consumed on turn-in)
+    Kill,    // Kill target_amount of animal species target_id (AnimalSpecies debug name)
+    Deliver, // Carry a Sealed Parcel to the quest giver with npc_key target_id
+}
+
+/// Single step in a quest chain
+#[derive(SpacetimeType, Clone, Debug)]
+pub struct NpcQuestStep {
+    pub step_type: NpcQuestStepType,
+    pub target_id: String,
+    pub target_amount: u32,
+    pub description: String,
+}
+
+/// Stationary quest giver NPC placed at a monument
+#[spacetimedb::table(accessor = quest_giver_npc, public)]
+#[derive(Clone, Debug)]
+pub struct QuestGiverNpc {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub npc_key: String,          // e.g., "fishing_village_elder"
+    pub name: String,             // Display name
+    pub greeting: String,         // Shown when there's nothing left to do today
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub chunk_index: u32,
+}
+
+/// NPC Quest Chain Definition - pool of chains offered by each quest giver
+#[spacetimedb::table(accessor = npc_quest_chain_definition, public)]
+#[derive(Clone, Debug)]
+pub struct NpcQuestChainDefinition {
+    #[primary_key]
+    pub id: String,               // e.g., "fv_smoked_catch"
+    pub npc_key: String,          // Which quest giver offers this chain
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<NpcQuestStep>,
+    pub xp_reward: u64,
+    pub shard_reward: u64,
+    pub reward_item_name: Option<String>,
+    pub reward_item_quantity: u32,
+}
+
+/// Player NPC Quest Chain - today's chain from one quest giver
+#[spacetimedb::table(
+    accessor = player_npc_quest_chain,
+    public,
+    index(accessor = idx_npc_chain_player, name = "idx_npc_chain_player", btree(columns = [player_id]))
+)]
+#[derive(Clone, Debug)]
+pub struct PlayerNpcQuestChain {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub player_id: Identity,
+    pub npc_key: String,
+    pub chain_def_id: String,
+    pub assigned_day: u32,            // World day when assigned
+    pub current_step: u32,            // Index into the chain's steps
+    pub step_progress: u32,           // Progress toward the current step (kill steps)
+    pub status: QuestStatus,
+    pub completed_at: Option<Timestamp>,
+}
+
+/// Schedule that rotates NPC quest chains when the world day changes
+#[spacetimedb::table(accessor = npc_quest_rotation_schedule, scheduled(rotate_npc_quest_chains))]
+#[derive(Clone, Debug)]
+pub struct NpcQuestRotationSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: spacetimedb::ScheduleAt,
+}
+
+/// Current world day (cycle_count = number of full day cycles)
+fn current_world_day(ctx: &ReducerContext) -> u32 {
+    ctx.db.world_state().iter().next()
+        .map(|ws| ws.cycle_count)
+        .unwrap_or(0)
+}
+
+/// Sends a line of NPC dialogue to the player's chat
+fn send_npc_dialogue(ctx: &ReducerContext, player_id: Identity, npc: &QuestGiverNpc, message: &str) {
+    send_sova_quest_message(ctx, player_id, &format!("{}: {}", npc.name, message), "npc_dialogue", None);
+}
+
+/// Removes up to `amount` of an item (by name) from the player's inventory and hotbar
+fn consume_player_item_by_name(ctx: &ReducerContext, player_id: Identity, item_name: &str, amount: u32) -> Result<(), String> {
+    let item_def_id = ctx.db.item_definition().iter()
+        .find(|d| d.name == item_name)
+        .map(|d| d.id)
+        .ok_or_else(|| format!("{} item definition not found", item_name))?;
+
+    let inventory = ctx.db.inventory_item();
+    let items: Vec<_> = inventory.iter()
+        .filter(|item| {
+            let is_owned = match &item.location {
+                ItemLocation::Inventory(data) => data.owner_id == player_id,
+                ItemLocation::Hotbar(data) => data.owner_id == player_id,
+                _ => false,
+            };
+            is_owned && item.item_def_id == item_def_id
+        })
+        .collect();
+
+    let available: u32 = items.iter().map(|item| item.quantity).sum();
+    if available < amount {
+        return Err(format!("Not enough {}. Required: {}, Available: {}", item_name, amount, available));
+    }
+
+    let mut remaining = amount;
+    for item in items {
+        if remaining == 0 {
+            break;
+        }
+        let take = remaining.min(item.quantity);
+        remaining -= take;
+        if take == item.quantity {
+            inventory.instance_id().delete(item.instance_id);
+        } else {
+            let mut updated = item.clone();
+            updated.quantity -= take;
+            inventory.instance_id().update(updated);
+        }
+    }
+    Ok(())
+}
+
+/// Assign today's quest chain from every quest giver (idempotent per world day)
+pub fn assign_npc_quest_chains(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
+    let chain_table = ctx.db.player_npc_quest_chain();
+    let world_day = current_world_day(ctx);
+
+    let player_chains: Vec<PlayerNpcQuestChain> = chain_table.idx_npc_chain_player()
+        .filter(&player_id)
+        .collect();
+
+    if player_chains.iter().any(|c| c.assigned_day == world_day) {
+        return Ok(()); // Already has today's chains
+    }
+
+    // Clear previous days' chains (unfinished chains expire with the day)
+    for old_chain in player_chains {
+        chain_table.id().delete(old_chain.id);
+    }
+
+    let chain_defs: Vec<NpcQuestChainDefinition> = ctx.db.npc_quest_chain_definition().iter().collect();
+    let npcs: Vec<QuestGiverNpc> = ctx.db.quest_giver_npc().iter().collect();
+
+    let mut assigned_count = 0;
+    for npc in npcs {
+        let offered: Vec<&NpcQuestChainDefinition> = chain_defs.iter()
+            .filter(|def| def.npc_key == npc.npc_key)
+            .collect();
+        if offered.is_empty() {
+            continue;
+        }
+
+        let def = offered[ctx.rng().gen_range(0..offered.len())];
+        chain_table.insert(PlayerNpcQuestChain {
+            id: 0,
+            player_id,
+            npc_key: npc.npc_key.clone(),
+            chain_def_id: def.id.clone(),
+            assigned_day: world_day,
+            current_step: 0,
+            step_progress: 0,
+            status: QuestStatus::Available,
+            completed_at: None,
+        });
+        assigned_count += 1;
+    }
+
+    if assigned_count > 0 {
+        log::info!("[Quests] Assigned {} NPC quest chains to player {:?} for day {}", assigned_count, player_id, world_day);
+    }
+    Ok(())
+}
+
+/// Sets up the current step of a chain (hands out the parcel for delivery steps)
+fn activate_npc_chain_step(ctx: &ReducerContext, player_id: Identity, step: &NpcQuestStep) -> Result<(), String> {
+    if step.step_type == NpcQuestStepType::Deliver {
+        let parcel_def_id = ctx.db.item_definition().iter()
+            .find(|d| d.name == NPC_DELIVERY_ITEM_NAME)
+            .map(|d| d.id)
+            .ok_or_else(|| format!("{} item definition not found", NPC_DELIVERY_ITEM_NAME))?;
+        crate::dropped_item::give_item_to_player_or_drop(ctx, player_id, parcel_def_id, 1)?;
+    }
+    Ok(())
+}
+
+/// Moves a chain to its next step, completing it and awarding rewards after the last one.
+/// Returns the message to show the player.
+fn advance_npc_chain(
+    ctx: &ReducerContext,
+    chain: &mut PlayerNpcQuestChain,
+    def: &NpcQuestChainDefinition,
+) -> Result<String, String> {
+    chain.current_step += 1;
+    chain.step_progress = 0;
+
+    if let Some(next_step) = def.steps.get(chain.current_step as usize) {
+        activate_npc_chain_step(ctx, chain.player_id, next_step)?;
+        return Ok(next_step.description.clone());
+    }
+
+    // Chain complete
+    chain.status = QuestStatus::Completed;
+    chain.completed_at = Some(ctx.timestamp);
+
+    award_quest_rewards(ctx, chain.player_id, def.xp_reward, def.shard_reward)?;
+    if let Some(item_name) = &def.reward_item_name {
+        match ctx.db.item_definition().iter().find(|d| &d.name == item_name) {
+            Some(item_def) => {
+                crate::dropped_item::give_item_to_player_or_drop(ctx, chain.player_id, item_def.id, def.reward_item_quantity)?;
+            }
+            None => log::error!("[Quests] Reward item '{}' for chain {} not found", item_name, def.id),
+        }
+    }
+
+    ctx.db.quest_completion_notification().insert(QuestCompletionNotification {
+        id: 0,
+        player_id: chain.player_id,
+        quest_name: def.name.clone(),
+        quest_type: "npc_chain".to_string(),
+        xp_awarded: def.xp_reward,
+        shards_awarded: def.shard_reward,
+        unlocked_recipe: None,
+        completed_at: ctx.timestamp,
+    });
+
+    log::info!("[Quests] Player {:?} completed NPC quest chain: {}", chain.player_id, def.name);
+    Ok("Good work. That's everything for today - come back tomorrow.".to_string())
+}
+
+/// Track kill progress for active NPC quest chains (called from track_quest_progress)
+fn track_npc_chain_progress(
+    ctx: &ReducerContext,
+    player_id: Identity,
+    objective_type: &QuestObjectiveType,
+    target_id: Option<&str>,
+    amount: u32,
+) -> Result<(), String> {
+    if *objective_type != QuestObjectiveType::KillSpecificAnimal {
+        return Ok(());
+    }
+    let Some(species) = target_id else {
+        return Ok(());
+    };
+
+    let chain_table = ctx.db.player_npc_quest_chain();
+    let world_day = current_world_day(ctx);
+    let active_chains: Vec<PlayerNpcQuestChain> = chain_table.idx_npc_chain_player()
+        .filter(&player_id)
+        .filter(|c| c.assigned_day == world_day && c.status == QuestStatus::InProgress)
+        .collect();
+
+    for mut chain in active_chains {
+        let Some(def) = ctx.db.npc_quest_chain_definition().id().find(&chain.chain_def_id) else {
+            continue;
+        };
+        let Some(step) = def.steps.get(chain.current_step as usize) else {
+            continue;
+        };
+        if step.step_type != NpcQuestStepType::Kill || step.target_id != species {
+            continue;
+        }
+
+        chain.step_progress += amount;
+        if chain.step_progress >= step.target_amount {
+            let next_message = advance_npc_chain(ctx, &mut chain, &def)?;
+            send_sova_quest_message(ctx, player_id, &format!("{}: {}", def.name, next_message), "npc_quest_progress", None);
+        }
+        chain_table.id().update(chain);
+    }
+    Ok(())
+}
+
+/// Spawn quest givers at the fishing village and ALK central compound (idempotent)
+/// Called from init_module after world generation and ALK initialization
+pub fn spawn_quest_giver_npcs(ctx: &ReducerContext) {
+    let npc_table = ctx.db.quest_giver_npc();
+    let has_npc = |key: &str| npc_table.iter().any(|npc| npc.npc_key == key);
+
+    if !has_npc(NPC_KEY_FISHING_VILLAGE) {
+        use crate::monument_part as MonumentPartTableTrait;
+        let village_center = ctx.db.monument_part().iter()
+            .filter(|part| part.monument_type == crate::MonumentType::FishingVillage)
+            .max_by_key(|part| part.is_center)
+            .map(|part| (part.world_x, part.world_y));
+
+        match village_center {
+            Some((x, y)) => {
+                let pos_y = y + NPC_FISHING_VILLAGE_OFFSET_Y;
+                npc_table.insert(QuestGiverNpc {
+                    id: 0,
+                    npc_key: NPC_KEY_FISHING_VILLAGE.to_string(),
+                    name: "Village Elder Anuk".to_string(),
+                    greeting: "The sea gives what it gives. Nothing more for you today.".to_string(),
+                    pos_x: x,
+                    pos_y,
+                    chunk_index: crate::environment::calculate_chunk_index(x, pos_y),
+                });
+                log::info!("[Quests] Spawned fishing village quest giver at ({:.1}, {:.1})", x, pos_y);
+            }
+            None => log::warn!("[Quests] No fishing village found - skipping quest giver spawn"),
+        }
+    }
+
+    if !has_npc(NPC_KEY_ALK_COMPOUND) {
+        use crate::alk::alk_station as AlkStationTableTrait;
+        match ctx.db.alk_station().station_id().find(&0) {
+            Some(compound) => {
+                let pos_y = compound.world_pos_y + NPC_ALK_COMPOUND_OFFSET_Y;
+                npc_table.insert(QuestGiverNpc {
+                    id: 0,
+                    npc_key: NPC_KEY_ALK_COMPOUND.to_string(),
+                    name: "Quartermaster Ilse".to_string(),
+                    greeting: "Requisitions are filled for the day. Report back tomorrow.".to_string(),
+                    pos_x: compound.world_pos_x,
+                    pos_y,
+                    chunk_index: crate::environment::calculate_chunk_index(compound.world_pos_x, pos_y),
+                });
+                log::info!("[Quests] Spawned ALK compound quest giver at ({:.1}, {:.1})", compound.world_pos_x, pos_y);
+            }
+            None => log::warn!("[Quests] No ALK central compound found - skipping quest giver spawn"),
+        }
+    }
+}
+
+/// Initialize the NPC quest rotation schedule
+pub fn init_npc_quest_rotation_schedule(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.npc_quest_rotation_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("Starting NPC quest rotation schedule (every {}s).", NPC_QUEST_ROTATION_INTERVAL_SECS);
+        let interval = std::time::Duration::from_secs(NPC_QUEST_ROTATION_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            NpcQuestRotationSchedule {
+                id: 0,
+                scheduled_at: spacetimedb::ScheduleAt::Interval(spacetimedb::TimeDuration::from(interval)),
+            },
+            "NPC quest rotation"
+        );
+    }
+    Ok(())
+}
+
+/// Rotates every online player's NPC quest chains when the world day changes
+#[spacetimedb::reducer]
+pub fn rotate_npc_quest_chains(ctx: &ReducerContext, _args: NpcQuestRotationSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("NPC quest rotation can only be run by scheduler".to_string());
+    }
+
+    let online_players: Vec<Identity> = ctx.db.player().iter()
+        .filter(|p| p.is_online)
+        .map(|p| p.identity)
+        .collect();
+
+    for player_id in online_players {
+        if let Err(e) = assign_npc_quest_chains(ctx, player_id) {
+            log::error!("[Quests] Failed to rotate NPC quest chains for {:?}: {}", player_id, e);
+        }
+    }
+    Ok(())
+}
+
+/// Talk to a quest giver: accepts today's chain, turns in fetch/delivery steps, or reports progress
+#[spacetimedb::reducer]
+pub fn talk_to_npc(ctx: &ReducerContext, npc_id: u64) -> Result<(), String> {
+    let player_id = ctx.sender();
+
+    let player = ctx.db.player().identity().find(&player_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    if player.is_dead || player.is_knocked_out {
+        return Err("Cannot talk right now.".to_string());
+    }
+
+    let npc = ctx.db.quest_giver_npc().id().find(&npc_id)
+        .ok_or_else(|| format!("Quest giver {} not found", npc_id))?;
+
+    let dx = player.position_x - npc.pos_x;
+    let dy = player.position_y - npc.pos_y;
+    if dx * dx + dy * dy > NPC_QUEST_GIVER_INTERACTION_DISTANCE_SQUARED {
+        return Err("Too far away to talk.".to_string());
+    }
+
+    assign_npc_quest_chains(ctx, player_id)?;
+
+    let chain_table = ctx.db.player_npc_quest_chain();
+    let world_day = current_world_day(ctx);
+    let todays_chains: Vec<PlayerNpcQuestChain> = chain_table.idx_npc_chain_player()
+        .filter(&player_id)
+        .filter(|c| c.assigned_day == world_day)
+        .collect();
+
+    // Hand in parcels addressed to this NPC from other quest givers' chains
+    for mut chain in todays_chains.iter().filter(|c| c.status == QuestStatus::InProgress && c.npc_key != npc.npc_key).cloned() {
+        let Some(def) = ctx.db.npc_quest_chain_definition().id().find(&chain.chain_def_id) else {
+            continue;
+        };
+        let Some(step) = def.steps.get(chain.current_step as usize) else {
+            continue;
+        };
+        if step.step_type != NpcQuestStepType::Deliver || step.target_id != npc.npc_key {
+            continue;
+        }
+        if count_player_item_by_name(ctx, player_id, NPC_DELIVERY_ITEM_NAME) == 0 {
+            send_npc_dialogue(ctx, player_id, &npc, "You were supposed to bring me a parcel. Where is it?");
+            continue;
+        }
+
+        consume_player_item_by_name(ctx, player_id, NPC_DELIVERY_ITEM_NAME, 1)?;
+        let next_message = advance_npc_chain(ctx, &mut chain, &def)?;
+        send_npc_dialogue(ctx, player_id, &npc, &format!("Parcel received. ({}) {}", def.name, next_message));
+        chain_table.id().update(chain);
+    }
+
+    // This NPC's own chain
+    let Some(mut chain) = todays_chains.into_iter().find(|c| c.npc_key == npc.npc_key) else {
+        send_npc_dialogue(ctx, player_id, &npc, &npc.greeting);
+        return Ok(());
+    };
+    // Re-read in case a delivery above changed it
+    if let Some(fresh) = chain_table.id().find(&chain.id) {
+        chain = fresh;
+    }
+
+    let def = ctx.db.npc_quest_chain_definition().id().find(&chain.chain_def_id)
+        .ok_or_else(|| format!("Quest chain {} not found", chain.chain_def_id))?;
+
+    match chain.status {
+        QuestStatus::Available => {
+            chain.status = QuestStatus::InProgress;
+            let first_step = def.steps.first()
+                .ok_or_else(|| format!("Quest chain {} has no steps", def.id))?;
+            activate_npc_chain_step(ctx, player_id, first_step)?;
+            send_npc_dialogue(ctx, player_id, &npc, &format!("{} {}", def.description, first_step.description));
+        }
+        QuestStatus::InProgress => {
+            let step = def.steps.get(chain.current_step as usize)
+                .ok_or_else(|| format!("Quest chain {} step {} missing", def.id, chain.current_step))?;
+            match step.step_type {
+                NpcQuestStepType::Fetch => {
+                    let have = count_player_item_by_name(ctx, player_id, &step.target_id);
+                    if have >= step.target_amount {
+                        consume_player_item_by_name(ctx, player_id, &step.target_id, step.target_amount)?;
+                        let next_message = advance_npc_chain(ctx, &mut chain, &def)?;
+                        send_npc_dialogue(ctx, player_id, &npc, &next_message);
+                    } else {
+                        send_npc_dialogue(ctx, player_id, &npc, &format!(
+                            "{} ({}/{} {})", step.description, have, step.target_amount, step.target_id
+                        ));
+                    }
+                }
+                NpcQuestStepType::Kill => {
+                    send_npc_dialogue(ctx, player_id, &npc, &format!(
+                        "{} ({}/{})", step.description, chain.step_progress, step.target_amount
+                    ));
+                }
+                NpcQuestStepType::Deliver => {
+                    send_npc_dialogue(ctx, player_id, &npc, &step.description);
+                }
+            }
+        }
+        _ => {
+            send_npc_dialogue(ctx, player_id, &npc, &npc.greeting);
+        }
+    }
+
+    chain_table.id().update(chain);
+    Ok(())
+}
+
+/// Seed NPC quest chains (upserts so chain edits apply on republish)
+fn seed_npc_quest_chains(ctx: &ReducerContext) -> Result<(), String> {
+    let table = ctx.db.npc_quest_chain_definition();
+
+    let step = |step_type: NpcQuestStepType, target_id: &str, target_amount: u32, description: &str| NpcQuestStep {
+        step_type,
+        target_id: target_id.to_string(),
+        target_amount,
+        description: description.to_string(),
+    };
+
+    let chains = vec![
+        // ===== FISHING VILLAGE ELDER =====
+        NpcQuestChainDefinition {
+            id: "fv_smokehouse_stock".to_string(),
+            npc_key: NPC_KEY_FISHING_VILLAGE.to_string(),
+            name: "Smokehouse Stock".to_string(),
+            description: "The smokehouse is running low before the storms.".to_string(),
+            steps: vec![
+                step(NpcQuestStepType::Fetch, "Wood", 200, "Bring me 200 wood for the smoke fires."),
+                step(NpcQuestStepType::Fetch, "Plant Fiber", 50, "Now 50 plant fiber to bind the drying racks."),
+                step(NpcQuestStepType::Deliver, NPC_KEY_ALK_COMPOUND, 1, "Take this parcel of smoked fish to the ALK quartermaster."),
+            ],
+            xp_reward: 150,
+            shard_reward: 90,
+            reward_item_name: Some("Rope".to_string()),
+            reward_item_quantity: 2,
+        },
+        NpcQuestChainDefinition {
+            id: "fv_crab_cull".to_string(),
+            npc_key: NPC_KEY_FISHING_VILLAGE.to_string(),
+            name: "Crab Cull".to_string(),
+            description: "Crabs are shredding our nets on the beach.".to_string(),
+            steps: vec![
+                step(NpcQuestStepType::Kill, "BeachCrab", 5, "Thin out 5 beach crabs along the shore."),
+                step(NpcQuestStepType::Fetch, "Stone", 100, "Bring 100 stone to weigh down the new nets."),
+            ],
+            xp_reward: 120,
+            shard_reward: 70,
+            reward_item_name: Some("Cloth".to_string()),
+            reward_item_quantity: 20,
+        },
+        NpcQuestChainDefinition {
+            id: "fv_wolf_trouble".to_string(),
+            npc_key: NPC_KEY_FISHING_VILLAGE.to_string(),
+            name: "Wolf Trouble".to_string(),
+            description: "Wolves have been circling the huts at night.".to_string(),
+            steps: vec![
+                step(NpcQuestStepType::Kill, "TundraWolf", 3, "Hunt down 3 tundra wolves."),
+                step(NpcQuestStepType::Deliver, NPC_KEY_ALK_COMPOUND, 1, "Carry this report to the ALK quartermaster so they send patrols."),
+            ],
+            xp_reward: 200,
+            shard_reward: 120,
+            reward_item_name: Some("Bandage".to_string()),
+            reward_item_quantity: 3,
+        },
+        // ===== ALK QUARTERMASTER =====
+        NpcQuestChainDefinition {
+            id: "alk_scrap_requisition".to_string(),
+            npc_key: NPC_KEY_ALK_COMPOUND.to_string(),
+            name: "Scrap Requisition".to_string(),
+            description: "The compound needs materials for repairs.".to_string(),
+            steps: vec![
+                step(NpcQuestStepType::Fetch, "Metal Fragments", 50, "Requisition 50 metal fragments."),
+                step(NpcQuestStepType::Fetch, "Stone", 250, "Now 250 stone for the perimeter."),
+                step(NpcQuestStepType::Deliver, NPC_KEY_FISHING_VILLAGE, 1, "Deliver these ration vouchers to the fishing village elder."),
+            ],
+            xp_reward: 220,
+            shard_reward: 140,
+            reward_item_name: None,
+            reward_item_quantity: 0,
+        },
+        NpcQuestChainDefinition {
+            id: "alk_predator_control".to_string(),
+            npc_key: NPC_KEY_ALK_COMPOUND.to_string(),
+            name: "Predator Control".to_string(),
+            description: "Predators are threatening supply routes.".to_string(),
+            steps: vec![
+                step(NpcQuestStepType::Kill, "CinderFox", 3, "Eliminate 3 cinder foxes."),
+                step(NpcQuestStepType::Kill, "Wolverine", 1, "Eliminate 1 wolverine."),
+            ],
+            xp_reward: 250,
+            shard_reward: 160,
+            reward_item_name: Some("Metal Fragments".to_string()),
+            reward_item_quantity: 40,
+        },
+        NpcQuestChainDefinition {
+            id: "alk_field_survey".to_string(),
+            npc_key: NPC_KEY_ALK_COMPOUND.to_string(),
+            name: "Field Survey".to_string(),
+            description: "ALK needs samples from the wilds.".to_string(),
+            steps: vec![
+                step(NpcQuestStepType::Fetch, "Plant Fiber", 100, "Collect 100 plant fiber samples."),
+                step(NpcQuestStepType::Kill, "Caribou", 1, "Bring down a caribou for the biology team."),
+                step(NpcQuestStepType::Deliver, NPC_KEY_FISHING_VILLAGE, 1, "Deliver the survey findings to the fishing village elder."),
+            ],
+            xp_reward: 200,
+            shard_reward: 130,
+            reward_item_name: Some("Cloth".to_string()),
+            reward_item_quantity: 30,
+        },
+    ];
+
+    let chain_count = chains.len();
+    for chain in chains {
+        if table.id().find(&chain.id).is_some() {
+            table.id().update(chain);
+        } else {
+            table.insert(chain);
+        }
+    }
+
+    log::info!("[Quests] Seeded {} NPC quest chains", chain_count);
+    Ok(())
+}
@@ -3,6 +3,9 @@
  * Defines the RainCollector entity and its data structure.                  *
  * Handles placing collectors and managing their internal inventory.          *
  * Each collector automatically fills water containers during rain events.   *
+ * Water Purifiers are collectors fitted with a charcoal still: salt water    *
+ * poured into them is slowly desalinated into fresh water (losing some to   *
+ * brine) by a scheduled purification tick.                                  *
  *                                                                            *
  ******************************************************************************/
 
@@ -42,6 +45,12 @@ pub const MODERATE_RAIN_COLLECTION_RATE: f32 = 0.05; // units per second
 pub const HEAVY_RAIN_COLLECTION_RATE: f32 = 0.08;    // units per second
 pub const HEAVY_STORM_COLLECTION_RATE: f32 = 0.12;   // units per second
 
+// --- Water purifier constants ---
+pub const WATER_PURIFIER_ITEM_NAME: &str = "Water Purifier";
+pub const WATER_PURIFIER_SECS_PER_LITER: f32 = 15.0; // A full 40L reservoir takes 10 minutes
+pub const WATER_PURIFIER_BRINE_LOSS: f32 = 0.25; // Fraction of the batch left behind as brine
+pub const WATER_PURIFIER_TICK_INTERVAL_SECS: u64 = 5;
+
 // --- Container constants ---
 const RAIN_COLLECTOR_NUM_SLOTS: usize = 1; // Single slot for water container
 
@@ -95,6 +104,8 @@ pub struct RainCollector {
     pub total_water_collected: f32, // Lifetime total for statistics
     pub last_collection_time: Option<Timestamp>, // Last time water was collected
     pub is_salt_water: bool, // True if collected water is salt water
+    pub is_purifier: bool, // True for Water Purifiers - desalinate salt water over time
+    pub purification_progress_secs: f32, // Seconds spent purifying the current salt batch
     
     // --- Monument Placeable System ---
     pub is_monument: bool, // If true, this is a permanent monument placeable (indestructible, public access)
@@ -177,6 +188,9 @@ pub fn place_rain_collector(ctx: &ReducerContext, item_instance_id: u64, world_x
     // Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
     crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     // --- Get player and validate ---
     let players = ctx.db.player();
     let player = players.identity().find(&ctx.sender())
@@ -204,10 +218,11 @@ pub fn place_rain_collector(ctx: &ReducerContext, item_instance_id: u64, world_x
     let item_def = item_defs.id().find(&item.item_def_id)
         .ok_or_else(|| "Item definition not found.".to_string())?;
 
-    // Verify it's actually a rain collector
-    if item_def.name != "Reed Rain Collector" {
+    // Verify it's actually a rain collector (or a purifier, which is a collector with a still)
+    if item_def.name != "Reed Rain Collector" && item_def.name != WATER_PURIFIER_ITEM_NAME {
         return Err("This item is not a rain collector.".to_string());
     }
+    let is_purifier = item_def.name == WATER_PURIFIER_ITEM_NAME;
 
     // --- Validate placement position ---
     let distance_to_player = ((world_x - player.position_x).powi(2) + (world_y - player.position_y).powi(2)).sqrt();
@@ -281,6 +296,8 @@ pub fn place_rain_collector(ctx: &ReducerContext, item_instance_id: u64, world_x
         total_water_collected: 0.0,
         last_collection_time: None,
         is_salt_water: false, // Start with fresh water (rain is always fresh)
+        is_purifier,
+        purification_progress_secs: 0.0,
         // Monument placeable system (player-placed collectors are not monuments)
         is_monument: false,
         active_user_id: None,
@@ -577,6 +594,7 @@ pub fn empty_rain_collector_reservoir(ctx: &ReducerContext, collector_id: u32) -
     // --- Empty the reservoir ---
     collector.total_water_collected = 0.0;
     collector.is_salt_water = false; // Reset to fresh water state
+    collector.purification_progress_secs = 0.0;
 
     // --- Update the collector ---
     ctx.db.rain_collector().id().update(collector);
@@ -685,6 +703,79 @@ pub fn add_water_to_collector(ctx: &ReducerContext, collector_id: u32, amount: f
     Ok(())
 }
 
+/******************************************************************************
+ *                              WATER PURIFICATION                            *
+ ******************************************************************************/
+
+#[spacetimedb::table(accessor = water_purifier_schedule, scheduled(process_water_purifiers))]
+#[derive(Clone)]
+pub struct WaterPurifierSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub schedule_id: u64,
+    pub scheduled_at: spacetimedb::ScheduleAt,
+}
+
+/// Initializes the water purifier schedule
+/// Called from init_module in lib.rs
+pub fn init_water_purifier_schedule(ctx: &ReducerContext) -> Result<(), String> {
+    use spacetimedb::spacetimedb_lib::ScheduleAt;
+    use spacetimedb::TimeDuration;
+
+    let schedule_table = ctx.db.water_purifier_schedule();
+    if schedule_table.iter().count() > 0 {
+        log::debug!("Water purifier schedule already initialized.");
+        return Ok(());
+    }
+
+    log::info!("Initializing water purifier schedule (runs every {} seconds)...", WATER_PURIFIER_TICK_INTERVAL_SECS);
+    let interval = TimeDuration::from_micros((WATER_PURIFIER_TICK_INTERVAL_SECS * 1_000_000) as i64);
+    let schedule = WaterPurifierSchedule {
+        schedule_id: 0, // Auto-increment
+        scheduled_at: ScheduleAt::Interval(interval),
+    };
+
+    crate::try_insert_schedule!(
+        schedule_table,
+        schedule.clone(),
+        "Water purifier"
+    );
+
+    Ok(())
+}
+
+/// Scheduled reducer - advances purification on every purifier holding salt water.
+/// Once a batch has run for WATER_PURIFIER_SECS_PER_LITER per liter it turns fresh,
+/// minus the brine that's discarded.
+#[spacetimedb::reducer]
+pub fn process_water_purifiers(ctx: &ReducerContext, _args: WaterPurifierSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Water purification can only be run by scheduler".to_string());
+    }
+
+    let collectors = ctx.db.rain_collector();
+    let purifying: Vec<RainCollector> = collectors.iter()
+        .filter(|c| c.is_purifier && !c.is_destroyed && c.is_salt_water && c.total_water_collected > 0.0)
+        .collect();
+
+    for mut collector in purifying {
+        collector.purification_progress_secs += WATER_PURIFIER_TICK_INTERVAL_SECS as f32;
+        let required_secs = collector.total_water_collected * WATER_PURIFIER_SECS_PER_LITER;
+
+        if collector.purification_progress_secs >= required_secs {
+            let salt_liters = collector.total_water_collected;
+            collector.total_water_collected = salt_liters * (1.0 - WATER_PURIFIER_BRINE_LOSS);
+            collector.is_salt_water = false;
+            collector.purification_progress_secs = 0.0;
+            log::info!("[WaterPurifier] Purifier {} desalinated {:.1}L of salt water into {:.1}L of fresh water",
+                collector.id, salt_liters, collector.total_water_collected);
+        }
+        collectors.id().update(collector);
+    }
+
+    Ok(())
+}
+
 /******************************************************************************
  *                              HELPER FUNCTIONS                              *
  ******************************************************************************/
@@ -13,5 +13,10 @@ pub struct RangedWeaponStats {
     pub magazine_capacity: u8,      // How many rounds can be loaded at once (0 = single-shot like bow)
     pub is_automatic: bool,         // If true, weapon fires continuously when holding mouse button (e.g., SMGs)
     pub magazine_reload_time_secs: f32, // Time to reload/nock (0 = instant like bow)
+    pub spread_base_deg: f32,       // Cone half-angle (degrees) for a first, rested shot
+    pub spread_per_shot_deg: f32,   // Extra spread added by each consecutive shot (recoil)
+    pub spread_max_deg: f32,        // Cap on base + recoil spread (before stance modifiers)
+    pub spread_recovery_secs: f32,  // Seconds for one shot's worth of recoil to wear off
+    pub draw_time_secs: f32,        // Hold time to reach a full-power draw (0 = no draw, e.g. crossbow)
     // pub ammo_item_def_id: Option<u64>, // Future: if different ammo types are used
 }
\ No newline at end of file
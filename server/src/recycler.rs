@@ -0,0 +1,235 @@
+/******************************************************************************
+ *                                                                            *
+ * Recycler - Breaking items back into crafting materials                     *
+ *                                                                            *
+ * Recyclers are WoodenStorageBox entities with BOX_TYPE_RECYCLER. The first  *
+ * RECYCLER_INPUT_SLOTS slots take items to recycle; on a schedule each       *
+ * recycler breaks one batch of an input item into a share of the            *
+ * ingredients of its crafting recipe, placed in the output slots. Items      *
+ * without a recipe are left alone.                                           *
+ *                                                                            *
+ * ALK stations have indestructible monument recyclers; players can craft a   *
+ * Shredder, which returns less. This gives a sink for low-value barrel loot. *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table};
+use spacetimedb::spacetimedb_lib::{ScheduleAt, TimeDuration};
+use log;
+use std::time::Duration;
+
+use crate::wooden_storage_box::{WoodenStorageBox, BOX_TYPE_RECYCLER, wooden_storage_box as WoodenStorageBoxTableTrait};
+use crate::items::{InventoryItem, inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::crafting::{Recipe, recipe as RecipeTableTrait};
+use crate::inventory_management::ItemContainer;
+use crate::models::{ItemLocation, ContainerType, ContainerLocationData};
+
+// --- Recycler Constants ---
+pub const RECYCLE_PROCESS_INTERVAL_SECS: u64 = 5; // One batch per recycler every 5 seconds
+pub const RECYCLER_INPUT_SLOTS: u8 = 6; // Slots 0-5 are input, the rest are output
+pub const ALK_RECYCLER_YIELD: f32 = 0.6; // Monument recyclers at ALK stations
+pub const SHREDDER_YIELD: f32 = 0.4; // Player-built shredders
+
+// --- Recycler Schedule Table ---
+#[spacetimedb::table(accessor = recycler_process_schedule, scheduled(process_recyclers))]
+#[derive(Clone)]
+pub struct RecyclerProcessSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Initialize the recycler processing system
+pub fn init_recycler_system(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.recycler_process_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("Starting recycler schedule (every {}s).", RECYCLE_PROCESS_INTERVAL_SECS);
+        let interval = Duration::from_secs(RECYCLE_PROCESS_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            RecyclerProcessSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Recycler processing"
+        );
+    } else {
+        log::debug!("Recycler schedule already exists.");
+    }
+    Ok(())
+}
+
+/// Share of an item's crafting materials a recycler returns
+pub fn recycler_yield(recycler: &WoodenStorageBox) -> f32 {
+    if recycler.is_monument { ALK_RECYCLER_YIELD } else { SHREDDER_YIELD }
+}
+
+/// Materials returned for recycling `quantity` of a recipe's output: (item_def_id, quantity)
+pub fn recycle_outputs(recipe: &Recipe, quantity: u32, yield_fraction: f32) -> Vec<(u64, u32)> {
+    let output_quantity = recipe.output_quantity.max(1);
+    recipe.ingredients.iter()
+        .map(|ing| {
+            let amount = (ing.quantity * quantity) as f32 / output_quantity as f32 * yield_fraction;
+            (ing.item_def_id, amount.floor() as u32)
+        })
+        .filter(|(_, amount)| *amount > 0)
+        .collect()
+}
+
+/// Scheduled reducer that recycles one batch on every recycler
+#[spacetimedb::reducer]
+pub fn process_recyclers(ctx: &ReducerContext, _args: RecyclerProcessSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Recycler processing can only be run by scheduler".to_string());
+    }
+
+    let recyclers: Vec<WoodenStorageBox> = ctx.db.wooden_storage_box().iter()
+        .filter(|b| b.box_type == BOX_TYPE_RECYCLER && !b.is_destroyed)
+        .collect();
+    for mut recycler in recyclers {
+        if recycle_one_batch(ctx, &mut recycler) {
+            ctx.db.wooden_storage_box().id().update(recycler);
+        }
+    }
+    Ok(())
+}
+
+/// Recycles the first recyclable input item. Returns true if the recycler's slots changed.
+/// A recycler with no room for the materials leaves its input waiting.
+fn recycle_one_batch(ctx: &ReducerContext, recycler: &mut WoodenStorageBox) -> bool {
+    let inventory_items = ctx.db.inventory_item();
+    let item_defs = ctx.db.item_definition();
+    let recipes = ctx.db.recipe();
+    let yield_fraction = recycler_yield(recycler);
+
+    for slot in 0..RECYCLER_INPUT_SLOTS.min(recycler.num_slots() as u8) {
+        let Some(mut input) = recycler.get_slot_instance_id(slot)
+            .and_then(|id| inventory_items.instance_id().find(id)) else {
+            continue;
+        };
+        let Some(recipe) = recipes.iter().find(|r| r.output_item_def_id == input.item_def_id) else {
+            continue; // Not craftable - nothing to break it into
+        };
+
+        // Stackable outputs (arrows, ammo) are recycled a full craft batch at a time
+        let batch = input.quantity.min(recipe.output_quantity.max(1));
+        let outputs: Vec<(u64, u32, u32)> = recycle_outputs(&recipe, batch, yield_fraction).into_iter()
+            .filter_map(|(def_id, amount)| {
+                item_defs.id().find(def_id)
+                    .map(|def| (def_id, amount, if def.is_stackable { def.stack_size } else { 1 }))
+            })
+            .collect();
+        if outputs.is_empty() || !outputs_fit(ctx, recycler, &outputs) {
+            continue;
+        }
+
+        let mut slots_changed = false;
+        if input.quantity > batch {
+            input.quantity -= batch;
+            inventory_items.instance_id().update(input);
+        } else {
+            recycler.set_slot(slot, None, None);
+            inventory_items.instance_id().delete(input.instance_id);
+            slots_changed = true;
+        }
+        for (def_id, amount, max_stack) in &outputs {
+            slots_changed |= deposit_output(ctx, recycler, *def_id, *amount, *max_stack);
+        }
+
+        log::debug!("[Recycler] Recycler {} broke down {}x item def {} into {} materials", recycler.id, batch, recipe.output_item_def_id, outputs.len());
+        return slots_changed;
+    }
+    false
+}
+
+/// Whether every output fits in the output slots, merging into matching stacks first
+fn outputs_fit(ctx: &ReducerContext, recycler: &WoodenStorageBox, outputs: &[(u64, u32, u32)]) -> bool {
+    let inventory_items = ctx.db.inventory_item();
+    // (item_def_id in the slot, room left in it) for every output slot
+    let mut slots: Vec<(Option<u64>, u32)> = (RECYCLER_INPUT_SLOTS..recycler.num_slots() as u8)
+        .map(|slot| match recycler.get_slot_instance_id(slot).and_then(|id| inventory_items.instance_id().find(id)) {
+            Some(item) => {
+                let room = outputs.iter()
+                    .find(|(def_id, _, _)| *def_id == item.item_def_id)
+                    .map(|(_, _, max_stack)| max_stack.saturating_sub(item.quantity))
+                    .unwrap_or(0);
+                (Some(item.item_def_id), room)
+            }
+            None => (None, 0),
+        })
+        .collect();
+
+    for (def_id, amount, max_stack) in outputs {
+        let mut remaining = *amount;
+        for slot in slots.iter_mut().filter(|(slot_def, _)| *slot_def == Some(*def_id)) {
+            let taken = remaining.min(slot.1);
+            slot.1 -= taken;
+            remaining -= taken;
+        }
+        while remaining > 0 {
+            let Some(slot) = slots.iter_mut().find(|(slot_def, _)| slot_def.is_none()) else {
+                return false;
+            };
+            let taken = remaining.min(*max_stack);
+            *slot = (Some(*def_id), max_stack - taken);
+            remaining -= taken;
+        }
+    }
+    true
+}
+
+/// Adds materials to the output slots (room was checked by outputs_fit). Returns true if slots changed.
+fn deposit_output(ctx: &ReducerContext, recycler: &mut WoodenStorageBox, def_id: u64, amount: u32, max_stack: u32) -> bool {
+    let inventory_items = ctx.db.inventory_item();
+    let mut remaining = amount;
+    let mut slots_changed = false;
+
+    for slot in RECYCLER_INPUT_SLOTS..recycler.num_slots() as u8 {
+        if remaining == 0 {
+            break;
+        }
+        if recycler.get_slot_def_id(slot) != Some(def_id) {
+            continue;
+        }
+        if let Some(mut stack) = recycler.get_slot_instance_id(slot).and_then(|id| inventory_items.instance_id().find(id)) {
+            let taken = remaining.min(max_stack.saturating_sub(stack.quantity));
+            if taken > 0 {
+                stack.quantity += taken;
+                remaining -= taken;
+                inventory_items.instance_id().update(stack);
+            }
+        }
+    }
+
+    while remaining > 0 {
+        let Some(slot) = (RECYCLER_INPUT_SLOTS..recycler.num_slots() as u8)
+            .find(|slot| recycler.get_slot_instance_id(*slot).is_none()) else {
+            log::error!("[Recycler] Recycler {} ran out of output room for {} of item def {}", recycler.id, remaining, def_id);
+            break;
+        };
+        let taken = remaining.min(max_stack);
+        match inventory_items.try_insert(InventoryItem {
+            instance_id: 0,
+            item_def_id: def_id,
+            quantity: taken,
+            location: ItemLocation::Container(ContainerLocationData {
+                container_type: ContainerType::WoodenStorageBox,
+                container_id: recycler.id as u64,
+                slot_index: slot,
+            }),
+            item_data: None,
+        }) {
+            Ok(inserted) => {
+                recycler.set_slot(slot, Some(inserted.instance_id), Some(def_id));
+                slots_changed = true;
+                remaining -= taken;
+            }
+            Err(e) => {
+                log::error!("[Recycler] Failed to insert output into recycler {}: {:?}", recycler.id, e);
+                break;
+            }
+        }
+    }
+    slots_changed
+}
@@ -137,6 +137,9 @@ pub fn place_repair_bench(
     if (dx * dx + dy * dy) > (REPAIR_BENCH_PLACEMENT_MAX_DISTANCE * REPAIR_BENCH_PLACEMENT_MAX_DISTANCE) {
         return Err("Placement location is too far away.".to_string());
     }
+
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, pos_x, pos_y, ctx.sender())?;
     
     // Calculate chunk index for the position
     let chunk_index = calculate_chunk_index(pos_x, pos_y);
@@ -0,0 +1,159 @@
+/******************************************************************************
+ *                                                                            *
+ * Research - Item-level blueprint unlocks                                    *
+ *                                                                            *
+ * Advanced recipes are locked behind blueprints. A player learns one either  *
+ * by sacrificing a copy of the item (plus memory shards) at a research      *
+ * table, or by reading a "<Item> Blueprint" found in barrels and supply     *
+ * drops. Learned blueprints are permanent and per-player; the crafting       *
+ * reducers refuse blueprint-locked recipes the player hasn't learned.       *
+ *                                                                            *
+ * Research tables are WoodenStorageBox entities (BOX_TYPE_RESEARCH_TABLE)   *
+ * with a single slot that holds the item being researched.                  *
+ *                                                                            *
+ * This complements the Memory Grid: the grid unlocks broad branches, while  *
+ * research unlocks individual items.                                        *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use log;
+
+use crate::wooden_storage_box::{BOX_TYPE_RESEARCH_TABLE, validate_box_interaction, wooden_storage_box as WoodenStorageBoxTableTrait};
+use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::inventory_management::ItemContainer;
+
+/// Suffix of the blueprint item that teaches a locked recipe (e.g. "Makarov PM Blueprint")
+pub const BLUEPRINT_ITEM_SUFFIX: &str = " Blueprint";
+
+/// Items that must be learned before they can be crafted, with the memory shard cost of researching them
+pub const BLUEPRINT_LOCKED_ITEMS: &[(&str, u32)] = &[
+    ("Flashbang", 50),
+    ("Water Purifier", 75),
+    ("Signal Disruptor", 100),
+    ("Tallow Steam Turret", 150),
+    ("Makarov PM", 250),
+    ("PP-91 KEDR", 400),
+];
+
+/// A blueprint a player has learned
+#[spacetimedb::table(accessor = learned_blueprint, public)]
+#[derive(Clone, Debug)]
+pub struct LearnedBlueprint {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub player_id: Identity,
+    pub item_def_id: u64,
+    pub learned_at: Timestamp,
+}
+
+/// Research shard cost for an item, or None if it isn't blueprint-locked
+pub fn research_cost(item_name: &str) -> Option<u32> {
+    BLUEPRINT_LOCKED_ITEMS.iter()
+        .find(|(name, _)| *name == item_name)
+        .map(|(_, cost)| *cost)
+}
+
+pub fn has_learned_blueprint(ctx: &ReducerContext, player_id: Identity, item_def_id: u64) -> bool {
+    ctx.db.learned_blueprint().player_id().filter(player_id)
+        .any(|bp| bp.item_def_id == item_def_id)
+}
+
+/// Ensures the player has learned the blueprint for a locked recipe
+pub fn check_blueprint_requirement(ctx: &ReducerContext, player_id: Identity, item_def_id: u64, item_name: &str) -> Result<(), String> {
+    if research_cost(item_name).is_none() || has_learned_blueprint(ctx, player_id, item_def_id) {
+        return Ok(());
+    }
+    Err(format!("You haven't learned the blueprint for {}. Research it at a research table or find its blueprint.", item_name))
+}
+
+fn learn_blueprint(ctx: &ReducerContext, player_id: Identity, item_def_id: u64, item_name: &str) -> Result<(), String> {
+    if has_learned_blueprint(ctx, player_id, item_def_id) {
+        return Err(format!("You already know how to craft {}.", item_name));
+    }
+    ctx.db.learned_blueprint().insert(LearnedBlueprint {
+        id: 0,
+        player_id,
+        item_def_id,
+        learned_at: ctx.timestamp,
+    });
+    log::info!("[Research] Player {:?} learned the blueprint for {}", player_id, item_name);
+    Ok(())
+}
+
+/// --- Research Item ---
+/// Destroys the item in the research table's slot and spends memory shards to learn its blueprint.
+#[spacetimedb::reducer]
+pub fn research_item(ctx: &ReducerContext, box_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let (_player, mut table) = validate_box_interaction(ctx, box_id)?;
+    if table.box_type != BOX_TYPE_RESEARCH_TABLE {
+        return Err("This is not a research table.".to_string());
+    }
+
+    let inventory_items = ctx.db.inventory_item();
+    let item_instance_id = table.get_slot_instance_id(0)
+        .ok_or_else(|| "Place an item on the research table first.".to_string())?;
+    let item = inventory_items.instance_id().find(item_instance_id)
+        .ok_or_else(|| "Item on the research table not found.".to_string())?;
+    let item_def = ctx.db.item_definition().id().find(item.item_def_id)
+        .ok_or_else(|| "Item definition not found.".to_string())?;
+
+    let shard_cost = research_cost(&item_def.name)
+        .ok_or_else(|| format!("{} has no blueprint to research.", item_def.name))?;
+    if has_learned_blueprint(ctx, sender_id, item_def.id) {
+        return Err(format!("You already know how to craft {}.", item_def.name));
+    }
+    if crate::memory_grid::count_memory_shards_in_inventory(ctx, sender_id) < shard_cost as u64 {
+        return Err(format!("Researching {} costs {} memory shards.", item_def.name, shard_cost));
+    }
+
+    crate::memory_grid::consume_memory_shards(ctx, sender_id, shard_cost as u64)?;
+    // The researched item is destroyed - one copy only
+    if item.quantity > 1 {
+        let mut remaining = item;
+        remaining.quantity -= 1;
+        inventory_items.instance_id().update(remaining);
+    } else {
+        table.set_slot(0, None, None);
+        inventory_items.instance_id().delete(item_instance_id);
+        ctx.db.wooden_storage_box().id().update(table);
+    }
+
+    learn_blueprint(ctx, sender_id, item_def.id, &item_def.name)
+}
+
+/// --- Read Blueprint ---
+/// Consumes a blueprint item from the player's inventory and learns the recipe it teaches.
+#[spacetimedb::reducer]
+pub fn read_blueprint(ctx: &ReducerContext, item_instance_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let inventory_items = ctx.db.inventory_item();
+    let item_defs = ctx.db.item_definition();
+
+    let item = inventory_items.instance_id().find(item_instance_id)
+        .ok_or_else(|| "Blueprint not found.".to_string())?;
+    if item.location.is_player_bound() != Some(sender_id) {
+        return Err("That blueprint isn't yours.".to_string());
+    }
+    let blueprint_def = item_defs.id().find(item.item_def_id)
+        .ok_or_else(|| "Item definition not found.".to_string())?;
+    let taught_name = blueprint_def.name.strip_suffix(BLUEPRINT_ITEM_SUFFIX)
+        .filter(|name| research_cost(name).is_some())
+        .ok_or_else(|| format!("{} is not a recipe blueprint.", blueprint_def.name))?;
+    let taught_def = item_defs.iter().find(|def| def.name == taught_name)
+        .ok_or_else(|| format!("Item definition for '{}' not found.", taught_name))?;
+
+    learn_blueprint(ctx, sender_id, taught_def.id, &taught_def.name)?;
+
+    if item.quantity > 1 {
+        let mut remaining = item;
+        remaining.quantity -= 1;
+        inventory_items.instance_id().update(remaining);
+    } else {
+        inventory_items.instance_id().delete(item_instance_id);
+    }
+    Ok(())
+}
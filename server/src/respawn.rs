@@ -247,6 +247,7 @@ pub fn respawn_randomly(ctx: &ReducerContext) -> Result<(), String> { // Renamed
     // --- Set Position to Found Land Location ---
     current_player.position_x = spawn_x;
     current_player.position_y = spawn_y;
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(current_player.identity), current_player.position_x, current_player.position_y);
     current_player.direction = "down".to_string();
 
     // --- Reset Stats and State ---
@@ -257,9 +258,15 @@ pub fn respawn_randomly(ctx: &ReducerContext) -> Result<(), String> { // Renamed
     current_player.stamina = 100.0;
     current_player.insanity = 0.0; // Reset insanity on respawn
     current_player.shard_carry_start_time = None; // Reset shard carry time on respawn
+    current_player.oxygen = crate::player_stats::PLAYER_MAX_OXYGEN; // Surface with full breath
     current_player.jump_start_time_ms = 0;
     current_player.is_sprinting = false;
     current_player.is_dead = false; // Mark as alive again
+    crate::ghost::clear_ghost(ctx, sender_id);
+    crate::restraints::clear_restraint(ctx, sender_id);
+    crate::spyglass::stop_scoping(ctx, sender_id);
+    current_player.is_sleeping = false;
+    current_player.elevation = crate::building_floor::ELEVATION_GROUND; // Respawn on the ground
     current_player.death_timestamp = None; // Clear death timestamp
     current_player.last_hit_time = None;
     
@@ -288,6 +295,7 @@ pub fn respawn_randomly(ctx: &ReducerContext) -> Result<(), String> { // Renamed
 
     // --- Apply Player Changes ---
     players.identity().update(current_player);
+    crate::hallucination::clear_player_hallucinations(ctx, sender_id);
     log::info!("Player {:?} respawned on land at ({:.1}, {:.1}).", sender_id, spawn_x, spawn_y);
     log::info!("RESPAWN SUCCESS: Player position updated to ({:.1}, {:.1})", spawn_x, spawn_y);
 
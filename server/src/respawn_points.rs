@@ -0,0 +1,127 @@
+/******************************************************************************
+ *                                                                            *
+ * Respawn Points - Per-player view and management of owned sleeping bags     *
+ *                                                                            *
+ * respawn_point mirrors each player's intact sleeping bags with their names  *
+ * and respawn cooldowns, so the death screen can list them without scanning *
+ * every bag. Rows are rebuilt by sync_respawn_points whenever a bag is      *
+ * placed, renamed, given away, used or destroyed.                            *
+ *                                                                            *
+ * Respawning at a bag starts its cooldown, and the cooldown is shared with  *
+ * every other bag the player owns within SHARED_COOLDOWN_RADIUS, so a        *
+ * cluster of bags can't be used to chain-spawn at one spot.                  *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table, TimeDuration, Timestamp};
+use log;
+
+use crate::sleeping_bag::{SleepingBag, sleeping_bag as SleepingBagTableTrait};
+use crate::player as PlayerTableTrait;
+
+pub const SLEEPING_BAG_RESPAWN_COOLDOWN_SECS: i64 = 300;
+/// Bags of the same owner this close together share one respawn cooldown
+pub const SHARED_COOLDOWN_RADIUS: f32 = 600.0;
+pub const MAX_SLEEPING_BAG_NAME_LENGTH: usize = 32;
+pub const DEFAULT_SLEEPING_BAG_NAME: &str = "Sleeping Bag";
+
+/// A respawn point owned by a player (one row per intact sleeping bag)
+#[spacetimedb::table(accessor = respawn_point, public)]
+#[derive(Clone, Debug)]
+pub struct RespawnPoint {
+    #[primary_key]
+    pub bag_id: u32,
+    #[index(btree)]
+    pub owner_id: Identity,
+    pub name: String,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    /// When the bag can be respawned at again (None = ready)
+    pub cooldown_until: Option<Timestamp>,
+}
+
+// Players only see their own respawn points
+#[client_visibility_filter]
+const ONLY_OWN_RESPAWN_POINTS: Filter = Filter::Sql("SELECT * FROM respawn_point WHERE owner_id = :sender");
+
+/// When the bag's shared cooldown ends, if it is still running
+pub fn respawn_cooldown_until(ctx: &ReducerContext, bag: &SleepingBag) -> Option<Timestamp> {
+    let radius_sq = SHARED_COOLDOWN_RADIUS * SHARED_COOLDOWN_RADIUS;
+    let last_used = ctx.db.sleeping_bag().iter()
+        .filter(|other| other.placed_by == bag.placed_by && !other.is_destroyed)
+        .filter(|other| {
+            let dx = other.pos_x - bag.pos_x;
+            let dy = other.pos_y - bag.pos_y;
+            dx * dx + dy * dy <= radius_sq
+        })
+        .filter_map(|other| other.last_respawn_at)
+        .max()?;
+    let until = last_used + TimeDuration::from_micros(SLEEPING_BAG_RESPAWN_COOLDOWN_SECS * 1_000_000);
+    (until > ctx.timestamp).then_some(until)
+}
+
+/// Rebuilds a player's respawn_point rows from their sleeping bags
+pub fn sync_respawn_points(ctx: &ReducerContext, owner_id: Identity) {
+    let points = ctx.db.respawn_point();
+    for point in points.owner_id().filter(owner_id).collect::<Vec<_>>() {
+        points.bag_id().delete(point.bag_id);
+    }
+    for bag in ctx.db.sleeping_bag().iter().filter(|b| b.placed_by == owner_id && !b.is_destroyed) {
+        points.insert(RespawnPoint {
+            bag_id: bag.id,
+            owner_id,
+            name: bag.name.clone(),
+            pos_x: bag.pos_x,
+            pos_y: bag.pos_y,
+            cooldown_until: respawn_cooldown_until(ctx, &bag),
+        });
+    }
+}
+
+fn find_owned_bag(ctx: &ReducerContext, bag_id: u32) -> Result<SleepingBag, String> {
+    let bag = ctx.db.sleeping_bag().id().find(bag_id)
+        .filter(|b| !b.is_destroyed)
+        .ok_or_else(|| format!("Sleeping Bag {} not found", bag_id))?;
+    if bag.placed_by != ctx.sender() {
+        return Err("You don't own this sleeping bag.".to_string());
+    }
+    Ok(bag)
+}
+
+/// --- Rename Sleeping Bag ---
+/// An empty name restores the default.
+#[spacetimedb::reducer]
+pub fn rename_sleeping_bag(ctx: &ReducerContext, bag_id: u32, name: String) -> Result<(), String> {
+    let mut bag = find_owned_bag(ctx, bag_id)?;
+    let name = name.trim();
+    if name.chars().count() > MAX_SLEEPING_BAG_NAME_LENGTH {
+        return Err(format!("Name must be at most {} characters.", MAX_SLEEPING_BAG_NAME_LENGTH));
+    }
+    bag.name = if name.is_empty() { DEFAULT_SLEEPING_BAG_NAME.to_string() } else { name.to_string() };
+    let owner_id = bag.placed_by;
+    ctx.db.sleeping_bag().id().update(bag);
+    sync_respawn_points(ctx, owner_id);
+    Ok(())
+}
+
+/// --- Give Sleeping Bag ---
+/// Hands ownership of a sleeping bag (and its running cooldown) to another player by username.
+#[spacetimedb::reducer]
+pub fn give_sleeping_bag(ctx: &ReducerContext, bag_id: u32, target_username: String) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let mut bag = find_owned_bag(ctx, bag_id)?;
+    let username = target_username.trim().to_lowercase();
+    let target = ctx.db.player().iter()
+        .find(|p| p.username.to_lowercase() == username)
+        .ok_or_else(|| "Player not found".to_string())?;
+    if target.identity == sender_id {
+        return Err("You already own this sleeping bag.".to_string());
+    }
+
+    bag.placed_by = target.identity;
+    ctx.db.sleeping_bag().id().update(bag);
+    sync_respawn_points(ctx, sender_id);
+    sync_respawn_points(ctx, target.identity);
+    log::info!("[RespawnPoints] Player {:?} gave sleeping bag {} to {}", sender_id, bag_id, target.username);
+    Ok(())
+}
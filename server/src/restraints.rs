@@ -0,0 +1,172 @@
+/******************************************************************************
+ *                                                                            *
+ * Restraints - Handcuffing knocked out players                               *
+ *                                                                            *
+ * Handcuffs can be used on a knocked out player. The restraint outlasts the  *
+ * knockout: once the prisoner recovers they can walk, but combat and item    *
+ * reducers reject them (see is_restrained) until they either struggle free   *
+ * - by holding the struggle input, which the client reports through          *
+ * struggle_against_restraints - or another free player releases them and    *
+ * gets the handcuffs back. Struggling free breaks the handcuffs.             *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use log;
+
+use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::player as PlayerTableTrait;
+use crate::dropped_item::try_give_item_to_player;
+
+pub const HANDCUFFS_ITEM_NAME: &str = "Handcuffs";
+/// How close a player must be to restrain or release someone
+pub const RESTRAIN_INTERACTION_DISTANCE: f32 = 96.0;
+/// Seconds of held struggling needed to break free
+pub const STRUGGLE_FREE_SECS: f32 = 20.0;
+/// Longest gap between struggle reports that still counts as holding the input
+const MAX_STRUGGLE_STEP_SECS: f32 = 0.5;
+
+/// A player in handcuffs (one row per prisoner)
+#[spacetimedb::table(accessor = restrained_player, public)]
+#[derive(Clone, Debug)]
+pub struct RestrainedPlayer {
+    #[primary_key]
+    pub player_id: Identity,
+    pub restrained_by: Identity,
+    pub restrained_at: Timestamp,
+    /// Seconds of struggling so far (free at STRUGGLE_FREE_SECS)
+    pub escape_progress_secs: f32,
+    pub last_struggle_at: Option<Timestamp>,
+}
+
+/// Whether a player is handcuffed and may not attack or use items
+pub fn is_restrained(ctx: &ReducerContext, player_id: Identity) -> bool {
+    ctx.db.restrained_player().player_id().find(player_id).is_some()
+}
+
+/// Removes a player's handcuffs without returning them (on respawn)
+pub fn clear_restraint(ctx: &ReducerContext, player_id: Identity) {
+    ctx.db.restrained_player().player_id().delete(player_id);
+}
+
+fn within_reach(a: &crate::Player, b: &crate::Player) -> bool {
+    let dx = a.position_x - b.position_x;
+    let dy = a.position_y - b.position_y;
+    dx * dx + dy * dy <= RESTRAIN_INTERACTION_DISTANCE * RESTRAIN_INTERACTION_DISTANCE
+}
+
+/// --- Restrain Player ---
+/// Uses one pair of handcuffs from the sender's inventory on a knocked out player.
+#[spacetimedb::reducer]
+pub fn restrain_player(ctx: &ReducerContext, target_player_id: Identity, item_instance_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let players = ctx.db.player();
+    let inventory_items = ctx.db.inventory_item();
+
+    let player = players.identity().find(sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    let target = players.identity().find(target_player_id)
+        .ok_or_else(|| "Target player not found".to_string())?;
+    if player.is_dead || player.is_knocked_out || is_restrained(ctx, sender_id) {
+        return Err("You can't restrain anyone right now.".to_string());
+    }
+    if !target.is_knocked_out || target.is_dead {
+        return Err("Only knocked out players can be restrained.".to_string());
+    }
+    if is_restrained(ctx, target_player_id) {
+        return Err("That player is already restrained.".to_string());
+    }
+    if !within_reach(&player, &target) {
+        return Err("Too far away to restrain that player.".to_string());
+    }
+
+    let item = inventory_items.instance_id().find(item_instance_id)
+        .ok_or_else(|| format!("Item instance {} not found.", item_instance_id))?;
+    if item.location.is_player_bound() != Some(sender_id) {
+        return Err("Those handcuffs are not in your inventory.".to_string());
+    }
+    let is_handcuffs = ctx.db.item_definition().id().find(item.item_def_id)
+        .is_some_and(|def| def.name == HANDCUFFS_ITEM_NAME);
+    if !is_handcuffs {
+        return Err("That item is not a pair of handcuffs.".to_string());
+    }
+
+    if item.quantity > 1 {
+        let mut updated_item = item.clone();
+        updated_item.quantity -= 1;
+        inventory_items.instance_id().update(updated_item);
+    } else {
+        inventory_items.instance_id().delete(item_instance_id);
+    }
+
+    ctx.db.restrained_player().insert(RestrainedPlayer {
+        player_id: target_player_id,
+        restrained_by: sender_id,
+        restrained_at: ctx.timestamp,
+        escape_progress_secs: 0.0,
+        last_struggle_at: None,
+    });
+    log::info!("[Restraints] Player {:?} restrained player {:?}", sender_id, target_player_id);
+    Ok(())
+}
+
+/// --- Release Restrained Player ---
+/// Takes the handcuffs off a prisoner and gives them to the releasing player.
+#[spacetimedb::reducer]
+pub fn release_restrained_player(ctx: &ReducerContext, target_player_id: Identity) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let players = ctx.db.player();
+    let player = players.identity().find(sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    let target = players.identity().find(target_player_id)
+        .ok_or_else(|| "Target player not found".to_string())?;
+    if player.is_dead || player.is_knocked_out || is_restrained(ctx, sender_id) {
+        return Err("You can't release anyone right now.".to_string());
+    }
+    if !is_restrained(ctx, target_player_id) {
+        return Err("That player is not restrained.".to_string());
+    }
+    if !within_reach(&player, &target) {
+        return Err("Too far away to release that player.".to_string());
+    }
+
+    clear_restraint(ctx, target_player_id);
+    if let Some(def) = ctx.db.item_definition().iter().find(|def| def.name == HANDCUFFS_ITEM_NAME) {
+        if let Err(e) = try_give_item_to_player(ctx, sender_id, def.id, 1) {
+            log::warn!("[Restraints] Could not return handcuffs to {:?}: {}", sender_id, e);
+        }
+    }
+    log::info!("[Restraints] Player {:?} released player {:?}", sender_id, target_player_id);
+    Ok(())
+}
+
+/// --- Struggle Against Restraints ---
+/// Sent repeatedly while the prisoner holds the struggle input. Progress only accrues for
+/// the time between consecutive reports, so releasing the input pauses it.
+#[spacetimedb::reducer]
+pub fn struggle_against_restraints(ctx: &ReducerContext) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let player = ctx.db.player().identity().find(sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    if player.is_dead || player.is_knocked_out {
+        return Err("You can't struggle right now.".to_string());
+    }
+    let mut restraint = ctx.db.restrained_player().player_id().find(sender_id)
+        .ok_or_else(|| "You are not restrained.".to_string())?;
+
+    if let Some(last) = restraint.last_struggle_at {
+        let step_secs = (ctx.timestamp.to_micros_since_unix_epoch() - last.to_micros_since_unix_epoch()) as f32 / 1_000_000.0;
+        if (0.0..=MAX_STRUGGLE_STEP_SECS).contains(&step_secs) {
+            restraint.escape_progress_secs += step_secs;
+        }
+    }
+    restraint.last_struggle_at = Some(ctx.timestamp);
+
+    if restraint.escape_progress_secs >= STRUGGLE_FREE_SECS {
+        clear_restraint(ctx, sender_id);
+        log::info!("[Restraints] Player {:?} struggled free (handcuffs broke)", sender_id);
+    } else {
+        ctx.db.restrained_player().player_id().update(restraint);
+    }
+    Ok(())
+}
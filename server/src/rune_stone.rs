@@ -6,6 +6,10 @@
  * - Red (Production): Reduces crafting time for specific items             *
  * - Blue (Memory Shard): Spawns memory shards at night                     *
  *                                                                            *
+ * Blue stones are charged at night and fill their surroundings with memory  *
+ * static: unprotected players there go insane fast and take damage, so      *
+ * shard farming needs the Static Ward Cowl.                                  *
+ *                                                                            *
  ******************************************************************************/
 
 use spacetimedb::{SpacetimeType, Timestamp, Table, ScheduleAt};
@@ -84,6 +88,8 @@ pub(crate) const BLUE_RUNE_SHARD_SPAWN_INTERVAL_SECS: u64 = 60; // Check every 6
 pub(crate) const BLUE_RUNE_SHARD_SPAWN_RADIUS: f32 = RUNE_STONE_EFFECT_RADIUS;
 pub(crate) const BLUE_RUNE_SHARD_MIN_DISTANCE: f32 = 150.0;
 pub(crate) const BLUE_RUNE_SHARD_CHECK_RADIUS: f32 = 500.0; // Radius to check for existing shards
+pub(crate) const BLUE_RUNE_AURORA_BONUS_SHARDS_MIN: u32 = 2; // Extra shards when an aurora hangs over the stone
+pub(crate) const BLUE_RUNE_AURORA_BONUS_SHARDS_MAX: u32 = 3;
 
 // Night Lighting Constants
 pub(crate) const RUNE_STONE_LIGHT_RADIUS: f32 = 400.0; // Light radius for night glow
@@ -91,6 +97,12 @@ pub(crate) const RUNE_STONE_LIGHT_RADIUS: f32 = 400.0; // Light radius for night
 // Hostile NPC Deterrence Constants - Apparitions can't enter the light radius
 pub const RUNE_STONE_DETERRENCE_RADIUS: f32 = RUNE_STONE_LIGHT_RADIUS; // Matches light radius
 
+// Memory Static Hazard Constants - charged blue rune stones irradiate the shard fields around them
+pub(crate) const MEMORY_STATIC_RADIUS: f32 = 600.0;
+pub(crate) const MEMORY_STATIC_RADIUS_SQUARED: f32 = MEMORY_STATIC_RADIUS * MEMORY_STATIC_RADIUS;
+pub(crate) const MEMORY_STATIC_INSANITY_PER_SECOND: f32 = 0.5; // 0 -> 100% insanity in ~3.3 minutes
+pub(crate) const MEMORY_STATIC_DAMAGE_PER_SECOND: f32 = 0.5;
+
 // --- Rune Stone Types ---
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize, SpacetimeType)]
@@ -174,6 +186,30 @@ pub struct RuneStoneSeedSpawnSchedule {
 
 // --- Helper Functions ---
 
+/// Night periods (twilight evening to twilight morning), when blue rune stones are charged
+pub(crate) fn is_rune_charging_period(time_of_day: &crate::world_state::TimeOfDay) -> bool {
+    matches!(
+        time_of_day,
+        crate::world_state::TimeOfDay::TwilightEvening
+            | crate::world_state::TimeOfDay::Night
+            | crate::world_state::TimeOfDay::Midnight
+            | crate::world_state::TimeOfDay::TwilightMorning
+    )
+}
+
+/// Whether a position lies in the memory static of a charged rune stone
+pub fn is_position_in_memory_static(ctx: &spacetimedb::ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    let is_charged = ctx.db.world_state().iter().next()
+        .is_some_and(|world_state| is_rune_charging_period(&world_state.time_of_day));
+    is_charged && ctx.db.rune_stone().iter()
+        .filter(|stone| stone.rune_type == RuneStoneType::Blue)
+        .any(|stone| {
+            let dx = pos_x - stone.pos_x;
+            let dy = pos_y - stone.pos_y;
+            dx * dx + dy * dy <= MEMORY_STATIC_RADIUS_SQUARED
+        })
+}
+
 /// Generate a random seed loot table for a green rune stone
 /// Each rune stone gets 2-4 random seed types from all available plantable seeds
 /// This creates variety and permanence across server wipes
@@ -363,6 +399,64 @@ pub fn get_red_rune_crafting_time_multiplier(
     1.0 // No reduction
 }
 
+/// Drops memory shards at random land points around a rune stone, returns how many landed
+fn spawn_shards_around_rune_stone(ctx: &spacetimedb::ReducerContext, rune_stone: &RuneStone, memory_shard_def_id: u64, count: u32, rng: &mut impl rand::Rng) -> u32 {
+    let mut spawned_count = 0;
+    
+    for _ in 0..count {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let distance = rng.gen_range(BLUE_RUNE_SHARD_MIN_DISTANCE..BLUE_RUNE_SHARD_SPAWN_RADIUS);
+        let shard_x = rune_stone.pos_x + angle.cos() * distance;
+        let shard_y = rune_stone.pos_y + angle.sin() * distance;
+        
+        // Check if position is valid (not in water, etc.)
+        if !crate::environment::is_position_on_water(ctx, shard_x, shard_y) {
+            let chunk_idx = crate::environment::calculate_chunk_index(shard_x, shard_y);
+            
+            // Create dropped item
+            let inserted = ctx.db.dropped_item().insert(crate::dropped_item::DroppedItem {
+                id: 0,
+                item_def_id: memory_shard_def_id,
+                quantity: 1,
+                pos_x: shard_x,
+                pos_y: shard_y,
+                chunk_index: chunk_idx,
+                created_at: ctx.timestamp,
+                item_data: None,
+                spawn_x: None,
+                spawn_y: None,
+            });
+            crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), inserted.pos_x, inserted.pos_y);
+            
+            spawned_count += 1;
+        }
+    }
+    
+    spawned_count
+}
+
+/// Drops bonus shards around every blue rune stone under a newly started aurora
+pub fn spawn_aurora_bonus_shards(ctx: &spacetimedb::ReducerContext) {
+    use rand::Rng;
+    
+    let Some(memory_shard_def_id) = ctx.db.item_definition().iter()
+        .find(|def| def.name == "Memory Shard")
+        .map(|def| def.id) else {
+        return;
+    };
+    let mut rng = ctx.rng();
+    
+    for rune_stone in ctx.db.rune_stone().iter() {
+        if rune_stone.rune_type != RuneStoneType::Blue
+            || !crate::world_state::is_under_aurora(ctx, rune_stone.pos_x, rune_stone.pos_y) {
+            continue;
+        }
+        let bonus = rng.gen_range(BLUE_RUNE_AURORA_BONUS_SHARDS_MIN..=BLUE_RUNE_AURORA_BONUS_SHARDS_MAX);
+        let spawned_count = spawn_shards_around_rune_stone(ctx, &rune_stone, memory_shard_def_id, bonus, &mut rng);
+        log::info!("Blue rune stone {} spawned {} bonus memory shards under the aurora", rune_stone.id, spawned_count);
+    }
+}
+
 /// Scheduled reducer to spawn memory shards from blue rune stones at night
 #[spacetimedb::reducer]
 pub fn spawn_memory_shards_at_night(
@@ -394,13 +488,7 @@ pub fn spawn_memory_shards_at_night(
     let is_full_moon = world_state.is_full_moon;
     
     // Only spawn during night periods (twilight evening to twilight morning)
-    let is_night_period = matches!(
-        time_of_day,
-        crate::world_state::TimeOfDay::TwilightEvening
-            | crate::world_state::TimeOfDay::Night
-            | crate::world_state::TimeOfDay::Midnight
-            | crate::world_state::TimeOfDay::TwilightMorning
-    );
+    let is_night_period = is_rune_charging_period(time_of_day);
     
     if !is_night_period {
         // Not night time, reschedule for later
@@ -502,35 +590,7 @@ pub fn spawn_memory_shards_at_night(
         
         // Spawn all shards for this night at once (2-3 normal, 5-6 full moon)
         let shards_to_spawn = rng.gen_range(min_shards..=max_shards);
-        let mut spawned_count = 0;
-        
-        for _ in 0..shards_to_spawn {
-            let angle = rng.gen_range(0.0..std::f32::consts::TAU);
-            let distance = rng.gen_range(BLUE_RUNE_SHARD_MIN_DISTANCE..BLUE_RUNE_SHARD_SPAWN_RADIUS);
-            let shard_x = rune_stone.pos_x + angle.cos() * distance;
-            let shard_y = rune_stone.pos_y + angle.sin() * distance;
-            
-            // Check if position is valid (not in water, etc.)
-            if !crate::environment::is_position_on_water(ctx, shard_x, shard_y) {
-                let chunk_idx = crate::environment::calculate_chunk_index(shard_x, shard_y);
-                
-                // Create dropped item
-                ctx.db.dropped_item().insert(crate::dropped_item::DroppedItem {
-                    id: 0,
-                    item_def_id: memory_shard_def_id,
-                    quantity: 1,
-                    pos_x: shard_x,
-                    pos_y: shard_y,
-                    chunk_index: chunk_idx,
-                    created_at: current_time,
-                    item_data: None,
-                    spawn_x: None,
-                    spawn_y: None,
-                });
-                
-                spawned_count += 1;
-            }
-        }
+        let spawned_count = spawn_shards_around_rune_stone(ctx, &rune_stone, memory_shard_def_id, shards_to_spawn, &mut rng);
         
         config.shards_spawned_this_night = spawned_count;
         config.last_shard_spawn_time = Some(current_time);
@@ -718,7 +778,7 @@ pub fn spawn_items_at_night(
                 let chunk_idx = crate::environment::calculate_chunk_index(item_x, item_y);
                 
                 // Create dropped item
-                ctx.db.dropped_item().insert(crate::dropped_item::DroppedItem {
+                let inserted = ctx.db.dropped_item().insert(crate::dropped_item::DroppedItem {
                     id: 0,
                     item_def_id,
                     quantity: 1,
@@ -730,6 +790,7 @@ pub fn spawn_items_at_night(
                     spawn_x: None,
                     spawn_y: None,
                 });
+                crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), inserted.pos_x, inserted.pos_y);
                 
                 spawned_count += 1;
             }
@@ -945,7 +1006,7 @@ pub fn spawn_seeds_at_night(
                 let chunk_idx = crate::environment::calculate_chunk_index(seed_x, seed_y);
                 
                 // Create dropped seed item
-                ctx.db.dropped_item().insert(crate::dropped_item::DroppedItem {
+                let inserted = ctx.db.dropped_item().insert(crate::dropped_item::DroppedItem {
                     id: 0,
                     item_def_id: seed_def_id,
                     quantity: 1,
@@ -957,6 +1018,7 @@ pub fn spawn_seeds_at_night(
                     spawn_x: None,
                     spawn_y: None,
                 });
+                crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), inserted.pos_x, inserted.pos_y);
                 
                 spawned_count += 1;
                 spawned_seed_names.push(seed_name.clone());
@@ -0,0 +1,221 @@
+// server/src/safe_zone.rs
+// ------------------------------------
+// Safe zones - areas where players are protected from PvP and hostile NPCs.
+//
+// Zones are configured per kind in the `safe_zone_config` table (seeded with
+// defaults at init, editable without a code change):
+// - ALK central compound and substations (radius scales the station's
+//   interaction_radius so zones keep matching the building restriction)
+// - Fishing village, hunting village and whale bone graveyard (radius in px
+//   around the monument center piece)
+//
+// Each zone can independently block player damage (`blocks_pvp`, enforced via
+// the SafeZone effect checked by combat, projectiles and animal attacks) and
+// hostile night spawns (`blocks_hostile_spawns`).
+//
+// Boundary abuse: a player who steps out of a zone only regains protection
+// SAFE_ZONE_REENTRY_GRACE_SECS after leaving, so ducking out to shoot and
+// stepping back in no longer grants instant immunity.
+// ------------------------------------
+
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+use crate::alk::alk_station as AlkStationTableTrait;
+use crate::monument_part as MonumentPartTableTrait;
+use crate::MonumentType;
+
+// =============================================================================
+// SAFE ZONE CONSTANTS
+// =============================================================================
+
+/// Safe zone radius multipliers for ALK stations (multiples of interaction_radius)
+/// These MUST match the building restriction radii to prevent abuse
+pub const SAFE_ZONE_RADIUS_MULTIPLIER_CENTRAL: f32 = 8.75; // ~2188px for the central compound
+pub const SAFE_ZONE_RADIUS_MULTIPLIER_SUBSTATION: f32 = 3.0; // ~600px for substations
+
+/// Fishing village communal protection radius around the campfire
+pub const FISHING_VILLAGE_SAFE_ZONE_RADIUS: f32 = 600.0;
+
+/// Whale bone graveyard radius (zone ships disabled - the graveyard is PvP territory)
+pub const WHALE_BONE_GRAVEYARD_SAFE_ZONE_RADIUS: f32 = 600.0;
+
+/// Seconds after leaving a safe zone before protection can be regained
+pub const SAFE_ZONE_REENTRY_GRACE_SECS: u64 = 10;
+
+// =============================================================================
+// TYPES & TABLES
+// =============================================================================
+
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SafeZoneKind {
+    AlkCentral,
+    AlkSubstation,
+    FishingVillage,
+    HuntingVillage,
+    WhaleBoneGraveyard,
+}
+
+/// Per-kind safe zone configuration (public so clients can draw zone boundaries)
+#[spacetimedb::table(accessor = safe_zone_config, public)]
+#[derive(Clone, Debug)]
+pub struct SafeZoneConfig {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub kind: SafeZoneKind,
+    /// Master toggle for the zone kind
+    pub enabled: bool,
+    /// Radius in px (ALK kinds: multiplier of the station's interaction_radius)
+    pub radius: f32,
+    /// Players inside get the SafeZone effect (no player/animal/projectile damage)
+    pub blocks_pvp: bool,
+    /// Hostile night NPCs can't spawn inside
+    pub blocks_hostile_spawns: bool,
+}
+
+/// When each player last left a PvP safe zone (for the re-entry grace timer)
+#[spacetimedb::table(accessor = safe_zone_exit)]
+#[derive(Clone, Debug)]
+pub struct SafeZoneExit {
+    #[primary_key]
+    pub player_id: Identity,
+    pub left_at: Timestamp,
+}
+
+// =============================================================================
+// CONFIGURATION
+// =============================================================================
+
+fn default_safe_zone_configs() -> Vec<SafeZoneConfig> {
+    let zone = |kind, enabled, radius| SafeZoneConfig {
+        id: 0,
+        kind,
+        enabled,
+        radius,
+        blocks_pvp: true,
+        blocks_hostile_spawns: true,
+    };
+    vec![
+        zone(SafeZoneKind::AlkCentral, true, SAFE_ZONE_RADIUS_MULTIPLIER_CENTRAL),
+        zone(SafeZoneKind::AlkSubstation, true, SAFE_ZONE_RADIUS_MULTIPLIER_SUBSTATION),
+        zone(SafeZoneKind::FishingVillage, true, FISHING_VILLAGE_SAFE_ZONE_RADIUS),
+        zone(SafeZoneKind::HuntingVillage, true, crate::hunting_village::HUNTING_VILLAGE_SAFE_ZONE_RADIUS),
+        zone(SafeZoneKind::WhaleBoneGraveyard, false, WHALE_BONE_GRAVEYARD_SAFE_ZONE_RADIUS),
+    ]
+}
+
+/// Seeds the default config for any zone kind that doesn't have one yet (idempotent)
+pub fn seed_safe_zone_configs(ctx: &ReducerContext) -> Result<(), String> {
+    let configs = ctx.db.safe_zone_config();
+    for default in default_safe_zone_configs() {
+        if !configs.iter().any(|c| c.kind == default.kind) {
+            log::info!("Seeding safe zone config for {:?} (enabled: {}, radius: {})", default.kind, default.enabled, default.radius);
+            configs.insert(default);
+        }
+    }
+    Ok(())
+}
+
+/// Active zone configs (defaults until the table is seeded)
+fn active_configs(ctx: &ReducerContext) -> Vec<SafeZoneConfig> {
+    let configs: Vec<SafeZoneConfig> = ctx.db.safe_zone_config().iter().collect();
+    let configs = if configs.is_empty() { default_safe_zone_configs() } else { configs };
+    configs.into_iter().filter(|c| c.enabled).collect()
+}
+
+// =============================================================================
+// ZONE CHECKS
+// =============================================================================
+
+/// Returns the config of the first enabled safe zone containing the position that matches `filter`
+fn find_zone_at(ctx: &ReducerContext, pos_x: f32, pos_y: f32, filter: impl Fn(&SafeZoneConfig) -> bool) -> Option<SafeZoneConfig> {
+    let configs: Vec<SafeZoneConfig> = active_configs(ctx).into_iter().filter(|c| filter(c)).collect();
+    if configs.is_empty() {
+        return None;
+    }
+    let config_for = |kind: SafeZoneKind| configs.iter().find(|c| c.kind == kind);
+    let within = |cx: f32, cy: f32, radius: f32| {
+        let dx = pos_x - cx;
+        let dy = pos_y - cy;
+        dx * dx + dy * dy <= radius * radius
+    };
+
+    // ALK stations
+    for station in ctx.db.alk_station().iter() {
+        if !station.is_active {
+            continue;
+        }
+        let kind = if station.station_id == 0 { SafeZoneKind::AlkCentral } else { SafeZoneKind::AlkSubstation };
+        if let Some(config) = config_for(kind) {
+            if within(station.world_pos_x, station.world_pos_y, station.interaction_radius * config.radius) {
+                return Some(config.clone());
+            }
+        }
+    }
+
+    // Monument zones - only the center piece creates the zone
+    for part in ctx.db.monument_part().iter() {
+        if !part.is_center {
+            continue;
+        }
+        let kind = match part.monument_type {
+            MonumentType::FishingVillage => SafeZoneKind::FishingVillage,
+            MonumentType::HuntingVillage => SafeZoneKind::HuntingVillage,
+            MonumentType::WhaleBoneGraveyard => SafeZoneKind::WhaleBoneGraveyard,
+            _ => continue,
+        };
+        if let Some(config) = config_for(kind) {
+            if within(part.world_x, part.world_y, config.radius) {
+                return Some(config.clone());
+            }
+        }
+    }
+
+    None
+}
+
+/// Checks if a position is inside any enabled safe zone
+pub fn is_position_in_safe_zone(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    find_zone_at(ctx, pos_x, pos_y, |_| true).is_some()
+}
+
+/// Checks if a position is inside a safe zone that blocks player damage
+pub fn is_position_in_pvp_safe_zone(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    find_zone_at(ctx, pos_x, pos_y, |c| c.blocks_pvp).is_some()
+}
+
+/// Checks if a position is inside a safe zone where hostile NPCs can't spawn
+pub fn is_position_in_hostile_free_zone(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    find_zone_at(ctx, pos_x, pos_y, |c| c.blocks_hostile_spawns).is_some()
+}
+
+// =============================================================================
+// RE-ENTRY GRACE TIMER
+// =============================================================================
+
+/// Records that a player just left a PvP safe zone
+pub fn record_safe_zone_exit(ctx: &ReducerContext, player_id: Identity) {
+    let exits = ctx.db.safe_zone_exit();
+    let exit = SafeZoneExit { player_id, left_at: ctx.timestamp };
+    if exits.player_id().find(&player_id).is_some() {
+        exits.player_id().update(exit);
+    } else {
+        exits.insert(exit);
+    }
+}
+
+/// True while the player must wait before safe zone protection applies again
+pub fn is_reentry_grace_active(ctx: &ReducerContext, player_id: Identity) -> bool {
+    let Some(exit) = ctx.db.safe_zone_exit().player_id().find(&player_id) else {
+        return false;
+    };
+    match ctx.timestamp.duration_since(exit.left_at) {
+        Some(elapsed) if elapsed.as_secs() < SAFE_ZONE_REENTRY_GRACE_SECS => true,
+        Some(_) => {
+            // Grace over - drop the record
+            ctx.db.safe_zone_exit().player_id().delete(&player_id);
+            false
+        }
+        None => false,
+    }
+}
@@ -0,0 +1,481 @@
+/******************************************************************************
+ *                                                                            *
+ * Security Cameras - CCTV for base surveillance                              *
+ *                                                                            *
+ * Players place security cameras around their base and a monitor station   *
+ * to watch them from. The monitor's owner picks one of their own cameras in *
+ * range (view_security_camera) and receives a live feed of the players and  *
+ * animals the camera can see: a scheduled pulse writes one                  *
+ * camera_feed_entry row per entity in view of every watched camera, and     *
+ * row-level security only sends a client the entries of the camera it is    *
+ * watching. Walls, closed doors and shelters block a camera's view.         *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, ScheduleAt, Table, Timestamp, TimeDuration};
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::Player;
+use crate::items::{
+    InventoryItem,
+    inventory_item as InventoryItemTableTrait,
+    item_definition as ItemDefinitionTableTrait,
+};
+use crate::wild_animal_npc::{wild_animal as WildAnimalTableTrait, AnimalSpecies};
+use crate::player_inventory::find_first_empty_player_slot;
+use crate::environment::calculate_chunk_index;
+
+// --- Constants ---
+pub const SECURITY_CAMERA_ITEM_NAME: &str = "Security Camera";
+pub const SECURITY_MONITOR_ITEM_NAME: &str = "Security Monitor";
+
+/// How far a camera sees
+pub const CAMERA_VIEW_RADIUS: f32 = 480.0;
+pub const CAMERA_VIEW_RADIUS_SQUARED: f32 = CAMERA_VIEW_RADIUS * CAMERA_VIEW_RADIUS;
+/// Cameras further than this from a monitor can't be watched on it
+pub const MONITOR_CAMERA_LINK_RANGE: f32 = 2000.0;
+/// How often watched camera feeds are refreshed
+pub const CAMERA_FEED_INTERVAL_MS: u64 = 1000;
+
+const SECURITY_PLACEMENT_MAX_DISTANCE: f32 = 150.0;
+/// Also how close a viewer has to stay to their monitor to keep watching
+const SECURITY_INTERACTION_DISTANCE_SQUARED: f32 = 120.0 * 120.0;
+
+/// --- Security Camera Data Structure ---
+#[spacetimedb::table(accessor = security_camera, public)]
+#[derive(Clone, Debug)]
+pub struct SecurityCamera {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub chunk_index: u32,
+    pub placed_by: Identity,
+    pub placed_at: Timestamp,
+}
+
+/// --- Security Monitor Data Structure ---
+#[spacetimedb::table(accessor = security_monitor, public)]
+#[derive(Clone, Debug)]
+pub struct SecurityMonitor {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub chunk_index: u32,
+    pub placed_by: Identity,
+    pub placed_at: Timestamp,
+}
+
+/// A player watching a camera on a monitor (one row per viewer)
+#[spacetimedb::table(accessor = camera_viewer, public)]
+#[derive(Clone, Debug)]
+pub struct CameraViewer {
+    #[primary_key]
+    pub viewer_id: Identity,
+    pub monitor_id: u32,
+    #[index(btree)]
+    pub camera_id: u32,
+    pub started_at: Timestamp,
+}
+
+/// What kind of entity a feed entry shows
+#[derive(Clone, Copy, Debug, PartialEq, spacetimedb::SpacetimeType)]
+pub enum CameraFeedEntityKind {
+    Player,
+    WildAnimal,
+}
+
+/// One entity currently in view of a watched camera
+#[spacetimedb::table(accessor = camera_feed_entry, public)]
+#[derive(Clone, Debug)]
+pub struct CameraFeedEntry {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub camera_id: u32,
+    pub kind: CameraFeedEntityKind,
+    pub player_id: Option<Identity>,
+    pub animal_id: Option<u64>,
+    pub species: Option<AnimalSpecies>,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub seen_at: Timestamp,
+}
+
+// --- Row-Level Security Filters ---
+
+#[client_visibility_filter]
+const ONLY_OWN_CAMERA_VIEWER: Filter = Filter::Sql("SELECT * FROM camera_viewer WHERE viewer_id = :sender");
+
+// Feed entries are only sent for the camera the client is watching
+#[client_visibility_filter]
+const CAMERA_FEED_OF_WATCHED_CAMERA: Filter = Filter::Sql(
+    "SELECT camera_feed_entry.* FROM camera_feed_entry JOIN camera_viewer ON camera_feed_entry.camera_id = camera_viewer.camera_id WHERE camera_viewer.viewer_id = :sender"
+);
+
+// --- Camera Feed Schedule ---
+#[spacetimedb::table(accessor = camera_feed_schedule, scheduled(process_camera_feeds))]
+#[derive(Clone, Debug)]
+pub struct CameraFeedSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// --- Initialization ---
+pub fn init_security_camera_system(ctx: &ReducerContext) -> Result<(), String> {
+    if ctx.db.camera_feed_schedule().iter().next().is_some() {
+        return Ok(());
+    }
+
+    let feed_interval = TimeDuration::from_micros(CAMERA_FEED_INTERVAL_MS as i64 * 1_000);
+    crate::try_insert_schedule!(
+        ctx.db.camera_feed_schedule(),
+        CameraFeedSchedule {
+            id: 0,
+            scheduled_at: ScheduleAt::Interval(feed_interval),
+        },
+        "Security camera feed"
+    );
+    log::info!("[SecurityCameraInit] Camera feeds refresh every {}ms", CAMERA_FEED_INTERVAL_MS);
+    Ok(())
+}
+
+// --- Helper Functions ---
+
+/// Validates the player is alive and within reach of a position
+fn validate_security_interaction(ctx: &ReducerContext, pos_x: f32, pos_y: f32, what: &str) -> Result<Player, String> {
+    let player = ctx.db.player().identity().find(&ctx.sender())
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead {
+        return Err(format!("Cannot use {} while dead.", what));
+    }
+    if player.is_knocked_out {
+        return Err(format!("Cannot use {} while knocked out.", what));
+    }
+
+    let dx = pos_x - player.position_x;
+    let dy = pos_y - player.position_y;
+    if dx * dx + dy * dy > SECURITY_INTERACTION_DISTANCE_SQUARED {
+        return Err(format!("Too far away from {}.", what));
+    }
+
+    Ok(player)
+}
+
+/// Validates placement of a security item and consumes it. Returns the chunk index of the position.
+fn consume_security_item_for_placement(ctx: &ReducerContext, item_instance_id: u64, item_name: &str, world_x: f32, world_y: f32) -> Result<u32, String> {
+    let sender_id = ctx.sender();
+
+    crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
+
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+
+    if player.is_dead {
+        return Err(format!("Cannot place {} while dead.", item_name));
+    }
+
+    // --- Validate item ---
+    let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+        .ok_or_else(|| "Item not found.".to_string())?;
+
+    if item.location.is_player_bound() != Some(sender_id) {
+        return Err("You don't own this item.".to_string());
+    }
+
+    let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
+        .ok_or_else(|| "Item definition not found.".to_string())?;
+
+    if item_def.name != item_name {
+        return Err(format!("This item is not a {}.", item_name));
+    }
+
+    // --- Validate placement position ---
+    let dx = world_x - player.position_x;
+    let dy = world_y - player.position_y;
+    if dx * dx + dy * dy > SECURITY_PLACEMENT_MAX_DISTANCE * SECURITY_PLACEMENT_MAX_DISTANCE {
+        return Err(format!("Cannot place {} that far away.", item_name));
+    }
+
+    if crate::environment::is_position_on_water(ctx, world_x, world_y) {
+        return Err(format!("Cannot place {} on water.", item_name));
+    }
+
+    if crate::building::is_position_on_wall(ctx, world_x, world_y) {
+        return Err(format!("Cannot place {} on a wall.", item_name));
+    }
+
+    crate::placeable_collision::check_placeable_overlap(ctx, world_x, world_y, 32.0, 32.0, true)?;
+
+    // Consume one item
+    if item.quantity > 1 {
+        let mut updated_item = item.clone();
+        updated_item.quantity -= 1;
+        ctx.db.inventory_item().instance_id().update(updated_item);
+    } else {
+        ctx.db.inventory_item().instance_id().delete(&item_instance_id);
+    }
+
+    Ok(calculate_chunk_index(world_x, world_y))
+}
+
+/// Gives a picked up security item back to the player
+fn return_security_item(ctx: &ReducerContext, player_id: Identity, item_name: &str) -> Result<(), String> {
+    let item_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == item_name)
+        .ok_or_else(|| format!("{} item definition not found.", item_name))?;
+
+    let new_location = find_first_empty_player_slot(ctx, player_id)
+        .ok_or_else(|| format!("Player inventory is full, cannot pickup {}.", item_name))?;
+
+    ctx.db.inventory_item().try_insert(InventoryItem {
+        instance_id: 0, // Auto-inc
+        item_def_id: item_def.id,
+        quantity: 1,
+        location: new_location,
+        item_data: None,
+    }).map_err(|e| format!("Failed to insert {} item: {}", item_name, e))?;
+    Ok(())
+}
+
+fn clear_camera_feed(ctx: &ReducerContext, camera_id: u32) {
+    let feed = ctx.db.camera_feed_entry();
+    for entry in feed.camera_id().filter(camera_id).collect::<Vec<_>>() {
+        feed.id().delete(entry.id);
+    }
+}
+
+/// Stops a player watching a camera, clearing the camera's feed if nobody else is watching it
+fn stop_viewing(ctx: &ReducerContext, viewer_id: Identity) {
+    let Some(viewer) = ctx.db.camera_viewer().viewer_id().find(&viewer_id) else {
+        return;
+    };
+    ctx.db.camera_viewer().viewer_id().delete(&viewer_id);
+    if ctx.db.camera_viewer().camera_id().filter(viewer.camera_id).next().is_none() {
+        clear_camera_feed(ctx, viewer.camera_id);
+    }
+}
+
+/// Rewrites a camera's feed with the players and animals currently in its view
+fn refresh_camera_feed(ctx: &ReducerContext, camera: &SecurityCamera) {
+    clear_camera_feed(ctx, camera.id);
+    let in_view = |x: f32, y: f32| {
+        crate::utils::get_distance_squared(camera.pos_x, camera.pos_y, x, y) <= CAMERA_VIEW_RADIUS_SQUARED &&
+            crate::animal_collision::has_clear_line_of_sight(ctx, camera.pos_x, camera.pos_y, x, y)
+    };
+
+    let feed = ctx.db.camera_feed_entry();
+    for player in ctx.db.player().iter() {
+        if player.is_dead || !player.is_online || !in_view(player.position_x, player.position_y) {
+            continue;
+        }
+        feed.insert(CameraFeedEntry {
+            id: 0,
+            camera_id: camera.id,
+            kind: CameraFeedEntityKind::Player,
+            player_id: Some(player.identity),
+            animal_id: None,
+            species: None,
+            pos_x: player.position_x,
+            pos_y: player.position_y,
+            seen_at: ctx.timestamp,
+        });
+    }
+    for animal in ctx.db.wild_animal().iter().map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row)) {
+        if animal.health <= 0.0 || !in_view(animal.pos_x, animal.pos_y) {
+            continue;
+        }
+        feed.insert(CameraFeedEntry {
+            id: 0,
+            camera_id: camera.id,
+            kind: CameraFeedEntityKind::WildAnimal,
+            player_id: None,
+            animal_id: Some(animal.id),
+            species: Some(animal.species),
+            pos_x: animal.pos_x,
+            pos_y: animal.pos_y,
+            seen_at: ctx.timestamp,
+        });
+    }
+}
+
+// --- Reducers ---
+
+/// --- Place Security Camera ---
+#[spacetimedb::reducer]
+pub fn place_security_camera(ctx: &ReducerContext, item_instance_id: u64, world_x: f32, world_y: f32) -> Result<(), String> {
+    let chunk_index = consume_security_item_for_placement(ctx, item_instance_id, SECURITY_CAMERA_ITEM_NAME, world_x, world_y)?;
+
+    ctx.db.security_camera().try_insert(SecurityCamera {
+        id: 0, // Auto-increment
+        pos_x: world_x,
+        pos_y: world_y,
+        chunk_index,
+        placed_by: ctx.sender(),
+        placed_at: ctx.timestamp,
+    }).map_err(|e| format!("Failed to place security camera: {}", e))?;
+
+    log::info!("Player {:?} placed security camera at ({:.1}, {:.1})", ctx.sender(), world_x, world_y);
+    Ok(())
+}
+
+/// --- Place Security Monitor ---
+#[spacetimedb::reducer]
+pub fn place_security_monitor(ctx: &ReducerContext, item_instance_id: u64, world_x: f32, world_y: f32) -> Result<(), String> {
+    let chunk_index = consume_security_item_for_placement(ctx, item_instance_id, SECURITY_MONITOR_ITEM_NAME, world_x, world_y)?;
+
+    ctx.db.security_monitor().try_insert(SecurityMonitor {
+        id: 0, // Auto-increment
+        pos_x: world_x,
+        pos_y: world_y,
+        chunk_index,
+        placed_by: ctx.sender(),
+        placed_at: ctx.timestamp,
+    }).map_err(|e| format!("Failed to place security monitor: {}", e))?;
+
+    log::info!("Player {:?} placed security monitor at ({:.1}, {:.1})", ctx.sender(), world_x, world_y);
+    Ok(())
+}
+
+/// --- View Security Camera ---
+/// Starts (or switches) watching one of the player's own cameras on their monitor.
+#[spacetimedb::reducer]
+pub fn view_security_camera(ctx: &ReducerContext, monitor_id: u32, camera_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let monitor = ctx.db.security_monitor().id().find(&monitor_id)
+        .ok_or_else(|| format!("Security monitor {} not found.", monitor_id))?;
+    validate_security_interaction(ctx, monitor.pos_x, monitor.pos_y, "security monitor")?;
+
+    if monitor.placed_by != sender_id {
+        return Err("Only the owner of this monitor can use it.".to_string());
+    }
+
+    let camera = ctx.db.security_camera().id().find(&camera_id)
+        .ok_or_else(|| format!("Security camera {} not found.", camera_id))?;
+    if camera.placed_by != sender_id {
+        return Err("You can only watch your own cameras.".to_string());
+    }
+    if crate::utils::get_distance_squared(monitor.pos_x, monitor.pos_y, camera.pos_x, camera.pos_y)
+        > MONITOR_CAMERA_LINK_RANGE * MONITOR_CAMERA_LINK_RANGE {
+        return Err("That camera is out of range of this monitor.".to_string());
+    }
+
+    stop_viewing(ctx, sender_id);
+    ctx.db.camera_viewer().insert(CameraViewer {
+        viewer_id: sender_id,
+        monitor_id,
+        camera_id,
+        started_at: ctx.timestamp,
+    });
+    refresh_camera_feed(ctx, &camera);
+
+    log::info!("Player {:?} is watching security camera {} on monitor {}", sender_id, camera_id, monitor_id);
+    Ok(())
+}
+
+/// --- Stop Viewing Security Camera ---
+#[spacetimedb::reducer]
+pub fn stop_viewing_security_camera(ctx: &ReducerContext) -> Result<(), String> {
+    stop_viewing(ctx, ctx.sender());
+    Ok(())
+}
+
+/// --- Pickup Security Camera ---
+/// Returns a camera to the player who placed it, cutting its feed.
+#[spacetimedb::reducer]
+pub fn pickup_security_camera(ctx: &ReducerContext, camera_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let camera = ctx.db.security_camera().id().find(&camera_id)
+        .ok_or_else(|| format!("Security camera {} not found.", camera_id))?;
+    validate_security_interaction(ctx, camera.pos_x, camera.pos_y, "security camera")?;
+
+    if camera.placed_by != sender_id {
+        return Err("You can only pick up your own security cameras.".to_string());
+    }
+
+    return_security_item(ctx, sender_id, SECURITY_CAMERA_ITEM_NAME)?;
+
+    for viewer in ctx.db.camera_viewer().camera_id().filter(camera_id).collect::<Vec<_>>() {
+        ctx.db.camera_viewer().viewer_id().delete(&viewer.viewer_id);
+    }
+    clear_camera_feed(ctx, camera_id);
+    ctx.db.security_camera().id().delete(camera_id);
+
+    log::info!("Player {:?} picked up security camera {}", sender_id, camera_id);
+    Ok(())
+}
+
+/// --- Pickup Security Monitor ---
+#[spacetimedb::reducer]
+pub fn pickup_security_monitor(ctx: &ReducerContext, monitor_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let monitor = ctx.db.security_monitor().id().find(&monitor_id)
+        .ok_or_else(|| format!("Security monitor {} not found.", monitor_id))?;
+    validate_security_interaction(ctx, monitor.pos_x, monitor.pos_y, "security monitor")?;
+
+    if monitor.placed_by != sender_id {
+        return Err("You can only pick up your own security monitors.".to_string());
+    }
+
+    return_security_item(ctx, sender_id, SECURITY_MONITOR_ITEM_NAME)?;
+
+    let viewers: Vec<Identity> = ctx.db.camera_viewer().iter()
+        .filter(|viewer| viewer.monitor_id == monitor_id)
+        .map(|viewer| viewer.viewer_id)
+        .collect();
+    for viewer_id in viewers {
+        stop_viewing(ctx, viewer_id);
+    }
+    ctx.db.security_monitor().id().delete(monitor_id);
+
+    log::info!("Player {:?} picked up security monitor {}", sender_id, monitor_id);
+    Ok(())
+}
+
+// --- Scheduled Feed ---
+
+/// Refreshes the feed of every watched camera and drops viewers who left their monitor
+#[spacetimedb::reducer]
+pub fn process_camera_feeds(ctx: &ReducerContext, _schedule: CameraFeedSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("process_camera_feeds may only be called by the scheduler.".to_string());
+    }
+
+    let viewers: Vec<CameraViewer> = ctx.db.camera_viewer().iter().collect();
+    let mut watched_cameras: Vec<u32> = Vec::new();
+    for viewer in viewers {
+        let still_watching = match (
+            ctx.db.player().identity().find(&viewer.viewer_id),
+            ctx.db.security_monitor().id().find(&viewer.monitor_id),
+        ) {
+            (Some(player), Some(monitor)) => {
+                player.is_online && !player.is_dead && !player.is_knocked_out &&
+                    crate::utils::get_distance_squared(player.position_x, player.position_y, monitor.pos_x, monitor.pos_y)
+                        <= SECURITY_INTERACTION_DISTANCE_SQUARED
+            }
+            _ => false,
+        };
+        if !still_watching {
+            stop_viewing(ctx, viewer.viewer_id);
+        } else if !watched_cameras.contains(&viewer.camera_id) {
+            watched_cameras.push(viewer.camera_id);
+        }
+    }
+
+    for camera_id in watched_cameras {
+        if let Some(camera) = ctx.db.security_camera().id().find(&camera_id) {
+            refresh_camera_feed(ctx, &camera);
+        }
+    }
+    Ok(())
+}
@@ -6,7 +6,6 @@
  ******************************************************************************/
 
 use spacetimedb::{Identity, Timestamp, ReducerContext, Table, log};
-use rand::Rng;
 
 // Import table traits and concrete types
 use crate::Player; // Corrected import for Player struct
@@ -19,6 +18,7 @@ use crate::items::{
 use crate::active_equipment::active_equipment as ActiveEquipmentTableTrait;
 use crate::environment::calculate_chunk_index;
 use crate::combat::AttackResult; // Import combat types
+use crate::building::{StructureRef, StructureDamageType};
 use crate::models::TargetType; // Import TargetType directly from models
 
 // Import resource modules for cleanup
@@ -161,6 +161,9 @@ pub fn place_shelter(ctx: &ReducerContext, item_instance_id: u64, world_x: f32,
     // Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
     crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     // 1. Validate Player and Placement Rules
     let player = players.identity().find(sender_id)
         .ok_or_else(|| "Player not found".to_string())?;
@@ -623,14 +626,13 @@ pub fn is_owner_inside_shelter(ctx: &ReducerContext, player_id: Identity, player
     None
 }
 
-/// Applies damage to a shelter and handles destruction
+/// Applies a player's weapon hit to a shelter (or repairs it when holding a repair hammer)
 pub fn damage_shelter(
     ctx: &ReducerContext,
     attacker_id: Identity,
     shelter_id: u32,
     damage: f32,
     timestamp: Timestamp,
-    rng: &mut impl Rng
 ) -> Result<AttackResult, String> {
     // Check if the attacker is using a repair hammer
     if let Some(active_equip) = ctx.db.active_equipment().player_identity().find(attacker_id) {
@@ -646,54 +648,17 @@ pub fn damage_shelter(
         }
     }
 
-    // Original damage logic if not using repair hammer
-    let mut shelters_table = ctx.db.shelter();
-    let mut shelter = shelters_table.id().find(shelter_id)
-        .ok_or_else(|| format!("Target shelter {} disappeared", shelter_id))?;
-
-    if shelter.is_destroyed {
-        return Ok(AttackResult { hit: false, target_type: Some(TargetType::Shelter), resource_granted: None });
-    }
-
-    let old_health = shelter.health;
-    shelter.health = (shelter.health - damage).max(0.0);
-    shelter.last_hit_time = Some(timestamp);
-    shelter.last_damaged_by = Some(attacker_id);
-
-    log::info!(
-        "Player {:?} hit Shelter {} for {:.1} damage. Health: {:.1} -> {:.1}",
-        attacker_id, shelter_id, damage, old_health, shelter.health
-    );
-
-    if shelter.health <= 0.0 {
-        shelter.is_destroyed = true;
-        shelter.destroyed_at = Some(timestamp);
-        
-        // Update shelter to mark as destroyed before deleting, so clients see the state change
-        shelters_table.id().update(shelter.clone());
-        // Then delete the shelter entity
-        shelters_table.id().delete(shelter_id);
-        crate::spatial_grid::invalidate_static_grid();
-
-        log::info!(
-            "Shelter {} destroyed by player {:?}. Consider dropping constituent materials.",
-            shelter_id, attacker_id
-        );
-
-        // TODO: Implement logic to drop some constituent materials (e.g., wood, stone, fiber)
-        // Example: grant_resource(ctx, attacker_id, "Wood", rng.gen_range(50..=150))?;
-        // This would require shelter to store its original crafter or make resources drop at location.
-        // For now, just logs a message.
-
-    } else {
-        shelters_table.id().update(shelter);
+    match crate::building::apply_structure_damage(ctx, StructureRef::Shelter(shelter_id), damage, StructureDamageType::Weapon, Some(attacker_id)) {
+        Ok(_) => Ok(AttackResult {
+            hit: true,
+            target_type: Some(TargetType::Shelter),
+            resource_granted: None, // TODO: drop some constituent materials on destruction
+        }),
+        Err(e) => {
+            log::debug!("Shelter {} not damaged: {}", shelter_id, e);
+            Ok(AttackResult { hit: false, target_type: Some(TargetType::Shelter), resource_granted: None })
+        }
     }
-
-    Ok(AttackResult {
-        hit: true,
-        target_type: Some(TargetType::Shelter),
-        resource_granted: None, // No direct resource grant on hit, only on destruction (TODO)
-    })
 }
 
 /// Checks if a projectile path intersects with any shelter walls
@@ -0,0 +1,362 @@
+/******************************************************************************
+ *                                                                            *
+ * Defines placeable wooden signs and banners with player-editable text.     *
+ * Text is length-limited and profanity-filtered. Inside a hearth's          *
+ * privilege zone only authorized players may edit or pick up a sign;        *
+ * on unclaimed land only the player who placed it may. Signs outside any    *
+ * privilege zone slowly decay (processed alongside building decay).         *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::Player;
+use crate::items::{
+    InventoryItem,
+    inventory_item as InventoryItemTableTrait,
+    item_definition as ItemDefinitionTableTrait,
+};
+use crate::player_inventory::find_first_empty_player_slot;
+use crate::environment::calculate_chunk_index;
+
+// --- Constants ---
+pub const SIGN_TYPE_WOODEN: u8 = 0;
+pub const SIGN_TYPE_BANNER: u8 = 1;
+
+pub const WOODEN_SIGN_MAX_HEALTH: f32 = 200.0;
+pub const BANNER_MAX_HEALTH: f32 = 100.0;
+
+/// Maximum characters of text on a sign (matches client-side input limit)
+pub const SIGN_MAX_TEXT_LENGTH: usize = 120;
+/// Maximum number of text lines on a sign
+pub const SIGN_MAX_LINES: usize = 4;
+
+const SIGN_PLACEMENT_MAX_DISTANCE: f32 = 150.0;
+const SIGN_INTERACTION_DISTANCE_SQUARED: f32 = 120.0 * 120.0;
+
+// --- Decay constants ---
+/// Decay damage applied each building decay interval (15 min) to unclaimed signs
+/// Wooden sign lasts ~12.5 hours, banner ~6 hours outside a privilege zone
+pub const SIGN_DECAY_DAMAGE_PER_INTERVAL: f32 = 4.0;
+/// Signs don't decay during the first hour after placement
+pub const SIGN_DECAY_GRACE_PERIOD_SECONDS: u64 = 3600;
+
+/// Words masked out of sign text (matched case-insensitively, also inside longer words)
+const BLOCKED_WORDS: &[&str] = &[
+    "fuck", "shit", "cunt", "bitch", "asshole", "bastard",
+    "dick", "cock", "pussy", "whore", "slut", "faggot",
+];
+
+/// --- Sign Data Structure ---
+/// A placed sign or banner showing player-written text.
+#[spacetimedb::table(accessor = sign, public)]
+#[derive(Clone, Debug)]
+pub struct Sign {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub sign_type: u8, // 0 = Wooden Sign, 1 = Banner
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub chunk_index: u32,
+    pub placed_by: Identity,
+    pub placed_at: Timestamp,
+
+    pub text: String,
+    pub last_edited_by: Option<Identity>,
+    pub last_edited_at: Option<Timestamp>,
+
+    pub health: f32,
+    pub max_health: f32,
+    pub is_destroyed: bool,
+    pub destroyed_at: Option<Timestamp>,
+}
+
+// --- Helper Functions ---
+
+/// Item name for a sign type
+fn get_item_name_for_sign_type(sign_type: u8) -> &'static str {
+    match sign_type {
+        SIGN_TYPE_BANNER => "Banner",
+        _ => "Wooden Sign",
+    }
+}
+
+/// Sign type for an item name, if the item is a sign
+fn get_sign_type_for_item_name(item_name: &str) -> Option<u8> {
+    match item_name {
+        "Wooden Sign" => Some(SIGN_TYPE_WOODEN),
+        "Banner" => Some(SIGN_TYPE_BANNER),
+        _ => None,
+    }
+}
+
+/// Max health for a sign type
+fn get_max_health_for_sign_type(sign_type: u8) -> f32 {
+    match sign_type {
+        SIGN_TYPE_BANNER => BANNER_MAX_HEALTH,
+        _ => WOODEN_SIGN_MAX_HEALTH,
+    }
+}
+
+/// Replaces blocked words with asterisks, keeping the rest of the text intact
+pub fn filter_profanity(text: &str) -> String {
+    // ASCII lowercasing keeps byte offsets aligned with the original text
+    let lowered = text.to_ascii_lowercase();
+    let mut masked = vec![false; text.len()];
+
+    for word in BLOCKED_WORDS {
+        let mut search_from = 0;
+        while let Some(found) = lowered[search_from..].find(word) {
+            let start = search_from + found;
+            let end = start + word.len();
+            masked[start..end].iter_mut().for_each(|m| *m = true);
+            search_from = end;
+        }
+    }
+
+    text.char_indices()
+        .map(|(index, c)| if masked[index] { '*' } else { c })
+        .collect()
+}
+
+/// Validates and cleans up player-submitted sign text
+fn sanitize_sign_text(text: &str) -> Result<String, String> {
+    // Drop control characters other than line breaks
+    let cleaned: String = text.chars()
+        .filter(|c| *c == '\n' || !c.is_control())
+        .collect();
+    let cleaned = cleaned.trim();
+
+    if cleaned.chars().count() > SIGN_MAX_TEXT_LENGTH {
+        return Err(format!("Sign text too long (max {} characters).", SIGN_MAX_TEXT_LENGTH));
+    }
+    if cleaned.lines().count() > SIGN_MAX_LINES {
+        return Err(format!("Sign text has too many lines (max {}).", SIGN_MAX_LINES));
+    }
+
+    Ok(filter_profanity(cleaned))
+}
+
+/// Checks if a player may edit or pick up a sign
+/// Inside a hearth's privilege zone this follows hearth privilege; on unclaimed land only the owner
+fn can_player_manage_sign(ctx: &ReducerContext, sign: &Sign, player_id: Identity) -> bool {
+    use crate::homestead_hearth::{is_position_claimed, is_position_privileged};
+
+    if is_position_claimed(ctx, sign.pos_x, sign.pos_y) {
+        is_position_privileged(ctx, sign.pos_x, sign.pos_y, player_id)
+    } else {
+        sign.placed_by == player_id
+    }
+}
+
+/// Validates that the player can reach a sign
+fn validate_sign_interaction(ctx: &ReducerContext, sign_id: u32) -> Result<(Player, Sign), String> {
+    let player = ctx.db.player().identity().find(&ctx.sender())
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot interact with sign while dead.".to_string());
+    }
+    if player.is_knocked_out {
+        return Err("Cannot interact with sign while knocked out.".to_string());
+    }
+
+    let sign = ctx.db.sign().id().find(&sign_id)
+        .ok_or_else(|| format!("Sign {} not found.", sign_id))?;
+
+    if sign.is_destroyed {
+        return Err("Sign is destroyed.".to_string());
+    }
+
+    let dx = sign.pos_x - player.position_x;
+    let dy = sign.pos_y - player.position_y;
+    if dx * dx + dy * dy > SIGN_INTERACTION_DISTANCE_SQUARED {
+        return Err("Too far away from sign.".to_string());
+    }
+
+    Ok((player, sign))
+}
+
+// --- Reducers ---
+
+/// --- Place Sign ---
+/// Places a wooden sign or banner from the player's inventory.
+#[spacetimedb::reducer]
+pub fn place_sign(ctx: &ReducerContext, item_instance_id: u64, world_x: f32, world_y: f32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+
+    // Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
+    crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
+
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, sender_id)?;
+
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot place sign while dead.".to_string());
+    }
+
+    // --- Validate item ---
+    let item = ctx.db.inventory_item().instance_id().find(&item_instance_id)
+        .ok_or_else(|| "Item not found.".to_string())?;
+
+    if item.location.is_player_bound() != Some(sender_id) {
+        return Err("You don't own this item.".to_string());
+    }
+
+    let item_def = ctx.db.item_definition().id().find(&item.item_def_id)
+        .ok_or_else(|| "Item definition not found.".to_string())?;
+
+    let sign_type = get_sign_type_for_item_name(&item_def.name)
+        .ok_or_else(|| "This item is not a sign.".to_string())?;
+
+    // --- Validate placement position ---
+    let dx = world_x - player.position_x;
+    let dy = world_y - player.position_y;
+    if dx * dx + dy * dy > SIGN_PLACEMENT_MAX_DISTANCE * SIGN_PLACEMENT_MAX_DISTANCE {
+        return Err("Cannot place sign that far away.".to_string());
+    }
+
+    if crate::environment::is_position_on_water(ctx, world_x, world_y) {
+        return Err("Cannot place sign on water.".to_string());
+    }
+
+    if crate::building::is_position_on_wall(ctx, world_x, world_y) {
+        return Err("Cannot place sign on a wall.".to_string());
+    }
+
+    // Prevent overlapping other placeables
+    crate::placeable_collision::check_placeable_overlap(ctx, world_x, world_y, 32.0, 32.0, true)?;
+
+    let max_health = get_max_health_for_sign_type(sign_type);
+    let new_sign = Sign {
+        id: 0, // Auto-increment
+        sign_type,
+        pos_x: world_x,
+        pos_y: world_y,
+        chunk_index: calculate_chunk_index(world_x, world_y),
+        placed_by: sender_id,
+        placed_at: ctx.timestamp,
+        text: String::new(),
+        last_edited_by: None,
+        last_edited_at: None,
+        health: max_health,
+        max_health,
+        is_destroyed: false,
+        destroyed_at: None,
+    };
+
+    ctx.db.sign().try_insert(new_sign)
+        .map_err(|e| format!("Failed to place sign: {}", e))?;
+
+    // Consume one sign item
+    if item.quantity > 1 {
+        let mut updated_item = item.clone();
+        updated_item.quantity -= 1;
+        ctx.db.inventory_item().instance_id().update(updated_item);
+    } else {
+        ctx.db.inventory_item().instance_id().delete(&item_instance_id);
+    }
+
+    log::info!("Player {:?} placed {} at ({:.1}, {:.1})", sender_id, item_def.name, world_x, world_y);
+    Ok(())
+}
+
+/// --- Set Sign Text ---
+/// Replaces a sign's text after length checks and profanity filtering.
+#[spacetimedb::reducer]
+pub fn set_sign_text(ctx: &ReducerContext, sign_id: u32, text: String) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let (_player, mut sign) = validate_sign_interaction(ctx, sign_id)?;
+
+    if !can_player_manage_sign(ctx, &sign, sender_id) {
+        return Err("You don't have permission to edit this sign.".to_string());
+    }
+
+    sign.text = sanitize_sign_text(&text)?;
+    sign.last_edited_by = Some(sender_id);
+    sign.last_edited_at = Some(ctx.timestamp);
+    ctx.db.sign().id().update(sign);
+
+    log::info!("Player {:?} edited sign {}", sender_id, sign_id);
+    Ok(())
+}
+
+/// --- Pickup Sign ---
+/// Returns the sign item to the player's inventory. Text is discarded.
+#[spacetimedb::reducer]
+pub fn pickup_sign(ctx: &ReducerContext, sign_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let (_player, sign) = validate_sign_interaction(ctx, sign_id)?;
+
+    if !can_player_manage_sign(ctx, &sign, sender_id) {
+        return Err("You don't have permission to pick up this sign.".to_string());
+    }
+
+    let item_name = get_item_name_for_sign_type(sign.sign_type);
+    let item_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == item_name)
+        .ok_or_else(|| format!("{} item definition not found.", item_name))?;
+
+    let new_location = find_first_empty_player_slot(ctx, sender_id)
+        .ok_or_else(|| format!("Player inventory is full, cannot pickup {}.", item_name))?;
+
+    ctx.db.inventory_item().try_insert(InventoryItem {
+        instance_id: 0, // Auto-inc
+        item_def_id: item_def.id,
+        quantity: 1,
+        location: new_location,
+        item_data: None,
+    }).map_err(|e| format!("Failed to insert {} item: {}", item_name, e))?;
+
+    ctx.db.sign().id().delete(sign_id);
+
+    log::info!("Player {:?} picked up {} {}", sender_id, item_name, sign_id);
+    Ok(())
+}
+
+// --- Decay ---
+
+/// Applies decay to signs outside every hearth privilege zone.
+/// Called from the building decay schedule so signs share its interval.
+pub fn process_sign_decay(ctx: &ReducerContext) {
+    let signs = ctx.db.sign();
+    let grace_period_micros = (SIGN_DECAY_GRACE_PERIOD_SECONDS as i64) * 1_000_000;
+    let now_micros = ctx.timestamp.to_micros_since_unix_epoch();
+
+    let mut signs_to_update = Vec::new();
+    for sign in signs.iter() {
+        if sign.is_destroyed {
+            continue;
+        }
+
+        let time_since_placement = now_micros.saturating_sub(sign.placed_at.to_micros_since_unix_epoch());
+        if time_since_placement < grace_period_micros {
+            continue; // Still in grace period
+        }
+
+        // Signs inside a hearth's privilege zone are maintained by that base
+        if crate::homestead_hearth::is_position_claimed(ctx, sign.pos_x, sign.pos_y) {
+            continue;
+        }
+
+        let mut sign_mut = sign.clone();
+        sign_mut.health = (sign_mut.health - SIGN_DECAY_DAMAGE_PER_INTERVAL).max(0.0);
+        if sign_mut.health <= 0.0 {
+            sign_mut.is_destroyed = true;
+            sign_mut.destroyed_at = Some(ctx.timestamp);
+            log::info!("[Decay] Sign {} at ({:.1}, {:.1}) destroyed by decay", sign.id, sign.pos_x, sign.pos_y);
+        }
+        signs_to_update.push(sign_mut);
+    }
+
+    for sign in signs_to_update {
+        signs.id().update(sign);
+    }
+}
@@ -3,6 +3,10 @@
  * Defines the SleepingBag entity, its data structure, and associated logic.  *
  * Handles placing the sleeping bag, interaction checks, and picking it up.   *
  *                                                                            *
+ * Players can sleep in their own bags at night. Sleepers regenerate health   *
+ * faster and shed a little insanity, but can't move or attack and take      *
+ * extra melee damage. Any hit, dawn, or losing the bag wakes them up.        *
+ *                                                                            *
  ******************************************************************************/
 
 use spacetimedb::{Identity, ReducerContext, Table, Timestamp, TimeDuration, ScheduleAt};
@@ -22,6 +26,11 @@ const SLEEPING_BAG_DETERIORATION_CHECK_INTERVAL_SECS: i64 = 3600; // Check every
 const SLEEPING_BAG_DETERIORATION_DAMAGE_PER_HOUR: f32 = 250.0 / 24.0; // ~10.42 health per hour (takes 24 hours to fully deteriorate)
 const TREE_PROTECTION_DISTANCE_SQ: f32 = 100.0 * 100.0; // 100px protection radius (same as campfire)
 
+// --- Sleep Constants (defaults for SleepSettings) ---
+pub(crate) const DEFAULT_SLEEP_HEALTH_REGEN_MULTIPLIER: f32 = 3.0; // Passive health regen while asleep
+pub(crate) const DEFAULT_SLEEP_INSANITY_REDUCTION_PER_SEC: f32 = 0.05; // ~3 insanity per minute of sleep
+pub(crate) const DEFAULT_SLEEPING_DAMAGE_MULTIPLIER: f32 = 1.5; // Melee hits on a sleeper
+
 // --- Import Dependencies ---
 use crate::environment::calculate_chunk_index;
 use crate::sleeping_bag::sleeping_bag as SleepingBagTableTrait; // Import self trait
@@ -58,9 +67,10 @@ pub struct SleepingBag {
     #[index(btree)]
     pub chunk_index: u32, 
 
-    pub placed_by: Identity, // Who placed this sleeping bag
+    pub placed_by: Identity, // Who owns this sleeping bag (the placer, unless given away)
     pub placed_at: Timestamp, // When it was placed
-    // Add future fields here (e.g., is_occupied, owner_identity for respawn)
+    pub name: String, // Player-chosen label shown in the respawn list
+    pub last_respawn_at: Option<Timestamp>, // Last respawn here (see respawn_points for the shared cooldown)
 
     // --- Destruction Fields ---
     pub health: f32,
@@ -86,6 +96,27 @@ pub struct SleepingBagDeteriorationSchedule {
     pub scheduled_at: ScheduleAt,
 }
 
+/// --- Sleep Settings ---
+/// Tunable sleep effects (singleton, id 0). Defaults apply until an admin sets them.
+#[spacetimedb::table(accessor = sleep_settings, public)]
+#[derive(Clone, Debug)]
+pub struct SleepSettings {
+    #[primary_key]
+    pub id: u32,
+    pub health_regen_multiplier: f32,
+    pub insanity_reduction_per_sec: f32,
+    pub sleeping_damage_multiplier: f32,
+}
+
+pub fn get_sleep_settings(ctx: &ReducerContext) -> SleepSettings {
+    ctx.db.sleep_settings().id().find(0).unwrap_or(SleepSettings {
+        id: 0,
+        health_regen_multiplier: DEFAULT_SLEEP_HEALTH_REGEN_MULTIPLIER,
+        insanity_reduction_per_sec: DEFAULT_SLEEP_INSANITY_REDUCTION_PER_SEC,
+        sleeping_damage_multiplier: DEFAULT_SLEEPING_DAMAGE_MULTIPLIER,
+    })
+}
+
 /******************************************************************************
  *                                REDUCERS                                    *
  ******************************************************************************/
@@ -108,6 +139,9 @@ pub fn place_sleeping_bag(ctx: &ReducerContext, item_instance_id: u64, world_x:
     // Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
     crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     // 1. Find the 'Sleeping Bag' Item Definition ID
     let bag_def_id = item_defs.iter()
         .find(|def| def.name == "Sleeping Bag")
@@ -187,6 +221,8 @@ pub fn place_sleeping_bag(ctx: &ReducerContext, item_instance_id: u64, world_x:
         chunk_index: chunk_idx,
         placed_by: sender_id,
         placed_at: ctx.timestamp,
+        name: crate::respawn_points::DEFAULT_SLEEPING_BAG_NAME.to_string(),
+        last_respawn_at: None,
         // --- Destruction Fields Initialization ---
         health: 250.0,
         max_health: 250.0,
@@ -198,6 +234,7 @@ pub fn place_sleeping_bag(ctx: &ReducerContext, item_instance_id: u64, world_x:
     
     // Schedule deterioration processing for the new sleeping bag
     schedule_sleeping_bag_deterioration(ctx, inserted_bag.id as u64);
+    crate::respawn_points::sync_respawn_points(ctx, sender_id);
 
     log::info!(
         "[PlaceSleepingBag] Successfully placed Sleeping Bag {} at ({:.1}, {:.1}) by {:?}",
@@ -242,12 +279,18 @@ pub fn respawn_at_sleeping_bag(ctx: &ReducerContext, bag_id: u32) -> Result<(),
     }
 
     // 2. Find Sleeping Bag
-    let sleeping_bag = sleeping_bags.id().find(bag_id)
+    let mut sleeping_bag = sleeping_bags.id().find(bag_id)
         .ok_or_else(|| format!("Sleeping Bag {} not found", bag_id))?;
 
     // 3. Verify Ownership
     if sleeping_bag.placed_by != sender_id {
-        return Err("Cannot respawn at a sleeping bag you didn't place.".to_string());
+        return Err("Cannot respawn at a sleeping bag you don't own.".to_string());
+    }
+
+    // 3b. Shared cooldown across nearby bags
+    if let Some(until) = crate::respawn_points::respawn_cooldown_until(ctx, &sleeping_bag) {
+        let remaining_secs = (until.to_micros_since_unix_epoch() - ctx.timestamp.to_micros_since_unix_epoch()) / 1_000_000 + 1;
+        return Err(format!("This sleeping bag can be used again in {} seconds.", remaining_secs));
     }
 
     log::info!(
@@ -296,9 +339,15 @@ pub fn respawn_at_sleeping_bag(ctx: &ReducerContext, bag_id: u32) -> Result<(),
 
     // 4. Respawn Player at Bag Location (Reset stats)
     player.is_dead = false;
+    crate::ghost::clear_ghost(ctx, sender_id);
+    crate::restraints::clear_restraint(ctx, sender_id);
+    crate::spyglass::stop_scoping(ctx, sender_id);
+    player.is_sleeping = false;
+    player.elevation = crate::building_floor::ELEVATION_GROUND; // Bags sit on the ground floor
     player.health = crate::player_stats::PLAYER_MAX_HEALTH; // Use fully qualified path
     player.position_x = sleeping_bag.pos_x;
     player.position_y = sleeping_bag.pos_y;
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(player.identity), player.position_x, player.position_y);
     player.death_timestamp = None; // Clear death timestamp
     // Reset other stats like in respawn_randomly
     player.hunger = PLAYER_STARTING_HUNGER;
@@ -307,6 +356,7 @@ pub fn respawn_at_sleeping_bag(ctx: &ReducerContext, bag_id: u32) -> Result<(),
     player.stamina = 100.0;
     player.insanity = 0.0; // Reset insanity on respawn
     player.shard_carry_start_time = None; // Reset shard carry time on respawn
+    player.oxygen = crate::player_stats::PLAYER_MAX_OXYGEN; // Surface with full breath
     player.jump_start_time_ms = 0;
     player.is_sprinting = false;
     player.last_hit_time = None;
@@ -329,6 +379,7 @@ pub fn respawn_at_sleeping_bag(ctx: &ReducerContext, bag_id: u32) -> Result<(),
     player.last_respawn_time = ctx.timestamp; // Track respawn time for fat accumulation
 
     players.identity().update(player);
+    crate::hallucination::clear_player_hallucinations(ctx, sender_id);
 
     // Ensure item is unequipped on respawn
     match crate::active_equipment::clear_active_item_reducer(ctx, sender_id) {
@@ -341,6 +392,11 @@ pub fn respawn_at_sleeping_bag(ctx: &ReducerContext, bag_id: u32) -> Result<(),
         sender_id, bag_id, sleeping_bag.pos_x, sleeping_bag.pos_y
     );
 
+    // Start the shared respawn cooldown
+    sleeping_bag.last_respawn_at = Some(ctx.timestamp);
+    sleeping_bags.id().update(sleeping_bag);
+    crate::respawn_points::sync_respawn_points(ctx, sender_id);
+
     Ok(())
 }
 
@@ -354,6 +410,72 @@ pub fn interact_with_sleeping_bag(ctx: &ReducerContext, bag_id: u32) -> Result<(
     Ok(())
 }
 
+/// --- Sleep in Sleeping Bag ---
+/// Lies down in one of the player's own sleeping bags. Only possible at night and out of combat.
+#[spacetimedb::reducer]
+pub fn sleep_in_sleeping_bag(ctx: &ReducerContext, bag_id: u32) -> Result<(), String> {
+    let (mut player, sleeping_bag) = validate_sleeping_bag_interaction(ctx, bag_id)?;
+    if sleeping_bag.placed_by != player.identity {
+        return Err("You can only sleep in your own sleeping bag.".to_string());
+    }
+    if player.is_dead || player.is_knocked_out {
+        return Err("Cannot sleep right now.".to_string());
+    }
+    if player.is_sleeping {
+        return Err("You are already asleep.".to_string());
+    }
+    if !is_sleep_time(ctx) {
+        return Err("You can only sleep at night.".to_string());
+    }
+    if player.last_hit_time.is_some() {
+        return Err("You can't sleep while under attack.".to_string());
+    }
+
+    player.is_sleeping = true;
+    player.position_x = sleeping_bag.pos_x;
+    player.position_y = sleeping_bag.pos_y;
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(player.identity), player.position_x, player.position_y);
+    player.elevation = crate::building_floor::ELEVATION_GROUND;
+    player.is_sprinting = false;
+    player.is_aiming_throw = false;
+    player.last_update = ctx.timestamp;
+    ctx.db.player().identity().update(player);
+    log::info!("[Sleep] Player {:?} went to sleep in bag {}", ctx.sender(), bag_id);
+    Ok(())
+}
+
+/// --- Wake Up ---
+#[spacetimedb::reducer]
+pub fn wake_up(ctx: &ReducerContext) -> Result<(), String> {
+    let mut player = ctx.db.player().identity().find(ctx.sender())
+        .ok_or_else(|| "Player not found".to_string())?;
+    if !player.is_sleeping {
+        return Err("You are not asleep.".to_string());
+    }
+    player.is_sleeping = false;
+    player.last_update = ctx.timestamp;
+    ctx.db.player().identity().update(player);
+    Ok(())
+}
+
+/// --- Set Sleep Settings (Admin) ---
+#[spacetimedb::reducer]
+pub fn set_sleep_settings(ctx: &ReducerContext, health_regen_multiplier: f32, insanity_reduction_per_sec: f32, sleeping_damage_multiplier: f32) -> Result<(), String> {
+    if !crate::combat_balance::is_balance_admin(ctx) {
+        return Err("Only admins can change sleep settings.".to_string());
+    }
+    if health_regen_multiplier < 1.0 || insanity_reduction_per_sec < 0.0 || sleeping_damage_multiplier < 1.0 {
+        return Err("Sleep must not slow regen, raise insanity, or reduce damage taken.".to_string());
+    }
+    let settings = SleepSettings { id: 0, health_regen_multiplier, insanity_reduction_per_sec, sleeping_damage_multiplier };
+    if ctx.db.sleep_settings().id().find(0).is_some() {
+        ctx.db.sleep_settings().id().update(settings);
+    } else {
+        ctx.db.sleep_settings().insert(settings);
+    }
+    Ok(())
+}
+
 /// --- Scheduled Deterioration Processing ---
 /// Processes deterioration for a sleeping bag if it's not protected.
 /// This reducer is called periodically for each sleeping bag.
@@ -406,7 +528,9 @@ pub fn process_sleeping_bag_deterioration(ctx: &ReducerContext, schedule_args: S
                 "[SleepingBagDeterioration] Sleeping bag {} has deteriorated completely and been destroyed.",
                 bag_id
             );
+            let owner_id = sleeping_bag.placed_by;
             sleeping_bags.id().update(sleeping_bag);
+            crate::respawn_points::sync_respawn_points(ctx, owner_id);
             // Remove schedule when destroyed
             ctx.db.sleeping_bag_deterioration_schedule().sleeping_bag_id().delete(schedule_args.sleeping_bag_id);
             return Ok(());
@@ -430,6 +554,25 @@ pub fn process_sleeping_bag_deterioration(ctx: &ReducerContext, schedule_args: S
  *                             HELPER FUNCTIONS                               *
  ******************************************************************************/
 
+/// Whether it is dark enough to sleep (evening twilight through to dawn)
+pub fn is_sleep_time(ctx: &ReducerContext) -> bool {
+    use crate::world_state::{world_state as WorldStateTableTrait, TimeOfDay};
+    ctx.db.world_state().iter().next().is_some_and(|ws| matches!(ws.time_of_day,
+        TimeOfDay::TwilightEvening | TimeOfDay::Night | TimeOfDay::Midnight | TimeOfDay::TwilightMorning))
+}
+
+/// Whether a sleeping player should wake up: hit since the last stat tick, daybreak, or no bag under them
+pub fn should_wake(ctx: &ReducerContext, player: &Player) -> bool {
+    if player.last_hit_time.is_some() || !is_sleep_time(ctx) {
+        return true;
+    }
+    !ctx.db.sleeping_bag().iter().any(|bag| {
+        let dx = bag.pos_x - player.position_x;
+        let dy = bag.pos_y - player.position_y;
+        !bag.is_destroyed && bag.placed_by == player.identity && dx * dx + dy * dy < 1.0
+    })
+}
+
 /// --- Validate Sleeping Bag Interaction ---
 /// Checks if a player is close enough to interact with a specific sleeping bag.
 fn validate_sleeping_bag_interaction(
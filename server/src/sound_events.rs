@@ -1,4 +1,4 @@
-use spacetimedb::{table, Identity, Timestamp, ReducerContext, Table, reducer, SpacetimeType, ScheduleAt, TimeDuration};
+use spacetimedb::{table, client_visibility_filter, Filter, Identity, Timestamp, ReducerContext, Table, reducer, SpacetimeType, ScheduleAt, TimeDuration};
 use rand::Rng;
 
 // --- Sound Event Types ---
@@ -79,6 +79,8 @@ pub enum SoundType {
     FoundationTwigDestroyed,   // twig_foundation_destroyed.mp3 (1 variation - when twig foundation is destroyed)
     ItemThrown,                // item_thrown.mp3 (1 variation - when a weapon/item is thrown)
     BreakItem,                 // break_item.mp3 (1 variation - when arrows/projectiles break on impact)
+    ChainsawCut,               // chainsaw_cut.mp3 (1 variation - loud powered tool, carries far)
+    JackhammerDrill,           // jackhammer_drill.mp3 (1 variation - loud powered tool, carries far)
     ErrorResources,           // error_resources.mp3 (1 variation - when player doesn't have enough resources)
     ErrorCantPickUpCauldron,  // error_cant_pick_up_cauldron.mp3 (1 variation - when trying to pick up cauldron with contents)
     DoneCooking,              // done_cooking.mp3 (1 variation - when items finish cooking in campfire)
@@ -136,6 +138,8 @@ pub enum SoundType {
     UnravelRope,             // unravel_rope.mp3 (1 variation - for unraveling rope into plant fiber)
     DroneFlying,             // plane_flying.mp3 (3 variations - eerie drone flyover across the island)
     ChewingGum,             // chewing_gum.mp3 (1 variation - continuous looping sound when player chews gum)
+    HowlWolf,               // howl_wolf.mp3 (1 variation - wounded wolf calling the pack for reinforcements)
+    AlarmTrap,              // alarm_trap.mp3 (1 variation - tripwire alarm rattling when an intruder crosses it)
     // Add more as needed - extensible system
 }
 
@@ -214,6 +218,8 @@ impl SoundType {
             SoundType::FoundationTwigDestroyed => "twig_foundation_destroyed",
             SoundType::ItemThrown => "item_thrown",
             SoundType::BreakItem => "break_item",
+            SoundType::ChainsawCut => "chainsaw_cut",
+            SoundType::JackhammerDrill => "jackhammer_drill",
             SoundType::ErrorResources => "error_resources",
             SoundType::ErrorCantPickUpCauldron => "error_cant_pick_up_cauldron",
             SoundType::DoneCooking => "done_cooking",
@@ -271,6 +277,8 @@ impl SoundType {
             SoundType::UnravelRope => "unravel_rope",
             SoundType::DroneFlying => "plane_flying",
             SoundType::ChewingGum => "chewing_gum",
+            SoundType::HowlWolf => "howl_wolf",
+            SoundType::AlarmTrap => "alarm_trap",
         }
     }
 
@@ -345,6 +353,8 @@ impl SoundType {
             SoundType::FoundationTwigDestroyed => 1,
             SoundType::ItemThrown => 1,
             SoundType::BreakItem => 1,
+            SoundType::ChainsawCut => 1,
+            SoundType::JackhammerDrill => 1,
             SoundType::ErrorResources => 3, // error_resources.mp3, error_resources2.mp3, error_resources3.mp3
             SoundType::ErrorCantPickUpCauldron => 1, // error_cant_pick_up_cauldron.mp3 (single variation)
             SoundType::DoneCooking => 1,
@@ -405,6 +415,8 @@ impl SoundType {
             SoundType::UnravelRope => 1, // unravel_rope.mp3 (single variation)
             SoundType::DroneFlying => 3, // plane_flying.mp3, plane_flying1.mp3, plane_flying2.mp3 (3 variations)
             SoundType::ChewingGum => 1, // chewing_gum.mp3 (single variation - continuous looping)
+            SoundType::HowlWolf => 1, // howl_wolf.mp3 (single variation)
+            SoundType::AlarmTrap => 1, // alarm_trap.mp3 (single variation)
         }
     }
 
@@ -445,6 +457,28 @@ pub struct SoundEvent {
     /// Velocity of sound source (px/sec) for Doppler effect. (0,0) = no Doppler.
     pub velocity_x: f32,
     pub velocity_y: f32,
+    pub chunk_index: u32,       // Chunk the sound plays in (for viewport streaming)
+    pub is_global: bool,        // Sent to every client regardless of viewport
+}
+
+/// Sounds audible further than this are sent to every client. Closer sounds only go to
+/// clients streaming the sound's chunk (viewport plus margin covers about this far around a player).
+pub const LOCAL_SOUND_MAX_DISTANCE_PX: f32 = 1500.0;
+
+// --- Row-Level Security Filters ---
+// Local sounds are only delivered to clients whose visible chunks include them (see chunk_streaming).
+
+#[client_visibility_filter]
+const GLOBAL_SOUND_EVENTS: Filter = Filter::Sql("SELECT * FROM sound_event WHERE is_global = true");
+
+#[client_visibility_filter]
+const SOUND_EVENTS_IN_VIEWPORT: Filter = Filter::Sql(
+    "SELECT sound_event.* FROM sound_event JOIN client_visible_chunk ON sound_event.chunk_index = client_visible_chunk.chunk_index WHERE client_visible_chunk.client_identity = :sender"
+);
+
+/// Chunk and global flag for a sound event at a position
+fn sound_scope(pos_x: f32, pos_y: f32, max_distance: f32) -> (u32, bool) {
+    (crate::environment::calculate_chunk_index(pos_x.max(0.0), pos_y.max(0.0)), max_distance > LOCAL_SOUND_MAX_DISTANCE_PX)
 }
 
 /// Continuous sound table - tracks active looping sounds (campfires, lanterns, etc.)
@@ -599,6 +633,7 @@ pub fn emit_sound_at_position_with_distance_and_velocity(
 ) -> Result<(), String> {
     let mut rng = ctx.rng();
     let filename = sound_type.get_random_filename(&mut rng);
+    let (chunk_index, is_global) = sound_scope(pos_x, pos_y, max_distance);
     
     let sound_event = SoundEvent {
         id: 0, // Auto-incremented
@@ -613,6 +648,8 @@ pub fn emit_sound_at_position_with_distance_and_velocity(
         pitch_multiplier: 1.0, // Default pitch multiplier
         velocity_x,
         velocity_y,
+        chunk_index,
+        is_global,
     };
 
     match ctx.db.sound_event().try_insert(sound_event) {
@@ -874,6 +911,7 @@ pub fn emit_shoot_crossbow_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, p
 /// Emit a pistol shooting sound (when pistol is fired)
 pub fn emit_shoot_pistol_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, player_id: Identity) {
     let _ = emit_sound_at_position_with_distance(ctx, SoundType::ShootPistol, pos_x, pos_y, 1.2, 900.0, player_id);
+    register_noise(ctx, pos_x, pos_y, GUNSHOT_NOISE_RADIUS, GUNSHOT_NOISE_INTENSITY, player_id);
 }
 
 /// Emit a turret shooting sound (when turret fires a bullet)
@@ -937,6 +975,11 @@ pub fn emit_wolf_growl_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, playe
     let _ = emit_sound_at_position_with_distance(ctx, SoundType::GrowlWolf, pos_x, pos_y, 1.2, 800.0, player_id);
 }
 
+/// Emit a wolf howl (a wounded wolf calling nearby wolves for help - carries much further than a growl)
+pub fn emit_wolf_howl_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, max_distance: f32, player_id: Identity) {
+    let _ = emit_sound_at_position_with_distance(ctx, SoundType::HowlWolf, pos_x, pos_y, 1.5, max_distance, player_id);
+}
+
 /// Emit a fox growl sound (when foxes detect and start attacking players)
 pub fn emit_fox_growl_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, player_id: Identity) {
     let _ = emit_sound_at_position_with_distance(ctx, SoundType::GrowlFox, pos_x, pos_y, 1.0, 650.0, player_id);
@@ -1073,6 +1116,8 @@ pub fn emit_animal_walking_sound(
         pitch_multiplier,
         velocity_x: 0.0,
         velocity_y: 0.0,
+        chunk_index: crate::environment::calculate_chunk_index(pos_x, pos_y),
+        is_global: false,
     };
 
     match ctx.db.sound_event().try_insert(sound_event) {
@@ -1138,6 +1183,11 @@ pub fn emit_item_thrown_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, play
     let _ = emit_sound_at_position_with_distance(ctx, SoundType::ItemThrown, pos_x, pos_y, 0.9, 500.0, player_id);
 }
 
+/// Emit powered tool sound - much louder and farther-reaching than hand tools
+pub fn emit_powered_tool_sound(ctx: &ReducerContext, sound_type: SoundType, pos_x: f32, pos_y: f32, max_distance: f32, player_id: Identity) {
+    let _ = emit_sound_at_position_with_distance(ctx, sound_type, pos_x, pos_y, 1.0, max_distance, player_id);
+}
+
 /// Emit projectile/item break sound at impact position (arrows, projectiles breaking on impact).
 pub fn emit_break_item_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, player_id: Identity) {
     let mut rng = ctx.rng();
@@ -1155,6 +1205,8 @@ pub fn emit_break_item_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, playe
         pitch_multiplier: 1.0,
         velocity_x: 0.0,
         velocity_y: 0.0,
+        chunk_index: crate::environment::calculate_chunk_index(pos_x, pos_y),
+        is_global: false,
     };
 
     if let Err(e) = ctx.db.sound_event().try_insert(sound_event) {
@@ -1185,6 +1237,8 @@ pub fn emit_global_sound(
         pitch_multiplier: 1.0, // Default pitch multiplier
         velocity_x: 0.0,
         velocity_y: 0.0,
+        chunk_index: 0,
+        is_global: true,
     };
 
     match ctx.db.sound_event().try_insert(sound_event) {
@@ -1690,6 +1744,7 @@ pub fn emit_explosion_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, player
     if let Err(e) = emit_sound_at_position_with_distance(ctx, SoundType::Explosion, pos_x, pos_y, 2.0, 2000.0, player_id) {
         log::warn!("Failed to emit explosion sound: {}", e);
     }
+    register_noise(ctx, pos_x, pos_y, EXPLOSION_NOISE_RADIUS, EXPLOSION_NOISE_INTENSITY, player_id);
 }
 
 /// Emit explosive dud sound (fizzle when explosive fails to detonate)
@@ -1792,4 +1847,81 @@ pub fn emit_thunder_sound_at_chunk(ctx: &ReducerContext, chunk_index: u32, volum
     ) {
         log::warn!("Failed to emit positional thunder sound: {}", e);
     }
-}
\ No newline at end of file
+}
+
+/// Emit a tripwire alarm going off (see alarm_trap)
+pub fn emit_alarm_trap_sound(ctx: &ReducerContext, pos_x: f32, pos_y: f32, max_distance: f32, player_id: Identity) {
+    let _ = emit_sound_at_position_with_distance(ctx, SoundType::AlarmTrap, pos_x, pos_y, 1.5, max_distance, player_id);
+}
+
+// ============================================================================
+// NOISE / AGGRO PROPAGATION
+// ============================================================================
+// Sound events are cosmetic for clients. Loud ones (gunshots, explosions, powered
+// tools, thrown stones) also register a short-lived noise that hostile NPC and wolf
+// AI poll each tick - an idle or patrolling listener inside the noise radius goes
+// to investigate the source (see update_animal_ai_state).
+
+/// How long a noise stays audible to AI
+pub const NOISE_LIFETIME_MICROS: i64 = 3_000_000;
+
+pub const GUNSHOT_NOISE_RADIUS: f32 = 900.0;
+pub const GUNSHOT_NOISE_INTENSITY: f32 = 0.8;
+pub const EXPLOSION_NOISE_RADIUS: f32 = 1400.0;
+pub const EXPLOSION_NOISE_INTENSITY: f32 = 1.0;
+
+/// Active noises AI can hear (server-only)
+#[table(accessor = noise_event)]
+#[derive(Clone, Debug)]
+pub struct NoiseEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub radius: f32,    // Listeners beyond this distance don't hear it
+    pub intensity: f32, // 0.0 to 1.0 - louder noises win over quieter ones
+    pub triggered_by: Identity,
+    pub expires_at: Timestamp,
+}
+
+/// Registers a noise that nearby hostile NPCs and wolves will investigate
+pub fn register_noise(ctx: &ReducerContext, pos_x: f32, pos_y: f32, radius: f32, intensity: f32, triggered_by: Identity) {
+    let noises = ctx.db.noise_event();
+    let expired_ids: Vec<u64> = noises.iter()
+        .filter(|noise| noise.expires_at <= ctx.timestamp)
+        .map(|noise| noise.id)
+        .collect();
+    for id in expired_ids {
+        noises.id().delete(id);
+    }
+
+    noises.insert(NoiseEvent {
+        id: 0, // auto_inc
+        pos_x,
+        pos_y,
+        radius,
+        intensity,
+        triggered_by,
+        expires_at: ctx.timestamp + TimeDuration::from_micros(NOISE_LIFETIME_MICROS),
+    });
+}
+
+/// Returns the loudest active noise audible at a position.
+/// Loudness falls off linearly with distance from the source.
+pub fn find_loudest_audible_noise(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> Option<NoiseEvent> {
+    ctx.db.noise_event().iter()
+        .filter(|noise| noise.expires_at > ctx.timestamp)
+        .filter_map(|noise| {
+            let dx = noise.pos_x - pos_x;
+            let dy = noise.pos_y - pos_y;
+            let distance = (dx * dx + dy * dy).sqrt();
+            if distance > noise.radius {
+                return None;
+            }
+            let loudness = noise.intensity * (1.0 - distance / noise.radius);
+            Some((loudness, noise))
+        })
+        .max_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(_, noise)| noise)
+}
@@ -1,4 +1,7 @@
-use spacetimedb::{Identity, Table, Timestamp};
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use spacetimedb::spacetimedb_lib::{ScheduleAt, TimeDuration};
+use std::collections::HashMap;
+use std::time::Duration;
 
 use crate::{PLAYER_RADIUS, WORLD_HEIGHT_PX, WORLD_WIDTH_PX};
 
@@ -25,6 +28,8 @@ use crate::wooden_storage_box::wooden_storage_box as WoodenStorageBoxTableTrait;
 
 pub const GRID_CELL_SIZE: f32 = PLAYER_RADIUS * 8.0;
 const DYNAMIC_REFRESH_INTERVAL_MICROS: i64 = 500_000;
+/// Full resync of the persistent grid - a safety net; spawns, deletes, deaths and teleports track/untrack directly
+pub const SPATIAL_GRID_RECONCILE_INTERVAL_SECS: u64 = 30;
 
 pub fn grid_width() -> usize {
     (WORLD_WIDTH_PX / GRID_CELL_SIZE).ceil() as usize
@@ -34,7 +39,7 @@ pub fn grid_height() -> usize {
     (WORLD_HEIGHT_PX / GRID_CELL_SIZE).ceil() as usize
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, spacetimedb::SpacetimeType)]
 pub enum EntityType {
     Player(Identity),
     Tree(u64),
@@ -58,6 +63,28 @@ pub enum EntityType {
     Turret(u32),
 }
 
+/// Persistent grid membership for players, wild animals and dropped items.
+/// Updated incrementally as they move (a write only happens when the cell changes),
+/// so the cached grid no longer rescans those tables on every refresh.
+#[spacetimedb::table(accessor = spatial_grid_entry)]
+#[derive(Clone, Debug)]
+pub struct SpatialGridEntry {
+    #[primary_key]
+    pub entity_key: String,
+    #[index(btree)]
+    pub cell_index: u32,
+    pub entity: EntityType,
+}
+
+#[spacetimedb::table(accessor = spatial_grid_reconcile_schedule, scheduled(reconcile_spatial_grid))]
+#[derive(Clone)]
+pub struct SpatialGridReconcileSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct GridCell {
     pub entities: Vec<EntityType>,
@@ -124,6 +151,12 @@ impl SpatialGrid {
         }
     }
 
+    fn add_entity_to_cell(&mut self, entity_type: EntityType, cell_index: usize) {
+        if let Some(cell) = self.cells.get_mut(cell_index) {
+            cell.entities.push(entity_type);
+        }
+    }
+
     pub fn get_entities_at(&self, x: f32, y: f32) -> &[EntityType] {
         if let Some(index) = self.get_cell_index(x, y) {
             &self.cells[index].entities
@@ -172,7 +205,8 @@ impl SpatialGrid {
             + CairnTableTrait
             + SeaStackTableTrait
             + LanternTableTrait
-            + TurretTableTrait,
+            + TurretTableTrait
+            + spatial_grid_entry,
     >(
         &mut self,
         db: &DB,
@@ -256,17 +290,16 @@ impl SpatialGrid {
             + CairnTableTrait
             + SeaStackTableTrait
             + LanternTableTrait
-            + TurretTableTrait,
+            + TurretTableTrait
+            + spatial_grid_entry,
     >(
         &mut self,
         db: &DB,
-        current_time: Timestamp,
     ) {
         self.clear();
-        for player in db.player().iter() {
-            if !player.is_dead {
-                self.add_entity(EntityType::Player(player.identity), player.position_x, player.position_y);
-            }
+        // Players, wild animals and dropped items come from the persistent grid
+        for entry in db.spatial_grid_entry().iter() {
+            self.add_entity_to_cell(entry.entity, entry.cell_index as usize);
         }
         for campfire in db.campfire().iter() {
             self.add_entity(EntityType::Campfire(campfire.id), campfire.pos_x, campfire.pos_y);
@@ -279,9 +312,6 @@ impl SpatialGrid {
                 self.add_entity(EntityType::HarvestableResource(resource.id), resource.pos_x, resource.pos_y);
             }
         }
-        for item in db.dropped_item().iter() {
-            self.add_entity(EntityType::DroppedItem(item.id), item.pos_x, item.pos_y);
-        }
         for corpse in db.player_corpse().iter() {
             self.add_entity(EntityType::PlayerCorpse(corpse.id), corpse.pos_x, corpse.pos_y);
         }
@@ -295,11 +325,6 @@ impl SpatialGrid {
                 self.add_entity(EntityType::Furnace(furnace.id), furnace.pos_x, furnace.pos_y);
             }
         }
-        for animal in db.wild_animal().iter() {
-            if animal.hide_until.is_none() || animal.hide_until.unwrap() <= current_time {
-                self.add_entity(EntityType::WildAnimal(animal.id), animal.pos_x, animal.pos_y);
-            }
-        }
         for hearth in db.homestead_hearth().iter() {
             if !hearth.is_destroyed {
                 self.add_entity(EntityType::HomesteadHearth(hearth.id), hearth.pos_x, hearth.pos_y);
@@ -346,7 +371,8 @@ pub fn get_cached_spatial_grid<
         + CairnTableTrait
         + SeaStackTableTrait
         + LanternTableTrait
-        + TurretTableTrait,
+        + TurretTableTrait
+        + spatial_grid_entry,
 >(
     db: &DB,
     current_time: Timestamp,
@@ -370,7 +396,7 @@ pub fn get_cached_spatial_grid<
         };
         if dynamic_needs_refresh {
             let mut new_dynamic_grid = SpatialGrid::new();
-            new_dynamic_grid.populate_dynamic_from_world(db, current_time);
+            new_dynamic_grid.populate_dynamic_from_world(db);
             DYNAMIC_GRID = Some(CachedGrid {
                 grid: new_dynamic_grid,
                 last_refresh: current_time,
@@ -413,6 +439,145 @@ pub fn invalidate_spatial_grid_cache() {
     }
 }
 
+// --- Persistent Grid Maintenance ---
+
+fn entity_key(entity: &EntityType) -> String {
+    format!("{:?}", entity)
+}
+
+/// Grid cell containing a world position, if it is inside the world
+pub fn cell_index_for(x: f32, y: f32) -> Option<u32> {
+    if x < 0.0 || y < 0.0 || x >= WORLD_WIDTH_PX || y >= WORLD_HEIGHT_PX {
+        return None;
+    }
+    let cell_x = ((x / GRID_CELL_SIZE) as usize).min(grid_width() - 1);
+    let cell_y = ((y / GRID_CELL_SIZE) as usize).min(grid_height() - 1);
+    Some((cell_y * grid_width() + cell_x) as u32)
+}
+
+/// Records an entity's position in the persistent grid. Only writes when its cell changes.
+pub fn track_entity(ctx: &ReducerContext, entity: EntityType, x: f32, y: f32) {
+    let entries = ctx.db.spatial_grid_entry();
+    let key = entity_key(&entity);
+    let Some(cell_index) = cell_index_for(x, y) else {
+        entries.entity_key().delete(&key);
+        return;
+    };
+    match entries.entity_key().find(&key) {
+        Some(entry) if entry.cell_index == cell_index => {}
+        Some(mut entry) => {
+            entry.cell_index = cell_index;
+            entries.entity_key().update(entry);
+        }
+        None => {
+            entries.insert(SpatialGridEntry { entity_key: key, cell_index, entity });
+        }
+    }
+}
+
+/// Removes an entity from the persistent grid (despawned, hidden or dead)
+pub fn untrack_entity(ctx: &ReducerContext, entity: EntityType) {
+    ctx.db.spatial_grid_entry().entity_key().delete(&entity_key(&entity));
+}
+
+/// Tracked entities in an inclusive rectangle of grid cells
+pub fn entities_in_cell_range(ctx: &ReducerContext, min_cell_x: i32, min_cell_y: i32, max_cell_x: i32, max_cell_y: i32) -> Vec<EntityType> {
+    let mut result = Vec::new();
+    for cell_y in min_cell_y.max(0)..=max_cell_y.min(grid_height() as i32 - 1) {
+        for cell_x in min_cell_x.max(0)..=max_cell_x.min(grid_width() as i32 - 1) {
+            let cell_index = (cell_y as usize * grid_width() + cell_x as usize) as u32;
+            result.extend(ctx.db.spatial_grid_entry().cell_index().filter(cell_index).map(|entry| entry.entity));
+        }
+    }
+    result
+}
+
+/// Tracked entities in the cells overlapping a circle (callers check exact distances)
+pub fn entities_near(ctx: &ReducerContext, x: f32, y: f32, radius: f32) -> Vec<EntityType> {
+    entities_in_cell_range(
+        ctx,
+        ((x - radius) / GRID_CELL_SIZE).floor() as i32,
+        ((y - radius) / GRID_CELL_SIZE).floor() as i32,
+        ((x + radius) / GRID_CELL_SIZE).floor() as i32,
+        ((y + radius) / GRID_CELL_SIZE).floor() as i32,
+    )
+}
+
+/// Initialize the persistent grid (full build) and its reconcile schedule
+pub fn init_spatial_grid_system(ctx: &ReducerContext) -> Result<(), String> {
+    sync_spatial_grid_entries(ctx);
+    let schedule_table = ctx.db.spatial_grid_reconcile_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("Starting spatial grid reconcile schedule (every {}s).", SPATIAL_GRID_RECONCILE_INTERVAL_SECS);
+        let interval = Duration::from_secs(SPATIAL_GRID_RECONCILE_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            SpatialGridReconcileSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Spatial grid reconcile"
+        );
+    } else {
+        log::debug!("Spatial grid reconcile schedule already exists.");
+    }
+    Ok(())
+}
+
+/// Scheduled reducer that resyncs the persistent grid with the entity tables
+#[spacetimedb::reducer]
+pub fn reconcile_spatial_grid(ctx: &ReducerContext, _args: SpatialGridReconcileSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Spatial grid reconcile can only be run by scheduler".to_string());
+    }
+    sync_spatial_grid_entries(ctx);
+    Ok(())
+}
+
+/// Brings the persistent grid in line with the player, animal and dropped item tables
+pub fn sync_spatial_grid_entries(ctx: &ReducerContext) {
+    let mut wanted: HashMap<String, (u32, EntityType)> = HashMap::new();
+    let mut want = |entity: EntityType, x: f32, y: f32| {
+        if let Some(cell_index) = cell_index_for(x, y) {
+            wanted.insert(entity_key(&entity), (cell_index, entity));
+        }
+    };
+    for player in ctx.db.player().iter().filter(|p| !p.is_dead) {
+        want(EntityType::Player(player.identity), player.position_x, player.position_y);
+    }
+    for animal in ctx.db.wild_animal().iter().filter(|a| a.hide_until.is_none_or(|t| t <= ctx.timestamp)) {
+        let (animal_x, animal_y) = crate::wild_animal_npc::transform_batch::live_position(ctx, &animal);
+        want(EntityType::WildAnimal(animal.id), animal_x, animal_y);
+    }
+    for item in ctx.db.dropped_item().iter() {
+        want(EntityType::DroppedItem(item.id), item.pos_x, item.pos_y);
+    }
+
+    let entries = ctx.db.spatial_grid_entry();
+    let mut changed = 0;
+    for mut entry in entries.iter().collect::<Vec<_>>() {
+        match wanted.remove(&entry.entity_key) {
+            Some((cell_index, _)) if cell_index == entry.cell_index => {}
+            Some((cell_index, _)) => {
+                entry.cell_index = cell_index;
+                entries.entity_key().update(entry);
+                changed += 1;
+            }
+            None => {
+                entries.entity_key().delete(&entry.entity_key);
+                changed += 1;
+            }
+        }
+    }
+    for (entity_key, (cell_index, entity)) in wanted {
+        entries.insert(SpatialGridEntry { entity_key, cell_index, entity });
+        changed += 1;
+    }
+    if changed > 0 {
+        log::debug!("[SpatialGrid] Reconcile corrected {} grid entries", changed);
+    }
+}
+
 impl Default for SpatialGrid {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,140 @@
+/******************************************************************************
+ *                                                                            *
+ * Irrigation Sprinkler - Automatic crop watering                             *
+ *                                                                            *
+ * Sprinklers are WoodenStorageBox entities (BOX_TYPE_SPRINKLER) with no      *
+ * inventory. Each one is fed by the nearest rain collector within reach and  *
+ * on every tick lays a water patch on each planted seed in its radius that   *
+ * isn't already watered, drawing the same amount of water from the           *
+ * collector as a manual splash would.                                        *
+ *                                                                            *
+ * Sprinklers refuse to spray salt water - it would stunt the crops.          *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table};
+use spacetimedb::spacetimedb_lib::{ScheduleAt, TimeDuration};
+use log;
+use std::time::Duration;
+
+use crate::wooden_storage_box::{BOX_TYPE_SPRINKLER, wooden_storage_box as WoodenStorageBoxTableTrait};
+use crate::rain_collector::{RainCollector, rain_collector as RainCollectorTableTrait};
+use crate::planted_seeds::planted_seed as PlantedSeedTableTrait;
+use crate::water_patch::{water_patch as WaterPatchTableTrait, WATER_CONSUMPTION_PER_USE, WATER_PATCH_RADIUS, create_water_patch};
+
+// --- Sprinkler Constants ---
+pub const SPRINKLER_PROCESS_INTERVAL_SECS: u64 = 60; // Water patches last 5 minutes, so this keeps fields topped up
+pub const SPRINKLER_RADIUS: f32 = 200.0; // Crops within this radius get watered
+pub const SPRINKLER_WATER_SOURCE_RANGE: f32 = 150.0; // A rain collector must be this close to feed the sprinkler
+pub const SPRINKLER_MAX_PATCHES_PER_TICK: usize = 24; // Caps water drawn per tick on very dense fields
+
+// --- Sprinkler Schedule Table ---
+#[spacetimedb::table(accessor = sprinkler_process_schedule, scheduled(process_sprinklers))]
+#[derive(Clone)]
+pub struct SprinklerProcessSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Initialize the sprinkler processing system
+pub fn init_sprinkler_system(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.sprinkler_process_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("Starting sprinkler processing schedule (every {}s).", SPRINKLER_PROCESS_INTERVAL_SECS);
+        let interval = Duration::from_secs(SPRINKLER_PROCESS_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            SprinklerProcessSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Sprinkler processing"
+        );
+    } else {
+        log::debug!("Sprinkler processing schedule already exists.");
+    }
+    Ok(())
+}
+
+/// Finds the closest intact rain collector within reach of a sprinkler
+fn find_water_source(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> Option<RainCollector> {
+    let range_sq = SPRINKLER_WATER_SOURCE_RANGE * SPRINKLER_WATER_SOURCE_RANGE;
+    ctx.db.rain_collector().iter()
+        .filter(|c| !c.is_destroyed)
+        .map(|c| {
+            let dist_sq = (c.pos_x - pos_x).powi(2) + (c.pos_y - pos_y).powi(2);
+            (c, dist_sq)
+        })
+        .filter(|(_, dist_sq)| *dist_sq <= range_sq)
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(c, _)| c)
+}
+
+/// Whether a fresh water patch already covers this position
+fn is_already_watered(ctx: &ReducerContext, x: f32, y: f32) -> bool {
+    let radius_sq = WATER_PATCH_RADIUS * WATER_PATCH_RADIUS;
+    ctx.db.water_patch().iter().any(|patch| {
+        !patch.is_salt_water && (patch.pos_x - x).powi(2) + (patch.pos_y - y).powi(2) <= radius_sq
+    })
+}
+
+/// Scheduled reducer - every sprinkler waters the dry crops around it from its rain collector
+#[spacetimedb::reducer]
+pub fn process_sprinklers(ctx: &ReducerContext, _args: SprinklerProcessSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Sprinkler processing can only be run by scheduler".to_string());
+    }
+
+    let sprinklers: Vec<_> = ctx.db.wooden_storage_box().iter()
+        .filter(|b| b.box_type == BOX_TYPE_SPRINKLER && !b.is_destroyed)
+        .collect();
+    if sprinklers.is_empty() {
+        return Ok(());
+    }
+
+    let radius_sq = SPRINKLER_RADIUS * SPRINKLER_RADIUS;
+
+    for sprinkler in sprinklers {
+        let Some(mut collector) = find_water_source(ctx, sprinkler.pos_x, sprinkler.pos_y) else {
+            continue;
+        };
+        if collector.is_salt_water || collector.total_water_collected < WATER_CONSUMPTION_PER_USE {
+            continue;
+        }
+
+        let dry_seeds: Vec<(f32, f32)> = ctx.db.planted_seed().iter()
+            .filter(|seed| (seed.pos_x - sprinkler.pos_x).powi(2) + (seed.pos_y - sprinkler.pos_y).powi(2) <= radius_sq)
+            .filter(|seed| !is_already_watered(ctx, seed.pos_x, seed.pos_y))
+            .map(|seed| (seed.pos_x, seed.pos_y))
+            .take(SPRINKLER_MAX_PATCHES_PER_TICK)
+            .collect();
+        if dry_seeds.is_empty() {
+            continue;
+        }
+
+        let mut patches_created = 0;
+        for (seed_x, seed_y) in dry_seeds {
+            if collector.total_water_collected < WATER_CONSUMPTION_PER_USE {
+                break;
+            }
+            // A patch laid for a neighbouring seed this tick may already cover this one
+            if is_already_watered(ctx, seed_x, seed_y) {
+                continue;
+            }
+            create_water_patch(ctx, seed_x, seed_y, sprinkler.placed_by, WATER_CONSUMPTION_PER_USE, false);
+            collector.total_water_collected -= WATER_CONSUMPTION_PER_USE;
+            patches_created += 1;
+        }
+
+        if patches_created > 0 {
+            log::info!("[Sprinkler] Sprinkler {} watered {} crops from rain collector {} ({:.2}L left)",
+                sprinkler.id, patches_created, collector.id, collector.total_water_collected);
+            ctx.db.rain_collector().id().update(collector);
+            crate::sound_events::emit_watering_sound(ctx, sprinkler.pos_x, sprinkler.pos_y, sprinkler.placed_by);
+        }
+    }
+
+    Ok(())
+}
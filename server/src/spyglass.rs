@@ -0,0 +1,114 @@
+/******************************************************************************
+ *                                                                            *
+ * Spyglass & Binoculars - Scoping out distant areas                          *
+ *                                                                            *
+ * Looking through an equipped spyglass or binoculars (toggle_scope) widens  *
+ * the area streamed to that client (see chunk_streaming): the reported      *
+ * viewport is scaled up around its center so distant grass, dropped items   *
+ * and animals stream in. A scoping player can turn but can't move; moving   *
+ * the scope away (switching or unequipping the item) stops scoping.         *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::active_equipment::active_equipment as ActiveEquipmentTableTrait;
+use crate::items::item_definition as ItemDefinitionTableTrait;
+use crate::client_viewport as ClientViewportTableTrait;
+
+// --- Constants ---
+
+/// Viewport scale while looking through a crafted spyglass
+pub const SPYGLASS_VIEWPORT_MULTIPLIER: f32 = 1.75;
+/// Viewport scale while looking through military binoculars
+pub const BINOCULARS_VIEWPORT_MULTIPLIER: f32 = 2.5;
+
+/// A player currently looking through a scope (public so clients can draw the scope pose)
+#[spacetimedb::table(accessor = scoping_player, public)]
+#[derive(Clone, Debug)]
+pub struct ScopingPlayer {
+    #[primary_key]
+    pub player_id: Identity,
+    pub viewport_multiplier: f32,
+    pub started_at: Timestamp,
+}
+
+/// Viewport scale of a scoping item, or None if the item isn't a scope
+fn scope_viewport_multiplier(item_name: &str) -> Option<f32> {
+    match item_name {
+        "Spyglass" => Some(SPYGLASS_VIEWPORT_MULTIPLIER),
+        "Binoculars" => Some(BINOCULARS_VIEWPORT_MULTIPLIER),
+        _ => None,
+    }
+}
+
+pub fn is_scoping(ctx: &ReducerContext, player_id: Identity) -> bool {
+    ctx.db.scoping_player().player_id().find(&player_id).is_some()
+}
+
+/// How much a client's streamed viewport is scaled up (1.0 when not scoping)
+pub fn get_viewport_multiplier(ctx: &ReducerContext, player_id: Identity) -> f32 {
+    ctx.db.scoping_player().player_id().find(&player_id)
+        .map_or(1.0, |scope| scope.viewport_multiplier)
+}
+
+/// Re-streams a client's last reported viewport so a scope change takes effect immediately
+fn refresh_streamed_viewport(ctx: &ReducerContext, player_id: Identity) {
+    if let Some(viewport) = ctx.db.client_viewport().client_identity().find(&player_id) {
+        crate::chunk_streaming::refresh_visible_chunks(ctx, player_id, viewport.min_x, viewport.min_y, viewport.max_x, viewport.max_y);
+    }
+}
+
+/// Stops scoping (on switching or unequipping the scope, disconnecting, etc.)
+pub fn stop_scoping(ctx: &ReducerContext, player_id: Identity) {
+    if ctx.db.scoping_player().player_id().delete(&player_id) {
+        refresh_streamed_viewport(ctx, player_id);
+        log::info!("Player {:?} stopped scoping.", player_id);
+    }
+}
+
+/// --- Toggle Scope ---
+/// Raises or lowers the equipped spyglass or binoculars.
+#[spacetimedb::reducer]
+pub fn toggle_scope(ctx: &ReducerContext) -> Result<(), String> {
+    let sender_id = ctx.sender();
+
+    if is_scoping(ctx, sender_id) {
+        stop_scoping(ctx, sender_id);
+        return Ok(());
+    }
+
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot look through a scope while dead.".to_string());
+    }
+    if player.is_knocked_out {
+        return Err("Cannot look through a scope while knocked out.".to_string());
+    }
+    if player.is_snorkeling {
+        return Err("Cannot look through a scope while underwater.".to_string());
+    }
+
+    let equipment = ctx.db.active_equipment().player_identity().find(&sender_id)
+        .ok_or_else(|| "Player has no active equipment record.".to_string())?;
+    let item_def_id = equipment.equipped_item_def_id
+        .ok_or_else(|| "No item equipped to look through.".to_string())?;
+    let item_def = ctx.db.item_definition().id().find(item_def_id)
+        .ok_or_else(|| "Equipped item definition not found.".to_string())?;
+    let viewport_multiplier = scope_viewport_multiplier(&item_def.name)
+        .ok_or_else(|| format!("Cannot look through: {} is not a spyglass or binoculars.", item_def.name))?;
+
+    ctx.db.scoping_player().insert(ScopingPlayer {
+        player_id: sender_id,
+        viewport_multiplier,
+        started_at: ctx.timestamp,
+    });
+    refresh_streamed_viewport(ctx, sender_id);
+
+    log::info!("Player {:?} is looking through their {} ({:.2}x view).", sender_id, item_def.name, viewport_multiplier);
+    Ok(())
+}
@@ -219,6 +219,9 @@ pub fn place_stash(ctx: &ReducerContext, item_instance_id: u64, world_x: f32, wo
     // Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
     crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     // Validate placement distance (small 48x48 item, allow 96px range)
     const STASH_PLACEMENT_MAX_DISTANCE: f32 = 96.0;
     let players = ctx.db.player();
@@ -365,124 +368,30 @@ fn validate_stash_item_interaction(ctx: &ReducerContext, stash_id: u32) -> Resul
     Ok((player, stash))
 }
 
-#[spacetimedb::reducer]
-pub fn move_item_to_stash(ctx: &ReducerContext, stash_id: u32, target_slot_index: u8, item_instance_id: u64) -> Result<(), String> {
-    let (_player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    inventory_management::handle_move_to_container_slot(ctx, &mut stash, target_slot_index, item_instance_id)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
-}
-
-#[spacetimedb::reducer]
-pub fn move_item_from_stash(
-    ctx: &ReducerContext,
-    stash_id: u32,
-    source_slot_index: u8,
-    target_slot_type: String, // "inventory" or "hotbar"
-    target_slot_index: u32
-) -> Result<(), String> {
-    let (_player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    inventory_management::handle_move_from_container_slot(ctx, &mut stash, source_slot_index, target_slot_type, target_slot_index)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
-}
-
-#[spacetimedb::reducer]
-pub fn move_item_within_stash(
-    ctx: &ReducerContext,
-    stash_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    let (_player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    inventory_management::handle_move_within_container(ctx, &mut stash, source_slot_index, target_slot_index)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
-}
-
-#[spacetimedb::reducer]
-pub fn split_stack_into_stash(
-    ctx: &ReducerContext,
-    stash_id: u32,
-    target_slot_index: u8,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (_player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    inventory_management::handle_split_into_container(ctx, &mut stash, target_slot_index, source_item_instance_id, quantity_to_split)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
-}
-
-#[spacetimedb::reducer]
-pub fn split_stack_from_stash(
-    ctx: &ReducerContext,
-    stash_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_type: String,
-    target_slot_index: u32,
-) -> Result<(), String> {
-    let (_player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    inventory_management::handle_split_from_container(ctx, &mut stash, source_slot_index, quantity_to_split, target_slot_type, target_slot_index)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
-}
-
-#[spacetimedb::reducer]
-pub fn split_stack_within_stash(
-    ctx: &ReducerContext,
-    stash_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (_player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    inventory_management::handle_split_within_container(ctx, &mut stash, source_slot_index, target_slot_index, quantity_to_split)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
-}
-
-#[spacetimedb::reducer]
-pub fn quick_move_to_stash(ctx: &ReducerContext, stash_id: u32, item_instance_id: u64) -> Result<(), String> {
-    let (_player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    inventory_management::handle_quick_move_to_container(ctx, &mut stash, item_instance_id)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
-}
-
-#[spacetimedb::reducer]
-pub fn quick_move_from_stash(ctx: &ReducerContext, stash_id: u32, source_slot_index: u8) -> Result<(), String> {
-    let (_player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    inventory_management::handle_quick_move_from_container(ctx, &mut stash, source_slot_index)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
-}
+impl inventory_management::ContainerHandle for Stash {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_stash_item_interaction(ctx, container_id)
+    }
 
-#[spacetimedb::reducer]
-pub fn drop_item_from_stash_slot_to_world(
-    ctx: &ReducerContext,
-    stash_id: u32,
-    slot_index: u8,
-) -> Result<(), String> {
-    let (player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    // Note: handle_drop_from_container_slot needs a &Player for drop location calculation.
-    // The player instance from validate_stash_item_interaction is suitable here.
-    inventory_management::handle_drop_from_container_slot(ctx, &mut stash, slot_index, &player)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.stash().id().update(self.clone());
+    }
 }
 
-#[spacetimedb::reducer]
-pub fn split_and_drop_item_from_stash_slot_to_world(
-    ctx: &ReducerContext,
-    stash_id: u32,
-    slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let (player, mut stash) = validate_stash_item_interaction(ctx, stash_id)?;
-    // Similar to above, player instance is needed for drop location.
-    inventory_management::handle_split_and_drop_from_container_slot(ctx, &mut stash, slot_index, quantity_to_split, &player)?;
-    ctx.db.stash().id().update(stash);
-    Ok(())
+crate::container_reducers! {
+    Stash;
+    move_to: move_item_to_stash(stash_id),
+    move_from: move_item_from_stash(stash_id),
+    move_within: move_item_within_stash(stash_id),
+    split_into: split_stack_into_stash(stash_id),
+    split_from: split_stack_from_stash(stash_id),
+    split_within: split_stack_within_stash(stash_id),
+    quick_move_to: quick_move_to_stash(stash_id),
+    quick_move_from: quick_move_from_stash(stash_id),
+    drop: drop_item_from_stash_slot_to_world(stash_id),
+    split_and_drop: split_and_drop_item_from_stash_slot_to_world(stash_id),
+    move_all_of_type: move_all_of_type_to_stash(stash_id),
+    deposit_matching: deposit_matching_to_stash(stash_id),
+    take_all: take_all_from_stash(stash_id),
+    sort: sort_stash(stash_id),
 } 
\ No newline at end of file
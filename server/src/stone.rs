@@ -182,6 +182,64 @@ pub(crate) const MEMORY_SHARD_MAX_RESOURCES: u32 = 100; // Maximum memory shards
 pub(crate) const MIN_STONE_RESPAWN_TIME_SECS: u64 = 240; // 4 minutes
 pub(crate) const MAX_STONE_RESPAWN_TIME_SECS: u64 = 480; // 8 minutes
 
+// --- Ore Quality ---
+// Every node rolls a quality (1-100) when it spawns or respawns. Quality scales both the node's
+// total resources and its per-hit yield, so a rich vein is worth walking to.
+pub(crate) const ORE_QUALITY_MIN: u8 = 1;
+pub(crate) const ORE_QUALITY_MAX: u8 = 100;
+pub(crate) const ORE_QUALITY_MIN_YIELD_MULTIPLIER: f32 = 0.75; // Quality 1
+pub(crate) const ORE_QUALITY_MAX_YIELD_MULTIPLIER: f32 = 1.25; // Quality 100
+
+// --- Hot Spots ---
+// Each node has a sweet spot on its rim (an angle around the collision center). Hits landing within
+// the arc grant bonus resources and move the spot elsewhere, so players keep circling the rock.
+// The hit point is derived server-side from the attacker's position - the client only renders it.
+pub(crate) const HOT_SPOT_HALF_ARC_RADIANS: f32 = 0.45; // ~26 degrees either side of the spot
+pub(crate) const HOT_SPOT_YIELD_MULTIPLIER: f32 = 1.5;
+pub(crate) const HOT_SPOT_MIN_MOVE_RADIANS: f32 = std::f32::consts::FRAC_PI_3; // Spot jumps at least 60 degrees
+
+/// Rolls node quality - average of two rolls so mid-quality nodes are most common
+pub fn roll_ore_quality(rng: &mut impl Rng) -> u8 {
+    let a = rng.gen_range(ORE_QUALITY_MIN..=ORE_QUALITY_MAX) as u16;
+    let b = rng.gen_range(ORE_QUALITY_MIN..=ORE_QUALITY_MAX) as u16;
+    ((a + b) / 2) as u8
+}
+
+pub fn ore_quality_yield_multiplier(quality: u8) -> f32 {
+    let t = (quality.clamp(ORE_QUALITY_MIN, ORE_QUALITY_MAX) - ORE_QUALITY_MIN) as f32
+        / (ORE_QUALITY_MAX - ORE_QUALITY_MIN) as f32;
+    ORE_QUALITY_MIN_YIELD_MULTIPLIER + (ORE_QUALITY_MAX_YIELD_MULTIPLIER - ORE_QUALITY_MIN_YIELD_MULTIPLIER) * t
+}
+
+/// Scales a node's rolled resource amount by its quality
+pub fn apply_ore_quality(resource_amount: u32, quality: u8) -> u32 {
+    ((resource_amount as f32) * ore_quality_yield_multiplier(quality)).round().max(1.0) as u32
+}
+
+pub fn roll_hot_spot_angle(rng: &mut impl Rng) -> f32 {
+    rng.gen_range(0.0..std::f32::consts::TAU)
+}
+
+/// New hot spot angle at least HOT_SPOT_MIN_MOVE_RADIANS away from the current one
+pub fn next_hot_spot_angle(current: f32, rng: &mut impl Rng) -> f32 {
+    let offset = rng.gen_range(HOT_SPOT_MIN_MOVE_RADIANS..=(std::f32::consts::TAU - HOT_SPOT_MIN_MOVE_RADIANS));
+    (current + offset).rem_euclid(std::f32::consts::TAU)
+}
+
+/// Whether a hit coming from (hit_from_x, hit_from_y) lands on the stone's hot spot.
+/// The swing strikes the rim facing the attacker, so the hit angle is the direction
+/// from the collision center towards them.
+pub fn is_hot_spot_hit(stone: &Stone, hit_from_x: f32, hit_from_y: f32) -> bool {
+    let dx = hit_from_x - stone.pos_x;
+    let dy = hit_from_y - (stone.pos_y - STONE_COLLISION_Y_OFFSET);
+    if dx == 0.0 && dy == 0.0 {
+        return false;
+    }
+    let hit_angle = dy.atan2(dx).rem_euclid(std::f32::consts::TAU);
+    let diff = (hit_angle - stone.hot_spot_angle).rem_euclid(std::f32::consts::TAU);
+    diff.min(std::f32::consts::TAU - diff) <= HOT_SPOT_HALF_ARC_RADIANS
+}
+
 // --- Stone Struct and Table ---
 #[spacetimedb::table(accessor = stone, public)]
 #[derive(Clone)]
@@ -194,6 +252,8 @@ pub struct Stone {
     pub health: u32, // Stones just disappear when health is 0
     pub resource_remaining: u32, // NEW: How much stone ore is left to collect
     pub ore_type: OreType, // Type of ore this stone node contains
+    pub quality: u8, // Ore quality (1-100) - scales total resources and per-hit yield
+    pub hot_spot_angle: f32, // Radians around the collision center; hits here grant bonus yield
     #[index(btree)]
     pub chunk_index: u32, // Added for spatial filtering/queries
     pub last_hit_time: Option<Timestamp>, // Added for shake effect
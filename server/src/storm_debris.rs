@@ -169,7 +169,7 @@ pub fn spawn_storm_debris_on_beaches(ctx: &ReducerContext, chunk_index: u32) ->
         } else if spawn_roll < 0.50 {
             // 30% chance: Spawn seaweed
             let quantity = rng.gen_range(1..=3);
-            ctx.db.dropped_item().insert(DroppedItem {
+            let inserted = ctx.db.dropped_item().insert(DroppedItem {
                 id: 0,
                 item_def_id: seaweed_id,
                 quantity,
@@ -181,12 +181,13 @@ pub fn spawn_storm_debris_on_beaches(ctx: &ReducerContext, chunk_index: u32) ->
                 spawn_x: None,
                 spawn_y: None,
             });
+            crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), inserted.pos_x, inserted.pos_y);
             spawned_items += 1;
             log::info!("Storm spawned {} seaweed at ({:.1}, {:.1})", quantity, pos_x, pos_y);
         } else if spawn_roll < 0.85 {
             // 35% chance: Spawn coral fragments
             let quantity = rng.gen_range(1..=3);
-            ctx.db.dropped_item().insert(DroppedItem {
+            let inserted = ctx.db.dropped_item().insert(DroppedItem {
                 id: 0,
                 item_def_id: coral_frag_id,
                 quantity,
@@ -198,11 +199,12 @@ pub fn spawn_storm_debris_on_beaches(ctx: &ReducerContext, chunk_index: u32) ->
                 spawn_x: None,
                 spawn_y: None,
             });
+            crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), inserted.pos_x, inserted.pos_y);
             spawned_items += 1;
             log::info!("Storm spawned {} coral fragments at ({:.1}, {:.1})", quantity, pos_x, pos_y);
         } else if spawn_roll < 0.95 {
             // 10% chance: Spawn shell
-            ctx.db.dropped_item().insert(DroppedItem {
+            let inserted = ctx.db.dropped_item().insert(DroppedItem {
                 id: 0,
                 item_def_id: shell_id,
                 quantity: 1,
@@ -214,12 +216,13 @@ pub fn spawn_storm_debris_on_beaches(ctx: &ReducerContext, chunk_index: u32) ->
                 spawn_x: None,
                 spawn_y: None,
             });
+            crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), inserted.pos_x, inserted.pos_y);
             spawned_items += 1;
             log::info!("Storm spawned shell at ({:.1}, {:.1})", pos_x, pos_y);
         } else {
             // 5% chance: Spawn Memory Shard (RARE technological debris)
             // Lore: Violent storms dislodge ancient cognitive archives from the seafloor
-            ctx.db.dropped_item().insert(DroppedItem {
+            let inserted = ctx.db.dropped_item().insert(DroppedItem {
                 id: 0,
                 item_def_id: memory_shard_id,
                 quantity: 1, // Always just 1 - these are precious
@@ -231,6 +234,7 @@ pub fn spawn_storm_debris_on_beaches(ctx: &ReducerContext, chunk_index: u32) ->
                 spawn_x: None,
                 spawn_y: None,
             });
+            crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), inserted.pos_x, inserted.pos_y);
             spawned_items += 1;
             log::info!("⚡ Storm spawned Memory Shard at ({:.1}, {:.1})!", pos_x, pos_y);
         }
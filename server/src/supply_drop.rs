@@ -0,0 +1,513 @@
+/******************************************************************************
+ *                                                                            *
+ * Supply Drop World Event                                                    *
+ *                                                                            *
+ * At random intervals a supply crate is dropped onto a random patch of open  *
+ * land. The drop is announced in chat and marked on the minimap. The crate   *
+ * is a loot container (like a military crate) filled from the shared loot   *
+ * table subsystem: every drop rolls a tier first, and higher tiers unlock    *
+ * extra entries and larger stacks.                                           *
+ *                                                                            *
+ * Key features:                                                              *
+ * - Random interval between drops (requires at least 1 player online)       *
+ * - Only one unclaimed supply drop at a time                                 *
+ * - Landing spot must be open land: no water, forest or nearby trees         *
+ * - Crate despawns (with its loot) if nobody opens it before it expires     *
+ * - Opened crates stay until emptied, like military crates                   *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table, Timestamp, TimeDuration, ScheduleAt};
+use rand::Rng;
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::chat::message as MessageTableTrait;
+use crate::tree::tree as TreeTableTrait;
+use crate::items::{InventoryItem, inventory_item as InventoryItemTableTrait};
+use crate::wooden_storage_box::{
+    WoodenStorageBox, BOX_TYPE_SUPPLY_DROP, NUM_SUPPLY_DROP_SLOTS,
+    SUPPLY_DROP_INITIAL_HEALTH, SUPPLY_DROP_MAX_HEALTH,
+    wooden_storage_box as WoodenStorageBoxTableTrait,
+};
+use crate::world_state::{WorldEventMarker, WorldEventType, world_event_marker as WorldEventMarkerTableTrait};
+use crate::environment::{is_position_on_water, calculate_chunk_index};
+use crate::inventory_management::{ItemContainer, is_container_empty};
+use crate::models::{ItemLocation, ContainerLocationData, ContainerType};
+use crate::TileType;
+use crate::loot_table::{LootEntryDefault, LootSourceDefault};
+use crate::{WORLD_WIDTH_PX, WORLD_HEIGHT_PX};
+
+// === CONSTANTS ===
+
+/// Random delay between supply drops
+pub const SUPPLY_DROP_MIN_INTERVAL_SECS: u64 = 1800; // 30 minutes
+pub const SUPPLY_DROP_MAX_INTERVAL_SECS: u64 = 3600; // 60 minutes
+
+/// How long an unopened crate stays in the world
+pub const SUPPLY_DROP_LIFETIME_SECS: u64 = 1200; // 20 minutes
+
+/// Minimap marker radius - shows the rough area, not the exact crate position
+pub const SUPPLY_DROP_MARKER_RADIUS_PX: f32 = 300.0;
+
+/// Keep the crate away from tree trunks so it isn't wedged in a thicket
+const MIN_DISTANCE_FROM_TREES_PX: f32 = 150.0;
+
+/// Minimum distance from player foundations
+const MIN_DISTANCE_FROM_BASES_PX: f32 = 1200.0;
+
+/// Minimum distance from world edges (in pixels)
+const WORLD_EDGE_BUFFER_PX: f32 = 800.0;
+
+const MAX_POSITION_ATTEMPTS: u32 = 100;
+
+// === TABLES ===
+
+/// Tracks supply drops (active until despawned or looted empty)
+#[spacetimedb::table(accessor = supply_drop_event, public)]
+#[derive(Clone, Debug)]
+pub struct SupplyDropEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub box_id: u32,           // WoodenStorageBox holding the loot
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub loot_tier: u8,         // SUPPLY_DROP_TIER_* constant
+    pub dropped_at: Timestamp,
+    pub expires_at: Timestamp, // Despawns at this time if still unopened
+    pub opened_at: Option<Timestamp>,
+    pub is_active: bool,
+}
+
+/// One-shot schedule for the next supply drop (reschedules itself with a random delay)
+#[spacetimedb::table(accessor = supply_drop_spawn_schedule, scheduled(spawn_supply_drop))]
+#[derive(Clone, Debug)]
+pub struct SupplyDropSpawnSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// One-shot schedule that despawns a supply drop if it was never opened
+#[spacetimedb::table(accessor = supply_drop_expiry_schedule, scheduled(expire_supply_drop))]
+#[derive(Clone, Debug)]
+pub struct SupplyDropExpirySchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub event_id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+// === LOOT TIERS ===
+
+pub const SUPPLY_DROP_TIER_SUPPLIES: u8 = 0;
+pub const SUPPLY_DROP_TIER_FIELD_KIT: u8 = 1;
+pub const SUPPLY_DROP_TIER_MILITARY: u8 = 2;
+
+/// Tier roll weights, in tier order (must sum to 1.0)
+const SUPPLY_DROP_TIER_WEIGHTS: [f32; 3] = [0.60, 0.30, 0.10];
+
+fn get_tier_name(tier: u8) -> &'static str {
+    match tier {
+        SUPPLY_DROP_TIER_FIELD_KIT => "field kit",
+        SUPPLY_DROP_TIER_MILITARY => "military",
+        _ => "supply",
+    }
+}
+
+pub const SUPPLY_DROP_LOOT_SOURCE: &str = "supply_drop";
+
+/// Default supply drop loot, seeded into the loot_table subsystem. Higher tiers unlock
+/// their own entries on top of the lower tiers' and roll larger stacks.
+pub fn default_supply_drop_loot_sources() -> Vec<LootSourceDefault> {
+    let entries = vec![
+        // --- SUPPLIES (every tier) ---
+        LootEntryDefault::new("Memory Shard", 1, 3, 0.80),
+        LootEntryDefault::new("Bandage", 2, 4, 0.65),
+        LootEntryDefault::new("Canned Meat", 1, 2, 0.55),
+        LootEntryDefault::new("Rope", 2, 4, 0.50),
+        LootEntryDefault::new("Metal Fragments", 5, 15, 0.50),
+        LootEntryDefault::new("Bone Arrow", 4, 8, 0.40),
+        LootEntryDefault::new("Reed Water Bottle", 1, 1, 0.35),
+        LootEntryDefault::new("Torch", 1, 1, 0.30),
+        // --- FIELD KIT (tier 1+) ---
+        LootEntryDefault::new("Med Kit", 1, 1, 0.45).tier(SUPPLY_DROP_TIER_FIELD_KIT),
+        LootEntryDefault::new("Anti-Venom", 1, 1, 0.35).tier(SUPPLY_DROP_TIER_FIELD_KIT),
+        LootEntryDefault::new("Hunting Bow", 1, 1, 0.30).tier(SUPPLY_DROP_TIER_FIELD_KIT),
+        LootEntryDefault::new("Fire Arrow", 3, 6, 0.40).tier(SUPPLY_DROP_TIER_FIELD_KIT),
+        LootEntryDefault::new("AK74 Bayonet", 1, 1, 0.25).tier(SUPPLY_DROP_TIER_FIELD_KIT),
+        LootEntryDefault::new("Headlamp", 1, 1, 0.25).tier(SUPPLY_DROP_TIER_FIELD_KIT),
+        LootEntryDefault::new("Water Purifier Blueprint", 1, 1, 0.15).tier(SUPPLY_DROP_TIER_FIELD_KIT),
+        LootEntryDefault::new("Flashbang Blueprint", 1, 1, 0.15).tier(SUPPLY_DROP_TIER_FIELD_KIT),
+        LootEntryDefault::new("Plastic Water Jug", 1, 1, 0.25).tier(SUPPLY_DROP_TIER_FIELD_KIT),
+        // --- MILITARY (tier 2) ---
+        LootEntryDefault::new("9x18mm Round", 8, 16, 0.55).tier(SUPPLY_DROP_TIER_MILITARY),
+        LootEntryDefault::new("Makarov PM", 1, 1, 0.30).tier(SUPPLY_DROP_TIER_MILITARY),
+        LootEntryDefault::new("PP-91 KEDR", 1, 1, 0.15).tier(SUPPLY_DROP_TIER_MILITARY),
+        LootEntryDefault::new("Military Crowbar", 1, 1, 0.20).tier(SUPPLY_DROP_TIER_MILITARY),
+        LootEntryDefault::new("Naval Cutlass", 1, 1, 0.20).tier(SUPPLY_DROP_TIER_MILITARY),
+        LootEntryDefault::new("Scrap Batteries", 1, 2, 0.25).tier(SUPPLY_DROP_TIER_MILITARY),
+        LootEntryDefault::new("Makarov PM Blueprint", 1, 1, 0.15).tier(SUPPLY_DROP_TIER_MILITARY),
+        LootEntryDefault::new("PP-91 KEDR Blueprint", 1, 1, 0.08).tier(SUPPLY_DROP_TIER_MILITARY),
+        LootEntryDefault::new("Tallow Steam Turret Blueprint", 1, 1, 0.10).tier(SUPPLY_DROP_TIER_MILITARY),
+        LootEntryDefault::new("Signal Disruptor Blueprint", 1, 1, 0.10).tier(SUPPLY_DROP_TIER_MILITARY),
+    ];
+
+    vec![
+        LootSourceDefault::new(SUPPLY_DROP_LOOT_SOURCE, entries)
+            .drops(1, NUM_SUPPLY_DROP_SLOTS as u32)
+            .tier_scaling(0.0, 0.5), // +50% stack size per tier
+    ]
+}
+
+/// Weighted roll for the loot tier of a new drop
+fn roll_supply_drop_tier(ctx: &ReducerContext) -> u8 {
+    let roll: f32 = ctx.rng().gen();
+    let mut cumulative = 0.0;
+    for (tier, weight) in SUPPLY_DROP_TIER_WEIGHTS.iter().enumerate() {
+        cumulative += weight;
+        if roll < cumulative {
+            return tier as u8;
+        }
+    }
+    SUPPLY_DROP_TIER_SUPPLIES
+}
+
+// === INITIALIZATION / SCHEDULING ===
+
+/// Schedules the first supply drop if none is pending. Called from init_module.
+pub fn init_supply_drop_system(ctx: &ReducerContext) {
+    if ctx.db.supply_drop_spawn_schedule().iter().next().is_some() {
+        log::debug!("[SupplyDrop] Supply drop schedule already exists.");
+        return;
+    }
+    if let Err(e) = schedule_next_supply_drop(ctx) {
+        log::error!("[SupplyDrop] Failed to schedule first supply drop: {}", e);
+    }
+}
+
+fn schedule_next_supply_drop(ctx: &ReducerContext) -> Result<(), String> {
+    let delay_secs = ctx.rng().gen_range(SUPPLY_DROP_MIN_INTERVAL_SECS..=SUPPLY_DROP_MAX_INTERVAL_SECS);
+    let next_at = ctx.timestamp + TimeDuration::from_micros((delay_secs * 1_000_000) as i64);
+    ctx.db.supply_drop_spawn_schedule().try_insert(SupplyDropSpawnSchedule {
+        id: 0,
+        scheduled_at: ScheduleAt::Time(next_at),
+    }).map_err(|e| format!("Failed to schedule supply drop: {}", e))?;
+    log::info!("[SupplyDrop] Next supply drop in {} minutes", delay_secs / 60);
+    Ok(())
+}
+
+/// Scheduled: drops a supply crate (if conditions allow) and schedules the next one
+#[spacetimedb::reducer]
+pub fn spawn_supply_drop(ctx: &ReducerContext, _args: SupplyDropSpawnSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Supply drop spawning can only be run by scheduler".to_string());
+    }
+
+    let online_players = ctx.db.player().iter().filter(|p| p.is_online).count();
+    if online_players == 0 {
+        log::debug!("[SupplyDrop] No players online, skipping supply drop");
+    } else if ctx.db.supply_drop_event().iter().any(|e| e.is_active && e.opened_at.is_none()) {
+        log::debug!("[SupplyDrop] Unopened supply drop still in the world, skipping");
+    } else if let Err(e) = start_supply_drop(ctx) {
+        log::warn!("[SupplyDrop] Failed to drop supply crate: {}", e);
+    }
+
+    schedule_next_supply_drop(ctx)
+}
+
+/// Picks a landing spot, spawns the crate with loot, announces it and schedules expiry
+fn start_supply_drop(ctx: &ReducerContext) -> Result<(), String> {
+    let (pos_x, pos_y) = find_supply_drop_position(ctx)
+        .ok_or_else(|| format!("No open land found after {} attempts", MAX_POSITION_ATTEMPTS))?;
+
+    let loot_tier = roll_supply_drop_tier(ctx);
+    let box_id = spawn_supply_crate_with_loot(ctx, pos_x, pos_y, loot_tier)?;
+
+    let now = ctx.timestamp;
+    let expires_at = now + TimeDuration::from_micros((SUPPLY_DROP_LIFETIME_SECS * 1_000_000) as i64);
+
+    let event = ctx.db.supply_drop_event().insert(SupplyDropEvent {
+        id: 0,
+        box_id,
+        pos_x,
+        pos_y,
+        loot_tier,
+        dropped_at: now,
+        expires_at,
+        opened_at: None,
+        is_active: true,
+    });
+
+    ctx.db.world_event_marker().insert(WorldEventMarker {
+        id: 0,
+        event_type: WorldEventType::SupplyDrop,
+        event_id: event.id,
+        pos_x,
+        pos_y,
+        radius: SUPPLY_DROP_MARKER_RADIUS_PX,
+        label: "Supply Drop".to_string(),
+        created_at: now,
+        expires_at,
+    });
+
+    ctx.db.supply_drop_expiry_schedule().try_insert(SupplyDropExpirySchedule {
+        id: 0,
+        event_id: event.id,
+        scheduled_at: ScheduleAt::Time(expires_at),
+    }).map_err(|e| format!("Failed to schedule supply drop expiry: {}", e))?;
+
+    let grid_label = crate::beacon_event::minimap_grid_label(pos_x, pos_y);
+    send_server_announcement(ctx, format!(
+        "A {} crate has been dropped near grid {}! It will be recovered in {} minutes if nobody claims it.",
+        get_tier_name(loot_tier), grid_label, SUPPLY_DROP_LIFETIME_SECS / 60
+    ));
+    log::info!("[SupplyDrop] 📦 Supply drop {} (tier {}) landed at ({:.0}, {:.0}) grid {}",
+              event.id, loot_tier, pos_x, pos_y, grid_label);
+    Ok(())
+}
+
+// === SPAWN POSITION ===
+
+/// Random open land position: walkable non-forest tile, clear of trees, bases and monuments
+fn find_supply_drop_position(ctx: &ReducerContext) -> Option<(f32, f32)> {
+    for _ in 0..MAX_POSITION_ATTEMPTS {
+        let pos_x = ctx.rng().gen_range(WORLD_EDGE_BUFFER_PX..(WORLD_WIDTH_PX - WORLD_EDGE_BUFFER_PX));
+        let pos_y = ctx.rng().gen_range(WORLD_EDGE_BUFFER_PX..(WORLD_HEIGHT_PX - WORLD_EDGE_BUFFER_PX));
+        if is_valid_supply_drop_position(ctx, pos_x, pos_y) {
+            return Some((pos_x, pos_y));
+        }
+    }
+    None
+}
+
+fn is_valid_supply_drop_position(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    let (tile_x, tile_y) = crate::world_pos_to_tile_coords(pos_x, pos_y);
+    match crate::get_tile_type_at_position(ctx, tile_x, tile_y) {
+        Some(tile_type) if tile_type.is_walkable() && tile_type != TileType::Forest && !tile_type.blocks_building() => {}
+        _ => return false,
+    }
+
+    if is_position_on_water(ctx, pos_x, pos_y)
+        || crate::building::check_monument_zone_placement(ctx, pos_x, pos_y).is_err()
+        || crate::monument::is_position_near_monument(ctx, pos_x, pos_y)
+        || crate::homestead_hearth::is_position_claimed(ctx, pos_x, pos_y)
+        || is_near_player_base(ctx, pos_x, pos_y)
+    {
+        return false;
+    }
+
+    let min_tree_dist_sq = MIN_DISTANCE_FROM_TREES_PX * MIN_DISTANCE_FROM_TREES_PX;
+    !ctx.db.tree().iter()
+        .filter(|t| t.respawn_at == Timestamp::UNIX_EPOCH) // Felled trees don't count
+        .any(|t| {
+            let dx = pos_x - t.pos_x;
+            let dy = pos_y - t.pos_y;
+            dx * dx + dy * dy < min_tree_dist_sq
+        })
+}
+
+fn is_near_player_base(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    use crate::building::{foundation_cell as FoundationCellTableTrait, FOUNDATION_TILE_SIZE_PX};
+    let min_dist_sq = MIN_DISTANCE_FROM_BASES_PX * MIN_DISTANCE_FROM_BASES_PX;
+    ctx.db.foundation_cell().iter()
+        .filter(|f| !f.is_destroyed)
+        .any(|f| {
+            let fx = (f.cell_x as f32 + 0.5) * FOUNDATION_TILE_SIZE_PX as f32;
+            let fy = (f.cell_y as f32 + 0.5) * FOUNDATION_TILE_SIZE_PX as f32;
+            let dx = pos_x - fx;
+            let dy = pos_y - fy;
+            dx * dx + dy * dy < min_dist_sq
+        })
+}
+
+// === CRATE ===
+
+/// Spawns the supply crate container and fills it from the tier's loot roll. Returns the box ID.
+fn spawn_supply_crate_with_loot(ctx: &ReducerContext, pos_x: f32, pos_y: f32, tier: u8) -> Result<u32, String> {
+    let boxes = ctx.db.wooden_storage_box();
+    let inventory_items = ctx.db.inventory_item();
+
+    let supply_crate = WoodenStorageBox {
+        id: 0,
+        pos_x,
+        pos_y,
+        chunk_index: calculate_chunk_index(pos_x, pos_y),
+        placed_by: ctx.identity(),
+        box_type: BOX_TYPE_SUPPLY_DROP,
+        slot_instance_id_0: None, slot_def_id_0: None,
+        slot_instance_id_1: None, slot_def_id_1: None,
+        slot_instance_id_2: None, slot_def_id_2: None,
+        slot_instance_id_3: None, slot_def_id_3: None,
+        slot_instance_id_4: None, slot_def_id_4: None,
+        slot_instance_id_5: None, slot_def_id_5: None,
+        slot_instance_id_6: None, slot_def_id_6: None,
+        slot_instance_id_7: None, slot_def_id_7: None,
+        slot_instance_id_8: None, slot_def_id_8: None,
+        slot_instance_id_9: None, slot_def_id_9: None,
+        slot_instance_id_10: None, slot_def_id_10: None,
+        slot_instance_id_11: None, slot_def_id_11: None,
+        slot_instance_id_12: None, slot_def_id_12: None,
+        slot_instance_id_13: None, slot_def_id_13: None,
+        slot_instance_id_14: None, slot_def_id_14: None,
+        slot_instance_id_15: None, slot_def_id_15: None,
+        slot_instance_id_16: None, slot_def_id_16: None,
+        slot_instance_id_17: None, slot_def_id_17: None,
+        slot_instance_id_18: None, slot_def_id_18: None,
+        slot_instance_id_19: None, slot_def_id_19: None,
+        slot_instance_id_20: None, slot_def_id_20: None,
+        slot_instance_id_21: None, slot_def_id_21: None,
+        slot_instance_id_22: None, slot_def_id_22: None,
+        slot_instance_id_23: None, slot_def_id_23: None,
+        slot_instance_id_24: None, slot_def_id_24: None,
+        slot_instance_id_25: None, slot_def_id_25: None,
+        slot_instance_id_26: None, slot_def_id_26: None,
+        slot_instance_id_27: None, slot_def_id_27: None,
+        slot_instance_id_28: None, slot_def_id_28: None,
+        slot_instance_id_29: None, slot_def_id_29: None,
+        slot_instance_id_30: None, slot_def_id_30: None,
+        slot_instance_id_31: None, slot_def_id_31: None,
+        slot_instance_id_32: None, slot_def_id_32: None,
+        slot_instance_id_33: None, slot_def_id_33: None,
+        slot_instance_id_34: None, slot_def_id_34: None,
+        slot_instance_id_35: None, slot_def_id_35: None,
+        slot_instance_id_36: None, slot_def_id_36: None,
+        slot_instance_id_37: None, slot_def_id_37: None,
+        slot_instance_id_38: None, slot_def_id_38: None,
+        slot_instance_id_39: None, slot_def_id_39: None,
+        slot_instance_id_40: None, slot_def_id_40: None,
+        slot_instance_id_41: None, slot_def_id_41: None,
+        slot_instance_id_42: None, slot_def_id_42: None,
+        slot_instance_id_43: None, slot_def_id_43: None,
+        slot_instance_id_44: None, slot_def_id_44: None,
+        slot_instance_id_45: None, slot_def_id_45: None,
+        slot_instance_id_46: None, slot_def_id_46: None,
+        slot_instance_id_47: None, slot_def_id_47: None,
+        health: SUPPLY_DROP_INITIAL_HEALTH,
+        max_health: SUPPLY_DROP_MAX_HEALTH,
+        is_destroyed: false,
+        destroyed_at: None,
+        last_hit_time: None,
+        last_damaged_by: None,
+        respawn_at: Timestamp::UNIX_EPOCH,
+        is_monument: false,
+        active_user_id: None,
+        active_user_since: None,
+    };
+
+    // Insert the box first so item locations can reference its real ID
+    let mut inserted = boxes.try_insert(supply_crate)
+        .map_err(|e| format!("Failed to insert supply crate: {}", e))?;
+
+    let drops = crate::loot_table::roll_loot(ctx, SUPPLY_DROP_LOOT_SOURCE, tier);
+    for (slot_index, drop) in drops.into_iter().take(NUM_SUPPLY_DROP_SLOTS).enumerate() {
+        let item = inventory_items.insert(InventoryItem {
+            instance_id: 0,
+            item_def_id: drop.item_def_id,
+            quantity: drop.quantity,
+            location: ItemLocation::Container(ContainerLocationData {
+                container_type: ContainerType::WoodenStorageBox,
+                container_id: inserted.id as u64,
+                slot_index: slot_index as u8,
+            }),
+            item_data: None,
+        });
+        inserted.set_slot(slot_index as u8, Some(item.instance_id), Some(item.item_def_id));
+    }
+
+    let box_id = inserted.id;
+    boxes.id().update(inserted);
+    Ok(box_id)
+}
+
+// === CONTAINER HOOKS ===
+
+/// Called from open_storage_box_container - an opened drop no longer expires
+pub fn on_supply_crate_opened(ctx: &ReducerContext, box_id: u32) {
+    let Some(mut event) = ctx.db.supply_drop_event().box_id().filter(&box_id).find(|e| e.is_active) else {
+        return;
+    };
+    if event.opened_at.is_some() {
+        return;
+    }
+
+    event.opened_at = Some(ctx.timestamp);
+    ctx.db.supply_drop_event().id().update(event.clone());
+    crate::world_state::remove_world_event_markers(ctx, WorldEventType::SupplyDrop, event.id);
+
+    let grid_label = crate::beacon_event::minimap_grid_label(event.pos_x, event.pos_y);
+    send_server_announcement(ctx, format!("The supply drop near grid {} has been claimed.", grid_label));
+    log::info!("[SupplyDrop] Supply drop {} opened by {:?}", event.id, ctx.sender());
+}
+
+/// Checks if a supply crate is empty and deletes it if so (mirrors military crates)
+pub fn check_and_despawn_supply_drop_if_empty(ctx: &ReducerContext, box_id: u32) -> Result<(), String> {
+    let supply_crate = ctx.db.wooden_storage_box().id().find(box_id)
+        .ok_or("Supply crate not found")?;
+
+    if supply_crate.box_type != BOX_TYPE_SUPPLY_DROP {
+        return Ok(());
+    }
+
+    if is_container_empty(&supply_crate) {
+        ctx.db.wooden_storage_box().id().delete(box_id);
+        if let Some(event) = ctx.db.supply_drop_event().box_id().filter(&box_id).find(|e| e.is_active) {
+            finish_supply_drop(ctx, event);
+        }
+        log::info!("[SupplyDrop] Auto-despawned empty supply crate {}", box_id);
+    }
+
+    Ok(())
+}
+
+// === EXPIRY ===
+
+/// Scheduled: removes the crate and its loot if nobody opened it in time
+#[spacetimedb::reducer]
+pub fn expire_supply_drop(ctx: &ReducerContext, args: SupplyDropExpirySchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Supply drop expiry can only be run by scheduler".to_string());
+    }
+
+    let Some(event) = ctx.db.supply_drop_event().id().find(&args.event_id) else {
+        return Ok(());
+    };
+    if !event.is_active || event.opened_at.is_some() {
+        return Ok(());
+    }
+
+    if let Some(supply_crate) = ctx.db.wooden_storage_box().id().find(event.box_id) {
+        let inventory_items = ctx.db.inventory_item();
+        for slot_index in 0..supply_crate.num_slots() as u8 {
+            if let Some(instance_id) = supply_crate.get_slot_instance_id(slot_index) {
+                inventory_items.instance_id().delete(instance_id);
+            }
+        }
+        ctx.db.wooden_storage_box().id().delete(event.box_id);
+    }
+
+    log::info!("[SupplyDrop] Unopened supply drop {} despawned", event.id);
+    finish_supply_drop(ctx, event);
+    Ok(())
+}
+
+fn finish_supply_drop(ctx: &ReducerContext, mut event: SupplyDropEvent) {
+    crate::world_state::remove_world_event_markers(ctx, WorldEventType::SupplyDrop, event.id);
+    event.is_active = false;
+    ctx.db.supply_drop_event().id().update(event);
+}
+
+fn send_server_announcement(ctx: &ReducerContext, text: String) {
+    ctx.db.message().insert(crate::chat::Message {
+        id: 0,
+        sender: ctx.identity(), // Server identity
+        sender_username: "[SERVER]".to_string(),
+        sender_title: None,
+        text,
+        sent: ctx.timestamp,
+    });
+}
@@ -0,0 +1,158 @@
+/******************************************************************************
+ *                                                                            *
+ * Tanning Rack - Curing raw hides into leather                               *
+ *                                                                            *
+ * Skinning an animal yields Raw Hide, which has to be cured before it can    *
+ * be worked. Tanning racks are WoodenStorageBox entities with               *
+ * BOX_TYPE_TANNING_RACK: hides left on the rack are cured into Animal       *
+ * Leather one at a time on a schedule, much like a furnace smelting ore.    *
+ * Leather feeds the leather armor tier and the leather saddle.              *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table};
+use spacetimedb::spacetimedb_lib::{ScheduleAt, TimeDuration};
+use log;
+use std::time::Duration;
+
+use crate::wooden_storage_box::{WoodenStorageBox, BOX_TYPE_TANNING_RACK, wooden_storage_box as WoodenStorageBoxTableTrait};
+use crate::items::{InventoryItem, inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::inventory_management::ItemContainer;
+use crate::models::{ItemLocation, ContainerType, ContainerLocationData};
+
+// --- Tanning Constants ---
+pub const TANNING_PROCESS_INTERVAL_SECS: u64 = 60; // Each rack cures one hide a minute
+pub const RAW_HIDE_ITEM_NAME: &str = "Raw Hide";
+pub const LEATHER_ITEM_NAME: &str = "Animal Leather";
+pub const LEATHER_PER_HIDE: u32 = 1;
+
+// --- Tanning Schedule Table ---
+#[spacetimedb::table(accessor = tanning_process_schedule, scheduled(process_tanning_racks))]
+#[derive(Clone)]
+pub struct TanningProcessSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Initialize the tanning rack processing system
+pub fn init_tanning_system(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.tanning_process_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("Starting tanning rack schedule (every {}s).", TANNING_PROCESS_INTERVAL_SECS);
+        let interval = Duration::from_secs(TANNING_PROCESS_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            TanningProcessSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Tanning rack processing"
+        );
+    } else {
+        log::debug!("Tanning rack schedule already exists.");
+    }
+    Ok(())
+}
+
+/// Scheduled reducer that cures one raw hide on every tanning rack
+#[spacetimedb::reducer]
+pub fn process_tanning_racks(ctx: &ReducerContext, _args: TanningProcessSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Tanning processing can only be run by scheduler".to_string());
+    }
+
+    let racks: Vec<WoodenStorageBox> = ctx.db.wooden_storage_box().iter()
+        .filter(|b| b.box_type == BOX_TYPE_TANNING_RACK && !b.is_destroyed)
+        .collect();
+    if racks.is_empty() {
+        return Ok(());
+    }
+
+    let item_defs = ctx.db.item_definition();
+    let hide_def_id = item_defs.iter().find(|def| def.name == RAW_HIDE_ITEM_NAME).map(|def| def.id)
+        .ok_or_else(|| format!("{} item definition not found", RAW_HIDE_ITEM_NAME))?;
+    let (leather_def_id, leather_max_stack) = item_defs.iter()
+        .find(|def| def.name == LEATHER_ITEM_NAME)
+        .map(|def| (def.id, if def.is_stackable { def.stack_size } else { 1 }))
+        .ok_or_else(|| format!("{} item definition not found", LEATHER_ITEM_NAME))?;
+
+    for mut rack in racks {
+        if cure_one_hide(ctx, &mut rack, hide_def_id, leather_def_id, leather_max_stack) {
+            ctx.db.wooden_storage_box().id().update(rack);
+        }
+    }
+    Ok(())
+}
+
+/// Cures a single hide on the rack. Returns true if the rack's slots changed.
+/// A rack with no room for the leather leaves its hides waiting.
+fn cure_one_hide(
+    ctx: &ReducerContext,
+    rack: &mut WoodenStorageBox,
+    hide_def_id: u64,
+    leather_def_id: u64,
+    leather_max_stack: u32,
+) -> bool {
+    let inventory_items = ctx.db.inventory_item();
+
+    let Some(hide_slot) = (0..rack.num_slots() as u8)
+        .find(|slot| rack.get_slot_def_id(*slot) == Some(hide_def_id)) else {
+        return false;
+    };
+    let Some(mut hide) = rack.get_slot_instance_id(hide_slot)
+        .and_then(|id| inventory_items.instance_id().find(id)) else {
+        return false;
+    };
+
+    // Find room for the leather before using up the hide
+    let leather_stack = (0..rack.num_slots() as u8)
+        .filter(|slot| rack.get_slot_def_id(*slot) == Some(leather_def_id))
+        .filter_map(|slot| rack.get_slot_instance_id(slot))
+        .filter_map(|id| inventory_items.instance_id().find(id))
+        .find(|item| item.quantity + LEATHER_PER_HIDE <= leather_max_stack);
+    // The hide's own slot frees up when the last hide of a stack is cured
+    let empty_slot = (0..rack.num_slots() as u8)
+        .find(|slot| rack.get_slot_instance_id(*slot).is_none())
+        .or(if hide.quantity == 1 { Some(hide_slot) } else { None });
+    if leather_stack.is_none() && empty_slot.is_none() {
+        return false;
+    }
+
+    let mut slots_changed = false;
+    if hide.quantity > 1 {
+        hide.quantity -= 1;
+        inventory_items.instance_id().update(hide);
+    } else {
+        rack.set_slot(hide_slot, None, None);
+        inventory_items.instance_id().delete(hide.instance_id);
+        slots_changed = true;
+    }
+
+    if let Some(mut leather) = leather_stack {
+        leather.quantity += LEATHER_PER_HIDE;
+        inventory_items.instance_id().update(leather);
+    } else if let Some(slot) = empty_slot {
+        match inventory_items.try_insert(InventoryItem {
+            instance_id: 0,
+            item_def_id: leather_def_id,
+            quantity: LEATHER_PER_HIDE,
+            location: ItemLocation::Container(ContainerLocationData {
+                container_type: ContainerType::WoodenStorageBox,
+                container_id: rack.id as u64,
+                slot_index: slot,
+            }),
+            item_data: None,
+        }) {
+            Ok(inserted) => {
+                rack.set_slot(slot, Some(inserted.instance_id), Some(leather_def_id));
+                slots_changed = true;
+            }
+            Err(e) => log::error!("[TanningRack] Failed to insert leather into rack {}: {:?}", rack.id, e),
+        }
+    }
+
+    log::debug!("[TanningRack] Rack {} cured a hide into {}x {}", rack.id, LEATHER_PER_HIDE, LEATHER_ITEM_NAME);
+    slots_changed
+}
@@ -0,0 +1,261 @@
+/******************************************************************************
+ *                                                                            *
+ * Temperature - Unified per-player temperature model                        *
+ *                                                                            *
+ * Every stat tick each player gets an effective temperature in degrees C,   *
+ * built from:                                                                *
+ *   - the biome tile they stand on (alpine and tundra are cold, beach warm) *
+ *   - time of day (warm noon, freezing midnight)                            *
+ *   - rain and wetness from `wet` (wind chill)                              *
+ *   - nearby heat sources (campfires, furnaces, village fires, lit torch)   *
+ *   - shelter (buildings cut the cold deficit)                              *
+ *                                                                            *
+ * Clothing from `armor` acts as insulation: it slows warmth loss below the  *
+ * comfort temperature, and wet clothing loses insulation (half by default). *
+ * Heavily insulated players overheat in extreme heat (fumaroles, hot        *
+ * springs, hugging a fire at noon) and sweat out thirst.                    *
+ * player_stats turns the reading into a warmth change per second.           *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+use crate::Player;
+use crate::world_state::TimeOfDay;
+use crate::campfire::{campfire as CampfireTableTrait, WARMTH_RADIUS};
+use crate::furnace::furnace as FurnaceTableTrait;
+use crate::monument_part as MonumentPartTableTrait;
+use crate::MonumentType;
+
+// --- Temperature Constants ---
+pub const COMFORT_TEMPERATURE_C: f32 = 12.0; // Above this players warm up, below it they lose warmth
+pub(crate) const WARMTH_GAIN_PER_DEGREE_ABOVE_COMFORT: f32 = 0.25; // Noon in temperate biomes (20C) = +2.0 warmth/sec
+pub(crate) const WARMTH_LOSS_PER_DEGREE_BELOW_COMFORT: f32 = 0.01; // Midnight in temperate biomes (2C) = -0.10 warmth/sec
+
+// Biome base temperatures (before time of day)
+const TEMPERATE_BASE_TEMPERATURE_C: f32 = 10.0;
+const BEACH_BASE_TEMPERATURE_C: f32 = 12.0;
+const SEA_BASE_TEMPERATURE_C: f32 = 8.0; // Open water is chilly
+const HOT_SPRING_BASE_TEMPERATURE_C: f32 = 30.0;
+const TUNDRA_BASE_TEMPERATURE_C: f32 = 5.0;
+const ALPINE_BASE_TEMPERATURE_C: f32 = 2.0;
+
+// Heat sources - heat falls off linearly from the center to the edge of the radius
+const CAMPFIRE_HEAT_CENTER_C: f32 = 32.0;
+const CAMPFIRE_HEAT_EDGE_C: f32 = 16.0;
+const VILLAGE_CAMPFIRE_HEAT_RADIUS: f32 = 450.0; // Village fires are always burning and larger
+const FURNACE_HEAT_RADIUS: f32 = 200.0;
+const FURNACE_HEAT_CENTER_C: f32 = 24.0;
+const FURNACE_HEAT_EDGE_C: f32 = 10.0;
+const TORCH_HEAT_C: f32 = 7.0;
+const FUMAROLE_HEAT_C: f32 = 28.0; // Quarry fumaroles vent scalding steam
+
+// Weather and wetness
+const RAIN_CHILL_C_PER_DRAIN_POINT: f32 = 10.0; // Storm (1.0 drain) = -10C, light rain (0.2) = -2C
+const WET_CHILL_C: f32 = 4.0;
+
+// Shelter
+const INDOOR_COLD_DEFICIT_MULTIPLIER: f32 = 0.65; // Enclosed buildings cut the cold deficit by 35%
+
+// Overheating - insulated clothing traps heat in extreme temperatures
+pub const OVERHEAT_TEMPERATURE_C: f32 = 30.0;
+pub const OVERHEAT_MIN_INSULATION: f32 = 0.3; // Roughly three fur pieces or a full leather set
+pub(crate) const OVERHEAT_THIRST_DRAIN_PER_SECOND: f32 = 0.5; // At full insulation - scaled by insulation
+
+/// Last computed effective temperature per player, for the client HUD
+#[spacetimedb::table(accessor = player_temperature, public)]
+#[derive(Clone, Debug)]
+pub struct PlayerTemperature {
+    #[primary_key]
+    pub player_id: Identity,
+    pub temperature_c: f32,
+    pub insulation: f32, // 0.0-0.9 share of cold loss blocked by clothing
+    pub is_overheating: bool,
+    pub updated_at: Timestamp,
+}
+
+/// A warm spot in the world - gathered once per stat tick
+#[derive(Clone, Debug)]
+pub struct HeatSource {
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub radius: f32,
+    pub center_c: f32,
+    pub edge_c: f32,
+}
+
+/// Breakdown of one player's temperature
+#[derive(Clone, Debug)]
+pub struct TemperatureReading {
+    pub ambient_c: f32,       // Biome + time of day
+    pub heat_c: f32,          // Fires, furnaces, fumaroles and torch
+    pub chill_c: f32,         // Rain and wetness (positive number, subtracted)
+    pub effective_c: f32,     // Final temperature after shelter
+    pub insulation: f32,      // Clothing insulation (0.0-0.9)
+    pub is_overheating: bool, // Too hot for the clothing being worn
+}
+
+/// Gathers every active heat source: burning campfires, burning furnaces and village campfires
+pub fn collect_heat_sources(ctx: &ReducerContext) -> Vec<HeatSource> {
+    let mut sources = Vec::new();
+
+    for fire in ctx.db.campfire().iter().filter(|f| f.is_burning && !f.is_destroyed) {
+        sources.push(HeatSource {
+            pos_x: fire.pos_x,
+            pos_y: fire.pos_y,
+            radius: WARMTH_RADIUS,
+            center_c: CAMPFIRE_HEAT_CENTER_C,
+            edge_c: CAMPFIRE_HEAT_EDGE_C,
+        });
+    }
+
+    for furnace in ctx.db.furnace().iter().filter(|f| f.is_burning && !f.is_destroyed) {
+        sources.push(HeatSource {
+            pos_x: furnace.pos_x,
+            pos_y: furnace.pos_y,
+            radius: FURNACE_HEAT_RADIUS,
+            center_c: FURNACE_HEAT_CENTER_C,
+            edge_c: FURNACE_HEAT_EDGE_C,
+        });
+    }
+
+    // Village campfires (fishing/hunting) are monument parts that always burn
+    for part in ctx.db.monument_part().iter().filter(|p| {
+        (p.monument_type == MonumentType::FishingVillage || p.monument_type == MonumentType::HuntingVillage)
+            && p.part_type == "campfire"
+    }) {
+        sources.push(HeatSource {
+            pos_x: part.world_x,
+            pos_y: part.world_y,
+            radius: VILLAGE_CAMPFIRE_HEAT_RADIUS,
+            center_c: CAMPFIRE_HEAT_CENTER_C,
+            edge_c: CAMPFIRE_HEAT_EDGE_C,
+        });
+    }
+
+    sources
+}
+
+/// Base temperature of the tile at a position
+pub fn get_biome_base_temperature(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> f32 {
+    let (tile_x, tile_y) = crate::world_pos_to_tile_coords(pos_x, pos_y);
+    match crate::get_tile_type_at_position(ctx, tile_x, tile_y) {
+        Some(tile_type) if tile_type.is_alpine() => ALPINE_BASE_TEMPERATURE_C,
+        Some(tile_type) if tile_type.is_tundra() => TUNDRA_BASE_TEMPERATURE_C,
+        Some(tile_type) if tile_type.is_sea_water() => SEA_BASE_TEMPERATURE_C,
+        Some(crate::TileType::HotSpringWater) => HOT_SPRING_BASE_TEMPERATURE_C,
+        Some(crate::TileType::Beach) | Some(crate::TileType::Sand) => BEACH_BASE_TEMPERATURE_C,
+        _ => TEMPERATE_BASE_TEMPERATURE_C,
+    }
+}
+
+/// How much warmer or colder the time of day makes things
+pub fn get_time_of_day_temperature_offset(time_of_day: &TimeOfDay) -> f32 {
+    match time_of_day {
+        TimeOfDay::Midnight => -8.0,
+        TimeOfDay::Night => -6.0,
+        TimeOfDay::TwilightEvening => -1.0,
+        TimeOfDay::Dusk => 3.0,
+        TimeOfDay::Afternoon => 7.0,
+        TimeOfDay::Noon => 10.0,
+        TimeOfDay::Morning => 7.0,
+        TimeOfDay::TwilightMorning => 5.0,
+        TimeOfDay::Dawn => 3.0,
+    }
+}
+
+/// Computes a player's effective temperature from biome, time, weather, heat sources, shelter and clothing
+pub fn calculate_player_temperature(
+    ctx: &ReducerContext,
+    player: &Player,
+    time_of_day: &TimeOfDay,
+    heat_sources: &[HeatSource],
+) -> TemperatureReading {
+    let ambient_c = get_biome_base_temperature(ctx, player.position_x, player.position_y)
+        + get_time_of_day_temperature_offset(time_of_day);
+
+    // Heat sources stack - sitting between two fires is warmer than one
+    let mut heat_c = 0.0;
+    for source in heat_sources {
+        let dx = player.position_x - source.pos_x;
+        let dy = player.position_y - source.pos_y;
+        let distance_sq = dx * dx + dy * dy;
+        if distance_sq < source.radius * source.radius {
+            let falloff = 1.0 - distance_sq.sqrt() / source.radius;
+            heat_c += source.edge_c + (source.center_c - source.edge_c) * falloff;
+        }
+    }
+    if player.is_torch_lit {
+        heat_c += TORCH_HEAT_C;
+    }
+    if crate::active_effects::player_has_fumarole_effect(ctx, player.identity) {
+        heat_c += FUMAROLE_HEAT_C;
+    }
+
+    // Rain returns 0 under tree cover or in clear weather
+    let is_wet = crate::active_effects::player_has_wet_effect(ctx, player.identity);
+    let mut chill_c = crate::world_state::get_rain_warmth_drain_modifier(ctx, player.position_x, player.position_y)
+        * RAIN_CHILL_C_PER_DRAIN_POINT;
+    if is_wet {
+        chill_c += WET_CHILL_C;
+    }
+
+    let mut effective_c = ambient_c + heat_c - chill_c;
+    if player.is_inside_building && effective_c < COMFORT_TEMPERATURE_C {
+        effective_c = COMFORT_TEMPERATURE_C - (COMFORT_TEMPERATURE_C - effective_c) * INDOOR_COLD_DEFICIT_MULTIPLIER;
+    }
+
+    let insulation = crate::armor::calculate_total_insulation(ctx, player.identity, is_wet);
+    let is_overheating = insulation >= OVERHEAT_MIN_INSULATION && effective_c >= OVERHEAT_TEMPERATURE_C
+        && !crate::armor::has_environmental_protection(ctx, player.identity, crate::models::EnvironmentalHazard::Heat);
+
+    TemperatureReading { ambient_c, heat_c, chill_c, effective_c, insulation, is_overheating }
+}
+
+/// Converts a temperature reading into warmth gained (positive) or lost (negative) per second.
+/// `cold_loss_multiplier` scales the loss side only (e.g. WarmthBoost broth).
+pub fn get_warmth_change_per_second(reading: &TemperatureReading, cold_loss_multiplier: f32) -> f32 {
+    if reading.effective_c >= COMFORT_TEMPERATURE_C {
+        (reading.effective_c - COMFORT_TEMPERATURE_C) * WARMTH_GAIN_PER_DEGREE_ABOVE_COMFORT
+    } else {
+        -(COMFORT_TEMPERATURE_C - reading.effective_c) * WARMTH_LOSS_PER_DEGREE_BELOW_COMFORT
+            * (1.0 - reading.insulation) * cold_loss_multiplier
+    }
+}
+
+/// Extra thirst drained per second by sweating in heavy clothing (0 when not overheating)
+pub fn get_overheat_thirst_drain_per_second(reading: &TemperatureReading) -> f32 {
+    if reading.is_overheating {
+        OVERHEAT_THIRST_DRAIN_PER_SECOND * reading.insulation
+    } else {
+        0.0
+    }
+}
+
+/// Stores the latest reading for the client, skipping writes when nothing visible changed
+pub fn record_player_temperature(ctx: &ReducerContext, player_id: Identity, reading: &TemperatureReading) {
+    let table = ctx.db.player_temperature();
+    match table.player_id().find(player_id) {
+        Some(mut existing) => {
+            if (existing.temperature_c - reading.effective_c).abs() < 0.1
+                && (existing.insulation - reading.insulation).abs() < 0.01
+                && existing.is_overheating == reading.is_overheating {
+                return;
+            }
+            existing.temperature_c = reading.effective_c;
+            existing.insulation = reading.insulation;
+            existing.is_overheating = reading.is_overheating;
+            existing.updated_at = ctx.timestamp;
+            table.player_id().update(existing);
+        }
+        None => {
+            table.insert(PlayerTemperature {
+                player_id,
+                temperature_c: reading.effective_c,
+                insulation: reading.insulation,
+                is_overheating: reading.is_overheating,
+                updated_at: ctx.timestamp,
+            });
+        }
+    }
+}
@@ -0,0 +1,254 @@
+/******************************************************************************
+ *                                                                            *
+ * Throwable items (Throwing Stone, Smoke Bomb, Flashbang).                   *
+ *                                                                            *
+ * throw_item launches one item from the player's inventory as a short-lived *
+ * player projectile. Hits and landings are resolved by update_projectiles,  *
+ * which hands the impact position back here for the area effect:            *
+ * - Throwing Stone: minor direct-hit damage, and the clatter is a noise that *
+ *   draws nearby hostiles to the landing spot. Lands as a dropped item.      *
+ * - Smoke Bomb: leaves a smoke cloud (public, so clients obscure vision)     *
+ *   that cuts animal perception for anything seen into, out of or through it.*
+ * - Flashbang: staggers players and animals in the blast radius.            *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp, TimeDuration};
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::projectile::{Projectile, PROJECTILE_SOURCE_PLAYER, NPC_PROJECTILE_NONE};
+use crate::wild_animal_npc::wild_animal as WildAnimalTableTrait;
+use crate::{PlayerLastAttackTimestamp, player_last_attack_timestamp as PlayerLastAttackTimestampTableTrait};
+use crate::active_effects;
+use crate::sound_events;
+
+// --- Constants ---
+
+pub const THROW_SPEED: f32 = 600.0;
+pub const THROW_MAX_RANGE: f32 = 400.0;
+pub const THROW_COOLDOWN_MS: i64 = 800;
+
+/// Hostile NPCs and wolves within this distance of a landing stone go to investigate it
+pub const STONE_DISTRACTION_RADIUS: f32 = 500.0;
+/// Quiet next to gunfire - a louder noise nearby wins
+pub const STONE_NOISE_INTENSITY: f32 = 0.3;
+
+pub const SMOKE_CLOUD_RADIUS: f32 = 160.0;
+pub const SMOKE_CLOUD_DURATION_SECS: i64 = 15;
+/// Animal perception multiplier when the line of sight touches a smoke cloud
+pub const SMOKE_PERCEPTION_MULTIPLIER: f32 = 0.25;
+
+pub const FLASHBANG_RADIUS: f32 = 200.0;
+pub const FLASHBANG_STAGGER_SECS: f32 = 2.0;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ThrowableKind {
+    Stone,
+    SmokeBomb,
+    Flashbang,
+}
+
+impl ThrowableKind {
+    /// Stones can be picked back up; smoke bombs and flashbangs are spent on impact
+    pub fn drops_on_impact(&self) -> bool {
+        matches!(self, ThrowableKind::Stone)
+    }
+}
+
+/// Returns the throwable kind for an item, or None if it can't be thrown
+pub fn get_throwable_kind(item_name: &str) -> Option<ThrowableKind> {
+    match item_name {
+        "Throwing Stone" => Some(ThrowableKind::Stone),
+        "Smoke Bomb" => Some(ThrowableKind::SmokeBomb),
+        "Flashbang" => Some(ThrowableKind::Flashbang),
+        _ => None,
+    }
+}
+
+// --- Tables ---
+
+/// Active smoke clouds (public so clients can render them and hide what's inside)
+#[spacetimedb::table(accessor = smoke_cloud, public)]
+#[derive(Clone, Debug)]
+pub struct SmokeCloud {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub radius: f32,
+    pub thrown_by: Identity,
+    pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+}
+
+// --- Throwing ---
+
+/// Throws one of the given throwable item toward the target position
+#[spacetimedb::reducer]
+pub fn throw_item(ctx: &ReducerContext, item_instance_id: u64, target_x: f32, target_y: f32, client_shot_id: String) -> Result<(), String> {
+    let player_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&player_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    if player.is_dead || player.is_knocked_out || player.is_sleeping || crate::restraints::is_restrained(ctx, player_id) {
+        return Err("Cannot throw items right now.".to_string());
+    }
+    if active_effects::player_has_stun_effect(ctx, player_id) || active_effects::player_has_staggered_effect(ctx, player_id) {
+        return Err("Cannot throw while stunned or staggered.".to_string());
+    }
+
+    let mut item = crate::items::get_player_item(ctx, item_instance_id)?;
+    let item_def = ctx.db.item_definition().id().find(item.item_def_id)
+        .ok_or_else(|| "Item definition not found.".to_string())?;
+    if get_throwable_kind(&item_def.name).is_none() {
+        return Err(format!("{} can't be thrown.", item_def.name));
+    }
+
+    // Throwing shares the attack cooldown so it can't be spammed between swings and shots
+    let last_attacks = ctx.db.player_last_attack_timestamp();
+    if let Some(last) = last_attacks.player_id().find(&player_id) {
+        let elapsed_micros = ctx.timestamp.to_micros_since_unix_epoch() - last.last_attack_timestamp.to_micros_since_unix_epoch();
+        if elapsed_micros < THROW_COOLDOWN_MS * 1000 {
+            return Err("Too soon to throw again.".to_string());
+        }
+    }
+
+    let dx = target_x - player.position_x;
+    let dy = target_y - player.position_y;
+    let distance = (dx * dx + dy * dy).sqrt();
+    if distance < 1.0 {
+        return Err("Target too close".to_string());
+    }
+
+    // Straight-line flight that lands at the target (or at max range)
+    let projectile = Projectile {
+        id: 0, // auto_inc
+        client_shot_id,
+        owner_id: player_id,
+        item_def_id: item_def.id,
+        ammo_def_id: item_def.id, // Self-ammo: the thrown item is the projectile
+        source_type: PROJECTILE_SOURCE_PLAYER,
+        npc_projectile_type: NPC_PROJECTILE_NONE,
+        start_time: ctx.timestamp,
+        start_pos_x: player.position_x,
+        start_pos_y: player.position_y,
+        velocity_x: dx / distance * THROW_SPEED,
+        velocity_y: dy / distance * THROW_SPEED,
+        max_range: distance.min(THROW_MAX_RANGE),
+        elevation: player.elevation,
+        damage_multiplier: 1.0,
+    };
+    crate::projectile::insert_projectile_with_runtime_state(ctx, projectile);
+
+    if item.quantity > 1 {
+        item.quantity -= 1;
+        ctx.db.inventory_item().instance_id().update(item);
+    } else {
+        ctx.db.inventory_item().instance_id().delete(item_instance_id);
+    }
+
+    let timestamp_record = PlayerLastAttackTimestamp { player_id, last_attack_timestamp: ctx.timestamp };
+    if last_attacks.player_id().find(&player_id).is_some() {
+        last_attacks.player_id().update(timestamp_record);
+    } else {
+        last_attacks.insert(timestamp_record);
+    }
+
+    sound_events::emit_item_thrown_sound(ctx, player.position_x, player.position_y, player_id);
+    log::info!("[Throwables] Player {:?} threw {} toward ({:.1}, {:.1})", player_id, item_def.name, target_x, target_y);
+    Ok(())
+}
+
+// --- Impact Effects ---
+
+/// Applies a throwable's area effect where it landed (called by update_projectiles)
+pub fn resolve_throwable_impact(ctx: &ReducerContext, kind: ThrowableKind, thrower_id: Identity, pos_x: f32, pos_y: f32) {
+    match kind {
+        ThrowableKind::Stone => distract_hostiles(ctx, thrower_id, pos_x, pos_y),
+        ThrowableKind::SmokeBomb => create_smoke_cloud(ctx, thrower_id, pos_x, pos_y),
+        ThrowableKind::Flashbang => detonate_flashbang(ctx, thrower_id, pos_x, pos_y),
+    }
+}
+
+fn distract_hostiles(ctx: &ReducerContext, thrower_id: Identity, pos_x: f32, pos_y: f32) {
+    sound_events::emit_stone_hit_sound(ctx, pos_x, pos_y, thrower_id);
+    sound_events::register_noise(ctx, pos_x, pos_y, STONE_DISTRACTION_RADIUS, STONE_NOISE_INTENSITY, thrower_id);
+}
+
+fn create_smoke_cloud(ctx: &ReducerContext, thrower_id: Identity, pos_x: f32, pos_y: f32) {
+    // Expired clouds are cleaned up lazily whenever a new one is created
+    let clouds = ctx.db.smoke_cloud();
+    let expired_ids: Vec<u64> = clouds.iter()
+        .filter(|cloud| cloud.expires_at <= ctx.timestamp)
+        .map(|cloud| cloud.id)
+        .collect();
+    for id in expired_ids {
+        clouds.id().delete(id);
+    }
+
+    clouds.insert(SmokeCloud {
+        id: 0, // auto_inc
+        pos_x,
+        pos_y,
+        radius: SMOKE_CLOUD_RADIUS,
+        thrown_by: thrower_id,
+        created_at: ctx.timestamp,
+        expires_at: ctx.timestamp + TimeDuration::from_micros(SMOKE_CLOUD_DURATION_SECS * 1_000_000),
+    });
+    log::info!("[Throwables] Smoke cloud created at ({:.1}, {:.1}) by {:?}", pos_x, pos_y, thrower_id);
+}
+
+fn detonate_flashbang(ctx: &ReducerContext, thrower_id: Identity, pos_x: f32, pos_y: f32) {
+    sound_events::emit_explosion_sound(ctx, pos_x, pos_y, thrower_id);
+    let radius_sq = FLASHBANG_RADIUS * FLASHBANG_RADIUS;
+    let within = |x: f32, y: f32| {
+        let dx = x - pos_x;
+        let dy = y - pos_y;
+        dx * dx + dy * dy <= radius_sq
+    };
+
+    // Players: the thrower is always caught by their own flash; others only when PvP rules allow it
+    let thrower = ctx.db.player().identity().find(&thrower_id);
+    for target in ctx.db.player().iter() {
+        if target.is_dead || !target.is_online || !within(target.position_x, target.position_y) {
+            continue;
+        }
+        if target.identity != thrower_id {
+            let pvp_allowed = crate::combat::PVP_ENABLED
+                && thrower.as_ref().map(|t| crate::combat::is_pvp_active_for_player(t, ctx.timestamp)).unwrap_or(false)
+                && crate::combat::is_pvp_active_for_player(&target, ctx.timestamp)
+                && !active_effects::player_has_safe_zone_effect(ctx, target.identity);
+            if !pvp_allowed {
+                continue;
+            }
+        }
+        if let Err(e) = active_effects::apply_staggered_effect(ctx, target.identity, FLASHBANG_STAGGER_SECS) {
+            log::error!("[Throwables] Failed to stagger player {:?}: {}", target.identity, e);
+        }
+    }
+
+    // Animals: pushing the last attack time into the future delays their next attack
+    let stagger_micros = (FLASHBANG_STAGGER_SECS * 1_000_000.0) as i64;
+    let dazed: Vec<_> = ctx.db.wild_animal().iter()
+        .filter(|animal| {
+            let (animal_x, animal_y) = crate::wild_animal_npc::transform_batch::live_position(ctx, animal);
+            within(animal_x, animal_y)
+        })
+        .collect();
+    for mut animal in dazed {
+        animal.last_attack_time = Some(ctx.timestamp + TimeDuration::from_micros(stagger_micros));
+        ctx.db.wild_animal().id().update(animal);
+    }
+    log::info!("[Throwables] Flashbang detonated at ({:.1}, {:.1}) by {:?}", pos_x, pos_y, thrower_id);
+}
+
+// --- Smoke Queries ---
+
+/// True if the sight line between two points starts, ends or passes through an active smoke cloud
+pub fn is_sight_obscured_by_smoke(ctx: &ReducerContext, from_x: f32, from_y: f32, to_x: f32, to_y: f32) -> bool {
+    ctx.db.smoke_cloud().iter()
+        .filter(|cloud| cloud.expires_at > ctx.timestamp)
+        .any(|cloud| crate::projectile::line_intersects_circle(from_x, from_y, to_x, to_y, cloud.pos_x, cloud.pos_y, cloud.radius))
+}
@@ -0,0 +1,252 @@
+/******************************************************************************
+ *                                                                            *
+ * Tracks - Footprints in tundra and snow                                     *
+ *                                                                            *
+ * Players and animals moving over tundra and alpine ground - and over any    *
+ * land while winter snow covers it - leave short-lived tracks pointing the  *
+ * way they went. Tracks are public so every client can draw them, but who   *
+ * left them is kept in the private track_maker table: only a skilled hunter *
+ * (enough animal kills) can inspect a track to learn what passed and when,  *
+ * which is written to their own track_reading row.                           *
+ *                                                                            *
+ * Falling snow fills tracks in, so they vanish faster in winter weather and *
+ * fastest in a blizzard.                                                     *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{client_visibility_filter, Filter, Identity, ReducerContext, Table, TimeDuration, Timestamp};
+use std::collections::HashMap;
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::player_progression::player_stats as PlayerStatsTableTrait;
+use crate::world_state::{chunk_weather as ChunkWeatherTableTrait, Season, WeatherType};
+use crate::wild_animal_npc::AnimalSpecies;
+use crate::environment::calculate_chunk_index;
+use crate::TileType;
+
+// === CONSTANTS ===
+
+/// How long a track lasts in calm weather
+pub const TRACK_LIFETIME_SECS: f32 = 240.0;
+/// Tracks vanish this many times faster while snow is falling on them
+pub const SNOWFALL_TRACK_ERASE_MULTIPLIER: f32 = 2.0;
+/// ...and this many times faster in a blizzard
+pub const BLIZZARD_TRACK_ERASE_MULTIPLIER: f32 = 4.0;
+/// Minimum distance between two tracks of the same maker
+pub const TRACK_SPACING_PX: f32 = 96.0;
+/// How close a player must be to inspect a track
+pub const TRACK_INSPECT_DISTANCE: f32 = 96.0;
+/// Animal kills needed to read tracks (the "Competent Hunter" achievement)
+pub const TRACK_READING_MIN_ANIMALS_KILLED: u32 = 25;
+const TRACK_CLEANUP_INTERVAL_SECS: u64 = 10;
+
+/// A footprint (public - position and heading only)
+#[spacetimedb::table(accessor = track, public)]
+#[derive(Clone, Debug)]
+pub struct Track {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub chunk_index: u32,
+    /// Heading of the maker in radians
+    pub direction: f32,
+    pub created_at: Timestamp,
+}
+
+/// Who left a track (private - revealed only by inspecting)
+#[spacetimedb::table(accessor = track_maker)]
+#[derive(Clone, Debug)]
+pub struct TrackMaker {
+    #[primary_key]
+    pub track_id: u64,
+    pub player_id: Option<Identity>,
+    pub animal_id: Option<u64>,
+    pub species: Option<AnimalSpecies>,
+}
+
+/// What a hunter learned from the last track they inspected (one row per player)
+#[spacetimedb::table(accessor = track_reading, public)]
+#[derive(Clone, Debug)]
+pub struct TrackReading {
+    #[primary_key]
+    pub player_id: Identity,
+    pub track_id: u64,
+    pub maker_name: String,
+    pub direction: f32,
+    pub age_secs: u32,
+    pub read_at: Timestamp,
+}
+
+// Only the hunter sees what they read from a track
+#[client_visibility_filter]
+const ONLY_OWN_TRACK_READING: Filter = Filter::Sql("SELECT * FROM track_reading WHERE player_id = :sender");
+
+#[spacetimedb::table(accessor = track_cleanup_schedule, scheduled(cleanup_tracks))]
+#[derive(Clone, Debug)]
+pub struct TrackCleanupSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub schedule_id: u64,
+    pub scheduled_at: spacetimedb::ScheduleAt,
+}
+
+pub fn init_track_cleanup_schedule(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.track_cleanup_schedule();
+    if schedule_table.iter().count() > 0 {
+        return Ok(());
+    }
+    let interval = TimeDuration::from_micros((TRACK_CLEANUP_INTERVAL_SECS * 1_000_000) as i64);
+    crate::try_insert_schedule!(
+        schedule_table,
+        TrackCleanupSchedule { schedule_id: 0, scheduled_at: spacetimedb::ScheduleAt::Interval(interval) },
+        "Track cleanup"
+    );
+    Ok(())
+}
+
+/// Ground that holds footprints: tundra and alpine always, any open land under winter snow
+fn holds_tracks(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    let (tile_x, tile_y) = crate::world_pos_to_tile_coords(pos_x, pos_y);
+    match crate::get_tile_type_at_position(ctx, tile_x, tile_y) {
+        Some(TileType::Tundra | TileType::TundraGrass | TileType::Alpine) => true,
+        Some(TileType::Asphalt) => false,
+        Some(tile_type) if tile_type.is_walkable() => {
+            matches!(crate::world_state::get_current_season(ctx), Ok(Season::Winter))
+        }
+        _ => false,
+    }
+}
+
+/// Leaves a track when something moved from (from_x, from_y) onto track-holding ground.
+/// Only one track per TRACK_SPACING_PX of travel is kept for each maker.
+fn leave_track(ctx: &ReducerContext, maker: TrackMaker, from_x: f32, from_y: f32, to_x: f32, to_y: f32) {
+    if crate::world_pos_to_tile_coords(from_x, from_y) == crate::world_pos_to_tile_coords(to_x, to_y) {
+        return; // Only check once per tile entered
+    }
+    if !holds_tracks(ctx, to_x, to_y) {
+        return;
+    }
+
+    let chunk_index = calculate_chunk_index(to_x, to_y);
+    let makers = ctx.db.track_maker();
+    let too_close = ctx.db.track().chunk_index().filter(chunk_index)
+        .filter(|t| crate::utils::get_distance_squared(t.pos_x, t.pos_y, to_x, to_y) < TRACK_SPACING_PX * TRACK_SPACING_PX)
+        .any(|t| makers.track_id().find(t.id)
+            .is_some_and(|m| m.player_id == maker.player_id && m.animal_id == maker.animal_id));
+    if too_close {
+        return;
+    }
+
+    let track = ctx.db.track().insert(Track {
+        id: 0,
+        pos_x: to_x,
+        pos_y: to_y,
+        chunk_index,
+        direction: (to_y - from_y).atan2(to_x - from_x),
+        created_at: ctx.timestamp,
+    });
+    makers.insert(TrackMaker { track_id: track.id, ..maker });
+}
+
+pub fn leave_player_track(ctx: &ReducerContext, player_id: Identity, from_x: f32, from_y: f32, to_x: f32, to_y: f32) {
+    let maker = TrackMaker { track_id: 0, player_id: Some(player_id), animal_id: None, species: None };
+    leave_track(ctx, maker, from_x, from_y, to_x, to_y);
+}
+
+pub fn leave_animal_track(ctx: &ReducerContext, animal_id: u64, species: AnimalSpecies, from_x: f32, from_y: f32, to_x: f32, to_y: f32) {
+    let maker = TrackMaker { track_id: 0, player_id: None, animal_id: Some(animal_id), species: Some(species) };
+    leave_track(ctx, maker, from_x, from_y, to_x, to_y);
+}
+
+/// How many times faster tracks fill in under a chunk's weather
+fn track_erase_multiplier(ctx: &ReducerContext, chunk_index: u32, is_winter: bool) -> f32 {
+    let weather = ctx.db.chunk_weather().chunk_index().find(&chunk_index)
+        .map(|w| w.current_weather)
+        .unwrap_or(WeatherType::Clear);
+    match weather {
+        WeatherType::HeavyStorm if is_winter => BLIZZARD_TRACK_ERASE_MULTIPLIER,
+        WeatherType::Clear => 1.0,
+        _ if is_winter => SNOWFALL_TRACK_ERASE_MULTIPLIER,
+        _ => 1.0,
+    }
+}
+
+/// Scheduled reducer that removes tracks once the snow or time has erased them
+#[spacetimedb::reducer]
+pub fn cleanup_tracks(ctx: &ReducerContext, _schedule: TrackCleanupSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("cleanup_tracks may only be called by the scheduler.".to_string());
+    }
+
+    let is_winter = matches!(crate::world_state::get_current_season(ctx), Ok(Season::Winter));
+    let now_micros = ctx.timestamp.to_micros_since_unix_epoch();
+    let mut erase_multipliers: HashMap<u32, f32> = HashMap::new();
+    let erased: Vec<u64> = ctx.db.track().iter()
+        .filter(|t| {
+            let multiplier = *erase_multipliers.entry(t.chunk_index)
+                .or_insert_with(|| track_erase_multiplier(ctx, t.chunk_index, is_winter));
+            let age_secs = (now_micros - t.created_at.to_micros_since_unix_epoch()) as f32 / 1_000_000.0;
+            age_secs * multiplier >= TRACK_LIFETIME_SECS
+        })
+        .map(|t| t.id)
+        .collect();
+
+    for track_id in &erased {
+        ctx.db.track().id().delete(track_id);
+        ctx.db.track_maker().track_id().delete(track_id);
+    }
+    if !erased.is_empty() {
+        log::debug!("[Tracks] {} tracks faded", erased.len());
+    }
+    Ok(())
+}
+
+/// --- Inspect Track ---
+/// A skilled hunter reads what left a nearby track and how long ago.
+#[spacetimedb::reducer]
+pub fn inspect_track(ctx: &ReducerContext, track_id: u64) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let player = ctx.db.player().identity().find(sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+    if player.is_dead || player.is_knocked_out {
+        return Err("You can't inspect tracks right now.".to_string());
+    }
+    let track = ctx.db.track().id().find(track_id)
+        .ok_or_else(|| "Those tracks have faded.".to_string())?;
+    if crate::utils::get_distance_squared(player.position_x, player.position_y, track.pos_x, track.pos_y)
+        > TRACK_INSPECT_DISTANCE * TRACK_INSPECT_DISTANCE {
+        return Err("Too far away to read those tracks.".to_string());
+    }
+    let animals_killed = ctx.db.player_stats().player_id().find(sender_id).map_or(0, |s| s.animals_killed);
+    if animals_killed < TRACK_READING_MIN_ANIMALS_KILLED {
+        return Err("You can't tell what left these tracks - you need more hunting experience.".to_string());
+    }
+
+    let maker = ctx.db.track_maker().track_id().find(track_id);
+    let maker_name = match maker.and_then(|m| m.species) {
+        Some(species) => crate::wild_animal_npc::core::animal_display_name(species).to_string(),
+        None => "Human".to_string(),
+    };
+    let age_secs = ((ctx.timestamp.to_micros_since_unix_epoch() - track.created_at.to_micros_since_unix_epoch()) / 1_000_000).max(0) as u32;
+    let reading = TrackReading {
+        player_id: sender_id,
+        track_id,
+        maker_name,
+        direction: track.direction,
+        age_secs,
+        read_at: ctx.timestamp,
+    };
+    log::info!("[Tracks] Player {:?} read track {}: {} ({}s old)", sender_id, track_id, reading.maker_name, age_secs);
+
+    let readings = ctx.db.track_reading();
+    if readings.player_id().find(sender_id).is_some() {
+        readings.player_id().update(reading);
+    } else {
+        readings.insert(reading);
+    }
+    Ok(())
+}
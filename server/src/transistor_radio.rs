@@ -88,7 +88,8 @@ pub fn spawn_transistor_radio(ctx: &ReducerContext) -> Result<(), String> {
         spawn_y: None,
     };
     
-    dropped_item_table.insert(dropped_item);
+    let inserted = dropped_item_table.insert(dropped_item);
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), inserted.pos_x, inserted.pos_y);
     
     log::info!(
         "Transistor Radio spawned at Crashed Research Drone ({:.1}, {:.1})",
@@ -111,4 +111,6 @@ pub struct Tree {
     /// Whether this tree was planted by a player (from Pinecone/Birch Catkin).
     /// Player-planted trees: don't respawn, yield less wood (60% of normal).
     pub is_player_planted: bool,
+    /// Whether this tree was burned down by a wildfire (client renders a burnt stump until respawn).
+    pub is_burnt: bool,
 }
\ No newline at end of file
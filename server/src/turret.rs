@@ -205,7 +205,7 @@ fn find_target(ctx: &ReducerContext, turret: &Turret, current_time: Timestamp) -
     
     // Priority 1: Hostile NPCs (always target - NOT regular wild animals like wolves/foxes)
     let mut closest_npc: Option<(u64, f32)> = None;
-    for animal in ctx.db.wild_animal().iter() {
+    for animal in ctx.db.wild_animal().iter().map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row)) {
         // ONLY target hostile NPCs (Shorebound, Shardkin, DrownedWatch)
         // Never target regular animals (wolves, foxes, etc.)
         if animal.is_hostile_npc && animal.health > 0.0 {
@@ -272,7 +272,7 @@ fn find_monument_target(ctx: &ReducerContext, turret: &Turret, current_time: Tim
     let mut closest_target: Option<(TargetInfo, f32)> = None;
     
     // Target ONLY the 3 apparitions (Shorebound, Shardkin, Drowned Watch) - not regular wild animals
-    for animal in ctx.db.wild_animal().iter() {
+    for animal in ctx.db.wild_animal().iter().map(|row| crate::wild_animal_npc::transform_batch::with_live_position(ctx, row)) {
         if animal.health <= 0.0 {
             continue;
         }
@@ -383,6 +383,9 @@ pub fn place_turret(ctx: &ReducerContext, item_instance_id: u64, world_x: f32, w
 
     crate::placeable_collision::check_placeable_overlap(ctx, world_x, world_y, 48.0, 48.0, true)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     // Create turret
     let chunk_index = calculate_chunk_index(world_x, world_y);
     let new_turret = Turret {
@@ -709,7 +712,7 @@ pub fn process_turret_logic_scheduled(ctx: &ReducerContext, _schedule: TurretPro
                 // Get target position
                 let (target_x, target_y) = if let Some(animal_id) = turret.current_target_id {
                     if let Some(animal) = ctx.db.wild_animal().id().find(&animal_id) {
-                        (animal.pos_x, animal.pos_y)
+                        crate::wild_animal_npc::transform_batch::live_position(ctx, &animal)
                     } else {
                         ctx.db.turret().id().update(turret);
                         continue;
@@ -751,6 +754,8 @@ pub fn process_turret_logic_scheduled(ctx: &ReducerContext, _schedule: TurretPro
                         velocity_x,
                         velocity_y,
                         max_range: MONUMENT_TURRET_RANGE * 1.5,
+                        elevation: crate::building_floor::ELEVATION_GROUND,
+                        damage_multiplier: 1.0,
                     };
                     
                     let inserted_projectile = ctx.db.projectile().insert(projectile);
@@ -811,7 +816,7 @@ pub fn process_turret_logic_scheduled(ctx: &ReducerContext, _schedule: TurretPro
                     // Get target position
                     let (target_x, target_y) = if let Some(animal_id) = turret.current_target_id {
                         if let Some(animal) = ctx.db.wild_animal().id().find(&animal_id) {
-                            (animal.pos_x, animal.pos_y)
+                            crate::wild_animal_npc::transform_batch::live_position(ctx, &animal)
                         } else {
                             continue; // Target disappeared
                         }
@@ -856,6 +861,8 @@ pub fn process_turret_logic_scheduled(ctx: &ReducerContext, _schedule: TurretPro
                             velocity_x,
                             velocity_y,
                             max_range: TURRET_RANGE * 1.5, // Slightly longer than detection range
+                            elevation: crate::building_floor::ELEVATION_GROUND, // Turrets are placed on the ground floor
+                            damage_multiplier: 1.0,
                         };
                         
                         let inserted_projectile = ctx.db.projectile().insert(projectile);
@@ -104,7 +104,7 @@ fn calculate_watering_position(player_x: f32, player_y: f32, facing_direction: f
 }
 
 /// Check if there's already a water patch at the target location (prevent stacking)
-fn has_water_patch_at_location(ctx: &ReducerContext, x: f32, y: f32) -> bool {
+pub(crate) fn has_water_patch_at_location(ctx: &ReducerContext, x: f32, y: f32) -> bool {
     for patch in ctx.db.water_patch().iter() {
         let dx = patch.pos_x - x;
         let dy = patch.pos_y - y;
@@ -157,6 +157,23 @@ pub fn get_water_patch_growth_multiplier(ctx: &ReducerContext, plant_x: f32, pla
     best_multiplier
 }
 
+/// Insert a fresh water patch at the given position (shared by manual watering and sprinklers)
+pub(crate) fn create_water_patch(ctx: &ReducerContext, x: f32, y: f32, created_by: Identity, water_amount: f32, is_salt_water: bool) -> WaterPatch {
+    let duration = TimeDuration::from(Duration::from_secs(WATER_PATCH_DURATION_SECS));
+    ctx.db.water_patch().insert(WaterPatch {
+        id: 0, // Auto-inc
+        pos_x: x,
+        pos_y: y,
+        chunk_index: calculate_chunk_index(x, y),
+        created_at: ctx.timestamp,
+        expires_at: ctx.timestamp + duration,
+        created_by,
+        water_amount,
+        current_opacity: 1.0, // Start fully visible
+        is_salt_water, // Track if this is salt water (negative effect on crops)
+    })
+}
+
 // --- Reducers ---
 
 /// Water crops with a water container (triggered by left-click with water container)
@@ -329,24 +346,7 @@ pub fn water_crops(ctx: &ReducerContext, container_instance_id: u64) -> Result<(
         }
         
         // Create water patch
-        let chunk_index = calculate_chunk_index(water_x, water_y);
-        let duration = TimeDuration::from(Duration::from_secs(WATER_PATCH_DURATION_SECS));
-        let expires_at = ctx.timestamp + duration;
-        
-        let water_patch = WaterPatch {
-            id: 0, // Auto-inc
-            pos_x: water_x,
-            pos_y: water_y,
-            chunk_index,
-            created_at: ctx.timestamp,
-            expires_at,
-            created_by: player_id,
-            water_amount: WATER_CONSUMPTION_PER_USE,
-            current_opacity: 1.0, // Start fully visible
-            is_salt_water, // Track if this is salt water (negative effect on crops)
-        };
-        
-        ctx.db.water_patch().insert(water_patch);
+        create_water_patch(ctx, water_x, water_y, player_id, WATER_CONSUMPTION_PER_USE, is_salt_water);
         
         if is_salt_water {
             log::info!("Player {} created salt water patch at ({:.1}, {:.1}) using {:.1}L of salt water (will harm crops)", 
@@ -113,15 +113,6 @@ pub fn is_position_in_whale_bone_graveyard_exclusion_zone(ctx: &ReducerContext,
     None
 }
 
-/// Check if a position is within the safe zone of the whale bone graveyard
-/// NOTE: Safe zone DISABLED - whale bone graveyard is NOT a PvP-protected area
-/// Always returns false - players can engage in PvP combat here
-pub fn is_position_in_whale_bone_graveyard_safe_zone(_ctx: &ReducerContext, _pos_x: f32, _pos_y: f32) -> bool {
-    // Safe zone disabled per design decision
-    // The whale bone graveyard is a hostile/mysterious area where PvP is allowed
-    false
-}
-
 /// Check if a position is within the cozy radius of the whale bone graveyard
 /// Players within this zone get the cozy effect (warmth, comfort)
 pub fn is_position_in_whale_bone_graveyard_cozy_zone(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
@@ -356,7 +347,8 @@ pub fn spawn_bone_carving_kit(ctx: &ReducerContext) -> Result<(), String> {
         spawn_y: None,
     };
     
-    dropped_item_table.insert(dropped_item);
+    let inserted = dropped_item_table.insert(dropped_item);
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(inserted.id), inserted.pos_x, inserted.pos_y);
     
     log::info!(
         "Bone Carving Kit spawned at Whale Bone Graveyard ({:.1}, {:.1})",
@@ -15,6 +15,7 @@ use crate::models::TargetType;
 use crate::items::ItemCategory;
 use crate::environment::calculate_chunk_index;
 use super::core::AnimalSpecies; // Use AnimalSpecies from core module
+use crate::corpse_decay::CorpseDecayStage;
 
 // Define constants for animal corpses
 const DEFAULT_ANIMAL_CORPSE_DESPAWN_SECONDS: u64 = 300; // 5 minutes - more forgiving if interrupted while hunting
@@ -51,6 +52,9 @@ pub struct AnimalCorpse {
     pub max_health: u32,
     pub last_hit_time: Option<Timestamp>,
     pub last_hit_by: Option<Identity>, // Track who last hit this corpse
+
+    pub decay_stage: CorpseDecayStage, // Fresh -> Decaying -> Bones (see corpse_decay.rs)
+    pub butchering_stage: ButcheringStage, // Skinning -> Butchering -> Boning as the corpse is harvested
 }
 
 impl AnimalCorpse {
@@ -79,6 +83,8 @@ impl AnimalCorpse {
             max_health: ANIMAL_CORPSE_INITIAL_HEALTH,
             last_hit_time: None,
             last_hit_by: None,
+            decay_stage: CorpseDecayStage::Fresh,
+            butchering_stage: ButcheringStage::Skinning,
         }
     }
 }
@@ -187,6 +193,190 @@ fn get_meat_type(animal_species: AnimalSpecies) -> &'static str {
     }
 }
 
+// --- Loot Table Sources ---
+
+/// Loot tags used by damage_animal_corpse to apply per-resource bonuses
+pub const CORPSE_LOOT_TAG_FAT: &str = "fat";
+pub const CORPSE_LOOT_TAG_CLOTH: &str = "cloth";
+pub const CORPSE_LOOT_TAG_LEATHER: &str = "leather";
+pub const CORPSE_LOOT_TAG_BONE: &str = "bone";
+pub const CORPSE_LOOT_TAG_MEAT: &str = "meat";
+
+// --- Butchering ---
+
+/// Corpses are harvested in order: pelt first, then meat and fat, then bones.
+/// Each harvesting hit only yields resources for the corpse's current stage.
+#[derive(SpacetimeType, Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum ButcheringStage {
+    Skinning,   // Fur, scales, feathers and leather
+    Butchering, // Meat and fat
+    Boning,     // Bones
+}
+
+/// Corpse health fraction at which each stage ends
+const SKINNING_END_HEALTH_FRACTION: f32 = 2.0 / 3.0;
+const BUTCHERING_END_HEALTH_FRACTION: f32 = 1.0 / 3.0;
+
+impl ButcheringStage {
+    /// Whether a harvest loot entry (by tag) can come off the corpse in this stage
+    pub fn yields_tag(&self, tag: &str) -> bool {
+        let is_pelt = tag == CORPSE_LOOT_TAG_CLOTH || tag == CORPSE_LOOT_TAG_LEATHER;
+        match self {
+            ButcheringStage::Skinning => is_pelt,
+            ButcheringStage::Butchering => !is_pelt && tag != CORPSE_LOOT_TAG_BONE,
+            ButcheringStage::Boning => tag == CORPSE_LOOT_TAG_BONE,
+        }
+    }
+
+    /// Stage a corpse has reached once harvested down to this much health
+    pub fn for_health(health: u32, max_health: u32) -> Self {
+        let fraction = health as f32 / max_health.max(1) as f32;
+        if fraction > SKINNING_END_HEALTH_FRACTION {
+            ButcheringStage::Skinning
+        } else if fraction > BUTCHERING_END_HEALTH_FRACTION {
+            ButcheringStage::Butchering
+        } else {
+            ButcheringStage::Boning
+        }
+    }
+}
+
+/// How well a tool butchers - decides which resources come off intact and how much of them
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ButcheringTool {
+    Knife,   // Clean cuts: the only way to get pelts off intact
+    Cleaver, // Heavy chopping: more meat and bone, but ruins the pelt
+    Crude,   // Rocks, clubs and anything else: ruins the pelt and mangles the meat
+}
+
+impl ButcheringTool {
+    pub fn from_item_name(item_name: &str) -> Self {
+        match item_name {
+            "Bone Knife" | "AK74 Bayonet" | "Tidebreaker Blade" | "Metal Dagger" | "Bone Shiv" => ButcheringTool::Knife,
+            "Metal Cleaver" | "Bush Knife" | "Battle Axe" | "Naval Cutlass" => ButcheringTool::Cleaver,
+            _ => ButcheringTool::Crude,
+        }
+    }
+
+    /// Only knives take pelts off intact - anything else hacks straight through the hide
+    pub fn keeps_pelts_intact(&self) -> bool {
+        *self == ButcheringTool::Knife
+    }
+
+    /// Quantity multiplier for a harvest loot entry (by tag)
+    pub fn yield_multiplier(&self, tag: &str) -> f32 {
+        match (self, tag) {
+            (ButcheringTool::Cleaver, CORPSE_LOOT_TAG_BONE) => 1.5,
+            (ButcheringTool::Cleaver, _) => 1.25,
+            (ButcheringTool::Crude, CORPSE_LOOT_TAG_BONE) => 1.0,
+            (ButcheringTool::Crude, _) => 0.5,
+            (ButcheringTool::Knife, _) => 1.0,
+        }
+    }
+}
+
+/// Species that leave harvestable corpses (hostile NPCs and bees never do)
+const HARVESTABLE_CORPSE_SPECIES: [AnimalSpecies; 15] = [
+    AnimalSpecies::CinderFox, AnimalSpecies::TundraWolf, AnimalSpecies::CableViper,
+    AnimalSpecies::ArcticWalrus, AnimalSpecies::BeachCrab, AnimalSpecies::Tern,
+    AnimalSpecies::Crow, AnimalSpecies::Vole, AnimalSpecies::Wolverine,
+    AnimalSpecies::Caribou, AnimalSpecies::SalmonShark, AnimalSpecies::Jellyfish,
+    AnimalSpecies::PolarBear, AnimalSpecies::Hare, AnimalSpecies::SnowyOwl,
+];
+
+/// Loot source rolled on every harvesting hit ("animal_corpse:CinderFox", ...)
+pub fn animal_corpse_harvest_source_key(species: AnimalSpecies) -> String {
+    format!("animal_corpse:{:?}", species)
+}
+
+/// Loot source granted once when the corpse is depleted
+pub fn animal_corpse_depleted_source_key(species: AnimalSpecies) -> String {
+    format!("animal_corpse_depleted:{:?}", species)
+}
+
+/// Cloth-type resource dropped while harvesting (fur, scales, feathers)
+fn get_corpse_cloth_type(animal_species: AnimalSpecies) -> Option<&'static str> {
+    match animal_species {
+        AnimalSpecies::CinderFox => Some("Fox Fur"),
+        AnimalSpecies::TundraWolf => Some("Wolf Fur"),
+        AnimalSpecies::CableViper => Some("Viper Scale"),
+        AnimalSpecies::Tern => Some("Tern Feathers"),
+        AnimalSpecies::Crow => Some("Crow Feathers"),
+        AnimalSpecies::Jellyfish => Some("Jellyfish Membrane"),
+        // Walrus, crab, vole, wolverine, caribou, shark and alpine animals drop Raw Hide instead
+        _ => None,
+    }
+}
+
+/// Skull trophy granted when the corpse is depleted (crabs and jellyfish have none)
+fn get_corpse_skull_type(animal_species: AnimalSpecies) -> Option<&'static str> {
+    match animal_species {
+        AnimalSpecies::CinderFox => Some("Fox Skull"),
+        AnimalSpecies::TundraWolf => Some("Wolf Skull"),
+        AnimalSpecies::CableViper => Some("Viper Skull"),
+        AnimalSpecies::ArcticWalrus => Some("Walrus Skull"),
+        AnimalSpecies::Tern => Some("Tern Skull"),
+        AnimalSpecies::Crow => Some("Crow Skull"),
+        AnimalSpecies::Vole => Some("Vole Skull"),
+        AnimalSpecies::Wolverine => Some("Wolverine Skull"),
+        AnimalSpecies::Caribou => Some("Caribou Skull"),
+        AnimalSpecies::SalmonShark => Some("Shark Skull"),
+        AnimalSpecies::PolarBear => Some("Polar Bear Skull"),
+        AnimalSpecies::Hare => Some("Hare Skull"),
+        AnimalSpecies::SnowyOwl => Some("Owl Skull"),
+        _ => None,
+    }
+}
+
+/// Default corpse loot, seeded into the loot_table subsystem. Harvest entry quantities
+/// are per unit of tool yield - damage_animal_corpse multiplies them by the tool's
+/// per-hit quantity and caps each chance at its base value.
+pub fn default_animal_corpse_loot_sources() -> Vec<crate::loot_table::LootSourceDefault> {
+    use crate::loot_table::{LootEntryDefault, LootSourceDefault};
+    const RAW_HIDE_CHANCE: f32 = 0.40;
+
+    let mut sources = Vec::new();
+    for species in HARVESTABLE_CORPSE_SPECIES {
+        let (fat_chance, cloth_chance, bone_chance, meat_chance) = get_animal_loot_chances(species);
+        let is_crab = species == AnimalSpecies::BeachCrab; // Exoskeleton: no fat, leather or bone
+        let mut harvest = Vec::new();
+
+        if !is_crab && fat_chance > 0.0 {
+            harvest.push(LootEntryDefault::new("Animal Fat", 1, 1, fat_chance as f32).tag(CORPSE_LOOT_TAG_FAT));
+        }
+        if let Some(cloth_name) = get_corpse_cloth_type(species) {
+            if cloth_chance > 0.0 {
+                harvest.push(LootEntryDefault::new(cloth_name, 1, 1, cloth_chance as f32).tag(CORPSE_LOOT_TAG_CLOTH));
+            }
+        }
+        if !is_crab {
+            // Hides have to be cured on a tanning rack before they're usable leather
+            harvest.push(LootEntryDefault::new(crate::tanning_rack::RAW_HIDE_ITEM_NAME, 1, 1, RAW_HIDE_CHANCE).tag(CORPSE_LOOT_TAG_LEATHER));
+        }
+        if !is_crab && bone_chance > 0.0 {
+            harvest.push(LootEntryDefault::new("Animal Bone", 1, 1, bone_chance as f32).tag(CORPSE_LOOT_TAG_BONE));
+        }
+        if meat_chance > 0.0 {
+            harvest.push(LootEntryDefault::new(get_meat_type(species), 1, 1, meat_chance as f32).tag(CORPSE_LOOT_TAG_MEAT));
+        }
+        sources.push(LootSourceDefault::new(animal_corpse_harvest_source_key(species), harvest));
+
+        let mut depleted = Vec::new();
+        if let Some(skull_name) = get_corpse_skull_type(species) {
+            depleted.push(LootEntryDefault::new(skull_name, 1, 1, 1.0));
+        }
+        // Guaranteed species drops
+        match species {
+            AnimalSpecies::CableViper => depleted.push(LootEntryDefault::new("Cable Viper Gland", 1, 1, 1.0)),
+            AnimalSpecies::SalmonShark => depleted.push(LootEntryDefault::new("Shark Fin", 1, 1, 1.0)),
+            AnimalSpecies::Jellyfish => depleted.push(LootEntryDefault::new("Jellyfish Stinger", 1, 1, 1.0)),
+            _ => {}
+        }
+        sources.push(LootSourceDefault::new(animal_corpse_depleted_source_key(species), depleted));
+    }
+    sources
+}
+
 /// Scheduled reducer for cleaning up old animal corpses
 /// This will be called periodically to remove corpses that have been around too long
 #[spacetimedb::reducer]
@@ -217,6 +407,9 @@ pub fn cleanup_expired_animal_corpses(ctx: &ReducerContext) -> Result<(), String
             super::walrus::cleanup_walrus_breeding_data(ctx, animal_id);
         }
         
+        // Tamed animal product cooldowns die with the animal
+        super::animal_products::cleanup_tamed_animal_production(ctx, animal_id);
+        
         if animal_corpse_table.id().delete(&corpse_id) {
             cleaned_count += 1;
             log::debug!("Cleaned up expired animal corpse {}", corpse_id);
@@ -345,7 +538,7 @@ pub fn get_harvest_loot(
             animal_leather_chance = animal_leather_chance.max(MIN_BASIC_RESOURCE_CHANCE);
         }
         if rng.gen_bool(animal_leather_chance) {
-            loot.push(("Animal Leather".to_string(), base_quantity));
+            loot.push((crate::tanning_rack::RAW_HIDE_ITEM_NAME.to_string(), base_quantity));
         }
     }
     
@@ -0,0 +1,214 @@
+/******************************************************************************
+ *                                                                            *
+ * Tamed Animal Products - Passive produce from tamed animals                 *
+ *                                                                            *
+ * Tamed animals periodically produce something their owner can collect      *
+ * (milk, feathers, wool...). Each species declares its product in           *
+ * get_animal_product(); readiness is tracked per animal in the              *
+ * TamedAnimalProduction table by game day (world_state.cycle_count), so a   *
+ * product with a 1-day cooldown becomes ready again at the next dawn.       *
+ *                                                                            *
+ * Current products:                                                          *
+ *   - Caribou (adult female): Raw Milk, once per game day                   *
+ *   - Arctic Walrus (adult female): Raw Milk, once per game day             *
+ *                                                                            *
+ * Milk products keep the breeding data's last_milked_day in sync so the     *
+ * client milking indicator keeps working.                                   *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Identity, Table};
+use rand::Rng;
+use log;
+
+use crate::utils::get_distance_squared;
+use crate::player as PlayerTableTrait;
+use crate::items::item_definition as ItemDefinitionTableTrait;
+use crate::world_state::world_state as WorldStateTableTrait;
+use crate::wild_animal_npc::caribou::caribou_breeding_data as CaribouBreedingDataTableTrait;
+use crate::wild_animal_npc::walrus::walrus_breeding_data as WalrusBreedingDataTableTrait;
+
+use super::core::{AnimalSpecies, WildAnimal, wild_animal as WildAnimalTableTrait};
+
+/// How close the owner must stand to collect a product
+const HARVEST_TAMED_ANIMAL_RANGE: f32 = 100.0;
+const HARVEST_TAMED_ANIMAL_RANGE_SQUARED: f32 = HARVEST_TAMED_ANIMAL_RANGE * HARVEST_TAMED_ANIMAL_RANGE;
+
+/// What a tamed animal produces and how often
+#[derive(Clone, Copy, Debug)]
+pub struct AnimalProduct {
+    pub item_name: &'static str,
+    pub min_quantity: u32,
+    pub max_quantity: u32,
+    pub cooldown_days: u32, // Game days between harvests (1 = ready again at next dawn)
+}
+
+const RAW_MILK: AnimalProduct = AnimalProduct { item_name: "Raw Milk", min_quantity: 1, max_quantity: 1, cooldown_days: 1 };
+
+// --- Production Table ---
+#[spacetimedb::table(accessor = tamed_animal_production, public)]
+#[derive(Clone, Debug)]
+pub struct TamedAnimalProduction {
+    #[primary_key]
+    pub animal_id: u64,
+    pub product_item_name: String,
+    pub last_harvested_day: Option<u32>, // Game day (cycle_count) of the last harvest
+    pub total_harvested: u32,
+}
+
+/// Returns the product a tamed animal can give, or an error explaining why it can't.
+/// Age/sex requirements come from the species' breeding data.
+pub fn get_animal_product(ctx: &ReducerContext, animal: &WildAnimal) -> Result<AnimalProduct, String> {
+    match animal.species {
+        AnimalSpecies::Caribou => {
+            use crate::wild_animal_npc::caribou::{CaribouSex, CaribouAgeStage};
+            let breeding_data = ctx.db.caribou_breeding_data().animal_id().find(animal.id)
+                .ok_or_else(|| "Caribou breeding data not found".to_string())?;
+            if breeding_data.sex != CaribouSex::Female {
+                return Err("Only female caribou can be milked".to_string());
+            }
+            if breeding_data.age_stage != CaribouAgeStage::Adult {
+                return Err("Only adult caribou can be milked".to_string());
+            }
+            Ok(RAW_MILK)
+        }
+        AnimalSpecies::ArcticWalrus => {
+            use crate::wild_animal_npc::walrus::{WalrusSex, WalrusAgeStage};
+            let breeding_data = ctx.db.walrus_breeding_data().animal_id().find(animal.id)
+                .ok_or_else(|| "Walrus breeding data not found".to_string())?;
+            if breeding_data.sex != WalrusSex::Female {
+                return Err("Only female walruses can be milked".to_string());
+            }
+            if breeding_data.age_stage != WalrusAgeStage::Adult {
+                return Err("Only adult walruses can be milked".to_string());
+            }
+            Ok(RAW_MILK)
+        }
+        _ => Err(format!("{:?} doesn't produce anything", animal.species)),
+    }
+}
+
+/// Seeds a production row for animals milked before this table existed
+fn get_legacy_last_milked_day(ctx: &ReducerContext, animal: &WildAnimal) -> Option<u32> {
+    match animal.species {
+        AnimalSpecies::Caribou => ctx.db.caribou_breeding_data().animal_id().find(animal.id).and_then(|b| b.last_milked_day),
+        AnimalSpecies::ArcticWalrus => ctx.db.walrus_breeding_data().animal_id().find(animal.id).and_then(|b| b.last_milked_day),
+        _ => None,
+    }
+}
+
+/// Mirrors a milk harvest into the breeding data (read by the client milking indicator)
+fn sync_last_milked_day(ctx: &ReducerContext, animal: &WildAnimal, current_day: u32) {
+    match animal.species {
+        AnimalSpecies::Caribou => {
+            if let Some(mut breeding_data) = ctx.db.caribou_breeding_data().animal_id().find(animal.id) {
+                breeding_data.last_milked_day = Some(current_day);
+                ctx.db.caribou_breeding_data().animal_id().update(breeding_data);
+            }
+        }
+        AnimalSpecies::ArcticWalrus => {
+            if let Some(mut breeding_data) = ctx.db.walrus_breeding_data().animal_id().find(animal.id) {
+                breeding_data.last_milked_day = Some(current_day);
+                ctx.db.walrus_breeding_data().animal_id().update(breeding_data);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether a production row is off cooldown on the given game day
+pub fn is_product_ready(production: Option<&TamedAnimalProduction>, product: &AnimalProduct, current_day: u32) -> bool {
+    match production.and_then(|p| p.last_harvested_day) {
+        None => true, // Never harvested
+        Some(last_day) => current_day >= last_day + product.cooldown_days,
+    }
+}
+
+/// Collect the product of a tamed animal
+/// Requirements:
+/// - Animal must be tamed by the caller
+/// - Animal must meet its species' product requirements (e.g. adult female for milk)
+/// - Product must be off cooldown (cooldowns reset at dawn)
+/// - Player must be close enough to the animal
+#[spacetimedb::reducer]
+pub fn harvest_tamed_animal(ctx: &ReducerContext, animal_id: u64) -> Result<(), String> {
+    let player = ctx.db.player().identity().find(&ctx.sender())
+        .ok_or_else(|| "Player not found".to_string())?;
+    if player.is_dead {
+        return Err("Cannot harvest while dead".to_string());
+    }
+
+    let animal = ctx.db.wild_animal().id().find(animal_id)
+        .map(|row| super::transform_batch::with_live_position(ctx, row))
+        .ok_or_else(|| "Animal not found".to_string())?;
+
+    let tamed_by = animal.tamed_by.ok_or_else(|| "Animal is not tamed".to_string())?;
+    if tamed_by != ctx.sender() {
+        return Err("You don't own this animal".to_string());
+    }
+
+    let distance_sq = get_distance_squared(player.position_x, player.position_y, animal.pos_x, animal.pos_y);
+    if distance_sq > HARVEST_TAMED_ANIMAL_RANGE_SQUARED {
+        return Err("Too far from animal to harvest".to_string());
+    }
+
+    let product = get_animal_product(ctx, &animal)?;
+
+    let current_day = ctx.db.world_state().iter().next()
+        .ok_or_else(|| "World state not found".to_string())?
+        .cycle_count;
+
+    let production_table = ctx.db.tamed_animal_production();
+    let existing = production_table.animal_id().find(animal.id);
+    let mut production = existing.clone().unwrap_or_else(|| TamedAnimalProduction {
+        animal_id: animal.id,
+        product_item_name: product.item_name.to_string(),
+        last_harvested_day: get_legacy_last_milked_day(ctx, &animal),
+        total_harvested: 0,
+    });
+
+    if !is_product_ready(Some(&production), &product, current_day) {
+        return Err(format!("This {:?} has no {} ready yet - check back after dawn", animal.species, product.item_name));
+    }
+
+    give_product_to_player(ctx, ctx.sender(), &product)?;
+
+    production.product_item_name = product.item_name.to_string();
+    production.last_harvested_day = Some(current_day);
+    production.total_harvested += 1;
+    if existing.is_some() {
+        production_table.animal_id().update(production);
+    } else {
+        production_table.insert(production);
+    }
+
+    if product.item_name == RAW_MILK.item_name {
+        sync_last_milked_day(ctx, &animal, current_day);
+    }
+
+    log::info!("🐾 Player {} harvested {} from tamed {:?} {} (day {})",
+              ctx.sender(), product.item_name, animal.species, animal.id, current_day);
+    Ok(())
+}
+
+/// Milk a tamed caribou or walrus - kept for existing clients, same as harvest_tamed_animal
+#[spacetimedb::reducer]
+pub fn milk_animal(ctx: &ReducerContext, animal_id: u64) -> Result<(), String> {
+    harvest_tamed_animal(ctx, animal_id)
+}
+
+/// Helper to give a product item to the player
+fn give_product_to_player(ctx: &ReducerContext, player_id: Identity, product: &AnimalProduct) -> Result<(), String> {
+    let item_def = ctx.db.item_definition().iter()
+        .find(|def| def.name == product.item_name)
+        .ok_or_else(|| format!("{} item definition not found", product.item_name))?;
+
+    let quantity = ctx.rng().gen_range(product.min_quantity..=product.max_quantity);
+    crate::items::add_item_to_player_inventory(ctx, player_id, item_def.id, quantity)?;
+    Ok(())
+}
+
+/// Removes production rows for animals that no longer exist (called from corpse/cleanup paths)
+pub fn cleanup_tamed_animal_production(ctx: &ReducerContext, animal_id: u64) {
+    ctx.db.tamed_animal_production().animal_id().delete(animal_id);
+}
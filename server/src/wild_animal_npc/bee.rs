@@ -7,6 +7,8 @@
  *                                                                            *
  * Key behaviors:                                                             *
  * - Spawns at wild beehives (BOX_TYPE_WILD_BEEHIVE)                         *
+ * - Roused from player beehives when attacked; settles back into the hive   *
+ *   once the attacker is gone (never aggros on passersby)                   *
  * - Very fast movement, attacks frequently with low damage                  *
  * - Returns to home hive when player leaves range                           *
  * - IMMUNE to normal damage - only dies from fire proximity                 *
@@ -21,7 +23,7 @@ use log;
 
 use crate::Player;
 use crate::utils::get_distance_squared;
-use crate::wooden_storage_box::BOX_TYPE_WILD_BEEHIVE;
+use crate::wooden_storage_box::{BOX_TYPE_WILD_BEEHIVE, BOX_TYPE_PLAYER_BEEHIVE};
 use crate::environment::calculate_chunk_index;
 
 // Table trait imports  
@@ -48,6 +50,7 @@ const BEE_TORCH_KILL_RADIUS: f32 = 180.0; // Larger kill radius for torches - be
 const BEE_TORCH_KILL_RADIUS_SQ: f32 = BEE_TORCH_KILL_RADIUS * BEE_TORCH_KILL_RADIUS;
 const BEE_FIRE_PATCH_KILL_RADIUS: f32 = 80.0; // Fire patches (from fire arrows) - increased from 50
 const BEE_FIRE_PATCH_KILL_RADIUS_SQ: f32 = BEE_FIRE_PATCH_KILL_RADIUS * BEE_FIRE_PATCH_KILL_RADIUS;
+const BEE_SETTLE_RADIUS: f32 = 60.0; // Player-hive bees re-enter the hive within this range of home
 
 impl AnimalBehavior for BeeBehavior {
     fn get_stats(&self) -> AnimalStats {
@@ -104,6 +107,20 @@ impl AnimalBehavior for BeeBehavior {
         }
         
         match animal.state {
+            AnimalState::Idle | AnimalState::Patrolling if is_player_hive_bee(ctx, animal) => {
+                // Defenders from a player beehive fly home and settle back in once the threat is gone
+                let dist_sq = get_distance_squared(animal.pos_x, animal.pos_y, animal.spawn_x, animal.spawn_y);
+                if dist_sq <= BEE_SETTLE_RADIUS * BEE_SETTLE_RADIUS {
+                    use super::core::wild_animal as WildAnimalTableTrait;
+                    ctx.db.wild_animal().id().delete(&animal.id);
+                    crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(animal.id));
+                    log::debug!("🐝 Bee {} settled back into player beehive", animal.id);
+                } else {
+                    animal.investigation_x = Some(animal.spawn_x);
+                    animal.investigation_y = Some(animal.spawn_y);
+                }
+            },
+            
             AnimalState::Idle | AnimalState::Patrolling => {
                 if let Some(player) = detected_player {
                     // Only aggro if player is near the home hive
@@ -248,6 +265,11 @@ impl AnimalBehavior for BeeBehavior {
             return false;
         }
         
+        // Player-hive bees only go after whoever attacked the hive
+        if is_player_hive_bee(ctx, animal) {
+            return false;
+        }
+        
         player_to_hive_dist_sq < BEE_AGGRO_RANGE * BEE_AGGRO_RANGE
     }
 
@@ -278,6 +300,13 @@ impl AnimalBehavior for BeeBehavior {
     }
 }
 
+/// Whether this bee was roused from a player-built beehive (pack_id is the home hive's box ID)
+fn is_player_hive_bee(ctx: &ReducerContext, animal: &WildAnimal) -> bool {
+    animal.pack_id
+        .and_then(|hive_id| ctx.db.wooden_storage_box().id().find(hive_id as u32))
+        .map_or(false, |hive| hive.box_type == BOX_TYPE_PLAYER_BEEHIVE)
+}
+
 /// Check if bee is near fire and should die instantly
 /// Returns true if the bee died
 fn check_and_apply_fire_death(ctx: &ReducerContext, animal: &mut WildAnimal) -> bool {
@@ -374,6 +403,7 @@ fn emit_bee_death_and_delete(ctx: &ReducerContext, animal: &WildAnimal) {
     
     // Delete the bee - no corpse for bees
     ctx.db.wild_animal().id().delete(&animal.id);
+    crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(animal.id));
     log::debug!("🐝 Bee {} removed after fire death (no corpse)", animal.id);
 }
 
@@ -455,6 +485,7 @@ pub fn spawn_bees_at_hive(
         };
         
         let inserted = ctx.db.wild_animal().insert(bee);
+        crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
         spawned_ids.push(inserted.id);
         log::debug!("Spawned bee {} at hive {} ({}, {})", inserted.id, hive_id, spawn_x, spawn_y);
     }
@@ -48,6 +48,7 @@ use super::core::{
     TAMING_PROTECT_RADIUS, ThreatType, detect_threats_to_owner, find_closest_threat,
     handle_generic_threat_targeting, detect_and_handle_stuck_movement, set_flee_destination_away_from_threat,
 };
+use super::crop_grazing::{GrazingConfig, try_start_grazing, update_grazing, is_grazing, clear_grazing_target};
 
 // =============================================================================
 // CARIBOU BREEDING SYSTEM - ENUMS AND CONSTANTS
@@ -202,6 +203,13 @@ const CARIBOU_HERD_DETECTION_RADIUS: f32 = 1400.0; // Large radius so scattered
 const CARIBOU_HERD_CLOSE_DISTANCE: f32 = 400.0; // Distance at which caribou consider themselves "close enough" to herd
 const CARIBOU_LOW_HEALTH_THRESHOLD: f32 = 0.30; // Below 30% health, caribou fight back
 
+// Caribou wander into farms and trample whatever they graze on
+const CARIBOU_GRAZING: GrazingConfig = GrazingConfig {
+    detection_radius: 450.0,
+    check_chance: 0.02, // ~every 6s while patrolling (AI ticks 8x per second)
+    bite_growth_loss: 1.0, // Trampled - crop is destroyed outright
+};
+
 pub struct CaribouBehavior;
 
 // Caribou-specific trait (for future extensions)
@@ -337,9 +345,15 @@ impl AnimalBehavior for CaribouBehavior {
                         return Ok(());
                     }
                 }
+                
+                // 🌾 Wander over to graze on nearby player crops
+                try_start_grazing(ctx, animal, &CARIBOU_GRAZING, current_time, rng);
             },
             
             AnimalState::Fleeing => {
+                // Spooked off any crop we were heading for
+                clear_grazing_target(animal);
+                
                 // Check if we're cornered (low health while fleeing)
                 if health_percent < CARIBOU_LOW_HEALTH_THRESHOLD {
                     if let Some(player) = detected_player {
@@ -436,8 +450,8 @@ impl AnimalBehavior for CaribouBehavior {
             },
             
             AnimalState::Investigating => {
-                // Caribou use Investigating state to return to herd after fleeing
-                // Still check for nearby threats while returning
+                // Caribou use Investigating state to return to herd after fleeing (or to reach crops)
+                // Still check for nearby threats while moving
                 if let Some(player) = detected_player {
                     let distance_sq = get_distance_squared(
                         animal.pos_x, animal.pos_y,
@@ -453,12 +467,19 @@ impl AnimalBehavior for CaribouBehavior {
                             600.0,
                             rng
                         );
+                        clear_grazing_target(animal);
                         transition_to_state(animal, AnimalState::Fleeing, current_time, None, "spooked while returning to herd");
                         emit_species_sound(ctx, animal, player.identity, "spooked");
                         return Ok(());
                     }
                 }
                 
+                // Investigating is also used to walk to crops
+                if is_grazing(animal) {
+                    update_grazing(ctx, animal, &CARIBOU_GRAZING, current_time);
+                    return Ok(());
+                }
+                
                 // Check if reached herd destination
                 if let (Some(target_x), Some(target_y)) = (animal.investigation_x, animal.investigation_y) {
                     let distance_sq = get_distance_squared(animal.pos_x, animal.pos_y, target_x, target_y);
@@ -1021,6 +1042,7 @@ fn process_calf_mortality(ctx: &ReducerContext) -> Result<(), String> {
         if let Some(animal) = ctx.db.wild_animal().id().find(&animal_id) {
             // Create a corpse if desired, or just remove
             ctx.db.wild_animal().id().delete(&animal_id);
+            crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(animal_id));
         }
     }
     
@@ -1289,6 +1311,7 @@ fn spawn_caribou_calf(
     
     let inserted = ctx.db.wild_animal().try_insert(new_calf)
         .map_err(|e| format!("Failed to spawn calf: {}", e))?;
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
     
     // Create breeding data for the calf
     create_caribou_breeding_data(ctx, inserted.id, sex, current_day, true)?;
@@ -36,6 +36,7 @@ use crate::animal_collision::{
 use crate::player as PlayerTableTrait;
 use crate::wild_animal_npc::wild_animal as WildAnimalTableTrait;
 use crate::wild_animal_npc::wild_animal_ai_schedule as WildAnimalAiScheduleTableTrait;
+use crate::wild_animal_npc::animal_ai_shard_state as AnimalAiShardStateTableTrait;
 // Breeding data table traits for milking
 use crate::wild_animal_npc::caribou::caribou_breeding_data as CaribouBreedingDataTableTrait;
 use crate::wild_animal_npc::walrus::walrus_breeding_data as WalrusBreedingDataTableTrait;
@@ -101,7 +102,7 @@ const PACK_FORMATION_RADIUS: f32 = 400.0; // Distance wolves can form packs (inc
 const PACK_FORMATION_CHANCE: f32 = 0.20; // 20% chance per encounter to form pack (increased)
 const PACK_DISSOLUTION_CHANCE: f32 = 0.03; // 3% chance per AI tick for wolf to leave pack (reduced for longer packs)
 const PACK_CHECK_INTERVAL_MS: i64 = 5000; // Check pack formation/dissolution every 5 seconds (longer intervals)
-const MAX_PACK_SIZE: usize = 5; // Maximum wolves per pack (epic threat requiring 4-5 coordinated players)
+pub const MAX_PACK_SIZE: usize = 5; // Maximum wolves per pack (epic threat requiring 4-5 coordinated players)
 const PACK_COHESION_RADIUS: f32 = 350.0; // Distance pack members try to stay near alpha (increased with formation radius)
 
 // Taming behavior constants
@@ -122,7 +123,9 @@ pub const TAMING_STAY_DISTANCE_SQUARED: f32 = TAMING_STAY_DISTANCE * TAMING_STAY
 // Animal AI tick interval - determines how often animals update their position/behavior
 // 125ms (8x/sec) provides smooth movement that matches player responsiveness
 pub const AI_TICK_INTERVAL_MS: u64 = 125; // AI processes 8 times per second for smooth movement
-pub const MAX_ANIMALS_PER_CHUNK: u32 = 3;
+// AI LOAD SPREADING: non-urgent animals are split into buckets by id and one bucket runs per tick,
+// so each is processed every AI_SHARD_COUNT ticks (500ms). Urgent animals run every tick.
+pub const AI_SHARD_COUNT: u64 = 4;
 pub const ANIMAL_SPAWN_COOLDOWN_SECS: u64 = 120; // 2 minutes between spawns
 
 // VIEWPORT-BASED CULLING OPTIMIZATION
@@ -171,6 +174,8 @@ pub struct PreFetchedAIData {
     pub burning_campfires: Vec<Campfire>,
     pub active_foundations: Vec<FoundationCell>,
     pub active_foundation_positions: Vec<(f32, f32)>,
+    pub meat_scented_players: HashSet<Identity>, // Players carrying raw meat/fish (see scent.rs)
+    pub active_scent_markers: Vec<super::scent::ScentMarker>,
 }
 
 impl PreFetchedAIData {
@@ -198,6 +203,7 @@ impl PreFetchedAIData {
                 (x, y)
             })
             .collect();
+        let meat_scented_players = super::scent::collect_meat_scented_players(ctx, &all_players);
         Self {
             // Only online players matter for active zone / wander checks - saves iterations
             all_players,
@@ -207,6 +213,8 @@ impl PreFetchedAIData {
                 .collect(),
             active_foundations,
             active_foundation_positions,
+            meat_scented_players,
+            active_scent_markers: super::scent::collect_active_scent_markers(ctx),
         }
     }
 }
@@ -293,7 +301,7 @@ pub struct AnimalStats {
 
 // --- Main Animal Entity Table ---
 #[spacetimedb::table(accessor = wild_animal, public)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct WildAnimal {
     #[primary_key]
     #[auto_inc]
@@ -368,6 +376,15 @@ pub struct WildAnimalAiSchedule {
     pub scheduled_at: ScheduleAt,
 }
 
+// --- AI Shard Rotation (singleton, id 0) ---
+#[spacetimedb::table(accessor = animal_ai_shard_state)]
+#[derive(Clone, Debug)]
+pub struct AnimalAiShardState {
+    #[primary_key]
+    pub id: u32,
+    pub tick: u64,
+}
+
 // --- Species-Specific Behavior Trait ---
 pub trait AnimalBehavior {
     /// Get species-specific stats
@@ -841,6 +858,23 @@ pub fn init_wild_animal_ai_schedule(ctx: &ReducerContext) -> Result<(), String>
     Ok(())
 }
 
+/// Advances the AI shard rotation, returning this tick's number
+fn advance_ai_shard(ctx: &ReducerContext) -> u64 {
+    let shard_states = ctx.db.animal_ai_shard_state();
+    match shard_states.id().find(0) {
+        Some(mut state) => {
+            state.tick = state.tick.wrapping_add(1);
+            let tick = state.tick;
+            shard_states.id().update(state);
+            tick
+        }
+        None => {
+            shard_states.insert(AnimalAiShardState { id: 0, tick: 0 });
+            0
+        }
+    }
+}
+
 // --- AI Processing Reducer ---
 
 #[spacetimedb::reducer]
@@ -858,6 +892,9 @@ pub fn process_wild_animal_ai(ctx: &ReducerContext, _schedule: WildAnimalAiSched
         return Ok(());
     }
 
+    // Fish schools drift on the same tick (throttled per school inside)
+    crate::fishing::update_fish_schools(ctx);
+
     // Early return if there are no animals - prevents unnecessary processing
     let animal_count = ctx.db.wild_animal().iter().count();
     if animal_count == 0 {
@@ -885,10 +922,21 @@ pub fn process_wild_animal_ai(ctx: &ReducerContext, _schedule: WildAnimalAiSched
     let animals: Vec<WildAnimal> = collect_animals_in_active_chunks(ctx, &prefetched.all_players);
     let pack_snapshot = build_pack_snapshot(ctx);
     
+    // TRANSFORM BATCHING: positions live in per-chunk batches between full row syncs
+    let batching_enabled = super::transform_batch::is_batching_enabled(ctx);
+    let mut processed_transforms: Vec<super::transform_batch::AnimalLivePosition> = Vec::new();
+    
+    // AI LOAD SPREADING: this tick's bucket of non-urgent animals
+    let shard_bucket = advance_ai_shard(ctx) % AI_SHARD_COUNT;
+    
     for mut animal in animals {
         // CRITICAL FIX: Wrap each animal's processing in error handling to prevent one bad animal from stopping the entire AI system
         let animal_id = animal.id;
         let animal_species = animal.species;
+        let stored_animal = if batching_enabled { Some(animal.clone()) } else { None };
+        if batching_enabled {
+            super::transform_batch::apply_batched_transform(ctx, &mut animal);
+        }
         
         // VIEWPORT CULLING OPTIMIZATION: Skip animals far from all players
         // They remain frozen in place until a player gets close
@@ -909,13 +957,23 @@ pub fn process_wild_animal_ai(ctx: &ReducerContext, _schedule: WildAnimalAiSched
             continue;
         }
         
+        // Urgent animals (tamed, fighting, fleeing, hunting structures) run every tick;
+        // the rest only on their bucket's tick, moving AI_SHARD_COUNT ticks' worth at once
+        // (resolve_animal_collision walks long moves in sub-steps so they can't skip past obstacles)
+        let is_urgent = is_tamed || is_active_state
+            || matches!(animal.state, AnimalState::Stalking | AnimalState::AttackingStructure);
+        if !is_urgent && animal.id % AI_SHARD_COUNT != shard_bucket {
+            continue;
+        }
+        let ticks_elapsed = if is_urgent { 1 } else { AI_SHARD_COUNT as u32 };
+        
         // Process this animal and catch any errors
         let process_result = (|| -> Result<(), String> {
             let behavior = animal.species.get_behavior();
             let stats = behavior.get_stats();
             
             // Find nearby players for perception checks (uses pre-fetched data)
-            let nearby_players = find_nearby_players_prefetched(&prefetched.all_players, &animal, &stats);
+            let nearby_players = find_nearby_players_prefetched(&prefetched.all_players, &prefetched.meat_scented_players, &animal, &stats);
             
             // Update AI state based on current conditions
             update_animal_ai_state(ctx, &prefetched, &mut animal, &behavior, &stats, &nearby_players, current_time, &mut rng)?;
@@ -1190,7 +1248,7 @@ pub fn process_wild_animal_ai(ctx: &ReducerContext, _schedule: WildAnimalAiSched
                             };
                             
                             match crate::wild_animal_npc::hostile_spawning::hostile_attack_structure(
-                                ctx, struct_id, &struct_type, structure_damage, current_time
+                                ctx, struct_id, &struct_type, structure_damage
                             ) {
                                 Ok(destroyed) => {
                                     animal.last_attack_time = Some(current_time);
@@ -1252,12 +1310,27 @@ pub fn process_wild_animal_ai(ctx: &ReducerContext, _schedule: WildAnimalAiSched
             }
             
             // Execute movement based on current state
-            execute_animal_movement(ctx, &mut animal, &behavior, &stats, current_time, &mut rng)?;
+            let (prev_x, prev_y) = (animal.pos_x, animal.pos_y);
+            execute_animal_movement(ctx, &mut animal, &behavior, &stats, current_time, ticks_elapsed, &mut rng)?;
+            crate::tracks::leave_animal_track(ctx, animal.id, animal.species, prev_x, prev_y, animal.pos_x, animal.pos_y);
             
             // Update the animal in database, BUT only if it wasn't deleted during processing
             // (e.g., bees die from fire in check_and_apply_fire_death and get deleted there)
             // Without this check, the update() would RE-INSERT the deleted animal!
             if ctx.db.wild_animal().id().find(&animal.id).is_some() {
+                let grid_entity = crate::spatial_grid::EntityType::WildAnimal(animal.id);
+                if animal.hide_until.is_some_and(|t| t > current_time) {
+                    crate::spatial_grid::untrack_entity(ctx, grid_entity);
+                } else {
+                    crate::spatial_grid::track_entity(ctx, grid_entity, animal.pos_x, animal.pos_y);
+                }
+                if let Some(stored) = &stored_animal {
+                    if !super::transform_batch::needs_full_sync(stored, &animal) {
+                        processed_transforms.push(super::transform_batch::AnimalLivePosition::new(&animal, stored.pos_x, stored.pos_y));
+                        return Ok(()); // Transform-only change - the batch carries it
+                    }
+                    processed_transforms.push(super::transform_batch::AnimalLivePosition::new(&animal, animal.pos_x, animal.pos_y));
+                }
                 ctx.db.wild_animal().id().update(animal);
             }
             
@@ -1271,6 +1344,10 @@ pub fn process_wild_animal_ai(ctx: &ReducerContext, _schedule: WildAnimalAiSched
         }
     }
 
+    if batching_enabled {
+        super::transform_batch::write_batches(ctx, processed_transforms);
+    }
+
     Ok(())
 }
 
@@ -1598,7 +1675,7 @@ fn update_animal_ai_state(
     
     let detected_player = if should_skip_fire_filtering {
         // These species don't filter players based on fire - use all nearby players
-        find_detected_player(ctx, animal, stats, &nearby_players)
+        find_detected_player(ctx, animal, stats, &nearby_players, &prefetched.meat_scented_players)
     } else {
         // Normal species: filter out players with fire if this animal fears it
         let mut fire_safe_players = Vec::new();
@@ -1621,10 +1698,35 @@ fn update_animal_ai_state(
             }
         }
         
-        find_detected_player(ctx, animal, stats, &fire_safe_players)
+        find_detected_player(ctx, animal, stats, &fire_safe_players, &prefetched.meat_scented_players)
     };
     behavior.update_ai_state_logic(ctx, animal, stats, detected_player.as_ref(), current_time, rng)?;
 
+    // 🔊 NOISE: Hostile NPCs and wolves that haven't spotted anyone go to investigate loud noises
+    // (gunshots, explosions, powered tools, thrown stones - see sound_events::register_noise)
+    let listens_for_noise = animal.is_hostile_npc || animal.species == AnimalSpecies::TundraWolf;
+    if listens_for_noise && detected_player.is_none() && matches!(animal.state, AnimalState::Idle | AnimalState::Patrolling) {
+        if let Some(noise) = crate::sound_events::find_loudest_audible_noise(ctx, animal.pos_x, animal.pos_y) {
+            transition_to_state(animal, AnimalState::Investigating, current_time, None, "heard a loud noise");
+            animal.investigation_x = Some(noise.pos_x);
+            animal.investigation_y = Some(noise.pos_y);
+            log::debug!("🔊 {:?} {} investigating noise at ({:.1}, {:.1})",
+                       animal.species, animal.id, noise.pos_x, noise.pos_y);
+        }
+    }
+
+    // 🥩 SCENT: Land predators that haven't spotted anyone follow the smell of dropped raw meat
+    if super::scent::follows_scent_markers(animal.species) && detected_player.is_none() &&
+       matches!(animal.state, AnimalState::Idle | AnimalState::Patrolling) {
+        if let Some(marker) = super::scent::find_nearest_scent_marker(&prefetched.active_scent_markers, animal.pos_x, animal.pos_y) {
+            transition_to_state(animal, AnimalState::Investigating, current_time, None, "following meat scent");
+            animal.investigation_x = Some(marker.pos_x);
+            animal.investigation_y = Some(marker.pos_y);
+            log::debug!("🥩 {:?} {} following meat scent to ({:.1}, {:.1})",
+                       animal.species, animal.id, marker.pos_x, marker.pos_y);
+        }
+    }
+
     Ok(())
 }
 
@@ -1636,11 +1738,13 @@ fn execute_animal_movement(
     behavior: &AnimalBehaviorEnum,
     stats: &AnimalStats,
     current_time: Timestamp,
+    ticks_elapsed: u32,
     rng: &mut impl Rng,
 ) -> Result<(), String> {
     // CRITICAL: dt must match AI_TICK_INTERVAL_MS (500ms = 0.5 seconds)
     // This was incorrectly set to 0.125 (125ms) causing animals to move at 25% speed
-    let dt = AI_TICK_INTERVAL_MS as f32 / 1000.0; // 500ms = 0.5 seconds
+    // Sharded animals cover every tick since they were last processed
+    let dt = (AI_TICK_INTERVAL_MS * ticks_elapsed as u64) as f32 / 1000.0;
     
     let mut is_sprinting = false;
     
@@ -2062,6 +2166,14 @@ fn execute_attack(
         }
         // <<< END LINE OF SIGHT CHECK >>>
         
+        // <<< ELEVATION CHECK - Ground animals can't reach players on a second floor >>>
+        if !can_reach_player_elevation(animal, &target) {
+            log::info!("Animal {:?} {} attack blocked - Player {:?} is on an upper floor",
+                animal.species, animal.id, target.identity);
+            return Ok(());
+        }
+        // <<< END ELEVATION CHECK >>>
+        
         // <<< SAFE ZONE CHECK - Players in safe zones are immune to animal damage >>>
         if crate::active_effects::player_has_safe_zone_effect(ctx, target.identity) {
             log::info!("Animal {:?} {} attack blocked - Player {:?} is in a safe zone",
@@ -2091,6 +2203,18 @@ fn execute_attack(
         }
         // <<< END SHIPWRECK PROTECTION CHECK >>>
         
+        // <<< PARRY CHECK - A well-timed parry negates the attack and staggers the animal >>>
+        if crate::combat::is_parrying(ctx, target.identity) {
+            log::info!("Animal {:?} {} attack parried by Player {:?}",
+                animal.species, animal.id, target.identity);
+            // Pushing the last attack time into the future delays the next attack (see can_attack)
+            let stagger_micros = (crate::combat_balance::get_config(ctx).animal_parry_stagger_secs * 1_000_000.0) as i64;
+            animal.last_attack_time = Some(current_time + TimeDuration::from_micros(stagger_micros));
+            crate::sound_events::emit_melee_hit_sharp_sound(ctx, target.position_x, target.position_y, target.identity);
+            return Ok(());
+        }
+        // <<< END PARRY CHECK >>>
+        
         // Get species-specific damage and effects
         let raw_damage = behavior.execute_attack_effects(ctx, animal, target_player, stats, current_time, rng)?;
         
@@ -2119,10 +2243,11 @@ fn execute_attack(
         target.health = (target.health - final_damage).max(0.0);
         target.last_hit_time = Some(current_time);
         let actual_damage = old_health - target.health;
+        crate::kill_feed::record_damage_event(ctx, target.identity, None, animal_display_name(animal.species), None, actual_damage, target.health);
         
         // Apply knockback to player if damage was dealt
         if actual_damage > 0.0 && target.is_online {
-            apply_knockback_to_player(animal, &mut target, current_time);
+            apply_knockback_to_player(ctx, animal, &mut target, current_time);
         }
         
         // Save values before moving target
@@ -2165,6 +2290,7 @@ fn find_nearby_players(ctx: &ReducerContext, animal: &WildAnimal, stats: &Animal
         .filter(|player| {
             !player.is_dead && 
             !player.is_snorkeling && // 🤿 Snorkeling players are completely hidden
+            can_reach_player_elevation(animal, player) && // Second-floor players are out of reach
             get_distance_squared(animal.pos_x, animal.pos_y, player.position_x, player.position_y) 
                 <= (stats.perception_range * 1.5).powi(2)
         })
@@ -2172,13 +2298,27 @@ fn find_nearby_players(ctx: &ReducerContext, animal: &WildAnimal, stats: &Animal
 }
 
 /// PERFORMANCE OPTIMIZATION: Uses pre-fetched player data instead of querying database
-fn find_nearby_players_prefetched(all_players: &[Player], animal: &WildAnimal, stats: &AnimalStats) -> Vec<Player> {
+fn find_nearby_players_prefetched(all_players: &[Player], meat_scented_players: &HashSet<Identity>, animal: &WildAnimal, stats: &AnimalStats) -> Vec<Player> {
+    // 🦈 Sharks can scent bleeding swimmers from further away - the bleed check itself happens in find_detected_player
+    let range_multiplier = if animal.species == AnimalSpecies::SalmonShark {
+        super::salmon_shark::SHARK_BLOOD_SCENT_PERCEPTION_MULTIPLIER.max(1.5)
+    } else {
+        1.5
+    };
+    // 🥩 Predators smell raw meat carriers from further away
+    let smells_meat = super::scent::is_scent_predator(animal.species);
     all_players
         .iter()
         .filter(|player| {
+            let player_range_multiplier = if smells_meat && meat_scented_players.contains(&player.identity) {
+                range_multiplier.max(super::scent::MEAT_SCENT_PERCEPTION_MULTIPLIER)
+            } else {
+                range_multiplier
+            };
             !player.is_snorkeling && // 🤿 Snorkeling players are completely hidden
+            can_reach_player_elevation(animal, player) && // Second-floor players are out of reach
             get_distance_squared(animal.pos_x, animal.pos_y, player.position_x, player.position_y) 
-                <= (stats.perception_range * 1.5).powi(2)
+                <= (stats.perception_range * player_range_multiplier).powi(2)
         })
         .cloned()
         .collect()
@@ -2252,7 +2392,7 @@ fn should_animal_wander(all_players: &[Player], animal: &WildAnimal) -> bool {
     get_closest_player_distance_squared(all_players, animal) <= WANDER_ACTIVATION_DISTANCE_SQUARED
 }
 
-fn find_detected_player(ctx: &ReducerContext, animal: &WildAnimal, stats: &AnimalStats, nearby_players: &[Player]) -> Option<Player> {
+fn find_detected_player(ctx: &ReducerContext, animal: &WildAnimal, stats: &AnimalStats, nearby_players: &[Player], meat_scented_players: &HashSet<Identity>) -> Option<Player> {
     // Check if this is a hostile NPC that should respect shipwreck protection zones
     let is_hostile_npc = matches!(animal.species, 
         AnimalSpecies::Shorebound | AnimalSpecies::Shardkin | AnimalSpecies::DrownedWatch);
@@ -2311,6 +2451,15 @@ fn find_detected_player(ctx: &ReducerContext, animal: &WildAnimal, stats: &Anima
             player.position_x, player.position_y
         );
         
+        // 🌿 HUNTING BLIND: The blind's walls block sight - hostiles only spot players inside from up close
+        let in_hunting_blind = crate::hunting_blind::is_position_in_hunting_blind(ctx, player.position_x, player.position_y);
+        if in_hunting_blind && is_hostile_npc &&
+           distance_sq > crate::hunting_blind::HUNTING_BLIND_HOSTILE_REVEAL_DISTANCE * crate::hunting_blind::HUNTING_BLIND_HOSTILE_REVEAL_DISTANCE {
+            log::debug!("🌿 {:?} {} cannot see player {} - hidden in a hunting blind",
+                       animal.species, animal.id, player.identity);
+            continue;
+        }
+        
         // 🥷 STEALTH MECHANIC: Crouching reduces animal detection radius by 50%
         let mut effective_perception_range = if player.is_crouching {
             stats.perception_range * 0.5 // 50% reduction when crouching
@@ -2345,9 +2494,40 @@ fn find_detected_player(ctx: &ReducerContext, animal: &WildAnimal, stats: &Anima
                        player.identity, effective_perception_range);
         }
         
+        // 💨 SMOKE: Smoke clouds block sight - anything seen into, out of or through smoke is much harder to spot
+        if crate::throwables::is_sight_obscured_by_smoke(ctx, animal.pos_x, animal.pos_y, player.position_x, player.position_y) {
+            effective_perception_range *= crate::throwables::SMOKE_PERCEPTION_MULTIPLIER;
+            log::debug!("💨 {:?} {} sight of player {} obscured by smoke, perception now {:.1}px",
+                       animal.species, animal.id, player.identity, effective_perception_range);
+        }
+        
+        // 🌿 HUNTING BLIND: Animals barely notice a player waiting inside a blind
+        if in_hunting_blind {
+            effective_perception_range *= crate::hunting_blind::HUNTING_BLIND_PERCEPTION_MULTIPLIER;
+            log::debug!("🌿 Player {} is in a hunting blind, reducing {:?} perception to {:.1}px",
+                       player.identity, animal.species, effective_perception_range);
+        }
+        
+        // 🦈🩸 BLOOD IN THE WATER: Sharks sense bleeding players in water at triple range, from any direction
+        // Blood scent ignores stealth reductions - crouching or fox fur won't mask it
+        let smells_blood = animal.species == AnimalSpecies::SalmonShark &&
+            super::salmon_shark::is_blood_in_water(ctx, player);
+        if smells_blood {
+            effective_perception_range = effective_perception_range
+                .max(stats.perception_range * super::salmon_shark::SHARK_BLOOD_SCENT_PERCEPTION_MULTIPLIER);
+        }
+
+        // 🥩 RAW MEAT SCENT: Predators smell players carrying raw meat or fish from further away, from any direction
+        let smells_meat = super::scent::is_scent_predator(animal.species) &&
+            meat_scented_players.contains(&player.identity);
+        if smells_meat {
+            effective_perception_range = effective_perception_range
+                .max(stats.perception_range * super::scent::MEAT_SCENT_PERCEPTION_MULTIPLIER);
+        }
+        
         if distance_sq <= effective_perception_range * effective_perception_range {
             // Check if within perception cone (except for Cable Viper which has 360° detection)
-            if animal.species == AnimalSpecies::CableViper || 
+            if animal.species == AnimalSpecies::CableViper || smells_blood || smells_meat ||
                is_within_perception_cone(animal, player, stats) {
                 
                 // Log stealth detection for debugging
@@ -2412,6 +2592,8 @@ fn can_animal_traverse_water(animal: &WildAnimal) -> bool {
 }
 
 pub fn move_towards_target(ctx: &ReducerContext, animal: &mut WildAnimal, target_x: f32, target_y: f32, speed: f32, dt: f32) {
+    // Route around shorelines and structures instead of walking straight into them
+    let (target_x, target_y) = super::pathfinding::next_waypoint(ctx, animal, target_x, target_y);
     let dx = target_x - animal.pos_x;
     let dy = target_y - animal.pos_y;
     let distance = (dx * dx + dy * dy).sqrt();
@@ -2720,37 +2902,18 @@ fn enforce_minimum_player_distance(animal: &mut WildAnimal, player: &Player, sta
     }
 }
 
-fn apply_knockback_to_player(animal: &WildAnimal, target: &mut Player, current_time: Timestamp) {
+/// Duration of a knockback, used to turn its distance into an impact speed
+/// (only the heaviest hitters - walrus, Drowned Watch, polar bear - reach the impact threshold)
+const KNOCKBACK_IMPACT_SECS: f32 = 0.15;
+
+fn apply_knockback_to_player(ctx: &ReducerContext, animal: &WildAnimal, target: &mut Player, current_time: Timestamp) {
     let dx_target_from_animal = target.position_x - animal.pos_x;
     let dy_target_from_animal = target.position_y - animal.pos_y;
     let distance_sq = dx_target_from_animal * dx_target_from_animal + dy_target_from_animal * dy_target_from_animal;
     
     if distance_sq > 0.001 {
         let distance = distance_sq.sqrt();
-        let knockback_distance = match animal.species {
-            AnimalSpecies::TundraWolf => 48.0,
-            AnimalSpecies::CinderFox => 32.0,
-            AnimalSpecies::CableViper => 24.0,
-            AnimalSpecies::ArcticWalrus => 64.0, // Strongest knockback - massive walrus attack
-            AnimalSpecies::BeachCrab => 16.0, // Small knockback - crab pinch
-            AnimalSpecies::Tern => 8.0, // Very small knockback - bird peck
-            AnimalSpecies::Crow => 12.0, // Small knockback - crow peck
-            AnimalSpecies::Vole => 4.0, // Tiny knockback - tiny rodent bite
-            AnimalSpecies::Wolverine => 56.0, // Strong knockback - aggressive predator
-            AnimalSpecies::Caribou => 48.0, // Strong knockback - large herbivore charge
-            AnimalSpecies::SalmonShark => 40.0, // Moderate knockback - shark bite
-            AnimalSpecies::Jellyfish => 16.0,  // Light knockback - electric shock pushback
-            // Hostile NPCs
-            AnimalSpecies::Shorebound => 36.0, // Fast stalker - moderate knockback
-            AnimalSpecies::Shardkin => 20.0, // Small swarmer - light knockback
-            AnimalSpecies::DrownedWatch => 72.0, // Heavy brute - strong knockback
-            // Bees - tiny knockback
-            AnimalSpecies::Bee => 8.0, // Tiny insect - minimal knockback
-            // Alpine animals
-            AnimalSpecies::PolarBear => 72.0, // Massive apex predator - strongest knockback
-            AnimalSpecies::Hare => 4.0,       // Tiny prey - minimal knockback
-            AnimalSpecies::SnowyOwl => 12.0,  // Medium bird - light knockback
-        };
+        let knockback_distance = crate::combat_balance::animal_knockback_to_player(ctx, animal.species);
         
         let knockback_dx = (dx_target_from_animal / distance) * knockback_distance;
         let knockback_dy = (dy_target_from_animal / distance) * knockback_distance;
@@ -2761,8 +2924,18 @@ fn apply_knockback_to_player(animal: &WildAnimal, target: &mut Player, current_t
         let final_x = proposed_x.clamp(32.0, WORLD_WIDTH_PX - 32.0);
         let final_y = proposed_y.clamp(32.0, WORLD_HEIGHT_PX - 32.0);
         
+        // Being thrown into a cliff stops the player at its edge; heavy hitters make it hurt
+        let (final_x, final_y) = match crate::player_movement::find_cliff_stop_point(ctx, target.position_x, target.position_y, final_x, final_y) {
+            Some(stop_point) => {
+                crate::player_movement::apply_impact(ctx, target, knockback_distance / KNOCKBACK_IMPACT_SECS);
+                stop_point
+            }
+            None => (final_x, final_y),
+        };
+        
         target.position_x = final_x;
         target.position_y = final_y;
+        crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(target.identity), target.position_x, target.position_y);
         target.last_update = current_time;
         
         log::debug!("Applied knockback to player {} from {} (species: {:?}): distance={:.1}px", 
@@ -2773,6 +2946,7 @@ fn apply_knockback_to_player(animal: &WildAnimal, target: &mut Player, current_t
 fn handle_player_death(ctx: &ReducerContext, target: &mut Player, animal: &WildAnimal, current_time: Timestamp) -> Result<(), String> {
     target.is_dead = true;
     target.death_timestamp = Some(current_time);
+    crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::Player(target.identity));
     log::info!("Player {} killed by {} (species: {:?})", 
               target.identity, animal.id, animal.species);
     
@@ -2793,30 +2967,7 @@ fn handle_player_death(ctx: &ReducerContext, target: &mut Player, animal: &WildA
     log::info!("[PlayerDeath] Cleared all active effects for player {:?} killed by wild animal", target.identity);
     
     // Create death marker for wild animal kill
-    let death_cause = match animal.species {
-        AnimalSpecies::CinderFox => "Cinder Fox",
-        AnimalSpecies::TundraWolf => "Tundra Wolf", 
-        AnimalSpecies::CableViper => "Cable Viper",
-        AnimalSpecies::ArcticWalrus => "Arctic Walrus",
-        AnimalSpecies::BeachCrab => "Beach Crab",
-        AnimalSpecies::Tern => "Tern",
-        AnimalSpecies::Crow => "Crow",
-        AnimalSpecies::Vole => "Vole",
-        AnimalSpecies::Wolverine => "Wolverine",
-        AnimalSpecies::Caribou => "Caribou",
-        AnimalSpecies::SalmonShark => "Salmon Shark",
-        AnimalSpecies::Jellyfish => "Jellyfish",
-        // Hostile NPCs
-        AnimalSpecies::Shorebound => "The Shorebound",
-        AnimalSpecies::Shardkin => "The Shardkin",
-        AnimalSpecies::DrownedWatch => "The Drowned Watch",
-        // Bees
-        AnimalSpecies::Bee => "A Bee",
-        // Alpine animals
-        AnimalSpecies::PolarBear => "Polar Bear",
-        AnimalSpecies::Hare => "Hare",
-        AnimalSpecies::SnowyOwl => "Snowy Owl",
-    };
+    let death_cause = animal_display_name(animal.species);
     
     let new_death_marker = crate::death_marker::DeathMarker {
         player_id: target.identity,
@@ -2841,9 +2992,39 @@ fn handle_player_death(ctx: &ReducerContext, target: &mut Player, animal: &WildA
         log::error!("Failed to create corpse for player {:?} killed by wild animal: {}", target.identity, e);
     }
     
+    crate::kill_feed::record_player_death(ctx, target, None, death_cause, death_cause, None, Some((animal.pos_x, animal.pos_y)));
+    
     Ok(())
 }
 
+/// Display name used for death markers, the kill feed, and death recaps
+pub(crate) fn animal_display_name(species: AnimalSpecies) -> &'static str {
+    match species {
+        AnimalSpecies::CinderFox => "Cinder Fox",
+        AnimalSpecies::TundraWolf => "Tundra Wolf", 
+        AnimalSpecies::CableViper => "Cable Viper",
+        AnimalSpecies::ArcticWalrus => "Arctic Walrus",
+        AnimalSpecies::BeachCrab => "Beach Crab",
+        AnimalSpecies::Tern => "Tern",
+        AnimalSpecies::Crow => "Crow",
+        AnimalSpecies::Vole => "Vole",
+        AnimalSpecies::Wolverine => "Wolverine",
+        AnimalSpecies::Caribou => "Caribou",
+        AnimalSpecies::SalmonShark => "Salmon Shark",
+        AnimalSpecies::Jellyfish => "Jellyfish",
+        // Hostile NPCs
+        AnimalSpecies::Shorebound => "The Shorebound",
+        AnimalSpecies::Shardkin => "The Shardkin",
+        AnimalSpecies::DrownedWatch => "The Drowned Watch",
+        // Bees
+        AnimalSpecies::Bee => "A Bee",
+        // Alpine animals
+        AnimalSpecies::PolarBear => "Polar Bear",
+        AnimalSpecies::Hare => "Hare",
+        AnimalSpecies::SnowyOwl => "Snowy Owl",
+    }
+}
+
 // --- Spawning Functions ---
 
 #[spacetimedb::reducer]
@@ -2932,7 +3113,8 @@ pub fn spawn_wild_animal(
         last_shock_time: None,
     };
     
-    ctx.db.wild_animal().insert(animal);
+    let inserted = ctx.db.wild_animal().insert(animal);
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
     
     if is_bird {
         log::info!("🐦 Spawned {:?} at ({:.0}, {:.0}) - GROUNDED (is_flying=false, walking sprite)", species, pos_x, pos_y);
@@ -3050,7 +3232,8 @@ pub fn damage_wild_animal_with_weapon(
 ) -> Result<(), String> {
     let mut rng = ctx.rng();
 
-    if let Some(mut animal) = ctx.db.wild_animal().id().find(&animal_id) {
+    if let Some(mut animal) = ctx.db.wild_animal().id().find(&animal_id)
+        .map(|row| super::transform_batch::with_live_position(ctx, row)) {
         // BURROWED PROTECTION: Animals that are burrowed underground cannot be attacked
         if animal.state == AnimalState::Burrowed {
             log::debug!("Cannot attack burrowed {:?} {} - it's underground!", animal.species, animal.id);
@@ -3078,7 +3261,7 @@ pub fn damage_wild_animal_with_weapon(
         
         // Apply knockback effects
         if actual_damage > 0.0 {
-            apply_damage_knockback_effects(ctx, &animal, attacker_id)?;
+            apply_damage_knockback_effects(ctx, &mut animal, attacker_id)?;
             
             // Play weapon hit sound when player hits animal
             if let Some(attacker) = ctx.db.player().identity().find(&attacker_id) {
@@ -3118,33 +3301,24 @@ pub fn damage_wild_animal_with_weapon(
                 log::info!("👹 [HOSTILE DEATH] {:?} {} killed at ({:.1}, {:.1}) - dropping memory shards", 
                           animal.species, animal.id, animal.pos_x, animal.pos_y);
                 
-                // Tiered shard rewards based on enemy difficulty
-                // Night combat should be MUCH more rewarding than safe barrel farming
-                let shard_count = match animal.species {
-                    AnimalSpecies::Shardkin => rng.gen_range(8..=15),      // Swarmers - dangerous in groups
-                    AnimalSpecies::Shorebound => rng.gen_range(15..=25),   // Stalkers - worth the risk
-                    AnimalSpecies::DrownedWatch => rng.gen_range(50..=80), // Brutes - boss-tier reward
-                    _ => rng.gen_range(10..=20), // Fallback
-                } as u32;
-                
+                // Tiered shard rewards based on enemy difficulty (tunable via the loot_table subsystem)
                 // Drop memory shards at the hostile NPC's death location for player to pick up
                 // This adds to the dopamine loop of seeing loot drop and collecting it
-                if let Some(shard_def) = ctx.db.item_definition().iter()
-                    .find(|def| def.name == "Memory Shard") 
-                {
+                let source_key = super::hostile_spawning::hostile_npc_loot_source_key(animal.species);
+                for drop in crate::loot_table::roll_loot(ctx, &source_key, 0) {
                     match crate::dropped_item::create_dropped_item_entity(
                         ctx, 
-                        shard_def.id, 
-                        shard_count,
+                        drop.item_def_id, 
+                        drop.quantity,
                         animal.pos_x,
                         animal.pos_y,
                     ) {
                         Ok(_) => {
-                            log::info!("👹 Dropped {} memory shards at ({:.1}, {:.1}) from {:?} kill", 
-                                      shard_count, animal.pos_x, animal.pos_y, animal.species);
+                            log::info!("👹 Dropped {} {} at ({:.1}, {:.1}) from {:?} kill", 
+                                      drop.quantity, drop.item_name, animal.pos_x, animal.pos_y, animal.species);
                         }
                         Err(e) => {
-                            log::error!("👹 Failed to drop memory shards: {}", e);
+                            log::error!("👹 Failed to drop {}: {}", drop.item_name, e);
                         }
                     }
                 }
@@ -3158,6 +3332,7 @@ pub fn damage_wild_animal_with_weapon(
                 );
                 
                 ctx.db.wild_animal().id().delete(&animal_id);
+                crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(animal_id));
                 log::info!("👹 Hostile NPC {:?} {} removed after death", animal.species, animal_id);
                 
                 // Award XP for hostile NPC kill (more XP than regular animals)
@@ -3197,6 +3372,7 @@ pub fn damage_wild_animal_with_weapon(
                 // combat.rs damage_animal_corpse when the corpse is depleted or despawns.
                 
                 ctx.db.wild_animal().id().delete(&animal_id);
+                crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(animal_id));
                 log::info!("Wild animal {} killed by player {} - corpse created", animal_id, attacker_id);
                 
                 // Award XP for regular animal kill
@@ -3636,9 +3812,11 @@ pub fn damage_wild_animal_by_animal(
 ) -> Result<bool, String> {
     // Verify both animals exist
     let attacker_animal = ctx.db.wild_animal().id().find(&attacker_animal_id)
+        .map(|row| super::transform_batch::with_live_position(ctx, row))
         .ok_or_else(|| format!("Attacker animal {} not found", attacker_animal_id))?;
     
     let mut target_animal = ctx.db.wild_animal().id().find(&target_animal_id)
+        .map(|row| super::transform_batch::with_live_position(ctx, row))
         .ok_or_else(|| format!("Target animal {} not found", target_animal_id))?;
     
     // BURROWED PROTECTION: Animals that are burrowed underground cannot be attacked
@@ -3660,7 +3838,7 @@ pub fn damage_wild_animal_by_animal(
     
     if actual_damage > 0.0 {
         // Apply knockback effects between animals
-        apply_animal_knockback_effects(ctx, &target_animal, &attacker_animal)?;
+        apply_animal_knockback_effects(ctx, &mut target_animal, &attacker_animal)?;
         
         // Play attack sound
         emit_species_sound(ctx, &attacker_animal, attacker_animal.tamed_by.unwrap_or(ctx.identity()), "attack");
@@ -3691,6 +3869,7 @@ pub fn damage_wild_animal_by_animal(
         }
         
         ctx.db.wild_animal().id().delete(&target_animal_id);
+        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(target_animal_id));
     } else {
         // If target survives, handle damage response
         let target_behavior = target_animal.species.get_behavior();
@@ -3711,10 +3890,10 @@ pub fn damage_wild_animal_by_animal(
     Ok(animal_died)
 }
 
-/// Apply knockback effects between two animals
+/// Apply knockback effects between two animals (the target is pushed in place and in its row)
 fn apply_animal_knockback_effects(
     ctx: &ReducerContext,
-    target_animal: &WildAnimal,
+    target_animal: &mut WildAnimal,
     attacker_animal: &WildAnimal,
 ) -> Result<(), String> {
     // Calculate direction from attacker to target
@@ -3726,29 +3905,31 @@ fn apply_animal_knockback_effects(
         let distance = distance_sq.sqrt();
         
         // Apply smaller knockback for animal vs animal combat
-        const ANIMAL_KNOCKBACK_DISTANCE: f32 = 12.0; // Smaller than player knockback
+        let animal_knockback_distance = crate::combat_balance::get_config(ctx).animal_vs_animal_knockback_distance;
         
-        let knockback_dx = (dx_target_from_attacker / distance) * ANIMAL_KNOCKBACK_DISTANCE;
-        let knockback_dy = (dy_target_from_attacker / distance) * ANIMAL_KNOCKBACK_DISTANCE;
+        let knockback_dx = (dx_target_from_attacker / distance) * animal_knockback_distance;
+        let knockback_dy = (dy_target_from_attacker / distance) * animal_knockback_distance;
         
         // Update target animal position (with basic bounds checking)
-        let mut updated_target = target_animal.clone();
-        let new_x = (updated_target.pos_x + knockback_dx).clamp(32.0, WORLD_WIDTH_PX - 32.0);
-        let new_y = (updated_target.pos_y + knockback_dy).clamp(32.0, WORLD_HEIGHT_PX - 32.0);
+        let new_x = (target_animal.pos_x + knockback_dx).clamp(32.0, WORLD_WIDTH_PX - 32.0);
+        let new_y = (target_animal.pos_y + knockback_dy).clamp(32.0, WORLD_HEIGHT_PX - 32.0);
         
         // Use centralized position update function
-        update_animal_position(&mut updated_target, new_x, new_y);
+        update_animal_position(target_animal, new_x, new_y);
         
-        ctx.db.wild_animal().id().update(updated_target);
+        ctx.db.wild_animal().id().update(target_animal.clone());
+        super::transform_batch::reseed_batched_transform(ctx, target_animal);
+        crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(target_animal.id), new_x, new_y);
         
         log::debug!("Applied animal knockback: {} -> {} distance={:.1}px", 
-                   attacker_animal.id, target_animal.id, ANIMAL_KNOCKBACK_DISTANCE);
+                   attacker_animal.id, target_animal.id, animal_knockback_distance);
     }
     
     Ok(())
 }
 
-fn apply_damage_knockback_effects(ctx: &ReducerContext, animal: &WildAnimal, attacker_id: Identity) -> Result<(), String> {
+/// Pushes the animal away from the attacking player (in place and in its row) and recoils the attacker
+fn apply_damage_knockback_effects(ctx: &ReducerContext, animal: &mut WildAnimal, attacker_id: Identity) -> Result<(), String> {
     if let Some(mut attacker) = ctx.db.player().identity().find(&attacker_id) {
         if attacker.is_online {
             let dx_animal_from_attacker = animal.pos_x - attacker.position_x;
@@ -3759,33 +3940,9 @@ fn apply_damage_knockback_effects(ctx: &ReducerContext, animal: &WildAnimal, att
                 let distance = distance_sq.sqrt();
                 
                 // --- KNOCKBACK THE ANIMAL AWAY FROM PLAYER ---
-                // Knockback distance varies by species mass/size:
+                // Knockback distance varies by species mass/size (tuned in combat_balance):
                 // Lighter animals get knocked back more, massive ones are immune
-                let animal_knockback_distance = match animal.species {
-                    // Tiny - sent flying
-                    AnimalSpecies::Vole => 28.0,
-                    AnimalSpecies::Hare => 28.0,
-                    AnimalSpecies::Bee => 20.0,
-                    // Small - solid knockback
-                    AnimalSpecies::CinderFox => 22.0,
-                    AnimalSpecies::BeachCrab => 18.0,
-                    AnimalSpecies::Tern => 20.0,
-                    AnimalSpecies::Crow => 20.0,
-                    AnimalSpecies::SnowyOwl => 20.0,
-                    AnimalSpecies::CableViper => 18.0,
-                    AnimalSpecies::Shardkin => 22.0,
-                    AnimalSpecies::Jellyfish => 16.0,
-                    // Medium - moderate knockback
-                    AnimalSpecies::TundraWolf => 16.0,
-                    AnimalSpecies::Wolverine => 14.0,
-                    AnimalSpecies::Shorebound => 16.0,
-                    AnimalSpecies::Caribou => 12.0,
-                    // MASSIVE - immune to knockback (too heavy to push)
-                    AnimalSpecies::ArcticWalrus => 0.0,
-                    AnimalSpecies::PolarBear => 0.0,
-                    AnimalSpecies::DrownedWatch => 0.0,
-                    AnimalSpecies::SalmonShark => 0.0,
-                };
+                let animal_knockback_distance = crate::combat_balance::animal_knockback_from_player(ctx, animal.species);
                 
                 // Push animal away from player (skip if immune to knockback)
                 if animal_knockback_distance > 0.0 {
@@ -3793,11 +3950,15 @@ fn apply_damage_knockback_effects(ctx: &ReducerContext, animal: &WildAnimal, att
                         let kb_dx = (dx_animal_from_attacker / distance) * animal_knockback_distance;
                         let kb_dy = (dy_animal_from_attacker / distance) * animal_knockback_distance;
                         
-                        let new_x = (updated_animal.pos_x + kb_dx).clamp(32.0, WORLD_WIDTH_PX - 32.0);
-                        let new_y = (updated_animal.pos_y + kb_dy).clamp(32.0, WORLD_HEIGHT_PX - 32.0);
+                        let new_x = (animal.pos_x + kb_dx).clamp(32.0, WORLD_WIDTH_PX - 32.0);
+                        let new_y = (animal.pos_y + kb_dy).clamp(32.0, WORLD_HEIGHT_PX - 32.0);
                         
+                        // Keep the caller's copy in step so its own row write doesn't undo the knockback
+                        update_animal_position(animal, new_x, new_y);
                         update_animal_position(&mut updated_animal, new_x, new_y);
-                        ctx.db.wild_animal().id().update(updated_animal);
+                        ctx.db.wild_animal().id().update(updated_animal.clone());
+                        super::transform_batch::reseed_batched_transform(ctx, &updated_animal);
+                        crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(animal.id), new_x, new_y);
                         
                         log::debug!("Applied knockback to animal {} ({:?}) from player {}: distance={:.1}px", 
                                    animal.id, animal.species, attacker_id, animal_knockback_distance);
@@ -3807,7 +3968,7 @@ fn apply_damage_knockback_effects(ctx: &ReducerContext, animal: &WildAnimal, att
                 // --- SMALL PLAYER RECOIL FOR MELEE HITS ---
                 // Skip recoil when snorkeling - 8px push can move player from water to land, forcing unwanted surface emergence
                 if distance <= 100.0 && !attacker.is_snorkeling {
-                    let attacker_recoil_distance = 8.0; // Small recoil - focus is on pushing the animal
+                    let attacker_recoil_distance = crate::combat_balance::get_config(ctx).animal_hit_recoil_distance; // Small recoil - focus is on pushing the animal
                     let attacker_recoil_dx = (-dx_animal_from_attacker / distance) * attacker_recoil_distance;
                     let attacker_recoil_dy = (-dy_animal_from_attacker / distance) * attacker_recoil_distance;
                     
@@ -3816,6 +3977,7 @@ fn apply_damage_knockback_effects(ctx: &ReducerContext, animal: &WildAnimal, att
                     
                     attacker.position_x = proposed_attacker_x.clamp(32.0, WORLD_WIDTH_PX - 32.0);
                     attacker.position_y = proposed_attacker_y.clamp(32.0, WORLD_HEIGHT_PX - 32.0);
+                    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(attacker.identity), attacker.position_x, attacker.position_y);
                     attacker.last_update = ctx.timestamp;
                     
                     ctx.db.player().identity().update(attacker);
@@ -4243,7 +4405,7 @@ pub fn get_flashlight_hesitation_multiplier(ctx: &ReducerContext, animal: &WildA
 fn count_nearby_group_members(ctx: &ReducerContext, animal: &WildAnimal) -> usize {
     let mut count = 1; // Count self
     
-    for other_animal in ctx.db.wild_animal().iter() {
+    for other_animal in ctx.db.wild_animal().iter().map(|row| super::transform_batch::with_live_position(ctx, row)) {
         if other_animal.id == animal.id || other_animal.species != animal.species {
             continue;
         }
@@ -4595,7 +4757,7 @@ fn find_nearby_packable_wolf(
     animal: &WildAnimal,
     pack_size_map: &HashMap<u64, usize>,
 ) -> Option<WildAnimal> {
-    for other_animal in ctx.db.wild_animal().iter() {
+    for other_animal in ctx.db.wild_animal().iter().map(|row| super::transform_batch::with_live_position(ctx, row)) {
         if other_animal.id == animal.id || other_animal.species != AnimalSpecies::TundraWolf {
             continue;
         }
@@ -5664,6 +5826,14 @@ pub fn is_flying_species(species: &AnimalSpecies) -> bool {
     matches!(species, AnimalSpecies::Tern | AnimalSpecies::Crow | AnimalSpecies::SnowyOwl)
 }
 
+/// Whether an animal can reach a player on their current building level.
+/// Ground animals can't climb to a second floor; birds and bees fly up to it.
+pub fn can_reach_player_elevation(animal: &WildAnimal, player: &Player) -> bool {
+    player.elevation == crate::building_floor::ELEVATION_GROUND
+        || is_flying_species(&animal.species)
+        || animal.species == AnimalSpecies::Bee
+}
+
 /// Update the facing direction string based on direction vector
 pub fn update_facing_direction(animal: &mut WildAnimal) {
     if animal.direction_x.abs() > animal.direction_y.abs() {
@@ -6106,7 +6276,7 @@ pub fn detect_threats_to_owner(
     }
     
     // Check wild animal threats
-    for animal in ctx.db.wild_animal().iter() {
+    for animal in ctx.db.wild_animal().iter().map(|row| super::transform_batch::with_live_position(ctx, row)) {
         if animal.id == protecting_animal.id { 
             continue; // Skip self
         }
@@ -6149,6 +6319,7 @@ pub fn find_closest_threat(
             },
             ThreatType::WildAnimal(id) => {
                 ctx.db.wild_animal().id().find(id)
+                    .map(|row| super::transform_batch::with_live_position(ctx, row))
                     .map(|w| ((protecting_animal.pos_x - w.pos_x).powi(2) + 
                               (protecting_animal.pos_y - w.pos_y).powi(2)).sqrt())
                     .unwrap_or(f32::MAX)
@@ -6162,6 +6333,7 @@ pub fn find_closest_threat(
             },
             ThreatType::WildAnimal(id) => {
                 ctx.db.wild_animal().id().find(id)
+                    .map(|row| super::transform_batch::with_live_position(ctx, row))
                     .map(|w| ((protecting_animal.pos_x - w.pos_x).powi(2) + 
                               (protecting_animal.pos_y - w.pos_y).powi(2)).sqrt())
                     .unwrap_or(f32::MAX)
@@ -6302,6 +6474,7 @@ pub fn handle_animal_eat_food(
         
         // Remove the dropped item from the world
         ctx.db.dropped_item().id().delete(dropped_item.id);
+        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(dropped_item.id));
         
         // Emit eating sound
         emit_species_sound(ctx, animal, tamer.identity, "eating");
@@ -6322,6 +6495,7 @@ pub fn handle_animal_eat_food(
     } else {
         // No suitable player found - just eat the food without taming
         ctx.db.dropped_item().id().delete(dropped_item.id);
+        crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(dropped_item.id));
         log::info!("{:?} {} ate food but no player nearby to become tamed", animal.species, animal.id);
         Ok(false) // Ate food but not tamed
     }
@@ -6379,7 +6553,7 @@ pub fn process_taming_behavior(
 }
 
 /// Handle following behavior for tamed animals
-/// TAMING IS PERMANENT - animals never become wild again once tamed
+/// Taming survives owner absence - animals only go wild again if they starve (see feeding_trough.rs)
 /// Behavior:
 /// - If owner is nearby (within TAMING_STAY_DISTANCE), follow them
 /// - If owner is far away (beyond TAMING_STAY_DISTANCE), stay in place (allows penning)
@@ -6512,7 +6686,7 @@ pub fn handle_tamed_protecting(
     let mut player_threats = Vec::new();
     
     // Check for wild animals that might be threats (untamed or tamed by others)
-    for other_animal in ctx.db.wild_animal().iter() {
+    for other_animal in ctx.db.wild_animal().iter().map(|row| super::transform_batch::with_live_position(ctx, row)) {
         if other_animal.id == animal.id {
             continue;
         }
@@ -6876,205 +7050,3 @@ pub fn handle_water_unstuck(
     }
     false // No water collision detected
 }
-
-// =============================================================================
-// MILKING SYSTEM - Milk tamed female caribou and walruses once per game day
-// =============================================================================
-
-/// Milk a tamed animal (caribou or walrus)
-/// Requirements:
-/// - Animal must be tamed by the caller
-/// - Animal must be female
-/// - Animal must be adult age
-/// - Animal must not have been milked today (resets at dawn each day)
-/// - Player must be close enough to the animal
-#[spacetimedb::reducer]
-pub fn milk_animal(ctx: &ReducerContext, animal_id: u64) -> Result<(), String> {
-    // Get the player
-    let player = ctx.db.player().identity().find(&ctx.sender())
-        .ok_or_else(|| "Player not found".to_string())?;
-    
-    if player.is_dead {
-        return Err("Cannot milk while dead".to_string());
-    }
-    
-    // Get the animal
-    let mut animal = ctx.db.wild_animal().id().find(animal_id)
-        .ok_or_else(|| "Animal not found".to_string())?;
-    
-    // Check if animal is tamed by this player
-    let tamed_by = animal.tamed_by.ok_or_else(|| "Animal is not tamed".to_string())?;
-    if tamed_by != ctx.sender() {
-        return Err("You don't own this animal".to_string());
-    }
-    
-    // Check proximity (must be within 100 pixels)
-    let distance_sq = get_distance_squared(player.position_x, player.position_y, animal.pos_x, animal.pos_y);
-    if distance_sq > 10000.0 { // 100^2
-        return Err("Too far from animal to milk".to_string());
-    }
-    
-    // Get current game day from world state
-    let world_state = ctx.db.world_state().iter().next()
-        .ok_or_else(|| "World state not found".to_string())?;
-    let current_day = world_state.cycle_count;
-    
-    // Handle milking based on species
-    match animal.species {
-        AnimalSpecies::Caribou => {
-            milk_caribou(ctx, &mut animal, current_day, ctx.sender())?;
-        }
-        AnimalSpecies::ArcticWalrus => {
-            milk_walrus(ctx, &mut animal, current_day, ctx.sender())?;
-        }
-        _ => {
-            return Err(format!("{:?} cannot be milked", animal.species));
-        }
-    }
-    
-    // Update the animal (in case any state changed)
-    ctx.db.wild_animal().id().update(animal);
-    
-    Ok(())
-}
-
-/// Internal function to milk a caribou
-fn milk_caribou(ctx: &ReducerContext, animal: &mut WildAnimal, current_day: u32, player_id: Identity) -> Result<(), String> {
-    use crate::wild_animal_npc::caribou::{CaribouSex, CaribouAgeStage};
-    
-    // Get breeding data
-    let mut breeding_data = ctx.db.caribou_breeding_data().animal_id().find(animal.id)
-        .ok_or_else(|| "Caribou breeding data not found".to_string())?;
-    
-    // Check if female
-    if breeding_data.sex != CaribouSex::Female {
-        return Err("Only female caribou can be milked".to_string());
-    }
-    
-    // Check if adult
-    if breeding_data.age_stage != CaribouAgeStage::Adult {
-        return Err("Only adult caribou can be milked".to_string());
-    }
-    
-    // Check if already milked today
-    if let Some(last_milked) = breeding_data.last_milked_day {
-        if last_milked >= current_day {
-            return Err("This caribou has already been milked today".to_string());
-        }
-    }
-    
-    // Update last milked day
-    breeding_data.last_milked_day = Some(current_day);
-    ctx.db.caribou_breeding_data().animal_id().update(breeding_data);
-    
-    // Give player milk
-    give_milk_to_player(ctx, player_id)?;
-    
-    log::info!("🥛 Player {} milked caribou {} (day {})", player_id, animal.id, current_day);
-    
-    Ok(())
-}
-
-/// Internal function to milk a walrus
-fn milk_walrus(ctx: &ReducerContext, animal: &mut WildAnimal, current_day: u32, player_id: Identity) -> Result<(), String> {
-    use crate::wild_animal_npc::walrus::{WalrusSex, WalrusAgeStage};
-    
-    // Get breeding data
-    let mut breeding_data = ctx.db.walrus_breeding_data().animal_id().find(animal.id)
-        .ok_or_else(|| "Walrus breeding data not found".to_string())?;
-    
-    // Check if female
-    if breeding_data.sex != WalrusSex::Female {
-        return Err("Only female walruses can be milked".to_string());
-    }
-    
-    // Check if adult
-    if breeding_data.age_stage != WalrusAgeStage::Adult {
-        return Err("Only adult walruses can be milked".to_string());
-    }
-    
-    // Check if already milked today
-    if let Some(last_milked) = breeding_data.last_milked_day {
-        if last_milked >= current_day {
-            return Err("This walrus has already been milked today".to_string());
-        }
-    }
-    
-    // Update last milked day
-    breeding_data.last_milked_day = Some(current_day);
-    ctx.db.walrus_breeding_data().animal_id().update(breeding_data);
-    
-    // Give player milk
-    give_milk_to_player(ctx, player_id)?;
-    
-    log::info!("🥛 Player {} milked walrus {} (day {})", player_id, animal.id, current_day);
-    
-    Ok(())
-}
-
-/// Helper to give milk item to player
-fn give_milk_to_player(ctx: &ReducerContext, player_id: Identity) -> Result<(), String> {
-    // Find the Raw Milk item definition
-    let milk_def = ctx.db.item_definition().iter()
-        .find(|def| def.name == "Raw Milk")
-        .ok_or_else(|| "Raw Milk item definition not found".to_string())?;
-    
-    // Add milk to player's inventory (1 milk per milking)
-    crate::items::add_item_to_player_inventory(ctx, player_id, milk_def.id, 1)?;
-    
-    Ok(())
-}
-
-/// Check if an animal is milkable right now
-/// Used by client to determine if milking indicator should be shown
-pub fn is_animal_milkable(
-    animal: &WildAnimal,
-    caribou_breeding: Option<&super::caribou::CaribouBreedingData>,
-    walrus_breeding: Option<&super::walrus::WalrusBreedingData>,
-    current_day: u32,
-) -> bool {
-    // Must be tamed
-    if animal.tamed_by.is_none() {
-        return false;
-    }
-    
-    match animal.species {
-        AnimalSpecies::Caribou => {
-            if let Some(breeding) = caribou_breeding {
-                // Must be female adult
-                if breeding.sex != super::caribou::CaribouSex::Female {
-                    return false;
-                }
-                if breeding.age_stage != super::caribou::CaribouAgeStage::Adult {
-                    return false;
-                }
-                // Check if not milked today
-                match breeding.last_milked_day {
-                    None => true, // Never milked
-                    Some(last_day) => last_day < current_day, // Milked on a previous day
-                }
-            } else {
-                false
-            }
-        }
-        AnimalSpecies::ArcticWalrus => {
-            if let Some(breeding) = walrus_breeding {
-                // Must be female adult
-                if breeding.sex != super::walrus::WalrusSex::Female {
-                    return false;
-                }
-                if breeding.age_stage != super::walrus::WalrusAgeStage::Adult {
-                    return false;
-                }
-                // Check if not milked today
-                match breeding.last_milked_day {
-                    None => true, // Never milked
-                    Some(last_day) => last_day < current_day, // Milked on a previous day
-                }
-            } else {
-                false
-            }
-        }
-        _ => false, // Other species cannot be milked
-    }
-}
\ No newline at end of file
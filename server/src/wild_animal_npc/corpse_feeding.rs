@@ -0,0 +1,139 @@
+/******************************************************************************
+ *                                                                            *
+ * Corpse Feeding - Scavengers Picking at Unattended Corpses                 *
+ *                                                                            *
+ * Terns, crows and wolverines that haven't spotted a player go to the       *
+ * nearest unattended corpse and take bites out of it. Every bite eats into  *
+ * what's left to harvest, and a corpse that's eaten down is picked clean    *
+ * to bones (see corpse_decay.rs). Stand near a kill to keep them off it.    *
+ *                                                                            *
+ * Flow (driven from each species' update_ai_state_logic):                   *
+ *   - Patrolling: occasionally look for a corpse in range and head to it    *
+ *     (Investigating state, target stored in held_item_name)                *
+ *   - Investigating: on arrival take a bite, then go back to patrolling -   *
+ *     a scavenger that stays nearby soon comes back for another             *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Timestamp};
+use rand::Rng;
+use log;
+
+use crate::corpse_decay::{self, ScavengedCorpse};
+use crate::utils::get_distance_squared;
+
+use super::core::{AnimalState, WildAnimal, transition_to_state, is_flying_species};
+
+/// Markers stored in `held_item_name` while a scavenger heads to a corpse (same scheme as crop grazing)
+const FEED_ANIMAL_CORPSE_PREFIX: &str = "__FEED_ANIMAL_CORPSE:";
+const FEED_PLAYER_CORPSE_PREFIX: &str = "__FEED_PLAYER_CORPSE:";
+const FEED_BITE_RADIUS: f32 = 40.0; // How close the scavenger must be to take a bite
+const FEED_BITE_RADIUS_SQUARED: f32 = FEED_BITE_RADIUS * FEED_BITE_RADIUS;
+const FEED_TIMEOUT_MS: i64 = 20_000; // Give up if the corpse can't be reached
+const FEED_CHECK_CHANCE: f32 = 0.1; // Chance per AI tick to look for a corpse while patrolling
+/// Birds further than this fly to the corpse; closer ones walk up to it
+const BIRD_LANDING_DISTANCE: f32 = 150.0;
+
+/// Whether the animal is currently heading to (or feeding on) a corpse
+pub fn is_feeding(animal: &WildAnimal) -> bool {
+    feeding_target(animal).is_some()
+}
+
+/// Drop any feeding target - used when the scavenger is spooked off the corpse
+pub fn clear_feeding_target(animal: &mut WildAnimal) {
+    if is_feeding(animal) {
+        animal.held_item_name = None;
+    }
+}
+
+fn feeding_target(animal: &WildAnimal) -> Option<ScavengedCorpse> {
+    let marker = animal.held_item_name.as_ref()?;
+    if let Some(id) = marker.strip_prefix(FEED_ANIMAL_CORPSE_PREFIX) {
+        id.parse().ok().map(ScavengedCorpse::Animal)
+    } else if let Some(id) = marker.strip_prefix(FEED_PLAYER_CORPSE_PREFIX) {
+        id.parse().ok().map(ScavengedCorpse::Player)
+    } else {
+        None
+    }
+}
+
+/// Rolls for corpse awareness while patrolling. Returns true if the scavenger started heading to a corpse.
+pub fn try_start_corpse_feeding(
+    ctx: &ReducerContext,
+    animal: &mut WildAnimal,
+    current_time: Timestamp,
+    rng: &mut impl Rng,
+) -> bool {
+    if animal.tamed_by.is_some() || animal.held_item_name.is_some() {
+        return false;
+    }
+    if rng.gen::<f32>() >= FEED_CHECK_CHANCE {
+        return false;
+    }
+
+    let Some((corpse, corpse_x, corpse_y)) = corpse_decay::find_nearest_edible_corpse(ctx, animal.pos_x, animal.pos_y) else {
+        return false;
+    };
+
+    animal.held_item_name = Some(match corpse {
+        ScavengedCorpse::Animal(id) => format!("{}{}", FEED_ANIMAL_CORPSE_PREFIX, id),
+        ScavengedCorpse::Player(id) => format!("{}{}", FEED_PLAYER_CORPSE_PREFIX, id),
+    });
+    animal.investigation_x = Some(corpse_x);
+    animal.investigation_y = Some(corpse_y);
+    if is_flying_species(&animal.species) {
+        animal.is_flying = get_distance_squared(animal.pos_x, animal.pos_y, corpse_x, corpse_y) > BIRD_LANDING_DISTANCE * BIRD_LANDING_DISTANCE;
+    }
+    transition_to_state(animal, AnimalState::Investigating, current_time, None, "smelled a corpse");
+    log::debug!("🦴 {:?} {} heading to feed on {:?} at ({:.1}, {:.1})",
+               animal.species, animal.id, corpse, corpse_x, corpse_y);
+    true
+}
+
+/// Advances a feeding scavenger (call from the Investigating state). Returns to patrol once the
+/// bite is taken, the corpse is gone or attended, or the scavenger gave up trying to reach it.
+pub fn update_corpse_feeding(
+    ctx: &ReducerContext,
+    animal: &mut WildAnimal,
+    current_time: Timestamp,
+) {
+    let Some(corpse) = feeding_target(animal) else {
+        finish_feeding(animal, current_time, "no corpse target");
+        return;
+    };
+    let Some((corpse_x, corpse_y)) = corpse_decay::edible_corpse_position(ctx, corpse) else {
+        finish_feeding(animal, current_time, "corpse gone");
+        return;
+    };
+
+    let distance_sq = get_distance_squared(animal.pos_x, animal.pos_y, corpse_x, corpse_y);
+    if distance_sq <= FEED_BITE_RADIUS_SQUARED {
+        corpse_decay::take_scavenger_bite(ctx, corpse);
+        if is_flying_species(&animal.species) {
+            animal.is_flying = false; // Stay landed by the corpse
+        }
+        finish_feeding(animal, current_time, "took a bite");
+        return;
+    }
+
+    let time_in_state_ms = (current_time.to_micros_since_unix_epoch() - animal.state_change_time.to_micros_since_unix_epoch()) / 1000;
+    if time_in_state_ms > FEED_TIMEOUT_MS {
+        finish_feeding(animal, current_time, "corpse unreachable");
+        return;
+    }
+
+    if is_flying_species(&animal.species) && distance_sq <= BIRD_LANDING_DISTANCE * BIRD_LANDING_DISTANCE {
+        animal.is_flying = false; // Land and walk the last stretch
+    }
+
+    // Core Investigating movement clears the target on arrival - keep steering at the corpse
+    animal.investigation_x = Some(corpse_x);
+    animal.investigation_y = Some(corpse_y);
+}
+
+fn finish_feeding(animal: &mut WildAnimal, current_time: Timestamp, reason: &str) {
+    animal.held_item_name = None;
+    animal.investigation_x = None;
+    animal.investigation_y = None;
+    transition_to_state(animal, AnimalState::Patrolling, current_time, None, reason);
+}
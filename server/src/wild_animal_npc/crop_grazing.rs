@@ -0,0 +1,167 @@
+/******************************************************************************
+ *                                                                            *
+ * Crop Grazing - Wild Herbivores Raiding Player Farms                       *
+ *                                                                            *
+ * Wild herbivores (Caribou, Vole) wander over to player-planted crops,      *
+ * eat them and flee when players come close. Unlike crows, they walk -      *
+ * so fences around a farm keep them out.                                    *
+ *                                                                            *
+ * Flow (driven from each species' update_ai_state_logic):                   *
+ *   - Patrolling: occasionally look for a planted seed in range and walk    *
+ *     to it (Investigating state, target stored in held_item_name)          *
+ *   - Investigating: on arrival take a bite - the seed loses growth        *
+ *     progress and is destroyed once none is left                           *
+ *                                                                            *
+ * Crops inside greenhouses, underwater crops and tree saplings are ignored. *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Timestamp, Table};
+use rand::Rng;
+use log;
+
+use crate::planted_seeds::planted_seed as PlantedSeedTableTrait;
+use crate::plants_database::PlantType;
+use crate::utils::get_distance_squared;
+
+use super::core::{AnimalState, WildAnimal, transition_to_state};
+
+/// Marker stored in `held_item_name` while a herbivore walks to a crop (same scheme as crow farm raids)
+const GRAZE_TARGET_PREFIX: &str = "__GRAZE_SEED:";
+const GRAZE_EAT_RADIUS: f32 = 40.0; // How close the animal must be to take a bite
+const GRAZE_EAT_RADIUS_SQUARED: f32 = GRAZE_EAT_RADIUS * GRAZE_EAT_RADIUS;
+const GRAZE_TIMEOUT_MS: i64 = 15_000; // Give up if the crop can't be reached (fenced in)
+
+/// Per-species grazing tuning
+pub struct GrazingConfig {
+    pub detection_radius: f32, // How far the animal notices crops
+    pub check_chance: f32,     // Chance per AI tick to look for crops while patrolling
+    pub bite_growth_loss: f32, // Growth progress removed per bite (>= 1.0 destroys the crop outright)
+}
+
+/// Whether the animal is currently walking to (or eating) a crop
+pub fn is_grazing(animal: &WildAnimal) -> bool {
+    animal.held_item_name.as_ref().map_or(false, |s| s.starts_with(GRAZE_TARGET_PREFIX))
+}
+
+/// Drop any grazing target - used when the animal is spooked off the crop
+pub fn clear_grazing_target(animal: &mut WildAnimal) {
+    if is_grazing(animal) {
+        animal.held_item_name = None;
+    }
+}
+
+/// Rolls for crop awareness while patrolling. Returns true if the animal started walking to a crop.
+pub fn try_start_grazing(
+    ctx: &ReducerContext,
+    animal: &mut WildAnimal,
+    config: &GrazingConfig,
+    current_time: Timestamp,
+    rng: &mut impl Rng,
+) -> bool {
+    if animal.tamed_by.is_some() || animal.held_item_name.is_some() {
+        return false;
+    }
+    if rng.gen::<f32>() >= config.check_chance {
+        return false;
+    }
+
+    let Some((seed_id, seed_x, seed_y)) = find_nearest_grazeable_seed(ctx, animal.pos_x, animal.pos_y, config.detection_radius) else {
+        return false;
+    };
+
+    animal.held_item_name = Some(format!("{}{}", GRAZE_TARGET_PREFIX, seed_id));
+    animal.investigation_x = Some(seed_x);
+    animal.investigation_y = Some(seed_y);
+    transition_to_state(animal, AnimalState::Investigating, current_time, None, "spotted crops");
+    log::debug!("🌾 {:?} {} spotted planted seed {} at ({:.1}, {:.1}) - moving to graze",
+               animal.species, animal.id, seed_id, seed_x, seed_y);
+    true
+}
+
+/// Advances a grazing animal (call from the Investigating state). Returns to patrol once the
+/// bite is taken, the crop is gone, or the animal gave up trying to reach it.
+pub fn update_grazing(
+    ctx: &ReducerContext,
+    animal: &mut WildAnimal,
+    config: &GrazingConfig,
+    current_time: Timestamp,
+) {
+    let seed_id: u64 = animal.held_item_name.as_ref()
+        .and_then(|s| s.strip_prefix(GRAZE_TARGET_PREFIX))
+        .and_then(|id| id.parse().ok())
+        .unwrap_or(0);
+
+    let Some(seed) = ctx.db.planted_seed().id().find(seed_id) else {
+        finish_grazing(animal, current_time, "crop gone");
+        return;
+    };
+
+    let distance_sq = get_distance_squared(animal.pos_x, animal.pos_y, seed.pos_x, seed.pos_y);
+    if distance_sq <= GRAZE_EAT_RADIUS_SQUARED {
+        graze_planted_seed(ctx, animal, seed, config.bite_growth_loss);
+        finish_grazing(animal, current_time, "finished grazing");
+        return;
+    }
+
+    let time_in_state_ms = (current_time.to_micros_since_unix_epoch() - animal.state_change_time.to_micros_since_unix_epoch()) / 1000;
+    if time_in_state_ms > GRAZE_TIMEOUT_MS {
+        finish_grazing(animal, current_time, "crop unreachable");
+        return;
+    }
+
+    // Core Investigating movement clears the target on arrival - keep steering at the crop
+    animal.investigation_x = Some(seed.pos_x);
+    animal.investigation_y = Some(seed.pos_y);
+}
+
+fn finish_grazing(animal: &mut WildAnimal, current_time: Timestamp, reason: &str) {
+    animal.held_item_name = None;
+    animal.investigation_x = None;
+    animal.investigation_y = None;
+    transition_to_state(animal, AnimalState::Patrolling, current_time, None, reason);
+}
+
+/// Nearest planted seed a wild herbivore can reach and eat
+fn find_nearest_grazeable_seed(ctx: &ReducerContext, x: f32, y: f32, radius: f32) -> Option<(u64, f32, f32)> {
+    let radius_sq = radius * radius;
+    let mut closest: Option<(u64, f32, f32, f32)> = None; // (id, pos_x, pos_y, dist_sq)
+    let mut greenhouse_footprints: Option<Vec<(f32, f32)>> = None;
+
+    for seed in ctx.db.planted_seed().iter() {
+        // Underwater crops and saplings aren't on the menu
+        if matches!(seed.plant_type, PlantType::SeaweedBed) || seed.target_tree_type.is_some() {
+            continue;
+        }
+
+        let distance_sq = get_distance_squared(x, y, seed.pos_x, seed.pos_y);
+        if distance_sq > radius_sq || closest.as_ref().map_or(false, |c| distance_sq >= c.3) {
+            continue;
+        }
+
+        // Greenhouses are only looked up once a candidate is in range
+        let footprints = greenhouse_footprints.get_or_insert_with(|| crate::greenhouse::get_greenhouse_footprints(ctx));
+        if crate::greenhouse::is_position_in_any_greenhouse(footprints, seed.pos_x, seed.pos_y) {
+            continue;
+        }
+
+        closest = Some((seed.id, seed.pos_x, seed.pos_y, distance_sq));
+    }
+
+    closest.map(|(id, pos_x, pos_y, _)| (id, pos_x, pos_y))
+}
+
+/// Takes a bite out of a planted seed - removes growth progress, destroying the crop when none is left
+fn graze_planted_seed(ctx: &ReducerContext, animal: &WildAnimal, mut seed: crate::planted_seeds::PlantedSeed, bite_growth_loss: f32) {
+    let remaining_progress = seed.growth_progress - bite_growth_loss;
+    if remaining_progress <= 0.0 {
+        ctx.db.planted_seed().id().delete(seed.id);
+        log::info!("🌾 {:?} {} ate planted seed '{}' (ID: {}) at ({:.1}, {:.1})",
+                  animal.species, animal.id, seed.seed_type, seed.id, seed.pos_x, seed.pos_y);
+    } else {
+        seed.growth_progress = remaining_progress;
+        log::info!("🌾 {:?} {} grazed planted seed '{}' (ID: {}) - growth set back to {:.0}%",
+                  animal.species, animal.id, seed.seed_type, seed.id, remaining_progress * 100.0);
+        ctx.db.planted_seed().id().update(seed);
+    }
+}
@@ -41,6 +41,7 @@ use super::core::{
     get_player_distance, execute_flying_patrol, execute_grounded_idle, execute_flying_chase,
     is_bird_flying, update_facing_direction, set_flee_destination_away_from_threat,
 };
+use super::corpse_feeding::{try_start_corpse_feeding, update_corpse_feeding, is_feeding, clear_feeding_target};
 
 /// Stealing constants
 const STEAL_DETECTION_RADIUS: f32 = 250.0; // How far crows can detect players with items
@@ -160,6 +161,20 @@ impl AnimalBehavior for CrowBehavior {
                     return Ok(());
                 }
                 
+                // 🪺 STASH LOOT 🪺
+                // A crow that got away with something flies it back to the nearest nest
+                if is_carrying_loot(animal) {
+                    if let Some(nest) = super::crow_nest::find_nearest_crow_nest(ctx, animal.pos_x, animal.pos_y) {
+                        animal.is_flying = true;
+                        animal.investigation_x = Some(nest.pos_x);
+                        animal.investigation_y = Some(nest.pos_y);
+                        animal.target_player_id = None;
+                        transition_to_state(animal, AnimalState::Stealing, current_time, None, "returning loot to nest");
+                        log::debug!("🪺 Crow {} flying loot to nest {} at ({:.1}, {:.1})", animal.id, nest.id, nest.pos_x, nest.pos_y);
+                        return Ok(());
+                    }
+                }
+                
                 // Check for players with food to follow
                 if let Some(player) = detected_player {
                     let distance = get_player_distance(animal, player);
@@ -284,13 +299,53 @@ impl AnimalBehavior for CrowBehavior {
                         }
                     }
                 }
+                
+                // 🦴 Nobody around - pick at any unattended corpse nearby
+                if detected_player.is_none() && try_start_corpse_feeding(ctx, animal, current_time, rng) {
+                    return Ok(());
+                }
                 // Normal walking/flying behavior handled by core movement system based on is_flying flag
             },
             
+            AnimalState::Investigating => {
+                // 🦴 Feeding on an unattended corpse - a player coming close scares it off
+                if let Some(player) = detected_player.filter(|p| get_player_distance(animal, p) < 120.0) {
+                    clear_feeding_target(animal);
+                    animal.is_flying = true;
+                    set_flee_destination_away_from_threat(animal, player.position_x, player.position_y, 300.0, rng);
+                    transition_to_state(animal, AnimalState::Fleeing, current_time, None, "spooked off corpse");
+                } else if is_feeding(animal) {
+                    update_corpse_feeding(ctx, animal, current_time);
+                } else if animal.investigation_x.is_none() {
+                    transition_to_state(animal, AnimalState::Patrolling, current_time, None, "nothing to investigate");
+                }
+            },
+            
             AnimalState::Stealing => {
-                // Attempting to steal from a player OR a campfire/barbecue
+                // Attempting to steal from a player OR a campfire/barbecue (or flying loot back to a nest)
                 animal.is_flying = true; // Always fly while stealing
                 
+                // === RETURNING LOOT TO NEST ===
+                if animal.target_player_id.is_none() && is_carrying_loot(animal) {
+                    if let (Some(target_x), Some(target_y)) = (animal.investigation_x, animal.investigation_y) {
+                        let distance_sq = get_distance_squared(animal.pos_x, animal.pos_y, target_x, target_y);
+                        if distance_sq > super::crow_nest::CROW_NEST_DEPOSIT_RADIUS * super::crow_nest::CROW_NEST_DEPOSIT_RADIUS {
+                            return Ok(()); // Movement toward nest handled by core movement system
+                        }
+                        let item_name = animal.held_item_name.clone().unwrap_or_default();
+                        let quantity = animal.held_item_quantity.unwrap_or(1);
+                        if super::crow_nest::deposit_loot_at(ctx, target_x, target_y, &item_name, quantity) {
+                            animal.held_item_name = None;
+                            animal.held_item_quantity = None;
+                        }
+                    }
+                    // Deposited (or the nest is gone) - back to patrol
+                    animal.investigation_x = None;
+                    animal.investigation_y = None;
+                    transition_to_state(animal, AnimalState::Flying, current_time, None, "loot stashed in nest");
+                    return Ok(());
+                }
+                
                 // Check if we're targeting a cooking container instead of a player
                 let is_targeting_cooking_container = animal.held_item_name.as_ref()
                     .map(|s| s.starts_with("__CAMPFIRE_TARGET:") || s.starts_with("__BARBECUE_TARGET:"))
@@ -557,6 +612,7 @@ impl AnimalBehavior for CrowBehavior {
         rng: &mut impl Rng,
     ) -> Result<(), String> {
         // Crows drop held items and flee when damaged
+        clear_feeding_target(animal);
         if animal.held_item_name.is_some() {
             drop_held_item(ctx, animal, current_time);
         }
@@ -622,6 +678,11 @@ fn player_has_food(ctx: &ReducerContext, player_id: Identity) -> bool {
     false
 }
 
+/// Whether the crow is holding a real stolen item (not a campfire/farm target marker)
+fn is_carrying_loot(animal: &WildAnimal) -> bool {
+    animal.held_item_name.as_ref().map_or(false, |name| !name.starts_with("__"))
+}
+
 /// Check if enough time has passed since last steal attempt
 fn should_attempt_steal(animal: &WildAnimal, current_time: Timestamp) -> bool {
     if let Some(last_food_check) = animal.last_food_check {
@@ -644,7 +705,7 @@ fn should_attempt_campfire_steal(animal: &WildAnimal, current_time: Timestamp) -
 
 /// Check if a position is within the deterrence radius of any scarecrow
 /// Scarecrows are WoodenStorageBox entities with box_type == BOX_TYPE_SCARECROW
-fn is_position_near_scarecrow(ctx: &ReducerContext, x: f32, y: f32) -> bool {
+pub(crate) fn is_position_near_scarecrow(ctx: &ReducerContext, x: f32, y: f32) -> bool {
     for storage_box in ctx.db.wooden_storage_box().iter() {
         if storage_box.box_type == BOX_TYPE_SCARECROW {
             let dx = x - storage_box.pos_x;
@@ -707,8 +768,15 @@ fn should_attempt_farm_destruction(animal: &WildAnimal, current_time: Timestamp)
 fn find_nearest_farm_target(ctx: &ReducerContext, crow_x: f32, crow_y: f32) -> Option<FarmTarget> {
     let mut closest_target: Option<(FarmTarget, f32)> = None;
     
+    // Crops inside greenhouses are out of reach
+    let greenhouse_footprints = crate::greenhouse::get_greenhouse_footprints(ctx);
+    
     // Check planted seeds (always player-planted, always valid targets)
     for seed in ctx.db.planted_seed().iter() {
+        if crate::greenhouse::is_position_in_any_greenhouse(&greenhouse_footprints, seed.pos_x, seed.pos_y) {
+            continue;
+        }
+        
         let dx = crow_x - seed.pos_x;
         let dy = crow_y - seed.pos_y;
         let distance_sq = dx * dx + dy * dy;
@@ -738,6 +806,10 @@ fn find_nearest_farm_target(ctx: &ReducerContext, crow_x: f32, crow_y: f32) -> O
             continue;
         }
         
+        if crate::greenhouse::is_position_in_any_greenhouse(&greenhouse_footprints, resource.pos_x, resource.pos_y) {
+            continue;
+        }
+        
         let dx = crow_x - resource.pos_x;
         let dy = crow_y - resource.pos_y;
         let distance_sq = dx * dx + dy * dy;
@@ -0,0 +1,220 @@
+/******************************************************************************
+ *                                                                            *
+ * Crow Nests - Where Stolen Loot Ends Up                                    *
+ *                                                                            *
+ * Crow nests are spawned at forest trees during world generation. A crow    *
+ * that gets away with a stolen item flies it to the nearest nest and drops  *
+ * it in, so loot accumulates over time. Players who track a nest down can   *
+ * raid it to recover everything inside - the nest itself stays, and the    *
+ * local crows start filling it again.                                        *
+ *                                                                            *
+ * Nest contents are kept in a private table; the public nest row only       *
+ * exposes how many stacks are inside so clients can show a full nest.       *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table, Timestamp};
+use rand::Rng;
+use log;
+
+use crate::player as PlayerTableTrait;
+use crate::environment::{calculate_chunk_index, is_position_on_forest_tile};
+use crate::utils::get_distance_squared;
+
+// --- Constants ---
+
+/// How far a crow will fly to stash loot in a nest
+pub const CROW_NEST_SEARCH_RADIUS: f32 = 3000.0;
+/// How close a crow needs to be to drop loot into the nest
+pub const CROW_NEST_DEPOSIT_RADIUS: f32 = 60.0;
+/// Minimum distance between nests so each forest patch gets its own
+const MIN_NEST_DISTANCE: f32 = 1500.0;
+const MIN_NEST_DISTANCE_SQ: f32 = MIN_NEST_DISTANCE * MIN_NEST_DISTANCE;
+/// Roughly one nest per this many forest trees
+const FOREST_TREES_PER_NEST: usize = 80;
+/// Nests sit just in front of the trunk so they can be reached on foot
+const NEST_TREE_Y_OFFSET: f32 = 20.0;
+const PLAYER_CROW_NEST_INTERACTION_DISTANCE_SQUARED: f32 = 96.0 * 96.0;
+
+// --- Tables ---
+
+/// --- Crow Nest Data Structure ---
+/// A nest at a forest tree where crows stash stolen items.
+#[spacetimedb::table(accessor = crow_nest, public)]
+#[derive(Clone, Debug)]
+pub struct CrowNest {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    #[index(btree)]
+    pub chunk_index: u32,
+    pub loot_stack_count: u32, // Number of distinct item stacks inside (0 = empty nest)
+    pub last_deposit_at: Option<Timestamp>,
+    pub last_raided_at: Option<Timestamp>,
+}
+
+/// One stack of stolen items inside a nest (private - contents stay hidden until raided)
+#[spacetimedb::table(accessor = crow_nest_loot)]
+#[derive(Clone, Debug)]
+pub struct CrowNestLoot {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    #[index(btree)]
+    pub nest_id: u32,
+    pub item_def_id: u64,
+    pub quantity: u32,
+}
+
+// --- World Generation ---
+
+/// Spawns crow nests at forest trees during world generation
+/// Called from environment.rs after trees are spawned
+pub fn spawn_crow_nests_in_forests(ctx: &ReducerContext, spawned_tree_positions: &[(f32, f32)]) -> u32 {
+    let forest_trees: Vec<(f32, f32)> = spawned_tree_positions.iter()
+        .filter(|(x, y)| is_position_on_forest_tile(ctx, *x, *y))
+        .copied()
+        .collect();
+
+    if forest_trees.is_empty() {
+        log::info!("[CrowNest] No forest trees found, skipping nest spawning");
+        return 0;
+    }
+
+    let target_nests = (forest_trees.len() / FOREST_TREES_PER_NEST).max(1);
+
+    // Shuffle tree order for random selection
+    let mut tree_indices: Vec<usize> = (0..forest_trees.len()).collect();
+    for i in (1..tree_indices.len()).rev() {
+        let j = ctx.rng().gen_range(0..=i);
+        tree_indices.swap(i, j);
+    }
+
+    let mut nest_positions: Vec<(f32, f32)> = Vec::new();
+    for tree_idx in tree_indices {
+        if nest_positions.len() >= target_nests {
+            break;
+        }
+
+        let (tree_x, tree_y) = forest_trees[tree_idx];
+        let pos_x = tree_x;
+        let pos_y = tree_y + NEST_TREE_Y_OFFSET;
+
+        if crate::environment::is_position_on_water(ctx, pos_x, pos_y) {
+            continue;
+        }
+        let too_close = nest_positions.iter()
+            .any(|(nx, ny)| get_distance_squared(pos_x, pos_y, *nx, *ny) < MIN_NEST_DISTANCE_SQ);
+        if too_close {
+            continue;
+        }
+
+        ctx.db.crow_nest().insert(CrowNest {
+            id: 0, // Auto-increment
+            pos_x,
+            pos_y,
+            chunk_index: calculate_chunk_index(pos_x, pos_y),
+            loot_stack_count: 0,
+            last_deposit_at: None,
+            last_raided_at: None,
+        });
+        nest_positions.push((pos_x, pos_y));
+    }
+
+    log::info!("[CrowNest] Spawned {} crow nests near {} forest trees", nest_positions.len(), forest_trees.len());
+    nest_positions.len() as u32
+}
+
+// --- Crow AI Helpers ---
+
+/// Nearest nest a crow at this position would fly its loot to
+pub fn find_nearest_crow_nest(ctx: &ReducerContext, x: f32, y: f32) -> Option<CrowNest> {
+    let search_radius_sq = CROW_NEST_SEARCH_RADIUS * CROW_NEST_SEARCH_RADIUS;
+    ctx.db.crow_nest().iter()
+        .map(|nest| {
+            let dist_sq = get_distance_squared(x, y, nest.pos_x, nest.pos_y);
+            (nest, dist_sq)
+        })
+        .filter(|(_, dist_sq)| *dist_sq <= search_radius_sq)
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(nest, _)| nest)
+}
+
+/// Drops stolen items into the nest at this position, stacking with matching loot.
+/// Returns false if no nest is there any more (the crow keeps its item).
+pub fn deposit_loot_at(ctx: &ReducerContext, x: f32, y: f32, item_name: &str, quantity: u32) -> bool {
+    use crate::items::item_definition as ItemDefinitionTableTrait;
+
+    let deposit_radius_sq = CROW_NEST_DEPOSIT_RADIUS * CROW_NEST_DEPOSIT_RADIUS;
+    let Some(mut nest) = ctx.db.crow_nest().iter()
+        .find(|nest| get_distance_squared(x, y, nest.pos_x, nest.pos_y) <= deposit_radius_sq) else {
+        return false;
+    };
+    let Some(item_def_id) = ctx.db.item_definition().iter()
+        .find(|def| def.name == item_name)
+        .map(|def| def.id) else {
+        log::error!("[CrowNest] Could not find item definition for '{}'", item_name);
+        return false;
+    };
+
+    let loot = ctx.db.crow_nest_loot();
+    if let Some(mut existing) = loot.nest_id().filter(nest.id).find(|stack| stack.item_def_id == item_def_id) {
+        existing.quantity += quantity;
+        loot.id().update(existing);
+    } else {
+        loot.insert(CrowNestLoot { id: 0, nest_id: nest.id, item_def_id, quantity });
+        nest.loot_stack_count += 1;
+    }
+    nest.last_deposit_at = Some(ctx.timestamp);
+    log::info!("[CrowNest] Deposited {} x{} into nest {} ({} stacks inside)", item_name, quantity, nest.id, nest.loot_stack_count);
+    ctx.db.crow_nest().id().update(nest);
+    true
+}
+
+// --- Reducers ---
+
+/// --- Raid Crow Nest ---
+/// Takes everything the crows have stashed in a nest. Items that don't fit are dropped at the player's feet.
+#[spacetimedb::reducer]
+pub fn raid_crow_nest(ctx: &ReducerContext, nest_id: u32) -> Result<(), String> {
+    let sender_id = ctx.sender();
+    let player = ctx.db.player().identity().find(&sender_id)
+        .ok_or_else(|| "Player not found".to_string())?;
+
+    if player.is_dead {
+        return Err("Cannot raid a nest while dead.".to_string());
+    }
+    if player.is_knocked_out {
+        return Err("Cannot raid a nest while knocked out.".to_string());
+    }
+
+    let mut nest = ctx.db.crow_nest().id().find(&nest_id)
+        .ok_or_else(|| format!("Crow nest {} not found.", nest_id))?;
+
+    if get_distance_squared(player.position_x, player.position_y, nest.pos_x, nest.pos_y) > PLAYER_CROW_NEST_INTERACTION_DISTANCE_SQUARED {
+        return Err("Too far away from crow nest.".to_string());
+    }
+
+    let loot = ctx.db.crow_nest_loot();
+    let stacks: Vec<CrowNestLoot> = loot.nest_id().filter(nest_id).collect();
+    if stacks.is_empty() {
+        return Err("The nest is empty.".to_string());
+    }
+
+    for stack in &stacks {
+        if let Err(e) = crate::dropped_item::give_item_to_player_or_drop(ctx, sender_id, stack.item_def_id, stack.quantity) {
+            log::error!("[CrowNest] Failed to give nest loot (def {}, x{}) to {:?}: {}", stack.item_def_id, stack.quantity, sender_id, e);
+            continue;
+        }
+        loot.id().delete(stack.id);
+    }
+
+    nest.loot_stack_count = loot.nest_id().filter(nest_id).count() as u32;
+    nest.last_raided_at = Some(ctx.timestamp);
+    ctx.db.crow_nest().id().update(nest);
+
+    log::info!("Player {:?} raided crow nest {} and recovered {} stacks", sender_id, nest_id, stacks.len());
+    Ok(())
+}
@@ -108,7 +108,8 @@ impl AnimalBehavior for DrownedWatchBehavior {
         rng: &mut impl Rng,
     ) -> Result<(), String> {
         match animal.state {
-            AnimalState::Idle | AnimalState::Patrolling => {
+            // Investigating = drawn to a distraction (thrown stone); players still take priority
+            AnimalState::Idle | AnimalState::Patrolling | AnimalState::Investigating => {
                 if let Some(player) = detected_player {
                     // FLASHLIGHT HESITATION: If in player's flashlight beam, don't escalate to chasing
                     // The light keeps apparitions hesitant - they stay in patrol mode
@@ -131,6 +132,8 @@ impl AnimalBehavior for DrownedWatchBehavior {
                         transition_to_state(animal, AnimalState::Chasing, current_time, Some(player.identity), "detected player");
                         emit_species_sound(ctx, animal, player.identity, "chase_start");
                     }
+                } else if animal.state == AnimalState::Investigating && animal.investigation_x.is_none() {
+                    transition_to_state(animal, AnimalState::Patrolling, current_time, None, "distraction investigated");
                 }
             },
             
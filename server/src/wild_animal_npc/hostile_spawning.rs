@@ -29,6 +29,7 @@ use crate::rune_stone::{rune_stone as RuneStoneTableTrait, RUNE_STONE_DETERRENCE
 use crate::building::{
     foundation_cell as FoundationCellTableTrait,
     FOUNDATION_TILE_SIZE_PX,
+    StructureRef, StructureDamageType,
 };
 use crate::building_enclosure::is_position_inside_building;
 use crate::animal_collision::validate_animal_spawn_position;
@@ -946,6 +947,33 @@ pub fn emit_hostile_death_sound(
     log::debug!("💀 Hostile death sound emitted at ({:.0}, {:.0})", pos_x, pos_y);
 }
 
+// --- Death Loot ---
+
+/// Loot source key for a hostile NPC species ("hostile_npc:Shardkin", ...)
+pub fn hostile_npc_loot_source_key(species: AnimalSpecies) -> String {
+    format!("hostile_npc:{:?}", species)
+}
+
+/// Default memory shard drops for hostile NPC kills, seeded into the loot_table subsystem.
+/// Reward scales with difficulty - MUST be worth the risk vs safe barrel farming!
+/// Barrels: 1-2 shards at 92% chance (~1.38 avg), no danger, infinite respawn
+pub fn default_hostile_npc_loot_sources() -> Vec<crate::loot_table::LootSourceDefault> {
+    use crate::loot_table::{LootEntryDefault, LootSourceDefault};
+    [
+        (AnimalSpecies::Shardkin, 8, 15),      // Swarmers - dangerous in groups
+        (AnimalSpecies::Shorebound, 15, 25),   // Stalkers - worth the risk
+        (AnimalSpecies::DrownedWatch, 50, 80), // Brutes - boss-tier reward
+    ]
+    .into_iter()
+    .map(|(species, min_shards, max_shards)| {
+        LootSourceDefault::new(
+            hostile_npc_loot_source_key(species),
+            vec![LootEntryDefault::new("Memory Shard", min_shards, max_shards, 1.0)],
+        )
+    })
+    .collect()
+}
+
 // --- Initialization ---
 
 /// Initialize hostile NPC spawning system
@@ -1037,6 +1065,7 @@ pub fn process_hostile_spawns(ctx: &ReducerContext, _args: HostileSpawnSchedule)
                       daytime_hostiles.len(), world_state.time_of_day);
             for id in &daytime_hostiles {
                 ctx.db.wild_animal().id().delete(id);
+                crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(*id));
             }
         }
         
@@ -1436,6 +1465,11 @@ fn is_valid_spawn_position(
         return false;
     }
     
+    // Check safe zones (ALK stations, villages) - no hostile spawns inside
+    if crate::safe_zone::is_position_in_hostile_free_zone(ctx, spawn_x, spawn_y) {
+        return false;
+    }
+    
     // Check general spawn validation (water, collisions, etc.)
     // Hostile NPCs spawn on land - pass None (blocks water)
     if validate_animal_spawn_position(ctx, spawn_x, spawn_y, None).is_err() {
@@ -1514,7 +1548,8 @@ fn spawn_hostile_npc(
         last_shock_time: None,
     };
     
-    ctx.db.wild_animal().insert(animal);
+    let inserted = ctx.db.wild_animal().insert(animal);
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
     log::info!("👹 [HostileNPC] Spawned {:?} at ({:.0}, {:.0})", species, pos_x, pos_y);
 }
 
@@ -1595,6 +1630,7 @@ pub fn process_dawn_cleanup(ctx: &ReducerContext, args: HostileDawnCleanupSchedu
         
         for id in &hostile_ids {
             ctx.db.wild_animal().id().delete(id);
+            crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(*id));
         }
         
         if !hostile_ids.is_empty() {
@@ -1641,6 +1677,7 @@ pub fn process_dawn_cleanup(ctx: &ReducerContext, args: HostileDawnCleanupSchedu
         if rng.gen::<f32>() < despawn_chance {
             // Mark for despawn and immediately delete
             ctx.db.wild_animal().id().delete(&hostile.id);
+            crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(hostile.id));
             log::debug!("🌅 [HostileNPC] {:?} {} dissolved at dawn", hostile.species, hostile.id);
         }
     }
@@ -1839,160 +1876,24 @@ pub fn find_nearest_active_ward(
 
 /// Apply hostile NPC damage to a structure
 /// BYPASSES normal melee damage reduction (hostile attacks are effective)
+/// Returns true if the structure was destroyed, false if it was hit but still stands
+/// or was already gone (missing or destroyed)
 pub fn hostile_attack_structure(
     ctx: &ReducerContext,
     structure_id: u64,
     structure_type: &str,
     damage: f32,
-    current_time: Timestamp,
 ) -> Result<bool, String> {
-    match structure_type {
-        "door" => {
-            let doors = ctx.db.door();
-            if let Some(mut door) = doors.id().find(structure_id) {
-                if door.is_destroyed {
-                    return Ok(false);
-                }
-                
-                // HOSTILE ATTACKS BYPASS MELEE REDUCTION - full damage!
-                let old_health = door.health;
-                door.health = (door.health - damage).max(0.0);
-                door.last_hit_time = Some(current_time);
-                
-                let destroyed = door.health <= 0.0;
-                if destroyed {
-                    door.is_destroyed = true;
-                    door.destroyed_at = Some(current_time);
-                    log::info!("👹 [HostileNPC] Door {} destroyed by hostile attack!", structure_id);
-                } else {
-                    log::info!("👹 [HostileNPC] Door {} took {:.1} damage from hostile. Health: {:.1} -> {:.1}", 
-                              structure_id, damage, old_health, door.health);
-                }
-                
-                ctx.db.door().id().update(door);
-                return Ok(destroyed);
-            }
-        },
-        "wall" => {
-            let walls = ctx.db.wall_cell();
-            if let Some(mut wall) = walls.id().find(structure_id) {
-                if wall.is_destroyed {
-                    return Ok(false);
-                }
-                
-                // HOSTILE ATTACKS BYPASS MELEE REDUCTION - full damage!
-                let old_health = wall.health;
-                wall.health = (wall.health - damage).max(0.0);
-                wall.last_hit_time = Some(current_time);
-                
-                let destroyed = wall.health <= 0.0;
-                if destroyed {
-                    wall.is_destroyed = true;
-                    wall.destroyed_at = Some(current_time);
-                    log::info!("👹 [HostileNPC] Wall {} destroyed by hostile attack!", structure_id);
-                } else {
-                    log::info!("👹 [HostileNPC] Wall {} took {:.1} damage from hostile. Health: {:.1} -> {:.1}", 
-                              structure_id, damage, old_health, wall.health);
-                }
-                
-                ctx.db.wall_cell().id().update(wall);
-                return Ok(destroyed);
-            }
-        },
-        "shelter" => {
-            let shelters = ctx.db.shelter();
-            if let Some(mut shelter) = shelters.id().find(structure_id as u32) {
-                if shelter.is_destroyed {
-                    return Ok(false);
-                }
-                
-                // HOSTILE ATTACKS BYPASS MELEE REDUCTION - full damage!
-                let old_health = shelter.health;
-                shelter.health = (shelter.health - damage).max(0.0);
-                shelter.last_hit_time = Some(current_time);
-                
-                let destroyed = shelter.health <= 0.0;
-                if destroyed {
-                    shelter.is_destroyed = true;
-                    shelter.destroyed_at = Some(current_time);
-                    log::info!("👹 [HostileNPC] Shelter {} destroyed by hostile attack!", structure_id);
-                } else {
-                    log::info!("👹 [HostileNPC] Shelter {} took {:.1} damage from hostile. Health: {:.1} -> {:.1}", 
-                              structure_id, damage, old_health, shelter.health);
-                }
-                
-                ctx.db.shelter().id().update(shelter);
-                return Ok(destroyed);
-            }
-        },
-        "ward" => {
-            // DrownedWatch attacks wards to destroy ward protection!
-            let lanterns = ctx.db.lantern();
-            if let Some(mut lantern) = lanterns.id().find(structure_id as u32) {
-                if lantern.is_destroyed {
-                    return Ok(false);
-                }
-                
-                // Get ward type name for logging
-                let ward_name = match lantern.lantern_type {
-                    LANTERN_TYPE_ANCESTRAL_WARD => "Ancestral Ward",
-                    LANTERN_TYPE_SIGNAL_DISRUPTOR => "Signal Disruptor",
-                    _ => "Ward",
-                };
-                
-                // HOSTILE ATTACKS BYPASS MELEE REDUCTION - full damage!
-                let old_health = lantern.health;
-                lantern.health = (lantern.health - damage).max(0.0);
-                lantern.last_hit_time = Some(current_time);
-                
-                let destroyed = lantern.health <= 0.0;
-                if destroyed {
-                    lantern.is_destroyed = true;
-                    lantern.destroyed_at = Some(current_time);
-                    // Stop ward sound if it was burning
-                    if lantern.is_burning && lantern.lantern_type == LANTERN_TYPE_LANTERN {
-                        crate::sound_events::stop_lantern_sound(ctx, lantern.id as u64);
-                    }
-                    log::info!("👹 [DrownedWatch] {} {} destroyed! Ward protection removed!", ward_name, structure_id);
-                } else {
-                    log::info!("👹 [DrownedWatch] {} {} took {:.1} damage. Health: {:.1} -> {:.1}", 
-                              ward_name, structure_id, damage, old_health, lantern.health);
-                }
-                
-                ctx.db.lantern().id().update(lantern);
-                return Ok(destroyed);
-            }
-        },
-        "fence" => {
-            let fences = ctx.db.fence();
-            if let Some(mut fence) = fences.id().find(structure_id) {
-                if fence.is_destroyed {
-                    return Ok(false);
-                }
-                
-                // HOSTILE ATTACKS BYPASS MELEE REDUCTION - full damage!
-                let old_health = fence.health;
-                fence.health = (fence.health - damage).max(0.0);
-                fence.last_hit_time = Some(current_time);
-                
-                let destroyed = fence.health <= 0.0;
-                if destroyed {
-                    fence.is_destroyed = true;
-                    fence.destroyed_at = Some(current_time);
-                    log::info!("👹 [HostileNPC] Fence {} destroyed by hostile attack!", structure_id);
-                } else {
-                    log::info!("👹 [HostileNPC] Fence {} took {:.1} damage from hostile. Health: {:.1} -> {:.1}", 
-                              structure_id, damage, old_health, fence.health);
-                }
-                
-                ctx.db.fence().id().update(fence);
-                return Ok(destroyed);
-            }
-        },
-        _ => {
-            return Err(format!("Unknown structure type: {}", structure_type));
-        }
+    let target = match structure_type {
+        "door" => StructureRef::Door(structure_id),
+        "wall" => StructureRef::Wall(structure_id),
+        "shelter" => StructureRef::Shelter(structure_id as u32),
+        "ward" => StructureRef::Lantern(structure_id as u32), // DrownedWatch attacks wards to remove their protection
+        "fence" => StructureRef::Fence(structure_id),
+        _ => return Err(format!("Unknown structure type: {}", structure_type)),
+    };
+    if !crate::building::is_structure_standing(ctx, target) {
+        return Ok(false);
     }
-    
-    Ok(false)
+    crate::building::apply_structure_damage(ctx, target, damage, StructureDamageType::Hostile, None)
 }
@@ -0,0 +1,403 @@
+/******************************************************************************
+ *                                                                            *
+ * Caribou Migration - Seasonal herd movement between Tundra and Grassland   *
+ *                                                                            *
+ * A handful of migration routes are generated once the world exists. Each   *
+ * route runs overland from a Tundra tile to a Grass tile and carries a herd  *
+ * anchor point. In spring and summer herds graze the tundra; when autumn     *
+ * arrives the anchor walks down the route to the grasslands, and back up     *
+ * again in spring - so the good caribou hunting grounds shift with the       *
+ * seasons instead of staying at fixed spawn chunks.                          *
+ *                                                                            *
+ * Every tick the anchor advances, caribou near it get their spawn (herd      *
+ * gathering) point moved along, and:                                         *
+ *   - herds a player can see walk to the new anchor (Investigating state)   *
+ *   - herds nobody is watching are simply moved along with the anchor       *
+ * Herds that have been hunted down are topped up near the anchor, out of    *
+ * sight of players, so the route always stays populated.                    *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table, Timestamp};
+use spacetimedb::spacetimedb_lib::{ScheduleAt, TimeDuration};
+use rand::Rng;
+use log;
+use std::time::Duration;
+
+use crate::{TILE_SIZE_PX, WORLD_WIDTH_TILES, WORLD_HEIGHT_TILES, TileType, get_tile_type_at_position, world_pos_to_tile_coords};
+use crate::environment::{calculate_chunk_index, is_position_on_water, is_position_in_central_compound};
+use crate::utils::get_distance_squared;
+use crate::player as PlayerTableTrait;
+use crate::world_chunk_data as WorldChunkDataTableTrait;
+use crate::world_state::{Season, world_state as WorldStateTableTrait};
+
+use super::core::{AnimalSpecies, AnimalState, WildAnimal, transition_to_state, update_animal_position, wild_animal as WildAnimalTableTrait};
+
+// --- Migration Constants ---
+const MIGRATION_PROCESS_INTERVAL_SECS: u64 = 30;
+const MIGRATION_ROUTE_COUNT: usize = 3;
+const ROUTE_MIN_LENGTH_PX: f32 = 2400.0;
+const ROUTE_MAX_LENGTH_PX: f32 = 7200.0;
+const ROUTE_SEGMENT_PX: f32 = 400.0; // Distance between generated route waypoints
+const ROUTE_JITTER_PX: f32 = 150.0; // Sideways wobble so routes aren't ruler-straight
+const ROUTE_MIN_SEPARATION_PX: f32 = 2000.0; // Keep routes' tundra ends apart
+const ANCHOR_STEP_PX: f32 = 120.0; // Anchor movement per tick (~4px/s - a slow trek)
+const HERD_ANCHOR_RADIUS: f32 = 1400.0; // Caribou this close to an anchor belong to its herd
+const HERD_SPREAD_PX: f32 = 300.0; // Max distance of a member's gathering point from the anchor
+const HERD_CLOSE_DISTANCE: f32 = 400.0; // Visible caribou further than this from their gathering point walk over
+const PLAYER_VIEW_DISTANCE: f32 = 1400.0; // Matches the animal AI active zone
+const MIN_HERD_SIZE: usize = 3;
+const HERD_RESPAWN_COOLDOWN_SECS: i64 = 1200; // A route's herd is topped up at most every 20 minutes
+
+// --- Migration Route Table ---
+#[spacetimedb::table(accessor = caribou_migration_route, public)]
+#[derive(Clone, Debug)]
+pub struct CaribouMigrationRoute {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub waypoints_x: Vec<f32>, // First waypoint is the tundra end, last is the grassland end
+    pub waypoints_y: Vec<f32>,
+    pub anchor_x: f32, // Current herd anchor
+    pub anchor_y: f32,
+    pub anchor_progress: f32, // Distance travelled along the route from the tundra end (px)
+    pub heading_to_grassland: bool,
+    pub is_migrating: bool,
+    pub last_respawn_at: Option<Timestamp>,
+}
+
+// --- Migration Schedule Table ---
+#[spacetimedb::table(accessor = caribou_migration_schedule, scheduled(process_caribou_migration))]
+#[derive(Clone)]
+pub struct CaribouMigrationSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Initialize the caribou migration system. Routes are generated lazily on the first tick
+/// after world generation, since the tile data may not exist yet at init time.
+pub fn init_caribou_migration_system(ctx: &ReducerContext) -> Result<(), String> {
+    let schedule_table = ctx.db.caribou_migration_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("🦌 Starting caribou migration schedule (every {}s).", MIGRATION_PROCESS_INTERVAL_SECS);
+        let interval = Duration::from_secs(MIGRATION_PROCESS_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            CaribouMigrationSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Caribou migration"
+        );
+    } else {
+        log::debug!("Caribou migration schedule already exists.");
+    }
+    Ok(())
+}
+
+/// Herds winter on the grasslands and summer on the tundra
+fn season_wants_grassland(season: &Season) -> bool {
+    matches!(season, Season::Autumn | Season::Winter)
+}
+
+fn tile_type_at(ctx: &ReducerContext, x: f32, y: f32) -> Option<TileType> {
+    let (tile_x, tile_y) = world_pos_to_tile_coords(x, y);
+    get_tile_type_at_position(ctx, tile_x, tile_y)
+}
+
+fn route_length(route: &CaribouMigrationRoute) -> f32 {
+    route.waypoints_x.windows(2).zip(route.waypoints_y.windows(2))
+        .map(|(xs, ys)| ((xs[1] - xs[0]).powi(2) + (ys[1] - ys[0]).powi(2)).sqrt())
+        .sum()
+}
+
+/// Point `distance` px along the route from its tundra end
+fn point_along_route(route: &CaribouMigrationRoute, distance: f32) -> (f32, f32) {
+    let mut remaining = distance.max(0.0);
+    for i in 0..route.waypoints_x.len().saturating_sub(1) {
+        let (x0, y0) = (route.waypoints_x[i], route.waypoints_y[i]);
+        let (x1, y1) = (route.waypoints_x[i + 1], route.waypoints_y[i + 1]);
+        let segment = ((x1 - x0).powi(2) + (y1 - y0).powi(2)).sqrt();
+        if remaining <= segment && segment > 0.0 {
+            let t = remaining / segment;
+            return (x0 + (x1 - x0) * t, y0 + (y1 - y0) * t);
+        }
+        remaining -= segment;
+    }
+    match (route.waypoints_x.last(), route.waypoints_y.last()) {
+        (Some(&x), Some(&y)) => (x, y),
+        _ => (route.anchor_x, route.anchor_y),
+    }
+}
+
+/// Tries to lay out one overland route from a tundra tile to a grassland tile
+fn try_generate_route(ctx: &ReducerContext, existing: &[CaribouMigrationRoute], rng: &mut impl Rng) -> Option<(Vec<f32>, Vec<f32>)> {
+    let margin = 10.0 * TILE_SIZE_PX as f32;
+    let world_width = (WORLD_WIDTH_TILES * TILE_SIZE_PX) as f32;
+    let world_height = (WORLD_HEIGHT_TILES * TILE_SIZE_PX) as f32;
+
+    let start_x = rng.gen_range(margin..world_width - margin);
+    let start_y = rng.gen_range(margin..world_height - margin);
+    if !matches!(tile_type_at(ctx, start_x, start_y), Some(TileType::Tundra | TileType::TundraGrass)) {
+        return None;
+    }
+    let too_close = existing.iter().any(|r| {
+        get_distance_squared(r.waypoints_x[0], r.waypoints_y[0], start_x, start_y) < ROUTE_MIN_SEPARATION_PX * ROUTE_MIN_SEPARATION_PX
+    });
+    if too_close {
+        return None;
+    }
+
+    for _ in 0..40 {
+        let angle = rng.gen::<f32>() * 2.0 * std::f32::consts::PI;
+        let length = rng.gen_range(ROUTE_MIN_LENGTH_PX..ROUTE_MAX_LENGTH_PX);
+        let end_x = start_x + angle.cos() * length;
+        let end_y = start_y + angle.sin() * length;
+        if end_x < margin || end_x > world_width - margin || end_y < margin || end_y > world_height - margin {
+            continue;
+        }
+        if tile_type_at(ctx, end_x, end_y) != Some(TileType::Grass) {
+            continue;
+        }
+
+        // Walk the line - caribou don't swim, and the central compound is off limits
+        let segments = (length / ROUTE_SEGMENT_PX).ceil() as usize;
+        let (perp_x, perp_y) = (-angle.sin(), angle.cos());
+        let mut waypoints_x = vec![start_x];
+        let mut waypoints_y = vec![start_y];
+        let mut blocked = false;
+        for i in 1..=segments {
+            let t = i as f32 / segments as f32;
+            let mut x = start_x + (end_x - start_x) * t;
+            let mut y = start_y + (end_y - start_y) * t;
+            if is_position_on_water(ctx, x, y) || is_position_in_central_compound(x, y) {
+                blocked = true;
+                break;
+            }
+            if i < segments {
+                let jitter = rng.gen_range(-ROUTE_JITTER_PX..ROUTE_JITTER_PX);
+                let (jx, jy) = (x + perp_x * jitter, y + perp_y * jitter);
+                if !is_position_on_water(ctx, jx, jy) {
+                    x = jx;
+                    y = jy;
+                }
+            }
+            waypoints_x.push(x);
+            waypoints_y.push(y);
+        }
+        if !blocked {
+            return Some((waypoints_x, waypoints_y));
+        }
+    }
+    None
+}
+
+fn generate_migration_routes(ctx: &ReducerContext, heading_to_grassland: bool) {
+    let mut rng = ctx.rng();
+    let mut routes: Vec<CaribouMigrationRoute> = Vec::new();
+
+    for _ in 0..400 {
+        if routes.len() >= MIGRATION_ROUTE_COUNT {
+            break;
+        }
+        let Some((waypoints_x, waypoints_y)) = try_generate_route(ctx, &routes, &mut rng) else {
+            continue;
+        };
+        let mut route = CaribouMigrationRoute {
+            id: 0,
+            anchor_x: waypoints_x[0],
+            anchor_y: waypoints_y[0],
+            waypoints_x,
+            waypoints_y,
+            anchor_progress: 0.0,
+            heading_to_grassland,
+            is_migrating: false,
+            last_respawn_at: None,
+        };
+        // New routes start where the current season wants the herd
+        if heading_to_grassland {
+            route.anchor_progress = route_length(&route);
+            let (x, y) = point_along_route(&route, route.anchor_progress);
+            route.anchor_x = x;
+            route.anchor_y = y;
+        }
+        routes.push(route);
+    }
+
+    for route in routes {
+        let inserted = ctx.db.caribou_migration_route().insert(route);
+        log::info!("🦌 Generated caribou migration route {} ({} waypoints, {:.0}px)",
+                  inserted.id, inserted.waypoints_x.len(), route_length(&inserted));
+    }
+}
+
+fn any_player_near(players: &[(f32, f32)], x: f32, y: f32) -> bool {
+    players.iter().any(|&(px, py)| get_distance_squared(px, py, x, y) <= PLAYER_VIEW_DISTANCE * PLAYER_VIEW_DISTANCE)
+}
+
+/// Scheduled reducer - advances migrating herds and tops up depleted ones
+#[spacetimedb::reducer]
+pub fn process_caribou_migration(ctx: &ReducerContext, _args: CaribouMigrationSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Caribou migration can only be run by scheduler".to_string());
+    }
+
+    let Some(world_state) = ctx.db.world_state().iter().next() else {
+        return Ok(());
+    };
+    let wants_grassland = season_wants_grassland(&world_state.current_season);
+
+    if ctx.db.caribou_migration_route().iter().next().is_none() {
+        if ctx.db.world_chunk_data().iter().next().is_none() {
+            return Ok(()); // World not generated yet
+        }
+        generate_migration_routes(ctx, wants_grassland);
+    }
+
+    let players: Vec<(f32, f32)> = ctx.db.player().iter()
+        .filter(|p| p.is_online && !p.is_dead)
+        .map(|p| (p.position_x, p.position_y))
+        .collect();
+    let wild_caribou: Vec<WildAnimal> = ctx.db.wild_animal().iter()
+        .filter(|a| a.species == AnimalSpecies::Caribou && a.tamed_by.is_none())
+        .collect();
+
+    let routes: Vec<CaribouMigrationRoute> = ctx.db.caribou_migration_route().iter().collect();
+    for mut route in routes {
+        if route.heading_to_grassland != wants_grassland {
+            route.heading_to_grassland = wants_grassland;
+            route.is_migrating = true;
+            log::info!("🦌 Caribou herd on route {} begins migrating to the {}",
+                      route.id, if wants_grassland { "grasslands" } else { "tundra" });
+        }
+
+        let (old_anchor_x, old_anchor_y) = (route.anchor_x, route.anchor_y);
+        if route.is_migrating {
+            let length = route_length(&route);
+            route.anchor_progress = if route.heading_to_grassland {
+                (route.anchor_progress + ANCHOR_STEP_PX).min(length)
+            } else {
+                (route.anchor_progress - ANCHOR_STEP_PX).max(0.0)
+            };
+            let (x, y) = point_along_route(&route, route.anchor_progress);
+            route.anchor_x = x;
+            route.anchor_y = y;
+            if route.anchor_progress <= 0.0 || route.anchor_progress >= length {
+                route.is_migrating = false;
+                log::info!("🦌 Caribou herd on route {} reached the {}",
+                          route.id, if route.heading_to_grassland { "grasslands" } else { "tundra" });
+            }
+        }
+        let (delta_x, delta_y) = (route.anchor_x - old_anchor_x, route.anchor_y - old_anchor_y);
+
+        let members: Vec<&WildAnimal> = wild_caribou.iter()
+            .filter(|c| get_distance_squared(c.pos_x, c.pos_y, old_anchor_x, old_anchor_y) <= HERD_ANCHOR_RADIUS * HERD_ANCHOR_RADIUS)
+            .collect();
+
+        if route.is_migrating {
+            for member in &members {
+                move_herd_member(ctx, member, &route, old_anchor_x, old_anchor_y, delta_x, delta_y, &players);
+            }
+        }
+
+        if members.len() < MIN_HERD_SIZE {
+            respawn_herd_on_route(ctx, &mut route, &players);
+        }
+
+        ctx.db.caribou_migration_route().id().update(route);
+    }
+
+    Ok(())
+}
+
+/// Moves one caribou's gathering point with the anchor. Watched caribou walk there, unwatched ones are moved.
+fn move_herd_member(
+    ctx: &ReducerContext,
+    member: &WildAnimal,
+    route: &CaribouMigrationRoute,
+    old_anchor_x: f32,
+    old_anchor_y: f32,
+    delta_x: f32,
+    delta_y: f32,
+    players: &[(f32, f32)],
+) {
+    // Only calm caribou follow the migration - fleeing/fighting ones sort themselves out first
+    if !matches!(member.state, AnimalState::Idle | AnimalState::Patrolling | AnimalState::Investigating) {
+        return;
+    }
+    let Some(mut caribou) = ctx.db.wild_animal().id().find(member.id)
+        .map(|row| super::transform_batch::with_live_position(ctx, row)) else { return };
+
+    // Keep each caribou's place in the herd, pulled in to a sensible spread
+    let mut offset_x = caribou.spawn_x - old_anchor_x;
+    let mut offset_y = caribou.spawn_y - old_anchor_y;
+    let offset_len = (offset_x * offset_x + offset_y * offset_y).sqrt();
+    if offset_len > HERD_SPREAD_PX {
+        offset_x *= HERD_SPREAD_PX / offset_len;
+        offset_y *= HERD_SPREAD_PX / offset_len;
+    }
+    let new_spawn_x = route.anchor_x + offset_x;
+    let new_spawn_y = route.anchor_y + offset_y;
+    if is_position_on_water(ctx, new_spawn_x, new_spawn_y) {
+        caribou.spawn_x = route.anchor_x;
+        caribou.spawn_y = route.anchor_y;
+    } else {
+        caribou.spawn_x = new_spawn_x;
+        caribou.spawn_y = new_spawn_y;
+    }
+
+    if any_player_near(players, caribou.pos_x, caribou.pos_y) {
+        let far_from_herd = get_distance_squared(caribou.pos_x, caribou.pos_y, caribou.spawn_x, caribou.spawn_y)
+            > HERD_CLOSE_DISTANCE * HERD_CLOSE_DISTANCE;
+        if far_from_herd && caribou.held_item_name.is_none() {
+            caribou.investigation_x = Some(caribou.spawn_x);
+            caribou.investigation_y = Some(caribou.spawn_y);
+            transition_to_state(&mut caribou, AnimalState::Investigating, ctx.timestamp, None, "migrating with herd");
+        }
+    } else {
+        let (new_x, new_y) = (caribou.pos_x + delta_x, caribou.pos_y + delta_y);
+        if !is_position_on_water(ctx, new_x, new_y) {
+            update_animal_position(&mut caribou, new_x, new_y);
+        }
+    }
+
+    ctx.db.wild_animal().id().update(caribou.clone());
+    super::transform_batch::reseed_batched_transform(ctx, &caribou);
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(caribou.id), caribou.pos_x, caribou.pos_y);
+}
+
+/// Spawns a fresh breeding group near the anchor of a depleted herd, out of players' sight
+fn respawn_herd_on_route(ctx: &ReducerContext, route: &mut CaribouMigrationRoute, players: &[(f32, f32)]) {
+    if let Some(last) = route.last_respawn_at {
+        let elapsed_secs = (ctx.timestamp.to_micros_since_unix_epoch() - last.to_micros_since_unix_epoch()) / 1_000_000;
+        if elapsed_secs < HERD_RESPAWN_COOLDOWN_SECS {
+            return;
+        }
+    }
+
+    let mut rng = ctx.rng();
+    for _ in 0..20 {
+        let angle = rng.gen::<f32>() * 2.0 * std::f32::consts::PI;
+        let distance = rng.gen::<f32>() * HERD_SPREAD_PX;
+        let x = route.anchor_x + angle.cos() * distance;
+        let y = route.anchor_y + angle.sin() * distance;
+        if any_player_near(players, x, y) || is_position_in_central_compound(x, y)
+            || super::population::is_position_in_building_privilege_zone(ctx, x, y) {
+            continue;
+        }
+        if crate::animal_collision::validate_animal_spawn_position(ctx, x, y, Some(AnimalSpecies::Caribou)).is_err() {
+            continue;
+        }
+
+        match super::respawn::spawn_herd_animal_group(ctx, AnimalSpecies::Caribou, x, y, calculate_chunk_index(x, y)) {
+            Ok(first) => {
+                route.last_respawn_at = Some(ctx.timestamp);
+                log::info!("🦌 Topped up caribou herd on route {} near ({:.0}, {:.0}) (first: {})", route.id, x, y, first.id);
+            }
+            Err(e) => log::warn!("Failed to respawn caribou herd on route {}: {}", route.id, e),
+        }
+        return;
+    }
+}
@@ -17,8 +17,17 @@ pub mod jellyfish;
 pub mod polar_bear;
 pub mod hare;
 pub mod snowy_owl;
+pub mod pathfinding; // A* routing around water and structures (used by move_towards_target)
+pub mod crop_grazing; // Shared crop raiding for wild herbivores (caribou, vole)
+pub mod corpse_feeding; // Terns, crows and wolverines feeding on unattended corpses
 pub mod respawn;
+pub mod population; // Biome-aware population census (target densities per species per biome)
+pub mod migration; // Seasonal caribou herd migration between tundra and grassland
 pub mod animal_corpse;
+pub mod animal_products; // Tamed animal produce (milk, ...) with per-animal cooldowns
+pub mod crow_nest; // Forest nests where crows stash stolen items for players to raid
+pub mod scent; // Raw meat scent: carried meat and dropped-meat markers attract predators
+pub mod transform_batch; // Optional per-chunk packed position feed, instead of a row update per animal per tick
 
 // Night hostile NPC behaviors
 pub mod shorebound;
@@ -0,0 +1,454 @@
+/******************************************************************************
+ *                                                                            *
+ * Animal Pathfinding - A* routing around water, cliffs and structures       *
+ *                                                                            *
+ * move_towards_target() asks next_waypoint() where to actually head. While  *
+ * the straight line to the target is clear the target is returned as-is, so *
+ * open-ground movement costs one short line check. When the line is blocked *
+ * (shoreline, cliff, sea stack, building wall, fence, shelter) a bounded A* *
+ * search runs over the tile grid - water and cliffs read from the           *
+ * compressed world_chunk_data and structures rasterised into blocked tiles  *
+ * - and the smoothed path is cached per animal in AnimalPathCache.          *
+ *                                                                            *
+ * Cached paths are followed waypoint by waypoint and only recomputed when   *
+ * the target moves significantly or the path gets old. If no path exists    *
+ * within the search window the animal heads for the reachable tile closest  *
+ * to the target, and the existing collision/water-edge recovery takes over. *
+ *                                                                            *
+ * Aquatic, flying and bee movement skips pathfinding entirely.              *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table, Timestamp};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use log;
+
+use crate::{TILE_SIZE_PX, WORLD_WIDTH_TILES, WORLD_HEIGHT_TILES, TileType};
+use crate::environment::{CHUNK_SIZE_TILES, WORLD_WIDTH_CHUNKS, WORLD_HEIGHT_CHUNKS};
+use crate::world_chunk_data as WorldChunkDataTableTrait;
+use crate::building::{FOUNDATION_TILE_SIZE_PX, wall_type_blocks_movement, wall_cell as WallCellTableTrait};
+use crate::fence::{fence_blocks, fence as FenceTableTrait};
+use crate::door::door as DoorTableTrait;
+use crate::shelter::{SHELTER_AABB_HALF_WIDTH, SHELTER_AABB_HALF_HEIGHT, SHELTER_AABB_CENTER_Y_OFFSET_FROM_POS_Y, shelter as ShelterTableTrait};
+use crate::sea_stack::{get_sea_stack_collision_dimensions, sea_stack as SeaStackTableTrait};
+
+use super::core::{AnimalSpecies, WildAnimal};
+
+// --- Pathfinding Constants ---
+const TILE_PX: f32 = TILE_SIZE_PX as f32;
+const TILES_PER_FOUNDATION_CELL: i32 = (FOUNDATION_TILE_SIZE_PX / TILE_SIZE_PX) as i32;
+const PATH_SEARCH_RADIUS_TILES: i32 = 24; // A* never looks further than this from the animal
+const PATH_MAX_EXPANDED_NODES: usize = 1500; // Hard cap on A* work per search
+const DIRECT_ROUTE_LOOKAHEAD_PX: f32 = 240.0; // Only the next few tiles of the straight line are checked
+const DIRECT_ROUTE_SAMPLE_STEP_PX: f32 = TILE_PX / 2.0;
+const WAYPOINT_REACHED_RADIUS: f32 = 28.0; // Close enough to a waypoint to move on to the next one
+const REPATH_TARGET_MOVE_PX: f32 = 96.0; // Recompute once the target has moved this far
+const MIN_REPATH_INTERVAL_MS: i64 = 500; // Never recompute the same animal's path more often than this
+const PATH_MAX_AGE_MS: i64 = 8_000; // Paths are recomputed after this long regardless
+const PATH_OFF_ROUTE_PX: f32 = TILE_PX * 3.0; // Pushed this far off the next waypoint - recompute
+const STALE_PATH_PRUNE_MS: i64 = 30_000; // Rows untouched this long belong to dead/idle animals
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+// --- Path Cache Table ---
+#[spacetimedb::table(accessor = animal_path_cache)]
+#[derive(Clone, Debug)]
+pub struct AnimalPathCache {
+    #[primary_key]
+    pub animal_id: u64,
+    pub target_x: f32, // Target the path was computed for
+    pub target_y: f32,
+    pub waypoints_x: Vec<f32>,
+    pub waypoints_y: Vec<f32>,
+    pub next_waypoint: u32,
+    pub computed_at: Timestamp,
+}
+
+/// Animals that swim, fly or buzz over obstacles move in straight lines
+fn uses_pathfinding(animal: &WildAnimal) -> bool {
+    !animal.is_flying && !matches!(
+        animal.species,
+        AnimalSpecies::ArcticWalrus
+            | AnimalSpecies::BeachCrab
+            | AnimalSpecies::SalmonShark
+            | AnimalSpecies::Jellyfish
+            | AnimalSpecies::Bee
+    )
+}
+
+fn elapsed_ms(since: Timestamp, now: Timestamp) -> i64 {
+    (now.to_micros_since_unix_epoch() - since.to_micros_since_unix_epoch()) / 1000
+}
+
+fn world_to_tile(x: f32, y: f32) -> (i32, i32) {
+    ((x / TILE_PX).floor() as i32, (y / TILE_PX).floor() as i32)
+}
+
+fn tile_center(tile: (i32, i32)) -> (f32, f32) {
+    (tile.0 as f32 * TILE_PX + TILE_PX / 2.0, tile.1 as f32 * TILE_PX + TILE_PX / 2.0)
+}
+
+/// Returns the point the animal should steer towards on its way to (target_x, target_y)
+pub fn next_waypoint(ctx: &ReducerContext, animal: &WildAnimal, target_x: f32, target_y: f32) -> (f32, f32) {
+    if !uses_pathfinding(animal) {
+        return (target_x, target_y);
+    }
+
+    let cache_table = ctx.db.animal_path_cache();
+    let cached = cache_table.animal_id().find(animal.id);
+
+    if let Some(mut path) = cached.clone() {
+        let age_ms = elapsed_ms(path.computed_at, ctx.timestamp);
+        let target_moved_sq = (path.target_x - target_x).powi(2) + (path.target_y - target_y).powi(2);
+        let target_moved = target_moved_sq > REPATH_TARGET_MOVE_PX * REPATH_TARGET_MOVE_PX;
+        let too_old = age_ms > PATH_MAX_AGE_MS;
+
+        if !too_old && (!target_moved || age_ms < MIN_REPATH_INTERVAL_MS) {
+            let len = path.waypoints_x.len() as u32;
+            let start_index = path.next_waypoint;
+            while path.next_waypoint < len {
+                let i = path.next_waypoint as usize;
+                let d_sq = (path.waypoints_x[i] - animal.pos_x).powi(2) + (path.waypoints_y[i] - animal.pos_y).powi(2);
+                if d_sq > WAYPOINT_REACHED_RADIUS * WAYPOINT_REACHED_RADIUS {
+                    break;
+                }
+                path.next_waypoint += 1;
+            }
+
+            if path.next_waypoint >= len {
+                cache_table.animal_id().delete(animal.id);
+                return (target_x, target_y);
+            }
+
+            let i = path.next_waypoint as usize;
+            let (wp_x, wp_y) = (path.waypoints_x[i], path.waypoints_y[i]);
+            let off_route_sq = (wp_x - animal.pos_x).powi(2) + (wp_y - animal.pos_y).powi(2);
+            if off_route_sq <= PATH_OFF_ROUTE_PX * PATH_OFF_ROUTE_PX || age_ms < MIN_REPATH_INTERVAL_MS {
+                if path.next_waypoint != start_index {
+                    cache_table.animal_id().update(path);
+                }
+                return (wp_x, wp_y);
+            }
+        }
+    }
+
+    // No usable cached path - walk straight if nothing is in the way
+    if is_direct_route_clear(ctx, animal.pos_x, animal.pos_y, target_x, target_y) {
+        if cached.is_some() {
+            cache_table.animal_id().delete(animal.id);
+        }
+        return (target_x, target_y);
+    }
+
+    // With no route found, cache the straight line anyway so the search isn't rerun every tick
+    let waypoints = find_path(ctx, (animal.pos_x, animal.pos_y), (target_x, target_y))
+        .unwrap_or_else(|| vec![(target_x, target_y)]);
+
+    prune_stale_paths(ctx);
+
+    let first = waypoints[0];
+    let path = AnimalPathCache {
+        animal_id: animal.id,
+        target_x,
+        target_y,
+        waypoints_x: waypoints.iter().map(|w| w.0).collect(),
+        waypoints_y: waypoints.iter().map(|w| w.1).collect(),
+        next_waypoint: 0,
+        computed_at: ctx.timestamp,
+    };
+    log::debug!("[Pathfinding] {:?} {} routing to ({:.0}, {:.0}) via {} waypoints",
+               animal.species, animal.id, target_x, target_y, waypoints.len());
+    if cache_table.animal_id().find(animal.id).is_some() {
+        cache_table.animal_id().update(path);
+    } else {
+        cache_table.insert(path);
+    }
+    first
+}
+
+/// Drops a cached path (e.g. when an animal is despawned)
+pub fn clear_animal_path(ctx: &ReducerContext, animal_id: u64) {
+    ctx.db.animal_path_cache().animal_id().delete(animal_id);
+}
+
+/// Removes cache rows that haven't been refreshed in a while (animal died, despawned or stopped)
+fn prune_stale_paths(ctx: &ReducerContext) {
+    let cache_table = ctx.db.animal_path_cache();
+    let stale_ids: Vec<u64> = cache_table.iter()
+        .filter(|p| elapsed_ms(p.computed_at, ctx.timestamp) > STALE_PATH_PRUNE_MS)
+        .map(|p| p.animal_id)
+        .collect();
+    for animal_id in stale_ids {
+        cache_table.animal_id().delete(animal_id);
+    }
+}
+
+/// Cheap check of the next stretch of the straight line: no water, cliffs or structures in the way
+fn is_direct_route_clear(ctx: &ReducerContext, from_x: f32, from_y: f32, to_x: f32, to_y: f32) -> bool {
+    let dx = to_x - from_x;
+    let dy = to_y - from_y;
+    let distance = (dx * dx + dy * dy).sqrt();
+    if distance < TILE_PX {
+        return true;
+    }
+
+    let check_distance = distance.min(DIRECT_ROUTE_LOOKAHEAD_PX);
+    let end_x = from_x + dx / distance * check_distance;
+    let end_y = from_y + dy / distance * check_distance;
+
+    let steps = (check_distance / DIRECT_ROUTE_SAMPLE_STEP_PX).ceil() as i32;
+    for step in 1..=steps {
+        let t = step as f32 / steps as f32;
+        let (sample_x, sample_y) = (from_x + (end_x - from_x) * t, from_y + (end_y - from_y) * t);
+        if crate::fishing::is_water_tile(ctx, sample_x, sample_y) || crate::is_position_on_cliff(ctx, sample_x, sample_y) {
+            return false;
+        }
+    }
+
+    crate::animal_collision::has_clear_line_of_sight(ctx, from_x, from_y, end_x, end_y)
+        && crate::animal_collision::check_fence_line_collision(ctx, from_x, from_y, end_x, end_y).is_none()
+}
+
+/// Walkability of the tiles inside one search window. Water and cliffs come from the compressed
+/// chunk data (loaded once per chunk), structures are rasterised up front.
+struct PathGrid<'a> {
+    ctx: &'a ReducerContext,
+    min_tile: (i32, i32),
+    max_tile: (i32, i32),
+    chunk_tiles: HashMap<(i32, i32), Option<Vec<u8>>>,
+    blocked_tiles: HashSet<(i32, i32)>,
+}
+
+impl<'a> PathGrid<'a> {
+    fn new(ctx: &'a ReducerContext, center: (i32, i32)) -> Self {
+        let min_tile = ((center.0 - PATH_SEARCH_RADIUS_TILES).max(0), (center.1 - PATH_SEARCH_RADIUS_TILES).max(0));
+        let max_tile = (
+            (center.0 + PATH_SEARCH_RADIUS_TILES).min(WORLD_WIDTH_TILES as i32 - 1),
+            (center.1 + PATH_SEARCH_RADIUS_TILES).min(WORLD_HEIGHT_TILES as i32 - 1),
+        );
+        let mut grid = PathGrid { ctx, min_tile, max_tile, chunk_tiles: HashMap::new(), blocked_tiles: HashSet::new() };
+        grid.rasterise_structures();
+        grid
+    }
+
+    fn in_bounds(&self, tile: (i32, i32)) -> bool {
+        tile.0 >= self.min_tile.0 && tile.0 <= self.max_tile.0 && tile.1 >= self.min_tile.1 && tile.1 <= self.max_tile.1
+    }
+
+    fn is_blocked(&mut self, tile: (i32, i32)) -> bool {
+        if !self.in_bounds(tile) || self.blocked_tiles.contains(&tile) {
+            return true;
+        }
+        self.is_impassable_terrain(tile)
+    }
+
+    fn is_impassable_terrain(&mut self, tile: (i32, i32)) -> bool {
+        let chunk_size = CHUNK_SIZE_TILES as i32;
+        let chunk = (tile.0 / chunk_size, tile.1 / chunk_size);
+        let ctx = self.ctx;
+        let tiles = self.chunk_tiles.entry(chunk).or_insert_with(|| {
+            ctx.db.world_chunk_data().idx_chunk_coords().filter(chunk).next().map(|c| c.tile_types)
+        });
+        let Some(tiles) = tiles else {
+            let (center_x, center_y) = tile_center(tile);
+            return crate::fishing::is_water_tile(ctx, center_x, center_y) || crate::is_position_on_cliff(ctx, center_x, center_y);
+        };
+        let index = ((tile.1 % chunk_size) * chunk_size + (tile.0 % chunk_size)) as usize;
+        tiles.get(index).and_then(|t| TileType::from_u8(*t)).map_or(false, |t| !t.is_walkable())
+    }
+
+    fn block_rect(&mut self, left: f32, top: f32, right: f32, bottom: f32) {
+        let (min_x, min_y) = world_to_tile(left, top);
+        let (max_x, max_y) = world_to_tile(right, bottom);
+        for tx in min_x.max(self.min_tile.0)..=max_x.min(self.max_tile.0) {
+            for ty in min_y.max(self.min_tile.1)..=max_y.min(self.max_tile.1) {
+                self.blocked_tiles.insert((tx, ty));
+            }
+        }
+    }
+
+    /// Blocks the tiles on both sides of a foundation cell edge. Animals keep a 45px collision
+    /// radius, so the tile row hugging a wall isn't walkable in practice either.
+    fn block_cell_edge(&mut self, cell_x: i32, cell_y: i32, edge: u8) {
+        let n = TILES_PER_FOUNDATION_CELL;
+        let (base_x, base_y) = (cell_x * n, cell_y * n);
+        let tiles: Vec<(i32, i32)> = match edge {
+            0 => (0..n).flat_map(|i| [(base_x + i, base_y - 1), (base_x + i, base_y)]).collect(),
+            1 => (0..n).flat_map(|i| [(base_x + n - 1, base_y + i), (base_x + n, base_y + i)]).collect(),
+            2 => (0..n).flat_map(|i| [(base_x + i, base_y + n - 1), (base_x + i, base_y + n)]).collect(),
+            3 => (0..n).flat_map(|i| [(base_x - 1, base_y + i), (base_x, base_y + i)]).collect(),
+            _ => Vec::new(),
+        };
+        for tile in tiles {
+            if self.in_bounds(tile) {
+                self.blocked_tiles.insert(tile);
+            }
+        }
+    }
+
+    fn rasterise_structures(&mut self) {
+        let ctx = self.ctx;
+        let chunk_size = CHUNK_SIZE_TILES as i32;
+        let mut chunk_indices = Vec::new();
+        for cy in (self.min_tile.1 / chunk_size)..=(self.max_tile.1 / chunk_size).min(WORLD_HEIGHT_CHUNKS as i32 - 1) {
+            for cx in (self.min_tile.0 / chunk_size)..=(self.max_tile.0 / chunk_size).min(WORLD_WIDTH_CHUNKS as i32 - 1) {
+                chunk_indices.push((cy * WORLD_WIDTH_CHUNKS as i32 + cx) as u32);
+            }
+        }
+
+        for &chunk_index in &chunk_indices {
+            for wall in ctx.db.wall_cell().idx_chunk().filter(chunk_index) {
+                if !wall.is_destroyed && wall_type_blocks_movement(wall.wall_type) {
+                    self.block_cell_edge(wall.cell_x, wall.cell_y, wall.edge);
+                }
+            }
+            for fence in ctx.db.fence().idx_chunk().filter(chunk_index) {
+                if fence_blocks(&fence) {
+                    self.block_cell_edge(fence.cell_x, fence.cell_y, fence.edge);
+                }
+            }
+            for door in ctx.db.door().idx_chunk().filter(chunk_index) {
+                if !door.is_destroyed && !door.is_open {
+                    self.block_cell_edge(door.cell_x, door.cell_y, door.edge);
+                }
+            }
+            for sea_stack in ctx.db.sea_stack().chunk_index().filter(chunk_index) {
+                let (half_width, half_height, y_offset) = get_sea_stack_collision_dimensions(sea_stack.scale);
+                let center_y = sea_stack.pos_y - y_offset;
+                self.block_rect(sea_stack.pos_x - half_width, center_y - half_height, sea_stack.pos_x + half_width, center_y + half_height);
+            }
+        }
+
+        let (window_left, window_top) = (self.min_tile.0 as f32 * TILE_PX, self.min_tile.1 as f32 * TILE_PX);
+        let (window_right, window_bottom) = ((self.max_tile.0 + 1) as f32 * TILE_PX, (self.max_tile.1 + 1) as f32 * TILE_PX);
+        for shelter in ctx.db.shelter().iter() {
+            if shelter.is_destroyed {
+                continue;
+            }
+            let center_y = shelter.pos_y - SHELTER_AABB_CENTER_Y_OFFSET_FROM_POS_Y;
+            let (left, right) = (shelter.pos_x - SHELTER_AABB_HALF_WIDTH, shelter.pos_x + SHELTER_AABB_HALF_WIDTH);
+            let (top, bottom) = (center_y - SHELTER_AABB_HALF_HEIGHT, center_y + SHELTER_AABB_HALF_HEIGHT);
+            if right < window_left || left > window_right || bottom < window_top || top > window_bottom {
+                continue;
+            }
+            self.block_rect(left, top, right, bottom);
+        }
+    }
+
+    /// Grid line of sight, used to smooth the raw tile path
+    fn is_segment_walkable(&mut self, from: (f32, f32), to: (f32, f32)) -> bool {
+        let distance = ((to.0 - from.0).powi(2) + (to.1 - from.1).powi(2)).sqrt();
+        let steps = ((distance / (TILE_PX / 4.0)).ceil() as i32).max(1);
+        (1..=steps).all(|step| {
+            let t = step as f32 / steps as f32;
+            !self.is_blocked(world_to_tile(from.0 + (to.0 - from.0) * t, from.1 + (to.1 - from.1) * t))
+        })
+    }
+}
+
+fn octile_heuristic(a: (i32, i32), b: (i32, i32)) -> u32 {
+    let dx = (a.0 - b.0).unsigned_abs();
+    let dy = (a.1 - b.1).unsigned_abs();
+    STRAIGHT_COST * dx.max(dy) + (DIAGONAL_COST - STRAIGHT_COST) * dx.min(dy)
+}
+
+/// Bounded A* from `from` towards `to`. Returns smoothed world-space waypoints, ending at the
+/// target when it was reached or at the closest reachable tile otherwise.
+fn find_path(ctx: &ReducerContext, from: (f32, f32), to: (f32, f32)) -> Option<Vec<(f32, f32)>> {
+    let start = world_to_tile(from.0, from.1);
+    let goal = world_to_tile(to.0, to.1);
+    if start == goal {
+        return None;
+    }
+
+    let mut grid = PathGrid::new(ctx, start);
+    let mut open = BinaryHeap::new();
+    let mut g_scores: HashMap<(i32, i32), u32> = HashMap::new();
+    let mut came_from: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+    let mut closed: HashSet<(i32, i32)> = HashSet::new();
+
+    g_scores.insert(start, 0);
+    open.push(Reverse((octile_heuristic(start, goal), 0u32, start)));
+    let mut best = (octile_heuristic(start, goal), start); // Closest tile to the goal seen so far
+    let mut reached_goal = false;
+
+    while let Some(Reverse((_, g, current))) = open.pop() {
+        if current == goal {
+            reached_goal = true;
+            break;
+        }
+        if !closed.insert(current) {
+            continue;
+        }
+        if closed.len() > PATH_MAX_EXPANDED_NODES {
+            break;
+        }
+
+        let h = octile_heuristic(current, goal);
+        if h < best.0 {
+            best = (h, current);
+        }
+
+        for (dx, dy) in [(1, 0), (-1, 0), (0, 1), (0, -1), (1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let next = (current.0 + dx, current.1 + dy);
+            if closed.contains(&next) || grid.is_blocked(next) {
+                continue;
+            }
+            let is_diagonal = dx != 0 && dy != 0;
+            // No cutting corners past obstacles
+            if is_diagonal && (grid.is_blocked((current.0 + dx, current.1)) || grid.is_blocked((current.0, current.1 + dy))) {
+                continue;
+            }
+            let tentative_g = g + if is_diagonal { DIAGONAL_COST } else { STRAIGHT_COST };
+            if g_scores.get(&next).map_or(true, |&existing| tentative_g < existing) {
+                g_scores.insert(next, tentative_g);
+                came_from.insert(next, current);
+                open.push(Reverse((tentative_g + octile_heuristic(next, goal), tentative_g, next)));
+            }
+        }
+    }
+
+    let end = if reached_goal { goal } else { best.1 };
+    if end == start {
+        return None;
+    }
+
+    let mut tiles = vec![end];
+    let mut current = end;
+    while let Some(&previous) = came_from.get(&current) {
+        if previous == start {
+            break;
+        }
+        tiles.push(previous);
+        current = previous;
+    }
+    tiles.reverse();
+
+    let mut points: Vec<(f32, f32)> = tiles.into_iter().map(tile_center).collect();
+    if reached_goal {
+        if let Some(last) = points.last_mut() {
+            *last = to;
+        }
+    }
+
+    // String-pull: skip waypoints that can be reached in a straight line
+    let mut smoothed = Vec::new();
+    let mut anchor = from;
+    let mut i = 0;
+    while i < points.len() {
+        let mut furthest = i;
+        for j in (i + 1)..points.len() {
+            if grid.is_segment_walkable(anchor, points[j]) {
+                furthest = j;
+            } else {
+                break;
+            }
+        }
+        smoothed.push(points[furthest]);
+        anchor = points[furthest];
+        i = furthest + 1;
+    }
+
+    Some(smoothed)
+}
@@ -0,0 +1,329 @@
+/******************************************************************************
+ *                                                                            *
+ * Wild Animal Population - Biome-aware density balancing                    *
+ *                                                                            *
+ * Replaces the old global "respawn when below 50%" check with its one-      *
+ * animal-per-chunk cap. Target densities are configured per species per     *
+ * biome in AnimalBiomeDensity (animals per 10,000 tiles of that biome), so  *
+ * the alpine gets its bears and hares, the beaches their crabs and walruses, *
+ * and no single biome fills up with whatever the dice rolled most.          *
+ *                                                                            *
+ * A scheduled census groups wild animals by species and home biome (the     *
+ * tile at their spawn point) and compares each group to its target:         *
+ *   - groups under target get a few new animals per census                  *
+ *   - groups well over target have calm animals far from players culled     *
+ * No animals are ever spawned inside a building privilege zone - bases      *
+ * shouldn't have wildlife popping into existence between their walls.       *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table};
+use spacetimedb::spacetimedb_lib::{ScheduleAt, TimeDuration};
+use rand::Rng;
+use log;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::{TILE_SIZE_PX, WORLD_WIDTH_TILES, WORLD_HEIGHT_TILES, TileType, get_tile_type_at_position, world_pos_to_tile_coords};
+use crate::environment::calculate_chunk_index;
+use crate::utils::{calculate_tile_bounds, get_distance_squared};
+use crate::homestead_hearth::building_privilege_zone as BuildingPrivilegeZoneTableTrait;
+use crate::player as PlayerTableTrait;
+use crate::world_chunk_data as WorldChunkDataTableTrait;
+
+use super::core::{AnimalSpecies, AnimalState, WildAnimal, init_wild_animal_ai_schedule, wild_animal as WildAnimalTableTrait};
+use super::respawn::{get_existing_positions, is_valid_spawn_position, spawn_animal};
+
+// --- Population Constants ---
+const CENSUS_INTERVAL_SECS: u64 = 60;
+const DENSITY_TILE_UNIT: f32 = 10_000.0; // Densities are animals per this many biome tiles
+const MAX_SPAWNS_PER_CENSUS: usize = 4; // Gradual growth - a cleared area refills over several minutes
+const MAX_CULLS_PER_CENSUS: usize = 2;
+const CULL_THRESHOLD_MULTIPLIER: f32 = 1.5; // Only cull groups well above target (breeding/migration overshoot is fine)
+const SPAWN_ATTEMPTS_PER_ANIMAL: usize = 60;
+const CULL_MIN_PLAYER_DISTANCE: f32 = 1400.0; // Never make an animal vanish in front of a player
+
+// --- Density Configuration Table ---
+#[spacetimedb::table(accessor = animal_biome_density, public)]
+#[derive(Clone, Debug)]
+pub struct AnimalBiomeDensity {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub species: AnimalSpecies,
+    pub biome: TileType,
+    pub animals_per_10k_tiles: f32,
+}
+
+// --- Biome Tile Count Cache ---
+// Counting biome tiles means decoding every chunk, so it's done once and cached
+#[spacetimedb::table(accessor = biome_tile_count)]
+#[derive(Clone, Debug)]
+pub struct BiomeTileCount {
+    #[primary_key]
+    pub biome_id: u8, // TileType::to_u8()
+    pub tile_count: u32,
+}
+
+// --- Census Schedule Table ---
+#[spacetimedb::table(accessor = animal_population_census_schedule, scheduled(process_animal_population_census))]
+#[derive(Clone)]
+pub struct AnimalPopulationCensusSchedule {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub scheduled_at: ScheduleAt,
+}
+
+/// Default target densities (animals per 10k tiles of the biome).
+/// Mirrors the habitats accepted by is_wild_animal_location_suitable.
+const DEFAULT_BIOME_DENSITIES: &[(AnimalSpecies, TileType, f32)] = &[
+    // Temperate grassland - the main hunting grounds
+    (AnimalSpecies::CinderFox, TileType::Grass, 6.0),
+    (AnimalSpecies::Vole, TileType::Grass, 4.0),
+    (AnimalSpecies::Crow, TileType::Grass, 3.0),
+    (AnimalSpecies::Caribou, TileType::Grass, 1.5),
+    (AnimalSpecies::TundraWolf, TileType::Grass, 1.5),
+    (AnimalSpecies::CableViper, TileType::Grass, 1.5),
+    (AnimalSpecies::Wolverine, TileType::Grass, 0.8),
+    // Dirt patches
+    (AnimalSpecies::CinderFox, TileType::Dirt, 4.0),
+    (AnimalSpecies::Vole, TileType::Dirt, 3.0),
+    (AnimalSpecies::CableViper, TileType::Dirt, 2.0),
+    // Forest
+    (AnimalSpecies::Vole, TileType::Forest, 3.0),
+    // Coast
+    (AnimalSpecies::BeachCrab, TileType::Beach, 10.0),
+    (AnimalSpecies::Tern, TileType::Beach, 8.0),
+    (AnimalSpecies::ArcticWalrus, TileType::Beach, 6.0),
+    (AnimalSpecies::CableViper, TileType::Beach, 1.0),
+    (AnimalSpecies::BeachCrab, TileType::Sand, 3.0),
+    // Tundra - caribou country
+    (AnimalSpecies::Caribou, TileType::Tundra, 6.0),
+    (AnimalSpecies::Vole, TileType::Tundra, 3.0),
+    (AnimalSpecies::Wolverine, TileType::Tundra, 2.0),
+    (AnimalSpecies::Caribou, TileType::TundraGrass, 6.0),
+    (AnimalSpecies::Wolverine, TileType::TundraGrass, 1.0),
+    // Alpine
+    (AnimalSpecies::Hare, TileType::Alpine, 8.0),
+    (AnimalSpecies::SnowyOwl, TileType::Alpine, 4.0),
+    (AnimalSpecies::PolarBear, TileType::Alpine, 2.0),
+    (AnimalSpecies::Caribou, TileType::Alpine, 1.5),
+    // Open water - the sea is huge, so keep these sparse
+    (AnimalSpecies::Jellyfish, TileType::Sea, 0.8),
+    (AnimalSpecies::SalmonShark, TileType::Sea, 0.5),
+];
+
+/// Initialize the population census and seed default densities if the table is empty
+pub fn init_animal_population_system(ctx: &ReducerContext) -> Result<(), String> {
+    let densities = ctx.db.animal_biome_density();
+    if densities.iter().next().is_none() {
+        for (species, biome, density) in DEFAULT_BIOME_DENSITIES {
+            densities.insert(AnimalBiomeDensity {
+                id: 0,
+                species: *species,
+                biome: biome.clone(),
+                animals_per_10k_tiles: *density,
+            });
+        }
+        log::info!("Seeded {} default animal biome densities", DEFAULT_BIOME_DENSITIES.len());
+    }
+
+    let schedule_table = ctx.db.animal_population_census_schedule();
+    if schedule_table.iter().count() == 0 {
+        log::info!("Starting animal population census (every {}s).", CENSUS_INTERVAL_SECS);
+        let interval = Duration::from_secs(CENSUS_INTERVAL_SECS);
+        crate::try_insert_schedule!(
+            schedule_table,
+            AnimalPopulationCensusSchedule {
+                id: 0,
+                scheduled_at: ScheduleAt::Interval(TimeDuration::from(interval)),
+            },
+            "Animal population census"
+        );
+    }
+    Ok(())
+}
+
+/// Whether a position is inside any player's building privilege zone.
+/// Wild animals are never spawned inside one.
+pub fn is_position_in_building_privilege_zone(ctx: &ReducerContext, x: f32, y: f32) -> bool {
+    ctx.db.building_privilege_zone().iter().any(|zone| zone.contains(x, y))
+}
+
+fn biome_at(ctx: &ReducerContext, x: f32, y: f32) -> Option<TileType> {
+    let (tile_x, tile_y) = world_pos_to_tile_coords(x, y);
+    get_tile_type_at_position(ctx, tile_x, tile_y)
+}
+
+/// Tile counts per biome, computed from chunk data on first use
+fn get_biome_tile_counts(ctx: &ReducerContext) -> HashMap<u8, u32> {
+    let cache = ctx.db.biome_tile_count();
+    if cache.iter().next().is_none() {
+        let mut counts: HashMap<u8, u32> = HashMap::new();
+        for chunk in ctx.db.world_chunk_data().iter() {
+            for &tile in &chunk.tile_types {
+                *counts.entry(tile).or_insert(0) += 1;
+            }
+        }
+        for (&biome_id, &tile_count) in &counts {
+            cache.insert(BiomeTileCount { biome_id, tile_count });
+        }
+        log::info!("Cached biome tile counts for {} biomes", counts.len());
+        return counts;
+    }
+    cache.iter().map(|row| (row.biome_id, row.tile_count)).collect()
+}
+
+/// One species/biome population group in the census
+struct CensusGroup {
+    species: AnimalSpecies,
+    biome: TileType,
+    target: usize,
+    members: Vec<WildAnimal>,
+}
+
+/// Scheduled reducer - counts wild animals per species and biome, then spawns/culls toward targets
+#[spacetimedb::reducer]
+pub fn process_animal_population_census(ctx: &ReducerContext, _args: AnimalPopulationCensusSchedule) -> Result<(), String> {
+    if ctx.sender() != ctx.identity() {
+        return Err("Animal population census can only be run by scheduler".to_string());
+    }
+    if ctx.db.world_chunk_data().iter().next().is_none() {
+        return Ok(()); // World not generated yet
+    }
+
+    let biome_tiles = get_biome_tile_counts(ctx);
+    let mut groups: Vec<CensusGroup> = ctx.db.animal_biome_density().iter()
+        .map(|row| {
+            let tiles = biome_tiles.get(&row.biome.to_u8()).copied().unwrap_or(0) as f32;
+            CensusGroup {
+                species: row.species,
+                target: (tiles * row.animals_per_10k_tiles / DENSITY_TILE_UNIT).round() as usize,
+                biome: row.biome,
+                members: Vec::new(),
+            }
+        })
+        .collect();
+
+    // Wild animals belong to the biome they call home (their spawn point)
+    for animal in ctx.db.wild_animal().iter() {
+        if animal.tamed_by.is_some() {
+            continue;
+        }
+        let Some(home_biome) = biome_at(ctx, animal.spawn_x, animal.spawn_y) else { continue };
+        if let Some(group) = groups.iter_mut().find(|g| g.species == animal.species && g.biome == home_biome) {
+            group.members.push(animal);
+        }
+    }
+
+    // Largest deficits first so sparse groups don't starve behind full ones
+    groups.sort_by_key(|g| std::cmp::Reverse(g.target.saturating_sub(g.members.len())));
+
+    let spawned = spawn_toward_targets(ctx, &groups);
+    let culled = cull_overpopulated_groups(ctx, &groups);
+
+    if spawned > 0 || culled > 0 {
+        log::info!("Animal census: spawned {}, culled {} across {} species/biome groups", spawned, culled, groups.len());
+    }
+    if spawned > 0 {
+        // Restart AI schedule if it was stopped (e.g., after database clear) so new animals move
+        if let Err(e) = init_wild_animal_ai_schedule(ctx) {
+            log::warn!("Failed to restart AI schedule after census spawns: {}", e);
+        }
+    }
+
+    Ok(())
+}
+
+fn spawn_toward_targets(ctx: &ReducerContext, groups: &[CensusGroup]) -> usize {
+    let mut existing_positions = get_existing_positions(ctx);
+    let mut rng = ctx.rng();
+    let margin_tiles = 5;
+    let (min_tile_x, max_tile_x, min_tile_y, max_tile_y) =
+        calculate_tile_bounds(WORLD_WIDTH_TILES, WORLD_HEIGHT_TILES, margin_tiles);
+
+    let mut spawned = 0;
+    for group in groups {
+        if spawned >= MAX_SPAWNS_PER_CENSUS || group.members.len() >= group.target {
+            continue;
+        }
+
+        for _ in 0..SPAWN_ATTEMPTS_PER_ANIMAL {
+            let tile_x = rng.gen_range(min_tile_x..max_tile_x);
+            let tile_y = rng.gen_range(min_tile_y..max_tile_y);
+            if get_tile_type_at_position(ctx, tile_x as i32, tile_y as i32).as_ref() != Some(&group.biome) {
+                continue;
+            }
+            let pos_x = (tile_x as f32 + 0.5) * TILE_SIZE_PX as f32;
+            let pos_y = (tile_y as f32 + 0.5) * TILE_SIZE_PX as f32;
+            if is_position_in_building_privilege_zone(ctx, pos_x, pos_y) {
+                continue;
+            }
+            if !is_valid_spawn_position(ctx, pos_x, pos_y, group.species, &existing_positions) {
+                continue;
+            }
+
+            let chunk_idx = calculate_chunk_index(pos_x, pos_y);
+            match spawn_animal(ctx, group.species, pos_x, pos_y, chunk_idx) {
+                Ok(inserted) => {
+                    spawned += 1;
+                    existing_positions.animals.push((pos_x, pos_y));
+                    log::info!("Census spawned {:?} #{} in {:?} at ({:.1}, {:.1}) [{}/{}]",
+                              group.species, inserted.id, group.biome, pos_x, pos_y, group.members.len() + 1, group.target);
+                }
+                Err(e) => log::warn!("Failed to spawn {:?} for census: {}", group.species, e),
+            }
+            break;
+        }
+    }
+    spawned
+}
+
+fn cull_overpopulated_groups(ctx: &ReducerContext, groups: &[CensusGroup]) -> usize {
+    let players: Vec<(f32, f32)> = ctx.db.player().iter()
+        .filter(|p| p.is_online && !p.is_dead)
+        .map(|p| (p.position_x, p.position_y))
+        .collect();
+
+    let mut culled = 0;
+    for group in groups {
+        let cull_threshold = (group.target as f32 * CULL_THRESHOLD_MULTIPLIER).ceil() as usize;
+        if group.members.len() <= cull_threshold {
+            continue;
+        }
+
+        let excess = group.members.len() - group.target;
+        let candidates = group.members.iter().filter(|animal| {
+            matches!(animal.state, AnimalState::Idle | AnimalState::Patrolling)
+                && animal.pack_id.is_none()
+                && animal.held_item_name.is_none()
+                && !players.iter().any(|&(px, py)| {
+                    get_distance_squared(px, py, animal.pos_x, animal.pos_y) < CULL_MIN_PLAYER_DISTANCE * CULL_MIN_PLAYER_DISTANCE
+                })
+        });
+
+        for animal in candidates.take(excess) {
+            if culled >= MAX_CULLS_PER_CENSUS {
+                return culled;
+            }
+            cull_animal(ctx, animal);
+            culled += 1;
+            log::info!("Census culled {:?} #{} from overpopulated {:?} ({}/{})",
+                      animal.species, animal.id, group.biome, group.members.len(), group.target);
+        }
+    }
+    culled
+}
+
+/// Removes a wild animal from the world along with its per-animal side tables
+pub fn cull_animal(ctx: &ReducerContext, animal: &WildAnimal) {
+    match animal.species {
+        AnimalSpecies::Caribou => super::caribou::cleanup_caribou_breeding_data(ctx, animal.id),
+        AnimalSpecies::ArcticWalrus => super::walrus::cleanup_walrus_breeding_data(ctx, animal.id),
+        _ => {}
+    }
+    super::pathfinding::clear_animal_path(ctx, animal.id);
+    ctx.db.wild_animal().id().delete(&animal.id);
+    crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(animal.id));
+}
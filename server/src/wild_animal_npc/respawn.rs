@@ -268,156 +268,8 @@ pub fn maintain_spawn_zone_populations(ctx: &ReducerContext) -> Result<(), Strin
     Ok(())
 }
 
-/// Maintains minimum wild animal population levels by spawning new animals when population drops too low.
-/// Uses similar validation logic to resource respawning with collision detection.
-/// Implements a Rust-like gradual respawn system rather than instant full population.
-pub fn maintain_wild_animal_population(ctx: &ReducerContext) -> Result<(), String> {
-    let wild_animals = ctx.db.wild_animal();
-    let current_animal_count = wild_animals.iter().count();
-    
-    // Population thresholds - SCALED with map size for consistency
-    // Same density as initial seeding in environment.rs (0.0002 = 0.02% of tiles)
-    const WILD_ANIMAL_DENSITY: f32 = 0.00025; // Slightly higher than seeding to maintain population
-    let total_tiles = (WORLD_WIDTH_TILES * WORLD_HEIGHT_TILES) as f32;
-    let target_population = (total_tiles * WILD_ANIMAL_DENSITY) as usize;
-    let min_population_threshold = target_population / 2; // Start respawning when below 50% of target
-    const MAX_RESPAWN_PER_CYCLE: usize = 3; // Max animals to spawn per respawn cycle (reduced for gradual growth)
-    
-    // Only respawn if population is below minimum threshold
-    if current_animal_count >= min_population_threshold {
-        log::debug!("Wild animal population ({}) above minimum threshold ({}). No respawn needed.", 
-                   current_animal_count, min_population_threshold);
-        return Ok(());
-    }
-    
-    let animals_needed = (target_population - current_animal_count).min(MAX_RESPAWN_PER_CYCLE);
-    log::info!("Wild animal population low ({}/{}). Attempting to spawn {} animals.", 
-               current_animal_count, target_population, animals_needed);
-    
-    // Species distribution (same as initial seeding)
-    let species_weights = [
-        (AnimalSpecies::CinderFox, 17),      // 17% - Common (reduced to make room for aquatic)
-        (AnimalSpecies::ArcticWalrus, 10),   // 10% - Common (beaches only)
-        (AnimalSpecies::BeachCrab, 13),      // 13% - Common beach creature
-        (AnimalSpecies::TundraWolf, 5),      // 5% - RARE predator
-        (AnimalSpecies::CableViper, 5),      // 5% - RARE ambush predator
-        (AnimalSpecies::Tern, 10),           // 10% - Coastal scavenger bird (beaches)
-        (AnimalSpecies::Crow, 8),            // 8% - Inland thief bird
-        (AnimalSpecies::Vole, 16),           // 16% - Common prey animal (tundra/grassland/forest)
-        (AnimalSpecies::Wolverine, 6),       // 6% - Uncommon aggressive predator
-        (AnimalSpecies::Caribou, 10),        // 10% - Tundra/alpine herd animal
-        // Aquatic animals - REQUIRE water tiles to spawn
-        (AnimalSpecies::SalmonShark, 4),     // 4% - RARE aquatic apex predator (deep water only)
-        (AnimalSpecies::Jellyfish, 5),       // 5% - Uncommon aquatic hazard (deep water only)
-        // Alpine animals
-        (AnimalSpecies::PolarBear, 3),       // 3% - RARE alpine apex predator
-        (AnimalSpecies::Hare, 10),           // 10% - Common alpine prey animal
-        (AnimalSpecies::SnowyOwl, 5),        // 5% - Uncommon alpine flying predator
-    ];
-    let total_weight: u32 = species_weights.iter().map(|(_, weight)| weight).sum();
-    
-    // Get existing positions for collision avoidance
-    let existing_positions = get_existing_positions(ctx);
-    
-    // Track animals spawned per chunk to maintain distribution
-    let mut animals_per_chunk_map = get_animals_per_chunk_map(ctx);
-    
-    let mut spawned_count = 0;
-    let max_spawn_attempts = animals_needed * 20; // 20 attempts per needed animal
-    let mut spawn_attempts = 0;
-    
-    // Calculate spawn bounds (avoid world edges)
-    let margin_tiles = 5;
-    let (min_tile_x, max_tile_x, min_tile_y, max_tile_y) = 
-        calculate_tile_bounds(WORLD_WIDTH_TILES, WORLD_HEIGHT_TILES, margin_tiles);
-    
-    while spawned_count < animals_needed && spawn_attempts < max_spawn_attempts {
-        spawn_attempts += 1;
-        
-        // Choose species using weighted random selection
-        let chosen_species = choose_random_species(&species_weights, total_weight, &mut ctx.rng());
-        
-        // Aquatic animals (sharks, jellyfish) ONLY spawn on Sea tiles. Retry until we find water.
-        let is_aquatic = matches!(chosen_species, AnimalSpecies::SalmonShark | AnimalSpecies::Jellyfish);
-        const MAX_AQUATIC_RESPAWN_ATTEMPTS: u32 = 150;
-        
-        let (pos_x, pos_y) = if is_aquatic {
-            let mut rng = ctx.rng();
-            let mut found = None;
-            for _ in 0..MAX_AQUATIC_RESPAWN_ATTEMPTS {
-                let tile_x = rng.gen_range(min_tile_x..max_tile_x);
-                let tile_y = rng.gen_range(min_tile_y..max_tile_y);
-                let px = (tile_x as f32 + 0.5) * TILE_SIZE_PX as f32;
-                let py = (tile_y as f32 + 0.5) * TILE_SIZE_PX as f32;
-                if is_wild_animal_location_suitable(ctx, px, py, chosen_species, &existing_positions.trees)
-                    && is_position_on_water(ctx, px, py)
-                {
-                    found = Some((px, py));
-                    break;
-                }
-            }
-            match found {
-                Some(p) => p,
-                None => continue, // No valid water tile; skip this respawn attempt
-            }
-        } else {
-            let tile_x = ctx.rng().gen_range(min_tile_x..max_tile_x);
-            let tile_y = ctx.rng().gen_range(min_tile_y..max_tile_y);
-            let pos_x = (tile_x as f32 + 0.5) * TILE_SIZE_PX as f32;
-            let pos_y = (tile_y as f32 + 0.5) * TILE_SIZE_PX as f32;
-            (pos_x, pos_y)
-        };
-        
-        // Calculate chunk index and check distribution
-        let chunk_idx = calculate_chunk_index(pos_x, pos_y);
-        let current_animals_in_chunk = animals_per_chunk_map.get(&chunk_idx).copied().unwrap_or(0);
-        
-        // Skip if chunk already has too many animals (maintain distribution)
-        if current_animals_in_chunk >= 1 { // Max 1 animal per chunk (reduced for performance)
-            continue;
-        }
-        
-        // Validate spawn position
-        if !is_valid_spawn_position(ctx, pos_x, pos_y, chosen_species, &existing_positions) {
-            continue;
-        }
-        
-        // Create and spawn the animal
-        match spawn_animal(ctx, chosen_species, pos_x, pos_y, chunk_idx) {
-            Ok(inserted_animal) => {
-                spawned_count += 1;
-                animals_per_chunk_map.insert(chunk_idx, current_animals_in_chunk + 1);
-                log::info!("Respawned {:?} #{} at ({:.1}, {:.1}) in chunk {} [population: {}/{}]", 
-                          chosen_species, inserted_animal.id, pos_x, pos_y, chunk_idx, 
-                          current_animal_count + spawned_count, target_population);
-            }
-            Err(e) => {
-                log::warn!("Failed to respawn wild animal (attempt {}): {}. Continuing.", spawn_attempts, e);
-            }
-        }
-    }
-    
-    if spawned_count > 0 {
-        log::info!("Wild animal respawn cycle complete: spawned {} animals in {} attempts (population: {}/{})", 
-                   spawned_count, spawn_attempts, current_animal_count + spawned_count, target_population);
-        
-        // CRITICAL FIX: Restart AI schedule if it was stopped (e.g., after database clear)
-        // This ensures animals will actually move and function after spawning
-        if let Err(e) = init_wild_animal_ai_schedule(ctx) {
-            log::warn!("Failed to restart AI schedule after spawning animals: {}. Animals may not move until next AI tick.", e);
-        } else {
-            log::debug!("AI schedule restarted after spawning {} animals", spawned_count);
-        }
-    } else if animals_needed > 0 {
-        log::warn!("Failed to spawn any animals this cycle despite low population ({}/{}). Will retry next cycle.", 
-                   current_animal_count, target_population);
-    }
-
-    Ok(())
-}
-
 /// Get existing positions for collision checking
-fn get_existing_positions(ctx: &ReducerContext) -> ExistingPositions {
+pub(super) fn get_existing_positions(ctx: &ReducerContext) -> ExistingPositions {
     let existing_animal_positions: Vec<(f32, f32)> = ctx.db.wild_animal().iter()
         .map(|animal| (animal.pos_x, animal.pos_y))
         .collect();
@@ -439,38 +291,8 @@ fn get_existing_positions(ctx: &ReducerContext) -> ExistingPositions {
     }
 }
 
-/// Track animals per chunk for distribution
-fn get_animals_per_chunk_map(ctx: &ReducerContext) -> std::collections::HashMap<u32, u32> {
-    let mut animals_per_chunk_map: std::collections::HashMap<u32, u32> = std::collections::HashMap::new();
-    for animal in ctx.db.wild_animal().iter() {
-        let count = animals_per_chunk_map.get(&animal.chunk_index).copied().unwrap_or(0);
-        animals_per_chunk_map.insert(animal.chunk_index, count + 1);
-    }
-    animals_per_chunk_map
-}
-
-/// Choose random species based on weights
-fn choose_random_species(
-    species_weights: &[(AnimalSpecies, u32)],
-    total_weight: u32,
-    rng: &mut impl Rng,
-) -> AnimalSpecies {
-    let species_roll = rng.gen_range(0..total_weight);
-    let mut cumulative_weight = 0;
-    
-    for &(species, weight) in species_weights {
-        cumulative_weight += weight;
-        if species_roll < cumulative_weight {
-            return species;
-        }
-    }
-    
-    // Fallback (shouldn't happen)
-    AnimalSpecies::CinderFox
-}
-
 /// Validate if position is suitable for spawning
-fn is_valid_spawn_position(
+pub(super) fn is_valid_spawn_position(
     ctx: &ReducerContext,
     pos_x: f32,
     pos_y: f32,
@@ -559,7 +381,7 @@ fn is_valid_spawn_position(
 }
 
 /// Create and spawn a new animal
-fn spawn_animal(
+pub(super) fn spawn_animal(
     ctx: &ReducerContext,
     species: AnimalSpecies,
     pos_x: f32,
@@ -577,7 +399,7 @@ fn spawn_animal(
 
 /// Spawn herd animals (caribou, walrus) in breeding-viable groups
 /// Ensures at least one male and one female adult per group
-fn spawn_herd_animal_group(
+pub fn spawn_herd_animal_group(
     ctx: &ReducerContext,
     species: AnimalSpecies,
     pos_x: f32,
@@ -790,6 +612,7 @@ fn spawn_single_animal(
     // Attempt to spawn the animal
     let inserted = ctx.db.wild_animal().try_insert(new_animal)
         .map_err(|e| e.to_string())?;
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
     
     // NOTE: For herd animals (caribou, walrus), sex assignment is handled by spawn_herd_animal_group
     // to ensure breeding viability. For individual spawns of non-herd animals, no sex assignment needed.
@@ -798,8 +621,8 @@ fn spawn_single_animal(
 }
 
 /// Helper struct to organize existing positions
-struct ExistingPositions {
-    animals: Vec<(f32, f32)>,
-    trees: Vec<(f32, f32)>,
-    stones: Vec<(f32, f32)>,
+pub(super) struct ExistingPositions {
+    pub(super) animals: Vec<(f32, f32)>,
+    pub(super) trees: Vec<(f32, f32)>,
+    pub(super) stones: Vec<(f32, f32)>,
 } 
\ No newline at end of file
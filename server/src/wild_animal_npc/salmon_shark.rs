@@ -13,6 +13,7 @@
  *   - Persistent: Rarely abandons chase (10x normal multiplier)            *
  *   - Lone hunter: Does not herd or group                                   *
  *   - Cannot be tamed                                                        *
+ *   - Blood in the water: bleeding players in water are sensed at 3x range  *
  *                                                                            *
  * COMBAT BALANCE:                                                            *
  *   - Primary counter: Reed Harpoon Gun/Darts                               *
@@ -40,6 +41,7 @@ use super::core::{
 // Salmon Shark constants
 const SHARK_PATROL_WATER_CHECK_RADIUS: i32 = 3; // Check tiles within 3 tile radius for water
 const SHARK_CHASE_WATER_RANGE: f32 = 1200.0; // Will chase players up to 1200px while in water
+pub const SHARK_BLOOD_SCENT_PERCEPTION_MULTIPLIER: f32 = 3.0; // Bleeding players in water are sensed at 3x perception range
 
 pub struct SalmonSharkBehavior;
 
@@ -132,7 +134,14 @@ impl AnimalBehavior for SalmonSharkBehavior {
                 if let Some(player) = detected_player {
                     let distance = get_player_distance(animal, player);
                     
-                    if distance <= stats.chase_trigger_range {
+                    // 🩸 Blood in the water: a bleeding swimmer draws the shark in from its full scent range
+                    let smells_blood = is_blood_in_water(ctx, player);
+                    if smells_blood {
+                        log::info!("🦈🩸 Salmon Shark {} smells blood from player {} at {:.1}px!", 
+                                  animal.id, player.identity, distance);
+                    }
+                    
+                    if distance <= stats.chase_trigger_range || smells_blood {
                         // Chase the player - sharks attack both snorkeling and surface players
                         transition_to_state(animal, AnimalState::SwimmingChase, current_time, Some(player.identity), "prey detected");
                         
@@ -224,6 +233,9 @@ impl AnimalBehavior for SalmonSharkBehavior {
         }
         
         let distance = get_player_distance(animal, player);
+        if is_blood_in_water(ctx, player) {
+            return distance <= stats.perception_range * SHARK_BLOOD_SCENT_PERCEPTION_MULTIPLIER;
+        }
         distance <= stats.chase_trigger_range
     }
 
@@ -260,6 +272,12 @@ impl AnimalBehavior for SalmonSharkBehavior {
 // Helper functions for water-based behavior
 // ============================================================================
 
+/// Blood in the water: the player is bleeding while in the water, so sharks can scent them from afar
+pub fn is_blood_in_water(ctx: &ReducerContext, player: &Player) -> bool {
+    (player.is_on_water || player.is_snorkeling)
+        && crate::active_effects::player_has_bleed_effect(ctx, player.identity)
+}
+
 /// Check if a position is on a water tile (Sea)
 fn is_position_on_water(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
     let tile_x = (pos_x / crate::TILE_SIZE_PX as f32).floor() as i32;
@@ -0,0 +1,147 @@
+/******************************************************************************
+ *                                                                            *
+ * Scent - Raw Meat Attracts Predators                                       *
+ *                                                                            *
+ * Predators (Tundra Wolf, Wolverine, Salmon Shark) can smell raw meat and   *
+ * raw fish:                                                                  *
+ *   - Carrying any in your inventory or hotbar stretches their perception   *
+ *     range toward you, from any direction (no vision cone). The set of     *
+ *     scented players is built once per AI tick in PreFetchedAIData.        *
+ *   - Raw meat dropped on the ground leaves a scent marker that lingers    *
+ *     after the meat is picked up. Land predators that haven't spotted a    *
+ *     player walk over to investigate the nearest one.                      *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp, TimeDuration};
+use std::collections::HashSet;
+use log;
+
+use crate::Player;
+use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
+use crate::models::ItemLocation;
+use crate::utils::get_distance_squared;
+
+use super::core::AnimalSpecies;
+
+/// Predator perception multiplier toward players carrying raw meat
+pub const MEAT_SCENT_PERCEPTION_MULTIPLIER: f32 = 1.75;
+/// How long a dropped-meat scent marker lingers
+pub const SCENT_MARKER_DURATION_SECS: i64 = 300;
+/// How far away land predators can smell a scent marker
+pub const SCENT_MARKER_DETECTION_RADIUS: f32 = 700.0;
+/// Predators already this close to a marker prowl around it instead of walking back to it
+const SCENT_MARKER_PROWL_RADIUS: f32 = 150.0;
+/// A new marker this close to an existing one refreshes it instead of stacking
+const SCENT_MARKER_MERGE_RADIUS: f32 = 64.0;
+
+/// Raw items that aren't meat or fish
+const RAW_NON_MEAT_ITEMS: [&str; 2] = ["Raw Corn", "Raw Milk"];
+
+/// Dropped-meat scent markers (server-only - clients don't need to see them)
+#[spacetimedb::table(accessor = scent_marker)]
+#[derive(Clone, Debug)]
+pub struct ScentMarker {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub expires_at: Timestamp,
+}
+
+/// Whether an item is raw meat or raw fish
+pub fn is_raw_meat_or_fish(item_name: &str) -> bool {
+    item_name.starts_with("Raw ") && !RAW_NON_MEAT_ITEMS.contains(&item_name)
+}
+
+/// Species that pick up the scent of raw meat
+pub fn is_scent_predator(species: AnimalSpecies) -> bool {
+    matches!(species, AnimalSpecies::TundraWolf | AnimalSpecies::Wolverine | AnimalSpecies::SalmonShark)
+}
+
+/// Species that follow scent markers on the ground (sharks can't leave the water)
+pub fn follows_scent_markers(species: AnimalSpecies) -> bool {
+    matches!(species, AnimalSpecies::TundraWolf | AnimalSpecies::Wolverine)
+}
+
+/// Players carrying raw meat or fish in their inventory or hotbar (one item table scan per AI tick)
+pub fn collect_meat_scented_players(ctx: &ReducerContext, players: &[Player]) -> HashSet<Identity> {
+    let mut scented = HashSet::new();
+    if players.is_empty() {
+        return scented;
+    }
+
+    let raw_meat_def_ids: HashSet<u64> = ctx.db.item_definition().iter()
+        .filter(|def| is_raw_meat_or_fish(&def.name))
+        .map(|def| def.id)
+        .collect();
+    let player_ids: HashSet<Identity> = players.iter().map(|p| p.identity).collect();
+
+    for item in ctx.db.inventory_item().iter() {
+        if !raw_meat_def_ids.contains(&item.item_def_id) {
+            continue;
+        }
+        let owner_id = match &item.location {
+            ItemLocation::Inventory(data) => data.owner_id,
+            ItemLocation::Hotbar(data) => data.owner_id,
+            _ => continue,
+        };
+        if player_ids.contains(&owner_id) {
+            scented.insert(owner_id);
+        }
+    }
+    scented
+}
+
+/// Leaves a scent marker where raw meat was dropped (called when dropped items are created)
+pub fn leave_scent_if_raw_meat(ctx: &ReducerContext, item_def_id: u64, pos_x: f32, pos_y: f32) {
+    let is_meat = ctx.db.item_definition().id().find(item_def_id)
+        .map(|def| is_raw_meat_or_fish(&def.name))
+        .unwrap_or(false);
+    if !is_meat {
+        return;
+    }
+
+    let markers = ctx.db.scent_marker();
+    let expires_at = ctx.timestamp + TimeDuration::from_micros(SCENT_MARKER_DURATION_SECS * 1_000_000);
+
+    // Expired markers are cleaned up lazily whenever a new one is left
+    let expired_ids: Vec<u64> = markers.iter()
+        .filter(|marker| marker.expires_at <= ctx.timestamp)
+        .map(|marker| marker.id)
+        .collect();
+    for id in expired_ids {
+        markers.id().delete(id);
+    }
+
+    let merge_radius_sq = SCENT_MARKER_MERGE_RADIUS * SCENT_MARKER_MERGE_RADIUS;
+    if let Some(mut existing) = markers.iter()
+        .find(|marker| get_distance_squared(marker.pos_x, marker.pos_y, pos_x, pos_y) <= merge_radius_sq)
+    {
+        existing.expires_at = expires_at;
+        markers.id().update(existing);
+        return;
+    }
+
+    markers.insert(ScentMarker { id: 0, pos_x, pos_y, expires_at });
+    log::debug!("[Scent] Raw meat dropped at ({:.1}, {:.1}) - scent marker created", pos_x, pos_y);
+}
+
+/// Active scent markers (pre-fetched once per AI tick)
+pub fn collect_active_scent_markers(ctx: &ReducerContext) -> Vec<ScentMarker> {
+    ctx.db.scent_marker().iter()
+        .filter(|marker| marker.expires_at > ctx.timestamp)
+        .collect()
+}
+
+/// Nearest scent marker within smelling range of a position (ignoring any the animal is already prowling around)
+pub fn find_nearest_scent_marker(markers: &[ScentMarker], x: f32, y: f32) -> Option<&ScentMarker> {
+    let radius_sq = SCENT_MARKER_DETECTION_RADIUS * SCENT_MARKER_DETECTION_RADIUS;
+    let prowl_radius_sq = SCENT_MARKER_PROWL_RADIUS * SCENT_MARKER_PROWL_RADIUS;
+    markers.iter()
+        .map(|marker| (marker, get_distance_squared(marker.pos_x, marker.pos_y, x, y)))
+        .filter(|(_, dist_sq)| *dist_sq <= radius_sq && *dist_sq > prowl_radius_sq)
+        .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(marker, _)| marker)
+}
@@ -93,7 +93,8 @@ impl AnimalBehavior for ShardkinBehavior {
         rng: &mut impl Rng,
     ) -> Result<(), String> {
         match animal.state {
-            AnimalState::Idle | AnimalState::Patrolling => {
+            // Investigating = drawn to a distraction (thrown stone); players still take priority
+            AnimalState::Idle | AnimalState::Patrolling | AnimalState::Investigating => {
                 if let Some(player) = detected_player {
                     // FLASHLIGHT HESITATION: If in player's flashlight beam, don't escalate to chasing
                     // The light keeps apparitions hesitant - they stay in patrol mode
@@ -108,6 +109,8 @@ impl AnimalBehavior for ShardkinBehavior {
                     transition_to_state(animal, AnimalState::Chasing, current_time, Some(player.identity), "spotted player - immediate chase");
                     emit_species_sound(ctx, animal, player.identity, "chase_start");
                     log::debug!("Shardkin {} immediately chasing player {}", animal.id, player.identity);
+                } else if animal.state == AnimalState::Investigating && animal.investigation_x.is_none() {
+                    transition_to_state(animal, AnimalState::Patrolling, current_time, None, "distraction investigated");
                 }
             },
             
@@ -103,7 +103,8 @@ impl AnimalBehavior for ShoreboundBehavior {
         rng: &mut impl Rng,
     ) -> Result<(), String> {
         match animal.state {
-            AnimalState::Idle | AnimalState::Patrolling => {
+            // Investigating = drawn to a distraction (thrown stone); players still take priority
+            AnimalState::Idle | AnimalState::Patrolling | AnimalState::Investigating => {
                 if let Some(player) = detected_player {
                     // Transition to stalking instead of immediate chase
                     transition_to_state(animal, AnimalState::Stalking, current_time, Some(player.identity), "detected player - begin stalking");
@@ -118,6 +119,8 @@ impl AnimalBehavior for ShoreboundBehavior {
                     emit_species_sound(ctx, animal, player.identity, "chase_start");
                     
                     log::debug!("Shorebound {} begins stalking player {}", animal.id, player.identity);
+                } else if animal.state == AnimalState::Investigating && animal.investigation_x.is_none() {
+                    transition_to_state(animal, AnimalState::Patrolling, current_time, None, "distraction investigated");
                 }
             },
             
@@ -28,6 +28,7 @@ use super::core::{
     get_player_distance, execute_flying_patrol, execute_grounded_idle, execute_flying_chase,
     is_bird_flying, update_facing_direction, set_flee_destination_away_from_threat,
 };
+use super::corpse_feeding::{try_start_corpse_feeding, update_corpse_feeding, is_feeding, clear_feeding_target};
 
 /// Scavenging constants
 const SCAVENGE_DETECTION_RADIUS: f32 = 200.0; // How far terns can detect dropped items
@@ -140,6 +141,11 @@ impl AnimalBehavior for TernBehavior {
                     if animal.target_player_id.is_some() {
                         animal.target_player_id = None;
                     }
+                    
+                    // 🦴 Pick at any unattended corpse nearby
+                    if try_start_corpse_feeding(ctx, animal, current_time, rng) {
+                        return Ok(());
+                    }
                 }
                 // Normal walking/flying behavior handled by core movement system based on is_flying flag
             },
@@ -185,6 +191,20 @@ impl AnimalBehavior for TernBehavior {
                 }
             },
             
+            AnimalState::Investigating => {
+                // 🦴 Feeding on an unattended corpse - a player coming close scares it off
+                if let Some(player) = detected_player.filter(|p| get_player_distance(animal, p) < 150.0) {
+                    clear_feeding_target(animal);
+                    animal.is_flying = true;
+                    set_flee_destination_away_from_threat(animal, player.position_x, player.position_y, 400.0, rng);
+                    transition_to_state(animal, AnimalState::Fleeing, current_time, None, "spooked off corpse");
+                } else if is_feeding(animal) {
+                    update_corpse_feeding(ctx, animal, current_time);
+                } else if animal.investigation_x.is_none() {
+                    transition_to_state(animal, AnimalState::Patrolling, current_time, None, "nothing to investigate");
+                }
+            },
+            
             AnimalState::Fleeing => {
                 // Terns always flee by flying
                 animal.is_flying = true;
@@ -295,6 +315,7 @@ impl AnimalBehavior for TernBehavior {
         rng: &mut impl Rng,
     ) -> Result<(), String> {
         // Terns always flee when damaged - drop held item and fly away
+        clear_feeding_target(animal);
         if animal.held_item_name.is_some() {
             // Drop the held item
             drop_held_item(ctx, animal, current_time);
@@ -360,7 +381,8 @@ fn find_nearby_dropped_item(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> Opt
     for item in ctx.db.dropped_item().iter() {
         let distance_sq = get_distance_squared(pos_x, pos_y, item.pos_x, item.pos_y);
         
-        if distance_sq < closest_distance_sq {
+        // 🎃 Scarecrows keep terns off items dropped nearby (same deterrence as crows)
+        if distance_sq < closest_distance_sq && !super::crow::is_position_near_scarecrow(ctx, item.pos_x, item.pos_y) {
             closest_distance_sq = distance_sq;
             closest_item = Some((item.id as u64, item.pos_x, item.pos_y));
         }
@@ -389,6 +411,7 @@ fn try_pickup_dropped_item(ctx: &ReducerContext, target_x: f32, target_y: f32) -
             
             // Delete the dropped item from the world
             if ctx.db.dropped_item().id().delete(&item.id) {
+                crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::DroppedItem(item.id));
                 log::info!("Tern scavenged dropped item: {} x{} (ID: {})", item_name, quantity, item.id);
                 return Some((item_name, quantity));
             }
@@ -0,0 +1,263 @@
+/******************************************************************************
+ *                                                                            *
+ * Animal Transform Batching - Per-chunk position feed for wild animals       *
+ *                                                                            *
+ * Rewriting every moving WildAnimal row each AI tick floods subscribers.    *
+ * With batching enabled, the AI tick publishes positions and states as one  *
+ * packed row per chunk (animal_transform_batch), and the full WildAnimal    *
+ * row is only written when something other than the transform changes or   *
+ * the animal drifts FULL_SYNC_DISTANCE_PX from its stored position.         *
+ *                                                                            *
+ * Each batched animal's live position is also kept in a private per-animal *
+ * row (animal_live_position) that the AI tick and every other position     *
+ * reader goes through (with_live_position / live_position). It records the *
+ * row position it was based on, so when something outside the AI tick      *
+ * moves the row (knockback, migration...) the row wins and the entry is    *
+ * reseeded from it. Disabling batching flushes positions into the rows.    *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{client_visibility_filter, Filter, ReducerContext, Table, Timestamp};
+use std::collections::HashSet;
+use log;
+
+use super::core::{AnimalState, WildAnimal};
+use crate::wild_animal_npc::wild_animal as WildAnimalTableTrait;
+
+/// Stored rows are resynced once an animal's batched position drifts this far from them
+pub const FULL_SYNC_DISTANCE_PX: f32 = 96.0;
+
+/// Packed transforms for every batched animal in one chunk (parallel arrays)
+#[spacetimedb::table(accessor = animal_transform_batch, public)]
+#[derive(Clone, Debug)]
+pub struct AnimalTransformBatch {
+    #[primary_key]
+    pub chunk_index: u32,
+    pub animal_ids: Vec<u64>,
+    pub pos_x: Vec<f32>,
+    pub pos_y: Vec<f32>,
+    pub states: Vec<AnimalState>,
+    pub updated_at: Timestamp,
+}
+
+// Batches stream by viewport like the animal rows themselves (see chunk_streaming)
+#[client_visibility_filter]
+const ANIMAL_BATCHES_IN_VIEWPORT: Filter = Filter::Sql(
+    "SELECT animal_transform_batch.* FROM animal_transform_batch JOIN client_visible_chunk ON animal_transform_batch.chunk_index = client_visible_chunk.chunk_index WHERE client_visible_chunk.client_identity = :sender"
+);
+
+/// Whether animal transforms are batched (singleton, id 0)
+#[spacetimedb::table(accessor = animal_transform_settings, public)]
+#[derive(Clone, Debug)]
+pub struct AnimalTransformSettings {
+    #[primary_key]
+    pub id: u32,
+    pub batching_enabled: bool,
+}
+
+/// Live position of a batched animal (private: clients read the batches)
+#[spacetimedb::table(accessor = animal_live_position)]
+#[derive(Clone, Debug)]
+pub struct AnimalLivePosition {
+    #[primary_key]
+    pub animal_id: u64,
+    #[index(btree)]
+    pub chunk_index: u32,
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub state: AnimalState,
+    /// WildAnimal row position this entry was based on; a different row position means the row was moved since
+    pub row_pos_x: f32,
+    pub row_pos_y: f32,
+}
+
+impl AnimalLivePosition {
+    /// An AI-tick result, based on the row position as it is after the tick
+    pub fn new(animal: &WildAnimal, row_pos_x: f32, row_pos_y: f32) -> Self {
+        Self {
+            animal_id: animal.id,
+            chunk_index: animal.chunk_index,
+            pos_x: animal.pos_x,
+            pos_y: animal.pos_y,
+            state: animal.state,
+            row_pos_x,
+            row_pos_y,
+        }
+    }
+
+    fn matches_row(&self, animal: &WildAnimal) -> bool {
+        self.row_pos_x == animal.pos_x && self.row_pos_y == animal.pos_y
+    }
+}
+
+pub fn is_batching_enabled(ctx: &ReducerContext) -> bool {
+    ctx.db.animal_transform_settings().id().find(0).is_some_and(|s| s.batching_enabled)
+}
+
+/// Applies an animal's live position on top of its stored row (no-op when it isn't batched or the row was moved since)
+pub fn apply_batched_transform(ctx: &ReducerContext, animal: &mut WildAnimal) {
+    if let Some(live) = ctx.db.animal_live_position().animal_id().find(animal.id) {
+        if live.matches_row(animal) {
+            animal.pos_x = live.pos_x;
+            animal.pos_y = live.pos_y;
+            animal.chunk_index = live.chunk_index;
+        }
+    }
+}
+
+/// A WildAnimal row with its live position - use this wherever a row's position is read outside the AI tick
+pub fn with_live_position(ctx: &ReducerContext, mut animal: WildAnimal) -> WildAnimal {
+    apply_batched_transform(ctx, &mut animal);
+    animal
+}
+
+/// An animal's live (pos_x, pos_y)
+pub fn live_position(ctx: &ReducerContext, animal: &WildAnimal) -> (f32, f32) {
+    match ctx.db.animal_live_position().animal_id().find(animal.id) {
+        Some(live) if live.matches_row(animal) => (live.pos_x, live.pos_y),
+        _ => (animal.pos_x, animal.pos_y),
+    }
+}
+
+/// Animals whose live position is in a chunk (the row's chunk_index lags behind while batched)
+pub fn live_animals_in_chunk(ctx: &ReducerContext, chunk_index: u32) -> Vec<WildAnimal> {
+    let mut animals: Vec<WildAnimal> = ctx.db.wild_animal().chunk_index().filter(chunk_index)
+        .map(|row| with_live_position(ctx, row))
+        .filter(|animal| animal.chunk_index == chunk_index)
+        .collect();
+    for live in ctx.db.animal_live_position().chunk_index().filter(chunk_index) {
+        if let Some(row) = ctx.db.wild_animal().id().find(live.animal_id) {
+            if row.chunk_index != chunk_index && live.matches_row(&row) {
+                animals.push(with_live_position(ctx, row));
+            }
+        }
+    }
+    animals
+}
+
+/// Reseeds an animal's batch entry from its row after the row was moved outside the AI tick,
+/// so clients see the new position right away instead of on the animal's next AI tick
+pub fn reseed_batched_transform(ctx: &ReducerContext, animal: &WildAnimal) {
+    let live_positions = ctx.db.animal_live_position();
+    let Some(live) = live_positions.animal_id().find(animal.id) else {
+        return; // Not batched
+    };
+    let old_chunk = live.chunk_index;
+    live_positions.animal_id().update(AnimalLivePosition::new(animal, animal.pos_x, animal.pos_y));
+    rebuild_batch(ctx, old_chunk, ctx.timestamp);
+    if animal.chunk_index != old_chunk {
+        rebuild_batch(ctx, animal.chunk_index, ctx.timestamp);
+    }
+}
+
+/// Whether an AI-tick change must be written to the WildAnimal row rather than only the batch
+pub fn needs_full_sync(stored: &WildAnimal, updated: &WildAnimal) -> bool {
+    let dx = updated.pos_x - stored.pos_x;
+    let dy = updated.pos_y - stored.pos_y;
+    if dx * dx + dy * dy > FULL_SYNC_DISTANCE_PX * FULL_SYNC_DISTANCE_PX {
+        return true;
+    }
+    // Compare everything except the transform itself
+    let mut masked = updated.clone();
+    masked.pos_x = stored.pos_x;
+    masked.pos_y = stored.pos_y;
+    masked.direction_x = stored.direction_x;
+    masked.direction_y = stored.direction_y;
+    masked.facing_direction = stored.facing_direction.clone();
+    masked.chunk_index = stored.chunk_index;
+    masked != *stored
+}
+
+/// Repacks one chunk's batch from the live positions in it, dropping animals that no longer exist
+fn rebuild_batch(ctx: &ReducerContext, chunk_index: u32, now: Timestamp) {
+    let live_positions = ctx.db.animal_live_position();
+    let mut entries = Vec::new();
+    for live in live_positions.chunk_index().filter(chunk_index) {
+        if ctx.db.wild_animal().id().find(live.animal_id).is_some() {
+            entries.push(live);
+        } else {
+            live_positions.animal_id().delete(live.animal_id);
+        }
+    }
+
+    let batches = ctx.db.animal_transform_batch();
+    if entries.is_empty() {
+        batches.chunk_index().delete(chunk_index);
+        return;
+    }
+    let batch = AnimalTransformBatch {
+        chunk_index,
+        animal_ids: entries.iter().map(|e| e.animal_id).collect(),
+        pos_x: entries.iter().map(|e| e.pos_x).collect(),
+        pos_y: entries.iter().map(|e| e.pos_y).collect(),
+        states: entries.iter().map(|e| e.state).collect(),
+        updated_at: now,
+    };
+    if batches.chunk_index().find(chunk_index).is_some() {
+        batches.chunk_index().update(batch);
+    } else {
+        batches.insert(batch);
+    }
+}
+
+/// Stores the live positions processed this tick and rewrites the batches they touched:
+/// the chunks those animals are in now or were in before. Other animals in those chunks are carried over.
+pub fn write_batches(ctx: &ReducerContext, processed: Vec<AnimalLivePosition>) {
+    if processed.is_empty() {
+        return;
+    }
+    let live_positions = ctx.db.animal_live_position();
+    let mut touched_chunks: HashSet<u32> = HashSet::new();
+    for live in processed {
+        touched_chunks.insert(live.chunk_index);
+        if let Some(old) = live_positions.animal_id().find(live.animal_id) {
+            touched_chunks.insert(old.chunk_index); // Drop it from the chunk it left
+            live_positions.animal_id().update(live);
+        } else {
+            live_positions.insert(live);
+        }
+    }
+    for chunk_index in touched_chunks {
+        rebuild_batch(ctx, chunk_index, ctx.timestamp);
+    }
+}
+
+/// Writes every live position back into the WildAnimal rows and empties the batch tables
+fn flush_batches(ctx: &ReducerContext) {
+    let live_entries: Vec<AnimalLivePosition> = ctx.db.animal_live_position().iter().collect();
+    for live in &live_entries {
+        if let Some(mut animal) = ctx.db.wild_animal().id().find(live.animal_id) {
+            if live.matches_row(&animal) {
+                animal.pos_x = live.pos_x;
+                animal.pos_y = live.pos_y;
+                animal.chunk_index = live.chunk_index;
+                ctx.db.wild_animal().id().update(animal);
+            }
+        }
+        ctx.db.animal_live_position().animal_id().delete(live.animal_id);
+    }
+    let chunks: Vec<u32> = ctx.db.animal_transform_batch().iter().map(|b| b.chunk_index).collect();
+    for chunk_index in chunks {
+        ctx.db.animal_transform_batch().chunk_index().delete(chunk_index);
+    }
+    log::info!("[AnimalTransforms] Flushed {} batched transforms into animal rows", live_entries.len());
+}
+
+/// --- Toggle Animal Transform Batching (Admin) ---
+#[spacetimedb::reducer]
+pub fn set_animal_transform_batching(ctx: &ReducerContext, enabled: bool) -> Result<(), String> {
+    if !crate::combat_balance::is_balance_admin(ctx) {
+        return Err("Only admins can change animal transform batching.".to_string());
+    }
+    if !enabled {
+        flush_batches(ctx);
+    }
+    let settings = AnimalTransformSettings { id: 0, batching_enabled: enabled };
+    if ctx.db.animal_transform_settings().id().find(0).is_some() {
+        ctx.db.animal_transform_settings().id().update(settings);
+    } else {
+        ctx.db.animal_transform_settings().insert(settings);
+    }
+    log::info!("[AnimalTransforms] Batching {}", if enabled { "enabled" } else { "disabled" });
+    Ok(())
+}
@@ -12,6 +12,7 @@
  * - Extremely fast when fleeing                                             *
  * - Immediately flees when any player is detected                           *
  * - Can burrow underground to hide                                          *
+ * - Sneaks into farms to nibble on growing crops                            *
  *                                                                            *
  ******************************************************************************/
 
@@ -29,11 +30,19 @@ use super::core::{
     get_player_distance, set_flee_destination_away_from_threat,
     detect_and_handle_stuck_movement, handle_water_unstuck,
 };
+use super::crop_grazing::{GrazingConfig, try_start_grazing, update_grazing, is_grazing, clear_grazing_target};
 
 // Vole-specific constants
 const VOLE_BURROW_DURATION_MS: u64 = 8000; // Hide underground for 8 seconds
 const VOLE_FLEE_DISTANCE: f32 = 400.0; // How far to flee
 
+// Voles nibble crops - each bite sets growth back rather than destroying the plant
+const VOLE_GRAZING: GrazingConfig = GrazingConfig {
+    detection_radius: 300.0,
+    check_chance: 0.03,
+    bite_growth_loss: 0.35,
+};
+
 pub struct VoleBehavior;
 
 impl AnimalBehavior for VoleBehavior {
@@ -115,6 +124,22 @@ impl AnimalBehavior for VoleBehavior {
                         log::debug!("Vole {} fleeing from player {} at distance {:.1}", 
                                    animal.id, player.identity, distance);
                     }
+                } else {
+                    // 🌾 Coast is clear - sneak over to nibble on nearby crops
+                    try_start_grazing(ctx, animal, &VOLE_GRAZING, current_time, rng);
+                }
+            },
+            
+            AnimalState::Investigating => {
+                // Voles only investigate to reach crops - abandon them the moment a player shows up
+                if let Some(player) = detected_player {
+                    clear_grazing_target(animal);
+                    set_flee_destination_away_from_threat(animal, player.position_x, player.position_y, VOLE_FLEE_DISTANCE, rng);
+                    transition_to_state(animal, AnimalState::Fleeing, current_time, None, "spooked while grazing");
+                } else if is_grazing(animal) {
+                    update_grazing(ctx, animal, &VOLE_GRAZING, current_time);
+                } else {
+                    transition_to_state(animal, AnimalState::Patrolling, current_time, None, "nothing to investigate");
                 }
             },
             
@@ -139,6 +164,8 @@ impl AnimalBehavior for VoleBehavior {
             },
             
             AnimalState::Fleeing => {
+                clear_grazing_target(animal);
+                
                 // Check if reached flee destination or should burrow
                 if let (Some(target_x), Some(target_y)) = (animal.investigation_x, animal.investigation_y) {
                     let distance_to_target_sq = get_distance_squared(animal.pos_x, animal.pos_y, target_x, target_y);
@@ -634,6 +634,8 @@ impl AnimalBehavior for ArcticWalrusBehavior {
             "Raw Dolly Varden", "Cooked Dolly Varden",
             "Raw Rockfish", "Cooked Rockfish",
             "Raw Steelhead", "Cooked Steelhead",
+            "Raw Arctic Grayling", "Cooked Arctic Grayling",
+            "Raw Northern Pike", "Cooked Northern Pike",
             // Large/premium fish
             "Raw Pink Salmon", "Cooked Pink Salmon",
             "Raw Sockeye Salmon", "Cooked Sockeye Salmon",
@@ -1218,6 +1220,7 @@ fn process_walrus_pup_mortality(ctx: &ReducerContext) -> Result<(), String> {
         // Remove the animal
         if let Some(_animal) = ctx.db.wild_animal().id().find(&animal_id) {
             ctx.db.wild_animal().id().delete(&animal_id);
+            crate::spatial_grid::untrack_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(animal_id));
         }
     }
     
@@ -1486,6 +1489,7 @@ fn spawn_walrus_pup(
     
     let inserted = ctx.db.wild_animal().try_insert(new_pup)
         .map_err(|e| format!("Failed to spawn walrus pup: {}", e))?;
+    crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::WildAnimal(inserted.id), inserted.pos_x, inserted.pos_y);
     
     // Create breeding data for the pup
     create_walrus_breeding_data(ctx, inserted.id, sex, current_day, true)?;
@@ -14,6 +14,12 @@
  * - IMPORTANT: Pack behavior does NOT affect combat/hunting behavior!       *
  *   All wolves still chase and attack players independently                 *
  *                                                                            *
+ * 🐺 HOWL REINFORCEMENTS:                                                    *
+ * - A wounded wolf howls (sound event) at most once per cooldown            *
+ * - Wolves that hear it perk up (Alert), then run to the attacker's         *
+ *   position (Investigating) and chase whoever they find there               *
+ * - Lone responders join the howler's pack mid-fight                         *
+ *                                                                            *
  ******************************************************************************/
 
 use spacetimedb::{ReducerContext, Identity, Timestamp, Table};
@@ -36,9 +42,28 @@ use super::core::{
     set_flee_destination_away_from_threat,
     handle_fire_trap_escape, calculate_escape_angle_from_threats, detect_and_handle_stuck_movement,
     is_position_in_shelter, get_pack_alpha, should_follow_pack_alpha, get_pack_cohesion_movement,
-    update_animal_position,
+    update_animal_position, MAX_PACK_SIZE,
 };
 
+// --- Howl Constants ---
+pub const WOLF_HOWL_RADIUS: f32 = 2000.0; // Wolves (and players) hear a howl from this far away
+const WOLF_HOWL_COOLDOWN_MS: i64 = 10_000; // A wolf howls at most once per 10 seconds
+const WOLF_HOWL_ALERT_MS: i64 = 600; // Ears-perked pause before running to the howl
+const WOLF_HOWL_RESPONSE_TIMEOUT_MS: i64 = 20_000; // Give up on a howl that led nowhere
+
+// --- Howl Table ---
+/// Last howl per wolf - throttles howls and records where the fight is
+#[spacetimedb::table(accessor = wolf_howl)]
+#[derive(Clone, Debug)]
+pub struct WolfHowl {
+    #[primary_key]
+    pub wolf_id: u64,
+    pub howled_at: Timestamp,
+    pub attacker_id: Identity,
+    pub attacker_x: f32,
+    pub attacker_y: f32,
+}
+
 pub struct TundraWolfBehavior;
 
 pub trait WolfBehavior {
@@ -172,6 +197,13 @@ impl AnimalBehavior for TundraWolfBehavior {
                 // Wolf sniffing behavior - investigate for a short time then chase
                 let time_in_state = (current_time.to_micros_since_unix_epoch() -
                                     animal.state_change_time.to_micros_since_unix_epoch()) / 1000;
+
+                // 🐺 HOWL RESPONSE: Heard a packmate howl - head for the fight
+                if animal.investigation_x.is_some() && time_in_state > WOLF_HOWL_ALERT_MS {
+                    let attacker = animal.target_player_id;
+                    transition_to_state(animal, AnimalState::Investigating, current_time, attacker, "answering howl");
+                    return Ok(());
+                }
                 
                                     if time_in_state > 1500 { // Reduced from 4000ms to 1.5 seconds - wolves are aggressive
                         if let Some(player) = detected_player {
@@ -191,6 +223,26 @@ impl AnimalBehavior for TundraWolfBehavior {
                     }
             },
             
+            AnimalState::Investigating => {
+                // 🐺 HOWL RESPONSE: Running to where the howl came from - chase anyone found on the way
+                if let Some(player) = detected_player {
+                    if self.should_chase_player(ctx, animal, stats, player) {
+                        transition_to_state(animal, AnimalState::Chasing, current_time, Some(player.identity), "found attacker after howl");
+                        emit_species_sound(ctx, animal, player.identity, "chase_start");
+                        return Ok(());
+                    }
+                }
+
+                let time_in_state = (current_time.to_micros_since_unix_epoch() -
+                                    animal.state_change_time.to_micros_since_unix_epoch()) / 1000;
+                if animal.investigation_x.is_none() || time_in_state > WOLF_HOWL_RESPONSE_TIMEOUT_MS {
+                    // Core movement clears the target on arrival - nobody here
+                    animal.investigation_x = None;
+                    animal.investigation_y = None;
+                    transition_to_state(animal, AnimalState::Patrolling, current_time, None, "howl led nowhere");
+                }
+            },
+
             AnimalState::Fleeing => {
                 // Check if fled far enough to return to patrolling
                 if let Some(investigation_x) = animal.investigation_x {
@@ -411,6 +463,9 @@ impl AnimalBehavior for TundraWolfBehavior {
     ) -> Result<(), String> {
         // Wolves are aggressive and rarely back down
         let health_percent = animal.health / stats.max_health;
+
+        // 🐺 Call the pack - wolves in earshot come running whether this one fights or flees
+        howl_for_reinforcements(ctx, animal, attacker, current_time);
         
         if health_percent > 0.3 {
             // High health - retaliate aggressively
@@ -442,4 +497,88 @@ impl AnimalBehavior for TundraWolfBehavior {
     fn get_chase_abandonment_multiplier(&self) -> f32 {
         3.5 // Wolves are very persistent - give up at 3.5x chase trigger range (630 units for 180 chase range)
     }
-}
\ No newline at end of file
+}
+
+/// A wounded wolf howls for help. Wolves within WOLF_HOWL_RADIUS that aren't already
+/// fighting become Alert, then converge on the attacker's position. Lone responders
+/// join the howler's pack (a new one is founded if the howler has none).
+pub fn howl_for_reinforcements(ctx: &ReducerContext, howler: &mut WildAnimal, attacker: &Player, current_time: Timestamp) {
+    if howler.tamed_by.is_some() {
+        return;
+    }
+
+    let howls = ctx.db.wolf_howl();
+    if let Some(last_howl) = howls.wolf_id().find(howler.id) {
+        let since_last_ms = (current_time.to_micros_since_unix_epoch() - last_howl.howled_at.to_micros_since_unix_epoch()) / 1000;
+        if since_last_ms < WOLF_HOWL_COOLDOWN_MS {
+            return;
+        }
+    }
+
+    // Drop howl rows from wolves that died or have long since calmed down
+    let stale_ids: Vec<u64> = howls.iter()
+        .filter(|h| (current_time.to_micros_since_unix_epoch() - h.howled_at.to_micros_since_unix_epoch()) / 1000 > WOLF_HOWL_RESPONSE_TIMEOUT_MS)
+        .map(|h| h.wolf_id)
+        .collect();
+    for wolf_id in stale_ids {
+        howls.wolf_id().delete(wolf_id);
+    }
+    let howl = WolfHowl {
+        wolf_id: howler.id,
+        howled_at: current_time,
+        attacker_id: attacker.identity,
+        attacker_x: attacker.position_x,
+        attacker_y: attacker.position_y,
+    };
+    if howls.wolf_id().find(howler.id).is_some() {
+        howls.wolf_id().update(howl);
+    } else {
+        howls.insert(howl);
+    }
+
+    crate::sound_events::emit_wolf_howl_sound(ctx, howler.pos_x, howler.pos_y, WOLF_HOWL_RADIUS, attacker.identity);
+
+    let mut responders: Vec<WildAnimal> = ctx.db.wild_animal().iter()
+        .filter(|w| w.id != howler.id
+            && w.species == AnimalSpecies::TundraWolf
+            && w.tamed_by.is_none()
+            && matches!(w.state, AnimalState::Idle | AnimalState::Patrolling | AnimalState::Alert)
+            && get_distance_squared(w.pos_x, w.pos_y, howler.pos_x, howler.pos_y) <= WOLF_HOWL_RADIUS * WOLF_HOWL_RADIUS)
+        .collect();
+    if responders.is_empty() {
+        log::info!("🐺 Tundra Wolf {} howled but no wolves were in earshot", howler.id);
+        return;
+    }
+
+    // Found a pack around the howler if it doesn't have one (same id scheme as pack formation)
+    if howler.pack_id.is_none() {
+        let pack_taken = ctx.db.wild_animal().iter().any(|w| w.pack_id == Some(howler.id));
+        if !pack_taken {
+            howler.pack_id = Some(howler.id);
+            howler.is_pack_leader = true;
+            howler.pack_join_time = Some(current_time);
+        }
+    }
+    let mut pack_size = howler.pack_id
+        .map_or(0, |pack_id| ctx.db.wild_animal().iter().filter(|w| w.pack_id == Some(pack_id) && w.id != howler.id).count() + 1);
+
+    let responder_count = responders.len();
+    for wolf in responders.iter_mut() {
+        if let Some(pack_id) = howler.pack_id {
+            if wolf.pack_id.is_none() && pack_size < MAX_PACK_SIZE {
+                wolf.pack_id = Some(pack_id);
+                wolf.is_pack_leader = false;
+                wolf.pack_join_time = Some(current_time);
+                pack_size += 1;
+                log::info!("🐺 Lone wolf {} joined pack {} answering a howl", wolf.id, pack_id);
+            }
+        }
+
+        wolf.investigation_x = Some(attacker.position_x);
+        wolf.investigation_y = Some(attacker.position_y);
+        transition_to_state(wolf, AnimalState::Alert, current_time, Some(attacker.identity), "heard howl");
+        ctx.db.wild_animal().id().update(wolf.clone());
+    }
+
+    log::info!("🐺 Tundra Wolf {} howled for help against {} - {} wolves answering", howler.id, attacker.identity, responder_count);
+}
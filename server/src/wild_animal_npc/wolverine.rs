@@ -31,6 +31,7 @@ use super::core::{
     get_player_distance, is_player_in_chase_range,
     detect_and_handle_stuck_movement, update_animal_position,
 };
+use super::corpse_feeding::{try_start_corpse_feeding, update_corpse_feeding, is_feeding, clear_feeding_target};
 
 pub struct WolverineBehavior;
 
@@ -98,7 +99,7 @@ impl AnimalBehavior for WolverineBehavior {
         stats: &AnimalStats,
         detected_player: Option<&Player>,
         current_time: Timestamp,
-        _rng: &mut impl Rng,
+        rng: &mut impl Rng,
     ) -> Result<(), String> {
         match animal.state {
             AnimalState::Patrolling | AnimalState::Idle => {
@@ -115,6 +116,9 @@ impl AnimalBehavior for WolverineBehavior {
                         log::info!("Wolverine {} spotted player {} at {:.1}px - attacking!", 
                                   animal.id, player.identity, get_player_distance(animal, player));
                     }
+                } else {
+                    // 🦴 Nobody around - feed on any unattended corpse nearby
+                    try_start_corpse_feeding(ctx, animal, current_time, rng);
                 }
             },
             
@@ -145,6 +149,21 @@ impl AnimalBehavior for WolverineBehavior {
                 }
             },
             
+            AnimalState::Investigating => {
+                // 🥩 Following a meat scent - attack anyone found on the way
+                // 🦴 ...or heading to feed on a corpse
+                if let Some(player) = detected_player {
+                    clear_feeding_target(animal);
+                    transition_to_state(animal, AnimalState::Chasing, current_time, Some(player.identity), "found player at scent");
+                    emit_species_sound(ctx, animal, player.identity, "chase_start");
+                } else if is_feeding(animal) {
+                    update_corpse_feeding(ctx, animal, current_time);
+                } else if animal.investigation_x.is_none() {
+                    // Core movement clears the target on arrival
+                    transition_to_state(animal, AnimalState::Patrolling, current_time, None, "scent led nowhere");
+                }
+            },
+            
             // Wolverines should NEVER be in fleeing state, but handle it anyway
             AnimalState::Fleeing => {
                 // Wolverines don't flee - immediately switch back to attacking
@@ -79,6 +79,7 @@ pub(crate) fn get_box_collision_radius(box_type: u8) -> f32 {
         BOX_TYPE_SCARECROW => SCARECROW_COLLISION_RADIUS,
         BOX_TYPE_MILITARY_RATION => BOX_COLLISION_RADIUS,
         BOX_TYPE_MILITARY_CRATE => BOX_COLLISION_RADIUS,
+        BOX_TYPE_SUPPLY_DROP => BOX_COLLISION_RADIUS,
         BOX_TYPE_MINE_CART => MINE_CART_COLLISION_RADIUS,
         BOX_TYPE_FISH_TRAP => FISH_TRAP_COLLISION_RADIUS,
         BOX_TYPE_WILD_BEEHIVE => WILD_BEEHIVE_COLLISION_RADIUS,
@@ -94,6 +95,8 @@ pub(crate) fn get_box_player_collision_radius(box_type: u8) -> f32 {
     match box_type {
         // Ground rugs should never block player movement.
         BOX_TYPE_WOLF_PELT | BOX_TYPE_FOX_PELT | BOX_TYPE_POLAR_BEAR_PELT | BOX_TYPE_WALRUS_PELT => 0.0,
+        // Greenhouses are walk-in structures - players need to reach the crops inside.
+        BOX_TYPE_GREENHOUSE => 0.0,
         BOX_TYPE_PLAYER_BEEHIVE | BOX_TYPE_WILD_BEEHIVE => BOX_COLLISION_RADIUS,
         _ => get_box_collision_radius(box_type),
     }
@@ -186,6 +189,83 @@ pub const NUM_PELT_SLOTS: usize = 0; // Decorative only
 pub const PELT_INITIAL_HEALTH: f32 = 200.0;
 pub const PELT_MAX_HEALTH: f32 = 200.0;
 
+// --- Supply Drop --- (random world event crate, despawns if unopened - see supply_drop.rs)
+pub const BOX_TYPE_SUPPLY_DROP: u8 = 18;
+pub const NUM_SUPPLY_DROP_SLOTS: usize = 6;
+pub const SUPPLY_DROP_INITIAL_HEALTH: f32 = 500.0;
+pub const SUPPLY_DROP_MAX_HEALTH: f32 = 500.0;
+
+// --- Sprinkler --- (waters crops from a nearby rain collector - see sprinkler.rs)
+pub const BOX_TYPE_SPRINKLER: u8 = 19;
+pub const NUM_SPRINKLER_SLOTS: usize = 0; // No inventory - draws water from a rain collector
+pub const SPRINKLER_INITIAL_HEALTH: f32 = 250.0;
+pub const SPRINKLER_MAX_HEALTH: f32 = 250.0;
+
+// --- Greenhouse --- (protects the crops inside its footprint - see greenhouse.rs)
+pub const BOX_TYPE_GREENHOUSE: u8 = 20;
+pub const NUM_GREENHOUSE_SLOTS: usize = 0; // No inventory - players walk in and farm inside it
+pub const GREENHOUSE_INITIAL_HEALTH: f32 = 600.0;
+pub const GREENHOUSE_MAX_HEALTH: f32 = 600.0;
+
+// --- Feeding Trough --- (tamed animals in range eat from it - see feeding_trough.rs)
+pub const BOX_TYPE_FEEDING_TROUGH: u8 = 21;
+pub const NUM_FEEDING_TROUGH_SLOTS: usize = 6;
+pub const FEEDING_TROUGH_INITIAL_HEALTH: f32 = 300.0;
+pub const FEEDING_TROUGH_MAX_HEALTH: f32 = 300.0;
+
+// --- Kennel / Stable --- (store tamed animals while offline - see kennel.rs)
+pub const BOX_TYPE_KENNEL: u8 = 22;
+pub const NUM_KENNEL_SLOTS: usize = 0; // No inventory - holds animals, not items
+pub const KENNEL_INITIAL_HEALTH: f32 = 400.0;
+pub const KENNEL_MAX_HEALTH: f32 = 400.0;
+pub const BOX_TYPE_STABLE: u8 = 23;
+pub const NUM_STABLE_SLOTS: usize = 0;
+pub const STABLE_INITIAL_HEALTH: f32 = 800.0;
+pub const STABLE_MAX_HEALTH: f32 = 800.0;
+
+// --- Tanning Rack --- (cures raw hides into leather - see tanning_rack.rs)
+pub const BOX_TYPE_TANNING_RACK: u8 = 24;
+pub const NUM_TANNING_RACK_SLOTS: usize = 8;
+pub const TANNING_RACK_INITIAL_HEALTH: f32 = 350.0;
+pub const TANNING_RACK_MAX_HEALTH: f32 = 350.0;
+
+// --- Workbenches --- (gate higher-tier crafting recipes - see workbench.rs)
+pub const BOX_TYPE_WORKBENCH_T1: u8 = 25;
+pub const BOX_TYPE_WORKBENCH_T2: u8 = 26;
+pub const BOX_TYPE_WORKBENCH_T3: u8 = 27;
+pub const NUM_WORKBENCH_SLOTS: usize = 0; // No inventory - proximity crafting only
+pub const WORKBENCH_T1_INITIAL_HEALTH: f32 = 500.0;
+pub const WORKBENCH_T1_MAX_HEALTH: f32 = 500.0;
+pub const WORKBENCH_T2_INITIAL_HEALTH: f32 = 800.0;
+pub const WORKBENCH_T2_MAX_HEALTH: f32 = 800.0;
+pub const WORKBENCH_T3_INITIAL_HEALTH: f32 = 1200.0;
+pub const WORKBENCH_T3_MAX_HEALTH: f32 = 1200.0;
+
+// --- Research Table --- (sacrifice items to learn their blueprints - see research.rs)
+pub const BOX_TYPE_RESEARCH_TABLE: u8 = 28;
+pub const NUM_RESEARCH_TABLE_SLOTS: usize = 1; // The item being researched
+pub const RESEARCH_TABLE_INITIAL_HEALTH: f32 = 400.0;
+pub const RESEARCH_TABLE_MAX_HEALTH: f32 = 400.0;
+
+// --- Recycler --- (breaks items back into crafting materials - see recycler.rs)
+// ALK stations have indestructible monument recyclers; players can craft a weaker Shredder
+pub const BOX_TYPE_RECYCLER: u8 = 29;
+pub const NUM_RECYCLER_SLOTS: usize = 12; // 6 input + 6 output
+pub const RECYCLER_INITIAL_HEALTH: f32 = 600.0;
+pub const RECYCLER_MAX_HEALTH: f32 = 600.0;
+
+// --- Locked Crate --- (opened with a keycard or lockpick - see locked_crate.rs)
+pub const BOX_TYPE_LOCKED_CRATE: u8 = 30;
+pub const NUM_LOCKED_CRATE_SLOTS: usize = 6;
+pub const LOCKED_CRATE_INITIAL_HEALTH: f32 = 500.0;
+pub const LOCKED_CRATE_MAX_HEALTH: f32 = 500.0;
+
+// --- Vault Crate --- (puzzle room loot, opened by solving the room - see monument_puzzle.rs)
+pub const BOX_TYPE_VAULT_CRATE: u8 = 31;
+pub const NUM_VAULT_CRATE_SLOTS: usize = 8;
+pub const VAULT_CRATE_INITIAL_HEALTH: f32 = 1000.0;
+pub const VAULT_CRATE_MAX_HEALTH: f32 = 1000.0;
+
 // Re-export refrigerator constants for backward compatibility
 pub use crate::refrigerator::{NUM_REFRIGERATOR_SLOTS, REFRIGERATOR_INITIAL_HEALTH, REFRIGERATOR_MAX_HEALTH};
 
@@ -203,7 +283,7 @@ use crate::items::{
 use crate::inventory_management::{self, ItemContainer, ContainerItemClearer, merge_or_place_into_container_slot};
 use crate::wooden_storage_box::wooden_storage_box as WoodenStorageBoxTableTrait;
 use crate::environment::calculate_chunk_index;
-use crate::models::{ContainerType, ItemLocation, InventoryLocationData, HotbarLocationData, DroppedLocationData, EquippedLocationData, ContainerLocationData};
+use crate::models::{ContainerType, ItemLocation, InventoryLocationData, HotbarLocationData, DroppedLocationData, EquippedLocationData};
 use crate::player_inventory::{find_first_empty_player_slot, move_item_to_inventory, move_item_to_hotbar, get_player_item};
 use crate::items::ItemCategory;
 // Re-export refrigerator validation function for backward compatibility
@@ -350,452 +430,96 @@ pub struct WoodenStorageBox {
  *                           REDUCERS (Generic Handlers)                        *
  ******************************************************************************/
 
-/// --- Move Item to Storage Box ---
-/// Moves an item from the player's inventory/hotbar INTO a specified slot in the storage box.
-/// Uses the generic handler from inventory_management.rs to perform the move operation.
-/// The handler validates the item, checks slot availability, and handles stacking logic.
-#[spacetimedb::reducer]
-pub fn move_item_to_box(
-    ctx: &ReducerContext, 
-    box_id: u32, 
-    target_slot_index: u8, 
-    item_instance_id: u64 // Pass ID directly
-) -> Result<(), String> {
-    // Get mutable box table handle
-    let mut boxes = ctx.db.wooden_storage_box();
-    let inventory_items = ctx.db.inventory_item();
-    let item_defs = ctx.db.item_definition();
-    // NOTE: Other tables (inventory, item_defs) are accessed within the handler via ctx
-
-    // --- Basic Validations --- 
-    let (_player, mut storage_box) = validate_box_interaction(ctx, box_id)?;
-    // REMOVED: Item fetching/validation moved to handler
-    // REMOVED: Target slot index validation moved to handler (using container.num_slots())
-    // NOTE: Refrigerator-specific validation is now handled by refrigerator.rs reducers
-
-    // Check if we're placing a Queen Bee in slot 0 of a player beehive (for sound)
-    let is_player_beehive = storage_box.box_type == BOX_TYPE_PLAYER_BEEHIVE;
-    let mut should_start_beehive_sound = false;
-    if is_player_beehive && target_slot_index == 0 {
-        // Check if the item being moved is a Queen Bee
-        if let Some(item) = inventory_items.instance_id().find(item_instance_id) {
-            if let Some(item_def) = item_defs.id().find(item.item_def_id) {
-                if item_def.name == "Queen Bee" {
-                    should_start_beehive_sound = true;
-                }
-            }
-        }
+impl inventory_management::ContainerHandle for WoodenStorageBox {
+    fn load_for_interaction(ctx: &ReducerContext, container_id: u32) -> Result<(Player, Self), String> {
+        validate_box_interaction(ctx, container_id)
     }
 
-    // --- Call GENERIC Handler --- 
-    inventory_management::handle_move_to_container_slot(
-        ctx, 
-        &mut storage_box, 
-        target_slot_index, 
-        item_instance_id // Pass the ID
-        // REMOVED item references
-    )?;
-
-    // --- Commit Box Update --- 
-    boxes.id().update(storage_box.clone());
-    
-    // Start beehive buzzing sound if Queen Bee was placed in slot 0
-    if should_start_beehive_sound {
-        let beehive_y_offset = BOX_COLLISION_Y_OFFSET + 100.0; // Beehives use larger offset
-        let visual_center_y = storage_box.pos_y - beehive_y_offset;
-        crate::sound_events::start_beehive_sound(ctx, box_id as u64, storage_box.pos_x, visual_center_y);
-        log::info!("[Beehive] Started buzzing sound - Queen Bee placed in beehive {} slot 0", box_id);
+    fn save(&self, ctx: &ReducerContext) {
+        ctx.db.wooden_storage_box().id().update(self.clone());
     }
-    
-    Ok(())
-}
 
-/// --- Move Item from Storage Box ---
-/// Moves an item FROM a storage box slot INTO the player's inventory/hotbar.
-/// Uses the generic handler from inventory_management.rs to perform the move operation.
-/// The handler validates the item, checks slot availability, and handles stacking logic.
-#[spacetimedb::reducer]
-pub fn move_item_from_box(
-    ctx: &ReducerContext, 
-    box_id: u32, 
-    source_slot_index: u8,
-    target_slot_type: String, // NEW: "inventory" or "hotbar"
-    target_slot_index: u32    // NEW: Index within inventory or hotbar
-) -> Result<(), String> {
-    // Get mutable box table handle
-    let mut boxes = ctx.db.wooden_storage_box();
-
-    // --- Validations --- 
-    let (_player, mut storage_box) = validate_box_interaction(ctx, box_id)?;
-    // NOTE: Basic distance/existence checked by validate_box_interaction
-    // NOTE: Item details, slot checks, target validation now handled by inventory_management handler
-
-    // --- Call Handler to attempt move to player inventory FIRST --- 
-    inventory_management::handle_move_from_container_slot(
-        ctx, 
-        &mut storage_box, // Pass mutably, handler will clear slot on success
-        source_slot_index,
-        target_slot_type, // Pass through
-        target_slot_index // Pass through
-    )?;
-    // ^ If this returns Ok, it means the move/merge/swap into the player slot succeeded.
-
-    // Check box type before moving storage_box
-    let is_backpack = storage_box.box_type == BOX_TYPE_BACKPACK;
-    let is_military_ration = storage_box.box_type == BOX_TYPE_MILITARY_RATION;
-    let is_military_crate = storage_box.box_type == BOX_TYPE_MILITARY_CRATE;
-    let is_mine_cart = storage_box.box_type == BOX_TYPE_MINE_CART;
-    let is_wild_beehive = storage_box.box_type == BOX_TYPE_WILD_BEEHIVE;
-    let is_player_beehive = storage_box.box_type == BOX_TYPE_PLAYER_BEEHIVE;
-
-    // --- Commit Box Update --- 
-    // The handler modified storage_box (cleared the slot) if the move was successful.
-    boxes.id().update(storage_box);
-    
-    // Stop beehive buzzing sound if Queen Bee was removed from slot 0
-    if is_player_beehive && source_slot_index == 0 {
-        crate::sound_events::stop_beehive_sound(ctx, box_id as u64);
-    }
-
-    // Auto-despawn empty backpacks
-    if is_backpack {
-        let _ = crate::backpack::check_and_despawn_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty military rations
-    if is_military_ration {
-        let _ = crate::military_ration::check_and_despawn_military_ration_if_empty(ctx, box_id);
-    }
-    if is_military_crate {
-        let _ = crate::military_ration::check_and_despawn_military_crate_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty mine carts
-    if is_mine_cart {
-        let _ = crate::mine_cart::check_and_despawn_mine_cart_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty wild beehives
-    if is_wild_beehive {
-        let _ = crate::wild_beehive::check_and_despawn_wild_beehive_if_empty(ctx, box_id);
-    }
-
-    Ok(())
-}
-
-/// Moves an item BETWEEN two slots within the same storage box.
-#[spacetimedb::reducer]
-pub fn move_item_within_box(
-    ctx: &ReducerContext,
-    box_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-) -> Result<(), String> {
-    // Get mutable box table handle
-    let mut boxes = ctx.db.wooden_storage_box();
-    let item_defs = ctx.db.item_definition();
-    // NOTE: Other tables accessed in handler via ctx
-
-    // --- Basic Validations --- 
-    let (_player, mut storage_box) = validate_box_interaction(ctx, box_id)?;
-    // REMOVED: Item fetching/validation moved to handler
-    // NOTE: Slot index validation moved to handler
-
-    // Check if this is a player beehive - we need to handle sound for Queen Bee in slot 0
-    let is_player_beehive = storage_box.box_type == BOX_TYPE_PLAYER_BEEHIVE;
-    let mut had_queen_bee_in_slot_0 = false;
-    
-    if is_player_beehive {
-        // Check if slot 0 currently has a Queen Bee
-        if let Some(def_id) = storage_box.slot_def_id_0 {
-            if let Some(item_def) = item_defs.id().find(def_id) {
-                had_queen_bee_in_slot_0 = item_def.name == "Queen Bee";
+    /// Compost: a stack split within the bin starts composting from 0, the source stack keeps its timestamp
+    fn after_operation(&mut self, ctx: &ReducerContext, op: &inventory_management::ContainerOp, _before: &Self) {
+        let inventory_management::ContainerOp::SplitWithin { target_slot, .. } = *op else {
+            return;
+        };
+        if self.box_type != BOX_TYPE_COMPOST {
+            return;
+        }
+        use crate::compost::{set_compost_timestamp, is_item_compostable};
+        let inventory_items = ctx.db.inventory_item();
+        let Some(mut new_item) = self.get_slot_instance_id(target_slot).and_then(|id| inventory_items.instance_id().find(id)) else {
+            return;
+        };
+        if let Some(item_def) = ctx.db.item_definition().id().find(new_item.item_def_id) {
+            if is_item_compostable(&item_def, Some(&new_item)) {
+                set_compost_timestamp(&mut new_item, ctx.timestamp);
+                log::debug!("[Compost] Set fresh timestamp on new split item {} in compost (slot {}).", new_item.instance_id, target_slot);
+                inventory_items.instance_id().update(new_item);
             }
         }
     }
 
-    // --- Call GENERIC Handler --- 
-    inventory_management::handle_move_within_container(
-        ctx, 
-        &mut storage_box, 
-        source_slot_index, 
-        target_slot_index
-        // Removed table args
-    )?;
-
-    // --- Commit Box Update --- 
-    boxes.id().update(storage_box.clone());
-    
-    // Handle beehive sound based on Queen Bee presence in slot 0
-    if is_player_beehive {
-        // Check if slot 0 now has a Queen Bee
-        let mut has_queen_bee_in_slot_0 = false;
-        if let Some(def_id) = storage_box.slot_def_id_0 {
-            if let Some(item_def) = item_defs.id().find(def_id) {
-                has_queen_bee_in_slot_0 = item_def.name == "Queen Bee";
+    fn after_saved(&self, ctx: &ReducerContext, op: &inventory_management::ContainerOp, before: &Self) {
+        // Player beehives buzz while a Queen Bee sits in slot 0
+        if self.box_type == BOX_TYPE_PLAYER_BEEHIVE {
+            let had_queen = has_queen_bee_in_slot_0(ctx, before);
+            let has_queen = has_queen_bee_in_slot_0(ctx, self);
+            if !had_queen && has_queen {
+                let beehive_y_offset = BOX_COLLISION_Y_OFFSET + 100.0; // Beehives use larger offset
+                let visual_center_y = self.pos_y - beehive_y_offset;
+                crate::sound_events::start_beehive_sound(ctx, self.id as u64, self.pos_x, visual_center_y);
+                log::info!("[Beehive] Started buzzing sound - Queen Bee placed in beehive {} slot 0", self.id);
+            } else if had_queen && !has_queen {
+                crate::sound_events::stop_beehive_sound(ctx, self.id as u64);
+                log::info!("[Beehive] Stopped buzzing sound - Queen Bee left slot 0 in beehive {}", self.id);
             }
         }
-        
-        // Start/stop sound based on change
-        if !had_queen_bee_in_slot_0 && has_queen_bee_in_slot_0 {
-            // Queen Bee was placed in slot 0 - start sound
-            let beehive_y_offset = BOX_COLLISION_Y_OFFSET + 100.0;
-            let visual_center_y = storage_box.pos_y - beehive_y_offset;
-            crate::sound_events::start_beehive_sound(ctx, box_id as u64, storage_box.pos_x, visual_center_y);
-            log::info!("[Beehive] Started buzzing sound - Queen Bee moved to slot 0 in beehive {}", box_id);
-        } else if had_queen_bee_in_slot_0 && !has_queen_bee_in_slot_0 {
-            // Queen Bee was removed from slot 0 - stop sound
-            crate::sound_events::stop_beehive_sound(ctx, box_id as u64);
-            log::info!("[Beehive] Stopped buzzing sound - Queen Bee moved from slot 0 in beehive {}", box_id);
-        }
-    }
-    
-    Ok(())
-}
-
-/// --- Split Stack Into Box ---
-/// Splits a stack from player inventory/hotbar into a specific box slot.
-/// Validates the box interaction and source item, then uses the generic container handler
-/// to split the stack and move the specified quantity to the box.
-#[spacetimedb::reducer]
-pub fn split_stack_into_box(
-    ctx: &ReducerContext,
-    box_id: u32,
-    target_slot_index: u8,
-    source_item_instance_id: u64,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let mut boxes = ctx.db.wooden_storage_box();
-    let (_player, mut storage_box) = validate_box_interaction(ctx, box_id)?;
-    
-    // The handler will fetch the source_item, validate its location/ownership, quantity, and stackability.
-    inventory_management::handle_split_into_container(
-        ctx, 
-        &mut storage_box, 
-        target_slot_index, 
-        source_item_instance_id, 
-        quantity_to_split
-    )?;
-
-    boxes.id().update(storage_box);
-    Ok(())
-}
-
-/// --- Split Stack From Box ---
-/// Splits a stack from a box slot into the player's inventory/hotbar.
-/// Validates the box interaction, then uses the generic container handler
-/// to split the stack and move the specified quantity to the player's inventory.
-#[spacetimedb::reducer]
-pub fn split_stack_from_box(
-    ctx: &ReducerContext,
-    box_id: u32,
-    source_slot_index: u8,
-    quantity_to_split: u32,
-    target_slot_type: String, 
-    target_slot_index: u32,   
-) -> Result<(), String> {
-    let mut boxes = ctx.db.wooden_storage_box();
-    let (_player, mut storage_box) = validate_box_interaction(ctx, box_id)?;
-    
-    inventory_management::handle_split_from_container(
-        ctx, 
-        &mut storage_box, 
-        source_slot_index, 
-        quantity_to_split,
-        target_slot_type, 
-        target_slot_index
-    )?;
-    
-    // Note: When splitting FROM compost, the source stack keeps its original timestamp
-    // The split item (now in player inventory) doesn't need a compost timestamp
-    // If it's moved back to compost later, it will get a fresh timestamp then
-    
-    boxes.id().update(storage_box);
-    Ok(())
-}
-
-/// --- Split Stack Within Box ---
-/// Splits a stack FROM one box slot TO another within the same box.
-/// Validates the box interaction, then uses the generic container handler
-/// to split the stack and move the specified quantity to the target slot.
-#[spacetimedb::reducer]
-pub fn split_stack_within_box(
-    ctx: &ReducerContext,
-    box_id: u32,
-    source_slot_index: u8,
-    target_slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    // Get tables
-    let mut boxes = ctx.db.wooden_storage_box();
-    // NOTE: Other tables accessed in handler
-
-    // --- Validations --- 
-    let (_player, mut storage_box) = validate_box_interaction(ctx, box_id)?;
-    // REMOVED: Item fetching/validation moved to handler
-    // NOTE: Slot index/target empty validation moved to handler
 
-    // --- Call GENERIC Handler ---
-    inventory_management::handle_split_within_container(
-        ctx,
-        &mut storage_box,
-        source_slot_index,
-        target_slot_index,
-        quantity_to_split
-    )?;
-
-    // --- Compost-specific: Set timestamp on new split item only ---
-    // Note: Source stack keeps its original timestamp (continues from where it was)
-    // Only the new split item gets a fresh timestamp (starts composting from 0)
-    if storage_box.box_type == crate::wooden_storage_box::BOX_TYPE_COMPOST {
-        use crate::compost::{set_compost_timestamp, is_item_compostable};
-        use crate::items::{inventory_item as InventoryItemTableTrait, item_definition as ItemDefinitionTableTrait};
-        let mut inventory_items = ctx.db.inventory_item();
-        let item_defs = ctx.db.item_definition();
-        
-        // Set timestamp on new split item in target slot (starts fresh)
-        if let Some(new_item_id) = storage_box.get_slot_instance_id(target_slot_index) {
-            if let Some(mut new_item) = inventory_items.instance_id().find(&new_item_id) {
-                if let Some(item_def) = item_defs.id().find(&new_item.item_def_id) {
-                    if is_item_compostable(&item_def, Some(&new_item)) {
-                        set_compost_timestamp(&mut new_item, ctx.timestamp);
-                        inventory_items.instance_id().update(new_item);
-                        log::debug!("[Compost] Set fresh timestamp on new split item {} in compost (slot {}). Source stack keeps original timestamp.", new_item_id, target_slot_index);
-                    }
-                }
-            }
+        if op.removes_items() {
+            despawn_if_emptied(ctx, self);
         }
     }
-
-    // --- Commit Box Update --- 
-    boxes.id().update(storage_box);
-    Ok(())
 }
 
-/// --- Quick Move From Box ---
-/// Quickly moves an item FROM a box slot TO the player inventory.
-/// Validates the box interaction, then uses the generic container handler
-/// to move the item to the player's inventory.
-#[spacetimedb::reducer]
-pub fn quick_move_from_box(
-    ctx: &ReducerContext, 
-    box_id: u32, 
-    source_slot_index: u8
-) -> Result<(), String> {
-    // Get mutable box table handle
-    let mut boxes = ctx.db.wooden_storage_box();
-
-    // --- Basic Validations --- 
-    let (_player, mut storage_box) = validate_box_interaction(ctx, box_id)?;
-    // REMOVED: Item fetching/slot empty validation moved to handler
-
-    // --- Call Handler --- 
-    inventory_management::handle_quick_move_from_container(
-        ctx, 
-        &mut storage_box, 
-        source_slot_index
-    )?;
-
-    // Check box type before moving storage_box
-    let is_backpack = storage_box.box_type == BOX_TYPE_BACKPACK;
-    let is_military_ration = storage_box.box_type == BOX_TYPE_MILITARY_RATION;
-    let is_military_crate = storage_box.box_type == BOX_TYPE_MILITARY_CRATE;
-    let is_mine_cart = storage_box.box_type == BOX_TYPE_MINE_CART;
-    let is_wild_beehive = storage_box.box_type == BOX_TYPE_WILD_BEEHIVE;
-    let is_player_beehive = storage_box.box_type == BOX_TYPE_PLAYER_BEEHIVE;
-
-    // --- Commit Box Update --- 
-    boxes.id().update(storage_box);
-    
-    // Stop beehive buzzing sound if Queen Bee was removed from slot 0
-    if is_player_beehive && source_slot_index == 0 {
-        // Queen Bee was removed from the beehive - stop the buzzing sound
-        crate::sound_events::stop_beehive_sound(ctx, box_id as u64);
-    }
-
-    // Auto-despawn empty backpacks
-    if is_backpack {
-        let _ = crate::backpack::check_and_despawn_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty military rations
-    if is_military_ration {
-        let _ = crate::military_ration::check_and_despawn_military_ration_if_empty(ctx, box_id);
-    }
-    if is_military_crate {
-        let _ = crate::military_ration::check_and_despawn_military_crate_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty mine carts
-    if is_mine_cart {
-        let _ = crate::mine_cart::check_and_despawn_mine_cart_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty wild beehives
-    if is_wild_beehive {
-        let _ = crate::wild_beehive::check_and_despawn_wild_beehive_if_empty(ctx, box_id);
-    }
-
-    Ok(())
+crate::container_reducers! {
+    WoodenStorageBox;
+    move_to: move_item_to_box(box_id),
+    move_from: move_item_from_box(box_id),
+    move_within: move_item_within_box(box_id),
+    split_into: split_stack_into_box(box_id),
+    split_from: split_stack_from_box(box_id),
+    split_within: split_stack_within_box(box_id),
+    quick_move_from: quick_move_from_box(box_id),
+    quick_move_to: quick_move_to_box(box_id),
+    drop: drop_item_from_box_slot_to_world(box_id),
+    split_and_drop: split_and_drop_item_from_box_slot_to_world(box_id),
+    move_all_of_type: move_all_of_type_to_box(box_id),
+    deposit_matching: deposit_matching_to_box(box_id),
+    take_all: take_all_from_box(box_id),
+    sort: sort_storage_box(box_id),
 }
 
-/// Quickly moves an item FROM player inventory/hotbar TO the first available/mergeable slot in the box.
-#[spacetimedb::reducer]
-pub fn quick_move_to_box(
-    ctx: &ReducerContext, 
-    box_id: u32, 
-    item_instance_id: u64 // Pass ID directly
-) -> Result<(), String> {
-    // Get tables
-    let mut boxes = ctx.db.wooden_storage_box();
-    let inventory_items = ctx.db.inventory_item();
-    let item_defs = ctx.db.item_definition();
-    // NOTE: Other tables accessed in handler via ctx
-
-    // --- Validations --- 
-    let (_player, mut storage_box) = validate_box_interaction(ctx, box_id)?;
-    // REMOVED: Item fetching/validation moved to handler
-
-    // Check if this is a player beehive and if we're moving a Queen Bee
-    let is_player_beehive = storage_box.box_type == BOX_TYPE_PLAYER_BEEHIVE;
-    let mut had_queen_bee_in_slot_0 = false;
-    
-    if is_player_beehive {
-        // Check if slot 0 currently has a Queen Bee
-        if let Some(def_id) = storage_box.slot_def_id_0 {
-            if let Some(item_def) = item_defs.id().find(def_id) {
-                had_queen_bee_in_slot_0 = item_def.name == "Queen Bee";
-            }
-        }
-    }
-
-    // --- Call Handler --- 
-    inventory_management::handle_quick_move_to_container(
-        ctx, 
-        &mut storage_box, 
-        item_instance_id // Pass the ID
-        // REMOVED item references
-    )?;
+fn has_queen_bee_in_slot_0(ctx: &ReducerContext, storage_box: &WoodenStorageBox) -> bool {
+    storage_box.slot_def_id_0
+        .and_then(|def_id| ctx.db.item_definition().id().find(def_id))
+        .is_some_and(|item_def| item_def.name == "Queen Bee")
+}
 
-    // --- Commit Box Update --- 
-    boxes.id().update(storage_box.clone());
-    
-    // Handle beehive sound if a Queen Bee was placed in slot 0
-    if is_player_beehive && !had_queen_bee_in_slot_0 {
-        // Check if slot 0 now has a Queen Bee
-        if let Some(def_id) = storage_box.slot_def_id_0 {
-            if let Some(item_def) = item_defs.id().find(def_id) {
-                if item_def.name == "Queen Bee" {
-                    let beehive_y_offset = BOX_COLLISION_Y_OFFSET + 100.0;
-                    let visual_center_y = storage_box.pos_y - beehive_y_offset;
-                    crate::sound_events::start_beehive_sound(ctx, box_id as u64, storage_box.pos_x, visual_center_y);
-                    log::info!("[Beehive] Started buzzing sound - Queen Bee quick-moved to slot 0 in beehive {}", box_id);
-                }
-            }
-        }
-    }
-    
-    Ok(())
+/// Auto-despawns loot containers (backpacks, rations, crates, supply drops, mine carts, wild beehives) once emptied
+fn despawn_if_emptied(ctx: &ReducerContext, storage_box: &WoodenStorageBox) {
+    let box_id = storage_box.id;
+    let _ = match storage_box.box_type {
+        BOX_TYPE_BACKPACK => crate::backpack::check_and_despawn_if_empty(ctx, box_id),
+        BOX_TYPE_MILITARY_RATION => crate::military_ration::check_and_despawn_military_ration_if_empty(ctx, box_id),
+        BOX_TYPE_MILITARY_CRATE => crate::military_ration::check_and_despawn_military_crate_if_empty(ctx, box_id),
+        BOX_TYPE_LOCKED_CRATE => crate::locked_crate::check_and_despawn_locked_crate_if_empty(ctx, box_id),
+        BOX_TYPE_SUPPLY_DROP => crate::supply_drop::check_and_despawn_supply_drop_if_empty(ctx, box_id),
+        BOX_TYPE_MINE_CART => crate::mine_cart::check_and_despawn_mine_cart_if_empty(ctx, box_id),
+        BOX_TYPE_WILD_BEEHIVE => crate::wild_beehive::check_and_despawn_wild_beehive_if_empty(ctx, box_id),
+        _ => Ok(()),
+    };
 }
 
 /******************************************************************************
@@ -819,6 +543,9 @@ pub fn place_wooden_storage_box(ctx: &ReducerContext, item_instance_id: u64, wor
     // Check if position is within monument zones (ALK stations, rune stones, hot springs, quarries)
     crate::building::check_monument_zone_placement(ctx, world_x, world_y)?;
 
+    // Check building privilege (cannot place inside another player's hearth radius)
+    crate::homestead_hearth::check_position_privileged(ctx, world_x, world_y, ctx.sender())?;
+
     // 1. Validate Player
     let player = players.identity().find(sender_id)
         .ok_or_else(|| "Player not found.".to_string())?;
@@ -842,6 +569,28 @@ pub fn place_wooden_storage_box(ctx: &ReducerContext, item_instance_id: u64, wor
         BOX_TYPE_BACKPACK
     } else if item_def.name == "Scarecrow" {
         BOX_TYPE_SCARECROW
+    } else if item_def.name == "Sprinkler" {
+        BOX_TYPE_SPRINKLER
+    } else if item_def.name == "Greenhouse" {
+        BOX_TYPE_GREENHOUSE
+    } else if item_def.name == "Feeding Trough" {
+        BOX_TYPE_FEEDING_TROUGH
+    } else if item_def.name == "Kennel" {
+        BOX_TYPE_KENNEL
+    } else if item_def.name == "Stable" {
+        BOX_TYPE_STABLE
+    } else if item_def.name == "Tanning Rack" {
+        BOX_TYPE_TANNING_RACK
+    } else if item_def.name == "Workbench Tier 1" {
+        BOX_TYPE_WORKBENCH_T1
+    } else if item_def.name == "Workbench Tier 2" {
+        BOX_TYPE_WORKBENCH_T2
+    } else if item_def.name == "Workbench Tier 3" {
+        BOX_TYPE_WORKBENCH_T3
+    } else if item_def.name == "Research Table" {
+        BOX_TYPE_RESEARCH_TABLE
+    } else if item_def.name == "Shredder" {
+        BOX_TYPE_RECYCLER
     } else if item_def.name == "Fish Trap" {
         // Fish traps can be placed in water within 600px of shore
         const FISH_TRAP_MAX_DISTANCE_FROM_SHORE: f32 = 600.0;
@@ -881,7 +630,7 @@ pub fn place_wooden_storage_box(ctx: &ReducerContext, item_instance_id: u64, wor
     let max_placement_dist = match box_type {
         BOX_TYPE_LARGE => LARGE_BOX_PLACEMENT_MAX_DISTANCE,
         BOX_TYPE_WOLF_PELT | BOX_TYPE_FOX_PELT | BOX_TYPE_POLAR_BEAR_PELT | BOX_TYPE_WALRUS_PELT => LARGE_BOX_PLACEMENT_MAX_DISTANCE,
-        BOX_TYPE_COMPOST | BOX_TYPE_SCARECROW | BOX_TYPE_PLAYER_BEEHIVE => TALL_BOX_PLACEMENT_MAX_DISTANCE,
+        BOX_TYPE_COMPOST | BOX_TYPE_SCARECROW | BOX_TYPE_PLAYER_BEEHIVE | BOX_TYPE_GREENHOUSE => TALL_BOX_PLACEMENT_MAX_DISTANCE,
         _ => BOX_PLACEMENT_MAX_DISTANCE,
     };
     let dx = player.position_x - world_x;
@@ -908,6 +657,11 @@ pub fn place_wooden_storage_box(ctx: &ReducerContext, item_instance_id: u64, wor
         }
     }
 
+    // Greenhouses need open soil across their whole footprint
+    if box_type == BOX_TYPE_GREENHOUSE {
+        crate::greenhouse::validate_greenhouse_placement(ctx, world_x, world_y)?;
+    }
+
     // 3. Validate Placement Location (Collision Checks)
     let new_chunk_index = calculate_chunk_index(world_x, world_y);
     
@@ -962,6 +716,17 @@ pub fn place_wooden_storage_box(ctx: &ReducerContext, item_instance_id: u64, wor
         },
         BOX_TYPE_BACKPACK => (BACKPACK_INITIAL_HEALTH, BACKPACK_MAX_HEALTH),
         BOX_TYPE_SCARECROW => (SCARECROW_INITIAL_HEALTH, SCARECROW_MAX_HEALTH),
+        BOX_TYPE_SPRINKLER => (SPRINKLER_INITIAL_HEALTH, SPRINKLER_MAX_HEALTH),
+        BOX_TYPE_GREENHOUSE => (GREENHOUSE_INITIAL_HEALTH, GREENHOUSE_MAX_HEALTH),
+        BOX_TYPE_FEEDING_TROUGH => (FEEDING_TROUGH_INITIAL_HEALTH, FEEDING_TROUGH_MAX_HEALTH),
+        BOX_TYPE_KENNEL => (KENNEL_INITIAL_HEALTH, KENNEL_MAX_HEALTH),
+        BOX_TYPE_STABLE => (STABLE_INITIAL_HEALTH, STABLE_MAX_HEALTH),
+        BOX_TYPE_TANNING_RACK => (TANNING_RACK_INITIAL_HEALTH, TANNING_RACK_MAX_HEALTH),
+        BOX_TYPE_WORKBENCH_T1 => (WORKBENCH_T1_INITIAL_HEALTH, WORKBENCH_T1_MAX_HEALTH),
+        BOX_TYPE_WORKBENCH_T2 => (WORKBENCH_T2_INITIAL_HEALTH, WORKBENCH_T2_MAX_HEALTH),
+        BOX_TYPE_WORKBENCH_T3 => (WORKBENCH_T3_INITIAL_HEALTH, WORKBENCH_T3_MAX_HEALTH),
+        BOX_TYPE_RESEARCH_TABLE => (RESEARCH_TABLE_INITIAL_HEALTH, RESEARCH_TABLE_MAX_HEALTH),
+        BOX_TYPE_RECYCLER => (RECYCLER_INITIAL_HEALTH, RECYCLER_MAX_HEALTH),
         BOX_TYPE_FISH_TRAP => (FISH_TRAP_INITIAL_HEALTH, FISH_TRAP_MAX_HEALTH),
         BOX_TYPE_PLAYER_BEEHIVE => (PLAYER_BEEHIVE_INITIAL_HEALTH, PLAYER_BEEHIVE_MAX_HEALTH),
         BOX_TYPE_WOLF_PELT | BOX_TYPE_FOX_PELT | BOX_TYPE_POLAR_BEAR_PELT | BOX_TYPE_WALRUS_PELT => {
@@ -1056,6 +821,17 @@ pub fn place_wooden_storage_box(ctx: &ReducerContext, item_instance_id: u64, wor
         BOX_TYPE_COMPOST => "Compost",
         BOX_TYPE_BACKPACK => "Backpack",
         BOX_TYPE_SCARECROW => "Scarecrow",
+        BOX_TYPE_SPRINKLER => "Sprinkler",
+        BOX_TYPE_GREENHOUSE => "Greenhouse",
+        BOX_TYPE_FEEDING_TROUGH => "Feeding Trough",
+        BOX_TYPE_KENNEL => "Kennel",
+        BOX_TYPE_STABLE => "Stable",
+        BOX_TYPE_TANNING_RACK => "Tanning Rack",
+        BOX_TYPE_WORKBENCH_T1 => "Workbench Tier 1",
+        BOX_TYPE_WORKBENCH_T2 => "Workbench Tier 2",
+        BOX_TYPE_WORKBENCH_T3 => "Workbench Tier 3",
+        BOX_TYPE_RESEARCH_TABLE => "Research Table",
+        BOX_TYPE_RECYCLER => "Shredder",
         BOX_TYPE_FISH_TRAP => "Fish Trap",
         BOX_TYPE_PLAYER_BEEHIVE => "Wooden Beehive",
         BOX_TYPE_WOLF_PELT => "Wolf Pelt",
@@ -1125,6 +901,10 @@ pub fn pickup_storage_box(ctx: &ReducerContext, box_id: u32) -> Result<(), Strin
             return Err("Cannot pick up storage box: It is not empty.".to_string());
         }
     }
+    if crate::kennel::is_kennel(storage_box_to_pickup.box_type)
+        && crate::kennel::count_stored_animals(ctx, storage_box_to_pickup.id) > 0 {
+        return Err("Cannot pick up kennel: Retrieve your animals first.".to_string());
+    }
 
     // 3. Find the correct ItemDefinition based on box type
     let item_name = match storage_box_to_pickup.box_type {
@@ -1134,6 +914,17 @@ pub fn pickup_storage_box(ctx: &ReducerContext, box_id: u32) -> Result<(), Strin
         BOX_TYPE_REPAIR_BENCH => "Repair Bench",
         BOX_TYPE_COOKING_STATION => "Cooking Station",
         BOX_TYPE_SCARECROW => "Scarecrow",
+        BOX_TYPE_SPRINKLER => "Sprinkler",
+        BOX_TYPE_GREENHOUSE => "Greenhouse",
+        BOX_TYPE_FEEDING_TROUGH => "Feeding Trough",
+        BOX_TYPE_KENNEL => "Kennel",
+        BOX_TYPE_STABLE => "Stable",
+        BOX_TYPE_TANNING_RACK => "Tanning Rack",
+        BOX_TYPE_WORKBENCH_T1 => "Workbench Tier 1",
+        BOX_TYPE_WORKBENCH_T2 => "Workbench Tier 2",
+        BOX_TYPE_WORKBENCH_T3 => "Workbench Tier 3",
+        BOX_TYPE_RESEARCH_TABLE => "Research Table",
+        BOX_TYPE_RECYCLER => "Shredder",
         BOX_TYPE_FISH_TRAP => "Fish Trap",
         BOX_TYPE_PLAYER_BEEHIVE => "Wooden Beehive",
         BOX_TYPE_WOLF_PELT => "Wolf Pelt",
@@ -1168,140 +959,6 @@ pub fn pickup_storage_box(ctx: &ReducerContext, box_id: u32) -> Result<(), Strin
     Ok(())
 }
 
-#[spacetimedb::reducer]
-pub fn drop_item_from_box_slot_to_world(
-    ctx: &ReducerContext,
-    box_id: u32,
-    slot_index: u8,
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player();
-    let mut wooden_box_table = ctx.db.wooden_storage_box();
-
-    log::info!("[DropFromBoxToWorld] Player {} attempting to drop item from box ID {}, slot index {}.", sender_id, box_id, slot_index);
-
-    // 1. Get Player (for drop position calculation and permission, though permission is implicit by calling)
-    let player = player_table.identity().find(sender_id)
-        .ok_or_else(|| format!("Player {} not found.", sender_id))?;
-
-    // 2. Get WoodenStorageBox
-    // Use validate_box_interaction to also check distance
-    let (_player_for_validation, mut wooden_box) = validate_box_interaction(ctx, box_id)?;
-    // We refetch player here specifically for the drop location, 
-    // as validate_box_interaction returns a potentially different instance.
-    let player_for_drop_location = player_table.identity().find(sender_id)
-        .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
-
-    // 3. Call the generic handler from inventory_management
-    // The handler will modify wooden_box (clear slot) and create dropped item, delete inventory_item.
-    crate::inventory_management::handle_drop_from_container_slot(ctx, &mut wooden_box, slot_index, &player_for_drop_location)?;
-
-    // Check box type before moving wooden_box
-    let is_backpack = wooden_box.box_type == BOX_TYPE_BACKPACK;
-    let is_military_ration = wooden_box.box_type == BOX_TYPE_MILITARY_RATION;
-    let is_military_crate = wooden_box.box_type == BOX_TYPE_MILITARY_CRATE;
-    let is_mine_cart = wooden_box.box_type == BOX_TYPE_MINE_CART;
-    let is_wild_beehive = wooden_box.box_type == BOX_TYPE_WILD_BEEHIVE;
-    let is_player_beehive = wooden_box.box_type == BOX_TYPE_PLAYER_BEEHIVE;
-
-    // 4. Persist changes to the WoodenStorageBox
-    wooden_box_table.id().update(wooden_box);
-    log::info!("[DropFromBoxToWorld] Successfully dropped item from box {}, slot {}. Box updated.", box_id, slot_index);
-    
-    // Stop beehive buzzing sound if Queen Bee was dropped from slot 0
-    if is_player_beehive && slot_index == 0 {
-        crate::sound_events::stop_beehive_sound(ctx, box_id as u64);
-    }
-
-    // Auto-despawn empty backpacks
-    if is_backpack {
-        let _ = crate::backpack::check_and_despawn_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty military rations
-    if is_military_ration {
-        let _ = crate::military_ration::check_and_despawn_military_ration_if_empty(ctx, box_id);
-    }
-    if is_military_crate {
-        let _ = crate::military_ration::check_and_despawn_military_crate_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty mine carts
-    if is_mine_cart {
-        let _ = crate::mine_cart::check_and_despawn_mine_cart_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty wild beehives
-    if is_wild_beehive {
-        let _ = crate::wild_beehive::check_and_despawn_wild_beehive_if_empty(ctx, box_id);
-    }
-
-    Ok(())
-}
-
-#[spacetimedb::reducer]
-pub fn split_and_drop_item_from_box_slot_to_world(
-    ctx: &ReducerContext,
-    box_id: u32,
-    slot_index: u8,
-    quantity_to_split: u32,
-) -> Result<(), String> {
-    let sender_id = ctx.sender();
-    let player_table = ctx.db.player();
-    let mut wooden_box_table = ctx.db.wooden_storage_box();
-
-    log::info!("[SplitDropFromBoxToWorld] Player {} attempting to split {} from box ID {}, slot {}.", 
-             sender_id, quantity_to_split, box_id, slot_index);
-
-    // 1. Get Player (for drop position calculation and permission)
-    // Use validate_box_interaction to also check distance
-    let (_player_for_validation, mut wooden_box) = validate_box_interaction(ctx, box_id)?;
-    // Refetch player for drop location
-    let player_for_drop_location = player_table.identity().find(sender_id)
-        .ok_or_else(|| format!("Player {} not found for drop location.", sender_id))?;
-
-
-    // 3. Call the generic handler from inventory_management
-    crate::inventory_management::handle_split_and_drop_from_container_slot(ctx, &mut wooden_box, slot_index, quantity_to_split, &player_for_drop_location)?;
-
-    // Check box type before moving wooden_box
-    let is_backpack = wooden_box.box_type == BOX_TYPE_BACKPACK;
-    let is_military_ration = wooden_box.box_type == BOX_TYPE_MILITARY_RATION;
-    let is_military_crate = wooden_box.box_type == BOX_TYPE_MILITARY_CRATE;
-    let is_mine_cart = wooden_box.box_type == BOX_TYPE_MINE_CART;
-    let is_wild_beehive = wooden_box.box_type == BOX_TYPE_WILD_BEEHIVE;
-
-    // 4. Persist changes to the WoodenStorageBox (if its slot was cleared because the whole stack was dropped)
-    wooden_box_table.id().update(wooden_box); 
-
-    log::info!("[SplitDropFromBoxToWorld] Successfully split and dropped from box {}, slot {}. Box updated if slot cleared.", box_id, slot_index);
-
-    // Auto-despawn empty backpacks
-    if is_backpack {
-        let _ = crate::backpack::check_and_despawn_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty military rations
-    if is_military_ration {
-        let _ = crate::military_ration::check_and_despawn_military_ration_if_empty(ctx, box_id);
-    }
-    if is_military_crate {
-        let _ = crate::military_ration::check_and_despawn_military_crate_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty mine carts
-    if is_mine_cart {
-        let _ = crate::mine_cart::check_and_despawn_mine_cart_if_empty(ctx, box_id);
-    }
-    
-    // Auto-despawn empty wild beehives
-    if is_wild_beehive {
-        let _ = crate::wild_beehive::check_and_despawn_wild_beehive_if_empty(ctx, box_id);
-    }
-    
-    Ok(())
-}
-
 /******************************************************************************
  *                            TRAIT IMPLEMENTATIONS                           *
  ******************************************************************************/
@@ -1326,8 +983,20 @@ impl ItemContainer for WoodenStorageBox {
             BOX_TYPE_REPAIR_BENCH => NUM_REPAIR_BENCH_SLOTS,
             BOX_TYPE_COOKING_STATION => NUM_COOKING_STATION_SLOTS,
             BOX_TYPE_SCARECROW => NUM_SCARECROW_SLOTS,
+            BOX_TYPE_SPRINKLER => NUM_SPRINKLER_SLOTS,
+            BOX_TYPE_GREENHOUSE => NUM_GREENHOUSE_SLOTS,
+            BOX_TYPE_FEEDING_TROUGH => NUM_FEEDING_TROUGH_SLOTS,
+            BOX_TYPE_KENNEL => NUM_KENNEL_SLOTS,
+            BOX_TYPE_STABLE => NUM_STABLE_SLOTS,
+            BOX_TYPE_TANNING_RACK => NUM_TANNING_RACK_SLOTS,
+            BOX_TYPE_WORKBENCH_T1 | BOX_TYPE_WORKBENCH_T2 | BOX_TYPE_WORKBENCH_T3 => NUM_WORKBENCH_SLOTS,
+            BOX_TYPE_RESEARCH_TABLE => NUM_RESEARCH_TABLE_SLOTS,
+            BOX_TYPE_RECYCLER => NUM_RECYCLER_SLOTS,
+            BOX_TYPE_LOCKED_CRATE => NUM_LOCKED_CRATE_SLOTS,
+            BOX_TYPE_VAULT_CRATE => NUM_VAULT_CRATE_SLOTS,
             BOX_TYPE_MILITARY_RATION => NUM_MILITARY_RATION_SLOTS,
             BOX_TYPE_MILITARY_CRATE => NUM_MILITARY_CRATE_SLOTS,
+            BOX_TYPE_SUPPLY_DROP => NUM_SUPPLY_DROP_SLOTS,
             BOX_TYPE_MINE_CART => NUM_MINE_CART_SLOTS,
             BOX_TYPE_FISH_TRAP => NUM_FISH_TRAP_SLOTS,
             BOX_TYPE_WILD_BEEHIVE => NUM_WILD_BEEHIVE_SLOTS,
@@ -1582,12 +1251,24 @@ impl ContainerItemClearer for WoodenStorageBoxClearer {
  ******************************************************************************/
 
 /// --- Validate Box Interaction ---
-/// Validates if a player can interact with a specific box (checks existence and distance).
+/// Validates if a player can interact with a specific box (checks existence, distance, crate locks and vault doors).
 /// Returns Ok((Player struct instance, WoodenStorageBox struct instance)) on success, or Err(String) on failure.
 /// Does NOT check ownership.
 pub fn validate_box_interaction(
     ctx: &ReducerContext,
     box_id: u32,
+) -> Result<(Player, WoodenStorageBox), String> {
+    let (player, storage_box) = validate_box_reach(ctx, box_id)?;
+    crate::locked_crate::ensure_crate_unlocked(ctx, &storage_box)?;
+    crate::monument_puzzle::ensure_vault_door_open(ctx, &storage_box)?;
+    Ok((player, storage_box))
+}
+
+/// --- Validate Box Reach ---
+/// Like validate_box_interaction but ignores crate locks - for reducers that act on the lock itself.
+pub fn validate_box_reach(
+    ctx: &ReducerContext,
+    box_id: u32,
 ) -> Result<(Player, WoodenStorageBox), String> { // Use corrected Player type
     let sender_id = ctx.sender();
     let players = ctx.db.player();
@@ -1657,98 +1338,6 @@ pub fn validate_box_interaction(
     Ok((player, storage_box))
 }
 
-/// --- Sort Storage Box ---
-/// Sorts items in the storage box by item type (alphabetically by name).
-/// Items of the same type appear next to each other for easier organization.
-#[spacetimedb::reducer]
-pub fn sort_storage_box(ctx: &ReducerContext, box_id: u32) -> Result<(), String> {
-    let mut boxes = ctx.db.wooden_storage_box();
-    let mut inventory_items = ctx.db.inventory_item();
-    let item_defs = ctx.db.item_definition();
-
-    let (_player, mut storage_box) = validate_box_interaction(ctx, box_id)?;
-
-    let num_slots = storage_box.num_slots();
-    if num_slots <= 1 {
-        return Err("Container has too few slots to sort.".to_string());
-    }
-
-    // Collect all items with their slot index, instance ID, def ID, category, and item name
-    let mut items_with_meta: Vec<(u8, u64, u64, crate::items::ItemCategory, String)> = Vec::new();
-    for slot in 0..num_slots as u8 {
-        if let (Some(inst_id), Some(def_id)) = (
-            storage_box.get_slot_instance_id(slot),
-            storage_box.get_slot_def_id(slot),
-        ) {
-            let (category, item_name) = item_defs
-                .id()
-                .find(def_id)
-                .map(|def| (def.category.clone(), def.name.clone()))
-                .unwrap_or_else(|| {
-                    (
-                        crate::items::ItemCategory::Material,
-                        format!("def_{}", def_id),
-                    )
-                });
-            items_with_meta.push((slot, inst_id, def_id, category, item_name));
-        }
-    }
-
-    if items_with_meta.is_empty() {
-        return Ok(()); // Nothing to sort
-    }
-
-    // Category sort order: Materials first, then Consumables, Ammunition, Tools, Weapons, Armor, Placeables
-    fn category_sort_order(cat: &crate::items::ItemCategory) -> u8 {
-        use crate::items::ItemCategory;
-        match cat {
-            ItemCategory::Material => 0,
-            ItemCategory::Consumable => 1,
-            ItemCategory::Ammunition => 2,
-            ItemCategory::Tool => 3,
-            ItemCategory::Weapon => 4,
-            ItemCategory::RangedWeapon => 5,
-            ItemCategory::Armor => 6,
-            ItemCategory::Placeable => 7,
-        }
-    }
-
-    // Sort by category first, then alphabetically by name within category
-    items_with_meta.sort_by(|a, b| {
-        let cat_a = category_sort_order(&a.3);
-        let cat_b = category_sort_order(&b.3);
-        cat_a.cmp(&cat_b).then_with(|| a.4.cmp(&b.4))
-    });
-
-    // Clear all slots
-    for slot in 0..num_slots as u8 {
-        storage_box.set_slot(slot, None, None);
-    }
-
-    // Place items back in sorted order and update their locations
-    let container_id = storage_box.id as u64;
-    let container_type = storage_box.get_container_type();
-
-    for (new_slot, (_, inst_id, def_id, _, _)) in items_with_meta.into_iter().enumerate() {
-        let new_slot = new_slot as u8;
-        storage_box.set_slot(new_slot, Some(inst_id), Some(def_id));
-
-        if let Some(mut item) = inventory_items.instance_id().find(&inst_id) {
-            item.location = ItemLocation::Container(ContainerLocationData {
-                container_type: container_type.clone(),
-                container_id,
-                slot_index: new_slot,
-            });
-            inventory_items.instance_id().update(item);
-        }
-    }
-
-    boxes.id().update(storage_box);
-    log::debug!("Player {:?} sorted storage box {}", ctx.sender(), box_id);
-
-    Ok(())
-}
-
 /// --- Open Storage Box Container ---
 /// Called when a player opens the storage box UI. Sets the active_user_id to prevent
 /// other players from using this container in safe zones.
@@ -1759,8 +1348,14 @@ pub fn open_storage_box_container(ctx: &ReducerContext, box_id: u32) -> Result<(
     // Set the active user
     storage_box.active_user_id = Some(ctx.sender());
     storage_box.active_user_since = Some(ctx.timestamp);
+    let is_supply_drop = storage_box.box_type == BOX_TYPE_SUPPLY_DROP;
     
     ctx.db.wooden_storage_box().id().update(storage_box);
+
+    // Opening a supply drop claims it (stops the unopened-despawn timer)
+    if is_supply_drop {
+        crate::supply_drop::on_supply_crate_opened(ctx, box_id);
+    }
     log::debug!("Player {:?} opened storage box {} container", ctx.sender(), box_id);
     
     Ok(())
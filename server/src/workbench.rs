@@ -0,0 +1,60 @@
+/******************************************************************************
+ *                                                                            *
+ * Workbench - Tiered crafting progression                                    *
+ *                                                                            *
+ * Workbenches are WoodenStorageBox entities with no inventory (one box type  *
+ * per tier, like the cooking station). Recipes carry a required workbench    *
+ * tier seeded from their item definition; the crafting reducers refuse to    *
+ * start a recipe unless the player stands within range of a bench of at      *
+ * least that tier. A higher-tier bench covers every tier below it.           *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{Identity, ReducerContext, Table};
+
+use crate::wooden_storage_box::{
+    BOX_COLLISION_Y_OFFSET, BOX_TYPE_WORKBENCH_T1, BOX_TYPE_WORKBENCH_T2, BOX_TYPE_WORKBENCH_T3,
+    wooden_storage_box as WoodenStorageBoxTableTrait,
+};
+use crate::player as PlayerTableTrait;
+
+/// How close a player must stand to a workbench to use it (matches the cooking station)
+pub const WORKBENCH_CRAFTING_DISTANCE: f32 = 250.0;
+pub const WORKBENCH_CRAFTING_DISTANCE_SQUARED: f32 = WORKBENCH_CRAFTING_DISTANCE * WORKBENCH_CRAFTING_DISTANCE;
+
+/// Workbench tier provided by a box type (0 = not a workbench)
+pub fn workbench_tier(box_type: u8) -> u8 {
+    match box_type {
+        BOX_TYPE_WORKBENCH_T1 => 1,
+        BOX_TYPE_WORKBENCH_T2 => 2,
+        BOX_TYPE_WORKBENCH_T3 => 3,
+        _ => 0,
+    }
+}
+
+/// Highest workbench tier within crafting range of a position (0 = none nearby)
+pub fn nearby_workbench_tier(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> u8 {
+    ctx.db.wooden_storage_box().iter()
+        .filter(|b| !b.is_destroyed)
+        .filter(|b| {
+            let dx = pos_x - b.pos_x;
+            let dy = pos_y - (b.pos_y - BOX_COLLISION_Y_OFFSET); // Adjust for visual offset
+            dx * dx + dy * dy <= WORKBENCH_CRAFTING_DISTANCE_SQUARED
+        })
+        .map(|b| workbench_tier(b.box_type))
+        .max()
+        .unwrap_or(0)
+}
+
+/// Ensures the player is near a workbench good enough for a recipe
+pub fn check_workbench_requirement(ctx: &ReducerContext, player_id: Identity, required_tier: u8, item_name: &str) -> Result<(), String> {
+    if required_tier == 0 {
+        return Ok(());
+    }
+    let player = ctx.db.player().identity().find(&player_id)
+        .ok_or_else(|| "Player not found.".to_string())?;
+    if nearby_workbench_tier(ctx, player.position_x, player.position_y) < required_tier {
+        return Err(format!("You must be near a Tier {} Workbench to craft {}.", required_tier, item_name));
+    }
+    Ok(())
+}
@@ -15,7 +15,8 @@ use crate::tide_pool as TidePoolTableTrait;
 
 use rand::{Rng, SeedableRng};
 use rand::rngs::StdRng;
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 
 // --- Hot Spring Constants (moved from hot_spring.rs) ---
 /// Base density for 600x600 map (360k tiles²) = 4 hot springs (increased for better visibility)
@@ -64,12 +65,99 @@ const TIDE_POOL_MAX_SHORE_DIST: f64 = 15.0;  // At most 15 tiles inland (beach z
 /// Radius of water inlet in center of tide pool (pixels) - creates actual water tiles like a coastal inlet
 const TIDE_POOL_INLET_RADIUS_PX: f32 = 90.0;
 
+/// Seed the current world terrain was generated from (singleton, id 0)
+#[spacetimedb::table(accessor = world_seed, public)]
+#[derive(Clone, Debug)]
+pub struct WorldSeed {
+    #[primary_key]
+    pub id: u32,
+    pub seed: u64,
+    pub generated_at: Timestamp,
+}
+
+/// Standard generation settings for the live world
+pub fn default_world_gen_config(seed: u64) -> WorldGenConfig {
+    WorldGenConfig {
+        seed,
+        world_width_tiles: WORLD_WIDTH_TILES,
+        world_height_tiles: WORLD_HEIGHT_TILES,
+        chunk_size: crate::environment::CHUNK_SIZE_TILES, // Use the same chunk size as runtime lookups
+        island_border_width: 5,
+        beach_width: 3,
+        river_frequency: 0.3,
+        dirt_patch_frequency: 0.2,
+        road_density: 0.1,
+        tundra_fraction: 0.23,
+        forest_density: 1.0,
+        quarry_count_scale: 1.0,
+        hot_spring_count_scale: 1.0,
+        wolf_den_count: 2,
+        forest_ruin_count: 14,
+    }
+}
+
+// --- World Gen Config Limits ---
+const MIN_WORLD_SIZE_TILES: u32 = 100;
+const MAX_WORLD_SIZE_TILES: u32 = 2000;
+const MAX_TUNDRA_FRACTION: f32 = 0.6; // Alpine takes the top 35%, leave some temperate land
+const MAX_DENSITY_SCALE: f32 = 4.0;
+const MAX_WOLF_DEN_COUNT: u32 = 8;
+const MAX_FOREST_RUIN_COUNT: u32 = 64;
+
+/// Rejects generation settings the generator can't honor
+pub fn validate_world_gen_config(config: &WorldGenConfig) -> Result<(), String> {
+    for (name, size) in [("world_width_tiles", config.world_width_tiles), ("world_height_tiles", config.world_height_tiles)] {
+        if !(MIN_WORLD_SIZE_TILES..=MAX_WORLD_SIZE_TILES).contains(&size) {
+            return Err(format!("{} must be between {} and {} (got {}).", name, MIN_WORLD_SIZE_TILES, MAX_WORLD_SIZE_TILES, size));
+        }
+    }
+    if config.chunk_size == 0 {
+        return Err("chunk_size must be greater than 0.".to_string());
+    }
+    if !(0.0..=MAX_TUNDRA_FRACTION).contains(&config.tundra_fraction) {
+        return Err(format!("tundra_fraction must be between 0.0 and {} (got {}).", MAX_TUNDRA_FRACTION, config.tundra_fraction));
+    }
+    for (name, scale) in [
+        ("forest_density", config.forest_density),
+        ("quarry_count_scale", config.quarry_count_scale),
+        ("hot_spring_count_scale", config.hot_spring_count_scale),
+    ] {
+        if !(0.0..=MAX_DENSITY_SCALE).contains(&scale) {
+            return Err(format!("{} must be between 0.0 and {} (got {}).", name, MAX_DENSITY_SCALE, scale));
+        }
+    }
+    if config.wolf_den_count > MAX_WOLF_DEN_COUNT {
+        return Err(format!("wolf_den_count must be at most {} (got {}).", MAX_WOLF_DEN_COUNT, config.wolf_den_count));
+    }
+    if config.forest_ruin_count > MAX_FOREST_RUIN_COUNT {
+        return Err(format!("forest_ruin_count must be at most {} (got {}).", MAX_FOREST_RUIN_COUNT, config.forest_ruin_count));
+    }
+    Ok(())
+}
+
+fn record_world_seed(ctx: &ReducerContext, seed: u64) {
+    let record = WorldSeed { id: 0, seed, generated_at: ctx.timestamp };
+    if ctx.db.world_seed().id().find(0).is_some() {
+        ctx.db.world_seed().id().update(record);
+    } else {
+        ctx.db.world_seed().insert(record);
+    }
+}
+
 #[spacetimedb::reducer]
 pub fn generate_world(ctx: &ReducerContext, config: WorldGenConfig) -> Result<(), String> {
     log::info!(
         "Starting world generation with seed {} ({}x{} tiles, {} chunk size)",
         config.seed, config.world_width_tiles, config.world_height_tiles, config.chunk_size
     );
+    validate_world_gen_config(&config)?;
+    if config.world_width_tiles != WORLD_WIDTH_TILES || config.world_height_tiles != WORLD_HEIGHT_TILES {
+        // Runtime systems (movement bounds, chunk lookups, the client) use the shared_config size
+        log::warn!(
+            "Generating a {}x{} map, but the server runs with {}x{} tiles - update shared_config to match before serving it",
+            config.world_width_tiles, config.world_height_tiles, WORLD_WIDTH_TILES, WORLD_HEIGHT_TILES
+        );
+    }
 
     // TEMPORARILY REMOVED: Security check for testing
     // if ctx.sender() != ctx.identity() {
@@ -95,6 +183,8 @@ pub fn generate_world(ctx: &ReducerContext, config: WorldGenConfig) -> Result<()
         }
     }
     
+    record_world_seed(ctx, config.seed);
+
     // Use the seed to create reproducible noise
     let noise = Perlin::new(config.seed as u32);
     
@@ -205,6 +295,11 @@ pub fn generate_world(ctx: &ReducerContext, config: WorldGenConfig) -> Result<()
                 Ok(count) => log::info!("🚢 Spawned {} monument placeables at Shipwreck", count),
                 Err(e) => log::warn!("Failed to spawn shipwreck placeables: {}", e),
             }
+            // Green keycard crate - first stop of the keycard loop
+            match crate::locked_crate::spawn_locked_crate(ctx, crate::locked_crate::KEYCARD_GREEN, center_x + 120.0, center_y + 160.0) {
+                Ok(id) => log::info!("🚢 Spawned green locked crate {} at Shipwreck", id),
+                Err(e) => log::warn!("Failed to spawn shipwreck locked crate: {}", e),
+            }
         }
     }
     
@@ -491,6 +586,10 @@ pub fn generate_world(ctx: &ReducerContext, config: WorldGenConfig) -> Result<()
             Ok(id) => log::info!("📡 Spawned military crate {} at Weather Station ({:.1}, {:.1})", id, crate_x, crate_y),
             Err(e) => log::warn!("Failed to spawn weather station military crate: {}", e),
         }
+        // Vault puzzle room on the far side of the station
+        if let Err(e) = crate::monument_puzzle::spawn_puzzle_room(ctx, &crate::monument_puzzle::weather_station_vault(), center_x, center_y) {
+            log::warn!("Failed to spawn weather station puzzle room: {}", e);
+        }
     }
     
     // Store alpine village positions in database table for client access
@@ -592,6 +691,10 @@ pub fn generate_world(ctx: &ReducerContext, config: WorldGenConfig) -> Result<()
         log::info!("🐺 Total wolves spawned at {} wolf dens: {}", world_features.wolf_den_centers.len(), total_wolves_spawned);
     }
     
+    // Scatter small forest ruins (abandoned cabins, ruined towers) through forest and tundra
+    // Placed after the named monuments so the monument clearance check keeps them apart
+    scatter_forest_ruins(ctx, &world_features, config.forest_ruin_count as usize);
+    
     // Store large quarry positions and types in database for client minimap display
     // Similar to shipwreck - client reads once, then treats as static config
     for (tile_x, tile_y, radius, quarry_type) in &world_features.large_quarry_centers {
@@ -608,6 +711,15 @@ pub fn generate_world(ctx: &ReducerContext, config: WorldGenConfig) -> Result<()
         });
     }
     
+    // Red keycard crates sit in the middle of every large quarry - the end of the keycard loop
+    for (tile_x, tile_y, _radius, _quarry_type) in &world_features.large_quarry_centers {
+        let world_x_px = (*tile_x + 0.5) * crate::TILE_SIZE_PX as f32;
+        let world_y_px = (*tile_y + 0.5) * crate::TILE_SIZE_PX as f32;
+        if let Err(e) = crate::locked_crate::spawn_locked_crate(ctx, crate::locked_crate::KEYCARD_RED, world_x_px, world_y_px) {
+            log::warn!("Failed to spawn quarry locked crate: {}", e);
+        }
+    }
+    
     if !world_features.large_quarry_centers.is_empty() {
         log::info!("Stored {} large quarry locations in database - client reads once for minimap labels", 
                    world_features.large_quarry_centers.len());
@@ -658,6 +770,96 @@ pub fn generate_world(ctx: &ReducerContext, config: WorldGenConfig) -> Result<()
     Ok(())
 }
 
+// --- Forest Ruins ---
+const FOREST_RUIN_PLACEMENT_ATTEMPTS: usize = 800;
+const FOREST_RUIN_MIN_SPACING_PX: f32 = 1600.0; // Keep ruins from clumping together
+const FOREST_RUIN_MIN_SHORE_DISTANCE: f64 = 10.0; // Tiles inland - keeps them off beaches and substations
+const FOREST_RUIN_CABIN_CHANCE: f32 = 0.6; // Otherwise a ruined tower
+const FOREST_RUIN_HOSTILE_CHANCE: f32 = 0.3; // Chance a ruin has a predator lurking nearby
+
+/// Scatters abandoned cabins and ruined towers across forest and tundra tiles.
+/// Each ruin is stored as a monument part (so it gets a clearance zone), gets a few loot containers,
+/// and sometimes a hostile animal: a wolverine in forest, a tundra wolf in tundra.
+fn scatter_forest_ruins(ctx: &ReducerContext, world_features: &WorldFeatures, max_ruins: usize) {
+    use crate::wild_animal_npc::AnimalSpecies;
+    
+    let width = world_features.forest_areas.first().map_or(0, |row| row.len());
+    let height = world_features.forest_areas.len();
+    if width == 0 || height == 0 {
+        return;
+    }
+    let tile_size_px = crate::TILE_SIZE_PX as f32;
+    let mut ruins: Vec<(f32, f32)> = Vec::new();
+    
+    for _ in 0..FOREST_RUIN_PLACEMENT_ATTEMPTS {
+        if ruins.len() >= max_ruins {
+            break;
+        }
+        let x = ctx.rng().gen_range(0..width);
+        let y = ctx.rng().gen_range(0..height);
+        let in_tundra = world_features.tundra_areas[y][x];
+        if !(world_features.forest_areas[y][x] || in_tundra) {
+            continue;
+        }
+        if world_features.shore_distance[y][x] < FOREST_RUIN_MIN_SHORE_DISTANCE
+            || world_features.river_network[y][x]
+            || world_features.lake_map[y][x]
+            || world_features.road_network[y][x]
+            || world_features.hot_spring_water[y][x]
+            || is_near_cliff(world_features, x, y, 4)
+            || is_too_close_to_center_compound(x, y, width, height)
+        {
+            continue;
+        }
+        
+        let world_x = (x as f32 + 0.5) * tile_size_px;
+        let world_y = (y as f32 + 0.5) * tile_size_px;
+        let too_close_to_ruin = ruins.iter().any(|(rx, ry)| {
+            let dx = world_x - rx;
+            let dy = world_y - ry;
+            dx * dx + dy * dy < FOREST_RUIN_MIN_SPACING_PX * FOREST_RUIN_MIN_SPACING_PX
+        });
+        if too_close_to_ruin || crate::monument::is_position_near_monument(ctx, world_x, world_y) {
+            continue;
+        }
+        
+        let is_cabin = ctx.rng().gen::<f32>() < FOREST_RUIN_CABIN_CHANCE;
+        let (monument_type, name, image_path, collision_radius, placeables) = if is_cabin {
+            (MonumentType::AbandonedCabin, "Abandoned Cabin", "abandoned_cabin.png", 70.0, crate::monument::get_abandoned_cabin_placeables())
+        } else {
+            (MonumentType::RuinedTower, "Ruined Tower", "ruined_tower.png", 50.0, crate::monument::get_ruined_tower_placeables())
+        };
+        
+        ctx.db.monument_part().insert(MonumentPart {
+            id: 0, // auto_inc
+            monument_type,
+            world_x,
+            world_y,
+            image_path: image_path.to_string(),
+            part_type: if is_cabin { "cabin".to_string() } else { "tower".to_string() },
+            is_center: true, // Single-part prefab
+            collision_radius,
+            rotation_rad: 0.0,
+        });
+        ruins.push((world_x, world_y));
+        
+        if let Err(e) = crate::monument::spawn_monument_placeables(ctx, name, world_x, world_y, &placeables) {
+            log::warn!("Failed to spawn {} loot at ({:.0}, {:.0}): {}", name, world_x, world_y, e);
+        }
+        
+        if ctx.rng().gen::<f32>() < FOREST_RUIN_HOSTILE_CHANCE {
+            let species = if in_tundra { AnimalSpecies::TundraWolf } else { AnimalSpecies::Wolverine };
+            let angle = ctx.rng().gen_range(0.0..std::f32::consts::TAU);
+            let distance = ctx.rng().gen_range(150.0..250.0);
+            if let Err(e) = crate::wild_animal_npc::spawn_wild_animal(ctx, species, world_x + angle.cos() * distance, world_y + angle.sin() * distance) {
+                log::warn!("Failed to spawn {} guard animal: {}", name, e);
+            }
+        }
+    }
+    
+    log::info!("🏚️ Scattered {} forest ruins (abandoned cabins and ruined towers)", ruins.len());
+}
+
 fn carve_tide_pool_inlets_post_pass(
     ctx: &ReducerContext,
     noise: &Perlin,
@@ -719,6 +921,7 @@ struct WorldFeatures {
     river_network: Vec<Vec<bool>>,
     lake_map: Vec<Vec<bool>>,
     road_network: Vec<Vec<bool>>,
+    road_bridges: Vec<Vec<bool>>, // Causeway tiles where monument link roads cross rivers/lakes
     dirt_paths: Vec<Vec<bool>>,
     hot_spring_water: Vec<Vec<bool>>, // Hot spring water (inner pool)
     hot_spring_beach: Vec<Vec<bool>>, // Hot spring beach (shore)
@@ -762,6 +965,8 @@ struct WorldFeatures {
     weather_station_roads: Vec<Vec<bool>>, // Dirt roads: spur to main road + paths to radars
     weather_station_center_dirt: Vec<Vec<bool>>, // Central jagged squarish area (rendered as asphalt)
     weather_station_grass_zone: Vec<Vec<bool>>, // Overrun with grass around center
+    cliff_map: Vec<Vec<bool>>, // Impassable cliff faces along terrain contours
+    ramp_map: Vec<Vec<bool>>, // Walkable ramps cut through cliff lines (roads + gaps)
     width: usize,
     height: usize,
 }
@@ -798,7 +1003,7 @@ fn generate_world_features(config: &WorldGenConfig, noise: &Perlin) -> WorldFeat
     let lake_map = generate_lakes(config, noise, &shore_distance, width, height);
     
     // Generate road network from corners to center
-    let road_network = generate_road_network(config, noise, width, height);
+    let mut road_network = generate_road_network(config, noise, width, height);
     
     // Generate additional dirt paths
     let dirt_paths = generate_dirt_paths(config, noise, &road_network, width, height);
@@ -885,10 +1090,10 @@ fn generate_world_features(config: &WorldGenConfig, noise: &Perlin) -> WorldFeat
     
     // Generate wolf den monuments in tundra biome (wolf pack spawn points)
     // Single wolf mound structures - spawns a pack of wolves each - NOT safe zones
-    // Can spawn up to 2 wolf dens in the tundra
+    // Spawns up to config.wolf_den_count wolf dens in the tundra
     // Must be away from hot springs, quarries, and other monuments
     let (wolf_den_centers, wolf_den_parts) = crate::monument::generate_wolf_den(
-        noise, &shore_distance, &river_network, &lake_map, &tundra_areas, &hot_spring_centers,
+        config.wolf_den_count as usize, noise, &shore_distance, &river_network, &lake_map, &tundra_areas, &hot_spring_centers,
         &shipwreck_centers, fishing_village_center, whale_bone_graveyard_center, hunting_village_center,
         crashed_research_drone_center, weather_station_center, alpine_village_center, &large_quarry_positions, width, height
     );
@@ -902,6 +1107,14 @@ fn generate_world_features(config: &WorldGenConfig, noise: &Perlin) -> WorldFeat
     // Generate tide pool centers (coastal beach inlets - crabs, terns, reeds, washed-up items)
     let tide_pool_centers = generate_tide_pool_centers(config, noise, &river_network, &lake_map, &shore_distance, width, height);
     
+    // Link the compound, substations, fishing village and large quarries into one road graph
+    // (A* around the sea) so roadside barrels form farming routes between them
+    let mut road_bridges = vec![vec![false; width]; height];
+    link_monuments_with_roads(
+        &mut road_network, &mut road_bridges, &shore_distance, &river_network, &lake_map, &hot_spring_water,
+        fishing_village_center, &large_quarry_centers, width, height
+    );
+    
     // Generate village dirt roads (fishing + hunting + alpine + weather station) - for lampposts and village atmosphere
     // Hunting village: center dirt (plaza) + farm dirt (crops) + roads (paths leading to center)
     // Alpine village: path to lodge + dirt center + grass zone (overrun with grass)
@@ -923,12 +1136,13 @@ fn generate_world_features(config: &WorldGenConfig, noise: &Perlin) -> WorldFeat
             height,
         );
     
-    WorldFeatures {
+    let mut features = WorldFeatures {
         heightmap,
         shore_distance,
         river_network,
         lake_map,
         road_network,
+        road_bridges,
         dirt_paths,
         hot_spring_water,
         hot_spring_beach,
@@ -972,9 +1186,134 @@ fn generate_world_features(config: &WorldGenConfig, noise: &Perlin) -> WorldFeat
         weather_station_roads,
         weather_station_center_dirt,
         weather_station_grass_zone,
+        cliff_map: Vec::new(),
+        ramp_map: Vec::new(),
         width,
         height,
+    };
+    
+    // Cliffs go in last so they can keep clear of every road, water body and monument
+    let (cliff_map, ramp_map) = generate_cliffs(noise, &features);
+    features.cliff_map = cliff_map;
+    features.ramp_map = ramp_map;
+    features
+}
+
+// --- Cliffs ---
+const CLIFF_HEIGHT_LEVELS: [f64; 2] = [0.25, 0.5]; // Heightmap contours that become cliff lines
+const CLIFF_RIDGE_MASK_THRESHOLD: f64 = 0.1; // Only parts of each contour become cliffs, the rest stays gentle slope
+const CLIFF_RAMP_NOISE_THRESHOLD: f64 = 0.45; // Noise-placed ramps break up long cliff lines
+const CLIFF_MIN_SHORE_DISTANCE: f64 = 25.0; // Keep beaches and the coast flat
+const CLIFF_MONUMENT_CLEARANCE_TILES: i32 = 20; // No cliffs across monuments
+const CLIFF_ROAD_RAMP_RADIUS: i32 = 2; // Roads cut a ramp this wide through any cliff they cross
+
+/// Carves cliff lines along heightmap contours, giving the flat island elevated terraces.
+/// A contour tile becomes cliff where a low-frequency ridge mask allows it, so cliffs form
+/// scattered escarpments rather than closed rings. Ramps are cut wherever a road crosses a
+/// cliff and at noise-placed gaps along each line. Coast, water, quarries, compounds and
+/// monuments are kept clear. Returns (cliff_map, ramp_map); ramp tiles are never cliff tiles.
+fn generate_cliffs(noise: &Perlin, features: &WorldFeatures) -> (Vec<Vec<bool>>, Vec<Vec<bool>>) {
+    let width = features.width;
+    let height = features.height;
+    let tile_size_px = crate::TILE_SIZE_PX as f32;
+    let mut cliff_map = vec![vec![false; width]; height];
+    let mut ramp_map = vec![vec![false; width]; height];
+    
+    // Monument centers (tiles) that cliffs must stay clear of
+    let mut monument_tiles: Vec<(i32, i32)> = vec![(width as i32 / 2, height as i32 / 2)];
+    let px_centers = features.shipwreck_centers.iter()
+        .chain(features.wolf_den_centers.iter())
+        .chain(features.reed_marsh_centers.iter())
+        .chain(features.tide_pool_centers.iter())
+        .copied()
+        .chain([
+            features.fishing_village_center,
+            features.whale_bone_graveyard_center,
+            features.hunting_village_center,
+            features.crashed_research_drone_center,
+            features.weather_station_center,
+            features.alpine_village_center,
+        ].into_iter().flatten());
+    for (px, py) in px_centers {
+        monument_tiles.push(((px / tile_size_px) as i32, (py / tile_size_px) as i32));
+    }
+    // Quarries and hot springs are stored in tiles with their radius
+    let round_areas: Vec<(i32, i32, i32)> = features.quarry_centers.iter()
+        .chain(features.hot_spring_centers.iter())
+        .map(|(x, y, r)| (*x as i32, *y as i32, *r + 6))
+        .collect();
+    
+    let is_road = |x: usize, y: usize| {
+        features.road_network[y][x] || features.dirt_paths[y][x] || features.quarry_roads[y][x]
+            || features.fishing_village_roads[y][x] || features.hunting_village_roads[y][x]
+            || features.alpine_village_roads[y][x] || features.weather_station_roads[y][x]
+    };
+    let is_protected = |x: usize, y: usize| {
+        features.shore_distance[y][x] < CLIFF_MIN_SHORE_DISTANCE
+            || features.river_network[y][x] || features.lake_map[y][x]
+            || features.hot_spring_water[y][x] || features.hot_spring_beach[y][x]
+            || features.quarry_dirt[y][x]
+            || features.asphalt_compound[y][x] || features.compound_dirt_ring[y][x]
+            || features.hunting_village_center_dirt[y][x] || features.hunting_village_farm_dirt[y][x]
+            || features.alpine_village_center_dirt[y][x] || features.alpine_village_grass_zone[y][x]
+            || features.weather_station_center_dirt[y][x] || features.weather_station_grass_zone[y][x]
+    };
+    let near_monument = |x: i32, y: i32| {
+        let clearance_sq = CLIFF_MONUMENT_CLEARANCE_TILES * CLIFF_MONUMENT_CLEARANCE_TILES;
+        monument_tiles.iter().any(|(mx, my)| (x - mx).pow(2) + (y - my).pow(2) < clearance_sq)
+            || round_areas.iter().any(|(cx, cy, r)| (x - cx).pow(2) + (y - cy).pow(2) < r * r)
+    };
+    
+    let mut cliff_tiles = 0;
+    let mut ramp_tiles = 0;
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            let h = features.heightmap[y][x];
+            // High side of a contour: at or above a level with a neighbour below it (2 tiles thick)
+            let on_contour = CLIFF_HEIGHT_LEVELS.iter().any(|&level| {
+                h >= level && (-1..=1i32).any(|dy| (-1..=1i32).any(|dx| {
+                    features.heightmap[(y as i32 + dy) as usize][(x as i32 + dx) as usize] < level
+                }))
+            });
+            if !on_contour {
+                continue;
+            }
+            if noise.get([x as f64 * 0.012, y as f64 * 0.012, 9300.0]) < CLIFF_RIDGE_MASK_THRESHOLD {
+                continue;
+            }
+            if is_protected(x, y) || near_monument(x as i32, y as i32) {
+                continue;
+            }
+            
+            let road_nearby = (-CLIFF_ROAD_RAMP_RADIUS..=CLIFF_ROAD_RAMP_RADIUS).any(|dy| {
+                (-CLIFF_ROAD_RAMP_RADIUS..=CLIFF_ROAD_RAMP_RADIUS).any(|dx| {
+                    let rx = x as i32 + dx;
+                    let ry = y as i32 + dy;
+                    rx >= 0 && ry >= 0 && rx < width as i32 && ry < height as i32 && is_road(rx as usize, ry as usize)
+                })
+            });
+            if road_nearby || noise.get([x as f64 * 0.08, y as f64 * 0.08, 9400.0]) > CLIFF_RAMP_NOISE_THRESHOLD {
+                ramp_map[y][x] = true;
+                ramp_tiles += 1;
+            } else {
+                cliff_map[y][x] = true;
+                cliff_tiles += 1;
+            }
+        }
     }
+    
+    log::info!("⛰️ Generated {} cliff tiles with {} ramp tiles", cliff_tiles, ramp_tiles);
+    (cliff_map, ramp_map)
+}
+
+/// Whether any cliff tile lies within `radius` tiles of (x, y)
+fn is_near_cliff(features: &WorldFeatures, x: usize, y: usize, radius: i32) -> bool {
+    (-radius..=radius).any(|dy| (-radius..=radius).any(|dx| {
+        let cx = x as i32 + dx;
+        let cy = y as i32 + dy;
+        cx >= 0 && cy >= 0 && (cx as usize) < features.width && (cy as usize) < features.height
+            && features.cliff_map[cy as usize][cx as usize]
+    }))
 }
 
 /// Generate dirt road tiles for fishing, hunting, alpine villages, and weather station.
@@ -2284,6 +2623,192 @@ fn connect_ring_to_cross_roads(roads: &mut Vec<Vec<bool>>, ring_points: &[(i32,
     }
 }
 
+// --- Monument link roads ---
+// A* cost per tile (x10 so diagonals can use 14)
+const LINK_ROAD_COST_EXISTING: u32 = 5; // Prefer merging into roads that already exist
+const LINK_ROAD_COST_LAND: u32 = 10;
+const LINK_ROAD_COST_BEACH: u32 = 25; // Beach tiles swallow road tiles, so stay inland where possible
+const LINK_ROAD_COST_WATER: u32 = 80; // Rivers/lakes are crossed by a short causeway at the narrowest point
+const LINK_ROAD_BEACH_SHORE_DISTANCE: f64 = 12.0;
+const LINK_ROAD_ANCHOR_SNAP_RADIUS: i32 = 12; // How far an anchor in water looks for dry land to start from
+
+/// Links the ALK central compound, the four substations, the fishing village and every large
+/// quarry into one connected road graph. Each anchor is routed with A* to the nearest anchor
+/// already on the graph (starting from the compound), so every link ends on a connected road.
+/// Carved tiles become DirtRoad; river/lake crossings are marked as causeways in `road_bridges`.
+fn link_monuments_with_roads(
+    road_network: &mut Vec<Vec<bool>>,
+    road_bridges: &mut Vec<Vec<bool>>,
+    shore_distance: &[Vec<f64>],
+    river_network: &[Vec<bool>],
+    lake_map: &[Vec<bool>],
+    hot_spring_water: &[Vec<bool>],
+    fishing_village_center: Option<(f32, f32)>,
+    large_quarry_centers: &[(f32, f32, i32, LargeQuarryType)],
+    width: usize,
+    height: usize,
+) {
+    let tile_size_px = crate::TILE_SIZE_PX as f32;
+    let center = (width as i32 / 2, height as i32 / 2);
+    
+    // Substations sit at 90% of the island radius along the diagonals (same formula as alk.rs)
+    let island_radius_tiles = width.min(height) as f32 * 0.35;
+    let diag = (island_radius_tiles * 0.90 / 1.414) as i32;
+    let mut anchors: Vec<(i32, i32)> = vec![
+        (center.0 - diag, center.1 - diag),
+        (center.0 + diag, center.1 - diag),
+        (center.0 - diag, center.1 + diag),
+        (center.0 + diag, center.1 + diag),
+    ];
+    if let Some((px, py)) = fishing_village_center {
+        anchors.push(((px / tile_size_px) as i32, (py / tile_size_px) as i32));
+    }
+    for (qx, qy, _, _) in large_quarry_centers {
+        anchors.push((*qx as i32, *qy as i32));
+    }
+    
+    let is_water = |x: usize, y: usize| river_network[y][x] || lake_map[y][x] || hot_spring_water[y][x];
+    let mut linked: Vec<(i32, i32)> = vec![center];
+    let mut links_built = 0;
+    
+    for anchor in anchors {
+        let Some(start) = snap_to_dry_land(anchor, shore_distance, &is_water, width, height) else {
+            log::warn!("🛣️ No dry land near road anchor ({}, {}), skipping link", anchor.0, anchor.1);
+            continue;
+        };
+        let goal = *linked.iter()
+            .min_by_key(|(lx, ly)| (lx - start.0).pow(2) + (ly - start.1).pow(2))
+            .expect("linked always holds the compound");
+        
+        match find_link_road_path(start, goal, road_network, shore_distance, &is_water, width, height) {
+            Some(path) => {
+                for &(x, y) in &path {
+                    for dy in -1..=1 {
+                        for dx in -1..=1 {
+                            let tx = x + dx;
+                            let ty = y + dy;
+                            if tx < 0 || ty < 0 || tx >= width as i32 || ty >= height as i32 {
+                                continue;
+                            }
+                            let (ux, uy) = (tx as usize, ty as usize);
+                            if shore_distance[uy][ux] < 0.0 {
+                                continue;
+                            }
+                            road_network[uy][ux] = true;
+                            if is_water(ux, uy) {
+                                road_bridges[uy][ux] = true;
+                            }
+                        }
+                    }
+                }
+                linked.push(start);
+                links_built += 1;
+            }
+            None => log::warn!("🛣️ No land route from ({}, {}) to the road network", start.0, start.1),
+        }
+    }
+    
+    log::info!("🛣️ Linked {} monuments into the road network", links_built);
+}
+
+/// Nearest tile to `pos` that is dry land (not sea, river, lake or hot spring)
+fn snap_to_dry_land(
+    pos: (i32, i32),
+    shore_distance: &[Vec<f64>],
+    is_water: &dyn Fn(usize, usize) -> bool,
+    width: usize,
+    height: usize,
+) -> Option<(i32, i32)> {
+    let mut best: Option<((i32, i32), i32)> = None;
+    for dy in -LINK_ROAD_ANCHOR_SNAP_RADIUS..=LINK_ROAD_ANCHOR_SNAP_RADIUS {
+        for dx in -LINK_ROAD_ANCHOR_SNAP_RADIUS..=LINK_ROAD_ANCHOR_SNAP_RADIUS {
+            let x = pos.0 + dx;
+            let y = pos.1 + dy;
+            if x < 0 || y < 0 || x >= width as i32 || y >= height as i32 {
+                continue;
+            }
+            if shore_distance[y as usize][x as usize] < 0.0 || is_water(x as usize, y as usize) {
+                continue;
+            }
+            let dist_sq = dx * dx + dy * dy;
+            if best.map_or(true, |(_, d)| dist_sq < d) {
+                best = Some(((x, y), dist_sq));
+            }
+        }
+    }
+    best.map(|(tile, _)| tile)
+}
+
+/// A* over the tile grid from `start` to `goal`. The sea is impassable; existing roads are cheap,
+/// beaches and inland water are expensive. Returns the path including both ends.
+fn find_link_road_path(
+    start: (i32, i32),
+    goal: (i32, i32),
+    road_network: &[Vec<bool>],
+    shore_distance: &[Vec<f64>],
+    is_water: &dyn Fn(usize, usize) -> bool,
+    width: usize,
+    height: usize,
+) -> Option<Vec<(i32, i32)>> {
+    let index = |x: i32, y: i32| y as usize * width + x as usize;
+    // Octile distance at the cheapest step cost keeps the heuristic admissible
+    let heuristic = |x: i32, y: i32| {
+        let dx = (x - goal.0).unsigned_abs();
+        let dy = (y - goal.1).unsigned_abs();
+        (dx.max(dy) * 10 + dx.min(dy) * 4) * LINK_ROAD_COST_EXISTING / 10
+    };
+    
+    let mut best_cost = vec![u32::MAX; width * height];
+    let mut came_from = vec![usize::MAX; width * height];
+    let mut open = BinaryHeap::new();
+    best_cost[index(start.0, start.1)] = 0;
+    open.push(Reverse((heuristic(start.0, start.1), start.0, start.1)));
+    
+    while let Some(Reverse((_, x, y))) = open.pop() {
+        if (x, y) == goal {
+            let mut path = vec![goal];
+            let mut current = index(x, y);
+            while came_from[current] != usize::MAX {
+                current = came_from[current];
+                path.push(((current % width) as i32, (current / width) as i32));
+            }
+            path.reverse();
+            return Some(path);
+        }
+        let current_cost = best_cost[index(x, y)];
+        
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1), (-1, -1), (1, -1), (-1, 1), (1, 1)] {
+            let nx = x + dx;
+            let ny = y + dy;
+            if nx < 0 || ny < 0 || nx >= width as i32 || ny >= height as i32 {
+                continue;
+            }
+            let (ux, uy) = (nx as usize, ny as usize);
+            if shore_distance[uy][ux] < 0.0 {
+                continue; // Never route through the sea
+            }
+            let tile_cost = if road_network[uy][ux] {
+                LINK_ROAD_COST_EXISTING
+            } else if is_water(ux, uy) {
+                LINK_ROAD_COST_WATER
+            } else if shore_distance[uy][ux] < LINK_ROAD_BEACH_SHORE_DISTANCE {
+                LINK_ROAD_COST_BEACH
+            } else {
+                LINK_ROAD_COST_LAND
+            };
+            let step_cost = if dx != 0 && dy != 0 { tile_cost * 14 / 10 } else { tile_cost };
+            let next_cost = current_cost + step_cost;
+            let next_index = index(nx, ny);
+            if next_cost < best_cost[next_index] {
+                best_cost[next_index] = next_cost;
+                came_from[next_index] = index(x, y);
+                open.push(Reverse((next_cost + heuristic(nx, ny), nx, ny)));
+            }
+        }
+    }
+    None
+}
+
 fn generate_dirt_paths(config: &WorldGenConfig, noise: &Perlin, road_network: &[Vec<bool>], width: usize, height: usize) -> Vec<Vec<bool>> {
     // DISABLED: No more dirt paths to prevent loops
     // Only keep the main cross-island roads (handled in road_network)
@@ -2308,7 +2833,10 @@ fn generate_hot_springs(
     // Calculate how many hot springs to generate based on map size
     let map_area_tiles = (width * height) as f32;
     let scale_factor = (map_area_tiles / HOT_SPRING_BASE_AREA_TILES).sqrt();
-    let target_hot_spring_count = ((HOT_SPRING_BASE_COUNT as f32) * scale_factor).round().max(2.0) as usize;
+    let min_hot_springs = if config.hot_spring_count_scale > 0.0 { 2.0 } else { 0.0 };
+    let target_hot_spring_count = ((HOT_SPRING_BASE_COUNT as f32) * scale_factor * config.hot_spring_count_scale)
+        .round()
+        .max(min_hot_springs) as usize;
     
     log::info!("🌊 Target hot springs: {} (map: {}x{} tiles, scale factor: {:.2}x)", target_hot_spring_count, width, height, scale_factor);
     
@@ -2654,13 +3182,15 @@ fn generate_quarries(
     // - 450x450 (scale=0.75): 2^0.85 * 0.75^0.85 = 1.5 large, 3.1 small
     // - 600x600 (scale=1.0): 2^0.85 * 1.0^0.85 = 2.0 large, 4.0 small ✓
     // - 800x800 (scale=1.33): 2^0.85 * 1.33^0.85 = 2.5 large, 5.0 small
-    let target_large_quarry_count = ((QUARRY_LARGE_BASE_COUNT as f32) * scale_factor.powf(0.85))
+    // config.quarry_count_scale scales both on top of the map-size curve (0 = no quarries)
+    let target_large_quarry_count = ((QUARRY_LARGE_BASE_COUNT as f32) * scale_factor.powf(0.85) * config.quarry_count_scale)
         .round()
         .max(0.0) as usize;
     
-    let target_small_quarry_count = ((QUARRY_SMALL_BASE_COUNT as f32) * scale_factor.powf(0.85))
+    let min_small_quarries = if config.quarry_count_scale > 0.0 { 1.0 } else { 0.0 };
+    let target_small_quarry_count = ((QUARRY_SMALL_BASE_COUNT as f32) * scale_factor.powf(0.85) * config.quarry_count_scale)
         .round()
-        .max(1.0) as usize; // Always at least 1 small quarry
+        .max(min_small_quarries) as usize; // At least 1 small quarry unless quarries are disabled
     
     log::info!("🏔️ Target large quarries (north): {} | Target small quarries (south): {} (scale factor: {:.2}x)", 
                target_large_quarry_count, target_small_quarry_count, scale_factor);
@@ -3094,7 +3624,7 @@ fn generate_forest_areas(
 /// - Middle: Tundra (arctic grassland) - ~35% of land  
 /// - North: Alpine (rocky, harsh terrain) - ~25% of land (EXPANDED - should feel like its own biome)
 fn generate_latitude_biomes(
-    _config: &WorldGenConfig,
+    config: &WorldGenConfig,
     noise: &Perlin,
     shore_distance: &[Vec<f64>],
     width: usize,
@@ -3106,8 +3636,9 @@ fn generate_latitude_biomes(
     // EXPANDED BIOMES: Alpine should feel like its own large region
     // Latitude thresholds (0.0 = north, 1.0 = south)
     let alpine_threshold = 0.35;  // Top 35% of map is alpine (EXPANDED from 28%)
-    let tundra_threshold = 0.58;  // 35-58% from top is tundra (23% of map - REDUCED from 32%)
-    // Below 58% (42% of map) is temperate (grass/forest)
+    // Tundra band below alpine (default 35-58% from top = 23% of map - REDUCED from 32%)
+    let tundra_threshold = alpine_threshold + config.tundra_fraction as f64;
+    // Everything further south is temperate (grass/forest)
     
     // Noise scales for natural, irregular biome boundaries with MORE variation
     let boundary_noise_scale = 0.012; // Larger features
@@ -3164,7 +3695,7 @@ fn generate_latitude_biomes(
 /// Grass meadows should dominate the south, with forests being occasional dense groves
 /// SPECIAL: Hot springs are surrounded by dense forest rings with organic paths leading in
 fn generate_forest_areas_with_biomes(
-    _config: &WorldGenConfig,
+    config: &WorldGenConfig,
     noise: &Perlin,
     shore_distance: &[Vec<f64>],
     river_network: &[Vec<bool>],
@@ -3184,7 +3715,8 @@ fn generate_forest_areas_with_biomes(
     // Forest generation parameters - Adjusted for larger, longer forests, especially in south
     // Forests should be ~12-15% of land (increased from 8-12%)
     let forest_noise_scale = 0.008; // Lower frequency = larger patches (was 0.012)
-    let forest_threshold = 0.50; // Lower threshold = more forests (was 0.62)
+    // Lower threshold = more forests (was 0.62); config.forest_density shifts it (1.0 = 0.50)
+    let forest_threshold = 0.50 - (config.forest_density as f64 - 1.0) * 0.15;
     let min_shore_distance = 25.0; // Forests stay further from shore
     let forest_edge_noise_scale = 0.06; // Fine detail for organic forest edges
     let secondary_noise_scale = 0.003; // Lower frequency = larger forest regions (was 0.004)
@@ -3856,16 +4388,21 @@ fn determine_realistic_tile_type(
         return ocean_tile(TileType::Sea);
     }
     
+    // Monument link road causeways are the only road tiles allowed over rivers/lakes
+    if features.road_bridges[y][x] {
+        return TileType::DirtRoad;
+    }
+    
     // CRITICAL FIX: Check rivers and lakes BEFORE beach check
-    // Rivers and lakes should be Sea, not Beach!
+    // Rivers and lakes are Freshwater (drinkable, river/lake fish), not Sea or Beach!
     // Rivers take priority and flow into sea
     if features.river_network[y][x] {
-        return ocean_tile(TileType::Sea);
+        return TileType::Freshwater;
     }
     
     // Lakes
     if features.lake_map[y][x] {
-        return ocean_tile(TileType::Sea);
+        return TileType::Freshwater;
     }
     
     // Hot spring water (inner pool) - uses distinct HotSpringWater tile type (teal/turquoise)
@@ -3923,6 +4460,14 @@ fn determine_realistic_tile_type(
         return TileType::Beach;
     }
     
+    // Cliffs and their ramps - roads crossing a cliff line were turned into ramps
+    if features.ramp_map[y][x] {
+        return TileType::Ramp;
+    }
+    if features.cliff_map[y][x] {
+        return TileType::Cliff;
+    }
+    
     // Roads can cross deep water (rivers/lakes) but NOT beaches
     // Check roads AFTER asphalt compounds so compound centers are paved
     if features.road_network[y][x] {
@@ -4110,7 +4655,7 @@ fn generate_tile_variant(noise: &Perlin, x: i32, y: i32, tile_type: &TileType) -
             // More variation for grass tiles
             ((variant_noise + 1.0) * 127.5) as u8
         },
-        TileType::Sea | TileType::DeepSea => {
+        TileType::Sea | TileType::DeepSea | TileType::Freshwater => {
             // Less variation for water (for consistent animation)
             ((variant_noise + 1.0) * 63.75) as u8
         },
@@ -4153,6 +4698,7 @@ pub fn generate_minimap_data(ctx: &ReducerContext, minimap_width: u32, minimap_h
             let color_value = match tile.tile_type {
                 TileType::Sea => 0,        // Dark blue water
                 TileType::DeepSea => 8,    // Darker blue (outer ring - empty deep ocean)
+                TileType::Freshwater => 16, // Lighter blue (rivers and lakes)
                 TileType::Beach => 64,     // Muted sandy beach
                 TileType::Sand => 96,      // Darker sand
                 TileType::Grass => 128,    // Muted forest green
@@ -4166,6 +4712,8 @@ pub fn generate_minimap_data(ctx: &ReducerContext, minimap_width: u32, minimap_h
                 TileType::Alpine => 180,   // Light gray (rocky terrain)
                 TileType::TundraGrass => 120, // Slightly greener than Tundra (grassy patches)
                 TileType::Tilled => 200,   // Slightly darker than Dirt (freshly tilled soil)
+                TileType::Cliff => 40,     // Dark rock (impassable cliff faces)
+                TileType::Ramp => 176,     // Light rocky slope through cliffs
             };
             
             // Write directly to buffer (overwriting if multiple tiles map to same pixel is fine/expected)
@@ -0,0 +1,280 @@
+/******************************************************************************
+ *                                                                            *
+ * World Regeneration - Rebuilding the terrain without wiping players         *
+ *                                                                            *
+ * Admins can regenerate the island from a chosen seed on a live server.     *
+ * Everything tied to the old terrain (tiles, resources, wildlife, monument  *
+ * loot, puzzle rooms) is cleared and the normal init pipeline runs again.   *
+ * Players keep their inventories, Memory Grid progress and tamed animals;   *
+ * anyone or anything left on water or a cliff is moved to the nearest       *
+ * walkable tile. Building pieces are left where they stand.                 *
+ *                                                                            *
+ ******************************************************************************/
+
+use spacetimedb::{ReducerContext, Table};
+use log;
+
+use crate::{TILE_SIZE_PX, WORLD_WIDTH_PX, WORLD_HEIGHT_PX};
+use crate::models::{ItemLocation, ContainerType};
+use crate::environment::calculate_chunk_index;
+use crate::player as PlayerTableTrait;
+use crate::world_chunk_data as WorldChunkDataTableTrait;
+use crate::coastal_spawn_point as CoastalSpawnPointTableTrait;
+use crate::large_quarry as LargeQuarryTableTrait;
+use crate::reed_marsh as ReedMarshTableTrait;
+use crate::tide_pool as TidePoolTableTrait;
+use crate::items::inventory_item as InventoryItemTableTrait;
+use crate::tree::tree as TreeTableTrait;
+use crate::stone::stone as StoneTableTrait;
+use crate::harvestable_resource::harvestable_resource as HarvestableResourceTableTrait;
+use crate::grass::{grass as GrassTableTrait, grass_state as GrassStateTableTrait};
+use crate::sea_stack::sea_stack as SeaStackTableTrait;
+use crate::coral::living_coral as LivingCoralTableTrait;
+use crate::fumarole::fumarole as FumaroleTableTrait;
+use crate::basalt_column::basalt_column as BasaltColumnTableTrait;
+use crate::barrel::barrel as BarrelTableTrait;
+use crate::rune_stone::rune_stone as RuneStoneTableTrait;
+use crate::cairn::cairn as CairnTableTrait;
+use crate::road_lamppost::road_lamppost as RoadLamppostTableTrait;
+use crate::wild_animal_npc::wild_animal as WildAnimalTableTrait;
+use crate::wild_animal_npc::crow_nest::crow_nest as CrowNestTableTrait;
+use crate::wooden_storage_box::wooden_storage_box as WoodenStorageBoxTableTrait;
+use crate::campfire::campfire as CampfireTableTrait;
+use crate::furnace::furnace as FurnaceTableTrait;
+use crate::barbecue::barbecue as BarbecueTableTrait;
+use crate::rain_collector::rain_collector as RainCollectorTableTrait;
+use crate::lantern::lantern as LanternTableTrait;
+use crate::turret::turret as TurretTableTrait;
+use crate::fence::fence as FenceTableTrait;
+use crate::sleeping_bag::sleeping_bag as SleepingBagTableTrait;
+use crate::stash::stash as StashTableTrait;
+use crate::locked_crate::crate_lock as CrateLockTableTrait;
+use crate::monument_puzzle::{puzzle_room as PuzzleRoomTableTrait, puzzle_element as PuzzleElementTableTrait};
+
+/// How far (in tiles) to search for walkable ground when moving a player or deployable
+const RELOCATION_SEARCH_RADIUS_TILES: i32 = 40;
+
+/// Deletes every row of a table
+macro_rules! clear_table {
+    ($ctx:expr, $table:ident) => {{
+        let ids: Vec<_> = $ctx.db.$table().iter().map(|row| row.id).collect();
+        for id in &ids {
+            $ctx.db.$table().id().delete(id);
+        }
+        ids.len()
+    }};
+}
+
+/// Deletes monument and system-placed rows of a placeable table, remembering them as containers to empty
+macro_rules! clear_world_placeables {
+    ($ctx:expr, $table:ident, $container_type:expr, $containers:ident) => {{
+        let ids: Vec<_> = $ctx.db.$table().iter()
+            .filter(|row| row.is_monument || row.placed_by == $ctx.identity())
+            .map(|row| row.id)
+            .collect();
+        for id in &ids {
+            $ctx.db.$table().id().delete(id);
+            $containers.push(($container_type, *id as u64));
+        }
+        ids.len()
+    }};
+}
+
+/// Moves the surviving deployables of a table that no longer stand on walkable ground
+macro_rules! relocate_deployables {
+    ($ctx:expr, $table:ident) => {{
+        let mut moved = 0;
+        let stranded: Vec<_> = $ctx.db.$table().iter()
+            .filter(|row| !row.is_destroyed && !is_walkable_position($ctx, row.pos_x, row.pos_y))
+            .collect();
+        for mut row in stranded {
+            if let Some((x, y)) = find_nearest_walkable_position($ctx, row.pos_x, row.pos_y) {
+                row.pos_x = x;
+                row.pos_y = y;
+                row.chunk_index = calculate_chunk_index(x, y);
+                $ctx.db.$table().id().update(row);
+                moved += 1;
+            }
+        }
+        moved
+    }};
+}
+
+/// --- Regenerate World (Admin) ---
+/// Regenerates the terrain from `seed` while keeping players, their inventories,
+/// Memory Grid progress and deployables. Stranded players and deployables are moved
+/// to the nearest walkable tile.
+#[spacetimedb::reducer]
+pub fn regenerate_world_keep_players(ctx: &ReducerContext, seed: u64) -> Result<(), String> {
+    if !crate::combat_balance::is_balance_admin(ctx) {
+        return Err("Only admins can regenerate the world.".to_string());
+    }
+    log::info!("[WorldRegen] {:?} is regenerating the world from seed {}", ctx.sender(), seed);
+
+    clear_terrain_entities(ctx);
+
+    crate::world_generation::generate_world(ctx, crate::world_generation::default_world_gen_config(seed))?;
+    // Re-paves the ALK compound and substations (stations keep their fixed positions)
+    crate::alk::init_alk_system(ctx)?;
+    crate::environment::seed_environment(ctx)?;
+    crate::populate_coastal_spawn_points(ctx)?;
+    crate::world_generation::generate_compressed_chunk_data(ctx)?;
+    crate::world_generation::generate_minimap_data(ctx, 300, 300)?;
+    crate::spatial_grid::invalidate_static_grid();
+
+    let players_moved = relocate_players(ctx);
+    let deployables_moved = relocate_deployables!(ctx, wooden_storage_box)
+        + relocate_deployables!(ctx, campfire)
+        + relocate_deployables!(ctx, furnace)
+        + relocate_deployables!(ctx, barbecue)
+        + relocate_deployables!(ctx, rain_collector)
+        + relocate_deployables!(ctx, lantern)
+        + relocate_deployables!(ctx, turret)
+        + relocate_deployables!(ctx, sleeping_bag)
+        + relocate_deployables!(ctx, stash);
+    crate::spatial_grid::sync_spatial_grid_entries(ctx);
+
+    log::info!("[WorldRegen] World regenerated from seed {}. Moved {} players and {} deployables.", seed, players_moved, deployables_moved);
+    Ok(())
+}
+
+/// Removes everything generated from the old terrain so the init pipeline can reseed it.
+/// Player-owned entities, inventories and tamed animals are kept.
+fn clear_terrain_entities(ctx: &ReducerContext) {
+    let mut cleared = clear_table!(ctx, tree)
+        + clear_table!(ctx, stone)
+        + clear_table!(ctx, harvestable_resource)
+        + clear_table!(ctx, sea_stack)
+        + clear_table!(ctx, living_coral)
+        + clear_table!(ctx, basalt_column)
+        + clear_table!(ctx, barrel)
+        + clear_table!(ctx, rune_stone)
+        + clear_table!(ctx, cairn)
+        + clear_table!(ctx, road_lamppost)
+        + clear_table!(ctx, crow_nest)
+        + clear_table!(ctx, large_quarry)
+        + clear_table!(ctx, reed_marsh)
+        + clear_table!(ctx, tide_pool)
+        + clear_table!(ctx, puzzle_room)
+        + clear_table!(ctx, puzzle_element)
+        + clear_table!(ctx, coastal_spawn_point)
+        + clear_table!(ctx, world_chunk_data);
+
+    for grass_id in ctx.db.grass().iter().map(|g| g.id).collect::<Vec<_>>() {
+        ctx.db.grass_state().grass_id().delete(grass_id);
+        ctx.db.grass().id().delete(grass_id);
+        cleared += 1;
+    }
+
+    let wild_animals: Vec<_> = ctx.db.wild_animal().iter().filter(|a| a.tamed_by.is_none()).collect();
+    for animal in &wild_animals {
+        crate::wild_animal_npc::population::cull_animal(ctx, animal);
+    }
+    cleared += wild_animals.len();
+
+    // Monument and system-placed containers go with the terrain, along with their contents
+    let mut containers: Vec<(ContainerType, u64)> = Vec::new();
+    cleared += clear_world_placeables!(ctx, wooden_storage_box, ContainerType::WoodenStorageBox, containers)
+        + clear_world_placeables!(ctx, campfire, ContainerType::Campfire, containers)
+        + clear_world_placeables!(ctx, furnace, ContainerType::Furnace, containers)
+        + clear_world_placeables!(ctx, barbecue, ContainerType::Barbecue, containers)
+        + clear_world_placeables!(ctx, rain_collector, ContainerType::RainCollector, containers)
+        + clear_world_placeables!(ctx, lantern, ContainerType::Lantern, containers)
+        + clear_world_placeables!(ctx, turret, ContainerType::Turret, containers);
+    for fumarole_id in ctx.db.fumarole().iter().map(|f| f.id).collect::<Vec<_>>() {
+        ctx.db.fumarole().id().delete(fumarole_id);
+        containers.push((ContainerType::Fumarole, fumarole_id as u64));
+        cleared += 1;
+    }
+    for fence_id in ctx.db.fence().iter().filter(|f| f.is_monument).map(|f| f.id).collect::<Vec<_>>() {
+        ctx.db.fence().id().delete(fence_id);
+        cleared += 1;
+    }
+    for (_, box_id) in containers.iter().filter(|(t, _)| *t == ContainerType::WoodenStorageBox) {
+        ctx.db.crate_lock().box_id().delete(*box_id as u32);
+    }
+    delete_container_items(ctx, &containers);
+
+    log::info!("[WorldRegen] Cleared {} terrain-bound entities", cleared);
+}
+
+/// Deletes every item stored in the given containers
+fn delete_container_items(ctx: &ReducerContext, containers: &[(ContainerType, u64)]) {
+    let items: Vec<u64> = ctx.db.inventory_item().iter()
+        .filter(|item| match &item.location {
+            ItemLocation::Container(data) => containers.contains(&(data.container_type, data.container_id)),
+            _ => false,
+        })
+        .map(|item| item.instance_id)
+        .collect();
+    for instance_id in items {
+        ctx.db.inventory_item().instance_id().delete(instance_id);
+    }
+}
+
+fn is_walkable_position(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    let (tile_x, tile_y) = crate::world_pos_to_tile_coords(pos_x, pos_y);
+    is_walkable_tile(ctx, tile_x, tile_y)
+}
+
+/// Walkable ground, including tidal flats while the tide is out and sea ice
+fn is_walkable_tile(ctx: &ReducerContext, tile_x: i32, tile_y: i32) -> bool {
+    crate::get_tile_type_at_position(ctx, tile_x, tile_y).is_some_and(|t| t.is_walkable())
+        || crate::world_state::is_tile_exposed_by_tide(ctx, tile_x, tile_y)
+        || crate::ice::is_tile_frozen(ctx, tile_x, tile_y)
+}
+
+/// Center of the nearest walkable tile, searching outward ring by ring
+fn find_nearest_walkable_position(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> Option<(f32, f32)> {
+    let tile_size = TILE_SIZE_PX as f32;
+    let (origin_x, origin_y) = crate::world_pos_to_tile_coords(pos_x, pos_y);
+    for radius in 1..=RELOCATION_SEARCH_RADIUS_TILES {
+        let mut best: Option<((f32, f32), i32)> = None;
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                if dx.abs() != radius && dy.abs() != radius {
+                    continue; // Only the ring at this radius
+                }
+                let (tile_x, tile_y) = (origin_x + dx, origin_y + dy);
+                let center = ((tile_x as f32 + 0.5) * tile_size, (tile_y as f32 + 0.5) * tile_size);
+                if center.0 < 0.0 || center.1 < 0.0 || center.0 >= WORLD_WIDTH_PX || center.1 >= WORLD_HEIGHT_PX {
+                    continue;
+                }
+                let dist_sq = dx * dx + dy * dy;
+                if best.is_some_and(|(_, d)| d <= dist_sq) {
+                    continue;
+                }
+                if is_walkable_tile(ctx, tile_x, tile_y) {
+                    best = Some((center, dist_sq));
+                }
+            }
+        }
+        if let Some((center, _)) = best {
+            return Some(center);
+        }
+    }
+    None
+}
+
+/// Moves players standing on water or cliffs to nearby ground, or to a coastal spawn point
+fn relocate_players(ctx: &ReducerContext) -> u32 {
+    let mut moved = 0;
+    let stranded: Vec<_> = ctx.db.player().iter()
+        .filter(|p| !p.is_dead && !is_walkable_position(ctx, p.position_x, p.position_y))
+        .collect();
+    for mut player in stranded {
+        let destination = find_nearest_walkable_position(ctx, player.position_x, player.position_y)
+            .or_else(|| ctx.db.coastal_spawn_point().iter().next().map(|p| (p.world_x, p.world_y)));
+        let Some((x, y)) = destination else {
+            log::warn!("[WorldRegen] No walkable ground found for player {:?}", player.identity);
+            continue;
+        };
+        player.position_x = x;
+        player.position_y = y;
+        crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(player.identity), player.position_x, player.position_y);
+        player.last_update = ctx.timestamp;
+        ctx.db.player().identity().update(player);
+        moved += 1;
+    }
+    moved
+}
@@ -13,6 +13,11 @@ use crate::player; // ADDED: Import player module for chunk weather system
 use crate::world_state::world_state as WorldStateTableTrait;
 use crate::world_state::thunder_event as ThunderEventTableTrait;
 use crate::world_state::thunder_event_cleanup_schedule as ThunderEventCleanupScheduleTableTrait;
+use crate::world_state::world_event_marker as WorldEventMarkerTableTrait;
+use crate::world_state::aurora_event as AuroraEventTableTrait;
+use crate::world_state::tide_state as TideStateTableTrait;
+use crate::fire_patch::fire_patch as FirePatchTableTrait;
+use crate::tide_pool as TidePoolTableTrait;
 use crate::world_state::seasonal_plant_management_schedule as SeasonalPlantManagementScheduleTableTrait;
 use crate::world_state::chunk_weather as ChunkWeatherTableTrait;
 use crate::harvestable_resource::harvestable_resource as HarvestableResourceTableTrait;
@@ -310,6 +315,252 @@ pub enum TimeOfDay {
     Midnight, // Middle of the night - 0.92-0.97 (comes before TwilightMorning)
 }
 
+/// Kind of server-wide world event shown on the minimap
+#[derive(Clone, Debug, PartialEq, spacetimedb::SpacetimeType)]
+pub enum WorldEventType {
+    MeteorShower,
+    SupplyDrop,
+}
+
+/// Minimap marker for an active world event (meteor shower impact zone, supply drop, etc.)
+/// Owned by the event's module - inserted when the event starts, deleted when it ends
+#[spacetimedb::table(accessor = world_event_marker, public)]
+#[derive(Clone, Debug)]
+pub struct WorldEventMarker {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub event_type: WorldEventType,
+    pub event_id: u64,       // ID in the owning event table
+    pub pos_x: f32,
+    pub pos_y: f32,
+    pub radius: f32,         // Area to highlight on the minimap (pixels)
+    pub label: String,
+    pub created_at: Timestamp,
+    pub expires_at: Timestamp,
+}
+
+/// Removes all minimap markers belonging to a world event
+pub fn remove_world_event_markers(ctx: &ReducerContext, event_type: WorldEventType, event_id: u64) {
+    let markers = ctx.db.world_event_marker();
+    let marker_ids: Vec<u64> = markers.iter()
+        .filter(|m| m.event_type == event_type && m.event_id == event_id)
+        .map(|m| m.id)
+        .collect();
+    for marker_id in marker_ids {
+        markers.id().delete(marker_id);
+    }
+}
+
+/// Chance that an aurora lights up the arctic sky when night falls
+pub(crate) const AURORA_CHANCE: f32 = 0.12;
+
+/// An aurora over the arctic biomes (one row while it lasts: from nightfall until twilight morning)
+/// Public so clients can render it in the sky
+#[spacetimedb::table(accessor = aurora_event, public)]
+#[derive(Clone, Debug)]
+pub struct AuroraEvent {
+    #[primary_key]
+    #[auto_inc]
+    pub id: u64,
+    pub started_at: Timestamp,
+    pub intensity: f32, // 0.5 to 1.0 for sky brightness
+}
+
+/// Rolls for an aurora at nightfall
+fn roll_aurora_at_nightfall(ctx: &ReducerContext) {
+    if is_aurora_active(ctx) || ctx.rng().gen::<f32>() >= AURORA_CHANCE {
+        return;
+    }
+    let intensity = ctx.rng().gen_range(0.5..=1.0);
+    ctx.db.aurora_event().insert(AuroraEvent { id: 0, started_at: ctx.timestamp, intensity });
+    log::info!("🌌 An aurora lights up the arctic sky (intensity {:.2})", intensity);
+    crate::rune_stone::spawn_aurora_bonus_shards(ctx);
+}
+
+/// Ends any aurora when morning twilight comes
+fn end_aurora(ctx: &ReducerContext) {
+    let aurora_ids: Vec<u64> = ctx.db.aurora_event().iter().map(|a| a.id).collect();
+    for aurora_id in aurora_ids {
+        ctx.db.aurora_event().id().delete(aurora_id);
+        log::info!("🌌 Aurora {} faded with the morning twilight", aurora_id);
+    }
+}
+
+/// Whether an aurora is currently lit
+pub fn is_aurora_active(ctx: &ReducerContext) -> bool {
+    ctx.db.aurora_event().iter().next().is_some()
+}
+
+/// Whether a position is under an active aurora (it only hangs over tundra and alpine tiles)
+pub fn is_under_aurora(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    if !is_aurora_active(ctx) {
+        return false;
+    }
+    let (tile_x, tile_y) = crate::world_pos_to_tile_coords(pos_x, pos_y);
+    matches!(
+        crate::get_tile_type_at_position(ctx, tile_x, tile_y),
+        Some(crate::TileType::Tundra | crate::TileType::TundraGrass | crate::TileType::Alpine)
+    )
+}
+
+/// How long each tide lasts before it turns (low and high alternate)
+pub(crate) const TIDE_PHASE_DURATION_SECS: i64 = 20 * 60;
+/// Tidal flat spots tried around each tide pool when the tide goes out
+const TIDAL_SPAWN_ATTEMPTS_PER_POOL: u32 = 12;
+/// Most crabs and clams left behind per tide pool
+const TIDAL_SPAWNS_PER_POOL_MAX: u32 = 3;
+/// How far past a tide pool's edge the flats are searched
+const TIDAL_SPAWN_SEARCH_MARGIN_PX: f32 = 192.0;
+/// Share of tidal flat finds that are crabs (the rest are clams)
+const TIDAL_CRAB_CHANCE: f32 = 0.4;
+
+/// The current tide (single row, id 0). Public so clients can draw the exposed tidal flats
+#[spacetimedb::table(accessor = tide_state, public)]
+#[derive(Clone, Debug)]
+pub struct TideState {
+    #[primary_key]
+    pub id: u32,
+    pub is_low_tide: bool,
+    pub changed_at: Timestamp,
+}
+
+/// Whether the tide is currently out
+pub fn is_low_tide(ctx: &ReducerContext) -> bool {
+    ctx.db.tide_state().id().find(0).is_some_and(|t| t.is_low_tide)
+}
+
+/// Whether a tile is part of the tidal flats: a Sea tile bordering a beach
+pub fn is_tidal_flat_tile(ctx: &ReducerContext, tile_x: i32, tile_y: i32) -> bool {
+    if crate::get_tile_type_at_position(ctx, tile_x, tile_y) != Some(crate::TileType::Sea) {
+        return false;
+    }
+    (-1..=1).any(|dy| (-1..=1).any(|dx| {
+        (dx, dy) != (0, 0)
+            && crate::get_tile_type_at_position(ctx, tile_x + dx, tile_y + dy) == Some(crate::TileType::Beach)
+    }))
+}
+
+/// Whether the tide is out and this tile is walkable tidal flat instead of sea
+pub fn is_tile_exposed_by_tide(ctx: &ReducerContext, tile_x: i32, tile_y: i32) -> bool {
+    is_low_tide(ctx) && is_tidal_flat_tile(ctx, tile_x, tile_y)
+}
+
+/// Turns the tide once the current one has run its course
+fn update_tide(ctx: &ReducerContext) {
+    let tides = ctx.db.tide_state();
+    let Some(mut tide) = tides.id().find(0) else {
+        tides.insert(TideState { id: 0, is_low_tide: false, changed_at: ctx.timestamp });
+        return;
+    };
+    let elapsed_micros = ctx.timestamp.to_micros_since_unix_epoch() - tide.changed_at.to_micros_since_unix_epoch();
+    if elapsed_micros < TIDE_PHASE_DURATION_SECS * 1_000_000 {
+        return;
+    }
+
+    tide.is_low_tide = !tide.is_low_tide;
+    tide.changed_at = ctx.timestamp;
+    let is_low_tide = tide.is_low_tide;
+    tides.id().update(tide);
+
+    if is_low_tide {
+        let spawned = spawn_tidal_flat_harvestables(ctx);
+        log::info!("🌊 Low tide - the flats are exposed ({} crabs and clams left behind)", spawned);
+    } else {
+        let (pushed, doused, washed_away) = flood_tidal_flats(ctx);
+        log::info!("🌊 High tide - flats flooded: {} players pushed ashore, {} fires doused, {} crabs and clams washed away",
+            pushed, doused, washed_away);
+    }
+}
+
+/// Leaves crabs and clams on the tidal flats around each tide pool
+fn spawn_tidal_flat_harvestables(ctx: &ReducerContext) -> u32 {
+    let mut rng = ctx.rng();
+    let pools: Vec<(f32, f32, f32)> = ctx.db.tide_pool().iter().map(|p| (p.world_x, p.world_y, p.radius_px)).collect();
+    let mut spawned = 0;
+
+    for (pool_x, pool_y, radius_px) in pools {
+        let mut spawned_here = 0;
+        for _ in 0..TIDAL_SPAWN_ATTEMPTS_PER_POOL {
+            if spawned_here >= TIDAL_SPAWNS_PER_POOL_MAX {
+                break;
+            }
+            let angle = rng.gen_range(0.0..(2.0 * PI));
+            let distance = rng.gen_range(0.0..(radius_px + TIDAL_SPAWN_SEARCH_MARGIN_PX));
+            let pos_x = pool_x + angle.cos() * distance;
+            let pos_y = pool_y + angle.sin() * distance;
+            let (tile_x, tile_y) = crate::world_pos_to_tile_coords(pos_x, pos_y);
+            if !is_tidal_flat_tile(ctx, tile_x, tile_y) {
+                continue;
+            }
+            let plant_type = if rng.gen::<f32>() < TIDAL_CRAB_CHANCE {
+                crate::plants_database::PlantType::TidePoolCrab
+            } else {
+                crate::plants_database::PlantType::TidePoolClams
+            };
+            let resource = crate::harvestable_resource::create_harvestable_resource(
+                plant_type, pos_x, pos_y, calculate_chunk_index(pos_x, pos_y), false,
+            );
+            ctx.db.harvestable_resource().insert(resource);
+            spawned_here += 1;
+        }
+        spawned += spawned_here;
+    }
+    spawned
+}
+
+/// Floods the tidal flats: pushes players ashore, douses fires and washes away unharvested finds.
+/// Returns (players pushed, fires doused, finds washed away)
+fn flood_tidal_flats(ctx: &ReducerContext) -> (u32, u32, u32) {
+    let tile_size = crate::TILE_SIZE_PX as f32;
+
+    let mut pushed = 0;
+    let stranded: Vec<_> = ctx.db.player().iter()
+        .filter(|p| !p.is_dead)
+        .filter(|p| {
+            let (tile_x, tile_y) = crate::world_pos_to_tile_coords(p.position_x, p.position_y);
+            is_tidal_flat_tile(ctx, tile_x, tile_y) && !crate::ice::is_tile_frozen(ctx, tile_x, tile_y)
+        })
+        .collect();
+    for mut player in stranded {
+        let (tile_x, tile_y) = crate::world_pos_to_tile_coords(player.position_x, player.position_y);
+        let shore = (-1..=1).flat_map(|dy| (-1..=1).map(move |dx| (tile_x + dx, tile_y + dy)))
+            .find(|&(x, y)| crate::get_tile_type_at_position(ctx, x, y) == Some(crate::TileType::Beach));
+        let Some((shore_x, shore_y)) = shore else {
+            continue;
+        };
+        player.position_x = (shore_x as f32 + 0.5) * tile_size;
+        player.position_y = (shore_y as f32 + 0.5) * tile_size;
+        crate::spatial_grid::track_entity(ctx, crate::spatial_grid::EntityType::Player(player.identity), player.position_x, player.position_y);
+        player.is_on_water = false;
+        player.last_update = ctx.timestamp;
+        ctx.db.player().identity().update(player);
+        pushed += 1;
+    }
+
+    let flooded_fires: Vec<u64> = ctx.db.fire_patch().iter()
+        .filter(|f| {
+            let (tile_x, tile_y) = crate::world_pos_to_tile_coords(f.pos_x, f.pos_y);
+            is_tidal_flat_tile(ctx, tile_x, tile_y)
+        })
+        .map(|f| f.id)
+        .collect();
+    for fire_id in &flooded_fires {
+        ctx.db.fire_patch().id().delete(fire_id);
+    }
+
+    let washed_away: Vec<u64> = ctx.db.harvestable_resource().iter()
+        .filter(|r| matches!(r.plant_type,
+            crate::plants_database::PlantType::TidePoolCrab | crate::plants_database::PlantType::TidePoolClams))
+        .map(|r| r.id)
+        .collect();
+    for resource_id in &washed_away {
+        ctx.db.harvestable_resource().id().delete(resource_id);
+    }
+
+    (pushed, flooded_fires.len() as u32, washed_away.len() as u32)
+}
+
 #[spacetimedb::table(accessor = thunder_event, public)]
 #[derive(Clone, Debug)]
 pub struct ThunderEvent {
@@ -1135,6 +1386,8 @@ pub fn tick_world_state(ctx: &ReducerContext, _timestamp: Timestamp) -> Result<(
             if next_day > 960 { // New year starts (960 days = 4 seasons × 240 days)
                 let next_year = world_state.year + 1;
                 log::info!("New year started! Year {} -> Year {}", world_state.year, next_year);
+                // SEA ICE: Winter ends with the year - thaw the winter ice
+                crate::ice::update_ice_for_season(ctx, &calculate_season(1));
                 (1, next_year, calculate_season(1)) // Start new year with day 1
             } else {
                 let season = calculate_season(next_day);
@@ -1147,6 +1400,9 @@ pub fn tick_world_state(ctx: &ReducerContext, _timestamp: Timestamp) -> Result<(
                         log::error!("Failed to start seasonal plant transition: {}", e);
                     }
                     
+                    // SEA ICE: Coasts freeze for winter and thaw after it
+                    crate::ice::update_ice_for_season(ctx, &season);
+                    
                     // SEASONAL WEATHER TRANSITION: Reseed weather patterns for the new season
                     match reseed_weather_for_season(ctx, &season) {
                         Ok((cleared, created)) => {
@@ -1201,6 +1457,17 @@ pub fn tick_world_state(ctx: &ReducerContext, _timestamp: Timestamp) -> Result<(
             crate::beacon_event::on_dusk_started(ctx);
         }
 
+        // METEOR SHOWER: Roll for a meteor shower when night falls
+        if old_time_of_day != TimeOfDay::Night && new_time_of_day == TimeOfDay::Night {
+            crate::meteor_shower::on_night_started(ctx);
+            roll_aurora_at_nightfall(ctx);
+        }
+
+        // AURORA: Fades with the morning twilight
+        if old_time_of_day != TimeOfDay::TwilightMorning && new_time_of_day == TimeOfDay::TwilightMorning {
+            end_aurora(ctx);
+        }
+
         // Assign the calculated new values to the world_state object
         world_state.cycle_progress = new_progress;
         world_state.time_of_day = new_time_of_day;
@@ -1217,6 +1484,12 @@ pub fn tick_world_state(ctx: &ReducerContext, _timestamp: Timestamp) -> Result<(
         // Update chunk-based weather after updating time
         update_chunk_weather_system(ctx, &world_state, elapsed_seconds)?;
         
+        // TIDES: Turn the tide when it's due
+        update_tide(ctx);
+        
+        // SEA ICE: Freeze cracked ice over again
+        crate::ice::refreeze_ice_holes(ctx);
+        
         // log::debug!("World tick: Progress {:.2}, Time: {:?}, Cycle: {}, Full Moon: {}, Season: {:?} (Day {} of Year {}), Weather: {:?}", 
         //            new_progress, world_state.time_of_day, new_cycle_count, new_is_full_moon, 
         //            world_state.current_season, world_state.day_of_year, world_state.year, world_state.current_weather);
@@ -1595,7 +1868,10 @@ fn update_single_chunk_weather(
                     log::warn!("Failed to schedule thunder sound: {}", e);
                 }
 
-                // 3. Schedule next thunder
+                // 3. Lightning strikes somewhere in the chunk (damage, fire, rare fulgurite)
+                crate::lightning::strike_lightning_in_chunk(ctx, chunk_index, rng);
+
+                // 4. Schedule next thunder
                 chunk_weather.last_thunder_time = Some(now);
                 let next_interval = rng.gen_range(MIN_THUNDER_INTERVAL_SECS..=MAX_THUNDER_INTERVAL_SECS);
                 chunk_weather.next_thunder_time = Some(now + TimeDuration::from_micros((next_interval * 1_000_000.0) as i64));
@@ -1842,6 +2118,12 @@ pub fn get_light_intensity(progress: f32) -> f32 {
     intensity.max(0.0).min(1.0) // Clamp just in case
 }
 
+/// Whether a blizzard is raging at a position (a heavy storm in winter falls as snow)
+pub fn is_blizzard_at_position(ctx: &ReducerContext, pos_x: f32, pos_y: f32) -> bool {
+    matches!(get_current_season(ctx), Ok(Season::Winter))
+        && get_weather_for_position(ctx, pos_x, pos_y).current_weather == WeatherType::HeavyStorm
+}
+
 /// Gets the current rain warmth drain modifier based on chunk weather and player position
 /// This should be ADDED to the base warmth drain (stacks with time-of-day multipliers)
 /// Returns 0.0 if player is protected by tree cover (within 100px of any tree)
@@ -2070,10 +2352,15 @@ pub fn manage_seasonal_plants(ctx: &ReducerContext, args: SeasonalPlantManagemen
 /// Removes respawn timers from plants that can't grow in the new season
 fn remove_non_seasonal_plant_respawns(ctx: &ReducerContext, new_season: &Season) -> Result<u32, String> {
     let mut removed_count = 0;
+    let greenhouse_footprints = crate::greenhouse::get_greenhouse_footprints(ctx);
     
     // Get all harvestable resources with respawn timers
     for mut resource in ctx.db.harvestable_resource().iter() {
         if resource.respawn_at > Timestamp::UNIX_EPOCH {
+            // Greenhouse crops keep regrowing out of season
+            if resource.is_player_planted && crate::greenhouse::is_position_in_any_greenhouse(&greenhouse_footprints, resource.pos_x, resource.pos_y) {
+                continue;
+            }
             // Check if this plant can grow in the new season
             if !crate::plants_database::can_grow_in_season(&resource.plant_type, new_season) {
                 // Remove the respawn timer - this plant won't come back until its season